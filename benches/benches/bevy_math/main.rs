@@ -1,5 +1,6 @@
 use criterion::criterion_main;
 
 mod bezier;
+mod spatial_hash;
 
-criterion_main!(bezier::benches);
+criterion_main!(bezier::benches, spatial_hash::benches);
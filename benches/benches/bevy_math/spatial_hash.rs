@@ -0,0 +1,64 @@
+use benches::bench;
+use bevy_math::{bounding::Aabb3d, prelude::*, spatial_hash::SpatialHash3d};
+use core::hint::black_box;
+use criterion::{criterion_group, BenchmarkId, Criterion};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+criterion_group!(benches, insert, query_sphere, ray_cast);
+
+const WORLD_SIZE: f32 = 1000.0;
+
+/// Returns `count` random points in a cube of side `WORLD_SIZE`.
+fn points(count: usize) -> Vec<Vec3> {
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    (0..count)
+        .map(|_| Vec3::from_array(rng.gen::<[f32; 3]>()) * WORLD_SIZE)
+        .collect()
+}
+
+fn grid(points: &[Vec3]) -> SpatialHash3d<usize> {
+    let mut grid = SpatialHash3d::new(10.0);
+    for (i, point) in points.iter().enumerate() {
+        grid.insert(i, Aabb3d::new(*point, Vec3::splat(2.0)));
+    }
+    grid
+}
+
+fn insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group(bench!("spatial_hash_insert"));
+    for count in [1_000, 10_000, 100_000] {
+        let points = points(count);
+        group.bench_with_input(BenchmarkId::from_parameter(count), &points, |b, points| {
+            b.iter(|| grid(black_box(points)));
+        });
+    }
+    group.finish();
+}
+
+fn query_sphere(c: &mut Criterion) {
+    let mut group = c.benchmark_group(bench!("spatial_hash_query_sphere"));
+    for count in [1_000, 10_000, 100_000] {
+        let points = points(count);
+        let grid = grid(&points);
+        group.bench_with_input(BenchmarkId::from_parameter(count), &points, |b, points| {
+            // The neighbors of 100 objects, as gameplay code would query them every frame.
+            b.iter(|| {
+                for point in &points[..100] {
+                    for neighbor in grid.query_sphere(*point, 20.0) {
+                        black_box(neighbor);
+                    }
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+fn ray_cast(c: &mut Criterion) {
+    let grid = grid(&points(100_000));
+    let ray = Ray3d::new(Vec3::ZERO, Dir3::new(Vec3::ONE).unwrap());
+    c.bench_function(bench!("spatial_hash_ray_cast"), |b| {
+        b.iter(|| grid.ray_cast(black_box(ray), WORLD_SIZE));
+    });
+}
@@ -1,6 +1,6 @@
 use crate::{
-    First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, Plugins, PluginsState, SubApp,
-    SubApps,
+    DisabledPlugins, First, Last, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin,
+    PluginDependencies, Plugins, PluginsState, SubApp, SubAppChannel, SubApps,
 };
 use alloc::{
     boxed::Box,
@@ -10,7 +10,10 @@ use alloc::{
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
     component::RequiredComponentsError,
-    event::{event_update_system, EventCursor},
+    event::{
+        event_update_system, record_events, replay_events, EventCursor, EventRecorder,
+        EventRecording, EventReplayer, EventUpdates,
+    },
     intern::Interned,
     prelude::*,
     schedule::{ScheduleBuildSettings, ScheduleLabel},
@@ -41,6 +44,63 @@ pub use bevy_ecs::label::DynEq;
 /// A shorthand for `Interned<dyn AppLabel>`.
 pub type InternedAppLabel = Interned<dyn AppLabel>;
 
+/// Sorts `plugins` so that each plugin comes after the plugins of `plugins` it depends on or is
+/// ordered after, keeping their relative order otherwise.
+fn sort_plugins_by_dependencies(plugins: Vec<Box<dyn Plugin>>) -> Vec<Box<dyn Plugin>> {
+    fn visit(
+        index: usize,
+        plugins: &[Box<dyn Plugin>],
+        edges: &[Vec<usize>],
+        visited: &mut [Option<bool>],
+        order: &mut Vec<usize>,
+    ) {
+        match visited[index] {
+            Some(true) => return,
+            Some(false) => panic!(
+                "Error adding plugin {}: plugin dependencies contain a cycle",
+                plugins[index].name()
+            ),
+            None => {}
+        }
+        visited[index] = Some(false);
+        for &dependency in &edges[index] {
+            visit(dependency, plugins, edges, visited, order);
+        }
+        visited[index] = Some(true);
+        order.push(index);
+    }
+
+    let edges: Vec<Vec<usize>> = plugins
+        .iter()
+        .map(|plugin| {
+            let mut dependencies = PluginDependencies::default();
+            plugin.dependencies(&mut dependencies);
+            dependencies
+                .ordering()
+                .flat_map(|name| {
+                    plugins
+                        .iter()
+                        .enumerate()
+                        .filter(move |(_, other)| other.name() == name)
+                        .map(|(index, _)| index)
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut visited = alloc::vec![None; plugins.len()];
+    let mut order = Vec::with_capacity(plugins.len());
+    for index in 0..plugins.len() {
+        visit(index, &plugins, &edges, &mut visited, &mut order);
+    }
+
+    let mut plugins: Vec<_> = plugins.into_iter().map(Some).collect();
+    order
+        .into_iter()
+        .filter_map(|index| plugins[index].take())
+        .collect()
+}
+
 #[derive(Debug, Error)]
 pub(crate) enum AppError {
     #[error("duplicate plugin {plugin_name:?}")]
@@ -104,10 +164,15 @@ impl Default for App {
         {
             app.init_resource::<AppTypeRegistry>();
             app.register_type::<Name>();
+            app.register_type::<bevy_ecs::entity_disabling::Disabled>();
         }
 
         #[cfg(feature = "reflect_functions")]
-        app.init_resource::<AppFunctionRegistry>();
+        {
+            app.init_resource::<AppFunctionRegistry>();
+            app.register_type::<bevy_reflect::func::FunctionReference>();
+            app.register_type::<bevy_ecs::reflect::Callback>();
+        }
 
         app.add_plugins(MainSchedulePlugin);
         app.add_systems(
@@ -359,6 +424,56 @@ impl App {
         self
     }
 
+    /// Records the `T` events sent each frame into the [`EventRecorder<T>`] resource,
+    /// by scheduling [`record_events`] in [`Last`].
+    ///
+    /// The recording can be taken with [`EventRecorder::take`] and fed back on a later run
+    /// using [`App::replay_events`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::event::EventRecorder;
+    /// #
+    /// # #[derive(Event, Clone)]
+    /// # struct MyEvent;
+    /// let mut app = App::new();
+    /// app.add_event::<MyEvent>().record_events::<MyEvent>();
+    ///
+    /// app.world_mut().send_event(MyEvent);
+    /// app.update();
+    ///
+    /// let recording = app.world_mut().resource_mut::<EventRecorder<MyEvent>>().take();
+    /// assert_eq!(recording.frame(0).unwrap().len(), 1);
+    /// ```
+    pub fn record_events<T>(&mut self) -> &mut Self
+    where
+        T: Event + Clone,
+    {
+        if !self.world().contains_resource::<EventRecorder<T>>() {
+            self.init_resource::<EventRecorder<T>>();
+            self.add_systems(Last, record_events::<T>);
+        }
+        self
+    }
+
+    /// Sends the `T` events of `recording` again, one recorded frame per app update,
+    /// by scheduling [`replay_events`] in [`First`] right after the event queues are updated.
+    ///
+    /// Replaces any recording that was already being replayed for `T`.
+    pub fn replay_events<T>(&mut self, recording: EventRecording<T>) -> &mut Self
+    where
+        T: Event + Clone,
+    {
+        if !self.world().contains_resource::<EventReplayer<T>>() {
+            self.add_systems(First, replay_events::<T>.after(EventUpdates));
+        }
+        self.insert_resource(EventReplayer::new(recording));
+        self
+    }
+
     /// Inserts the [`Resource`] into the app, overwriting any existing resource of the same type.
     ///
     /// There is also an [`init_resource`](Self::init_resource) for resources that have
@@ -456,12 +571,70 @@ impl App {
         plugin: Box<dyn Plugin>,
     ) -> Result<&mut Self, AppError> {
         debug!("added plugin: {}", plugin.name());
-        if plugin.is_unique() && self.main_mut().plugin_names.contains(plugin.name()) {
+        let main = self.main_mut();
+        if plugin.is_unique()
+            && (main.plugin_names.contains(plugin.name())
+                || main
+                    .plugin_batch
+                    .iter()
+                    .flatten()
+                    .any(|added| added.is_unique() && added.name() == plugin.name()))
+        {
             Err(AppError::DuplicatePlugin {
                 plugin_name: plugin.name().to_string(),
             })?;
         }
 
+        // Plugins added with `App::add_plugins` are built once sorted by their dependencies.
+        if let Some(batch) = &mut main.plugin_batch {
+            batch.push(plugin);
+            return Ok(self);
+        }
+
+        self.build_boxed_plugin(plugin);
+        Ok(self)
+    }
+
+    /// Adds `plugins`, then builds them after their [dependencies](Plugin::dependencies).
+    pub(crate) fn add_plugin_batch<M>(&mut self, plugins: impl Plugins<M>) {
+        // A batch is already being collected, for example by a plugin group.
+        if self.main().plugin_batch.is_some() {
+            plugins.add_to_app(self);
+            return;
+        }
+
+        self.main_mut().plugin_batch = Some(Vec::new());
+        plugins.add_to_app(self);
+        let batch = self.main_mut().plugin_batch.take().unwrap_or_default();
+
+        for plugin in sort_plugins_by_dependencies(batch) {
+            // A plugin built earlier in the batch may have added it already.
+            if plugin.is_unique() && self.main().plugin_names.contains(plugin.name()) {
+                debug!("skipped plugin already added: {}", plugin.name());
+                continue;
+            }
+            let mut dependencies = PluginDependencies::default();
+            plugin.dependencies(&mut dependencies);
+            for dependency in dependencies.required() {
+                if !self.main().plugin_names.contains(*dependency) {
+                    panic!(
+                        "Error adding plugin {}: plugin depends on {dependency}, which was not added to the application",
+                        plugin.name()
+                    );
+                }
+            }
+            self.build_boxed_plugin(plugin);
+        }
+    }
+
+    fn build_boxed_plugin(&mut self, plugin: Box<dyn Plugin>) {
+        #[cfg(feature = "plugin_config")]
+        let plugin = {
+            let mut plugin = plugin;
+            self.configure_plugin(&mut *plugin);
+            plugin
+        };
+
         // Reserve position in the plugin registry. If the plugin adds more plugins,
         // they'll all end up in insertion order.
         let index = self.main().plugin_registry.len();
@@ -470,6 +643,12 @@ impl App {
             .push(Box::new(PlaceholderPlugin));
 
         self.main_mut().plugin_build_depth += 1;
+        let can_disable = plugin.can_disable();
+        if can_disable {
+            self.main_mut()
+                .disableable_plugins_building
+                .push(plugin.name().to_string());
+        }
 
         let f = AssertUnwindSafe(|| plugin.build(self));
 
@@ -483,6 +662,9 @@ impl App {
             .plugin_names
             .insert(plugin.name().to_string());
         self.main_mut().plugin_build_depth -= 1;
+        if can_disable {
+            self.main_mut().disableable_plugins_building.pop();
+        }
 
         #[cfg(feature = "std")]
         if let Err(payload) = result {
@@ -490,7 +672,24 @@ impl App {
         }
 
         self.main_mut().plugin_registry[index] = plugin;
-        Ok(self)
+    }
+
+    /// Overrides the settings of `plugin` with the [`PluginConfig`](crate::PluginConfig), if any.
+    #[cfg(feature = "plugin_config")]
+    fn configure_plugin(&mut self, plugin: &mut dyn Plugin) {
+        let world = self.world();
+        let (Some(config), Some(registry)) = (
+            world.get_resource::<crate::PluginConfig>(),
+            world.get_resource::<AppTypeRegistry>(),
+        ) else {
+            return;
+        };
+        let Some(settings) = plugin.settings_mut() else {
+            return;
+        };
+        if let Err(error) = config.apply(settings, &registry.read()) {
+            log::error!("Failed to configure plugin {}: {error}", plugin.name());
+        }
     }
 
     /// Returns `true` if the [`Plugin`] has already been added.
@@ -501,6 +700,80 @@ impl App {
         self.main().is_plugin_added::<T>()
     }
 
+    /// Disables the plugin of type `T` at runtime: its systems stop running and its
+    /// [`Plugin::disable`] method is called. Does nothing if the plugin is already disabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the plugin wasn't added, or if it [can't be disabled](Plugin::can_disable).
+    pub fn disable_plugin<T: Plugin>(&mut self) -> &mut Self {
+        self.toggle_plugin::<T>(false)
+    }
+
+    /// Enables the plugin of type `T` again after it was disabled with [`App::disable_plugin`]:
+    /// its systems run again and its [`Plugin::enable`] method is called. Does nothing if the
+    /// plugin is already enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the plugin wasn't added, or if it [can't be disabled](Plugin::can_disable).
+    pub fn enable_plugin<T: Plugin>(&mut self) -> &mut Self {
+        self.toggle_plugin::<T>(true)
+    }
+
+    /// Returns `true` if the plugin of type `T` has been added and isn't
+    /// [disabled](App::disable_plugin).
+    pub fn is_plugin_enabled<T: Plugin>(&self) -> bool {
+        let Some(plugin) = self
+            .main()
+            .plugin_registry
+            .iter()
+            .find(|plugin| plugin.is::<T>())
+        else {
+            return false;
+        };
+        !self
+            .world()
+            .get_resource::<DisabledPlugins>()
+            .is_some_and(|disabled| disabled.0.contains(plugin.name()))
+    }
+
+    fn toggle_plugin<T: Plugin>(&mut self, enable: bool) -> &mut Self {
+        let plugins = core::mem::take(&mut self.main_mut().plugin_registry);
+        let Some(plugin) = plugins.iter().find(|plugin| plugin.is::<T>()) else {
+            self.main_mut().plugin_registry = plugins;
+            panic!(
+                "Error toggling plugin {}: plugin was not added to the application",
+                core::any::type_name::<T>()
+            );
+        };
+        if !plugin.can_disable() {
+            let name = plugin.name().to_string();
+            self.main_mut().plugin_registry = plugins;
+            panic!("Error toggling plugin {name}: plugin can't be disabled");
+        }
+
+        let mut disabled = self.world_mut().get_resource_or_init::<DisabledPlugins>();
+        let toggled = if enable {
+            disabled.0.remove(plugin.name())
+        } else {
+            disabled.0.insert(plugin.name().to_string())
+        };
+        if toggled {
+            if enable {
+                plugin.enable(self);
+            } else {
+                plugin.disable(self);
+            }
+        }
+
+        let main = self.main_mut();
+        // Keep the plugins added while toggling this one, after the others.
+        let added = core::mem::replace(&mut main.plugin_registry, plugins);
+        main.plugin_registry.extend(added);
+        self
+    }
+
     /// Returns a vector of references to all plugins of type `T` that have been added.
     ///
     /// This can be used to read the settings of any existing plugins.
@@ -544,6 +817,10 @@ impl App {
     /// You can also specify a group of [`Plugin`]s by using a tuple over [`Plugin`]s and
     /// [`PluginGroup`]s. See [`Plugins`] for more details.
     ///
+    /// The given plugins are built after their [dependencies](Plugin::dependencies), and in the
+    /// given order otherwise. A plugin that was already added by another plugin of the collection
+    /// when its turn comes is skipped.
+    ///
     /// ## Examples
     /// ```
     /// # use bevy_app::{prelude::*, PluginGroupBuilder, NoopPluginGroup as MinimalPlugins};
@@ -562,7 +839,8 @@ impl App {
     ///
     /// # Panics
     ///
-    /// Panics if one of the plugins had already been added to the application.
+    /// Panics if one of the plugins had already been added to the application, if one of their
+    /// required dependencies wasn't added, or if their dependencies contain a cycle.
     ///
     /// [`PluginGroup`]:super::PluginGroup
     #[track_caller]
@@ -575,7 +853,7 @@ impl App {
                 "Plugins cannot be added after App::cleanup() or App::finish() has been called."
             );
         }
-        plugins.add_to_app(self);
+        self.add_plugin_batch(plugins);
         self
     }
 
@@ -1109,6 +1387,40 @@ impl App {
         self.sub_apps.sub_apps.remove(&label.intern())
     }
 
+    /// Connects the main world and the [`SubApp`] with the given label with a [`SubAppChannel<T>`],
+    /// which is inserted as a resource in both worlds.
+    ///
+    /// Does nothing if they are already connected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sub-app doesn't exist, or if the main world already has a channel of type `T`
+    /// connected to another sub-app.
+    pub fn add_sub_app_channel<T: Send + Sync + 'static>(
+        &mut self,
+        label: impl AppLabel,
+    ) -> &mut Self {
+        let label = label.intern();
+        if let Some(channel) = self.world().get_resource::<SubAppChannel<T>>() {
+            assert_eq!(
+                channel.sub_app(),
+                label,
+                "The main world already has a channel of type {} connected to another sub-app.",
+                core::any::type_name::<T>(),
+            );
+            return self;
+        }
+        let Some(sub_app) = self.sub_apps.sub_apps.get_mut(&label) else {
+            panic!("No sub-app with label '{:?}' exists.", label);
+        };
+        sub_app.insert_resource(SubAppChannel::<T>::new(label));
+        sub_app
+            .channels
+            .push(Box::new(SubAppChannel::<T>::exchange));
+        self.insert_resource(SubAppChannel::<T>::new(label));
+        self
+    }
+
     /// Extract data from the main world into the [`SubApp`] with the given label and perform an update if it exists.
     pub fn update_sub_app_by_label(&mut self, label: impl AppLabel) {
         self.sub_apps.update_subapp_by_label(label);
@@ -1408,6 +1720,7 @@ impl Termination for AppExit {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
     use core::{iter, marker::PhantomData};
     use std::sync::Mutex;
 
@@ -1423,7 +1736,10 @@ mod tests {
         world::{FromWorld, World},
     };
 
-    use crate::{App, AppExit, Plugin, SubApp, Update};
+    use crate::{
+        self as bevy_app, App, AppExit, AppLabel, Plugin, PluginDependencies, SubApp,
+        SubAppChannel, Update,
+    };
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1478,6 +1794,214 @@ mod tests {
         App::new().add_plugins((PluginD, PluginD));
     }
 
+    #[derive(Resource, Default)]
+    struct BuildOrder(Vec<&'static str>);
+
+    struct PhysicsPlugin;
+    impl Plugin for PhysicsPlugin {
+        fn build(&self, app: &mut App) {
+            app.world_mut()
+                .get_resource_or_init::<BuildOrder>()
+                .0
+                .push("physics");
+        }
+    }
+
+    struct RagdollPlugin;
+    impl Plugin for RagdollPlugin {
+        fn build(&self, app: &mut App) {
+            app.world_mut()
+                .get_resource_or_init::<BuildOrder>()
+                .0
+                .push("ragdoll");
+        }
+
+        fn dependencies(&self, dependencies: &mut PluginDependencies) {
+            dependencies.depends_on::<PhysicsPlugin>();
+        }
+    }
+
+    struct DebugPlugin;
+    impl Plugin for DebugPlugin {
+        fn build(&self, app: &mut App) {
+            app.world_mut()
+                .get_resource_or_init::<BuildOrder>()
+                .0
+                .push("debug");
+        }
+
+        fn dependencies(&self, dependencies: &mut PluginDependencies) {
+            dependencies.after::<RagdollPlugin>().after::<PluginA>();
+        }
+    }
+
+    #[test]
+    fn plugins_are_built_after_their_dependencies() {
+        let mut app = App::new();
+        app.add_plugins((DebugPlugin, RagdollPlugin, PhysicsPlugin));
+        assert_eq!(
+            app.world().resource::<BuildOrder>().0,
+            ["physics", "ragdoll", "debug"]
+        );
+
+        // Dependencies can be added beforehand.
+        let mut app = App::new();
+        app.add_plugins(PhysicsPlugin).add_plugins(RagdollPlugin);
+        assert_eq!(
+            app.world().resource::<BuildOrder>().0,
+            ["physics", "ragdoll"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "depends on bevy_app::app::tests::PhysicsPlugin")]
+    fn cant_add_plugin_without_its_dependencies() {
+        App::new().add_plugins((RagdollPlugin, DebugPlugin));
+    }
+
+    #[test]
+    #[should_panic(expected = "plugin dependencies contain a cycle")]
+    fn cant_add_plugins_with_cyclic_dependencies() {
+        struct PingPlugin;
+        impl Plugin for PingPlugin {
+            fn build(&self, _app: &mut App) {}
+
+            fn dependencies(&self, dependencies: &mut PluginDependencies) {
+                dependencies.depends_on::<PongPlugin>();
+            }
+        }
+        struct PongPlugin;
+        impl Plugin for PongPlugin {
+            fn build(&self, _app: &mut App) {}
+
+            fn dependencies(&self, dependencies: &mut PluginDependencies) {
+                dependencies.after::<PingPlugin>();
+            }
+        }
+
+        App::new().add_plugins((PingPlugin, PongPlugin));
+    }
+
+    #[test]
+    fn plugins_added_by_earlier_plugins_are_skipped() {
+        struct AddsPhysicsPlugin;
+        impl Plugin for AddsPhysicsPlugin {
+            fn build(&self, app: &mut App) {
+                app.add_plugins(PhysicsPlugin);
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins((AddsPhysicsPlugin, PhysicsPlugin));
+        assert_eq!(app.world().resource::<BuildOrder>().0, ["physics"]);
+    }
+
+    #[test]
+    fn disable_and_enable_plugin() {
+        #[derive(Resource, Default)]
+        struct Ticks(u32);
+
+        #[derive(Resource)]
+        struct Overlay;
+
+        struct OverlayPlugin;
+        impl Plugin for OverlayPlugin {
+            fn build(&self, app: &mut App) {
+                app.init_resource::<Ticks>()
+                    .insert_resource(Overlay)
+                    .add_systems(Update, |mut ticks: ResMut<Ticks>| ticks.0 += 1);
+            }
+
+            fn can_disable(&self) -> bool {
+                true
+            }
+
+            fn disable(&self, app: &mut App) {
+                app.world_mut().remove_resource::<Overlay>();
+            }
+
+            fn enable(&self, app: &mut App) {
+                app.insert_resource(Overlay);
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(OverlayPlugin);
+        app.update();
+        assert_eq!(app.world().resource::<Ticks>().0, 1);
+
+        app.disable_plugin::<OverlayPlugin>();
+        assert!(!app.is_plugin_enabled::<OverlayPlugin>());
+        assert!(!app.world().contains_resource::<Overlay>());
+        app.update();
+        assert_eq!(app.world().resource::<Ticks>().0, 1);
+
+        app.enable_plugin::<OverlayPlugin>();
+        assert!(app.is_plugin_enabled::<OverlayPlugin>());
+        assert!(app.world().contains_resource::<Overlay>());
+        app.update();
+        assert_eq!(app.world().resource::<Ticks>().0, 2);
+    }
+
+    #[test]
+    fn disable_plugin_with_custom_name() {
+        struct NamedPlugin;
+        impl Plugin for NamedPlugin {
+            fn build(&self, _app: &mut App) {}
+
+            fn name(&self) -> &str {
+                "named"
+            }
+
+            fn can_disable(&self) -> bool {
+                true
+            }
+        }
+
+        let mut app = App::new();
+        assert!(!app.is_plugin_enabled::<NamedPlugin>());
+        app.add_plugins(NamedPlugin);
+        assert!(app.is_plugin_enabled::<NamedPlugin>());
+
+        app.disable_plugin::<NamedPlugin>();
+        assert!(!app.is_plugin_enabled::<NamedPlugin>());
+
+        app.enable_plugin::<NamedPlugin>();
+        assert!(app.is_plugin_enabled::<NamedPlugin>());
+    }
+
+    #[test]
+    #[should_panic(expected = "plugin can't be disabled")]
+    fn cant_disable_plugin_by_default() {
+        let mut app = App::new();
+        app.add_plugins(PluginA);
+        app.disable_plugin::<PluginA>();
+    }
+
+    #[test]
+    fn fixed_rate_runner() {
+        #[derive(Resource, Default)]
+        struct Updates(u32);
+
+        let tick_rate = core::time::Duration::from_millis(5);
+        let mut app = App::new();
+        app.add_plugins(crate::ScheduleRunnerPlugin::run_fixed_rate(tick_rate))
+            .init_resource::<Updates>()
+            .add_systems(
+                Update,
+                |mut updates: ResMut<Updates>, mut exits: EventWriter<AppExit>| {
+                    updates.0 += 1;
+                    if updates.0 == 4 {
+                        exits.send(AppExit::Success);
+                    }
+                },
+            );
+
+        let start = std::time::Instant::now();
+        assert_eq!(app.run(), AppExit::Success);
+        assert!(start.elapsed() >= tick_rate * 3);
+    }
+
     #[test]
     #[should_panic]
     fn cant_call_app_run_from_plugin_build() {
@@ -1816,4 +2340,100 @@ mod tests {
         assert_eq!(test_events.len(), 2); // Events are double-buffered, so we see 2 + 0 = 2
         assert_eq!(test_events.iter_current_update_events().count(), 0);
     }
+
+    #[test]
+    fn replay_recorded_events() {
+        use bevy_ecs::event::{EventReader, EventRecorder, EventReplayer};
+
+        #[derive(Event, Clone, PartialEq, Debug)]
+        struct TestEvent(u32);
+
+        #[derive(Resource, Default)]
+        struct Seen(Vec<u32>);
+
+        fn read_events(mut events: EventReader<TestEvent>, mut seen: ResMut<Seen>) {
+            seen.0.extend(events.read().map(|event| event.0));
+        }
+
+        let mut app = App::new();
+        app.add_event::<TestEvent>()
+            .record_events::<TestEvent>()
+            .init_resource::<Seen>()
+            .add_systems(Update, read_events);
+        app.world_mut().send_event(TestEvent(1));
+        app.update();
+        app.update();
+        app.world_mut().send_event(TestEvent(2));
+        app.world_mut().send_event(TestEvent(3));
+        app.update();
+        assert_eq!(app.world().resource::<Seen>().0, [1, 2, 3]);
+
+        let recording = app
+            .world_mut()
+            .resource_mut::<EventRecorder<TestEvent>>()
+            .take();
+        assert_eq!(recording.len(), 3);
+
+        let mut app = App::new();
+        app.add_event::<TestEvent>()
+            .replay_events(recording)
+            .init_resource::<Seen>()
+            .add_systems(Update, read_events);
+        app.update();
+        assert_eq!(app.world().resource::<Seen>().0, [1]);
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Seen>().0, [1, 2, 3]);
+        assert!(app
+            .world()
+            .resource::<EventReplayer<TestEvent>>()
+            .is_finished());
+    }
+
+    #[test]
+    fn sub_app_channel() {
+        #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AppLabel)]
+        struct Simulation;
+
+        let mut sub_app = SubApp::new();
+        sub_app.update_schedule = Some(Update.intern());
+        sub_app.add_systems(Update, |mut channel: ResMut<SubAppChannel<u32>>| {
+            let received: Vec<_> = channel.receive().collect();
+            channel.send_batch(received.into_iter().map(|n| n * 10));
+        });
+
+        let mut app = App::new();
+        app.insert_sub_app(Simulation, sub_app);
+        app.add_sub_app_channel::<u32>(Simulation)
+            .add_sub_app_channel::<u32>(Simulation);
+
+        let mut channel = app.world_mut().resource_mut::<SubAppChannel<u32>>();
+        channel.send(1);
+        channel.send(2);
+        app.update();
+        assert!(app.world().resource::<SubAppChannel<u32>>().is_empty());
+        app.update();
+        let received: Vec<_> = app
+            .world_mut()
+            .resource_mut::<SubAppChannel<u32>>()
+            .receive()
+            .collect();
+        assert_eq!(received, [10, 20]);
+    }
+
+    #[test]
+    #[should_panic(expected = "already has a channel of type u32 connected to another sub-app")]
+    fn sub_app_channel_connected_twice() {
+        #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AppLabel)]
+        struct Simulation;
+
+        #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AppLabel)]
+        struct Other;
+
+        let mut app = App::new();
+        app.insert_sub_app(Simulation, SubApp::new());
+        app.insert_sub_app(Other, SubApp::new());
+        app.add_sub_app_channel::<u32>(Simulation)
+            .add_sub_app_channel::<u32>(Other);
+    }
 }
@@ -29,9 +29,12 @@ mod app;
 mod main_schedule;
 mod panic_handler;
 mod plugin;
+#[cfg(feature = "plugin_config")]
+mod plugin_config;
 mod plugin_group;
 mod schedule_runner;
 mod sub_app;
+mod sub_app_channel;
 #[cfg(feature = "bevy_tasks")]
 mod task_pool_plugin;
 #[cfg(all(any(unix, windows), feature = "std"))]
@@ -41,9 +44,12 @@ pub use app::*;
 pub use main_schedule::*;
 pub use panic_handler::*;
 pub use plugin::*;
+#[cfg(feature = "plugin_config")]
+pub use plugin_config::*;
 pub use plugin_group::*;
 pub use schedule_runner::*;
 pub use sub_app::*;
+pub use sub_app_channel::*;
 #[cfg(feature = "bevy_tasks")]
 pub use task_pool_plugin::*;
 #[cfg(all(any(unix, windows), feature = "std"))]
@@ -1,4 +1,7 @@
 use crate::App;
+use alloc::{string::String, vec::Vec};
+use bevy_ecs::system::{Res, Resource};
+use bevy_utils::HashSet;
 use core::any::Any;
 use downcast_rs::{impl_downcast, Downcast};
 
@@ -21,6 +24,43 @@ use downcast_rs::{impl_downcast, Downcast};
 /// * it will then call all registered [`Plugin::finish`]
 /// * and call all registered [`Plugin::cleanup`]
 ///
+/// ## Disabling a plugin at runtime
+///
+/// A plugin that returns `true` from [`Plugin::can_disable`] can be disabled with
+/// [`App::disable_plugin`] and enabled again with [`App::enable_plugin`], for example to toggle
+/// an in-game editor. While it is disabled, the systems it added with [`App::add_systems`] don't
+/// run, and [`Plugin::disable`] and [`Plugin::enable`] let it tear down and restore its other
+/// state, like its resources.
+///
+/// ## Plugin dependencies
+///
+/// A plugin can declare the plugins it depends on in [`Plugin::dependencies`]. The plugins added
+/// with the same call to [`App::add_plugins`] are built after their dependencies, whatever the order
+/// they were given in, and adding a plugin without one of its required dependencies panics.
+///
+/// ```
+/// # use bevy_app::*;
+/// pub struct PhysicsPlugin;
+///
+/// impl Plugin for PhysicsPlugin {
+///     fn build(&self, app: &mut App) {}
+/// }
+///
+/// pub struct RagdollPlugin;
+///
+/// impl Plugin for RagdollPlugin {
+///     fn build(&self, app: &mut App) {
+///         assert!(app.is_plugin_added::<PhysicsPlugin>());
+///     }
+///
+///     fn dependencies(&self, dependencies: &mut PluginDependencies) {
+///         dependencies.depends_on::<PhysicsPlugin>();
+///     }
+/// }
+///
+/// App::new().add_plugins((RagdollPlugin, PhysicsPlugin));
+/// ```
+///
 /// ## Defining a plugin.
 ///
 /// Most plugins are simply functions that add configuration to an [`App`].
@@ -89,6 +129,43 @@ pub trait Plugin: Downcast + Any + Send + Sync {
     fn is_unique(&self) -> bool {
         true
     }
+
+    /// If the plugin can be disabled at runtime with [`App::disable_plugin`],
+    /// override this method to return `true`.
+    ///
+    /// The systems added by such a plugin during its [`build`](Plugin::build) don't run while the
+    /// plugin is disabled. This adds a run condition to each of them, so it is opt-in.
+    fn can_disable(&self) -> bool {
+        false
+    }
+
+    /// Tears down the plugin when it is disabled with [`App::disable_plugin`], for example by
+    /// removing the resources it inserted. Its systems are stopped automatically.
+    fn disable(&self, _app: &mut App) {
+        // do nothing
+    }
+
+    /// Restores the plugin when it is enabled again with [`App::enable_plugin`], undoing
+    /// [`disable`](Plugin::disable).
+    fn enable(&self, _app: &mut App) {
+        // do nothing
+    }
+
+    /// Declares the plugins this plugin depends on, and the plugins it should be built after.
+    ///
+    /// See [`PluginDependencies`] for more details.
+    fn dependencies(&self, _dependencies: &mut PluginDependencies) {
+        // no dependencies
+    }
+
+    /// Returns the settings of the plugin, which are overridden by the `PluginConfig` of the
+    /// [`App`], if any, right before the plugin is built.
+    ///
+    /// The settings must be a reflected struct, which can be the plugin itself.
+    #[cfg(feature = "bevy_reflect")]
+    fn settings_mut(&mut self) -> Option<&mut dyn bevy_reflect::PartialReflect> {
+        None
+    }
 }
 
 impl_downcast!(Plugin);
@@ -99,6 +176,57 @@ impl<T: Fn(&mut App) + Send + Sync + 'static> Plugin for T {
     }
 }
 
+/// The dependencies of a [`Plugin`], declared in [`Plugin::dependencies`].
+///
+/// Plugins are identified by their type name, which is the default [`Plugin::name`].
+#[derive(Default, Debug, Clone)]
+pub struct PluginDependencies {
+    pub(crate) required: Vec<&'static str>,
+    pub(crate) after: Vec<&'static str>,
+}
+
+impl PluginDependencies {
+    /// Requires the plugin `P` to be added to the [`App`] before this plugin is built.
+    ///
+    /// If both plugins are added with the same call to [`App::add_plugins`], `P` is built first.
+    /// Otherwise, `P` must have been added beforehand.
+    pub fn depends_on<P: Plugin>(&mut self) -> &mut Self {
+        self.required.push(core::any::type_name::<P>());
+        self
+    }
+
+    /// Builds this plugin after the plugin `P` if both are added with the same call to
+    /// [`App::add_plugins`], without requiring `P` to be added.
+    pub fn after<P: Plugin>(&mut self) -> &mut Self {
+        self.after.push(core::any::type_name::<P>());
+        self
+    }
+
+    /// Returns the type names of the plugins required by this plugin.
+    pub fn required(&self) -> &[&'static str] {
+        &self.required
+    }
+
+    /// Returns the type names of the plugins this plugin is built after when they are added
+    /// with it.
+    pub fn ordering(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.required.iter().chain(&self.after).copied()
+    }
+}
+
+/// The names of the plugins disabled with [`App::disable_plugin`].
+#[derive(Resource, Default, Debug)]
+pub(crate) struct DisabledPlugins(pub(crate) HashSet<String>);
+
+/// A run condition that returns `true` if none of the plugins named `plugins` is disabled.
+pub(crate) fn plugins_enabled(
+    plugins: Vec<String>,
+) -> impl FnMut(Option<Res<DisabledPlugins>>) -> bool + Clone {
+    move |disabled: Option<Res<DisabledPlugins>>| {
+        disabled.is_none_or(|disabled| !plugins.iter().any(|plugin| disabled.0.contains(plugin)))
+    }
+}
+
 /// Plugins state in the application
 #[derive(PartialEq, Eq, Debug, Clone, Copy, PartialOrd, Ord)]
 pub enum PluginsState {
@@ -0,0 +1,402 @@
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_ecs::system::Resource;
+use bevy_reflect::{
+    serde::TypedReflectDeserializer, ApplyError, PartialReflect, ReflectMut, ReflectRef, Struct,
+    TypeRegistration, TypeRegistry,
+};
+use core::fmt;
+use ron::error::SpannedError;
+use serde::de::{
+    Deserialize, DeserializeSeed, Deserializer, Error as _, IgnoredAny, MapAccess, Visitor,
+};
+use std::path::Path;
+use thiserror::Error;
+
+/// A [`Resource`] overriding the settings of the plugins added to the [`App`](crate::App),
+/// right before they are built.
+///
+/// A plugin exposes its settings with [`Plugin::settings_mut`](crate::Plugin::settings_mut), as a
+/// reflected struct registered in the [`AppTypeRegistry`](bevy_ecs::reflect::AppTypeRegistry),
+/// along with the types of its fields. Its fields are overridden by the section of the RON config
+/// named after the short type path of the settings, then by the environment variables named
+/// `<PREFIX>_<SETTINGS>_<FIELD>` in screaming snake case, if an
+/// [environment prefix](Self::with_env_prefix) was set. The values of the environment variables
+/// are in RON as well.
+///
+/// The config must be inserted before the plugins are added, and fields missing from it keep
+/// their value.
+///
+/// ```
+/// # use bevy_app::{App, Plugin, PluginConfig};
+/// # use bevy_reflect::{PartialReflect, Reflect};
+/// #[derive(Reflect)]
+/// struct NetworkPlugin {
+///     port: u16,
+///     host: String,
+/// }
+///
+/// impl Plugin for NetworkPlugin {
+///     fn build(&self, app: &mut App) {
+///         assert_eq!(self.port, 8080);
+///         assert_eq!(self.host, "localhost");
+///     }
+///
+///     fn settings_mut(&mut self) -> Option<&mut dyn PartialReflect> {
+///         Some(self)
+///     }
+/// }
+///
+/// let config = PluginConfig::from_ron("(NetworkPlugin: (port: 8080))").unwrap();
+/// App::new()
+///     .insert_resource(config)
+///     .register_type::<NetworkPlugin>()
+///     .add_plugins(NetworkPlugin {
+///         port: 80,
+///         host: "localhost".to_string(),
+///     });
+/// ```
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PluginConfig {
+    source: Option<String>,
+    env_prefix: Option<String>,
+}
+
+/// An error that occurred while overriding plugin settings with a [`PluginConfig`].
+#[derive(Error, Debug)]
+pub enum PluginConfigError {
+    /// The config file couldn't be read.
+    #[error("could not read the plugin config file: {0}")]
+    Io(#[from] std::io::Error),
+    /// The config is not valid RON, or its values don't match the settings.
+    #[error("invalid plugin config: {0}")]
+    Parse(#[from] SpannedError),
+    /// The settings exposed by a plugin are not a struct.
+    #[error("the settings `{0}` are not a struct")]
+    NotAStruct(String),
+    /// The value of an environment variable doesn't match the field it overrides.
+    #[error("invalid value for the environment variable `{variable}`: {error}")]
+    InvalidEnvVar {
+        /// The name of the environment variable.
+        variable: String,
+        /// The error that occurred while parsing its value.
+        error: SpannedError,
+    },
+    /// A value couldn't be applied to a field of the settings.
+    #[error("could not set the field `{field}` of the settings `{settings}`: {error}")]
+    Apply {
+        /// The short type path of the settings.
+        settings: String,
+        /// The name of the field.
+        field: String,
+        /// The error that occurred while applying the value.
+        error: ApplyError,
+    },
+}
+
+impl PluginConfig {
+    /// Creates a config from RON source, which is a struct or a map whose fields are the
+    /// sections of the settings to override.
+    pub fn from_ron(source: impl Into<String>) -> Result<Self, PluginConfigError> {
+        let source = source.into();
+        ron::from_str::<IgnoredAny>(&source)?;
+        Ok(Self {
+            source: Some(source),
+            env_prefix: None,
+        })
+    }
+
+    /// Loads a config from a RON file, see [`PluginConfig::from_ron`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, PluginConfigError> {
+        Self::from_ron(std::fs::read_to_string(path)?)
+    }
+
+    /// Also overrides the settings with environment variables starting with `prefix`.
+    pub fn with_env_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.env_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Overrides the fields of `settings` with this config.
+    pub fn apply(
+        &self,
+        settings: &mut dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> Result<(), PluginConfigError> {
+        let section = settings.reflect_short_type_path().to_owned();
+        let ReflectRef::Struct(fields) = settings.reflect_ref() else {
+            return Err(PluginConfigError::NotAStruct(section));
+        };
+
+        let mut values = Vec::new();
+        let seed = SettingsSeed {
+            section: &section,
+            fields,
+            registry,
+        };
+        if let Some(source) = &self.source {
+            let mut deserializer = ron::Deserializer::from_str(source)?;
+            values = ConfigSeed(seed)
+                .deserialize(&mut deserializer)
+                .map_err(|error| deserializer.span_error(error))?;
+        }
+        if let Some(prefix) = &self.env_prefix {
+            let prefix = format!("{prefix}_{}", screaming_snake_case(&section));
+            for index in 0..fields.field_len() {
+                let field = fields.name_at(index).unwrap_or_default();
+                let variable = format!("{prefix}_{}", screaming_snake_case(field));
+                let Ok(value) = std::env::var(&variable) else {
+                    continue;
+                };
+                let value = seed.deserialize_env_var(field, &value).map_err(|error| {
+                    PluginConfigError::InvalidEnvVar {
+                        variable: variable.clone(),
+                        error,
+                    }
+                })?;
+                values.push((field.to_string(), value));
+            }
+        }
+
+        let ReflectMut::Struct(fields) = settings.reflect_mut() else {
+            return Err(PluginConfigError::NotAStruct(section));
+        };
+        for (field, value) in values {
+            if let Some(target) = fields.field_mut(&field) {
+                target
+                    .try_apply(&*value)
+                    .map_err(|error| PluginConfigError::Apply {
+                        settings: section.clone(),
+                        field,
+                        error,
+                    })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Converts `name`, in camel case or snake case, to screaming snake case.
+fn screaming_snake_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    let mut previous_is_lowercase = false;
+    for c in name.chars() {
+        if c.is_uppercase() && previous_is_lowercase {
+            result.push('_');
+        }
+        previous_is_lowercase = c.is_lowercase() || c.is_ascii_digit();
+        result.extend(c.to_uppercase());
+    }
+    result
+}
+
+type FieldValues = Vec<(String, Box<dyn PartialReflect>)>;
+
+/// Deserializes the values of the fields of the settings named `section`.
+#[derive(Clone, Copy)]
+struct SettingsSeed<'a> {
+    section: &'a str,
+    fields: &'a dyn Struct,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> SettingsSeed<'a> {
+    fn registration(&self, field: &str) -> Result<&'a TypeRegistration, String> {
+        let Some(value) = self.fields.field(field) else {
+            return Err(format!(
+                "the settings `{}` have no field `{field}`",
+                self.section
+            ));
+        };
+        value
+            .get_represented_type_info()
+            .and_then(|info| self.registry.get(info.type_id()))
+            .ok_or_else(|| {
+                format!(
+                    "the type of the field `{field}` of the settings `{}` is not registered",
+                    self.section
+                )
+            })
+    }
+
+    fn deserialize_env_var(
+        &self,
+        field: &str,
+        value: &str,
+    ) -> Result<Box<dyn PartialReflect>, SpannedError> {
+        let mut deserializer = ron::Deserializer::from_str(value)?;
+        let registration = self
+            .registration(field)
+            .map_err(|error| deserializer.span_error(ron::Error::custom(error)))?;
+        let value = TypedReflectDeserializer::new(registration, self.registry)
+            .deserialize(&mut deserializer)
+            .map_err(|error| deserializer.span_error(error))?;
+        deserializer
+            .end()
+            .map_err(|error| deserializer.span_error(error))?;
+        Ok(value)
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for SettingsSeed<'_> {
+    type Value = FieldValues;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for SettingsSeed<'_> {
+    type Value = FieldValues;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the fields of the settings `{}`", self.section)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(Key(field)) = map.next_key()? {
+            let registration = self.registration(&field).map_err(A::Error::custom)?;
+            let value =
+                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+            values.push((field, value));
+        }
+        Ok(values)
+    }
+}
+
+/// Deserializes the section of a config matching the settings of its [`SettingsSeed`], ignoring
+/// the other sections.
+struct ConfigSeed<'a>(SettingsSeed<'a>);
+
+impl<'de> DeserializeSeed<'de> for ConfigSeed<'_> {
+    type Value = FieldValues;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ConfigSeed<'_> {
+    type Value = FieldValues;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of plugin settings")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(Key(section)) = map.next_key()? {
+            if section == self.0.section {
+                values.extend(map.next_value_seed(self.0)?);
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(values)
+    }
+}
+
+/// A map key, which RON also allows to be an identifier when the map is written as a struct.
+struct Key(String);
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(KeyVisitor)
+    }
+}
+
+struct KeyVisitor;
+
+impl<'de> Visitor<'de> for KeyVisitor {
+    type Value = Key;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a field name")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Key(value.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_reflect::Reflect;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Quality {
+        Low,
+        High,
+    }
+
+    #[derive(Reflect, Debug)]
+    struct GraphicsSettings {
+        quality: Quality,
+        max_fps: u32,
+        vsync: bool,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<GraphicsSettings>();
+        registry
+    }
+
+    fn settings() -> GraphicsSettings {
+        GraphicsSettings {
+            quality: Quality::Low,
+            max_fps: 60,
+            vsync: false,
+        }
+    }
+
+    #[test]
+    fn apply_config_section() {
+        let config = PluginConfig::from_ron(
+            "(
+                AudioSettings: (volume: 0.5),
+                GraphicsSettings: (quality: High, vsync: true),
+            )",
+        )
+        .unwrap();
+        let mut settings = settings();
+        config.apply(&mut settings, &registry()).unwrap();
+        assert_eq!(settings.quality, Quality::High);
+        assert_eq!(settings.max_fps, 60);
+        assert!(settings.vsync);
+
+        let config = PluginConfig::from_ron("(GraphicsSettings: (fps: 30))").unwrap();
+        assert!(matches!(
+            config.apply(&mut settings, &registry()),
+            Err(PluginConfigError::Parse(_))
+        ));
+        assert!(PluginConfig::from_ron("(GraphicsSettings: (").is_err());
+    }
+
+    #[test]
+    fn apply_env_vars() {
+        std::env::set_var("PLUGIN_CONFIG_TEST_GRAPHICS_SETTINGS_MAX_FPS", "144");
+        std::env::set_var("PLUGIN_CONFIG_TEST_GRAPHICS_SETTINGS_QUALITY", "High");
+        let config = PluginConfig::from_ron("(GraphicsSettings: (max_fps: 30, vsync: true))")
+            .unwrap()
+            .with_env_prefix("PLUGIN_CONFIG_TEST");
+        let mut settings = settings();
+        config.apply(&mut settings, &registry()).unwrap();
+        assert_eq!(settings.quality, Quality::High);
+        assert_eq!(settings.max_fps, 144);
+        assert!(settings.vsync);
+
+        std::env::set_var("PLUGIN_CONFIG_TEST_GRAPHICS_SETTINGS_MAX_FPS", "fast");
+        assert!(matches!(
+            config.apply(&mut settings, &registry()),
+            Err(PluginConfigError::InvalidEnvVar { .. })
+        ));
+    }
+}
@@ -257,6 +257,14 @@ impl PluginGroupBuilder {
             .is_some_and(|e| e.enabled)
     }
 
+    /// Returns a mutable reference to the given [`Plugin`], if the [`PluginGroupBuilder`]
+    /// contains it, to change its settings without replacing the ones already set.
+    pub fn get_mut<T: Plugin>(&mut self) -> Option<&mut T> {
+        self.plugins
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|entry| entry.plugin.downcast_mut())
+    }
+
     /// Finds the index of a target [`Plugin`].
     fn index_of<Target: Plugin>(&self) -> Option<usize> {
         self.order
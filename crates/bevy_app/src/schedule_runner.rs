@@ -28,6 +28,25 @@ pub enum RunMode {
     },
     /// Indicates that the [`App`]'s schedule should run only once.
     Once,
+    /// Indicates that the [`App`]'s schedule should run at a fixed tick rate, as dedicated
+    /// servers do.
+    ///
+    /// The runner sleeps until the next tick is due. When an update takes longer than
+    /// `tick_rate`, the following updates run without sleeping to catch up, until the runner is
+    /// more than `max_catch_up_ticks` ticks late, in which case the missed ticks are dropped.
+    ///
+    /// On platforms where the `Ctrl+C` handler is supported, the runner installs it unless
+    /// [`TerminalCtrlCHandlerPlugin`](crate::TerminalCtrlCHandlerPlugin) was added, so that the
+    /// app exits gracefully when interrupted.
+    ///
+    /// Without the `std` feature or on `wasm32`, this behaves like [`RunMode::Loop`] waiting
+    /// for `tick_rate`.
+    FixedRate {
+        /// The target [`Duration`] between the start of two updates.
+        tick_rate: Duration,
+        /// The maximum number of late updates run back to back before missed ticks are dropped.
+        max_catch_up_ticks: u32,
+    },
 }
 
 impl Default for RunMode {
@@ -70,6 +89,26 @@ impl ScheduleRunnerPlugin {
             },
         }
     }
+
+    /// See [`RunMode::FixedRate`]. The runner catches up at most 5 late ticks.
+    ///
+    /// ```no_run
+    /// # use bevy_app::{App, ScheduleRunnerPlugin};
+    /// # use core::time::Duration;
+    /// App::new()
+    ///     .add_plugins(ScheduleRunnerPlugin::run_fixed_rate(Duration::from_secs_f64(
+    ///         1.0 / 30.0,
+    ///     )))
+    ///     .run();
+    /// ```
+    pub fn run_fixed_rate(tick_rate: Duration) -> Self {
+        ScheduleRunnerPlugin {
+            run_mode: RunMode::FixedRate {
+                tick_rate,
+                max_catch_up_ticks: 5,
+            },
+        }
+    }
 }
 
 impl Plugin for ScheduleRunnerPlugin {
@@ -86,6 +125,14 @@ impl Plugin for ScheduleRunnerPlugin {
                 app.cleanup();
             }
 
+            #[cfg(not(all(feature = "std", not(target_arch = "wasm32"))))]
+            let run_mode = match run_mode {
+                RunMode::FixedRate { tick_rate, .. } => RunMode::Loop {
+                    wait: Some(tick_rate),
+                },
+                run_mode => run_mode,
+            };
+
             match run_mode {
                 RunMode::Once => {
                     app.update();
@@ -96,6 +143,13 @@ impl Plugin for ScheduleRunnerPlugin {
 
                     AppExit::Success
                 }
+                #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+                RunMode::FixedRate {
+                    tick_rate,
+                    max_catch_up_ticks,
+                } => run_fixed_rate(app, tick_rate, max_catch_up_ticks),
+                #[cfg(not(all(feature = "std", not(target_arch = "wasm32"))))]
+                RunMode::FixedRate { .. } => unreachable!("converted to `RunMode::Loop` above"),
                 RunMode::Loop { wait } => {
                     let tick = move |app: &mut App,
                                      _wait: Option<Duration>|
@@ -181,3 +235,40 @@ impl Plugin for ScheduleRunnerPlugin {
         });
     }
 }
+
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+fn run_fixed_rate(mut app: App, tick_rate: Duration, max_catch_up_ticks: u32) -> AppExit {
+    #[cfg(any(unix, windows))]
+    if !app.is_plugin_added::<crate::TerminalCtrlCHandlerPlugin>() {
+        crate::TerminalCtrlCHandlerPlugin::install_handler();
+        app.add_systems(
+            crate::Update,
+            crate::TerminalCtrlCHandlerPlugin::exit_on_flag,
+        );
+    }
+
+    let mut next_tick = Instant::now();
+    let mut late_ticks = 0;
+    loop {
+        app.update();
+
+        if let Some(exit) = app.should_exit() {
+            return exit;
+        }
+
+        next_tick += tick_rate;
+        let now = Instant::now();
+        if now < next_tick {
+            late_ticks = 0;
+            std::thread::sleep(next_tick - now);
+        } else if late_ticks < max_catch_up_ticks {
+            late_ticks += 1;
+        } else {
+            log::warn!(
+                "The app can't keep up with its tick rate of {tick_rate:?}, dropping missed ticks"
+            );
+            late_ticks = 0;
+            next_tick = now;
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{App, AppLabel, InternedAppLabel, Plugin, Plugins, PluginsState};
+use crate::{plugins_enabled, App, AppLabel, InternedAppLabel, Plugin, Plugins, PluginsState};
 use alloc::{boxed::Box, string::String, vec::Vec};
 use bevy_ecs::{
     event::EventRegistry,
@@ -68,12 +68,21 @@ pub struct SubApp {
     pub(crate) plugin_names: HashSet<String>,
     /// Panics if an update is attempted while plugins are building.
     pub(crate) plugin_build_depth: usize,
+    /// The plugins being added by [`App::add_plugins`], which are built once sorted by their
+    /// dependencies.
+    pub(crate) plugin_batch: Option<Vec<Box<dyn Plugin>>>,
+    /// The names of the plugins that [can be disabled](Plugin::can_disable) among the plugins
+    /// being built, whose systems only run while they are enabled.
+    pub(crate) disableable_plugins_building: Vec<String>,
     pub(crate) plugins_state: PluginsState,
     /// The schedule that will be run by [`update`](Self::update).
     pub update_schedule: Option<InternedScheduleLabel>,
     /// A function that gives mutable access to two app worlds. This is primarily
     /// intended for copying data from the main world to secondary worlds.
     extract: Option<ExtractFn>,
+    /// Functions exchanging the messages of each [`SubAppChannel`](crate::SubAppChannel) with
+    /// the main world, run before [`extract`](Self::extract).
+    pub(crate) channels: Vec<ExtractFn>,
 }
 
 impl Debug for SubApp {
@@ -91,9 +100,12 @@ impl Default for SubApp {
             plugin_registry: Vec::default(),
             plugin_names: HashSet::default(),
             plugin_build_depth: 0,
+            plugin_batch: None,
+            disableable_plugins_building: Vec::new(),
             plugins_state: PluginsState::Adding,
             update_schedule: None,
             extract: None,
+            channels: Vec::new(),
         }
     }
 }
@@ -147,9 +159,15 @@ impl SubApp {
 
     /// Extracts data from `world` into the app's world using the registered extract method.
     ///
-    /// **Note:** There is no default extract method. Calling `extract` does nothing if
+    /// The messages of the [`SubAppChannel`](crate::SubAppChannel)s of this app are exchanged
+    /// with `world` first.
+    ///
+    /// **Note:** There is no default extract method. Calling `extract` only exchanges messages if
     /// [`set_extract`](Self::set_extract) has not been called.
     pub fn extract(&mut self, world: &mut World) {
+        for exchange in &self.channels {
+            exchange(world, &mut self.world);
+        }
         if let Some(f) = self.extract.as_mut() {
             f(world, &mut self.world);
         }
@@ -214,7 +232,12 @@ impl SubApp {
         systems: impl IntoSystemConfigs<M>,
     ) -> &mut Self {
         let mut schedules = self.world.resource_mut::<Schedules>();
-        schedules.add_systems(schedule, systems);
+        if self.disableable_plugins_building.is_empty() {
+            schedules.add_systems(schedule, systems);
+        } else {
+            let condition = plugins_enabled(self.disableable_plugins_building.clone());
+            schedules.add_systems(schedule, systems.run_if(condition));
+        }
 
         self
     }
@@ -349,7 +372,7 @@ impl SubApp {
 
     /// See [`App::add_plugins`].
     pub fn add_plugins<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
-        self.run_as_app(|app| plugins.add_to_app(app));
+        self.run_as_app(|app| app.add_plugin_batch(plugins));
         self
     }
 
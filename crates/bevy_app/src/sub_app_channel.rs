@@ -0,0 +1,113 @@
+use crate::InternedAppLabel;
+use alloc::vec::Vec;
+use bevy_ecs::{system::Resource, world::World};
+use core::mem;
+
+/// A [`Resource`] exchanging messages of type `T` between the main world and the world of a
+/// [`SubApp`](crate::SubApp).
+///
+/// The channel is added to both worlds with [`App::add_sub_app_channel`](crate::App::add_sub_app_channel).
+/// Messages [sent](Self::send) from one world are [received](Self::receive) in the other one.
+/// They are exchanged each time the sub-app is [extracted](crate::SubApp::extract), right before
+/// it updates, so the sub-app receives the messages sent by the main world during the same update,
+/// and the main world receives the messages sent by the sub-app during the next one.
+///
+/// ```
+/// # use bevy_app::{App, AppLabel, Main, SubApp, SubAppChannel, Update};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AppLabel)]
+/// struct Simulation;
+///
+/// struct Step(u32);
+/// struct Report(u32);
+///
+/// let mut app = App::new();
+/// let mut sub_app = SubApp::new();
+/// sub_app.update_schedule = Some(Main.intern());
+/// sub_app.add_systems(
+///     Main,
+///     |mut steps: ResMut<SubAppChannel<Step>>, mut reports: ResMut<SubAppChannel<Report>>| {
+///         for Step(step) in steps.receive() {
+///             reports.send(Report(step * 2));
+///         }
+///     },
+/// );
+/// app.insert_sub_app(Simulation, sub_app);
+/// app.add_sub_app_channel::<Step>(Simulation)
+///     .add_sub_app_channel::<Report>(Simulation);
+///
+/// app.world_mut().resource_mut::<SubAppChannel<Step>>().send(Step(21));
+/// app.update();
+/// app.update();
+/// let reports: Vec<_> = app
+///     .world_mut()
+///     .resource_mut::<SubAppChannel<Report>>()
+///     .receive()
+///     .collect();
+/// assert!(matches!(reports[..], [Report(42)]));
+/// ```
+#[derive(Resource)]
+pub struct SubAppChannel<T: Send + Sync + 'static> {
+    peer: InternedAppLabel,
+    sent: Vec<T>,
+    received: Vec<T>,
+}
+
+impl<T: Send + Sync + 'static> SubAppChannel<T> {
+    pub(crate) fn new(peer: InternedAppLabel) -> Self {
+        Self {
+            peer,
+            sent: Vec::new(),
+            received: Vec::new(),
+        }
+    }
+
+    /// Returns the label of the sub-app this channel is connected to.
+    pub fn sub_app(&self) -> InternedAppLabel {
+        self.peer
+    }
+
+    /// Sends a message to the other world.
+    pub fn send(&mut self, message: T) {
+        self.sent.push(message);
+    }
+
+    /// Sends each message of `messages` to the other world.
+    pub fn send_batch(&mut self, messages: impl IntoIterator<Item = T>) {
+        self.sent.extend(messages);
+    }
+
+    /// Removes and returns the messages received from the other world, in the order they were sent.
+    pub fn receive(&mut self) -> impl Iterator<Item = T> + '_ {
+        self.received.drain(..)
+    }
+
+    /// Returns the number of received messages that weren't [received](Self::receive) yet.
+    pub fn len(&self) -> usize {
+        self.received.len()
+    }
+
+    /// Returns `true` if there is no message left to [receive](Self::receive).
+    pub fn is_empty(&self) -> bool {
+        self.received.is_empty()
+    }
+
+    /// Moves the messages sent from each world to the other one.
+    pub(crate) fn exchange(main_world: &mut World, sub_world: &mut World) {
+        let (Some(mut main), Some(mut sub)) = (
+            main_world.get_resource_mut::<Self>(),
+            sub_world.get_resource_mut::<Self>(),
+        ) else {
+            return;
+        };
+        if !main.sent.is_empty() {
+            let sent = mem::take(&mut main.sent);
+            sub.received.extend(sent);
+        }
+        if !sub.sent.is_empty() {
+            let sent = mem::take(&mut sub.sent);
+            main.received.extend(sent);
+        }
+    }
+}
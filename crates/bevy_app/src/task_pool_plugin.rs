@@ -171,100 +171,115 @@ impl TaskPoolOptions {
         }
     }
 
-    /// Inserts the default thread pools into the given resource map based on the configured values
-    pub fn create_default_pools(&self) {
+    /// Determines the number of threads of the IO, async compute and compute pools, in this order.
+    fn thread_counts(&self) -> [usize; 3] {
         let total_threads = bevy_tasks::available_parallelism()
             .clamp(self.min_total_threads, self.max_total_threads);
         trace!("Assigning {} cores to default task pools", total_threads);
 
         let mut remaining_threads = total_threads;
 
-        {
-            // Determine the number of IO threads we will use
-            let io_threads = self
-                .io
-                .get_number_of_threads(remaining_threads, total_threads);
-
-            trace!("IO Threads: {}", io_threads);
-            remaining_threads = remaining_threads.saturating_sub(io_threads);
-
-            IoTaskPool::get_or_init(|| {
-                #[cfg_attr(target_arch = "wasm32", expect(unused_mut))]
-                let mut builder = TaskPoolBuilder::default()
-                    .num_threads(io_threads)
-                    .thread_name("IO Task Pool".to_string());
-
-                #[cfg(not(target_arch = "wasm32"))]
-                {
-                    if let Some(f) = self.io.on_thread_spawn.clone() {
-                        builder = builder.on_thread_spawn(move || f());
-                    }
-                    if let Some(f) = self.io.on_thread_destroy.clone() {
-                        builder = builder.on_thread_destroy(move || f());
-                    }
+        // Determine the number of IO threads we will use
+        let io_threads = self
+            .io
+            .get_number_of_threads(remaining_threads, total_threads);
+        trace!("IO Threads: {}", io_threads);
+        remaining_threads = remaining_threads.saturating_sub(io_threads);
+
+        // Determine the number of async compute threads we will use
+        let async_compute_threads = self
+            .async_compute
+            .get_number_of_threads(remaining_threads, total_threads);
+        trace!("Async Compute Threads: {}", async_compute_threads);
+        remaining_threads = remaining_threads.saturating_sub(async_compute_threads);
+
+        // Determine the number of compute threads we will use
+        // This is intentionally last so that an end user can specify 1.0 as the percent
+        let compute_threads = self
+            .compute
+            .get_number_of_threads(remaining_threads, total_threads);
+        trace!("Compute Threads: {}", compute_threads);
+
+        [io_threads, async_compute_threads, compute_threads]
+    }
+
+    /// Inserts the default thread pools into the given resource map based on the configured values
+    pub fn create_default_pools(&self) {
+        let [io_threads, async_compute_threads, compute_threads] = self.thread_counts();
+
+        IoTaskPool::get_or_init(|| {
+            #[cfg_attr(target_arch = "wasm32", expect(unused_mut))]
+            let mut builder = TaskPoolBuilder::default()
+                .num_threads(io_threads)
+                .thread_name("IO Task Pool".to_string());
+
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                if let Some(f) = self.io.on_thread_spawn.clone() {
+                    builder = builder.on_thread_spawn(move || f());
                 }
+                if let Some(f) = self.io.on_thread_destroy.clone() {
+                    builder = builder.on_thread_destroy(move || f());
+                }
+            }
 
-                builder.build()
-            });
-        }
+            builder.build()
+        });
 
-        {
-            // Determine the number of async compute threads we will use
-            let async_compute_threads = self
-                .async_compute
-                .get_number_of_threads(remaining_threads, total_threads);
-
-            trace!("Async Compute Threads: {}", async_compute_threads);
-            remaining_threads = remaining_threads.saturating_sub(async_compute_threads);
-
-            AsyncComputeTaskPool::get_or_init(|| {
-                #[cfg_attr(target_arch = "wasm32", expect(unused_mut))]
-                let mut builder = TaskPoolBuilder::default()
-                    .num_threads(async_compute_threads)
-                    .thread_name("Async Compute Task Pool".to_string());
-
-                #[cfg(not(target_arch = "wasm32"))]
-                {
-                    if let Some(f) = self.async_compute.on_thread_spawn.clone() {
-                        builder = builder.on_thread_spawn(move || f());
-                    }
-                    if let Some(f) = self.async_compute.on_thread_destroy.clone() {
-                        builder = builder.on_thread_destroy(move || f());
-                    }
+        AsyncComputeTaskPool::get_or_init(|| {
+            #[cfg_attr(target_arch = "wasm32", expect(unused_mut))]
+            let mut builder = TaskPoolBuilder::default()
+                .num_threads(async_compute_threads)
+                .thread_name("Async Compute Task Pool".to_string());
+
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                if let Some(f) = self.async_compute.on_thread_spawn.clone() {
+                    builder = builder.on_thread_spawn(move || f());
+                }
+                if let Some(f) = self.async_compute.on_thread_destroy.clone() {
+                    builder = builder.on_thread_destroy(move || f());
                 }
+            }
 
-                builder.build()
-            });
-        }
+            builder.build()
+        });
+
+        ComputeTaskPool::get_or_init(|| {
+            #[cfg_attr(target_arch = "wasm32", expect(unused_mut))]
+            let mut builder = TaskPoolBuilder::default()
+                .num_threads(compute_threads)
+                .thread_name("Compute Task Pool".to_string());
 
-        {
-            // Determine the number of compute threads we will use
-            // This is intentionally last so that an end user can specify 1.0 as the percent
-            let compute_threads = self
-                .compute
-                .get_number_of_threads(remaining_threads, total_threads);
-
-            trace!("Compute Threads: {}", compute_threads);
-
-            ComputeTaskPool::get_or_init(|| {
-                #[cfg_attr(target_arch = "wasm32", expect(unused_mut))]
-                let mut builder = TaskPoolBuilder::default()
-                    .num_threads(compute_threads)
-                    .thread_name("Compute Task Pool".to_string());
-
-                #[cfg(not(target_arch = "wasm32"))]
-                {
-                    if let Some(f) = self.compute.on_thread_spawn.clone() {
-                        builder = builder.on_thread_spawn(move || f());
-                    }
-                    if let Some(f) = self.compute.on_thread_destroy.clone() {
-                        builder = builder.on_thread_destroy(move || f());
-                    }
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                if let Some(f) = self.compute.on_thread_spawn.clone() {
+                    builder = builder.on_thread_spawn(move || f());
                 }
+                if let Some(f) = self.compute.on_thread_destroy.clone() {
+                    builder = builder.on_thread_destroy(move || f());
+                }
+            }
 
-                builder.build()
-            });
-        }
+            builder.build()
+        });
+    }
+
+    /// Resizes the default thread pools based on the configured values, creating them if they
+    /// don't exist yet.
+    ///
+    /// This can be called at any time to give more or less threads to each pool, for example to
+    /// use less cores when running on battery, or to give the threads of the IO pool to the
+    /// compute pool once assets are loaded. See [`TaskPool::resize`](bevy_tasks::TaskPool::resize)
+    /// for what happens to the tasks of the pools. The thread callbacks of pools that already
+    /// exist are not changed.
+    pub fn reconfigure_default_pools(&self) {
+        self.create_default_pools();
+
+        let [io_threads, async_compute_threads, compute_threads] = self.thread_counts();
+        IoTaskPool::get().resize(io_threads);
+        AsyncComputeTaskPool::get().resize(async_compute_threads);
+        ComputeTaskPool::get().resize(compute_threads);
     }
 }
 
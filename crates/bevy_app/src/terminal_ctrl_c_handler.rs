@@ -53,10 +53,9 @@ impl TerminalCtrlCHandlerPlugin {
             events.send(AppExit::from_code(130));
         }
     }
-}
 
-impl Plugin for TerminalCtrlCHandlerPlugin {
-    fn build(&self, app: &mut App) {
+    /// Installs the `Ctrl+C` handler calling [`TerminalCtrlCHandlerPlugin::gracefully_exit`].
+    pub(crate) fn install_handler() {
         let result = ctrlc::try_set_handler(move || {
             Self::gracefully_exit();
         });
@@ -67,6 +66,12 @@ impl Plugin for TerminalCtrlCHandlerPlugin {
             }
             Err(err) => log::warn!("Failed to set `Ctrl+C` handler: {err}"),
         }
+    }
+}
+
+impl Plugin for TerminalCtrlCHandlerPlugin {
+    fn build(&self, app: &mut App) {
+        Self::install_handler();
 
         app.add_systems(Update, TerminalCtrlCHandlerPlugin::exit_on_flag);
     }
@@ -0,0 +1,426 @@
+use crate::AudioSource;
+use alloc::vec::Vec;
+use core::time::Duration;
+use rodio::{source::SeekError, Source};
+
+const WAVE_FORMAT_ADPCM: u16 = 0x0002;
+const WAVE_FORMAT_IMA_ADPCM: u16 = 0x0011;
+
+/// The coefficient pairs every Microsoft ADPCM file starts with, used for blocks with an
+/// invalid predictor index.
+const MS_ADPCM_COEFFICIENTS: [(i32, i32); 7] = [
+    (256, 0),
+    (512, -256),
+    (0, 0),
+    (192, 64),
+    (240, 0),
+    (460, -208),
+    (392, -232),
+];
+
+const MS_ADPCM_ADAPTATION: [i32; 16] = [
+    230, 230, 230, 230, 307, 409, 512, 614, 768, 614, 512, 409, 307, 230, 230, 230,
+];
+
+const IMA_ADPCM_INDEX: [i32; 16] = [-1, -1, -1, -1, 2, 4, 6, 8, -1, -1, -1, -1, 2, 4, 6, 8];
+
+const IMA_ADPCM_STEP: [i32; 89] = [
+    7, 8, 9, 10, 11, 12, 13, 14, 16, 17, 19, 21, 23, 25, 28, 31, 34, 37, 41, 45, 50, 55, 60, 66,
+    73, 80, 88, 97, 107, 118, 130, 143, 157, 173, 190, 209, 230, 253, 279, 307, 337, 371, 408, 449,
+    494, 544, 598, 658, 724, 796, 876, 963, 1060, 1166, 1282, 1411, 1552, 1707, 1878, 2066, 2272,
+    2499, 2749, 3024, 3327, 3660, 4026, 4428, 4871, 5358, 5894, 6484, 7132, 7845, 8630, 9493,
+    10442, 11487, 12635, 13899, 15289, 16818, 18500, 20350, 22385, 24623, 27086, 29794, 32767,
+];
+
+#[derive(Debug, Clone)]
+enum AdpcmCodec {
+    Ima,
+    Microsoft { coefficients: Vec<(i32, i32)> },
+}
+
+/// Decodes IMA and Microsoft ADPCM WAV files one block at a time.
+///
+/// Every block of these files has the same size and can be decoded on its own, so seeking
+/// jumps straight to the block containing the position, without decoding the blocks before it.
+#[derive(Debug, Clone)]
+pub struct AdpcmDecoder {
+    source: AudioSource,
+    codec: AdpcmCodec,
+    channels: u16,
+    sample_rate: u32,
+    block_align: usize,
+    frames_per_block: usize,
+    data: core::ops::Range<usize>,
+    total_frames: usize,
+    /// The index of the block after the one in `samples`.
+    next_block: usize,
+    /// The interleaved samples of the last decoded block.
+    samples: Vec<i16>,
+    position: usize,
+}
+
+impl AdpcmDecoder {
+    /// Creates a decoder of the audio source, if it's an IMA or Microsoft ADPCM WAV file.
+    pub fn new(source: AudioSource) -> Option<Self> {
+        let bytes = &source.bytes;
+        if bytes.get(0..4)? != b"RIFF" || bytes.get(8..12)? != b"WAVE" {
+            return None;
+        }
+        let mut format = None;
+        let mut fact_frames = None;
+        let mut data = None;
+        let mut offset = 12;
+        while let Some(header) = bytes.get(offset..offset + 8) {
+            let size = read_u32(&header[4..8]) as usize;
+            let start = offset + 8;
+            let chunk = bytes.get(start..(start + size).min(bytes.len()))?;
+            match &header[0..4] {
+                b"fmt " => format = Some(chunk),
+                b"fact" => fact_frames = chunk.get(0..4).map(|frames| read_u32(frames) as usize),
+                b"data" => data = Some(start..start + chunk.len()),
+                _ => {}
+            }
+            // Chunks are padded to an even size.
+            offset = start + size + (size & 1);
+        }
+        let format = format?;
+        let data = data?;
+
+        let channels = read_u16(format.get(2..4)?);
+        let sample_rate = read_u32(format.get(4..8)?);
+        let block_align = read_u16(format.get(12..14)?) as usize;
+        let frames_per_block = format.get(18..20).map(read_u16).unwrap_or(0) as usize;
+        let (codec, header_size, computed_frames_per_block) = match read_u16(format.get(0..2)?) {
+            WAVE_FORMAT_IMA_ADPCM => (
+                AdpcmCodec::Ima,
+                4,
+                (block_align.checked_sub(4 * channels as usize)? * 2) / channels as usize + 1,
+            ),
+            WAVE_FORMAT_ADPCM => {
+                let count = read_u16(format.get(20..22)?) as usize;
+                let coefficients = format
+                    .get(22..22 + count * 4)?
+                    .chunks_exact(4)
+                    .map(|pair| {
+                        (
+                            i32::from(read_u16(&pair[0..2]) as i16),
+                            i32::from(read_u16(&pair[2..4]) as i16),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                (
+                    AdpcmCodec::Microsoft { coefficients },
+                    7,
+                    (block_align.checked_sub(7 * channels as usize)? * 2) / channels as usize + 2,
+                )
+            }
+            _ => return None,
+        };
+        if channels == 0
+            || sample_rate == 0
+            || frames_per_block == 0
+            || frames_per_block > computed_frames_per_block
+            || block_align <= header_size * channels as usize
+        {
+            return None;
+        }
+
+        let blocks = data.len() / block_align;
+        let mut total_frames = blocks * frames_per_block;
+        let remainder = data.len() % block_align;
+        if remainder > header_size * channels as usize {
+            total_frames += partial_block_frames(&codec, channels as usize, remainder);
+        }
+        if let Some(fact_frames) = fact_frames {
+            total_frames = total_frames.min(fact_frames);
+        }
+
+        Some(Self {
+            source,
+            codec,
+            channels,
+            sample_rate,
+            block_align,
+            frames_per_block,
+            data,
+            total_frames,
+            next_block: 0,
+            samples: Vec::new(),
+            position: 0,
+        })
+    }
+
+    /// Decodes the block with the given index, returning false past the end of the data.
+    fn decode_block(&mut self, block: usize) -> bool {
+        let first_frame = block * self.frames_per_block;
+        if first_frame >= self.total_frames {
+            return false;
+        }
+        let start = self.data.start + block * self.block_align;
+        let end = (start + self.block_align).min(self.data.end);
+        let bytes = &self.source.bytes[start..end];
+        let channels = self.channels as usize;
+        let frames = self
+            .frames_per_block
+            .min(self.total_frames - first_frame)
+            .min(partial_block_frames(&self.codec, channels, bytes.len()));
+
+        self.samples.clear();
+        self.samples.resize(frames * channels, 0);
+        match &self.codec {
+            AdpcmCodec::Ima => decode_ima_block(bytes, channels, &mut self.samples),
+            AdpcmCodec::Microsoft { coefficients } => {
+                decode_ms_block(bytes, channels, coefficients, &mut self.samples);
+            }
+        }
+        self.next_block = block + 1;
+        self.position = 0;
+        true
+    }
+}
+
+/// Returns how many frames a block of the given size holds.
+fn partial_block_frames(codec: &AdpcmCodec, channels: usize, len: usize) -> usize {
+    match codec {
+        AdpcmCodec::Ima => len.saturating_sub(4 * channels) * 2 / channels + 1,
+        AdpcmCodec::Microsoft { .. } => len.saturating_sub(7 * channels) * 2 / channels + 2,
+    }
+}
+
+fn read_u16(bytes: &[u8]) -> u16 {
+    u16::from_le_bytes([bytes[0], bytes[1]])
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn clamp_sample(sample: i32) -> i32 {
+    sample.clamp(i16::MIN.into(), i16::MAX.into())
+}
+
+/// Decodes an IMA ADPCM block into interleaved samples.
+///
+/// After a 4 byte header per channel, the nibbles of each channel come in groups of 4 bytes,
+/// low nibble first.
+fn decode_ima_block(bytes: &[u8], channels: usize, samples: &mut [i16]) {
+    let frames = samples.len() / channels;
+    for channel in 0..channels {
+        let header = &bytes[channel * 4..channel * 4 + 4];
+        let mut predictor = i32::from(read_u16(header) as i16);
+        let mut index = i32::from(header[2]).min(88);
+        samples[channel] = predictor as i16;
+
+        for frame in 1..frames {
+            let nibble_index = frame - 1;
+            let group = nibble_index / 8;
+            let byte = 4 * channels + (group * channels + channel) * 4 + (nibble_index % 8) / 2;
+            let nibble = if nibble_index.is_multiple_of(2) {
+                bytes[byte] & 0x0f
+            } else {
+                bytes[byte] >> 4
+            };
+
+            let step = IMA_ADPCM_STEP[index as usize];
+            let mut difference = step >> 3;
+            if nibble & 1 != 0 {
+                difference += step >> 2;
+            }
+            if nibble & 2 != 0 {
+                difference += step >> 1;
+            }
+            if nibble & 4 != 0 {
+                difference += step;
+            }
+            if nibble & 8 != 0 {
+                difference = -difference;
+            }
+            predictor = clamp_sample(predictor + difference);
+            index = (index + IMA_ADPCM_INDEX[nibble as usize]).clamp(0, 88);
+            samples[frame * channels + channel] = predictor as i16;
+        }
+    }
+}
+
+/// Decodes a Microsoft ADPCM block into interleaved samples.
+///
+/// The header holds the predictor index, delta and two first samples of every channel, in
+/// that order, after which the nibbles of the channels are interleaved, high nibble first.
+fn decode_ms_block(
+    bytes: &[u8],
+    channels: usize,
+    coefficients: &[(i32, i32)],
+    samples: &mut [i16],
+) {
+    let mut state = Vec::with_capacity(channels);
+    for channel in 0..channels {
+        let read = |field: usize| {
+            i32::from(read_u16(&bytes[channels * (1 + 2 * field) + 2 * channel..]) as i16)
+        };
+        let coefficients = coefficients
+            .get(bytes[channel] as usize)
+            .copied()
+            .unwrap_or(MS_ADPCM_COEFFICIENTS[0]);
+        let (delta, sample1, sample2) = (read(0), read(1), read(2));
+        // The second sample of the header is the first one played.
+        samples[channel] = sample2 as i16;
+        if let Some(sample) = samples.get_mut(channels + channel) {
+            *sample = sample1 as i16;
+        }
+        state.push((coefficients, delta, sample1, sample2));
+    }
+
+    for (index, sample) in samples.iter_mut().enumerate().skip(2 * channels) {
+        let nibble_index = index - 2 * channels;
+        let byte = bytes[7 * channels + nibble_index / 2];
+        let nibble = if nibble_index.is_multiple_of(2) {
+            byte >> 4
+        } else {
+            byte & 0x0f
+        };
+
+        let ((coefficient1, coefficient2), delta, sample1, sample2) = &mut state[index % channels];
+        let signed = i32::from(nibble) - if nibble & 8 != 0 { 16 } else { 0 };
+        let predictor = (*sample1 * *coefficient1 + *sample2 * *coefficient2) >> 8;
+        let decoded = clamp_sample(predictor + signed * *delta);
+        *sample2 = *sample1;
+        *sample1 = decoded;
+        *delta = ((MS_ADPCM_ADAPTATION[nibble as usize] * *delta) >> 8).max(16);
+        *sample = decoded as i16;
+    }
+}
+
+impl Iterator for AdpcmDecoder {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        if self.position >= self.samples.len() && !self.decode_block(self.next_block) {
+            return None;
+        }
+        let sample = self.samples.get(self.position).copied();
+        self.position += 1;
+        sample
+    }
+}
+
+impl Source for AdpcmDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        // The channels and sample rate never change.
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f64(
+            self.total_frames as f64 / f64::from(self.sample_rate),
+        ))
+    }
+
+    fn try_seek(&mut self, pos: Duration) -> Result<(), SeekError> {
+        // Keep the channels of the next sample the same as before seeking.
+        let channel = self.position % self.channels as usize;
+        let frame = ((pos.as_secs_f64() * f64::from(self.sample_rate)) as usize)
+            .min(self.total_frames.saturating_sub(1));
+        if self.decode_block(frame / self.frames_per_block) {
+            self.position = (frame % self.frames_per_block) * self.channels as usize + channel;
+        } else {
+            self.samples.clear();
+            self.position = 0;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a WAV file with the given format chunk, header fields excluded, and data.
+    fn wav(
+        format_tag: u16,
+        channels: u16,
+        block_align: u16,
+        extra_format: &[u8],
+        data: &[u8],
+    ) -> AudioSource {
+        let mut format = Vec::new();
+        format.extend_from_slice(&format_tag.to_le_bytes());
+        format.extend_from_slice(&channels.to_le_bytes());
+        format.extend_from_slice(&8000u32.to_le_bytes());
+        format.extend_from_slice(&4000u32.to_le_bytes());
+        format.extend_from_slice(&block_align.to_le_bytes());
+        format.extend_from_slice(&4u16.to_le_bytes());
+        format.extend_from_slice(&(extra_format.len() as u16).to_le_bytes());
+        format.extend_from_slice(extra_format);
+
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(4 + 8 + format.len() as u32 + 8 + data.len() as u32).to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&(format.len() as u32).to_le_bytes());
+        wav.extend_from_slice(&format);
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        wav.extend_from_slice(data);
+        AudioSource { bytes: wav.into() }
+    }
+
+    #[test]
+    fn decode_ima_adpcm() {
+        // Mono blocks of 16 bytes hold 25 samples.
+        let mut data = vec![0; 16 * 4];
+        data[4] = 0x77;
+        let source = wav(WAVE_FORMAT_IMA_ADPCM, 1, 16, &25u16.to_le_bytes(), &data);
+
+        let decoder = AdpcmDecoder::new(source.clone()).unwrap();
+        assert_eq!(decoder.channels(), 1);
+        assert_eq!(decoder.sample_rate(), 8000);
+        assert_eq!(
+            decoder.total_duration(),
+            Some(Duration::from_secs_f64(100.0 / 8000.0))
+        );
+        let samples = decoder.collect::<Vec<_>>();
+        assert_eq!(samples.len(), 100);
+        assert_eq!(samples[..3], [0, 11, 41]);
+
+        let mut decoder = AdpcmDecoder::new(source).unwrap();
+        decoder
+            .try_seek(Duration::from_secs_f64(60.0 / 8000.0))
+            .unwrap();
+        assert_eq!(decoder.count(), 40);
+    }
+
+    #[test]
+    fn decode_ms_adpcm() {
+        let mut extra_format = Vec::new();
+        extra_format.extend_from_slice(&3u16.to_le_bytes());
+        extra_format.extend_from_slice(&7u16.to_le_bytes());
+        for (coefficient1, coefficient2) in MS_ADPCM_COEFFICIENTS {
+            extra_format.extend_from_slice(&(coefficient1 as i16).to_le_bytes());
+            extra_format.extend_from_slice(&(coefficient2 as i16).to_le_bytes());
+        }
+        // A stereo block with the header of both channels followed by two nibbles.
+        let mut block = vec![0, 0];
+        for field in [16i16, 16, 100, -100, 50, -50] {
+            block.extend_from_slice(&field.to_le_bytes());
+        }
+        block.push(0x00);
+        let source = wav(WAVE_FORMAT_ADPCM, 2, 15, &extra_format, &block);
+
+        let samples = AdpcmDecoder::new(source).unwrap().collect::<Vec<_>>();
+        assert_eq!(samples, [50, -50, 100, -100, 100, -100]);
+    }
+
+    #[test]
+    fn not_adpcm() {
+        let source = wav(1, 1, 2, &[], &[0; 8]);
+        assert!(AdpcmDecoder::new(source).is_none());
+    }
+}
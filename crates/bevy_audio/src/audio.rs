@@ -3,6 +3,7 @@ use bevy_asset::{Asset, Handle};
 use bevy_ecs::prelude::*;
 use bevy_math::Vec3;
 use bevy_reflect::prelude::*;
+use core::time::Duration;
 
 /// The way Bevy manages the sound playback.
 #[derive(Debug, Clone, Copy, Reflect)]
@@ -56,6 +57,12 @@ pub struct PlaybackSettings {
     /// Optional scale factor applied to the positions of this audio source and the listener,
     /// overriding the default value configured on [`AudioPlugin::default_spatial_scale`](crate::AudioPlugin::default_spatial_scale).
     pub spatial_scale: Option<SpatialScale>,
+    /// The position in the audio source to start playing from.
+    ///
+    /// Decoding starts there instead of at the beginning of the source, so this should only be
+    /// used with formats which can seek, like FLAC or WAV. A looping source loops back to this
+    /// position rather than to its beginning.
+    pub start_position: Option<Duration>,
 }
 
 impl Default for PlaybackSettings {
@@ -80,6 +87,7 @@ impl PlaybackSettings {
         muted: false,
         spatial: false,
         spatial_scale: None,
+        start_position: None,
     };
 
     /// Will play the associated audio source in a loop.
@@ -135,6 +143,12 @@ impl PlaybackSettings {
         self.spatial_scale = Some(spatial_scale);
         self
     }
+
+    /// Helper to start playing from a position in the audio source.
+    pub const fn with_start_position(mut self, start_position: Duration) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
 }
 
 /// Settings for the listener for spatial audio sources.
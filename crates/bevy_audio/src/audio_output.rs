@@ -158,9 +158,11 @@ pub(crate) fn play_queued_audio_system<Source: Asset + Decodable>(
             };
 
             match settings.mode {
-                PlaybackMode::Loop => sink.append(audio_source.decoder().repeat_infinite()),
+                PlaybackMode::Loop => {
+                    sink.append(decoder(audio_source, settings).repeat_infinite());
+                }
                 PlaybackMode::Once | PlaybackMode::Despawn | PlaybackMode::Remove => {
-                    sink.append(audio_source.decoder());
+                    sink.append(decoder(audio_source, settings));
                 }
             };
 
@@ -198,9 +200,11 @@ pub(crate) fn play_queued_audio_system<Source: Asset + Decodable>(
             };
 
             match settings.mode {
-                PlaybackMode::Loop => sink.append(audio_source.decoder().repeat_infinite()),
+                PlaybackMode::Loop => {
+                    sink.append(decoder(audio_source, settings).repeat_infinite());
+                }
                 PlaybackMode::Once | PlaybackMode::Despawn | PlaybackMode::Remove => {
-                    sink.append(audio_source.decoder());
+                    sink.append(decoder(audio_source, settings));
                 }
             };
 
@@ -232,6 +236,20 @@ pub(crate) fn play_queued_audio_system<Source: Asset + Decodable>(
     }
 }
 
+/// Returns a decoder of the audio source, seeked to the start position of its settings.
+fn decoder<Source: Decodable>(
+    audio_source: &Source,
+    settings: &PlaybackSettings,
+) -> Source::Decoder {
+    let mut decoder = audio_source.decoder();
+    if let Some(start_position) = settings.start_position {
+        if let Err(err) = decoder.try_seek(start_position) {
+            warn!("Error seeking to the start position of an audio source: {err:?}");
+        }
+    }
+    decoder
+}
+
 pub(crate) fn cleanup_finished_audio<T: Decodable + Asset>(
     mut commands: Commands,
     query_nonspatial_despawn: Query<
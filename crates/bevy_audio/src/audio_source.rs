@@ -1,6 +1,7 @@
 use alloc::sync::Arc;
 use bevy_asset::{io::Reader, Asset, AssetLoader, LoadContext};
 use bevy_reflect::TypePath;
+use core::time::Duration;
 use std::io::Cursor;
 
 /// A source of audio data
@@ -8,7 +9,8 @@ use std::io::Cursor;
 pub struct AudioSource {
     /// Raw data of the audio source.
     ///
-    /// The data must be one of the file formats supported by Bevy (`wav`, `ogg`, `flac`, or `mp3`).
+    /// The data must be one of the file formats supported by Bevy (`wav`, including ADPCM
+    /// encoded `wav`, `ogg`, `flac`, or `mp3`).
     /// However, support for these file formats is not part of Bevy's [`default feature set`](https://docs.rs/bevy/latest/bevy/index.html#default-features).
     /// In order to be able to use these file formats, you will have to enable the appropriate [`optional features`](https://docs.rs/bevy/latest/bevy/index.html#optional-features).
     ///
@@ -33,7 +35,12 @@ impl AsRef<[u8]> for AudioSource {
 /// Other file endings can be loaded from with additional features:
 /// `.mp3` with `bevy/mp3`
 /// `.flac` with `bevy/flac`
-/// `.wav` with `bevy/wav`
+/// `.wav` with `bevy/wav`, and IMA or Microsoft ADPCM encoded `.wav` with `bevy/adpcm`
+///
+/// The whole file is loaded into memory, but it is only decoded while playing, so sounds can
+/// start playing without being decoded upfront. FLAC files are seeked with their seek table
+/// when they have one, and ADPCM files are decoded one block at a time, which is cheap enough
+/// for long music tracks on low-power targets.
 #[derive(Default)]
 pub struct AudioLoader;
 
@@ -59,9 +66,18 @@ impl AssetLoader for AudioLoader {
         &[
             #[cfg(feature = "mp3")]
             "mp3",
-            #[cfg(feature = "flac")]
+            #[cfg(any(
+                feature = "flac",
+                feature = "symphonia-flac",
+                feature = "symphonia-all"
+            ))]
             "flac",
-            #[cfg(feature = "wav")]
+            #[cfg(any(
+                feature = "wav",
+                feature = "adpcm",
+                feature = "symphonia-wav",
+                feature = "symphonia-all"
+            ))]
             "wav",
             #[cfg(feature = "vorbis")]
             "oga",
@@ -94,12 +110,89 @@ pub trait Decodable: Send + Sync + 'static {
     fn decoder(&self) -> Self::Decoder;
 }
 
+/// The decoder of an [`AudioSource`].
+pub enum AudioSourceDecoder {
+    /// Decodes the formats supported by [`rodio::Decoder`].
+    Rodio(rodio::Decoder<Cursor<AudioSource>>),
+    /// Decodes IMA and Microsoft ADPCM WAV files.
+    ///
+    /// These are decoded by Bevy rather than by [`rodio::Decoder`], which can't seek in them.
+    #[cfg(feature = "adpcm")]
+    Adpcm(crate::AdpcmDecoder),
+}
+
+impl Iterator for AudioSourceDecoder {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        match self {
+            AudioSourceDecoder::Rodio(decoder) => decoder.next(),
+            #[cfg(feature = "adpcm")]
+            AudioSourceDecoder::Adpcm(decoder) => decoder.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            AudioSourceDecoder::Rodio(decoder) => decoder.size_hint(),
+            #[cfg(feature = "adpcm")]
+            AudioSourceDecoder::Adpcm(decoder) => decoder.size_hint(),
+        }
+    }
+}
+
+impl rodio::Source for AudioSourceDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        match self {
+            AudioSourceDecoder::Rodio(decoder) => decoder.current_frame_len(),
+            #[cfg(feature = "adpcm")]
+            AudioSourceDecoder::Adpcm(decoder) => decoder.current_frame_len(),
+        }
+    }
+
+    fn channels(&self) -> u16 {
+        match self {
+            AudioSourceDecoder::Rodio(decoder) => decoder.channels(),
+            #[cfg(feature = "adpcm")]
+            AudioSourceDecoder::Adpcm(decoder) => decoder.channels(),
+        }
+    }
+
+    fn sample_rate(&self) -> u32 {
+        match self {
+            AudioSourceDecoder::Rodio(decoder) => decoder.sample_rate(),
+            #[cfg(feature = "adpcm")]
+            AudioSourceDecoder::Adpcm(decoder) => decoder.sample_rate(),
+        }
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        match self {
+            AudioSourceDecoder::Rodio(decoder) => decoder.total_duration(),
+            #[cfg(feature = "adpcm")]
+            AudioSourceDecoder::Adpcm(decoder) => decoder.total_duration(),
+        }
+    }
+
+    fn try_seek(&mut self, pos: Duration) -> Result<(), rodio::source::SeekError> {
+        match self {
+            AudioSourceDecoder::Rodio(decoder) => decoder.try_seek(pos),
+            #[cfg(feature = "adpcm")]
+            AudioSourceDecoder::Adpcm(decoder) => decoder.try_seek(pos),
+        }
+    }
+}
+
 impl Decodable for AudioSource {
-    type DecoderItem = <rodio::Decoder<Cursor<AudioSource>> as Iterator>::Item;
-    type Decoder = rodio::Decoder<Cursor<AudioSource>>;
+    type DecoderItem = i16;
+    type Decoder = AudioSourceDecoder;
 
     fn decoder(&self) -> Self::Decoder {
-        rodio::Decoder::new(Cursor::new(self.clone())).unwrap()
+        #[cfg(feature = "adpcm")]
+        if let Some(decoder) = crate::AdpcmDecoder::new(self.clone()) {
+            return AudioSourceDecoder::Adpcm(decoder);
+        }
+        AudioSourceDecoder::Rodio(rodio::Decoder::new(Cursor::new(self.clone())).unwrap())
     }
 }
 
@@ -34,6 +34,8 @@
 
 extern crate alloc;
 
+#[cfg(feature = "adpcm")]
+mod adpcm;
 mod audio;
 mod audio_output;
 mod audio_source;
@@ -52,12 +54,18 @@ pub mod prelude {
     };
 }
 
+#[cfg(feature = "adpcm")]
+pub use adpcm::*;
 pub use audio::*;
 pub use audio_source::*;
 pub use pitch::*;
 pub use volume::*;
 
-pub use rodio::{cpal::Sample as CpalSample, source::Source, Sample};
+pub use rodio::{
+    cpal::Sample as CpalSample,
+    source::{SeekError, Source},
+    Sample,
+};
 pub use sinks::*;
 
 use bevy_app::prelude::*;
@@ -105,7 +113,16 @@ impl Plugin for AudioPlugin {
             )
             .init_resource::<AudioOutput>();
 
-        #[cfg(any(feature = "mp3", feature = "flac", feature = "wav", feature = "vorbis"))]
+        #[cfg(any(
+            feature = "mp3",
+            feature = "flac",
+            feature = "wav",
+            feature = "vorbis",
+            feature = "adpcm",
+            feature = "symphonia-all",
+            feature = "symphonia-flac",
+            feature = "symphonia-wav",
+        ))]
         {
             app.add_audio_source::<AudioSource>();
             app.init_asset_loader::<AudioLoader>();
@@ -1,7 +1,8 @@
 use bevy_ecs::component::Component;
 use bevy_math::Vec3;
 use bevy_transform::prelude::Transform;
-use rodio::{Sink, SpatialSink};
+use core::time::Duration;
+use rodio::{source::SeekError, Sink, SpatialSink};
 
 /// Common interactions with an audio sink.
 pub trait AudioSinkPlayback {
@@ -75,6 +76,22 @@ pub trait AudioSinkPlayback {
     /// Sinks can be paused and resumed using [`pause`](Self::pause) and [`play`](Self::play).
     fn is_paused(&self) -> bool;
 
+    /// Returns the position of the sound that is playing.
+    ///
+    /// This is the time the sound has been played for, scaled by its [`speed`](Self::speed),
+    /// adjusted by any [`try_seek`](Self::try_seek) and reset to zero when a looping sound
+    /// starts over.
+    fn position(&self) -> Duration;
+
+    /// Seeks to the given position in the sound that is playing.
+    ///
+    /// This blocks for up to a few milliseconds, until the audio thread has performed the seek.
+    /// Seeking beyond the end of a sound whose duration is known seeks to its end. Whether
+    /// seeking is supported depends on the format of the sound: FLAC files use their seek
+    /// table when they have one, and WAV files, including ADPCM ones, seek to the block
+    /// containing the position. An error is returned if the decoder can't seek.
+    fn try_seek(&self, position: Duration) -> Result<(), SeekError>;
+
     /// Stops the sink.
     ///
     /// It won't be possible to restart it afterwards.
@@ -178,6 +195,14 @@ impl AudioSinkPlayback for AudioSink {
         self.sink.is_paused()
     }
 
+    fn position(&self) -> Duration {
+        self.sink.get_pos()
+    }
+
+    fn try_seek(&self, position: Duration) -> Result<(), SeekError> {
+        self.sink.try_seek(position)
+    }
+
     fn stop(&self) {
         self.sink.stop();
     }
@@ -273,6 +298,14 @@ impl AudioSinkPlayback for SpatialAudioSink {
         self.sink.is_paused()
     }
 
+    fn position(&self) -> Duration {
+        self.sink.get_pos()
+    }
+
+    fn try_seek(&self, position: Duration) -> Result<(), SeekError> {
+        self.sink.try_seek(position)
+    }
+
     fn stop(&self) {
         self.sink.stop();
     }
@@ -0,0 +1,877 @@
+//! Module containing logic for the in-game developer console.
+//!
+//! The [`DevConsolePlugin`] adds a console overlay, toggled with the backquote key by default,
+//! executing commands typed by the user:
+//! - Any function registered in the [`AppFunctionRegistry`] can be called by name, with its
+//!   arguments written in [RON] and separated by spaces, e.g. `spawn_enemies 10 "goblin"`.
+//! - `get <Resource>[.path]` prints a reflected resource, or one of its fields.
+//! - `set <Resource>[.path] <value>` overwrites a reflected resource or one of its fields, with
+//!   the new value written in [RON], e.g. `set Gravity.0.y -4.9`.
+//! - `resources` lists the reflected resources, `help` lists the available commands,
+//!   `help <function>` prints the signature of a function and `clear` clears the output.
+//!
+//! Pressing `Tab` autocompletes function and resource names, and the `Up` and `Down` arrow keys
+//! browse the command history. Commands can also be run from code with [`run_command`].
+//!
+//! [RON]: https://github.com/ron-rs/ron
+
+use alloc::{borrow::Cow, collections::VecDeque};
+
+use bevy_app::{App, Plugin, Startup, Update};
+use bevy_asset::Handle;
+use bevy_color::Color;
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::Component,
+    event::EventReader,
+    query::{With, Without},
+    reflect::{AppFunctionRegistry, AppTypeRegistry, ReflectResource},
+    schedule::{common_conditions::resource_changed, IntoSystemConfigs},
+    system::{Commands, Query, Res, ResMut, Resource},
+    world::World,
+};
+use bevy_hierarchy::{BuildChildren, ChildBuild};
+use bevy_input::{
+    keyboard::{Key, KeyCode, KeyboardInput},
+    ButtonState,
+};
+use bevy_reflect::{
+    func::{
+        args::Ownership, ArgList, DynamicFunction, FunctionError, FunctionRegistry, Return,
+        SignatureInfo,
+    },
+    serde::TypedReflectDeserializer,
+    GetPath, PartialReflect, TypeRegistry,
+};
+use bevy_text::{Font, TextColor, TextFont};
+use bevy_ui::{
+    widget::Text, BackgroundColor, FlexDirection, GlobalZIndex, Node, PositionType, UiRect, Val,
+};
+use serde::de::DeserializeSeed;
+use thiserror::Error;
+
+use crate::overlay::{self, OverlayConfig};
+
+/// [`GlobalZIndex`] used to render the developer console.
+///
+/// One below [`PROFILER_ZINDEX`](crate::profiler::PROFILER_ZINDEX), so it can be used with the other overlays.
+pub const DEV_CONSOLE_ZINDEX: i32 = crate::profiler::PROFILER_ZINDEX - 1;
+
+/// Commands handled by the console itself, rather than by registered functions.
+const BUILTIN_COMMANDS: [&str; 5] = ["clear", "get", "help", "resources", "set"];
+
+/// A plugin that adds an in-game developer console to the Bevy application.
+///
+/// See the [module-level documentation](self) for the supported commands.
+///
+/// Note: the console doesn't consume keyboard input, so other systems still receive the keys
+/// typed into it.
+#[derive(Default)]
+pub struct DevConsolePlugin {
+    /// Starting configuration of the console, this can later be changed through the [`DevConsoleConfig`] resource.
+    pub config: DevConsoleConfig,
+}
+
+impl Plugin for DevConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone())
+            .init_resource::<DevConsole>()
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    overlay::toggle_with_key::<DevConsoleConfig>,
+                    (
+                        customize_text,
+                        overlay::toggle_display::<DevConsoleConfig, DevConsoleRoot>,
+                    )
+                        .run_if(resource_changed::<DevConsoleConfig>),
+                    handle_input.run_if(|config: Res<DevConsoleConfig>| config.enabled),
+                    execute_commands,
+                    update_text.run_if(resource_changed::<DevConsole>),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Configuration options for the developer console.
+#[derive(Resource, Clone)]
+pub struct DevConsoleConfig {
+    /// Configuration of text in the console.
+    pub text_config: TextFont,
+    /// Color of text in the console.
+    pub text_color: Color,
+    /// Color of the autocompletion hints.
+    pub hint_color: Color,
+    /// How many lines of output are displayed.
+    pub visible_lines: usize,
+    /// Key toggling [`DevConsoleConfig::enabled`], if any.
+    pub toggle_key: Option<KeyCode>,
+    /// Displays the console and captures keyboard input if true.
+    pub enabled: bool,
+}
+
+impl Default for DevConsoleConfig {
+    fn default() -> Self {
+        DevConsoleConfig {
+            text_config: TextFont {
+                font: Handle::<Font>::default(),
+                font_size: 14.0,
+                ..Default::default()
+            },
+            text_color: Color::WHITE,
+            hint_color: Color::srgb(0.6, 0.6, 0.6),
+            visible_lines: 16,
+            toggle_key: Some(KeyCode::Backquote),
+            enabled: false,
+        }
+    }
+}
+
+impl OverlayConfig for DevConsoleConfig {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn toggle_key(&self) -> Option<KeyCode> {
+        self.toggle_key
+    }
+}
+
+/// The state of the developer console: its input line, output and command history.
+#[derive(Resource, Default, Debug)]
+pub struct DevConsole {
+    /// The command being typed.
+    pub input: String,
+    output: VecDeque<String>,
+    history: Vec<String>,
+    history_index: Option<usize>,
+    pending: Vec<String>,
+}
+
+impl DevConsole {
+    /// Maximum number of output lines kept by the console.
+    pub const MAX_OUTPUT_LINES: usize = 1000;
+
+    /// Queues a command, executed during the next [`Update`].
+    pub fn submit(&mut self, command: impl Into<String>) {
+        let command = command.into();
+        if self.history.last() != Some(&command) {
+            self.history.push(command.clone());
+        }
+        self.history_index = None;
+        self.pending.push(command);
+    }
+
+    /// Appends a line to the output of the console.
+    pub fn print(&mut self, line: impl Into<String>) {
+        for line in line.into().lines() {
+            if self.output.len() == Self::MAX_OUTPUT_LINES {
+                self.output.pop_front();
+            }
+            self.output.push_back(line.to_owned());
+        }
+    }
+
+    /// Clears the output of the console.
+    pub fn clear(&mut self) {
+        self.output.clear();
+    }
+
+    /// Returns the lines of output, oldest first.
+    pub fn output(&self) -> impl DoubleEndedIterator<Item = &str> {
+        self.output.iter().map(String::as_str)
+    }
+
+    /// Returns the previously submitted commands, oldest first.
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    fn browse_history(&mut self, older: bool) {
+        let index = match (self.history_index, older) {
+            (None, true) => self.history.len().checked_sub(1),
+            (None, false) => None,
+            (Some(index), true) => Some(index.saturating_sub(1)),
+            (Some(index), false) => Some(index + 1).filter(|&index| index < self.history.len()),
+        };
+        self.history_index = index;
+        self.input = index
+            .map(|index| self.history[index].clone())
+            .unwrap_or_default();
+    }
+}
+
+/// An error returned by a command run in the developer console.
+#[derive(Error, Debug)]
+pub enum ConsoleError {
+    /// The command is neither a built-in command nor a registered function.
+    #[error("unknown command `{0}`, type `help` to list commands")]
+    UnknownCommand(String),
+    /// The command was called with the wrong arguments.
+    #[error("usage: {0}")]
+    Usage(&'static str),
+    /// The resource isn't registered as a reflected resource, or isn't present in the world.
+    #[error("unknown resource `{0}`")]
+    UnknownResource(String),
+    /// The field path doesn't exist in the resource.
+    #[error("invalid path: {0}")]
+    InvalidPath(String),
+    /// A value couldn't be parsed from RON.
+    #[error("invalid value `{value}` for `{type_path}`: {error}")]
+    InvalidValue {
+        /// The text of the value.
+        value: String,
+        /// The type the value was parsed as.
+        type_path: &'static str,
+        /// The parsing error.
+        error: String,
+    },
+    /// A type isn't registered in the [`AppTypeRegistry`], so it can't be parsed.
+    #[error("type `{0}` isn't registered")]
+    UnregisteredType(&'static str),
+    /// The function failed.
+    #[error(transparent)]
+    Function(#[from] FunctionError),
+}
+
+/// Runs a command of the developer console on the `world`, returning its output.
+///
+/// See the [module-level documentation](self) for the supported commands. `clear` is handled
+/// by the console itself and does nothing here.
+pub fn run_command(world: &mut World, command: &str) -> Result<String, ConsoleError> {
+    let tokens = tokenize(command);
+    let Some((&name, args)) = tokens.split_first() else {
+        return Ok(String::new());
+    };
+
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    match name {
+        "clear" => Ok(String::new()),
+        "help" => {
+            let functions = world.resource::<AppFunctionRegistry>().read();
+            match args {
+                [] => Ok(help(&functions)),
+                [function] => {
+                    let function = find_function(&functions, function)
+                        .ok_or_else(|| ConsoleError::UnknownCommand((*function).to_owned()))?;
+                    Ok(function
+                        .info()
+                        .signatures()
+                        .iter()
+                        .map(|signature| format_signature(function.name(), signature))
+                        .collect::<Vec<_>>()
+                        .join("\n"))
+                }
+                _ => Err(ConsoleError::Usage("help [function]")),
+            }
+        }
+        "resources" => {
+            let mut resources: Vec<&str> = type_registry
+                .iter_with_data::<ReflectResource>()
+                .filter(|(_, reflect_resource)| reflect_resource.reflect(world).is_some())
+                .map(|(registration, _)| registration.type_info().type_path_table().short_path())
+                .collect();
+            resources.sort_unstable();
+            Ok(resources.join("\n"))
+        }
+        "get" => {
+            let [target] = args else {
+                return Err(ConsoleError::Usage("get <Resource>[.path]"));
+            };
+            let (resource, path) = split_resource_path(target);
+            let reflect_resource = find_resource(&type_registry, resource)?;
+            let value = reflect_resource
+                .reflect(world)
+                .ok_or_else(|| ConsoleError::UnknownResource(resource.to_owned()))?;
+            let value = match path {
+                "" => value.as_partial_reflect(),
+                path => value
+                    .reflect_path(path)
+                    .map_err(|err| ConsoleError::InvalidPath(err.to_string()))?,
+            };
+            Ok(format!("{value:?}"))
+        }
+        "set" => {
+            let [target, value] = args else {
+                return Err(ConsoleError::Usage("set <Resource>[.path] <value>"));
+            };
+            let (resource, path) = split_resource_path(target);
+            let reflect_resource = find_resource(&type_registry, resource)?;
+            let mut resource_value = reflect_resource
+                .reflect_mut(world)
+                .ok_or_else(|| ConsoleError::UnknownResource(resource.to_owned()))?;
+            let field = match path {
+                "" => resource_value.as_partial_reflect_mut(),
+                path => resource_value
+                    .reflect_path_mut(path)
+                    .map_err(|err| ConsoleError::InvalidPath(err.to_string()))?,
+            };
+            let type_info = field
+                .get_represented_type_info()
+                .ok_or(ConsoleError::UnregisteredType("dynamic value"))?;
+            let new_value = parse_value(&type_registry, type_info.type_id(), value)?;
+            field
+                .try_apply(new_value.as_ref())
+                .map_err(|err| ConsoleError::InvalidValue {
+                    value: (*value).to_owned(),
+                    type_path: type_info.type_path(),
+                    error: err.to_string(),
+                })?;
+            Ok(format!("{field:?}"))
+        }
+        name => {
+            let functions = world.resource::<AppFunctionRegistry>().clone();
+            let functions = functions.read();
+            let function = find_function(&functions, name)
+                .ok_or_else(|| ConsoleError::UnknownCommand(name.to_owned()))?;
+
+            // Try every overload taking this many arguments, until one of them can parse them.
+            let mut result = Err(ConsoleError::Usage("wrong number of arguments, see `help`"));
+            for signature in function.info().signatures() {
+                if signature.arg_count() != args.len() {
+                    continue;
+                }
+                let mut values = match parse_args(&type_registry, signature, args) {
+                    Ok(values) => values,
+                    Err(err) => {
+                        result = Err(err);
+                        continue;
+                    }
+                };
+                let mut arg_list = ArgList::new();
+                for (value, arg) in values.iter_mut().zip(signature.args()) {
+                    arg_list = match arg.ownership() {
+                        Ownership::Ref => arg_list.push_ref(&**value),
+                        Ownership::Mut => arg_list.push_mut(&mut **value),
+                        Ownership::Owned => arg_list.push_boxed(
+                            // Owned arguments must be taken out of the list.
+                            core::mem::replace(value, Box::new(())),
+                        ),
+                    };
+                }
+                return Ok(match function.call(arg_list)? {
+                    Return::Owned(value) if value.try_downcast_ref::<()>().is_some() => {
+                        String::new()
+                    }
+                    Return::Owned(value) => format!("{value:?}"),
+                    Return::Ref(value) => format!("{value:?}"),
+                    Return::Mut(value) => format!("{value:?}"),
+                });
+            }
+            result
+        }
+    }
+}
+
+/// Returns the names that may complete the last word of `input`.
+///
+/// The first word is completed with the console's built-in commands and the names of the
+/// functions in `functions`, while the resource of `get` and `set` commands is completed with
+/// the short type paths of the reflected resources in `types`.
+pub fn completions(functions: &FunctionRegistry, types: &TypeRegistry, input: &str) -> Vec<String> {
+    let tokens = tokenize(input);
+    let completing_new_word = input.ends_with(char::is_whitespace) || tokens.is_empty();
+    let word = if completing_new_word {
+        ""
+    } else {
+        tokens.last().copied().unwrap_or_default()
+    };
+    let word_index = if completing_new_word {
+        tokens.len()
+    } else {
+        tokens.len() - 1
+    };
+
+    let mut candidates: Vec<String> = match (word_index, tokens.first()) {
+        (0, _) => BUILTIN_COMMANDS
+            .iter()
+            .map(|command| Cow::Borrowed(*command))
+            .chain(functions.iter().filter_map(|function| {
+                function
+                    .name()
+                    .map(|name| Cow::Borrowed(command_name(functions, name)))
+            }))
+            .filter(|candidate| candidate.starts_with(word))
+            .map(Cow::into_owned)
+            .collect(),
+        (1, Some(&("get" | "set"))) => types
+            .iter_with_data::<ReflectResource>()
+            .map(|(registration, _)| registration.type_info().type_path_table().short_path())
+            .filter(|candidate| candidate.starts_with(word))
+            .map(ToOwned::to_owned)
+            .collect(),
+        (1, Some(&"help")) => functions
+            .iter()
+            .filter_map(|function| function.name())
+            .map(|name| command_name(functions, name))
+            .filter(|candidate| candidate.starts_with(word))
+            .map(ToOwned::to_owned)
+            .collect(),
+        _ => Vec::new(),
+    };
+    candidates.sort_unstable();
+    candidates.dedup();
+    candidates
+}
+
+/// Splits a command into words, keeping quoted strings and bracketed RON values together.
+fn tokenize(command: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut start = None;
+    for (i, c) in command.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(start) = start.take() {
+                    tokens.push(&command[start..i]);
+                }
+                continue;
+            }
+            '"' => in_string = true,
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        start.get_or_insert(i);
+    }
+    if let Some(start) = start {
+        tokens.push(&command[start..]);
+    }
+    tokens
+}
+
+/// Returns the name a function is called by: the last segment of its path if no other function
+/// shares it, or its full path otherwise.
+fn command_name<'a>(functions: &FunctionRegistry, name: &'a str) -> &'a str {
+    let short_name = name.rsplit("::").next().unwrap_or(name);
+    let ambiguous = functions
+        .iter()
+        .filter_map(|function| function.name())
+        .filter(|other| other.rsplit("::").next() == Some(short_name))
+        .nth(1)
+        .is_some();
+    if ambiguous {
+        name
+    } else {
+        short_name
+    }
+}
+
+fn find_function<'a>(
+    functions: &'a FunctionRegistry,
+    name: &str,
+) -> Option<&'a DynamicFunction<'static>> {
+    functions.get(name).or_else(|| {
+        let mut matching = functions.iter().filter(|function| {
+            function
+                .name()
+                .is_some_and(|function_name| command_name(functions, function_name) == name)
+        });
+        matching.next().filter(|_| matching.next().is_none())
+    })
+}
+
+fn help(functions: &FunctionRegistry) -> String {
+    let mut lines = vec![
+        "clear                          clear the output".to_owned(),
+        "get <Resource>[.path]          print a resource".to_owned(),
+        "help [function]                list commands or print a function's signature".to_owned(),
+        "resources                      list the reflected resources".to_owned(),
+        "set <Resource>[.path] <value>  change a resource".to_owned(),
+    ];
+    let mut names: Vec<&str> = functions
+        .iter()
+        .filter_map(|function| function.name())
+        .map(|name| command_name(functions, name))
+        .collect();
+    names.sort_unstable();
+    if !names.is_empty() {
+        lines.push(format!("functions: {}", names.join(", ")));
+    }
+    lines.join("\n")
+}
+
+fn format_signature(name: Option<&Cow<'static, str>>, signature: &SignatureInfo) -> String {
+    let args = signature
+        .args()
+        .iter()
+        .map(|arg| match arg.name() {
+            Some(name) => format!("{name}: {}", arg.type_path()),
+            None => arg.type_path().to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "{}({args}) -> {}",
+        name.map_or("<anonymous>", |name| name.as_ref()),
+        signature.return_info().type_path()
+    )
+}
+
+/// Splits `Resource.path` into the resource name and the reflect path of one of its fields.
+fn split_resource_path(target: &str) -> (&str, &str) {
+    let mut depth = 0usize;
+    for (i, c) in target.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            '.' | '[' if depth == 0 => return (&target[..i], &target[i..]),
+            _ => {}
+        }
+    }
+    (target, "")
+}
+
+fn find_resource<'a>(
+    type_registry: &'a TypeRegistry,
+    name: &str,
+) -> Result<&'a ReflectResource, ConsoleError> {
+    type_registry
+        .get_with_type_path(name)
+        .or_else(|| type_registry.get_with_short_type_path(name))
+        .and_then(|registration| registration.data::<ReflectResource>())
+        .ok_or_else(|| ConsoleError::UnknownResource(name.to_owned()))
+}
+
+fn parse_value(
+    type_registry: &TypeRegistry,
+    type_id: core::any::TypeId,
+    value: &str,
+) -> Result<Box<dyn PartialReflect>, ConsoleError> {
+    let registration = type_registry
+        .get(type_id)
+        .ok_or(ConsoleError::UnregisteredType("unknown type"))?;
+    let type_path = registration.type_info().type_path();
+    let mut deserializer =
+        ron::Deserializer::from_str(value).map_err(|err| ConsoleError::InvalidValue {
+            value: value.to_owned(),
+            type_path,
+            error: err.to_string(),
+        })?;
+    TypedReflectDeserializer::new(registration, type_registry)
+        .deserialize(&mut deserializer)
+        .map_err(|err| ConsoleError::InvalidValue {
+            value: value.to_owned(),
+            type_path,
+            error: err.to_string(),
+        })
+}
+
+fn parse_args(
+    type_registry: &TypeRegistry,
+    signature: &SignatureInfo,
+    args: &[&str],
+) -> Result<Vec<Box<dyn PartialReflect>>, ConsoleError> {
+    signature
+        .args()
+        .iter()
+        .zip(args)
+        .map(|(arg, value)| {
+            // References are parsed as the type they point to.
+            let registration = match arg.ownership() {
+                Ownership::Owned => type_registry.get(arg.type_id()),
+                Ownership::Ref | Ownership::Mut => type_registry.get_with_type_path(
+                    arg.type_path()
+                        .trim_start_matches('&')
+                        .trim_start_matches("mut "),
+                ),
+            }
+            .ok_or(ConsoleError::UnregisteredType(arg.type_path()))?;
+            parse_value(type_registry, registration.type_id(), value)
+        })
+        .collect()
+}
+
+#[derive(Component)]
+struct DevConsoleRoot;
+
+#[derive(Component)]
+struct DevConsoleOutput;
+
+#[derive(Component)]
+struct DevConsoleInput;
+
+#[derive(Component)]
+struct DevConsoleHint;
+
+fn setup(mut commands: Commands, config: Res<DevConsoleConfig>) {
+    commands
+        .spawn((
+            Node {
+                // We need to make sure the console doesn't affect the position of other UI nodes
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                top: Val::Px(0.0),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+            // Render the console on top of everything
+            GlobalZIndex(DEV_CONSOLE_ZINDEX),
+            overlay::visibility(config.enabled),
+            DevConsoleRoot,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::default(),
+                config.text_config.clone(),
+                TextColor(config.text_color),
+                DevConsoleOutput,
+            ));
+            parent.spawn((
+                Text::new("> _"),
+                config.text_config.clone(),
+                TextColor(config.text_color),
+                DevConsoleInput,
+            ));
+            parent.spawn((
+                Text::default(),
+                config.text_config.clone(),
+                TextColor(config.hint_color),
+                DevConsoleHint,
+            ));
+        });
+}
+
+fn handle_input(
+    mut events: EventReader<KeyboardInput>,
+    config: Res<DevConsoleConfig>,
+    mut console: ResMut<DevConsole>,
+    functions: Option<Res<AppFunctionRegistry>>,
+    types: Res<AppTypeRegistry>,
+) {
+    for event in events.read() {
+        if event.state != ButtonState::Pressed || Some(event.key_code) == config.toggle_key {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter => {
+                let command = core::mem::take(&mut console.input);
+                if !command.trim().is_empty() {
+                    console.submit(command);
+                }
+            }
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::ArrowUp => console.browse_history(true),
+            Key::ArrowDown => console.browse_history(false),
+            Key::Tab => {
+                let Some(functions) = &functions else {
+                    continue;
+                };
+                let candidates = completions(&functions.read(), &types.read(), &console.input);
+                let word_start = console
+                    .input
+                    .rfind(char::is_whitespace)
+                    .map_or(0, |index| index + 1);
+                let word = &console.input[word_start..];
+                // Complete up to the longest prefix shared by every candidate.
+                let Some(first) = candidates.first() else {
+                    continue;
+                };
+                let mut prefix = first.as_str();
+                for candidate in &candidates[1..] {
+                    let shared = prefix
+                        .char_indices()
+                        .zip(candidate.chars())
+                        .find(|((_, a), b)| a != b)
+                        .map_or(prefix.len().min(candidate.len()), |((index, _), _)| index);
+                    prefix = &prefix[..shared];
+                }
+                if prefix.len() > word.len() {
+                    let prefix = prefix.to_owned();
+                    console.input.truncate(word_start);
+                    console.input.push_str(&prefix);
+                    if candidates.len() == 1 {
+                        console.input.push(' ');
+                    }
+                }
+            }
+            _ => {
+                if let Some(text) = &event.text {
+                    console
+                        .input
+                        .extend(text.chars().filter(|c| !c.is_control()));
+                }
+            }
+        }
+    }
+}
+
+fn execute_commands(world: &mut World) {
+    let pending = core::mem::take(
+        &mut world
+            .resource_mut::<DevConsole>()
+            .bypass_change_detection()
+            .pending,
+    );
+    for command in pending {
+        let output = if command.trim() == "clear" {
+            world.resource_mut::<DevConsole>().clear();
+            continue;
+        } else if world.contains_resource::<AppFunctionRegistry>() {
+            run_command(world, &command)
+        } else {
+            Err(ConsoleError::UnknownCommand(command.clone()))
+        };
+
+        let mut console = world.resource_mut::<DevConsole>();
+        console.print(format!("> {command}"));
+        match output {
+            Ok(output) if output.is_empty() => {}
+            Ok(output) => console.print(output),
+            Err(err) => console.print(format!("error: {err}")),
+        }
+    }
+}
+
+fn update_text(
+    config: Res<DevConsoleConfig>,
+    console: Res<DevConsole>,
+    functions: Option<Res<AppFunctionRegistry>>,
+    types: Res<AppTypeRegistry>,
+    mut output: Query<&mut Text, With<DevConsoleOutput>>,
+    mut input: Query<&mut Text, (With<DevConsoleInput>, Without<DevConsoleOutput>)>,
+    mut hint: Query<
+        &mut Text,
+        (
+            With<DevConsoleHint>,
+            Without<DevConsoleOutput>,
+            Without<DevConsoleInput>,
+        ),
+    >,
+) {
+    let mut lines: Vec<&str> = console.output().rev().take(config.visible_lines).collect();
+    lines.reverse();
+    for mut text in &mut output {
+        text.0 = lines.join("\n");
+    }
+    for mut text in &mut input {
+        text.0 = format!("> {}_", console.input);
+    }
+
+    // Show the signature of the function being called, or the possible completions.
+    let hint_text = functions.map_or_else(String::new, |functions| {
+        let functions = functions.read();
+        let tokens = tokenize(&console.input);
+        match tokens
+            .first()
+            .and_then(|name| find_function(&functions, name))
+        {
+            Some(function) if tokens.len() > 1 || console.input.ends_with(' ') => function
+                .info()
+                .signatures()
+                .iter()
+                .map(|signature| format_signature(function.name(), signature))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            _ if console.input.is_empty() => String::new(),
+            _ => completions(&functions, &types.read(), &console.input).join("  "),
+        }
+    });
+    for mut text in &mut hint {
+        text.0.clone_from(&hint_text);
+    }
+}
+
+fn customize_text(
+    config: Res<DevConsoleConfig>,
+    mut query: Query<(&mut TextFont, &mut TextColor, Option<&DevConsoleHint>)>,
+) {
+    for (mut font, mut color, hint) in &mut query {
+        *font = config.text_config.clone();
+        color.0 = match hint {
+            Some(_) => config.hint_color,
+            None => config.text_color,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::reflect::ReflectResource;
+    use bevy_reflect::Reflect;
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Gravity {
+        strength: f32,
+    }
+
+    fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.register_type::<Gravity>()
+            .init_resource::<Gravity>()
+            .register_function(add);
+        app
+    }
+
+    #[test]
+    fn tokenize_keeps_values_together() {
+        assert_eq!(
+            tokenize(r#"spawn "two words" (x: 1, y: 2)  [1, 2]"#),
+            vec!["spawn", r#""two words""#, "(x: 1, y: 2)", "[1, 2]"]
+        );
+    }
+
+    #[test]
+    fn functions_are_called() {
+        let mut app = setup_app();
+        assert_eq!(run_command(app.world_mut(), "add 1 2").unwrap(), "3");
+        assert!(matches!(
+            run_command(app.world_mut(), "add 1"),
+            Err(ConsoleError::Usage(_))
+        ));
+        assert!(matches!(
+            run_command(app.world_mut(), "sub 1 2"),
+            Err(ConsoleError::UnknownCommand(_))
+        ));
+    }
+
+    #[test]
+    fn resources_are_tweaked() {
+        let mut app = setup_app();
+        run_command(app.world_mut(), "set Gravity.strength 9.8").unwrap();
+        assert_eq!(app.world().resource::<Gravity>().strength, 9.8);
+        assert_eq!(
+            run_command(app.world_mut(), "get Gravity.strength").unwrap(),
+            "9.8"
+        );
+    }
+
+    #[test]
+    fn names_are_completed() {
+        let app = setup_app();
+        let world = app.world();
+        let functions = world.resource::<AppFunctionRegistry>().read();
+        let types = world.resource::<AppTypeRegistry>().read();
+        assert_eq!(completions(&functions, &types, "ad"), vec!["add"]);
+        assert_eq!(completions(&functions, &types, "set Gra"), vec!["Gravity"]);
+    }
+}
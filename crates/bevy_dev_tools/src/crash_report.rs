@@ -0,0 +1,294 @@
+//! Module containing logic for crash reports.
+//!
+//! The [`CrashReportPlugin`] installs a panic hook gathering what the engine was doing when the
+//! application panicked into a [`CrashReport`]: the panic message and location, the schedule and
+//! system being run, the adapter in use by the renderer and the most recent log events. The
+//! report is written to a file and given to an optional callback, for example to upload it.
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_dev_tools::{crash_report::CrashReportPlugin, log_panel::log_capture_layer};
+//! # use bevy_log::LogPlugin;
+//! App::new()
+//!     .add_plugins((
+//!         // The recent log events are only included when they are captured.
+//!         LogPlugin {
+//!             custom_layer: log_capture_layer,
+//!             ..Default::default()
+//!         },
+//!         CrashReportPlugin::default().on_crash(|report| eprintln!("{}", report.message)),
+//!     ))
+//!     .run();
+//! ```
+//!
+//! Only panics are reported, including those aborting the process with `panic = "abort"`.
+//! Crashes raising a signal, such as segmentation faults, are not: almost nothing can be safely
+//! done from a signal handler, and certainly not gathering the engine state.
+
+use alloc::sync::Arc;
+use core::fmt;
+use std::{
+    backtrace::Backtrace,
+    fs, io,
+    panic::{self, PanicHookInfo},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::{
+    schedule::{current_thread_system, enable_system_tracking, last_started_schedule},
+    system::{Res, Resource},
+};
+use bevy_render::renderer::RenderAdapterInfo;
+
+use crate::log_panel::{LogBuffer, LogEntry};
+
+/// A callback given every [`CrashReport`], see [`CrashReportPlugin::on_crash`].
+pub type CrashCallback = Arc<dyn Fn(&CrashReport) + Send + Sync>;
+
+/// A plugin that reports panics of the application, see the [module documentation](self).
+///
+/// System tracking is enabled by this plugin, see
+/// [`enable_system_tracking`](bevy_ecs::schedule::enable_system_tracking).
+pub struct CrashReportPlugin {
+    /// The directory crash reports are written to, or `None` not to write them.
+    pub output_dir: Option<PathBuf>,
+    /// How many of the most recent log events are included in crash reports.
+    pub max_log_lines: usize,
+    /// A callback given every crash report, after it was written.
+    pub callback: Option<CrashCallback>,
+}
+
+impl Default for CrashReportPlugin {
+    fn default() -> Self {
+        Self {
+            output_dir: Some(PathBuf::from("crash_reports")),
+            max_log_lines: 50,
+            callback: None,
+        }
+    }
+}
+
+impl CrashReportPlugin {
+    /// Sets the callback given every crash report.
+    pub fn on_crash(mut self, callback: impl Fn(&CrashReport) + Send + Sync + 'static) -> Self {
+        self.callback = Some(Arc::new(callback));
+        self
+    }
+}
+
+impl Plugin for CrashReportPlugin {
+    fn build(&self, app: &mut App) {
+        enable_system_tracking(true);
+
+        let context = CrashContext::default();
+        app.insert_resource(context.clone())
+            .add_systems(Startup, capture_context);
+
+        let output_dir = self.output_dir.clone();
+        let max_log_lines = self.max_log_lines;
+        let callback = self.callback.clone();
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            previous_hook(info);
+
+            let report = CrashReport::from_panic(info, &context, max_log_lines);
+            if let Some(output_dir) = &output_dir {
+                match report.write_to(output_dir) {
+                    Ok(path) => eprintln!("Crash report written to {}", path.display()),
+                    Err(error) => eprintln!("Failed to write the crash report: {error}"),
+                }
+            }
+            if let Some(callback) = &callback {
+                callback(&report);
+            }
+        }));
+    }
+}
+
+/// The state captured from the app for crash reports.
+#[derive(Resource, Clone, Default)]
+struct CrashContext(Arc<Mutex<CrashContextInner>>);
+
+#[derive(Default)]
+struct CrashContextInner {
+    adapter: Option<String>,
+    logs: Option<LogBuffer>,
+}
+
+/// Captures the adapter in use by the renderer and the [`LogBuffer`], once they exist.
+fn capture_context(
+    context: Res<CrashContext>,
+    adapter: Option<Res<RenderAdapterInfo>>,
+    logs: Option<Res<LogBuffer>>,
+) {
+    let Ok(mut context) = context.0.lock() else {
+        return;
+    };
+    context.adapter = adapter.map(|adapter| {
+        format!(
+            "{} ({:?}, {:?}, driver {} {})",
+            adapter.name, adapter.device_type, adapter.backend, adapter.driver, adapter.driver_info
+        )
+    });
+    context.logs = logs.map(|logs| logs.clone());
+}
+
+/// What the engine was doing when the application panicked, see [`CrashReportPlugin`].
+#[derive(Debug, Clone)]
+pub struct CrashReport {
+    /// The panic message.
+    pub message: String,
+    /// The source location of the panic.
+    pub location: Option<String>,
+    /// The name of the thread that panicked.
+    pub thread: Option<String>,
+    /// The backtrace of the panic.
+    pub backtrace: String,
+    /// The schedule most recently started, see
+    /// [`last_started_schedule`](bevy_ecs::schedule::last_started_schedule).
+    pub schedule: Option<String>,
+    /// The system most recently started on the thread that panicked.
+    pub system: Option<String>,
+    /// Whether [`system`](Self::system) was still running, which means it panicked.
+    pub system_running: bool,
+    /// The adapter in use by the renderer.
+    pub adapter: Option<String>,
+    /// The most recent log events, if captured with
+    /// [`log_capture_layer`](crate::log_panel::log_capture_layer).
+    pub recent_logs: Vec<LogEntry>,
+    /// The number of seconds since the Unix epoch when the report was made.
+    pub timestamp: u64,
+    /// The version of Bevy.
+    pub bevy_version: &'static str,
+}
+
+impl CrashReport {
+    fn from_panic(info: &PanicHookInfo, context: &CrashContext, max_log_lines: usize) -> Self {
+        let payload = info.payload();
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            (*message).to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "Box<dyn Any>".to_string()
+        };
+        let mut report = Self::new(message, context, max_log_lines);
+        report.location = info.location().map(ToString::to_string);
+        report.backtrace = Backtrace::force_capture().to_string();
+        report
+    }
+
+    fn new(message: String, context: &CrashContext, max_log_lines: usize) -> Self {
+        let system = current_thread_system();
+        // The lock can be poisoned when the panic happened while capturing the context.
+        let context = context
+            .0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let recent_logs = context.logs.as_ref().map_or_else(Vec::new, |logs| {
+            logs.with_entries(|entries| {
+                let skip = entries.len().saturating_sub(max_log_lines);
+                entries.iter().skip(skip).cloned().collect()
+            })
+        });
+        Self {
+            message,
+            location: None,
+            thread: thread::current().name().map(ToString::to_string),
+            backtrace: String::new(),
+            schedule: last_started_schedule().map(|label| format!("{label:?}")),
+            system_running: system.as_ref().is_some_and(|system| system.running),
+            system: system.map(|system| system.name.into_owned()),
+            adapter: context.adapter.clone(),
+            recent_logs,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |time| time.as_secs()),
+            bevy_version: env!("CARGO_PKG_VERSION"),
+        }
+    }
+
+    /// Writes the report to a new file named after its timestamp in `dir`, creating the directory
+    /// if needed. Returns the path of the file.
+    pub fn write_to(&self, dir: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        let mut path = dir.join(format!("crash-{}.txt", self.timestamp));
+        let mut index = 1;
+        while path.exists() {
+            path = dir.join(format!("crash-{}-{index}.txt", self.timestamp));
+            index += 1;
+        }
+        fs::write(&path, self.to_string())?;
+        Ok(path)
+    }
+}
+
+impl fmt::Display for CrashReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn or_unknown(value: &Option<String>) -> &str {
+            value.as_deref().unwrap_or("unknown")
+        }
+
+        writeln!(f, "Bevy {} crash report", self.bevy_version)?;
+        writeln!(f, "Timestamp: {}", self.timestamp)?;
+        writeln!(f, "Message: {}", self.message)?;
+        writeln!(f, "Location: {}", or_unknown(&self.location))?;
+        writeln!(f, "Thread: {}", or_unknown(&self.thread))?;
+        writeln!(f, "Schedule: {}", or_unknown(&self.schedule))?;
+        match &self.system {
+            Some(system) if self.system_running => writeln!(f, "System: {system}")?,
+            Some(system) => writeln!(f, "System: none, last finished {system}")?,
+            None => writeln!(f, "System: unknown")?,
+        }
+        writeln!(f, "Adapter: {}", or_unknown(&self.adapter))?;
+        if !self.recent_logs.is_empty() {
+            writeln!(f, "\nRecent logs:")?;
+            for entry in &self.recent_logs {
+                writeln!(f, "{entry}")?;
+            }
+        }
+        if !self.backtrace.is_empty() {
+            writeln!(f, "\nBacktrace:\n{}", self.backtrace)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_log::tracing::Level;
+
+    #[test]
+    fn report_includes_recent_logs() {
+        let logs = LogBuffer::default();
+        for index in 0..5 {
+            logs.push(LogEntry {
+                level: Level::INFO,
+                target: "crash".to_string(),
+                message: format!("event {index}"),
+                time: 0.0,
+            });
+        }
+        let context = CrashContext::default();
+        context.0.lock().unwrap().logs = Some(logs);
+
+        let report = CrashReport::new("oops".to_string(), &context, 2);
+        let messages: Vec<_> = report
+            .recent_logs
+            .iter()
+            .map(|entry| entry.message.as_str())
+            .collect();
+        assert_eq!(messages, ["event 3", "event 4"]);
+
+        let text = report.to_string();
+        assert!(text.contains("Message: oops"));
+        assert!(text.contains("event 4"));
+        assert!(text.contains("Adapter: unknown"));
+    }
+}
@@ -0,0 +1,360 @@
+//! Module containing logic for the frame time graph overlay.
+//!
+//! Unlike the [`fps_overlay`](crate::fps_overlay), which only shows a smoothed FPS number, this
+//! overlay shows the distribution of recent frame times: a graph of the last frames, a histogram,
+//! the 1% and 0.1% lows and the CPU time spent in each schedule of the [`Main`](bevy_app::Main)
+//! schedule.
+
+use alloc::collections::VecDeque;
+use core::time::Duration;
+
+use bevy_app::{Plugin, Startup, Update};
+use bevy_asset::Handle;
+use bevy_color::{Alpha, Color, LinearRgba};
+use bevy_diagnostic::{
+    Diagnostic, DiagnosticsStore, ScheduleTimeDiagnostics, ScheduleTimeDiagnosticsPlugin,
+};
+use bevy_ecs::{
+    component::Component,
+    query::With,
+    schedule::{common_conditions::resource_changed, IntoSystemConfigs},
+    system::{Commands, Query, Res, ResMut, Resource},
+};
+use bevy_hierarchy::{BuildChildren, ChildBuild};
+use bevy_input::keyboard::KeyCode;
+use bevy_text::{Font, TextColor, TextFont};
+use bevy_time::{Real, Time};
+use bevy_ui::{
+    widget::Text, AlignItems, BackgroundColor, FlexDirection, GlobalZIndex, Node, PositionType,
+    UiRect, Val,
+};
+
+use crate::overlay::{self, OverlayConfig};
+
+/// [`GlobalZIndex`] used to render the frame time graph overlay.
+///
+/// One below [`FPS_OVERLAY_ZINDEX`](crate::fps_overlay::FPS_OVERLAY_ZINDEX), so both overlays can be used together.
+pub const FRAME_TIME_GRAPH_ZINDEX: i32 = crate::fps_overlay::FPS_OVERLAY_ZINDEX - 1;
+
+/// Number of frame times kept in the [`FrameTimeHistory`].
+///
+/// Large enough for the 0.1% low to be computed from at least one frame.
+pub const FRAME_TIME_HISTORY_LENGTH: usize = 1000;
+
+const GRAPH_BARS: usize = 120;
+const GRAPH_BAR_WIDTH: f32 = 2.0;
+const GRAPH_HEIGHT: f32 = 60.0;
+const HISTOGRAM_BUCKETS: usize = 24;
+const HISTOGRAM_BUCKET_WIDTH: f32 = GRAPH_BARS as f32 * GRAPH_BAR_WIDTH / HISTOGRAM_BUCKETS as f32;
+const HISTOGRAM_HEIGHT: f32 = 40.0;
+
+/// A plugin that adds a frame time graph overlay to the Bevy application.
+///
+/// This plugin will add the [`ScheduleTimeDiagnosticsPlugin`] if it wasn't added before.
+#[derive(Default)]
+pub struct FrameTimeGraphPlugin {
+    /// Starting configuration of the overlay, this can later be changed through the [`FrameTimeGraphConfig`] resource.
+    pub config: FrameTimeGraphConfig,
+}
+
+impl Plugin for FrameTimeGraphPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        // TODO: Use plugin dependencies, see https://github.com/bevyengine/bevy/issues/69
+        if !app.is_plugin_added::<ScheduleTimeDiagnosticsPlugin>() {
+            app.add_plugins(ScheduleTimeDiagnosticsPlugin);
+        }
+        app.insert_resource(self.config.clone())
+            .init_resource::<FrameTimeHistory>()
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    record_frame_time,
+                    overlay::toggle_with_key::<FrameTimeGraphConfig>,
+                    (
+                        customize_text,
+                        overlay::toggle_display::<FrameTimeGraphConfig, FrameTimeGraph>,
+                    )
+                        .run_if(resource_changed::<FrameTimeGraphConfig>),
+                    (update_graph, update_histogram, update_text).run_if(overlay_enabled),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Configuration options for the frame time graph overlay.
+#[derive(Resource, Clone)]
+pub struct FrameTimeGraphConfig {
+    /// Configuration of text in the overlay.
+    pub text_config: TextFont,
+    /// Color of text in the overlay.
+    pub text_color: Color,
+    /// Frame time the overlay colors frames against.
+    ///
+    /// Frames up to this time are drawn green, frames up to twice this time yellow, and slower
+    /// frames red. The graph and histogram are scaled to four times this time.
+    pub target_frame_time: Duration,
+    /// Key toggling [`FrameTimeGraphConfig::enabled`], if any.
+    pub toggle_key: Option<KeyCode>,
+    /// Displays the overlay if true.
+    pub enabled: bool,
+}
+
+impl Default for FrameTimeGraphConfig {
+    fn default() -> Self {
+        FrameTimeGraphConfig {
+            text_config: TextFont {
+                font: Handle::<Font>::default(),
+                font_size: 14.0,
+                ..Default::default()
+            },
+            text_color: Color::WHITE,
+            target_frame_time: Duration::from_secs_f64(1.0 / 60.0),
+            toggle_key: Some(KeyCode::F10),
+            enabled: true,
+        }
+    }
+}
+
+impl OverlayConfig for FrameTimeGraphConfig {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn toggle_key(&self) -> Option<KeyCode> {
+        self.toggle_key
+    }
+}
+
+/// The real time taken by the last [`FRAME_TIME_HISTORY_LENGTH`] frames.
+#[derive(Resource, Default, Debug)]
+pub struct FrameTimeHistory {
+    frame_times: VecDeque<Duration>,
+}
+
+impl FrameTimeHistory {
+    /// Records the time taken by a frame, dropping the oldest one if the history is full.
+    pub fn push(&mut self, frame_time: Duration) {
+        if self.frame_times.len() == FRAME_TIME_HISTORY_LENGTH {
+            self.frame_times.pop_front();
+        }
+        self.frame_times.push_back(frame_time);
+    }
+
+    /// Returns an iterator over the recorded frame times, from oldest to newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Duration> + ExactSizeIterator + '_ {
+        self.frame_times.iter().copied()
+    }
+
+    /// Returns the average frame time, or `None` if no frame was recorded.
+    pub fn average(&self) -> Option<Duration> {
+        let count = u32::try_from(self.frame_times.len())
+            .ok()
+            .filter(|n| *n > 0)?;
+        Some(self.iter().sum::<Duration>() / count)
+    }
+
+    /// Returns the average of the slowest `fraction` of the recorded frames, e.g. `0.01` for the
+    /// frame time of the "1% low", or `None` if no frame was recorded.
+    ///
+    /// At least one frame is always included.
+    pub fn low(&self, fraction: f64) -> Option<Duration> {
+        if self.frame_times.is_empty() {
+            return None;
+        }
+        let mut frame_times: Vec<Duration> = self.iter().collect();
+        frame_times.sort_unstable_by(|a, b| b.cmp(a));
+        let count = ((frame_times.len() as f64 * fraction).ceil() as usize).max(1);
+        Some(frame_times.iter().take(count).sum::<Duration>() / count as u32)
+    }
+}
+
+#[derive(Component)]
+struct FrameTimeGraph;
+
+#[derive(Component)]
+struct FrameTimeGraphText;
+
+/// A bar of the graph, the index counting back from the most recent frame.
+#[derive(Component)]
+struct GraphBar(usize);
+
+#[derive(Component)]
+struct HistogramBar(usize);
+
+fn setup(mut commands: Commands, config: Res<FrameTimeGraphConfig>) {
+    let bar_container = |height: f32| Node {
+        width: Val::Px(GRAPH_BARS as f32 * GRAPH_BAR_WIDTH),
+        height: Val::Px(height),
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::End,
+        ..Default::default()
+    };
+
+    commands
+        .spawn((
+            Node {
+                // We need to make sure the overlay doesn't affect the position of other UI nodes
+                position_type: PositionType::Absolute,
+                right: Val::Px(0.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(4.0)),
+                row_gap: Val::Px(4.0),
+                ..Default::default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.6)),
+            // Render overlay on top of everything
+            GlobalZIndex(FRAME_TIME_GRAPH_ZINDEX),
+            overlay::visibility(config.enabled),
+            FrameTimeGraph,
+        ))
+        .with_children(|p| {
+            p.spawn(bar_container(GRAPH_HEIGHT)).with_children(|p| {
+                // Oldest frame on the left.
+                for index in (0..GRAPH_BARS).rev() {
+                    p.spawn((
+                        Node {
+                            width: Val::Px(GRAPH_BAR_WIDTH),
+                            height: Val::Px(0.0),
+                            ..Default::default()
+                        },
+                        BackgroundColor(Color::NONE),
+                        GraphBar(index),
+                    ));
+                }
+            });
+            p.spawn(bar_container(HISTOGRAM_HEIGHT)).with_children(|p| {
+                for index in 0..HISTOGRAM_BUCKETS {
+                    p.spawn((
+                        Node {
+                            width: Val::Px(HISTOGRAM_BUCKET_WIDTH - 1.0),
+                            height: Val::Px(0.0),
+                            margin: UiRect::right(Val::Px(1.0)),
+                            ..Default::default()
+                        },
+                        BackgroundColor(Color::NONE),
+                        HistogramBar(index),
+                    ));
+                }
+            });
+            p.spawn((
+                Text::default(),
+                config.text_config.clone(),
+                TextColor(config.text_color),
+                FrameTimeGraphText,
+            ));
+        });
+}
+
+fn overlay_enabled(config: Res<FrameTimeGraphConfig>) -> bool {
+    config.enabled
+}
+
+/// Returns `frame_time` relative to the target, and the color it is drawn with.
+fn frame_time_ratio(frame_time: Duration, target: Duration) -> (f32, Color) {
+    let ratio = frame_time.as_secs_f32() / target.as_secs_f32().max(f32::EPSILON);
+    let color = if ratio <= 1.0 {
+        LinearRgba::GREEN
+    } else if ratio <= 2.0 {
+        LinearRgba::rgb(1.0, 1.0, 0.0)
+    } else {
+        LinearRgba::RED
+    };
+    (ratio, color.into())
+}
+
+fn record_frame_time(time: Res<Time<Real>>, mut history: ResMut<FrameTimeHistory>) {
+    let delta = time.delta();
+    if delta.is_zero() {
+        return;
+    }
+    history.push(delta);
+}
+
+fn update_graph(
+    config: Res<FrameTimeGraphConfig>,
+    history: Res<FrameTimeHistory>,
+    mut query: Query<(&GraphBar, &mut Node, &mut BackgroundColor)>,
+) {
+    for (bar, mut node, mut background) in &mut query {
+        let Some(frame_time) = history.iter().rev().nth(bar.0) else {
+            node.height = Val::Px(0.0);
+            continue;
+        };
+        let (ratio, color) = frame_time_ratio(frame_time, config.target_frame_time);
+        node.height = Val::Px((ratio / 4.0).min(1.0) * GRAPH_HEIGHT);
+        background.0 = color;
+    }
+}
+
+fn update_histogram(
+    config: Res<FrameTimeGraphConfig>,
+    history: Res<FrameTimeHistory>,
+    mut query: Query<(&HistogramBar, &mut Node, &mut BackgroundColor)>,
+) {
+    // Buckets cover up to four times the target, the last one also counting slower frames.
+    let mut counts = [0usize; HISTOGRAM_BUCKETS];
+    for frame_time in history.iter() {
+        let (ratio, _) = frame_time_ratio(frame_time, config.target_frame_time);
+        let bucket = (ratio / 4.0 * HISTOGRAM_BUCKETS as f32) as usize;
+        counts[bucket.min(HISTOGRAM_BUCKETS - 1)] += 1;
+    }
+    let max_count = counts.iter().copied().max().unwrap_or(0).max(1);
+
+    for (bar, mut node, mut background) in &mut query {
+        let bucket_time = config
+            .target_frame_time
+            .mul_f32(4.0 * bar.0 as f32 / HISTOGRAM_BUCKETS as f32);
+        let (_, color) = frame_time_ratio(bucket_time, config.target_frame_time);
+        node.height = Val::Px(counts[bar.0] as f32 / max_count as f32 * HISTOGRAM_HEIGHT);
+        background.0 = color;
+    }
+}
+
+fn update_text(
+    history: Res<FrameTimeHistory>,
+    diagnostics: Res<DiagnosticsStore>,
+    schedule_times: Option<Res<ScheduleTimeDiagnostics>>,
+    mut query: Query<&mut Text, With<FrameTimeGraphText>>,
+) {
+    let fps = |frame_time: Duration| 1.0 / frame_time.as_secs_f64().max(f64::EPSILON);
+    let (Some(average), Some(low_1), Some(low_01)) =
+        (history.average(), history.low(0.01), history.low(0.001))
+    else {
+        return;
+    };
+
+    let mut text = format!(
+        "{:.2} ms ({:.1} FPS)\n1% low: {:.1} FPS\n0.1% low: {:.1} FPS",
+        average.as_secs_f64() * 1000.0,
+        fps(average),
+        fps(low_1),
+        fps(low_01),
+    );
+    for path in schedule_times.iter().flat_map(|times| times.paths()) {
+        let Some(value) = diagnostics.get(path).and_then(Diagnostic::smoothed) else {
+            continue;
+        };
+        let name = path.components().last().unwrap_or_default();
+        text.push_str(&format!("\n{name}: {value:.2} ms"));
+    }
+
+    for mut text_node in &mut query {
+        text_node.0.clone_from(&text);
+    }
+}
+
+fn customize_text(
+    config: Res<FrameTimeGraphConfig>,
+    mut query: Query<(&mut TextFont, &mut TextColor), With<FrameTimeGraphText>>,
+) {
+    for (mut font, mut color) in &mut query {
+        *font = config.text_config.clone();
+        color.0 = config.text_color;
+    }
+}
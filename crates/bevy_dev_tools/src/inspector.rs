@@ -0,0 +1,952 @@
+//! Module containing logic for the world inspector.
+//!
+//! The [`InspectorPlugin`] adds an overlay, toggled with `F8` by default, to browse the world:
+//! - The entities tab lists entities following their hierarchy, and the resources tab lists
+//!   reflected resources.
+//! - Clicking an entity shows the values of its components, and clicking a resource shows its
+//!   value. Values are displayed through reflection, so only types registered in the
+//!   [`AppTypeRegistry`] with `#[reflect(Component)]` or `#[reflect(Resource)]` can be inspected.
+//! - Clicking a field edits it: type its new value in [RON] and press `Enter` to apply it, or
+//!   `Escape` to cancel.
+//! - Clicking the search box filters entities by name or component, and resources by name.
+//!
+//! The data displayed by the overlay is also available from code, through [`entity_rows`],
+//! [`component_fields`], [`resource_fields`] and [`set_field`].
+//!
+//! [RON]: https://github.com/ron-rs/ron
+
+use alloc::collections::VecDeque;
+use core::{fmt::Write, time::Duration};
+
+use bevy_app::{App, Plugin, Startup, Update};
+use bevy_asset::Handle;
+use bevy_color::Color;
+use bevy_ecs::{
+    change_detection::{DetectChanges, DetectChangesMut},
+    component::Component,
+    entity::Entity,
+    event::EventReader,
+    name::Name,
+    query::{Changed, With},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    schedule::{common_conditions::resource_changed, IntoSystemConfigs},
+    system::{Commands, Local, Query, Res, ResMut, Resource},
+    world::{EntityRef, World},
+};
+use bevy_hierarchy::{BuildChildren, ChildBuild, Children, DespawnRecursiveExt, Parent};
+use bevy_input::{
+    keyboard::{Key, KeyCode, KeyboardInput},
+    ButtonState,
+};
+use bevy_reflect::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    GetPath, PartialReflect, Reflect, ReflectRef, TypeRegistry,
+};
+use bevy_text::{Font, TextColor, TextFont};
+use bevy_time::{Real, Time};
+use bevy_ui::{
+    widget::{Button, Text},
+    BackgroundColor, FlexDirection, GlobalZIndex, Interaction, Node, PositionType, UiRect, Val,
+};
+use disqualified::ShortName;
+use serde::de::DeserializeSeed;
+use thiserror::Error;
+
+use crate::overlay::{self, OverlayConfig};
+
+/// [`GlobalZIndex`] used to render the inspector.
+///
+/// Two below [`PROFILER_ZINDEX`](crate::profiler::PROFILER_ZINDEX), so the developer console
+/// is displayed above it.
+pub const INSPECTOR_ZINDEX: i32 = crate::profiler::PROFILER_ZINDEX - 2;
+
+/// Nested fields deeper than this are displayed, and edited, as a whole.
+const MAX_FIELD_DEPTH: usize = 4;
+
+/// Only the first items of lists and arrays are displayed.
+const MAX_LIST_ITEMS: usize = 16;
+
+/// Values longer than this are truncated.
+const MAX_VALUE_LENGTH: usize = 60;
+
+/// A plugin that adds a world inspector overlay to the Bevy application.
+///
+/// See the [module-level documentation](self) for how to use it.
+///
+/// Note: the inspector doesn't consume keyboard input, so other systems still receive the keys
+/// typed into it.
+#[derive(Default)]
+pub struct InspectorPlugin {
+    /// Starting configuration of the inspector, this can later be changed through the [`InspectorConfig`] resource.
+    pub config: InspectorConfig,
+}
+
+impl Plugin for InspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone())
+            .init_resource::<Inspector>()
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    overlay::toggle_with_key::<InspectorConfig>,
+                    overlay::toggle_display::<InspectorConfig, InspectorRoot>
+                        .run_if(resource_changed::<InspectorConfig>),
+                    (handle_clicks, handle_input, apply_edits, refresh)
+                        .chain()
+                        .run_if(|config: Res<InspectorConfig>| config.enabled),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Configuration options for the world inspector.
+#[derive(Resource, Clone)]
+pub struct InspectorConfig {
+    /// Configuration of text in the inspector.
+    pub text_config: TextFont,
+    /// Color of text in the inspector.
+    pub text_color: Color,
+    /// Color of the selected entries and of the field being edited.
+    pub highlight_color: Color,
+    /// Color of error messages.
+    pub error_color: Color,
+    /// How many entities or resources are listed, and how many fields are displayed.
+    pub max_rows: usize,
+    /// How often the displayed values are refreshed.
+    pub refresh_interval: Duration,
+    /// Key toggling [`InspectorConfig::enabled`], if any.
+    pub toggle_key: Option<KeyCode>,
+    /// Displays the inspector if true.
+    pub enabled: bool,
+}
+
+impl Default for InspectorConfig {
+    fn default() -> Self {
+        InspectorConfig {
+            text_config: TextFont {
+                font: Handle::<Font>::default(),
+                font_size: 13.0,
+                ..Default::default()
+            },
+            text_color: Color::WHITE,
+            highlight_color: Color::srgb(1.0, 0.8, 0.2),
+            error_color: Color::srgb(1.0, 0.3, 0.3),
+            max_rows: 24,
+            refresh_interval: Duration::from_millis(250),
+            toggle_key: Some(KeyCode::F8),
+            enabled: false,
+        }
+    }
+}
+
+impl OverlayConfig for InspectorConfig {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn toggle_key(&self) -> Option<KeyCode> {
+        self.toggle_key
+    }
+}
+
+/// A tab of the world inspector.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InspectorTab {
+    /// Lists the entities, and the components of the selected entity.
+    #[default]
+    Entities,
+    /// Lists the reflected resources, and the value of the selected resource.
+    Resources,
+}
+
+/// What owns a field displayed or edited by the inspector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldTarget {
+    /// A component of this entity.
+    Component(Entity),
+    /// A resource.
+    Resource,
+}
+
+/// The state of the world inspector.
+#[derive(Resource, Default, Debug)]
+pub struct Inspector {
+    /// The displayed tab.
+    pub tab: InspectorTab,
+    /// Filters the listed entities or resources.
+    pub search: String,
+    /// The entity whose components are displayed.
+    pub selected_entity: Option<Entity>,
+    /// The type path of the resource whose value is displayed.
+    pub selected_resource: Option<String>,
+    focus: Focus,
+    pending: VecDeque<FieldEdit>,
+    message: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+enum Focus {
+    #[default]
+    None,
+    Search,
+    Edit(FieldEdit),
+}
+
+/// A field being edited, with the text of its new value.
+#[derive(Debug, Clone, PartialEq)]
+struct FieldEdit {
+    target: FieldTarget,
+    type_path: String,
+    path: String,
+    value: String,
+}
+
+/// An entity listed by the inspector, see [`entity_rows`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityRow {
+    /// The listed entity.
+    pub entity: Entity,
+    /// The depth of the entity in the hierarchy, `0` for root entities.
+    pub depth: usize,
+    /// The name of the entity, or its ID if it doesn't have a [`Name`].
+    pub label: String,
+}
+
+/// A field of a component or resource, see [`component_fields`] and [`resource_fields`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldRow {
+    /// The type path of the component or resource owning the field.
+    pub type_path: String,
+    /// The [reflect path](bevy_reflect::ReflectPath) of the field, empty for the component or resource itself.
+    pub path: String,
+    /// How deeply the field is nested, `0` for the component or resource itself.
+    pub depth: usize,
+    /// The name of the field, or of the component or resource.
+    pub label: String,
+    /// The value of the field, if it isn't displayed through its own fields.
+    pub value: Option<String>,
+    /// The value of the field written in RON, if it can be edited.
+    pub ron: Option<String>,
+}
+
+/// An error returned when the inspector fails to edit a value.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum InspectorError {
+    /// The entity doesn't exist.
+    #[error("entity {0} doesn't exist")]
+    NoSuchEntity(Entity),
+    /// The type isn't registered, or isn't reflected as a component or resource.
+    #[error("type `{0}` isn't a reflected component or resource")]
+    NotReflected(String),
+    /// The entity doesn't have the component, or the world doesn't have the resource.
+    #[error("`{0}` is missing")]
+    Missing(String),
+    /// The field doesn't exist.
+    #[error("invalid path: {0}")]
+    InvalidPath(String),
+    /// The new value couldn't be parsed, or applied to the field.
+    #[error("invalid value: {0}")]
+    InvalidValue(String),
+}
+
+/// Marks the entities of the inspector's interface, which are not listed by the inspector.
+#[derive(Component)]
+struct InspectorNode;
+
+#[derive(Component)]
+struct InspectorRoot;
+
+/// What happens when a row of the inspector is clicked.
+#[derive(Component, Clone)]
+enum InspectorAction {
+    Tab(InspectorTab),
+    FocusSearch,
+    SelectEntity(Entity),
+    SelectResource(String),
+    Edit(FieldEdit),
+}
+
+/// Returns the rows of the entity tree, or the entities matching `search`.
+///
+/// Without a search, entities are listed following their hierarchy. Otherwise, they are listed
+/// flat, if their name, ID or the name of one of their components contains `search`, ignoring
+/// case. The entities of the inspector itself are never listed.
+pub fn entity_rows(world: &World, search: &str) -> Vec<EntityRow> {
+    let search = search.to_lowercase();
+    let mut rows = Vec::new();
+    if search.is_empty() {
+        let mut roots: Vec<Entity> = world
+            .iter_entities()
+            .filter(|entity| !entity.contains::<Parent>() && !entity.contains::<InspectorNode>())
+            .map(|entity| entity.id())
+            .collect();
+        roots.sort_unstable();
+        for root in roots {
+            push_entity_tree(world, root, 0, &mut rows);
+        }
+    } else {
+        rows.extend(
+            world
+                .iter_entities()
+                .filter(|entity| !entity.contains::<InspectorNode>())
+                .filter(|entity| {
+                    entity_label(entity).to_lowercase().contains(&search)
+                        || entity.archetype().components().any(|id| {
+                            world.components().get_info(id).is_some_and(|info| {
+                                ShortName(info.name())
+                                    .to_string()
+                                    .to_lowercase()
+                                    .contains(&search)
+                            })
+                        })
+                })
+                .map(|entity| EntityRow {
+                    entity: entity.id(),
+                    depth: 0,
+                    label: entity_label(&entity),
+                }),
+        );
+        rows.sort_unstable_by_key(|row| row.entity);
+    }
+    rows
+}
+
+fn push_entity_tree(world: &World, entity: Entity, depth: usize, rows: &mut Vec<EntityRow>) {
+    let Ok(entity_ref) = world.get_entity(entity) else {
+        return;
+    };
+    rows.push(EntityRow {
+        entity,
+        depth,
+        label: entity_label(&entity_ref),
+    });
+    if let Some(children) = entity_ref.get::<Children>() {
+        for &child in children {
+            push_entity_tree(world, child, depth + 1, rows);
+        }
+    }
+}
+
+fn entity_label(entity: &EntityRef) -> String {
+    match entity.get::<Name>() {
+        Some(name) => format!("{name} ({})", entity.id()),
+        None => format!("Entity {}", entity.id()),
+    }
+}
+
+/// Returns the fields of every component of `entity`, sorted by component name.
+///
+/// Components that aren't reflected are listed without a value.
+pub fn component_fields(world: &World, entity: Entity) -> Result<Vec<FieldRow>, InspectorError> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let entity_ref = world
+        .get_entity(entity)
+        .map_err(|_| InspectorError::NoSuchEntity(entity))?;
+
+    let mut components: Vec<_> = entity_ref
+        .archetype()
+        .components()
+        .filter_map(|id| world.components().get_info(id))
+        .map(|info| {
+            let reflect_component = info
+                .type_id()
+                .and_then(|type_id| type_registry.get(type_id))
+                .and_then(|registration| registration.data::<ReflectComponent>());
+            (info.name(), reflect_component)
+        })
+        .collect();
+    components.sort_by_cached_key(|(name, _)| ShortName(name).to_string());
+
+    let mut rows = Vec::new();
+    for (name, reflect_component) in components {
+        match reflect_component.and_then(|reflect_component| reflect_component.reflect(entity_ref))
+        {
+            Some(value) => push_fields(
+                &type_registry,
+                name,
+                value.as_partial_reflect(),
+                String::new(),
+                ShortName(name).to_string(),
+                0,
+                &mut rows,
+            ),
+            None => rows.push(FieldRow {
+                type_path: name.to_owned(),
+                path: String::new(),
+                depth: 0,
+                label: ShortName(name).to_string(),
+                value: Some("(not reflected)".to_owned()),
+                ron: None,
+            }),
+        }
+    }
+    Ok(rows)
+}
+
+/// Returns the short type paths and type paths of the reflected resources present in the
+/// world, sorted by short type path.
+pub fn reflected_resources(world: &World) -> Vec<(&'static str, &'static str)> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let mut resources: Vec<_> = type_registry
+        .iter_with_data::<ReflectResource>()
+        .filter(|(_, reflect_resource)| reflect_resource.reflect(world).is_some())
+        .map(|(registration, _)| {
+            let table = registration.type_info().type_path_table();
+            (table.short_path(), table.path())
+        })
+        .collect();
+    resources.sort_unstable();
+    resources
+}
+
+/// Returns the fields of the resource with the given type path.
+pub fn resource_fields(world: &World, type_path: &str) -> Result<Vec<FieldRow>, InspectorError> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let registration = type_registry
+        .get_with_type_path(type_path)
+        .ok_or_else(|| InspectorError::NotReflected(type_path.to_owned()))?;
+    let value = registration
+        .data::<ReflectResource>()
+        .ok_or_else(|| InspectorError::NotReflected(type_path.to_owned()))?
+        .reflect(world)
+        .ok_or_else(|| InspectorError::Missing(type_path.to_owned()))?;
+
+    let mut rows = Vec::new();
+    push_fields(
+        &type_registry,
+        registration.type_info().type_path(),
+        value.as_partial_reflect(),
+        String::new(),
+        registration
+            .type_info()
+            .type_path_table()
+            .short_path()
+            .to_owned(),
+        0,
+        &mut rows,
+    );
+    Ok(rows)
+}
+
+/// Pushes the row of `value`, followed by the rows of its fields if it has any.
+fn push_fields(
+    type_registry: &TypeRegistry,
+    type_path: &str,
+    value: &dyn PartialReflect,
+    path: String,
+    label: String,
+    depth: usize,
+    rows: &mut Vec<FieldRow>,
+) {
+    let fields: Vec<(String, String, &dyn PartialReflect)> = match value.reflect_ref() {
+        _ if depth >= MAX_FIELD_DEPTH => Vec::new(),
+        ReflectRef::Struct(value) => (0..value.field_len())
+            .filter_map(|i| {
+                let name = value.name_at(i)?;
+                Some((
+                    format!("{path}.{name}"),
+                    name.to_owned(),
+                    value.field_at(i)?,
+                ))
+            })
+            .collect(),
+        ReflectRef::TupleStruct(value) => value
+            .iter_fields()
+            .enumerate()
+            .map(|(i, field)| (format!("{path}.{i}"), i.to_string(), field))
+            .collect(),
+        ReflectRef::Tuple(value) => value
+            .iter_fields()
+            .enumerate()
+            .map(|(i, field)| (format!("{path}.{i}"), i.to_string(), field))
+            .collect(),
+        ReflectRef::List(value) => value
+            .iter()
+            .take(MAX_LIST_ITEMS)
+            .enumerate()
+            .map(|(i, item)| (format!("{path}[{i}]"), format!("[{i}]"), item))
+            .collect(),
+        ReflectRef::Array(value) => value
+            .iter()
+            .take(MAX_LIST_ITEMS)
+            .enumerate()
+            .map(|(i, item)| (format!("{path}[{i}]"), format!("[{i}]"), item))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    // Values without fields, such as enums and primitives, are displayed and edited as a whole.
+    let ron = value
+        .get_represented_type_info()
+        .and_then(|info| type_registry.get(info.type_id()))
+        .and_then(|_| ron::to_string(&TypedReflectSerializer::new(value, type_registry)).ok());
+    rows.push(FieldRow {
+        type_path: type_path.to_owned(),
+        path,
+        depth,
+        label,
+        value: fields.is_empty().then(|| truncate(format!("{value:?}"))),
+        ron: ron.filter(|_| fields.is_empty()),
+    });
+    for (path, label, field) in fields {
+        push_fields(
+            type_registry,
+            type_path,
+            field,
+            path,
+            label,
+            depth + 1,
+            rows,
+        );
+    }
+}
+
+fn truncate(mut value: String) -> String {
+    if let Some((index, _)) = value.char_indices().nth(MAX_VALUE_LENGTH) {
+        value.truncate(index);
+        value.push('…');
+    }
+    value
+}
+
+/// Sets the field at `path` of a component or resource to a value written in RON.
+///
+/// `type_path` is the type path of the component or resource, and `path` the
+/// [reflect path](bevy_reflect::ReflectPath) of the field, which can be empty to replace the
+/// whole value.
+pub fn set_field(
+    world: &mut World,
+    target: FieldTarget,
+    type_path: &str,
+    path: &str,
+    value: &str,
+) -> Result<(), InspectorError> {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let registration = type_registry
+        .get_with_type_path(type_path)
+        .ok_or_else(|| InspectorError::NotReflected(type_path.to_owned()))?;
+
+    match target {
+        FieldTarget::Component(entity) => {
+            let reflect_component = registration
+                .data::<ReflectComponent>()
+                .ok_or_else(|| InspectorError::NotReflected(type_path.to_owned()))?;
+            let mut entity = world
+                .get_entity_mut(entity)
+                .map_err(|_| InspectorError::NoSuchEntity(entity))?;
+            let mut component = reflect_component
+                .reflect_mut(&mut entity)
+                .ok_or_else(|| InspectorError::Missing(type_path.to_owned()))?;
+            apply_field(&type_registry, &mut *component, path, value)
+        }
+        FieldTarget::Resource => {
+            let mut resource = registration
+                .data::<ReflectResource>()
+                .ok_or_else(|| InspectorError::NotReflected(type_path.to_owned()))?
+                .reflect_mut(world)
+                .ok_or_else(|| InspectorError::Missing(type_path.to_owned()))?;
+            apply_field(&type_registry, &mut *resource, path, value)
+        }
+    }
+}
+
+fn apply_field(
+    type_registry: &TypeRegistry,
+    value: &mut dyn Reflect,
+    path: &str,
+    new_value: &str,
+) -> Result<(), InspectorError> {
+    let field = match path {
+        "" => value.as_partial_reflect_mut(),
+        path => value
+            .reflect_path_mut(path)
+            .map_err(|err| InspectorError::InvalidPath(err.to_string()))?,
+    };
+    let registration = field
+        .get_represented_type_info()
+        .and_then(|info| type_registry.get(info.type_id()))
+        .ok_or_else(|| InspectorError::NotReflected(field.reflect_type_path().to_owned()))?;
+    let mut deserializer = ron::Deserializer::from_str(new_value)
+        .map_err(|err| InspectorError::InvalidValue(err.to_string()))?;
+    let new_value = TypedReflectDeserializer::new(registration, type_registry)
+        .deserialize(&mut deserializer)
+        .map_err(|err| InspectorError::InvalidValue(err.to_string()))?;
+    field
+        .try_apply(new_value.as_ref())
+        .map_err(|err| InspectorError::InvalidValue(err.to_string()))
+}
+
+fn setup(mut commands: Commands, config: Res<InspectorConfig>) {
+    commands.spawn((
+        Node {
+            // We need to make sure the inspector doesn't affect the position of other UI nodes
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::Column,
+            top: Val::Px(0.0),
+            right: Val::Px(0.0),
+            min_width: Val::Px(320.0),
+            max_width: Val::Percent(50.0),
+            padding: UiRect::all(Val::Px(6.0)),
+            ..Default::default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        // Render the inspector on top of everything
+        GlobalZIndex(INSPECTOR_ZINDEX),
+        overlay::visibility(config.enabled),
+        InspectorRoot,
+        InspectorNode,
+    ));
+}
+
+fn handle_clicks(
+    query: Query<(&Interaction, &InspectorAction), Changed<Interaction>>,
+    mut inspector: ResMut<Inspector>,
+) {
+    for (interaction, action) in &query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        inspector.message = None;
+        match action.clone() {
+            InspectorAction::Tab(tab) => {
+                inspector.tab = tab;
+                inspector.focus = Focus::None;
+            }
+            InspectorAction::FocusSearch => inspector.focus = Focus::Search,
+            InspectorAction::SelectEntity(entity) => {
+                inspector.selected_entity = Some(entity);
+                inspector.focus = Focus::None;
+            }
+            InspectorAction::SelectResource(type_path) => {
+                inspector.selected_resource = Some(type_path);
+                inspector.focus = Focus::None;
+            }
+            InspectorAction::Edit(edit) => inspector.focus = Focus::Edit(edit),
+        }
+    }
+}
+
+fn handle_input(
+    mut events: EventReader<KeyboardInput>,
+    config: Res<InspectorConfig>,
+    mut inspector: ResMut<Inspector>,
+) {
+    for event in events.read() {
+        if event.state != ButtonState::Pressed || Some(event.key_code) == config.toggle_key {
+            continue;
+        }
+        let inspector = &mut *inspector;
+        let text = match &mut inspector.focus {
+            Focus::None => continue,
+            Focus::Search => &mut inspector.search,
+            Focus::Edit(edit) => &mut edit.value,
+        };
+        match &event.logical_key {
+            Key::Escape => inspector.focus = Focus::None,
+            Key::Enter => {
+                if let Focus::Edit(edit) = core::mem::take(&mut inspector.focus) {
+                    inspector.pending.push_back(edit);
+                }
+            }
+            Key::Backspace => {
+                text.pop();
+            }
+            _ => {
+                if let Some(input) = &event.text {
+                    text.extend(input.chars().filter(|c| !c.is_control()));
+                }
+            }
+        }
+    }
+}
+
+fn apply_edits(world: &mut World) {
+    while let Some(edit) = world
+        .resource_mut::<Inspector>()
+        .bypass_change_detection()
+        .pending
+        .pop_front()
+    {
+        let result = set_field(world, edit.target, &edit.type_path, &edit.path, &edit.value);
+        world.resource_mut::<Inspector>().message = result.err().map(|err| err.to_string());
+    }
+}
+
+/// Rebuilds the interface of the inspector, when its state changed or regularly to display
+/// up-to-date values.
+fn refresh(world: &mut World, mut last_refresh: Local<Option<Duration>>) {
+    let now = world.resource::<Time<Real>>().elapsed();
+    let config = world.resource::<InspectorConfig>().clone();
+    let changed = world.resource_ref::<Inspector>().is_changed();
+    if !changed
+        && last_refresh.is_some_and(|last_refresh| now - last_refresh < config.refresh_interval)
+    {
+        return;
+    }
+    *last_refresh = Some(now);
+
+    let inspector = world.resource::<Inspector>();
+    let mut rows = Vec::new();
+    let row = |text: String, color: Color, action: Option<InspectorAction>| (text, color, action);
+
+    // Tabs and search box.
+    for (tab, name) in [
+        (InspectorTab::Entities, "Entities"),
+        (InspectorTab::Resources, "Resources"),
+    ] {
+        let color = match inspector.tab == tab {
+            true => config.highlight_color,
+            false => config.text_color,
+        };
+        rows.push(row(
+            format!("[{name}]"),
+            color,
+            Some(InspectorAction::Tab(tab)),
+        ));
+    }
+    let (search_text, search_color) = match inspector.focus {
+        Focus::Search => (
+            format!("search: {}_", inspector.search),
+            config.highlight_color,
+        ),
+        _ if inspector.search.is_empty() => {
+            ("search: (click to type)".to_owned(), config.text_color)
+        }
+        _ => (format!("search: {}", inspector.search), config.text_color),
+    };
+    rows.push(row(
+        search_text,
+        search_color,
+        Some(InspectorAction::FocusSearch),
+    ));
+    if let Some(message) = &inspector.message {
+        rows.push(row(message.clone(), config.error_color, None));
+    }
+
+    // List of entities or resources, followed by the fields of the selected one.
+    let fields = match inspector.tab {
+        InspectorTab::Entities => {
+            let entities = entity_rows(world, &inspector.search);
+            push_limited(&mut rows, &config, entities.len(), |rows| {
+                for entity in entities.iter().take(config.max_rows) {
+                    let color = match inspector.selected_entity == Some(entity.entity) {
+                        true => config.highlight_color,
+                        false => config.text_color,
+                    };
+                    rows.push(row(
+                        format!("{}{}", "  ".repeat(entity.depth), entity.label),
+                        color,
+                        Some(InspectorAction::SelectEntity(entity.entity)),
+                    ));
+                }
+            });
+            inspector.selected_entity.map(|entity| {
+                (
+                    FieldTarget::Component(entity),
+                    component_fields(world, entity),
+                )
+            })
+        }
+        InspectorTab::Resources => {
+            let search = inspector.search.to_lowercase();
+            let resources: Vec<_> = reflected_resources(world)
+                .into_iter()
+                .filter(|(short_path, _)| short_path.to_lowercase().contains(&search))
+                .collect();
+            push_limited(&mut rows, &config, resources.len(), |rows| {
+                for (short_path, path) in resources.iter().take(config.max_rows) {
+                    let color = match inspector.selected_resource.as_deref() == Some(*path) {
+                        true => config.highlight_color,
+                        false => config.text_color,
+                    };
+                    rows.push(row(
+                        (*short_path).to_owned(),
+                        color,
+                        Some(InspectorAction::SelectResource((*path).to_owned())),
+                    ));
+                }
+            });
+            inspector
+                .selected_resource
+                .as_deref()
+                .map(|type_path| (FieldTarget::Resource, resource_fields(world, type_path)))
+        }
+    };
+
+    match fields {
+        None => {}
+        Some((_, Err(err))) => rows.push(row(err.to_string(), config.error_color, None)),
+        Some((target, Ok(fields))) => {
+            rows.push(row("────────".to_owned(), config.text_color, None));
+            push_limited(&mut rows, &config, fields.len(), |rows| {
+                for field in fields.iter().take(config.max_rows) {
+                    let indent = "  ".repeat(field.depth);
+                    let editing = match &inspector.focus {
+                        Focus::Edit(edit)
+                            if edit.target == target
+                                && edit.type_path == field.type_path
+                                && edit.path == field.path =>
+                        {
+                            Some(&edit.value)
+                        }
+                        _ => None,
+                    };
+                    let mut text = format!("{indent}{}", field.label);
+                    if let Some(value) = editing {
+                        let _ = write!(text, ": {value}_");
+                    } else if let Some(value) = &field.value {
+                        let _ = write!(text, ": {value}");
+                    }
+                    let action = field.ron.as_ref().map(|ron| {
+                        InspectorAction::Edit(FieldEdit {
+                            target,
+                            type_path: field.type_path.clone(),
+                            path: field.path.clone(),
+                            value: ron.clone(),
+                        })
+                    });
+                    let color = match editing {
+                        Some(_) => config.highlight_color,
+                        None => config.text_color,
+                    };
+                    rows.push(row(text, color, action));
+                }
+            });
+        }
+    }
+
+    let mut roots = world.query_filtered::<Entity, With<InspectorRoot>>();
+    let roots: Vec<Entity> = roots.iter(world).collect();
+    for root in roots {
+        world
+            .entity_mut(root)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for (text, color, action) in &rows {
+                    let mut row = parent.spawn((
+                        Text::new(text.clone()),
+                        config.text_config.clone(),
+                        TextColor(*color),
+                        InspectorNode,
+                    ));
+                    if let Some(action) = action {
+                        row.insert((Button, action.clone()));
+                    }
+                }
+            });
+    }
+}
+
+/// Pushes rows with `push`, followed by a row counting the rows left out.
+fn push_limited(
+    rows: &mut Vec<(String, Color, Option<InspectorAction>)>,
+    config: &InspectorConfig,
+    count: usize,
+    push: impl FnOnce(&mut Vec<(String, Color, Option<InspectorAction>)>),
+) {
+    push(rows);
+    if count > config.max_rows {
+        rows.push((
+            format!("… {} more", count - config.max_rows),
+            config.text_color,
+            None,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health {
+        current: f32,
+        max: f32,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    fn setup_world() -> (World, Entity, Entity) {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register::<Score>();
+        }
+        world.init_resource::<Score>();
+        let child = world.spawn(Name::new("Sword")).id();
+        let parent = world
+            .spawn((Name::new("Player"), Health::default()))
+            .add_child(child)
+            .id();
+        (world, parent, child)
+    }
+
+    #[test]
+    fn entities_follow_hierarchy() {
+        let (world, parent, child) = setup_world();
+        let rows = entity_rows(&world, "");
+        let parent_index = rows.iter().position(|row| row.entity == parent).unwrap();
+        assert_eq!(rows[parent_index + 1].entity, child);
+        assert_eq!(rows[parent_index + 1].depth, rows[parent_index].depth + 1);
+
+        let rows = entity_rows(&world, "health");
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].entity, parent);
+    }
+
+    #[test]
+    fn component_fields_are_edited() {
+        let (mut world, parent, _) = setup_world();
+        let fields = component_fields(&world, parent).unwrap();
+        let current = fields
+            .iter()
+            .find(|field| field.path == ".current")
+            .unwrap();
+        assert_eq!(current.ron.as_deref(), Some("0.0"));
+
+        set_field(
+            &mut world,
+            FieldTarget::Component(parent),
+            &current.type_path,
+            ".current",
+            "42.0",
+        )
+        .unwrap();
+        assert_eq!(world.get::<Health>(parent).unwrap().current, 42.0);
+        assert!(matches!(
+            set_field(
+                &mut world,
+                FieldTarget::Component(parent),
+                &current.type_path,
+                ".current",
+                "\"a string\"",
+            ),
+            Err(InspectorError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn resource_fields_are_edited() {
+        let (mut world, _, _) = setup_world();
+        let (_, type_path) = reflected_resources(&world)
+            .into_iter()
+            .find(|(short_path, _)| *short_path == "Score")
+            .unwrap();
+        set_field(&mut world, FieldTarget::Resource, type_path, ".0", "7").unwrap();
+        assert_eq!(world.resource::<Score>().0, 7);
+        let fields = resource_fields(&world, type_path).unwrap();
+        assert_eq!(fields[1].value.as_deref(), Some("7"));
+    }
+}
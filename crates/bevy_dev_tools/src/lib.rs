@@ -13,17 +13,40 @@
 //! This crate provides additional utilities for the [Bevy game engine](https://bevyengine.org),
 //! focused on improving developer experience.
 
+extern crate alloc;
+
 use bevy_app::prelude::*;
 
 #[cfg(feature = "bevy_ci_testing")]
 pub mod ci_testing;
 
+#[cfg(feature = "reflect_functions")]
+pub mod console;
+
+pub mod crash_report;
+
 pub mod fps_overlay;
 
+pub mod frame_time_graph;
+
+#[cfg(feature = "inspector")]
+pub mod inspector;
+
+pub mod log_panel;
+
+mod overlay;
+
 pub mod picking_debug;
 
+pub mod profiler;
+
 pub mod states;
 
+pub mod transform_diagnostics;
+
+#[cfg(feature = "visual_regression")]
+pub mod visual_regression;
+
 /// Enables developer tools in an [`App`]. This plugin is added automatically with `bevy_dev_tools`
 /// feature.
 ///
@@ -0,0 +1,507 @@
+//! Module containing logic for the on-screen log panel.
+//!
+//! The [`LogPanelPlugin`] displays the most recent log events in an overlay, toggled with `F7`
+//! by default, which is useful on devices without a terminal. Events are captured by the layer
+//! returned by [`log_capture_layer`], which must be given to the [`LogPlugin`]:
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_dev_tools::log_panel::{log_capture_layer, LogPanelPlugin};
+//! # use bevy_log::LogPlugin;
+//! App::new()
+//!     .add_plugins((
+//!         LogPlugin {
+//!             custom_layer: log_capture_layer,
+//!             ..Default::default()
+//!         },
+//!         LogPanelPlugin::default(),
+//!     ))
+//!     .run();
+//! ```
+//!
+//! Which events are displayed can be changed at runtime through [`LogPanelConfig`], with a
+//! minimum level per module. Events filtered out by the [`LogPlugin`] are never captured: its
+//! filter can be changed at runtime through the [`LogFilter`](bevy_log::LogFilter) resource.
+
+use alloc::{collections::VecDeque, sync::Arc};
+use core::fmt::{self, Write};
+use std::sync::Mutex;
+
+use bevy_app::{App, Plugin, Startup, Update};
+use bevy_asset::Handle;
+use bevy_color::Color;
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    query::With,
+    schedule::{common_conditions::resource_changed, IntoSystemConfigs},
+    system::{Commands, Local, Query, Res, Resource},
+};
+use bevy_hierarchy::{BuildChildren, ChildBuild, DespawnRecursiveExt};
+use bevy_input::keyboard::KeyCode;
+use bevy_log::{
+    tracing::{
+        field::{Field, Visit},
+        Event, Level, Subscriber,
+    },
+    tracing_subscriber::{layer::Context, Layer},
+    BoxedLayer,
+};
+use bevy_text::{Font, TextColor, TextFont, TextSpan};
+use bevy_ui::{widget::Text, BackgroundColor, GlobalZIndex, Node, PositionType, UiRect, Val};
+use bevy_utils::Instant;
+
+use crate::overlay::{self, OverlayConfig};
+
+/// [`GlobalZIndex`] used to render the log panel.
+///
+/// Three below [`PROFILER_ZINDEX`](crate::profiler::PROFILER_ZINDEX), so the developer console
+/// and the inspector are displayed above it.
+pub const LOG_PANEL_ZINDEX: i32 = crate::profiler::PROFILER_ZINDEX - 3;
+
+/// Returns a layer capturing log events into the [`LogBuffer`], to be used as the
+/// [`LogPlugin::custom_layer`](bevy_log::LogPlugin::custom_layer).
+///
+/// The [`LogBuffer`] resource is inserted into the app, keeping up to
+/// [`LogBuffer::DEFAULT_CAPACITY`] events.
+pub fn log_capture_layer(app: &mut App) -> Option<BoxedLayer> {
+    let buffer = LogBuffer::default();
+    app.insert_resource(buffer.clone());
+    Some(Box::new(LogCaptureLayer {
+        buffer,
+        start: Instant::now(),
+    }))
+}
+
+/// A log event captured by the [`LogBuffer`].
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    /// The level of the event.
+    pub level: Level,
+    /// The target of the event, which is its module path unless overridden.
+    pub target: String,
+    /// The message of the event, followed by its other fields.
+    pub message: String,
+    /// How long after the capture started the event happened, in seconds.
+    pub time: f64,
+}
+
+impl fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>9.3} {:>5} {}: {}",
+            self.time, self.level, self.target, self.message
+        )
+    }
+}
+
+/// A ring buffer of the most recent log events, filled by the layer returned by
+/// [`log_capture_layer`].
+#[derive(Resource, Clone)]
+pub struct LogBuffer(Arc<Mutex<LogBufferInner>>);
+
+struct LogBufferInner {
+    entries: VecDeque<LogEntry>,
+    capacity: usize,
+    /// Incremented with every captured event, so that readers can tell when the buffer changed.
+    generation: u64,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl LogBuffer {
+    /// How many events are kept by default.
+    pub const DEFAULT_CAPACITY: usize = 1000;
+
+    /// Creates a buffer keeping up to `capacity` events.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Arc::new(Mutex::new(LogBufferInner {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            generation: 0,
+        })))
+    }
+
+    /// Adds an event, dropping the oldest one if the buffer is full.
+    pub fn push(&self, entry: LogEntry) {
+        let mut inner = self.0.lock().unwrap();
+        if inner.capacity == 0 {
+            return;
+        }
+        if inner.entries.len() == inner.capacity {
+            inner.entries.pop_front();
+        }
+        inner.entries.push_back(entry);
+        inner.generation += 1;
+    }
+
+    /// Changes how many events are kept, dropping the oldest ones if needed.
+    pub fn set_capacity(&self, capacity: usize) {
+        let mut inner = self.0.lock().unwrap();
+        inner.capacity = capacity;
+        while inner.entries.len() > capacity {
+            inner.entries.pop_front();
+        }
+    }
+
+    /// Removes every event.
+    pub fn clear(&self) {
+        let mut inner = self.0.lock().unwrap();
+        inner.entries.clear();
+        inner.generation += 1;
+    }
+
+    /// Returns a copy of the captured events, oldest first.
+    pub fn entries(&self) -> Vec<LogEntry> {
+        self.0.lock().unwrap().entries.iter().cloned().collect()
+    }
+
+    /// Calls `f` with the captured events, oldest first, without copying them.
+    pub fn with_entries<R>(&self, f: impl FnOnce(&VecDeque<LogEntry>) -> R) -> R {
+        f(&self.0.lock().unwrap().entries)
+    }
+
+    fn generation(&self) -> u64 {
+        self.0.lock().unwrap().generation
+    }
+}
+
+/// The layer capturing events into a [`LogBuffer`].
+struct LogCaptureLayer {
+    buffer: LogBuffer,
+    start: Instant,
+}
+
+impl<S: Subscriber> Layer<S> for LogCaptureLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        self.buffer.push(LogEntry {
+            level: *metadata.level(),
+            target: metadata.target().to_owned(),
+            message: visitor.finish(),
+            time: self.start.elapsed().as_secs_f64(),
+        });
+    }
+}
+
+/// Formats the fields of an event, starting with its message.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(mut self) -> String {
+        // Events without a message only have fields.
+        self.message.push_str(self.fields.trim_start());
+        self.message
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "message" => {
+                let _ = write!(self.message, "{value:?}{}", self.fields);
+                self.fields.clear();
+            }
+            // Fields added by `tracing-log` for events of the `log` crate.
+            name if name.starts_with("log.") => {}
+            name if self.message.is_empty() => {
+                let _ = write!(self.fields, " {name}={value:?}");
+            }
+            name => {
+                let _ = write!(self.message, " {name}={value:?}");
+            }
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "message" => self.record_debug(field, &format_args!("{value}")),
+            _ => self.record_debug(field, &value),
+        }
+    }
+}
+
+/// A plugin that displays the most recent log events in an overlay.
+///
+/// This plugin only displays events captured by the layer returned by [`log_capture_layer`],
+/// see the [module-level documentation](self).
+#[derive(Default)]
+pub struct LogPanelPlugin {
+    /// Starting configuration of the overlay, this can later be changed through the [`LogPanelConfig`] resource.
+    pub config: LogPanelConfig,
+}
+
+impl Plugin for LogPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone())
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    overlay::toggle_with_key::<LogPanelConfig>,
+                    (
+                        customize_text,
+                        overlay::toggle_display::<LogPanelConfig, LogPanelText>,
+                    )
+                        .run_if(resource_changed::<LogPanelConfig>),
+                    update_text.run_if(|config: Res<LogPanelConfig>| config.enabled),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Configuration options for the log panel.
+#[derive(Resource, Clone)]
+pub struct LogPanelConfig {
+    /// Configuration of text in the overlay.
+    pub text_config: TextFont,
+    /// Color of `INFO` events, and of text in the overlay.
+    pub text_color: Color,
+    /// Color of `WARN` events.
+    pub warn_color: Color,
+    /// Color of `ERROR` events.
+    pub error_color: Color,
+    /// Color of `DEBUG` and `TRACE` events.
+    pub debug_color: Color,
+    /// How many of the most recent events are displayed.
+    pub max_lines: usize,
+    /// Least verbose level of the displayed events, for modules without their own level.
+    pub level: Level,
+    /// Least verbose level of the displayed events of some modules, overriding
+    /// [`LogPanelConfig::level`].
+    ///
+    /// Each entry applies to a target and the targets nested in it, such as `bevy_render` and
+    /// `bevy_render::renderer`. The longest matching target wins.
+    pub module_levels: Vec<(String, Level)>,
+    /// Key toggling [`LogPanelConfig::enabled`], if any.
+    pub toggle_key: Option<KeyCode>,
+    /// Displays the overlay if true.
+    ///
+    /// Events are captured even while the overlay is hidden.
+    pub enabled: bool,
+}
+
+impl Default for LogPanelConfig {
+    fn default() -> Self {
+        LogPanelConfig {
+            text_config: TextFont {
+                font: Handle::<Font>::default(),
+                font_size: 12.0,
+                ..Default::default()
+            },
+            text_color: Color::WHITE,
+            warn_color: Color::srgb(1.0, 0.8, 0.2),
+            error_color: Color::srgb(1.0, 0.3, 0.3),
+            debug_color: Color::srgb(0.6, 0.6, 0.6),
+            max_lines: 20,
+            level: Level::INFO,
+            module_levels: Vec::new(),
+            toggle_key: Some(KeyCode::F7),
+            enabled: false,
+        }
+    }
+}
+
+impl OverlayConfig for LogPanelConfig {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn toggle_key(&self) -> Option<KeyCode> {
+        self.toggle_key
+    }
+}
+
+impl LogPanelConfig {
+    /// Sets the least verbose level of the displayed events of `module` and its submodules.
+    pub fn set_module_level(&mut self, module: impl Into<String>, level: Level) {
+        let module = module.into();
+        match self.module_levels.iter_mut().find(|(m, _)| *m == module) {
+            Some((_, module_level)) => *module_level = level,
+            None => self.module_levels.push((module, level)),
+        }
+    }
+
+    /// Removes the level of `module`, which then uses the level of its parent module.
+    pub fn clear_module_level(&mut self, module: &str) {
+        self.module_levels.retain(|(m, _)| m != module);
+    }
+
+    /// Returns whether an event is displayed by the log panel.
+    pub fn displays(&self, entry: &LogEntry) -> bool {
+        let level = self
+            .module_levels
+            .iter()
+            .filter(|(module, _)| {
+                entry
+                    .target
+                    .strip_prefix(module.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(module, _)| module.len())
+            .map_or(self.level, |(_, level)| *level);
+        // More verbose levels compare as greater.
+        entry.level <= level
+    }
+
+    fn color(&self, level: Level) -> Color {
+        match level {
+            Level::ERROR => self.error_color,
+            Level::WARN => self.warn_color,
+            Level::INFO => self.text_color,
+            _ => self.debug_color,
+        }
+    }
+}
+
+#[derive(Component)]
+struct LogPanelText;
+
+fn setup(mut commands: Commands, config: Res<LogPanelConfig>) {
+    commands.spawn((
+        Text::default(),
+        config.text_config.clone(),
+        TextColor(config.text_color),
+        Node {
+            // We need to make sure the overlay doesn't affect the position of other UI nodes
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            bottom: Val::Px(0.0),
+            padding: UiRect::all(Val::Px(4.0)),
+            ..Default::default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        // Render overlay on top of everything
+        GlobalZIndex(LOG_PANEL_ZINDEX),
+        overlay::visibility(config.enabled),
+        LogPanelText,
+    ));
+}
+
+fn update_text(
+    mut commands: Commands,
+    config: Res<LogPanelConfig>,
+    buffer: Option<Res<LogBuffer>>,
+    mut last_generation: Local<Option<u64>>,
+    query: Query<Entity, With<LogPanelText>>,
+) {
+    let Some(buffer) = buffer else {
+        return;
+    };
+    let generation = buffer.generation();
+    if *last_generation == Some(generation) && !config.is_changed() {
+        return;
+    }
+    *last_generation = Some(generation);
+
+    // Each line is a text span, so that it can be colored by level.
+    let lines: Vec<(String, Color)> = buffer.with_entries(|entries| {
+        let mut lines: Vec<_> = entries
+            .iter()
+            .rev()
+            .filter(|entry| config.displays(entry))
+            .take(config.max_lines)
+            .map(|entry| (format!("{entry}\n"), config.color(entry.level)))
+            .collect();
+        lines.reverse();
+        lines
+    });
+
+    for entity in &query {
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for (line, color) in &lines {
+                    parent.spawn((
+                        TextSpan::new(line.clone()),
+                        config.text_config.clone(),
+                        TextColor(*color),
+                    ));
+                }
+            });
+    }
+}
+
+fn customize_text(
+    config: Res<LogPanelConfig>,
+    mut query: Query<(&mut TextFont, &mut TextColor), With<LogPanelText>>,
+) {
+    for (mut font, mut color) in &mut query {
+        *font = config.text_config.clone();
+        color.0 = config.text_color;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_log::tracing::{self, subscriber::with_default};
+    use bevy_log::tracing_subscriber::{layer::SubscriberExt, Registry};
+
+    fn entry(level: Level, target: &str) -> LogEntry {
+        LogEntry {
+            level,
+            target: target.to_owned(),
+            message: String::new(),
+            time: 0.0,
+        }
+    }
+
+    #[test]
+    fn buffer_keeps_most_recent_events() {
+        let buffer = LogBuffer::with_capacity(2);
+        let layer = LogCaptureLayer {
+            buffer: buffer.clone(),
+            start: Instant::now(),
+        };
+        with_default(Registry::default().with(layer), || {
+            tracing::info!("first");
+            tracing::warn!(count = 2, "second");
+            tracing::error!(target: "my_game::ai", "third");
+        });
+
+        let entries = buffer.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].level, Level::WARN);
+        assert_eq!(entries[0].message, "second count=2");
+        assert_eq!(entries[1].target, "my_game::ai");
+        assert_eq!(entries[1].message, "third");
+    }
+
+    #[test]
+    fn module_levels_override_default_level() {
+        let mut config = LogPanelConfig::default();
+        config.set_module_level("bevy_render", Level::WARN);
+        config.set_module_level("bevy_render::renderer", Level::DEBUG);
+
+        assert!(config.displays(&entry(Level::INFO, "my_game")));
+        assert!(!config.displays(&entry(Level::DEBUG, "my_game")));
+        assert!(!config.displays(&entry(Level::INFO, "bevy_render::view")));
+        assert!(config.displays(&entry(Level::WARN, "bevy_render")));
+        assert!(config.displays(&entry(Level::DEBUG, "bevy_render::renderer")));
+        // Only whole modules match.
+        assert!(config.displays(&entry(Level::INFO, "bevy_render_extra")));
+
+        config.clear_module_level("bevy_render");
+        assert!(config.displays(&entry(Level::INFO, "bevy_render::view")));
+    }
+}
@@ -0,0 +1,114 @@
+//! Helpers shared by the overlays of this crate, which are shown and hidden with a key.
+
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::Component,
+    query::With,
+    system::{Query, Res, ResMut, Resource},
+};
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_render::view::Visibility;
+
+/// The configuration of an overlay that can be toggled with a key.
+pub(crate) trait OverlayConfig: Resource {
+    /// Whether the overlay is shown.
+    fn enabled(&self) -> bool;
+
+    /// Shows or hides the overlay.
+    fn set_enabled(&mut self, enabled: bool);
+
+    /// The key toggling the overlay, if any.
+    fn toggle_key(&self) -> Option<KeyCode>;
+}
+
+/// The [`Visibility`] of an overlay that is `enabled` or not.
+pub(crate) fn visibility(enabled: bool) -> Visibility {
+    match enabled {
+        true => Visibility::Visible,
+        false => Visibility::Hidden,
+    }
+}
+
+/// Toggles the overlay when its [key](OverlayConfig::toggle_key) is pressed.
+pub(crate) fn toggle_with_key<C: OverlayConfig>(
+    input: Option<Res<ButtonInput<KeyCode>>>,
+    mut config: ResMut<C>,
+) {
+    let (Some(input), Some(key)) = (input, config.toggle_key()) else {
+        return;
+    };
+    if input.just_pressed(key) {
+        let enabled = config.enabled();
+        config.set_enabled(!enabled);
+    }
+}
+
+/// Shows or hides the root entities of the overlay, marked with `R`.
+pub(crate) fn toggle_display<C: OverlayConfig, R: Component>(
+    config: Res<C>,
+    mut query: Query<&mut Visibility, With<R>>,
+) {
+    let visibility = visibility(config.enabled());
+    for mut root_visibility in &mut query {
+        root_visibility.set_if_neq(visibility);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::{App, Update};
+    use bevy_ecs::schedule::IntoSystemConfigs;
+
+    #[derive(Resource)]
+    struct TestConfig {
+        enabled: bool,
+    }
+
+    impl OverlayConfig for TestConfig {
+        fn enabled(&self) -> bool {
+            self.enabled
+        }
+
+        fn set_enabled(&mut self, enabled: bool) {
+            self.enabled = enabled;
+        }
+
+        fn toggle_key(&self) -> Option<KeyCode> {
+            Some(KeyCode::F1)
+        }
+    }
+
+    #[derive(Component)]
+    struct TestRoot;
+
+    #[test]
+    fn toggle_overlay() {
+        let mut app = App::new();
+        app.insert_resource(TestConfig { enabled: false })
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(
+                Update,
+                (
+                    toggle_with_key::<TestConfig>,
+                    toggle_display::<TestConfig, TestRoot>,
+                )
+                    .chain(),
+            );
+        let root = app.world_mut().spawn((TestRoot, visibility(false))).id();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::F1);
+        app.update();
+        assert!(app.world().resource::<TestConfig>().enabled);
+        assert_eq!(app.world().get(root), Some(&Visibility::Visible));
+
+        // The key is still held, which doesn't toggle the overlay again.
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear();
+        app.update();
+        assert!(app.world().resource::<TestConfig>().enabled);
+    }
+}
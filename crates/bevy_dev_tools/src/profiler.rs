@@ -0,0 +1,334 @@
+//! Module containing logic for the in-app profiler.
+//!
+//! The [`ProfilerPlugin`] collects the CPU time spent in every system of the main and render
+//! worlds, and the CPU and GPU time spent in every render pass, into the [`FrameProfile`]
+//! resource once per frame. It can be inspected from code, or through a text overlay listing
+//! the most expensive entries.
+
+use alloc::{borrow::Cow, sync::Arc};
+use core::time::Duration;
+use std::sync::Mutex;
+
+use bevy_app::{App, First, Plugin, Startup, Update};
+use bevy_asset::Handle;
+use bevy_color::Color;
+use bevy_diagnostic::DiagnosticsStore;
+use bevy_ecs::{
+    component::Component,
+    query::With,
+    schedule::{
+        common_conditions::resource_changed, InternedScheduleLabel, IntoSystemConfigs,
+        SystemTimings,
+    },
+    system::{Commands, Query, Res, ResMut, Resource},
+};
+use bevy_input::keyboard::KeyCode;
+use bevy_render::{diagnostic::RenderDiagnosticsPlugin, Render, RenderApp, RenderSet};
+use bevy_text::{Font, TextColor, TextFont};
+use bevy_ui::{widget::Text, BackgroundColor, GlobalZIndex, Node, PositionType, UiRect, Val};
+use disqualified::ShortName;
+
+use crate::overlay::{self, OverlayConfig};
+
+/// [`GlobalZIndex`] used to render the profiler overlay.
+///
+/// One below [`FRAME_TIME_GRAPH_ZINDEX`](crate::frame_time_graph::FRAME_TIME_GRAPH_ZINDEX), so it can be used with the other overlays.
+pub const PROFILER_ZINDEX: i32 = crate::frame_time_graph::FRAME_TIME_GRAPH_ZINDEX - 1;
+
+/// A plugin that profiles the systems and render passes of the Bevy application.
+///
+/// This plugin will add the [`RenderDiagnosticsPlugin`] if it wasn't added before, to record
+/// the GPU time of render passes.
+///
+/// Note: GPU timings are only available on platforms supporting timestamp queries, see
+/// [`RenderDiagnosticsPlugin`] for details.
+#[derive(Default)]
+pub struct ProfilerPlugin {
+    /// Starting configuration of the overlay, this can later be changed through the [`ProfilerConfig`] resource.
+    pub config: ProfilerConfig,
+}
+
+impl Plugin for ProfilerPlugin {
+    fn build(&self, app: &mut App) {
+        // TODO: Use plugin dependencies, see https://github.com/bevyengine/bevy/issues/69
+        if !app.is_plugin_added::<RenderDiagnosticsPlugin>() {
+            app.add_plugins(RenderDiagnosticsPlugin);
+        }
+
+        let render_timings = RenderSystemTimings::default();
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app
+                .init_resource::<SystemTimings>()
+                .insert_resource(render_timings.clone())
+                .add_systems(Render, send_render_timings.in_set(RenderSet::PostCleanup));
+        }
+
+        app.insert_resource(self.config.clone())
+            .insert_resource(render_timings)
+            .init_resource::<SystemTimings>()
+            .init_resource::<FrameProfile>()
+            .add_systems(First, collect_profile)
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    overlay::toggle_with_key::<ProfilerConfig>,
+                    (
+                        customize_text,
+                        overlay::toggle_display::<ProfilerConfig, ProfilerText>,
+                    )
+                        .run_if(resource_changed::<ProfilerConfig>),
+                    update_text.run_if(|config: Res<ProfilerConfig>| config.enabled),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Configuration options for the profiler overlay.
+#[derive(Resource, Clone)]
+pub struct ProfilerConfig {
+    /// Configuration of text in the overlay.
+    pub text_config: TextFont,
+    /// Color of text in the overlay.
+    pub text_color: Color,
+    /// How many of the most expensive systems and render passes are listed.
+    pub max_entries: usize,
+    /// Key toggling [`ProfilerConfig::enabled`], if any.
+    pub toggle_key: Option<KeyCode>,
+    /// Displays the overlay if true.
+    ///
+    /// The [`FrameProfile`] is collected even while the overlay is hidden.
+    pub enabled: bool,
+}
+
+impl Default for ProfilerConfig {
+    fn default() -> Self {
+        ProfilerConfig {
+            text_config: TextFont {
+                font: Handle::<Font>::default(),
+                font_size: 12.0,
+                ..Default::default()
+            },
+            text_color: Color::WHITE,
+            max_entries: 12,
+            toggle_key: Some(KeyCode::F9),
+            enabled: false,
+        }
+    }
+}
+
+impl OverlayConfig for ProfilerConfig {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn toggle_key(&self) -> Option<KeyCode> {
+        self.toggle_key
+    }
+}
+
+/// The profile of the last complete frame, collected by the [`ProfilerPlugin`].
+#[derive(Resource, Default, Debug)]
+pub struct FrameProfile {
+    /// CPU time of every system that ran during the frame, slowest first.
+    pub systems: Vec<SystemProfile>,
+    /// Time of every render pass recorded by the [`RenderDiagnosticsPlugin`], slowest on the GPU first.
+    pub render_passes: Vec<RenderPassProfile>,
+}
+
+/// The CPU time spent in a system, in a [`FrameProfile`].
+#[derive(Debug, Clone)]
+pub struct SystemProfile {
+    /// The schedule the system belongs to.
+    pub schedule: InternedScheduleLabel,
+    /// The name of the system.
+    pub name: Cow<'static, str>,
+    /// Whether the system belongs to the render world.
+    pub render_world: bool,
+    /// How many times the system ran during the frame.
+    pub runs: u32,
+    /// The total CPU time spent running the system during the frame.
+    pub elapsed: Duration,
+}
+
+/// The time spent in a render pass, in a [`FrameProfile`].
+#[derive(Debug, Clone)]
+pub struct RenderPassProfile {
+    /// The path of the pass, e.g. `main_opaque_pass_3d`.
+    pub name: String,
+    /// CPU time spent encoding the pass.
+    pub cpu_time: Option<Duration>,
+    /// GPU time spent executing the pass, if timestamp queries are supported.
+    pub gpu_time: Option<Duration>,
+}
+
+/// System timings of the render world, waiting to be collected by the main world.
+#[derive(Resource, Clone, Default)]
+struct RenderSystemTimings(Arc<Mutex<Vec<SystemProfile>>>);
+
+fn system_profiles(
+    timings: &SystemTimings,
+    render_world: bool,
+) -> impl Iterator<Item = SystemProfile> + '_ {
+    timings.iter().flat_map(move |(schedule, timings)| {
+        timings
+            .systems
+            .iter()
+            .filter(|system| system.runs > 0)
+            .map(move |system| SystemProfile {
+                schedule,
+                name: system.name.clone(),
+                render_world,
+                runs: system.runs,
+                elapsed: system.elapsed,
+            })
+    })
+}
+
+fn send_render_timings(mut timings: ResMut<SystemTimings>, shared: Res<RenderSystemTimings>) {
+    let mut render_timings = shared.0.lock().unwrap();
+    render_timings.clear();
+    render_timings.extend(system_profiles(&timings, true));
+    timings.clear();
+}
+
+fn collect_profile(
+    mut timings: ResMut<SystemTimings>,
+    render_timings: Res<RenderSystemTimings>,
+    diagnostics: Res<DiagnosticsStore>,
+    mut profile: ResMut<FrameProfile>,
+) {
+    let profile = &mut *profile;
+
+    profile.systems.clear();
+    profile.systems.extend(system_profiles(&timings, false));
+    profile
+        .systems
+        .extend(render_timings.0.lock().unwrap().iter().cloned());
+    profile
+        .systems
+        .sort_by_key(|system| core::cmp::Reverse(system.elapsed));
+    timings.clear();
+
+    profile.render_passes.clear();
+    for diagnostic in diagnostics.iter() {
+        let Some((name, field)) = diagnostic
+            .path()
+            .as_str()
+            .strip_prefix("render/")
+            .and_then(|path| path.rsplit_once('/'))
+        else {
+            continue;
+        };
+        let is_gpu = match field {
+            "elapsed_gpu" => true,
+            "elapsed_cpu" => false,
+            _ => continue,
+        };
+        let time = diagnostic
+            .value()
+            .map(|ms| Duration::from_secs_f64(ms.max(0.0) / 1000.0));
+
+        let pass = match profile
+            .render_passes
+            .iter_mut()
+            .find(|pass| pass.name == name)
+        {
+            Some(pass) => pass,
+            None => {
+                profile.render_passes.push(RenderPassProfile {
+                    name: name.into(),
+                    cpu_time: None,
+                    gpu_time: None,
+                });
+                profile.render_passes.last_mut().unwrap()
+            }
+        };
+        if is_gpu {
+            pass.gpu_time = time;
+        } else {
+            pass.cpu_time = time;
+        }
+    }
+    profile.render_passes.sort_by(|a, b| {
+        b.gpu_time
+            .cmp(&a.gpu_time)
+            .then(b.cpu_time.cmp(&a.cpu_time))
+    });
+}
+
+#[derive(Component)]
+struct ProfilerText;
+
+fn setup(mut commands: Commands, config: Res<ProfilerConfig>) {
+    commands.spawn((
+        Text::default(),
+        config.text_config.clone(),
+        TextColor(config.text_color),
+        Node {
+            // We need to make sure the overlay doesn't affect the position of other UI nodes
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            bottom: Val::Px(0.0),
+            padding: UiRect::all(Val::Px(4.0)),
+            ..Default::default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        // Render overlay on top of everything
+        GlobalZIndex(PROFILER_ZINDEX),
+        overlay::visibility(config.enabled),
+        ProfilerText,
+    ));
+}
+
+fn update_text(
+    config: Res<ProfilerConfig>,
+    profile: Res<FrameProfile>,
+    mut query: Query<&mut Text, With<ProfilerText>>,
+) {
+    let ms = |duration: Duration| duration.as_secs_f64() * 1000.0;
+
+    let mut text = String::from("CPU (system)");
+    for system in profile.systems.iter().take(config.max_entries) {
+        text.push_str(&format!(
+            "\n{:>7.3} ms  {}{:?}::{}",
+            ms(system.elapsed),
+            if system.render_world { "render/" } else { "" },
+            system.schedule,
+            ShortName(&system.name),
+        ));
+        if system.runs > 1 {
+            text.push_str(&format!(" (x{})", system.runs));
+        }
+    }
+
+    text.push_str("\n\nGPU / CPU (render pass)");
+    for pass in profile.render_passes.iter().take(config.max_entries) {
+        let gpu = pass
+            .gpu_time
+            .map_or_else(|| "      -".into(), |time| format!("{:>7.3}", ms(time)));
+        let cpu = pass
+            .cpu_time
+            .map_or_else(|| "      -".into(), |time| format!("{:>7.3}", ms(time)));
+        text.push_str(&format!("\n{gpu} / {cpu} ms  {}", pass.name));
+    }
+
+    for mut text_node in &mut query {
+        text_node.0.clone_from(&text);
+    }
+}
+
+fn customize_text(
+    config: Res<ProfilerConfig>,
+    mut query: Query<(&mut TextFont, &mut TextColor), With<ProfilerText>>,
+) {
+    for (mut font, mut color) in &mut query {
+        *font = config.text_config.clone();
+        color.0 = config.text_color;
+    }
+}
@@ -0,0 +1,48 @@
+//! Module containing the transform propagation diagnostics.
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::{schedule::IntoSystemConfigs, system::Res};
+use bevy_transform::{systems::TransformPropagationStats, TransformSystem};
+
+/// Adds diagnostics about transform propagation to an App, from the
+/// [`TransformPropagationStats`] of every frame.
+///
+/// A static hierarchy isn't visited by the propagation, so these show how much of the hierarchies
+/// actually change every frame.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](bevy_diagnostic::LogDiagnosticsPlugin) to output diagnostics to the
+/// console.
+#[derive(Default)]
+pub struct TransformDiagnosticsPlugin;
+
+impl Plugin for TransformDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TransformPropagationStats>()
+            .register_diagnostic(Diagnostic::new(Self::DIRTY_ROOTS))
+            .register_diagnostic(Diagnostic::new(Self::PROPAGATED))
+            .register_diagnostic(Diagnostic::new(Self::VISITED))
+            .add_systems(
+                PostUpdate,
+                Self::diagnostic_system.after(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+impl TransformDiagnosticsPlugin {
+    /// The number of hierarchies with a changed transform.
+    pub const DIRTY_ROOTS: DiagnosticPath = DiagnosticPath::const_new("transform/dirty_roots");
+    /// The number of entities in a hierarchy whose global transform was updated.
+    pub const PROPAGATED: DiagnosticPath = DiagnosticPath::const_new("transform/propagated");
+    /// The number of entities in a hierarchy visited by the propagation.
+    pub const VISITED: DiagnosticPath = DiagnosticPath::const_new("transform/visited");
+
+    /// Records the [`TransformPropagationStats`] of the frame.
+    pub fn diagnostic_system(mut diagnostics: Diagnostics, stats: Res<TransformPropagationStats>) {
+        diagnostics.add_measurement(&Self::DIRTY_ROOTS, || stats.dirty_roots as f64);
+        diagnostics.add_measurement(&Self::PROPAGATED, || stats.propagated as f64);
+        diagnostics.add_measurement(&Self::VISITED, || stats.visited as f64);
+    }
+}
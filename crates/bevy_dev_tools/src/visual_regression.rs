@@ -0,0 +1,564 @@
+//! Utilities for screenshot-based visual regression testing.
+//!
+//! A visual regression test renders a camera to an image, reads it back to the CPU once its
+//! content has settled, and compares it against a reference image stored on disk:
+//!
+//! 1. Add the [`VisualRegressionPlugin`], and a camera rendering to an image created with
+//!    [`headless_render_target`].
+//! 2. Add a [`VisualRegressionTest`] component to the camera.
+//! 3. Call [`run_visual_regression_tests`] to update the app until every test has completed, and
+//!    check the returned [`VisualRegressionResult`]s.
+//!
+//! Images are compared in the Oklab color space, so that the tolerance matches the perceived
+//! difference between colors, see [`ImageTolerance`]. When a test fails, the captured image and
+//! an image highlighting the differences are written to [`VisualRegressionConfig::output_dir`].
+//! Missing references are written to [`VisualRegressionConfig::reference_dir`] when
+//! [`VisualRegressionConfig::update_references`] is enabled, which is the case when the
+//! `BEVY_UPDATE_REFERENCES` environment variable is set.
+//!
+//! ```no_run
+//! # use bevy_app::{App, Startup};
+//! # use bevy_asset::Assets;
+//! # use bevy_dev_tools::visual_regression::*;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_image::Image;
+//! # use bevy_render::camera::{Camera, RenderTarget};
+//! fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+//!     let target = headless_render_target(&mut images, 256, 256);
+//!     commands.spawn((
+//!         Camera {
+//!             target: RenderTarget::Image(target.into()),
+//!             ..Default::default()
+//!         },
+//!         VisualRegressionTest::new("my_scene"),
+//!     ));
+//! }
+//!
+//! let mut app = App::new();
+//! // Add the rendering plugins, with a headless configuration...
+//! app.add_plugins(VisualRegressionPlugin)
+//!     .add_systems(Startup, setup);
+//! for result in run_visual_regression_tests(&mut app, 100) {
+//!     assert!(result.passed(), "{result}");
+//! }
+//! ```
+
+use core::fmt;
+use std::path::{Path, PathBuf};
+
+use bevy_app::{App, Plugin, Update};
+use bevy_asset::{Assets, Handle, RenderAssetUsages};
+use bevy_color::{ColorToComponents, Oklaba, Srgba};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    observer::Trigger,
+    system::{Commands, Query, Res, ResMut, Resource},
+};
+use bevy_image::{Image, TextureFormatPixelInfo};
+use bevy_render::{
+    camera::Camera,
+    render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages},
+    view::screenshot::{Screenshot, ScreenshotCaptured},
+};
+use image::{Rgba, RgbaImage};
+use tracing::{info, warn};
+
+/// Environment variable enabling [`VisualRegressionConfig::update_references`] when set.
+pub const UPDATE_REFERENCES_ENV: &str = "BEVY_UPDATE_REFERENCES";
+
+/// A plugin capturing the cameras with a [`VisualRegressionTest`] and comparing them against
+/// their reference image.
+///
+/// See the [module-level documentation](self) for how to write a test.
+#[derive(Default)]
+pub struct VisualRegressionPlugin;
+
+impl Plugin for VisualRegressionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<VisualRegressionConfig>()
+            .init_resource::<VisualRegressionResults>()
+            .add_systems(Update, capture_tests);
+    }
+}
+
+/// Configuration of the [`VisualRegressionPlugin`].
+#[derive(Resource, Clone, Debug)]
+pub struct VisualRegressionConfig {
+    /// The directory containing the reference images, named `{name}.png`.
+    pub reference_dir: PathBuf,
+    /// The directory the captured images and diffs of failed tests are written to.
+    pub output_dir: PathBuf,
+    /// Overwrites the reference images with the captured images instead of comparing them.
+    ///
+    /// Defaults to `true` if the [`UPDATE_REFERENCES_ENV`] environment variable is set.
+    pub update_references: bool,
+}
+
+impl Default for VisualRegressionConfig {
+    fn default() -> Self {
+        Self {
+            reference_dir: PathBuf::from("tests/references"),
+            output_dir: PathBuf::from("target/visual_regression"),
+            update_references: std::env::var_os(UPDATE_REFERENCES_ENV).is_some(),
+        }
+    }
+}
+
+/// How much a captured image can differ from its reference.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ImageTolerance {
+    /// Largest difference between two pixels for them to be considered identical.
+    ///
+    /// This is the euclidean distance between the colors in the Oklab color space, with their
+    /// alpha as a fourth component. A difference of `0.02` is barely noticeable.
+    pub max_pixel_difference: f32,
+    /// Largest proportion of pixels, between `0.0` and `1.0`, that can differ for the images to be
+    /// considered identical.
+    pub max_mismatched_ratio: f32,
+}
+
+impl ImageTolerance {
+    /// No difference at all is accepted.
+    pub const EXACT: Self = Self {
+        max_pixel_difference: 0.0,
+        max_mismatched_ratio: 0.0,
+    };
+}
+
+impl Default for ImageTolerance {
+    fn default() -> Self {
+        Self {
+            max_pixel_difference: 0.02,
+            max_mismatched_ratio: 0.001,
+        }
+    }
+}
+
+/// Add this component to a [`Camera`] rendering to an image to capture it and compare it
+/// against the reference image named `{name}.png`.
+///
+/// The component is removed once the image has been captured.
+#[derive(Component, Clone, Debug)]
+pub struct VisualRegressionTest {
+    /// The name of the test, used to name its reference image and artifacts.
+    pub name: String,
+    /// How much the captured image can differ from its reference.
+    pub tolerance: ImageTolerance,
+    /// How many frames are rendered before capturing the image, leaving time for assets to
+    /// load and for temporal effects to settle.
+    pub warmup_frames: u32,
+}
+
+impl VisualRegressionTest {
+    /// Creates a test with the default tolerance, capturing the image after 10 frames.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            tolerance: ImageTolerance::default(),
+            warmup_frames: 10,
+        }
+    }
+
+    /// Sets how much the captured image can differ from its reference.
+    #[must_use]
+    pub fn with_tolerance(mut self, tolerance: ImageTolerance) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets how many frames are rendered before capturing the image.
+    #[must_use]
+    pub fn with_warmup_frames(mut self, warmup_frames: u32) -> Self {
+        self.warmup_frames = warmup_frames;
+        self
+    }
+}
+
+/// The results of the visual regression tests, in the order they completed.
+#[derive(Resource, Clone, Default, Debug)]
+pub struct VisualRegressionResults {
+    /// The results of the completed tests.
+    pub completed: Vec<VisualRegressionResult>,
+    /// How many tests are still waiting for their image to be captured.
+    pub pending: usize,
+}
+
+/// The result of a [`VisualRegressionTest`].
+#[derive(Clone, Debug)]
+pub struct VisualRegressionResult {
+    /// The name of the test.
+    pub name: String,
+    /// The outcome of the test.
+    pub outcome: VisualRegressionOutcome,
+}
+
+impl VisualRegressionResult {
+    /// Returns `true` if the captured image matched its reference, or if the reference was
+    /// updated.
+    pub fn passed(&self) -> bool {
+        matches!(
+            self.outcome,
+            VisualRegressionOutcome::Passed(_) | VisualRegressionOutcome::ReferenceUpdated
+        )
+    }
+}
+
+impl fmt::Display for VisualRegressionResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "visual regression test `{}` ", self.name)?;
+        match &self.outcome {
+            VisualRegressionOutcome::Passed(comparison) => write!(f, "passed ({comparison})"),
+            VisualRegressionOutcome::Failed(comparison) => write!(f, "failed ({comparison})"),
+            VisualRegressionOutcome::SizeMismatch { actual, reference } => write!(
+                f,
+                "failed: captured a {}x{} image, but the reference is {}x{}",
+                actual.0, actual.1, reference.0, reference.1
+            ),
+            VisualRegressionOutcome::MissingReference => write!(
+                f,
+                "failed: no reference image, set {UPDATE_REFERENCES_ENV} to create it"
+            ),
+            VisualRegressionOutcome::ReferenceUpdated => write!(f, "updated its reference"),
+            VisualRegressionOutcome::Error(error) => write!(f, "failed: {error}"),
+        }
+    }
+}
+
+/// The outcome of a [`VisualRegressionTest`].
+#[derive(Clone, Debug)]
+pub enum VisualRegressionOutcome {
+    /// The captured image matched the reference within the tolerance.
+    Passed(ImageComparison),
+    /// The captured image differed from the reference more than the tolerance allows.
+    Failed(ImageComparison),
+    /// The captured image and the reference have different sizes.
+    SizeMismatch {
+        /// The width and height of the captured image.
+        actual: (u32, u32),
+        /// The width and height of the reference image.
+        reference: (u32, u32),
+    },
+    /// There is no reference image for this test.
+    MissingReference,
+    /// The reference image was overwritten by the captured image.
+    ReferenceUpdated,
+    /// The image couldn't be captured, or an image couldn't be read or written.
+    Error(String),
+}
+
+/// The result of [`compare_images`].
+#[derive(Clone, Debug)]
+pub struct ImageComparison {
+    /// How many pixels differ more than [`ImageTolerance::max_pixel_difference`].
+    pub mismatched_pixels: u64,
+    /// How many pixels were compared.
+    pub total_pixels: u64,
+    /// The largest difference between two pixels.
+    pub max_difference: f32,
+    /// Whether the images are identical within the tolerance.
+    pub passed: bool,
+    /// An image highlighting the mismatched pixels in red, over a faded copy of the reference.
+    pub diff: RgbaImage,
+}
+
+impl fmt::Display for ImageComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} pixels differ, largest difference {:.4}",
+            self.mismatched_pixels, self.total_pixels, self.max_difference
+        )
+    }
+}
+
+/// Compares two images of the same size, returning `None` if their sizes differ.
+pub fn compare_images(
+    actual: &RgbaImage,
+    reference: &RgbaImage,
+    tolerance: ImageTolerance,
+) -> Option<ImageComparison> {
+    if actual.dimensions() != reference.dimensions() {
+        return None;
+    }
+
+    let oklab = |pixel: &Rgba<u8>| {
+        let [r, g, b, a] = pixel.0;
+        Oklaba::from(Srgba::rgba_u8(r, g, b, a))
+    };
+
+    let mut diff = RgbaImage::new(actual.width(), actual.height());
+    let mut mismatched_pixels = 0;
+    let mut max_difference = 0.0f32;
+    for ((actual, reference), diff) in actual
+        .pixels()
+        .zip(reference.pixels())
+        .zip(diff.pixels_mut())
+    {
+        let (a, b) = (oklab(actual), oklab(reference));
+        let difference = a.to_vec4().distance(b.to_vec4());
+        max_difference = max_difference.max(difference);
+
+        *diff = if difference > tolerance.max_pixel_difference {
+            mismatched_pixels += 1;
+            Rgba([255, 0, 0, 255])
+        } else {
+            // Fade the reference, so that the mismatched pixels stand out.
+            let luma = (b.lightness.clamp(0.0, 1.0) * 64.0) as u8;
+            Rgba([luma, luma, luma, 255])
+        };
+    }
+
+    let total_pixels = u64::from(actual.width()) * u64::from(actual.height());
+    let passed =
+        mismatched_pixels as f64 <= tolerance.max_mismatched_ratio as f64 * total_pixels as f64;
+    Some(ImageComparison {
+        mismatched_pixels,
+        total_pixels,
+        max_difference,
+        passed,
+        diff,
+    })
+}
+
+/// Creates an image that cameras can render to, and that can be captured by a
+/// [`VisualRegressionTest`].
+pub fn headless_render_target(
+    images: &mut Assets<Image>,
+    width: u32,
+    height: u32,
+) -> Handle<Image> {
+    let format = TextureFormat::Rgba8UnormSrgb;
+    let mut image = Image::new_fill(
+        Extent3d {
+            width,
+            height,
+            ..Default::default()
+        },
+        TextureDimension::D2,
+        &vec![0; format.pixel_size()],
+        format,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage |=
+        TextureUsages::COPY_SRC | TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING;
+    images.add(image)
+}
+
+/// Updates the app until every [`VisualRegressionTest`] has completed, or until `max_updates`
+/// updates have run, and returns the results of the completed tests.
+///
+/// Tests that didn't start capturing their image are reported as errors.
+pub fn run_visual_regression_tests(app: &mut App, max_updates: u32) -> Vec<VisualRegressionResult> {
+    let mut tests = app.world_mut().query::<&VisualRegressionTest>();
+    for _ in 0..max_updates {
+        app.update();
+        let world = app.world();
+        if world.resource::<VisualRegressionResults>().pending == 0
+            && tests.iter(world).next().is_none()
+        {
+            break;
+        }
+    }
+
+    let world = app.world_mut();
+    let mut results =
+        core::mem::take(&mut world.resource_mut::<VisualRegressionResults>().completed);
+    results.extend(tests.iter(world).map(|test| VisualRegressionResult {
+        name: test.name.clone(),
+        outcome: VisualRegressionOutcome::Error(format!(
+            "not captured after {max_updates} updates"
+        )),
+    }));
+    results
+}
+
+/// Counts down the warmup frames of the tests, and captures the images of the tests that are
+/// ready.
+fn capture_tests(
+    mut commands: Commands,
+    mut tests: Query<(Entity, &Camera, &mut VisualRegressionTest)>,
+    config: Res<VisualRegressionConfig>,
+    mut results: ResMut<VisualRegressionResults>,
+) {
+    for (entity, camera, mut test) in &mut tests {
+        if test.warmup_frames > 0 {
+            test.warmup_frames -= 1;
+            continue;
+        }
+        commands.entity(entity).remove::<VisualRegressionTest>();
+        results.pending += 1;
+
+        let test = test.clone();
+        let config = config.clone();
+        commands.spawn(Screenshot(camera.target.clone())).observe(
+            move |trigger: Trigger<ScreenshotCaptured>,
+                  mut results: ResMut<VisualRegressionResults>| {
+                let outcome = check_capture(&config, &test, trigger.event().0.clone());
+                let result = VisualRegressionResult {
+                    name: test.name.clone(),
+                    outcome,
+                };
+                match result.passed() {
+                    true => info!("{result}"),
+                    false => warn!("{result}"),
+                }
+                results.pending -= 1;
+                results.completed.push(result);
+            },
+        );
+    }
+}
+
+/// Compares a captured image against its reference, writing the artifacts of failed tests.
+fn check_capture(
+    config: &VisualRegressionConfig,
+    test: &VisualRegressionTest,
+    image: Image,
+) -> VisualRegressionOutcome {
+    let actual = match image.try_into_dynamic() {
+        Ok(image) => image.to_rgba8(),
+        Err(err) => return VisualRegressionOutcome::Error(err.to_string()),
+    };
+
+    let reference_path = config.reference_dir.join(format!("{}.png", test.name));
+    if config.update_references {
+        return match save(&actual, &reference_path) {
+            Ok(()) => VisualRegressionOutcome::ReferenceUpdated,
+            Err(err) => VisualRegressionOutcome::Error(err),
+        };
+    }
+
+    let actual_path = config.output_dir.join(format!("{}.actual.png", test.name));
+    let reference = match image::open(&reference_path) {
+        Ok(reference) => reference.to_rgba8(),
+        Err(image::ImageError::IoError(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+            return match save(&actual, &actual_path) {
+                Ok(()) => VisualRegressionOutcome::MissingReference,
+                Err(err) => VisualRegressionOutcome::Error(err),
+            };
+        }
+        Err(err) => {
+            return VisualRegressionOutcome::Error(format!(
+                "cannot read {}: {err}",
+                reference_path.display()
+            ))
+        }
+    };
+
+    let Some(comparison) = compare_images(&actual, &reference, test.tolerance) else {
+        let _ = save(&actual, &actual_path);
+        return VisualRegressionOutcome::SizeMismatch {
+            actual: actual.dimensions(),
+            reference: reference.dimensions(),
+        };
+    };
+    if comparison.passed {
+        return VisualRegressionOutcome::Passed(comparison);
+    }
+
+    let diff_path = config.output_dir.join(format!("{}.diff.png", test.name));
+    match save(&actual, &actual_path).and_then(|()| save(&comparison.diff, &diff_path)) {
+        Ok(()) => VisualRegressionOutcome::Failed(comparison),
+        Err(err) => VisualRegressionOutcome::Error(err),
+    }
+}
+
+fn save(image: &RgbaImage, path: &Path) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|err| format!("cannot create {}: {err}", dir.display()))?;
+    }
+    image
+        .save(path)
+        .map_err(|err| format!("cannot write {}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard(size: u32, dark: Rgba<u8>) -> RgbaImage {
+        RgbaImage::from_fn(size, size, |x, y| match (x + y) % 2 {
+            0 => Rgba([255, 255, 255, 255]),
+            _ => dark,
+        })
+    }
+
+    #[test]
+    fn comparison_tolerates_small_differences() {
+        let reference = checkerboard(8, Rgba([128, 128, 128, 255]));
+        let close = checkerboard(8, Rgba([130, 129, 128, 255]));
+        let far = checkerboard(8, Rgba([128, 0, 0, 255]));
+
+        let comparison = compare_images(&close, &reference, ImageTolerance::default()).unwrap();
+        assert!(comparison.passed);
+        assert_eq!(comparison.mismatched_pixels, 0);
+        assert!(comparison.max_difference > 0.0);
+
+        let comparison = compare_images(&close, &reference, ImageTolerance::EXACT).unwrap();
+        assert!(!comparison.passed);
+
+        let comparison = compare_images(&far, &reference, ImageTolerance::default()).unwrap();
+        assert!(!comparison.passed);
+        assert_eq!(comparison.mismatched_pixels, 32);
+        assert_eq!(comparison.diff.get_pixel(1, 0), &Rgba([255, 0, 0, 255]));
+        assert_ne!(comparison.diff.get_pixel(0, 0), &Rgba([255, 0, 0, 255]));
+
+        let comparison = compare_images(
+            &far,
+            &reference,
+            ImageTolerance {
+                max_mismatched_ratio: 0.5,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert!(comparison.passed);
+    }
+
+    #[test]
+    fn comparison_requires_same_size() {
+        let reference = checkerboard(8, Rgba([0, 0, 0, 255]));
+        let actual = checkerboard(4, Rgba([0, 0, 0, 255]));
+        assert!(compare_images(&actual, &reference, ImageTolerance::default()).is_none());
+    }
+
+    #[test]
+    fn missing_reference_writes_actual_image() {
+        let dir = std::env::temp_dir().join("bevy_visual_regression_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        let config = VisualRegressionConfig {
+            reference_dir: dir.join("references"),
+            output_dir: dir.join("output"),
+            update_references: false,
+        };
+        let test = VisualRegressionTest::new("missing");
+        let image = Image::new_fill(
+            Extent3d {
+                width: 2,
+                height: 2,
+                ..Default::default()
+            },
+            TextureDimension::D2,
+            &[255, 0, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+
+        let outcome = check_capture(&config, &test, image.clone());
+        assert!(matches!(outcome, VisualRegressionOutcome::MissingReference));
+        assert!(dir.join("output/missing.actual.png").exists());
+
+        let updating = VisualRegressionConfig {
+            update_references: true,
+            ..config.clone()
+        };
+        let outcome = check_capture(&updating, &test, image.clone());
+        assert!(matches!(outcome, VisualRegressionOutcome::ReferenceUpdated));
+
+        let outcome = check_capture(&config, &test, image);
+        assert!(matches!(outcome, VisualRegressionOutcome::Passed(_)));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
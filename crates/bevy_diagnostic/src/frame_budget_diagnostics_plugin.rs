@@ -0,0 +1,82 @@
+use alloc::format;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::FrameBudget;
+
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+
+/// Adds "used" and "deferred" diagnostics for the [`FrameBudget`] of a system set, which
+/// must be set up with [`FrameBudgetPlugin`](bevy_time::FrameBudgetPlugin).
+///
+/// The diagnostics record the time the set took each frame, in milliseconds, and the number of
+/// work items it deferred to later frames.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct FrameBudgetDiagnosticsPlugin<S>(pub S);
+
+impl<S: SystemSet> Plugin for FrameBudgetDiagnosticsPlugin<S> {
+    fn build(&self, app: &mut App) {
+        let used = Self::used(&self.0);
+        let deferred = Self::deferred(&self.0);
+        app.register_diagnostic(Diagnostic::new(used.clone()).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(deferred.clone()))
+            .add_systems(
+                Last,
+                move |mut diagnostics: Diagnostics, budget: Res<FrameBudget<S>>| {
+                    diagnostics
+                        .add_measurement(&used, || budget.last_used().as_secs_f64() * 1000.0);
+                    diagnostics.add_measurement(&deferred, || budget.last_deferred() as f64);
+                },
+            );
+    }
+}
+
+impl<S: SystemSet> FrameBudgetDiagnosticsPlugin<S> {
+    /// Prefix shared by the paths of every frame budget diagnostic.
+    pub const PREFIX: &'static str = "frame_budget";
+
+    /// Returns the [`DiagnosticPath`] the time used by `set` is recorded under.
+    pub fn used(set: &S) -> DiagnosticPath {
+        DiagnosticPath::from_components([Self::PREFIX, &format!("{set:?}"), "used"])
+    }
+
+    /// Returns the [`DiagnosticPath`] the work deferred by `set` is recorded under.
+    pub fn deferred(set: &S) -> DiagnosticPath {
+        DiagnosticPath::from_components([Self::PREFIX, &format!("{set:?}"), "deferred"])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DiagnosticsPlugin, DiagnosticsStore};
+    use bevy_time::FrameBudgetPlugin;
+    use core::time::Duration;
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Pathfinding;
+
+    #[test]
+    fn frame_budgets_are_measured() {
+        let mut app = App::new();
+        app.add_plugins((
+            DiagnosticsPlugin,
+            FrameBudgetPlugin::new(Update, Pathfinding, Duration::from_millis(1)),
+            FrameBudgetDiagnosticsPlugin(Pathfinding),
+        ))
+        .add_systems(
+            Update,
+            (|budget: Res<FrameBudget<Pathfinding>>| budget.defer(3)).in_set(Pathfinding),
+        );
+        app.update();
+
+        let path = FrameBudgetDiagnosticsPlugin::deferred(&Pathfinding);
+        assert_eq!(path.as_str(), "frame_budget/Pathfinding/deferred");
+        let store = app.world().resource::<DiagnosticsStore>();
+        assert_eq!(store.get_measurement(&path).unwrap().value, 3.0);
+        let used = FrameBudgetDiagnosticsPlugin::used(&Pathfinding);
+        assert!(store.get_measurement(&used).is_some());
+    }
+}
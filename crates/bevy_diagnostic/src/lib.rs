@@ -19,18 +19,34 @@ extern crate alloc;
 
 mod diagnostic;
 mod entity_count_diagnostics_plugin;
+mod frame_budget_diagnostics_plugin;
 mod frame_count_diagnostics_plugin;
 mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
+#[cfg(feature = "otlp_exporter")]
+mod otlp_exporter_plugin;
+#[cfg(feature = "prometheus_exporter")]
+mod prometheus_exporter_plugin;
+mod schedule_time_diagnostics_plugin;
 #[cfg(feature = "sysinfo_plugin")]
 mod system_information_diagnostics_plugin;
 
 pub use diagnostic::*;
 
 pub use entity_count_diagnostics_plugin::EntityCountDiagnosticsPlugin;
+pub use frame_budget_diagnostics_plugin::FrameBudgetDiagnosticsPlugin;
 pub use frame_count_diagnostics_plugin::{update_frame_count, FrameCount, FrameCountPlugin};
 pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::LogDiagnosticsPlugin;
+#[cfg(feature = "otlp_exporter")]
+pub use otlp_exporter_plugin::{OtlpExporter, OtlpExporterPlugin, DEFAULT_OTLP_PORT};
+#[cfg(feature = "prometheus_exporter")]
+pub use prometheus_exporter_plugin::{
+    PrometheusExporter, PrometheusExporterPlugin, DEFAULT_PROMETHEUS_PORT,
+};
+pub use schedule_time_diagnostics_plugin::{
+    ScheduleTimeDiagnostics, ScheduleTimeDiagnosticsPlugin,
+};
 #[cfg(feature = "sysinfo_plugin")]
 pub use system_information_diagnostics_plugin::{SystemInfo, SystemInformationDiagnosticsPlugin};
 
@@ -0,0 +1,360 @@
+use crate::{Diagnostic, DiagnosticPath, DiagnosticsStore};
+use alloc::{borrow::Cow, format, string::String, vec::Vec};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time, Timer, TimerMode};
+use core::{
+    fmt::Write as _,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    time::Duration,
+};
+use std::{
+    io::{self, Read, Write},
+    net::TcpStream,
+    sync::mpsc::{self, SyncSender, TrySendError},
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tracing::{error, warn};
+
+/// Default port of the [`OtlpExporterPlugin`], the one OpenTelemetry collectors receive OTLP/HTTP on.
+pub const DEFAULT_OTLP_PORT: u16 = 4318;
+
+/// Longest time a push may take, from connecting to the collector to receiving its answer.
+const PUSH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// An App Plugin that pushes diagnostics to an OpenTelemetry collector over
+/// [OTLP/HTTP](https://opentelemetry.io/docs/specs/otlp/#otlphttp), using its JSON encoding.
+///
+/// Every enabled [`Diagnostic`] is pushed as a gauge holding its smoothed value, named after its
+/// path with the configured [`namespace`](Self::namespace) prepended, e.g. `bevy.frame_time`.
+///
+/// Diagnostics are encoded in [`Last`] every [`interval`](Self::interval), and sent from a
+/// background thread so that a slow or unreachable collector never stalls the app. A push is
+/// skipped if the previous one is still in progress.
+///
+/// Only plain HTTP is supported, so the collector should be reachable on a trusted network,
+/// typically as an agent running next to the server.
+///
+/// # See also
+///
+/// [`PrometheusExporterPlugin`](crate::PrometheusExporterPlugin) to have diagnostics scraped instead.
+pub struct OtlpExporterPlugin {
+    /// Address of the collector receiving the metrics.
+    pub endpoint: SocketAddr,
+    /// Path of the HTTP request the metrics are pushed with.
+    pub path: Cow<'static, str>,
+    /// Time between two pushes.
+    pub interval: Duration,
+    /// The `service.name` resource attribute identifying the app.
+    pub service_name: Cow<'static, str>,
+    /// Prefix of every exported metric name.
+    pub namespace: Cow<'static, str>,
+    /// Attributes attached to every exported data point, e.g. the server's name or region.
+    pub labels: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    /// Diagnostics to export, or `None` to export every registered diagnostic.
+    pub filter: Option<Vec<DiagnosticPath>>,
+}
+
+impl Default for OtlpExporterPlugin {
+    fn default() -> Self {
+        OtlpExporterPlugin {
+            endpoint: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), DEFAULT_OTLP_PORT),
+            path: Cow::Borrowed("/v1/metrics"),
+            interval: Duration::from_secs(10),
+            service_name: Cow::Borrowed("bevy"),
+            namespace: Cow::Borrowed("bevy"),
+            labels: Vec::new(),
+            filter: None,
+        }
+    }
+}
+
+impl OtlpExporterPlugin {
+    /// Adds an attribute attached to every exported data point.
+    pub fn with_label(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.labels.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl Plugin for OtlpExporterPlugin {
+    fn build(&self, app: &mut App) {
+        // A single pending push, so that pushes are skipped rather than piling up when the
+        // collector is slow.
+        let (sender, receiver) = mpsc::sync_channel::<Vec<u8>>(1);
+        let endpoint = self.endpoint;
+        let request = format!(
+            "POST {} HTTP/1.1\r\n\
+            Host: {endpoint}\r\n\
+            Content-Type: application/json\r\n\
+            Connection: close\r\n",
+            self.path
+        );
+        let spawned = thread::Builder::new()
+            .name("OTLP exporter".into())
+            .spawn(move || {
+                // Ends once the exporter is dropped along with the app.
+                for body in receiver {
+                    if let Err(err) = push(endpoint, &request, &body) {
+                        warn!("Failed to push diagnostics to {endpoint}: {err}");
+                    }
+                }
+            });
+        if let Err(err) = spawned {
+            error!("Failed to start OTLP exporter: {err}");
+            return;
+        }
+
+        app.insert_resource(OtlpExporter {
+            timer: Timer::new(self.interval, TimerMode::Repeating),
+            sender,
+            service_name: self.service_name.clone(),
+            namespace: self.namespace.clone(),
+            labels: self.labels.clone(),
+            filter: self.filter.clone(),
+        })
+        .add_systems(Last, OtlpExporter::push_system);
+    }
+}
+
+/// State used by the [`OtlpExporterPlugin`].
+#[derive(Resource)]
+pub struct OtlpExporter {
+    timer: Timer,
+    sender: SyncSender<Vec<u8>>,
+    service_name: Cow<'static, str>,
+    namespace: Cow<'static, str>,
+    labels: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    filter: Option<Vec<DiagnosticPath>>,
+}
+
+impl OtlpExporter {
+    /// Encodes `diagnostics` measured at `time` as an OTLP `ExportMetricsServiceRequest`, in the
+    /// JSON encoding pushed to the collector.
+    pub fn encode(&self, diagnostics: &DiagnosticsStore, time: SystemTime) -> String {
+        let time = time
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_nanos() as u64);
+
+        let mut metrics = Vec::new();
+        match &self.filter {
+            Some(filter) => {
+                for diagnostic in filter.iter().filter_map(|path| diagnostics.get(path)) {
+                    metrics.extend(self.encode_diagnostic(diagnostic, time));
+                }
+            }
+            None => {
+                for diagnostic in diagnostics.iter() {
+                    metrics.extend(self.encode_diagnostic(diagnostic, time));
+                }
+            }
+        }
+
+        let mut body = String::from(r#"{"resourceMetrics":[{"resource":{"attributes":["#);
+        write_attribute(&mut body, "service.name", &self.service_name);
+        body.push_str(r#"]},"scopeMetrics":[{"scope":{"name":"bevy_diagnostic","version":"#);
+        write_string(&mut body, env!("CARGO_PKG_VERSION"));
+        body.push_str(r#"},"metrics":["#);
+        body.push_str(&metrics.join(","));
+        body.push_str("]}]}]}");
+        body
+    }
+
+    fn encode_diagnostic(&self, diagnostic: &Diagnostic, time: u64) -> Option<String> {
+        if !diagnostic.is_enabled {
+            return None;
+        }
+        // JSON has no representation for infinite and NaN values.
+        let value = diagnostic.smoothed().filter(|value| value.is_finite())?;
+
+        let name = [self.namespace.as_ref()]
+            .into_iter()
+            .chain(diagnostic.path().components())
+            .filter(|component| !component.is_empty())
+            .collect::<Vec<_>>()
+            .join(".");
+
+        let mut metric = String::from(r#"{"name":"#);
+        write_string(&mut metric, &name);
+        metric.push_str(r#","description":"#);
+        write_string(&mut metric, diagnostic.path().as_str());
+        metric.push_str(r#","unit":"#);
+        write_string(&mut metric, &diagnostic.suffix);
+        let _ = write!(
+            metric,
+            r#","gauge":{{"dataPoints":[{{"timeUnixNano":"{time}","asDouble":{value:?},"attributes":["#
+        );
+        for (i, (label, value)) in self.labels.iter().enumerate() {
+            if i > 0 {
+                metric.push(',');
+            }
+            write_attribute(&mut metric, label, value);
+        }
+        metric.push_str("]}]}}");
+        Some(metric)
+    }
+
+    fn push_system(
+        mut exporter: ResMut<OtlpExporter>,
+        diagnostics: Res<DiagnosticsStore>,
+        time: Res<Time<Real>>,
+    ) {
+        if !exporter.timer.tick(time.delta()).just_finished() {
+            return;
+        }
+        let body = exporter.encode(&diagnostics, SystemTime::now());
+        match exporter.sender.try_send(body.into_bytes()) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                warn!("Skipping diagnostics push, the previous one is still in progress");
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                warn!("Skipping diagnostics push, the OTLP exporter has stopped");
+            }
+        }
+    }
+}
+
+/// Posts `body` to the collector at `endpoint` with the `request` line and headers.
+fn push(endpoint: SocketAddr, request: &str, body: &[u8]) -> io::Result<()> {
+    let mut stream = TcpStream::connect_timeout(&endpoint, PUSH_TIMEOUT)?;
+    stream.set_write_timeout(Some(PUSH_TIMEOUT))?;
+    stream.set_read_timeout(Some(PUSH_TIMEOUT))?;
+    stream.write_all(request.as_bytes())?;
+    stream.write_all(format!("Content-Length: {}\r\n\r\n", body.len()).as_bytes())?;
+    stream.write_all(body)?;
+    stream.flush()?;
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response)?;
+    let status = response
+        .split(|&byte| byte == b'\r' || byte == b'\n')
+        .next()
+        .map(String::from_utf8_lossy)
+        .unwrap_or_default();
+    match status.split(' ').nth(1) {
+        Some(code) if code.starts_with('2') => Ok(()),
+        _ => Err(io::Error::other(format!("collector answered `{status}`"))),
+    }
+}
+
+/// Writes a `KeyValue` attribute with a string value.
+fn write_attribute(out: &mut String, key: &str, value: &str) {
+    out.push_str(r#"{"key":"#);
+    write_string(out, key);
+    out.push_str(r#","value":{"stringValue":"#);
+    write_string(out, value);
+    out.push_str("}}");
+}
+
+/// Writes `value` as a JSON string.
+fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DiagnosticMeasurement, DiagnosticsPlugin};
+    use bevy_time::TimePlugin;
+    use bevy_utils::Instant;
+    use std::net::TcpListener;
+
+    fn store() -> DiagnosticsStore {
+        let path = DiagnosticPath::const_new("render/frame-time");
+        let mut diagnostic = Diagnostic::new(path).with_suffix("ms");
+        diagnostic.add_measurement(DiagnosticMeasurement {
+            time: Instant::now(),
+            value: 2.5,
+        });
+        let mut store = DiagnosticsStore::default();
+        store.add(diagnostic);
+        store
+    }
+
+    #[test]
+    fn diagnostics_are_encoded() {
+        let mut app = App::new();
+        app.add_plugins((
+            DiagnosticsPlugin,
+            OtlpExporterPlugin::default().with_label("server", "eu \"1\""),
+        ));
+
+        let exporter = app.world().resource::<OtlpExporter>();
+        let time = UNIX_EPOCH + Duration::from_secs(2);
+        assert_eq!(
+            exporter.encode(&store(), time),
+            format!(
+                "{{\"resourceMetrics\":[{{\"resource\":{{\"attributes\":[\
+                {{\"key\":\"service.name\",\"value\":{{\"stringValue\":\"bevy\"}}}}]}},\
+                \"scopeMetrics\":[{{\"scope\":{{\"name\":\"bevy_diagnostic\",\"version\":\"{}\"}},\
+                \"metrics\":[{{\"name\":\"bevy.render.frame-time\",\"description\":\"render/frame-time\",\
+                \"unit\":\"ms\",\"gauge\":{{\"dataPoints\":[{{\"timeUnixNano\":\"2000000000\",\
+                \"asDouble\":2.5,\"attributes\":[\
+                {{\"key\":\"server\",\"value\":{{\"stringValue\":\"eu \\\"1\\\"\"}}}}]}}]}}}}]}}]}}]}}",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+    }
+
+    #[test]
+    fn diagnostics_are_pushed() {
+        let collector = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            DiagnosticsPlugin,
+            OtlpExporterPlugin {
+                endpoint: collector.local_addr().unwrap(),
+                interval: Duration::from_millis(1),
+                ..Default::default()
+            },
+        ));
+        *app.world_mut().resource_mut::<DiagnosticsStore>() = store();
+
+        let received = thread::spawn(move || {
+            let (mut stream, _) = collector.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            // The body ends the JSON document, which is all on one line.
+            while !request.ends_with(b"]}]}]}") {
+                let read = stream.read(&mut buffer).unwrap();
+                assert!(read > 0);
+                request.extend_from_slice(&buffer[..read]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        let start = Instant::now();
+        while !received.is_finished() {
+            assert!(start.elapsed() < Duration::from_secs(30));
+            app.update();
+            thread::sleep(Duration::from_millis(1));
+        }
+        let request = received.join().unwrap();
+        assert!(request.starts_with("POST /v1/metrics HTTP/1.1\r\n"));
+        assert!(request.contains("\"name\":\"bevy.render.frame-time\""));
+    }
+}
@@ -0,0 +1,428 @@
+use crate::{Diagnostic, DiagnosticPath, DiagnosticsStore};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_utils::Instant;
+use core::{
+    fmt::Write as _,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    time::Duration,
+};
+use std::{
+    io::{ErrorKind, Read, Write},
+    net::{TcpListener, TcpStream},
+};
+use tracing::{error, warn};
+
+/// Default port of the [`PrometheusExporterPlugin`], the one reserved for OpenTelemetry's
+/// Prometheus exporter.
+pub const DEFAULT_PROMETHEUS_PORT: u16 = 9464;
+
+/// Longest time a scrape connection is kept open, waiting for its request or for its response to
+/// be sent, before it is dropped.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Largest scrape request accepted, headers included.
+const MAX_REQUEST_LENGTH: usize = 8 * 1024;
+
+/// An App Plugin that serves diagnostics over HTTP in the
+/// [Prometheus text exposition format](https://prometheus.io/docs/instrumenting/exposition_formats/).
+///
+/// Every enabled [`Diagnostic`] is exposed as a gauge holding its smoothed value, named after its
+/// path with the configured [`namespace`](Self::namespace) prepended, e.g. `bevy_frame_time`.
+/// Any request made to [`address`](Self::address) is answered, whatever its path, so both
+/// Prometheus and OpenTelemetry collectors using a Prometheus receiver can scrape it.
+///
+/// Connections are polled without blocking once per frame in [`Last`], so scrapes are answered
+/// with at most one frame of latency and never stall the app. Responses that don't fit in the
+/// socket's send buffer at once are written over the following frames.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct PrometheusExporterPlugin {
+    /// Address the scrape endpoint listens on.
+    pub address: SocketAddr,
+    /// Prefix of every exported metric name.
+    pub namespace: Cow<'static, str>,
+    /// Labels attached to every exported metric, e.g. the server's name or region.
+    pub labels: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    /// Diagnostics to export, or `None` to export every registered diagnostic.
+    pub filter: Option<Vec<DiagnosticPath>>,
+}
+
+impl Default for PrometheusExporterPlugin {
+    fn default() -> Self {
+        PrometheusExporterPlugin {
+            address: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), DEFAULT_PROMETHEUS_PORT),
+            namespace: Cow::Borrowed("bevy"),
+            labels: Vec::new(),
+            filter: None,
+        }
+    }
+}
+
+impl PrometheusExporterPlugin {
+    /// Adds a label attached to every exported metric.
+    pub fn with_label(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.labels.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl Plugin for PrometheusExporterPlugin {
+    fn build(&self, app: &mut App) {
+        let listener = match TcpListener::bind(self.address) {
+            Ok(listener) => listener,
+            Err(err) => {
+                error!(
+                    "Failed to bind Prometheus exporter to {}: {err}",
+                    self.address
+                );
+                return;
+            }
+        };
+        if let Err(err) = listener.set_nonblocking(true) {
+            error!("Failed to make Prometheus exporter non-blocking: {err}");
+            return;
+        }
+
+        app.insert_resource(PrometheusExporter {
+            listener,
+            connections: Vec::new(),
+            namespace: self.namespace.clone(),
+            labels: self.labels.clone(),
+            filter: self.filter.clone(),
+        })
+        .add_systems(Last, PrometheusExporter::serve_system);
+    }
+}
+
+/// State used by the [`PrometheusExporterPlugin`].
+#[derive(Resource)]
+pub struct PrometheusExporter {
+    listener: TcpListener,
+    connections: Vec<Scrape>,
+    namespace: Cow<'static, str>,
+    labels: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    filter: Option<Vec<DiagnosticPath>>,
+}
+
+/// A scrape connection, kept until its response has been fully written.
+struct Scrape {
+    stream: TcpStream,
+    state: ScrapeState,
+    accepted: Instant,
+}
+
+enum ScrapeState {
+    /// The request has not been fully received yet.
+    Reading(Vec<u8>),
+    /// The response is being sent, of which `written` bytes have been written already.
+    Writing { response: Vec<u8>, written: usize },
+}
+
+impl PrometheusExporter {
+    /// Returns the address the scrape endpoint listens on.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.listener.local_addr().ok()
+    }
+
+    /// Encodes `diagnostics` in the Prometheus text exposition format, as served by the endpoint.
+    pub fn encode(&self, diagnostics: &DiagnosticsStore) -> String {
+        let mut body = String::new();
+        match &self.filter {
+            Some(filter) => {
+                for diagnostic in filter.iter().filter_map(|path| diagnostics.get(path)) {
+                    self.encode_diagnostic(&mut body, diagnostic);
+                }
+            }
+            None => {
+                for diagnostic in diagnostics.iter() {
+                    self.encode_diagnostic(&mut body, diagnostic);
+                }
+            }
+        }
+        body
+    }
+
+    fn encode_diagnostic(&self, body: &mut String, diagnostic: &Diagnostic) {
+        if !diagnostic.is_enabled {
+            return;
+        }
+        let Some(value) = diagnostic.smoothed() else {
+            return;
+        };
+
+        let mut name = String::new();
+        for component in [self.namespace.as_ref()]
+            .into_iter()
+            .chain(diagnostic.path().components())
+            .filter(|component| !component.is_empty())
+        {
+            if !name.is_empty() {
+                name.push('_');
+            }
+            name.extend(component.chars().map(|c| match c {
+                'a'..='z' | 'A'..='Z' | '0'..='9' | '_' => c,
+                _ => '_',
+            }));
+        }
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            name.insert(0, '_');
+        }
+
+        let path = diagnostic.path();
+        let _ = match diagnostic.suffix.as_ref() {
+            "" => writeln!(body, "# HELP {name} {path}"),
+            suffix => writeln!(body, "# HELP {name} {path} ({suffix})"),
+        };
+        let _ = writeln!(body, "# TYPE {name} gauge");
+
+        body.push_str(&name);
+        for (i, (label, value)) in self.labels.iter().enumerate() {
+            body.push(if i == 0 { '{' } else { ',' });
+            body.push_str(label);
+            body.push_str("=\"");
+            for c in value.chars() {
+                match c {
+                    '\\' => body.push_str("\\\\"),
+                    '"' => body.push_str("\\\""),
+                    '\n' => body.push_str("\\n"),
+                    c => body.push(c),
+                }
+            }
+            body.push('"');
+        }
+        if !self.labels.is_empty() {
+            body.push('}');
+        }
+        let _ = writeln!(body, " {value}");
+    }
+
+    fn serve_system(mut exporter: ResMut<PrometheusExporter>, diagnostics: Res<DiagnosticsStore>) {
+        let exporter = &mut *exporter;
+
+        loop {
+            match exporter.listener.accept() {
+                Ok((stream, _)) => {
+                    if let Err(err) = stream.set_nonblocking(true) {
+                        warn!("Dropping Prometheus scrape connection: {err}");
+                        continue;
+                    }
+                    exporter.connections.push(Scrape {
+                        stream,
+                        state: ScrapeState::Reading(Vec::new()),
+                        accepted: Instant::now(),
+                    });
+                }
+                Err(err) if err.kind() == ErrorKind::WouldBlock => break,
+                Err(err) => {
+                    warn!("Failed to accept Prometheus scrape connection: {err}");
+                    break;
+                }
+            }
+        }
+
+        if exporter.connections.is_empty() {
+            return;
+        }
+
+        let mut response = None;
+        let mut connections = core::mem::take(&mut exporter.connections);
+        connections.retain_mut(|connection| {
+            let done = connection.poll(|| {
+                response
+                    .get_or_insert_with(|| {
+                        let body = exporter.encode(&diagnostics);
+                        let mut response = String::with_capacity(body.len() + 128);
+                        let _ = write!(
+                            response,
+                            "HTTP/1.1 200 OK\r\n\
+                            Content-Type: text/plain; version=0.0.4; charset=utf-8\r\n\
+                            Content-Length: {}\r\n\
+                            Connection: close\r\n\r\n{body}",
+                            body.len()
+                        );
+                        response.into_bytes()
+                    })
+                    .clone()
+            });
+            !done && connection.accepted.elapsed() < CONNECTION_TIMEOUT
+        });
+        exporter.connections = connections;
+    }
+}
+
+impl Scrape {
+    /// Reads the request and then writes the response built by `response` without blocking,
+    /// returning `true` once the connection is done with.
+    fn poll(&mut self, response: impl FnOnce() -> Vec<u8>) -> bool {
+        if let ScrapeState::Reading(request) = &mut self.state {
+            if !read_request(&mut self.stream, request) {
+                return false;
+            }
+            self.state = ScrapeState::Writing {
+                response: response(),
+                written: 0,
+            };
+        }
+        match &mut self.state {
+            ScrapeState::Reading(_) => false,
+            ScrapeState::Writing { response, written } => {
+                write_response(&mut self.stream, response, written)
+            }
+        }
+    }
+}
+
+/// Reads what has been received of a request so far, returning `true` once its headers are
+/// complete or the connection can't be read from anymore.
+fn read_request(stream: &mut TcpStream, request: &mut Vec<u8>) -> bool {
+    let mut buffer = [0; 1024];
+    loop {
+        match stream.read(&mut buffer) {
+            Ok(0) => return true,
+            Ok(read) => {
+                request.extend_from_slice(&buffer[..read]);
+                if request.windows(4).any(|window| window == b"\r\n\r\n")
+                    || request.len() >= MAX_REQUEST_LENGTH
+                {
+                    return true;
+                }
+            }
+            Err(err) if err.kind() == ErrorKind::WouldBlock => return false,
+            Err(err) if err.kind() == ErrorKind::Interrupted => {}
+            Err(_) => return true,
+        }
+    }
+}
+
+/// Writes as much of `response` as the connection accepts without blocking, returning `true`
+/// once it has been fully written or the connection can't be written to anymore.
+fn write_response(stream: &mut TcpStream, response: &[u8], written: &mut usize) -> bool {
+    while *written < response.len() {
+        match stream.write(&response[*written..]) {
+            Ok(0) => {
+                warn!("Failed to answer Prometheus scrape: connection closed");
+                return true;
+            }
+            Ok(count) => *written += count,
+            Err(err) if err.kind() == ErrorKind::WouldBlock => return false,
+            Err(err) if err.kind() == ErrorKind::Interrupted => {}
+            Err(err) => {
+                warn!("Failed to answer Prometheus scrape: {err}");
+                return true;
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DiagnosticMeasurement, DiagnosticsPlugin};
+
+    #[test]
+    fn diagnostics_are_encoded() {
+        let mut app = App::new();
+        app.add_plugins((
+            DiagnosticsPlugin,
+            PrometheusExporterPlugin {
+                address: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                ..Default::default()
+            }
+            .with_label("server", "eu \"1\""),
+        ));
+
+        let path = DiagnosticPath::const_new("render/frame-time");
+        let mut diagnostic = Diagnostic::new(path).with_suffix("ms");
+        diagnostic.add_measurement(DiagnosticMeasurement {
+            time: Instant::now(),
+            value: 2.5,
+        });
+        let mut store = DiagnosticsStore::default();
+        store.add(diagnostic);
+
+        let exporter = app.world().resource::<PrometheusExporter>();
+        assert_eq!(
+            exporter.encode(&store),
+            "# HELP bevy_render_frame_time render/frame-time (ms)\n\
+            # TYPE bevy_render_frame_time gauge\n\
+            bevy_render_frame_time{server=\"eu \\\"1\\\"\"} 2.5\n"
+        );
+    }
+
+    #[test]
+    fn scrapes_are_answered() {
+        let mut app = App::new();
+        app.add_plugins((
+            DiagnosticsPlugin,
+            PrometheusExporterPlugin {
+                address: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                ..Default::default()
+            },
+        ));
+        let mut diagnostic = Diagnostic::new(DiagnosticPath::const_new("test/value"));
+        diagnostic.add_measurement(DiagnosticMeasurement {
+            time: Instant::now(),
+            value: 1.0,
+        });
+        app.world_mut()
+            .resource_mut::<DiagnosticsStore>()
+            .add(diagnostic);
+
+        let address = app
+            .world()
+            .resource::<PrometheusExporter>()
+            .local_addr()
+            .unwrap();
+        let client = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(address).unwrap();
+            stream.write_all(b"GET /metrics HTTP/1.1\r\n\r\n").unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        });
+
+        let start = Instant::now();
+        while !client.is_finished() {
+            assert!(start.elapsed() < Duration::from_secs(30));
+            app.update();
+            std::thread::yield_now();
+        }
+        let response = client.join().unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.ends_with("bevy_test_value 1\n"));
+    }
+
+    #[test]
+    fn responses_are_written_without_blocking() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (mut stream, _) = listener.accept().unwrap();
+        stream.set_nonblocking(true).unwrap();
+
+        // Much larger than the socket's buffers, which the client doesn't read from yet.
+        let response: Vec<u8> = (0..64 * 1024 * 1024).map(|i| i as u8).collect();
+        let mut written = 0;
+        assert!(!write_response(&mut stream, &response, &mut written));
+        assert!(written < response.len());
+
+        let reader = std::thread::spawn(move || {
+            let mut received = Vec::new();
+            client.read_to_end(&mut received).unwrap();
+            received
+        });
+        while !write_response(&mut stream, &response, &mut written) {
+            std::thread::yield_now();
+        }
+        drop(stream);
+        assert!(reader.join().unwrap() == response);
+    }
+}
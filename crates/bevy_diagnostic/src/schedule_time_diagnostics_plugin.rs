@@ -0,0 +1,120 @@
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use alloc::vec::Vec;
+use bevy_app::{prelude::*, MainScheduleOrder};
+use bevy_ecs::{
+    prelude::*,
+    schedule::{ExecutorKind, ScheduleLabel},
+};
+use bevy_utils::Instant;
+
+/// Adds a "schedule time" diagnostic to an App for every schedule run by the [`Main`] schedule,
+/// measuring the wall-clock CPU time spent in each of them.
+///
+/// Measurements are taken by small marker schedules inserted between the labels of
+/// [`MainScheduleOrder`] when the plugin is finished, so schedules added to the order after
+/// that point are attributed to the schedule they follow.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct ScheduleTimeDiagnosticsPlugin;
+
+impl Plugin for ScheduleTimeDiagnosticsPlugin {
+    fn build(&self, _app: &mut App) {}
+
+    fn finish(&self, app: &mut App) {
+        let labels = app.world().resource::<MainScheduleOrder>().labels.clone();
+
+        let paths: Vec<DiagnosticPath> = labels.iter().copied().map(Self::path).collect();
+        for path in &paths {
+            app.register_diagnostic(Diagnostic::new(path.clone()).with_suffix("ms"));
+        }
+
+        let mut timed_labels = Vec::with_capacity(labels.len() * 2 + 1);
+        for index in 0..=labels.len() {
+            let mut schedule = Schedule::new(ScheduleTimingMark(index));
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+            schedule.add_systems(mark_system(index));
+            app.add_schedule(schedule);
+
+            timed_labels.push(ScheduleTimingMark(index).intern());
+            timed_labels.extend(labels.get(index).copied());
+        }
+
+        app.world_mut().resource_mut::<MainScheduleOrder>().labels = timed_labels;
+        app.insert_resource(ScheduleTimeDiagnostics {
+            paths,
+            last_mark: None,
+        });
+    }
+}
+
+impl ScheduleTimeDiagnosticsPlugin {
+    /// Prefix shared by the paths of every schedule time diagnostic.
+    pub const PREFIX: &'static str = "schedule_time";
+
+    /// Returns the [`DiagnosticPath`] the time spent in `label` is recorded under.
+    pub fn path(label: impl ScheduleLabel) -> DiagnosticPath {
+        DiagnosticPath::from_components([Self::PREFIX, &alloc::format!("{label:?}")])
+    }
+}
+
+/// Lists the schedule time diagnostics registered by [`ScheduleTimeDiagnosticsPlugin`].
+#[derive(Resource, Debug)]
+pub struct ScheduleTimeDiagnostics {
+    paths: Vec<DiagnosticPath>,
+    last_mark: Option<Instant>,
+}
+
+impl ScheduleTimeDiagnostics {
+    /// Returns the diagnostic paths of the measured schedules, in the order they run.
+    pub fn paths(&self) -> &[DiagnosticPath] {
+        &self.paths
+    }
+}
+
+/// Schedule run before the measured schedule at the same index of [`MainScheduleOrder`].
+#[derive(ScheduleLabel, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct ScheduleTimingMark(usize);
+
+fn mark_system(
+    index: usize,
+) -> impl FnMut(Diagnostics, ResMut<ScheduleTimeDiagnostics>) + Send + Sync + 'static {
+    move |mut diagnostics, mut timings| {
+        let now = Instant::now();
+        if let (Some(last_mark), Some(path)) = (
+            timings.last_mark,
+            index.checked_sub(1).and_then(|i| timings.paths.get(i)),
+        ) {
+            diagnostics.add_measurement(path, || {
+                now.duration_since(last_mark).as_secs_f64() * 1000.0
+            });
+        }
+        // The first mark starts a new frame, so time spent outside of `Main` is never measured.
+        timings.last_mark = (index < timings.paths.len()).then_some(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DiagnosticsPlugin, DiagnosticsStore};
+
+    #[test]
+    fn schedule_times_are_measured() {
+        let mut app = App::new();
+        app.add_plugins((DiagnosticsPlugin, ScheduleTimeDiagnosticsPlugin));
+        app.finish();
+        app.update();
+
+        let store = app.world().resource::<DiagnosticsStore>();
+        for path in app.world().resource::<ScheduleTimeDiagnostics>().paths() {
+            let diagnostic = store.get(path).unwrap();
+            assert_eq!(1, diagnostic.history_len());
+        }
+        assert!(store
+            .get(&ScheduleTimeDiagnosticsPlugin::path(Update))
+            .is_some());
+    }
+}
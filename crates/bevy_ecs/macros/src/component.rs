@@ -9,7 +9,7 @@ use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
     token::{Comma, Paren},
-    DeriveInput, ExprClosure, ExprPath, Ident, LitStr, Path, Result,
+    Data, DeriveInput, ExprClosure, ExprPath, Fields, Ident, LitStr, Member, Path, Result, Type,
 };
 
 pub fn derive_event(input: TokenStream) -> TokenStream {
@@ -21,13 +21,31 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
         .predicates
         .push(parse_quote! { Self: Send + Sync + 'static });
 
+    let mut auto_propagate = false;
+    let mut traversal: Type = parse_quote!(());
+    for attr in ast.attrs.iter().filter(|attr| attr.path().is_ident(EVENT)) {
+        if let Err(e) = attr.parse_nested_meta(|nested| {
+            if nested.path.is_ident(AUTO_PROPAGATE) {
+                auto_propagate = true;
+                Ok(())
+            } else if nested.path.is_ident(TRAVERSAL) {
+                traversal = nested.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(nested.error("Unsupported attribute"))
+            }
+        }) {
+            return e.into_compile_error().into();
+        }
+    }
+
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
     TokenStream::from(quote! {
         impl #impl_generics #bevy_ecs_path::event::Event for #struct_name #type_generics #where_clause {
-            type Traversal = ();
-            const AUTO_PROPAGATE: bool = false;
+            type Traversal = #traversal;
+            const AUTO_PROPAGATE: bool = #auto_propagate;
         }
 
         impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
@@ -64,12 +82,49 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         Err(e) => return e.into_compile_error().into(),
     };
 
+    let relationship = match derive_relationship(&ast, &attrs, &bevy_ecs_path) {
+        Ok(relationship) => relationship,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    let relationship_target = match derive_relationship_target(&ast, &attrs, &bevy_ecs_path) {
+        Ok(relationship_target) => relationship_target,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
+    let tracked_fields = match derive_tracked_fields(&ast, &attrs, &bevy_ecs_path) {
+        Ok(tracked_fields) => tracked_fields,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
     let storage = storage_path(&bevy_ecs_path, attrs.storage);
 
+    let field_ticks = tracked_fields.is_some().then(|| {
+        quote! {
+            const FIELD_TICKS: Option<fn(&mut Self) -> &mut #bevy_ecs_path::change_detection::FieldTicks> =
+                Some(<Self as #bevy_ecs_path::change_detection::TrackedFields>::field_ticks_mut);
+        }
+    });
+
+    let mut on_insert = attrs.on_insert;
+    let mut on_replace = attrs.on_replace;
+    if relationship.is_some() {
+        on_insert =
+            Some(parse_quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_insert));
+        on_replace =
+            Some(parse_quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_replace));
+    }
+    if relationship_target.is_some() {
+        on_replace = Some(
+            parse_quote!(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace),
+        );
+    }
+
     let on_add = hook_register_function_call(quote! {on_add}, attrs.on_add);
-    let on_insert = hook_register_function_call(quote! {on_insert}, attrs.on_insert);
-    let on_replace = hook_register_function_call(quote! {on_replace}, attrs.on_replace);
+    let on_insert = hook_register_function_call(quote! {on_insert}, on_insert);
+    let on_replace = hook_register_function_call(quote! {on_replace}, on_replace);
     let on_remove = hook_register_function_call(quote! {on_remove}, attrs.on_remove);
+    let on_archetype_move =
+        hook_register_function_call(quote! {on_archetype_move}, attrs.on_archetype_move);
 
     ast.generics
         .make_where_clause()
@@ -132,17 +187,31 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
-    let mutable_type = attrs
-        .immutable
+    let mutable_type = (attrs.immutable || relationship.is_some())
         .then_some(quote! { #bevy_ecs_path::component::Immutable })
         .unwrap_or(quote! { #bevy_ecs_path::component::Mutable });
 
+    // The sources of a relationship target shouldn't be copied to clones of the target,
+    // as they don't point to them
+    let clone_handler = if relationship_target.is_some() {
+        quote! {
+            #bevy_ecs_path::component::ComponentCloneHandler::ignore()
+        }
+    } else {
+        quote! {
+            use #bevy_ecs_path::component::{ComponentCloneViaClone, ComponentCloneBase};
+            (&&&#bevy_ecs_path::component::ComponentCloneSpecializationWrapper::<Self>::default())
+                .get_component_clone_handler()
+        }
+    };
+
     // This puts `register_required` before `register_recursive_requires` to ensure that the constructors of _all_ top
     // level components are initialized first, giving them precedence over recursively defined constructors for the same component type
     TokenStream::from(quote! {
         impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
             const STORAGE_TYPE: #bevy_ecs_path::component::StorageType = #storage;
             type Mutability = #mutable_type;
+            #field_ticks
             fn register_required_components(
                 requiree: #bevy_ecs_path::component::ComponentId,
                 components: &mut #bevy_ecs_path::component::Components,
@@ -165,17 +234,245 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
                 #on_insert
                 #on_replace
                 #on_remove
+                #on_archetype_move
             }
 
             fn get_component_clone_handler() -> #bevy_ecs_path::component::ComponentCloneHandler {
-                use #bevy_ecs_path::component::{ComponentCloneViaClone, ComponentCloneBase};
-                (&&&#bevy_ecs_path::component::ComponentCloneSpecializationWrapper::<Self>::default())
-                    .get_component_clone_handler()
+                #clone_handler
             }
         }
+
+        #relationship
+
+        #relationship_target
+
+        #tracked_fields
     })
 }
 
+/// Returns the member of the single field of a relationship component.
+fn relationship_field(ast: &DeriveInput, attribute: &str) -> Result<Member> {
+    let fields = match &ast.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new(
+                ast.span(),
+                format!("`#[{attribute}]` can only be used on structs"),
+            ))
+        }
+    };
+
+    match fields {
+        Fields::Named(fields) if fields.named.len() == 1 => {
+            Ok(Member::Named(fields.named[0].ident.clone().unwrap()))
+        }
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Ok(Member::Unnamed(0.into())),
+        _ => Err(syn::Error::new(
+            fields.span(),
+            format!("`#[{attribute}]` can only be used on structs with a single field"),
+        )),
+    }
+}
+
+/// Returns the constructor of a relationship component from the value of its single field.
+fn relationship_constructor(member: &Member, value: &Ident) -> TokenStream2 {
+    match member {
+        Member::Named(ident) => quote! { Self { #ident: #value } },
+        Member::Unnamed(_) => quote! { Self(#value) },
+    }
+}
+
+fn derive_tracked_fields(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    if !attrs.track_fields {
+        return Ok(None);
+    }
+    if attrs.immutable {
+        return Err(syn::Error::new(
+            ast.span(),
+            "immutable components cannot track changes of their fields",
+        ));
+    }
+    let fields = match &ast.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            fields => {
+                return Err(syn::Error::new(
+                    fields.span(),
+                    format!(
+                    "`#[component({TRACK_FIELDS})]` can only be used on structs with named fields"
+                ),
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                ast.span(),
+                format!("`#[component({TRACK_FIELDS})]` can only be used on structs"),
+            ))
+        }
+    };
+
+    // The field storing the ticks is found by its type, every other field is tracked.
+    let is_field_ticks = |ty: &Type| matches!(ty, Type::Path(path) if path.path.segments.last().is_some_and(|segment| segment.ident == "FieldTicks"));
+    let mut ticks_fields = fields.iter().filter(|field| is_field_ticks(&field.ty));
+    let (Some(ticks_field), None) = (ticks_fields.next(), ticks_fields.next()) else {
+        return Err(syn::Error::new(
+            ast.span(),
+            format!(
+                "`#[component({TRACK_FIELDS})]` requires exactly one field of type `FieldTicks`"
+            ),
+        ));
+    };
+    let ticks_ident = &ticks_field.ident;
+
+    let tracked: Vec<_> = fields
+        .iter()
+        .filter(|field| !is_field_ticks(&field.ty))
+        .filter_map(|field| field.ident.as_ref())
+        .collect();
+    let names: Vec<_> = tracked
+        .iter()
+        .map(|ident| ident.to_string().trim_start_matches("r#").to_owned())
+        .collect();
+    let constants = tracked
+        .iter()
+        .zip(&names)
+        .enumerate()
+        .map(|(index, (ident, name))| {
+            let constant = Ident::new(&format!("{}_FIELD", name.to_uppercase()), ident.span());
+            let doc =
+                format!("The index of the `{name}` field, used for per-field change detection.");
+            quote! {
+                #[doc = #doc]
+                pub const #constant: usize = #index;
+            }
+        });
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::change_detection::TrackedFields for #struct_name #type_generics #where_clause {
+            const FIELDS: &'static [&'static str] = &[#(#names),*];
+
+            #[inline]
+            fn field_ticks(&self) -> &#bevy_ecs_path::change_detection::FieldTicks {
+                &self.#ticks_ident
+            }
+
+            #[inline]
+            fn field_ticks_mut(&mut self) -> &mut #bevy_ecs_path::change_detection::FieldTicks {
+                &mut self.#ticks_ident
+            }
+        }
+
+        impl #impl_generics #struct_name #type_generics #where_clause {
+            #(#constants)*
+        }
+    }))
+}
+
+fn derive_relationship(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship) = &attrs.relationship else {
+        return Ok(None);
+    };
+    if attrs.on_insert.is_some() || attrs.on_replace.is_some() {
+        return Err(syn::Error::new(
+            ast.span(),
+            "custom `on_insert` and `on_replace` hooks are not supported on relationships",
+        ));
+    }
+
+    let member = relationship_field(ast, RELATIONSHIP)?;
+    let entity = Ident::new("entity", Span::call_site());
+    let constructor = relationship_constructor(&member, &entity);
+    let relationship_target = &relationship.relationship_target;
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::Relationship for #struct_name #type_generics #where_clause {
+            type RelationshipTarget = #relationship_target;
+
+            #[inline(always)]
+            fn get(&self) -> #bevy_ecs_path::entity::Entity {
+                self.#member
+            }
+
+            #[inline]
+            fn from(#entity: #bevy_ecs_path::entity::Entity) -> Self {
+                #constructor
+            }
+        }
+    }))
+}
+
+fn derive_relationship_target(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship_target) = &attrs.relationship_target else {
+        return Ok(None);
+    };
+    if attrs.relationship.is_some() {
+        return Err(syn::Error::new(
+            ast.span(),
+            "a component cannot be both a relationship and a relationship target",
+        ));
+    }
+    if attrs.on_replace.is_some() {
+        return Err(syn::Error::new(
+            ast.span(),
+            "custom `on_replace` hooks are not supported on relationship targets",
+        ));
+    }
+    if attrs.immutable {
+        return Err(syn::Error::new(
+            ast.span(),
+            "relationship targets cannot be immutable",
+        ));
+    }
+
+    let member = relationship_field(ast, RELATIONSHIP_TARGET)?;
+    let collection = Ident::new("collection", Span::call_site());
+    let constructor = relationship_constructor(&member, &collection);
+    let relationship = &relationship_target.relationship;
+    let linked_spawn = relationship_target.linked_spawn;
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::RelationshipTarget for #struct_name #type_generics #where_clause {
+            type Relationship = #relationship;
+            const LINKED_SPAWN: bool = #linked_spawn;
+
+            #[inline]
+            fn collection(&self) -> &#bevy_ecs_path::__macro_exports::Vec<#bevy_ecs_path::entity::Entity> {
+                &self.#member
+            }
+
+            #[inline]
+            fn collection_mut_risky(&mut self) -> &mut #bevy_ecs_path::__macro_exports::Vec<#bevy_ecs_path::entity::Entity> {
+                &mut self.#member
+            }
+
+            #[inline]
+            fn from_collection_risky(#collection: #bevy_ecs_path::__macro_exports::Vec<#bevy_ecs_path::entity::Entity>) -> Self {
+                #constructor
+            }
+        }
+    }))
+}
+
 pub fn document_required_components(attr: TokenStream, item: TokenStream) -> TokenStream {
     let paths = parse_macro_input!(attr with Punctuated::<Require, Comma>::parse_terminated)
         .iter()
@@ -204,6 +501,10 @@ pub fn document_required_components(attr: TokenStream, item: TokenStream) -> Tok
     out
 }
 
+pub const EVENT: &str = "event";
+pub const AUTO_PROPAGATE: &str = "auto_propagate";
+pub const TRAVERSAL: &str = "traversal";
+
 pub const COMPONENT: &str = "component";
 pub const STORAGE: &str = "storage";
 pub const REQUIRE: &str = "require";
@@ -212,8 +513,23 @@ pub const ON_ADD: &str = "on_add";
 pub const ON_INSERT: &str = "on_insert";
 pub const ON_REPLACE: &str = "on_replace";
 pub const ON_REMOVE: &str = "on_remove";
+pub const ON_ARCHETYPE_MOVE: &str = "on_archetype_move";
 
 pub const IMMUTABLE: &str = "immutable";
+pub const TRACK_FIELDS: &str = "track_fields";
+
+pub const RELATIONSHIP: &str = "relationship";
+pub const RELATIONSHIP_TARGET: &str = "relationship_target";
+pub const LINKED_SPAWN: &str = "linked_spawn";
+
+struct Relationship {
+    relationship_target: Type,
+}
+
+struct RelationshipTarget {
+    relationship: Type,
+    linked_spawn: bool,
+}
 
 struct Attrs {
     storage: StorageTy,
@@ -222,7 +538,11 @@ struct Attrs {
     on_insert: Option<ExprPath>,
     on_replace: Option<ExprPath>,
     on_remove: Option<ExprPath>,
+    on_archetype_move: Option<ExprPath>,
     immutable: bool,
+    track_fields: bool,
+    relationship: Option<Relationship>,
+    relationship_target: Option<RelationshipTarget>,
 }
 
 #[derive(Clone, Copy)]
@@ -252,8 +572,12 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
         on_insert: None,
         on_replace: None,
         on_remove: None,
+        on_archetype_move: None,
         requires: None,
         immutable: false,
+        track_fields: false,
+        relationship: None,
+        relationship_target: None,
     };
 
     let mut require_paths = HashSet::new();
@@ -283,9 +607,15 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
                 } else if nested.path.is_ident(ON_REMOVE) {
                     attrs.on_remove = Some(nested.value()?.parse::<ExprPath>()?);
                     Ok(())
+                } else if nested.path.is_ident(ON_ARCHETYPE_MOVE) {
+                    attrs.on_archetype_move = Some(nested.value()?.parse::<ExprPath>()?);
+                    Ok(())
                 } else if nested.path.is_ident(IMMUTABLE) {
                     attrs.immutable = true;
                     Ok(())
+                } else if nested.path.is_ident(TRACK_FIELDS) {
+                    attrs.track_fields = true;
+                    Ok(())
                 } else {
                     Err(nested.error("Unsupported attribute"))
                 }
@@ -306,6 +636,49 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
             } else {
                 attrs.requires = Some(punctuated);
             }
+        } else if attr.path().is_ident(RELATIONSHIP) {
+            let mut relationship_target = None;
+            attr.parse_nested_meta(|nested| {
+                if nested.path.is_ident(RELATIONSHIP_TARGET) {
+                    relationship_target = Some(nested.value()?.parse::<Type>()?);
+                    Ok(())
+                } else {
+                    Err(nested.error("Unsupported attribute"))
+                }
+            })?;
+            let relationship_target = relationship_target.ok_or_else(|| {
+                syn::Error::new(
+                    attr.span(),
+                    "expected `#[relationship(relationship_target = T)]`",
+                )
+            })?;
+            attrs.relationship = Some(Relationship {
+                relationship_target,
+            });
+        } else if attr.path().is_ident(RELATIONSHIP_TARGET) {
+            let mut relationship = None;
+            let mut linked_spawn = false;
+            attr.parse_nested_meta(|nested| {
+                if nested.path.is_ident(RELATIONSHIP) {
+                    relationship = Some(nested.value()?.parse::<Type>()?);
+                    Ok(())
+                } else if nested.path.is_ident(LINKED_SPAWN) {
+                    linked_spawn = true;
+                    Ok(())
+                } else {
+                    Err(nested.error("Unsupported attribute"))
+                }
+            })?;
+            let relationship = relationship.ok_or_else(|| {
+                syn::Error::new(
+                    attr.span(),
+                    "expected `#[relationship_target(relationship = R)]`",
+                )
+            })?;
+            attrs.relationship_target = Some(RelationshipTarget {
+                relationship,
+                linked_spawn,
+            });
         }
     }
 
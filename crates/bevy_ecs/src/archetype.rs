@@ -26,9 +26,10 @@ use crate::{
     observer::Observers,
     storage::{ImmutableSparseSet, SparseArray, SparseSet, SparseSetIndex, TableId, TableRow},
 };
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, format, string::String, vec::Vec};
 use bevy_utils::HashMap;
 use core::{
+    fmt::Write,
     hash::Hash,
     ops::{Index, IndexMut, RangeFrom},
 };
@@ -358,6 +359,7 @@ bitflags::bitflags! {
         const ON_INSERT_OBSERVER = (1 << 5);
         const ON_REPLACE_OBSERVER = (1 << 6);
         const ON_REMOVE_OBSERVER = (1 << 7);
+        const ON_ARCHETYPE_MOVE_HOOK = (1 << 8);
     }
 }
 
@@ -672,6 +674,13 @@ impl Archetype {
         self.flags().contains(ArchetypeFlags::ON_REMOVE_HOOK)
     }
 
+    /// Returns true if any of the components in this archetype have `on_archetype_move` hooks
+    #[inline]
+    pub fn has_archetype_move_hook(&self) -> bool {
+        self.flags()
+            .contains(ArchetypeFlags::ON_ARCHETYPE_MOVE_HOOK)
+    }
+
     /// Returns true if any of the components in this archetype have at least one [`OnAdd`] observer
     ///
     /// [`OnAdd`]: crate::world::OnAdd
@@ -770,6 +779,124 @@ impl SparseSetIndex for ArchetypeComponentId {
 /// along with an [`ArchetypeRecord`] which contains some metadata about how the component is stored in the archetype.
 pub type ComponentIndex = HashMap<ComponentId, HashMap<ArchetypeId, ArchetypeRecord>>;
 
+/// A statement about the components of an [`Archetype`], used to build [`ArchetypeInvariant`]s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArchetypeStatement {
+    /// True if the archetype has all of the components.
+    AllOf(Vec<ComponentId>),
+    /// True if the archetype has at least one of the components.
+    AnyOf(Vec<ComponentId>),
+    /// True if the archetype has at most one of the components.
+    AtMostOneOf(Vec<ComponentId>),
+    /// True if the archetype has none of the components.
+    NoneOf(Vec<ComponentId>),
+}
+
+impl ArchetypeStatement {
+    /// Returns `true` if the statement holds for the given archetype.
+    pub fn test(&self, archetype: &Archetype) -> bool {
+        self.test_with(|id| archetype.contains(id))
+    }
+
+    fn test_with(&self, contains: impl Fn(ComponentId) -> bool) -> bool {
+        match self {
+            Self::AllOf(ids) => ids.iter().all(|&id| contains(id)),
+            Self::AnyOf(ids) => ids.iter().any(|&id| contains(id)),
+            Self::AtMostOneOf(ids) => ids.iter().filter(|&&id| contains(id)).count() <= 1,
+            Self::NoneOf(ids) => !ids.iter().any(|&id| contains(id)),
+        }
+    }
+
+    fn describe(&self, components: &Components) -> String {
+        let (prefix, ids) = match self {
+            Self::AllOf(ids) => ("all of", ids),
+            Self::AnyOf(ids) => ("any of", ids),
+            Self::AtMostOneOf(ids) => ("at most one of", ids),
+            Self::NoneOf(ids) => ("none of", ids),
+        };
+        format!(
+            "{prefix} {}",
+            describe_components(components, ids.iter().copied())
+        )
+    }
+}
+
+/// A rule about which combinations of components entities are allowed to have.
+///
+/// Whenever an entity would be moved to an archetype for which the `premise` holds
+/// but the `consequence` doesn't, the world panics instead.
+///
+/// Invariants are checked once per archetype, when the archetype is created, so they add no
+/// cost to inserting or removing components on entities whose archetype already exists.
+/// They can be registered with [`World::register_archetype_invariant`].
+///
+/// [`World::register_archetype_invariant`]: crate::world::World::register_archetype_invariant
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchetypeInvariant {
+    /// The statement that selects the archetypes this invariant applies to.
+    pub premise: ArchetypeStatement,
+    /// The statement that must hold for every archetype selected by the premise.
+    pub consequence: ArchetypeStatement,
+}
+
+impl ArchetypeInvariant {
+    /// Creates an invariant requiring `consequence` to hold for every archetype `premise` holds for.
+    pub fn new(premise: ArchetypeStatement, consequence: ArchetypeStatement) -> Self {
+        Self {
+            premise,
+            consequence,
+        }
+    }
+
+    /// Creates an invariant requiring `consequence` to hold for every archetype.
+    pub fn always(consequence: ArchetypeStatement) -> Self {
+        Self::new(ArchetypeStatement::AllOf(Vec::new()), consequence)
+    }
+
+    /// Returns `true` if the invariant holds for the given archetype.
+    pub fn test(&self, archetype: &Archetype) -> bool {
+        self.test_with(|id| archetype.contains(id))
+    }
+
+    fn test_with(&self, contains: impl Fn(ComponentId) -> bool + Copy) -> bool {
+        !self.premise.test_with(contains) || self.consequence.test_with(contains)
+    }
+
+    #[track_caller]
+    fn assert_holds(
+        &self,
+        components: &Components,
+        archetype_components: impl Iterator<Item = ComponentId> + Clone,
+    ) {
+        let contains = |id| archetype_components.clone().any(|other| other == id);
+        if !self.test_with(contains) {
+            panic!(
+                "Archetype invariant violated: entities with {} must have {}, but an entity would have {}",
+                self.premise.describe(components),
+                self.consequence.describe(components),
+                describe_components(components, archetype_components),
+            );
+        }
+    }
+}
+
+fn describe_components(components: &Components, ids: impl Iterator<Item = ComponentId>) -> String {
+    let mut description = String::from("[");
+    for (i, id) in ids.enumerate() {
+        if i > 0 {
+            description.push_str(", ");
+        }
+        match components.get_name(id) {
+            Some(name) => description.push_str(name),
+            None => {
+                let _ = write!(description, "{id:?}");
+            }
+        }
+    }
+    description.push(']');
+    description
+}
+
 /// The backing store of all [`Archetype`]s within a [`World`].
 ///
 /// For more information, see the *[module level documentation]*.
@@ -783,6 +910,8 @@ pub struct Archetypes {
     by_components: HashMap<ArchetypeComponents, ArchetypeId>,
     /// find all the archetypes that contain a component
     pub(crate) by_component: ComponentIndex,
+    /// rules checked whenever a new archetype is created
+    invariants: Vec<ArchetypeInvariant>,
 }
 
 /// Metadata about how a component is stored in an [`Archetype`].
@@ -800,6 +929,7 @@ impl Archetypes {
             by_components: Default::default(),
             by_component: Default::default(),
             archetype_component_count: 0,
+            invariants: Vec::new(),
         };
         // SAFETY: Empty archetype has no components
         unsafe {
@@ -920,6 +1050,7 @@ impl Archetypes {
         let archetypes = &mut self.archetypes;
         let archetype_component_count = &mut self.archetype_component_count;
         let component_index = &mut self.by_component;
+        let invariants = &self.invariants;
         let archetype_id = *self
             .by_components
             .entry(archetype_identity)
@@ -928,6 +1059,15 @@ impl Archetypes {
                     table_components,
                     sparse_set_components,
                 } = identity;
+                for invariant in invariants {
+                    invariant.assert_holds(
+                        components,
+                        table_components
+                            .iter()
+                            .chain(sparse_set_components.iter())
+                            .copied(),
+                    );
+                }
                 let id = ArchetypeId::new(archetypes.len());
                 let table_start = *archetype_component_count;
                 *archetype_component_count += table_components.len();
@@ -957,6 +1097,25 @@ impl Archetypes {
         archetype_id
     }
 
+    /// Returns the [`ArchetypeInvariant`]s every archetype is checked against when it is created.
+    #[inline]
+    pub fn invariants(&self) -> &[ArchetypeInvariant] {
+        &self.invariants
+    }
+
+    /// Adds an [`ArchetypeInvariant`] that every new archetype will be checked against.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an existing archetype violates the invariant.
+    #[track_caller]
+    pub(crate) fn add_invariant(&mut self, components: &Components, invariant: ArchetypeInvariant) {
+        for archetype in &self.archetypes {
+            invariant.assert_holds(components, archetype.components());
+        }
+        self.invariants.push(invariant);
+    }
+
     /// Returns the number of components that are stored in archetypes.
     /// Note that if some component `T` is stored in more than one archetype, it will be counted once for each archetype it's present in.
     #[inline]
@@ -1208,6 +1208,14 @@ impl<'w> BundleInserter<'w> {
         // SAFETY: All components in the bundle are guaranteed to exist in the World
         // as they must be initialized before creating the BundleInfo.
         unsafe {
+            if !matches!(self.archetype_move_type, ArchetypeMoveType::SameArchetype) {
+                // Every component of the old archetype was kept by the move.
+                deferred_world.trigger_on_archetype_move(
+                    new_archetype,
+                    entity,
+                    self.archetype.as_ref().components(),
+                );
+            }
             deferred_world.trigger_on_add(
                 new_archetype,
                 entity,
@@ -1755,6 +1763,29 @@ mod tests {
         assert_eq!(2, world.resource::<R>().0);
     }
 
+    #[test]
+    fn component_hook_archetype_move() {
+        let mut world = World::new();
+        world.init_resource::<R>();
+        world
+            .register_component_hooks::<A>()
+            .on_archetype_move(|mut world, entity, _| {
+                assert!(world.entity(entity).contains::<A>());
+                world.resource_mut::<R>().0 += 1;
+            });
+
+        let mut entity = world.spawn(A);
+        entity.insert(A); // same archetype, this will not trigger on_archetype_move
+        entity.insert(B);
+        entity.remove::<B>();
+        entity.remove::<C>(); // not present, this will not trigger on_archetype_move
+        entity.insert(C);
+        entity.remove::<C>();
+        entity.remove::<A>(); // A itself is removed, this will not trigger on_archetype_move
+        entity.flush();
+        assert_eq!(4, world.resource::<R>().0);
+    }
+
     #[test]
     fn component_hook_order_recursive() {
         let mut world = World::new();
@@ -1,11 +1,11 @@
 //! Types that detect when their internal data mutate.
 
 use crate::{
-    component::{Tick, TickCells},
+    component::{Component, Tick, TickCells},
     ptr::PtrMut,
     system::Resource,
 };
-use alloc::borrow::ToOwned;
+use alloc::{borrow::ToOwned, vec::Vec};
 use bevy_ptr::{Ptr, UnsafeCellDeref};
 use core::{
     mem,
@@ -376,6 +376,7 @@ macro_rules! change_detection_mut_impl {
             #[track_caller]
             fn set_changed(&mut self) {
                 *self.ticks.changed = self.ticks.this_run;
+                self.mark_all_fields_changed();
                 #[cfg(feature = "track_location")]
                 {
                     *self.changed_by = Location::caller();
@@ -436,6 +437,7 @@ macro_rules! impl_methods {
             #[doc = stringify!($name)]
             /// <T>`, but you need a `Mut<T>`.
             pub fn reborrow(&mut self) -> Mut<'_, $target> {
+                let field_ticks = self.field_ticks();
                 Mut {
                     value: self.value,
                     ticks: TicksMut {
@@ -444,6 +446,7 @@ macro_rules! impl_methods {
                         last_run: self.ticks.last_run,
                         this_run: self.ticks.this_run,
                     },
+                    field_ticks,
                     #[cfg(feature = "track_location")]
                     changed_by: self.changed_by,
                 }
@@ -471,10 +474,13 @@ macro_rules! impl_methods {
             /// }
             /// # bevy_ecs::system::assert_is_system(reset_positions);
             /// ```
-            pub fn map_unchanged<U: ?Sized>(self, f: impl FnOnce(&mut $target) -> &mut U) -> Mut<'w, U> {
+            pub fn map_unchanged<U: ?Sized>(mut self, f: impl FnOnce(&mut $target) -> &mut U) -> Mut<'w, U> {
+                // The mapped value can't reach the field ticks anymore.
+                self.mark_all_fields_changed();
                 Mut {
                     value: f(self.value),
                     ticks: self.ticks,
+                    field_ticks: None,
                     #[cfg(feature = "track_location")]
                     changed_by: self.changed_by,
                 }
@@ -484,11 +490,13 @@ macro_rules! impl_methods {
             /// This is useful in a situation where you need to convert a `Mut<T>` to a `Mut<U>`, but only if `T` contains `U`.
             ///
             /// As with `map_unchanged`, you should never modify the argument passed to the closure.
-            pub fn filter_map_unchanged<U: ?Sized>(self, f: impl FnOnce(&mut $target) -> Option<&mut U>) -> Option<Mut<'w, U>> {
+            pub fn filter_map_unchanged<U: ?Sized>(mut self, f: impl FnOnce(&mut $target) -> Option<&mut U>) -> Option<Mut<'w, U>> {
+                self.mark_all_fields_changed();
                 let value = f(self.value);
                 value.map(|value| Mut {
                     value,
                     ticks: self.ticks,
+                    field_ticks: None,
                     #[cfg(feature = "track_location")]
                     changed_by: self.changed_by,
                 })
@@ -502,6 +510,14 @@ macro_rules! impl_methods {
                 self.reborrow().map_unchanged(|v| v.deref_mut())
             }
 
+            /// Marks all fields of the value as changed if it is a component [tracking its fields](TrackedFields),
+            /// without marking the value itself as changed.
+            #[inline]
+            fn mark_all_fields_changed(&mut self) {
+                if let Some(field_ticks) = self.field_ticks() {
+                    field_ticks(self.value).set_all_changed(self.ticks.this_run);
+                }
+            }
         }
     };
 }
@@ -712,6 +728,14 @@ change_detection_mut_impl!(ResMut<'w, T>, T, Resource);
 impl_methods!(ResMut<'w, T>, T, Resource);
 impl_debug!(ResMut<'w, T>, Resource);
 
+impl<'w, T: ?Sized + Resource> ResMut<'w, T> {
+    /// Resources never track changes of their fields.
+    #[inline]
+    fn field_ticks(&self) -> Option<fn(&mut T) -> &mut FieldTicks> {
+        None
+    }
+}
+
 impl<'w, T: Resource> From<ResMut<'w, T>> for Mut<'w, T> {
     /// Convert this `ResMut` into a `Mut`. This allows keeping the change-detection feature of `Mut`
     /// while losing the specificity of `ResMut` for resources.
@@ -719,6 +743,7 @@ impl<'w, T: Resource> From<ResMut<'w, T>> for Mut<'w, T> {
         Mut {
             value: other.value,
             ticks: other.ticks,
+            field_ticks: None,
             #[cfg(feature = "track_location")]
             changed_by: other.changed_by,
         }
@@ -748,6 +773,14 @@ change_detection_mut_impl!(NonSendMut<'w, T>, T,);
 impl_methods!(NonSendMut<'w, T>, T,);
 impl_debug!(NonSendMut<'w, T>,);
 
+impl<'w, T: ?Sized> NonSendMut<'w, T> {
+    /// Non-send resources never track changes of their fields.
+    #[inline]
+    fn field_ticks(&self) -> Option<fn(&mut T) -> &mut FieldTicks> {
+        None
+    }
+}
+
 impl<'w, T: 'static> From<NonSendMut<'w, T>> for Mut<'w, T> {
     /// Convert this `NonSendMut` into a `Mut`. This allows keeping the change-detection feature of `Mut`
     /// while losing the specificity of `NonSendMut`.
@@ -755,6 +788,7 @@ impl<'w, T: 'static> From<NonSendMut<'w, T>> for Mut<'w, T> {
         Mut {
             value: other.value,
             ticks: other.ticks,
+            field_ticks: None,
             #[cfg(feature = "track_location")]
             changed_by: other.changed_by,
         }
@@ -921,6 +955,8 @@ impl_debug!(Ref<'w, T>,);
 pub struct Mut<'w, T: ?Sized> {
     pub(crate) value: &'w mut T,
     pub(crate) ticks: TicksMut<'w>,
+    /// Returns the [`FieldTicks`] of `value`, if it is a component [tracking its fields](TrackedFields).
+    pub(crate) field_ticks: Option<fn(&mut T) -> &mut FieldTicks>,
     #[cfg(feature = "track_location")]
     pub(crate) changed_by: &'w mut &'static Location<'static>,
 }
@@ -957,10 +993,35 @@ impl<'w, T: ?Sized> Mut<'w, T> {
                 last_run,
                 this_run,
             },
+            field_ticks: None,
             #[cfg(feature = "track_location")]
             changed_by: caller,
         }
     }
+
+    /// Creates a new change-detection enabled smart pointer to a component, which marks all of its fields
+    /// as changed when it is mutated without [`Mut::field_mut`].
+    pub(crate) fn new_component(
+        value: &'w mut T,
+        ticks: TicksMut<'w>,
+        #[cfg(feature = "track_location")] caller: &'w mut &'static Location<'static>,
+    ) -> Self
+    where
+        T: Component,
+    {
+        Self {
+            value,
+            ticks,
+            field_ticks: T::FIELD_TICKS,
+            #[cfg(feature = "track_location")]
+            changed_by: caller,
+        }
+    }
+
+    #[inline]
+    fn field_ticks(&self) -> Option<fn(&mut T) -> &mut FieldTicks> {
+        self.field_ticks
+    }
 }
 
 impl<'w, T: ?Sized> From<Mut<'w, T>> for Ref<'w, T> {
@@ -1004,6 +1065,143 @@ change_detection_mut_impl!(Mut<'w, T>, T,);
 impl_methods!(Mut<'w, T>, T,);
 impl_debug!(Mut<'w, T>,);
 
+impl<'w, T: TrackedFields> Mut<'w, T> {
+    /// Returns a mutable reference to one field of the component, marking only this field as changed
+    /// for the [`FieldChanged`](crate::query::FieldChanged) filter.
+    ///
+    /// The component itself is still marked as changed, so [`Changed`](crate::query::Changed) filters
+    /// behave as if the whole component was mutably dereferenced.
+    ///
+    /// `field` is the index of the field, usually one of the constants generated by
+    /// `#[component(track_fields)]`, and `get` must return a reference to that same field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::change_detection::FieldTicks;
+    /// # use bevy_ecs::query::FieldChanged;
+    /// #[derive(Component, Default)]
+    /// #[component(track_fields)]
+    /// struct Inventory {
+    ///     items: Vec<u32>,
+    ///     gold: u32,
+    ///     ticks: FieldTicks,
+    /// }
+    ///
+    /// fn earn_gold(mut inventories: Query<&mut Inventory>) {
+    ///     for mut inventory in &mut inventories {
+    ///         *inventory.field_mut(Inventory::GOLD_FIELD, |i| &mut i.gold) += 1;
+    ///     }
+    /// }
+    ///
+    /// // This system only runs its expensive logic when the items actually changed.
+    /// fn sort_items(inventories: Query<&Inventory, FieldChanged<Inventory, { Inventory::ITEMS_FIELD }>>) {
+    ///     for inventory in &inventories {
+    ///         // ...
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(earn_gold);
+    /// # bevy_ecs::system::assert_is_system(sort_items);
+    /// ```
+    #[track_caller]
+    pub fn field_mut<F: ?Sized>(
+        &mut self,
+        field: usize,
+        get: impl FnOnce(&mut T) -> &mut F,
+    ) -> &mut F {
+        debug_assert!(
+            field < T::FIELDS.len(),
+            "{} has no tracked field with index {field}",
+            core::any::type_name::<T>()
+        );
+        let this_run = self.ticks.this_run;
+        *self.ticks.changed = this_run;
+        #[cfg(feature = "track_location")]
+        {
+            *self.changed_by = Location::caller();
+        }
+        self.value.field_ticks_mut().set_changed(field, this_run);
+        get(self.value)
+    }
+}
+
+/// A [`Component`] tracking when each of its fields was changed, in addition to the component as a whole.
+///
+/// This trait is implemented by `#[derive(Component)]` with the `#[component(track_fields)]` attribute,
+/// for structs with named fields that store their [`FieldTicks`] in one of them. The derive also adds a
+/// `<FIELD>_FIELD` constant to the struct for each other field, holding the index of that field.
+///
+/// Fields are marked as changed with [`Mut::field_mut`] and can be filtered on with
+/// [`FieldChanged`](crate::query::FieldChanged). This is useful for large components whose fields
+/// trigger expensive downstream work independently of each other.
+pub trait TrackedFields: Component {
+    /// The names of the tracked fields, in order of their field index.
+    const FIELDS: &'static [&'static str];
+
+    /// Returns the change ticks of the fields of this component.
+    fn field_ticks(&self) -> &FieldTicks;
+
+    /// Returns the change ticks of the fields of this component mutably.
+    fn field_ticks_mut(&mut self) -> &mut FieldTicks;
+}
+
+/// The change ticks of the fields of a [`TrackedFields`] component.
+///
+/// Mutating the component through [`DerefMut`] rather than [`Mut::field_mut`] marks every field as changed.
+#[derive(Debug, Clone, Default)]
+pub struct FieldTicks {
+    changed: Vec<Tick>,
+    all_changed: Tick,
+    last_tracked: Tick,
+}
+
+impl FieldTicks {
+    /// Returns the tick at which the given field was last changed through [`Mut::field_mut`], if ever.
+    pub fn changed(&self, field: usize) -> Option<Tick> {
+        self.changed.get(field).copied()
+    }
+
+    /// Records that the given field was changed at `tick`.
+    pub fn set_changed(&mut self, field: usize, tick: Tick) {
+        if self.changed.len() <= field {
+            self.changed.resize(field + 1, Tick::new(0));
+        }
+        self.changed[field] = tick;
+        self.last_tracked = tick;
+    }
+
+    /// Records that all fields were changed at `tick`, because the component was mutated without
+    /// [`Mut::field_mut`].
+    pub fn set_all_changed(&mut self, tick: Tick) {
+        self.all_changed = tick;
+    }
+
+    /// Returns `true` if the given field was changed after the system last ran, given the tick at which
+    /// the component as a whole was last changed.
+    pub fn is_field_changed(
+        &self,
+        field: usize,
+        component_changed: Tick,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> bool {
+        if !component_changed.is_newer_than(last_run, this_run) {
+            return false;
+        }
+        // The component was mutated without `field_mut`, so any field may have changed.
+        if self.all_changed.is_newer_than(last_run, this_run) {
+            return true;
+        }
+        // The component was changed without going through `Mut` at all, such as when it was inserted.
+        if component_changed != self.last_tracked {
+            return true;
+        }
+        self.changed(field)
+            .is_some_and(|tick| tick.is_newer_than(last_run, this_run))
+    }
+}
+
 /// Unique mutable borrow of resources or an entity's component.
 ///
 /// Similar to [`Mut`], but not generic over the component type, instead
@@ -1093,6 +1291,7 @@ impl<'w> MutUntyped<'w> {
         Mut {
             value: f(self.value),
             ticks: self.ticks,
+            field_ticks: None,
             #[cfg(feature = "track_location")]
             changed_by: self.changed_by,
         }
@@ -1107,6 +1306,7 @@ impl<'w> MutUntyped<'w> {
             // SAFETY: `value` is `Aligned` and caller ensures the pointee type is `T`.
             value: unsafe { self.value.deref_mut() },
             ticks: self.ticks,
+            field_ticks: None,
             // SAFETY: `caller` is `Aligned`.
             #[cfg(feature = "track_location")]
             changed_by: self.changed_by,
@@ -1180,7 +1380,9 @@ impl core::fmt::Debug for MutUntyped<'_> {
 }
 
 impl<'w, T> From<Mut<'w, T>> for MutUntyped<'w> {
-    fn from(value: Mut<'w, T>) -> Self {
+    fn from(mut value: Mut<'w, T>) -> Self {
+        // The untyped value can't reach the field ticks anymore.
+        value.mark_all_fields_changed();
         MutUntyped {
             value: value.value.into(),
             ticks: value.ticks,
@@ -1253,12 +1455,15 @@ mod tests {
     use crate::{
         self as bevy_ecs,
         change_detection::{
-            Mut, NonSendMut, Ref, ResMut, TicksMut, CHECK_TICK_THRESHOLD, MAX_CHANGE_AGE,
+            FieldTicks, Mut, NonSendMut, Ref, ResMut, TicksMut, TrackedFields,
+            CHECK_TICK_THRESHOLD, MAX_CHANGE_AGE,
         },
         component::{Component, ComponentTicks, Tick},
-        system::{IntoSystem, Single, System},
+        query::{Changed, FieldChanged},
+        system::{IntoSystem, Query, Single, System},
         world::World,
     };
+    use alloc::{vec, vec::Vec};
 
     use super::{DetectChanges, DetectChangesMut, MutUntyped};
 
@@ -1268,6 +1473,14 @@ mod tests {
     #[derive(Resource)]
     struct R;
 
+    #[derive(Component, Default)]
+    #[component(track_fields)]
+    struct Inventory {
+        items: Vec<u32>,
+        gold: u32,
+        ticks: FieldTicks,
+    }
+
     #[derive(Resource, PartialEq)]
     struct R2(u8);
 
@@ -1475,6 +1688,7 @@ mod tests {
         let ptr = Mut {
             value: &mut outer,
             ticks,
+            field_ticks: None,
             #[cfg(feature = "track_location")]
             changed_by: &mut caller,
         };
@@ -1603,6 +1817,7 @@ mod tests {
         let mut_typed = Mut {
             value: &mut c,
             ticks,
+            field_ticks: None,
             #[cfg(feature = "track_location")]
             changed_by: &mut caller,
         };
@@ -1613,4 +1828,108 @@ mod tests {
         assert_eq!(3, into_mut.ticks.last_run.get());
         assert_eq!(4, into_mut.ticks.this_run.get());
     }
+
+    #[test]
+    fn field_change_detection() {
+        fn earn_gold(mut query: Query<&mut Inventory>) {
+            for mut inventory in &mut query {
+                *inventory.field_mut(Inventory::GOLD_FIELD, |i| &mut i.gold) += 1;
+            }
+        }
+
+        fn clear(mut query: Query<&mut Inventory>) {
+            for mut inventory in &mut query {
+                inventory.items.clear();
+            }
+        }
+
+        fn earn_gold_and_clear(mut query: Query<&mut Inventory>) {
+            for mut inventory in &mut query {
+                *inventory.field_mut(Inventory::GOLD_FIELD, |i| &mut i.gold) += 1;
+                inventory.items.clear();
+            }
+        }
+
+        fn clear_mapped(mut query: Query<&mut Inventory>) {
+            for mut inventory in &mut query {
+                *inventory.field_mut(Inventory::GOLD_FIELD, |i| &mut i.gold) += 1;
+                inventory.map_unchanged(|i| &mut i.items).clear();
+            }
+        }
+
+        fn changed(query: Query<(), Changed<Inventory>>) -> usize {
+            query.iter().count()
+        }
+
+        fn gold_changed(
+            query: Query<(), FieldChanged<Inventory, { Inventory::GOLD_FIELD }>>,
+        ) -> usize {
+            query.iter().count()
+        }
+
+        fn items_changed(
+            query: Query<(), FieldChanged<Inventory, { Inventory::ITEMS_FIELD }>>,
+        ) -> usize {
+            query.iter().count()
+        }
+
+        assert_eq!(Inventory::FIELDS, ["items", "gold"]);
+
+        let mut world = World::new();
+        world.spawn(Inventory {
+            items: vec![1, 2],
+            ..Default::default()
+        });
+
+        let mut earn_gold = IntoSystem::into_system(earn_gold);
+        let mut clear = IntoSystem::into_system(clear);
+        let mut earn_gold_and_clear = IntoSystem::into_system(earn_gold_and_clear);
+        let mut clear_mapped = IntoSystem::into_system(clear_mapped);
+        let mut changed = IntoSystem::into_system(changed);
+        let mut gold_changed = IntoSystem::into_system(gold_changed);
+        let mut items_changed = IntoSystem::into_system(items_changed);
+        earn_gold.initialize(&mut world);
+        clear.initialize(&mut world);
+        earn_gold_and_clear.initialize(&mut world);
+        clear_mapped.initialize(&mut world);
+        changed.initialize(&mut world);
+        gold_changed.initialize(&mut world);
+        items_changed.initialize(&mut world);
+
+        // Adding the component counts as changing all of its fields.
+        assert_eq!(changed.run((), &mut world), 1);
+        assert_eq!(gold_changed.run((), &mut world), 1);
+        assert_eq!(items_changed.run((), &mut world), 1);
+
+        earn_gold.run((), &mut world);
+        assert_eq!(changed.run((), &mut world), 1);
+        assert_eq!(gold_changed.run((), &mut world), 1);
+        assert_eq!(items_changed.run((), &mut world), 0);
+
+        // Changes that don't go through `field_mut` count as changing all fields.
+        clear.run((), &mut world);
+        assert_eq!(changed.run((), &mut world), 1);
+        assert_eq!(gold_changed.run((), &mut world), 1);
+        assert_eq!(items_changed.run((), &mut world), 1);
+
+        // Even when they happen after a change through `field_mut` in the same system.
+        earn_gold_and_clear.run((), &mut world);
+        assert_eq!(changed.run((), &mut world), 1);
+        assert_eq!(gold_changed.run((), &mut world), 1);
+        assert_eq!(items_changed.run((), &mut world), 1);
+
+        clear_mapped.run((), &mut world);
+        assert_eq!(changed.run((), &mut world), 1);
+        assert_eq!(gold_changed.run((), &mut world), 1);
+        assert_eq!(items_changed.run((), &mut world), 1);
+
+        earn_gold.run((), &mut world);
+        assert_eq!(changed.run((), &mut world), 1);
+        assert_eq!(gold_changed.run((), &mut world), 1);
+        assert_eq!(items_changed.run((), &mut world), 0);
+
+        assert_eq!(changed.run((), &mut world), 0);
+        assert_eq!(gold_changed.run((), &mut world), 0);
+        assert_eq!(items_changed.run((), &mut world), 0);
+    }
 }
@@ -4,8 +4,8 @@ use crate::{
     self as bevy_ecs,
     archetype::ArchetypeFlags,
     bundle::BundleInfo,
-    change_detection::MAX_CHANGE_AGE,
-    entity::{ComponentCloneCtx, Entity},
+    change_detection::{FieldTicks, MAX_CHANGE_AGE},
+    entity::{ComponentCloneCtx, Entity, MapEntities},
     query::DebugCheckedUnwrap,
     storage::{SparseSetIndex, SparseSets, Storages, Table, TableRow},
     system::{Local, Resource, SystemParam},
@@ -89,6 +89,10 @@ pub use bevy_ecs_macros::require;
 /// See the documentation for [`ComponentMutability`] for more details around this
 /// feature.
 ///
+/// Structs with named fields can track changes of each of their fields separately by adding the
+/// `#[component(track_fields)]` attribute and a field of type [`FieldTicks`].
+/// See the documentation for [`TrackedFields`] for more details around this feature.
+///
 /// See the [`entity`] module level documentation to learn how to add or remove components from an entity.
 ///
 /// See the documentation for [`Query`] to learn how to access component data from a system.
@@ -96,6 +100,8 @@ pub use bevy_ecs_macros::require;
 /// [`entity`]: crate::entity#usage
 /// [`Query`]: crate::system::Query
 /// [`ComponentMutability`]: crate::component::ComponentMutability
+/// [`FieldTicks`]: crate::change_detection::FieldTicks
+/// [`TrackedFields`]: crate::change_detection::TrackedFields
 ///
 /// # Choosing a storage type
 ///
@@ -302,6 +308,7 @@ pub use bevy_ecs_macros::require;
 /// - `#[component(on_insert = on_insert_function)]`
 /// - `#[component(on_replace = on_replace_function)]`
 /// - `#[component(on_remove = on_remove_function)]`
+/// - `#[component(on_archetype_move = on_archetype_move_function)]`
 ///
 /// ```
 /// # use bevy_ecs::component::Component;
@@ -403,6 +410,16 @@ pub trait Component: Send + Sync + 'static {
     /// * For a component to be immutable, this type must be [`Immutable`].
     type Mutability: ComponentMutability;
 
+    /// Returns the [`FieldTicks`] of this component, if it [tracks changes of its fields](TrackedFields).
+    ///
+    /// This is set by `#[derive(Component)]` with `#[component(track_fields)]`, so that mutating the component
+    /// without [`Mut::field_mut`] marks all of its fields as changed.
+    ///
+    /// [`TrackedFields`]: crate::change_detection::TrackedFields
+    /// [`Mut::field_mut`]: crate::change_detection::Mut::field_mut
+    #[doc(hidden)]
+    const FIELD_TICKS: Option<fn(&mut Self) -> &mut FieldTicks> = None;
+
     /// Called when registering this component, allowing mutable access to its [`ComponentHooks`].
     fn register_component_hooks(_hooks: &mut ComponentHooks) {}
 
@@ -563,6 +580,7 @@ pub struct ComponentHooks {
     pub(crate) on_insert: Option<ComponentHook>,
     pub(crate) on_replace: Option<ComponentHook>,
     pub(crate) on_remove: Option<ComponentHook>,
+    pub(crate) on_archetype_move: Option<ComponentHook>,
 }
 
 impl ComponentHooks {
@@ -629,6 +647,21 @@ impl ComponentHooks {
             .expect("Component already has an on_remove hook")
     }
 
+    /// Register a [`ComponentHook`] that will be run when an entity with this component moves to
+    /// another archetype while keeping this component, such as when other components are inserted
+    /// or removed.
+    ///
+    /// This hook runs after the entity was moved, so the component can be found in its new storage location.
+    /// It does *not* run when this component itself is added or removed, use `on_add` and `on_remove` instead.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the component already has an `on_archetype_move` hook
+    pub fn on_archetype_move(&mut self, hook: ComponentHook) -> &mut Self {
+        self.try_on_archetype_move(hook)
+            .expect("Component already has an on_archetype_move hook")
+    }
+
     /// Attempt to register a [`ComponentHook`] that will be run when this component is added to an entity.
     ///
     /// This is a fallible version of [`Self::on_add`].
@@ -680,6 +713,19 @@ impl ComponentHooks {
         self.on_remove = Some(hook);
         Some(self)
     }
+
+    /// Attempt to register a [`ComponentHook`] that will be run when an entity with this component moves to another archetype.
+    ///
+    /// This is a fallible version of [`Self::on_archetype_move`].
+    ///
+    /// Returns `None` if the component already has an `on_archetype_move` hook.
+    pub fn try_on_archetype_move(&mut self, hook: ComponentHook) -> Option<&mut Self> {
+        if self.on_archetype_move.is_some() {
+            return None;
+        }
+        self.on_archetype_move = Some(hook);
+        Some(self)
+    }
 }
 
 /// Stores metadata for a type of component or resource stored in a specific [`World`].
@@ -775,6 +821,9 @@ impl ComponentInfo {
         if self.hooks().on_remove.is_some() {
             flags.insert(ArchetypeFlags::ON_REMOVE_HOOK);
         }
+        if self.hooks().on_archetype_move.is_some() {
+            flags.insert(ArchetypeFlags::ON_ARCHETYPE_MOVE_HOOK);
+        }
     }
 
     /// Provides a reference to the collection of hooks associated with this [`Component`]
@@ -1007,6 +1056,14 @@ impl ComponentCloneHandler {
         Self(Some(component_clone_via_clone::<C>))
     }
 
+    /// Set clone handler based on `Clone` trait that also remaps entity references of the clone
+    /// using [`MapEntities`] and the [`EntityCloner`](crate::entity::EntityCloner) mapping.
+    ///
+    /// If set as a handler for a component that is not the same as the one used to create this handler, it will panic.
+    pub fn clone_and_map_entities_handler<C: Component + Clone + MapEntities>() -> Self {
+        Self(Some(component_clone_via_clone_and_map_entities::<C>))
+    }
+
     /// Set clone handler based on `Reflect` trait.
     #[cfg(feature = "bevy_reflect")]
     pub fn reflect_handler() -> Self {
@@ -2179,6 +2236,23 @@ pub fn component_clone_via_clone<C: Clone + Component>(
     }
 }
 
+/// Component [clone handler function](ComponentCloneFn) implemented using the [`Clone`] and [`MapEntities`] traits.
+/// Entity references in the clone are remapped with [`EntityCloner::map_entity`](crate::entity::EntityCloner::map_entity),
+/// so a component pointing at the source entity will point at the target entity instead.
+/// It will panic if set as handler for any other component.
+///
+/// See [`ComponentCloneHandlers`] for more details.
+pub fn component_clone_via_clone_and_map_entities<C: Clone + Component + MapEntities>(
+    _world: &mut DeferredWorld,
+    ctx: &mut ComponentCloneCtx,
+) {
+    if let Some(component) = ctx.read_source_component::<C>() {
+        let mut component = component.clone();
+        component.map_entities(&mut ctx.entity_cloner());
+        ctx.write_target_component(component);
+    }
+}
+
 /// Component [clone handler function](ComponentCloneFn) implemented using reflect.
 /// Can be [set](ComponentCloneHandlers::set_component_handler) as clone handler for any registered component,
 /// but only reflected components will be cloned.
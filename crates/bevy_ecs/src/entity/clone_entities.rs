@@ -17,7 +17,7 @@ use alloc::sync::Arc;
 use crate::{
     bundle::Bundle,
     component::{Component, ComponentCloneHandler, ComponentId, ComponentInfo, Components},
-    entity::Entity,
+    entity::{Entity, EntityHashMap, EntityMapper},
     query::DebugCheckedUnwrap,
     world::World,
 };
@@ -274,6 +274,7 @@ pub struct EntityCloner {
     filter_allows_components: bool,
     filter: Arc<HashSet<ComponentId>>,
     clone_handlers_overrides: Arc<HashMap<ComponentId, ComponentCloneHandler>>,
+    entity_mapping: Arc<EntityHashMap<Entity>>,
     move_components: bool,
 }
 
@@ -385,9 +386,29 @@ impl EntityCloner {
             target,
             filter: self.filter.clone(),
             clone_handlers_overrides: self.clone_handlers_overrides.clone(),
+            entity_mapping: self.entity_mapping.clone(),
             ..*self
         }
     }
+
+    /// Maps an [`Entity`] referenced by a component of the source entity to the one
+    /// the clone should reference instead.
+    ///
+    /// The source entity is always mapped to the target entity, any other entity is mapped
+    /// using the mappings added through [`EntityCloneBuilder::map_entity`], and entities
+    /// without a mapping are returned unchanged.
+    pub fn map_entity(&self, entity: Entity) -> Entity {
+        if entity == self.source {
+            return self.target;
+        }
+        self.entity_mapping.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
+impl EntityMapper for &EntityCloner {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        EntityCloner::map_entity(self, entity)
+    }
 }
 
 /// Builder struct to clone an entity. Allows configuring which components to clone, as well as how to clone them.
@@ -454,6 +475,7 @@ pub struct EntityCloneBuilder<'w> {
     filter_allows_components: bool,
     filter: HashSet<ComponentId>,
     clone_handlers_overrides: HashMap<ComponentId, ComponentCloneHandler>,
+    entity_mapping: EntityHashMap<Entity>,
     attach_required_components: bool,
     move_components: bool,
 }
@@ -466,6 +488,7 @@ impl<'w> EntityCloneBuilder<'w> {
             filter_allows_components: false,
             filter: Default::default(),
             clone_handlers_overrides: Default::default(),
+            entity_mapping: Default::default(),
             attach_required_components: true,
             move_components: false,
         }
@@ -478,6 +501,7 @@ impl<'w> EntityCloneBuilder<'w> {
             filter_allows_components,
             filter,
             clone_handlers_overrides,
+            entity_mapping,
             move_components,
             ..
         } = self;
@@ -488,6 +512,7 @@ impl<'w> EntityCloneBuilder<'w> {
             filter_allows_components,
             filter: Arc::new(filter),
             clone_handlers_overrides: Arc::new(clone_handlers_overrides),
+            entity_mapping: Arc::new(entity_mapping),
             move_components,
         }
         .clone_entity(world);
@@ -621,6 +646,16 @@ impl<'w> EntityCloneBuilder<'w> {
         self
     }
 
+    /// Makes references to `source` inside cloned components point to `target` instead.
+    ///
+    /// References to the cloned entity itself are always remapped to the clone. This is only
+    /// applied by handlers that fix up entity references, such as
+    /// [`ComponentCloneHandler::clone_and_map_entities_handler`].
+    pub fn map_entity(&mut self, source: Entity, target: Entity) -> &mut Self {
+        self.entity_mapping.insert(source, target);
+        self
+    }
+
     /// Helper function that allows a component through the filter.
     fn filter_allow(&mut self, id: ComponentId) {
         if self.filter_allows_components {
@@ -1019,6 +1054,48 @@ mod tests {
         assert!(world.get::<C>(e_clone).is_none());
     }
 
+    #[test]
+    fn clone_entity_with_entity_mapping() {
+        use crate::entity::{Entity, VisitEntities, VisitEntitiesMut};
+
+        #[derive(Component, Clone, VisitEntities, VisitEntitiesMut)]
+        struct Target(Entity);
+
+        #[derive(Component, Clone, VisitEntities, VisitEntitiesMut)]
+        struct Owner(Entity);
+
+        let mut world = World::default();
+        let target = world.register_component::<Target>();
+        let owner = world.register_component::<Owner>();
+        let handlers = world.get_component_clone_handlers_mut();
+        handlers.set_component_handler(
+            target,
+            ComponentCloneHandler::clone_and_map_entities_handler::<Target>(),
+        );
+        handlers.set_component_handler(
+            owner,
+            ComponentCloneHandler::clone_and_map_entities_handler::<Owner>(),
+        );
+
+        let outside = world.spawn_empty().id();
+        let replacement = world.spawn_empty().id();
+        let e = world.spawn_empty().id();
+        world.entity_mut(e).insert((Target(e), Owner(outside)));
+        let e_clone = world.spawn_empty().id();
+
+        let mut builder = EntityCloneBuilder::new(&mut world);
+        builder.map_entity(outside, replacement);
+        builder.clone_entity(e, e_clone);
+
+        assert_eq!(world.get::<Target>(e_clone).unwrap().0, e_clone);
+        assert_eq!(world.get::<Owner>(e_clone).unwrap().0, replacement);
+
+        let e_clone = world.spawn_empty().id();
+        EntityCloneBuilder::new(&mut world).clone_entity(e, e_clone);
+        assert_eq!(world.get::<Target>(e_clone).unwrap().0, e_clone);
+        assert_eq!(world.get::<Owner>(e_clone).unwrap().0, outside);
+    }
+
     #[test]
     fn clone_entity_with_required_components() {
         #[derive(Component, Clone, PartialEq, Debug)]
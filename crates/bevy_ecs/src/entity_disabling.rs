@@ -0,0 +1,226 @@
+//! Disabled entities do not show up in queries unless the query explicitly mentions them.
+//!
+//! If for example we have `Disabled` as an entity disabling component,
+//! when you add `Disabled` to an entity, the entity will not be matched by
+//! `Query<&A>` or `Query<Entity>`, but will be matched by `Query<&A, Allows<Disabled>>`,
+//! `Query<(&A, Has<Disabled>)>` or `Query<&A, With<Disabled>>`.
+//!
+//! This is useful for pooling entities, or for hiding objects in an editor,
+//! without having to add a custom marker filter to every system.
+//!
+//! ```
+//! # use bevy_ecs::{entity_disabling::Disabled, prelude::*, query::Allows};
+//! #[derive(Component)]
+//! struct Bullet;
+//!
+//! let mut world = World::new();
+//! world.spawn(Bullet);
+//! // A pooled bullet, waiting to be reused
+//! let pooled = world.spawn((Bullet, Disabled)).id();
+//!
+//! let mut bullets = world.query_filtered::<Entity, With<Bullet>>();
+//! assert_eq!(bullets.iter(&world).count(), 1);
+//!
+//! let mut all_bullets = world.query_filtered::<Entity, (With<Bullet>, Allows<Disabled>)>();
+//! assert_eq!(all_bullets.iter(&world).count(), 2);
+//!
+//! // Re-enable the bullet
+//! world.entity_mut(pooled).remove::<Disabled>();
+//! assert_eq!(bullets.iter(&world).count(), 2);
+//! ```
+//!
+//! Queries giving access to every component, such as `Query<EntityRef>`,
+//! do not count as mentioning `Disabled`, and skip disabled entities too.
+//! Disabled entities can still be accessed directly with [`World::entity`].
+//!
+//! The default filtering is controlled by the [`DefaultQueryFilters`] resource,
+//! which is inserted when the [`World`] is created.
+//! It only applies to queries created after it was changed.
+
+use crate::{
+    self as bevy_ecs,
+    component::{ComponentId, Components, StorageType},
+    prelude::{Component, FromWorld, Resource, World},
+    query::FilteredAccess,
+};
+use smallvec::SmallVec;
+
+#[cfg(feature = "bevy_reflect")]
+use {crate::reflect::ReflectComponent, bevy_reflect::Reflect};
+
+/// A marker component for disabled entities.
+///
+/// Disabled entities are skipped by queries unless they explicitly mention [`Disabled`],
+/// for example using [`Allows<Disabled>`](crate::query::Allows).
+/// See the [module docs](crate::entity_disabling) for more information.
+#[derive(Component, Clone, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component, Debug))]
+pub struct Disabled;
+
+/// The default filters applied to every query.
+///
+/// These filters are applied when the query is created, and exclude the entities having any
+/// of the disabling components, unless the query explicitly mentions the component.
+/// By default, [`Disabled`] is the only disabling component.
+#[derive(Resource, Debug)]
+pub struct DefaultQueryFilters {
+    disabling: SmallVec<[ComponentId; 4]>,
+}
+
+impl FromWorld for DefaultQueryFilters {
+    fn from_world(world: &mut World) -> Self {
+        let mut filters = DefaultQueryFilters::empty();
+        let disabled_component_id = world.register_component::<Disabled>();
+        filters.register_disabling_component(disabled_component_id);
+        filters
+    }
+}
+
+impl DefaultQueryFilters {
+    /// Creates a new [`DefaultQueryFilters`] which doesn't filter out any entities.
+    pub fn empty() -> Self {
+        Self {
+            disabling: SmallVec::new(),
+        }
+    }
+
+    /// Adds the given component to the set of disabling components,
+    /// so that entities with it are excluded from queries not mentioning it.
+    ///
+    /// This does not affect the queries that were already created.
+    pub fn register_disabling_component(&mut self, component_id: ComponentId) {
+        if !self.disabling.contains(&component_id) {
+            self.disabling.push(component_id);
+        }
+    }
+
+    /// Returns an iterator over the disabling components.
+    pub fn disabling_ids(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.disabling.iter().copied()
+    }
+
+    /// Modifies the provided [`FilteredAccess`] to exclude the entities having any of the
+    /// disabling components not mentioned by the access.
+    pub fn modify_access(&self, component_access: &mut FilteredAccess<ComponentId>) {
+        for component_id in self.disabling_ids() {
+            if !component_access.contains(component_id) {
+                component_access.and_without(component_id);
+            }
+        }
+    }
+
+    /// Returns `true` if every disabling component is stored in tables,
+    /// so that the filters don't prevent dense iteration.
+    pub(crate) fn is_dense(&self, components: &Components) -> bool {
+        self.disabling_ids().all(|component_id| {
+            components
+                .get_info(component_id)
+                .is_some_and(|info| info.storage_type() == StorageType::Table)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{Entity, Has, QueryBuilder, With},
+        query::Allows,
+        world::EntityRef,
+    };
+    use alloc::vec::Vec;
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct CustomDisabled;
+
+    #[test]
+    fn filters_modify_access() {
+        let mut world = World::new();
+        let disabled = world.register_component::<Disabled>();
+        let filters = world.resource::<DefaultQueryFilters>();
+
+        let mut access = FilteredAccess::<ComponentId>::default();
+        filters.modify_access(&mut access);
+        assert_eq!(access.without_filters().collect::<Vec<_>>(), [disabled]);
+
+        // Mentioning the component opts out of the default filter
+        let mut access = FilteredAccess::<ComponentId>::default();
+        access.and_with(disabled);
+        filters.modify_access(&mut access);
+        assert!(access.without_filters().next().is_none());
+
+        let mut access = FilteredAccess::<ComponentId>::default();
+        access.access_mut().add_archetypal(disabled);
+        filters.modify_access(&mut access);
+        assert!(access.without_filters().next().is_none());
+    }
+
+    #[test]
+    fn queries_skip_disabled_entities() {
+        let mut world = World::new();
+        let enabled = world.spawn(A).id();
+        let disabled = world.spawn((A, Disabled)).id();
+
+        let mut query = world.query::<Entity>();
+        assert_eq!(query.iter(&world).collect::<Vec<_>>(), [enabled]);
+
+        let mut query = world.query_filtered::<Entity, With<A>>();
+        assert_eq!(query.iter(&world).collect::<Vec<_>>(), [enabled]);
+
+        let mut query = world.query_filtered::<Entity, Allows<Disabled>>();
+        assert_eq!(query.iter(&world).count(), 2);
+
+        let mut query = world.query::<(Entity, Has<Disabled>)>();
+        assert_eq!(query.iter(&world).count(), 2);
+
+        let mut query = world.query_filtered::<Entity, With<Disabled>>();
+        assert_eq!(query.iter(&world).collect::<Vec<_>>(), [disabled]);
+
+        let mut query = world.query::<EntityRef>();
+        assert_eq!(query.iter(&world).count(), 1);
+
+        let mut query = world.query_filtered::<EntityRef, Allows<Disabled>>();
+        assert_eq!(query.iter(&world).count(), 2);
+
+        let mut query = QueryBuilder::<Entity>::new(&mut world).with::<A>().build();
+        assert_eq!(query.iter(&world).collect::<Vec<_>>(), [enabled]);
+
+        world.entity_mut(disabled).remove::<Disabled>();
+        let mut query = world.query::<&A>();
+        assert_eq!(query.iter(&world).count(), 2);
+    }
+
+    #[test]
+    fn custom_disabling_components() {
+        let mut world = World::new();
+        let custom = world.register_component::<CustomDisabled>();
+        world
+            .resource_mut::<DefaultQueryFilters>()
+            .register_disabling_component(custom);
+
+        let enabled = world.spawn(A).id();
+        world.spawn((A, CustomDisabled));
+        world.spawn((A, Disabled));
+
+        let mut query = world.query::<(Entity, &A)>();
+        assert_eq!(
+            query
+                .iter(&world)
+                .map(|(entity, _)| entity)
+                .collect::<Vec<_>>(),
+            [enabled]
+        );
+
+        let mut query = world.query_filtered::<&A, Allows<CustomDisabled>>();
+        assert_eq!(query.iter(&world).count(), 2);
+
+        // Default filters can be removed entirely
+        world.insert_resource(DefaultQueryFilters::empty());
+        let mut query = world.query::<&A>();
+        assert_eq!(query.iter(&world).count(), 3);
+    }
+}
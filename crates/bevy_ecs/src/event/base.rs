@@ -17,7 +17,23 @@ use core::{
 ///
 /// Events can also be "triggered" on a [`World`], which will then cause any [`Observer`] of that trigger to run.
 ///
-/// This trait can be derived.
+/// This trait can be derived. The [`Traversal`](Event::Traversal) and
+/// [`AUTO_PROPAGATE`](Event::AUTO_PROPAGATE) of a derived event can be configured with the `event` attribute:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::traversal::Traversal;
+/// # #[derive(Component)]
+/// # struct Vehicle(Entity);
+/// # impl<D> Traversal<D> for &Vehicle {
+/// #     fn traverse(item: Self::Item<'_>, _: &D) -> Option<Entity> {
+/// #         Some(item.0)
+/// #     }
+/// # }
+/// #[derive(Event)]
+/// #[event(traversal = &'static Vehicle, auto_propagate)]
+/// struct Damage(f32);
+/// ```
 ///
 /// Events implement the [`Component`] type (and they automatically do when they are derived). Events are (generally)
 /// not directly inserted as components. More often, the [`ComponentId`] is used to identify the event type within the
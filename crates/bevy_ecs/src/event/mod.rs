@@ -6,6 +6,7 @@ mod iterators;
 mod mut_iterators;
 mod mutator;
 mod reader;
+mod recording;
 mod registry;
 mod update;
 mod writer;
@@ -23,6 +24,7 @@ pub use mut_iterators::EventMutParIter;
 pub use mut_iterators::{EventMutIterator, EventMutIteratorWithId};
 pub use mutator::EventMutator;
 pub use reader::EventReader;
+pub use recording::{record_events, replay_events, EventRecorder, EventRecording, EventReplayer};
 pub use registry::{EventRegistry, ShouldUpdateEvents};
 pub use update::{
     event_update_condition, event_update_system, signal_event_update_system, EventUpdates,
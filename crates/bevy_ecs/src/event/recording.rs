@@ -0,0 +1,289 @@
+//! Recording of the events sent each frame, so that they can be replayed deterministically later.
+//!
+//! An [`EventRecorder`] captures every event of one type sent during each frame into an
+//! [`EventRecording`], and an [`EventReplayer`] sends the events of that recording again, one
+//! frame at a time. With the `serialize` feature, an [`EventRecording`] can be written to and
+//! read from a file using any `serde` format, which makes bug reproductions and headless
+//! regression tests of gameplay logic possible.
+//!
+//! Input resources such as `ButtonInput` are derived from input events, so recording and
+//! replaying those events reproduces them as well.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::event::{record_events, replay_events, EventRecorder, EventReplayer};
+//! # use bevy_ecs::system::RunSystemOnce;
+//! #[derive(Event, Clone, PartialEq, Debug)]
+//! struct Jump(u32);
+//!
+//! let mut world = World::new();
+//! world.init_resource::<Events<Jump>>();
+//! world.insert_resource(EventRecorder::<Jump>::new());
+//!
+//! // Frame 0: the player jumps once.
+//! world.send_event(Jump(1));
+//! world.run_system_once(record_events::<Jump>).unwrap();
+//! world.resource_mut::<Events<Jump>>().update();
+//!
+//! // Frame 1: nothing happens.
+//! world.run_system_once(record_events::<Jump>).unwrap();
+//!
+//! let recording = world.resource_mut::<EventRecorder<Jump>>().take();
+//! assert_eq!(recording.frames(), [vec![Jump(1)], vec![]]);
+//!
+//! // Later, feed the recorded events back into a fresh world.
+//! let mut world = World::new();
+//! world.init_resource::<Events<Jump>>();
+//! world.insert_resource(EventReplayer::new(recording));
+//! world.run_system_once(replay_events::<Jump>).unwrap();
+//! assert_eq!(world.resource::<Events<Jump>>().len(), 1);
+//! ```
+
+use crate::{
+    self as bevy_ecs,
+    event::{Event, EventCursor, Events},
+    system::{Res, ResMut, Resource},
+};
+use alloc::vec::Vec;
+use core::mem;
+
+/// The events of type `E` sent during a number of consecutive frames.
+///
+/// Created by an [`EventRecorder`] and replayed by an [`EventReplayer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventRecording<E> {
+    frames: Vec<Vec<E>>,
+}
+
+impl<E> Default for EventRecording<E> {
+    fn default() -> Self {
+        Self { frames: Vec::new() }
+    }
+}
+
+impl<E> EventRecording<E> {
+    /// Creates a recording from the events sent during each frame.
+    pub fn from_frames(frames: Vec<Vec<E>>) -> Self {
+        Self { frames }
+    }
+
+    /// Returns the events sent during each recorded frame.
+    pub fn frames(&self) -> &[Vec<E>] {
+        &self.frames
+    }
+
+    /// Returns the events sent during the given frame, or `None` if it wasn't recorded.
+    pub fn frame(&self, frame: usize) -> Option<&[E]> {
+        self.frames.get(frame).map(Vec::as_slice)
+    }
+
+    /// Returns the number of recorded frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns `true` if no frames were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Appends a frame with the given events to the recording.
+    pub fn push_frame(&mut self, events: Vec<E>) {
+        self.frames.push(events);
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<E: serde::Serialize> serde::Serialize for EventRecording<E> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.frames.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de, E: serde::Deserialize<'de>> serde::Deserialize<'de> for EventRecording<E> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Vec::deserialize(deserializer).map(Self::from_frames)
+    }
+}
+
+/// A [`Resource`] capturing the events of type `E` sent each frame into an [`EventRecording`].
+///
+/// Frames are recorded by the [`record_events`] system, which should run once per frame,
+/// after every system that may send `E`.
+#[derive(Resource)]
+pub struct EventRecorder<E: Event> {
+    recording: EventRecording<E>,
+    cursor: EventCursor<E>,
+    paused: bool,
+}
+
+impl<E: Event> Default for EventRecorder<E> {
+    fn default() -> Self {
+        Self {
+            recording: EventRecording::default(),
+            cursor: EventCursor::default(),
+            paused: false,
+        }
+    }
+}
+
+impl<E: Event> EventRecorder<E> {
+    /// Creates a recorder with an empty recording.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the events recorded so far.
+    pub fn recording(&self) -> &EventRecording<E> {
+        &self.recording
+    }
+
+    /// Returns the events recorded so far, leaving an empty recording in their place.
+    pub fn take(&mut self) -> EventRecording<E> {
+        mem::take(&mut self.recording)
+    }
+
+    /// Pauses or resumes recording. Events sent while paused are not recorded,
+    /// and neither are the frames they were sent in.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Returns `true` if recording is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+}
+
+/// Records the events of type `E` sent since the last time this system ran as a new frame
+/// of the [`EventRecorder<E>`], if present.
+pub fn record_events<E: Event + Clone>(
+    recorder: Option<ResMut<EventRecorder<E>>>,
+    events: Res<Events<E>>,
+) {
+    let Some(mut recorder) = recorder else {
+        return;
+    };
+    let recorder = &mut *recorder;
+    let frame: Vec<E> = recorder.cursor.read(&events).cloned().collect();
+    if !recorder.paused {
+        recorder.recording.push_frame(frame);
+    }
+}
+
+/// A [`Resource`] sending the events of an [`EventRecording`] again, one frame at a time.
+///
+/// Frames are sent by the [`replay_events`] system, which should run once per frame,
+/// before every system that may read `E`.
+#[derive(Resource)]
+pub struct EventReplayer<E: Event> {
+    recording: EventRecording<E>,
+    next_frame: usize,
+}
+
+impl<E: Event> EventReplayer<E> {
+    /// Creates a replayer starting at the first frame of `recording`.
+    pub fn new(recording: EventRecording<E>) -> Self {
+        Self {
+            recording,
+            next_frame: 0,
+        }
+    }
+
+    /// Returns the recording being replayed.
+    pub fn recording(&self) -> &EventRecording<E> {
+        &self.recording
+    }
+
+    /// Returns the index of the next frame that will be replayed.
+    pub fn next_frame(&self) -> usize {
+        self.next_frame
+    }
+
+    /// Returns `true` once every frame of the recording was replayed.
+    pub fn is_finished(&self) -> bool {
+        self.next_frame >= self.recording.len()
+    }
+
+    /// Starts replaying the recording from its first frame again.
+    pub fn restart(&mut self) {
+        self.next_frame = 0;
+    }
+}
+
+/// Sends the events of the next frame of the [`EventReplayer<E>`], if present.
+pub fn replay_events<E: Event + Clone>(
+    replayer: Option<ResMut<EventReplayer<E>>>,
+    mut events: ResMut<Events<E>>,
+) {
+    let Some(mut replayer) = replayer else {
+        return;
+    };
+    if let Some(frame) = replayer.recording.frame(replayer.next_frame) {
+        events.send_batch(frame.iter().cloned());
+        replayer.next_frame += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{system::RunSystemOnce, world::World};
+    use alloc::vec;
+
+    #[derive(Event, Clone, PartialEq, Debug)]
+    struct TestEvent(u32);
+
+    fn run_frame(world: &mut World, sent: &[u32]) {
+        world.run_system_once(replay_events::<TestEvent>).unwrap();
+        for &i in sent {
+            world.send_event(TestEvent(i));
+        }
+        world.run_system_once(record_events::<TestEvent>).unwrap();
+        world.resource_mut::<Events<TestEvent>>().update();
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let mut world = World::new();
+        world.init_resource::<Events<TestEvent>>();
+        world.insert_resource(EventRecorder::<TestEvent>::new());
+
+        run_frame(&mut world, &[1, 2]);
+        run_frame(&mut world, &[]);
+        world
+            .resource_mut::<EventRecorder<TestEvent>>()
+            .set_paused(true);
+        run_frame(&mut world, &[3]);
+        world
+            .resource_mut::<EventRecorder<TestEvent>>()
+            .set_paused(false);
+        run_frame(&mut world, &[4]);
+
+        let recording = world.resource_mut::<EventRecorder<TestEvent>>().take();
+        assert_eq!(
+            recording.frames(),
+            [vec![TestEvent(1), TestEvent(2)], vec![], vec![TestEvent(4)]]
+        );
+
+        let mut world = World::new();
+        world.init_resource::<Events<TestEvent>>();
+        world.insert_resource(EventRecorder::<TestEvent>::new());
+        world.insert_resource(EventReplayer::new(recording.clone()));
+        for _ in 0..4 {
+            run_frame(&mut world, &[]);
+        }
+
+        assert!(world.resource::<EventReplayer<TestEvent>>().is_finished());
+        let replayed = world.resource_mut::<EventRecorder<TestEvent>>().take();
+        assert_eq!(&replayed.frames()[..3], recording.frames());
+        assert_eq!(replayed.frame(3), Some(&[][..]));
+    }
+}
@@ -0,0 +1,278 @@
+//! Indexes mapping the values of a component to the entities holding them,
+//! allowing fast lookups of every entity with a given value.
+//!
+//! Indexes must be created for each component using [`World::add_index`],
+//! and are then kept up to date by observers.
+//! Only [immutable](crate::component::Immutable) components can be indexed,
+//! as mutating them in place would bypass those observers.
+//!
+//! ```
+//! # use bevy_ecs::{prelude::*, index::QueryByIndex};
+//! #[derive(Component, PartialEq, Eq, Hash, Clone, Copy)]
+//! #[component(immutable)]
+//! struct TeamId(u32);
+//!
+//! #[derive(Component)]
+//! struct Health(u32);
+//!
+//! fn heal_team(mut players: QueryByIndex<TeamId, &mut Health>) {
+//!     let mut players = players.at_mut(&TeamId(3));
+//!     while let Some(mut health) = players.fetch_next() {
+//!         health.0 += 10;
+//!     }
+//! }
+//!
+//! let mut world = World::new();
+//! world.add_index::<TeamId>();
+//! let player = world.spawn((TeamId(3), Health(50))).id();
+//! let other_player = world.spawn((TeamId(1), Health(50))).id();
+//!
+//! world.run_system_cached(heal_team).unwrap();
+//! assert_eq!(world.get::<Health>(player).unwrap().0, 60);
+//! assert_eq!(world.get::<Health>(other_player).unwrap().0, 50);
+//! ```
+
+use crate::{
+    self as bevy_ecs,
+    component::{Component, Immutable},
+    entity::{Entity, EntityHashSet},
+    observer::Trigger,
+    query::{QueryData, QueryFilter, QueryManyIter, ROQueryItem, With},
+    system::{Query, Res, ResMut, Resource, SystemParam},
+    world::{OnInsert, OnReplace, World},
+};
+use bevy_utils::HashMap;
+use core::hash::Hash;
+
+/// A [`Resource`] mapping each value of the component `C` to the entities holding it.
+///
+/// This is created by [`World::add_index`], and kept up to date as `C` is inserted,
+/// replaced and removed.
+pub struct ComponentIndex<C: Component<Mutability = Immutable> + Eq + Hash + Clone> {
+    entities: HashMap<C, EntityHashSet>,
+}
+
+impl<C: Component<Mutability = Immutable> + Eq + Hash + Clone> Resource for ComponentIndex<C> {}
+
+impl<C: Component<Mutability = Immutable> + Eq + Hash + Clone> Default for ComponentIndex<C> {
+    fn default() -> Self {
+        Self {
+            entities: HashMap::default(),
+        }
+    }
+}
+
+impl<C: Component<Mutability = Immutable> + Eq + Hash + Clone> ComponentIndex<C> {
+    /// Returns the entities whose `C` component is equal to `key`, if there are any.
+    pub fn get(&self, key: &C) -> Option<&EntityHashSet> {
+        self.entities.get(key)
+    }
+
+    /// Returns an iterator over the entities whose `C` component is equal to `key`.
+    pub fn iter(&self, key: &C) -> impl Iterator<Item = Entity> + '_ {
+        self.get(key).into_iter().flatten().copied()
+    }
+
+    /// Returns an iterator over every distinct value of `C` held by at least one entity.
+    pub fn keys(&self) -> impl Iterator<Item = &C> {
+        self.entities.keys()
+    }
+
+    fn insert(&mut self, key: C, entity: Entity) {
+        self.entities.entry(key).or_default().insert(entity);
+    }
+
+    fn remove(&mut self, key: &C, entity: Entity) {
+        if let Some(entities) = self.entities.get_mut(key) {
+            entities.remove(&entity);
+            if entities.is_empty() {
+                self.entities.remove(key);
+            }
+        }
+    }
+}
+
+fn index_on_insert<C: Component<Mutability = Immutable> + Eq + Hash + Clone>(
+    trigger: Trigger<OnInsert, C>,
+    query: Query<&C>,
+    mut index: ResMut<ComponentIndex<C>>,
+) {
+    let entity = trigger.target();
+    if let Ok(component) = query.get(entity) {
+        index.insert(component.clone(), entity);
+    }
+}
+
+fn index_on_replace<C: Component<Mutability = Immutable> + Eq + Hash + Clone>(
+    trigger: Trigger<OnReplace, C>,
+    query: Query<&C>,
+    mut index: ResMut<ComponentIndex<C>>,
+) {
+    let entity = trigger.target();
+    if let Ok(component) = query.get(entity) {
+        index.remove(component, entity);
+    }
+}
+
+impl World {
+    /// Creates a [`ComponentIndex`] for the component `C`,
+    /// allowing entities to be looked up by the value of `C` using [`QueryByIndex`].
+    ///
+    /// Entities already holding `C` are added to the index,
+    /// which is then kept up to date by observers.
+    /// Calling this again for the same component does nothing.
+    pub fn add_index<C: Component<Mutability = Immutable> + Eq + Hash + Clone>(
+        &mut self,
+    ) -> &mut Self {
+        if self.contains_resource::<ComponentIndex<C>>() {
+            return self;
+        }
+
+        let mut index = ComponentIndex::<C>::default();
+        let mut query = self.query::<(Entity, &C)>();
+        for (entity, component) in query.iter(self) {
+            index.insert(component.clone(), entity);
+        }
+        self.insert_resource(index);
+
+        self.add_observer(index_on_insert::<C>);
+        self.add_observer(index_on_replace::<C>);
+        self
+    }
+}
+
+/// A [`SystemParam`] querying only the entities whose component `C` is equal to a given key,
+/// using the [`ComponentIndex`] of `C` rather than iterating over every entity.
+///
+/// # Panics
+///
+/// Panics when used in a system if no index was created for `C` using [`World::add_index`].
+#[derive(SystemParam)]
+pub struct QueryByIndex<
+    'w,
+    's,
+    C: Component<Mutability = Immutable> + Eq + Hash + Clone,
+    D: QueryData + 'static,
+    F: QueryFilter + 'static = (),
+> {
+    index: Res<'w, ComponentIndex<C>>,
+    query: Query<'w, 's, D, (F, With<C>)>,
+}
+
+impl<'w, 's, C, D, F> QueryByIndex<'w, 's, C, D, F>
+where
+    C: Component<Mutability = Immutable> + Eq + Hash + Clone,
+    D: QueryData + 'static,
+    F: QueryFilter + 'static,
+{
+    /// Returns the [`ComponentIndex`] of `C`.
+    pub fn index(&self) -> &ComponentIndex<C> {
+        &self.index
+    }
+
+    /// Returns an iterator over the read-only query items of the entities
+    /// whose `C` component is equal to `key`.
+    pub fn at(&self, key: &C) -> impl Iterator<Item = ROQueryItem<'_, D>> {
+        self.query
+            .iter_many(self.index.get(key).into_iter().flatten())
+    }
+
+    /// Returns an iterator over the query items of the entities
+    /// whose `C` component is equal to `key`.
+    ///
+    /// Use [`QueryManyIter::fetch_next`] to access the items.
+    pub fn at_mut(
+        &mut self,
+        key: &C,
+    ) -> QueryManyIter<'_, 's, D, (F, With<C>), impl Iterator<Item = &Entity>> {
+        self.query
+            .iter_many_mut(self.index.get(key).into_iter().flatten())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::RunSystemOnce;
+    use alloc::vec::Vec;
+
+    #[derive(Component, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+    #[component(immutable)]
+    struct TeamId(u32);
+
+    #[derive(Component)]
+    struct Score(u32);
+
+    fn team(world: &World, team_id: u32) -> Vec<Entity> {
+        let mut entities: Vec<_> = world
+            .resource::<ComponentIndex<TeamId>>()
+            .iter(&TeamId(team_id))
+            .collect();
+        entities.sort();
+        entities
+    }
+
+    #[test]
+    fn should_index_existing_and_new_entities() {
+        let mut world = World::new();
+        let a = world.spawn(TeamId(1)).id();
+        world.add_index::<TeamId>();
+        let b = world.spawn(TeamId(1)).id();
+        let c = world.spawn(TeamId(2)).id();
+
+        assert_eq!(team(&world, 1), [a, b]);
+        assert_eq!(team(&world, 2), [c]);
+        assert!(team(&world, 3).is_empty());
+    }
+
+    #[test]
+    fn should_update_index_on_replace_and_remove() {
+        let mut world = World::new();
+        world.add_index::<TeamId>();
+        let a = world.spawn(TeamId(1)).id();
+        let b = world.spawn(TeamId(1)).id();
+        let c = world.spawn(TeamId(1)).id();
+
+        world.entity_mut(a).insert(TeamId(2));
+        world.entity_mut(b).remove::<TeamId>();
+        world.despawn(c);
+
+        assert!(team(&world, 1).is_empty());
+        assert_eq!(team(&world, 2), [a]);
+        assert_eq!(
+            world
+                .resource::<ComponentIndex<TeamId>>()
+                .keys()
+                .collect::<Vec<_>>(),
+            [&TeamId(2)]
+        );
+    }
+
+    #[test]
+    fn should_query_by_index() {
+        let mut world = World::new();
+        world.add_index::<TeamId>();
+        world.spawn((TeamId(1), Score(1)));
+        world.spawn((TeamId(1), Score(2)));
+        world.spawn((TeamId(2), Score(4)));
+        world.spawn(TeamId(1));
+
+        let total = world
+            .run_system_once(|query: QueryByIndex<TeamId, &Score>| {
+                query.at(&TeamId(1)).map(|score| score.0).sum::<u32>()
+            })
+            .unwrap();
+        assert_eq!(total, 3);
+
+        world
+            .run_system_once(|mut query: QueryByIndex<TeamId, &mut Score>| {
+                let mut scores = query.at_mut(&TeamId(2));
+                while let Some(mut score) = scores.fetch_next() {
+                    score.0 *= 10;
+                }
+            })
+            .unwrap();
+        let mut scores = world.query::<&Score>();
+        assert_eq!(scores.iter(&world).map(|score| score.0).sum::<u32>(), 43);
+    }
+}
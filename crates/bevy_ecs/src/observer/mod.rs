@@ -84,6 +84,15 @@ impl<'w, E, B: Bundle> Trigger<'w, E, B> {
         self.trigger.target
     }
 
+    /// Returns the [`Entity`] the `event` was originally triggered on. It may be [`Entity::PLACEHOLDER`].
+    ///
+    /// This is the same as [`target`](Self::target) unless the event has [propagated](Self::propagate)
+    /// to another entity, in which case `target()` is the entity currently being visited and
+    /// `original_target()` is the entity the event started at.
+    pub fn original_target(&self) -> Entity {
+        self.trigger.original_target
+    }
+
     /// Returns the components that triggered the observer, out of the
     /// components defined in `B`. Does not necessarily include all of them as
     /// `B` acts like an `OR` filter rather than an `AND` filter.
@@ -312,6 +321,8 @@ pub struct ObserverTrigger {
     components: SmallVec<[ComponentId; 2]>,
     /// The entity the trigger targeted.
     pub target: Entity,
+    /// The entity the trigger was originally targeted at before any propagation.
+    pub original_target: Entity,
 }
 
 impl ObserverTrigger {
@@ -382,6 +393,7 @@ impl Observers {
         mut world: DeferredWorld,
         event_type: ComponentId,
         target: Entity,
+        original_target: Entity,
         components: impl Iterator<Item = ComponentId> + Clone,
         data: &mut T,
         propagate: &mut bool,
@@ -409,6 +421,7 @@ impl Observers {
                     event_type,
                     components: components.clone().collect(),
                     target,
+                    original_target,
                 },
                 data.into(),
                 propagate,
@@ -806,6 +819,10 @@ mod tests {
         const AUTO_PROPAGATE: bool = true;
     }
 
+    #[derive(Event)]
+    #[event(traversal = &'static Parent, auto_propagate)]
+    struct EventPropagatingDerived;
+
     #[test]
     fn observer_order_spawn_despawn() {
         let mut world = World::new();
@@ -1201,6 +1218,37 @@ mod tests {
         assert_eq!(vec!["child", "parent"], world.resource::<Order>().0);
     }
 
+    #[test]
+    fn observer_propagating_derived() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        let parent = world
+            .spawn_empty()
+            .observe(
+                |trigger: Trigger<EventPropagatingDerived>, mut res: ResMut<Order>| {
+                    assert_ne!(trigger.target(), trigger.original_target());
+                    res.observed("parent");
+                },
+            )
+            .id();
+
+        let child = world
+            .spawn(Parent(parent))
+            .observe(
+                |trigger: Trigger<EventPropagatingDerived>, mut res: ResMut<Order>| {
+                    assert_eq!(trigger.target(), trigger.original_target());
+                    res.observed("child");
+                },
+            )
+            .id();
+
+        world.flush();
+        world.trigger_targets(EventPropagatingDerived, child);
+        world.flush();
+        assert_eq!(vec!["child", "parent"], world.resource::<Order>().0);
+    }
+
     #[test]
     fn observer_propagating_redundant_dispatch_same_entity() {
         let mut world = World::new();
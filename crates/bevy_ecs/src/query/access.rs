@@ -488,6 +488,7 @@ impl<T: SparseSetIndex> Access<T> {
         self.resource_read_and_writes
             .union_with(&other.resource_read_and_writes);
         self.resource_writes.union_with(&other.resource_writes);
+        self.archetypal.union_with(&other.archetypal);
     }
 
     /// Returns `true` if the access and `other` can be active at the same time,
@@ -1123,6 +1124,22 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             .iter()
             .flat_map(|f| f.without.ones().map(T::get_sparse_set_index))
     }
+
+    /// Returns `true` if the component given by `index` is explicitly mentioned by this access,
+    /// either through a read, a write, an archetypal access, or a filter.
+    ///
+    /// Access to all components (e.g. through [`EntityRef`](crate::world::EntityRef))
+    /// does not count as a mention of each component.
+    pub fn contains(&self, index: T) -> bool {
+        let index = index.sparse_set_index();
+        (!self.access.component_read_and_writes_inverted
+            && self.access.component_read_and_writes.contains(index))
+            || self.access.archetypal.contains(index)
+            || self
+                .filter_sets
+                .iter()
+                .any(|f| f.with.contains(index) || f.without.contains(index))
+    }
 }
 
 #[derive(Eq, PartialEq)]
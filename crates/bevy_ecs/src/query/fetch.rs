@@ -1552,29 +1552,29 @@ unsafe impl<'__w, T: Component> WorldQuery for &'__w mut T {
                 #[cfg(feature = "track_location")]
                 let caller = unsafe { _callers.get(table_row.as_usize()) };
 
-                Mut {
-                    value: component.deref_mut(),
-                    ticks: TicksMut {
+                Mut::new_component(
+                    component.deref_mut(),
+                    TicksMut {
                         added: added.deref_mut(),
                         changed: changed.deref_mut(),
                         this_run: fetch.this_run,
                         last_run: fetch.last_run,
                     },
                     #[cfg(feature = "track_location")]
-                    changed_by: caller.deref_mut(),
-                }
+                    caller.deref_mut(),
+                )
             },
             |sparse_set| {
                 // SAFETY: The caller ensures `entity` is in range.
                 let (component, ticks, _caller) =
                     unsafe { sparse_set.get_with_ticks(entity).debug_checked_unwrap() };
 
-                Mut {
-                    value: component.assert_unique().deref_mut(),
-                    ticks: TicksMut::from_tick_cells(ticks, fetch.last_run, fetch.this_run),
+                Mut::new_component(
+                    component.assert_unique().deref_mut(),
+                    TicksMut::from_tick_cells(ticks, fetch.last_run, fetch.this_run),
                     #[cfg(feature = "track_location")]
-                    changed_by: _caller.deref_mut(),
-                }
+                    _caller.deref_mut(),
+                )
             },
         )
     }
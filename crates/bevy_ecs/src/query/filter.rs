@@ -1,5 +1,6 @@
 use crate::{
     archetype::Archetype,
+    change_detection::{Ref, TrackedFields},
     component::{Component, ComponentId, Components, StorageType, Tick},
     entity::Entity,
     query::{DebugCheckedUnwrap, FilteredAccess, StorageSwitch, WorldQuery},
@@ -331,6 +332,114 @@ unsafe impl<T: Component> QueryFilter for Without<T> {
     }
 }
 
+/// Filter that allows entities with a component `T`, without requiring it.
+///
+/// This has no effect on its own, but opts the query out of the [default query filters]
+/// excluding entities with `T`, such as [`Disabled`].
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::entity_disabling::Disabled;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::query::Allows;
+/// #
+/// # #[derive(Component)]
+/// # struct Name { name: &'static str };
+/// #
+/// fn all_names_system(query: Query<&Name, Allows<Disabled>>) {
+///     for name in &query {
+///         println!("{} may or may not be disabled", name.name);
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(all_names_system);
+/// ```
+///
+/// [default query filters]: crate::entity_disabling::DefaultQueryFilters
+/// [`Disabled`]: crate::entity_disabling::Disabled
+pub struct Allows<T>(PhantomData<T>);
+
+/// SAFETY:
+/// `update_component_access` only adds an archetypal access, which never conflicts.
+/// This is sound because `fetch` does not access any components.
+/// This is sound because `matches_component_set` always returns `true`.
+unsafe impl<T: Component> WorldQuery for Allows<T> {
+    type Item<'w> = ();
+    type Fetch<'w> = ();
+    type State = ComponentId;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(_: Self::Item<'wlong>) -> Self::Item<'wshort> {}
+
+    fn shrink_fetch<'wlong: 'wshort, 'wshort>(_: Self::Fetch<'wlong>) -> Self::Fetch<'wshort> {}
+
+    #[inline]
+    unsafe fn init_fetch(
+        _world: UnsafeWorldCell,
+        _state: &ComponentId,
+        _last_run: Tick,
+        _this_run: Tick,
+    ) {
+    }
+
+    // `Allows<T>` matches every entity of the archetypes it's given.
+    const IS_DENSE: bool = true;
+
+    #[inline]
+    unsafe fn set_archetype(
+        _fetch: &mut (),
+        _state: &ComponentId,
+        _archetype: &Archetype,
+        _table: &Table,
+    ) {
+    }
+
+    #[inline]
+    unsafe fn set_table(_fetch: &mut (), _state: &Self::State, _table: &Table) {}
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        _fetch: &mut Self::Fetch<'w>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> Self::Item<'w> {
+    }
+
+    #[inline]
+    fn update_component_access(&id: &ComponentId, access: &mut FilteredAccess<ComponentId>) {
+        access.access_mut().add_archetypal(id);
+    }
+
+    fn init_state(world: &mut World) -> ComponentId {
+        world.register_component::<T>()
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        components.component_id::<T>()
+    }
+
+    fn matches_component_set(
+        _state: &ComponentId,
+        _set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        // `Allows<T>` always matches
+        true
+    }
+}
+
+// SAFETY: WorldQuery impl performs no access at all
+unsafe impl<T: Component> QueryFilter for Allows<T> {
+    const IS_ARCHETYPAL: bool = true;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        _fetch: &mut Self::Fetch<'_>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> bool {
+        true
+    }
+}
+
 /// A filter that tests if any of the given filters apply.
 ///
 /// This is useful for example if a system with multiple components in a query only wants to run
@@ -1023,6 +1132,139 @@ unsafe impl<T: Component> QueryFilter for Changed<T> {
     }
 }
 
+/// A filter on a field of a component that has been changed.
+///
+/// Like [`Changed`], but only matches entities whose component `T` had the field with index `FIELD`
+/// changed since the system last ran. Fields are marked as changed by [`Mut::field_mut`], and `T` must
+/// implement [`TrackedFields`], usually by deriving `Component` with `#[component(track_fields)]`.
+///
+/// Mutably dereferencing the component without [`Mut::field_mut`] marks all of its fields as changed.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::change_detection::FieldTicks;
+/// # use bevy_ecs::query::FieldChanged;
+/// #
+/// #[derive(Component)]
+/// #[component(track_fields)]
+/// struct Inventory {
+///     items: Vec<u32>,
+///     gold: u32,
+///     ticks: FieldTicks,
+/// }
+///
+/// fn rebuild_item_ui(query: Query<&Inventory, FieldChanged<Inventory, { Inventory::ITEMS_FIELD }>>) {
+///     for inventory in &query {
+///         // rebuild the UI of the items, but not when only the gold changed.
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(rebuild_item_ui);
+/// ```
+///
+/// [`Mut::field_mut`]: crate::change_detection::Mut::field_mut
+pub struct FieldChanged<T, const FIELD: usize>(PhantomData<T>);
+
+/// SAFETY:
+/// `fetch` accesses a single component in a readonly way, by delegating to `Ref<T>`.
+/// This is sound because `update_component_access` delegates to `Ref<T>` as well.
+unsafe impl<T: TrackedFields, const FIELD: usize> WorldQuery for FieldChanged<T, FIELD> {
+    type Item<'w> = bool;
+    type Fetch<'w> = <Ref<'static, T> as WorldQuery>::Fetch<'w>;
+    type State = ComponentId;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::Item<'wlong>) -> Self::Item<'wshort> {
+        item
+    }
+
+    fn shrink_fetch<'wlong: 'wshort, 'wshort>(fetch: Self::Fetch<'wlong>) -> Self::Fetch<'wshort> {
+        <Ref<'static, T> as WorldQuery>::shrink_fetch(fetch)
+    }
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        state: &ComponentId,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        // SAFETY: The invariants are uphold by the caller.
+        unsafe { <Ref<'static, T> as WorldQuery>::init_fetch(world, state, last_run, this_run) }
+    }
+
+    const IS_DENSE: bool = <Ref<'static, T> as WorldQuery>::IS_DENSE;
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        state: &ComponentId,
+        archetype: &'w Archetype,
+        table: &'w Table,
+    ) {
+        // SAFETY: The invariants are uphold by the caller.
+        unsafe { <Ref<'static, T> as WorldQuery>::set_archetype(fetch, state, archetype, table) }
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(fetch: &mut Self::Fetch<'w>, state: &ComponentId, table: &'w Table) {
+        // SAFETY: The invariants are uphold by the caller.
+        unsafe { <Ref<'static, T> as WorldQuery>::set_table(fetch, state, table) }
+    }
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> Self::Item<'w> {
+        // SAFETY: The invariants are uphold by the caller.
+        let item = unsafe { <Ref<'static, T> as WorldQuery>::fetch(fetch, entity, table_row) };
+        item.field_ticks().is_field_changed(
+            FIELD,
+            *item.ticks.changed,
+            item.ticks.last_run,
+            item.ticks.this_run,
+        )
+    }
+
+    #[inline]
+    fn update_component_access(state: &ComponentId, access: &mut FilteredAccess<ComponentId>) {
+        <Ref<'static, T> as WorldQuery>::update_component_access(state, access);
+    }
+
+    fn init_state(world: &mut World) -> ComponentId {
+        world.register_component::<T>()
+    }
+
+    fn get_state(components: &Components) -> Option<ComponentId> {
+        components.component_id::<T>()
+    }
+
+    fn matches_component_set(
+        &id: &ComponentId,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        set_contains_id(id)
+    }
+}
+
+// SAFETY: WorldQuery impl performs only read access on the component
+unsafe impl<T: TrackedFields, const FIELD: usize> QueryFilter for FieldChanged<T, FIELD> {
+    const IS_ARCHETYPAL: bool = false;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        fetch: &mut Self::Fetch<'_>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> bool {
+        // SAFETY: The invariants are uphold by the caller.
+        unsafe { Self::fetch(fetch, entity, table_row) }
+    }
+}
+
 /// A marker trait to indicate that the filter works at an archetype level.
 ///
 /// This is needed to implement [`ExactSizeIterator`] for
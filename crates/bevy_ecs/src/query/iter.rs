@@ -3,7 +3,7 @@ use crate::{
     archetype::{Archetype, ArchetypeEntity, Archetypes},
     bundle::Bundle,
     component::Tick,
-    entity::{Entities, Entity, EntityBorrow, EntitySet, EntitySetIterator},
+    entity::{Entities, Entity, EntityBorrow, EntityHashSet, EntitySet, EntitySetIterator},
     query::{ArchetypeFilter, DebugCheckedUnwrap, QueryState, StorageId},
     storage::{Table, TableRow, Tables},
     world::{
@@ -1145,11 +1145,51 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Debug for QueryIter<'w, 's, D, F> {
     }
 }
 
+/// The sorted order of the entities of a [`Query`](crate::system::Query), kept across system runs.
+///
+/// This is used by [`Query::iter_sorted_by_key`] and [`Query::iter_sorted_by`],
+/// and is usually stored in a [`Local`](crate::system::Local).
+/// The entities are only sorted again when the entities matched by the query changed,
+/// or when a component read by the sort lens was changed since the last sort.
+///
+/// A cache should only be used with a single query and sort key,
+/// as it cannot detect changes to either of them.
+///
+/// [`Query::iter_sorted_by_key`]: crate::system::Query::iter_sorted_by_key
+/// [`Query::iter_sorted_by`]: crate::system::Query::iter_sorted_by
+#[derive(Default, Debug)]
+pub struct QuerySortCache {
+    pub(crate) entities: Vec<Entity>,
+    pub(crate) matched: EntityHashSet,
+    pub(crate) sorted_at: Option<Tick>,
+}
+
+impl QuerySortCache {
+    /// Creates an empty cache, which will sort the entities on its first use.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the entities in the order of the last sort.
+    pub fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+
+    /// Clears the cache, so that the entities are sorted again on its next use.
+    pub fn invalidate(&mut self) {
+        self.sorted_at = None;
+    }
+}
+
 /// An [`Iterator`] over sorted query results of a [`Query`](crate::system::Query).
 ///
 /// This struct is created by the [`QueryIter::sort`], [`QueryIter::sort_unstable`],
 /// [`QueryIter::sort_by`], [`QueryIter::sort_unstable_by`], [`QueryIter::sort_by_key`],
-/// [`QueryIter::sort_unstable_by_key`], and [`QueryIter::sort_by_cached_key`] methods.
+/// [`QueryIter::sort_unstable_by_key`], and [`QueryIter::sort_by_cached_key`] methods,
+/// as well as by [`Query::iter_sorted_by_key`] and [`Query::iter_sorted_by`].
+///
+/// [`Query::iter_sorted_by_key`]: crate::system::Query::iter_sorted_by_key
+/// [`Query::iter_sorted_by`]: crate::system::Query::iter_sorted_by
 pub struct QuerySortedIter<'w, 's, D: QueryData, F: QueryFilter, I>
 where
     I: Iterator<Item = Entity>,
@@ -2950,7 +2990,7 @@ impl<T> Ord for NeutralOrd<T> {
 
 #[cfg(test)]
 mod tests {
-    use alloc::vec::Vec;
+    use alloc::{vec, vec::Vec};
     use std::println;
 
     #[allow(unused_imports)]
@@ -2968,6 +3008,66 @@ mod tests {
     #[component(storage = "SparseSet")]
     struct Sparse(usize);
 
+    #[test]
+    fn query_sorts_are_cached() {
+        use super::QuerySortCache;
+        use crate::system::{Local, Query, ResMut, Resource};
+
+        #[derive(Component)]
+        struct Initiative(u32);
+
+        #[derive(Resource, Default)]
+        struct Sorted {
+            order: Vec<Entity>,
+            key_calls: usize,
+        }
+
+        fn sort_by_initiative(
+            query: Query<(Entity, &Initiative)>,
+            mut cache: Local<QuerySortCache>,
+            mut sorted: ResMut<Sorted>,
+        ) {
+            let mut key_calls = 0;
+            let order = query
+                .iter_sorted_by_key::<&Initiative, _>(&mut cache, |initiative| {
+                    key_calls += 1;
+                    initiative.0
+                })
+                .map(|(entity, _)| entity)
+                .collect();
+            sorted.order = order;
+            sorted.key_calls = key_calls;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Sorted>();
+        let system = world.register_system(sort_by_initiative);
+        let a = world.spawn(Initiative(3)).id();
+        let b = world.spawn(Initiative(1)).id();
+        let c = world.spawn(Initiative(2)).id();
+
+        let run = |world: &mut World| {
+            world.run_system(system).unwrap();
+            let sorted = world.resource::<Sorted>();
+            (sorted.order.clone(), sorted.key_calls > 0)
+        };
+
+        assert_eq!(run(&mut world), (vec![b, c, a], true));
+        // Nothing changed, so the cached order is reused
+        assert_eq!(run(&mut world), (vec![b, c, a], false));
+
+        world.get_mut::<Initiative>(a).unwrap().0 = 0;
+        assert_eq!(run(&mut world), (vec![a, b, c], true));
+        assert_eq!(run(&mut world), (vec![a, b, c], false));
+
+        let d = world.spawn(Initiative(5)).id();
+        assert_eq!(run(&mut world), (vec![a, b, c, d], true));
+
+        world.despawn(b);
+        assert_eq!(run(&mut world), (vec![a, c, d], true));
+        assert_eq!(run(&mut world), (vec![a, c, d], false));
+    }
+
     #[allow(clippy::unnecessary_sort_by)]
     #[test]
     fn query_iter_sorts() {
@@ -3,6 +3,7 @@ use crate::{
     batching::BatchingStrategy,
     component::{ComponentId, Tick},
     entity::{Entity, EntityBorrow, EntitySet},
+    entity_disabling::DefaultQueryFilters,
     prelude::FromWorld,
     query::{
         Access, DebugCheckedUnwrap, FilteredAccess, QueryCombinationIter, QueryIter, QueryParIter,
@@ -214,7 +215,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     fn new_uninitialized(world: &mut World) -> Self {
         let fetch_state = D::init_state(world);
         let filter_state = F::init_state(world);
-        Self::from_states_uninitialized(world.id(), fetch_state, filter_state)
+        Self::from_states_uninitialized(world, fetch_state, filter_state)
     }
 
     /// Creates a new [`QueryState`] but does not populate it with the matched results from the World yet
@@ -225,7 +226,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         let fetch_state = D::get_state(world.components())?;
         let filter_state = F::get_state(world.components())?;
         Some(Self::from_states_uninitialized(
-            world.id(),
+            world,
             fetch_state,
             filter_state,
         ))
@@ -236,7 +237,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     /// `new_archetype` and its variants must be called on all of the World's archetypes before the
     /// state can return valid query results.
     fn from_states_uninitialized(
-        world_id: WorldId,
+        world: &World,
         fetch_state: <D as WorldQuery>::State,
         filter_state: <F as WorldQuery>::State,
     ) -> Self {
@@ -255,10 +256,15 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
 
         // For queries without dynamic filters the dense-ness of the query is equal to the dense-ness
         // of its static type parameters.
-        let is_dense = D::IS_DENSE && F::IS_DENSE;
+        let mut is_dense = D::IS_DENSE && F::IS_DENSE;
+
+        if let Some(default_filters) = world.get_resource::<DefaultQueryFilters>() {
+            default_filters.modify_access(&mut component_access);
+            is_dense &= default_filters.is_dense(world.components());
+        }
 
         Self {
-            world_id,
+            world_id: world.id(),
             archetype_generation: ArchetypeGeneration::initial(),
             matched_storage_ids: Vec::new(),
             is_dense,
@@ -282,15 +288,22 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         let filter_state = F::init_state(builder.world_mut());
         D::set_access(&mut fetch_state, builder.access());
 
+        // For dynamic queries the dense-ness is given by the query builder.
+        let mut is_dense = builder.is_dense();
+        let mut component_access = builder.access().clone();
+        if let Some(default_filters) = builder.world().get_resource::<DefaultQueryFilters>() {
+            default_filters.modify_access(&mut component_access);
+            is_dense &= default_filters.is_dense(builder.world().components());
+        }
+
         let mut state = Self {
             world_id: builder.world().id(),
             archetype_generation: ArchetypeGeneration::initial(),
             matched_storage_ids: Vec::new(),
-            // For dynamic queries the dense-ness is given by the query builder.
-            is_dense: builder.is_dense(),
+            is_dense,
             fetch_state,
             filter_state,
-            component_access: builder.access().clone(),
+            component_access,
             matched_tables: Default::default(),
             matched_archetypes: Default::default(),
             #[cfg(feature = "trace")]
@@ -691,6 +704,24 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         self.join_filtered::<_, (), NewD, ()>(world, other)
     }
 
+    /// Use this to combine two queries into one yielding the items of both, for the entities
+    /// matched by both. Unlike [`Self::join`], the filters of both queries are kept.
+    ///
+    /// Only the archetypes matched by both queries are iterated, so this is faster than
+    /// iterating one query and calling `get` on the other one for each entity.
+    ///
+    /// ## Panics
+    ///
+    /// Will panic if the data accessed by `D` and `OtherD` conflicts, for example if both
+    /// access the same component and one of them mutably.
+    pub fn join_items<'a, OtherD: QueryData, OtherF: QueryFilter>(
+        &self,
+        world: impl Into<UnsafeWorldCell<'a>>,
+        other: &QueryState<OtherD, OtherF>,
+    ) -> QueryState<(D, OtherD), (F, OtherF)> {
+        self.join_filtered(world, other)
+    }
+
     /// Use this to combine two queries. The data accessed will be the intersection
     /// of archetypes included in both queries.
     ///
@@ -2289,6 +2320,37 @@ mod tests {
         assert!(new_query.get(&world, entity_abc).is_err());
     }
 
+    #[test]
+    fn join_items() {
+        let mut world = World::new();
+        world.spawn(A(0));
+        world.spawn(B(1));
+        world.spawn((A(2), B(3)));
+        world.spawn((A(4), B(5), C(6)));
+
+        let query_1 = QueryState::<&A, Without<C>>::new(&mut world);
+        let query_2 = QueryState::<&mut B>::new(&mut world);
+        let mut new_query = query_1.join_items(&world, &query_2);
+
+        for (a, mut b) in new_query.iter_mut(&mut world) {
+            b.0 += a.0;
+        }
+
+        let mut query = world.query::<&B>();
+        let mut values: Vec<_> = query.iter(&world).map(|b| b.0).collect();
+        values.sort();
+        assert_eq!(values, [1, 5, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "conflicts with a previous access in this query")]
+    fn cannot_join_items_with_conflicting_access() {
+        let mut world = World::new();
+        let query_1 = QueryState::<&mut A>::new(&mut world);
+        let query_2 = QueryState::<&A>::new(&mut world);
+        let _ = query_1.join_items(&world, &query_2);
+    }
+
     #[test]
     #[should_panic(expected = "Joined state for (&bevy_ecs::query::state::tests::C, ()) \
             attempts to access terms that are not allowed by state \
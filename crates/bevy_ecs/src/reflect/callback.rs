@@ -0,0 +1,218 @@
+use alloc::borrow::Cow;
+
+use bevy_reflect::{
+    func::{
+        ArgList, DynamicFunction, FunctionError, FunctionInfo, FunctionReference, FunctionRegistry,
+        Return,
+    },
+    Reflect,
+};
+use log::warn;
+use thiserror::Error;
+
+use crate as bevy_ecs;
+use crate::{
+    component::Component,
+    entity::Entity,
+    event::Event,
+    observer::Trigger,
+    reflect::{AppFunctionRegistry, ReflectComponent},
+    system::{Query, Res},
+};
+
+/// A [`Component`] that refers to a reflected function to call in response to an interaction,
+/// such as a button being pressed or a trigger volume being entered.
+///
+/// The function is referred to by the name it was registered with in the [`AppFunctionRegistry`],
+/// so callbacks can be serialized and defined in scenes and other assets.
+///
+/// A callback can be called directly with [`Callback::call`],
+/// or automatically with its entity as the only argument
+/// by adding [`run_callback`] as an observer.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::reflect::{run_callback, AppFunctionRegistry, Callback};
+/// # use bevy_reflect::func::FunctionRegistry;
+/// #[derive(Event)]
+/// struct Pressed;
+///
+/// fn on_pressed(entity: Entity) {
+///     println!("{entity} was pressed");
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<AppFunctionRegistry>();
+/// world
+///     .resource::<AppFunctionRegistry>()
+///     .write()
+///     .register_with_name("my_game::on_pressed", on_pressed)
+///     .unwrap();
+///
+/// world.add_observer(run_callback::<Pressed>);
+///
+/// let button = world.spawn(Callback::new("my_game::on_pressed")).id();
+/// world.trigger_targets(Pressed, button);
+/// ```
+#[derive(Component, Reflect, Clone, Debug, PartialEq, Eq, Hash)]
+#[reflect(Component, Debug, PartialEq, Hash)]
+pub struct Callback(pub FunctionReference);
+
+impl Callback {
+    /// Create a new callback calling the function registered with the given name.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(FunctionReference::new(name))
+    }
+
+    /// The name of the function called by this callback.
+    pub fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    /// Returns the [`FunctionInfo`] of the function called by this callback.
+    ///
+    /// This can be used to check which arguments the function expects before calling it.
+    ///
+    /// Returns `None` if no function is registered in the given registry with the name of this callback.
+    pub fn info<'r>(&self, registry: &'r FunctionRegistry) -> Option<&'r FunctionInfo> {
+        self.0.get(registry).map(DynamicFunction::info)
+    }
+
+    /// Call the function of this callback with the given arguments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no function is registered in the given registry with the name of this callback,
+    /// or if the function call itself fails.
+    pub fn call<'a>(
+        &self,
+        registry: &FunctionRegistry,
+        args: ArgList<'a>,
+    ) -> Result<Return<'a>, CallbackError> {
+        self.0
+            .call(registry, args)
+            .ok_or_else(|| CallbackError::MissingFunction(Cow::Owned(self.name().into())))?
+            .map_err(CallbackError::from)
+    }
+
+    /// Call the function of this callback with the given entity as its only argument.
+    ///
+    /// See [`Callback::call`] for details.
+    pub fn call_for_entity(
+        &self,
+        registry: &FunctionRegistry,
+        entity: Entity,
+    ) -> Result<Return<'static>, CallbackError> {
+        self.call(registry, ArgList::new().push_owned(entity))
+    }
+}
+
+impl From<FunctionReference> for Callback {
+    fn from(function: FunctionReference) -> Self {
+        Self(function)
+    }
+}
+
+/// An error that occurs when calling a [`Callback`].
+#[derive(Error, Debug, PartialEq)]
+pub enum CallbackError {
+    /// No function is registered with the name of the callback.
+    #[error("no function is registered with the name `{0}`")]
+    MissingFunction(Cow<'static, str>),
+    /// An error occurred while calling the function.
+    #[error(transparent)]
+    FunctionError(#[from] FunctionError),
+}
+
+/// An observer that calls the [`Callback`] of the entity targeted by an event of type `E`,
+/// passing the entity as the only argument.
+///
+/// Events that don't target an entity with a [`Callback`] are ignored,
+/// and any errors returned by the callback are logged.
+///
+/// This can be added globally with [`World::add_observer`],
+/// or to specific entities with [`EntityWorldMut::observe`].
+///
+/// [`World::add_observer`]: crate::world::World::add_observer
+/// [`EntityWorldMut::observe`]: crate::world::EntityWorldMut::observe
+pub fn run_callback<E: Event>(
+    trigger: Trigger<E>,
+    callbacks: Query<&Callback>,
+    registry: Res<AppFunctionRegistry>,
+) {
+    let entity = trigger.target();
+    let Ok(callback) = callbacks.get(entity) else {
+        return;
+    };
+
+    if let Err(err) = callback.call_for_entity(&registry.read(), entity) {
+        warn!("failed to run callback of entity {entity}: {err}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::World;
+    use alloc::sync::Arc;
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    #[derive(Event)]
+    struct Pressed;
+
+    #[test]
+    fn should_run_callback_for_target() {
+        let presses = Arc::new(AtomicU32::new(0));
+
+        let mut world = World::new();
+        world.init_resource::<AppFunctionRegistry>();
+        {
+            let presses = presses.clone();
+            world
+                .resource::<AppFunctionRegistry>()
+                .write()
+                .register_with_name("press", move |_: Entity| {
+                    presses.fetch_add(1, Ordering::Relaxed);
+                })
+                .unwrap();
+        }
+        world.add_observer(run_callback::<Pressed>);
+
+        let button = world.spawn(Callback::new("press")).id();
+        let other = world.spawn_empty().id();
+
+        world.trigger_targets(Pressed, button);
+        world.trigger_targets(Pressed, other);
+        world.flush();
+        assert_eq!(presses.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn should_return_callback_errors() {
+        let mut registry = FunctionRegistry::default();
+        registry
+            .register_with_name("double", |value: i32| value * 2)
+            .unwrap();
+
+        let callback = Callback::new("double");
+        assert!(callback.info(&registry).is_some());
+
+        let value = callback
+            .call(&registry, ArgList::new().push_owned(21_i32))
+            .unwrap();
+        assert_eq!(value.unwrap_owned().try_take::<i32>().unwrap(), 42);
+
+        assert!(matches!(
+            callback.call_for_entity(&registry, Entity::PLACEHOLDER),
+            Err(CallbackError::FunctionError(FunctionError::ArgError(_)))
+        ));
+        assert_eq!(
+            Callback::new("missing")
+                .call(&registry, ArgList::new())
+                .unwrap_err(),
+            CallbackError::MissingFunction(Cow::Borrowed("missing"))
+        );
+    }
+}
@@ -0,0 +1,421 @@
+//! Components defined at runtime from a reflected schema.
+//!
+//! A dynamic component is registered with [`World::register_dynamic_component`] from a
+//! [`DynamicStruct`] describing its fields and their default values. Its values are stored as
+//! [`DynamicStruct`]s, so it needs no Rust type: modding and editor tools can create new
+//! components while the app is running.
+//!
+//! Dynamic components have a [`ComponentId`] like any other component, so they can be used in
+//! queries built with a [`QueryBuilder`](crate::query::QueryBuilder), observed, or given hooks with
+//! [`World::register_component_hooks_by_id`]. Their values are read through the
+//! [`DynamicComponents`] resource, and `bevy_scene` stores them in scenes by name.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::reflect::DynamicComponents;
+//! # use bevy_ecs::world::FilteredEntityRef;
+//! # use bevy_reflect::{DynamicStruct, GetField};
+//! let mut world = World::new();
+//!
+//! let mut schema = DynamicStruct::default();
+//! schema.insert("hit_points", 100u32);
+//! let health = world.register_dynamic_component("mod::Health", schema);
+//!
+//! let entity = world.spawn_empty().id();
+//! world.entity_mut(entity).insert_dynamic_default(health);
+//! world
+//!     .entity_mut(entity)
+//!     .get_dynamic_mut(health)
+//!     .unwrap()
+//!     .insert("hit_points", 40u32);
+//!
+//! let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+//!     .ref_id(health)
+//!     .build();
+//! let components = world.resource::<DynamicComponents>();
+//! for entity in query.iter(&world) {
+//!     let health = components.get(entity, health).unwrap();
+//!     assert_eq!(health.get_field::<u32>("hit_points"), Some(&40));
+//! }
+//! ```
+
+use crate::{
+    self as bevy_ecs,
+    change_detection::Mut,
+    component::{ComponentCloneHandler, ComponentDescriptor, ComponentId, StorageType},
+    entity::ComponentCloneCtx,
+    system::Resource,
+    world::{DeferredWorld, EntityWorldMut, FilteredEntityMut, FilteredEntityRef, World, WorldId},
+};
+use alloc::borrow::Cow;
+use bevy_ptr::OwningPtr;
+use bevy_reflect::{DynamicStruct, PartialReflect, Struct};
+use bevy_utils::HashMap;
+use core::{alloc::Layout, any::TypeId};
+
+/// Describes a component registered with [`World::register_dynamic_component`].
+pub struct DynamicComponentInfo {
+    id: ComponentId,
+    name: Cow<'static, str>,
+    schema: DynamicStruct,
+}
+
+impl DynamicComponentInfo {
+    /// Returns the [`ComponentId`] of the component.
+    pub fn id(&self) -> ComponentId {
+        self.id
+    }
+
+    /// Returns the name of the component.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the fields of the component, with their default values.
+    pub fn schema(&self) -> &DynamicStruct {
+        &self.schema
+    }
+
+    /// Returns a value of the component where every field has its default value.
+    pub fn default_value(&self) -> DynamicStruct {
+        self.schema.clone_dynamic()
+    }
+
+    /// Returns `true` if `value` has the same fields as the schema of the component, in the
+    /// same order and with the same types.
+    pub fn is_valid(&self, value: &DynamicStruct) -> bool {
+        fn type_id(field: &dyn PartialReflect) -> Option<TypeId> {
+            field
+                .get_represented_type_info()
+                .map(bevy_reflect::TypeInfo::type_id)
+        }
+
+        value.field_len() == self.schema.field_len()
+            && (0..value.field_len()).all(|index| {
+                value.name_at(index) == self.schema.name_at(index)
+                    && value.field_at(index).map(type_id)
+                        == self.schema.field_at(index).map(type_id)
+            })
+    }
+}
+
+/// A [`Resource`] holding the components of a [`World`] registered with
+/// [`World::register_dynamic_component`], whose values are stored as [`DynamicStruct`]s.
+#[derive(Resource)]
+pub struct DynamicComponents {
+    world_id: WorldId,
+    ids: HashMap<Cow<'static, str>, ComponentId>,
+    infos: HashMap<ComponentId, DynamicComponentInfo>,
+}
+
+impl DynamicComponents {
+    /// Returns the [`ComponentId`] of the dynamic component with the given name.
+    pub fn get_id(&self, name: &str) -> Option<ComponentId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Returns the description of the dynamic component with the given [`ComponentId`].
+    pub fn get_info(&self, id: ComponentId) -> Option<&DynamicComponentInfo> {
+        self.infos.get(&id)
+    }
+
+    /// Iterates over the dynamic components.
+    pub fn iter(&self) -> impl Iterator<Item = &DynamicComponentInfo> + '_ {
+        self.infos.values()
+    }
+
+    /// Returns the value of the dynamic component with the given [`ComponentId`] for `entity`.
+    ///
+    /// Returns `None` if the entity doesn't have the component, if the query the entity comes
+    /// from doesn't have read access to it, or if `id` isn't a dynamic component of the world of
+    /// the entity.
+    pub fn get<'w>(
+        &self,
+        entity: impl Into<FilteredEntityRef<'w>>,
+        id: ComponentId,
+    ) -> Option<&'w DynamicStruct> {
+        let entity = entity.into();
+        if entity.world_id() != self.world_id || !self.infos.contains_key(&id) {
+            return None;
+        }
+        let ptr = entity.get_by_id(id)?;
+        // SAFETY: The values of dynamic components of this world are `DynamicStruct`s.
+        Some(unsafe { ptr.deref::<DynamicStruct>() })
+    }
+
+    /// Returns the value of the dynamic component with the given [`ComponentId`] for `entity`,
+    /// marking it as changed.
+    ///
+    /// Returns `None` if the entity doesn't have the component, if the query the entity comes
+    /// from doesn't have write access to it, or if `id` isn't a dynamic component of the world
+    /// of the entity.
+    pub fn get_mut<'a>(
+        &self,
+        entity: &'a mut FilteredEntityMut<'_>,
+        id: ComponentId,
+    ) -> Option<Mut<'a, DynamicStruct>> {
+        if entity.world_id() != self.world_id || !self.infos.contains_key(&id) {
+            return None;
+        }
+        let value = entity.get_mut_by_id(id)?;
+        // SAFETY: The values of dynamic components of this world are `DynamicStruct`s.
+        Some(unsafe { value.with_type::<DynamicStruct>() })
+    }
+}
+
+/// # Safety
+///
+/// `ptr` must point to a valid [`DynamicStruct`].
+unsafe fn drop_dynamic_struct(ptr: OwningPtr<'_>) {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { ptr.drop_as::<DynamicStruct>() }
+}
+
+/// Clones the values of dynamic components, which can't be cloned through their type registration.
+fn clone_dynamic_component(_world: &mut DeferredWorld, ctx: &mut ComponentCloneCtx) {
+    // SAFETY: The values of dynamic components are `DynamicStruct`s, which is also the layout of
+    // the target buffer.
+    unsafe {
+        ctx.write_target_component_ptr(|source, target| {
+            let value = source.deref::<DynamicStruct>().clone_dynamic();
+            target.cast::<DynamicStruct>().write(value);
+            true
+        });
+    }
+}
+
+impl World {
+    /// Registers a component with the given name, whose fields and their default values are
+    /// described by `schema`, and returns its [`ComponentId`].
+    ///
+    /// The values of the component are [`DynamicStruct`]s, which can be inserted with
+    /// [`EntityWorldMut::insert_dynamic`] and read with [`DynamicComponents`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a dynamic component with the same name was already registered.
+    pub fn register_dynamic_component(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        schema: DynamicStruct,
+    ) -> ComponentId {
+        let name = name.into();
+        let world_id = self.id();
+        let components = self.get_resource_or_insert_with(|| DynamicComponents {
+            world_id,
+            ids: HashMap::default(),
+            infos: HashMap::default(),
+        });
+        assert_eq!(
+            components.world_id, world_id,
+            "The `DynamicComponents` resource was moved from another world."
+        );
+        assert!(
+            components.get_id(&name).is_none(),
+            "A dynamic component named `{name}` was already registered."
+        );
+
+        // SAFETY:
+        // - The drop function is only called on values of the component, which are `DynamicStruct`s.
+        // - `DynamicStruct` is `Send` and `Sync`.
+        let descriptor = unsafe {
+            ComponentDescriptor::new_with_layout(
+                name.clone(),
+                StorageType::Table,
+                Layout::new::<DynamicStruct>(),
+                Some(drop_dynamic_struct),
+                true,
+            )
+        };
+        let id = self.register_component_with_descriptor(descriptor);
+        self.get_component_clone_handlers_mut()
+            .set_component_handler(
+                id,
+                ComponentCloneHandler::custom_handler(clone_dynamic_component),
+            );
+        let mut components = self.resource_mut::<DynamicComponents>();
+        components.ids.insert(name.clone(), id);
+        components
+            .infos
+            .insert(id, DynamicComponentInfo { id, name, schema });
+        id
+    }
+}
+
+impl<'w> EntityWorldMut<'w> {
+    /// Inserts a value of the dynamic component with the given [`ComponentId`], registered with
+    /// [`World::register_dynamic_component`].
+    ///
+    /// This will overwrite any previous value of the component.
+    ///
+    /// # Panics
+    ///
+    /// - If `id` isn't a dynamic component of this world.
+    /// - If `value` doesn't match the schema of the component, see [`DynamicComponentInfo::is_valid`].
+    #[track_caller]
+    pub fn insert_dynamic(&mut self, id: ComponentId, value: DynamicStruct) -> &mut Self {
+        let info = self
+            .world()
+            .get_resource::<DynamicComponents>()
+            .filter(|components| components.world_id == self.world().id())
+            .and_then(|components| components.get_info(id))
+            .unwrap_or_else(|| panic!("{id:?} is not a dynamic component of this world."));
+        assert!(
+            info.is_valid(&value),
+            "The value doesn't match the schema of the dynamic component `{}`.",
+            info.name()
+        );
+        OwningPtr::make(value, |ptr| {
+            // SAFETY:
+            // - `id` is a component of this world.
+            // - The values of dynamic components are `DynamicStruct`s.
+            unsafe {
+                self.insert_by_id(id, ptr);
+            }
+        });
+        self
+    }
+
+    /// Inserts the default value of the dynamic component with the given [`ComponentId`].
+    ///
+    /// # Panics
+    ///
+    /// If `id` isn't a dynamic component of this world.
+    #[track_caller]
+    pub fn insert_dynamic_default(&mut self, id: ComponentId) -> &mut Self {
+        let value = self
+            .world()
+            .get_resource::<DynamicComponents>()
+            .filter(|components| components.world_id == self.world().id())
+            .and_then(|components| components.get_info(id))
+            .unwrap_or_else(|| panic!("{id:?} is not a dynamic component of this world."))
+            .default_value();
+        self.insert_dynamic(id, value)
+    }
+
+    /// Returns the value of the dynamic component with the given [`ComponentId`], if the entity
+    /// has it.
+    pub fn get_dynamic(&self, id: ComponentId) -> Option<&DynamicStruct> {
+        self.world()
+            .get_resource::<DynamicComponents>()?
+            .get(self, id)
+    }
+
+    /// Returns the value of the dynamic component with the given [`ComponentId`], if the entity
+    /// has it, marking it as changed.
+    pub fn get_dynamic_mut(&mut self, id: ComponentId) -> Option<Mut<'_, DynamicStruct>> {
+        let is_dynamic = self
+            .world()
+            .get_resource::<DynamicComponents>()
+            .is_some_and(|components| {
+                components.world_id == self.world().id() && components.get_info(id).is_some()
+            });
+        if !is_dynamic {
+            return None;
+        }
+        let value = self.get_mut_by_id(id).ok()?;
+        // SAFETY: The values of dynamic components of this world are `DynamicStruct`s.
+        Some(unsafe { value.with_type::<DynamicStruct>() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::*,
+        query::QueryBuilder,
+        world::{FilteredEntityMut, FilteredEntityRef},
+    };
+    use alloc::vec::Vec;
+    use bevy_reflect::GetField;
+
+    #[derive(Resource, Default)]
+    struct Added(u32);
+
+    fn health_schema() -> DynamicStruct {
+        let mut schema = DynamicStruct::default();
+        schema.insert("hit_points", 100u32);
+        schema.insert("regenerates", false);
+        schema
+    }
+
+    #[test]
+    fn dynamic_components() {
+        let mut world = World::new();
+        world.init_resource::<Added>();
+        let health = world.register_dynamic_component("mod::Health", health_schema());
+        assert_eq!(
+            world.resource::<DynamicComponents>().get_id("mod::Health"),
+            Some(health)
+        );
+        world
+            .register_component_hooks_by_id(health)
+            .unwrap()
+            .on_add(|mut world, _, _| world.resource_mut::<Added>().0 += 1);
+
+        let a = world.spawn_empty().insert_dynamic_default(health).id();
+        let mut value = health_schema();
+        value.insert("hit_points", 10u32);
+        let b = world.spawn_empty().insert_dynamic(health, value).id();
+        world.spawn_empty();
+        assert_eq!(world.resource::<Added>().0, 2);
+
+        let mut query = QueryBuilder::<FilteredEntityMut>::new(&mut world)
+            .mut_id(health)
+            .build();
+        world.resource_scope(|world, components: Mut<DynamicComponents>| {
+            for mut entity in query.iter_mut(world) {
+                let mut health = components.get_mut(&mut entity, health).unwrap();
+                let hit_points = health.get_field_mut::<u32>("hit_points").unwrap();
+                *hit_points += 1;
+            }
+        });
+
+        let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+            .ref_id(health)
+            .build();
+        let components = world.resource::<DynamicComponents>();
+        let mut hit_points: Vec<_> = query
+            .iter(&world)
+            .map(|entity| {
+                *components
+                    .get(entity, health)
+                    .unwrap()
+                    .get_field::<u32>("hit_points")
+                    .unwrap()
+            })
+            .collect();
+        hit_points.sort();
+        assert_eq!(hit_points, [11, 101]);
+
+        let clone = world.spawn_empty().id();
+        world.entity_mut(b).clone_with(clone, move |builder| {
+            builder.allow_by_ids([health]);
+        });
+        assert_eq!(
+            world
+                .entity_mut(clone)
+                .get_dynamic(health)
+                .unwrap()
+                .get_field::<u32>("hit_points"),
+            Some(&11)
+        );
+
+        world.entity_mut(a).remove_by_id(health);
+        assert!(world.entity_mut(a).get_dynamic(health).is_none());
+        assert!(world.entity_mut(b).get_dynamic(health).is_some());
+        world.despawn(b);
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't match the schema of the dynamic component `mod::Health`")]
+    fn invalid_dynamic_component_value() {
+        let mut world = World::new();
+        let health = world.register_dynamic_component("mod::Health", health_schema());
+        let mut value = DynamicStruct::default();
+        value.insert("hit_points", 1.0f32);
+        value.insert("regenerates", false);
+        world.spawn_empty().insert_dynamic(health, value);
+    }
+}
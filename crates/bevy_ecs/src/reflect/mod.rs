@@ -13,7 +13,10 @@ use bevy_reflect::{
 };
 
 mod bundle;
+#[cfg(feature = "reflect_functions")]
+mod callback;
 mod component;
+mod dynamic_component;
 mod entity_commands;
 mod from_world;
 mod map_entities;
@@ -21,7 +24,10 @@ mod resource;
 mod visit_entities;
 
 pub use bundle::{ReflectBundle, ReflectBundleFns};
+#[cfg(feature = "reflect_functions")]
+pub use callback::{run_callback, Callback, CallbackError};
 pub use component::{ReflectComponent, ReflectComponentFns};
+pub use dynamic_component::{DynamicComponentInfo, DynamicComponents};
 pub use entity_commands::ReflectCommandExt;
 pub use from_world::{ReflectFromWorld, ReflectFromWorldFns};
 pub use map_entities::ReflectMapEntities;
@@ -52,7 +58,10 @@ impl DerefMut for AppTypeRegistry {
 /// A [`Resource`] storing [`FunctionRegistry`] for
 /// function registrations relevant to a whole app.
 ///
+/// Functions registered here can be referred to from scenes and assets using a [`FunctionReference`].
+///
 /// [`FunctionRegistry`]: bevy_reflect::func::FunctionRegistry
+/// [`FunctionReference`]: bevy_reflect::func::FunctionReference
 #[cfg(feature = "reflect_functions")]
 #[derive(Resource, Clone, Default)]
 pub struct AppFunctionRegistry(pub bevy_reflect::func::FunctionRegistryArc);
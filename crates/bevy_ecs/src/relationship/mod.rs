@@ -0,0 +1,398 @@
+//! Entity relationships, which allow components to point to other entities
+//! while keeping an automatically updated index of the entities pointing back.
+//!
+//! A relationship is made of two components:
+//! - A [`Relationship`] component on the _source_ entity, which points to a single _target_ entity.
+//! - A [`RelationshipTarget`] component on the target entity,
+//!   which contains the list of every source entity pointing to it.
+//!
+//! Only the [`Relationship`] component should be inserted or removed by users.
+//! Its component hooks keep the [`RelationshipTarget`] component in sync:
+//! - Inserting the relationship adds the source to the target's [`RelationshipTarget`],
+//!   inserting that component if necessary.
+//! - Removing the relationship, or despawning the source, removes the source from the target's
+//!   [`RelationshipTarget`], removing that component once it no longer has any sources.
+//! - Despawning the target, or removing its [`RelationshipTarget`], removes the relationship from
+//!   every source, or despawns them if the relationship was declared with `linked_spawn`.
+//!
+//! Both components are usually implemented using the [`Component`] derive:
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! /// The entity liked by this entity.
+//! #[derive(Component)]
+//! #[relationship(relationship_target = LikedBy)]
+//! struct Likes(Entity);
+//!
+//! /// The entities liking this entity.
+//! #[derive(Component)]
+//! #[relationship_target(relationship = Likes)]
+//! struct LikedBy(Vec<Entity>);
+//!
+//! let mut world = World::new();
+//! let alice = world.spawn_empty().id();
+//! let bob = world.spawn(Likes(alice)).id();
+//! let carol = world.spawn(Likes(alice)).id();
+//! world.flush();
+//!
+//! assert_eq!(world.get::<LikedBy>(alice).unwrap().collection(), &[bob, carol]);
+//!
+//! // Relationships are regular components, and can be queried as such
+//! let mut likers = world.query_filtered::<Entity, With<Likes>>();
+//! assert_eq!(likers.iter(&world).count(), 2);
+//!
+//! world.despawn(bob);
+//! assert_eq!(world.get::<LikedBy>(alice).unwrap().collection(), &[carol]);
+//!
+//! world.despawn(alice);
+//! world.flush();
+//! assert!(world.get::<Likes>(carol).is_none());
+//! ```
+//!
+//! The relationship component must have a single [`Entity`] field, and the target component
+//! a single `Vec<Entity>` field.
+//! Relationship components are [immutable](crate::component::Immutable),
+//! as their hooks only run when they're inserted or removed,
+//! so changing the target of a relationship is done by inserting it again.
+
+mod related_methods;
+mod relationship_query;
+
+use crate::{
+    component::{Component, ComponentId, Immutable, Mutable},
+    entity::Entity,
+    world::{DeferredWorld, World},
+};
+use alloc::vec::Vec;
+use log::warn;
+
+/// A [`Component`] on a _source_ entity pointing to a _target_ entity,
+/// which keeps the [`RelationshipTarget`] of the target entity in sync.
+///
+/// This is usually implemented using `#[derive(Component)]` together with the
+/// `#[relationship(relationship_target = T)]` attribute.
+/// See the [module docs](crate::relationship) for more information.
+pub trait Relationship: Component<Mutability = Immutable> + Sized {
+    /// The component on the target entity containing every source entity pointing to it.
+    type RelationshipTarget: RelationshipTarget<Relationship = Self>;
+
+    /// Returns the target entity of this relationship.
+    fn get(&self) -> Entity;
+
+    /// Creates a relationship pointing to the given target entity.
+    fn from(entity: Entity) -> Self;
+
+    /// The `on_insert` component hook, adding the source entity to the target's [`RelationshipTarget`].
+    fn on_insert(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target = world.entity(entity).get::<Self>().unwrap().get();
+        if target == entity {
+            warn!(
+                "the {} relationship on entity {entity} points to itself, and will be removed",
+                core::any::type_name::<Self>()
+            );
+            world.commands().entity(entity).remove::<Self>();
+            return;
+        }
+
+        let Ok(mut target_entity) = world.get_entity_mut(target) else {
+            warn!(
+                "the {} relationship on entity {entity} points to entity {target}, which does not exist, and will be removed",
+                core::any::type_name::<Self>()
+            );
+            world.commands().entity(entity).remove::<Self>();
+            return;
+        };
+
+        if let Some(mut relationship_target) = target_entity.get_mut::<Self::RelationshipTarget>() {
+            relationship_target.collection_mut_risky().push(entity);
+            return;
+        }
+
+        // Several sources may point to the same new target before commands are applied,
+        // so the target component is inserted by the first of them and extended by the others.
+        world.commands().queue(move |world: &mut World| {
+            if world.get::<Self>(entity).map(Self::get) != Some(target) {
+                return;
+            }
+            let Ok(mut target_entity) = world.get_entity_mut(target) else {
+                return;
+            };
+            match target_entity.get_mut::<Self::RelationshipTarget>() {
+                Some(mut relationship_target) => {
+                    if !relationship_target.collection().contains(&entity) {
+                        relationship_target.collection_mut_risky().push(entity);
+                    }
+                }
+                None => {
+                    target_entity.insert(Self::RelationshipTarget::from_collection_risky(
+                        alloc::vec![entity],
+                    ));
+                }
+            }
+        });
+    }
+
+    /// The `on_replace` component hook, removing the source entity from the target's [`RelationshipTarget`].
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target = world.entity(entity).get::<Self>().unwrap().get();
+        let Ok(mut target_entity) = world.get_entity_mut(target) else {
+            return;
+        };
+        let Some(mut relationship_target) = target_entity.get_mut::<Self::RelationshipTarget>()
+        else {
+            return;
+        };
+
+        relationship_target
+            .collection_mut_risky()
+            .retain(|source| *source != entity);
+        if relationship_target.collection().is_empty() {
+            world.commands().queue(move |world: &mut World| {
+                let Ok(mut target_entity) = world.get_entity_mut(target) else {
+                    return;
+                };
+                if target_entity
+                    .get::<Self::RelationshipTarget>()
+                    .is_some_and(|relationship_target| relationship_target.collection().is_empty())
+                {
+                    target_entity.remove::<Self::RelationshipTarget>();
+                }
+            });
+        }
+    }
+}
+
+/// A [`Component`] on a _target_ entity containing every _source_ entity whose [`Relationship`]
+/// points to it.
+///
+/// This component is maintained by the hooks of the [`Relationship`],
+/// and should not be inserted or modified directly.
+///
+/// This is usually implemented using `#[derive(Component)]` together with the
+/// `#[relationship_target(relationship = R)]` attribute,
+/// optionally adding `linked_spawn` to despawn the sources when the target is despawned.
+/// See the [module docs](crate::relationship) for more information.
+pub trait RelationshipTarget: Component<Mutability = Mutable> + Sized {
+    /// The component on the source entities pointing to this entity.
+    type Relationship: Relationship<RelationshipTarget = Self>;
+
+    /// Whether the source entities should be despawned along with the target entity,
+    /// instead of only having their [`Relationship`] removed.
+    const LINKED_SPAWN: bool;
+
+    /// Returns the source entities pointing to this entity.
+    fn collection(&self) -> &Vec<Entity>;
+
+    /// Returns the source entities pointing to this entity, mutably.
+    ///
+    /// Modifying the collection won't update the [`Relationship`] of the sources,
+    /// so this should only be used to keep the collection in sync with them.
+    fn collection_mut_risky(&mut self) -> &mut Vec<Entity>;
+
+    /// Creates the component from a collection of source entities.
+    ///
+    /// This won't insert the [`Relationship`] on the sources,
+    /// so this should only be used with entities already pointing to the target.
+    fn from_collection_risky(collection: Vec<Entity>) -> Self;
+
+    /// Returns an iterator over the source entities pointing to this entity.
+    fn iter(&self) -> core::iter::Copied<core::slice::Iter<'_, Entity>> {
+        self.collection().iter().copied()
+    }
+
+    /// Returns the number of source entities pointing to this entity.
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns `true` if no source entities point to this entity.
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+
+    /// The `on_replace` component hook, removing the [`Relationship`] of every source entity,
+    /// or despawning them when [`LINKED_SPAWN`](Self::LINKED_SPAWN) is set.
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let sources = world
+            .entity(entity)
+            .get::<Self>()
+            .unwrap()
+            .collection()
+            .clone();
+        for source in sources {
+            world.commands().queue(move |world: &mut World| {
+                if world
+                    .get::<Self::Relationship>(source)
+                    .map(Relationship::get)
+                    != Some(entity)
+                {
+                    return;
+                }
+                let Ok(mut source_entity) = world.get_entity_mut(source) else {
+                    return;
+                };
+                if Self::LINKED_SPAWN {
+                    source_entity.despawn();
+                } else {
+                    source_entity.remove::<Self::Relationship>();
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        entity::Entity,
+        query::With,
+        relationship::{Relationship, RelationshipTarget},
+        system::{Query, RunSystemOnce},
+        world::World,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component, Debug, PartialEq)]
+    #[relationship(relationship_target = LikedBy)]
+    struct Likes(Entity);
+
+    #[derive(Component, Debug)]
+    #[relationship_target(relationship = Likes)]
+    struct LikedBy(Vec<Entity>);
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Owned)]
+    struct OwnedBy {
+        owner: Entity,
+    }
+
+    #[derive(Component)]
+    #[relationship_target(relationship = OwnedBy, linked_spawn)]
+    struct Owned {
+        items: Vec<Entity>,
+    }
+
+    fn liked_by(world: &World, entity: Entity) -> Option<Vec<Entity>> {
+        world
+            .get::<LikedBy>(entity)
+            .map(|liked_by| liked_by.iter().collect())
+    }
+
+    #[test]
+    fn should_index_relationship_sources() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(Likes(a)).id();
+        let c = world.spawn(Likes(a)).id();
+        world.flush();
+        assert_eq!(liked_by(&world, a), Some(vec![b, c]));
+
+        let d = world.spawn(Likes(a)).id();
+        assert_eq!(liked_by(&world, a), Some(vec![b, c, d]));
+
+        // Changing the target moves the source between indexes
+        world.entity_mut(c).insert(Likes(b));
+        world.flush();
+        assert_eq!(liked_by(&world, a), Some(vec![b, d]));
+        assert_eq!(liked_by(&world, b), Some(vec![c]));
+
+        world.entity_mut(b).remove::<Likes>();
+        world.despawn(d);
+        world.flush();
+        assert_eq!(liked_by(&world, a), None);
+        assert_eq!(liked_by(&world, b), Some(vec![c]));
+    }
+
+    #[test]
+    fn should_clean_up_on_despawn() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(Likes(a)).id();
+        let c = world.spawn(Likes(a)).id();
+        world.flush();
+
+        world.despawn(a);
+        world.flush();
+        assert!(world.get::<Likes>(b).is_none());
+        assert!(world.get::<Likes>(c).is_none());
+        assert!(world.get_entity(b).is_ok());
+    }
+
+    #[test]
+    fn should_despawn_linked_sources() {
+        let mut world = World::new();
+        let owner = world.spawn_empty().id();
+        let sword = world.spawn(OwnedBy { owner }).id();
+        let shield = world.spawn(OwnedBy { owner }).id();
+        world.flush();
+        assert_eq!(
+            world.get::<Owned>(owner).unwrap().items,
+            vec![sword, shield]
+        );
+        assert_eq!(world.get::<OwnedBy>(sword).unwrap().get(), owner);
+
+        world.despawn(owner);
+        world.flush();
+        assert!(world.get_entity(sword).is_err());
+        assert!(world.get_entity(shield).is_err());
+    }
+
+    #[test]
+    fn should_reject_invalid_targets() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        world.entity_mut(a).insert(Likes(a));
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+        let b = world.spawn(Likes(missing)).id();
+        world.flush();
+
+        assert!(world.get::<Likes>(a).is_none());
+        assert!(world.get::<LikedBy>(a).is_none());
+        assert!(world.get::<Likes>(b).is_none());
+    }
+
+    #[test]
+    fn should_query_relationships() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(Likes(a)).id();
+        let c = world.spawn(Likes(a)).id();
+        world.flush();
+
+        world
+            .run_system_once(
+                move |likes: Query<&Likes>,
+                      liked_by: Query<&LikedBy>,
+                      likers: Query<Entity, With<Likes>>| {
+                    assert_eq!(likes.related(b), Some(a));
+                    assert_eq!(likes.related(a), None);
+                    assert_eq!(
+                        liked_by.relationship_sources(a).collect::<Vec<_>>(),
+                        vec![b, c]
+                    );
+                    assert_eq!(likers.iter().count(), 2);
+                },
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn should_add_related_entities() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn_empty().id();
+        world.entity_mut(a).add_related::<Likes>(&[b, c]);
+        world.flush();
+        assert_eq!(liked_by(&world, a), Some(vec![b, c]));
+        assert_eq!(world.get::<Likes>(b), Some(&Likes(a)));
+
+        let d = world.spawn_empty().id();
+        world.commands().entity(a).add_related::<Likes>(&[d]);
+        world.flush();
+        assert_eq!(liked_by(&world, a), Some(vec![b, c, d]));
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    entity::Entity,
+    relationship::Relationship,
+    system::{Commands, EntityCommands},
+    world::EntityWorldMut,
+};
+
+impl<'w> EntityWorldMut<'w> {
+    /// Makes the given entities point to this entity through the [`Relationship`] `R`,
+    /// replacing any existing relationship of that type on them.
+    pub fn add_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let id = self.id();
+        self.world_scope(|world| {
+            for related in related {
+                world.entity_mut(*related).insert(R::from(id));
+            }
+        });
+        self
+    }
+}
+
+impl<'a> EntityCommands<'a> {
+    /// Makes the given entities point to this entity through the [`Relationship`] `R`,
+    /// replacing any existing relationship of that type on them.
+    pub fn add_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let id = self.id();
+        let mut commands: Commands = self.commands();
+        for related in related {
+            commands.entity(*related).insert(R::from(id));
+        }
+        self
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{
+    entity::Entity,
+    query::{QueryData, QueryFilter, WorldQuery},
+    relationship::{Relationship, RelationshipTarget},
+    system::Query,
+};
+
+impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
+    /// Returns the target entity of the [`Relationship`] on the given entity, if it matches the query.
+    pub fn related<R: Relationship>(&'w self, entity: Entity) -> Option<Entity>
+    where
+        <D as QueryData>::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+    {
+        self.get(entity).ok().map(R::get)
+    }
+
+    /// Returns an iterator over the source entities pointing to the given entity
+    /// through its [`RelationshipTarget`], if it matches the query.
+    pub fn relationship_sources<S: RelationshipTarget>(
+        &'w self,
+        entity: Entity,
+    ) -> impl Iterator<Item = Entity> + 'w
+    where
+        <D as QueryData>::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+    {
+        self.get(entity)
+            .into_iter()
+            .flat_map(RelationshipTarget::iter)
+    }
+}
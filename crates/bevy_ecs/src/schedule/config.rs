@@ -2,7 +2,7 @@ use alloc::{boxed::Box, vec, vec::Vec};
 use variadics_please::all_tuples;
 
 use crate::{
-    result::Result,
+    result::{Error, Result},
     schedule::{
         condition::{BoxedCondition, Condition},
         graph::{Ambiguity, Dependency, DependencyKind, GraphInfo},
@@ -10,6 +10,7 @@ use crate::{
         Chain,
     },
     system::{BoxedSystem, InfallibleSystemWrapper, IntoSystem, ScheduleSystem, System},
+    world::World,
 };
 
 fn new_condition<M>(condition: impl Condition<M>) -> BoxedCondition {
@@ -46,6 +47,8 @@ pub struct NodeConfig<T> {
     /// Hierarchy and dependency metadata for this node
     pub(crate) graph_info: GraphInfo,
     pub(crate) conditions: Vec<BoxedCondition>,
+    /// Handles the errors returned by the node, if it is a system.
+    pub(crate) error_handler: Option<fn(&mut World, Error)>,
 }
 
 /// Stores configuration for a single system.
@@ -80,8 +83,22 @@ impl SystemConfigs {
                 ..Default::default()
             },
             conditions: Vec::new(),
+            error_handler: None,
         })
     }
+
+    fn on_error_inner(&mut self, error_handler: fn(&mut World, Error)) {
+        match self {
+            Self::NodeConfig(config) => {
+                config.error_handler = Some(error_handler);
+            }
+            Self::Configs { configs, .. } => {
+                for config in configs {
+                    config.on_error_inner(error_handler);
+                }
+            }
+        }
+    }
 }
 
 impl<T> NodeConfigs<T> {
@@ -446,6 +463,27 @@ where
     fn chain_ignore_deferred(self) -> SystemConfigs {
         self.into_configs().chain_ignore_deferred()
     }
+
+    /// Handles the errors returned by these systems with `error_handler` instead of the
+    /// [`SystemErrorHandler`](crate::system::error_handler::SystemErrorHandler) of the world.
+    ///
+    /// The error is given to the handler as a
+    /// [`SystemError`](crate::system::error_handler::SystemError) once the schedule has finished.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::system::error_handler;
+    /// # let mut schedule = Schedule::default();
+    /// fn load_save() -> bevy_ecs::result::Result {
+    ///     Err("the save is corrupted".into())
+    /// }
+    ///
+    /// schedule.add_systems(load_save.on_error(error_handler::warn()));
+    /// # schedule.run(&mut World::new());
+    /// ```
+    fn on_error(self, error_handler: fn(&mut World, Error)) -> SystemConfigs {
+        self.into_configs().on_error(error_handler)
+    }
 }
 
 impl IntoSystemConfigs<()> for SystemConfigs {
@@ -517,6 +555,11 @@ impl IntoSystemConfigs<()> for SystemConfigs {
     fn chain_ignore_deferred(self) -> Self {
         self.chain_ignore_deferred_inner()
     }
+
+    fn on_error(mut self, error_handler: fn(&mut World, Error)) -> Self {
+        self.on_error_inner(error_handler);
+        self
+    }
 }
 
 /// Marker component to allow for conflicting implementations of [`IntoSystemConfigs`]
@@ -602,6 +645,7 @@ impl SystemSetConfig {
             node: set,
             graph_info: GraphInfo::default(),
             conditions: Vec::new(),
+            error_handler: None,
         }
     }
 }
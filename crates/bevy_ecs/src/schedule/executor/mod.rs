@@ -4,7 +4,7 @@ mod simple;
 mod single_threaded;
 
 use alloc::{borrow::Cow, vec, vec::Vec};
-use core::any::TypeId;
+use core::{any::TypeId, time::Duration};
 
 pub use self::{simple::SimpleExecutor, single_threaded::SingleThreadedExecutor};
 
@@ -18,8 +18,8 @@ use crate::{
     component::{ComponentId, Tick},
     prelude::{IntoSystemSet, SystemSet},
     query::Access,
-    result::Result,
-    schedule::{BoxedCondition, InternedSystemSet, NodeId, SystemTypeSet},
+    result::{Error, Result},
+    schedule::{BoxedCondition, InternedSystemSet, NodeId, ScheduleRunHooks, SystemTypeSet},
     system::{ScheduleSystem, System, SystemIn},
     world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
 };
@@ -98,6 +98,20 @@ pub struct SystemSchedule {
     ///
     /// If a set doesn't run because of its conditions, this is used to skip all systems in it.
     pub(super) systems_in_sets_with_conditions: Vec<FixedBitSet>,
+    /// Whether executors should measure the time spent running each system.
+    pub(super) record_system_times: bool,
+    /// Indexed by system node id.
+    /// Time spent running the system during the last run, if it ran and times were recorded.
+    pub(super) system_times: Vec<Option<Duration>>,
+    /// The [`SystemRunHooks`](super::SystemRunHooks) to call around each system during the current run.
+    pub(super) run_hooks: Option<ScheduleRunHooks>,
+    /// Indexed by system node id.
+    /// Handles the errors returned by the system, overriding the default error handler.
+    pub(super) system_error_handlers: Vec<Option<fn(&mut World, Error)>>,
+    /// Handles the errors returned by systems without their own error handler during the current run.
+    pub(super) default_error_handler: Option<fn(&mut World, Error)>,
+    /// The errors returned by systems during the current run, along with the index of the system.
+    pub(super) system_errors: Vec<(usize, Error)>,
 }
 
 impl SystemSchedule {
@@ -113,8 +127,58 @@ impl SystemSchedule {
             system_dependents: Vec::new(),
             sets_with_conditions_of_systems: Vec::new(),
             systems_in_sets_with_conditions: Vec::new(),
+            record_system_times: false,
+            system_times: Vec::new(),
+            run_hooks: None,
+            system_error_handlers: Vec::new(),
+            default_error_handler: None,
+            system_errors: Vec::new(),
         }
     }
+
+    /// Returns the handler of the errors returned by the system at `index`, if any.
+    pub(super) fn error_handler(&self, index: usize) -> Option<fn(&mut World, Error)> {
+        self.system_error_handlers
+            .get(index)
+            .copied()
+            .flatten()
+            .or(self.default_error_handler)
+    }
+
+    /// Stores the `error` returned by the system at `index` to be handled once the schedule has
+    /// run, or panics if the system has no error handler.
+    pub(super) fn report_error(&mut self, index: usize, error: Error) {
+        if self.error_handler(index).is_none() {
+            panic!(
+                "Encountered an error in system `{}`: {:?}",
+                &*self.systems[index].name(),
+                error
+            );
+        }
+        self.system_errors.push((index, error));
+    }
+
+    /// Stores the time spent running the system at `index`, if system times are being recorded.
+    pub(super) fn set_system_time(&mut self, index: usize, elapsed: Option<Duration>) {
+        if let Some(time) = self.system_times.get_mut(index) {
+            *time = elapsed;
+        }
+    }
+
+    /// Prepares the schedule for a run, enabling the recording of system times if `record` is `true`.
+    pub(super) fn prepare_system_times(&mut self, record: bool) {
+        self.record_system_times = record;
+        self.system_times.clear();
+        if record {
+            self.system_times.resize(self.systems.len(), None);
+        }
+    }
+
+    /// Returns whether executors should measure the time spent running each system, either to
+    /// record system times or to give it to the run hooks.
+    pub(super) fn measure_system_times(&self) -> bool {
+        self.record_system_times || self.run_hooks.is_some()
+    }
 }
 
 /// See [`ApplyDeferred`].
@@ -249,6 +313,22 @@ impl IntoSystemSet<()> for ApplyDeferred {
     }
 }
 
+/// Runs `f`, measuring how long it took if `record` is `true` and time can be measured.
+#[inline]
+pub(super) fn timed<R>(record: bool, f: impl FnOnce() -> R) -> (R, Option<Duration>) {
+    #[cfg(feature = "std")]
+    if record {
+        let start = bevy_utils::Instant::now();
+        let result = f();
+        return (result, Some(start.elapsed()));
+    }
+
+    #[cfg(not(feature = "std"))]
+    let _ = record;
+
+    (f(), None)
+}
+
 /// These functions hide the bottom of the callstack from `RUST_BACKTRACE=1` (assuming the default panic handler is used).
 ///
 /// The full callstack will still be visible with `RUST_BACKTRACE=full`.
@@ -270,6 +350,8 @@ mod __rust_begin_short_backtrace {
     /// See `System::run_unsafe`.
     #[inline(never)]
     pub(super) unsafe fn run_unsafe(system: &mut ScheduleSystem, world: UnsafeWorldCell) -> Result {
+        #[cfg(feature = "std")]
+        let _guard = crate::schedule::tracking::SystemGuard::enter(system);
         let result = system.run_unsafe((), world);
         black_box(());
         result
@@ -291,6 +373,8 @@ mod __rust_begin_short_backtrace {
 
     #[inline(never)]
     pub(super) fn run(system: &mut ScheduleSystem, world: &mut World) -> Result {
+        #[cfg(feature = "std")]
+        let _guard = crate::schedule::tracking::SystemGuard::enter(system);
         let result = system.run((), world);
         black_box(());
         result
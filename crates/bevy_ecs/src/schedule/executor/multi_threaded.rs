@@ -2,7 +2,7 @@ use alloc::{boxed::Box, vec::Vec};
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
 use bevy_utils::{default, syncunsafecell::SyncUnsafeCell};
 use concurrent_queue::ConcurrentQueue;
-use core::{any::Any, panic::AssertUnwindSafe};
+use core::{any::Any, panic::AssertUnwindSafe, time::Duration};
 use fixedbitset::FixedBitSet;
 use std::{
     eprintln,
@@ -22,14 +22,18 @@ use crate::{
     archetype::ArchetypeComponentId,
     prelude::Resource,
     query::Access,
-    schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
+    result::{Error, Result},
+    schedule::{
+        is_apply_deferred, BoxedCondition, ExecutorKind, ScheduleRunHooks, SystemExecutor,
+        SystemSchedule,
+    },
     system::ScheduleSystem,
     world::{unsafe_world_cell::UnsafeWorldCell, World},
 };
 
 use crate as bevy_ecs;
 
-use super::__rust_begin_short_backtrace;
+use super::{__rust_begin_short_backtrace, timed};
 
 /// Borrowed data used by the [`MultiThreadedExecutor`].
 struct Environment<'env, 'sys> {
@@ -37,6 +41,10 @@ struct Environment<'env, 'sys> {
     systems: &'sys [SyncUnsafeCell<ScheduleSystem>],
     conditions: SyncUnsafeCell<Conditions<'sys>>,
     world_cell: UnsafeWorldCell<'env>,
+    measure_system_times: bool,
+    run_hooks: Option<&'sys ScheduleRunHooks>,
+    system_error_handlers: &'sys [Option<fn(&mut World, Error)>],
+    has_default_error_handler: bool,
 }
 
 struct Conditions<'a> {
@@ -52,6 +60,7 @@ impl<'env, 'sys> Environment<'env, 'sys> {
         schedule: &'sys mut SystemSchedule,
         world: &'env mut World,
     ) -> Self {
+        let measure_system_times = schedule.measure_system_times();
         Environment {
             executor,
             systems: SyncUnsafeCell::from_mut(schedule.systems.as_mut_slice()).as_slice_of_cells(),
@@ -62,8 +71,30 @@ impl<'env, 'sys> Environment<'env, 'sys> {
                 systems_in_sets_with_conditions: &schedule.systems_in_sets_with_conditions,
             }),
             world_cell: world.as_unsafe_world_cell(),
+            measure_system_times,
+            run_hooks: schedule.run_hooks.as_ref(),
+            system_error_handlers: &schedule.system_error_handlers,
+            has_default_error_handler: schedule.default_error_handler.is_some(),
         }
     }
+
+    /// Returns the error returned by the system at `index`, to be handled once the schedule has
+    /// run, or panics if the system has no error handler.
+    fn take_error(&self, index: usize, system: &ScheduleSystem, result: Result) -> Option<Error> {
+        let error = result.err()?;
+        let has_error_handler = self
+            .system_error_handlers
+            .get(index)
+            .is_some_and(Option::is_some);
+        if !has_error_handler && !self.has_default_error_handler {
+            panic!(
+                "Encountered an error in system `{}`: {:?}",
+                &*system.name(),
+                error
+            );
+        }
+        Some(error)
+    }
 }
 
 /// Per-system data used by the [`MultiThreadedExecutor`].
@@ -82,6 +113,10 @@ struct SystemTaskMetadata {
 /// The result of running a system that is sent across a channel.
 struct SystemResult {
     system_index: usize,
+    /// Time spent running the system, if system times are being recorded.
+    elapsed: Option<Duration>,
+    /// The error returned by the system, if it has an error handler.
+    error: Option<Error>,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
@@ -128,6 +163,10 @@ pub struct ExecutorState {
     completed_systems: FixedBitSet,
     /// Systems that have run but have not had their buffers applied.
     unapplied_systems: FixedBitSet,
+    /// Time spent running each system, if system times are being recorded.
+    system_times: Vec<Option<Duration>>,
+    /// The errors returned by systems, along with the index of the system.
+    system_errors: Vec<(usize, Error)>,
 }
 
 /// References to data required by the executor.
@@ -195,6 +234,10 @@ impl SystemExecutor for MultiThreadedExecutor {
             return;
         }
         state.num_running_systems = 0;
+        state.system_times.clear();
+        if schedule.record_system_times {
+            state.system_times.resize(schedule.systems.len(), None);
+        }
         state
             .num_dependencies_remaining
             .clone_from(&schedule.system_dependencies);
@@ -249,6 +292,7 @@ impl SystemExecutor for MultiThreadedExecutor {
             }
             state.unapplied_systems.clear();
         }
+        schedule.system_errors.append(&mut state.system_errors);
 
         // check to see if there was a panic
         let payload = self.panic_payload.get_mut().unwrap();
@@ -258,6 +302,7 @@ impl SystemExecutor for MultiThreadedExecutor {
 
         debug_assert!(state.ready_systems.is_clear());
         debug_assert!(state.running_systems.is_clear());
+        schedule.system_times.clone_from(&state.system_times);
         state.active_access.clear();
         state.evaluated_sets.clear();
         state.skipped_systems.clear();
@@ -273,14 +318,23 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
     fn system_completed(
         &self,
         system_index: usize,
-        res: Result<(), Box<dyn Any + Send>>,
+        res: core::result::Result<Option<Error>, Box<dyn Any + Send>>,
+        elapsed: Option<Duration>,
         system: &ScheduleSystem,
     ) {
+        let (error, res) = match res {
+            Ok(error) => (error, Ok(())),
+            Err(payload) => (None, Err(payload)),
+        };
         // tell the executor that the system finished
         self.environment
             .executor
             .system_completion
-            .push(SystemResult { system_index })
+            .push(SystemResult {
+                system_index,
+                elapsed,
+                error,
+            })
             .unwrap_or_else(|error| unreachable!("{}", error));
         if let Err(payload) = res {
             eprintln!("Encountered a panic in system `{}`!", &*system.name());
@@ -354,6 +408,8 @@ impl ExecutorState {
             skipped_systems: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
+            system_times: Vec::new(),
+            system_errors: Vec::new(),
         }
     }
 
@@ -602,26 +658,29 @@ impl ExecutorState {
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
-            let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
-                // SAFETY:
-                // - The caller ensures that we have permission to
-                // access the world data used by the system.
-                // - `update_archetype_component_access` has been called.
-                unsafe {
-                    // TODO: implement an error-handling API instead of panicking.
-                    if let Err(err) = __rust_begin_short_backtrace::run_unsafe(
-                        system,
-                        context.environment.world_cell,
-                    ) {
-                        panic!(
-                            "Encountered an error in system `{}`: {:?}",
-                            &*system.name(),
-                            err
-                        );
+            let change_tick = context.environment.world_cell.change_tick();
+            if let Some(hooks) = context.environment.run_hooks {
+                hooks.before(system, change_tick);
+            }
+            let (res, elapsed) = timed(context.environment.measure_system_times, || {
+                std::panic::catch_unwind(AssertUnwindSafe(|| {
+                    // SAFETY:
+                    // - The caller ensures that we have permission to
+                    // access the world data used by the system.
+                    // - `update_archetype_component_access` has been called.
+                    let result = unsafe {
+                        __rust_begin_short_backtrace::run_unsafe(
+                            system,
+                            context.environment.world_cell,
+                        )
                     };
-                };
-            }));
-            context.system_completed(system_index, res, system);
+                    context.environment.take_error(system_index, system, result)
+                }))
+            });
+            if let (Some(hooks), Ok(_)) = (context.environment.run_hooks, &res) {
+                hooks.after(system, change_tick, elapsed);
+            }
+            context.system_completed(system_index, res, elapsed, system);
         };
 
         self.active_access
@@ -651,8 +710,11 @@ impl ExecutorState {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
-                let res = apply_deferred(&unapplied_systems, context.environment.systems, world);
-                context.system_completed(system_index, res, system);
+                let (res, elapsed) = timed(context.environment.measure_system_times, || {
+                    apply_deferred(&unapplied_systems, context.environment.systems, world)
+                        .map(|()| None)
+                });
+                context.system_completed(system_index, res, elapsed, system);
             };
 
             context.scope.spawn_on_scope(task);
@@ -661,17 +723,20 @@ impl ExecutorState {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
-                let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
-                    // TODO: implement an error-handling API instead of panicking.
-                    if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
-                        panic!(
-                            "Encountered an error in system `{}`: {:?}",
-                            &*system.name(),
-                            err
-                        );
-                    };
-                }));
-                context.system_completed(system_index, res, system);
+                let change_tick = world.change_tick();
+                if let Some(hooks) = context.environment.run_hooks {
+                    hooks.before(system, change_tick);
+                }
+                let (res, elapsed) = timed(context.environment.measure_system_times, || {
+                    std::panic::catch_unwind(AssertUnwindSafe(|| {
+                        let result = __rust_begin_short_backtrace::run(system, world);
+                        context.environment.take_error(system_index, system, result)
+                    }))
+                });
+                if let (Some(hooks), Ok(_)) = (context.environment.run_hooks, &res) {
+                    hooks.after(system, change_tick, elapsed);
+                }
+                context.system_completed(system_index, res, elapsed, system);
             };
 
             context.scope.spawn_on_scope(task);
@@ -682,7 +747,17 @@ impl ExecutorState {
     }
 
     fn finish_system_and_handle_dependents(&mut self, result: SystemResult) {
-        let SystemResult { system_index, .. } = result;
+        let SystemResult {
+            system_index,
+            elapsed,
+            error,
+        } = result;
+        if let Some(time) = self.system_times.get_mut(system_index) {
+            *time = elapsed;
+        }
+        if let Some(error) = error {
+            self.system_errors.push((system_index, error));
+        }
 
         if self.system_task_metadata[system_index].is_exclusive {
             self.exclusive_running = false;
@@ -14,7 +14,7 @@ use crate::{
     world::World,
 };
 
-use super::__rust_begin_short_backtrace;
+use super::{__rust_begin_short_backtrace, timed};
 
 /// A variant of [`SingleThreadedExecutor`](crate::schedule::SingleThreadedExecutor) that calls
 /// [`apply_deferred`](crate::system::System::apply_deferred) immediately after running each system.
@@ -52,6 +52,7 @@ impl SystemExecutor for SimpleExecutor {
             self.completed_systems |= skipped_systems;
         }
 
+        let measure_system_times = schedule.measure_system_times();
         for system_index in 0..schedule.systems.len() {
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
@@ -103,28 +104,34 @@ impl SystemExecutor for SimpleExecutor {
                 continue;
             }
 
-            let f = AssertUnwindSafe(|| {
-                // TODO: implement an error-handling API instead of panicking.
-                if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
-                    panic!(
-                        "Encountered an error in system `{}`: {:?}",
-                        &*system.name(),
-                        err
-                    );
-                }
-            });
+            let change_tick = world.read_change_tick();
+            if let Some(hooks) = &schedule.run_hooks {
+                hooks.before(system, change_tick);
+            }
+
+            let f = AssertUnwindSafe(|| __rust_begin_short_backtrace::run(system, world));
 
             #[cfg(feature = "std")]
-            {
-                if let Err(payload) = std::panic::catch_unwind(f) {
-                    eprintln!("Encountered a panic in system `{}`!", &*system.name());
-                    std::panic::resume_unwind(payload);
+            let (result, elapsed) = {
+                let (result, elapsed) = timed(measure_system_times, || std::panic::catch_unwind(f));
+                match result {
+                    Ok(result) => (result, elapsed),
+                    Err(payload) => {
+                        eprintln!("Encountered a panic in system `{}`!", &*system.name());
+                        std::panic::resume_unwind(payload);
+                    }
                 }
-            }
+            };
 
             #[cfg(not(feature = "std"))]
-            {
-                (f)();
+            let (result, elapsed) = timed(measure_system_times, f);
+
+            if let Some(hooks) = &schedule.run_hooks {
+                hooks.after(system, change_tick, elapsed);
+            }
+            schedule.set_system_time(system_index, elapsed);
+            if let Err(error) = result {
+                schedule.report_error(system_index, error);
             }
         }
 
@@ -12,7 +12,7 @@ use crate::{
     world::World,
 };
 
-use super::__rust_begin_short_backtrace;
+use super::{__rust_begin_short_backtrace, timed};
 
 /// Runs the schedule using a single thread.
 ///
@@ -58,6 +58,7 @@ impl SystemExecutor for SingleThreadedExecutor {
             self.completed_systems |= skipped_systems;
         }
 
+        let measure_system_times = schedule.measure_system_times();
         for system_index in 0..schedule.systems.len() {
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
@@ -106,50 +107,52 @@ impl SystemExecutor for SingleThreadedExecutor {
             }
 
             if is_apply_deferred(system) {
-                self.apply_deferred(schedule, world);
+                let ((), elapsed) = timed(schedule.record_system_times, || {
+                    self.apply_deferred(schedule, world);
+                });
+                schedule.set_system_time(system_index, elapsed);
                 continue;
             }
 
+            let change_tick = world.read_change_tick();
+            if let Some(hooks) = &schedule.run_hooks {
+                hooks.before(system, change_tick);
+            }
+
             let f = AssertUnwindSafe(|| {
                 if system.is_exclusive() {
-                    // TODO: implement an error-handling API instead of panicking.
-                    if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
-                        panic!(
-                            "Encountered an error in system `{}`: {:?}",
-                            &*system.name(),
-                            err
-                        );
-                    }
+                    __rust_begin_short_backtrace::run(system, world)
                 } else {
                     // Use run_unsafe to avoid immediately applying deferred buffers
                     let world = world.as_unsafe_world_cell();
                     system.update_archetype_component_access(world);
                     // SAFETY: We have exclusive, single-threaded access to the world and
                     // update_archetype_component_access is being called immediately before this.
-                    unsafe {
-                        // TODO: implement an error-handling API instead of panicking.
-                        if let Err(err) = __rust_begin_short_backtrace::run_unsafe(system, world) {
-                            panic!(
-                                "Encountered an error in system `{}`: {:?}",
-                                &*system.name(),
-                                err
-                            );
-                        }
-                    };
+                    unsafe { __rust_begin_short_backtrace::run_unsafe(system, world) }
                 }
             });
 
             #[cfg(feature = "std")]
-            {
-                if let Err(payload) = std::panic::catch_unwind(f) {
-                    eprintln!("Encountered a panic in system `{}`!", &*system.name());
-                    std::panic::resume_unwind(payload);
+            let (result, elapsed) = {
+                let (result, elapsed) = timed(measure_system_times, || std::panic::catch_unwind(f));
+                match result {
+                    Ok(result) => (result, elapsed),
+                    Err(payload) => {
+                        eprintln!("Encountered a panic in system `{}`!", &*system.name());
+                        std::panic::resume_unwind(payload);
+                    }
                 }
-            }
+            };
 
             #[cfg(not(feature = "std"))]
-            {
-                (f)();
+            let (result, elapsed) = timed(measure_system_times, f);
+
+            if let Some(hooks) = &schedule.run_hooks {
+                hooks.after(system, change_tick, elapsed);
+            }
+            schedule.set_system_time(system_index, elapsed);
+            if let Err(error) = result {
+                schedule.report_error(system_index, error);
             }
 
             self.unapplied_systems.insert(system_index);
@@ -4,15 +4,23 @@ mod condition;
 mod config;
 mod executor;
 mod graph;
+mod run_hooks;
 #[allow(clippy::module_inception)]
 mod schedule;
 mod set;
 mod stepping;
+mod timings;
+#[cfg(feature = "std")]
+mod tracking;
 
 use self::graph::*;
-pub use self::{condition::*, config::*, executor::*, schedule::*, set::*};
+pub use self::{
+    condition::*, config::*, executor::*, run_hooks::*, schedule::*, set::*, timings::*,
+};
 
 pub use self::graph::NodeId;
+#[cfg(feature = "std")]
+pub use self::tracking::*;
 
 #[cfg(test)]
 mod tests {
@@ -1226,4 +1234,199 @@ mod tests {
             assert_executor_supports_stepping!(ExecutorKind::MultiThreaded);
         }
     }
+
+    mod system_timings {
+        use super::*;
+
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct TestSchedule;
+
+        fn assert_executor_records_timings(executor: ExecutorKind) {
+            let mut world = World::default();
+            world.init_resource::<SystemOrder>();
+            world.init_resource::<SystemTimings>();
+
+            let mut schedule = Schedule::new(TestSchedule);
+            schedule.set_executor_kind(executor).add_systems((
+                named_system,
+                named_exclusive_system,
+                make_function_system(0).run_if(|| false),
+            ));
+            schedule.run(&mut world);
+            schedule.run(&mut world);
+
+            let timings = world.resource::<SystemTimings>();
+            let timings = timings.get(TestSchedule.intern()).unwrap();
+            assert_eq!(timings.runs, 2);
+            assert_eq!(timings.systems.len(), 3);
+            for system in &timings.systems {
+                let expected_runs = if system.name.contains("named") { 2 } else { 0 };
+                assert_eq!(system.runs, expected_runs, "{}", system.name);
+            }
+        }
+
+        #[test]
+        fn simple_executor() {
+            assert_executor_records_timings(ExecutorKind::Simple);
+        }
+
+        #[test]
+        fn single_threaded_executor() {
+            assert_executor_records_timings(ExecutorKind::SingleThreaded);
+        }
+
+        #[test]
+        fn multi_threaded_executor() {
+            assert_executor_records_timings(ExecutorKind::MultiThreaded);
+        }
+    }
+
+    mod system_run_hooks {
+        use super::*;
+        use alloc::sync::Arc;
+        use std::sync::Mutex;
+
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct TestSchedule;
+
+        #[derive(Resource)]
+        struct Counter(u32);
+
+        fn assert_executor_calls_run_hooks(executor: ExecutorKind) {
+            let before = Arc::new(Mutex::new(Vec::new()));
+            let after = Arc::new(Mutex::new(Vec::new()));
+
+            let mut world = World::default();
+            world.insert_resource(Counter(0));
+            let mut hooks = SystemRunHooks::default();
+            let runs = before.clone();
+            hooks.on_before_run(move |info| {
+                assert!(info.elapsed.is_none());
+                runs.lock().unwrap().push(info.name.to_string());
+            });
+            let runs = after.clone();
+            hooks.on_after_run(move |info| {
+                assert_eq!(info.schedule, TestSchedule.intern());
+                assert!(info.elapsed.is_some());
+                runs.lock().unwrap().push((
+                    info.is_exclusive,
+                    info.component_access.has_any_resource_write(),
+                ));
+            });
+            world.insert_resource(hooks);
+
+            let mut schedule = Schedule::new(TestSchedule);
+            schedule.set_executor_kind(executor).add_systems((
+                (|mut counter: ResMut<Counter>| counter.0 += 1),
+                |_: &mut World| {},
+                || {},
+                make_function_system(0).run_if(|| false),
+            ));
+            schedule.run(&mut world);
+
+            assert_eq!(before.lock().unwrap().len(), 3);
+            let mut after = after.lock().unwrap().clone();
+            after.sort();
+            assert_eq!(after, [(false, false), (false, true), (true, false)]);
+
+            world.remove_resource::<SystemRunHooks>();
+            schedule.run(&mut world);
+            assert_eq!(before.lock().unwrap().len(), 3);
+        }
+
+        #[test]
+        fn simple_executor() {
+            assert_executor_calls_run_hooks(ExecutorKind::Simple);
+        }
+
+        #[test]
+        fn single_threaded_executor() {
+            assert_executor_calls_run_hooks(ExecutorKind::SingleThreaded);
+        }
+
+        #[test]
+        fn multi_threaded_executor() {
+            assert_executor_calls_run_hooks(ExecutorKind::MultiThreaded);
+        }
+    }
+
+    mod system_errors {
+        use super::*;
+        use crate::{
+            result::{Error, Result},
+            system::error_handler::{SystemError, SystemErrorHandler},
+        };
+        use alloc::{format, string::String};
+
+        #[derive(Resource, Default)]
+        struct HandledErrors(Vec<String>);
+
+        fn record_error(world: &mut World, error: Error) {
+            let error = error.downcast::<SystemError>().unwrap();
+            world
+                .resource_mut::<HandledErrors>()
+                .0
+                .push(error.error.to_string());
+        }
+
+        fn record_overridden_error(world: &mut World, error: Error) {
+            let error = error.downcast::<SystemError>().unwrap();
+            world
+                .resource_mut::<HandledErrors>()
+                .0
+                .push(format!("overridden {}", error.error));
+        }
+
+        fn failing_system() -> Result {
+            Err("failing".into())
+        }
+
+        fn failing_exclusive_system(_: &mut World) -> Result {
+            Err("failing exclusive".into())
+        }
+
+        fn assert_executor_handles_errors(executor: ExecutorKind) {
+            let mut world = World::default();
+            world.init_resource::<HandledErrors>();
+            world.insert_resource(SystemErrorHandler(record_error));
+
+            let mut schedule = Schedule::default();
+            schedule.set_executor_kind(executor).add_systems((
+                failing_system,
+                failing_exclusive_system.on_error(record_overridden_error),
+                || -> Result { Ok(()) },
+            ));
+            schedule.run(&mut world);
+
+            let mut errors = world.resource_mut::<HandledErrors>().0.clone();
+            errors.sort();
+            assert_eq!(errors, ["failing", "overridden failing exclusive"]);
+        }
+
+        #[test]
+        fn simple_executor() {
+            assert_executor_handles_errors(ExecutorKind::Simple);
+        }
+
+        #[test]
+        fn single_threaded_executor() {
+            assert_executor_handles_errors(ExecutorKind::SingleThreaded);
+        }
+
+        #[test]
+        fn multi_threaded_executor() {
+            assert_executor_handles_errors(ExecutorKind::MultiThreaded);
+        }
+
+        #[test]
+        #[should_panic(expected = "Encountered an error in system")]
+        fn panics_without_error_handler() {
+            let mut world = World::default();
+            let mut schedule = Schedule::default();
+            schedule
+                .set_executor_kind(ExecutorKind::SingleThreaded)
+                .add_systems(failing_system);
+            schedule.run(&mut world);
+        }
+    }
 }
@@ -0,0 +1,159 @@
+use alloc::{borrow::Cow, vec::Vec};
+use core::time::Duration;
+
+#[cfg(feature = "portable-atomic")]
+use portable_atomic_util::Arc;
+
+#[cfg(not(feature = "portable-atomic"))]
+use alloc::sync::Arc;
+
+use crate::{
+    self as bevy_ecs,
+    component::{ComponentId, Tick},
+    query::Access,
+    schedule::InternedScheduleLabel,
+    system::{Resource, ScheduleSystem},
+};
+
+type SystemRunHook = Arc<dyn Fn(&SystemRunInfo) + Send + Sync>;
+
+/// Callbacks invoked by the executors before and after each system of every schedule run on the
+/// [`World`], for as long as this resource is present in it.
+///
+/// This can be used to implement custom profilers, or to enforce time budgets, without
+/// modifying the executors. Hooks of systems running in parallel are called from the threads
+/// running them, so they must be cheap and are best used to forward the [`SystemRunInfo`]
+/// somewhere else. The [`ApplyDeferred`](crate::schedule::ApplyDeferred) sync points and systems
+/// skipped by their run conditions don't call the hooks.
+///
+/// Hooks are read when a schedule starts running, so hooks added while it runs are only called
+/// from its next run on.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::SystemRunHooks;
+/// # use std::sync::{Arc, Mutex};
+/// let runs = Arc::new(Mutex::new(Vec::new()));
+///
+/// let mut world = World::new();
+/// let mut hooks = SystemRunHooks::default();
+/// let recorded = runs.clone();
+/// hooks.on_after_run(move |info| {
+///     recorded.lock().unwrap().push(info.name.to_string());
+/// });
+/// world.insert_resource(hooks);
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(|| {});
+/// schedule.run(&mut world);
+/// assert_eq!(runs.lock().unwrap().len(), 1);
+/// ```
+///
+/// [`World`]: crate::world::World
+#[derive(Resource, Default, Clone)]
+pub struct SystemRunHooks {
+    before: Vec<SystemRunHook>,
+    after: Vec<SystemRunHook>,
+}
+
+/// Information about a system run, given to the [`SystemRunHooks`].
+#[derive(Debug)]
+pub struct SystemRunInfo<'a> {
+    /// The name of the system.
+    pub name: Cow<'static, str>,
+    /// The label of the schedule running the system.
+    pub schedule: InternedScheduleLabel,
+    /// Whether the system is exclusive, in which case it has access to the whole world.
+    pub is_exclusive: bool,
+    /// The components and resources the system accesses.
+    pub component_access: &'a Access<ComponentId>,
+    /// The change tick of the world when the system started running.
+    pub change_tick: Tick,
+    /// The time spent running the system, which is `None` before it runs, or if time
+    /// can't be measured because the `std` feature is disabled.
+    pub elapsed: Option<Duration>,
+}
+
+impl SystemRunHooks {
+    /// Adds a callback invoked right before each system runs.
+    pub fn on_before_run(
+        &mut self,
+        hook: impl Fn(&SystemRunInfo) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.before.push(Arc::new(hook));
+        self
+    }
+
+    /// Adds a callback invoked right after each system ran, with the time spent running it.
+    ///
+    /// It isn't called when the system panics.
+    pub fn on_after_run(
+        &mut self,
+        hook: impl Fn(&SystemRunInfo) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.after.push(Arc::new(hook));
+        self
+    }
+
+    /// Returns `true` if no hook was added.
+    pub fn is_empty(&self) -> bool {
+        self.before.is_empty() && self.after.is_empty()
+    }
+
+    /// Removes every hook.
+    pub fn clear(&mut self) {
+        self.before.clear();
+        self.after.clear();
+    }
+}
+
+/// The [`SystemRunHooks`] of a schedule being run.
+pub(super) struct ScheduleRunHooks {
+    pub(super) label: InternedScheduleLabel,
+    pub(super) hooks: SystemRunHooks,
+}
+
+impl ScheduleRunHooks {
+    fn info<'a>(
+        &self,
+        system: &'a ScheduleSystem,
+        change_tick: Tick,
+        elapsed: Option<Duration>,
+    ) -> SystemRunInfo<'a> {
+        SystemRunInfo {
+            name: system.name(),
+            schedule: self.label,
+            is_exclusive: system.is_exclusive(),
+            component_access: system.component_access(),
+            change_tick,
+            elapsed,
+        }
+    }
+
+    /// Calls the hooks to invoke before `system` runs.
+    pub(super) fn before(&self, system: &ScheduleSystem, change_tick: Tick) {
+        if self.hooks.before.is_empty() {
+            return;
+        }
+        let info = self.info(system, change_tick, None);
+        for hook in &self.hooks.before {
+            hook(&info);
+        }
+    }
+
+    /// Calls the hooks to invoke after `system` ran.
+    pub(super) fn after(
+        &self,
+        system: &ScheduleSystem,
+        change_tick: Tick,
+        elapsed: Option<Duration>,
+    ) {
+        if self.hooks.after.is_empty() {
+            return;
+        }
+        let info = self.info(system, change_tick, elapsed);
+        for hook in &self.hooks.after {
+            hook(&info);
+        }
+    }
+}
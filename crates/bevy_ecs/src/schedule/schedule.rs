@@ -19,14 +19,17 @@ use crate::{
     self as bevy_ecs,
     component::{ComponentId, Components, Tick},
     prelude::Component,
-    result::Result,
+    result::{Error, Result},
     schedule::*,
-    system::{IntoSystem, Resource, ScheduleSystem},
+    system::{
+        error_handler::{SystemError, SystemErrorHandler},
+        IntoSystem, Resource, ScheduleSystem,
+    },
     world::World,
 };
 
 use crate::{query::AccessConflicts, storage::SparseSetIndex};
-pub use stepping::Stepping;
+pub use stepping::{SteppedSystem, Stepping};
 use Direction::{Incoming, Outgoing};
 
 /// Resource that stores [`Schedule`]s mapped to [`ScheduleLabel`]s excluding the current running [`Schedule`].
@@ -383,10 +386,26 @@ impl Schedule {
         #[cfg(feature = "trace")]
         let _span = info_span!("schedule", name = ?self.label).entered();
 
+        #[cfg(feature = "std")]
+        track_schedule(self.label);
+
         world.check_change_ticks();
         self.initialize(world)
             .unwrap_or_else(|e| panic!("Error when initializing schedule {:?}: {e}", self.label));
 
+        let record_system_times = world.contains_resource::<SystemTimings>();
+        self.executable.prepare_system_times(record_system_times);
+        self.executable.run_hooks = world
+            .get_resource::<SystemRunHooks>()
+            .filter(|hooks| !hooks.is_empty())
+            .map(|hooks| ScheduleRunHooks {
+                label: self.label,
+                hooks: hooks.clone(),
+            });
+        self.executable.default_error_handler = world
+            .get_resource::<SystemErrorHandler>()
+            .map(|handler| handler.0);
+
         #[cfg(not(feature = "bevy_debug_stepping"))]
         self.executor.run(&mut self.executable, world, None);
 
@@ -400,6 +419,24 @@ impl Schedule {
             self.executor
                 .run(&mut self.executable, world, skip_systems.as_ref());
         }
+
+        if record_system_times {
+            if let Some(mut timings) = world.get_resource_mut::<SystemTimings>() {
+                timings.record(self.label, &self.executable);
+            }
+        }
+        self.executable.run_hooks = None;
+
+        for (index, error) in core::mem::take(&mut self.executable.system_errors) {
+            let Some(handler) = self.executable.error_handler(index) else {
+                continue;
+            };
+            let error = SystemError {
+                system: self.executable.systems[index].name(),
+                error,
+            };
+            handler(world, error.into());
+        }
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -441,6 +478,173 @@ impl Schedule {
         &mut self.graph
     }
 
+    /// Renders the [`ScheduleGraph`] in the [DOT] format, to be visualized by external tools such as Graphviz.
+    ///
+    /// Systems are drawn as boxes and system sets as ellipses, both labeled with their run conditions.
+    /// Dashed edges go from system sets to their members, solid edges from systems and sets to the
+    /// ones that run after them, and red undirected edges connect ambiguous systems.
+    ///
+    /// Ambiguities are only known once the schedule was initialized, for example with
+    /// [`Schedule::initialize`]. Automatically inserted sync points are not drawn.
+    ///
+    /// [DOT]: https://graphviz.org/doc/info/lang.html
+    pub fn graph_dot(&self) -> String {
+        let graph = &self.graph;
+        let executable_systems = self.executable_indices(&self.executable.system_ids);
+        let executable_sets = self.executable_indices(&self.executable.set_ids);
+
+        let mut dot = String::new();
+        writeln!(
+            dot,
+            "digraph \"{}\" {{",
+            dot_escape(&format!("{:?}", self.label))
+        )
+        .unwrap();
+
+        let node_id = |id: &NodeId| match id {
+            NodeId::System(index) => format!("system_{index}"),
+            NodeId::Set(index) => format!("set_{index}"),
+        };
+        // The sets of single systems are implied by the systems themselves.
+        let is_shown = |id: &NodeId| match id {
+            NodeId::System(index) => {
+                graph.systems[*index].get().is_some() || executable_systems.contains_key(id)
+            }
+            NodeId::Set(index) => !graph.system_sets[*index].is_system_type(),
+        };
+
+        let mut nodes: Vec<_> = graph
+            .hierarchy
+            .graph
+            .nodes()
+            .filter(|id| is_shown(id))
+            .collect();
+        nodes.sort_by_key(|id| (id.is_set(), id.index()));
+        for id in nodes {
+            let (mut label, conditions, shape) = match id {
+                NodeId::System(index) => match executable_systems.get(&id) {
+                    Some(&i) => (
+                        self.system_name(&self.executable.systems[i]),
+                        &self.executable.system_conditions[i],
+                        "box",
+                    ),
+                    None => (
+                        graph.get_node_name_inner(&id, false),
+                        &graph.system_conditions[index],
+                        "box",
+                    ),
+                },
+                NodeId::Set(index) => {
+                    let set = &graph.system_sets[index];
+                    // The name of anonymous sets lists their members, which are drawn as edges instead.
+                    let name = if set.is_anonymous() {
+                        String::from("(anonymous set)")
+                    } else {
+                        graph.get_node_name_inner(&id, false)
+                    };
+                    let conditions = match executable_sets.get(&id) {
+                        Some(&i) => &self.executable.set_conditions[i],
+                        None => &graph.system_set_conditions[index],
+                    };
+                    (name, conditions, "ellipse")
+                }
+            };
+            for condition in conditions {
+                write!(label, "\nrun_if: {}", condition.name()).unwrap();
+            }
+            writeln!(
+                dot,
+                "    {} [label=\"{}\", shape={shape}];",
+                node_id(&id),
+                dot_escape(&label)
+            )
+            .unwrap();
+        }
+
+        for (parent, child) in graph.hierarchy.graph.all_edges() {
+            if is_shown(&parent) && is_shown(&child) {
+                writeln!(
+                    dot,
+                    "    {} -> {} [style=dashed];",
+                    node_id(&parent),
+                    node_id(&child)
+                )
+                .unwrap();
+            }
+        }
+        for (before, after) in graph.dependency.graph.all_edges() {
+            if is_shown(&before) && is_shown(&after) {
+                writeln!(dot, "    {} -> {};", node_id(&before), node_id(&after)).unwrap();
+            }
+        }
+        for (a, b, _) in &graph.conflicting_systems {
+            writeln!(
+                dot,
+                "    {} -> {} [dir=none, color=red, constraint=false];",
+                node_id(a),
+                node_id(b)
+            )
+            .unwrap();
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Returns the ambiguities detected between the systems of this schedule, sorted by the names
+    /// of the systems, so that the report is stable between runs and can be compared in CI.
+    ///
+    /// Ambiguities that are ignored through [`ScheduleBuildSettings`], `ambiguous_with` or
+    /// ignored components are not reported.
+    ///
+    /// Note: this method will return [`ScheduleNotInitialized`] if the
+    /// schedule has never been initialized or run.
+    pub fn ambiguity_report(
+        &self,
+        components: &Components,
+    ) -> Result<Vec<SystemAmbiguity>, ScheduleNotInitialized> {
+        if !self.executor_initialized {
+            return Err(ScheduleNotInitialized);
+        }
+
+        let executable_systems = self.executable_indices(&self.executable.system_ids);
+        let name = |id: &NodeId| self.system_name(&self.executable.systems[executable_systems[id]]);
+        let mut report: Vec<_> = self
+            .graph
+            .conflicting_systems
+            .iter()
+            .map(|(a, b, conflicts)| {
+                let (a, b) = (name(a), name(b));
+                let (system_a, system_b) = if a <= b { (a, b) } else { (b, a) };
+                let mut conflicts: Vec<String> = conflicts
+                    .iter()
+                    .map(|id| components.get_name(*id).unwrap().to_string())
+                    .collect();
+                conflicts.sort();
+                SystemAmbiguity {
+                    system_a,
+                    system_b,
+                    conflicts,
+                }
+            })
+            .collect();
+        report.sort();
+        Ok(report)
+    }
+
+    fn executable_indices(&self, ids: &[NodeId]) -> HashMap<NodeId, usize> {
+        ids.iter().enumerate().map(|(i, &id)| (id, i)).collect()
+    }
+
+    fn system_name(&self, system: &ScheduleSystem) -> String {
+        let name = system.name();
+        if self.graph.settings.use_shortnames {
+            ShortName(&name).to_string()
+        } else {
+            name.to_string()
+        }
+    }
+
     /// Returns the [`SystemSchedule`].
     pub(crate) fn executable(&self) -> &SystemSchedule {
         &self.executable
@@ -599,6 +803,8 @@ pub struct ScheduleGraph {
     systems: Vec<SystemNode>,
     /// List of conditions for each system, in the same order as `systems`
     system_conditions: Vec<Vec<BoxedCondition>>,
+    /// The error handler of each system, in the same order as `systems`
+    system_error_handlers: Vec<Option<fn(&mut World, Error)>>,
     /// List of system sets in the schedule
     system_sets: Vec<SystemSetNode>,
     /// List of conditions for each system set, in the same order as `system_sets`
@@ -629,6 +835,7 @@ impl ScheduleGraph {
         Self {
             systems: Vec::new(),
             system_conditions: Vec::new(),
+            system_error_handlers: Vec::new(),
             system_sets: Vec::new(),
             system_set_conditions: Vec::new(),
             system_set_ids: HashMap::default(),
@@ -873,6 +1080,7 @@ impl ScheduleGraph {
         self.uninit.push((id, 0));
         self.systems.push(SystemNode::new(config.node));
         self.system_conditions.push(config.conditions);
+        self.system_error_handlers.push(config.error_handler);
 
         Ok(id)
     }
@@ -888,6 +1096,7 @@ impl ScheduleGraph {
             node: set,
             graph_info,
             mut conditions,
+            error_handler: _,
         } = set;
 
         let id = match self.system_set_ids.get(&set) {
@@ -1204,6 +1413,7 @@ impl ScheduleGraph {
                 ApplyDeferred,
             ))));
         self.system_conditions.push(Vec::new());
+        self.system_error_handlers.push(None);
 
         // ignore ambiguities with auto sync points
         // They aren't under user control, so no one should know or care.
@@ -1489,6 +1699,12 @@ impl ScheduleGraph {
             system_dependents,
             sets_with_conditions_of_systems,
             systems_in_sets_with_conditions,
+            record_system_times: false,
+            system_times: Vec::new(),
+            run_hooks: None,
+            system_error_handlers: Vec::with_capacity(sys_count),
+            default_error_handler: None,
+            system_errors: Vec::new(),
         }
     }
 
@@ -1531,6 +1747,9 @@ impl ScheduleGraph {
             let conditions = core::mem::take(&mut self.system_conditions[id.index()]);
             schedule.systems.push(system);
             schedule.system_conditions.push(conditions);
+            schedule
+                .system_error_handlers
+                .push(self.system_error_handlers[id.index()]);
         }
 
         for &id in &schedule.set_ids {
@@ -1937,6 +2156,39 @@ impl ScheduleGraph {
     }
 }
 
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// An ambiguity between two systems of a schedule, reported by [`Schedule::ambiguity_report`].
+///
+/// Two systems are ambiguous when they have conflicting data access and no ordering between them,
+/// so the order in which they run can change between runs of the schedule.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SystemAmbiguity {
+    /// The name of the first system, which is the one sorted first.
+    pub system_a: String,
+    /// The name of the second system.
+    pub system_b: String,
+    /// The names of the components and resources the systems conflict on.
+    ///
+    /// If this is empty, the systems conflict on [`World`] access.
+    pub conflicts: Vec<String>,
+}
+
+impl core::fmt::Display for SystemAmbiguity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} <-> {}: ", self.system_a, self.system_b)?;
+        if self.conflicts.is_empty() {
+            write!(f, "{}", core::any::type_name::<World>())
+        } else {
+            write!(f, "{}", self.conflicts.join(", "))
+        }
+    }
+}
+
 /// Category of errors encountered during schedule construction.
 #[derive(Error, Debug)]
 #[non_exhaustive]
@@ -2050,6 +2302,7 @@ pub struct ScheduleNotInitialized;
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
     use bevy_ecs_macros::ScheduleLabel;
 
     use crate::{
@@ -2071,6 +2324,43 @@ mod tests {
     #[derive(Resource)]
     struct Resource2;
 
+    #[test]
+    fn graph_export() {
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Set;
+
+        fn write_1(_: ResMut<Resource1>) {}
+        fn write_2(_: ResMut<Resource1>) {}
+        fn read(_: Res<Resource1>) {}
+
+        let mut world = World::new();
+        world.insert_resource(Resource1);
+        let mut schedule = Schedule::default();
+        schedule.configure_sets(Set.run_if(|| true));
+        schedule.add_systems(((write_1, read).chain(), write_2).in_set(Set));
+        schedule.initialize(&mut world).unwrap();
+
+        let report = schedule.ambiguity_report(world.components()).unwrap();
+        let names: Vec<_> = report
+            .iter()
+            .map(|ambiguity| (ambiguity.system_a.as_str(), ambiguity.system_b.as_str()))
+            .collect();
+        assert_eq!(names.len(), 2);
+        assert!(names.iter().all(|(a, b)| a < b));
+        assert!(names.iter().all(|(_, b)| b.ends_with("write_2")));
+        assert!(report
+            .iter()
+            .all(|ambiguity| ambiguity.conflicts[0].ends_with("Resource1")));
+
+        let dot = schedule.graph_dot();
+        assert!(dot.starts_with("digraph \"DefaultSchedule\" {"));
+        assert!(dot.contains("shape=ellipse"));
+        assert!(dot.contains("run_if: "));
+        assert_eq!(dot.matches("shape=box").count(), 3);
+        assert_eq!(dot.matches("[style=dashed]").count(), 3);
+        assert_eq!(dot.matches("color=red").count(), 2);
+    }
+
     // regression test for https://github.com/bevyengine/bevy/issues/9114
     #[test]
     fn ambiguous_with_not_breaking_run_conditions() {
@@ -1,8 +1,10 @@
 use crate::{
+    component::ComponentId,
+    query::Access,
     schedule::{InternedScheduleLabel, NodeId, Schedule, ScheduleLabel},
     system::{IntoSystem, ResMut, Resource},
 };
-use alloc::vec::Vec;
+use alloc::{borrow::Cow, vec::Vec};
 use bevy_utils::{HashMap, TypeIdMap};
 use core::any::TypeId;
 use fixedbitset::FixedBitSet;
@@ -91,6 +93,24 @@ enum Update {
 #[error("not available until all configured schedules have been run; try again next frame")]
 pub struct NotReady;
 
+/// A system run by the most recent [`Stepping::step_frame`] or
+/// [`Stepping::continue_frame`], see [`Stepping::stepped_systems`].
+#[derive(Debug, Clone)]
+pub struct SteppedSystem {
+    /// The schedule the system is in.
+    pub schedule: InternedScheduleLabel,
+    /// The [`NodeId`] of the system within its schedule.
+    pub node: NodeId,
+    /// The name of the system.
+    pub name: Cow<'static, str>,
+    /// The components and resources the system declared access to.
+    ///
+    /// This is what the system *may* read or write, not what it actually
+    /// mutated during the step: use [`Access::has_component_write`] and
+    /// [`Access::resource_writes`] to find out what it could have changed.
+    pub declared_access: Access<ComponentId>,
+}
+
 #[derive(Resource, Default)]
 /// Resource for controlling system stepping behavior
 pub struct Stepping {
@@ -111,6 +131,9 @@ pub struct Stepping {
 
     // Updates apply at the start of the next render frame
     updates: Vec<Update>,
+
+    // Systems run by the most recent step or continue action
+    stepped: Vec<SteppedSystem>,
 }
 
 impl core::fmt::Debug for Stepping {
@@ -176,6 +199,16 @@ impl Stepping {
             .map(|node_id| (*label, *node_id))
     }
 
+    /// Return the systems run by the most recent call to [`Stepping::step_frame`]
+    /// or [`Stepping::continue_frame`], in the order they were run.
+    ///
+    /// Systems marked with [`Stepping::always_run`] are not included. The list is
+    /// kept until the next step or continue action is performed, so it can be
+    /// inspected while stepping is waiting for input.
+    pub fn stepped_systems(&self) -> &[SteppedSystem] {
+        &self.stepped
+    }
+
     /// Enable stepping for the provided schedule
     pub fn add_schedule(&mut self, schedule: impl ScheduleLabel) -> &mut Self {
         self.updates.push(Update::AddSchedule(schedule.intern()));
@@ -508,6 +541,10 @@ impl Stepping {
         if reset_cursor {
             self.reset_cursor();
         }
+
+        if matches!(self.action, Action::Step | Action::Continue) {
+            self.stepped.clear();
+        }
     }
 
     /// get the list of systems this schedule should skip for this render
@@ -559,6 +596,24 @@ impl Stepping {
             let (skip_list, next_system) =
                 state.skipped_systems(schedule, cursor.system, self.action);
 
+            // record the systems this action runs, so they can be reported
+            // back through `stepped_systems()`
+            if matches!(self.action, Action::Step | Action::Continue) {
+                for (i, (node, system)) in schedule.systems().into_iter().flatten().enumerate() {
+                    if skip_list.contains(i)
+                        || matches!(state.behaviors.get(&node), Some(SystemBehavior::AlwaysRun))
+                    {
+                        continue;
+                    }
+                    self.stepped.push(SteppedSystem {
+                        schedule: label,
+                        node,
+                        name: system.name(),
+                        declared_access: system.component_access().clone(),
+                    });
+                }
+            }
+
             // if we just stepped this schedule, then we'll switch the action
             // to be waiting
             if self.action == Action::Step {
@@ -1125,6 +1180,48 @@ mod tests {
         assert_schedule_runs!(&schedule, &mut stepping,);
     }
 
+    #[test]
+    fn stepped_systems_report() {
+        #[derive(Resource, Default)]
+        struct Counter(u32);
+
+        fn increment(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let counter = world.resource_id::<Counter>().unwrap();
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_systems((first_system, increment, second_system).chain());
+        schedule.initialize(&mut world).unwrap();
+
+        let mut stepping = Stepping::new();
+        stepping
+            .add_schedule(TestSchedule)
+            .enable()
+            .always_run(TestSchedule, first_system)
+            .step_frame();
+
+        assert_schedule_runs!(&schedule, &mut stepping, first_system, increment);
+        let stepped = stepping.stepped_systems();
+        assert_eq!(stepped.len(), 1);
+        assert!(stepped[0].name.ends_with("increment"));
+        assert_eq!(stepped[0].schedule, TestSchedule.intern());
+        assert!(stepped[0].declared_access.has_resource_write(counter));
+
+        // the report is kept while waiting
+        assert_schedule_runs!(&schedule, &mut stepping, first_system);
+        assert_eq!(stepping.stepped_systems().len(), 1);
+
+        stepping.continue_frame();
+        assert_schedule_runs!(&schedule, &mut stepping, first_system, second_system);
+        let stepped = stepping.stepped_systems();
+        assert_eq!(stepped.len(), 1);
+        assert!(stepped[0].name.ends_with("second_system"));
+        assert!(!stepped[0].declared_access.has_any_resource_write());
+    }
+
     #[test]
     fn continue_breakpoint() {
         let (schedule, _world) = setup();
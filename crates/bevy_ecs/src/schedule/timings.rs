@@ -0,0 +1,100 @@
+use alloc::{borrow::Cow, vec::Vec};
+use core::time::Duration;
+
+use bevy_utils::HashMap;
+
+use crate::{
+    self as bevy_ecs,
+    schedule::{InternedScheduleLabel, SystemSchedule},
+    system::Resource,
+};
+
+/// Records the CPU time spent running each system of every schedule run on the [`World`],
+/// for as long as this resource is present in it.
+///
+/// Timings accumulate over every run of a schedule until [`SystemTimings::clear`] is called,
+/// so a schedule that runs several times per frame, such as `FixedUpdate`, reports the total
+/// time spent in it during that frame if cleared once per frame.
+///
+/// Timings are only recorded when the `std` feature is enabled.
+///
+/// [`World`]: crate::world::World
+#[derive(Resource, Default, Debug)]
+pub struct SystemTimings {
+    schedules: HashMap<InternedScheduleLabel, ScheduleTimings>,
+}
+
+/// The timings recorded for one schedule in [`SystemTimings`].
+#[derive(Default, Debug, Clone)]
+pub struct ScheduleTimings {
+    /// How many times the schedule was run.
+    pub runs: u32,
+    /// The timings of its systems, in the order they are executed by a single-threaded executor.
+    ///
+    /// Includes the [`ApplyDeferred`](crate::schedule::ApplyDeferred) sync points inserted in the schedule.
+    pub systems: Vec<SystemTiming>,
+}
+
+/// The timing recorded for one system in [`SystemTimings`].
+#[derive(Debug, Clone)]
+pub struct SystemTiming {
+    /// The name of the system.
+    pub name: Cow<'static, str>,
+    /// How many times the system was run. Systems skipped by their run conditions are not counted.
+    pub runs: u32,
+    /// The total CPU time spent running the system.
+    pub elapsed: Duration,
+}
+
+impl ScheduleTimings {
+    /// Returns the total CPU time spent running the systems of the schedule.
+    ///
+    /// This can be higher than the time spent running the schedule when systems run in parallel.
+    pub fn elapsed(&self) -> Duration {
+        self.systems.iter().map(|system| system.elapsed).sum()
+    }
+}
+
+impl SystemTimings {
+    /// Returns the timings recorded for the schedule with the given `label`, if it was run.
+    pub fn get(&self, label: InternedScheduleLabel) -> Option<&ScheduleTimings> {
+        self.schedules.get(&label)
+    }
+
+    /// Returns an iterator over the timings of every schedule that was run.
+    pub fn iter(&self) -> impl Iterator<Item = (InternedScheduleLabel, &ScheduleTimings)> {
+        self.schedules
+            .iter()
+            .map(|(label, timings)| (*label, timings))
+    }
+
+    /// Discards every recorded timing.
+    pub fn clear(&mut self) {
+        self.schedules.clear();
+    }
+
+    pub(super) fn record(&mut self, label: InternedScheduleLabel, schedule: &SystemSchedule) {
+        let timings = self.schedules.entry(label).or_default();
+        timings.runs += 1;
+
+        // The schedule was rebuilt since the last run.
+        if timings.systems.len() != schedule.systems.len() {
+            timings.systems = schedule
+                .systems
+                .iter()
+                .map(|system| SystemTiming {
+                    name: system.name(),
+                    runs: 0,
+                    elapsed: Duration::ZERO,
+                })
+                .collect();
+        }
+
+        for (timing, elapsed) in timings.systems.iter_mut().zip(&schedule.system_times) {
+            if let Some(elapsed) = elapsed {
+                timing.runs += 1;
+                timing.elapsed += *elapsed;
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use alloc::borrow::Cow;
+use core::{
+    cell::RefCell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+use std::sync::Mutex;
+
+use crate::{schedule::InternedScheduleLabel, system::ScheduleSystem};
+
+/// Whether the schedules and systems being run are tracked.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// The schedule most recently started on any thread.
+static LAST_SCHEDULE: Mutex<Option<InternedScheduleLabel>> = Mutex::new(None);
+
+std::thread_local! {
+    /// The system most recently started on this thread.
+    static CURRENT_SYSTEM: RefCell<Option<TrackedSystem>> = const { RefCell::new(None) };
+}
+
+/// A system started on the current thread, recorded while system tracking is enabled.
+///
+/// See [`enable_system_tracking`].
+#[derive(Debug, Clone)]
+pub struct TrackedSystem {
+    /// The name of the system.
+    pub name: Cow<'static, str>,
+    /// The schedule most recently started when the system started, see [`last_started_schedule`].
+    pub schedule: Option<InternedScheduleLabel>,
+    /// Whether the system is still running, which is the case when it panicked.
+    pub running: bool,
+}
+
+/// Enables or disables the tracking of the schedules and systems being run.
+///
+/// While enabled, [`last_started_schedule`] and [`current_thread_system`] report what the
+/// executors are running, which is useful for diagnostics such as crash reports. Tracking is
+/// disabled by default, as it adds a small cost to every system run.
+pub fn enable_system_tracking(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether the schedules and systems being run are tracked, see [`enable_system_tracking`].
+pub fn is_system_tracking_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Returns the schedule most recently started on any thread, if system tracking is enabled.
+///
+/// Schedules are not reported as finished, so this is the innermost schedule running when
+/// called from a system, and the last schedule to have run otherwise.
+pub fn last_started_schedule() -> Option<InternedScheduleLabel> {
+    // The lock may be poisoned by a panic, which doesn't matter to a plain value.
+    *LAST_SCHEDULE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Returns the system most recently started on the current thread, if system tracking is enabled.
+///
+/// This can be called from a panic hook to know which system panicked.
+pub fn current_thread_system() -> Option<TrackedSystem> {
+    CURRENT_SYSTEM
+        .try_with(|system| system.try_borrow().ok().and_then(|system| system.clone()))
+        .ok()
+        .flatten()
+}
+
+/// Records the start of a schedule, if system tracking is enabled.
+pub(super) fn track_schedule(label: InternedScheduleLabel) {
+    if is_system_tracking_enabled() {
+        *LAST_SCHEDULE
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(label);
+    }
+}
+
+/// Records that a system is running on the current thread until dropped, if system tracking is
+/// enabled.
+pub(super) struct SystemGuard(bool);
+
+impl SystemGuard {
+    pub(super) fn enter(system: &ScheduleSystem) -> Self {
+        if !is_system_tracking_enabled() {
+            return Self(false);
+        }
+        let tracked = TrackedSystem {
+            name: system.name(),
+            schedule: last_started_schedule(),
+            running: true,
+        };
+        let _ = CURRENT_SYSTEM.try_with(|system| {
+            if let Ok(mut system) = system.try_borrow_mut() {
+                *system = Some(tracked);
+            }
+        });
+        Self(true)
+    }
+}
+
+impl Drop for SystemGuard {
+    fn drop(&mut self) {
+        if !self.0 {
+            return;
+        }
+        let _ = CURRENT_SYSTEM.try_with(|system| {
+            if let Ok(mut system) = system.try_borrow_mut() {
+                if let Some(system) = system.as_mut() {
+                    system.running = false;
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        self as bevy_ecs,
+        schedule::{Schedule, ScheduleLabel},
+        world::World,
+    };
+
+    #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct TrackedSchedule;
+
+    #[test]
+    fn tracks_running_system() {
+        // Other tests may run schedules concurrently, so only the thread local system is checked.
+        fn check_tracking() {
+            let system = current_thread_system().unwrap();
+            assert!(system.name.ends_with("check_tracking"));
+            assert!(system.running);
+            assert!(system.schedule.is_some());
+        }
+
+        enable_system_tracking(true);
+        let mut world = World::new();
+        let mut schedule = Schedule::new(TrackedSchedule);
+        schedule.set_executor_kind(crate::schedule::ExecutorKind::SingleThreaded);
+        schedule.add_systems(check_tracking);
+        schedule.run(&mut world);
+
+        let system = current_thread_system().unwrap();
+        assert!(system.name.ends_with("check_tracking"));
+        assert!(!system.running);
+        assert!(last_started_schedule().is_some());
+    }
+}
@@ -0,0 +1,321 @@
+use alloc::{boxed::Box, vec::Vec};
+use bevy_tasks::futures::check_ready;
+use bevy_utils::synccell::SyncCell;
+use core::{
+    future::{poll_fn, Future},
+    mem,
+    pin::Pin,
+    task::{Poll, Waker},
+};
+use spin::Mutex;
+
+#[cfg(feature = "portable-atomic")]
+use portable_atomic_util::Arc;
+
+#[cfg(not(feature = "portable-atomic"))]
+use alloc::sync::Arc;
+
+use crate::{
+    self as bevy_ecs,
+    system::{IntoSystem, Resource, RunSystemError, RunSystemOnce},
+    world::World,
+};
+
+type WorldRequest = Box<dyn FnOnce(&mut World) + Send>;
+
+/// A handle given to async systems, used to access the [`World`] across frames.
+///
+/// Every access is queued and performed the next time [`run_async_systems`] runs, after which the
+/// async system resumes on the following run. In between, the schedule keeps running as usual.
+#[derive(Clone)]
+pub struct AsyncWorld {
+    requests: Arc<Mutex<Vec<WorldRequest>>>,
+}
+
+struct Pending<R> {
+    output: Option<R>,
+    waker: Option<Waker>,
+}
+
+impl AsyncWorld {
+    /// Runs `f` with exclusive access to the [`World`], and returns its output.
+    pub fn run<F, R>(&self, f: F) -> impl Future<Output = R> + Send + 'static
+    where
+        F: FnOnce(&mut World) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let requests = self.requests.clone();
+        async move {
+            let pending = Arc::new(Mutex::new(Pending {
+                output: None,
+                waker: None,
+            }));
+            let slot = pending.clone();
+            requests.lock().push(Box::new(move |world: &mut World| {
+                let output = f(world);
+                let mut slot = slot.lock();
+                slot.output = Some(output);
+                if let Some(waker) = slot.waker.take() {
+                    waker.wake();
+                }
+            }));
+            poll_fn(|cx| {
+                let mut pending = pending.lock();
+                match pending.output.take() {
+                    Some(output) => Poll::Ready(output),
+                    None => {
+                        pending.waker = Some(cx.waker().clone());
+                        Poll::Pending
+                    }
+                }
+            })
+            .await
+        }
+    }
+
+    /// Runs `system` once and returns its output.
+    ///
+    /// The parameters of the system are validated against the current state of the [`World`]
+    /// when it runs rather than when the async system started, and [`RunSystemError::InvalidParams`]
+    /// is returned if they aren't valid anymore and the system doesn't panic on invalid parameters.
+    pub fn run_system<T, Out, Marker>(
+        &self,
+        system: T,
+    ) -> impl Future<Output = Result<Out, RunSystemError>> + Send + 'static
+    where
+        T: IntoSystem<(), Out, Marker>,
+        Out: Send + 'static,
+    {
+        let system = IntoSystem::into_system(system);
+        self.run(move |world| world.run_system_once(system))
+    }
+
+    /// Waits until the next run of [`run_async_systems`].
+    pub fn next_frame(&self) -> impl Future<Output = ()> + Send + 'static {
+        self.run(|_| ())
+    }
+}
+
+/// Identifies an async system spawned with [`AsyncSystems::spawn`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsyncSystemId(u64);
+
+struct AsyncSystemTask {
+    id: AsyncSystemId,
+    future: SyncCell<Pin<Box<dyn Future<Output = ()> + Send>>>,
+    requests: Arc<Mutex<Vec<WorldRequest>>>,
+}
+
+/// A [`Resource`] holding the running async systems, which are driven by [`run_async_systems`].
+///
+/// An async system is an `async` block or function given an [`AsyncWorld`]. Each of its accesses
+/// to the world yields until the next run of [`run_async_systems`], so long-running flows such as
+/// a login or the generation of a level can be written sequentially while the rest of the
+/// schedule keeps running. Async systems can also await tasks spawned on the `bevy_tasks` pools.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::{run_async_systems, AsyncSystems, AsyncWorld};
+/// #[derive(Resource, Default)]
+/// struct Level(u32);
+///
+/// async fn load_level(world: AsyncWorld) {
+///     world.next_frame().await;
+///     world
+///         .run_system(|mut level: ResMut<Level>| level.0 += 1)
+///         .await
+///         .unwrap();
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Level>();
+/// world.init_resource::<AsyncSystems>();
+/// world.resource_mut::<AsyncSystems>().spawn(load_level);
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(run_async_systems);
+/// for _ in 0..3 {
+///     schedule.run(&mut world);
+/// }
+/// assert_eq!(world.resource::<Level>().0, 1);
+/// assert!(world.resource::<AsyncSystems>().is_empty());
+/// ```
+#[derive(Resource, Default)]
+pub struct AsyncSystems {
+    next_id: u64,
+    tasks: Vec<AsyncSystemTask>,
+    // The async systems being resumed by `run_async_systems`, whose tasks are moved out.
+    resuming: Vec<AsyncSystemId>,
+    cancelled: Vec<AsyncSystemId>,
+}
+
+impl AsyncSystems {
+    /// Spawns an async system, which starts running on the next run of [`run_async_systems`].
+    pub fn spawn<F, Fut>(&mut self, system: F) -> AsyncSystemId
+    where
+        F: FnOnce(AsyncWorld) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let id = AsyncSystemId(self.next_id);
+        self.next_id += 1;
+        let world = AsyncWorld {
+            requests: Arc::new(Mutex::new(Vec::new())),
+        };
+        let requests = world.requests.clone();
+        self.tasks.push(AsyncSystemTask {
+            id,
+            future: SyncCell::new(Box::pin(system(world))),
+            requests,
+        });
+        id
+    }
+
+    /// Stops the async system with the given `id`. Returns `false` if it isn't running anymore.
+    pub fn cancel(&mut self, id: AsyncSystemId) -> bool {
+        let running = self.tasks.len();
+        self.tasks.retain(|task| task.id != id);
+        if self.tasks.len() < running {
+            return true;
+        }
+        let is_resuming = self.resuming.contains(&id) && !self.cancelled.contains(&id);
+        if is_resuming {
+            self.cancelled.push(id);
+        }
+        is_resuming
+    }
+
+    /// Returns `true` if the async system with the given `id` is still running.
+    pub fn is_running(&self, id: AsyncSystemId) -> bool {
+        self.tasks.iter().any(|task| task.id == id)
+            || (self.resuming.contains(&id) && !self.cancelled.contains(&id))
+    }
+
+    /// Returns the number of running async systems.
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns `true` if no async system is running.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+}
+
+/// Resumes each async system of the [`AsyncSystems`] resource, if present, and performs the
+/// accesses to the [`World`] they are waiting for.
+///
+/// Async systems that finish are removed. This system should run once per frame.
+pub fn run_async_systems(world: &mut World) {
+    let Some(mut systems) = world.get_resource_mut::<AsyncSystems>() else {
+        return;
+    };
+    let mut tasks = mem::take(&mut systems.tasks);
+    systems.resuming = tasks.iter().map(|task| task.id).collect();
+    tasks.retain_mut(|task| {
+        if check_ready(task.future.get()).is_some() {
+            if let Some(mut systems) = world.get_resource_mut::<AsyncSystems>() {
+                systems.resuming.retain(|id| *id != task.id);
+            }
+            return false;
+        }
+        let requests = mem::take(&mut *task.requests.lock());
+        for request in requests {
+            request(world);
+        }
+        true
+    });
+
+    let Some(mut systems) = world.get_resource_mut::<AsyncSystems>() else {
+        return;
+    };
+    systems.resuming.clear();
+    let cancelled = mem::take(&mut systems.cancelled);
+    tasks.retain(|task| !cancelled.contains(&task.id));
+    // Keep the async systems spawned while the others were running.
+    tasks.append(&mut systems.tasks);
+    systems.tasks = tasks;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::{Res, ResMut, WithParamWarnPolicy};
+    use bevy_tasks::TaskPool;
+
+    #[derive(Resource, Default)]
+    struct Counter(u32);
+
+    #[derive(Resource)]
+    struct Missing;
+
+    #[test]
+    fn async_systems_resume_across_frames() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.init_resource::<AsyncSystems>();
+
+        let id = world
+            .resource_mut::<AsyncSystems>()
+            .spawn(|world| async move {
+                world
+                    .run_system(|mut counter: ResMut<Counter>| counter.0 += 1)
+                    .await
+                    .unwrap();
+                fn needs_missing(_: Res<Missing>) {}
+                let result = world.run_system(needs_missing.ignore_param_missing()).await;
+                assert!(matches!(result, Err(RunSystemError::InvalidParams(_))));
+                let task = TaskPool::new().spawn(async { 10 });
+                let value = task.await;
+                world
+                    .run(move |world| world.resource_mut::<Counter>().0 += value)
+                    .await;
+            });
+
+        run_async_systems(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+        assert!(world.resource::<AsyncSystems>().is_running(id));
+
+        for _ in 0..10_000 {
+            run_async_systems(&mut world);
+            if world.resource::<AsyncSystems>().is_empty() {
+                break;
+            }
+        }
+        assert_eq!(world.resource::<Counter>().0, 11);
+        assert!(!world.resource::<AsyncSystems>().is_running(id));
+    }
+
+    #[test]
+    fn cancel_async_system() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.init_resource::<AsyncSystems>();
+
+        let mut systems = world.resource_mut::<AsyncSystems>();
+        let id = systems.spawn(|world| async move {
+            loop {
+                world
+                    .run(|world| {
+                        world.resource_mut::<Counter>().0 += 1;
+                    })
+                    .await;
+            }
+        });
+        // Cancels the first async system from inside another one.
+        systems.spawn(|world| async move {
+            world.next_frame().await;
+            world
+                .run(move |world| {
+                    assert!(world.resource_mut::<AsyncSystems>().cancel(id));
+                })
+                .await;
+        });
+
+        for _ in 0..5 {
+            run_async_systems(&mut world);
+        }
+        assert_eq!(world.resource::<Counter>().0, 2);
+        assert!(world.resource::<AsyncSystems>().is_empty());
+        assert!(!world.resource_mut::<AsyncSystems>().cancel(id));
+    }
+}
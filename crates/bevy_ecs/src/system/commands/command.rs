@@ -4,6 +4,7 @@
 //! It also contains functions that return closures for use with
 //! [`Commands`](crate::system::Commands).
 
+use core::hash::Hash;
 #[cfg(feature = "track_location")]
 use core::panic::Location;
 
@@ -14,7 +15,7 @@ use crate::{
     observer::TriggerTargets,
     result::{Error, Result},
     schedule::ScheduleLabel,
-    system::{error_handler, IntoSystem, Resource, SystemId, SystemInput},
+    system::{error_handler::CommandErrorHandler, IntoSystem, Resource, SystemId, SystemInput},
     world::{FromWorld, SpawnBatchIter, World},
 };
 
@@ -72,11 +73,14 @@ pub trait HandleError<Out = ()> {
     fn handle_error_with(self, error_handler: fn(&mut World, Error)) -> impl Command;
     /// Takes a [`Command`] that returns a Result and uses the default error handler function to convert it into
     /// a [`Command`] that internally handles an error if it occurs and returns `()`.
+    ///
+    /// The handler is looked up when the command is applied, so a [`CommandErrorHandler`] resource
+    /// in the world takes precedence over the [default error handler](crate::system::error_handler::default).
     fn handle_error(self) -> impl Command
     where
         Self: Sized,
     {
-        self.handle_error_with(error_handler::default())
+        self.handle_error_with(CommandErrorHandler::handle)
     }
 }
 
@@ -245,6 +249,31 @@ where
     }
 }
 
+/// A [`Command`] that runs the system registered with the given key
+/// in a [`SystemPool`](crate::system::SystemPool).
+pub fn run_pooled_system<K>(key: K) -> impl Command<Result>
+where
+    K: Hash + Eq + Send + Sync + 'static,
+{
+    move |world: &mut World| -> Result {
+        world.run_pooled_system::<K, ()>(&key)?;
+        Ok(())
+    }
+}
+
+/// A [`Command`] that runs the system registered with the given key
+/// in a [`SystemPool`](crate::system::SystemPool), and provides the given input value.
+pub fn run_pooled_system_with<K, I>(key: K, input: I::Inner<'static>) -> impl Command<Result>
+where
+    K: Hash + Eq + Send + Sync + 'static,
+    I: SystemInput<Inner<'static>: Send> + 'static,
+{
+    move |world: &mut World| -> Result {
+        world.run_pooled_system_with::<K, I, ()>(&key, input)?;
+        Ok(())
+    }
+}
+
 /// A [`Command`] that removes a system previously registered with
 /// [`Commands::register_system`](crate::system::Commands::register_system) or
 /// [`World::register_system`].
@@ -1,9 +1,91 @@
 //! This module contains convenience functions that return simple error handlers
-//! for use with [`Commands::queue_handled`](super::Commands::queue_handled) and [`EntityCommands::queue_handled`](super::EntityCommands::queue_handled).
+//! for use with [`Commands::queue_handled`](super::Commands::queue_handled) and [`EntityCommands::queue_handled`](super::EntityCommands::queue_handled),
+//! as well as with fallible systems through [`SystemErrorHandler`] and
+//! [`IntoSystemConfigs::on_error`](crate::schedule::IntoSystemConfigs::on_error).
 
-use crate::{result::Error, world::World};
+use alloc::borrow::Cow;
+use core::fmt;
+
+use crate::{self as bevy_ecs, result::Error, system::Resource, world::World};
 use log::{error, warn};
 
+/// A resource that overrides the [default error handler](default) for commands applied to this [`World`].
+///
+/// Unlike `GLOBAL_ERROR_HANDLER`, this can be changed at any point and differ between worlds,
+/// and doesn't require any cargo feature. Commands queued with an explicit handler
+/// (for example through [`Commands::queue_handled`](super::Commands::queue_handled)) are not affected.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::error_handler::{self, CommandErrorHandler};
+/// let mut world = World::new();
+/// // Log errors from failed commands instead of panicking.
+/// world.insert_resource(CommandErrorHandler(error_handler::warn()));
+/// ```
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct CommandErrorHandler(pub fn(&mut World, Error));
+
+impl CommandErrorHandler {
+    /// Handles `error` using the [`CommandErrorHandler`] of `world`,
+    /// falling back to the [default error handler](default) if there is none.
+    pub fn handle(world: &mut World, error: Error) {
+        let handler = world
+            .get_resource::<CommandErrorHandler>()
+            .map_or_else(default, |handler| handler.0);
+        (handler)(world, error);
+    }
+}
+
+/// A resource handling the errors returned by the systems of the schedules run on this [`World`],
+/// instead of panicking.
+///
+/// Errors are handled once the schedule running the system has finished, and are given to the
+/// handler as a [`SystemError`], which can be recovered with [`downcast`](Box::downcast) to know
+/// which system failed. Systems configured with
+/// [`IntoSystemConfigs::on_error`](crate::schedule::IntoSystemConfigs::on_error) use their own
+/// handler instead. Systems without any handler panic when they return an error.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::error_handler::{self, SystemErrorHandler};
+/// let mut world = World::new();
+/// // Log errors returned by systems instead of panicking.
+/// world.insert_resource(SystemErrorHandler(error_handler::error()));
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(|| -> bevy_ecs::result::Result { Err("failed".into()) });
+/// schedule.run(&mut world);
+/// ```
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct SystemErrorHandler(pub fn(&mut World, Error));
+
+/// An error returned by a system, given to the handlers of system errors.
+///
+/// See [`SystemErrorHandler`].
+#[derive(Debug)]
+pub struct SystemError {
+    /// The name of the system that returned the error.
+    pub system: Cow<'static, str>,
+    /// The error returned by the system.
+    pub error: Error,
+}
+
+impl fmt::Display for SystemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Encountered an error in system `{}`: {}",
+            self.system, self.error
+        )
+    }
+}
+
+impl core::error::Error for SystemError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&*self.error)
+    }
+}
+
 /// An error handler that does nothing.
 pub fn silent() -> fn(&mut World, Error) {
     |_, _| {}
@@ -11,8 +11,8 @@ pub use entity_command::EntityCommand;
 #[cfg(feature = "std")]
 pub use parallel_scope::*;
 
-use alloc::boxed::Box;
-use core::marker::PhantomData;
+use alloc::{boxed::Box, vec::Vec};
+use core::{hash::Hash, marker::PhantomData};
 use log::error;
 
 #[cfg(feature = "track_location")]
@@ -95,8 +95,9 @@ use crate::{
 /// an error handler. Error handlers are functions/closures of the form
 /// `fn(&mut World, CommandError)`.
 ///
-/// The default error handler panics. It can be configured by enabling the `configurable_error_handler`
-/// cargo feature, then setting the `GLOBAL_ERROR_HANDLER`.
+/// The default error handler panics. It can be configured per [`World`] by inserting a
+/// [`CommandErrorHandler`](error_handler::CommandErrorHandler) resource, or globally by enabling
+/// the `configurable_error_handler` cargo feature, then setting the `GLOBAL_ERROR_HANDLER`.
 ///
 /// Alternatively, you can customize the error handler for a specific command by calling [`Commands::queue_handled`].
 ///
@@ -499,6 +500,57 @@ impl<'w, 's> Commands<'w, 's> {
         })
     }
 
+    /// Spawns a clone of the `source` entity and returns the [`EntityCommands`] of the clone.
+    ///
+    /// The clone will receive all the components of the original that implement
+    /// [`Clone`] or [`Reflect`](bevy_reflect::Reflect).
+    ///
+    /// This is equivalent to calling [`EntityCommands::clone_and_spawn`] on the `source` entity,
+    /// but doesn't require it to exist when the command is queued.
+    /// To configure cloning behavior, use [`Commands::clone_entity_with`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Resource)]
+    /// struct Prefab(Entity);
+    ///
+    /// fn example_system(mut commands: Commands, prefab: Res<Prefab>) {
+    ///     commands.clone_entity(prefab.0).insert(Health(50));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(example_system);
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// If the `source` entity does not exist when this command is applied,
+    /// the returned entity will have no components.
+    pub fn clone_entity(&mut self, source: Entity) -> EntityCommands {
+        self.clone_entity_with(source, |_| {})
+    }
+
+    /// Spawns a clone of the `source` entity and allows configuring cloning behavior
+    /// using [`EntityCloneBuilder`], returning the [`EntityCommands`] of the clone.
+    ///
+    /// See [`EntityCommands::clone_and_spawn_with`] for more details.
+    pub fn clone_entity_with(
+        &mut self,
+        source: Entity,
+        config: impl FnOnce(&mut EntityCloneBuilder) + Send + Sync + 'static,
+    ) -> EntityCommands {
+        let entity_clone = self.spawn_empty().id();
+        self.queue(entity_command::clone_with(entity_clone, config).with_entity(source));
+        EntityCommands {
+            entity: entity_clone,
+            commands: self.reborrow(),
+        }
+    }
+
     /// Pushes a [`Command`] to the queue for creating entities with a particular [`Bundle`] type.
     ///
     /// `bundles_iter` is a type that can be converted into a [`Bundle`] iterator
@@ -546,6 +598,48 @@ impl<'w, 's> Commands<'w, 's> {
         self.queue(command::spawn_batch(bundles_iter));
     }
 
+    /// Reserves an entity for each bundle of the given batch, and pushes a [`Command`] to the queue
+    /// for inserting the bundles into them. Returns the reserved entities, in the order of the bundles.
+    ///
+    /// Unlike [`spawn`](Self::spawn), which reserves entities one at a time, the entities of
+    /// the whole batch are reserved at once, and the bundles are inserted by a single command.
+    /// This makes it the fastest way of spawning many entities of the same [`Bundle`] type
+    /// whose ids are needed immediately, for example to build relationships between them.
+    /// If the ids are not needed, prefer [`spawn_batch`](Self::spawn_batch).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Particle(u32);
+    /// #
+    /// # fn system(mut commands: Commands) {
+    /// let particles = commands.spawn_batch_reserved((0..10_000).map(Particle));
+    /// assert_eq!(particles.len(), 10_000);
+    /// # }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the batch contains more than `u32::MAX` bundles.
+    #[track_caller]
+    pub fn spawn_batch_reserved<B: Bundle>(
+        &mut self,
+        bundles_iter: impl IntoIterator<Item = B>,
+    ) -> Vec<Entity> {
+        let bundles: Vec<B> = bundles_iter.into_iter().collect();
+        let count = u32::try_from(bundles.len()).expect("too many bundles in the batch");
+        let entities: Vec<Entity> = self.entities.reserve_entities(count).collect();
+        self.queue(command::insert_batch(
+            entities.clone().into_iter().zip(bundles),
+            InsertMode::Replace,
+        ));
+        entities
+    }
+
     /// Pushes a generic [`Command`] to the command queue.
     ///
     /// If the [`Command`] returns a [`Result`], it will be handled using the [default error handler](error_handler::default).
@@ -1034,6 +1128,29 @@ impl<'w, 's> Commands<'w, 's> {
         );
     }
 
+    /// Similar to [`Self::run_system`], but running the system registered with `key` in a
+    /// [`SystemPool`](crate::system::SystemPool).
+    ///
+    /// See [`World::register_pooled_system`] for more information.
+    pub fn run_pooled_system<K: Hash + Eq + Send + Sync + 'static>(&mut self, key: K) {
+        self.queue(command::run_pooled_system(key).handle_error_with(error_handler::warn()));
+    }
+
+    /// Similar to [`Self::run_system_with`], but running the system registered with `key` in a
+    /// [`SystemPool`](crate::system::SystemPool).
+    ///
+    /// See [`World::register_pooled_system`] for more information.
+    pub fn run_pooled_system_with<K, I>(&mut self, key: K, input: I::Inner<'static>)
+    where
+        K: Hash + Eq + Send + Sync + 'static,
+        I: SystemInput<Inner<'static>: Send> + 'static,
+    {
+        self.queue(
+            command::run_pooled_system_with::<K, I>(key, input)
+                .handle_error_with(error_handler::warn()),
+        );
+    }
+
     /// Sends a "global" [`Trigger`] without any targets. This will run any [`Observer`] of the `event` that
     /// isn't scoped to specific targets.
     ///
@@ -1149,8 +1266,9 @@ impl<'w, 's> Commands<'w, 's> {
 /// an error handler. Error handlers are functions/closures of the form
 /// `fn(&mut World, CommandError)`.
 ///
-/// The default error handler panics. It can be configured by enabling the `configurable_error_handler`
-/// cargo feature, then setting the `GLOBAL_ERROR_HANDLER`.
+/// The default error handler panics. It can be configured per [`World`] by inserting a
+/// [`CommandErrorHandler`](error_handler::CommandErrorHandler) resource, or globally by enabling
+/// the `configurable_error_handler` cargo feature, then setting the `GLOBAL_ERROR_HANDLER`.
 ///
 /// Alternatively, you can customize the error handler for a specific command by calling [`EntityCommands::queue_handled`].
 ///
@@ -2154,6 +2272,7 @@ mod tests {
     use crate::{
         self as bevy_ecs,
         component::{require, Component},
+        entity_disabling::DefaultQueryFilters,
         system::{Commands, Resource},
         world::{CommandQueue, FromWorld, World},
     };
@@ -2234,6 +2353,78 @@ mod tests {
         assert_eq!("*****", &world.get::<W<String>>(entity).unwrap().0);
     }
 
+    #[test]
+    fn spawn_batch_reserved() {
+        let mut world = World::default();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let entities = commands.spawn_batch_reserved((0..3_u32).map(W));
+        let other = commands.spawn_empty().id();
+        assert_eq!(entities.len(), 3);
+        assert!(!entities.contains(&other));
+        queue.apply(&mut world);
+
+        for (i, entity) in entities.into_iter().enumerate() {
+            assert_eq!(world.get::<W<u32>>(entity).unwrap().0, i as u32);
+        }
+        assert_eq!(world.entities().len(), 4);
+    }
+
+    #[test]
+    fn clone_entity() {
+        #[derive(Component, Clone, PartialEq, Debug)]
+        struct A(u32);
+        #[derive(Component, Clone, PartialEq, Debug)]
+        struct B(u32);
+
+        let mut world = World::default();
+        let source = world.spawn((A(1), B(2))).id();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let full = commands.clone_entity(source).id();
+        let partial = commands
+            .clone_entity_with(source, |builder| {
+                builder.deny::<B>();
+            })
+            .id();
+        queue.apply(&mut world);
+
+        assert_eq!(world.get::<A>(full), Some(&A(1)));
+        assert_eq!(world.get::<B>(full), Some(&B(2)));
+        assert_eq!(world.get::<A>(partial), Some(&A(1)));
+        assert_eq!(world.get::<B>(partial), None);
+        assert_eq!(world.get::<A>(source), Some(&A(1)));
+    }
+
+    #[test]
+    fn command_error_handler_resource() {
+        use crate::{result::Error, system::error_handler::CommandErrorHandler};
+
+        #[derive(Resource, Default)]
+        struct Failures(usize);
+
+        fn count_failures(world: &mut World, _: Error) {
+            world.resource_mut::<Failures>().0 += 1;
+        }
+
+        let mut world = World::default();
+        world.init_resource::<Failures>();
+        world.insert_resource(CommandErrorHandler(count_failures));
+        let entity = world.spawn_empty().id();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.entity(entity).despawn();
+        commands.entity(entity).insert(W(1u32));
+        commands.entity(entity).clear();
+        // Explicit handlers take precedence over the resource.
+        commands.entity(entity).try_insert(W(2u32));
+        queue.apply(&mut world);
+
+        assert_eq!(world.resource::<Failures>().0, 2);
+    }
+
     #[test]
     fn commands() {
         let mut world = World::default();
@@ -2490,6 +2681,7 @@ mod tests {
 
         fn nothing() {}
 
+        world.remove_resource::<DefaultQueryFilters>();
         assert!(world.iter_resources().count() == 0);
         let id = world.register_system_cached(nothing);
         assert!(world.iter_resources().count() == 1);
@@ -120,6 +120,8 @@
 //! [`Vec<P>`]: alloc::vec::Vec
 
 mod adapter_system;
+#[cfg(any(feature = "async_executor", feature = "edge_executor"))]
+mod async_system;
 mod builder;
 mod combinator;
 mod commands;
@@ -135,10 +137,14 @@ mod system;
 mod system_name;
 mod system_param;
 mod system_registry;
+#[cfg(any(feature = "async_executor", feature = "edge_executor"))]
+mod tasks;
 
 use core::any::TypeId;
 
 pub use adapter_system::*;
+#[cfg(any(feature = "async_executor", feature = "edge_executor"))]
+pub use async_system::*;
 pub use builder::*;
 pub use combinator::*;
 pub use commands::*;
@@ -153,6 +159,8 @@ pub use system::*;
 pub use system_name::*;
 pub use system_param::*;
 pub use system_registry::*;
+#[cfg(any(feature = "async_executor", feature = "edge_executor"))]
+pub use tasks::*;
 
 use crate::world::World;
 
@@ -4,14 +4,18 @@ use crate::{
     entity::{Entity, EntityBorrow, EntitySet},
     query::{
         QueryCombinationIter, QueryData, QueryEntityError, QueryFilter, QueryIter, QueryManyIter,
-        QueryManyUniqueIter, QueryParIter, QuerySingleError, QueryState, ROQueryItem,
-        ReadOnlyQueryData,
+        QueryManyUniqueIter, QueryParIter, QuerySingleError, QuerySortCache, QuerySortedIter,
+        QueryState, ROQueryItem, ReadOnlyQueryData,
     },
     world::unsafe_world_cell::UnsafeWorldCell,
 };
+use alloc::vec::Vec;
 use core::{
+    cmp::Ordering,
+    iter,
     marker::PhantomData,
     ops::{Deref, DerefMut},
+    slice,
 };
 
 /// [System parameter] that provides selective access to the [`Component`] data stored in a [`World`].
@@ -523,6 +527,131 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
         }
     }
 
+    /// Returns an [`Iterator`] over the read-only query items, stably sorted by a key extracted
+    /// from the query lens `L`.
+    ///
+    /// Unlike [`QueryIter::sort_by_key`], the sorted order is kept in `cache` across system runs,
+    /// and the entities are only sorted again when the matched entities changed, or when a
+    /// component read by `L` was changed since the last sort.
+    /// Checking for those changes is linear in the number of matched entities.
+    ///
+    /// Defining the lens works like [`QueryIter::sort_by_key`].
+    /// As the cache relies on change detection, the key should only depend on the components
+    /// read by `L`. Like [`Changed`](crate::query::Changed), changes made by the system itself
+    /// are not detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::{prelude::*, query::QuerySortCache};
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Name(&'static str);
+    /// #[derive(Component)]
+    /// struct Initiative(u32);
+    ///
+    /// fn take_turns(query: Query<(&Name, &Initiative)>, mut order: Local<QuerySortCache>) {
+    ///     for (name, _) in query.iter_sorted_by_key::<&Initiative, _>(&mut order, |initiative| initiative.0) {
+    ///         println!("{}'s turn", name.0);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(take_turns);
+    /// ```
+    pub fn iter_sorted_by_key<'a, L: ReadOnlyQueryData + 'a, K: Ord>(
+        &'a self,
+        cache: &'a mut QuerySortCache,
+        mut f: impl FnMut(&L::Item<'a>) -> K,
+    ) -> QuerySortedIter<'a, 's, D::ReadOnly, F, iter::Copied<slice::Iter<'a, Entity>>> {
+        self.update_sort_cache::<L>(cache, |keyed| {
+            keyed.sort_by_key(|(lens, _)| f(lens));
+        });
+        self.iter_cached_order(cache)
+    }
+
+    /// Returns an [`Iterator`] over the read-only query items, stably sorted by a comparator
+    /// function over the query lens `L`.
+    ///
+    /// The sorted order is kept in `cache` across system runs.
+    /// See [`iter_sorted_by_key`](Self::iter_sorted_by_key) for more information.
+    pub fn iter_sorted_by<'a, L: ReadOnlyQueryData + 'a>(
+        &'a self,
+        cache: &'a mut QuerySortCache,
+        mut compare: impl FnMut(&L::Item<'a>, &L::Item<'a>) -> Ordering,
+    ) -> QuerySortedIter<'a, 's, D::ReadOnly, F, iter::Copied<slice::Iter<'a, Entity>>> {
+        self.update_sort_cache::<L>(cache, |keyed| {
+            keyed.sort_by(|(a, _), (b, _)| compare(a, b));
+        });
+        self.iter_cached_order(cache)
+    }
+
+    /// Sorts the entities of this query into `cache`, unless its order is still valid.
+    fn update_sort_cache<'a, L: ReadOnlyQueryData + 'a>(
+        &'a self,
+        cache: &mut QuerySortCache,
+        sort: impl FnOnce(&mut [(L::Item<'a>, Entity)]),
+    ) {
+        let lens_state = self.state.transmute_filtered::<(L, Entity), F>(self.world);
+        let (lens_reads, reads_all) = lens_state
+            .component_access()
+            .access()
+            .component_reads_and_writes();
+        let lens_reads: Vec<_> = lens_reads.collect();
+
+        let iter_lens = || {
+            // SAFETY:
+            // - `self.world` has permission to access the components read by the lens.
+            // - The lens is read-only, so it can be aliased even if the query was originally mutable.
+            unsafe { lens_state.iter_unchecked_manual(self.world, self.last_run, self.this_run) }
+        };
+
+        if let Some(sorted_at) = cache.sorted_at.filter(|_| !reads_all) {
+            let mut matched = 0;
+            let is_valid = iter_lens().all(|(_, entity)| {
+                matched += 1;
+                cache.matched.contains(&entity)
+                    && !lens_reads.iter().any(|&component_id| {
+                        self.world.get_entity(entity).is_some_and(|cell| {
+                            // SAFETY: The lens has read access to `component_id`.
+                            unsafe { cell.get_change_ticks_by_id(component_id) }
+                                .is_some_and(|ticks| ticks.is_changed(sorted_at, self.this_run))
+                        })
+                    })
+            });
+            if is_valid && matched == cache.entities.len() {
+                return;
+            }
+        }
+
+        let mut keyed: Vec<_> = iter_lens().collect();
+        sort(&mut keyed);
+        cache.entities.clear();
+        cache
+            .entities
+            .extend(keyed.iter().map(|(_, entity)| *entity));
+        cache.matched.clear();
+        cache.matched.extend(cache.entities.iter().copied());
+        cache.sorted_at = Some(self.this_run);
+    }
+
+    fn iter_cached_order<'a>(
+        &'a self,
+        cache: &'a QuerySortCache,
+    ) -> QuerySortedIter<'a, 's, D::ReadOnly, F, iter::Copied<slice::Iter<'a, Entity>>> {
+        // SAFETY:
+        // - `self.world` has permission to access the required components.
+        // - The query is read-only, so it can be aliased even if it was originally mutable.
+        // - The cache only contains unique entities.
+        unsafe {
+            QuerySortedIter::new(
+                self.world,
+                self.state.as_readonly(),
+                cache.entities.iter().copied(),
+                self.last_run,
+                self.this_run,
+            )
+        }
+    }
+
     /// Returns a [`QueryCombinationIter`] over all combinations of `K` read-only query items without repetition.
     ///
     /// This iterator is always guaranteed to return results from each unique pair of matching entities.
@@ -1721,6 +1850,51 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
         self.join_filtered(other)
     }
 
+    /// Returns a [`QueryLens`] yielding the items of both queries, for the entities matched by both.
+    ///
+    /// Unlike [`Self::join`], the filters of both queries are kept, so non-archetypal filters like
+    /// `Added` and `Changed` are respected. Only the archetypes matched by both queries are iterated,
+    /// which is faster than iterating one query and calling `get` on the other one for each entity.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Transform;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Velocity;
+    /// #
+    /// # let mut world = World::default();
+    /// # world.spawn((Transform, Velocity));
+    ///
+    /// fn system(
+    ///     mut transforms: Query<&mut Transform>,
+    ///     mut velocities: Query<&Velocity, Changed<Velocity>>,
+    /// ) {
+    ///     let mut joined = transforms.join_items(&mut velocities);
+    ///     for (transform, velocity) in &mut joined.query() {
+    ///         // do something with transform and velocity
+    ///     }
+    /// }
+    ///
+    /// # let mut schedule = Schedule::default();
+    /// # schedule.add_systems(system);
+    /// # schedule.run(&mut world);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// This will panic if the data accessed by `D` and `OtherD` conflicts.
+    pub fn join_items<OtherD: QueryData, OtherF: QueryFilter>(
+        &mut self,
+        other: &mut Query<OtherD, OtherF>,
+    ) -> QueryLens<'_, (D, OtherD), (F, OtherF)> {
+        self.join_filtered(other)
+    }
+
     /// Equivalent to [`Self::join`] but also includes a [`QueryFilter`] type.
     ///
     /// Note that the lens with iterate a subset of the original queries' tables
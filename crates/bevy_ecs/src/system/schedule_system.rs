@@ -1,4 +1,5 @@
 use alloc::{borrow::Cow, vec::Vec};
+use core::any::TypeId;
 
 use crate::{
     archetype::ArchetypeComponentId,
@@ -30,6 +31,11 @@ impl<S: System<In = (), Out = ()>> System for InfallibleSystemWrapper<S> {
         self.0.name()
     }
 
+    #[inline]
+    fn type_id(&self) -> TypeId {
+        self.0.type_id()
+    }
+
     #[inline]
     fn component_access(&self) -> &Access<ComponentId> {
         self.0.component_access()
@@ -11,7 +11,8 @@ use alloc::boxed::Box;
 use bevy_ecs_macros::{require, Component, Resource};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
-use core::marker::PhantomData;
+use bevy_utils::HashMap;
+use core::{hash::Hash, marker::PhantomData};
 use thiserror::Error;
 
 /// A small wrapper for [`BoxedSystem`] that also keeps track whether or not the system has been initialized.
@@ -111,7 +112,7 @@ impl<I: SystemInput, O> PartialEq for SystemId<I, O> {
 }
 
 // A manual impl is used because the trait bounds should ignore the `I` and `O` phantom parameters.
-impl<I: SystemInput, O> core::hash::Hash for SystemId<I, O> {
+impl<I: SystemInput, O> Hash for SystemId<I, O> {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.entity.hash(state);
     }
@@ -129,6 +130,51 @@ impl<I: SystemInput, O> core::fmt::Debug for SystemId<I, O> {
 #[derive(Resource)]
 pub struct CachedSystemId<S: System>(pub SystemId<S::In, S::Out>);
 
+/// The [`SystemId`]s of the systems registered with [`World::register_pooled_system`], by key.
+///
+/// This resource is inserted by [`World::register_pooled_system`], with one resource per key type.
+#[derive(Resource)]
+pub struct SystemPool<K: Hash + Eq + Send + Sync + 'static> {
+    ids: HashMap<K, Entity>,
+}
+
+impl<K: Hash + Eq + Send + Sync + 'static> Default for SystemPool<K> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Send + Sync + 'static> SystemPool<K> {
+    /// Returns the [`SystemId`] of the system registered with `key`, if any.
+    ///
+    /// `I` and `O` must be the input and output types the system was registered with.
+    pub fn get<I: SystemInput, O>(&self, key: &K) -> Option<SystemId<I, O>> {
+        self.ids.get(key).copied().map(SystemId::from_entity)
+    }
+
+    /// Returns `true` if a system is registered with `key`.
+    pub fn contains(&self, key: &K) -> bool {
+        self.ids.contains_key(key)
+    }
+
+    /// Returns an iterator over the keys of the registered systems.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.ids.keys()
+    }
+
+    /// Returns the number of registered systems.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns `true` if no system is registered.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
+
 impl World {
     /// Registers a system and returns a [`SystemId`] so it can later be called by [`World::run_system`].
     ///
@@ -165,6 +211,34 @@ impl World {
         SystemId::from_entity(entity)
     }
 
+    /// Initializes a registered system ahead of its first run, so that running it doesn't pay for
+    /// the initialization of its parameters.
+    ///
+    /// Does nothing if the system has already been initialized.
+    pub fn warm_up_system<I, O>(
+        &mut self,
+        id: SystemId<I, O>,
+    ) -> Result<(), RegisteredSystemError<I, O>>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let mut entity = self
+            .get_entity_mut(id.entity)
+            .map_err(|_| RegisteredSystemError::SystemIdNotRegistered(id))?;
+        let mut registered_system = entity
+            .take::<RegisteredSystem<I, O>>()
+            .ok_or(RegisteredSystemError::Recursive(id))?;
+        if !registered_system.initialized {
+            registered_system.system.initialize(self);
+            registered_system.initialized = true;
+        }
+        if let Ok(mut entity) = self.get_entity_mut(id.entity) {
+            entity.insert(registered_system);
+        }
+        Ok(())
+    }
+
     /// Removes a registered system and returns the system, if it exists.
     /// After removing a system, the [`SystemId`] becomes invalid and attempting to use it afterwards will result in errors.
     /// Re-adding the removed system will register it on a new [`SystemId`].
@@ -454,6 +528,119 @@ impl World {
         let id = self.register_system_cached(system);
         self.run_system_with(id, input)
     }
+
+    /// Registers a system in the pool of systems keyed by `K` and [warms it up](World::warm_up_system),
+    /// or returns the [`SystemId`] of the system already registered with `key`.
+    ///
+    /// Unlike [`World::register_system_cached`], any system can be pooled, including closures that
+    /// capture their environment. The keys are stored in a [`SystemPool<K>`] resource, which lets
+    /// event-driven code run systems by key with [`World::run_pooled_system`] while their state is
+    /// kept between runs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource, Default)]
+    /// struct Score(u32);
+    ///
+    /// #[derive(Hash, PartialEq, Eq)]
+    /// enum Reward {
+    ///     Coin,
+    ///     Gem,
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<Score>();
+    /// for (reward, points) in [(Reward::Coin, 1), (Reward::Gem, 10)] {
+    ///     world.register_pooled_system(reward, move |mut score: ResMut<Score>| score.0 += points);
+    /// }
+    ///
+    /// world.run_pooled_system::<_, ()>(&Reward::Gem).unwrap();
+    /// world.run_pooled_system::<_, ()>(&Reward::Coin).unwrap();
+    /// assert_eq!(world.resource::<Score>().0, 11);
+    /// ```
+    pub fn register_pooled_system<K, I, O, M>(
+        &mut self,
+        key: K,
+        system: impl IntoSystem<I, O, M> + 'static,
+    ) -> SystemId<I, O>
+    where
+        K: Hash + Eq + Send + Sync + 'static,
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let pooled = self
+            .get_resource::<SystemPool<K>>()
+            .and_then(|pool| pool.get::<I, O>(&key));
+        if let Some(id) = pooled {
+            if self
+                .get_entity(id.entity)
+                .is_ok_and(|entity| entity.contains::<RegisteredSystem<I, O>>())
+            {
+                return id;
+            }
+        }
+
+        let id = self.register_system(system);
+        // The system was just registered, so it can't fail to warm up.
+        let _ = self.warm_up_system(id);
+        self.get_resource_or_init::<SystemPool<K>>()
+            .ids
+            .insert(key, id.entity);
+        id
+    }
+
+    /// Removes the system registered with `key` from its [`SystemPool`].
+    ///
+    /// See [`World::register_pooled_system`] for more information.
+    pub fn unregister_pooled_system<K, I, O>(
+        &mut self,
+        key: &K,
+    ) -> Result<RemovedSystem<I, O>, RegisteredSystemError<I, O>>
+    where
+        K: Hash + Eq + Send + Sync + 'static,
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let entity = self
+            .get_resource_mut::<SystemPool<K>>()
+            .and_then(|mut pool| pool.ids.remove(key))
+            .ok_or(RegisteredSystemError::SystemNotPooled)?;
+        self.unregister_system(SystemId::from_entity(entity))
+    }
+
+    /// Runs the system registered with `key` in the pool of systems keyed by `K`.
+    ///
+    /// See [`World::register_pooled_system`] for more information.
+    pub fn run_pooled_system<K, O>(&mut self, key: &K) -> Result<O, RegisteredSystemError<(), O>>
+    where
+        K: Hash + Eq + Send + Sync + 'static,
+        O: 'static,
+    {
+        self.run_pooled_system_with(key, ())
+    }
+
+    /// Runs the system registered with `key` in the pool of systems keyed by `K`, providing an
+    /// input value.
+    ///
+    /// See [`World::register_pooled_system`] for more information.
+    pub fn run_pooled_system_with<K, I, O>(
+        &mut self,
+        key: &K,
+        input: I::Inner<'_>,
+    ) -> Result<O, RegisteredSystemError<I, O>>
+    where
+        K: Hash + Eq + Send + Sync + 'static,
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let id = self
+            .get_resource::<SystemPool<K>>()
+            .and_then(|pool| pool.get(key))
+            .ok_or(RegisteredSystemError::SystemNotPooled)?;
+        self.run_system_with(id, input)
+    }
 }
 
 /// An operation with stored systems failed.
@@ -469,6 +656,11 @@ pub enum RegisteredSystemError<I: SystemInput = (), O = ()> {
     /// Did you forget to register it?
     #[error("Cached system was not found")]
     SystemNotCached,
+    /// A pooled system was run or removed by key, but no system was registered with that key.
+    ///
+    /// Did you forget to register it?
+    #[error("No system was registered with this key in the system pool")]
+    SystemNotPooled,
     /// A system tried to run itself recursively.
     #[error("System {0:?} tried to run itself recursively")]
     Recursive(SystemId<I, O>),
@@ -489,6 +681,7 @@ impl<I: SystemInput, O> core::fmt::Debug for RegisteredSystemError<I, O> {
                 f.debug_tuple("SystemIdNotRegistered").field(arg0).finish()
             }
             Self::SystemNotCached => write!(f, "SystemNotCached"),
+            Self::SystemNotPooled => write!(f, "SystemNotPooled"),
             Self::Recursive(arg0) => f.debug_tuple("Recursive").field(arg0).finish(),
             Self::SelfRemove(arg0) => f.debug_tuple("SelfRemove").field(arg0).finish(),
             Self::InvalidParams(arg0) => f.debug_tuple("InvalidParams").field(arg0).finish(),
@@ -737,6 +930,66 @@ mod tests {
         assert_eq!(world.resource::<Counter>().0, 1);
     }
 
+    #[test]
+    fn pooled_system() {
+        use crate::system::{RegisteredSystemError, SystemPool};
+        use alloc::vec::Vec;
+
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+
+        let ids: Vec<_> = (1..=3u8)
+            .map(|step| {
+                world.register_pooled_system(
+                    step,
+                    move |mut counter: ResMut<Counter>, mut runs: Local<u8>| {
+                        *runs += 1;
+                        counter.0 += step * *runs;
+                    },
+                )
+            })
+            .collect();
+        assert_eq!(world.resource::<SystemPool<u8>>().len(), 3);
+        // Registering a system with an existing key returns the pooled system.
+        let id = world.register_pooled_system(2u8, || {});
+        assert_eq!(id, ids[1]);
+
+        world.run_pooled_system::<u8, ()>(&2).unwrap();
+        world.run_pooled_system::<u8, ()>(&2).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(6));
+
+        world.commands().run_pooled_system(3u8);
+        world.flush_commands();
+        assert_eq!(*world.resource::<Counter>(), Counter(9));
+
+        // Pooled systems are initialized when registered.
+        let removed = world.unregister_pooled_system::<u8, (), ()>(&1).unwrap();
+        assert!(removed.initialized());
+        assert!(matches!(
+            world.run_pooled_system::<u8, ()>(&1),
+            Err(RegisteredSystemError::SystemNotPooled)
+        ));
+    }
+
+    #[test]
+    fn pooled_system_with_input() {
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+        world.register_pooled_system("add", |In(n): In<u8>, mut counter: ResMut<Counter>| {
+            counter.0 += n;
+        });
+        world.register_pooled_system("get", |counter: Res<Counter>| counter.0);
+
+        world
+            .run_pooled_system_with::<_, In<u8>, ()>(&"add", 5)
+            .unwrap();
+        world
+            .commands()
+            .run_pooled_system_with::<_, In<u8>>("add", 2);
+        world.flush_commands();
+        assert_eq!(world.run_pooled_system::<_, u8>(&"get").unwrap(), 7);
+    }
+
     #[test]
     fn cached_system_adapters() {
         fn four() -> i32 {
@@ -0,0 +1,290 @@
+use alloc::vec::Vec;
+use bevy_tasks::TrackedTask;
+
+use crate::{
+    self as bevy_ecs,
+    event::{Event, EventWriter},
+    system::{Local, Res, Resource, SystemParam},
+};
+
+/// Identifies a task spawned by a [`Tasks`] system parameter.
+///
+/// Identifiers are only unique for a given system.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TaskId(u64);
+
+/// Sent by [`Tasks::poll`] when a task has finished running.
+#[derive(Event, Debug)]
+pub struct TaskCompleted<T: Send + Sync + 'static> {
+    /// The task that finished.
+    pub id: TaskId,
+    /// The output of the task.
+    pub output: T,
+    /// Whether the task was asked to stop before finishing, in which case its output may be
+    /// partial.
+    pub cancelled: bool,
+}
+
+/// The order in which [`Tasks::poll`] reports finished tasks.
+///
+/// Insert this resource with [`TaskOrdering::Submission`] to make the results of tasks reach the
+/// world in a reproducible order, for replays and lockstep simulations, while the tasks still run
+/// on other threads. The default, when the resource is missing, is [`TaskOrdering::Completion`].
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TaskOrdering {
+    /// Tasks are reported as soon as they finish, in the order they are found finished.
+    #[default]
+    Completion,
+    /// Tasks are reported in the order they were pushed: a finished task is held back until all
+    /// the tasks pushed before it by the same system have been reported.
+    ///
+    /// This makes the order of the [`TaskCompleted`] events independent of the timing of the
+    /// tasks, but not the update during which they are sent.
+    Submission,
+}
+
+/// The tasks owned by a [`Tasks`] system parameter.
+pub struct TaskList<T> {
+    next_id: u64,
+    tasks: Vec<(TaskId, TrackedTask<T>)>,
+}
+
+impl<T> Default for TaskList<T> {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            tasks: Vec::new(),
+        }
+    }
+}
+
+/// A [`SystemParam`] running asynchronous tasks for a system, and sending a [`TaskCompleted`]
+/// event with the output of each task once it finishes.
+///
+/// The tasks are polled each time [`poll`](Self::poll) is called, typically once per run of the
+/// system, and can be cancelled or queried for their progress in the meantime. They are tracked
+/// with [`TrackedTask`], which means that cancellation is cooperative: a cancelled task still
+/// completes, but is expected to stop early.
+///
+/// Tasks are reported in the order set by the [`TaskOrdering`] resource.
+///
+/// The [`TaskCompleted<T>`] event must be registered with the world, for example with
+/// `App::add_event`.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::{Tasks, TaskCompleted};
+/// # use bevy_tasks::AsyncComputeTaskPool;
+/// #[derive(Resource)]
+/// struct Generate(u32);
+///
+/// fn generate_terrain(mut tasks: Tasks<Vec<f32>>, request: Option<Res<Generate>>) {
+///     if let Some(request) = request.filter(|request| request.is_added()) {
+///         let size = request.0;
+///         let pool = AsyncComputeTaskPool::get();
+///         tasks.push(pool.spawn_tracked(move |context| async move {
+///             let mut heights = Vec::new();
+///             for i in 0..size {
+///                 if context.is_cancelled() {
+///                     break;
+///                 }
+///                 heights.push(i as f32);
+///                 context.set_progress((i + 1) as f32 / size as f32);
+///             }
+///             heights
+///         }));
+///     }
+///     for (_, progress) in tasks.progress() {
+///         // Update a loading bar.
+///     }
+///     tasks.poll();
+/// }
+///
+/// fn spawn_terrain(mut completed: EventReader<TaskCompleted<Vec<f32>>>) {
+///     for terrain in completed.read() {
+///         // Use `terrain.output`.
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(generate_terrain);
+/// # bevy_ecs::system::assert_is_system(spawn_terrain);
+/// ```
+#[derive(SystemParam)]
+pub struct Tasks<'w, 's, T: Send + Sync + 'static> {
+    list: Local<'s, TaskList<T>>,
+    completed: EventWriter<'w, TaskCompleted<T>>,
+    ordering: Option<Res<'w, TaskOrdering>>,
+}
+
+impl<'w, 's, T: Send + Sync + 'static> Tasks<'w, 's, T> {
+    /// Starts tracking a task, usually spawned with `TaskPool::spawn_tracked`.
+    pub fn push(&mut self, task: TrackedTask<T>) -> TaskId {
+        let id = TaskId(self.list.next_id);
+        self.list.next_id += 1;
+        self.list.tasks.push((id, task));
+        id
+    }
+
+    /// Sends a [`TaskCompleted`] event for each task that has finished since the last call, in
+    /// the order set by [`TaskOrdering`], and stops tracking them. Returns the number of finished
+    /// tasks.
+    pub fn poll(&mut self) -> usize {
+        let ordering = self.ordering.as_deref().copied().unwrap_or_default();
+        let completed = &mut self.completed;
+        let mut send = |id: TaskId, task: &TrackedTask<T>, output: T| {
+            completed.send(TaskCompleted {
+                id,
+                output,
+                cancelled: task.is_cancelled(),
+            });
+        };
+        match ordering {
+            TaskOrdering::Completion => {
+                let running = self.list.tasks.len();
+                self.list
+                    .tasks
+                    .retain_mut(|(id, task)| match task.try_take() {
+                        Some(output) => {
+                            send(*id, task, output);
+                            false
+                        }
+                        None => true,
+                    });
+                running - self.list.tasks.len()
+            }
+            TaskOrdering::Submission => {
+                let mut finished = 0;
+                // Tasks are stored in the order they were pushed.
+                for (id, task) in &mut self.list.tasks {
+                    let Some(output) = task.try_take() else {
+                        break;
+                    };
+                    send(*id, task, output);
+                    finished += 1;
+                }
+                self.list.tasks.drain(..finished);
+                finished
+            }
+        }
+    }
+
+    /// Returns the task with the given `id`, if it is still running.
+    pub fn get(&self, id: TaskId) -> Option<&TrackedTask<T>> {
+        self.list
+            .tasks
+            .iter()
+            .find(|(task_id, _)| *task_id == id)
+            .map(|(_, task)| task)
+    }
+
+    /// Asks the task with the given `id` to stop. Returns `false` if it isn't running anymore.
+    pub fn cancel(&self, id: TaskId) -> bool {
+        self.get(id).inspect(|task| task.cancel()).is_some()
+    }
+
+    /// Asks all the running tasks to stop.
+    pub fn cancel_all(&self) {
+        for (_, task) in &self.list.tasks {
+            task.cancel();
+        }
+    }
+
+    /// Iterates over the running tasks and their progress.
+    pub fn progress(&self) -> impl Iterator<Item = (TaskId, f32)> + '_ {
+        self.list
+            .tasks
+            .iter()
+            .map(|(id, task)| (*id, task.progress()))
+    }
+
+    /// Returns the number of running tasks.
+    pub fn len(&self) -> usize {
+        self.list.tasks.len()
+    }
+
+    /// Returns `true` if no task is running.
+    pub fn is_empty(&self) -> bool {
+        self.list.tasks.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::{EventReader, Events},
+        system::{IntoSystem, Res, Resource, RunSystemOnce, System},
+        world::World,
+    };
+    use bevy_tasks::{TaskContext, TaskPool};
+
+    #[derive(Resource)]
+    struct Pool(TaskPool);
+
+    /// Waits for the task to finish on the threads of the pool, so that the next
+    /// [`Tasks::poll`] finds it finished whatever the timing of these threads.
+    fn wait_for<T: Send + Sync + 'static>(tasks: &Tasks<T>, id: TaskId) {
+        while !tasks.get(id).unwrap().is_finished() {
+            std::thread::yield_now();
+        }
+    }
+
+    #[test]
+    fn tasks_send_completion_events() {
+        let mut world = World::new();
+        world.init_resource::<Events<TaskCompleted<u32>>>();
+        world.insert_resource(Pool(TaskPool::new()));
+
+        let running = world
+            .run_system_once(|mut tasks: Tasks<u32>, pool: Res<Pool>| {
+                let first = tasks.push(pool.0.spawn_tracked(|_| async { 1 }));
+                let context = TaskContext::default();
+                let second = tasks.push(TrackedTask::new(pool.0.spawn(async { 2 }), context));
+                assert!(tasks.cancel(second));
+                wait_for(&tasks, first);
+                wait_for(&tasks, second);
+                assert_eq!(tasks.poll(), 2);
+                tasks.len()
+            })
+            .unwrap();
+        assert_eq!(running, 0);
+
+        let outputs = world
+            .run_system_once(|mut completed: EventReader<TaskCompleted<u32>>| {
+                let mut outputs: Vec<_> = completed
+                    .read()
+                    .map(|event| (event.output, event.cancelled))
+                    .collect();
+                outputs.sort();
+                outputs
+            })
+            .unwrap();
+        assert_eq!(outputs, [(1, false), (2, true)]);
+    }
+
+    #[test]
+    fn submission_ordering_holds_back_later_tasks() {
+        let mut world = World::new();
+        world.init_resource::<Events<TaskCompleted<u32>>>();
+        world.insert_resource(TaskOrdering::Submission);
+        world.insert_resource(Pool(TaskPool::new()));
+
+        let mut system = IntoSystem::into_system(
+            |mut tasks: Tasks<u32>, pool: Res<Pool>, mut spawned: Local<bool>| {
+                if !*spawned {
+                    *spawned = true;
+                    tasks.push(pool.0.spawn_tracked(|_| core::future::pending()));
+                    let finishing = tasks.push(pool.0.spawn_tracked(|_| async { 1 }));
+                    wait_for(&tasks, finishing);
+                }
+                tasks.poll()
+            },
+        );
+        system.initialize(&mut world);
+        // The second task has finished, but the first one never does.
+        assert_eq!(system.run((), &mut world), 0);
+        assert_eq!(system.run((), &mut world), 0);
+
+        world.insert_resource(TaskOrdering::Completion);
+        assert_eq!(system.run((), &mut world), 1);
+    }
+}
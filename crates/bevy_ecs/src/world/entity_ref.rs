@@ -12,7 +12,7 @@ use crate::{
     removal_detection::RemovedComponentEvents,
     storage::Storages,
     system::{IntoObserverSystem, Resource},
-    world::{error::EntityComponentError, DeferredWorld, Mut, World},
+    world::{error::EntityComponentError, DeferredWorld, Mut, World, WorldId},
 };
 use alloc::vec::Vec;
 use bevy_ptr::{OwningPtr, Ptr};
@@ -28,6 +28,12 @@ use core::{
 };
 use thiserror::Error;
 
+#[cfg(feature = "bevy_reflect")]
+use {
+    crate::reflect::ReflectComponent,
+    bevy_reflect::{Reflect, TypeRegistry},
+};
+
 use super::{unsafe_world_cell::UnsafeEntityCell, Ref, ON_REMOVE, ON_REPLACE};
 
 /// A read-only reference to a particular [`Entity`] and all of its components.
@@ -287,6 +293,51 @@ impl<'w> EntityRef<'w> {
         unsafe { self.cell.get_components::<Q>() }
     }
 
+    /// Returns an iterator over the components of the entity whose type is registered in
+    /// `registry` with [`ReflectComponent`], as reflected references along with their
+    /// [`ComponentId`].
+    ///
+    /// Components without [`ReflectComponent`] type data are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_reflect::{Reflect, TypeRegistry};
+    /// #[derive(Component, Reflect)]
+    /// #[reflect(Component)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct NotReflected;
+    ///
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<Health>();
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn((Health(10), NotReflected)).id();
+    ///
+    /// let components: Vec<_> = world.entity(entity).iter_reflect(&registry).collect();
+    /// assert_eq!(components.len(), 1);
+    /// assert!(components[0].1.is::<Health>());
+    /// ```
+    #[cfg(feature = "bevy_reflect")]
+    pub fn iter_reflect<'r>(
+        &self,
+        registry: &'r TypeRegistry,
+    ) -> impl Iterator<Item = (ComponentId, &'w dyn Reflect)> + 'r
+    where
+        'w: 'r,
+    {
+        let entity = *self;
+        let components = self.cell.world().components();
+        self.cell.archetype().components().filter_map(move |id| {
+            let type_id = components.get_info(id)?.type_id()?;
+            let reflect_component = registry.get_type_data::<ReflectComponent>(type_id)?;
+            Some((id, reflect_component.reflect(entity)?))
+        })
+    }
+
     /// Returns the source code location from which this entity has been spawned.
     #[cfg(feature = "track_location")]
     pub fn spawned_by(&self) -> &'static Location<'static> {
@@ -879,6 +930,46 @@ impl<'w> EntityMut<'w> {
         unsafe { component_ids.fetch_mut(self.cell) }
     }
 
+    /// Returns an iterator over the components of the entity whose type is registered in
+    /// `registry` with [`ReflectComponent`], as reflected references along with their
+    /// [`ComponentId`].
+    ///
+    /// See [`EntityRef::iter_reflect`] for more details.
+    #[cfg(feature = "bevy_reflect")]
+    pub fn iter_reflect<'a>(
+        &'a self,
+        registry: &'a TypeRegistry,
+    ) -> impl Iterator<Item = (ComponentId, &'a dyn Reflect)> + 'a {
+        self.as_readonly().iter_reflect(registry)
+    }
+
+    /// Returns an iterator over the mutable components of the entity whose type is registered in
+    /// `registry` with [`ReflectComponent`], as mutable reflected references along with their
+    /// [`ComponentId`].
+    ///
+    /// Components without [`ReflectComponent`] type data and
+    /// [immutable](crate::component::Immutable) components are skipped.
+    #[cfg(feature = "bevy_reflect")]
+    pub fn iter_reflect_mut<'a>(
+        &'a mut self,
+        registry: &'a TypeRegistry,
+    ) -> impl Iterator<Item = (ComponentId, Mut<'a, dyn Reflect>)> + 'a {
+        let cell = self.cell;
+        let components = cell.world().components();
+        cell.archetype().components().filter_map(move |id| {
+            let info = components.get_info(id)?;
+            if !info.mutable() {
+                return None;
+            }
+            let reflect_component = registry.get_type_data::<ReflectComponent>(info.type_id()?)?;
+            // SAFETY:
+            // - `&mut self` ensures that no references exist to this entity's components.
+            // - Each component of the archetype is only visited once, and is mutable.
+            let component = unsafe { reflect_component.reflect_unchecked_mut(cell) }?;
+            Some((id, component))
+        })
+    }
+
     /// Returns the source code location from which this entity has been spawned.
     #[cfg(feature = "track_location")]
     pub fn spawned_by(&self) -> &'static Location<'static> {
@@ -1771,6 +1862,8 @@ impl<'w> EntityWorldMut<'w> {
                 new_archetype_id,
             );
         }
+        // SAFETY: the entity was just moved to `new_archetype_id`
+        unsafe { trigger_on_archetype_move_hooks(self.world, new_archetype_id, entity) };
         self.world.flush();
         self.update_location();
         Some(result)
@@ -1941,6 +2034,8 @@ impl<'w> EntityWorldMut<'w> {
             &mut world.storages,
             new_archetype_id,
         );
+        // SAFETY: the entity was just moved to `new_archetype_id`
+        trigger_on_archetype_move_hooks(world, new_archetype_id, entity);
 
         new_location
     }
@@ -2565,6 +2660,30 @@ unsafe fn trigger_on_replace_and_on_remove_hooks_and_observers(
     deferred_world.trigger_on_remove(archetype, entity, bundle_info.iter_explicit_components());
 }
 
+/// Triggers the `on_archetype_move` hooks of the components kept by an entity that was moved to
+/// another archetype by a removal.
+///
+/// # Safety
+/// `entity` must currently exist in the archetype `archetype_id`
+unsafe fn trigger_on_archetype_move_hooks(
+    world: &mut World,
+    archetype_id: ArchetypeId,
+    entity: Entity,
+) {
+    if !world.archetypes[archetype_id].has_archetype_move_hook() {
+        return;
+    }
+    // SAFETY: Archetypes cannot be mutably aliased through DeferredWorld
+    let (archetype, mut deferred_world) = unsafe {
+        let world = world.as_unsafe_world_cell();
+        (&world.archetypes()[archetype_id], world.into_deferred())
+    };
+    // SAFETY: all components of the archetype exist in World
+    unsafe {
+        deferred_world.trigger_on_archetype_move(archetype, entity, archetype.components());
+    }
+}
+
 /// A view into a single entity and component in a world, which may either be vacant or occupied.
 ///
 /// This `enum` can only be constructed from the [`entry`] method on [`EntityWorldMut`].
@@ -2941,6 +3060,12 @@ impl<'w> FilteredEntityRef<'w> {
         Self { entity, access }
     }
 
+    /// Returns the [ID](WorldId) of the world the entity belongs to.
+    #[inline]
+    pub(crate) fn world_id(&self) -> WorldId {
+        self.entity.world().id()
+    }
+
     /// Returns the [ID](Entity) of the current entity.
     #[inline]
     #[must_use = "Omit the .id() call if you do not need to store the `Entity` identifier."]
@@ -2966,6 +3091,13 @@ impl<'w> FilteredEntityRef<'w> {
         &self.access
     }
 
+    /// Returns the [`Components`] of the [`World`] this entity belongs to.
+    #[cfg(feature = "bevy_reflect")]
+    #[inline]
+    pub(crate) fn components(&self) -> &'w Components {
+        self.entity.world().components()
+    }
+
     /// Returns `true` if the current entity has a component of type `T`.
     /// Otherwise, this returns `false`.
     ///
@@ -3257,6 +3389,12 @@ impl<'w> FilteredEntityMut<'w> {
         Self { entity, access }
     }
 
+    /// Returns the [ID](WorldId) of the world the entity belongs to.
+    #[inline]
+    pub(crate) fn world_id(&self) -> WorldId {
+        self.entity.world().id()
+    }
+
     /// Returns a new instance with a shorter lifetime.
     /// This is useful if you have `&mut FilteredEntityMut`, but you need `FilteredEntityMut`.
     pub fn reborrow(&mut self) -> FilteredEntityMut<'_> {
@@ -3295,6 +3433,13 @@ impl<'w> FilteredEntityMut<'w> {
         &self.access
     }
 
+    /// Returns the [`Components`] of the [`World`] this entity belongs to.
+    #[cfg(feature = "bevy_reflect")]
+    #[inline]
+    pub(crate) fn components(&self) -> &'w Components {
+        self.entity.world().components()
+    }
+
     /// Returns `true` if the current entity has a component of type `T`.
     /// Otherwise, this returns `false`.
     ///
@@ -4250,6 +4395,46 @@ mod tests {
         assert_eq!(test_component.0, 43);
     }
 
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn entity_iter_reflect() {
+        use crate::reflect::ReflectComponent;
+        use bevy_reflect::{Reflect, TypeRegistry};
+
+        #[derive(Component, Reflect, PartialEq, Debug)]
+        #[reflect(Component)]
+        struct A(u32);
+
+        #[derive(Component, Reflect, PartialEq, Debug)]
+        #[reflect(Component)]
+        #[component(immutable)]
+        struct B(u32);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<A>();
+        registry.register::<B>();
+
+        let mut world = World::new();
+        let a_id = world.register_component::<A>();
+        let b_id = world.register_component::<B>();
+        let entity = world.spawn((A(1), B(2), TestComponent(3))).id();
+
+        let mut components: Vec<_> = world.entity(entity).iter_reflect(&registry).collect();
+        components.sort_by_key(|(id, _)| *id);
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].0, a_id);
+        assert_eq!(components[0].1.downcast_ref::<A>(), Some(&A(1)));
+        assert_eq!(components[1].0, b_id);
+        assert_eq!(components[1].1.downcast_ref::<B>(), Some(&B(2)));
+
+        let mut entity_mut = world.entity_mut(entity).into_mutable();
+        for (id, mut component) in entity_mut.iter_reflect_mut(&registry) {
+            assert_eq!(id, a_id);
+            component.downcast_mut::<A>().unwrap().0 += 10;
+        }
+        assert_eq!(world.get::<A>(entity), Some(&A(11)));
+    }
+
     #[test]
     fn entity_ref_get_by_id_invalid_component_id() {
         let invalid_component_id = ComponentId::new(usize::MAX);
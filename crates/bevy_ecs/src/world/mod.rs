@@ -8,6 +8,7 @@ mod entity_ref;
 pub mod error;
 mod filtered_resource;
 mod identifier;
+pub mod snapshot;
 mod spawn_batch;
 pub mod unsafe_world_cell;
 
@@ -27,10 +28,11 @@ pub use entity_ref::{
 };
 pub use filtered_resource::*;
 pub use identifier::WorldId;
+pub use snapshot::{SnapshotRegistry, WorldSnapshot};
 pub use spawn_batch::*;
 
 use crate::{
-    archetype::{ArchetypeId, ArchetypeRow, Archetypes},
+    archetype::{ArchetypeId, ArchetypeInvariant, ArchetypeRow, ArchetypeStatement, Archetypes},
     bundle::{Bundle, BundleInfo, BundleInserter, BundleSpawner, Bundles, InsertMode},
     change_detection::{MutUntyped, TicksMut},
     component::{
@@ -39,6 +41,7 @@ use crate::{
         RequiredComponentsError, Tick,
     },
     entity::{AllocAtWithoutReplacement, Entities, Entity, EntityLocation},
+    entity_disabling::DefaultQueryFilters,
     event::{Event, EventId, Events, SendBatchIds},
     observer::Observers,
     query::{DebugCheckedUnwrap, QueryData, QueryFilter, QueryState},
@@ -52,7 +55,7 @@ use crate::{
         error::{EntityFetchError, TryRunScheduleError},
     },
 };
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use bevy_ptr::{OwningPtr, Ptr};
 use core::{any::TypeId, fmt};
 use log::warn;
@@ -151,6 +154,8 @@ impl World {
         assert_eq!(ON_INSERT, self.register_component::<OnInsert>());
         assert_eq!(ON_REPLACE, self.register_component::<OnReplace>());
         assert_eq!(ON_REMOVE, self.register_component::<OnRemove>());
+        // This sets up `Disabled` as a disabling component, via the FromWorld impl
+        self.init_resource::<DefaultQueryFilters>();
     }
     /// Creates a new empty [`World`].
     ///
@@ -501,6 +506,72 @@ impl World {
         Some(component_info.required_components())
     }
 
+    /// Registers an [`ArchetypeInvariant`], a rule about which combinations of components
+    /// entities in this world are allowed to have.
+    ///
+    /// Inserting or removing components such that an entity would end up with a combination
+    /// that violates the invariant panics. Invariants are only checked when a new archetype is created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a combination of components that already exists in this world violates the invariant.
+    ///
+    /// # Example
+    ///
+    /// ```should_panic
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::archetype::{ArchetypeInvariant, ArchetypeStatement};
+    /// #[derive(Component)]
+    /// struct Frozen;
+    ///
+    /// #[derive(Component)]
+    /// struct Burning;
+    ///
+    /// # let mut world = World::default();
+    /// let frozen = world.register_component::<Frozen>();
+    /// let burning = world.register_component::<Burning>();
+    /// world.register_archetype_invariant(ArchetypeInvariant::new(
+    ///     ArchetypeStatement::AllOf(vec![frozen]),
+    ///     ArchetypeStatement::NoneOf(vec![burning]),
+    /// ));
+    ///
+    /// // This panics.
+    /// world.spawn((Frozen, Burning));
+    /// ```
+    #[track_caller]
+    pub fn register_archetype_invariant(&mut self, invariant: ArchetypeInvariant) {
+        self.archetypes.add_invariant(&self.components, invariant);
+    }
+
+    /// Registers an [`ArchetypeInvariant`] forbidding entities from having more than one of the
+    /// components in the bundle `B`, for example `Alive` and `Dead`.
+    ///
+    /// See [`World::register_archetype_invariant`] for more details.
+    #[track_caller]
+    pub fn register_exclusive_components<B: Bundle>(&mut self) {
+        let ids = self.register_bundle::<B>().explicit_components().to_vec();
+        self.register_archetype_invariant(ArchetypeInvariant::always(
+            ArchetypeStatement::AtMostOneOf(ids),
+        ));
+    }
+
+    /// Registers an [`ArchetypeInvariant`] forbidding entities with the component `T` from missing
+    /// any component of the bundle `R`.
+    ///
+    /// Unlike [required components](World::register_required_components), the components of `R`
+    /// are never inserted automatically: they must be inserted together with `T` or before it.
+    ///
+    /// See [`World::register_archetype_invariant`] for more details.
+    #[track_caller]
+    pub fn register_invariant_requires<T: Component, R: Bundle>(&mut self) {
+        let id = self.register_component::<T>();
+        let required = self.register_bundle::<R>().explicit_components().to_vec();
+        self.register_archetype_invariant(ArchetypeInvariant::new(
+            ArchetypeStatement::AllOf(vec![id]),
+            ArchetypeStatement::AllOf(required),
+        ));
+    }
+
     /// Registers a new [`Component`] type and returns the [`ComponentId`] created for it.
     ///
     /// This method differs from [`World::register_component`] in that it uses a [`ComponentDescriptor`]
@@ -2683,6 +2754,7 @@ impl World {
                 last_run: last_change_tick,
                 this_run: change_tick,
             },
+            field_ticks: None,
             #[cfg(feature = "track_location")]
             changed_by: &mut _caller,
         };
@@ -3199,6 +3271,7 @@ impl World {
     /// # struct B(u32);
     /// #
     /// # let mut world = World::new();
+    /// # world.remove_resource::<bevy_ecs::entity_disabling::DefaultQueryFilters>();
     /// # world.insert_resource(A(1));
     /// # world.insert_resource(B(2));
     /// let mut total = 0;
@@ -3667,6 +3740,7 @@ mod tests {
         change_detection::DetectChangesMut,
         component::{ComponentDescriptor, ComponentInfo, StorageType},
         entity::EntityHashSet,
+        entity_disabling::DefaultQueryFilters,
         ptr::OwningPtr,
         system::Resource,
         world::error::EntityFetchError,
@@ -3856,6 +3930,7 @@ mod tests {
     #[test]
     fn iter_resources() {
         let mut world = World::new();
+        world.remove_resource::<DefaultQueryFilters>();
         world.insert_resource(TestResource(42));
         world.insert_resource(TestResource2("Hello, world!".to_string()));
         world.insert_resource(TestResource3);
@@ -3882,6 +3957,7 @@ mod tests {
     #[test]
     fn iter_resources_mut() {
         let mut world = World::new();
+        world.remove_resource::<DefaultQueryFilters>();
         world.insert_resource(TestResource(42));
         world.insert_resource(TestResource2("Hello, world!".to_string()));
         world.insert_resource(TestResource3);
@@ -4348,4 +4424,56 @@ mod tests {
             None
         );
     }
+
+    #[derive(Component)]
+    struct Alive;
+
+    #[derive(Component)]
+    struct Dead;
+
+    #[derive(Component)]
+    struct Player;
+
+    #[derive(Component)]
+    struct Health;
+
+    #[test]
+    fn archetype_invariants_allow_valid_combinations() {
+        let mut world = World::new();
+        world.register_exclusive_components::<(Alive, Dead)>();
+        world.register_invariant_requires::<Player, Health>();
+
+        let entity = world.spawn((Player, Health, Alive)).id();
+        world.entity_mut(entity).remove::<Alive>().insert(Dead);
+        world.entity_mut(entity).remove::<(Player, Health)>();
+        assert_eq!(world.archetypes().invariants().len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Archetype invariant violated")]
+    fn archetype_invariants_forbid_exclusive_components() {
+        let mut world = World::new();
+        world.register_exclusive_components::<(Alive, Dead)>();
+
+        let entity = world.spawn(Alive).id();
+        world.entity_mut(entity).insert(Dead);
+    }
+
+    #[test]
+    #[should_panic(expected = "Archetype invariant violated")]
+    fn archetype_invariants_forbid_removing_requirements() {
+        let mut world = World::new();
+        world.register_invariant_requires::<Player, Health>();
+
+        let entity = world.spawn((Player, Health)).id();
+        world.entity_mut(entity).remove::<Health>();
+    }
+
+    #[test]
+    #[should_panic(expected = "Archetype invariant violated")]
+    fn archetype_invariants_check_existing_archetypes() {
+        let mut world = World::new();
+        world.spawn((Alive, Dead));
+        world.register_exclusive_components::<(Alive, Dead)>();
+    }
 }
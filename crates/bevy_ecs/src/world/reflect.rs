@@ -5,9 +5,12 @@ use core::any::TypeId;
 use thiserror::Error;
 
 use alloc::string::{String, ToString};
-use bevy_reflect::{Reflect, ReflectFromPtr};
+use bevy_reflect::{Reflect, ReflectFromPtr, TypeRegistry};
 
-use crate::{prelude::*, world::ComponentId};
+use crate::{
+    prelude::*,
+    world::{ComponentId, FilteredEntityMut, FilteredEntityRef},
+};
 
 impl World {
     /// Retrieves a reference to the given `entity`'s [`Component`] of the given `type_id` using
@@ -198,6 +201,100 @@ impl World {
     }
 }
 
+impl<'w> FilteredEntityRef<'w> {
+    /// Retrieves a reference to the component of the given [`ComponentId`] using reflection.
+    ///
+    /// This allows accessing the components of queries built at runtime using
+    /// [`QueryBuilder::ref_id`], such as from scripts or editors.
+    ///
+    /// Returns `None` if the entity does not have the component, if the query does not have read
+    /// access to it, or if the component type has no [`ReflectFromPtr`] registered in
+    /// `type_registry`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_ecs::{prelude::*, world::FilteredEntityRef};
+    /// use bevy_reflect::{Reflect, TypeRegistry};
+    ///
+    /// #[derive(Component, Reflect, PartialEq, Debug)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// let mut type_registry = TypeRegistry::new();
+    /// type_registry.register::<Health>();
+    /// world.spawn(Health(10));
+    ///
+    /// // The component set could come from a script, only known at runtime.
+    /// let component_id = world.register_component::<Health>();
+    /// let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+    ///     .ref_id(component_id)
+    ///     .build();
+    ///
+    /// let entity = query.single(&world);
+    /// let health = entity
+    ///     .get_reflect_by_id(component_id, &type_registry)
+    ///     .unwrap();
+    /// assert_eq!(health.downcast_ref::<Health>(), Some(&Health(10)));
+    /// ```
+    ///
+    /// [`QueryBuilder::ref_id`]: crate::query::QueryBuilder::ref_id
+    pub fn get_reflect_by_id(
+        &self,
+        component_id: ComponentId,
+        type_registry: &TypeRegistry,
+    ) -> Option<&'w dyn Reflect> {
+        let type_id = self.components().get_info(component_id)?.type_id()?;
+        let reflect_from_ptr = type_registry.get_type_data::<ReflectFromPtr>(type_id)?;
+        let ptr = self.get_by_id(component_id)?;
+
+        // SAFETY:
+        // - `ptr` points to a component of type `type_id`
+        // - `reflect_from_ptr` was registered for type `type_id`
+        Some(unsafe { reflect_from_ptr.as_reflect(ptr) })
+    }
+}
+
+impl<'w> FilteredEntityMut<'w> {
+    /// Retrieves a reference to the component of the given [`ComponentId`] using reflection.
+    ///
+    /// See [`FilteredEntityRef::get_reflect_by_id`] for more information.
+    pub fn get_reflect_by_id(
+        &self,
+        component_id: ComponentId,
+        type_registry: &TypeRegistry,
+    ) -> Option<&'_ dyn Reflect> {
+        self.as_readonly()
+            .get_reflect_by_id(component_id, type_registry)
+    }
+
+    /// Retrieves a mutable reference to the component of the given [`ComponentId`] using
+    /// reflection.
+    ///
+    /// This allows modifying the components of queries built at runtime using
+    /// [`QueryBuilder::mut_id`], such as from scripts or editors.
+    ///
+    /// Returns `None` if the entity does not have the component, if the query does not have write
+    /// access to it, if the component is [immutable](crate::component::Immutable), or if the
+    /// component type has no [`ReflectFromPtr`] registered in `type_registry`.
+    ///
+    /// [`QueryBuilder::mut_id`]: crate::query::QueryBuilder::mut_id
+    pub fn get_reflect_mut_by_id(
+        &mut self,
+        component_id: ComponentId,
+        type_registry: &TypeRegistry,
+    ) -> Option<Mut<'_, dyn Reflect>> {
+        let type_id = self.components().get_info(component_id)?.type_id()?;
+        let reflect_from_ptr = type_registry.get_type_data::<ReflectFromPtr>(type_id)?;
+        let component = self.get_mut_by_id(component_id)?;
+
+        // SAFETY:
+        // - `component` points to a component of type `type_id`
+        // - `reflect_from_ptr` was registered for type `type_id`
+        Some(component.map_unchanged(|ptr| unsafe { reflect_from_ptr.as_reflect_mut(ptr) }))
+    }
+}
+
 /// The error type returned by [`World::get_reflect`] and [`World::get_reflect_mut`].
 #[derive(Error, Debug)]
 pub enum GetComponentReflectError {
@@ -254,7 +351,8 @@ mod tests {
     use crate::{
         // For bevy_ecs_macros
         self as bevy_ecs,
-        prelude::{AppTypeRegistry, Component, DetectChanges, World},
+        prelude::{AppTypeRegistry, Component, DetectChanges, QueryBuilder, World},
+        world::FilteredEntityMut,
     };
 
     #[derive(Component, Reflect)]
@@ -334,4 +432,41 @@ mod tests {
             assert!(reflect_opt.is_err());
         }
     }
+
+    #[test]
+    fn reflect_components_of_dynamic_query() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        type_registry.write().register::<RFoo>();
+
+        let rfoo_id = world.register_component::<RFoo>();
+        let bar_id = world.register_component::<Bar>();
+        let entity = world.spawn((RFoo(1), Bar)).id();
+        world.spawn(RFoo(2));
+
+        let mut query = QueryBuilder::<FilteredEntityMut>::new(&mut world)
+            .mut_id(rfoo_id)
+            .ref_id(bar_id)
+            .build();
+
+        let type_registry = type_registry.read();
+        let mut filtered_entity = query.single_mut(&mut world);
+        assert_eq!(filtered_entity.id(), entity);
+        // `Bar` is accessible, but not reflected
+        assert!(filtered_entity.get_by_id(bar_id).is_some());
+        assert!(filtered_entity
+            .get_reflect_by_id(bar_id, &type_registry)
+            .is_none());
+
+        let mut rfoo = filtered_entity
+            .get_reflect_mut_by_id(rfoo_id, &type_registry)
+            .unwrap();
+        rfoo.downcast_mut::<RFoo>().unwrap().0 = 42;
+        drop(type_registry);
+
+        let rfoo = world.entity(entity).get_ref::<RFoo>().unwrap();
+        assert!(rfoo.is_changed());
+        assert_eq!(rfoo.0, 42);
+    }
 }
@@ -0,0 +1,371 @@
+//! Cheap in-memory snapshots of the [`World`], which can be rolled back to later.
+//!
+//! Only the components and resources registered with
+//! [`World::register_snapshot_component`] and [`World::register_snapshot_resource`]
+//! are captured. They are copied using [`Clone`], which makes snapshots fast enough to be taken
+//! every tick, for example for rollback networking or for rewinding a game while debugging.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! #[derive(Component, Clone, PartialEq, Debug)]
+//! struct Position(i32);
+//!
+//! let mut world = World::new();
+//! world.register_snapshot_component::<Position>();
+//! let player = world.spawn(Position(0)).id();
+//!
+//! let snapshot = world.snapshot();
+//! world.entity_mut(player).insert(Position(10));
+//! let bullet = world.spawn(Position(5)).id();
+//!
+//! world.rollback(&snapshot);
+//! assert_eq!(world.get::<Position>(player), Some(&Position(0)));
+//! assert!(world.get_entity(bullet).is_err());
+//! ```
+
+use crate::{
+    self as bevy_ecs,
+    archetype::ArchetypeEntity,
+    component::{Component, ComponentId},
+    entity::{Entity, EntityHashSet},
+    system::Resource,
+    world::World,
+};
+use alloc::{boxed::Box, vec::Vec};
+use bevy_utils::HashSet;
+use core::any::Any;
+use log::warn;
+
+type SnapshotData = Box<dyn Any + Send + Sync>;
+
+#[derive(Clone, Copy)]
+struct SnapshotFns {
+    id: ComponentId,
+    capture: fn(&World, ComponentId) -> SnapshotData,
+    restore: fn(&mut World, ComponentId, &SnapshotData),
+}
+
+/// A [`Resource`] storing the components and resources captured by [`World::snapshot`].
+///
+/// This is inserted by the first call to [`World::register_snapshot_component`]
+/// or [`World::register_snapshot_resource`].
+#[derive(Resource, Default)]
+pub struct SnapshotRegistry {
+    components: Vec<SnapshotFns>,
+    resources: Vec<SnapshotFns>,
+    excluded: HashSet<ComponentId>,
+}
+
+impl SnapshotRegistry {
+    /// Excludes the component or resource with the given id from the snapshots taken from now on,
+    /// even if it was registered.
+    ///
+    /// This is useful to opt out of the snapshots of a type registered by a third-party plugin.
+    pub fn exclude(&mut self, id: ComponentId) {
+        self.excluded.insert(id);
+    }
+
+    /// Reverts [`SnapshotRegistry::exclude`], so that the component or resource with the given id
+    /// is captured again if it was registered.
+    pub fn include(&mut self, id: ComponentId) {
+        self.excluded.remove(&id);
+    }
+
+    /// Returns `true` if the component or resource with the given id is captured by snapshots.
+    pub fn is_captured(&self, id: ComponentId) -> bool {
+        !self.excluded.contains(&id)
+            && self
+                .components
+                .iter()
+                .chain(&self.resources)
+                .any(|fns| fns.id == id)
+    }
+
+    fn capture<'a>(
+        &'a self,
+        world: &'a World,
+        fns: &'a [SnapshotFns],
+    ) -> impl Iterator<Item = SnapshotEntry> + 'a {
+        fns.iter()
+            .filter(|fns| !self.excluded.contains(&fns.id))
+            .map(|fns| SnapshotEntry {
+                id: fns.id,
+                restore: fns.restore,
+                data: (fns.capture)(world, fns.id),
+            })
+    }
+}
+
+struct SnapshotEntry {
+    id: ComponentId,
+    restore: fn(&mut World, ComponentId, &SnapshotData),
+    data: SnapshotData,
+}
+
+/// A copy of the registered components and resources of a [`World`],
+/// created by [`World::snapshot`] and restored by [`World::rollback`].
+///
+/// See the [module docs](crate::world::snapshot) for more information.
+pub struct WorldSnapshot {
+    entities: EntityHashSet,
+    components: Vec<SnapshotEntry>,
+    resources: Vec<SnapshotEntry>,
+}
+
+impl WorldSnapshot {
+    /// Returns `true` if the component or resource with the given id was captured by this snapshot.
+    pub fn contains(&self, id: ComponentId) -> bool {
+        self.components
+            .iter()
+            .chain(&self.resources)
+            .any(|entry| entry.id == id)
+    }
+}
+
+fn entities_with(world: &World, id: ComponentId) -> Vec<Entity> {
+    world
+        .archetypes()
+        .iter()
+        .filter(|archetype| archetype.contains(id))
+        .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+        .collect()
+}
+
+fn capture_component<C: Component + Clone>(world: &World, id: ComponentId) -> SnapshotData {
+    let values: Vec<(Entity, C)> = entities_with(world, id)
+        .into_iter()
+        .filter_map(|entity| Some((entity, world.get::<C>(entity)?.clone())))
+        .collect();
+    Box::new(values)
+}
+
+fn restore_component<C: Component + Clone>(
+    world: &mut World,
+    id: ComponentId,
+    data: &SnapshotData,
+) {
+    let Some(values) = data.downcast_ref::<Vec<(Entity, C)>>() else {
+        return;
+    };
+    let saved: EntityHashSet = values.iter().map(|(entity, _)| *entity).collect();
+    for entity in entities_with(world, id) {
+        if !saved.contains(&entity) {
+            world.entity_mut(entity).remove::<C>();
+        }
+    }
+    if let Err(invalid) = world.insert_or_spawn_batch(values.iter().cloned()) {
+        warn!(
+            "Could not restore the {} component of entities {invalid:?} from a snapshot, as their ids have been reused.",
+            core::any::type_name::<C>()
+        );
+    }
+}
+
+fn capture_resource<R: Resource + Clone>(world: &World, _id: ComponentId) -> SnapshotData {
+    Box::new(world.get_resource::<R>().cloned())
+}
+
+fn restore_resource<R: Resource + Clone>(world: &mut World, _id: ComponentId, data: &SnapshotData) {
+    match data.downcast_ref::<Option<R>>() {
+        Some(Some(resource)) => world.insert_resource(resource.clone()),
+        Some(None) => {
+            world.remove_resource::<R>();
+        }
+        None => {}
+    }
+}
+
+impl World {
+    /// Registers the component `C` to be captured by [`World::snapshot`],
+    /// and restored by [`World::rollback`].
+    ///
+    /// Calling this again for the same component does nothing.
+    pub fn register_snapshot_component<C: Component + Clone>(&mut self) -> &mut Self {
+        let id = self.register_component::<C>();
+        let mut registry = self.get_resource_or_init::<SnapshotRegistry>();
+        if !registry.components.iter().any(|fns| fns.id == id) {
+            registry.components.push(SnapshotFns {
+                id,
+                capture: capture_component::<C>,
+                restore: restore_component::<C>,
+            });
+        }
+        self
+    }
+
+    /// Registers the resource `R` to be captured by [`World::snapshot`],
+    /// and restored by [`World::rollback`].
+    ///
+    /// Calling this again for the same resource does nothing.
+    pub fn register_snapshot_resource<R: Resource + Clone>(&mut self) -> &mut Self {
+        let id = self.register_resource::<R>();
+        let mut registry = self.get_resource_or_init::<SnapshotRegistry>();
+        if !registry.resources.iter().any(|fns| fns.id == id) {
+            registry.resources.push(SnapshotFns {
+                id,
+                capture: capture_resource::<R>,
+                restore: restore_resource::<R>,
+            });
+        }
+        self
+    }
+
+    /// Creates a [`WorldSnapshot`] holding a copy of the components and resources registered
+    /// in the [`SnapshotRegistry`], which can be restored later using [`World::rollback`].
+    ///
+    /// Components and resources excluded using [`SnapshotRegistry::exclude`] are skipped.
+    pub fn snapshot(&self) -> WorldSnapshot {
+        let entities = self
+            .archetypes()
+            .iter()
+            .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+            .collect();
+        let Some(registry) = self.get_resource::<SnapshotRegistry>() else {
+            return WorldSnapshot {
+                entities,
+                components: Vec::new(),
+                resources: Vec::new(),
+            };
+        };
+        WorldSnapshot {
+            entities,
+            components: registry.capture(self, &registry.components).collect(),
+            resources: registry.capture(self, &registry.resources).collect(),
+        }
+    }
+
+    /// Restores the components and resources captured by the given [`WorldSnapshot`].
+    ///
+    /// - Entities spawned after the snapshot was taken and holding any of the captured
+    ///   components are despawned.
+    /// - Entities despawned after the snapshot was taken are spawned again with the same id,
+    ///   but only with the captured components.
+    /// - Captured components and resources are set back to their previous values, and removed
+    ///   from the entities which did not hold them when the snapshot was taken.
+    ///
+    /// Any other component or resource is left untouched. Restored values are inserted as usual,
+    /// which triggers their hooks and observers and marks them as changed.
+    pub fn rollback(&mut self, snapshot: &WorldSnapshot) {
+        let mut spawned = EntityHashSet::default();
+        for entry in &snapshot.components {
+            spawned.extend(
+                entities_with(self, entry.id)
+                    .into_iter()
+                    .filter(|entity| !snapshot.entities.contains(entity)),
+            );
+        }
+        for entity in spawned {
+            self.despawn(entity);
+        }
+
+        for entry in snapshot.components.iter().chain(&snapshot.resources) {
+            (entry.restore)(self, entry.id, &entry.data);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as bevy_ecs, prelude::*, world::SnapshotRegistry};
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    struct Position(i32);
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    #[component(storage = "SparseSet")]
+    struct Frozen;
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    struct Name(&'static str);
+
+    #[derive(Resource, Clone, PartialEq, Debug)]
+    struct Score(u32);
+
+    #[derive(Resource, Clone, PartialEq, Debug)]
+    struct Paused;
+
+    #[test]
+    fn rollback_restores_components() {
+        let mut world = World::new();
+        world
+            .register_snapshot_component::<Position>()
+            .register_snapshot_component::<Frozen>();
+        let a = world.spawn(Position(1)).id();
+        let b = world.spawn((Position(2), Frozen)).id();
+        let c = world.spawn(Name("c")).id();
+
+        let snapshot = world.snapshot();
+        world.entity_mut(a).insert((Position(10), Frozen));
+        world.entity_mut(b).remove::<Frozen>();
+        world.entity_mut(c).insert(Position(3));
+
+        world.rollback(&snapshot);
+        assert_eq!(world.get::<Position>(a), Some(&Position(1)));
+        assert!(!world.entity(a).contains::<Frozen>());
+        assert_eq!(world.get::<Frozen>(b), Some(&Frozen));
+        assert!(!world.entity(c).contains::<Position>());
+        assert_eq!(world.get::<Name>(c), Some(&Name("c")));
+
+        // Snapshots can be restored several times
+        world.entity_mut(a).insert(Position(20));
+        world.rollback(&snapshot);
+        assert_eq!(world.get::<Position>(a), Some(&Position(1)));
+    }
+
+    #[test]
+    fn rollback_spawns_and_despawns_entities() {
+        let mut world = World::new();
+        world.register_snapshot_component::<Position>();
+        let a = world.spawn((Position(1), Name("a"))).id();
+
+        let snapshot = world.snapshot();
+        world.despawn(a);
+        let b = world.spawn(Position(2)).id();
+        let c = world.spawn(Name("c")).id();
+
+        world.rollback(&snapshot);
+        assert_eq!(world.get::<Position>(a), Some(&Position(1)));
+        // Components which were not captured are lost
+        assert!(!world.entity(a).contains::<Name>());
+        assert!(world.get_entity(b).is_err());
+        // Entities without captured components are left untouched
+        assert!(world.get_entity(c).is_ok());
+    }
+
+    #[test]
+    fn rollback_restores_resources() {
+        let mut world = World::new();
+        world
+            .register_snapshot_resource::<Score>()
+            .register_snapshot_resource::<Paused>();
+        world.insert_resource(Score(1));
+
+        let snapshot = world.snapshot();
+        world.insert_resource(Score(5));
+        world.insert_resource(Paused);
+
+        world.rollback(&snapshot);
+        assert_eq!(world.get_resource::<Score>(), Some(&Score(1)));
+        assert!(!world.contains_resource::<Paused>());
+    }
+
+    #[test]
+    fn excluded_components_are_not_captured() {
+        let mut world = World::new();
+        world
+            .register_snapshot_component::<Position>()
+            .register_snapshot_component::<Name>();
+        let name = world.register_component::<Name>();
+        world.resource_mut::<SnapshotRegistry>().exclude(name);
+        assert!(!world.resource::<SnapshotRegistry>().is_captured(name));
+
+        let a = world.spawn((Position(1), Name("a"))).id();
+        let snapshot = world.snapshot();
+        assert!(!snapshot.contains(name));
+        world.entity_mut(a).insert((Position(2), Name("b")));
+
+        world.rollback(&snapshot);
+        assert_eq!(world.get::<Position>(a), Some(&Position(1)));
+        assert_eq!(world.get::<Name>(a), Some(&Name("b")));
+    }
+}
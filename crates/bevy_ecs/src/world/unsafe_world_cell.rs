@@ -900,12 +900,14 @@ impl<'w> UnsafeEntityCell<'w> {
                 self.entity,
                 self.location,
             )
-            .map(|(value, cells, _caller)| Mut {
-                // SAFETY: returned component is of type T
-                value: value.assert_unique().deref_mut::<T>(),
-                ticks: TicksMut::from_tick_cells(cells, last_change_tick, change_tick),
-                #[cfg(feature = "track_location")]
-                changed_by: _caller.deref_mut(),
+            .map(|(value, cells, _caller)| {
+                Mut::new_component(
+                    // SAFETY: returned component is of type T
+                    value.assert_unique().deref_mut::<T>(),
+                    TicksMut::from_tick_cells(cells, last_change_tick, change_tick),
+                    #[cfg(feature = "track_location")]
+                    _caller.deref_mut(),
+                )
             })
         }
     }
@@ -75,6 +75,40 @@ impl Hash for GizmoLineStyle {
     }
 }
 
+/// An enum configuring how gizmos interact with the depth of the scene they are drawn in.
+///
+/// This setting only affects 3D cameras, 2D gizmos are always drawn on top.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Reflect)]
+pub enum GizmoDepthMode {
+    /// Gizmos are depth tested and hidden behind opaque geometry.
+    #[default]
+    DepthTest,
+    /// Gizmos are not depth tested and always drawn on top of everything else.
+    AlwaysOnTop,
+    /// Visible parts of gizmos are drawn normally and occluded parts are drawn dimmed,
+    /// like looking through the geometry with X-ray vision.
+    XRay {
+        /// The factor applied to the alpha of occluded parts, between `0.0` and `1.0`.
+        occluded_alpha: f32,
+    },
+}
+
+impl GizmoDepthMode {
+    /// X-ray mode drawing occluded parts with 20% alpha.
+    pub const XRAY: Self = Self::XRay {
+        occluded_alpha: 0.2,
+    };
+
+    /// Returns the alpha factor applied to occluded parts of gizmos.
+    pub(crate) fn occluded_alpha(&self) -> f32 {
+        match self {
+            Self::DepthTest => 0.,
+            Self::AlwaysOnTop => 1.,
+            Self::XRay { occluded_alpha } => occluded_alpha.clamp(0., 1.),
+        }
+    }
+}
+
 /// A trait used to create gizmo configs groups.
 ///
 /// Here you can store additional configuration for you gizmo group not covered by [`GizmoConfig`]
@@ -188,6 +222,12 @@ pub struct GizmoConfig {
     /// and your wireframe is z-fighting (flickering on/off) with your main model.
     /// You would set this value to a negative number close to 0.
     pub depth_bias: f32,
+    /// How gizmos are depth tested against the scene.
+    ///
+    /// In 2D this setting has no effect.
+    ///
+    /// Defaults to [`GizmoDepthMode::DepthTest`].
+    pub depth_mode: GizmoDepthMode,
     /// Describes which rendering layers gizmos will be rendered to.
     ///
     /// Gizmos will only be rendered to cameras with intersecting layers.
@@ -201,6 +241,7 @@ impl Default for GizmoConfig {
             enabled: true,
             line: Default::default(),
             depth_bias: 0.,
+            depth_mode: GizmoDepthMode::DepthTest,
             #[cfg(feature = "bevy_render")]
             render_layers: Default::default(),
         }
@@ -248,6 +289,7 @@ pub(crate) struct GizmoMeshConfig {
     pub line_perspective: bool,
     pub line_style: GizmoLineStyle,
     pub line_joints: GizmoLineJoint,
+    pub depth_mode: GizmoDepthMode,
     pub render_layers: bevy_render::view::RenderLayers,
     pub handle: Handle<GizmoAsset>,
 }
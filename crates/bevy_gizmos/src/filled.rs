@@ -0,0 +1,570 @@
+//! Additional [`GizmoBuffer`] Functions -- Filled shapes
+//!
+//! Includes the implementation of [`GizmoBuffer::triangle`], [`GizmoBuffer::filled_sphere`],
+//! [`GizmoBuffer::filled_cuboid`] and friends, and assorted support items.
+//!
+//! Unlike every other gizmo, these are drawn as solid triangles instead of lines. They are alpha
+//! blended and depth tested but do not write depth, so a translucent color can be used to visualize
+//! volumes such as triggers or sensor ranges without hiding what's inside of them.
+
+use crate::{
+    circles::DEFAULT_CIRCLE_RESOLUTION,
+    gizmos::{rect_inner, GizmoBuffer},
+    prelude::GizmoConfigGroup,
+};
+use bevy_color::{Color, LinearRgba};
+use bevy_math::{ops, Isometry2d, Isometry3d, Vec2, Vec3};
+use bevy_transform::TransformPoint;
+use core::{
+    f32::consts::{FRAC_PI_2, PI, TAU},
+    iter,
+};
+
+impl<Config, Clear> GizmoBuffer<Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Draw a filled triangle in 3D with the corners `a`, `b` and `c`.
+    ///
+    /// This should be called for each frame the triangle needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::{Alpha, palettes::basic::GREEN};
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.triangle(Vec3::ZERO, Vec3::X, Vec3::Y, GREEN.with_alpha(0.5));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn triangle(&mut self, a: Vec3, b: Vec3, c: Vec3, color: impl Into<Color>) {
+        if !self.enabled {
+            return;
+        }
+        self.triangle_positions.extend([a, b, c]);
+        self.add_triangle_color(color, 3);
+    }
+
+    /// Draw a filled triangle in 2D with the corners `a`, `b` and `c`.
+    ///
+    /// This should be called for each frame the triangle needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.triangle_2d(Vec2::ZERO, Vec2::X, Vec2::Y, GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn triangle_2d(&mut self, a: Vec2, b: Vec2, c: Vec2, color: impl Into<Color>) {
+        if !self.enabled {
+            return;
+        }
+        self.triangle(a.extend(0.), b.extend(0.), c.extend(0.), color);
+    }
+
+    /// Draw a filled rectangle in 3D with the given `isometry` applied.
+    ///
+    /// If `isometry == Isometry3d::IDENTITY` then
+    ///
+    /// - the center is at `Vec3::ZERO`
+    /// - the sizes are aligned with the `Vec3::X` and `Vec3::Y` axes.
+    ///
+    /// This should be called for each frame the rectangle needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::{Alpha, palettes::basic::GREEN};
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.filled_rect(Isometry3d::IDENTITY, Vec2::ONE, GREEN.with_alpha(0.2));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn filled_rect(
+        &mut self,
+        isometry: impl Into<Isometry3d>,
+        size: Vec2,
+        color: impl Into<Color>,
+    ) {
+        if !self.enabled {
+            return;
+        }
+        let isometry = isometry.into();
+        let [tl, tr, br, bl] = rect_inner(size).map(|vec2| isometry * vec2.extend(0.));
+        self.quad(tl, tr, br, bl, color);
+    }
+
+    /// Draw a filled rectangle in 2D with the given `isometry` applied.
+    ///
+    /// If `isometry == Isometry2d::IDENTITY` then
+    ///
+    /// - the center is at `Vec2::ZERO`
+    /// - the sizes are aligned with the `Vec2::X` and `Vec2::Y` axes.
+    ///
+    /// This should be called for each frame the rectangle needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.filled_rect_2d(Isometry2d::IDENTITY, Vec2::ONE, GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn filled_rect_2d(
+        &mut self,
+        isometry: impl Into<Isometry2d>,
+        size: Vec2,
+        color: impl Into<Color>,
+    ) {
+        if !self.enabled {
+            return;
+        }
+        let isometry = isometry.into();
+        let [tl, tr, br, bl] = rect_inner(size).map(|vec2| (isometry * vec2).extend(0.));
+        self.quad(tl, tr, br, bl, color);
+    }
+
+    /// Draw a filled cuboid in 3D with the given `transform` applied.
+    ///
+    /// This should be called for each frame the cuboid needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_transform::prelude::*;
+    /// # use bevy_color::{Alpha, palettes::basic::GREEN};
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.filled_cuboid(Transform::IDENTITY, GREEN.with_alpha(0.25));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn filled_cuboid(&mut self, transform: impl TransformPoint, color: impl Into<Color>) {
+        if !self.enabled {
+            return;
+        }
+        let color: Color = color.into();
+        let rect = rect_inner(Vec2::ONE);
+        // Front
+        let [tlf, trf, brf, blf] = rect.map(|vec2| transform.transform_point(vec2.extend(0.5)));
+        // Back
+        let [tlb, trb, brb, blb] = rect.map(|vec2| transform.transform_point(vec2.extend(-0.5)));
+
+        self.quad(tlf, trf, brf, blf, color); // Front
+        self.quad(trb, tlb, blb, brb, color); // Back
+        self.quad(tlb, trb, trf, tlf, color); // Top
+        self.quad(blf, brf, brb, blb, color); // Bottom
+        self.quad(tlb, tlf, blf, blb, color); // Left
+        self.quad(trf, trb, brb, brf, color); // Right
+    }
+
+    /// Draw a filled circle (a disc) in 3D with the given `isometry` applied.
+    ///
+    /// If `isometry == Isometry3d::IDENTITY` then
+    ///
+    /// - the center is at `Vec3::ZERO`
+    /// - the radius is aligned with the `Vec3::X` and `Vec3::Y` axes.
+    ///
+    /// This should be called for each frame the circle needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::{Alpha, palettes::basic::RED};
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos
+    ///         .filled_circle(Isometry3d::IDENTITY, 5., RED.with_alpha(0.3))
+    ///         .resolution(64);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn filled_circle(
+        &mut self,
+        isometry: impl Into<Isometry3d>,
+        radius: f32,
+        color: impl Into<Color>,
+    ) -> FilledCircleBuilder<'_, Config, Clear> {
+        FilledCircleBuilder {
+            gizmos: self,
+            isometry: isometry.into(),
+            radius,
+            color: color.into(),
+            resolution: DEFAULT_CIRCLE_RESOLUTION,
+        }
+    }
+
+    /// Draw a filled circle (a disc) in 2D with the given `isometry` applied.
+    ///
+    /// If `isometry == Isometry2d::IDENTITY` then
+    ///
+    /// - the center is at `Vec2::ZERO`
+    /// - the radius is aligned with the `Vec2::X` and `Vec2::Y` axes.
+    ///
+    /// This should be called for each frame the circle needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::RED;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.filled_circle_2d(Isometry2d::IDENTITY, 5., RED);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn filled_circle_2d(
+        &mut self,
+        isometry: impl Into<Isometry2d>,
+        radius: f32,
+        color: impl Into<Color>,
+    ) -> FilledCircle2dBuilder<'_, Config, Clear> {
+        FilledCircle2dBuilder {
+            gizmos: self,
+            isometry: isometry.into(),
+            radius,
+            color: color.into(),
+            resolution: DEFAULT_CIRCLE_RESOLUTION,
+        }
+    }
+
+    /// Draw a filled sphere in 3D with the given `isometry` applied.
+    ///
+    /// If `isometry == Isometry3d::IDENTITY` then
+    ///
+    /// - the center is at `Vec3::ZERO`
+    /// - the poles are aligned with the `Vec3::Y` axis.
+    ///
+    /// This should be called for each frame the sphere needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::{Alpha, palettes::basic::BLUE};
+    /// fn system(mut gizmos: Gizmos) {
+    ///     // A translucent sensor range.
+    ///     gizmos.filled_sphere(Vec3::ZERO, 4., BLUE.with_alpha(0.15));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn filled_sphere(
+        &mut self,
+        isometry: impl Into<Isometry3d>,
+        radius: f32,
+        color: impl Into<Color>,
+    ) -> FilledSphereBuilder<'_, Config, Clear> {
+        FilledSphereBuilder {
+            gizmos: self,
+            isometry: isometry.into(),
+            radius,
+            color: color.into(),
+            resolution: DEFAULT_CIRCLE_RESOLUTION,
+        }
+    }
+
+    /// Draw a filled capsule in 3D with the given `isometry` applied.
+    ///
+    /// If `isometry == Isometry3d::IDENTITY` then
+    ///
+    /// - the center is at `Vec3::ZERO`
+    /// - the capsule is aligned with the `Vec3::Y` axis, with `half_length` being the half
+    ///   length of its cylindrical part.
+    ///
+    /// This should be called for each frame the capsule needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::{Alpha, palettes::basic::YELLOW};
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.filled_capsule_3d(Vec3::ZERO, 0.5, 1., YELLOW.with_alpha(0.4));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn filled_capsule_3d(
+        &mut self,
+        isometry: impl Into<Isometry3d>,
+        radius: f32,
+        half_length: f32,
+        color: impl Into<Color>,
+    ) -> FilledCapsule3dBuilder<'_, Config, Clear> {
+        FilledCapsule3dBuilder {
+            gizmos: self,
+            isometry: isometry.into(),
+            radius,
+            half_length,
+            color: color.into(),
+            resolution: DEFAULT_CIRCLE_RESOLUTION,
+        }
+    }
+
+    #[inline]
+    fn quad(&mut self, a: Vec3, b: Vec3, c: Vec3, d: Vec3, color: impl Into<Color>) {
+        self.triangle_positions.extend([a, b, c, a, c, d]);
+        self.add_triangle_color(color, 6);
+    }
+
+    #[inline]
+    fn add_triangle_color(&mut self, color: impl Into<Color>, count: usize) {
+        let linear_color = LinearRgba::from(color.into());
+        self.triangle_colors
+            .extend(iter::repeat_n(linear_color, count));
+    }
+
+    /// Draws the surface of revolution of `profile` around the local `Vec3::Y` axis.
+    ///
+    /// `profile` is a list of `(radius, height)` pairs going from the top to the bottom of the shape.
+    fn lathe(
+        &mut self,
+        isometry: Isometry3d,
+        profile: &[(f32, f32)],
+        resolution: u32,
+        color: Color,
+    ) {
+        let resolution = resolution.max(3);
+        let ring = |(radius, height): (f32, f32), i: u32| {
+            let angle = i as f32 * TAU / resolution as f32;
+            let (sin, cos) = ops::sin_cos(angle);
+            isometry * Vec3::new(cos * radius, height, sin * radius)
+        };
+
+        for window in profile.windows(2) {
+            let (top, bottom) = (window[0], window[1]);
+            for i in 0..resolution {
+                let top_a = ring(top, i);
+                let top_b = ring(top, i + 1);
+                let bottom_a = ring(bottom, i);
+                let bottom_b = ring(bottom, i + 1);
+                // Rings with a zero radius would produce degenerate triangles.
+                if top.0 != 0. {
+                    self.triangle(top_a, top_b, bottom_a, color);
+                }
+                if bottom.0 != 0. {
+                    self.triangle(top_b, bottom_b, bottom_a, color);
+                }
+            }
+        }
+    }
+}
+
+/// A builder returned by [`GizmoBuffer::filled_circle`].
+pub struct FilledCircleBuilder<'a, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    gizmos: &'a mut GizmoBuffer<Config, Clear>,
+    isometry: Isometry3d,
+    radius: f32,
+    color: Color,
+    resolution: u32,
+}
+
+impl<Config, Clear> FilledCircleBuilder<'_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Set the number of triangles used to approximate the circle.
+    pub fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+}
+
+impl<Config, Clear> Drop for FilledCircleBuilder<'_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    fn drop(&mut self) {
+        if !self.gizmos.enabled {
+            return;
+        }
+
+        let center = self.isometry.translation.into();
+        let rim: Vec<Vec3> = circle_rim(self.radius, self.resolution)
+            .map(|vec2| self.isometry * vec2.extend(0.))
+            .collect();
+        for edge in rim.windows(2) {
+            self.gizmos.triangle(center, edge[0], edge[1], self.color);
+        }
+    }
+}
+
+/// A builder returned by [`GizmoBuffer::filled_circle_2d`].
+pub struct FilledCircle2dBuilder<'a, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    gizmos: &'a mut GizmoBuffer<Config, Clear>,
+    isometry: Isometry2d,
+    radius: f32,
+    color: Color,
+    resolution: u32,
+}
+
+impl<Config, Clear> FilledCircle2dBuilder<'_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Set the number of triangles used to approximate the circle.
+    pub fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+}
+
+impl<Config, Clear> Drop for FilledCircle2dBuilder<'_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    fn drop(&mut self) {
+        if !self.gizmos.enabled {
+            return;
+        }
+
+        let center = self.isometry.translation;
+        let rim: Vec<Vec2> = circle_rim(self.radius, self.resolution)
+            .map(|vec2| self.isometry * vec2)
+            .collect();
+        for edge in rim.windows(2) {
+            self.gizmos
+                .triangle_2d(center, edge[0], edge[1], self.color);
+        }
+    }
+}
+
+/// A builder returned by [`GizmoBuffer::filled_sphere`].
+pub struct FilledSphereBuilder<'a, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    gizmos: &'a mut GizmoBuffer<Config, Clear>,
+    isometry: Isometry3d,
+    radius: f32,
+    color: Color,
+    resolution: u32,
+}
+
+impl<Config, Clear> FilledSphereBuilder<'_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Set the number of segments around the equator of the sphere.
+    ///
+    /// Half as many segments are used from pole to pole.
+    pub fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+}
+
+impl<Config, Clear> Drop for FilledSphereBuilder<'_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    fn drop(&mut self) {
+        if !self.gizmos.enabled {
+            return;
+        }
+
+        let rings = (self.resolution / 2).max(2);
+        let profile: Vec<(f32, f32)> = (0..=rings)
+            .map(|i| {
+                let (sin, cos) = ops::sin_cos(i as f32 * PI / rings as f32);
+                (sin * self.radius, cos * self.radius)
+            })
+            .collect();
+        self.gizmos
+            .lathe(self.isometry, &profile, self.resolution, self.color);
+    }
+}
+
+/// A builder returned by [`GizmoBuffer::filled_capsule_3d`].
+pub struct FilledCapsule3dBuilder<'a, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    gizmos: &'a mut GizmoBuffer<Config, Clear>,
+    isometry: Isometry3d,
+    radius: f32,
+    half_length: f32,
+    color: Color,
+    resolution: u32,
+}
+
+impl<Config, Clear> FilledCapsule3dBuilder<'_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Set the number of segments around the axis of the capsule.
+    pub fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+}
+
+impl<Config, Clear> Drop for FilledCapsule3dBuilder<'_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    fn drop(&mut self) {
+        if !self.gizmos.enabled {
+            return;
+        }
+
+        let rings = (self.resolution / 4).max(2);
+        let radius = self.radius;
+        let hemisphere = |offset: f32, from: f32| {
+            (0..=rings).map(move |i| {
+                let (sin, cos) = ops::sin_cos(from + i as f32 * FRAC_PI_2 / rings as f32);
+                (sin * radius, cos * radius + offset)
+            })
+        };
+        // The top hemisphere ends and the bottom one starts at the equator, which
+        // connects both of them with the cylindrical part of the capsule.
+        let profile: Vec<(f32, f32)> = hemisphere(self.half_length, 0.)
+            .chain(hemisphere(-self.half_length, FRAC_PI_2))
+            .collect();
+        self.gizmos
+            .lathe(self.isometry, &profile, self.resolution, self.color);
+    }
+}
+
+fn circle_rim(radius: f32, resolution: u32) -> impl Iterator<Item = Vec2> {
+    let resolution = resolution.max(3);
+    (0..resolution + 1).map(move |i| {
+        let angle = i as f32 * TAU / resolution as f32;
+        let (sin, cos) = ops::sin_cos(angle);
+        Vec2::new(cos, sin) * radius
+    })
+}
@@ -30,6 +30,8 @@ pub struct GizmoStorage<Config, Clear> {
     pub(crate) list_colors: Vec<LinearRgba>,
     pub(crate) strip_positions: Vec<Vec3>,
     pub(crate) strip_colors: Vec<LinearRgba>,
+    pub(crate) triangle_positions: Vec<Vec3>,
+    pub(crate) triangle_colors: Vec<LinearRgba>,
     marker: PhantomData<(Config, Clear)>,
 }
 
@@ -40,6 +42,8 @@ impl<Config, Clear> Default for GizmoStorage<Config, Clear> {
             list_colors: default(),
             strip_positions: default(),
             strip_colors: default(),
+            triangle_positions: default(),
+            triangle_colors: default(),
             marker: PhantomData,
         }
     }
@@ -59,6 +63,9 @@ where
         self.list_colors.extend(other.list_colors.iter());
         self.strip_positions.extend(other.strip_positions.iter());
         self.strip_colors.extend(other.strip_colors.iter());
+        self.triangle_positions
+            .extend(other.triangle_positions.iter());
+        self.triangle_colors.extend(other.triangle_colors.iter());
     }
 
     pub(crate) fn swap<OtherConfig, OtherClear>(
@@ -69,6 +76,8 @@ where
         mem::swap(&mut self.list_colors, &mut other.list_colors);
         mem::swap(&mut self.strip_positions, &mut other.strip_positions);
         mem::swap(&mut self.strip_colors, &mut other.strip_colors);
+        mem::swap(&mut self.triangle_positions, &mut other.triangle_positions);
+        mem::swap(&mut self.triangle_colors, &mut other.triangle_colors);
     }
 
     /// Clear this gizmo storage of any requested gizmos.
@@ -77,6 +86,8 @@ where
         self.list_colors.clear();
         self.strip_positions.clear();
         self.strip_colors.clear();
+        self.triangle_positions.clear();
+        self.triangle_colors.clear();
     }
 }
 
@@ -283,6 +294,8 @@ where
     pub(crate) list_colors: Vec<LinearRgba>,
     pub(crate) strip_positions: Vec<Vec3>,
     pub(crate) strip_colors: Vec<LinearRgba>,
+    pub(crate) triangle_positions: Vec<Vec3>,
+    pub(crate) triangle_colors: Vec<LinearRgba>,
     #[reflect(ignore)]
     pub(crate) marker: PhantomData<(Config, Clear)>,
 }
@@ -299,6 +312,8 @@ where
             list_colors: Vec::new(),
             strip_positions: Vec::new(),
             strip_colors: Vec::new(),
+            triangle_positions: Vec::new(),
+            triangle_colors: Vec::new(),
             marker: PhantomData,
         }
     }
@@ -314,6 +329,10 @@ pub struct GizmoBufferView<'a> {
     pub strip_positions: &'a Vec<Vec3>,
     /// Vertex colors for line-strip topology.
     pub strip_colors: &'a Vec<LinearRgba>,
+    /// Vertex positions for triangle-list topology.
+    pub triangle_positions: &'a Vec<Vec3>,
+    /// Vertex colors for triangle-list topology.
+    pub triangle_colors: &'a Vec<LinearRgba>,
 }
 
 impl<Config, Clear> SystemBuffer for GizmoBuffer<Config, Clear>
@@ -327,6 +346,10 @@ where
         storage.list_colors.append(&mut self.list_colors);
         storage.strip_positions.append(&mut self.strip_positions);
         storage.strip_colors.append(&mut self.strip_colors);
+        storage
+            .triangle_positions
+            .append(&mut self.triangle_positions);
+        storage.triangle_colors.append(&mut self.triangle_colors);
     }
 }
 
@@ -341,6 +364,8 @@ where
         self.list_colors.clear();
         self.strip_positions.clear();
         self.strip_colors.clear();
+        self.triangle_positions.clear();
+        self.triangle_colors.clear();
     }
 
     /// Read-only view into the buffers data.
@@ -350,6 +375,8 @@ where
             list_colors,
             strip_positions,
             strip_colors,
+            triangle_positions,
+            triangle_colors,
             ..
         } = self;
         GizmoBufferView {
@@ -357,6 +384,8 @@ where
             list_colors,
             strip_positions,
             strip_colors,
+            triangle_positions,
+            triangle_colors,
         }
     }
     /// Draw a line in 3D from `start` to `end`.
@@ -830,7 +859,7 @@ where
     }
 }
 
-fn rect_inner(size: Vec2) -> [Vec2; 4] {
+pub(crate) fn rect_inner(size: Vec2) -> [Vec2; 4] {
     let half_size = size / 2.;
     let tl = Vec2::new(-half_size.x, half_size.y);
     let tr = Vec2::new(half_size.x, half_size.y);
@@ -43,11 +43,13 @@ pub mod circles;
 pub mod config;
 pub mod cross;
 pub mod curves;
+pub mod filled;
 pub mod gizmos;
 pub mod grid;
 pub mod primitives;
 pub mod retained;
 pub mod rounded_box;
+pub mod text;
 
 #[cfg(all(feature = "bevy_pbr", feature = "bevy_render"))]
 pub mod light;
@@ -68,7 +70,7 @@ pub mod prelude {
     pub use crate::{
         config::{
             DefaultGizmoConfigGroup, GizmoConfig, GizmoConfigGroup, GizmoConfigStore,
-            GizmoLineConfig, GizmoLineJoint, GizmoLineStyle,
+            GizmoDepthMode, GizmoLineConfig, GizmoLineJoint, GizmoLineStyle,
         },
         gizmos::Gizmos,
         primitives::{dim2::GizmoPrimitive2d, dim3::GizmoPrimitive3d},
@@ -145,6 +147,8 @@ use light::LightGizmoPlugin;
 const LINE_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(7414812689238026784);
 #[cfg(feature = "bevy_render")]
 const LINE_JOINT_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(1162780797909187908);
+#[cfg(feature = "bevy_render")]
+const TRIANGLE_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(5870637937118692703);
 
 /// A [`Plugin`] that provides an immediate mode drawing api for visual debugging.
 ///
@@ -164,6 +168,12 @@ impl Plugin for GizmoPlugin {
                 "line_joints.wgsl",
                 Shader::from_wgsl
             );
+            load_internal_asset!(
+                app,
+                TRIANGLE_SHADER_HANDLE,
+                "triangles.wgsl",
+                Shader::from_wgsl
+            );
         }
 
         app.register_type::<GizmoConfig>()
@@ -390,7 +400,10 @@ fn update_gizmo_meshes<Config: GizmoConfigGroup>(
     mut handles: ResMut<GizmoHandles>,
     mut storage: ResMut<GizmoStorage<Config, ()>>,
 ) {
-    if storage.list_positions.is_empty() && storage.strip_positions.is_empty() {
+    if storage.list_positions.is_empty()
+        && storage.strip_positions.is_empty()
+        && storage.triangle_positions.is_empty()
+    {
         handles.handles.insert(TypeId::of::<Config>(), None);
     } else if let Some(handle) = handles.handles.get_mut(&TypeId::of::<Config>()) {
         if let Some(handle) = handle {
@@ -400,6 +413,8 @@ fn update_gizmo_meshes<Config: GizmoConfigGroup>(
             gizmo.buffer.list_colors = mem::take(&mut storage.list_colors);
             gizmo.buffer.strip_positions = mem::take(&mut storage.strip_positions);
             gizmo.buffer.strip_colors = mem::take(&mut storage.strip_colors);
+            gizmo.buffer.triangle_positions = mem::take(&mut storage.triangle_positions);
+            gizmo.buffer.triangle_colors = mem::take(&mut storage.triangle_colors);
         } else {
             let gizmo = GizmoAsset {
                 config_ty: TypeId::of::<Config>(),
@@ -409,6 +424,8 @@ fn update_gizmo_meshes<Config: GizmoConfigGroup>(
                     list_colors: mem::take(&mut storage.list_colors),
                     strip_positions: mem::take(&mut storage.strip_positions),
                     strip_colors: mem::take(&mut storage.strip_colors),
+                    triangle_positions: mem::take(&mut storage.triangle_positions),
+                    triangle_colors: mem::take(&mut storage.triangle_colors),
                     marker: PhantomData,
                 },
             };
@@ -471,6 +488,7 @@ fn extract_gizmo_data(
                 joints_resolution,
                 gap_scale,
                 line_scale,
+                occluded_alpha: config.depth_mode.occluded_alpha(),
                 #[cfg(feature = "webgl")]
                 _padding: Default::default(),
             },
@@ -479,6 +497,7 @@ fn extract_gizmo_data(
                 line_perspective: config.line.perspective,
                 line_style: config.line.style,
                 line_joints: config.line.joints,
+                depth_mode: config.depth_mode,
                 render_layers: config.render_layers.clone(),
                 handle: handle.clone(),
             },
@@ -501,6 +520,8 @@ struct LineGizmoUniform {
     // Only used if the current configs `line_style` is set to `GizmoLineStyle::Dashed{_}`
     gap_scale: f32,
     line_scale: f32,
+    // Only used if the current configs `depth_mode` is set to `GizmoDepthMode::XRay{_}`
+    occluded_alpha: f32,
     /// WebGL2 structs must be 16 byte aligned.
     #[cfg(feature = "webgl")]
     _padding: Vec3,
@@ -546,6 +567,9 @@ struct GpuLineGizmo {
     strip_position_buffer: Buffer,
     strip_color_buffer: Buffer,
     strip_vertex_count: u32,
+    triangle_position_buffer: Buffer,
+    triangle_color_buffer: Buffer,
+    triangle_vertex_count: u32,
 }
 
 #[cfg(feature = "bevy_render")]
@@ -582,6 +606,19 @@ impl RenderAsset for GpuLineGizmo {
             contents: cast_slice(&gizmo.buffer.strip_colors),
         });
 
+        let triangle_position_buffer =
+            render_device.create_buffer_with_data(&BufferInitDescriptor {
+                usage: BufferUsages::VERTEX,
+                label: Some("LineGizmo Triangle Position Buffer"),
+                contents: cast_slice(&gizmo.buffer.triangle_positions),
+            });
+
+        let triangle_color_buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
+            usage: BufferUsages::VERTEX,
+            label: Some("LineGizmo Triangle Color Buffer"),
+            contents: cast_slice(&gizmo.buffer.triangle_colors),
+        });
+
         Ok(GpuLineGizmo {
             list_position_buffer,
             list_color_buffer,
@@ -589,6 +626,9 @@ impl RenderAsset for GpuLineGizmo {
             strip_position_buffer,
             strip_color_buffer,
             strip_vertex_count: gizmo.buffer.strip_positions.len() as u32,
+            triangle_position_buffer,
+            triangle_color_buffer,
+            triangle_vertex_count: gizmo.buffer.triangle_positions.len() as u32,
         })
     }
 }
@@ -789,6 +829,45 @@ impl<P: PhaseItem> RenderCommand<P> for DrawLineJointGizmo {
     }
 }
 
+#[cfg(feature = "bevy_render")]
+struct DrawTriangleGizmo;
+#[cfg(all(
+    feature = "bevy_render",
+    any(feature = "bevy_pbr", feature = "bevy_sprite")
+))]
+impl<P: PhaseItem> RenderCommand<P> for DrawTriangleGizmo {
+    type Param = SRes<RenderAssets<GpuLineGizmo>>;
+    type ViewQuery = ();
+    type ItemQuery = Read<GizmoMeshConfig>;
+
+    #[inline]
+    fn render<'w>(
+        _item: &P,
+        _view: ROQueryItem<'w, Self::ViewQuery>,
+        config: Option<ROQueryItem<'w, Self::ItemQuery>>,
+        line_gizmos: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let Some(config) = config else {
+            return RenderCommandResult::Skip;
+        };
+        let Some(line_gizmo) = line_gizmos.into_inner().get(&config.handle) else {
+            return RenderCommandResult::Skip;
+        };
+
+        if line_gizmo.triangle_vertex_count < 3 {
+            return RenderCommandResult::Success;
+        }
+
+        pass.set_vertex_buffer(0, line_gizmo.triangle_position_buffer.slice(..));
+        pass.set_vertex_buffer(1, line_gizmo.triangle_color_buffer.slice(..));
+
+        pass.draw(0..line_gizmo.triangle_vertex_count, 0..1);
+
+        RenderCommandResult::Success
+    }
+}
+
 #[cfg(all(
     feature = "bevy_render",
     any(feature = "bevy_pbr", feature = "bevy_sprite")
@@ -886,3 +965,31 @@ fn line_joint_gizmo_vertex_buffer_layouts() -> Vec<VertexBufferLayout> {
         color_layout.clone(),
     ]
 }
+
+#[cfg(all(
+    feature = "bevy_render",
+    any(feature = "bevy_pbr", feature = "bevy_sprite")
+))]
+fn triangle_gizmo_vertex_buffer_layouts() -> Vec<VertexBufferLayout> {
+    use VertexFormat::*;
+    vec![
+        VertexBufferLayout {
+            array_stride: Float32x3.size(),
+            step_mode: VertexStepMode::Vertex,
+            attributes: vec![VertexAttribute {
+                format: Float32x3,
+                offset: 0,
+                shader_location: 0,
+            }],
+        },
+        VertexBufferLayout {
+            array_stride: Float32x4.size(),
+            step_mode: VertexStepMode::Vertex,
+            attributes: vec![VertexAttribute {
+                format: Float32x4,
+                offset: 0,
+                shader_location: 1,
+            }],
+        },
+    ]
+}
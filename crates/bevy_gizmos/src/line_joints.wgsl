@@ -8,9 +8,12 @@ struct LineGizmoUniform {
     line_width: f32,
     depth_bias: f32,
     resolution: u32,
+    _gap_scale: f32,
+    _line_scale: f32,
+    occluded_alpha: f32,
 #ifdef SIXTEEN_BYTE_ALIGNMENT
     // WebGL2 structs must be 16 byte aligned.
-    _padding: f32,
+    _padding: vec3<f32>,
 #endif
 }
 
@@ -86,6 +89,9 @@ fn vertex_bevel(vertex: VertexInput) -> VertexOutput {
     let depth = depth(clip_b);
 
     var clip_position = vec4(clip_b.w * ((2. * screen) / resolution - 1.), depth, clip_b.w);
+#ifdef OCCLUDED
+    color.a *= joints_gizmo.occluded_alpha;
+#endif
     return VertexOutput(clip_position, color);
 }
 
@@ -148,6 +154,9 @@ fn vertex_miter(vertex: VertexInput) -> VertexOutput {
     var depth = depth(clip_b);
 
     var clip_position = vec4(clip_b.w * ((2. * screen) / resolution - 1.), depth, clip_b.w);
+#ifdef OCCLUDED
+    color.a *= joints_gizmo.occluded_alpha;
+#endif
     return VertexOutput(clip_position, color);
 }
 
@@ -206,6 +215,9 @@ fn vertex_round(vertex: VertexInput) -> VertexOutput {
     var depth = depth(clip_b);
 
     var clip_position = vec4(clip_b.w * ((2. * screen) / resolution - 1.), depth, clip_b.w);
+#ifdef OCCLUDED
+    color.a *= joints_gizmo.occluded_alpha;
+#endif
     return VertexOutput(clip_position, color);
 }
 
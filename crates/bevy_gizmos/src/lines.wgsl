@@ -11,6 +11,7 @@ struct LineGizmoUniform {
     _joints_resolution: u32,
     gap_scale: f32,
     line_scale: f32,
+    occluded_alpha: f32,
 #ifdef SIXTEEN_BYTE_ALIGNMENT
     // WebGL2 structs must be 16 byte aligned.
     _padding: vec3<f32>,
@@ -130,6 +131,10 @@ fn vertex(vertex: VertexInput) -> VertexOutput {
 
     var clip_position = vec4(clip.w * ((2. * screen) / resolution - 1.), depth, clip.w);
 
+#ifdef OCCLUDED
+    color.a *= line_gizmo.occluded_alpha;
+#endif
+
     let line_fraction = 2.0 * line_gizmo.line_scale / (line_gizmo.gap_scale + line_gizmo.line_scale);
     uv /= (line_gizmo.gap_scale + line_gizmo.line_scale) / 2.0;
     return VertexOutput(clip_position, color, uv, line_fraction);
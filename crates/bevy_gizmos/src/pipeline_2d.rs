@@ -1,8 +1,9 @@
 use crate::{
     config::{GizmoLineJoint, GizmoLineStyle, GizmoMeshConfig},
-    line_gizmo_vertex_buffer_layouts, line_joint_gizmo_vertex_buffer_layouts, DrawLineGizmo,
-    DrawLineJointGizmo, GizmoRenderSystem, GpuLineGizmo, LineGizmoUniformBindgroupLayout,
-    SetLineGizmoBindGroup, LINE_JOINT_SHADER_HANDLE, LINE_SHADER_HANDLE,
+    line_gizmo_vertex_buffer_layouts, line_joint_gizmo_vertex_buffer_layouts,
+    triangle_gizmo_vertex_buffer_layouts, DrawLineGizmo, DrawLineJointGizmo, DrawTriangleGizmo,
+    GizmoRenderSystem, GpuLineGizmo, LineGizmoUniformBindgroupLayout, SetLineGizmoBindGroup,
+    LINE_JOINT_SHADER_HANDLE, LINE_SHADER_HANDLE, TRIANGLE_SHADER_HANDLE,
 };
 use bevy_app::{App, Plugin};
 use bevy_core_pipeline::core_2d::{Transparent2d, CORE_2D_DEPTH_FORMAT};
@@ -41,8 +42,10 @@ impl Plugin for LineGizmo2dPlugin {
             .add_render_command::<Transparent2d, DrawLineGizmo2d>()
             .add_render_command::<Transparent2d, DrawLineGizmo2dStrip>()
             .add_render_command::<Transparent2d, DrawLineJointGizmo2d>()
+            .add_render_command::<Transparent2d, DrawTriangleGizmo2d>()
             .init_resource::<SpecializedRenderPipelines<LineGizmoPipeline>>()
             .init_resource::<SpecializedRenderPipelines<LineJointGizmoPipeline>>()
+            .init_resource::<SpecializedRenderPipelines<TriangleGizmoPipeline>>()
             .configure_sets(
                 Render,
                 GizmoRenderSystem::QueueLineGizmos2d
@@ -54,7 +57,11 @@ impl Plugin for LineGizmo2dPlugin {
             )
             .add_systems(
                 Render,
-                (queue_line_gizmos_2d, queue_line_joint_gizmos_2d)
+                (
+                    queue_line_gizmos_2d,
+                    queue_line_joint_gizmos_2d,
+                    queue_triangle_gizmos_2d,
+                )
                     .in_set(GizmoRenderSystem::QueueLineGizmos2d)
                     .after(prepare_assets::<GpuLineGizmo>),
             );
@@ -67,6 +74,7 @@ impl Plugin for LineGizmo2dPlugin {
 
         render_app.init_resource::<LineGizmoPipeline>();
         render_app.init_resource::<LineJointGizmoPipeline>();
+        render_app.init_resource::<TriangleGizmoPipeline>();
     }
 }
 
@@ -269,6 +277,96 @@ impl SpecializedRenderPipeline for LineJointGizmoPipeline {
     }
 }
 
+#[derive(Clone, Resource)]
+struct TriangleGizmoPipeline {
+    mesh_pipeline: Mesh2dPipeline,
+    uniform_layout: BindGroupLayout,
+}
+
+impl FromWorld for TriangleGizmoPipeline {
+    fn from_world(render_world: &mut World) -> Self {
+        TriangleGizmoPipeline {
+            mesh_pipeline: render_world.resource::<Mesh2dPipeline>().clone(),
+            uniform_layout: render_world
+                .resource::<LineGizmoUniformBindgroupLayout>()
+                .layout
+                .clone(),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct TriangleGizmoPipelineKey {
+    mesh_key: Mesh2dPipelineKey,
+}
+
+impl SpecializedRenderPipeline for TriangleGizmoPipeline {
+    type Key = TriangleGizmoPipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let format = if key.mesh_key.contains(Mesh2dPipelineKey::HDR) {
+            ViewTarget::TEXTURE_FORMAT_HDR
+        } else {
+            TextureFormat::bevy_default()
+        };
+
+        let shader_defs = vec![
+            #[cfg(feature = "webgl")]
+            "SIXTEEN_BYTE_ALIGNMENT".into(),
+        ];
+
+        let layout = vec![
+            self.mesh_pipeline.view_layout.clone(),
+            self.uniform_layout.clone(),
+        ];
+
+        RenderPipelineDescriptor {
+            vertex: VertexState {
+                shader: TRIANGLE_SHADER_HANDLE,
+                entry_point: "vertex".into(),
+                shader_defs: shader_defs.clone(),
+                buffers: triangle_gizmo_vertex_buffer_layouts(),
+            },
+            fragment: Some(FragmentState {
+                shader: TRIANGLE_SHADER_HANDLE,
+                shader_defs,
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format,
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            layout,
+            primitive: PrimitiveState::default(),
+            depth_stencil: Some(DepthStencilState {
+                format: CORE_2D_DEPTH_FORMAT,
+                depth_write_enabled: false,
+                depth_compare: CompareFunction::Always,
+                stencil: StencilState {
+                    front: StencilFaceState::IGNORE,
+                    back: StencilFaceState::IGNORE,
+                    read_mask: 0,
+                    write_mask: 0,
+                },
+                bias: DepthBiasState {
+                    constant: 0,
+                    slope_scale: 0.0,
+                    clamp: 0.0,
+                },
+            }),
+            multisample: MultisampleState {
+                count: key.mesh_key.msaa_samples(),
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            label: Some("TriangleGizmo Pipeline 2D".into()),
+            push_constant_ranges: vec![],
+            zero_initialize_workgroup_memory: false,
+        }
+    }
+}
+
 type DrawLineGizmo2d = (
     SetItemPipeline,
     SetMesh2dViewBindGroup<0>,
@@ -287,6 +385,12 @@ type DrawLineJointGizmo2d = (
     SetLineGizmoBindGroup<1>,
     DrawLineJointGizmo,
 );
+type DrawTriangleGizmo2d = (
+    SetItemPipeline,
+    SetMesh2dViewBindGroup<0>,
+    SetLineGizmoBindGroup<1>,
+    DrawTriangleGizmo,
+);
 
 fn queue_line_gizmos_2d(
     draw_functions: Res<DrawFunctions<Transparent2d>>,
@@ -420,3 +524,57 @@ fn queue_line_joint_gizmos_2d(
         }
     }
 }
+
+fn queue_triangle_gizmos_2d(
+    draw_functions: Res<DrawFunctions<Transparent2d>>,
+    pipeline: Res<TriangleGizmoPipeline>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<TriangleGizmoPipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    line_gizmos: Query<(Entity, &MainEntity, &GizmoMeshConfig)>,
+    line_gizmo_assets: Res<RenderAssets<GpuLineGizmo>>,
+    mut transparent_render_phases: ResMut<ViewSortedRenderPhases<Transparent2d>>,
+    views: Query<(Entity, &ExtractedView, &Msaa, Option<&RenderLayers>)>,
+) {
+    let draw_function = draw_functions
+        .read()
+        .get_id::<DrawTriangleGizmo2d>()
+        .unwrap();
+
+    for (view_entity, view, msaa, render_layers) in &views {
+        let Some(transparent_phase) = transparent_render_phases.get_mut(&view_entity) else {
+            continue;
+        };
+
+        let mesh_key = Mesh2dPipelineKey::from_msaa_samples(msaa.samples())
+            | Mesh2dPipelineKey::from_hdr(view.hdr);
+
+        let render_layers = render_layers.unwrap_or_default();
+        for (entity, main_entity, config) in &line_gizmos {
+            if !config.render_layers.intersects(render_layers) {
+                continue;
+            }
+
+            let Some(line_gizmo) = line_gizmo_assets.get(&config.handle) else {
+                continue;
+            };
+
+            if line_gizmo.triangle_vertex_count < 3 {
+                continue;
+            }
+
+            let pipeline = pipelines.specialize(
+                &pipeline_cache,
+                &pipeline,
+                TriangleGizmoPipelineKey { mesh_key },
+            );
+            transparent_phase.add(Transparent2d {
+                entity: (entity, *main_entity),
+                draw_function,
+                pipeline,
+                sort_key: FloatOrd(f32::INFINITY),
+                batch_range: 0..1,
+                extra_index: PhaseItemExtraIndex::None,
+            });
+        }
+    }
+}
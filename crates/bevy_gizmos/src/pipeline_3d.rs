@@ -1,8 +1,9 @@
 use crate::{
-    config::{GizmoLineJoint, GizmoLineStyle, GizmoMeshConfig},
-    line_gizmo_vertex_buffer_layouts, line_joint_gizmo_vertex_buffer_layouts, DrawLineGizmo,
-    DrawLineJointGizmo, GizmoRenderSystem, GpuLineGizmo, LineGizmoUniformBindgroupLayout,
-    SetLineGizmoBindGroup, LINE_JOINT_SHADER_HANDLE, LINE_SHADER_HANDLE,
+    config::{GizmoDepthMode, GizmoLineJoint, GizmoLineStyle, GizmoMeshConfig},
+    line_gizmo_vertex_buffer_layouts, line_joint_gizmo_vertex_buffer_layouts,
+    triangle_gizmo_vertex_buffer_layouts, DrawLineGizmo, DrawLineJointGizmo, DrawTriangleGizmo,
+    GizmoRenderSystem, GpuLineGizmo, LineGizmoUniformBindgroupLayout, SetLineGizmoBindGroup,
+    LINE_JOINT_SHADER_HANDLE, LINE_SHADER_HANDLE, TRIANGLE_SHADER_HANDLE,
 };
 use bevy_app::{App, Plugin};
 use bevy_core_pipeline::{
@@ -43,8 +44,10 @@ impl Plugin for LineGizmo3dPlugin {
             .add_render_command::<Transparent3d, DrawLineGizmo3d>()
             .add_render_command::<Transparent3d, DrawLineGizmo3dStrip>()
             .add_render_command::<Transparent3d, DrawLineJointGizmo3d>()
+            .add_render_command::<Transparent3d, DrawTriangleGizmo3d>()
             .init_resource::<SpecializedRenderPipelines<LineGizmoPipeline>>()
             .init_resource::<SpecializedRenderPipelines<LineJointGizmoPipeline>>()
+            .init_resource::<SpecializedRenderPipelines<TriangleGizmoPipeline>>()
             .configure_sets(
                 Render,
                 GizmoRenderSystem::QueueLineGizmos3d
@@ -53,7 +56,11 @@ impl Plugin for LineGizmo3dPlugin {
             )
             .add_systems(
                 Render,
-                (queue_line_gizmos_3d, queue_line_joint_gizmos_3d)
+                (
+                    queue_line_gizmos_3d,
+                    queue_line_joint_gizmos_3d,
+                    queue_triangle_gizmos_3d,
+                )
                     .in_set(GizmoRenderSystem::QueueLineGizmos3d)
                     .after(prepare_assets::<GpuLineGizmo>),
             );
@@ -66,6 +73,60 @@ impl Plugin for LineGizmo3dPlugin {
 
         render_app.init_resource::<LineGizmoPipeline>();
         render_app.init_resource::<LineJointGizmoPipeline>();
+        render_app.init_resource::<TriangleGizmoPipeline>();
+    }
+}
+
+/// The part of a gizmo drawn by a pipeline, depending on the [`GizmoDepthMode`] of the gizmo.
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum GizmoDepthPass {
+    /// Draws the parts of the gizmo that are in front of the scene.
+    Visible,
+    /// Draws the parts of the gizmo that are behind the scene, dimmed.
+    Occluded,
+    /// Draws the whole gizmo without depth testing.
+    AlwaysOnTop,
+}
+
+impl GizmoDepthPass {
+    fn passes(depth_mode: GizmoDepthMode) -> &'static [Self] {
+        match depth_mode {
+            GizmoDepthMode::DepthTest => &[Self::Visible],
+            GizmoDepthMode::AlwaysOnTop => &[Self::AlwaysOnTop],
+            GizmoDepthMode::XRay { .. } => &[Self::Visible, Self::Occluded],
+        }
+    }
+
+    /// The distance of the [`Transparent3d`] items of this pass, which are sorted in ascending
+    /// order: the occluded parts of x-ray gizmos are drawn right before their visible parts,
+    /// whatever the stability of the sort.
+    fn distance(self) -> f32 {
+        match self {
+            Self::Occluded => -f32::EPSILON,
+            Self::Visible | Self::AlwaysOnTop => 0.,
+        }
+    }
+
+    fn shader_defs(self, shader_defs: &mut Vec<ShaderDefVal>) {
+        if self == Self::Occluded {
+            shader_defs.push("OCCLUDED".into());
+        }
+    }
+
+    fn depth_stencil(self, depth_write_enabled: bool) -> DepthStencilState {
+        let (depth_write_enabled, depth_compare) = match self {
+            Self::Visible => (depth_write_enabled, CompareFunction::Greater),
+            // Bevy uses reverse-z, so fragments behind the scene have a lower depth.
+            Self::Occluded => (false, CompareFunction::Less),
+            Self::AlwaysOnTop => (false, CompareFunction::Always),
+        };
+        DepthStencilState {
+            format: CORE_3D_DEPTH_FORMAT,
+            depth_write_enabled,
+            depth_compare,
+            stencil: StencilState::default(),
+            bias: DepthBiasState::default(),
+        }
     }
 }
 
@@ -93,6 +154,7 @@ struct LineGizmoPipelineKey {
     strip: bool,
     perspective: bool,
     line_style: GizmoLineStyle,
+    depth_pass: GizmoDepthPass,
 }
 
 impl SpecializedRenderPipeline for LineGizmoPipeline {
@@ -108,6 +170,8 @@ impl SpecializedRenderPipeline for LineGizmoPipeline {
             shader_defs.push("PERSPECTIVE".into());
         }
 
+        key.depth_pass.shader_defs(&mut shader_defs);
+
         let format = if key.view_key.contains(MeshPipelineKey::HDR) {
             ViewTarget::TEXTURE_FORMAT_HDR
         } else {
@@ -146,13 +210,7 @@ impl SpecializedRenderPipeline for LineGizmoPipeline {
             }),
             layout,
             primitive: PrimitiveState::default(),
-            depth_stencil: Some(DepthStencilState {
-                format: CORE_3D_DEPTH_FORMAT,
-                depth_write_enabled: true,
-                depth_compare: CompareFunction::Greater,
-                stencil: StencilState::default(),
-                bias: DepthBiasState::default(),
-            }),
+            depth_stencil: Some(key.depth_pass.depth_stencil(true)),
             multisample: MultisampleState {
                 count: key.view_key.msaa_samples(),
                 mask: !0,
@@ -188,6 +246,7 @@ struct LineJointGizmoPipelineKey {
     view_key: MeshPipelineKey,
     perspective: bool,
     joints: GizmoLineJoint,
+    depth_pass: GizmoDepthPass,
 }
 
 impl SpecializedRenderPipeline for LineJointGizmoPipeline {
@@ -203,6 +262,8 @@ impl SpecializedRenderPipeline for LineJointGizmoPipeline {
             shader_defs.push("PERSPECTIVE".into());
         }
 
+        key.depth_pass.shader_defs(&mut shader_defs);
+
         let format = if key.view_key.contains(MeshPipelineKey::HDR) {
             ViewTarget::TEXTURE_FORMAT_HDR
         } else {
@@ -245,13 +306,7 @@ impl SpecializedRenderPipeline for LineJointGizmoPipeline {
             }),
             layout,
             primitive: PrimitiveState::default(),
-            depth_stencil: Some(DepthStencilState {
-                format: CORE_3D_DEPTH_FORMAT,
-                depth_write_enabled: true,
-                depth_compare: CompareFunction::Greater,
-                stencil: StencilState::default(),
-                bias: DepthBiasState::default(),
-            }),
+            depth_stencil: Some(key.depth_pass.depth_stencil(true)),
             multisample: MultisampleState {
                 count: key.view_key.msaa_samples(),
                 mask: !0,
@@ -264,6 +319,88 @@ impl SpecializedRenderPipeline for LineJointGizmoPipeline {
     }
 }
 
+#[derive(Clone, Resource)]
+struct TriangleGizmoPipeline {
+    mesh_pipeline: MeshPipeline,
+    uniform_layout: BindGroupLayout,
+}
+
+impl FromWorld for TriangleGizmoPipeline {
+    fn from_world(render_world: &mut World) -> Self {
+        TriangleGizmoPipeline {
+            mesh_pipeline: render_world.resource::<MeshPipeline>().clone(),
+            uniform_layout: render_world
+                .resource::<LineGizmoUniformBindgroupLayout>()
+                .layout
+                .clone(),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct TriangleGizmoPipelineKey {
+    view_key: MeshPipelineKey,
+    depth_pass: GizmoDepthPass,
+}
+
+impl SpecializedRenderPipeline for TriangleGizmoPipeline {
+    type Key = TriangleGizmoPipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let mut shader_defs = vec![
+            #[cfg(feature = "webgl")]
+            "SIXTEEN_BYTE_ALIGNMENT".into(),
+        ];
+
+        key.depth_pass.shader_defs(&mut shader_defs);
+
+        let format = if key.view_key.contains(MeshPipelineKey::HDR) {
+            ViewTarget::TEXTURE_FORMAT_HDR
+        } else {
+            TextureFormat::bevy_default()
+        };
+
+        let view_layout = self
+            .mesh_pipeline
+            .get_view_layout(key.view_key.into())
+            .clone();
+
+        let layout = vec![view_layout, self.uniform_layout.clone()];
+
+        RenderPipelineDescriptor {
+            vertex: VertexState {
+                shader: TRIANGLE_SHADER_HANDLE,
+                entry_point: "vertex".into(),
+                shader_defs: shader_defs.clone(),
+                buffers: triangle_gizmo_vertex_buffer_layouts(),
+            },
+            fragment: Some(FragmentState {
+                shader: TRIANGLE_SHADER_HANDLE,
+                shader_defs,
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format,
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            layout,
+            primitive: PrimitiveState::default(),
+            // Filled gizmos are usually translucent, so they are depth tested
+            // but don't write depth to keep overlapping volumes visible.
+            depth_stencil: Some(key.depth_pass.depth_stencil(false)),
+            multisample: MultisampleState {
+                count: key.view_key.msaa_samples(),
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            label: Some("TriangleGizmo 3d Pipeline".into()),
+            push_constant_ranges: vec![],
+            zero_initialize_workgroup_memory: false,
+        }
+    }
+}
+
 type DrawLineGizmo3d = (
     SetItemPipeline,
     SetMeshViewBindGroup<0>,
@@ -282,6 +419,12 @@ type DrawLineJointGizmo3d = (
     SetLineGizmoBindGroup<1>,
     DrawLineJointGizmo,
 );
+type DrawTriangleGizmo3d = (
+    SetItemPipeline,
+    SetMeshViewBindGroup<0>,
+    SetLineGizmoBindGroup<1>,
+    DrawTriangleGizmo,
+);
 
 fn queue_line_gizmos_3d(
     draw_functions: Res<DrawFunctions<Transparent3d>>,
@@ -352,43 +495,144 @@ fn queue_line_gizmos_3d(
                 continue;
             };
 
-            if line_gizmo.list_vertex_count > 0 {
-                let pipeline = pipelines.specialize(
-                    &pipeline_cache,
-                    &pipeline,
-                    LineGizmoPipelineKey {
-                        view_key,
-                        strip: false,
-                        perspective: config.line_perspective,
-                        line_style: config.line_style,
-                    },
-                );
-                transparent_phase.add(Transparent3d {
-                    entity: (entity, *main_entity),
-                    draw_function,
-                    pipeline,
-                    distance: 0.,
-                    batch_range: 0..1,
-                    extra_index: PhaseItemExtraIndex::None,
-                });
+            for &depth_pass in GizmoDepthPass::passes(config.depth_mode) {
+                if line_gizmo.list_vertex_count > 0 {
+                    let pipeline = pipelines.specialize(
+                        &pipeline_cache,
+                        &pipeline,
+                        LineGizmoPipelineKey {
+                            view_key,
+                            strip: false,
+                            perspective: config.line_perspective,
+                            line_style: config.line_style,
+                            depth_pass,
+                        },
+                    );
+                    transparent_phase.add(Transparent3d {
+                        entity: (entity, *main_entity),
+                        draw_function,
+                        pipeline,
+                        distance: depth_pass.distance(),
+                        batch_range: 0..1,
+                        extra_index: PhaseItemExtraIndex::None,
+                    });
+                }
+
+                if line_gizmo.strip_vertex_count >= 2 {
+                    let pipeline = pipelines.specialize(
+                        &pipeline_cache,
+                        &pipeline,
+                        LineGizmoPipelineKey {
+                            view_key,
+                            strip: true,
+                            perspective: config.line_perspective,
+                            line_style: config.line_style,
+                            depth_pass,
+                        },
+                    );
+                    transparent_phase.add(Transparent3d {
+                        entity: (entity, *main_entity),
+                        draw_function: draw_function_strip,
+                        pipeline,
+                        distance: depth_pass.distance(),
+                        batch_range: 0..1,
+                        extra_index: PhaseItemExtraIndex::None,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn queue_line_joint_gizmos_3d(
+    draw_functions: Res<DrawFunctions<Transparent3d>>,
+    pipeline: Res<LineJointGizmoPipeline>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<LineJointGizmoPipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    line_gizmos: Query<(Entity, &MainEntity, &GizmoMeshConfig)>,
+    line_gizmo_assets: Res<RenderAssets<GpuLineGizmo>>,
+    mut transparent_render_phases: ResMut<ViewSortedRenderPhases<Transparent3d>>,
+    views: Query<(
+        Entity,
+        &ExtractedView,
+        &Msaa,
+        Option<&RenderLayers>,
+        (
+            Has<NormalPrepass>,
+            Has<DepthPrepass>,
+            Has<MotionVectorPrepass>,
+            Has<DeferredPrepass>,
+        ),
+    )>,
+) {
+    let draw_function = draw_functions
+        .read()
+        .get_id::<DrawLineJointGizmo3d>()
+        .unwrap();
+
+    for (
+        view_entity,
+        view,
+        msaa,
+        render_layers,
+        (normal_prepass, depth_prepass, motion_vector_prepass, deferred_prepass),
+    ) in &views
+    {
+        let Some(transparent_phase) = transparent_render_phases.get_mut(&view_entity) else {
+            continue;
+        };
+
+        let render_layers = render_layers.unwrap_or_default();
+
+        let mut view_key = MeshPipelineKey::from_msaa_samples(msaa.samples())
+            | MeshPipelineKey::from_hdr(view.hdr);
+
+        if normal_prepass {
+            view_key |= MeshPipelineKey::NORMAL_PREPASS;
+        }
+
+        if depth_prepass {
+            view_key |= MeshPipelineKey::DEPTH_PREPASS;
+        }
+
+        if motion_vector_prepass {
+            view_key |= MeshPipelineKey::MOTION_VECTOR_PREPASS;
+        }
+
+        if deferred_prepass {
+            view_key |= MeshPipelineKey::DEFERRED_PREPASS;
+        }
+
+        for (entity, main_entity, config) in &line_gizmos {
+            if !config.render_layers.intersects(render_layers) {
+                continue;
             }
 
-            if line_gizmo.strip_vertex_count >= 2 {
+            let Some(line_gizmo) = line_gizmo_assets.get(&config.handle) else {
+                continue;
+            };
+
+            if line_gizmo.strip_vertex_count < 3 || config.line_joints == GizmoLineJoint::None {
+                continue;
+            }
+
+            for &depth_pass in GizmoDepthPass::passes(config.depth_mode) {
                 let pipeline = pipelines.specialize(
                     &pipeline_cache,
                     &pipeline,
-                    LineGizmoPipelineKey {
+                    LineJointGizmoPipelineKey {
                         view_key,
-                        strip: true,
                         perspective: config.line_perspective,
-                        line_style: config.line_style,
+                        joints: config.line_joints,
+                        depth_pass,
                     },
                 );
+
                 transparent_phase.add(Transparent3d {
                     entity: (entity, *main_entity),
-                    draw_function: draw_function_strip,
+                    draw_function,
                     pipeline,
-                    distance: 0.,
+                    distance: depth_pass.distance(),
                     batch_range: 0..1,
                     extra_index: PhaseItemExtraIndex::None,
                 });
@@ -397,10 +641,10 @@ fn queue_line_gizmos_3d(
     }
 }
 
-fn queue_line_joint_gizmos_3d(
+fn queue_triangle_gizmos_3d(
     draw_functions: Res<DrawFunctions<Transparent3d>>,
-    pipeline: Res<LineJointGizmoPipeline>,
-    mut pipelines: ResMut<SpecializedRenderPipelines<LineJointGizmoPipeline>>,
+    pipeline: Res<TriangleGizmoPipeline>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<TriangleGizmoPipeline>>,
     pipeline_cache: Res<PipelineCache>,
     line_gizmos: Query<(Entity, &MainEntity, &GizmoMeshConfig)>,
     line_gizmo_assets: Res<RenderAssets<GpuLineGizmo>>,
@@ -420,7 +664,7 @@ fn queue_line_joint_gizmos_3d(
 ) {
     let draw_function = draw_functions
         .read()
-        .get_id::<DrawLineJointGizmo3d>()
+        .get_id::<DrawTriangleGizmo3d>()
         .unwrap();
 
     for (
@@ -465,28 +709,102 @@ fn queue_line_joint_gizmos_3d(
                 continue;
             };
 
-            if line_gizmo.strip_vertex_count < 3 || config.line_joints == GizmoLineJoint::None {
+            if line_gizmo.triangle_vertex_count < 3 {
                 continue;
             }
 
-            let pipeline = pipelines.specialize(
-                &pipeline_cache,
-                &pipeline,
-                LineJointGizmoPipelineKey {
-                    view_key,
-                    perspective: config.line_perspective,
-                    joints: config.line_joints,
-                },
-            );
+            for &depth_pass in GizmoDepthPass::passes(config.depth_mode) {
+                let pipeline = pipelines.specialize(
+                    &pipeline_cache,
+                    &pipeline,
+                    TriangleGizmoPipelineKey {
+                        view_key,
+                        depth_pass,
+                    },
+                );
 
-            transparent_phase.add(Transparent3d {
-                entity: (entity, *main_entity),
-                draw_function,
-                pipeline,
-                distance: 0.,
-                batch_range: 0..1,
-                extra_index: PhaseItemExtraIndex::None,
-            });
+                transparent_phase.add(Transparent3d {
+                    entity: (entity, *main_entity),
+                    draw_function,
+                    pipeline,
+                    distance: depth_pass.distance(),
+                    batch_range: 0..1,
+                    extra_index: PhaseItemExtraIndex::None,
+                });
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::FloatOrd;
+
+    #[test]
+    fn depth_passes() {
+        assert!(matches!(
+            GizmoDepthPass::passes(GizmoDepthMode::DepthTest),
+            [GizmoDepthPass::Visible]
+        ));
+        assert!(matches!(
+            GizmoDepthPass::passes(GizmoDepthMode::AlwaysOnTop),
+            [GizmoDepthPass::AlwaysOnTop]
+        ));
+        assert!(matches!(
+            GizmoDepthPass::passes(GizmoDepthMode::XRAY),
+            [GizmoDepthPass::Visible, GizmoDepthPass::Occluded]
+        ));
+
+        let compare = |pass: GizmoDepthPass| {
+            let state = pass.depth_stencil(true);
+            (state.depth_write_enabled, state.depth_compare)
+        };
+        assert_eq!(
+            compare(GizmoDepthPass::Visible),
+            (true, CompareFunction::Greater)
+        );
+        assert_eq!(
+            compare(GizmoDepthPass::Occluded),
+            (false, CompareFunction::Less)
+        );
+        assert_eq!(
+            compare(GizmoDepthPass::AlwaysOnTop),
+            (false, CompareFunction::Always)
+        );
+    }
+
+    #[test]
+    fn occluded_pass_is_drawn_first() {
+        let mut passes = [GizmoDepthPass::Visible, GizmoDepthPass::Occluded];
+        passes.sort_by_key(|pass| FloatOrd(pass.distance()));
+        assert!(matches!(
+            passes,
+            [GizmoDepthPass::Occluded, GizmoDepthPass::Visible]
+        ));
+        // Only the occluded pass is moved away from the other gizmos.
+        assert_eq!(GizmoDepthPass::Visible.distance(), 0.);
+        assert_eq!(GizmoDepthPass::AlwaysOnTop.distance(), 0.);
+    }
+
+    #[test]
+    fn occluded_alpha() {
+        assert_eq!(GizmoDepthMode::DepthTest.occluded_alpha(), 0.);
+        assert_eq!(GizmoDepthMode::AlwaysOnTop.occluded_alpha(), 1.);
+        assert_eq!(GizmoDepthMode::XRAY.occluded_alpha(), 0.2);
+        assert_eq!(
+            GizmoDepthMode::XRay {
+                occluded_alpha: 1.5
+            }
+            .occluded_alpha(),
+            1.
+        );
+        assert_eq!(
+            GizmoDepthMode::XRay {
+                occluded_alpha: -1.
+            }
+            .occluded_alpha(),
+            0.
+        );
+    }
+}
@@ -22,7 +22,7 @@ use {
 };
 
 use crate::{
-    config::{ErasedGizmoConfigGroup, GizmoLineConfig},
+    config::{ErasedGizmoConfigGroup, GizmoDepthMode, GizmoLineConfig},
     gizmos::GizmoBuffer,
     GizmoAsset,
 };
@@ -96,6 +96,10 @@ pub struct Gizmo {
     /// and your wireframe is z-fighting (flickering on/off) with your main model.
     /// You would set this value to a negative number close to 0.
     pub depth_bias: f32,
+    /// How the gizmo is depth tested against the scene.
+    ///
+    /// In 2D this setting has no effect.
+    pub depth_mode: GizmoDepthMode,
 }
 
 #[cfg(feature = "bevy_render")]
@@ -143,6 +147,7 @@ pub(crate) fn extract_linegizmos(
                 joints_resolution,
                 gap_scale,
                 line_scale,
+                occluded_alpha: gizmo.depth_mode.occluded_alpha(),
                 #[cfg(feature = "webgl")]
                 _padding: Default::default(),
             },
@@ -151,6 +156,7 @@ pub(crate) fn extract_linegizmos(
                 line_perspective: gizmo.line_config.perspective,
                 line_style: gizmo.line_config.style,
                 line_joints: gizmo.line_config.joints,
+                depth_mode: gizmo.depth_mode,
                 render_layers: render_layers.cloned().unwrap_or_default(),
                 handle: gizmo.handle.clone_weak(),
             },
@@ -0,0 +1,799 @@
+//! Additional [`GizmoBuffer`] Functions -- Text
+//!
+//! Includes the implementation of [`GizmoBuffer::text_3d`], [`GizmoBuffer::text_2d`],
+//! [`GizmoBuffer::billboard_text_3d`] and [`GizmoBuffer::screen_text`], and assorted support items.
+//!
+//! Text is drawn with a built-in stroke font made of line segments, so labels go through
+//! the same line rendering as every other gizmo and respect the [`GizmoConfig`](crate::config::GizmoConfig)
+//! of their group (line width, depth bias, render layers, ...).
+
+use crate::{gizmos::GizmoBuffer, prelude::GizmoConfigGroup};
+use bevy_color::Color;
+use bevy_math::{Isometry2d, Isometry3d, Quat, Vec2, Vec3};
+#[cfg(feature = "bevy_render")]
+use {bevy_math::Mat4, bevy_render::camera::Camera, bevy_transform::components::GlobalTransform};
+
+/// A single stroke of a glyph, given as a polyline on the glyph grid.
+type Stroke = &'static [(i8, i8)];
+
+/// Width of a glyph cell in grid units.
+const GLYPH_WIDTH: f32 = 4.;
+/// Height of an uppercase glyph in grid units.
+const GLYPH_HEIGHT: f32 = 6.;
+/// Horizontal distance between the origins of two consecutive glyphs in grid units.
+const GLYPH_ADVANCE: f32 = 6.;
+/// Vertical distance between two consecutive lines in grid units.
+const LINE_ADVANCE: f32 = 10.;
+/// Scale applied to lowercase letters, which are drawn as small capitals.
+const SMALL_CAPS_SCALE: f32 = 0.7;
+
+impl<Config, Clear> GizmoBuffer<Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Draw text in 3D with the given `isometry` applied.
+    ///
+    /// If `isometry == Isometry3d::IDENTITY` then
+    ///
+    /// - the text is centered on `Vec3::ZERO`
+    /// - the text runs along `Vec3::X` and lines stack along `-Vec3::Y`
+    /// - the text is readable when looking along `-Vec3::Z`
+    ///
+    /// `size` is the height of an uppercase letter in world units. Lines can be broken with `\n`.
+    /// Characters outside of printable ASCII are drawn as a box.
+    ///
+    /// Text is depth tested like every other gizmo. To draw labels on top of the scene, put them
+    /// in a [`GizmoConfigGroup`] whose [`GizmoConfig::depth_mode`](crate::config::GizmoConfig::depth_mode)
+    /// is [`GizmoDepthMode::AlwaysOnTop`](crate::config::GizmoDepthMode::AlwaysOnTop).
+    ///
+    /// This should be called for each frame the text needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::{RED, WHITE};
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.text_3d(Isometry3d::IDENTITY, "Hello, gizmos!", 0.25, WHITE);
+    ///
+    ///     // Anchor the bottom left corner of the text block to the position.
+    ///     let speed = 12.5;
+    ///     gizmos
+    ///         .text_3d(Vec3::Y, &format!("speed: {speed:.1}"), 0.25, RED)
+    ///         .anchor(Vec2::new(-0.5, -0.5));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn text_3d<'t>(
+        &mut self,
+        isometry: impl Into<Isometry3d>,
+        text: &'t str,
+        size: f32,
+        color: impl Into<Color>,
+    ) -> Text3dBuilder<'_, 't, Config, Clear> {
+        Text3dBuilder {
+            gizmos: self,
+            isometry: isometry.into(),
+            text,
+            size,
+            color: color.into(),
+            anchor: Vec2::ZERO,
+        }
+    }
+
+    /// Draw text in 3D at `position`, facing a camera with the given `camera_rotation`.
+    ///
+    /// This is useful for labels attached to world positions that should stay readable from
+    /// any viewpoint. `camera_rotation` is usually the rotation of the camera's
+    /// [`GlobalTransform`](bevy_transform::components::GlobalTransform).
+    ///
+    /// See [`GizmoBuffer::text_3d`] for details on sizing, layout and depth testing.
+    ///
+    /// This should be called for each frame the text needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_transform::prelude::*;
+    /// # use bevy_color::palettes::basic::YELLOW;
+    /// # #[derive(Component)]
+    /// # struct Camera;
+    /// fn system(mut gizmos: Gizmos, camera: Single<&GlobalTransform, With<Camera>>) {
+    ///     let rotation = camera.rotation();
+    ///     gizmos
+    ///         .billboard_text_3d(Vec3::new(0., 2., 0.), rotation, "spawn point", 0.2, YELLOW)
+    ///         .anchor(Vec2::new(0., -0.5));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn billboard_text_3d<'t>(
+        &mut self,
+        position: Vec3,
+        camera_rotation: Quat,
+        text: &'t str,
+        size: f32,
+        color: impl Into<Color>,
+    ) -> Text3dBuilder<'_, 't, Config, Clear> {
+        self.text_3d(
+            Isometry3d::new(position, camera_rotation),
+            text,
+            size,
+            color,
+        )
+    }
+
+    /// Draw a screen-space label attached to the world `position`, as seen by `camera`.
+    ///
+    /// The text faces the camera and is `pixel_size` logical pixels tall whatever its distance to
+    /// the camera, like a UI node following `position`, without spawning UI entities. `camera`
+    /// and `camera_transform` are those of the camera the label is drawn for. Nothing is drawn if
+    /// `position` is behind the camera, or if the size of its viewport is not known yet.
+    ///
+    /// See [`GizmoBuffer::text_3d`] for details on layout and depth testing.
+    ///
+    /// This should be called for each frame the text needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_transform::prelude::*;
+    /// # use bevy_color::palettes::basic::WHITE;
+    /// # #[derive(Component)]
+    /// # struct Health(f32);
+    /// fn system(
+    ///     mut gizmos: Gizmos,
+    ///     camera: Single<(&Camera, &GlobalTransform)>,
+    ///     enemies: Query<(&GlobalTransform, &Health)>,
+    /// ) {
+    ///     let (camera, camera_transform) = *camera;
+    ///     for (transform, health) in &enemies {
+    ///         let text = format!("{:.0} HP", health.0);
+    ///         gizmos
+    ///             .screen_text(camera, camera_transform, transform.translation(), &text, 14., WHITE)
+    ///             .anchor(Vec2::new(0., -0.5));
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[cfg(feature = "bevy_render")]
+    #[inline]
+    pub fn screen_text<'t>(
+        &mut self,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+        position: Vec3,
+        text: &'t str,
+        pixel_size: f32,
+        color: impl Into<Color>,
+    ) -> Text3dBuilder<'_, 't, Config, Clear> {
+        let placement = camera.logical_viewport_size().and_then(|viewport_size| {
+            screen_text_placement(
+                camera.clip_from_view(),
+                camera_transform,
+                viewport_size,
+                position,
+            )
+        });
+        match placement {
+            Some((isometry, pixel_world_size)) => {
+                self.text_3d(isometry, text, pixel_size * pixel_world_size, color)
+            }
+            None => self.text_3d(Isometry3d::IDENTITY, "", 0., color),
+        }
+    }
+
+    /// Draw text in 2D with the given `isometry` applied.
+    ///
+    /// If `isometry == Isometry2d::IDENTITY` then
+    ///
+    /// - the text is centered on `Vec2::ZERO`
+    /// - the text runs along `Vec2::X` and lines stack along `-Vec2::Y`
+    ///
+    /// `size` is the height of an uppercase letter in world units. Lines can be broken with `\n`.
+    /// Characters outside of printable ASCII are drawn as a box.
+    ///
+    /// This should be called for each frame the text needs to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.text_2d(Isometry2d::IDENTITY, "60 FPS", 16., GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn text_2d<'t>(
+        &mut self,
+        isometry: impl Into<Isometry2d>,
+        text: &'t str,
+        size: f32,
+        color: impl Into<Color>,
+    ) -> Text2dBuilder<'_, 't, Config, Clear> {
+        Text2dBuilder {
+            gizmos: self,
+            isometry: isometry.into(),
+            text,
+            size,
+            color: color.into(),
+            anchor: Vec2::ZERO,
+        }
+    }
+}
+
+/// A builder returned by [`GizmoBuffer::text_3d`] and [`GizmoBuffer::billboard_text_3d`].
+pub struct Text3dBuilder<'a, 't, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    gizmos: &'a mut GizmoBuffer<Config, Clear>,
+    isometry: Isometry3d,
+    text: &'t str,
+    size: f32,
+    color: Color,
+    anchor: Vec2,
+}
+
+impl<Config, Clear> Text3dBuilder<'_, '_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Set the point of the text block that is placed at the origin of the isometry.
+    ///
+    /// `Vec2::ZERO` is the center of the block, `Vec2::new(-0.5, -0.5)` its bottom left corner
+    /// and `Vec2::new(0.5, 0.5)` its top right corner. Defaults to `Vec2::ZERO`.
+    pub fn anchor(mut self, anchor: Vec2) -> Self {
+        self.anchor = anchor;
+        self
+    }
+}
+
+impl<Config, Clear> Drop for Text3dBuilder<'_, '_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    fn drop(&mut self) {
+        if !self.gizmos.enabled {
+            return;
+        }
+
+        let isometry = self.isometry;
+        let color = self.color;
+        for_each_stroke(self.text, self.size, self.anchor, |points| {
+            self.gizmos
+                .linestrip(points.map(|point| isometry * point.extend(0.)), color);
+        });
+    }
+}
+
+/// A builder returned by [`GizmoBuffer::text_2d`].
+pub struct Text2dBuilder<'a, 't, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    gizmos: &'a mut GizmoBuffer<Config, Clear>,
+    isometry: Isometry2d,
+    text: &'t str,
+    size: f32,
+    color: Color,
+    anchor: Vec2,
+}
+
+impl<Config, Clear> Text2dBuilder<'_, '_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Set the point of the text block that is placed at the origin of the isometry.
+    ///
+    /// `Vec2::ZERO` is the center of the block, `Vec2::new(-0.5, -0.5)` its bottom left corner
+    /// and `Vec2::new(0.5, 0.5)` its top right corner. Defaults to `Vec2::ZERO`.
+    pub fn anchor(mut self, anchor: Vec2) -> Self {
+        self.anchor = anchor;
+        self
+    }
+}
+
+impl<Config, Clear> Drop for Text2dBuilder<'_, '_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    fn drop(&mut self) {
+        if !self.gizmos.enabled {
+            return;
+        }
+
+        let isometry = self.isometry;
+        let color = self.color;
+        for_each_stroke(self.text, self.size, self.anchor, |points| {
+            self.gizmos
+                .linestrip_2d(points.map(|point| isometry * point), color);
+        });
+    }
+}
+
+/// Returns the isometry of a label at `position` facing the camera, and the size in world units of
+/// a logical pixel at that depth, or `None` if `position` is outside of the depth range of the
+/// camera.
+#[cfg(feature = "bevy_render")]
+fn screen_text_placement(
+    clip_from_view: Mat4,
+    camera_transform: &GlobalTransform,
+    viewport_size: Vec2,
+    position: Vec3,
+) -> Option<(Isometry3d, f32)> {
+    let clip_from_world = clip_from_view * camera_transform.compute_matrix().inverse();
+    let ndc = clip_from_world.project_point3(position);
+    if ndc.is_nan() || !(0.0..=1.0).contains(&ndc.z) || viewport_size.y <= 0. {
+        return None;
+    }
+
+    // NDC span 2 units over the height of the viewport.
+    let pixel_above = ndc + Vec3::Y * (2. / viewport_size.y);
+    let pixel_world_size = clip_from_world
+        .inverse()
+        .project_point3(pixel_above)
+        .distance(position);
+    pixel_world_size.is_finite().then(|| {
+        (
+            Isometry3d::new(position, camera_transform.rotation()),
+            pixel_world_size,
+        )
+    })
+}
+
+/// Returns the size of the laid out `text` block in grid units.
+fn text_block_size(text: &str) -> Vec2 {
+    let mut lines = 0;
+    let mut max_chars = 0;
+    for line in text.split('\n') {
+        lines += 1;
+        max_chars = max_chars.max(line.chars().count());
+    }
+
+    let width = if max_chars == 0 {
+        0.
+    } else {
+        (max_chars - 1) as f32 * GLYPH_ADVANCE + GLYPH_WIDTH
+    };
+    let height = (lines - 1) as f32 * LINE_ADVANCE + GLYPH_HEIGHT;
+    Vec2::new(width, height)
+}
+
+/// Lays out `text` and calls `f` with the points of every stroke, in the local space of the text.
+fn for_each_stroke(text: &str, size: f32, anchor: Vec2, mut f: impl FnMut(StrokePoints)) {
+    let scale = size / GLYPH_HEIGHT;
+    let block = text_block_size(text);
+    // Offset of the top left corner of the block relative to the anchor point.
+    let top_left = Vec2::new(-block.x, block.y) * 0.5 - anchor * block;
+
+    for (line_index, line) in text.split('\n').enumerate() {
+        let baseline = top_left.y - GLYPH_HEIGHT - line_index as f32 * LINE_ADVANCE;
+        for (char_index, c) in line.chars().enumerate() {
+            let origin = Vec2::new(top_left.x + char_index as f32 * GLYPH_ADVANCE, baseline);
+            let (glyph_scale, strokes) = if c.is_ascii_lowercase() {
+                (SMALL_CAPS_SCALE, glyph(c.to_ascii_uppercase()))
+            } else {
+                (1., glyph(c))
+            };
+
+            for stroke in strokes {
+                f(StrokePoints {
+                    stroke,
+                    index: 0,
+                    origin,
+                    glyph_scale,
+                    scale,
+                });
+            }
+        }
+    }
+}
+
+/// Iterator over the points of a single glyph stroke, in the local space of the text.
+struct StrokePoints {
+    stroke: Stroke,
+    index: usize,
+    origin: Vec2,
+    glyph_scale: f32,
+    scale: f32,
+}
+
+impl Iterator for StrokePoints {
+    type Item = Vec2;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &(x, y) = self.stroke.get(self.index)?;
+        self.index += 1;
+        let local = Vec2::new(x as f32, y as f32) * self.glyph_scale;
+        Some((self.origin + local) * self.scale)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.stroke.len() - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+/// Returns the strokes of the glyph used to draw `c`.
+///
+/// Glyphs are drawn on a grid that is [`GLYPH_WIDTH`] units wide and [`GLYPH_HEIGHT`] units tall,
+/// with the baseline at `y = 0`. Descenders may go below the baseline.
+fn glyph(c: char) -> &'static [Stroke] {
+    match c {
+        ' ' => &[],
+        '!' => &[&[(2, 6), (2, 2)], &[(2, 1), (2, 0)]],
+        '"' => &[&[(1, 6), (1, 4)], &[(3, 6), (3, 4)]],
+        '#' => &[
+            &[(1, 0), (1, 6)],
+            &[(3, 0), (3, 6)],
+            &[(0, 2), (4, 2)],
+            &[(0, 4), (4, 4)],
+        ],
+        '$' => &[
+            &[
+                (4, 5),
+                (3, 6),
+                (1, 6),
+                (0, 5),
+                (0, 4),
+                (1, 3),
+                (3, 3),
+                (4, 2),
+                (4, 1),
+                (3, 0),
+                (1, 0),
+                (0, 1),
+            ],
+            &[(2, 7), (2, -1)],
+        ],
+        '%' => &[
+            &[(0, 0), (4, 6)],
+            &[(0, 6), (1, 6), (1, 5), (0, 5), (0, 6)],
+            &[(3, 1), (4, 1), (4, 0), (3, 0), (3, 1)],
+        ],
+        '&' => &[&[
+            (4, 0),
+            (1, 4),
+            (1, 5),
+            (2, 6),
+            (3, 5),
+            (3, 4),
+            (0, 2),
+            (0, 1),
+            (1, 0),
+            (2, 0),
+            (4, 2),
+        ]],
+        '\'' => &[&[(2, 6), (2, 4)]],
+        '(' => &[&[(3, 6), (1, 4), (1, 2), (3, 0)]],
+        ')' => &[&[(1, 6), (3, 4), (3, 2), (1, 0)]],
+        '*' => &[&[(2, 1), (2, 5)], &[(0, 2), (4, 4)], &[(0, 4), (4, 2)]],
+        '+' => &[&[(2, 1), (2, 5)], &[(0, 3), (4, 3)]],
+        ',' => &[&[(2, 1), (2, 0), (1, -1)]],
+        '-' => &[&[(0, 3), (4, 3)]],
+        '.' => &[&[(2, 0), (2, 1)]],
+        '/' => &[&[(0, 0), (4, 6)]],
+        '0' => &[
+            &[
+                (1, 0),
+                (0, 1),
+                (0, 5),
+                (1, 6),
+                (3, 6),
+                (4, 5),
+                (4, 1),
+                (3, 0),
+                (1, 0),
+            ],
+            &[(0, 1), (4, 5)],
+        ],
+        '1' => &[&[(1, 5), (2, 6), (2, 0)], &[(1, 0), (3, 0)]],
+        '2' => &[&[(0, 5), (1, 6), (3, 6), (4, 5), (4, 4), (0, 0), (4, 0)]],
+        '3' => &[&[
+            (0, 6),
+            (4, 6),
+            (2, 3),
+            (3, 3),
+            (4, 2),
+            (4, 1),
+            (3, 0),
+            (1, 0),
+            (0, 1),
+        ]],
+        '4' => &[&[(3, 0), (3, 6), (0, 2), (4, 2)]],
+        '5' => &[&[
+            (4, 6),
+            (0, 6),
+            (0, 3),
+            (3, 3),
+            (4, 2),
+            (4, 1),
+            (3, 0),
+            (0, 0),
+        ]],
+        '6' => &[&[
+            (4, 6),
+            (1, 6),
+            (0, 5),
+            (0, 1),
+            (1, 0),
+            (3, 0),
+            (4, 1),
+            (4, 2),
+            (3, 3),
+            (0, 3),
+        ]],
+        '7' => &[&[(0, 6), (4, 6), (1, 0)]],
+        '8' => &[&[
+            (1, 3),
+            (0, 4),
+            (0, 5),
+            (1, 6),
+            (3, 6),
+            (4, 5),
+            (4, 4),
+            (3, 3),
+            (1, 3),
+            (0, 2),
+            (0, 1),
+            (1, 0),
+            (3, 0),
+            (4, 1),
+            (4, 2),
+            (3, 3),
+        ]],
+        '9' => &[&[
+            (0, 0),
+            (3, 0),
+            (4, 1),
+            (4, 5),
+            (3, 6),
+            (1, 6),
+            (0, 5),
+            (0, 4),
+            (1, 3),
+            (4, 3),
+        ]],
+        ':' => &[&[(2, 1), (2, 2)], &[(2, 4), (2, 5)]],
+        ';' => &[&[(2, 4), (2, 5)], &[(2, 1), (2, 0), (1, -1)]],
+        '<' => &[&[(4, 6), (0, 3), (4, 0)]],
+        '=' => &[&[(0, 2), (4, 2)], &[(0, 4), (4, 4)]],
+        '>' => &[&[(0, 6), (4, 3), (0, 0)]],
+        '?' => &[
+            &[(0, 5), (1, 6), (3, 6), (4, 5), (4, 4), (2, 3), (2, 2)],
+            &[(2, 1), (2, 0)],
+        ],
+        '@' => &[&[
+            (3, 2),
+            (3, 4),
+            (1, 4),
+            (1, 2),
+            (3, 2),
+            (4, 2),
+            (4, 5),
+            (3, 6),
+            (1, 6),
+            (0, 5),
+            (0, 1),
+            (1, 0),
+            (4, 0),
+        ]],
+        'A' => &[&[(0, 0), (0, 4), (2, 6), (4, 4), (4, 0)], &[(0, 2), (4, 2)]],
+        'B' => &[
+            &[(0, 0), (0, 6), (3, 6), (4, 5), (4, 4), (3, 3), (0, 3)],
+            &[(3, 3), (4, 2), (4, 1), (3, 0), (0, 0)],
+        ],
+        'C' => &[&[
+            (4, 5),
+            (3, 6),
+            (1, 6),
+            (0, 5),
+            (0, 1),
+            (1, 0),
+            (3, 0),
+            (4, 1),
+        ]],
+        'D' => &[&[(0, 0), (0, 6), (2, 6), (4, 4), (4, 2), (2, 0), (0, 0)]],
+        'E' => &[&[(4, 6), (0, 6), (0, 0), (4, 0)], &[(0, 3), (3, 3)]],
+        'F' => &[&[(4, 6), (0, 6), (0, 0)], &[(0, 3), (3, 3)]],
+        'G' => &[&[
+            (4, 5),
+            (3, 6),
+            (1, 6),
+            (0, 5),
+            (0, 1),
+            (1, 0),
+            (3, 0),
+            (4, 1),
+            (4, 3),
+            (2, 3),
+        ]],
+        'H' => &[&[(0, 0), (0, 6)], &[(4, 0), (4, 6)], &[(0, 3), (4, 3)]],
+        'I' => &[&[(1, 6), (3, 6)], &[(2, 6), (2, 0)], &[(1, 0), (3, 0)]],
+        'J' => &[&[(4, 6), (4, 1), (3, 0), (1, 0), (0, 1)]],
+        'K' => &[&[(0, 0), (0, 6)], &[(4, 6), (0, 2)], &[(1, 3), (4, 0)]],
+        'L' => &[&[(0, 6), (0, 0), (4, 0)]],
+        'M' => &[&[(0, 0), (0, 6), (2, 3), (4, 6), (4, 0)]],
+        'N' => &[&[(0, 0), (0, 6), (4, 0), (4, 6)]],
+        'O' => &[&[
+            (1, 0),
+            (0, 1),
+            (0, 5),
+            (1, 6),
+            (3, 6),
+            (4, 5),
+            (4, 1),
+            (3, 0),
+            (1, 0),
+        ]],
+        'P' => &[&[(0, 0), (0, 6), (3, 6), (4, 5), (4, 4), (3, 3), (0, 3)]],
+        'Q' => &[
+            &[
+                (1, 0),
+                (0, 1),
+                (0, 5),
+                (1, 6),
+                (3, 6),
+                (4, 5),
+                (4, 1),
+                (3, 0),
+                (1, 0),
+            ],
+            &[(2, 2), (4, 0)],
+        ],
+        'R' => &[
+            &[(0, 0), (0, 6), (3, 6), (4, 5), (4, 4), (3, 3), (0, 3)],
+            &[(2, 3), (4, 0)],
+        ],
+        'S' => &[&[
+            (4, 5),
+            (3, 6),
+            (1, 6),
+            (0, 5),
+            (0, 4),
+            (1, 3),
+            (3, 3),
+            (4, 2),
+            (4, 1),
+            (3, 0),
+            (1, 0),
+            (0, 1),
+        ]],
+        'T' => &[&[(0, 6), (4, 6)], &[(2, 6), (2, 0)]],
+        'U' => &[&[(0, 6), (0, 1), (1, 0), (3, 0), (4, 1), (4, 6)]],
+        'V' => &[&[(0, 6), (2, 0), (4, 6)]],
+        'W' => &[&[(0, 6), (1, 0), (2, 3), (3, 0), (4, 6)]],
+        'X' => &[&[(0, 0), (4, 6)], &[(0, 6), (4, 0)]],
+        'Y' => &[&[(0, 6), (2, 3), (4, 6)], &[(2, 3), (2, 0)]],
+        'Z' => &[&[(0, 6), (4, 6), (0, 0), (4, 0)]],
+        '[' => &[&[(3, 6), (1, 6), (1, 0), (3, 0)]],
+        '\\' => &[&[(0, 6), (4, 0)]],
+        ']' => &[&[(1, 6), (3, 6), (3, 0), (1, 0)]],
+        '^' => &[&[(0, 4), (2, 6), (4, 4)]],
+        '_' => &[&[(0, -1), (4, -1)]],
+        '`' => &[&[(1, 6), (2, 5)]],
+        '{' => &[&[(3, 6), (2, 5), (2, 4), (1, 3), (2, 2), (2, 1), (3, 0)]],
+        '|' => &[&[(2, -1), (2, 7)]],
+        '}' => &[&[(1, 6), (2, 5), (2, 4), (3, 3), (2, 2), (2, 1), (1, 0)]],
+        '~' => &[&[(0, 3), (1, 4), (3, 2), (4, 3)]],
+        _ => &[&[(0, 0), (4, 0), (4, 6), (0, 6), (0, 0)]],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DefaultGizmoConfigGroup;
+
+    fn strokes(text: &str, size: f32, anchor: Vec2) -> Vec<Vec<Vec2>> {
+        let mut strokes = Vec::new();
+        for_each_stroke(text, size, anchor, |points| strokes.push(points.collect()));
+        strokes
+    }
+
+    #[test]
+    fn block_size() {
+        assert_eq!(text_block_size(""), Vec2::new(0., GLYPH_HEIGHT));
+        assert_eq!(text_block_size("AB"), Vec2::new(10., GLYPH_HEIGHT));
+        assert_eq!(text_block_size("AB\nC"), Vec2::new(10., 16.));
+    }
+
+    #[test]
+    fn glyph_layout() {
+        // A size equal to the glyph height makes grid units world units.
+        let l = strokes("L", GLYPH_HEIGHT, Vec2::new(-0.5, -0.5));
+        assert_eq!(
+            l,
+            [[Vec2::new(0., 6.), Vec2::new(0., 0.), Vec2::new(4., 0.)]]
+        );
+
+        // The second line starts one line advance below the first, at the same x.
+        let lines = strokes("L\nL", GLYPH_HEIGHT, Vec2::new(-0.5, 0.5));
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0][1], Vec2::new(0., -GLYPH_HEIGHT));
+        assert_eq!(lines[1][1], Vec2::new(0., -GLYPH_HEIGHT - LINE_ADVANCE));
+
+        // Lowercase letters are small capitals, unsupported characters are boxes.
+        let small = strokes("l", GLYPH_HEIGHT, Vec2::new(-0.5, -0.5));
+        assert_eq!(small[0][0], Vec2::new(0., 6. * SMALL_CAPS_SCALE));
+        assert_eq!(glyph('\u{e9}'), glyph('\u{1f600}'));
+    }
+
+    #[test]
+    fn line_and_segment_counts() {
+        let mut gizmos = GizmoBuffer::<DefaultGizmoConfigGroup, ()>::default();
+        gizmos.text_2d(Isometry2d::IDENTITY, "IL\nI", 1., Color::WHITE);
+
+        // Each stroke is a line strip, ended by a NaN separator.
+        let strip_len = |c: char| glyph(c).iter().map(|s| s.len() + 1).sum::<usize>();
+        assert_eq!(
+            gizmos.strip_positions.len(),
+            2 * strip_len('I') + strip_len('L')
+        );
+        assert_eq!(
+            gizmos
+                .strip_positions
+                .iter()
+                .filter(|point| point.is_nan())
+                .count(),
+            2 * glyph('I').len() + glyph('L').len()
+        );
+        assert!(gizmos.list_positions.is_empty());
+
+        gizmos.strip_positions.clear();
+        gizmos.text_3d(Isometry3d::IDENTITY, " \n ", 1., Color::WHITE);
+        assert!(gizmos.strip_positions.is_empty());
+
+        gizmos.enabled = false;
+        gizmos.text_3d(Isometry3d::IDENTITY, "IL", 1., Color::WHITE);
+        assert!(gizmos.strip_positions.is_empty());
+    }
+
+    #[cfg(feature = "bevy_render")]
+    #[test]
+    fn screen_text_keeps_its_pixel_size() {
+        use bevy_math::ops;
+        use bevy_render::camera::{CameraProjection, PerspectiveProjection};
+        use bevy_transform::components::Transform;
+
+        let projection = PerspectiveProjection::default();
+        let clip_from_view = projection.get_clip_from_view();
+        let camera_transform = GlobalTransform::from(Transform::from_xyz(0., 0., 10.));
+        let viewport_size = Vec2::new(800., 600.);
+
+        for distance in [1., 10., 100.] {
+            let position = Vec3::new(0., 0., 10. - distance);
+            let (isometry, pixel_world_size) =
+                screen_text_placement(clip_from_view, &camera_transform, viewport_size, position)
+                    .unwrap();
+            assert_eq!(Vec3::from(isometry.translation), position);
+            let expected = 2. * distance * ops::tan(projection.fov / 2.) / viewport_size.y;
+            assert!((pixel_world_size - expected).abs() < expected * 1e-3);
+        }
+
+        // Behind the camera.
+        assert!(screen_text_placement(
+            clip_from_view,
+            &camera_transform,
+            viewport_size,
+            Vec3::new(0., 0., 20.)
+        )
+        .is_none());
+    }
+}
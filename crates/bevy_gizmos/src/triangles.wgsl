@@ -0,0 +1,67 @@
+// TODO use common view binding
+#import bevy_render::{view::View, maths::affine3_to_square}
+
+@group(0) @binding(0) var<uniform> view: View;
+
+
+struct LineGizmoUniform {
+    world_from_local: mat3x4<f32>,
+    _line_width: f32,
+    depth_bias: f32,
+    _joints_resolution: u32,
+    _gap_scale: f32,
+    _line_scale: f32,
+    occluded_alpha: f32,
+#ifdef SIXTEEN_BYTE_ALIGNMENT
+    // WebGL2 structs must be 16 byte aligned.
+    _padding: vec3<f32>,
+#endif
+}
+
+@group(1) @binding(0) var<uniform> line_gizmo: LineGizmoUniform;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+};
+
+const EPSILON: f32 = 4.88e-04;
+
+@vertex
+fn vertex(vertex: VertexInput) -> VertexOutput {
+    let world_from_local = affine3_to_square(line_gizmo.world_from_local);
+    let clip = view.clip_from_world * world_from_local * vec4(vertex.position, 1.);
+
+    // Same depth bias as the lines, so filled shapes and their outlines stay in sync.
+    var depth: f32;
+    if line_gizmo.depth_bias >= 0. {
+        depth = clip.z * (1. - line_gizmo.depth_bias);
+    } else {
+        depth = clip.z * exp2(-line_gizmo.depth_bias * log2(clip.w / clip.z - EPSILON));
+    }
+
+    var color = vertex.color;
+#ifdef OCCLUDED
+    color.a *= line_gizmo.occluded_alpha;
+#endif
+
+    return VertexOutput(vec4(clip.xy, depth, clip.w), color);
+}
+
+struct FragmentInput {
+    @location(0) color: vec4<f32>,
+};
+
+struct FragmentOutput {
+    @location(0) color: vec4<f32>,
+};
+
+@fragment
+fn fragment(in: FragmentInput) -> FragmentOutput {
+    return FragmentOutput(in.color);
+}
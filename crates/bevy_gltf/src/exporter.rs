@@ -0,0 +1,1522 @@
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+
+use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine};
+#[cfg(feature = "bevy_animation")]
+use bevy_animation::{
+    animation_curves::EvaluatorId,
+    graph::{AnimationGraph, AnimationGraphHandle, AnimationNodeIndex, AnimationNodeType},
+    AnimationClip, AnimationEntityMut, AnimationTarget, AnimationTargetId, VariableCurve,
+};
+use bevy_asset::{AssetId, Assets, Handle};
+use bevy_color::ColorToComponents;
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap},
+    name::Name,
+    world::{EntityRef, World},
+};
+use bevy_hierarchy::{Children, Parent};
+use bevy_image::{Image, ImageAddressMode, ImageFilterMode, ImageSampler, ImageSamplerDescriptor};
+use bevy_math::{Mat4, Quat, Vec3};
+use bevy_pbr::{
+    DirectionalLight, MeshMaterial3d, PointLight, SpotLight, StandardMaterial, UvChannel,
+};
+#[cfg(feature = "bevy_animation")]
+use bevy_reflect::{TypeInfo, Typed};
+use bevy_render::{
+    alpha::AlphaMode,
+    camera::{Camera, Projection},
+    mesh::{
+        skinning::{SkinnedMesh, SkinnedMeshInverseBindposes},
+        Indices, Mesh, Mesh3d, MeshVertexAttribute, VertexAttributeValues,
+    },
+    render_resource::PrimitiveTopology,
+};
+use bevy_scene::Scene;
+use bevy_transform::components::Transform;
+use bevy_utils::HashMap;
+use gltf::{
+    binary::{Glb, Header},
+    json::{
+        self,
+        accessor::{ComponentType, GenericComponentType, Type},
+        buffer::Target,
+        extensions::scene::khr_lights_punctual,
+        material::{AlphaCutoff, EmissiveFactor, PbrBaseColorFactor, StrengthFactor},
+        mesh::{Mode, Semantic},
+        texture::{MagFilter, MinFilter, WrappingMode},
+        validation::{Checked, USize64},
+        Index,
+    },
+};
+use thiserror::Error;
+use tracing::warn;
+
+use crate::GltfMaterialName;
+
+/// An error that occurs when exporting a glTF file.
+#[derive(Error, Debug)]
+pub enum GltfExportError {
+    /// An exported entity doesn't exist.
+    #[error("entity {0} does not exist")]
+    MissingEntity(Entity),
+    /// The glTF JSON could not be serialized.
+    #[error("failed to serialize the glTF JSON: {0}")]
+    Json(#[from] json::Error),
+    /// The binary glTF file could not be written.
+    #[error("failed to write the binary glTF file: {0}")]
+    Glb(#[from] gltf::Error),
+}
+
+/// The kind of file written by a [`GltfExporter`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GltfExportFormat {
+    /// A binary glTF file, usually with the `.glb` extension.
+    #[default]
+    Glb,
+    /// A JSON glTF file, usually with the `.gltf` extension, with its binary data embedded as a
+    /// base64 data URI.
+    Gltf,
+}
+
+/// Specifies optional settings for exporting glTF files.
+#[derive(Clone, Debug)]
+pub struct GltfExportSettings {
+    /// The kind of file to write.
+    pub format: GltfExportFormat,
+    /// If true, the cameras are exported.
+    pub export_cameras: bool,
+    /// If true, the lights are exported with the `KHR_lights_punctual` extension.
+    pub export_lights: bool,
+    /// The number of samples per second taken from animation curves, which are exported as
+    /// linearly interpolated keyframes. Must be positive.
+    pub animation_sample_rate: f32,
+}
+
+impl Default for GltfExportSettings {
+    fn default() -> Self {
+        Self {
+            format: GltfExportFormat::default(),
+            export_cameras: true,
+            export_lights: true,
+            animation_sample_rate: 30.0,
+        }
+    }
+}
+
+/// Writes entities or scenes to glTF files, the inverse of the [`GltfLoader`](crate::GltfLoader).
+///
+/// Each exported entity becomes a glTF node, with its [`Name`], [`Transform`] and [`Children`],
+/// and with the [`Mesh3d`], [`MeshMaterial3d<StandardMaterial>`], [`SkinnedMesh`], camera and
+/// light components it has. The mesh, material, image and inverse bindpose assets are read from
+/// the world given to [`GltfExporter::new`], usually the world of the app.
+///
+/// Material textures are embedded as PNG images, which needs the `png` feature. With the
+/// `bevy_animation` feature, the clips in the animation graph of each exported animation player
+/// become glTF animations of the exported entities they target: their translation, rotation
+/// and scale curves are sampled at [`GltfExportSettings::animation_sample_rate`].
+///
+/// Morph targets are not exported yet, and neither are vertex attributes unknown to glTF.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_gltf::{GltfExporter, GltfExportError};
+/// fn export_level(world: &World, level: Entity) -> Result<(), GltfExportError> {
+///     let bytes = GltfExporter::new(world).export_entities(world, [level])?;
+///     // Write `bytes` to a `.glb` file.
+///     Ok(())
+/// }
+/// ```
+pub struct GltfExporter<'a> {
+    assets: &'a World,
+    settings: GltfExportSettings,
+}
+
+impl<'a> GltfExporter<'a> {
+    /// Creates an exporter reading the exported assets from `assets`.
+    pub fn new(assets: &'a World) -> Self {
+        Self {
+            assets,
+            settings: GltfExportSettings::default(),
+        }
+    }
+
+    /// Sets the settings used to export files.
+    pub fn with_settings(mut self, settings: GltfExportSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Exports the `roots` entities of `world` and their descendants as a single glTF scene.
+    pub fn export_entities(
+        &self,
+        world: &World,
+        roots: impl IntoIterator<Item = Entity>,
+    ) -> Result<Vec<u8>, GltfExportError> {
+        let mut builder = Builder {
+            exporter: self,
+            meshes: self.assets.get_resource(),
+            materials: self.assets.get_resource(),
+            images: self.assets.get_resource(),
+            inverse_bindposes: self.assets.get_resource(),
+            #[cfg(feature = "bevy_animation")]
+            clips: self.assets.get_resource(),
+            #[cfg(feature = "bevy_animation")]
+            graphs: self.assets.get_resource(),
+            root: json::Root::default(),
+            buffer: Vec::new(),
+            exported_meshes: HashMap::default(),
+            exported_materials: HashMap::default(),
+            exported_textures: HashMap::default(),
+            nodes: EntityHashMap::default(),
+            skins: Vec::new(),
+            lights: Vec::new(),
+            #[cfg(feature = "bevy_animation")]
+            animation_targets: HashMap::default(),
+            #[cfg(feature = "bevy_animation")]
+            animation_graphs: Vec::new(),
+        };
+        let mut scene_nodes = Vec::new();
+        for root in roots {
+            let entity = world
+                .get_entity(root)
+                .map_err(GltfExportError::MissingEntity)?;
+            scene_nodes.push(builder.export_node(world, entity)?);
+        }
+        builder.export_skins();
+        #[cfg(feature = "bevy_animation")]
+        builder.export_animations();
+        builder.root.scenes.push(json::Scene {
+            extensions: None,
+            extras: Default::default(),
+            name: None,
+            nodes: scene_nodes,
+        });
+        builder.root.scene = Some(Index::new(0));
+        builder.finish()
+    }
+
+    /// Exports the entities of `scene` as a single glTF scene.
+    pub fn export_scene(&self, scene: &Scene) -> Result<Vec<u8>, GltfExportError> {
+        let roots: Vec<_> = scene
+            .world
+            .iter_entities()
+            .filter(|entity| !entity.contains::<Parent>())
+            .map(|entity| entity.id())
+            .collect();
+        self.export_entities(&scene.world, roots)
+    }
+}
+
+struct Builder<'a> {
+    exporter: &'a GltfExporter<'a>,
+    meshes: Option<&'a Assets<Mesh>>,
+    materials: Option<&'a Assets<StandardMaterial>>,
+    images: Option<&'a Assets<Image>>,
+    inverse_bindposes: Option<&'a Assets<SkinnedMeshInverseBindposes>>,
+    #[cfg(feature = "bevy_animation")]
+    clips: Option<&'a Assets<AnimationClip>>,
+    #[cfg(feature = "bevy_animation")]
+    graphs: Option<&'a Assets<AnimationGraph>>,
+    root: json::Root,
+    buffer: Vec<u8>,
+    exported_meshes: HashMap<(AssetId<Mesh>, Option<AssetId<StandardMaterial>>), Index<json::Mesh>>,
+    exported_materials: HashMap<AssetId<StandardMaterial>, Index<json::Material>>,
+    exported_textures: HashMap<AssetId<Image>, Option<Index<json::Texture>>>,
+    nodes: EntityHashMap<Index<json::Node>>,
+    skins: Vec<(Index<json::Node>, &'a SkinnedMesh)>,
+    lights: Vec<khr_lights_punctual::Light>,
+    /// The nodes of the exported animation targets, by player and target id.
+    #[cfg(feature = "bevy_animation")]
+    animation_targets: HashMap<(Entity, AnimationTargetId), Index<json::Node>>,
+    /// The exported animation players and their graphs.
+    #[cfg(feature = "bevy_animation")]
+    animation_graphs: Vec<(Entity, &'a AnimationGraphHandle)>,
+}
+
+impl<'a> Builder<'a> {
+    fn export_node(
+        &mut self,
+        world: &'a World,
+        entity: EntityRef<'a>,
+    ) -> Result<Index<json::Node>, GltfExportError> {
+        let mut node = json::Node::default();
+        if let Some(name) = entity.get::<Name>() {
+            node.name = Some(name.to_string());
+        }
+        if let Some(transform) = entity.get::<Transform>() {
+            node.translation =
+                (transform.translation != Vec3::ZERO).then(|| transform.translation.to_array());
+            node.rotation = (transform.rotation != Quat::IDENTITY)
+                .then(|| json::scene::UnitQuaternion(transform.rotation.to_array()));
+            node.scale = (transform.scale != Vec3::ONE).then(|| transform.scale.to_array());
+        }
+        if let Some(mesh) = entity.get::<Mesh3d>() {
+            let material = entity.get::<MeshMaterial3d<StandardMaterial>>();
+            let material_name = entity.get::<GltfMaterialName>();
+            node.mesh = self.export_mesh(mesh, material, material_name);
+        }
+        if self.exporter.settings.export_cameras && entity.contains::<Camera>() {
+            node.camera = entity
+                .get::<Projection>()
+                .and_then(|projection| self.export_camera(projection, entity.get::<Name>()));
+        }
+        if self.exporter.settings.export_lights {
+            if let Some(light) = self.export_light(entity) {
+                node.extensions = Some(json::extensions::scene::Node {
+                    khr_lights_punctual: Some(khr_lights_punctual::KhrLightsPunctual { light }),
+                    ..Default::default()
+                });
+            }
+        }
+
+        let index = self.root.push(node);
+        self.nodes.insert(entity.id(), index);
+        if let Some(skinned_mesh) = entity.get::<SkinnedMesh>() {
+            self.skins.push((index, skinned_mesh));
+        }
+        #[cfg(feature = "bevy_animation")]
+        {
+            if let Some(target) = entity.get::<AnimationTarget>() {
+                self.animation_targets
+                    .insert((target.player, target.id), index);
+            }
+            if let Some(graph) = entity.get::<AnimationGraphHandle>() {
+                self.animation_graphs.push((entity.id(), graph));
+            }
+        }
+
+        if let Some(children) = entity.get::<Children>() {
+            let mut child_nodes = Vec::with_capacity(children.len());
+            for &child in children {
+                let child = world
+                    .get_entity(child)
+                    .map_err(GltfExportError::MissingEntity)?;
+                child_nodes.push(self.export_node(world, child)?);
+            }
+            self.root.nodes[index.value()].children = Some(child_nodes);
+        }
+        Ok(index)
+    }
+
+    fn export_mesh(
+        &mut self,
+        mesh: &Mesh3d,
+        material: Option<&MeshMaterial3d<StandardMaterial>>,
+        material_name: Option<&GltfMaterialName>,
+    ) -> Option<Index<json::Mesh>> {
+        let material_id = material.map(|material| material.id());
+        if let Some(index) = self.exported_meshes.get(&(mesh.id(), material_id)) {
+            return Some(*index);
+        }
+        let Some(mesh_asset) = self.meshes.and_then(|meshes| meshes.get(mesh)) else {
+            warn!("Skipping the mesh {:?}, which is not loaded", mesh.id());
+            return None;
+        };
+
+        let mut attributes = BTreeMap::new();
+        for (attribute, values) in mesh_asset.attributes() {
+            let Some(semantic) = semantic(attribute) else {
+                warn!(
+                    "Skipping the vertex attribute {}, which has no glTF equivalent",
+                    attribute.name
+                );
+                continue;
+            };
+            let Some(accessor) = self.export_attribute(values, &semantic) else {
+                warn!(
+                    "Skipping the vertex attribute {}, whose format has no glTF equivalent",
+                    attribute.name
+                );
+                continue;
+            };
+            attributes.insert(Checked::Valid(semantic), accessor);
+        }
+        let indices = mesh_asset.indices().map(|indices| {
+            let (bytes, component_type) = match indices {
+                Indices::U16(indices) => (
+                    indices.iter().flat_map(|i| i.to_le_bytes()).collect(),
+                    ComponentType::U16,
+                ),
+                Indices::U32(indices) => (
+                    indices
+                        .iter()
+                        .flat_map(|i| i.to_le_bytes())
+                        .collect::<Vec<_>>(),
+                    ComponentType::U32,
+                ),
+            };
+            let view = self.push_view(&bytes, Some(Target::ElementArrayBuffer));
+            self.push_accessor(
+                view,
+                indices.len(),
+                component_type,
+                Type::Scalar,
+                false,
+                None,
+            )
+        });
+        let material = material
+            .and_then(|material| self.export_material(material, material_name.map(|n| &n.0)));
+
+        let primitive = json::mesh::Primitive {
+            attributes,
+            extensions: None,
+            extras: Default::default(),
+            indices,
+            material,
+            mode: Checked::Valid(mode(mesh_asset.primitive_topology())),
+            targets: None,
+        };
+        let index = self.root.push(json::Mesh {
+            extensions: None,
+            extras: Default::default(),
+            name: None,
+            primitives: vec![primitive],
+            weights: None,
+        });
+        self.exported_meshes.insert((mesh.id(), material_id), index);
+        Some(index)
+    }
+
+    fn export_attribute(
+        &mut self,
+        values: &VertexAttributeValues,
+        semantic: &Semantic,
+    ) -> Option<Index<json::Accessor>> {
+        let (bytes, component_type, type_, normalized) = attribute_data(values)?;
+        let bounds = match (semantic, values) {
+            // glTF requires the bounds of the positions.
+            (Semantic::Positions, VertexAttributeValues::Float32x3(positions)) => {
+                let mut min = [f32::MAX; 3];
+                let mut max = [f32::MIN; 3];
+                for position in positions {
+                    for i in 0..3 {
+                        min[i] = min[i].min(position[i]);
+                        max[i] = max[i].max(position[i]);
+                    }
+                }
+                (!positions.is_empty()).then(|| (min.to_vec(), max.to_vec()))
+            }
+            _ => None,
+        };
+        let view = self.push_view(&bytes, Some(Target::ArrayBuffer));
+        Some(self.push_accessor(
+            view,
+            values.len(),
+            component_type,
+            type_,
+            normalized,
+            bounds,
+        ))
+    }
+
+    fn export_material(
+        &mut self,
+        material: &MeshMaterial3d<StandardMaterial>,
+        name: Option<&String>,
+    ) -> Option<Index<json::Material>> {
+        if let Some(index) = self.exported_materials.get(&material.id()) {
+            return Some(*index);
+        }
+        let Some(material_asset) = self.materials.and_then(|materials| materials.get(material))
+        else {
+            warn!(
+                "Skipping the material {:?}, which is not loaded",
+                material.id()
+            );
+            return None;
+        };
+
+        let mut json_material = json::Material {
+            name: name.cloned(),
+            double_sided: material_asset.double_sided,
+            ..Default::default()
+        };
+        json_material.pbr_metallic_roughness.base_color_factor =
+            PbrBaseColorFactor(material_asset.base_color.to_linear().to_f32_array());
+        json_material.pbr_metallic_roughness.metallic_factor =
+            StrengthFactor(material_asset.metallic);
+        json_material.pbr_metallic_roughness.roughness_factor =
+            StrengthFactor(material_asset.perceptual_roughness);
+        let (alpha_mode, alpha_cutoff) = match material_asset.alpha_mode {
+            AlphaMode::Opaque => (json::material::AlphaMode::Opaque, None),
+            AlphaMode::Mask(cutoff) => (json::material::AlphaMode::Mask, Some(cutoff)),
+            AlphaMode::AlphaToCoverage => (json::material::AlphaMode::Mask, None),
+            AlphaMode::Blend | AlphaMode::Premultiplied | AlphaMode::Add | AlphaMode::Multiply => {
+                (json::material::AlphaMode::Blend, None)
+            }
+        };
+        json_material.alpha_mode = Checked::Valid(alpha_mode);
+        json_material.alpha_cutoff = alpha_cutoff.map(AlphaCutoff);
+
+        let pbr = &mut json_material.pbr_metallic_roughness;
+        pbr.base_color_texture = self.export_texture_info(
+            &material_asset.base_color_texture,
+            &material_asset.base_color_channel,
+        );
+        pbr.metallic_roughness_texture = self.export_texture_info(
+            &material_asset.metallic_roughness_texture,
+            &material_asset.metallic_roughness_channel,
+        );
+        json_material.emissive_texture = self.export_texture_info(
+            &material_asset.emissive_texture,
+            &material_asset.emissive_channel,
+        );
+        json_material.normal_texture = self
+            .export_texture_info(
+                &material_asset.normal_map_texture,
+                &material_asset.normal_map_channel,
+            )
+            .map(|info| json::material::NormalTexture {
+                index: info.index,
+                scale: 1.0,
+                tex_coord: info.tex_coord,
+                extensions: None,
+                extras: Default::default(),
+            });
+        json_material.occlusion_texture = self
+            .export_texture_info(
+                &material_asset.occlusion_texture,
+                &material_asset.occlusion_channel,
+            )
+            .map(|info| json::material::OcclusionTexture {
+                index: info.index,
+                strength: StrengthFactor(1.0),
+                tex_coord: info.tex_coord,
+                extensions: None,
+                extras: Default::default(),
+            });
+
+        let mut extensions = json::extensions::material::Material::default();
+        // Emissive factors are limited to 1, brighter colors need the emissive strength.
+        let emissive = material_asset.emissive;
+        let strength = emissive.red.max(emissive.green).max(emissive.blue);
+        if strength > 1.0 {
+            json_material.emissive_factor = EmissiveFactor([
+                emissive.red / strength,
+                emissive.green / strength,
+                emissive.blue / strength,
+            ]);
+            extensions.emissive_strength = Some(json::extensions::material::EmissiveStrength {
+                emissive_strength: json::extensions::material::EmissiveStrengthFactor(strength),
+            });
+            self.use_extension("KHR_materials_emissive_strength");
+        } else {
+            json_material.emissive_factor =
+                EmissiveFactor([emissive.red, emissive.green, emissive.blue]);
+        }
+        if material_asset.unlit {
+            extensions.unlit = Some(json::extensions::material::Unlit {});
+            self.use_extension("KHR_materials_unlit");
+        }
+        if material_asset.specular_transmission > 0.0 {
+            extensions.transmission = Some(json::extensions::material::Transmission {
+                transmission_factor: json::extensions::material::TransmissionFactor(
+                    material_asset.specular_transmission,
+                ),
+                ..Default::default()
+            });
+            self.use_extension("KHR_materials_transmission");
+        }
+        if material_asset.ior != 1.5 {
+            extensions.ior = Some(json::extensions::material::Ior {
+                ior: json::extensions::material::IndexOfRefraction(material_asset.ior),
+                ..Default::default()
+            });
+            self.use_extension("KHR_materials_ior");
+        }
+        if extensions.emissive_strength.is_some()
+            || extensions.unlit.is_some()
+            || extensions.transmission.is_some()
+            || extensions.ior.is_some()
+        {
+            json_material.extensions = Some(extensions);
+        }
+
+        let index = self.root.push(json_material);
+        self.exported_materials.insert(material.id(), index);
+        Some(index)
+    }
+
+    fn export_texture_info(
+        &mut self,
+        image: &Option<Handle<Image>>,
+        channel: &UvChannel,
+    ) -> Option<json::texture::Info> {
+        let index = self.export_texture(image.as_ref()?)?;
+        Some(json::texture::Info {
+            index,
+            tex_coord: match channel {
+                UvChannel::Uv0 => 0,
+                UvChannel::Uv1 => 1,
+            },
+            extensions: None,
+            extras: Default::default(),
+        })
+    }
+
+    fn export_texture(&mut self, image: &Handle<Image>) -> Option<Index<json::Texture>> {
+        if let Some(index) = self.exported_textures.get(&image.id()) {
+            return *index;
+        }
+        let index = self.export_image(image);
+        // Images which can't be exported are only warned about once.
+        self.exported_textures.insert(image.id(), index);
+        index
+    }
+
+    fn export_image(&mut self, image: &Handle<Image>) -> Option<Index<json::Texture>> {
+        let Some(image_asset) = self.images.and_then(|images| images.get(image)) else {
+            warn!("Skipping the texture {:?}, which is not loaded", image.id());
+            return None;
+        };
+        let bytes = png(image_asset)?;
+        let view = self.push_view(&bytes, None);
+        let source = self.root.push(json::Image {
+            buffer_view: Some(view),
+            mime_type: Some(json::image::MimeType("image/png".to_string())),
+            name: None,
+            uri: None,
+            extensions: None,
+            extras: Default::default(),
+        });
+        let sampler = match &image_asset.sampler {
+            ImageSampler::Default => None,
+            ImageSampler::Descriptor(descriptor) => Some(self.root.push(sampler(descriptor))),
+        };
+        Some(self.root.push(json::Texture {
+            name: None,
+            sampler,
+            source,
+            extensions: None,
+            extras: Default::default(),
+        }))
+    }
+
+    fn export_camera(
+        &mut self,
+        projection: &Projection,
+        name: Option<&Name>,
+    ) -> Option<Index<json::Camera>> {
+        let (type_, perspective, orthographic) = match projection {
+            Projection::Perspective(perspective) => (
+                json::camera::Type::Perspective,
+                Some(json::camera::Perspective {
+                    aspect_ratio: Some(perspective.aspect_ratio),
+                    yfov: perspective.fov,
+                    zfar: Some(perspective.far),
+                    znear: perspective.near,
+                    extensions: None,
+                    extras: Default::default(),
+                }),
+                None,
+            ),
+            Projection::Orthographic(orthographic) => {
+                let half_size = orthographic.area.half_size();
+                let (xmag, ymag) = if half_size.x > 0.0 && half_size.y > 0.0 {
+                    (half_size.x, half_size.y)
+                } else {
+                    // The area hasn't been computed yet.
+                    (orthographic.scale, orthographic.scale)
+                };
+                (
+                    json::camera::Type::Orthographic,
+                    None,
+                    Some(json::camera::Orthographic {
+                        xmag,
+                        ymag,
+                        zfar: orthographic.far,
+                        znear: orthographic.near,
+                        extensions: None,
+                        extras: Default::default(),
+                    }),
+                )
+            }
+            Projection::Custom(_) => {
+                warn!("Skipping a camera with a custom projection, which has no glTF equivalent");
+                return None;
+            }
+        };
+        Some(self.root.push(json::Camera {
+            name: name.map(ToString::to_string),
+            orthographic,
+            perspective,
+            type_: Checked::Valid(type_),
+            extensions: None,
+            extras: Default::default(),
+        }))
+    }
+
+    fn export_light(&mut self, entity: EntityRef) -> Option<Index<khr_lights_punctual::Light>> {
+        // The inverse of the conversions done by the loader.
+        let light = if let Some(light) = entity.get::<DirectionalLight>() {
+            khr_lights_punctual::Light {
+                color: light.color.to_srgba().to_f32_array_no_alpha(),
+                intensity: light.illuminance,
+                range: None,
+                spot: None,
+                type_: Checked::Valid(khr_lights_punctual::Type::Directional),
+                name: None,
+                extensions: None,
+                extras: Default::default(),
+            }
+        } else if let Some(light) = entity.get::<PointLight>() {
+            khr_lights_punctual::Light {
+                color: light.color.to_srgba().to_f32_array_no_alpha(),
+                intensity: light.intensity / (4.0 * core::f32::consts::PI),
+                range: Some(light.range),
+                spot: None,
+                type_: Checked::Valid(khr_lights_punctual::Type::Point),
+                name: None,
+                extensions: None,
+                extras: Default::default(),
+            }
+        } else if let Some(light) = entity.get::<SpotLight>() {
+            khr_lights_punctual::Light {
+                color: light.color.to_srgba().to_f32_array_no_alpha(),
+                intensity: light.intensity / (4.0 * core::f32::consts::PI),
+                range: Some(light.range),
+                spot: Some(khr_lights_punctual::Spot {
+                    inner_cone_angle: light.inner_angle,
+                    outer_cone_angle: light.outer_angle,
+                }),
+                type_: Checked::Valid(khr_lights_punctual::Type::Spot),
+                name: None,
+                extensions: None,
+                extras: Default::default(),
+            }
+        } else {
+            return None;
+        };
+        let index = Index::new(self.lights.len() as u32);
+        self.lights.push(light);
+        Some(index)
+    }
+
+    fn export_skins(&mut self) {
+        for (node, skinned_mesh) in core::mem::take(&mut self.skins) {
+            let joints: Option<Vec<_>> = skinned_mesh
+                .joints
+                .iter()
+                .map(|joint| self.nodes.get(joint).copied())
+                .collect();
+            let Some(joints) = joints else {
+                warn!("Skipping a skin whose joints are not all exported");
+                continue;
+            };
+            let inverse_bind_matrices = self
+                .inverse_bindposes
+                .and_then(|bindposes| bindposes.get(&skinned_mesh.inverse_bindposes))
+                .filter(|bindposes| bindposes.len() == joints.len())
+                .map(|bindposes| {
+                    let bytes: Vec<u8> = bindposes
+                        .iter()
+                        .flat_map(Mat4::to_cols_array)
+                        .flat_map(f32::to_le_bytes)
+                        .collect();
+                    let view = self.push_view(&bytes, None);
+                    self.push_accessor(
+                        view,
+                        bindposes.len(),
+                        ComponentType::F32,
+                        Type::Mat4,
+                        false,
+                        None,
+                    )
+                });
+            let skin = self.root.push(json::Skin {
+                extensions: None,
+                extras: Default::default(),
+                inverse_bind_matrices,
+                joints,
+                name: None,
+                skeleton: None,
+            });
+            self.root.nodes[node.value()].skin = Some(skin);
+        }
+    }
+
+    #[cfg(feature = "bevy_animation")]
+    fn export_animations(&mut self) {
+        // Curves are sampled by applying them to the transform of a scratch entity.
+        let mut scratch = World::new();
+        let entity = scratch.spawn(Transform::default()).id();
+        for (player, graph) in core::mem::take(&mut self.animation_graphs) {
+            let Some(graph) = self.graphs.and_then(|graphs| graphs.get(&graph.0)) else {
+                warn!("Skipping the animations of {player}, whose graph is not loaded");
+                continue;
+            };
+            for node in graph.nodes() {
+                let Some(AnimationNodeType::Clip(clip)) =
+                    graph.get(node).map(|node| &node.node_type)
+                else {
+                    continue;
+                };
+                let Some(clip_asset) = self.clips.and_then(|clips| clips.get(clip)) else {
+                    warn!(
+                        "Skipping the animation {:?}, which is not loaded",
+                        clip.id()
+                    );
+                    continue;
+                };
+                self.export_animation(player, clip_asset, &mut scratch, entity);
+            }
+        }
+    }
+
+    #[cfg(feature = "bevy_animation")]
+    fn export_animation(
+        &mut self,
+        player: Entity,
+        clip: &AnimationClip,
+        scratch: &mut World,
+        entity: Entity,
+    ) {
+        let mut targets: Vec<_> = clip
+            .curves()
+            .iter()
+            .filter_map(|(id, curves)| Some((*self.animation_targets.get(&(player, *id))?, curves)))
+            .collect();
+        targets.sort_by_key(|(node, _)| node.value());
+
+        let mut animation = json::Animation {
+            extensions: None,
+            extras: Default::default(),
+            channels: Vec::new(),
+            name: None,
+            samplers: Vec::new(),
+        };
+        for (node, curves) in targets {
+            for curve in curves {
+                let Some((path, input, output)) =
+                    self.export_curve(curve, clip.duration(), scratch, entity)
+                else {
+                    continue;
+                };
+                animation.channels.push(json::animation::Channel {
+                    sampler: Index::new(animation.samplers.len() as u32),
+                    target: json::animation::Target {
+                        node,
+                        path: Checked::Valid(path),
+                        extensions: None,
+                        extras: Default::default(),
+                    },
+                    extensions: None,
+                    extras: Default::default(),
+                });
+                animation.samplers.push(json::animation::Sampler {
+                    input,
+                    interpolation: Checked::Valid(json::animation::Interpolation::Linear),
+                    output,
+                    extensions: None,
+                    extras: Default::default(),
+                });
+            }
+        }
+        if !animation.channels.is_empty() {
+            self.root.push(animation);
+        }
+    }
+
+    /// Samples a curve animating the translation, rotation or scale of a [`Transform`], returning
+    /// the animated property and the accessors of the keyframe times and values.
+    #[cfg(feature = "bevy_animation")]
+    fn export_curve(
+        &mut self,
+        curve: &VariableCurve,
+        duration: f32,
+        scratch: &mut World,
+        entity: Entity,
+    ) -> Option<(
+        json::animation::Property,
+        Index<json::Accessor>,
+        Index<json::Accessor>,
+    )> {
+        use json::animation::Property;
+
+        let field = match curve.0.evaluator_id() {
+            EvaluatorId::ComponentField(field)
+                if field.0 == core::any::TypeId::of::<Transform>() =>
+            {
+                match Transform::type_info() {
+                    TypeInfo::Struct(info) => {
+                        info.field_at(field.1).map(bevy_reflect::NamedField::name)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        let (property, type_) = match field {
+            Some("translation") => (Property::Translation, Type::Vec3),
+            Some("rotation") => (Property::Rotation, Type::Vec4),
+            Some("scale") => (Property::Scale, Type::Vec3),
+            _ => {
+                warn!("Skipping an animation curve which doesn't animate a transform");
+                return None;
+            }
+        };
+
+        let domain = curve.0.domain();
+        let (start, end) = if domain.is_bounded() {
+            (domain.start(), domain.end())
+        } else {
+            (0.0, duration)
+        };
+        let sample_rate = self.exporter.settings.animation_sample_rate;
+        let sample_count = ((end - start) * sample_rate).ceil() as usize + 1;
+        let times: Vec<f32> = (0..sample_count)
+            .map(|i| (start + i as f32 / sample_rate).min(end))
+            .collect();
+
+        let mut evaluator = curve.0.create_evaluator();
+        let mut values = Vec::with_capacity(times.len() * 4);
+        for &time in &times {
+            let sampled = curve
+                .0
+                .apply(&mut *evaluator, time, 1.0, AnimationNodeIndex::new(0))
+                .and_then(|()| {
+                    let mut query = scratch.query::<AnimationEntityMut>();
+                    let target = query.get_mut(scratch, entity).expect("scratch entity");
+                    evaluator.commit(target)
+                });
+            if let Err(error) = sampled {
+                warn!("Skipping an animation curve which failed to be sampled: {error:?}");
+                return None;
+            }
+            let transform = scratch.get::<Transform>(entity)?;
+            match property {
+                Property::Translation => values.extend(transform.translation.to_array()),
+                Property::Rotation => values.extend(transform.rotation.to_array()),
+                _ => values.extend(transform.scale.to_array()),
+            }
+        }
+
+        let bytes: Vec<u8> = times.iter().flat_map(|time| time.to_le_bytes()).collect();
+        let view = self.push_view(&bytes, None);
+        // glTF requires the bounds of the keyframe times.
+        let input = self.push_accessor(
+            view,
+            times.len(),
+            ComponentType::F32,
+            Type::Scalar,
+            false,
+            Some((vec![start], vec![end])),
+        );
+        let bytes: Vec<u8> = values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
+        let view = self.push_view(&bytes, None);
+        let output = self.push_accessor(view, times.len(), ComponentType::F32, type_, false, None);
+        Some((property, input, output))
+    }
+
+    fn use_extension(&mut self, extension: &str) {
+        if !self
+            .root
+            .extensions_used
+            .iter()
+            .any(|used| used == extension)
+        {
+            self.root.extensions_used.push(extension.to_string());
+        }
+    }
+
+    fn align_buffer(&mut self) {
+        self.buffer.resize(self.buffer.len().next_multiple_of(4), 0);
+    }
+
+    fn push_view(&mut self, bytes: &[u8], target: Option<Target>) -> Index<json::buffer::View> {
+        // Accessors must be aligned to the size of their components.
+        self.align_buffer();
+        let byte_offset = self.buffer.len();
+        self.buffer.extend_from_slice(bytes);
+        self.root.push(json::buffer::View {
+            buffer: Index::new(0),
+            byte_length: USize64::from(bytes.len()),
+            byte_offset: Some(USize64::from(byte_offset)),
+            byte_stride: None,
+            name: None,
+            target: target.map(Checked::Valid),
+            extensions: None,
+            extras: Default::default(),
+        })
+    }
+
+    fn push_accessor(
+        &mut self,
+        view: Index<json::buffer::View>,
+        count: usize,
+        component_type: ComponentType,
+        type_: Type,
+        normalized: bool,
+        bounds: Option<(Vec<f32>, Vec<f32>)>,
+    ) -> Index<json::Accessor> {
+        let (min, max) = bounds
+            .map(|(min, max)| (Some(json::Value::from(min)), Some(json::Value::from(max))))
+            .unwrap_or_default();
+        self.root.push(json::Accessor {
+            buffer_view: Some(view),
+            byte_offset: None,
+            count: USize64::from(count),
+            component_type: Checked::Valid(GenericComponentType(component_type)),
+            extensions: None,
+            extras: Default::default(),
+            type_: Checked::Valid(type_),
+            min,
+            max,
+            name: None,
+            normalized,
+            sparse: None,
+        })
+    }
+
+    fn finish(mut self) -> Result<Vec<u8>, GltfExportError> {
+        if !self.lights.is_empty() {
+            self.root.extensions = Some(json::extensions::root::Root {
+                khr_lights_punctual: Some(json::extensions::root::KhrLightsPunctual {
+                    lights: core::mem::take(&mut self.lights),
+                }),
+                ..Default::default()
+            });
+            self.use_extension("KHR_lights_punctual");
+        }
+        self.root.asset.generator = Some("Bevy".to_string());
+
+        self.align_buffer();
+        let has_buffer = !self.buffer.is_empty();
+        match self.exporter.settings.format {
+            GltfExportFormat::Glb => {
+                if has_buffer {
+                    self.root.push(json::Buffer {
+                        byte_length: USize64::from(self.buffer.len()),
+                        name: None,
+                        uri: None,
+                        extensions: None,
+                        extras: Default::default(),
+                    });
+                }
+                let json = self.root.to_vec()?;
+                let glb = Glb {
+                    header: Header {
+                        magic: *b"glTF",
+                        version: 2,
+                        // Computed when writing the file.
+                        length: 0,
+                    },
+                    json: Cow::Owned(json),
+                    bin: has_buffer.then_some(Cow::Owned(self.buffer)),
+                };
+                Ok(glb.to_vec()?)
+            }
+            GltfExportFormat::Gltf => {
+                if has_buffer {
+                    let uri = format!(
+                        "data:application/octet-stream;base64,{}",
+                        BASE64_STANDARD.encode(&self.buffer)
+                    );
+                    self.root.push(json::Buffer {
+                        byte_length: USize64::from(self.buffer.len()),
+                        name: None,
+                        uri: Some(uri),
+                        extensions: None,
+                        extras: Default::default(),
+                    });
+                }
+                Ok(self.root.to_vec_pretty()?)
+            }
+        }
+    }
+}
+
+/// Returns the glTF semantic of a vertex attribute, if it has one.
+fn semantic(attribute: &MeshVertexAttribute) -> Option<Semantic> {
+    let id = attribute.id;
+    Some(if id == Mesh::ATTRIBUTE_POSITION.id {
+        Semantic::Positions
+    } else if id == Mesh::ATTRIBUTE_NORMAL.id {
+        Semantic::Normals
+    } else if id == Mesh::ATTRIBUTE_TANGENT.id {
+        Semantic::Tangents
+    } else if id == Mesh::ATTRIBUTE_UV_0.id {
+        Semantic::TexCoords(0)
+    } else if id == Mesh::ATTRIBUTE_UV_1.id {
+        Semantic::TexCoords(1)
+    } else if id == Mesh::ATTRIBUTE_COLOR.id {
+        Semantic::Colors(0)
+    } else if id == Mesh::ATTRIBUTE_JOINT_INDEX.id {
+        Semantic::Joints(0)
+    } else if id == Mesh::ATTRIBUTE_JOINT_WEIGHT.id {
+        Semantic::Weights(0)
+    } else {
+        return None;
+    })
+}
+
+/// Returns the bytes and the glTF format of vertex attribute values, if glTF supports it.
+fn attribute_data(values: &VertexAttributeValues) -> Option<(Vec<u8>, ComponentType, Type, bool)> {
+    use VertexAttributeValues as Values;
+
+    fn bytes<T: Copy, const N: usize>(
+        values: &[[T; N]],
+        to_bytes: impl Fn(T) -> Vec<u8>,
+    ) -> Vec<u8> {
+        values.iter().flatten().flat_map(|&v| to_bytes(v)).collect()
+    }
+    let f32_bytes = |v: f32| v.to_le_bytes().to_vec();
+    let u16_bytes = |v: u16| v.to_le_bytes().to_vec();
+    let u8_bytes = |v: u8| vec![v];
+
+    Some(match values {
+        Values::Float32(values) => (
+            values.iter().flat_map(|v| v.to_le_bytes()).collect(),
+            ComponentType::F32,
+            Type::Scalar,
+            false,
+        ),
+        Values::Float32x2(values) => (
+            bytes(values, f32_bytes),
+            ComponentType::F32,
+            Type::Vec2,
+            false,
+        ),
+        Values::Float32x3(values) => (
+            bytes(values, f32_bytes),
+            ComponentType::F32,
+            Type::Vec3,
+            false,
+        ),
+        Values::Float32x4(values) => (
+            bytes(values, f32_bytes),
+            ComponentType::F32,
+            Type::Vec4,
+            false,
+        ),
+        Values::Uint16x2(values) => (
+            bytes(values, u16_bytes),
+            ComponentType::U16,
+            Type::Vec2,
+            false,
+        ),
+        Values::Uint16x4(values) => (
+            bytes(values, u16_bytes),
+            ComponentType::U16,
+            Type::Vec4,
+            false,
+        ),
+        Values::Unorm16x2(values) => (
+            bytes(values, u16_bytes),
+            ComponentType::U16,
+            Type::Vec2,
+            true,
+        ),
+        Values::Unorm16x4(values) => (
+            bytes(values, u16_bytes),
+            ComponentType::U16,
+            Type::Vec4,
+            true,
+        ),
+        Values::Uint8x2(values) => (
+            bytes(values, u8_bytes),
+            ComponentType::U8,
+            Type::Vec2,
+            false,
+        ),
+        Values::Uint8x4(values) => (
+            bytes(values, u8_bytes),
+            ComponentType::U8,
+            Type::Vec4,
+            false,
+        ),
+        Values::Unorm8x2(values) => (bytes(values, u8_bytes), ComponentType::U8, Type::Vec2, true),
+        Values::Unorm8x4(values) => (bytes(values, u8_bytes), ComponentType::U8, Type::Vec4, true),
+        _ => return None,
+    })
+}
+
+/// Encodes an image as PNG, the lossless image format of glTF.
+#[cfg(feature = "png")]
+fn png(image: &Image) -> Option<Vec<u8>> {
+    image
+        .to_buffer(bevy_image::ImageFormat::Png)
+        .map_err(|error| warn!("Skipping a texture which can't be encoded as PNG: {error}"))
+        .ok()
+}
+
+#[cfg(not(feature = "png"))]
+fn png(_image: &Image) -> Option<Vec<u8>> {
+    warn!("Skipping a texture, exporting textures requires the `png` feature");
+    None
+}
+
+/// The inverse of the conversion done by the loader.
+fn sampler(descriptor: &ImageSamplerDescriptor) -> json::texture::Sampler {
+    let wrapping_mode = |address_mode| {
+        Checked::Valid(match address_mode {
+            ImageAddressMode::Repeat => WrappingMode::Repeat,
+            ImageAddressMode::MirrorRepeat => WrappingMode::MirroredRepeat,
+            ImageAddressMode::ClampToEdge | ImageAddressMode::ClampToBorder => {
+                WrappingMode::ClampToEdge
+            }
+        })
+    };
+    let mag_filter = match descriptor.mag_filter {
+        ImageFilterMode::Nearest => MagFilter::Nearest,
+        ImageFilterMode::Linear => MagFilter::Linear,
+    };
+    let min_filter = match (descriptor.min_filter, descriptor.mipmap_filter) {
+        (ImageFilterMode::Nearest, ImageFilterMode::Nearest) => MinFilter::NearestMipmapNearest,
+        (ImageFilterMode::Linear, ImageFilterMode::Nearest) => MinFilter::LinearMipmapNearest,
+        (ImageFilterMode::Nearest, ImageFilterMode::Linear) => MinFilter::NearestMipmapLinear,
+        (ImageFilterMode::Linear, ImageFilterMode::Linear) => MinFilter::LinearMipmapLinear,
+    };
+    json::texture::Sampler {
+        mag_filter: Some(Checked::Valid(mag_filter)),
+        min_filter: Some(Checked::Valid(min_filter)),
+        name: None,
+        wrap_s: wrapping_mode(descriptor.address_mode_u),
+        wrap_t: wrapping_mode(descriptor.address_mode_v),
+        extensions: None,
+        extras: Default::default(),
+    }
+}
+
+fn mode(topology: PrimitiveTopology) -> Mode {
+    match topology {
+        PrimitiveTopology::PointList => Mode::Points,
+        PrimitiveTopology::LineList => Mode::Lines,
+        PrimitiveTopology::LineStrip => Mode::LineStrip,
+        PrimitiveTopology::TriangleList => Mode::Triangles,
+        PrimitiveTopology::TriangleStrip => Mode::TriangleStrip,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Gltf;
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        AssetApp, AssetPlugin, AssetServer, LoadState,
+    };
+    use bevy_color::{Color, LinearRgba};
+    use bevy_hierarchy::BuildChildren;
+    use bevy_math::primitives::Cuboid;
+    use bevy_render::mesh::MeshPlugin;
+    use bevy_scene::ScenePlugin;
+    use std::path::Path;
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world
+    }
+
+    #[test]
+    fn export_round_trip() {
+        let mut world = world();
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Mesh::from(Cuboid::default()));
+        let material = world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial {
+                base_color: Color::linear_rgb(1.0, 0.0, 0.0),
+                emissive: LinearRgba::rgb(4.0, 2.0, 0.0),
+                unlit: true,
+                ..Default::default()
+            });
+        let cube = world
+            .spawn((
+                Name::new("Cube"),
+                Transform::from_xyz(1.0, 2.0, 3.0),
+                Mesh3d(mesh),
+                MeshMaterial3d(material),
+            ))
+            .id();
+        let root = world
+            .spawn((Name::new("Root"), Transform::default()))
+            .add_child(cube)
+            .with_child((
+                PointLight {
+                    intensity: 4.0 * core::f32::consts::PI,
+                    ..Default::default()
+                },
+                Transform::from_translation(Vec3::Y),
+            ))
+            .id();
+
+        for format in [GltfExportFormat::Glb, GltfExportFormat::Gltf] {
+            let exporter = GltfExporter::new(&world).with_settings(GltfExportSettings {
+                format,
+                ..Default::default()
+            });
+            let bytes = exporter.export_entities(&world, [root]).unwrap();
+            let gltf = gltf::Gltf::from_slice(&bytes).unwrap();
+            let buffer = match format {
+                GltfExportFormat::Glb => gltf.blob.clone().unwrap(),
+                GltfExportFormat::Gltf => {
+                    let uri = match gltf.buffers().next().unwrap().source() {
+                        gltf::buffer::Source::Uri(uri) => uri,
+                        gltf::buffer::Source::Bin => panic!("expected a data URI"),
+                    };
+                    let data = uri.split_once("base64,").unwrap().1;
+                    BASE64_STANDARD.decode(data).unwrap()
+                }
+            };
+
+            let scene = gltf.default_scene().unwrap();
+            let root_node = scene.nodes().next().unwrap();
+            assert_eq!(root_node.name(), Some("Root"));
+            let children: Vec<_> = root_node.children().collect();
+            assert_eq!(children.len(), 2);
+            let cube_node = &children[0];
+            assert_eq!(cube_node.name(), Some("Cube"));
+            assert_eq!(cube_node.transform().decomposed().0, [1.0, 2.0, 3.0]);
+
+            let primitive = cube_node.mesh().unwrap().primitives().next().unwrap();
+            let reader = primitive.reader(|_| Some(&buffer));
+            assert_eq!(reader.read_positions().unwrap().count(), 24);
+            assert_eq!(reader.read_indices().unwrap().into_u32().count(), 36);
+            let material = primitive.material();
+            assert!(material.unlit());
+            assert_eq!(material.emissive_strength(), Some(4.0));
+            assert_eq!(material.emissive_factor(), [1.0, 0.5, 0.0]);
+            assert_eq!(
+                material.pbr_metallic_roughness().base_color_factor(),
+                [1.0, 0.0, 0.0, 1.0]
+            );
+
+            let light = children[1].light().unwrap();
+            assert!(matches!(
+                light.kind(),
+                gltf::khr_lights_punctual::Kind::Point
+            ));
+            assert_eq!(light.intensity(), 1.0);
+
+            let (app, handle) = reimport(bytes);
+            let gltf = app.world().resource::<Assets<Gltf>>().get(&handle).unwrap();
+            assert!(gltf.named_nodes.contains_key("Cube"));
+            let material = app
+                .world()
+                .resource::<Assets<StandardMaterial>>()
+                .get(&gltf.materials[0])
+                .unwrap();
+            assert!(material.unlit);
+            assert_eq!(material.emissive.to_f32_array_no_alpha(), [4.0, 2.0, 0.0]);
+        }
+    }
+
+    #[test]
+    fn export_scene_exports_root_entities() {
+        let assets = world();
+        let mut scene_world = World::new();
+        scene_world.spawn(Name::new("A")).with_child(Name::new("B"));
+        scene_world.spawn(Name::new("C"));
+        let bytes = GltfExporter::new(&assets)
+            .export_scene(&Scene::new(scene_world))
+            .unwrap();
+        let gltf = gltf::Gltf::from_slice(&bytes).unwrap();
+        assert_eq!(gltf.nodes().count(), 3);
+        assert_eq!(gltf.default_scene().unwrap().nodes().count(), 2);
+    }
+
+    /// Loads an exported file with the [`GltfLoader`](crate::GltfLoader).
+    fn reimport(bytes: Vec<u8>) -> (App, Handle<Gltf>) {
+        let dir = Dir::default();
+        dir.insert_asset(Path::new("exported.gltf"), bytes);
+        let reader = MemoryAssetReader { root: dir };
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(move || Box::new(reader.clone())),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+            MeshPlugin,
+            crate::GltfPlugin::default(),
+        ))
+        .init_asset::<StandardMaterial>()
+        .init_asset::<Image>();
+        #[cfg(feature = "bevy_animation")]
+        app.init_asset::<AnimationClip>();
+        app.finish();
+        app.cleanup();
+
+        let handle = app
+            .world()
+            .resource::<AssetServer>()
+            .load::<Gltf>("exported.gltf");
+        for _ in 0..10000 {
+            app.update();
+            match app.world().resource::<AssetServer>().load_state(&handle) {
+                LoadState::Loaded => return (app, handle),
+                LoadState::Failed(error) => panic!("{error}"),
+                _ => {}
+            }
+        }
+        panic!("the exported file was not loaded");
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn export_textures() {
+        use bevy_asset::RenderAssetUsages;
+        use bevy_render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+        let mut world = world();
+        world.init_resource::<Assets<Image>>();
+        let mut image = Image::new(
+            Extent3d {
+                width: 2,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            vec![255, 0, 0, 255, 0, 0, 255, 128],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        image.sampler = ImageSampler::nearest();
+        let image = world.resource_mut::<Assets<Image>>().add(image);
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Mesh::from(Cuboid::default()));
+        let material = world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial {
+                base_color_texture: Some(image.clone()),
+                emissive_texture: Some(image),
+                ..Default::default()
+            });
+        let cube = world.spawn((Mesh3d(mesh), MeshMaterial3d(material))).id();
+
+        let bytes = GltfExporter::new(&world)
+            .with_settings(GltfExportSettings {
+                format: GltfExportFormat::Gltf,
+                ..Default::default()
+            })
+            .export_entities(&world, [cube])
+            .unwrap();
+        let gltf = gltf::Gltf::from_slice(&bytes).unwrap();
+        // Both textures share the image.
+        assert_eq!(gltf.images().count(), 1);
+        assert_eq!(gltf.textures().count(), 1);
+
+        let (app, handle) = reimport(bytes);
+        let gltf = app.world().resource::<Assets<Gltf>>().get(&handle).unwrap();
+        let material = app
+            .world()
+            .resource::<Assets<StandardMaterial>>()
+            .get(&gltf.materials[0])
+            .unwrap();
+        let images = app.world().resource::<Assets<Image>>();
+        let image = images
+            .get(material.base_color_texture.as_ref().unwrap())
+            .unwrap();
+        assert_eq!(image.data, [255, 0, 0, 255, 0, 0, 255, 128]);
+        let ImageSampler::Descriptor(sampler) = &image.sampler else {
+            panic!("expected the sampler of the texture");
+        };
+        assert!(matches!(sampler.mag_filter, ImageFilterMode::Nearest));
+        assert!(matches!(sampler.min_filter, ImageFilterMode::Nearest));
+        assert!(material.emissive_texture.is_some());
+    }
+
+    #[cfg(feature = "bevy_animation")]
+    #[test]
+    fn export_animations() {
+        use bevy_animation::{
+            animated_field,
+            animation_curves::{AnimatableCurve, AnimatableKeyframeCurve, AnimatedField},
+            AnimationPlayer,
+        };
+
+        let mut world = world();
+        world.init_resource::<Assets<AnimationClip>>();
+        world.init_resource::<Assets<AnimationGraph>>();
+        let (root_name, cube_name) = (Name::new("Root"), Name::new("Cube"));
+        let target_id = AnimationTargetId::from_names([&root_name, &cube_name].into_iter());
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_target(
+            target_id,
+            AnimatableCurve::new(
+                animated_field!(Transform::translation),
+                AnimatableKeyframeCurve::new([(0.0, Vec3::ZERO), (1.0, Vec3::X)]).unwrap(),
+            ),
+        );
+        let clip = world.resource_mut::<Assets<AnimationClip>>().add(clip);
+        let (graph, _) = AnimationGraph::from_clip(clip);
+        let graph = world.resource_mut::<Assets<AnimationGraph>>().add(graph);
+        let root = world
+            .spawn((
+                root_name,
+                Transform::default(),
+                AnimationPlayer::default(),
+                AnimationGraphHandle(graph),
+            ))
+            .id();
+        world
+            .spawn((
+                cube_name,
+                Transform::default(),
+                AnimationTarget {
+                    id: target_id,
+                    player: root,
+                },
+            ))
+            .set_parent(root);
+
+        let bytes = GltfExporter::new(&world)
+            .with_settings(GltfExportSettings {
+                format: GltfExportFormat::Gltf,
+                animation_sample_rate: 10.0,
+                ..Default::default()
+            })
+            .export_entities(&world, [root])
+            .unwrap();
+        let gltf = gltf::Gltf::from_slice(&bytes).unwrap();
+        let uri = match gltf.buffers().next().unwrap().source() {
+            gltf::buffer::Source::Uri(uri) => uri,
+            gltf::buffer::Source::Bin => panic!("expected a data URI"),
+        };
+        let buffer = BASE64_STANDARD
+            .decode(uri.split_once("base64,").unwrap().1)
+            .unwrap();
+        let animation = gltf.animations().next().unwrap();
+        let channel = animation.channels().next().unwrap();
+        assert_eq!(channel.target().node().name(), Some("Cube"));
+        let reader = channel.reader(|_| Some(&buffer));
+        let times: Vec<_> = reader.read_inputs().unwrap().collect();
+        assert_eq!(times.len(), 11);
+        assert_eq!(times[10], 1.0);
+        let gltf::animation::util::ReadOutputs::Translations(translations) =
+            reader.read_outputs().unwrap()
+        else {
+            panic!("expected translations");
+        };
+        let translations: Vec<_> = translations.collect();
+        assert_eq!(translations[0], [0.0, 0.0, 0.0]);
+        assert!(Vec3::from(translations[5]).abs_diff_eq(Vec3::new(0.5, 0.0, 0.0), 1e-6));
+        assert_eq!(translations[10], [1.0, 0.0, 0.0]);
+
+        let (app, handle) = reimport(bytes);
+        let gltf = app.world().resource::<Assets<Gltf>>().get(&handle).unwrap();
+        let clip = app
+            .world()
+            .resource::<Assets<AnimationClip>>()
+            .get(&gltf.animations[0])
+            .unwrap();
+        assert_eq!(clip.duration(), 1.0);
+        assert_eq!(clip.curves_for_target(target_id).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,1455 @@
+//! Loading of Autodesk FBX scenes, in the binary or the ASCII format.
+
+mod parser;
+
+use alloc::vec;
+use base64::Engine;
+use bevy_asset::{io::Reader, AssetLoader, Handle, LoadContext};
+use bevy_color::{Alpha, Color, ColorToComponents, LinearRgba};
+use bevy_ecs::{entity::Entity, name::Name, world::World};
+use bevy_hierarchy::BuildChildren;
+use bevy_image::{
+    CompressedImageFormats, Image, ImageAddressMode, ImageSampler, ImageSamplerDescriptor,
+    TextureError,
+};
+use bevy_math::{Mat3, Mat4, Quat, Vec3};
+use bevy_pbr::{MeshMaterial3d, StandardMaterial, MAX_JOINTS};
+use bevy_render::{
+    alpha::AlphaMode,
+    mesh::{
+        skinning::{SkinnedMesh, SkinnedMeshInverseBindposes},
+        Mesh, Mesh3d, VertexAttributeValues,
+    },
+    render_asset::RenderAssetUsages,
+    render_resource::PrimitiveTopology,
+    view::Visibility,
+};
+use bevy_scene::Scene;
+use bevy_transform::components::Transform;
+use bevy_utils::HashMap;
+use thiserror::Error;
+use tracing::warn;
+
+use crate::{
+    import::{
+        add_embedded_texture, euler_rotation, flat_normals, load_texture_file,
+        roughness_from_shininess,
+    },
+    GltfAssetLabel,
+};
+use parser::{Document, Node, Property};
+
+#[cfg(feature = "bevy_animation")]
+use bevy_animation::{AnimationClip, AnimationPlayer, AnimationTarget, AnimationTargetId};
+
+/// An error that occurs when loading an FBX file.
+#[derive(Error, Debug)]
+pub enum FbxError {
+    /// Failed to read the file.
+    #[error("failed to read FBX file: {0}")]
+    Io(#[from] std::io::Error),
+    /// The file is neither a binary nor an ASCII FBX file.
+    #[error("not an FBX file")]
+    UnknownFormat,
+    /// The file is malformed.
+    #[error("invalid FBX file: {0}")]
+    Parse(String),
+    /// The file was written with a version of FBX older than 7.1, whose scene graph is
+    /// organized differently.
+    #[error("FBX version {0} is not supported, only FBX 7.1 and later are")]
+    UnsupportedVersion(u32),
+    /// Error when decoding an embedded texture. Might be due to a disabled image file format
+    /// feature.
+    #[error("You may need to add the feature for the file format: {0}")]
+    Texture(#[from] TextureError),
+}
+
+/// Loads FBX files (`.fbx`), in the binary or the ASCII format, as a [`Scene`].
+///
+/// Each model becomes an entity with a [`Name`] and a [`Transform`], converted to Bevy's Y-up
+/// axes and to meters from the axis system and unit scale of the file. Meshes get a child with
+/// a [`Mesh3d`] and a [`MeshMaterial3d`] for each of their materials, and a [`SkinnedMesh`] when
+/// they are deformed by a skin. Classic (Lambert and Phong) materials are converted to
+/// [`StandardMaterial`]s with their diffuse, emissive and normal textures, which may be
+/// embedded in the file.
+///
+/// Meshes, materials, embedded textures, skins and animation stacks are added as the
+/// `Mesh{n}`, `Material{n}`, `Texture{n}`, `Skin{n}` and `Animation{n}` labeled assets,
+/// numbered in the order they are found. Animations are only loaded with the `bevy_animation`
+/// feature: the first layer of each stack is sampled at its keys with linear interpolation,
+/// and the root of the scene gets the `AnimationPlayer`.
+///
+/// Only files written with FBX 7.1 or later are supported. Cameras, lights, blend shapes and
+/// the tangents of animation curves are not imported.
+pub struct FbxLoader {
+    pub(crate) supported_compressed_formats: CompressedImageFormats,
+}
+
+impl AssetLoader for FbxLoader {
+    type Asset = Scene;
+    type Settings = ();
+    type Error = FbxError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Scene, FbxError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let document = parser::parse(&bytes)?;
+        if document.version < 7100 {
+            return Err(FbxError::UnsupportedVersion(document.version));
+        }
+        SceneBuilder {
+            loader: self,
+            load_context,
+            objects: Objects::new(&document),
+            document: &document,
+            geometries: HashMap::default(),
+            materials: HashMap::default(),
+            textures: HashMap::default(),
+            mesh_count: 0,
+            skin_count: 0,
+            embedded_texture_count: 0,
+        }
+        .build()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["fbx"]
+    }
+}
+
+/// The objects of a document, and the connections between them.
+struct Objects<'a> {
+    nodes: HashMap<i64, &'a Node>,
+    /// The objects connected to each object, with the property they are connected to, in the
+    /// order of the connections.
+    sources: HashMap<i64, Vec<(i64, Option<&'a str>)>>,
+    /// The objects each object is connected to.
+    destinations: HashMap<i64, Vec<(i64, Option<&'a str>)>>,
+}
+
+impl<'a> Objects<'a> {
+    fn new(document: &'a Document) -> Self {
+        let mut objects = Self {
+            nodes: HashMap::default(),
+            sources: HashMap::default(),
+            destinations: HashMap::default(),
+        };
+        for node in document
+            .node("Objects")
+            .into_iter()
+            .flat_map(|objects| &objects.children)
+        {
+            if let Some(id) = node.property(0).and_then(Property::as_i64) {
+                objects.nodes.insert(id, node);
+            }
+        }
+        let connections = document.node("Connections");
+        for connection in connections
+            .into_iter()
+            .flat_map(|node| node.children_named("C"))
+        {
+            let (Some(source), Some(destination)) = (
+                connection.property(1).and_then(Property::as_i64),
+                connection.property(2).and_then(Property::as_i64),
+            ) else {
+                continue;
+            };
+            let property = connection.property(3).and_then(Property::as_str);
+            objects
+                .sources
+                .entry(destination)
+                .or_default()
+                .push((source, property));
+            objects
+                .destinations
+                .entry(source)
+                .or_default()
+                .push((destination, property));
+        }
+        objects
+    }
+
+    /// Returns the objects of the given kind connected to `id`.
+    fn sources(&self, id: i64, kind: &str) -> vec::IntoIter<(i64, &'a Node, Option<&'a str>)> {
+        self.filter(self.sources.get(&id), kind)
+    }
+
+    /// Returns the objects of the given kind `id` is connected to.
+    fn destinations(&self, id: i64, kind: &str) -> vec::IntoIter<(i64, &'a Node, Option<&'a str>)> {
+        self.filter(self.destinations.get(&id), kind)
+    }
+
+    fn filter(
+        &self,
+        connections: Option<&Vec<(i64, Option<&'a str>)>>,
+        kind: &str,
+    ) -> vec::IntoIter<(i64, &'a Node, Option<&'a str>)> {
+        connections
+            .into_iter()
+            .flatten()
+            .filter_map(|&(id, property)| {
+                let node = *self.nodes.get(&id)?;
+                (node.name == kind).then_some((id, node, property))
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+/// Returns the name of an object, which is followed or preceded by its class in the binary and
+/// the ASCII formats respectively.
+fn object_name(node: &Node) -> &str {
+    let name = node
+        .property(1)
+        .and_then(Property::as_str)
+        .unwrap_or_default();
+    if let Some((name, _class)) = name.split_once("\0\x01") {
+        name
+    } else if let Some((_class, name)) = name.split_once("::") {
+        name
+    } else {
+        name
+    }
+}
+
+fn object_class(node: &Node) -> &str {
+    node.property(2)
+        .and_then(Property::as_str)
+        .unwrap_or_default()
+}
+
+/// The `Properties70` of a node, by name.
+struct Properties<'a>(HashMap<&'a str, &'a [Property]>);
+
+impl<'a> Properties<'a> {
+    fn of(node: &'a Node) -> Self {
+        let properties = node
+            .child("Properties70")
+            .into_iter()
+            .flat_map(|properties| properties.children_named("P"))
+            .filter_map(|property| {
+                let name = property.property(0)?.as_str()?;
+                // The name is followed by the type, a label and flags.
+                Some((name, property.properties.get(4..)?))
+            });
+        Self(properties.collect())
+    }
+
+    fn f32(&self, name: &str) -> Option<f32> {
+        Some(self.0.get(name)?.first()?.as_f64()? as f32)
+    }
+
+    fn i64(&self, name: &str) -> Option<i64> {
+        self.0.get(name)?.first()?.as_i64()
+    }
+
+    fn vec3(&self, name: &str) -> Option<Vec3> {
+        match self.0.get(name)? {
+            [x, y, z, ..] => Some(Vec3::new(
+                x.as_f64()? as f32,
+                y.as_f64()? as f32,
+                z.as_f64()? as f32,
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the transform converting the axes and units of the file to Bevy's.
+fn axis_conversion(document: &Document) -> Transform {
+    let settings = document
+        .node("GlobalSettings")
+        .map(Properties::of)
+        .unwrap_or(Properties(HashMap::default()));
+    let axis = |axis: &str, sign: &str, default: usize| {
+        let axis = settings.i64(axis).map_or(default, |axis| axis as usize);
+        let sign = settings.i64(sign).unwrap_or(1).signum() as f32;
+        Vec3::AXES.get(axis).copied().unwrap_or(Vec3::AXES[default]) * sign
+    };
+    let right = axis("CoordAxis", "CoordAxisSign", 0);
+    let up = axis("UpAxis", "UpAxisSign", 1);
+    let front = axis("FrontAxis", "FrontAxisSign", 2);
+    // The axes of the file which map to the X, Y and Z axes of Bevy are the rows of the
+    // conversion.
+    let rotation = Mat3::from_cols(right, up, front).transpose();
+    // The unit scale factor is in centimeters.
+    let scale = settings.f32("UnitScaleFactor").unwrap_or(1.0) / 100.0;
+    Transform::from_matrix(Mat4::from_mat3(rotation) * Mat4::from_scale(Vec3::splat(scale)))
+}
+
+/// The transform of a model, made of many more components than a [`Transform`].
+#[derive(Clone, Copy)]
+struct ModelTransform {
+    translation: Vec3,
+    /// Euler angles in degrees, applied in `rotation_order`.
+    rotation: Vec3,
+    scaling: Vec3,
+    rotation_order: &'static str,
+    pre_rotation: Quat,
+    post_rotation: Quat,
+    rotation_offset: Vec3,
+    rotation_pivot: Vec3,
+    scaling_offset: Vec3,
+    scaling_pivot: Vec3,
+}
+
+impl ModelTransform {
+    fn of(model: &Node) -> Self {
+        let properties = Properties::of(model);
+        let rotation_order = match properties.i64("RotationOrder") {
+            Some(1) => "XZY",
+            Some(2) => "YZX",
+            Some(3) => "YXZ",
+            Some(4) => "ZXY",
+            Some(5) => "ZYX",
+            _ => "XYZ",
+        };
+        // Pre- and post-rotations are always applied in the XYZ order.
+        let xyz = |name| {
+            properties
+                .vec3(name)
+                .and_then(|angles| euler_rotation("XYZ", angles.to_array()))
+                .unwrap_or_default()
+        };
+        Self {
+            translation: properties.vec3("Lcl Translation").unwrap_or_default(),
+            rotation: properties.vec3("Lcl Rotation").unwrap_or_default(),
+            scaling: properties.vec3("Lcl Scaling").unwrap_or(Vec3::ONE),
+            rotation_order,
+            pre_rotation: xyz("PreRotation"),
+            post_rotation: xyz("PostRotation"),
+            rotation_offset: properties.vec3("RotationOffset").unwrap_or_default(),
+            rotation_pivot: properties.vec3("RotationPivot").unwrap_or_default(),
+            scaling_offset: properties.vec3("ScalingOffset").unwrap_or_default(),
+            scaling_pivot: properties.vec3("ScalingPivot").unwrap_or_default(),
+        }
+    }
+
+    /// Returns the local transform of the model with the given translation, rotation and
+    /// scaling, which may be animated.
+    fn matrix(&self, translation: Vec3, rotation: Vec3, scaling: Vec3) -> Mat4 {
+        let rotation = euler_rotation(self.rotation_order, rotation.to_array()).unwrap_or_default();
+        Mat4::from_translation(translation + self.rotation_offset + self.rotation_pivot)
+            * Mat4::from_quat(self.pre_rotation * rotation * self.post_rotation.inverse())
+            * Mat4::from_translation(self.scaling_offset + self.scaling_pivot - self.rotation_pivot)
+            * Mat4::from_scale(scaling)
+            * Mat4::from_translation(-self.scaling_pivot)
+    }
+}
+
+/// Returns the geometric transform of a model, which applies to its meshes but not to its
+/// children.
+fn geometric_transform(model: &Node) -> Transform {
+    let properties = Properties::of(model);
+    Transform {
+        translation: properties.vec3("GeometricTranslation").unwrap_or_default(),
+        rotation: properties
+            .vec3("GeometricRotation")
+            .and_then(|angles| euler_rotation("XYZ", angles.to_array()))
+            .unwrap_or_default(),
+        scale: properties.vec3("GeometricScaling").unwrap_or(Vec3::ONE),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mapping {
+    PolygonVertex,
+    ControlPoint,
+    Polygon,
+    AllSame,
+}
+
+/// A layer element of a geometry, such as its normals, mapping polygon vertices to values.
+struct LayerElement<T> {
+    values: Vec<T>,
+    indices: Option<Vec<i64>>,
+    mapping: Mapping,
+}
+
+impl<T: Copy> LayerElement<T> {
+    fn read(
+        geometry: &Node,
+        element: &str,
+        values: &str,
+        indices: &str,
+        convert: impl Fn(&[f64]) -> T,
+        width: usize,
+    ) -> Option<Self> {
+        let node = geometry.child(element)?;
+        let mapping = match node
+            .child_property("MappingInformationType")
+            .and_then(Property::as_str)?
+        {
+            "ByPolygonVertex" => Mapping::PolygonVertex,
+            "ByVertice" | "ByVertex" | "ByControlPoint" => Mapping::ControlPoint,
+            "ByPolygon" => Mapping::Polygon,
+            "AllSame" => Mapping::AllSame,
+            mapping => {
+                warn!(
+                    "Ignoring {element} of geometry {}, mapped {mapping}",
+                    object_name(geometry)
+                );
+                return None;
+            }
+        };
+        let indices = match node
+            .child_property("ReferenceInformationType")
+            .and_then(Property::as_str)
+        {
+            Some("IndexToDirect" | "Index") => Some(node.child_property(indices)?.to_ints()?),
+            _ => None,
+        };
+        let values = node
+            .child_property(values)?
+            .to_floats()?
+            .chunks_exact(width)
+            .map(convert)
+            .collect();
+        Some(Self {
+            values,
+            indices,
+            mapping,
+        })
+    }
+
+    fn get(&self, polygon_vertex: usize, control_point: usize, polygon: usize) -> Option<T> {
+        let element = match self.mapping {
+            Mapping::PolygonVertex => polygon_vertex,
+            Mapping::ControlPoint => control_point,
+            Mapping::Polygon => polygon,
+            Mapping::AllSame => 0,
+        };
+        let index = match &self.indices {
+            Some(indices) => usize::try_from(*indices.get(element)?).ok()?,
+            None => element,
+        };
+        self.values.get(index).copied()
+    }
+}
+
+/// The meshes of a geometry, one for each of the materials of its polygons.
+#[derive(Clone)]
+struct LoadedGeometry {
+    meshes: Vec<(usize, Handle<Mesh>)>,
+    skin: Option<LoadedSkin>,
+}
+
+#[derive(Clone)]
+struct LoadedSkin {
+    inverse_bindposes: Handle<SkinnedMeshInverseBindposes>,
+    /// The models of the joints.
+    joints: Vec<i64>,
+}
+
+/// The joints influencing each control point of a skinned geometry.
+struct SkinWeights {
+    skin: LoadedSkin,
+    indices: Vec<[u16; 4]>,
+    weights: Vec<[f32; 4]>,
+}
+
+struct SceneBuilder<'a, 'b, 'c> {
+    loader: &'a FbxLoader,
+    load_context: &'b mut LoadContext<'c>,
+    document: &'a Document,
+    objects: Objects<'a>,
+    geometries: HashMap<i64, Option<LoadedGeometry>>,
+    materials: HashMap<i64, Handle<StandardMaterial>>,
+    textures: HashMap<(i64, bool), Option<Handle<Image>>>,
+    mesh_count: usize,
+    skin_count: usize,
+    embedded_texture_count: usize,
+}
+
+impl<'a> SceneBuilder<'a, '_, '_> {
+    fn build(mut self) -> Result<Scene, FbxError> {
+        let mut world = World::default();
+        let root = world
+            .spawn((axis_conversion(self.document), Visibility::default()))
+            .id();
+
+        let mut models = self
+            .objects
+            .nodes
+            .iter()
+            .filter(|(_, node)| node.name == "Model")
+            .map(|(&id, &node)| (id, node))
+            .collect::<Vec<_>>();
+        models.sort_by_key(|&(id, _)| id);
+
+        // Spawn the hierarchy of models, parents first.
+        let mut entities = HashMap::<i64, Entity>::default();
+        let mut paths = HashMap::<i64, Vec<Name>>::default();
+        let mut pending = models.clone();
+        while !pending.is_empty() {
+            let count = pending.len();
+            pending.retain(|&(id, model)| {
+                let parent = self
+                    .objects
+                    .destinations(id, "Model")
+                    .next()
+                    .map(|(parent, _, _)| parent);
+                let (parent_entity, mut path) = match parent {
+                    Some(parent) => match entities.get(&parent) {
+                        Some(&entity) => (entity, paths[&parent].clone()),
+                        None => return true,
+                    },
+                    None => (root, Vec::new()),
+                };
+                let transform = ModelTransform::of(model);
+                let name = Name::new(object_name(model).to_string());
+                let entity = world
+                    .spawn((
+                        name.clone(),
+                        Transform::from_matrix(transform.matrix(
+                            transform.translation,
+                            transform.rotation,
+                            transform.scaling,
+                        )),
+                        Visibility::default(),
+                    ))
+                    .id();
+                world.entity_mut(parent_entity).add_child(entity);
+                path.push(name);
+                entities.insert(id, entity);
+                paths.insert(id, path);
+                false
+            });
+            if pending.len() == count {
+                warn!("Ignoring {count} models whose parents form a cycle");
+                break;
+            }
+        }
+
+        for &(id, model) in &models {
+            let Some((geometry_id, geometry, _)) = self.objects.sources(id, "Geometry").next()
+            else {
+                continue;
+            };
+            let Some(geometry) = self.load_geometry(geometry_id, geometry)? else {
+                continue;
+            };
+            let materials = self
+                .objects
+                .sources(id, "Material")
+                .map(|(id, node, _)| (id, node))
+                .collect::<Vec<_>>();
+            let geometric_transform = geometric_transform(model);
+            for (material_index, mesh) in geometry.meshes {
+                let (material, name) = match materials.get(material_index) {
+                    Some(&(material_id, material)) => (
+                        self.load_material(material_id, material)?,
+                        format!("{}.{}", object_name(model), object_name(material)),
+                    ),
+                    None => (
+                        self.load_context.add_labeled_asset(
+                            GltfAssetLabel::DefaultMaterial.to_string(),
+                            StandardMaterial::default(),
+                        ),
+                        object_name(model).to_string(),
+                    ),
+                };
+                let mut mesh_entity = world.spawn((
+                    Name::new(name),
+                    Mesh3d(mesh),
+                    MeshMaterial3d(material),
+                    geometric_transform,
+                    Visibility::default(),
+                ));
+                if let Some(skin) = &geometry.skin {
+                    let joints = skin
+                        .joints
+                        .iter()
+                        .map(|joint| entities.get(joint).copied().unwrap_or(Entity::PLACEHOLDER))
+                        .collect();
+                    mesh_entity.insert(SkinnedMesh {
+                        inverse_bindposes: skin.inverse_bindposes.clone(),
+                        joints,
+                    });
+                }
+                let mesh_entity = mesh_entity.id();
+                world.entity_mut(entities[&id]).add_child(mesh_entity);
+            }
+        }
+
+        #[cfg(feature = "bevy_animation")]
+        self.load_animations(&mut world, root, &models, &entities, &paths);
+        #[cfg(not(feature = "bevy_animation"))]
+        let _ = paths;
+
+        Ok(Scene::new(world))
+    }
+
+    fn load_geometry(
+        &mut self,
+        id: i64,
+        geometry: &'a Node,
+    ) -> Result<Option<LoadedGeometry>, FbxError> {
+        if let Some(loaded) = self.geometries.get(&id) {
+            return Ok(loaded.clone());
+        }
+        let loaded = self.build_geometry(id, geometry)?;
+        self.geometries.insert(id, loaded.clone());
+        Ok(loaded)
+    }
+
+    fn build_geometry(
+        &mut self,
+        id: i64,
+        geometry: &'a Node,
+    ) -> Result<Option<LoadedGeometry>, FbxError> {
+        let name = object_name(geometry);
+        if object_class(geometry) != "Mesh" {
+            return Ok(None);
+        }
+        let (Some(points), Some(polygon_vertices)) = (
+            geometry
+                .child_property("Vertices")
+                .and_then(Property::to_floats),
+            geometry
+                .child_property("PolygonVertexIndex")
+                .and_then(Property::to_ints),
+        ) else {
+            warn!("Ignoring geometry {name}, which has no vertices");
+            return Ok(None);
+        };
+        let points = points
+            .chunks_exact(3)
+            .map(|point| Vec3::new(point[0] as f32, point[1] as f32, point[2] as f32))
+            .collect::<Vec<_>>();
+
+        let vec3 = |values: &[f64]| [values[0] as f32, values[1] as f32, values[2] as f32];
+        let normals = LayerElement::read(
+            geometry,
+            "LayerElementNormal",
+            "Normals",
+            "NormalsIndex",
+            vec3,
+            3,
+        );
+        let uvs = LayerElement::read(
+            geometry,
+            "LayerElementUV",
+            "UV",
+            "UVIndex",
+            // FBX texture coordinates start at the bottom of the image.
+            |values| [values[0] as f32, 1.0 - values[1] as f32],
+            2,
+        );
+        let colors = LayerElement::read(
+            geometry,
+            "LayerElementColor",
+            "Colors",
+            "ColorIndex",
+            |values| {
+                let [r, g, b, a] = [0, 1, 2, 3].map(|i| values[i] as f32);
+                Color::srgba(r, g, b, a).to_linear().to_f32_array()
+            },
+            4,
+        );
+        let materials = LayerElement::read(
+            geometry,
+            "LayerElementMaterial",
+            "Materials",
+            "",
+            |values| values[0] as usize,
+            1,
+        );
+        let skin = self.load_skin(id, points.len());
+
+        // Fan-triangulate each polygon, grouping the triangles by material.
+        let mut corners_by_material = Vec::<(usize, Vec<(usize, usize, usize)>)>::new();
+        let mut start = 0;
+        let mut polygon = 0;
+        for (end, &index) in polygon_vertices.iter().enumerate() {
+            // The last vertex of each polygon is stored as the bitwise negation of its index.
+            if index >= 0 {
+                continue;
+            }
+            let material = materials
+                .as_ref()
+                .and_then(|materials| materials.get(0, 0, polygon))
+                .unwrap_or(0);
+            let corners = match corners_by_material
+                .iter_mut()
+                .find(|(index, _)| *index == material)
+            {
+                Some((_, corners)) => corners,
+                None => {
+                    corners_by_material.push((material, Vec::new()));
+                    &mut corners_by_material.last_mut().unwrap().1
+                }
+            };
+            for i in start + 1..end {
+                for polygon_vertex in [start, i, i + 1] {
+                    let index = polygon_vertices[polygon_vertex];
+                    let point = if index < 0 { !index } else { index } as usize;
+                    if point >= points.len() {
+                        return Err(FbxError::Parse(format!(
+                            "geometry {name} has a vertex index out of range"
+                        )));
+                    }
+                    corners.push((polygon_vertex, point, polygon));
+                }
+            }
+            start = end + 1;
+            polygon += 1;
+        }
+        corners_by_material.sort_by_key(|(material, _)| *material);
+
+        let mut meshes = Vec::new();
+        for (material, corners) in corners_by_material {
+            let positions = corners
+                .iter()
+                .map(|&(_, point, _)| points[point].to_array())
+                .collect::<Vec<_>>();
+            let vertex_normals = normals
+                .as_ref()
+                .and_then(|normals| {
+                    corners
+                        .iter()
+                        .map(|&(vertex, point, polygon)| normals.get(vertex, point, polygon))
+                        .collect()
+                })
+                .unwrap_or_else(|| flat_normals(&positions));
+            let mut mesh = Mesh::new(
+                PrimitiveTopology::TriangleList,
+                RenderAssetUsages::default(),
+            )
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, vertex_normals);
+            let attribute = |element: &Option<LayerElement<_>>| {
+                element.as_ref().and_then(|element| {
+                    corners
+                        .iter()
+                        .map(|&(vertex, point, polygon)| element.get(vertex, point, polygon))
+                        .collect::<Option<Vec<_>>>()
+                })
+            };
+            if let Some(uvs) = attribute(&uvs) {
+                mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+            }
+            if let Some(colors) = colors.as_ref().and_then(|colors| {
+                corners
+                    .iter()
+                    .map(|&(vertex, point, polygon)| colors.get(vertex, point, polygon))
+                    .collect::<Option<Vec<_>>>()
+            }) {
+                mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+            }
+            if let Some(skin) = &skin {
+                let indices = corners.iter().map(|&(_, point, _)| skin.indices[point]);
+                let weights = corners.iter().map(|&(_, point, _)| skin.weights[point]);
+                mesh.insert_attribute(
+                    Mesh::ATTRIBUTE_JOINT_INDEX,
+                    VertexAttributeValues::Uint16x4(indices.collect()),
+                );
+                mesh.insert_attribute(Mesh::ATTRIBUTE_JOINT_WEIGHT, weights.collect::<Vec<_>>());
+            }
+            if mesh.contains_attribute(Mesh::ATTRIBUTE_UV_0) {
+                if let Err(err) = mesh.generate_tangents() {
+                    warn!("Failed to generate tangents for geometry {name}: {err}");
+                }
+            }
+            let label = format!("Mesh{}", self.mesh_count);
+            self.mesh_count += 1;
+            meshes.push((material, self.load_context.add_labeled_asset(label, mesh)));
+        }
+        Ok(Some(LoadedGeometry {
+            meshes,
+            skin: skin.map(|skin| skin.skin),
+        }))
+    }
+
+    /// Loads the skin deforming a geometry, if any.
+    fn load_skin(&mut self, geometry: i64, point_count: usize) -> Option<SkinWeights> {
+        let (skin, _, _) = self
+            .objects
+            .sources(geometry, "Deformer")
+            .find(|(_, deformer, _)| object_class(deformer) == "Skin")?;
+        let mut influences = vec![Vec::<(u16, f32)>::new(); point_count];
+        let mut joints = Vec::new();
+        let mut inverse_bindposes = Vec::new();
+        for (cluster_id, cluster, _) in self.objects.sources(skin, "Deformer") {
+            if object_class(cluster) != "Cluster" {
+                continue;
+            }
+            let Some((joint, _, _)) = self.objects.sources(cluster_id, "Model").next() else {
+                continue;
+            };
+            let matrix = |name| {
+                let values = cluster.child_property(name)?.to_floats()?;
+                let values: [f64; 16] = values.try_into().ok()?;
+                Some(Mat4::from_cols_array(&values.map(|value| value as f32)))
+            };
+            // `Transform` is the global transform of the mesh and `TransformLink` the one of
+            // the joint, when the skin was bound.
+            let (mesh_bindpose, joint_bindpose) = (
+                matrix("Transform").unwrap_or_default(),
+                matrix("TransformLink").unwrap_or_default(),
+            );
+            let joint_index = joints.len() as u16;
+            joints.push(joint);
+            inverse_bindposes.push(joint_bindpose.inverse() * mesh_bindpose);
+
+            let indices = cluster
+                .child_property("Indexes")
+                .and_then(Property::to_ints)
+                .unwrap_or_default();
+            let weights = cluster
+                .child_property("Weights")
+                .and_then(Property::to_floats)
+                .unwrap_or_default();
+            for (&point, &weight) in indices.iter().zip(&weights) {
+                if let Some(influences) = usize::try_from(point)
+                    .ok()
+                    .and_then(|point| influences.get_mut(point))
+                {
+                    influences.push((joint_index, weight as f32));
+                }
+            }
+        }
+        if joints.is_empty() {
+            return None;
+        }
+        if joints.len() > MAX_JOINTS {
+            warn!(
+                "The skin of geometry {geometry} has {} joints, more than the maximum of {MAX_JOINTS}",
+                joints.len()
+            );
+        }
+
+        // Keep the 4 most influent joints of each control point.
+        let mut indices = Vec::with_capacity(point_count);
+        let mut weights = Vec::with_capacity(point_count);
+        for mut influences in influences {
+            influences.sort_by(|a, b| b.1.total_cmp(&a.1));
+            influences.truncate(4);
+            let total = influences.iter().map(|(_, weight)| weight).sum::<f32>();
+            let mut point_indices = [0; 4];
+            let mut point_weights = [0.0; 4];
+            for (i, (joint, weight)) in influences.into_iter().enumerate() {
+                point_indices[i] = joint;
+                point_weights[i] = if total > 0.0 { weight / total } else { 0.0 };
+            }
+            indices.push(point_indices);
+            weights.push(point_weights);
+        }
+
+        let label = format!("Skin{}", self.skin_count);
+        self.skin_count += 1;
+        let inverse_bindposes = self
+            .load_context
+            .add_labeled_asset(label, SkinnedMeshInverseBindposes::from(inverse_bindposes));
+        Some(SkinWeights {
+            skin: LoadedSkin {
+                inverse_bindposes,
+                joints,
+            },
+            indices,
+            weights,
+        })
+    }
+
+    fn load_material(
+        &mut self,
+        id: i64,
+        node: &'a Node,
+    ) -> Result<Handle<StandardMaterial>, FbxError> {
+        if let Some(material) = self.materials.get(&id) {
+            return Ok(material.clone());
+        }
+        let name = object_name(node);
+        let properties = Properties::of(node);
+        let srgb = |color: Vec3| Color::srgb(color.x, color.y, color.z);
+        let diffuse = properties
+            .vec3("DiffuseColor")
+            .or_else(|| properties.vec3("Diffuse"))
+            .unwrap_or(Vec3::splat(0.8));
+        let diffuse_factor = properties.f32("DiffuseFactor").unwrap_or(1.0);
+        let emissive = properties
+            .vec3("EmissiveColor")
+            .or_else(|| properties.vec3("Emissive"))
+            .unwrap_or_default();
+        let emissive_factor = properties.f32("EmissiveFactor").unwrap_or(1.0);
+        let opacity = properties.f32("Opacity").unwrap_or(1.0);
+        let shininess = properties
+            .f32("Shininess")
+            .or_else(|| properties.f32("ShininessExponent"));
+
+        let mut material = StandardMaterial {
+            base_color: srgb(diffuse * diffuse_factor).with_alpha(opacity),
+            emissive: srgb(emissive * emissive_factor).to_linear(),
+            perceptual_roughness: shininess.map_or(0.5, roughness_from_shininess),
+            metallic: 0.0,
+            ..Default::default()
+        };
+        if opacity < 1.0 {
+            material.alpha_mode = AlphaMode::Blend;
+        }
+
+        let mut diffuse_texture = None;
+        for (texture_id, texture, property) in self.objects.sources(id, "Texture") {
+            match property.unwrap_or_default() {
+                "DiffuseColor" | "Maya|baseColor" => {
+                    diffuse_texture = Some(texture_file(texture));
+                    material.base_color = Color::WHITE.with_alpha(opacity);
+                    material.base_color_texture = self.load_texture(texture_id, texture, true)?;
+                }
+                "EmissiveColor" => {
+                    material.emissive = LinearRgba::WHITE * emissive_factor;
+                    material.emissive_texture = self.load_texture(texture_id, texture, true)?;
+                }
+                "NormalMap" => {
+                    material.normal_map_texture = self.load_texture(texture_id, texture, false)?;
+                }
+                "TransparentColor" | "TransparencyFactor"
+                    if diffuse_texture.as_ref() == Some(&texture_file(texture)) =>
+                {
+                    material.alpha_mode = AlphaMode::Blend;
+                }
+                property => {
+                    warn!("Ignoring the unsupported {property} texture of material {name}");
+                }
+            }
+        }
+
+        let label = format!("Material{}", self.materials.len());
+        let handle = self.load_context.add_labeled_asset(label, material);
+        self.materials.insert(id, handle.clone());
+        Ok(handle)
+    }
+
+    fn load_texture(
+        &mut self,
+        id: i64,
+        node: &'a Node,
+        is_srgb: bool,
+    ) -> Result<Option<Handle<Image>>, FbxError> {
+        if let Some(texture) = self.textures.get(&(id, is_srgb)) {
+            return Ok(texture.clone());
+        }
+        let file = texture_file(node);
+        let properties = Properties::of(node);
+        let address_mode = |name| match properties.i64(name) {
+            Some(1) => ImageAddressMode::ClampToEdge,
+            _ => ImageAddressMode::Repeat,
+        };
+        let sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+            address_mode_u: address_mode("WrapModeU"),
+            address_mode_v: address_mode("WrapModeV"),
+            ..ImageSamplerDescriptor::linear()
+        });
+
+        let embedded = self.objects.sources(id, "Video").find_map(|(_, video, _)| {
+            match video.child_property("Content")? {
+                Property::Bytes(bytes) if !bytes.is_empty() => Some(bytes.clone()),
+                Property::String(base64) if !base64.is_empty() => {
+                    base64::engine::general_purpose::STANDARD
+                        .decode(base64)
+                        .ok()
+                }
+                _ => None,
+            }
+        });
+        let texture = match (embedded, file) {
+            (Some(bytes), file) => {
+                let label = format!("Texture{}", self.embedded_texture_count);
+                self.embedded_texture_count += 1;
+                Some(add_embedded_texture(
+                    self.load_context,
+                    label,
+                    file.as_deref().unwrap_or_default(),
+                    &bytes,
+                    self.loader.supported_compressed_formats,
+                    is_srgb,
+                    sampler,
+                )?)
+            }
+            (None, Some(file)) => Some(load_texture_file(
+                self.load_context,
+                &file,
+                is_srgb,
+                sampler,
+            )),
+            (None, None) => {
+                warn!("Texture {} has no file", object_name(node));
+                None
+            }
+        };
+        self.textures.insert((id, is_srgb), texture.clone());
+        Ok(texture)
+    }
+
+    #[cfg(feature = "bevy_animation")]
+    fn load_animations(
+        &mut self,
+        world: &mut World,
+        root: Entity,
+        models: &[(i64, &'a Node)],
+        entities: &HashMap<i64, Entity>,
+        paths: &HashMap<i64, Vec<Name>>,
+    ) {
+        use bevy_animation::{animated_field, animation_curves::*};
+        use bevy_math::curve::{ConstantCurve, Interval, UnevenSampleAutoCurve};
+
+        /// The number of ticks of FBX times in a second.
+        const TICKS_PER_SECOND: f64 = 46_186_158_000.0;
+
+        let mut stacks = self
+            .objects
+            .nodes
+            .iter()
+            .filter(|(_, node)| node.name == "AnimationStack")
+            .map(|(&id, &node)| (id, node))
+            .collect::<Vec<_>>();
+        stacks.sort_by_key(|&(id, _)| id);
+
+        let mut animated = false;
+        for (index, (stack_id, stack)) in stacks.into_iter().enumerate() {
+            let start = Properties::of(stack).i64("LocalStart").unwrap_or(0);
+            let Some((layer, _, _)) = self.objects.sources(stack_id, "AnimationLayer").next()
+            else {
+                continue;
+            };
+
+            // The curves of the translation, rotation and scaling of each model, for each axis.
+            let mut channels = HashMap::<i64, [[Option<Channel>; 3]; 3]>::default();
+            for (curve_node_id, curve_node, _) in self.objects.sources(layer, "AnimationCurveNode")
+            {
+                let Some((model, _, Some(property))) =
+                    self.objects.destinations(curve_node_id, "Model").next()
+                else {
+                    continue;
+                };
+                let channel = match property {
+                    "Lcl Translation" => 0,
+                    "Lcl Rotation" => 1,
+                    "Lcl Scaling" => 2,
+                    _ => continue,
+                };
+                let defaults = Properties::of(curve_node);
+                let axes = &mut channels.entry(model).or_default()[channel];
+                for (axis, name) in ["d|X", "d|Y", "d|Z"].into_iter().enumerate() {
+                    let curve = self
+                        .objects
+                        .sources(curve_node_id, "AnimationCurve")
+                        .find(|(_, _, property)| *property == Some(name));
+                    axes[axis] = match curve {
+                        Some((_, curve, _)) => Channel::read(curve),
+                        None => defaults.f32(name).map(Channel::Constant),
+                    };
+                }
+            }
+
+            let mut clip = AnimationClip::default();
+            for (model, channels) in channels {
+                let Some(&(_, node)) = models.iter().find(|(id, _)| *id == model) else {
+                    continue;
+                };
+                let mut times = channels
+                    .iter()
+                    .flatten()
+                    .flatten()
+                    .flat_map(Channel::times)
+                    .collect::<Vec<_>>();
+                times.sort_unstable();
+                times.dedup();
+                if times.is_empty() {
+                    continue;
+                }
+
+                let transform = ModelTransform::of(node);
+                let rest = [transform.translation, transform.rotation, transform.scaling];
+                let mut samples = Vec::with_capacity(times.len());
+                let mut previous_rotation = Quat::IDENTITY;
+                for &time in &times {
+                    let [translation, rotation, scaling] = [0, 1, 2].map(|channel| {
+                        Vec3::from_array([0, 1, 2].map(|axis| {
+                            channels[channel][axis]
+                                .as_ref()
+                                .map_or(rest[channel][axis], |curve| curve.evaluate(time))
+                        }))
+                    });
+                    let (scale, mut rotation, translation) = transform
+                        .matrix(translation, rotation, scaling)
+                        .to_scale_rotation_translation();
+                    // Keep consecutive rotations in the same hemisphere to interpolate them
+                    // along the shortest path.
+                    if rotation.dot(previous_rotation) < 0.0 {
+                        rotation = -rotation;
+                    }
+                    previous_rotation = rotation;
+                    let time = ((time - start) as f64 / TICKS_PER_SECOND) as f32;
+                    samples.push((time, translation, rotation, scale));
+                }
+
+                let target = AnimationTargetId::from_names(paths[&model].iter());
+                if let [(_, translation, rotation, scale)] = samples[..] {
+                    clip.add_curve_to_target(
+                        target,
+                        AnimatableCurve::new(
+                            animated_field!(Transform::translation),
+                            ConstantCurve::new(Interval::EVERYWHERE, translation),
+                        ),
+                    );
+                    clip.add_curve_to_target(
+                        target,
+                        AnimatableCurve::new(
+                            animated_field!(Transform::rotation),
+                            ConstantCurve::new(Interval::EVERYWHERE, rotation),
+                        ),
+                    );
+                    clip.add_curve_to_target(
+                        target,
+                        AnimatableCurve::new(
+                            animated_field!(Transform::scale),
+                            ConstantCurve::new(Interval::EVERYWHERE, scale),
+                        ),
+                    );
+                    continue;
+                }
+                let sampled = |value: fn(&(f32, Vec3, Quat, Vec3)) -> Vec3| {
+                    UnevenSampleAutoCurve::new(
+                        samples.iter().map(|sample| (sample.0, value(sample))),
+                    )
+                };
+                if let Ok(curve) = sampled(|sample| sample.1) {
+                    clip.add_curve_to_target(
+                        target,
+                        AnimatableCurve::new(animated_field!(Transform::translation), curve),
+                    );
+                }
+                if let Ok(curve) = UnevenSampleAutoCurve::new(
+                    samples
+                        .iter()
+                        .map(|&(time, _, rotation, _)| (time, rotation)),
+                ) {
+                    clip.add_curve_to_target(
+                        target,
+                        AnimatableCurve::new(animated_field!(Transform::rotation), curve),
+                    );
+                }
+                if let Ok(curve) = sampled(|sample| sample.3) {
+                    clip.add_curve_to_target(
+                        target,
+                        AnimatableCurve::new(animated_field!(Transform::scale), curve),
+                    );
+                }
+            }
+            self.load_context
+                .add_labeled_asset(format!("Animation{index}"), clip);
+            animated = true;
+        }
+
+        if animated {
+            world.entity_mut(root).insert(AnimationPlayer::default());
+            for (model, &entity) in entities {
+                world.entity_mut(entity).insert(AnimationTarget {
+                    id: AnimationTargetId::from_names(paths[model].iter()),
+                    player: root,
+                });
+            }
+        }
+    }
+}
+
+/// Returns the file of a texture, relative to the FBX file.
+fn texture_file(texture: &Node) -> Option<String> {
+    let file = |name| {
+        texture
+            .child_property(name)
+            .and_then(Property::as_str)
+            .filter(|file| !file.is_empty())
+            .map(|file| file.replace('\\', "/"))
+    };
+    file("RelativeFilename").or_else(|| {
+        // Absolute paths are usually from the machine the file was exported on, so look for the
+        // texture next to the FBX file instead.
+        let file = file("FileName")?;
+        Some(file.rsplit('/').next().unwrap_or_default().to_string())
+    })
+}
+
+/// A curve of an animated component of a transform.
+#[cfg(feature = "bevy_animation")]
+enum Channel {
+    Keys(Vec<(i64, f32)>),
+    Constant(f32),
+}
+
+#[cfg(feature = "bevy_animation")]
+impl Channel {
+    fn read(curve: &Node) -> Option<Channel> {
+        let times = curve.child_property("KeyTime")?.to_ints()?;
+        let values = curve.child_property("KeyValueFloat")?.to_floats()?;
+        let mut keys = times
+            .into_iter()
+            .zip(values.into_iter().map(|value| value as f32))
+            .collect::<Vec<_>>();
+        keys.sort_by_key(|&(time, _)| time);
+        (!keys.is_empty()).then_some(Channel::Keys(keys))
+    }
+
+    fn times(&self) -> impl Iterator<Item = i64> + '_ {
+        let keys = match self {
+            Channel::Keys(keys) => keys.as_slice(),
+            Channel::Constant(_) => &[],
+        };
+        keys.iter().map(|&(time, _)| time)
+    }
+
+    /// Evaluates the curve at `time`, interpolating linearly between keys.
+    fn evaluate(&self, time: i64) -> f32 {
+        let keys = match self {
+            Channel::Keys(keys) => keys,
+            Channel::Constant(value) => return *value,
+        };
+        match keys.binary_search_by_key(&time, |&(time, _)| time) {
+            Ok(index) => keys[index].1,
+            Err(0) => keys[0].1,
+            Err(index) if index == keys.len() => keys[index - 1].1,
+            Err(index) => {
+                let (start, from) = keys[index - 1];
+                let (end, to) = keys[index];
+                let t = (time - start) as f32 / (end - start) as f32;
+                from + (to - from) * t
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::load_test_scene;
+    use bevy_asset::Assets;
+    use bevy_hierarchy::Parent;
+    use bevy_math::Vec3;
+
+    const QUAD: &str = r#"; FBX 7.4.0 project file
+FBXHeaderExtension:  {
+    FBXVersion: 7400
+}
+GlobalSettings:  {
+    Properties70:  {
+        P: "UpAxis", "int", "Integer", "",2
+        P: "UpAxisSign", "int", "Integer", "",1
+        P: "FrontAxis", "int", "Integer", "",1
+        P: "FrontAxisSign", "int", "Integer", "",-1
+        P: "CoordAxis", "int", "Integer", "",0
+        P: "CoordAxisSign", "int", "Integer", "",1
+        P: "UnitScaleFactor", "double", "Number", "",100
+    }
+}
+Objects:  {
+    Geometry: 1, "Geometry::Quad", "Mesh" {
+        Vertices: *12 {
+            a: 0,0,0,1,0,0,1,1,0,0,1,0
+        }
+        PolygonVertexIndex: *4 {
+            a: 0,1,2,-4
+        }
+        LayerElementNormal: 0 {
+            MappingInformationType: "ByVertice"
+            ReferenceInformationType: "Direct"
+            Normals: *12 {
+                a: 0,0,1,0,0,1,0,0,1,0,0,1
+            }
+        }
+        LayerElementUV: 0 {
+            MappingInformationType: "ByPolygonVertex"
+            ReferenceInformationType: "IndexToDirect"
+            UV: *4 {
+                a: 0,0,1,1
+            }
+            UVIndex: *4 {
+                a: 0,0,1,1
+            }
+        }
+        LayerElementMaterial: 0 {
+            MappingInformationType: "AllSame"
+            ReferenceInformationType: "IndexToDirect"
+            Materials: *1 {
+                a: 0
+            }
+        }
+    }
+    Model: 2, "Model::Quad", "Mesh" {
+        Properties70:  {
+            P: "Lcl Translation", "Lcl Translation", "", "A",1,2,3
+        }
+    }
+    Model: 3, "Model::Bone", "LimbNode" {
+        Properties70:  {
+            P: "Lcl Translation", "Lcl Translation", "", "A",0,0,1
+        }
+    }
+    Material: 4, "Material::Red", "" {
+        Properties70:  {
+            P: "DiffuseColor", "Color", "", "A",1,0,0
+            P: "Opacity", "double", "Number", "",0.5
+        }
+    }
+    Texture: 5, "Texture::Diffuse", "" {
+        RelativeFilename: "textures\red.png"
+    }
+    Deformer: 6, "Deformer::Skin", "Skin" {
+    }
+    Deformer: 7, "SubDeformer::Bone", "Cluster" {
+        Indexes: *3 {
+            a: 0,1,3
+        }
+        Weights: *3 {
+            a: 1,1,0.5
+        }
+        Transform: *16 {
+            a: 1,0,0,0,0,1,0,0,0,0,1,0,0,0,0,1
+        }
+        TransformLink: *16 {
+            a: 1,0,0,0,0,1,0,0,0,0,1,0,0,0,1,1
+        }
+    }
+    AnimationStack: 8, "AnimStack::Take", "" {
+    }
+    AnimationLayer: 9, "AnimLayer::Base", "" {
+    }
+    AnimationCurveNode: 10, "AnimCurveNode::T", "" {
+        Properties70:  {
+            P: "d|X", "Number", "", "A",0
+            P: "d|Y", "Number", "", "A",0
+            P: "d|Z", "Number", "", "A",1
+        }
+    }
+    AnimationCurve: 11, "AnimCurve::", "" {
+        KeyTime: *2 {
+            a: 0,46186158000
+        }
+        KeyValueFloat: *2 {
+            a: 0,2
+        }
+    }
+}
+Connections:  {
+    C: "OO",2,0
+    C: "OO",3,2
+    C: "OO",1,2
+    C: "OO",4,2
+    C: "OP",5,4, "DiffuseColor"
+    C: "OO",6,1
+    C: "OO",7,6
+    C: "OO",3,7
+    C: "OO",9,8
+    C: "OO",10,9
+    C: "OP",10,3, "Lcl Translation"
+    C: "OP",11,10, "d|X"
+}
+"#;
+
+    fn check_quad(path: &str, bytes: &[u8]) {
+        let (mut app, handle) = load_test_scene(path, &[(path, bytes)]).unwrap();
+        let scenes = app.world_mut().resource_mut::<Assets<Scene>>();
+        let world = &mut scenes.into_inner().get_mut(&handle).unwrap().world;
+        let mut entity = |name: &str| {
+            world
+                .query::<(Entity, &Name)>()
+                .iter(world)
+                .find(|(_, entity_name)| entity_name.as_str() == name)
+                .map(|(entity, _)| entity)
+                .unwrap()
+        };
+        let (quad, bone, mesh) = (entity("Quad"), entity("Bone"), entity("Quad.Red"));
+
+        let root = world.get::<Parent>(quad).unwrap().get();
+        let axes = world.get::<Transform>(root).unwrap();
+        // The file is Z-up, in meters.
+        assert!((axes.rotation * Vec3::Z).abs_diff_eq(Vec3::Y, 1e-6));
+        assert!(axes.scale.abs_diff_eq(Vec3::ONE, 1e-6));
+        assert_eq!(
+            world.get::<Transform>(quad).unwrap().translation,
+            Vec3::new(1.0, 2.0, 3.0)
+        );
+        assert_eq!(world.get::<Parent>(bone).unwrap().get(), quad);
+        assert_eq!(world.get::<Parent>(mesh).unwrap().get(), quad);
+        assert_eq!(world.get::<SkinnedMesh>(mesh).unwrap().joints, vec![bone]);
+        #[cfg(feature = "bevy_animation")]
+        {
+            assert!(world.get::<AnimationPlayer>(root).is_some());
+            assert_eq!(world.get::<AnimationTarget>(bone).unwrap().player, root);
+        }
+        let mesh_handle = world.get::<Mesh3d>(mesh).unwrap().0.clone();
+        let material = world
+            .get::<MeshMaterial3d<StandardMaterial>>(mesh)
+            .unwrap()
+            .0
+            .clone();
+
+        let mesh = app
+            .world()
+            .resource::<Assets<Mesh>>()
+            .get(&mesh_handle)
+            .unwrap();
+        assert_eq!(mesh.count_vertices(), 6);
+        let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+        else {
+            panic!("missing texture coordinates");
+        };
+        assert_eq!(uvs[0], [0.0, 1.0]);
+        assert_eq!(uvs[2], [1.0, 0.0]);
+        let Some(VertexAttributeValues::Float32x4(weights)) =
+            mesh.attribute(Mesh::ATTRIBUTE_JOINT_WEIGHT)
+        else {
+            panic!("missing joint weights");
+        };
+        // The third corner of the first triangle isn't influenced by the bone.
+        assert_eq!(
+            &weights[..3],
+            &[[1.0, 0.0, 0.0, 0.0], [1.0, 0.0, 0.0, 0.0], [0.0; 4]]
+        );
+
+        let material = app
+            .world()
+            .resource::<Assets<StandardMaterial>>()
+            .get(&material)
+            .unwrap();
+        assert_eq!(material.base_color, Color::WHITE.with_alpha(0.5));
+        assert_eq!(material.alpha_mode, AlphaMode::Blend);
+        assert_eq!(
+            material
+                .base_color_texture
+                .as_ref()
+                .and_then(Handle::path)
+                .map(ToString::to_string),
+            Some("textures/red.png".into())
+        );
+
+        #[cfg(feature = "bevy_animation")]
+        {
+            // Clips aren't referenced by the scene, so they are only kept when loaded.
+            let asset_server = app.world().resource::<bevy_asset::AssetServer>().clone();
+            let clip = asset_server.load::<AnimationClip>(format!("{path}#Animation0"));
+            while !asset_server.is_loaded(&clip) {
+                app.update();
+            }
+            let clips = app.world().resource::<Assets<AnimationClip>>();
+            assert_eq!(clips.get(&clip).unwrap().duration(), 1.0);
+        }
+    }
+
+    #[test]
+    fn load_ascii() {
+        check_quad("quad.fbx", QUAD.as_bytes());
+    }
+
+    #[test]
+    fn load_binary() {
+        let document = parser::parse(QUAD.as_bytes()).unwrap();
+        let bytes = parser::write_binary(7400, &document.nodes);
+        check_quad("quad.fbx", &bytes);
+    }
+}
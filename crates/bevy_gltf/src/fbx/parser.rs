@@ -0,0 +1,640 @@
+//! Parsers for the binary and ASCII FBX formats, which both describe a tree of [`Node`]s.
+
+use std::io::Read;
+
+use flate2::read::ZlibDecoder;
+
+use super::FbxError;
+
+const BINARY_MAGIC: &[u8] = b"Kaydara FBX Binary  \0";
+
+/// A property of a [`Node`].
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Property {
+    Int(i64),
+    Float(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Ints(Vec<i64>),
+    Floats(Vec<f64>),
+}
+
+impl Property {
+    pub(crate) fn as_i64(&self) -> Option<i64> {
+        match self {
+            Property::Int(value) => Some(*value),
+            Property::Float(value) => Some(*value as i64),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_f64(&self) -> Option<f64> {
+        match self {
+            Property::Int(value) => Some(*value as f64),
+            Property::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Property::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of an array, which the ASCII format doesn't distinguish from
+    /// arrays of floats.
+    pub(crate) fn to_ints(&self) -> Option<Vec<i64>> {
+        match self {
+            Property::Ints(values) => Some(values.clone()),
+            Property::Floats(values) => Some(values.iter().map(|&value| value as i64).collect()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_floats(&self) -> Option<Vec<f64>> {
+        match self {
+            Property::Ints(values) => Some(values.iter().map(|&value| value as f64).collect()),
+            Property::Floats(values) => Some(values.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// A node of an FBX file, with its properties and children.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Node {
+    pub(crate) name: String,
+    pub(crate) properties: Vec<Property>,
+    pub(crate) children: Vec<Node>,
+}
+
+impl Node {
+    pub(crate) fn child(&self, name: &str) -> Option<&Node> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    pub(crate) fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Node> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    pub(crate) fn property(&self, index: usize) -> Option<&Property> {
+        self.properties.get(index)
+    }
+
+    /// Returns the first property of the child named `name`.
+    pub(crate) fn child_property(&self, name: &str) -> Option<&Property> {
+        self.child(name)?.property(0)
+    }
+}
+
+/// A parsed FBX file.
+pub(crate) struct Document {
+    /// The version, such as 7400 for FBX 7.4.
+    pub(crate) version: u32,
+    pub(crate) nodes: Vec<Node>,
+}
+
+impl Document {
+    pub(crate) fn node(&self, name: &str) -> Option<&Node> {
+        self.nodes.iter().find(|node| node.name == name)
+    }
+}
+
+/// Parses an FBX file in the binary or the ASCII format.
+pub(crate) fn parse(bytes: &[u8]) -> Result<Document, FbxError> {
+    if bytes.starts_with(BINARY_MAGIC) {
+        return BinaryReader::new(bytes)?.read_document();
+    }
+    let source = core::str::from_utf8(bytes).map_err(|_| FbxError::UnknownFormat)?;
+    let nodes = AsciiParser::new(source).parse()?;
+    let version = nodes
+        .iter()
+        .find(|node| node.name == "FBXHeaderExtension")
+        .and_then(|header| header.child_property("FBXVersion"))
+        .and_then(Property::as_i64)
+        .ok_or(FbxError::UnknownFormat)?;
+    Ok(Document {
+        version: version as u32,
+        nodes,
+    })
+}
+
+struct BinaryReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    version: u32,
+}
+
+impl<'a> BinaryReader<'a> {
+    fn new(bytes: &'a [u8]) -> Result<Self, FbxError> {
+        let mut reader = Self {
+            bytes,
+            // The magic is followed by two unknown bytes.
+            position: BINARY_MAGIC.len() + 2,
+            version: 0,
+        };
+        reader.version = reader.u32()?;
+        Ok(reader)
+    }
+
+    fn error(&self, message: &str) -> FbxError {
+        FbxError::Parse(format!("{message} at byte {}", self.position))
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], FbxError> {
+        let bytes = self
+            .position
+            .checked_add(length)
+            .and_then(|end| self.bytes.get(self.position..end))
+            .ok_or_else(|| self.error("unexpected end of file"))?;
+        self.position += length;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], FbxError> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    fn u32(&mut self) -> Result<u32, FbxError> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    /// Reads an offset or a count of a node record, which are 64-bit since FBX 7.5.
+    fn record_field(&mut self) -> Result<u64, FbxError> {
+        if self.version >= 7500 {
+            Ok(u64::from_le_bytes(self.array()?))
+        } else {
+            Ok(self.u32()? as u64)
+        }
+    }
+
+    fn read_document(mut self) -> Result<Document, FbxError> {
+        let mut nodes = Vec::new();
+        // The last node is followed by a null record and a footer, though some writers omit
+        // the null record.
+        let header_length = if self.version >= 7500 { 25 } else { 13 };
+        while self.bytes.len() - self.position >= header_length {
+            let Some(node) = self.read_node()? else {
+                break;
+            };
+            nodes.push(node);
+        }
+        Ok(Document {
+            version: self.version,
+            nodes,
+        })
+    }
+
+    /// Reads a node record, or returns `None` for the null record ending a list of nodes.
+    fn read_node(&mut self) -> Result<Option<Node>, FbxError> {
+        let end = self.record_field()? as usize;
+        let property_count = self.record_field()?;
+        let _property_list_length = self.record_field()?;
+        let name_length = self.take(1)?[0] as usize;
+        if end == 0 {
+            return Ok(None);
+        }
+        if end > self.bytes.len() || end < self.position {
+            return Err(self.error("invalid node end offset"));
+        }
+        let name = String::from_utf8_lossy(self.take(name_length)?).into_owned();
+        let properties = (0..property_count)
+            .map(|_| self.read_property())
+            .collect::<Result<_, _>>()?;
+        let mut children = Vec::new();
+        while self.position < end {
+            match self.read_node()? {
+                Some(child) => children.push(child),
+                None => break,
+            }
+        }
+        self.position = end;
+        Ok(Some(Node {
+            name,
+            properties,
+            children,
+        }))
+    }
+
+    fn read_property(&mut self) -> Result<Property, FbxError> {
+        let type_code = self.take(1)?[0];
+        Ok(match type_code {
+            b'C' => Property::Int(self.take(1)?[0] as i64),
+            b'Y' => Property::Int(i16::from_le_bytes(self.array()?) as i64),
+            b'I' => Property::Int(i32::from_le_bytes(self.array()?) as i64),
+            b'L' => Property::Int(i64::from_le_bytes(self.array()?)),
+            b'F' => Property::Float(f32::from_le_bytes(self.array()?) as f64),
+            b'D' => Property::Float(f64::from_le_bytes(self.array()?)),
+            b'S' => {
+                let length = self.u32()? as usize;
+                Property::String(String::from_utf8_lossy(self.take(length)?).into_owned())
+            }
+            b'R' => {
+                let length = self.u32()? as usize;
+                Property::Bytes(self.take(length)?.to_vec())
+            }
+            b'b' | b'i' | b'l' | b'f' | b'd' => {
+                let element_size = match type_code {
+                    b'b' => 1,
+                    b'i' | b'f' => 4,
+                    _ => 8,
+                };
+                let length = self.u32()? as usize;
+                let encoding = self.u32()?;
+                let stored_length = self.u32()? as usize;
+                let stored = self.take(stored_length)?;
+                let expected_length = length
+                    .checked_mul(element_size)
+                    .ok_or_else(|| self.error("array is too long"))?;
+                let data = match encoding {
+                    0 => stored.to_vec(),
+                    1 => {
+                        let mut data = Vec::with_capacity(expected_length);
+                        ZlibDecoder::new(stored)
+                            .read_to_end(&mut data)
+                            .map_err(|err| {
+                                self.error(&format!("corrupt compressed array: {err}"))
+                            })?;
+                        data
+                    }
+                    _ => return Err(self.error("unknown array encoding")),
+                };
+                if data.len() != expected_length {
+                    return Err(self.error("array length mismatch"));
+                }
+                let elements = data.chunks_exact(element_size);
+                match type_code {
+                    b'b' => Property::Ints(data.iter().map(|&value| value as i64).collect()),
+                    b'i' => Property::Ints(
+                        elements
+                            .map(|bytes| i32::from_le_bytes(bytes.try_into().unwrap()) as i64)
+                            .collect(),
+                    ),
+                    b'l' => Property::Ints(
+                        elements
+                            .map(|bytes| i64::from_le_bytes(bytes.try_into().unwrap()))
+                            .collect(),
+                    ),
+                    b'f' => Property::Floats(
+                        elements
+                            .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()) as f64)
+                            .collect(),
+                    ),
+                    _ => Property::Floats(
+                        elements
+                            .map(|bytes| f64::from_le_bytes(bytes.try_into().unwrap()))
+                            .collect(),
+                    ),
+                }
+            }
+            _ => return Err(self.error("unknown property type")),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token<'a> {
+    /// A name followed by a colon, which starts a node.
+    Key(&'a str),
+    Word(&'a str),
+    Number(&'a str),
+    String(&'a str),
+    Punct(char),
+}
+
+struct AsciiParser<'a> {
+    source: &'a str,
+    position: usize,
+    line: usize,
+    peeked: Option<Token<'a>>,
+}
+
+impl<'a> AsciiParser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            position: 0,
+            line: 1,
+            peeked: None,
+        }
+    }
+
+    fn error(&self, message: &str) -> FbxError {
+        FbxError::Parse(format!("{message} at line {}", self.line))
+    }
+
+    fn lex(&mut self) -> Result<Option<Token<'a>>, FbxError> {
+        loop {
+            let rest = &self.source[self.position..];
+            let Some(c) = rest.chars().next() else {
+                return Ok(None);
+            };
+            if c == '\n' {
+                self.line += 1;
+                self.position += 1;
+            } else if c.is_whitespace() {
+                self.position += c.len_utf8();
+            } else if c == ';' {
+                self.position += rest.find('\n').unwrap_or(rest.len());
+            } else {
+                break;
+            }
+        }
+        let rest = &self.source[self.position..];
+        let c = rest.chars().next().unwrap();
+        let (token, length) = match c {
+            '{' | '}' | ',' | '*' | ':' => (Token::Punct(c), 1),
+            '"' => {
+                let length = rest[1..]
+                    .find('"')
+                    .ok_or_else(|| self.error("unterminated string"))?;
+                self.line += rest[1..=length].matches('\n').count();
+                (Token::String(&rest[1..=length]), length + 2)
+            }
+            c if c.is_ascii_digit() || matches!(c, '-' | '+' | '.') => {
+                let length = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '.')))
+                    .unwrap_or(rest.len());
+                (Token::Number(&rest[..length]), length)
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let length = rest
+                    .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '|' | '.')))
+                    .unwrap_or(rest.len());
+                if rest[length..].starts_with(':') {
+                    (Token::Key(&rest[..length]), length + 1)
+                } else {
+                    (Token::Word(&rest[..length]), length)
+                }
+            }
+            c => return Err(self.error(&format!("unexpected character `{c}`"))),
+        };
+        self.position += length;
+        Ok(Some(token))
+    }
+
+    fn next(&mut self) -> Result<Option<Token<'a>>, FbxError> {
+        match self.peeked.take() {
+            Some(token) => Ok(Some(token)),
+            None => self.lex(),
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<&Token<'a>>, FbxError> {
+        if self.peeked.is_none() {
+            self.peeked = self.lex()?;
+        }
+        Ok(self.peeked.as_ref())
+    }
+
+    fn parse(mut self) -> Result<Vec<Node>, FbxError> {
+        let mut nodes = Vec::new();
+        while let Some(token) = self.next()? {
+            let Token::Key(name) = token else {
+                return Err(self.error("expected a node"));
+            };
+            nodes.push(self.parse_node(name)?);
+        }
+        Ok(nodes)
+    }
+
+    /// Parses a node, after its name.
+    fn parse_node(&mut self, name: &str) -> Result<Node, FbxError> {
+        let mut node = Node {
+            name: name.to_string(),
+            ..Node::default()
+        };
+        let mut is_array = false;
+        // Embedded contents of the ASCII format start with a stray comma.
+        if self.peek()? == Some(&Token::Punct(',')) {
+            self.next()?;
+        }
+        loop {
+            let property = match self.peek()? {
+                Some(Token::Number(number)) => {
+                    let number = *number;
+                    match number.parse::<i64>() {
+                        Ok(int) => Property::Int(int),
+                        Err(_) => Property::Float(
+                            number
+                                .parse()
+                                .map_err(|_| self.error(&format!("invalid number `{number}`")))?,
+                        ),
+                    }
+                }
+                Some(Token::String(string)) => Property::String(string.to_string()),
+                Some(Token::Word(word)) => Property::String(word.to_string()),
+                // The length of an array, whose values follow in an `a` node.
+                Some(Token::Punct('*')) => {
+                    self.next()?;
+                    let Some(Token::Number(_)) = self.next()? else {
+                        return Err(self.error("expected the length of an array"));
+                    };
+                    is_array = true;
+                    break;
+                }
+                _ => break,
+            };
+            self.next()?;
+            node.properties.push(property);
+            if self.peek()? != Some(&Token::Punct(',')) {
+                break;
+            }
+            self.next()?;
+        }
+
+        if self.peek()? == Some(&Token::Punct('{')) {
+            self.next()?;
+            loop {
+                match self.next()? {
+                    Some(Token::Punct('}')) => break,
+                    Some(Token::Key(name)) => {
+                        let child = self.parse_node(name)?;
+                        node.children.push(child);
+                    }
+                    _ => return Err(self.error("expected a node or `}`")),
+                }
+            }
+        }
+
+        if is_array {
+            let values = node
+                .children
+                .pop()
+                .filter(|values| values.name == "a")
+                .map(|values| values.properties)
+                .unwrap_or_default();
+            node.properties.push(
+                if values.iter().all(|value| matches!(value, Property::Int(_))) {
+                    Property::Ints(values.iter().filter_map(Property::as_i64).collect())
+                } else {
+                    Property::Floats(values.iter().filter_map(Property::as_f64).collect())
+                },
+            );
+        }
+        Ok(node)
+    }
+}
+
+/// Builds a binary FBX file of `nodes`.
+#[cfg(test)]
+pub(crate) fn write_binary(version: u32, nodes: &[Node]) -> Vec<u8> {
+    fn write_node(bytes: &mut Vec<u8>, node: &Node) {
+        let start = bytes.len();
+        bytes.extend([0; 12]);
+        bytes.push(node.name.len() as u8);
+        bytes.extend(node.name.as_bytes());
+        let properties_start = bytes.len();
+        for property in &node.properties {
+            match property {
+                Property::Int(value) => {
+                    bytes.push(b'L');
+                    bytes.extend(value.to_le_bytes());
+                }
+                Property::Float(value) => {
+                    bytes.push(b'D');
+                    bytes.extend(value.to_le_bytes());
+                }
+                Property::String(value) => {
+                    bytes.push(b'S');
+                    bytes.extend((value.len() as u32).to_le_bytes());
+                    bytes.extend(value.as_bytes());
+                }
+                Property::Bytes(value) => {
+                    bytes.push(b'R');
+                    bytes.extend((value.len() as u32).to_le_bytes());
+                    bytes.extend(value);
+                }
+                Property::Ints(values) => {
+                    bytes.push(b'l');
+                    bytes.extend((values.len() as u32).to_le_bytes());
+                    bytes.extend(0u32.to_le_bytes());
+                    bytes.extend((values.len() as u32 * 8).to_le_bytes());
+                    for value in values {
+                        bytes.extend(value.to_le_bytes());
+                    }
+                }
+                Property::Floats(values) => {
+                    // Compress arrays of floats, as exporters do for large arrays.
+                    use flate2::{write::ZlibEncoder, Compression};
+                    use std::io::Write;
+                    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                    for value in values {
+                        encoder.write_all(&value.to_le_bytes()).unwrap();
+                    }
+                    let compressed = encoder.finish().unwrap();
+                    bytes.push(b'd');
+                    bytes.extend((values.len() as u32).to_le_bytes());
+                    bytes.extend(1u32.to_le_bytes());
+                    bytes.extend((compressed.len() as u32).to_le_bytes());
+                    bytes.extend(compressed);
+                }
+            }
+        }
+        let properties_length = (bytes.len() - properties_start) as u32;
+        for child in &node.children {
+            write_node(bytes, child);
+        }
+        if !node.children.is_empty() {
+            bytes.extend([0; 13]);
+        }
+        let end = bytes.len() as u32;
+        bytes[start..start + 4].copy_from_slice(&end.to_le_bytes());
+        bytes[start + 4..start + 8].copy_from_slice(&(node.properties.len() as u32).to_le_bytes());
+        bytes[start + 8..start + 12].copy_from_slice(&properties_length.to_le_bytes());
+    }
+
+    let mut bytes = BINARY_MAGIC.to_vec();
+    bytes.extend([0x1a, 0]);
+    bytes.extend(version.to_le_bytes());
+    for node in nodes {
+        write_node(&mut bytes, node);
+    }
+    bytes.extend([0; 13]);
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ascii() {
+        let document = parse(
+            br#"; FBX 7.4.0 project file
+FBXHeaderExtension:  {
+    FBXVersion: 7400
+}
+Objects:  {
+    Geometry: 10, "Geometry::Cube", "Mesh" {
+        Vertices: *6 {
+            a: 0,0,0,1.5,
+            -2,3e1
+        }
+        PolygonVertexIndex: *3 {
+            a: 0,1,-3
+        }
+        Properties70:  {
+            P: "Lcl Translation", "Lcl Translation", "", "A",1,2,3
+        }
+        Shading: T
+    }
+}
+"#,
+        )
+        .unwrap();
+        assert_eq!(document.version, 7400);
+        let geometry = document.node("Objects").unwrap().child("Geometry").unwrap();
+        assert_eq!(geometry.properties[0], Property::Int(10));
+        assert_eq!(
+            geometry.property(1).unwrap().as_str(),
+            Some("Geometry::Cube")
+        );
+        assert_eq!(
+            geometry.child_property("Vertices"),
+            Some(&Property::Floats(vec![0.0, 0.0, 0.0, 1.5, -2.0, 30.0]))
+        );
+        assert_eq!(
+            geometry.child_property("PolygonVertexIndex"),
+            Some(&Property::Ints(vec![0, 1, -3]))
+        );
+        let translation = geometry.child("Properties70").unwrap().child("P").unwrap();
+        assert_eq!(translation.properties.len(), 7);
+        assert_eq!(
+            geometry.child_property("Shading"),
+            Some(&Property::String("T".into()))
+        );
+    }
+
+    #[test]
+    fn parse_binary() {
+        let nodes = vec![Node {
+            name: "Objects".into(),
+            properties: Vec::new(),
+            children: vec![Node {
+                name: "Geometry".into(),
+                properties: vec![
+                    Property::Int(10),
+                    Property::String("Cube\0\x01Geometry".into()),
+                ],
+                children: vec![
+                    Node {
+                        name: "Vertices".into(),
+                        properties: vec![Property::Floats(vec![0.0, 1.0, 2.0])],
+                        children: Vec::new(),
+                    },
+                    Node {
+                        name: "PolygonVertexIndex".into(),
+                        properties: vec![Property::Ints(vec![0, 1, -3])],
+                        children: Vec::new(),
+                    },
+                ],
+            }],
+        }];
+        let document = parse(&write_binary(7400, &nodes)).unwrap();
+        assert_eq!(document.version, 7400);
+        assert_eq!(document.nodes, nodes);
+    }
+}
@@ -0,0 +1,159 @@
+//! Helpers shared by the loaders of the formats other than glTF.
+
+use bevy_asset::{Handle, LoadContext};
+use bevy_image::{Image, ImageLoaderSettings, ImageSampler};
+use bevy_math::Vec3;
+
+#[cfg(any(feature = "fbx", feature = "usd"))]
+use bevy_math::{EulerRot, Quat};
+#[cfg(feature = "fbx")]
+use {
+    bevy_image::{CompressedImageFormats, ImageType, TextureError},
+    bevy_render::render_asset::RenderAssetUsages,
+};
+
+/// Computes a normal for each vertex of a non-indexed triangle list, from the triangle it
+/// belongs to.
+pub(crate) fn flat_normals(positions: &[[f32; 3]]) -> Vec<[f32; 3]> {
+    positions
+        .chunks_exact(3)
+        .flat_map(|triangle| {
+            let [a, b, c] = [triangle[0], triangle[1], triangle[2]].map(Vec3::from);
+            let normal = (b - a).cross(c - a).normalize_or_zero().to_array();
+            [normal; 3]
+        })
+        .collect()
+}
+
+/// Returns the rotation of Euler angles in degrees, applied in the order of `axes`, such as
+/// `"XYZ"` to rotate about the X axis first.
+#[cfg(any(feature = "fbx", feature = "usd"))]
+pub(crate) fn euler_rotation(axes: &str, degrees: [f32; 3]) -> Option<Quat> {
+    let order = match axes {
+        "XYZ" => EulerRot::ZYX,
+        "XZY" => EulerRot::YZX,
+        "YXZ" => EulerRot::ZXY,
+        "YZX" => EulerRot::XZY,
+        "ZXY" => EulerRot::YXZ,
+        "ZYX" => EulerRot::XYZ,
+        _ => return None,
+    };
+    // `degrees` holds the angles about X, Y and Z, while `Quat::from_euler` takes them in the
+    // order of the rotations it composes, outermost first.
+    let [x, y, z] = degrees.map(f32::to_radians);
+    let angle = |axis| match axis {
+        'X' => x,
+        'Y' => y,
+        _ => z,
+    };
+    let mut axes = axes.chars().rev();
+    let [a, b, c] = [(); 3].map(|()| angle(axes.next().unwrap()));
+    Some(Quat::from_euler(order, a, b, c))
+}
+
+/// Converts a Blinn-Phong specular exponent to a perceptual roughness.
+#[cfg(any(feature = "fbx", feature = "obj"))]
+pub(crate) fn roughness_from_shininess(shininess: f32) -> f32 {
+    (2.0 / (shininess.max(0.0) + 2.0)).sqrt()
+}
+
+/// Loads the texture at `file`, relative to the directory of the asset being loaded.
+pub(crate) fn load_texture_file(
+    load_context: &mut LoadContext,
+    file: &str,
+    is_srgb: bool,
+    sampler: ImageSampler,
+) -> Handle<Image> {
+    let path = load_context.path().parent().unwrap().join(file);
+    load_context
+        .loader()
+        .with_settings(move |settings: &mut ImageLoaderSettings| {
+            settings.is_srgb = is_srgb;
+            settings.sampler = sampler.clone();
+        })
+        .load(path)
+}
+
+/// Decodes a texture embedded in the asset being loaded, whose format is guessed from the
+/// extension of `file`, and adds it as a labeled asset.
+#[cfg(feature = "fbx")]
+pub(crate) fn add_embedded_texture(
+    load_context: &mut LoadContext,
+    label: String,
+    file: &str,
+    bytes: &[u8],
+    supported_compressed_formats: CompressedImageFormats,
+    is_srgb: bool,
+    sampler: ImageSampler,
+) -> Result<Handle<Image>, TextureError> {
+    let extension = file.rsplit_once('.').map_or("", |(_, extension)| extension);
+    let image = Image::from_buffer(
+        #[cfg(all(debug_assertions, feature = "dds"))]
+        file.to_string(),
+        bytes,
+        ImageType::Extension(extension),
+        supported_compressed_formats,
+        is_srgb,
+        sampler,
+        RenderAssetUsages::default(),
+    )?;
+    Ok(load_context.add_labeled_asset(label, image))
+}
+
+/// Loads the scene at `path` from the given files with [`GltfPlugin`](crate::GltfPlugin).
+#[cfg(test)]
+pub(crate) fn load_test_scene(
+    path: &str,
+    files: &[(&str, &[u8])],
+) -> Result<(bevy_app::App, Handle<bevy_scene::Scene>), String> {
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        AssetApp, AssetPlugin, AssetServer, LoadState,
+    };
+    use bevy_log::LogPlugin;
+    use bevy_pbr::StandardMaterial;
+    use bevy_render::mesh::MeshPlugin;
+    use bevy_scene::{Scene, ScenePlugin};
+    use std::path::Path;
+
+    let dir = Dir::default();
+    for (file, bytes) in files {
+        dir.insert_asset(Path::new(file), bytes.to_vec());
+    }
+    let reader = MemoryAssetReader { root: dir };
+    let mut app = App::new();
+    app.register_asset_source(
+        AssetSourceId::Default,
+        AssetSource::build().with_reader(move || Box::new(reader.clone())),
+    )
+    .add_plugins((
+        LogPlugin::default(),
+        TaskPoolPlugin::default(),
+        AssetPlugin::default(),
+        ScenePlugin,
+        MeshPlugin,
+        crate::GltfPlugin::default(),
+    ))
+    .init_asset::<StandardMaterial>()
+    .init_asset::<Image>();
+    #[cfg(feature = "bevy_animation")]
+    app.init_asset::<bevy_animation::AnimationClip>();
+    app.finish();
+    app.cleanup();
+
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    let handle = asset_server.load::<Scene>(path.to_string());
+    for _ in 0..10000 {
+        app.update();
+        match asset_server.get_load_state(&handle).unwrap() {
+            LoadState::Loaded => return Ok((app, handle)),
+            LoadState::Failed(err) => return Err(err.to_string()),
+            _ => {}
+        }
+    }
+    panic!("Ran out of loops waiting for the scene to load");
+}
@@ -101,9 +101,25 @@ extern crate alloc;
 use bevy_animation::AnimationClip;
 use bevy_utils::HashMap;
 
+mod exporter;
+#[cfg(feature = "fbx")]
+mod fbx;
+#[cfg(any(feature = "fbx", feature = "obj", feature = "usd"))]
+mod import;
 mod loader;
+#[cfg(feature = "obj")]
+mod obj;
+#[cfg(feature = "usd")]
+mod usd;
 mod vertex_attributes;
+pub use exporter::*;
+#[cfg(feature = "fbx")]
+pub use fbx::{FbxError, FbxLoader};
 pub use loader::*;
+#[cfg(feature = "obj")]
+pub use obj::{ObjError, ObjLoader};
+#[cfg(feature = "usd")]
+pub use usd::{UsdError, UsdLoader};
 
 use bevy_app::prelude::*;
 use bevy_asset::{Asset, AssetApp, AssetPath, Handle};
@@ -160,6 +176,12 @@ impl Plugin for GltfPlugin {
             .init_asset::<GltfMesh>()
             .init_asset::<GltfSkin>()
             .preregister_asset_loader::<GltfLoader>(&["gltf", "glb"]);
+        #[cfg(feature = "fbx")]
+        app.preregister_asset_loader::<FbxLoader>(&["fbx"]);
+        #[cfg(feature = "obj")]
+        app.preregister_asset_loader::<ObjLoader>(&["obj"]);
+        #[cfg(feature = "usd")]
+        app.preregister_asset_loader::<UsdLoader>(&["usda"]);
     }
 
     fn finish(&self, app: &mut App) {
@@ -171,6 +193,28 @@ impl Plugin for GltfPlugin {
             supported_compressed_formats,
             custom_vertex_attributes: self.custom_vertex_attributes.clone(),
         });
+        #[cfg(feature = "fbx")]
+        app.register_asset_loader(FbxLoader {
+            supported_compressed_formats,
+        });
+        #[cfg(feature = "obj")]
+        app.register_asset_loader(ObjLoader);
+        #[cfg(feature = "usd")]
+        app.register_asset_loader(UsdLoader);
+
+        // Allows glTF meshes to be converted to meshlet meshes by setting this processor in their `.meta` files.
+        #[cfg(feature = "meshlet_processor")]
+        if let Some(processor) = app
+            .world()
+            .get_resource::<bevy_asset::processor::AssetProcessor>()
+        {
+            processor.register_processor::<bevy_pbr::experimental::meshlet::MeshletMeshProcessor<GltfLoader>>(
+                bevy_asset::processor::LoadTransformAndSave::new(
+                    bevy_pbr::experimental::meshlet::MeshletMeshTransformer::default(),
+                    bevy_pbr::experimental::meshlet::MeshletMeshSaver,
+                ),
+            );
+        }
     }
 }
 
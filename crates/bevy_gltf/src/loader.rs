@@ -1097,23 +1097,38 @@ fn load_material(
         let color = pbr.base_color_factor();
         let base_color_channel = pbr
             .base_color_texture()
-            .map(|info| get_uv_channel(material, "base color", info.tex_coord()))
+            .map(|info| get_uv_channel(material, "base color", tex_coord(&info)))
             .unwrap_or_default();
         let base_color_texture = pbr
             .base_color_texture()
             .map(|info| texture_handle(load_context, &info.texture()));
 
-        let uv_transform = pbr
-            .base_color_texture()
-            .and_then(|info| {
-                info.texture_transform()
-                    .map(convert_texture_transform_to_affine2)
-            })
+        // `StandardMaterial` applies a single UV transform to all of its textures, so use the
+        // one of the first texture and warn about the textures with a different one.
+        let texture_transforms = material_texture_transforms(material, document);
+        let uv_transform = texture_transforms
+            .first()
+            .map(|(_, _, transform)| *transform)
             .unwrap_or_default();
+        for (texture_kind, texture, transform) in texture_transforms.iter().skip(1) {
+            warn_on_differing_texture_transforms(
+                material,
+                texture,
+                *transform,
+                uv_transform,
+                texture_kind,
+            );
+        }
 
         let normal_map_channel = material
             .normal_texture()
-            .map(|info| get_uv_channel(material, "normal map", info.tex_coord()))
+            .map(|info| {
+                let tex_coord =
+                    json_texture_transform(info.extension_value("KHR_texture_transform"))
+                        .and_then(|transform| transform.tex_coord)
+                        .unwrap_or(info.tex_coord());
+                get_uv_channel(material, "normal map", tex_coord)
+            })
             .unwrap_or_default();
         let normal_map_texture: Option<Handle<Image>> =
             material.normal_texture().map(|normal_texture| {
@@ -1123,21 +1138,21 @@ fn load_material(
 
         let metallic_roughness_channel = pbr
             .metallic_roughness_texture()
-            .map(|info| get_uv_channel(material, "metallic/roughness", info.tex_coord()))
+            .map(|info| get_uv_channel(material, "metallic/roughness", tex_coord(&info)))
             .unwrap_or_default();
-        let metallic_roughness_texture = pbr.metallic_roughness_texture().map(|info| {
-            warn_on_differing_texture_transforms(
-                material,
-                &info,
-                uv_transform,
-                "metallic/roughness",
-            );
-            texture_handle(load_context, &info.texture())
-        });
+        let metallic_roughness_texture = pbr
+            .metallic_roughness_texture()
+            .map(|info| texture_handle(load_context, &info.texture()));
 
         let occlusion_channel = material
             .occlusion_texture()
-            .map(|info| get_uv_channel(material, "occlusion", info.tex_coord()))
+            .map(|info| {
+                let tex_coord =
+                    json_texture_transform(info.extension_value("KHR_texture_transform"))
+                        .and_then(|transform| transform.tex_coord)
+                        .unwrap_or(info.tex_coord());
+                get_uv_channel(material, "occlusion", tex_coord)
+            })
             .unwrap_or_default();
         let occlusion_texture = material.occlusion_texture().map(|occlusion_texture| {
             // TODO: handle occlusion_texture.strength() (a scalar multiplier for occlusion strength)
@@ -1147,13 +1162,11 @@ fn load_material(
         let emissive = material.emissive_factor();
         let emissive_channel = material
             .emissive_texture()
-            .map(|info| get_uv_channel(material, "emissive", info.tex_coord()))
+            .map(|info| get_uv_channel(material, "emissive", tex_coord(&info)))
             .unwrap_or_default();
-        let emissive_texture = material.emissive_texture().map(|info| {
-            // TODO: handle occlusion_texture.strength() (a scalar multiplier for occlusion strength)
-            warn_on_differing_texture_transforms(material, &info, uv_transform, "emissive");
-            texture_handle(load_context, &info.texture())
-        });
+        let emissive_texture = material
+            .emissive_texture()
+            .map(|info| texture_handle(load_context, &info.texture()));
 
         #[cfg(feature = "pbr_transmission_textures")]
         let (specular_transmission, specular_transmission_channel, specular_transmission_texture) =
@@ -1163,7 +1176,7 @@ fn load_material(
                     let specular_transmission_channel = transmission
                         .transmission_texture()
                         .map(|info| {
-                            get_uv_channel(material, "specular/transmission", info.tex_coord())
+                            get_uv_channel(material, "specular/transmission", tex_coord(&info))
                         })
                         .unwrap_or_default();
                     let transmission_texture: Option<Handle<Image>> = transmission
@@ -1196,7 +1209,7 @@ fn load_material(
             |volume| {
                 let thickness_channel = volume
                     .thickness_texture()
-                    .map(|info| get_uv_channel(material, "thickness", info.tex_coord()))
+                    .map(|info| get_uv_channel(material, "thickness", tex_coord(&info)))
                     .unwrap_or_default();
                 let thickness_texture: Option<Handle<Image>> =
                     volume.thickness_texture().map(|thickness_texture| {
@@ -1235,6 +1248,12 @@ fn load_material(
         let anisotropy =
             AnisotropyExtension::parse(load_context, document, material).unwrap_or_default();
 
+        // Parse the `KHR_materials_sheen` extension data if necessary.
+        let sheen = SheenExtension::parse(material).unwrap_or_default();
+
+        // Parse the `KHR_materials_iridescence` extension data if necessary.
+        let iridescence = IridescenceExtension::parse(material).unwrap_or_default();
+
         // We need to operate in the Linear color space and be willing to exceed 1.0 in our channels
         let base_emissive = LinearRgba::rgb(emissive[0], emissive[1], emissive[2]);
         let emissive = base_emissive * material.emissive_strength().unwrap_or(1.0);
@@ -1297,6 +1316,12 @@ fn load_material(
             clearcoat_normal_channel: clearcoat.clearcoat_normal_channel,
             #[cfg(feature = "pbr_multi_layer_material_textures")]
             clearcoat_normal_texture: clearcoat.clearcoat_normal_texture,
+            sheen_color: Color::from(sheen.sheen_color()),
+            sheen_perceptual_roughness: sheen.sheen_roughness_factor.unwrap_or_default() as f32,
+            iridescence: iridescence.iridescence_factor.unwrap_or_default() as f32,
+            iridescence_ior: iridescence.iridescence_ior.unwrap_or(1.3) as f32,
+            iridescence_thickness: iridescence.iridescence_thickness_maximum.unwrap_or(400.0)
+                as f32,
             anisotropy_strength: anisotropy.anisotropy_strength.unwrap_or_default() as f32,
             anisotropy_rotation: anisotropy.anisotropy_rotation.unwrap_or_default() as f32,
             #[cfg(feature = "pbr_anisotropy_texture")]
@@ -1331,6 +1356,35 @@ fn get_uv_channel(material: &Material, texture_kind: &str, tex_coord: u32) -> Uv
     }
 }
 
+/// Returns the UV channel of a texture, which `KHR_texture_transform` may override.
+fn tex_coord(info: &Info) -> u32 {
+    info.texture_transform()
+        .and_then(|transform| transform.tex_coord())
+        .unwrap_or(info.tex_coord())
+}
+
+/// Returns the UV channel of a texture referenced by a material extension, which
+/// `KHR_texture_transform` may override.
+#[cfg(any(
+    feature = "pbr_anisotropy_texture",
+    feature = "pbr_multi_layer_material_textures"
+))]
+fn json_tex_coord(info: &json::texture::Info) -> u32 {
+    info.extensions
+        .as_ref()
+        .and_then(|extensions| extensions.texture_transform.as_ref())
+        .and_then(|transform| transform.tex_coord)
+        .unwrap_or(info.tex_coord)
+}
+
+/// Parses the `KHR_texture_transform` extension data of a texture for which the `gltf` crate
+/// doesn't expose it, such as normal and occlusion textures.
+fn json_texture_transform(
+    extension: Option<&Value>,
+) -> Option<json::extensions::texture::TextureTransform> {
+    value::from_value(extension?.clone()).ok()
+}
+
 fn convert_texture_transform_to_affine2(texture_transform: TextureTransform) -> Affine2 {
     Affine2::from_scale_angle_translation(
         texture_transform.scale().into(),
@@ -1339,23 +1393,133 @@ fn convert_texture_transform_to_affine2(texture_transform: TextureTransform) ->
     )
 }
 
+fn convert_json_texture_transform_to_affine2(
+    texture_transform: &json::extensions::texture::TextureTransform,
+) -> Affine2 {
+    Affine2::from_scale_angle_translation(
+        texture_transform.scale.0.into(),
+        -texture_transform.rotation.0,
+        texture_transform.offset.0.into(),
+    )
+}
+
+/// Returns the textures of a material along with their kind and UV transform, in the order
+/// in which the UV transform of the material is picked.
+fn material_texture_transforms<'a>(
+    material: &Material<'a>,
+    document: &'a Document,
+) -> Vec<(&'static str, gltf::Texture<'a>, Affine2)> {
+    let pbr = material.pbr_metallic_roughness();
+    let info_transform = |texture_kind, info: Info<'a>| {
+        let transform = info
+            .texture_transform()
+            .map(convert_texture_transform_to_affine2)
+            .unwrap_or_default();
+        (texture_kind, info.texture(), transform)
+    };
+    let json_transform = |texture_kind, texture, extension: Option<&Value>| {
+        let transform = json_texture_transform(extension)
+            .as_ref()
+            .map(convert_json_texture_transform_to_affine2)
+            .unwrap_or_default();
+        (texture_kind, texture, transform)
+    };
+
+    let mut texture_transforms = Vec::new();
+    texture_transforms.extend(
+        pbr.base_color_texture()
+            .map(|info| info_transform("base color", info)),
+    );
+    texture_transforms.extend(
+        pbr.metallic_roughness_texture()
+            .map(|info| info_transform("metallic/roughness", info)),
+    );
+    texture_transforms.extend(material.normal_texture().map(|info| {
+        json_transform(
+            "normal map",
+            info.texture(),
+            info.extension_value("KHR_texture_transform"),
+        )
+    }));
+    texture_transforms.extend(material.occlusion_texture().map(|info| {
+        json_transform(
+            "occlusion",
+            info.texture(),
+            info.extension_value("KHR_texture_transform"),
+        )
+    }));
+    texture_transforms.extend(
+        material
+            .emissive_texture()
+            .map(|info| info_transform("emissive", info)),
+    );
+    texture_transforms.extend(
+        material
+            .transmission()
+            .and_then(|transmission| transmission.transmission_texture())
+            .map(|info| info_transform("specular/transmission", info)),
+    );
+    texture_transforms.extend(
+        material
+            .volume()
+            .and_then(|volume| volume.thickness_texture())
+            .map(|info| info_transform("thickness", info)),
+    );
+
+    for (extension_name, texture_kind, texture_field_name) in [
+        ("KHR_materials_clearcoat", "clearcoat", "clearcoatTexture"),
+        (
+            "KHR_materials_clearcoat",
+            "clearcoat roughness",
+            "clearcoatRoughnessTexture",
+        ),
+        (
+            "KHR_materials_clearcoat",
+            "clearcoat normal",
+            "clearcoatNormalTexture",
+        ),
+        (
+            "KHR_materials_anisotropy",
+            "anisotropy",
+            "anisotropyTexture",
+        ),
+    ] {
+        let Some(json_info) = material
+            .extensions()
+            .and_then(|extensions| extensions.get(extension_name))
+            .and_then(|extension| extension.get(texture_field_name))
+            .and_then(|value| value::from_value::<json::texture::Info>(value.clone()).ok())
+        else {
+            continue;
+        };
+        let Some(texture) = document.textures().nth(json_info.index.value()) else {
+            continue;
+        };
+        let transform = json_info
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.texture_transform.as_ref())
+            .map(convert_json_texture_transform_to_affine2)
+            .unwrap_or_default();
+        texture_transforms.push((texture_kind, texture, transform));
+    }
+
+    texture_transforms
+}
+
 fn warn_on_differing_texture_transforms(
     material: &Material,
-    info: &Info,
+    texture: &gltf::Texture,
     texture_transform: Affine2,
+    material_transform: Affine2,
     texture_kind: &str,
 ) {
-    let has_differing_texture_transform = info
-        .texture_transform()
-        .map(convert_texture_transform_to_affine2)
-        .is_some_and(|t| t != texture_transform);
-    if has_differing_texture_transform {
+    if texture_transform != material_transform {
         let material_name = material
             .name()
             .map(|n| format!("the material \"{n}\""))
             .unwrap_or_else(|| "an unnamed material".to_string());
-        let texture_name = info
-            .texture()
+        let texture_name = texture
             .name()
             .map(|n| format!("its {texture_kind} texture \"{n}\""))
             .unwrap_or_else(|| format!("its unnamed {texture_kind} texture"));
@@ -1364,8 +1528,8 @@ fn warn_on_differing_texture_transforms(
             .map(|i| format!("index {i}"))
             .unwrap_or_else(|| "default".to_string());
         warn!(
-            "Only texture transforms on base color textures are supported, but {material_name} ({material_index}) \
-            has a texture transform on {texture_name} (index {}), which will be ignored.", info.texture().index()
+            "Only a single texture transform per material is supported, but {material_name} ({material_index}) \
+            has a different texture transform on {texture_name} (index {}), which will be ignored.", texture.index()
         );
     }
 }
@@ -2127,7 +2291,7 @@ impl ClearcoatExtension {
             .and_then(|value| value::from_value::<json::texture::Info>(value.clone()).ok())
             .map(|json_info| {
                 (
-                    get_uv_channel(material, "clearcoat", json_info.tex_coord),
+                    get_uv_channel(material, "clearcoat", json_tex_coord(&json_info)),
                     texture_handle_from_info(load_context, document, &json_info),
                 )
             })
@@ -2139,7 +2303,7 @@ impl ClearcoatExtension {
             .and_then(|value| value::from_value::<json::texture::Info>(value.clone()).ok())
             .map(|json_info| {
                 (
-                    get_uv_channel(material, "clearcoat roughness", json_info.tex_coord),
+                    get_uv_channel(material, "clearcoat roughness", json_tex_coord(&json_info)),
                     texture_handle_from_info(load_context, document, &json_info),
                 )
             })
@@ -2151,7 +2315,7 @@ impl ClearcoatExtension {
             .and_then(|value| value::from_value::<json::texture::Info>(value.clone()).ok())
             .map(|json_info| {
                 (
-                    get_uv_channel(material, "clearcoat normal", json_info.tex_coord),
+                    get_uv_channel(material, "clearcoat normal", json_tex_coord(&json_info)),
                     texture_handle_from_info(load_context, document, &json_info),
                 )
             })
@@ -2217,7 +2381,7 @@ impl AnisotropyExtension {
             .and_then(|value| value::from_value::<json::texture::Info>(value.clone()).ok())
             .map(|json_info| {
                 (
-                    get_uv_channel(material, "anisotropy", json_info.tex_coord),
+                    get_uv_channel(material, "anisotropy", json_tex_coord(&json_info)),
                     texture_handle_from_info(load_context, document, &json_info),
                 )
             })
@@ -2234,6 +2398,109 @@ impl AnisotropyExtension {
     }
 }
 
+/// Parsed data from the `KHR_materials_sheen` extension.
+///
+/// See the specification:
+/// <https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Khronos/KHR_materials_sheen/README.md>
+#[derive(Default)]
+struct SheenExtension {
+    sheen_color_factor: Option<[f32; 3]>,
+    sheen_roughness_factor: Option<f64>,
+}
+
+impl SheenExtension {
+    fn parse(material: &Material) -> Option<SheenExtension> {
+        let extension = material
+            .extensions()?
+            .get("KHR_materials_sheen")?
+            .as_object()?;
+
+        warn_on_unsupported_extension_textures(
+            material,
+            "KHR_materials_sheen",
+            extension,
+            &["sheenColorTexture", "sheenRoughnessTexture"],
+        );
+
+        Some(SheenExtension {
+            sheen_color_factor: extension
+                .get("sheenColorFactor")
+                .and_then(|value| value::from_value(value.clone()).ok()),
+            sheen_roughness_factor: extension
+                .get("sheenRoughnessFactor")
+                .and_then(Value::as_f64),
+        })
+    }
+
+    fn sheen_color(&self) -> LinearRgba {
+        let [red, green, blue] = self.sheen_color_factor.unwrap_or_default();
+        LinearRgba::rgb(red, green, blue)
+    }
+}
+
+/// Parsed data from the `KHR_materials_iridescence` extension.
+///
+/// See the specification:
+/// <https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Khronos/KHR_materials_iridescence/README.md>
+#[derive(Default)]
+struct IridescenceExtension {
+    iridescence_factor: Option<f64>,
+    iridescence_ior: Option<f64>,
+    /// Without a thickness texture, the thin film has its maximum thickness
+    /// everywhere.
+    iridescence_thickness_maximum: Option<f64>,
+}
+
+impl IridescenceExtension {
+    fn parse(material: &Material) -> Option<IridescenceExtension> {
+        let extension = material
+            .extensions()?
+            .get("KHR_materials_iridescence")?
+            .as_object()?;
+
+        warn_on_unsupported_extension_textures(
+            material,
+            "KHR_materials_iridescence",
+            extension,
+            &["iridescenceTexture", "iridescenceThicknessTexture"],
+        );
+
+        Some(IridescenceExtension {
+            iridescence_factor: extension.get("iridescenceFactor").and_then(Value::as_f64),
+            iridescence_ior: extension.get("iridescenceIor").and_then(Value::as_f64),
+            iridescence_thickness_maximum: extension
+                .get("iridescenceThicknessMaximum")
+                .and_then(Value::as_f64),
+        })
+    }
+}
+
+/// Warns about the textures of a material extension that `StandardMaterial` has no
+/// counterpart for, and which are ignored.
+fn warn_on_unsupported_extension_textures(
+    material: &Material,
+    extension_name: &str,
+    extension: &serde_json::Map<String, Value>,
+    texture_field_names: &[&str],
+) {
+    for texture_field_name in texture_field_names {
+        if extension.contains_key(*texture_field_name) {
+            let material_name = material
+                .name()
+                .map(|n| format!("the material \"{n}\""))
+                .unwrap_or_else(|| "an unnamed material".to_string());
+            let material_index = material
+                .index()
+                .map(|i| format!("index {i}"))
+                .unwrap_or_else(|| "default".to_string());
+            warn!(
+                "{material_name} ({material_index}) has a {texture_field_name} in {extension_name}, \
+                which is not supported and will be ignored."
+            );
+        }
+    }
+}
+
 /// Returns the index (within the `textures` array) of the texture with the
 /// given field name in the data for the material extension with the given name,
 /// if there is one.
@@ -2293,8 +2560,10 @@ mod test {
         },
         AssetApp, AssetPlugin, AssetServer, Assets, Handle, LoadState,
     };
+    use bevy_color::Color;
     use bevy_ecs::{system::Resource, world::World};
     use bevy_log::LogPlugin;
+    use bevy_pbr::StandardMaterial;
     use bevy_render::mesh::{skinning::SkinnedMeshInverseBindposes, MeshPlugin};
     use bevy_scene::ScenePlugin;
 
@@ -2312,7 +2581,8 @@ mod test {
             ScenePlugin,
             MeshPlugin,
             crate::GltfPlugin::default(),
-        ));
+        ))
+        .init_asset::<StandardMaterial>();
 
         app.finish();
         app.cleanup();
@@ -2713,4 +2983,58 @@ mod test {
         assert_eq!(skinned_node.children.len(), 2);
         assert_eq!(skinned_node.skin.as_ref(), Some(&gltf_root.skins[0]));
     }
+
+    #[test]
+    fn material_extensions() {
+        let gltf_path = "test.gltf";
+        let app = load_gltf_into_app(
+            gltf_path,
+            r#"
+{
+    "asset": {
+        "version": "2.0"
+    },
+    "extensionsUsed": ["KHR_materials_sheen", "KHR_materials_iridescence"],
+    "materials": [
+        {
+            "name": "velvet",
+            "extensions": {
+                "KHR_materials_sheen": {
+                    "sheenColorFactor": [0.5, 0.25, 1.0],
+                    "sheenRoughnessFactor": 0.75
+                }
+            }
+        },
+        {
+            "name": "soap",
+            "extensions": {
+                "KHR_materials_iridescence": {
+                    "iridescenceFactor": 1.0,
+                    "iridescenceIor": 1.4,
+                    "iridescenceThicknessMaximum": 300
+                }
+            }
+        }
+    ],
+    "scene": 0,
+    "scenes": [{ "nodes": [] }]
+}
+"#,
+        );
+        let asset_server = app.world().resource::<AssetServer>();
+        let handle = asset_server.load(gltf_path);
+        let gltf_root = app.world().resource::<Assets<Gltf>>().get(&handle).unwrap();
+        let materials = app.world().resource::<Assets<StandardMaterial>>();
+
+        let velvet = materials.get(&gltf_root.named_materials["velvet"]).unwrap();
+        assert_eq!(velvet.sheen_color, Color::linear_rgb(0.5, 0.25, 1.0));
+        assert_eq!(velvet.sheen_perceptual_roughness, 0.75);
+        assert_eq!(velvet.iridescence, 0.0);
+
+        let soap = materials.get(&gltf_root.named_materials["soap"]).unwrap();
+        assert_eq!(soap.sheen_color, Color::BLACK);
+        assert_eq!(soap.iridescence, 1.0);
+        assert_eq!(soap.iridescence_ior, 1.4);
+        assert_eq!(soap.iridescence_thickness, 300.0);
+    }
 }
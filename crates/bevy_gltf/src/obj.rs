@@ -0,0 +1,705 @@
+//! Loading of Wavefront OBJ files, with the materials of their MTL libraries.
+
+use std::path::Path;
+
+use bevy_asset::{io::Reader, AssetLoader, Handle, LoadContext};
+use bevy_color::{Alpha, Color, ColorToComponents, LinearRgba};
+use bevy_ecs::{name::Name, world::World};
+use bevy_hierarchy::{BuildChildren, ChildBuild};
+use bevy_image::ImageSampler;
+use bevy_math::{Vec2, Vec3};
+use bevy_pbr::{MeshMaterial3d, StandardMaterial};
+use bevy_render::{
+    alpha::AlphaMode,
+    mesh::{Indices, Mesh, Mesh3d},
+    render_asset::RenderAssetUsages,
+    render_resource::PrimitiveTopology,
+    view::Visibility,
+};
+use bevy_scene::Scene;
+use bevy_transform::components::Transform;
+use bevy_utils::HashMap;
+use thiserror::Error;
+use tracing::warn;
+
+use crate::{
+    import::{flat_normals, load_texture_file, roughness_from_shininess},
+    GltfAssetLabel,
+};
+
+/// An error that occurs when loading an OBJ file.
+#[derive(Error, Debug)]
+pub enum ObjError {
+    /// Failed to read the file.
+    #[error("failed to read OBJ file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A statement of the file is malformed.
+    #[error("invalid OBJ file at line {line}: {message}")]
+    Parse {
+        /// The line the error was found at.
+        line: usize,
+        /// A description of the error.
+        message: String,
+    },
+    /// The file is not valid UTF-8.
+    #[error("OBJ file is not valid UTF-8: {0}")]
+    Utf8(#[from] core::str::Utf8Error),
+}
+
+/// Loads Wavefront OBJ files (`.obj`) as a [`Scene`].
+///
+/// Each object (`o`) or group (`g`) of the file becomes an entity with a [`Name`], with a child
+/// holding a [`Mesh3d`] and a [`MeshMaterial3d`] for each material used by its faces. Meshes and
+/// materials are added as the `Mesh{n}` and `Material{n}` labeled assets, numbered in the order
+/// they are found, and faces without a material use the `DefaultMaterial`.
+///
+/// Materials are read from the MTL libraries named by `mtllib`, relative to the OBJ file. Their
+/// colors are in sRGB, and the PBR extension (`Pr`, `Pm`, `Pc`, `Pcr`) is supported alongside the
+/// classic parameters. Diffuse, emissive, normal (`norm` or `map_Bump`) and dissolve (`map_d`)
+/// textures are loaded, but their options, such as `-s` and `-o`, are ignored.
+pub struct ObjLoader;
+
+impl AssetLoader for ObjLoader {
+    type Asset = Scene;
+    type Settings = ();
+    type Error = ObjError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Scene, ObjError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let default_name = load_context
+            .path()
+            .file_stem()
+            .map_or("Object".into(), |stem| stem.to_string_lossy().into_owned());
+        let obj = parse_obj(core::str::from_utf8(&bytes)?, default_name)?;
+
+        let mut materials = HashMap::default();
+        for library in &obj.material_libraries {
+            let path = load_context.path().parent().unwrap().join(library);
+            let source = match load_context.read_asset_bytes(path.as_path()).await {
+                Ok(source) => source,
+                Err(err) => {
+                    warn!("Failed to read the material library {library}: {err}");
+                    continue;
+                }
+            };
+            let directory = Path::new(library).parent().unwrap_or(Path::new(""));
+            match core::str::from_utf8(&source) {
+                Ok(source) => parse_mtl(source, directory, &mut materials),
+                Err(err) => warn!("Material library {library} is not valid UTF-8: {err}"),
+            }
+        }
+
+        Ok(SceneBuilder {
+            load_context,
+            materials,
+            loaded_materials: HashMap::default(),
+            mesh_count: 0,
+        }
+        .build(&obj))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["obj"]
+    }
+}
+
+/// A corner of a face: the indices of its position, texture coordinates and normal.
+type Corner = (usize, Option<usize>, Option<usize>);
+
+#[derive(Default)]
+struct Obj {
+    positions: Vec<Vec3>,
+    /// The colors following the positions, in an extension supported by many exporters.
+    colors: Vec<Option<[f32; 3]>>,
+    tex_coords: Vec<Vec2>,
+    normals: Vec<Vec3>,
+    objects: Vec<Object>,
+    material_libraries: Vec<String>,
+}
+
+struct Object {
+    name: String,
+    groups: Vec<Group>,
+}
+
+/// The faces of an object using the same material.
+struct Group {
+    material: Option<String>,
+    triangles: Vec<[Corner; 3]>,
+}
+
+fn parse_obj(source: &str, default_name: String) -> Result<Obj, ObjError> {
+    let mut obj = Obj::default();
+    let mut current_object = default_name;
+    let mut current_material = None;
+    for (line_index, line) in logical_lines(source) {
+        let error = |message: String| ObjError::Parse {
+            line: line_index + 1,
+            message,
+        };
+        let mut words = line.split_whitespace();
+        let Some(keyword) = words.next() else {
+            continue;
+        };
+        let mut floats = || -> Result<Vec<f32>, ObjError> {
+            words
+                .by_ref()
+                .map(|word| {
+                    word.parse()
+                        .map_err(|_| error(format!("invalid number `{word}`")))
+                })
+                .collect()
+        };
+        match keyword {
+            "v" => {
+                let values = floats()?;
+                let [x, y, z, ref rest @ ..] = values[..] else {
+                    return Err(error("vertex positions need 3 coordinates".into()));
+                };
+                obj.positions.push(Vec3::new(x, y, z));
+                obj.colors.push(match *rest {
+                    [r, g, b] => Some([r, g, b]),
+                    _ => None,
+                });
+            }
+            "vt" => {
+                let values = floats()?;
+                let (&u, v) = values
+                    .split_first()
+                    .ok_or_else(|| error("texture coordinates need a coordinate".into()))?;
+                // OBJ texture coordinates start at the bottom of the image.
+                obj.tex_coords
+                    .push(Vec2::new(u, 1.0 - v.first().copied().unwrap_or(0.0)));
+            }
+            "vn" => {
+                let [x, y, z] = floats()?[..] else {
+                    return Err(error("normals need 3 coordinates".into()));
+                };
+                obj.normals.push(Vec3::new(x, y, z));
+            }
+            "f" => {
+                let corners = words
+                    .map(|word| parse_corner(word, &obj).map_err(&error))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if corners.len() < 3 {
+                    return Err(error("faces need at least 3 vertices".into()));
+                }
+                let group = current_group(&mut obj, &current_object, &current_material);
+                // Fan-triangulate the face.
+                for i in 1..corners.len() - 1 {
+                    group
+                        .triangles
+                        .push([corners[0], corners[i], corners[i + 1]]);
+                }
+            }
+            "o" | "g" => {
+                let name = words.collect::<Vec<_>>().join(" ");
+                if !name.is_empty() {
+                    current_object = name;
+                }
+            }
+            "usemtl" => current_material = Some(words.collect::<Vec<_>>().join(" ")),
+            "mtllib" => obj
+                .material_libraries
+                .extend(words.map(ToString::to_string)),
+            // Smoothing groups, lines, points and free-form geometry are ignored.
+            _ => {}
+        }
+    }
+    Ok(obj)
+}
+
+/// Returns the lines of a file with their index, joining lines ending with a backslash and
+/// removing comments.
+fn logical_lines(source: &str) -> impl Iterator<Item = (usize, String)> + '_ {
+    let mut lines = source.lines().enumerate();
+    core::iter::from_fn(move || {
+        let (index, first) = lines.next()?;
+        let mut line = String::from(first);
+        while line.ends_with('\\') {
+            line.pop();
+            match lines.next() {
+                Some((_, next)) => line.push_str(next),
+                None => break,
+            }
+        }
+        if let Some(comment) = line.find('#') {
+            line.truncate(comment);
+        }
+        Some((index, line))
+    })
+}
+
+/// Parses a corner of a face, such as `1/2/3`, `1//3` or `-1`.
+fn parse_corner(word: &str, obj: &Obj) -> Result<Corner, String> {
+    let mut indices = word.split('/');
+    let mut index = |count: usize, optional: bool| -> Result<Option<usize>, String> {
+        let index = match indices.next() {
+            Some("") | None if optional => return Ok(None),
+            Some(index) => index,
+            None => return Err(format!("invalid face vertex `{word}`")),
+        };
+        let index: isize = index
+            .parse()
+            .map_err(|_| format!("invalid face vertex `{word}`"))?;
+        // Indices start at 1, and negative indices are relative to the end.
+        let resolved = if index < 0 {
+            count.checked_sub(index.unsigned_abs())
+        } else {
+            (index as usize).checked_sub(1)
+        };
+        match resolved {
+            Some(resolved) if resolved < count => Ok(Some(resolved)),
+            _ => Err(format!("face vertex `{word}` is out of range")),
+        }
+    };
+    let position = index(obj.positions.len(), false)?.unwrap();
+    let tex_coord = index(obj.tex_coords.len(), true)?;
+    let normal = index(obj.normals.len(), true)?;
+    Ok((position, tex_coord, normal))
+}
+
+fn current_group<'a>(
+    obj: &'a mut Obj,
+    object_name: &str,
+    material: &Option<String>,
+) -> &'a mut Group {
+    if obj
+        .objects
+        .last()
+        .is_none_or(|object| object.name != object_name)
+    {
+        obj.objects.push(Object {
+            name: object_name.to_string(),
+            groups: Vec::new(),
+        });
+    }
+    let object = obj.objects.last_mut().unwrap();
+    let index = match object
+        .groups
+        .iter()
+        .position(|group| &group.material == material)
+    {
+        Some(index) => index,
+        None => {
+            object.groups.push(Group {
+                material: material.clone(),
+                triangles: Vec::new(),
+            });
+            object.groups.len() - 1
+        }
+    };
+    &mut object.groups[index]
+}
+
+/// A material of a MTL library.
+#[derive(Default)]
+struct MtlMaterial {
+    diffuse: Option<[f32; 3]>,
+    emissive: Option<[f32; 3]>,
+    shininess: Option<f32>,
+    dissolve: Option<f32>,
+    ior: Option<f32>,
+    roughness: Option<f32>,
+    metallic: Option<f32>,
+    clearcoat: Option<f32>,
+    clearcoat_roughness: Option<f32>,
+    diffuse_map: Option<String>,
+    emissive_map: Option<String>,
+    normal_map: Option<String>,
+    dissolve_map: Option<String>,
+}
+
+fn parse_mtl(source: &str, directory: &Path, materials: &mut HashMap<String, MtlMaterial>) {
+    let mut current = None;
+    for (line_index, line) in logical_lines(source) {
+        let mut words = line.split_whitespace();
+        let Some(keyword) = words.next() else {
+            continue;
+        };
+        if keyword == "newmtl" {
+            let name = words.collect::<Vec<_>>().join(" ");
+            materials.insert(name.clone(), MtlMaterial::default());
+            current = Some(name);
+            continue;
+        }
+        let Some(material) = current.as_ref().and_then(|name| materials.get_mut(name)) else {
+            continue;
+        };
+        let arguments = words.collect::<Vec<_>>();
+        let float = || arguments.first().and_then(|word| word.parse::<f32>().ok());
+        let color = || match arguments[..] {
+            [r, g, b, ..] => Some([r.parse().ok()?, g.parse().ok()?, b.parse().ok()?]),
+            [gray] => gray.parse().ok().map(|gray| [gray; 3]),
+            _ => None,
+        };
+        let map = || texture_path(&arguments, directory);
+        match keyword {
+            "Kd" => material.diffuse = color(),
+            "Ke" => material.emissive = color(),
+            "Ns" => material.shininess = float(),
+            "d" => material.dissolve = float(),
+            "Tr" => material.dissolve = float().map(|transparency| 1.0 - transparency),
+            "Ni" => material.ior = float(),
+            "Pr" => material.roughness = float(),
+            "Pm" => material.metallic = float(),
+            "Pc" => material.clearcoat = float(),
+            "Pcr" => material.clearcoat_roughness = float(),
+            "map_Kd" => material.diffuse_map = map(),
+            "map_Ke" => material.emissive_map = map(),
+            "norm" | "map_Bump" | "map_bump" | "bump" => material.normal_map = map(),
+            "map_d" => material.dissolve_map = map(),
+            keyword if keyword.starts_with("map_") || keyword == "disp" || keyword == "refl" => {
+                warn!(
+                    "Ignoring the unsupported texture `{keyword}` at line {} of a material library",
+                    line_index + 1
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the file of a texture statement, relative to the OBJ file, skipping its options.
+fn texture_path(arguments: &[&str], directory: &Path) -> Option<String> {
+    let mut index = 0;
+    while let Some(option) = arguments.get(index).and_then(|word| word.strip_prefix('-')) {
+        index += 1;
+        let values = match option {
+            "o" | "s" | "t" => 3,
+            "mm" => 2,
+            _ => 1,
+        };
+        // Optional values of `-o`, `-s` and `-t` are numbers.
+        for _ in 0..values {
+            match arguments.get(index) {
+                Some(word) if values == 1 || word.parse::<f32>().is_ok() => index += 1,
+                _ => break,
+            }
+        }
+    }
+    let file = arguments.get(index..)?.join(" ").replace('\\', "/");
+    if file.is_empty() {
+        return None;
+    }
+    Some(directory.join(file).to_string_lossy().into_owned())
+}
+
+struct SceneBuilder<'a, 'b> {
+    load_context: &'a mut LoadContext<'b>,
+    materials: HashMap<String, MtlMaterial>,
+    /// Materials by name, with whether they have a normal map.
+    loaded_materials: HashMap<Option<String>, (Handle<StandardMaterial>, bool)>,
+    mesh_count: usize,
+}
+
+impl SceneBuilder<'_, '_> {
+    fn build(mut self, obj: &Obj) -> Scene {
+        let mut world = World::default();
+        world
+            .spawn((Transform::default(), Visibility::default()))
+            .with_children(|parent| {
+                for object in &obj.objects {
+                    parent
+                        .spawn((
+                            Name::new(object.name.clone()),
+                            Transform::default(),
+                            Visibility::default(),
+                        ))
+                        .with_children(|parent| {
+                            for group in &object.groups {
+                                let (material, has_normal_map) =
+                                    self.load_material(&group.material);
+                                let mesh = build_mesh(obj, group, has_normal_map);
+                                let label = format!("Mesh{}", self.mesh_count);
+                                self.mesh_count += 1;
+                                let mesh = self.load_context.add_labeled_asset(label, mesh);
+                                let name = match &group.material {
+                                    Some(material) => format!("{}.{material}", object.name),
+                                    None => object.name.clone(),
+                                };
+                                parent.spawn((
+                                    Name::new(name),
+                                    Mesh3d(mesh),
+                                    MeshMaterial3d(material),
+                                    Transform::default(),
+                                    Visibility::default(),
+                                ));
+                            }
+                        });
+                }
+            });
+        Scene::new(world)
+    }
+
+    fn load_material(&mut self, name: &Option<String>) -> (Handle<StandardMaterial>, bool) {
+        if let Some(loaded) = self.loaded_materials.get(name) {
+            return loaded.clone();
+        }
+        let mtl = name.as_ref().and_then(|name| {
+            let material = self.materials.get(name);
+            if material.is_none() {
+                warn!("Material {name} isn't defined by the material libraries");
+            }
+            material
+        });
+        let Some(mtl) = mtl else {
+            let handle = self.load_context.add_labeled_asset(
+                GltfAssetLabel::DefaultMaterial.to_string(),
+                StandardMaterial::default(),
+            );
+            self.loaded_materials
+                .insert(name.clone(), (handle.clone(), false));
+            return (handle, false);
+        };
+
+        let srgb = |[r, g, b]: [f32; 3]| Color::srgb(r, g, b);
+        let alpha = mtl.dissolve.unwrap_or(1.0);
+        let mut material = StandardMaterial {
+            base_color: srgb(mtl.diffuse.unwrap_or([1.0; 3])).with_alpha(alpha),
+            emissive: mtl
+                .emissive
+                .map_or(LinearRgba::BLACK, |emissive| srgb(emissive).to_linear()),
+            perceptual_roughness: mtl
+                .roughness
+                .or(mtl.shininess.map(roughness_from_shininess))
+                .unwrap_or(0.5),
+            metallic: mtl.metallic.unwrap_or(0.0),
+            ior: mtl.ior.unwrap_or(1.5),
+            clearcoat: mtl.clearcoat.unwrap_or(0.0),
+            clearcoat_perceptual_roughness: mtl.clearcoat_roughness.unwrap_or(0.5),
+            ..Default::default()
+        };
+        if alpha < 1.0 {
+            material.alpha_mode = AlphaMode::Blend;
+        }
+
+        let mut load = |file: &Option<String>, is_srgb| {
+            file.as_ref().map(|file| {
+                load_texture_file(self.load_context, file, is_srgb, ImageSampler::Default)
+            })
+        };
+        material.base_color_texture = load(&mtl.diffuse_map, true);
+        material.emissive_texture = load(&mtl.emissive_map, true);
+        if material.emissive_texture.is_some() && mtl.emissive.is_none() {
+            material.emissive = LinearRgba::WHITE;
+        }
+        material.normal_map_texture = load(&mtl.normal_map, false);
+        match &mtl.dissolve_map {
+            // The alpha channel of the diffuse texture is used for transparency.
+            Some(file) if Some(file) == mtl.diffuse_map.as_ref() => {
+                material.alpha_mode = AlphaMode::Blend;
+            }
+            Some(file) => {
+                warn!("Dissolve texture {file} is only supported when it is the diffuse texture");
+            }
+            None => {}
+        }
+
+        let has_normal_map = material.normal_map_texture.is_some();
+        let label = format!("Material{}", self.loaded_materials.len());
+        let handle: Handle<StandardMaterial> = self.load_context.add_labeled_asset(label, material);
+        self.loaded_materials
+            .insert(name.clone(), (handle.clone(), has_normal_map));
+        (handle, has_normal_map)
+    }
+}
+
+fn build_mesh(obj: &Obj, group: &Group, has_normal_map: bool) -> Mesh {
+    let corners = group.triangles.iter().flatten();
+    let has_normals = corners.clone().all(|(_, _, normal)| normal.is_some());
+    let has_tex_coords = corners.clone().any(|(_, tex_coord, _)| tex_coord.is_some());
+    let has_colors = corners
+        .clone()
+        .any(|(position, _, _)| obj.colors[*position].is_some());
+
+    // Share the vertices of corners with the same attributes, unless normals need to be
+    // computed for each face.
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+    let mut shared = HashMap::<Corner, u32>::default();
+    for &corner in corners {
+        if !has_normals {
+            vertices.push(corner);
+            continue;
+        }
+        let index = *shared.entry(corner).or_insert_with(|| {
+            vertices.push(corner);
+            vertices.len() as u32 - 1
+        });
+        indices.push(index);
+    }
+
+    let positions = vertices
+        .iter()
+        .map(|&(position, _, _)| obj.positions[position].to_array())
+        .collect::<Vec<_>>();
+    let normals = if has_normals {
+        vertices
+            .iter()
+            .map(|&(_, _, normal)| obj.normals[normal.unwrap()].to_array())
+            .collect()
+    } else {
+        flat_normals(&positions)
+    };
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    if has_tex_coords {
+        let tex_coords = vertices
+            .iter()
+            .map(|&(_, tex_coord, _)| {
+                tex_coord.map_or([0.0; 2], |uv| obj.tex_coords[uv].to_array())
+            })
+            .collect::<Vec<_>>();
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, tex_coords);
+    }
+    if has_colors {
+        let colors = vertices
+            .iter()
+            .map(|&(position, _, _)| {
+                let [r, g, b] = obj.colors[position].unwrap_or([1.0; 3]);
+                Color::srgb(r, g, b).to_linear().to_f32_array()
+            })
+            .collect::<Vec<_>>();
+        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    }
+    if has_normals {
+        mesh.insert_indices(Indices::U32(indices));
+    } else {
+        mesh.insert_indices(Indices::U32((0..vertices.len() as u32).collect()));
+    }
+    if has_normal_map && has_tex_coords {
+        if let Err(err) = mesh.generate_tangents() {
+            warn!("Failed to generate tangents for an OBJ mesh: {err}");
+        }
+    }
+    mesh
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Assets;
+    use bevy_hierarchy::Children;
+    use bevy_render::mesh::VertexAttributeValues;
+
+    use super::*;
+    use crate::import::load_test_scene;
+
+    #[test]
+    fn parse_faces() {
+        let obj = parse_obj(
+            "v 0 0 0\nv 1 0 0 \\\n  \nv 1 1 0 1 0 0\nv 0 1 0\nvt 0 0\nvn 0 0 1 # normal\n\
+            f 1/1/1 2/1/1 3/1/1 -1/1/1\nf 1 2 3\n",
+            "default".into(),
+        )
+        .unwrap();
+        assert_eq!(obj.positions.len(), 4);
+        assert_eq!(obj.colors[2], Some([1.0, 0.0, 0.0]));
+        assert_eq!(obj.tex_coords, [Vec2::new(0.0, 1.0)]);
+        let object = &obj.objects[0];
+        assert_eq!(object.name, "default");
+        let triangles = &object.groups[0].triangles;
+        assert_eq!(triangles.len(), 3);
+        assert_eq!(triangles[1][2], (3, Some(0), Some(0)));
+        assert_eq!(triangles[2][0], (0, None, None));
+        assert!(parse_obj("f 1 2 5", "default".into()).is_err());
+    }
+
+    #[test]
+    fn texture_options() {
+        let directory = Path::new("materials");
+        assert_eq!(
+            texture_path(
+                &["-s", "2", "2", "-bm", "0.5", "my", "texture.png"],
+                directory
+            ),
+            Some("materials/my texture.png".into())
+        );
+        assert_eq!(
+            texture_path(&["-o", "1", "textures\\a.png"], directory),
+            Some("materials/textures/a.png".into())
+        );
+    }
+
+    #[test]
+    fn load_obj() {
+        let obj = "mtllib cube.mtl
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+vn 0 0 1
+o Panel
+usemtl Red
+f 1//1 2//1 3//1 4//1
+usemtl Missing
+f 1 3 4
+";
+        let mtl = "newmtl Red
+Kd 1 0 0
+d 0.5
+Pm 1
+map_Kd -s 1 1 red.png
+";
+        let (mut app, handle) = load_test_scene(
+            "cube.obj",
+            &[("cube.obj", obj.as_bytes()), ("cube.mtl", mtl.as_bytes())],
+        )
+        .unwrap();
+
+        let scenes = app.world_mut().resource_mut::<Assets<Scene>>();
+        let world = &mut scenes.into_inner().get_mut(&handle).unwrap().world;
+        let children = world
+            .query::<(&Name, &Children)>()
+            .iter(world)
+            .find(|(name, _)| name.as_str() == "Panel")
+            .map(|(_, children)| children.to_vec())
+            .unwrap();
+        assert_eq!(children.len(), 2);
+        let red = world.entity(children[0]);
+        assert_eq!(red.get::<Name>().unwrap().as_str(), "Panel.Red");
+        let material = red.get::<MeshMaterial3d<StandardMaterial>>().unwrap();
+        let material = material.0.clone();
+        let [red, missing] = [0, 1].map(|index| {
+            let mesh = world.entity(children[index]).get::<Mesh3d>().unwrap();
+            mesh.0.clone()
+        });
+
+        let meshes = app.world().resource::<Assets<Mesh>>();
+        let red = meshes.get(&red).unwrap();
+        // The quad shares its 4 vertices between its 2 triangles.
+        assert_eq!(red.count_vertices(), 4);
+        assert_eq!(red.indices().unwrap().len(), 6);
+        let missing = meshes.get(&missing).unwrap();
+        let Some(VertexAttributeValues::Float32x3(normals)) =
+            missing.attribute(Mesh::ATTRIBUTE_NORMAL)
+        else {
+            panic!("missing normals");
+        };
+        assert_eq!(normals, &[[0.0, 0.0, 1.0]; 3]);
+
+        let materials = app.world().resource::<Assets<StandardMaterial>>();
+        let material = materials.get(&material).unwrap();
+        assert_eq!(material.base_color, Color::srgba(1.0, 0.0, 0.0, 0.5));
+        assert_eq!(material.metallic, 1.0);
+        assert_eq!(material.alpha_mode, AlphaMode::Blend);
+        assert_eq!(
+            material
+                .base_color_texture
+                .as_ref()
+                .and_then(Handle::path)
+                .map(ToString::to_string),
+            Some("red.png".into())
+        );
+    }
+}
@@ -0,0 +1,776 @@
+//! Loading of [USD](https://openusd.org) scenes in the text format (`.usda`).
+
+mod usda;
+
+use bevy_asset::{io::Reader, AssetLoader, Handle, LoadContext};
+use bevy_color::{Alpha, Color, LinearRgba};
+use bevy_ecs::{name::Name, world::World};
+use bevy_hierarchy::{BuildChildren, ChildBuild, WorldChildBuilder};
+use bevy_image::{Image, ImageAddressMode, ImageSampler, ImageSamplerDescriptor};
+use bevy_math::{Mat4, Quat, Vec2, Vec3};
+use bevy_pbr::{MeshMaterial3d, StandardMaterial};
+use bevy_render::{
+    alpha::AlphaMode,
+    mesh::{Indices, Mesh, Mesh3d},
+    render_asset::RenderAssetUsages,
+    render_resource::PrimitiveTopology,
+    view::Visibility,
+};
+use bevy_scene::Scene;
+use bevy_transform::components::Transform;
+use bevy_utils::HashMap;
+use thiserror::Error;
+use tracing::warn;
+
+use crate::import::{euler_rotation, flat_normals, load_texture_file};
+use usda::{Layer, Prim, Value};
+
+/// An error that occurs when loading a USD file.
+#[derive(Error, Debug)]
+pub enum UsdError {
+    /// Failed to read the file.
+    #[error("failed to read USD file: {0}")]
+    Io(#[from] std::io::Error),
+    /// The text of the layer is malformed.
+    #[error("invalid USD file at line {line}: {message}")]
+    Parse {
+        /// The line the error was found at.
+        line: usize,
+        /// A description of the error.
+        message: String,
+    },
+    /// The layer is not valid UTF-8.
+    #[error("USD file is not valid UTF-8: {0}")]
+    Utf8(#[from] core::str::Utf8Error),
+}
+
+/// Loads USD files in the text format (`.usda`) as a [`Scene`].
+///
+/// Every `def` prim becomes an entity with a [`Name`] and a [`Transform`] composed from its
+/// `xformOpOrder`, and `Mesh` prims get a [`Mesh3d`] and a [`MeshMaterial3d`]. Materials are
+/// converted from `UsdPreviewSurface` shaders, whose inputs may be connected to `UsdUVTexture`
+/// shaders loading image files next to the layer. Meshes and materials are added as the
+/// `Mesh{n}` and `Material{n}` labeled assets, numbered in the order they are found.
+///
+/// Neither binary `.usdc` layers nor USDZ archives are supported, and neither are `.usd` files,
+/// which may be in either format. Composition arcs, such as references, payloads and variants,
+/// are not resolved, and subdivision surfaces, skinning and animation are not imported.
+pub struct UsdLoader;
+
+impl AssetLoader for UsdLoader {
+    type Asset = Scene;
+    type Settings = ();
+    type Error = UsdError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Scene, UsdError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let layer = usda::parse(core::str::from_utf8(&bytes)?)?;
+
+        let mut builder = SceneBuilder {
+            load_context,
+            prims: HashMap::default(),
+            materials: HashMap::default(),
+            textures: HashMap::default(),
+            mesh_count: 0,
+            material_count: 0,
+        };
+        for prim in &layer.prims {
+            builder.index_prims(prim, "");
+        }
+        Ok(builder.build(&layer))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["usda"]
+    }
+}
+
+/// A material converted from a `UsdPreviewSurface`.
+#[derive(Clone)]
+struct LoadedMaterial {
+    handle: Handle<StandardMaterial>,
+    has_normal_map: bool,
+}
+
+struct SceneBuilder<'a, 'b, 'c> {
+    load_context: &'b mut LoadContext<'c>,
+    /// Every prim of the layer, by path.
+    prims: HashMap<String, &'a Prim>,
+    materials: HashMap<String, LoadedMaterial>,
+    /// Textures by file and whether they are sRGB.
+    textures: HashMap<(String, bool), Handle<Image>>,
+    mesh_count: usize,
+    material_count: usize,
+}
+
+impl<'a> SceneBuilder<'a, '_, '_> {
+    fn index_prims(&mut self, prim: &'a Prim, parent: &str) {
+        let path = format!("{parent}/{}", prim.name);
+        for child in &prim.children {
+            self.index_prims(child, &path);
+        }
+        self.prims.insert(path, prim);
+    }
+
+    fn build(&mut self, layer: &'a Layer) -> Scene {
+        // Bevy is Y-up and measured in meters.
+        let mut root = Transform::default();
+        if layer.metadata.get("upAxis").and_then(Value::as_str) == Some("Z") {
+            root.rotation = Quat::from_rotation_x(-core::f32::consts::FRAC_PI_2);
+        }
+        if let Some(meters_per_unit) = layer.metadata.get("metersPerUnit").and_then(Value::as_f32) {
+            root.scale = Vec3::splat(meters_per_unit);
+        }
+
+        let mut world = World::default();
+        world
+            .spawn((root, Visibility::default()))
+            .with_children(|parent| {
+                for prim in &layer.prims {
+                    self.spawn_prim(parent, prim, "", None);
+                }
+            });
+        Scene::new(world)
+    }
+
+    fn spawn_prim(
+        &mut self,
+        parent: &mut WorldChildBuilder,
+        prim: &'a Prim,
+        parent_path: &str,
+        inherited_material: Option<&'a str>,
+    ) {
+        let type_name = prim.type_name.as_deref();
+        if prim.specifier != "def"
+            || matches!(
+                type_name,
+                Some("Material" | "Shader" | "NodeGraph" | "GeomSubset")
+            )
+        {
+            return;
+        }
+        let path = format!("{parent_path}/{}", prim.name);
+        let material = match prim.value("material:binding") {
+            Some(Value::Path(material)) => Some(material.as_str()),
+            _ => inherited_material,
+        };
+
+        let mut entity = parent.spawn((
+            Name::new(prim.name.clone()),
+            Transform::from_matrix(local_transform(prim)),
+            Visibility::default(),
+        ));
+        if type_name == Some("Mesh") {
+            let material = match material.and_then(|material| self.load_material(material)) {
+                Some(material) => material,
+                None => self.display_color_material(prim),
+            };
+            if let Some(mut mesh) = load_mesh(prim) {
+                if material.has_normal_map && mesh.contains_attribute(Mesh::ATTRIBUTE_UV_0) {
+                    if let Err(err) = mesh.generate_tangents() {
+                        warn!("Failed to generate tangents for the mesh {path}: {err}");
+                    }
+                }
+                let label = format!("Mesh{}", self.mesh_count);
+                self.mesh_count += 1;
+                let mesh = self.load_context.add_labeled_asset(label, mesh);
+                entity.insert((Mesh3d(mesh), MeshMaterial3d(material.handle)));
+            } else {
+                warn!("Skipping the mesh {path}, which has no valid faces");
+            }
+        }
+
+        entity.with_children(|parent| {
+            for child in &prim.children {
+                self.spawn_prim(parent, child, &path, material);
+            }
+        });
+    }
+
+    fn add_material(&mut self, material: StandardMaterial) -> Handle<StandardMaterial> {
+        let label = format!("Material{}", self.material_count);
+        self.material_count += 1;
+        self.load_context.add_labeled_asset(label, material)
+    }
+
+    /// Creates the material of a mesh without a bound material, from its `displayColor`.
+    fn display_color_material(&mut self, prim: &Prim) -> LoadedMaterial {
+        let color = prim
+            .value("primvars:displayColor")
+            .and_then(|value| match value {
+                Value::Array(colors) => colors.first(),
+                value => Some(value),
+            })
+            .and_then(Value::as_floats::<3>)
+            .unwrap_or([0.18; 3]);
+        LoadedMaterial {
+            handle: self.add_material(StandardMaterial {
+                base_color: Color::linear_rgb(color[0], color[1], color[2]),
+                ..Default::default()
+            }),
+            has_normal_map: false,
+        }
+    }
+
+    /// Returns the prim and the name of the property a connection targets.
+    fn connected(&self, connection: &str) -> Option<(&'a Prim, &'a str)> {
+        // Prim names can't contain periods, so the first one after the last slash starts the
+        // name of the property.
+        let split = connection.rfind('/').unwrap_or(0);
+        let (prim, property) = connection.split_at(connection[split..].find('.')? + split);
+        let prim = *self.prims.get(prim)?;
+        let (name, _) = prim.properties.get_key_value(&property[1..])?;
+        Some((prim, name))
+    }
+
+    /// Follows a connection through the outputs of node graphs, to the shader it comes from.
+    fn connected_shader(&self, mut connection: &'a str) -> Option<(&'a Prim, &'a str)> {
+        // Guard against connection cycles.
+        for _ in 0..16 {
+            let (prim, output) = self.connected(connection)?;
+            if prim.type_name.as_deref() == Some("Shader") {
+                return Some((prim, output));
+            }
+            connection = prim.connection(output)?;
+        }
+        None
+    }
+
+    fn load_material(&mut self, path: &'a str) -> Option<LoadedMaterial> {
+        if let Some(material) = self.materials.get(path) {
+            return Some(material.clone());
+        }
+        let Some(material_prim) = self.prims.get(path).copied() else {
+            warn!("Material {path} doesn't exist");
+            return None;
+        };
+        let surface = material_prim
+            .properties
+            .iter()
+            .filter(|(name, property)| {
+                name.starts_with("outputs:")
+                    && name.ends_with("surface")
+                    && property.connection.is_some()
+            })
+            // Prefer the universal render context over renderer-specific ones.
+            .min_by_key(|(name, _)| (name.as_str() != "outputs:surface", name.as_str()))
+            .and_then(|(_, property)| property.connection.as_deref())
+            .and_then(|connection| self.connected_shader(connection))
+            .map(|(shader, _)| shader);
+        let Some(surface) = surface.filter(|shader| {
+            shader.value("info:id").and_then(Value::as_str) == Some("UsdPreviewSurface")
+        }) else {
+            warn!("Material {path} has no UsdPreviewSurface shader");
+            return None;
+        };
+
+        let mut material = StandardMaterial {
+            base_color: Color::linear_rgb(0.18, 0.18, 0.18),
+            perceptual_roughness: 0.5,
+            metallic: 0.0,
+            ..Default::default()
+        };
+        let mut metallic_texture = None;
+        let mut roughness_texture = None;
+        let mut opacity_texture = None;
+        let mut diffuse_texture = None;
+        for (name, property) in &surface.properties {
+            let Some(input) = name.strip_prefix("inputs:") else {
+                continue;
+            };
+            let texture = property
+                .connection
+                .as_deref()
+                .and_then(|connection| self.connected_shader(connection))
+                .filter(|(shader, _)| {
+                    shader.value("info:id").and_then(Value::as_str) == Some("UsdUVTexture")
+                });
+            match (input, texture, &property.value) {
+                ("diffuseColor", Some((texture, _)), _) => {
+                    diffuse_texture = Some(texture);
+                    material.base_color = Color::WHITE;
+                    material.base_color_texture = self.load_texture(texture, true);
+                }
+                ("diffuseColor", None, Some(value)) => {
+                    if let Some([r, g, b]) = value.as_floats() {
+                        material.base_color = Color::linear_rgb(r, g, b);
+                    }
+                }
+                ("emissiveColor", Some((texture, _)), _) => {
+                    material.emissive = LinearRgba::WHITE;
+                    material.emissive_texture = self.load_texture(texture, true);
+                }
+                ("emissiveColor", None, Some(value)) => {
+                    if let Some([r, g, b]) = value.as_floats() {
+                        material.emissive = LinearRgba::rgb(r, g, b);
+                    }
+                }
+                ("normal", Some((texture, _)), _) => {
+                    material.normal_map_texture = self.load_texture(texture, false);
+                }
+                ("occlusion", Some((texture, output)), _) => {
+                    if output != "outputs:r" {
+                        warn!("Occlusion of material {path} must be read from the red channel of its texture");
+                    }
+                    material.occlusion_texture = self.load_texture(texture, false);
+                }
+                ("metallic", Some(texture), _) => metallic_texture = Some(texture),
+                ("roughness", Some(texture), _) => roughness_texture = Some(texture),
+                ("opacity", Some(texture), _) => opacity_texture = Some(texture),
+                ("metallic", None, Some(value)) => {
+                    material.metallic = value.as_f32().unwrap_or(material.metallic);
+                }
+                ("roughness", None, Some(value)) => {
+                    material.perceptual_roughness =
+                        value.as_f32().unwrap_or(material.perceptual_roughness);
+                }
+                ("opacity", None, Some(value)) => {
+                    if let Some(opacity) = value.as_f32() {
+                        material.base_color.set_alpha(opacity);
+                    }
+                }
+                ("ior", None, Some(value)) => material.ior = value.as_f32().unwrap_or(1.5),
+                ("clearcoat", None, Some(value)) => {
+                    material.clearcoat = value.as_f32().unwrap_or(0.0);
+                }
+                ("clearcoatRoughness", None, Some(value)) => {
+                    material.clearcoat_perceptual_roughness = value.as_f32().unwrap_or(0.01);
+                }
+                (_, Some(_), _) => {
+                    warn!("Textures of the {input} input of material {path} are not supported");
+                }
+                _ => {}
+            }
+        }
+
+        // Bevy reads metallic from the blue channel and roughness from the green channel of a
+        // single texture.
+        match (metallic_texture, roughness_texture) {
+            (None, None) => {}
+            (Some((metallic, "outputs:b")), Some((roughness, "outputs:g")))
+                if texture_file(metallic) == texture_file(roughness) =>
+            {
+                material.metallic = 1.0;
+                material.perceptual_roughness = 1.0;
+                material.metallic_roughness_texture = self.load_texture(metallic, false);
+            }
+            _ => warn!(
+                "Metallic and roughness textures of material {path} are only supported when read from the blue and green channels of the same texture"
+            ),
+        }
+
+        let opacity_threshold = surface
+            .value("inputs:opacityThreshold")
+            .and_then(Value::as_f32)
+            .unwrap_or(0.0);
+        let translucent = match opacity_texture {
+            Some((opacity, "outputs:a"))
+                if diffuse_texture
+                    .is_some_and(|diffuse| texture_file(diffuse) == texture_file(opacity)) =>
+            {
+                true
+            }
+            Some(_) => {
+                warn!("Opacity of material {path} must be read from the alpha channel of its diffuse texture");
+                false
+            }
+            None => material.base_color.alpha() < 1.0,
+        };
+        if opacity_threshold > 0.0 {
+            material.alpha_mode = AlphaMode::Mask(opacity_threshold);
+        } else if translucent {
+            material.alpha_mode = AlphaMode::Blend;
+        }
+
+        let loaded = LoadedMaterial {
+            has_normal_map: material.normal_map_texture.is_some(),
+            handle: self.add_material(material),
+        };
+        self.materials.insert(path.to_string(), loaded.clone());
+        Some(loaded)
+    }
+
+    fn load_texture(&mut self, shader: &Prim, mut is_srgb: bool) -> Option<Handle<Image>> {
+        let file = texture_file(shader)?;
+        match shader
+            .value("inputs:sourceColorSpace")
+            .and_then(Value::as_str)
+        {
+            Some("raw") => is_srgb = false,
+            Some("sRGB") => is_srgb = true,
+            _ => {}
+        }
+        let key = (file.to_string(), is_srgb);
+        if let Some(texture) = self.textures.get(&key) {
+            return Some(texture.clone());
+        }
+
+        let address_mode = |input: &str| match shader.value(input).and_then(Value::as_str) {
+            Some("clamp" | "black") => ImageAddressMode::ClampToEdge,
+            Some("mirror") => ImageAddressMode::MirrorRepeat,
+            _ => ImageAddressMode::Repeat,
+        };
+        let sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+            address_mode_u: address_mode("inputs:wrapS"),
+            address_mode_v: address_mode("inputs:wrapT"),
+            ..ImageSamplerDescriptor::linear()
+        });
+
+        let handle = load_texture_file(self.load_context, file, is_srgb, sampler);
+        self.textures.insert(key, handle.clone());
+        Some(handle)
+    }
+}
+
+/// Returns the file of a `UsdUVTexture` shader.
+fn texture_file(shader: &Prim) -> Option<&str> {
+    shader.value("inputs:file").and_then(Value::as_str)
+}
+
+/// Composes the transform of a prim from its `xformOpOrder`.
+fn local_transform(prim: &Prim) -> Mat4 {
+    let Some(order) = prim.value("xformOpOrder").and_then(Value::as_array) else {
+        return Mat4::IDENTITY;
+    };
+    order
+        .iter()
+        .filter_map(Value::as_str)
+        .fold(Mat4::IDENTITY, |transform, op| {
+            transform * xform_op(prim, op)
+        })
+}
+
+/// Evaluates a transform operation, such as `xformOp:translate` or `!invert!xformOp:scale:pivot`.
+fn xform_op(prim: &Prim, op: &str) -> Mat4 {
+    let (invert, name) = match op.strip_prefix("!invert!") {
+        Some(name) => (true, name),
+        None => (false, op),
+    };
+    let Some(value) = prim.value(name) else {
+        if name != "!resetXformStack!" {
+            warn!("Transform operation {name} of {} has no value", prim.name);
+        }
+        return Mat4::IDENTITY;
+    };
+    let kind = name
+        .strip_prefix("xformOp:")
+        .and_then(|kind| kind.split(':').next())
+        .unwrap_or_default();
+    let transform = match kind {
+        "translate" => value
+            .as_floats::<3>()
+            .map(|translation| Mat4::from_translation(translation.into())),
+        "scale" => value
+            .as_floats::<3>()
+            .map(|scale| Mat4::from_scale(scale.into())),
+        "orient" => value
+            .as_floats::<4>()
+            .map(|[w, x, y, z]| Mat4::from_quat(Quat::from_xyzw(x, y, z, w).normalize())),
+        "transform" => match value {
+            // USD matrices transform row vectors, so each row is a column of the Bevy matrix.
+            Value::Tuple(rows) if rows.len() == 4 => rows
+                .iter()
+                .map(Value::as_floats::<4>)
+                .collect::<Option<Vec<_>>>()
+                .map(|rows| Mat4::from_cols_array_2d(&[rows[0], rows[1], rows[2], rows[3]])),
+            _ => None,
+        },
+        "rotateX" => value
+            .as_f32()
+            .map(|x| Mat4::from_rotation_x(x.to_radians())),
+        "rotateY" => value
+            .as_f32()
+            .map(|y| Mat4::from_rotation_y(y.to_radians())),
+        "rotateZ" => value
+            .as_f32()
+            .map(|z| Mat4::from_rotation_z(z.to_radians())),
+        rotate => rotate
+            .strip_prefix("rotate")
+            .and_then(|axes| euler_rotation(axes, value.as_floats::<3>()?))
+            .map(Mat4::from_quat),
+    };
+    let Some(transform) = transform else {
+        warn!("Unsupported transform operation {name} of {}", prim.name);
+        return Mat4::IDENTITY;
+    };
+    if invert {
+        transform.inverse()
+    } else {
+        transform
+    }
+}
+
+/// Returns the value of a primvar for each corner of the triangulated mesh.
+///
+/// `corners` holds the face, the point and the face-vertex index of each corner.
+fn primvar<const N: usize>(
+    prim: &Prim,
+    name: &str,
+    corners: &[(usize, usize, usize)],
+    point_count: usize,
+) -> Option<Vec<[f32; N]>> {
+    let property = prim.properties.get(name)?;
+    let values = match property.value.as_ref()? {
+        Value::Array(values) => values
+            .iter()
+            .map(Value::as_floats::<N>)
+            .collect::<Option<Vec<_>>>()?,
+        value => vec![value.as_floats::<N>()?],
+    };
+    let indices = prim
+        .value(&format!("{name}:indices"))
+        .and_then(Value::as_array)
+        .map(|indices| {
+            indices
+                .iter()
+                .map(|index| index.as_f32().map(|index| index as usize))
+                .collect::<Option<Vec<_>>>()
+        })
+        .unwrap_or(Some(Vec::new()))?;
+    let element_count = if indices.is_empty() {
+        values.len()
+    } else {
+        indices.len()
+    };
+    let face_vertex_count = corners.iter().map(|(_, _, corner)| corner + 1).max()?;
+    // Infer the interpolation from the number of elements when it isn't authored.
+    let interpolation = property
+        .interpolation
+        .as_deref()
+        .unwrap_or(match element_count {
+            1 => "constant",
+            count if count == point_count => "vertex",
+            count if count == face_vertex_count => "faceVarying",
+            _ => "uniform",
+        });
+
+    corners
+        .iter()
+        .map(|&(face, point, corner)| {
+            let element = match interpolation {
+                "constant" => 0,
+                "uniform" => face,
+                "vertex" | "varying" => point,
+                _ => corner,
+            };
+            let value = match indices.is_empty() {
+                true => element,
+                false => *indices.get(element)?,
+            };
+            values.get(value).copied()
+        })
+        .collect()
+}
+
+/// Triangulates a `Mesh` prim, with a vertex for each corner of each triangle.
+fn load_mesh(prim: &Prim) -> Option<Mesh> {
+    let points = prim
+        .value("points")?
+        .as_array()?
+        .iter()
+        .map(|point| point.as_floats::<3>().map(Vec3::from))
+        .collect::<Option<Vec<_>>>()?;
+    let integers = |name: &str| -> Option<Vec<usize>> {
+        prim.value(name)?
+            .as_array()?
+            .iter()
+            .map(|value| value.as_f32().map(|value| value as usize))
+            .collect()
+    };
+    let counts = integers("faceVertexCounts")?;
+    let indices = integers("faceVertexIndices")?;
+    let left_handed = prim.value("orientation").and_then(Value::as_str) == Some("leftHanded");
+
+    // Fan-triangulate each face.
+    let mut corners = Vec::new();
+    let mut start = 0;
+    for (face, &count) in counts.iter().enumerate() {
+        if start + count > indices.len() {
+            return None;
+        }
+        for i in 1..count.saturating_sub(1) {
+            let mut triangle = [start, start + i, start + i + 1];
+            if left_handed {
+                triangle.swap(1, 2);
+            }
+            for corner in triangle {
+                let point = indices[corner];
+                if point >= points.len() {
+                    return None;
+                }
+                corners.push((face, point, corner));
+            }
+        }
+        start += count;
+    }
+    if corners.is_empty() {
+        return None;
+    }
+
+    let positions = corners
+        .iter()
+        .map(|&(_, point, _)| points[point].to_array())
+        .collect::<Vec<_>>();
+    let normals = primvar::<3>(prim, "primvars:normals", &corners, points.len())
+        .or_else(|| primvar::<3>(prim, "normals", &corners, points.len()))
+        .map(|normals| {
+            let flip = if left_handed { -1.0 } else { 1.0 };
+            normals
+                .into_iter()
+                .map(|normal| (Vec3::from(normal) * flip).to_array())
+                .collect()
+        })
+        .unwrap_or_else(|| flat_normals(&positions));
+
+    let uv_name = if prim.properties.contains_key("primvars:st") {
+        Some("primvars:st")
+    } else {
+        prim.properties
+            .iter()
+            .filter(|(name, property)| {
+                name.starts_with("primvars:") && property.type_name.starts_with("texCoord2")
+            })
+            .map(|(name, _)| name.as_str())
+            .min()
+    };
+    let uvs = uv_name.and_then(|name| primvar::<2>(prim, name, &corners, points.len()));
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+    .with_inserted_indices(Indices::U32((0..corners.len() as u32).collect()));
+    if let Some(uvs) = uvs {
+        // USD texture coordinates start at the bottom of the image.
+        let uvs = uvs
+            .into_iter()
+            .map(|[u, v]| Vec2::new(u, 1.0 - v).to_array())
+            .collect::<Vec<_>>();
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    }
+    Some(mesh)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Assets;
+    use bevy_hierarchy::Children;
+    use bevy_render::mesh::VertexAttributeValues;
+
+    use super::*;
+    use crate::import::load_test_scene;
+
+    const SCENE: &str = r#"#usda 1.0
+(
+    upAxis = "Z"
+)
+
+def Xform "Root"
+{
+    double3 xformOp:translate = (1, 2, 3)
+    float xformOp:rotateZ = 90
+    uniform token[] xformOpOrder = ["xformOp:translate", "xformOp:rotateZ"]
+    rel material:binding = </Materials/Red>
+
+    def Mesh "Quad"
+    {
+        int[] faceVertexCounts = [4]
+        int[] faceVertexIndices = [0, 1, 2, 3]
+        point3f[] points = [(0, 0, 0), (1, 0, 0), (1, 1, 0), (0, 1, 0)]
+        texCoord2f[] primvars:st = [(0, 0), (1, 0)] (
+            interpolation = "faceVarying"
+        )
+        int[] primvars:st:indices = [0, 1, 1, 0]
+    }
+}
+
+def Scope "Materials"
+{
+    def Material "Red"
+    {
+        token outputs:surface.connect = </Materials/Red/Surface.outputs:surface>
+
+        def Shader "Surface"
+        {
+            uniform token info:id = "UsdPreviewSurface"
+            color3f inputs:diffuseColor = (1, 0, 0)
+            float inputs:metallic = 1
+            float inputs:opacity = 0.5
+            token outputs:surface
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn load_usda() {
+        let (mut app, handle) =
+            load_test_scene("scene.usda", &[("scene.usda", SCENE.as_bytes())]).unwrap();
+
+        let material = app
+            .world()
+            .resource::<Assets<StandardMaterial>>()
+            .iter()
+            .next()
+            .unwrap()
+            .1
+            .clone();
+        assert_eq!(material.base_color, Color::linear_rgba(1.0, 0.0, 0.0, 0.5));
+        assert_eq!(material.metallic, 1.0);
+        assert_eq!(material.alpha_mode, AlphaMode::Blend);
+
+        let mut scenes = app.world_mut().resource_mut::<Assets<Scene>>();
+        let world = &mut scenes.get_mut(&handle).unwrap().world;
+        let (transform, children) = world
+            .query::<(&Name, &Transform, &Children)>()
+            .iter(world)
+            .find(|(name, ..)| name.as_str() == "Root")
+            .map(|(_, transform, children)| (*transform, children[0]))
+            .unwrap();
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(1.0, 2.0, 3.0), 1e-6));
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(core::f32::consts::FRAC_PI_2), 1e-6));
+
+        let quad = world.entity(children);
+        assert_eq!(quad.get::<Name>().unwrap().as_str(), "Quad");
+        let mesh = quad.get::<Mesh3d>().unwrap().0.clone();
+        assert!(quad.contains::<MeshMaterial3d<StandardMaterial>>());
+
+        let meshes = app.world().resource::<Assets<Mesh>>();
+        let mesh = meshes.get(&mesh).unwrap();
+        assert_eq!(mesh.count_vertices(), 6);
+        let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+        else {
+            panic!("missing texture coordinates");
+        };
+        assert_eq!(
+            uvs,
+            &[
+                [0.0, 1.0],
+                [1.0, 1.0],
+                [1.0, 1.0],
+                [0.0, 1.0],
+                [1.0, 1.0],
+                [0.0, 1.0]
+            ]
+        );
+        let Some(VertexAttributeValues::Float32x3(normals)) =
+            mesh.attribute(Mesh::ATTRIBUTE_NORMAL)
+        else {
+            panic!("missing normals");
+        };
+        assert!(normals.iter().all(|normal| *normal == [0.0, 0.0, 1.0]));
+    }
+}
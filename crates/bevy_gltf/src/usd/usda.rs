@@ -0,0 +1,609 @@
+//! A parser for the subset of the USD text format (`.usda`) needed to import scenes.
+//!
+//! Layers are parsed into a tree of [`Prim`]s holding the default values of their properties.
+//! Time samples, variant sets and dictionaries are skipped, and composition arcs such as
+//! references and payloads are not resolved.
+
+use alloc::borrow::Cow;
+use bevy_utils::HashMap;
+
+use super::UsdError;
+
+/// A value of an attribute or of metadata.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Value {
+    Number(f64),
+    String(String),
+    /// A bare word, such as `true`, `None` or an unquoted token.
+    Token(String),
+    /// An asset path, written `@path@`.
+    Asset(String),
+    /// A path to a prim or property, written `</Prim.property>`.
+    Path(String),
+    Tuple(Vec<Value>),
+    Array(Vec<Value>),
+    /// A value the importer has no use for, such as a dictionary.
+    Skipped,
+}
+
+impl Value {
+    pub(crate) fn as_f32(&self) -> Option<f32> {
+        match self {
+            Value::Number(number) => Some(*number as f32),
+            Value::Token(token) => match token.as_str() {
+                "true" => Some(1.0),
+                "false" => Some(0.0),
+                "inf" => Some(f32::INFINITY),
+                "nan" => Some(f32::NAN),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(string) | Value::Token(string) | Value::Asset(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Returns the components of a tuple, such as a `float3` or a `matrix4d` row.
+    pub(crate) fn as_floats<const N: usize>(&self) -> Option<[f32; N]> {
+        let Value::Tuple(values) = self else {
+            return None;
+        };
+        if values.len() != N {
+            return None;
+        }
+        let mut floats = [0.0; N];
+        for (float, value) in floats.iter_mut().zip(values) {
+            *float = value.as_f32()?;
+        }
+        Some(floats)
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+/// A property of a prim: an attribute or a relationship.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Property {
+    /// The type of the attribute, such as `point3f[]`, or `rel` for relationships.
+    pub(crate) type_name: String,
+    /// The default value, if any.
+    pub(crate) value: Option<Value>,
+    /// The target of the `.connect` of the attribute, if any.
+    pub(crate) connection: Option<String>,
+    /// The `interpolation` metadata of a primvar.
+    pub(crate) interpolation: Option<String>,
+}
+
+/// A prim, the node of a USD scene graph.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Prim {
+    /// `def`, `over` or `class`.
+    pub(crate) specifier: String,
+    /// The schema of the prim, such as `Xform` or `Mesh`, if it is typed.
+    pub(crate) type_name: Option<String>,
+    pub(crate) name: String,
+    pub(crate) properties: HashMap<String, Property>,
+    pub(crate) children: Vec<Prim>,
+}
+
+impl Prim {
+    pub(crate) fn value(&self, name: &str) -> Option<&Value> {
+        self.properties.get(name)?.value.as_ref()
+    }
+
+    pub(crate) fn connection(&self, name: &str) -> Option<&str> {
+        self.properties.get(name)?.connection.as_deref()
+    }
+}
+
+/// A parsed layer.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Layer {
+    pub(crate) metadata: HashMap<String, Value>,
+    pub(crate) prims: Vec<Prim>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token<'a> {
+    Word(&'a str),
+    Number(f64),
+    String(Cow<'a, str>),
+    Asset(&'a str),
+    Path(&'a str),
+    Punct(char),
+}
+
+struct Lexer<'a> {
+    source: &'a str,
+    position: usize,
+    line: usize,
+    peeked: Option<(Token<'a>, usize)>,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            position: 0,
+            line: 1,
+            peeked: None,
+        }
+    }
+
+    fn error(&self, message: impl Into<String>) -> UsdError {
+        UsdError::Parse {
+            line: self.line,
+            message: message.into(),
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    fn skip_whitespace_and_comments(&mut self) {
+        loop {
+            let rest = self.rest();
+            let Some(c) = rest.chars().next() else {
+                return;
+            };
+            if c == '\n' {
+                self.line += 1;
+                self.position += 1;
+            } else if c.is_whitespace() {
+                self.position += c.len_utf8();
+            } else if c == '#' {
+                self.position += rest.find('\n').unwrap_or(rest.len());
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn take_until(&mut self, skip: usize, end: &str) -> Result<&'a str, UsdError> {
+        let rest = &self.rest()[skip..];
+        let Some(length) = rest.find(end) else {
+            return Err(self.error(format!("unterminated literal, expected `{end}`")));
+        };
+        let literal = &rest[..length];
+        self.line += literal.matches('\n').count();
+        self.position += skip + length + end.len();
+        Ok(literal)
+    }
+
+    fn lex(&mut self) -> Result<Option<Token<'a>>, UsdError> {
+        self.skip_whitespace_and_comments();
+        let rest = self.rest();
+        let Some(c) = rest.chars().next() else {
+            return Ok(None);
+        };
+        let token = match c {
+            '(' | ')' | '[' | ']' | '{' | '}' | '=' | ',' | ';' | ':' => {
+                self.position += 1;
+                Token::Punct(c)
+            }
+            '@' => {
+                let skip = if rest.starts_with("@@@") { 3 } else { 1 };
+                Token::Asset(self.take_until(skip, &rest[..skip])?)
+            }
+            '<' => Token::Path(self.take_until(1, ">")?),
+            '"' | '\'' => {
+                let quote_length = if rest.starts_with("\"\"\"") || rest.starts_with("'''") {
+                    3
+                } else {
+                    1
+                };
+                let quote = &rest[..quote_length];
+                let mut length = quote.len();
+                let bytes = rest.as_bytes();
+                loop {
+                    if length >= rest.len() {
+                        return Err(self.error("unterminated string"));
+                    }
+                    if bytes[length] == b'\\' {
+                        length += 2;
+                    } else if rest[length..].starts_with(quote) {
+                        break;
+                    } else {
+                        length += 1;
+                    }
+                }
+                let literal = &rest[quote.len()..length];
+                self.line += literal.matches('\n').count();
+                self.position += length + quote.len();
+                Token::String(unescape(literal))
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' => {
+                let length = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '.')))
+                    .unwrap_or(rest.len());
+                let literal = &rest[..length];
+                let number = match literal.trim_start_matches(['-', '+']) {
+                    "inf" => {
+                        f64::INFINITY.copysign(if literal.starts_with('-') { -1.0 } else { 1.0 })
+                    }
+                    "nan" => f64::NAN,
+                    _ => literal
+                        .parse()
+                        .map_err(|_| self.error(format!("invalid number `{literal}`")))?,
+                };
+                self.position += length;
+                Token::Number(number)
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let length = rest
+                    .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | ':' | '.')))
+                    .unwrap_or(rest.len());
+                self.position += length;
+                Token::Word(&rest[..length])
+            }
+            c => return Err(self.error(format!("unexpected character `{c}`"))),
+        };
+        Ok(Some(token))
+    }
+
+    fn next(&mut self) -> Result<Option<Token<'a>>, UsdError> {
+        match self.peeked.take() {
+            Some((token, line)) => {
+                self.line = line;
+                Ok(Some(token))
+            }
+            None => self.lex(),
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<&Token<'a>>, UsdError> {
+        if self.peeked.is_none() {
+            let line = self.line;
+            if let Some(token) = self.lex()? {
+                self.peeked = Some((token, self.line));
+            }
+            self.line = line;
+        }
+        Ok(self.peeked.as_ref().map(|(token, _)| token))
+    }
+
+    fn expect_next(&mut self) -> Result<Token<'a>, UsdError> {
+        self.next()?
+            .ok_or_else(|| self.error("unexpected end of file"))
+    }
+
+    fn expect_punct(&mut self, expected: char) -> Result<(), UsdError> {
+        match self.expect_next()? {
+            Token::Punct(c) if c == expected => Ok(()),
+            token => Err(self.error(format!("expected `{expected}`, found {token:?}"))),
+        }
+    }
+
+    fn eat_punct(&mut self, expected: char) -> Result<bool, UsdError> {
+        if self.peek()? == Some(&Token::Punct(expected)) {
+            self.next()?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+fn unescape(literal: &str) -> Cow<'_, str> {
+    if !literal.contains('\\') {
+        return Cow::Borrowed(literal);
+    }
+    let mut unescaped = String::with_capacity(literal.len());
+    let mut chars = literal.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some(c) => unescaped.push(c),
+            None => {}
+        }
+    }
+    Cow::Owned(unescaped)
+}
+
+/// Parses a layer in the USD text format.
+pub(crate) fn parse(source: &str) -> Result<Layer, UsdError> {
+    if !source.starts_with("#usda") {
+        return Err(UsdError::Parse {
+            line: 1,
+            message: "missing `#usda` header".into(),
+        });
+    }
+    let mut lexer = Lexer::new(source);
+    let mut layer = Layer::default();
+    if lexer.eat_punct('(')? {
+        parse_metadata(&mut lexer, &mut layer.metadata, &mut None)?;
+    }
+    while let Some(token) = lexer.next()? {
+        match token {
+            Token::Word(specifier @ ("def" | "over" | "class")) => {
+                layer.prims.push(parse_prim(&mut lexer, specifier)?);
+            }
+            token => return Err(lexer.error(format!("expected a prim, found {token:?}"))),
+        }
+    }
+    Ok(layer)
+}
+
+/// Parses the entries of a metadata block, after its opening parenthesis.
+fn parse_metadata(
+    lexer: &mut Lexer,
+    metadata: &mut HashMap<String, Value>,
+    interpolation: &mut Option<String>,
+) -> Result<(), UsdError> {
+    loop {
+        match lexer.expect_next()? {
+            Token::Punct(')') => return Ok(()),
+            // A separator, or the documentation string.
+            Token::Punct(';') | Token::String(_) => {}
+            Token::Word("prepend" | "append" | "add" | "delete" | "reorder") => {
+                let Token::Word(_) = lexer.expect_next()? else {
+                    return Err(lexer.error("expected a metadata key"));
+                };
+                lexer.expect_punct('=')?;
+                parse_value(lexer)?;
+            }
+            Token::Word(key) => {
+                lexer.expect_punct('=')?;
+                let value = parse_value(lexer)?;
+                if key == "interpolation" {
+                    *interpolation = value.as_str().map(ToString::to_string);
+                }
+                metadata.insert(key.to_string(), value);
+            }
+            token => return Err(lexer.error(format!("expected metadata, found {token:?}"))),
+        }
+    }
+}
+
+fn parse_value(lexer: &mut Lexer) -> Result<Value, UsdError> {
+    Ok(match lexer.expect_next()? {
+        Token::Number(number) => Value::Number(number),
+        Token::String(string) => Value::String(string.into_owned()),
+        Token::Word(word) => Value::Token(word.to_string()),
+        Token::Asset(asset) => {
+            // References and payloads may target a prim of the asset, as in `@a.usda@</Prim>`.
+            if matches!(lexer.peek()?, Some(Token::Path(_))) {
+                lexer.next()?;
+            }
+            Value::Asset(asset.to_string())
+        }
+        Token::Path(path) => Value::Path(path.to_string()),
+        Token::Punct('(') => Value::Tuple(parse_sequence(lexer, ')')?),
+        Token::Punct('[') => Value::Array(parse_sequence(lexer, ']')?),
+        Token::Punct('{') => {
+            skip_block(lexer)?;
+            Value::Skipped
+        }
+        token => return Err(lexer.error(format!("expected a value, found {token:?}"))),
+    })
+}
+
+fn parse_sequence(lexer: &mut Lexer, end: char) -> Result<Vec<Value>, UsdError> {
+    let mut values = Vec::new();
+    loop {
+        if lexer.eat_punct(end)? {
+            return Ok(values);
+        }
+        values.push(parse_value(lexer)?);
+        if !lexer.eat_punct(',')? {
+            lexer.expect_punct(end)?;
+            return Ok(values);
+        }
+    }
+}
+
+/// Skips tokens up to the closing brace of a block, after its opening brace.
+fn skip_block(lexer: &mut Lexer) -> Result<(), UsdError> {
+    let mut depth = 1;
+    while depth > 0 {
+        match lexer.expect_next()? {
+            Token::Punct('{') => depth += 1,
+            Token::Punct('}') => depth -= 1,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn parse_prim(lexer: &mut Lexer, specifier: &str) -> Result<Prim, UsdError> {
+    let mut prim = Prim {
+        specifier: specifier.to_string(),
+        ..Prim::default()
+    };
+    match lexer.expect_next()? {
+        Token::Word(type_name) => {
+            prim.type_name = Some(type_name.to_string());
+            let Token::String(name) = lexer.expect_next()? else {
+                return Err(lexer.error("expected the name of the prim"));
+            };
+            prim.name = name.into_owned();
+        }
+        Token::String(name) => prim.name = name.into_owned(),
+        token => return Err(lexer.error(format!("expected a prim name, found {token:?}"))),
+    }
+    if lexer.eat_punct('(')? {
+        parse_metadata(lexer, &mut HashMap::default(), &mut None)?;
+    }
+    lexer.expect_punct('{')?;
+    loop {
+        match lexer.expect_next()? {
+            Token::Punct('}') => return Ok(prim),
+            Token::Punct(';') => {}
+            Token::Word(specifier @ ("def" | "over" | "class")) => {
+                prim.children.push(parse_prim(lexer, specifier)?);
+            }
+            Token::Word("variantSet") => {
+                lexer.expect_next()?;
+                lexer.expect_punct('=')?;
+                lexer.expect_punct('{')?;
+                skip_block(lexer)?;
+            }
+            Token::Word("reorder") => {
+                lexer.expect_next()?;
+                lexer.expect_punct('=')?;
+                parse_value(lexer)?;
+            }
+            Token::Word(word) => parse_property(lexer, &mut prim, word)?,
+            token => return Err(lexer.error(format!("expected a property, found {token:?}"))),
+        }
+    }
+}
+
+/// Parses a property, after its first word.
+fn parse_property<'a>(
+    lexer: &mut Lexer<'a>,
+    prim: &mut Prim,
+    mut word: &'a str,
+) -> Result<(), UsdError> {
+    while matches!(
+        word,
+        "uniform" | "custom" | "varying" | "config" | "prepend" | "append" | "add" | "delete"
+    ) {
+        let Token::Word(next) = lexer.expect_next()? else {
+            return Err(lexer.error("expected the type of a property"));
+        };
+        word = next;
+    }
+    let mut type_name = word.to_string();
+    if lexer.eat_punct('[')? {
+        lexer.expect_punct(']')?;
+        type_name.push_str("[]");
+    }
+    let Token::Word(name) = lexer.expect_next()? else {
+        return Err(lexer.error("expected the name of a property"));
+    };
+
+    let (name, suffix) = match name.rsplit_once('.') {
+        Some((name, suffix @ ("connect" | "timeSamples" | "spline"))) => (name, Some(suffix)),
+        _ => (name, None),
+    };
+    let property = prim.properties.entry(name.to_string()).or_default();
+    if suffix.is_none() {
+        property.type_name = type_name;
+    }
+
+    if lexer.eat_punct('=')? {
+        let value = parse_value(lexer)?;
+        match suffix {
+            Some("connect") => {
+                property.connection = match value {
+                    Value::Path(path) => Some(path),
+                    Value::Array(paths) => paths.into_iter().find_map(|path| match path {
+                        Value::Path(path) => Some(path),
+                        _ => None,
+                    }),
+                    _ => None,
+                };
+            }
+            Some(_) => {}
+            None if property.type_name == "rel" => {
+                // Relationships may target several paths; keep the first one.
+                property.value = match value {
+                    Value::Array(mut targets) if !targets.is_empty() => Some(targets.remove(0)),
+                    value => Some(value),
+                };
+            }
+            None => property.value = Some(value),
+        }
+    }
+    if lexer.eat_punct('(')? {
+        let mut interpolation = None;
+        parse_metadata(lexer, &mut HashMap::default(), &mut interpolation)?;
+        if interpolation.is_some() {
+            property.interpolation = interpolation;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_layer() {
+        let layer = parse(
+            r#"#usda 1.0
+(
+    defaultPrim = "Root"
+    upAxis = "Z"
+    doc = """A
+    multiline documentation string."""
+)
+
+def Xform "Root" (
+    kind = "component"
+)
+{
+    double3 xformOp:translate = (1, 2, -3.5e1)
+    uniform token[] xformOpOrder = ["xformOp:translate"]
+
+    def Mesh "Quad"
+    {
+        int[] faceVertexCounts = [4]
+        int[] faceVertexIndices = [0, 1, 2, 3]
+        point3f[] points = [(0, 0, 0), (1, 0, 0), (1, 1, 0), (0, 1, 0)]
+        texCoord2f[] primvars:st = [(0, 0), (1, 0), (1, 1), (0, 1)] (
+            interpolation = "faceVarying"
+        )
+        rel material:binding = </Root/Red>
+        float3 xformOp:rotateXYZ.timeSamples = {
+            0: (0, 0, 0),
+        }
+    }
+
+    def Material "Red"
+    {
+        token outputs:surface.connect = </Root/Red/Surface.outputs:surface>
+        variantSet "shading" = {
+            "matte" { }
+        }
+    }
+}
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(layer.metadata["upAxis"], Value::String("Z".into()));
+        let root = &layer.prims[0];
+        assert_eq!(root.type_name.as_deref(), Some("Xform"));
+        assert_eq!(
+            root.value("xformOp:translate").unwrap().as_floats(),
+            Some([1.0, 2.0, -35.0])
+        );
+
+        let quad = &root.children[0];
+        assert_eq!(quad.name, "Quad");
+        assert_eq!(quad.value("points").unwrap().as_array().unwrap().len(), 4);
+        assert_eq!(
+            quad.properties["primvars:st"].interpolation.as_deref(),
+            Some("faceVarying")
+        );
+        assert_eq!(
+            quad.value("material:binding"),
+            Some(&Value::Path("/Root/Red".into()))
+        );
+        assert_eq!(quad.value("xformOp:rotateXYZ"), None);
+
+        let red = &root.children[1];
+        assert_eq!(
+            red.connection("outputs:surface"),
+            Some("/Root/Red/Surface.outputs:surface")
+        );
+    }
+}
@@ -271,6 +271,10 @@ pub trait BuildChildren {
 
     /// Inserts children at the given index.
     ///
+    /// The first of the children will be at `index` once inserted, or at the end if `index` is
+    /// greater than the number of other children. Children that were already children of this
+    /// entity are moved to the index.
+    ///
     /// The children's [`Parent`] component will be updated to the new parent.
     ///
     /// If the children were previously children of another parent, that parent's [`Children`] component
@@ -332,6 +336,52 @@ pub trait BuildChildren {
     /// Also removes this entity from its parent's [`Children`] component. Removing all children from a parent causes
     /// its [`Children`] component to be removed from the entity.
     fn remove_parent(&mut self) -> &mut Self;
+
+    /// Moves this entity right before `sibling` in the [`Children`] of the parent of `sibling`.
+    ///
+    /// If this entity had another parent, it becomes a child of the parent of `sibling`, as with
+    /// [`set_parent`](Self::set_parent). Does nothing if `sibling` has no parent, or is this entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent of `sibling` is this entity.
+    fn move_before(&mut self, sibling: Entity) -> &mut Self;
+
+    /// Moves this entity right after `sibling` in the [`Children`] of the parent of `sibling`.
+    ///
+    /// If this entity had another parent, it becomes a child of the parent of `sibling`, as with
+    /// [`set_parent`](Self::set_parent). Does nothing if `sibling` has no parent, or is this entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent of `sibling` is this entity.
+    fn move_after(&mut self, sibling: Entity) -> &mut Self;
+}
+
+/// Moves `child` right before `sibling` if `offset` is 0, or right after it if `offset` is 1.
+fn move_next_to(world: &mut World, child: Entity, sibling: Entity, offset: usize) {
+    if child == sibling {
+        return;
+    }
+    let Some(parent) = world.get::<Parent>(sibling).map(Parent::get) else {
+        return;
+    };
+    if parent == child {
+        panic!("Cannot move entity next to one of its children.");
+    }
+    // `insert_children` removes the child before inserting it, so the index is among the other
+    // children.
+    let Some(index) = world.get::<Children>(parent).and_then(|children| {
+        children
+            .iter()
+            .filter(|entity| **entity != child)
+            .position(|entity| *entity == sibling)
+    }) else {
+        return;
+    };
+    world
+        .entity_mut(parent)
+        .insert_children(index + offset, &[child]);
 }
 
 impl BuildChildren for EntityCommands<'_> {
@@ -436,6 +486,18 @@ impl BuildChildren for EntityCommands<'_> {
             entity.remove_parent();
         })
     }
+
+    fn move_before(&mut self, sibling: Entity) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.move_before(sibling);
+        })
+    }
+
+    fn move_after(&mut self, sibling: Entity) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.move_after(sibling);
+        })
+    }
 }
 
 /// Struct for adding children to an entity directly through the [`World`] for use in exclusive systems.
@@ -561,6 +623,7 @@ impl BuildChildren for EntityWorldMut<'_> {
             children_component
                 .0
                 .retain(|value| !children.contains(value));
+            let index = index.min(children_component.len());
             children_component.0.insert_from_slice(index, children);
         } else {
             self.insert(Children::from_entities(children));
@@ -610,6 +673,18 @@ impl BuildChildren for EntityWorldMut<'_> {
     fn replace_children(&mut self, children: &[Entity]) -> &mut Self {
         self.clear_children().add_children(children)
     }
+
+    fn move_before(&mut self, sibling: Entity) -> &mut Self {
+        let child = self.id();
+        self.world_scope(|world| move_next_to(world, child, sibling, 0));
+        self
+    }
+
+    fn move_after(&mut self, sibling: Entity) -> &mut Self {
+        let child = self.id();
+        self.world_scope(|world| move_next_to(world, child, sibling, 1));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -618,6 +693,7 @@ mod tests {
     use crate::{
         components::{Children, Parent},
         HierarchyEvent::{self, ChildAdded, ChildMoved, ChildRemoved},
+        HierarchyQueryExt,
     };
     use alloc::{vec, vec::Vec};
     use smallvec::{smallvec, SmallVec};
@@ -626,7 +702,7 @@ mod tests {
         component::Component,
         entity::Entity,
         event::Events,
-        system::Commands,
+        system::{Commands, Query, SystemState},
         world::{CommandQueue, World},
     };
 
@@ -1044,6 +1120,44 @@ mod tests {
         assert!(world.get::<Parent>(child2).is_none());
     }
 
+    #[test]
+    fn move_children_next_to_siblings() {
+        let mut world = World::default();
+        let [a, b, c, d, other_parent, other_child] =
+            core::array::from_fn(|_| world.spawn_empty().id());
+        let parent = world.spawn_empty().add_children(&[a, b, c]).id();
+        world.entity_mut(other_parent).add_child(other_child);
+
+        // Indices out of bounds insert at the end rather than panicking.
+        world.entity_mut(parent).insert_children(10, &[a]);
+        assert_children(&world, parent, Some(&[b, c, a]));
+
+        world.entity_mut(a).move_before(b);
+        assert_children(&world, parent, Some(&[a, b, c]));
+        world.entity_mut(a).move_after(c);
+        assert_children(&world, parent, Some(&[b, c, a]));
+        world.entity_mut(a).move_after(b);
+        assert_children(&world, parent, Some(&[b, a, c]));
+        world.entity_mut(c).move_before(c);
+        assert_children(&world, parent, Some(&[b, a, c]));
+
+        // Moving next to a sibling with another parent reparents the entity.
+        world.entity_mut(other_child).move_before(a);
+        assert_children(&world, parent, Some(&[b, other_child, a, c]));
+        assert_children(&world, other_parent, None);
+        assert_parent(&world, other_child, Some(parent));
+
+        // Moving next to an entity without a parent does nothing.
+        world.entity_mut(a).move_before(d);
+        assert_parent(&world, a, Some(parent));
+
+        let mut system_state =
+            SystemState::<Query<(Option<&Parent>, Option<&Children>)>>::new(&mut world);
+        let query = system_state.get(&world);
+        assert_eq!(query.sibling_index(a), Some(2));
+        assert_eq!(query.sibling_index(d), None);
+    }
+
     #[test]
     fn push_and_replace_children_world() {
         let mut world = World::default();
@@ -15,6 +15,12 @@ use smallvec::SmallVec;
 /// Contains references to the child entities of this entity.
 ///
 /// Each child must contain a [`Parent`] component that points back to this entity.
+///
+/// The order of the children is meaningful, for example for UI layout, and is stable: it is only
+/// changed by operations explicitly inserting, moving or sorting children, such as
+/// [`BuildChildren::insert_children`] or [`BuildChildren::move_before`], and it is kept when
+/// serializing the hierarchy through reflection, such as in scenes.
+///
 /// This component rarely needs to be created manually,
 /// consider using higher level utilities like [`BuildChildren::with_children`]
 /// which are safer and easier to use.
@@ -25,6 +31,8 @@ use smallvec::SmallVec;
 /// [`Query`]: bevy_ecs::system::Query
 /// [`Parent`]: crate::components::parent::Parent
 /// [`BuildChildren::with_children`]: crate::child_builder::BuildChildren::with_children
+/// [`BuildChildren::insert_children`]: crate::child_builder::BuildChildren::insert_children
+/// [`BuildChildren::move_before`]: crate::child_builder::BuildChildren::move_before
 #[derive(Debug, VisitEntitiesMut)]
 #[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(
@@ -67,6 +75,12 @@ impl Children {
         Self(SmallVec::from_slice(entities))
     }
 
+    /// Returns the index of `child` among the children, if it is one of them.
+    #[inline]
+    pub fn index_of(&self, child: Entity) -> Option<usize> {
+        self.0.iter().position(|entity| *entity == child)
+    }
+
     /// Swaps the child at `a_index` with the child at `b_index`.
     #[inline]
     pub fn swap(&mut self, a_index: usize, b_index: usize) {
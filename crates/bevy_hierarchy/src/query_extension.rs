@@ -48,6 +48,11 @@ pub trait HierarchyQueryExt<'w, 's, D: QueryData, F: QueryFilter> {
     where
         D::ReadOnly: WorldQuery<Item<'w> = (Option<&'w Parent>, Option<&'w Children>)>;
 
+    /// Returns the index of `entity` among the [`Children`] of its parent, if it has one.
+    fn sibling_index(&'w self, entity: Entity) -> Option<usize>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = (Option<&'w Parent>, Option<&'w Children>)>;
+
     /// Returns an [`Iterator`] of [`Entity`]s over all of `entity`s descendants.
     ///
     /// Can only be called on a [`Query`] of [`Children`] (i.e. `Query<&Children>`).
@@ -160,6 +165,15 @@ impl<'w, 's, D: QueryData, F: QueryFilter> HierarchyQueryExt<'w, 's, D, F> for Q
             .copied()
     }
 
+    fn sibling_index(&'w self, entity: Entity) -> Option<usize>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = (Option<&'w Parent>, Option<&'w Children>)>,
+    {
+        let (parent, _) = self.get(entity).ok()?;
+        let (_, children) = self.get(parent?.get()).ok()?;
+        children?.index_of(entity)
+    }
+
     fn iter_descendants(&'w self, entity: Entity) -> DescendantIter<'w, 's, D, F>
     where
         D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
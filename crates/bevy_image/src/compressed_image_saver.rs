@@ -1,8 +1,12 @@
-use crate::{Image, ImageFormat, ImageFormatSetting, ImageLoader, ImageLoaderSettings};
+use crate::{
+    Image, ImageFormat, ImageFormatSetting, ImageLoader, ImageLoaderSettings, IntoDynamicImageError,
+};
 
 use bevy_asset::saver::{AssetSaver, SavedAsset};
 use futures_lite::AsyncWriteExt;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use wgpu_types::TextureFormat;
 
 pub struct CompressedImageSaver;
 
@@ -11,12 +15,364 @@ pub struct CompressedImageSaver;
 pub enum CompressedImageSaverError {
     #[error(transparent)]
     Io(#[from] std::io::Error),
+    #[error(transparent)]
+    IntoDynamicImage(#[from] IntoDynamicImageError),
+    /// The basis-universal compressor failed.
+    #[error("failed to compress the image: {0}")]
+    Compression(String),
+}
+
+/// The codec the [`CompressedImageSaver`] compresses images with.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompressedImageCodec {
+    /// High quality, at 8 bits per texel, which is transcoded to BC7 or ASTC on most GPUs.
+    #[default]
+    Uastc,
+    /// Lower quality, at 1 to 2 bits per texel, for when download size matters most.
+    Etc1s,
+}
+
+/// The container the [`CompressedImageSaver`] writes compressed images to.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompressedImageContainer {
+    /// A `.basis` file, which supports both codecs.
+    #[default]
+    Basis,
+    /// A KTX2 file, which supports both codecs.
+    #[cfg(feature = "ktx2")]
+    Ktx2 {
+        /// Whether to supercompress the mipmap levels of the file with zlib, which makes files
+        /// smaller at the cost of decompressing them when loading.
+        ///
+        /// ETC1S textures are always supercompressed with `BasisLZ`, so this only applies to
+        /// [`CompressedImageCodec::Uastc`].
+        #[cfg(feature = "flate2")]
+        zlib: bool,
+    },
+}
+
+/// Per-asset settings for the [`CompressedImageSaver`], set in the meta file of an image.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressedImageSaverSettings {
+    pub codec: CompressedImageCodec,
+    pub container: CompressedImageContainer,
+    /// The quality level of [`CompressedImageCodec::Uastc`], from
+    /// [`basis_universal::UASTC_QUALITY_MIN`] to [`basis_universal::UASTC_QUALITY_MAX`]. Higher
+    /// levels compress better, but more slowly.
+    pub uastc_quality: u32,
+    /// The quality level of [`CompressedImageCodec::Etc1s`], from
+    /// [`basis_universal::ETC1S_QUALITY_MIN`] to [`basis_universal::ETC1S_QUALITY_MAX`].
+    pub etc1s_quality: u32,
+    /// Whether to generate a full chain of mipmaps, down to 1x1.
+    pub generate_mipmaps: bool,
+    /// Whether the image is a normal map, which tunes the compression for normals and keeps
+    /// them linear.
+    pub normal_map: bool,
+}
+
+impl Default for CompressedImageSaverSettings {
+    fn default() -> Self {
+        Self {
+            codec: CompressedImageCodec::default(),
+            container: CompressedImageContainer::default(),
+            uastc_quality: basis_universal::UASTC_QUALITY_DEFAULT,
+            etc1s_quality: basis_universal::ETC1S_QUALITY_DEFAULT,
+            generate_mipmaps: true,
+            normal_map: false,
+        }
+    }
+}
+
+impl Image {
+    /// Compresses the first layer of the image with basis-universal, returning the file
+    /// described by the settings.
+    pub fn to_compressed_buffer(
+        &self,
+        settings: &CompressedImageSaverSettings,
+    ) -> Result<Vec<u8>, CompressedImageSaverError> {
+        let is_srgb = self.texture_descriptor.format.is_srgb() && !settings.normal_map;
+        let size = self.size();
+        let rgba;
+        let data = match self.texture_descriptor.format {
+            TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => {
+                &self.data[..(size.x * size.y * 4) as usize]
+            }
+            _ => {
+                rgba = self.clone().try_into_dynamic()?.into_rgba8();
+                rgba.as_raw()
+            }
+        };
+
+        let mut compressor_params = basis_universal::CompressorParams::new();
+        match settings.codec {
+            CompressedImageCodec::Uastc => {
+                compressor_params.set_basis_format(basis_universal::BasisTextureFormat::UASTC4x4);
+                compressor_params.set_uastc_quality_level(settings.uastc_quality);
+            }
+            CompressedImageCodec::Etc1s => {
+                compressor_params.set_basis_format(basis_universal::BasisTextureFormat::ETC1S);
+                compressor_params.set_etc1s_quality_level(settings.etc1s_quality);
+            }
+        }
+        compressor_params.set_generate_mipmaps(settings.generate_mipmaps);
+        let color_space = if is_srgb {
+            basis_universal::ColorSpace::Srgb
+        } else {
+            basis_universal::ColorSpace::Linear
+        };
+        compressor_params.set_color_space(color_space);
+        if settings.normal_map {
+            compressor_params.tune_for_normal_maps();
+        }
+
+        let mut source_image = compressor_params.source_image_mut(0);
+        source_image.init(data, size.x, size.y, 4);
+
+        let mut compressor = basis_universal::Compressor::new(4);
+        #[expect(
+            unsafe_code,
+            reason = "The basis-universal compressor cannot be interacted with except through unsafe functions"
+        )]
+        // SAFETY: the CompressorParams are "valid" to the best of our knowledge. The basis-universal
+        // library bindings note that invalid params might produce undefined behavior.
+        unsafe {
+            compressor.init(&compressor_params);
+            compressor
+                .process()
+                .map_err(|error| CompressedImageSaverError::Compression(format!("{error:?}")))?;
+        }
+
+        match settings.container {
+            CompressedImageContainer::Basis => Ok(compressor.basis_file().to_vec()),
+            #[cfg(feature = "ktx2")]
+            CompressedImageContainer::Ktx2 {
+                #[cfg(feature = "flate2")]
+                zlib,
+            } => {
+                #[cfg(not(feature = "flate2"))]
+                let zlib = false;
+                basis_to_ktx2(
+                    compressor.basis_file(),
+                    settings.codec,
+                    compressor.any_source_image_has_alpha(),
+                    is_srgb,
+                    zlib,
+                )
+            }
+        }
+    }
+}
+
+/// Copies the levels of a `.basis` file to a KTX2 file.
+///
+/// ETC1S levels are supercompressed with `BasisLZ`, whose global data holds the codebooks of the
+/// `.basis` file and the location of the color and alpha slices in each level.
+#[cfg(feature = "ktx2")]
+fn basis_to_ktx2(
+    basis: &[u8],
+    codec: CompressedImageCodec,
+    has_alpha: bool,
+    is_srgb: bool,
+    zlib: bool,
+) -> Result<Vec<u8>, CompressedImageSaverError> {
+    const IDENTIFIER: [u8; 12] = [
+        0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+    ];
+    const HEADER_SIZE: usize = 80;
+    const LEVEL_INDEX_ENTRY_SIZE: usize = 24;
+    const UASTC_BLOCK_SIZE: usize = 16;
+
+    let transcoder = basis_universal::Transcoder::new();
+    let invalid_basis = || CompressedImageSaverError::Compression("invalid .basis file".into());
+    let slice = |offset: u32, length: u32| {
+        basis
+            .get(offset as usize..(offset + length) as usize)
+            .ok_or_else(invalid_basis)
+    };
+    let etc1s = codec == CompressedImageCodec::Etc1s;
+    let zlib = zlib && !etc1s;
+    let level_count = transcoder.image_level_count(basis, 0);
+    let mut levels = Vec::new();
+    let mut image_descs = Vec::new();
+    let (mut width, mut height) = (0, 0);
+    for level in 0..level_count {
+        let info = transcoder
+            .image_level_info(basis, 0, level)
+            .ok_or_else(invalid_basis)?;
+        if level == 0 {
+            (width, height) = (info.m_orig_width, info.m_orig_height);
+        }
+        let mut data = slice(info.m_rgb_file_ofs, info.m_rgb_file_len)?.to_vec();
+        if etc1s {
+            let alpha = if has_alpha {
+                slice(info.m_alpha_file_ofs, info.m_alpha_file_len)?
+            } else {
+                &[]
+            };
+            // The image flags, then the offset and length of the color and alpha slices.
+            let alpha_offset = if has_alpha { data.len() } else { 0 };
+            image_descs.push([0, 0, data.len(), alpha_offset, alpha.len()]);
+            data.extend_from_slice(alpha);
+        }
+        levels.push(data);
+    }
+    #[cfg(feature = "flate2")]
+    if zlib {
+        use std::io::Write;
+        for level in &mut levels {
+            let mut encoder =
+                flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::best());
+            encoder.write_all(level)?;
+            *level = encoder.finish()?;
+        }
+    }
+
+    // The supercompression global data of BasisLZ: the number of endpoints and selectors, the
+    // lengths of the codebooks, the image descriptions and then the codebooks themselves.
+    let mut global_data = Vec::new();
+    if etc1s {
+        let field = |offset: usize, size: usize| {
+            let mut value = [0; 4];
+            value[..size].copy_from_slice(&basis[offset..offset + size]);
+            u32::from_le_bytes(value)
+        };
+        // The offsets and lengths of the endpoints, selectors, tables and extended data in the
+        // `.basis` file header.
+        let codebooks = [
+            (field(41, 4), field(45, 3)),
+            (field(50, 4), field(54, 3)),
+            (field(57, 4), field(61, 4)),
+            (field(69, 4), field(73, 4)),
+        ];
+        global_data.extend_from_slice(&(field(39, 2) as u16).to_le_bytes());
+        global_data.extend_from_slice(&(field(48, 2) as u16).to_le_bytes());
+        for (_, length) in codebooks {
+            global_data.extend_from_slice(&length.to_le_bytes());
+        }
+        for value in image_descs.into_iter().flatten() {
+            global_data.extend_from_slice(&(value as u32).to_le_bytes());
+        }
+        for (offset, length) in codebooks {
+            global_data.extend_from_slice(slice(offset, length)?);
+        }
+    }
+
+    // The data format descriptor, with the basic block describing the samples of a block.
+    let (color_model, bytes_plane, samples): (u8, u8, &[(u16, u8, u8)]) = match codec {
+        // All 128 bits of the block, in the RGB or RGBA channels.
+        CompressedImageCodec::Uastc => (
+            166,
+            // Bytes per plane, which is 0 for supercompressed data.
+            if zlib { 0 } else { UASTC_BLOCK_SIZE as u8 },
+            if has_alpha {
+                &[(0, 127, 3)]
+            } else {
+                &[(0, 127, 0)]
+            },
+        ),
+        // The 64 bits of the color slice in the RGB channels, followed by the alpha slice.
+        CompressedImageCodec::Etc1s => (
+            163,
+            0,
+            if has_alpha {
+                &[(0, 63, 0), (64, 63, 15)]
+            } else {
+                &[(0, 63, 0)]
+            },
+        ),
+    };
+    let mut dfd = Vec::new();
+    let dfd_size = 4 + 24 + 16 * samples.len();
+    dfd.extend_from_slice(&(dfd_size as u32).to_le_bytes());
+    // Vendor id and descriptor type, version number and block size.
+    dfd.extend_from_slice(&0u32.to_le_bytes());
+    dfd.extend_from_slice(&2u16.to_le_bytes());
+    dfd.extend_from_slice(&((dfd_size - 4) as u16).to_le_bytes());
+    // The color model, BT.709 primaries, the transfer function and straight alpha.
+    dfd.extend_from_slice(&[color_model, 1, if is_srgb { 2 } else { 1 }, 0]);
+    // Texel block dimensions minus one.
+    dfd.extend_from_slice(&[3, 3, 0, 0]);
+    dfd.extend_from_slice(&[bytes_plane, 0, 0, 0, 0, 0, 0, 0]);
+    for &(bit_offset, bit_length, channel_type) in samples {
+        dfd.extend_from_slice(&bit_offset.to_le_bytes());
+        dfd.extend_from_slice(&[bit_length, channel_type]);
+        dfd.extend_from_slice(&[0; 4]);
+        dfd.extend_from_slice(&0u32.to_le_bytes());
+        dfd.extend_from_slice(&u32::MAX.to_le_bytes());
+    }
+
+    // The supercompression scheme, 1 being BasisLZ and 3 zlib.
+    let supercompression_scheme: u32 = match (etc1s, zlib) {
+        (true, _) => 1,
+        (false, true) => 3,
+        (false, false) => 0,
+    };
+    // Supercompressed levels are not aligned to blocks.
+    let alignment = if supercompression_scheme == 0 {
+        UASTC_BLOCK_SIZE
+    } else {
+        1
+    };
+    let dfd_offset = HEADER_SIZE + LEVEL_INDEX_ENTRY_SIZE * levels.len();
+    let global_data_offset = if global_data.is_empty() {
+        0
+    } else {
+        (dfd_offset + dfd.len()).next_multiple_of(8)
+    };
+    let mut data_offset = (dfd_offset + dfd.len())
+        .max(global_data_offset + global_data.len())
+        .next_multiple_of(alignment);
+    // Levels are stored from the smallest to the largest.
+    let mut level_offsets = vec![0; levels.len()];
+    for (level, data) in levels.iter().enumerate().rev() {
+        level_offsets[level] = data_offset;
+        data_offset = (data_offset + data.len()).next_multiple_of(alignment);
+    }
+
+    let mut ktx2 = Vec::with_capacity(data_offset);
+    ktx2.extend_from_slice(&IDENTIFIER);
+    // The format is undefined, for a type size of 1.
+    ktx2.extend_from_slice(&0u32.to_le_bytes());
+    ktx2.extend_from_slice(&1u32.to_le_bytes());
+    for value in [width, height, 0, 0, 1, level_count, supercompression_scheme] {
+        ktx2.extend_from_slice(&value.to_le_bytes());
+    }
+    ktx2.extend_from_slice(&(dfd_offset as u32).to_le_bytes());
+    ktx2.extend_from_slice(&(dfd.len() as u32).to_le_bytes());
+    // There are no key/value pairs.
+    ktx2.extend_from_slice(&[0; 8]);
+    ktx2.extend_from_slice(&(global_data_offset as u64).to_le_bytes());
+    ktx2.extend_from_slice(&(global_data.len() as u64).to_le_bytes());
+    for (level, data) in levels.iter().enumerate() {
+        let info = transcoder
+            .image_level_info(basis, 0, level as u32)
+            .ok_or_else(invalid_basis)?;
+        // The uncompressed size of BasisLZ levels is unknown.
+        let uncompressed_size = if etc1s {
+            0
+        } else {
+            info.m_total_blocks as usize * UASTC_BLOCK_SIZE
+        };
+        for value in [level_offsets[level], data.len(), uncompressed_size] {
+            ktx2.extend_from_slice(&(value as u64).to_le_bytes());
+        }
+    }
+    ktx2.extend_from_slice(&dfd);
+    if !global_data.is_empty() {
+        ktx2.resize(global_data_offset, 0);
+        ktx2.extend_from_slice(&global_data);
+    }
+    for (level, data) in levels.iter().enumerate().rev() {
+        ktx2.resize(level_offsets[level], 0);
+        ktx2.extend_from_slice(data);
+    }
+    Ok(ktx2)
 }
 
 impl AssetSaver for CompressedImageSaver {
     type Asset = Image;
 
-    type Settings = ();
+    type Settings = CompressedImageSaverSettings;
     type OutputLoader = ImageLoader;
     type Error = CompressedImageSaverError;
 
@@ -24,46 +380,152 @@ impl AssetSaver for CompressedImageSaver {
         &self,
         writer: &mut bevy_asset::io::Writer,
         image: SavedAsset<'_, Self::Asset>,
-        _settings: &Self::Settings,
+        settings: &Self::Settings,
     ) -> Result<ImageLoaderSettings, Self::Error> {
-        let is_srgb = image.texture_descriptor.format.is_srgb();
-
-        let compressed_basis_data = {
-            let mut compressor_params = basis_universal::CompressorParams::new();
-            compressor_params.set_basis_format(basis_universal::BasisTextureFormat::UASTC4x4);
-            compressor_params.set_generate_mipmaps(true);
-            let color_space = if is_srgb {
-                basis_universal::ColorSpace::Srgb
-            } else {
-                basis_universal::ColorSpace::Linear
-            };
-            compressor_params.set_color_space(color_space);
-            compressor_params.set_uastc_quality_level(basis_universal::UASTC_QUALITY_DEFAULT);
-
-            let mut source_image = compressor_params.source_image_mut(0);
-            let size = image.size();
-            source_image.init(&image.data, size.x, size.y, 4);
-
-            let mut compressor = basis_universal::Compressor::new(4);
-            #[expect(
-                unsafe_code,
-                reason = "The basis-universal compressor cannot be interacted with except through unsafe functions"
-            )]
-            // SAFETY: the CompressorParams are "valid" to the best of our knowledge. The basis-universal
-            // library bindings note that invalid params might produce undefined behavior.
-            unsafe {
-                compressor.init(&compressor_params);
-                compressor.process().unwrap();
-            }
-            compressor.basis_file().to_vec()
+        let compressed_data = image.to_compressed_buffer(settings)?;
+        writer.write_all(&compressed_data).await?;
+        let format = match settings.container {
+            CompressedImageContainer::Basis => ImageFormat::Basis,
+            #[cfg(feature = "ktx2")]
+            CompressedImageContainer::Ktx2 { .. } => ImageFormat::Ktx2,
         };
-
-        writer.write_all(&compressed_basis_data).await?;
         Ok(ImageLoaderSettings {
-            format: ImageFormatSetting::Format(ImageFormat::Basis),
-            is_srgb,
+            format: ImageFormatSetting::Format(format),
+            is_srgb: image.texture_descriptor.format.is_srgb() && !settings.normal_map,
             sampler: image.sampler.clone(),
             asset_usage: image.asset_usage,
         })
     }
 }
+
+#[cfg(all(test, feature = "ktx2"))]
+mod tests {
+    use super::*;
+    use crate::{ktx2_buffer_to_image, CompressedImageFormats};
+    use bevy_asset::RenderAssetUsages;
+    use wgpu_types::{Extent3d, TextureDimension};
+
+    fn image() -> Image {
+        Image::new_fill(
+            Extent3d {
+                width: 8,
+                height: 4,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[255, 128, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+
+    fn save_ktx2(settings: CompressedImageSaverSettings) {
+        let image = image();
+        let buffer = image.to_compressed_buffer(&settings).unwrap();
+        let loaded = ktx2_buffer_to_image(&buffer, CompressedImageFormats::BC, true).unwrap();
+        assert_eq!(
+            loaded.texture_descriptor.format,
+            TextureFormat::Bc7RgbaUnormSrgb
+        );
+        assert_eq!(loaded.size(), image.size());
+        assert_eq!(loaded.texture_descriptor.mip_level_count, 4);
+        // Each mip is a single 16 byte block.
+        assert_eq!(loaded.data.len(), 2 * 16 + 3 * 16);
+    }
+
+    #[test]
+    fn save_uastc_ktx2() {
+        save_ktx2(CompressedImageSaverSettings {
+            container: CompressedImageContainer::Ktx2 {
+                #[cfg(feature = "flate2")]
+                zlib: false,
+            },
+            ..Default::default()
+        });
+    }
+
+    #[cfg(feature = "flate2")]
+    #[test]
+    fn save_uastc_ktx2_zlib() {
+        save_ktx2(CompressedImageSaverSettings {
+            container: CompressedImageContainer::Ktx2 { zlib: true },
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn transcode_single_channel_uastc_ktx2() {
+        let settings = CompressedImageSaverSettings {
+            container: CompressedImageContainer::Ktx2 {
+                #[cfg(feature = "flate2")]
+                zlib: false,
+            },
+            ..Default::default()
+        };
+        let mut buffer = image().to_compressed_buffer(&settings).unwrap();
+        // Mark the sample as a red channel, which is transcoded to BC4 and its 8 byte blocks.
+        let dfd_offset = u32::from_le_bytes(buffer[48..52].try_into().unwrap()) as usize;
+        buffer[dfd_offset + 4 + 24 + 3] = 4;
+        let loaded = ktx2_buffer_to_image(&buffer, CompressedImageFormats::BC, false).unwrap();
+        assert_eq!(loaded.texture_descriptor.format, TextureFormat::Bc4RUnorm);
+        // The levels are still read as 16 byte UASTC blocks.
+        assert_eq!(loaded.data.len(), 2 * 8 + 3 * 8);
+    }
+
+    #[test]
+    fn save_etc1s_ktx2() {
+        let settings = CompressedImageSaverSettings {
+            codec: CompressedImageCodec::Etc1s,
+            container: CompressedImageContainer::Ktx2 {
+                #[cfg(feature = "flate2")]
+                zlib: false,
+            },
+            ..Default::default()
+        };
+        let buffer = image().to_compressed_buffer(&settings).unwrap();
+        let header = ktx2::Reader::new(&buffer[..]).unwrap().header();
+        assert_eq!(
+            header.supercompression_scheme,
+            Some(ktx2::SupercompressionScheme::BasisLZ)
+        );
+        save_ktx2(settings);
+
+        // Without BC7, the levels are transcoded to RGBA.
+        let loaded = ktx2_buffer_to_image(&buffer, CompressedImageFormats::NONE, true).unwrap();
+        assert_eq!(
+            loaded.texture_descriptor.format,
+            TextureFormat::Rgba8UnormSrgb
+        );
+        assert_eq!(loaded.data.len(), (8 * 4 + 4 * 2 + 2 + 1) * 4);
+        // ETC1S is lossy, even for a single color.
+        assert_close(&loaded.data[..4], &[255, 128, 0, 255]);
+    }
+
+    #[test]
+    fn save_etc1s_ktx2_with_alpha() {
+        let mut image = image();
+        for pixel in image.data.chunks_mut(4) {
+            pixel[3] = 64;
+        }
+        let settings = CompressedImageSaverSettings {
+            codec: CompressedImageCodec::Etc1s,
+            container: CompressedImageContainer::Ktx2 {
+                #[cfg(feature = "flate2")]
+                zlib: false,
+            },
+            ..Default::default()
+        };
+        let buffer = image.to_compressed_buffer(&settings).unwrap();
+        let loaded = ktx2_buffer_to_image(&buffer, CompressedImageFormats::NONE, true).unwrap();
+        assert_close(&loaded.data[..4], &[255, 128, 0, 64]);
+    }
+
+    fn assert_close(actual: &[u8], expected: &[u8]) {
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!(
+                actual.abs_diff(*expected) <= 8,
+                "{actual:?} != {expected:?}"
+            );
+        }
+    }
+}
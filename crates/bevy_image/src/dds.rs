@@ -1,6 +1,8 @@
 //! [DirectDraw Surface](https://en.wikipedia.org/wiki/DirectDraw_Surface) functionality.
 
-use ddsfile::{Caps2, D3DFormat, Dds, DxgiFormat};
+use ddsfile::{
+    AlphaMode, Caps2, D3D10ResourceDimension, D3DFormat, Dds, DxgiFormat, MiscFlag, NewDxgiParams,
+};
 use std::io::Cursor;
 use wgpu::TextureViewDescriptor;
 use wgpu_types::{Extent3d, TextureDimension, TextureFormat, TextureViewDimension};
@@ -26,13 +28,23 @@ pub fn dds_buffer_to_image(
         )));
     }
     let mut image = Image::default();
-    let is_cubemap = dds.header.caps2.contains(Caps2::CUBEMAP);
-    let depth_or_array_layers = if dds.get_num_array_layers() > 1 {
-        dds.get_num_array_layers()
+    let is_dx10_cubemap = dds
+        .header10
+        .as_ref()
+        .is_some_and(|header10| header10.misc_flag.contains(MiscFlag::TEXTURECUBE));
+    let is_cubemap = is_dx10_cubemap || dds.header.caps2.contains(Caps2::CUBEMAP);
+    // The array size of DX10 cubemaps counts cubes rather than faces.
+    let array_layers = match &dds.header10 {
+        Some(_) if is_cubemap => dds.get_num_array_layers().max(1) * 6,
+        _ => dds.get_num_array_layers().max(1),
+    };
+    let depth_or_array_layers = if array_layers > 1 {
+        array_layers
     } else {
         dds.get_depth()
     };
     if is_cubemap
+        && !is_dx10_cubemap
         && !dds.header.caps2.contains(
             Caps2::CUBEMAP_NEGATIVEX
                 | Caps2::CUBEMAP_NEGATIVEY
@@ -62,6 +74,9 @@ pub fn dds_buffer_to_image(
         t => t,
     };
     image.texture_descriptor.mip_level_count = mip_map_level;
+    if is_cubemap && !depth_or_array_layers.is_multiple_of(6) {
+        return Err(TextureError::IncompleteCubemap);
+    }
     image.texture_descriptor.format = texture_format;
     image.texture_descriptor.dimension = if dds.get_depth() > 1 {
         TextureDimension::D3
@@ -70,21 +85,133 @@ pub fn dds_buffer_to_image(
     } else {
         TextureDimension::D1
     };
-    if is_cubemap {
-        let dimension = if image.texture_descriptor.size.depth_or_array_layers > 6 {
-            TextureViewDimension::CubeArray
+    let view_dimension = if is_cubemap {
+        if depth_or_array_layers > 6 {
+            Some(TextureViewDimension::CubeArray)
         } else {
-            TextureViewDimension::Cube
-        };
+            Some(TextureViewDimension::Cube)
+        }
+    } else if array_layers > 1 {
+        Some(TextureViewDimension::D2Array)
+    } else {
+        None
+    };
+    if let Some(dimension) = view_dimension {
         image.texture_view_descriptor = Some(TextureViewDescriptor {
             dimension: Some(dimension),
             ..Default::default()
         });
     }
-    image.data = dds.data;
+
+    // Layers are stored one after the other, each with all of its mips, which is the order
+    // wgpu expects by default.
+    let data_size = texture_data_size(&image);
+    let mut data = dds.data;
+    if data.len() < data_size {
+        return Err(TextureError::InvalidData(format!(
+            "DDS file has {} bytes of texture data, expected {data_size}",
+            data.len()
+        )));
+    }
+    data.truncate(data_size);
+    image.data = data;
     Ok(image)
 }
 
+/// Encodes an [`Image`] as a DDS file, with all of its layers and mips.
+///
+/// Cubemaps are recognized by the [`TextureViewDimension`] of the image's
+/// `texture_view_descriptor`.
+#[cfg(feature = "dds")]
+pub fn image_to_dds_buffer(image: &Image) -> Result<Vec<u8>, TextureError> {
+    let descriptor = &image.texture_descriptor;
+    let format = texture_format_to_dxgi_format(descriptor.format)?;
+    let is_cubemap = image
+        .texture_view_descriptor
+        .as_ref()
+        .and_then(|descriptor| descriptor.dimension)
+        .is_some_and(|dimension| {
+            matches!(
+                dimension,
+                TextureViewDimension::Cube | TextureViewDimension::CubeArray
+            )
+        });
+    if is_cubemap && !descriptor.size.depth_or_array_layers.is_multiple_of(6) {
+        return Err(TextureError::IncompleteCubemap);
+    }
+    let (depth, array_layers, resource_dimension) = match descriptor.dimension {
+        TextureDimension::D1 => (
+            None,
+            descriptor.size.depth_or_array_layers,
+            D3D10ResourceDimension::Texture1D,
+        ),
+        TextureDimension::D2 => (
+            None,
+            descriptor.size.depth_or_array_layers,
+            D3D10ResourceDimension::Texture2D,
+        ),
+        TextureDimension::D3 => (
+            Some(descriptor.size.depth_or_array_layers),
+            1,
+            D3D10ResourceDimension::Texture3D,
+        ),
+    };
+    let data_size = texture_data_size(image);
+    if image.data.len() != data_size {
+        return Err(TextureError::InvalidData(format!(
+            "image has {} bytes of texture data, expected {data_size}",
+            image.data.len()
+        )));
+    }
+    let mut dds = Dds::new_dxgi(NewDxgiParams {
+        height: descriptor.size.height,
+        width: descriptor.size.width,
+        depth,
+        format,
+        mipmap_levels: Some(descriptor.mip_level_count),
+        array_layers: Some(array_layers),
+        caps2: is_cubemap.then_some(Caps2::CUBEMAP | Caps2::CUBEMAP_ALLFACES),
+        is_cubemap,
+        resource_dimension,
+        alpha_mode: AlphaMode::Unknown,
+    })
+    .map_err(|error| TextureError::InvalidData(format!("Failed to create DDS file: {error}")))?;
+    dds.data.clone_from(&image.data);
+    let mut buffer = Vec::new();
+    dds.write(&mut buffer)
+        .map_err(|error| TextureError::InvalidData(format!("Failed to write DDS file: {error}")))?;
+    Ok(buffer)
+}
+
+/// Returns the size of the data of all the layers and mips of an image.
+fn texture_data_size(image: &Image) -> usize {
+    let descriptor = &image.texture_descriptor;
+    let format = descriptor.format;
+    let block_size = format.block_copy_size(None).unwrap_or(4) as usize;
+    let (block_width, block_height) = format.block_dimensions();
+    let layers = match descriptor.dimension {
+        TextureDimension::D3 => 1,
+        TextureDimension::D1 | TextureDimension::D2 => {
+            descriptor.size.depth_or_array_layers as usize
+        }
+    };
+    let layer_size = (0..descriptor.mip_level_count)
+        .filter_map(|mip| descriptor.mip_level_size(mip))
+        .map(|size| {
+            let depth = match descriptor.dimension {
+                TextureDimension::D3 => size.depth_or_array_layers,
+                TextureDimension::D1 | TextureDimension::D2 => 1,
+            };
+            let size = size.physical_size(format);
+            (size.width / block_width) as usize
+                * (size.height / block_height) as usize
+                * depth as usize
+                * block_size
+        })
+        .sum::<usize>();
+    layers * layer_size
+}
+
 #[cfg(feature = "dds")]
 pub fn dds_format_to_texture_format(
     dds: &Dds,
@@ -165,6 +292,17 @@ pub fn dds_format_to_texture_format(
             }
         }
     } else if let Some(dxgi_format) = dds.get_dxgi_format() {
+        // Formats which are explicitly sRGB are always loaded as such.
+        let is_srgb = is_srgb
+            || matches!(
+                dxgi_format,
+                DxgiFormat::R8G8B8A8_UNorm_sRGB
+                    | DxgiFormat::BC1_UNorm_sRGB
+                    | DxgiFormat::BC2_UNorm_sRGB
+                    | DxgiFormat::BC3_UNorm_sRGB
+                    | DxgiFormat::B8G8R8A8_UNorm_sRGB
+                    | DxgiFormat::BC7_UNorm_sRGB
+            );
         match dxgi_format {
             DxgiFormat::R32G32B32A32_Typeless | DxgiFormat::R32G32B32A32_Float => {
                 TextureFormat::Rgba32Float
@@ -281,14 +419,86 @@ pub fn dds_format_to_texture_format(
     })
 }
 
+/// Returns the [`DxgiFormat`] DDS files store textures of `format` with.
+#[cfg(feature = "dds")]
+pub fn texture_format_to_dxgi_format(format: TextureFormat) -> Result<DxgiFormat, TextureError> {
+    Ok(match format {
+        TextureFormat::Rgba32Float => DxgiFormat::R32G32B32A32_Float,
+        TextureFormat::Rgba32Uint => DxgiFormat::R32G32B32A32_UInt,
+        TextureFormat::Rgba32Sint => DxgiFormat::R32G32B32A32_SInt,
+        TextureFormat::Rgba16Float => DxgiFormat::R16G16B16A16_Float,
+        TextureFormat::Rgba16Unorm => DxgiFormat::R16G16B16A16_UNorm,
+        TextureFormat::Rgba16Uint => DxgiFormat::R16G16B16A16_UInt,
+        TextureFormat::Rgba16Snorm => DxgiFormat::R16G16B16A16_SNorm,
+        TextureFormat::Rgba16Sint => DxgiFormat::R16G16B16A16_SInt,
+        TextureFormat::Rg32Float => DxgiFormat::R32G32_Float,
+        TextureFormat::Rg32Uint => DxgiFormat::R32G32_UInt,
+        TextureFormat::Rg32Sint => DxgiFormat::R32G32_SInt,
+        TextureFormat::Rgb10a2Unorm => DxgiFormat::R10G10B10A2_UNorm,
+        TextureFormat::Rg11b10Ufloat => DxgiFormat::R11G11B10_Float,
+        TextureFormat::Rgba8Unorm => DxgiFormat::R8G8B8A8_UNorm,
+        TextureFormat::Rgba8UnormSrgb => DxgiFormat::R8G8B8A8_UNorm_sRGB,
+        TextureFormat::Rgba8Uint => DxgiFormat::R8G8B8A8_UInt,
+        TextureFormat::Rgba8Snorm => DxgiFormat::R8G8B8A8_SNorm,
+        TextureFormat::Rgba8Sint => DxgiFormat::R8G8B8A8_SInt,
+        TextureFormat::Rg16Float => DxgiFormat::R16G16_Float,
+        TextureFormat::Rg16Unorm => DxgiFormat::R16G16_UNorm,
+        TextureFormat::Rg16Uint => DxgiFormat::R16G16_UInt,
+        TextureFormat::Rg16Snorm => DxgiFormat::R16G16_SNorm,
+        TextureFormat::Rg16Sint => DxgiFormat::R16G16_SInt,
+        TextureFormat::R32Float => DxgiFormat::R32_Float,
+        TextureFormat::Depth32Float => DxgiFormat::D32_Float,
+        TextureFormat::R32Uint => DxgiFormat::R32_UInt,
+        TextureFormat::R32Sint => DxgiFormat::R32_SInt,
+        TextureFormat::Rg8Unorm => DxgiFormat::R8G8_UNorm,
+        TextureFormat::Rg8Uint => DxgiFormat::R8G8_UInt,
+        TextureFormat::Rg8Snorm => DxgiFormat::R8G8_SNorm,
+        TextureFormat::Rg8Sint => DxgiFormat::R8G8_SInt,
+        TextureFormat::R16Float => DxgiFormat::R16_Float,
+        TextureFormat::R16Unorm => DxgiFormat::R16_UNorm,
+        TextureFormat::R16Uint => DxgiFormat::R16_UInt,
+        TextureFormat::R16Snorm => DxgiFormat::R16_SNorm,
+        TextureFormat::R16Sint => DxgiFormat::R16_SInt,
+        TextureFormat::R8Unorm => DxgiFormat::R8_UNorm,
+        TextureFormat::R8Uint => DxgiFormat::R8_UInt,
+        TextureFormat::R8Snorm => DxgiFormat::R8_SNorm,
+        TextureFormat::R8Sint => DxgiFormat::R8_SInt,
+        TextureFormat::Rgb9e5Ufloat => DxgiFormat::R9G9B9E5_SharedExp,
+        TextureFormat::Bgra8Unorm => DxgiFormat::B8G8R8A8_UNorm,
+        TextureFormat::Bgra8UnormSrgb => DxgiFormat::B8G8R8A8_UNorm_sRGB,
+        TextureFormat::Bc1RgbaUnorm => DxgiFormat::BC1_UNorm,
+        TextureFormat::Bc1RgbaUnormSrgb => DxgiFormat::BC1_UNorm_sRGB,
+        TextureFormat::Bc2RgbaUnorm => DxgiFormat::BC2_UNorm,
+        TextureFormat::Bc2RgbaUnormSrgb => DxgiFormat::BC2_UNorm_sRGB,
+        TextureFormat::Bc3RgbaUnorm => DxgiFormat::BC3_UNorm,
+        TextureFormat::Bc3RgbaUnormSrgb => DxgiFormat::BC3_UNorm_sRGB,
+        TextureFormat::Bc4RUnorm => DxgiFormat::BC4_UNorm,
+        TextureFormat::Bc4RSnorm => DxgiFormat::BC4_SNorm,
+        TextureFormat::Bc5RgUnorm => DxgiFormat::BC5_UNorm,
+        TextureFormat::Bc5RgSnorm => DxgiFormat::BC5_SNorm,
+        TextureFormat::Bc6hRgbUfloat => DxgiFormat::BC6H_UF16,
+        TextureFormat::Bc6hRgbFloat => DxgiFormat::BC6H_SF16,
+        TextureFormat::Bc7RgbaUnorm => DxgiFormat::BC7_UNorm,
+        TextureFormat::Bc7RgbaUnormSrgb => DxgiFormat::BC7_UNorm_sRGB,
+        format => {
+            return Err(TextureError::UnsupportedTextureFormat(format!(
+                "{format:?} can't be stored in DDS files",
+            )))
+        }
+    })
+}
+
 #[cfg(test)]
 mod test {
     use wgpu::util::TextureDataOrder;
     use wgpu_types::{TextureDescriptor, TextureDimension, TextureFormat};
 
-    use crate::CompressedImageFormats;
+    use wgpu::TextureViewDescriptor;
+    use wgpu_types::{Extent3d, TextureViewDimension};
 
-    use super::dds_buffer_to_image;
+    use crate::{CompressedImageFormats, Image};
+
+    use super::{dds_buffer_to_image, image_to_dds_buffer};
 
     /// `wgpu::create_texture_with_data` that reads from data structure but doesn't actually talk to your GPU
     fn fake_wgpu_create_texture_with_data(
@@ -376,4 +586,121 @@ mod test {
             fake_wgpu_create_texture_with_data(&r.texture_descriptor, &r.data);
         }
     }
+
+    /// Returns an image whose bytes all differ, to check they are stored in the right order.
+    fn image(
+        size: Extent3d,
+        mip_level_count: u32,
+        data_size: usize,
+        format: TextureFormat,
+        view_dimension: Option<TextureViewDimension>,
+    ) -> Image {
+        let mut image = Image::default();
+        image.texture_descriptor.size = size;
+        image.texture_descriptor.format = format;
+        image.texture_descriptor.mip_level_count = mip_level_count;
+        image.data = (0..data_size).map(|i| (i % 251) as u8).collect();
+        image.texture_view_descriptor = view_dimension.map(|dimension| TextureViewDescriptor {
+            dimension: Some(dimension),
+            ..Default::default()
+        });
+        image
+    }
+
+    fn round_trip(image: &Image) -> Image {
+        let buffer = image_to_dds_buffer(image).unwrap();
+        let loaded = dds_buffer_to_image(
+            "".into(),
+            &buffer,
+            CompressedImageFormats::BC,
+            image.texture_descriptor.format.is_srgb(),
+        )
+        .unwrap();
+        fake_wgpu_create_texture_with_data(&loaded.texture_descriptor, &loaded.data);
+        assert_eq!(loaded.texture_descriptor, image.texture_descriptor);
+        assert_eq!(
+            loaded
+                .texture_view_descriptor
+                .as_ref()
+                .and_then(|descriptor| descriptor.dimension),
+            image
+                .texture_view_descriptor
+                .as_ref()
+                .and_then(|descriptor| descriptor.dimension)
+        );
+        assert_eq!(loaded.data, image.data);
+        loaded
+    }
+
+    #[test]
+    fn dds_bc7_array() {
+        // 2 layers of 8x8 and 4x4 mips, which are 4 and 1 blocks of 16 bytes.
+        let size = Extent3d {
+            width: 8,
+            height: 8,
+            depth_or_array_layers: 2,
+        };
+        round_trip(&image(
+            size,
+            2,
+            2 * 5 * 16,
+            TextureFormat::Bc7RgbaUnormSrgb,
+            Some(TextureViewDimension::D2Array),
+        ));
+        round_trip(&image(
+            size,
+            2,
+            2 * 5 * 16,
+            TextureFormat::Bc6hRgbUfloat,
+            Some(TextureViewDimension::D2Array),
+        ));
+    }
+
+    #[test]
+    fn dds_cubemap_mips() {
+        // 6 faces of 4x4, 2x2 and 1x1 mips.
+        let size = Extent3d {
+            width: 4,
+            height: 4,
+            depth_or_array_layers: 6,
+        };
+        round_trip(&image(
+            size,
+            3,
+            6 * 21 * 4,
+            TextureFormat::Rgba8Unorm,
+            Some(TextureViewDimension::Cube),
+        ));
+        // 2 cubes of BC6H, whose 2x2 and 1x1 mips take a whole block.
+        round_trip(&image(
+            Extent3d {
+                depth_or_array_layers: 12,
+                ..size
+            },
+            3,
+            12 * 3 * 16,
+            TextureFormat::Bc6hRgbFloat,
+            Some(TextureViewDimension::CubeArray),
+        ));
+    }
+
+    #[test]
+    fn dds_truncated_data() {
+        let image = image(
+            Extent3d {
+                width: 4,
+                height: 4,
+                depth_or_array_layers: 1,
+            },
+            3,
+            21 * 4,
+            TextureFormat::Rgba8Unorm,
+            None,
+        );
+        let mut buffer = image_to_dds_buffer(&image).unwrap();
+        buffer.pop();
+        assert!(
+            dds_buffer_to_image("".into(), &buffer, CompressedImageFormats::NONE, false).is_err()
+        );
+    }
 }
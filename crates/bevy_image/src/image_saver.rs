@@ -0,0 +1,189 @@
+use crate::{
+    Image, ImageFormat, ImageFormatSetting, ImageLoader, ImageLoaderSettings,
+    IntoDynamicImageError, TextureError,
+};
+
+use bevy_asset::saver::{AssetSaver, SavedAsset};
+use futures_lite::AsyncWriteExt;
+use image::DynamicImage;
+use std::path::Path;
+use thiserror::Error;
+
+/// An error that occurs when encoding an [`Image`] to a file.
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum ImageSaveError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// The path to save the image to doesn't have the extension of a supported format.
+    #[error("no enabled image format has the extension of {0}")]
+    UnknownExtension(String),
+    /// Images can't be encoded to this format.
+    #[error("saving images as {0:?} is not supported")]
+    UnsupportedFormat(ImageFormat),
+    #[error(transparent)]
+    IntoDynamicImage(#[from] IntoDynamicImageError),
+    #[error(transparent)]
+    Encode(#[from] image::ImageError),
+    #[error(transparent)]
+    Texture(#[from] TextureError),
+}
+
+impl Image {
+    /// Encodes the image as a file of the given format.
+    ///
+    /// DDS files keep the texture format and all the layers and mips of the image. The other
+    /// formats only keep its first layer and mip, converted to a color type they support: the
+    /// EXR and Radiance HDR formats store 32-bit floats, with and without alpha
+    /// respectively, which suits screenshots of HDR cameras and baked lighting, while the others
+    /// store 8 or 16-bit colors. See [`Image::try_into_dynamic`] for the texture formats which
+    /// can be converted.
+    pub fn to_buffer(&self, format: ImageFormat) -> Result<Vec<u8>, ImageSaveError> {
+        #[cfg(feature = "dds")]
+        if let ImageFormat::Dds = format {
+            return Ok(crate::image_to_dds_buffer(self)?);
+        }
+        let image_crate_format = format
+            .as_image_crate_format()
+            .ok_or(ImageSaveError::UnsupportedFormat(format))?;
+        let image = self.clone().try_into_dynamic()?;
+        let image = match image_crate_format {
+            image::ImageFormat::OpenExr => DynamicImage::ImageRgba32F(image.into_rgba32f()),
+            image::ImageFormat::Hdr => DynamicImage::ImageRgb32F(image.into_rgb32f()),
+            // JPEG doesn't support transparency.
+            image::ImageFormat::Jpeg => DynamicImage::ImageRgb8(image.into_rgb8()),
+            _ if matches!(image, DynamicImage::ImageRgba32F(_)) => {
+                DynamicImage::ImageRgba16(image.into_rgba16())
+            }
+            _ => image,
+        };
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        image.write_to(&mut buffer, image_crate_format)?;
+        Ok(buffer.into_inner())
+    }
+
+    /// Saves the image to a file, in the format of its extension. See [`Image::to_buffer`] for
+    /// what each format keeps of the image.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ImageSaveError> {
+        let path = path.as_ref();
+        let format = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(ImageFormat::from_extension)
+            .ok_or_else(|| ImageSaveError::UnknownExtension(path.display().to_string()))?;
+        std::fs::write(path, self.to_buffer(format)?)?;
+        Ok(())
+    }
+}
+
+/// Saves [`Image`] assets as files of the given format, for example to convert images when
+/// processing assets. See [`Image::to_buffer`] for what each format keeps of the image.
+pub struct ImageSaver {
+    pub format: ImageFormat,
+}
+
+impl AssetSaver for ImageSaver {
+    type Asset = Image;
+
+    type Settings = ();
+    type OutputLoader = ImageLoader;
+    type Error = ImageSaveError;
+
+    async fn save(
+        &self,
+        writer: &mut bevy_asset::io::Writer,
+        image: SavedAsset<'_, Self::Asset>,
+        _settings: &Self::Settings,
+    ) -> Result<ImageLoaderSettings, Self::Error> {
+        let buffer = image.to_buffer(self.format)?;
+        writer.write_all(&buffer).await?;
+        Ok(ImageLoaderSettings {
+            format: ImageFormatSetting::Format(self.format),
+            is_srgb: image.texture_descriptor.format.is_srgb(),
+            sampler: image.sampler.clone(),
+            asset_usage: image.asset_usage,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CompressedImageFormats, ImageSampler, ImageType};
+    use bevy_asset::RenderAssetUsages;
+    use wgpu_types::{Extent3d, TextureDimension, TextureFormat};
+
+    /// Returns a 2x1 image of floats, with values an HDR file stores exactly.
+    fn float_image() -> Image {
+        Image::new(
+            Extent3d {
+                width: 2,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            bytemuck::cast_slice(&[1.0f32, 0.5, 0.25, 1.0, 4.0, 2.0, 0.0, 1.0]).to_vec(),
+            TextureFormat::Rgba32Float,
+            RenderAssetUsages::default(),
+        )
+    }
+
+    fn load(buffer: &[u8], format: ImageFormat) -> Image {
+        Image::from_buffer(
+            #[cfg(all(debug_assertions, feature = "dds"))]
+            "test".into(),
+            buffer,
+            ImageType::Format(format),
+            CompressedImageFormats::NONE,
+            false,
+            ImageSampler::Default,
+            RenderAssetUsages::default(),
+        )
+        .unwrap()
+    }
+
+    #[cfg(feature = "exr")]
+    #[test]
+    fn save_exr() {
+        let image = float_image();
+        let saved = load(
+            &image.to_buffer(ImageFormat::OpenExr).unwrap(),
+            ImageFormat::OpenExr,
+        );
+        assert_eq!(saved.texture_descriptor.format, TextureFormat::Rgba32Float);
+        assert_eq!(saved.data, image.data);
+    }
+
+    #[cfg(feature = "hdr")]
+    #[test]
+    fn save_hdr() {
+        let image = float_image();
+        let saved = load(
+            &image.to_buffer(ImageFormat::Hdr).unwrap(),
+            ImageFormat::Hdr,
+        );
+        assert_eq!(saved.texture_descriptor.format, TextureFormat::Rgba32Float);
+        assert_eq!(saved.data, image.data);
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn save_png() {
+        let image = Image::new_fill(
+            Extent3d {
+                width: 2,
+                height: 2,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[255, 128, 0, 64],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        let saved = load(
+            &image.to_buffer(ImageFormat::Png).unwrap(),
+            ImageFormat::Png,
+        );
+        assert_eq!(saved.data, image.data);
+    }
+}
@@ -165,11 +165,26 @@ impl Image {
     /// error if the format is unsupported. Supported formats are:
     /// - `TextureFormat::R8Unorm`
     /// - `TextureFormat::Rg8Unorm`
+    /// - `TextureFormat::Rgba8Unorm`
     /// - `TextureFormat::Rgba8UnormSrgb`
+    /// - `TextureFormat::Bgra8Unorm`
     /// - `TextureFormat::Bgra8UnormSrgb`
+    /// - `TextureFormat::R16Unorm`
+    /// - `TextureFormat::Rgba16Unorm`
+    /// - `TextureFormat::Rgba16Float`
+    /// - `TextureFormat::Rgba32Float`
+    ///
+    /// Only the first layer and mip of the image is converted.
     ///
     /// To convert [`Image`] to a different format see: [`Image::convert`].
-    pub fn try_into_dynamic(self) -> Result<DynamicImage, IntoDynamicImageError> {
+    pub fn try_into_dynamic(mut self) -> Result<DynamicImage, IntoDynamicImageError> {
+        let format = self.texture_descriptor.format;
+        if let (Some(pixel_size), (1, 1)) =
+            (format.block_copy_size(None), format.block_dimensions())
+        {
+            let size = self.width() as usize * self.height() as usize * pixel_size as usize;
+            self.data.truncate(size);
+        }
         match self.texture_descriptor.format {
             TextureFormat::R8Unorm => ImageBuffer::from_raw(self.width(), self.height(), self.data)
                 .map(DynamicImage::ImageLuma8),
@@ -177,10 +192,37 @@ impl Image {
                 ImageBuffer::from_raw(self.width(), self.height(), self.data)
                     .map(DynamicImage::ImageLumaA8)
             }
-            TextureFormat::Rgba8UnormSrgb => {
+            TextureFormat::Rgba8UnormSrgb | TextureFormat::Rgba8Unorm => {
                 ImageBuffer::from_raw(self.width(), self.height(), self.data)
                     .map(DynamicImage::ImageRgba8)
             }
+            TextureFormat::R16Unorm => ImageBuffer::from_raw(
+                self.width(),
+                self.height(),
+                bytemuck::pod_collect_to_vec(&self.data),
+            )
+            .map(DynamicImage::ImageLuma16),
+            TextureFormat::Rgba16Unorm => ImageBuffer::from_raw(
+                self.width(),
+                self.height(),
+                bytemuck::pod_collect_to_vec(&self.data),
+            )
+            .map(DynamicImage::ImageRgba16),
+            TextureFormat::Rgba16Float => ImageBuffer::from_raw(
+                self.width(),
+                self.height(),
+                bytemuck::pod_collect_to_vec::<u8, u16>(&self.data)
+                    .into_iter()
+                    .map(f16_to_f32)
+                    .collect(),
+            )
+            .map(DynamicImage::ImageRgba32F),
+            TextureFormat::Rgba32Float => ImageBuffer::from_raw(
+                self.width(),
+                self.height(),
+                bytemuck::pod_collect_to_vec(&self.data),
+            )
+            .map(DynamicImage::ImageRgba32F),
             // This format is commonly used as the format for the swapchain texture
             // This conversion is added here to support screenshots
             TextureFormat::Bgra8UnormSrgb | TextureFormat::Bgra8Unorm => {
@@ -202,6 +244,24 @@ impl Image {
     }
 }
 
+/// Converts the bits of a half-precision float to a single-precision float.
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = u32::from(bits >> 15) << 31;
+    let exponent = u32::from((bits >> 10) & 0x1f);
+    let mantissa = u32::from(bits & 0x3ff);
+    let bits = match (exponent, mantissa) {
+        (0, 0) => sign,
+        // Subnormal numbers are normal in single precision.
+        (0, _) => {
+            let shift = mantissa.leading_zeros() - 21;
+            sign | ((113 - shift) << 23) | ((mantissa << shift) & 0x3ff) << 13
+        }
+        (0x1f, _) => sign | 0x7f80_0000 | (mantissa << 13),
+        _ => sign | ((exponent + 112) << 23) | (mantissa << 13),
+    };
+    f32::from_bits(bits)
+}
+
 /// Errors that occur while converting an [`Image`] into a [`DynamicImage`]
 #[non_exhaustive]
 #[derive(Error, Debug)]
@@ -232,4 +292,39 @@ mod test {
         // NOTE: Fails if `is_srgb = false` or the dynamic image is of the type rgb8.
         assert_eq!(initial, image.try_into_dynamic().unwrap());
     }
+
+    #[test]
+    fn half_float_conversion() {
+        for value in [
+            0.0,
+            -0.0,
+            1.0,
+            -2.5,
+            65504.0,
+            6.1035156e-5,
+            5.9604645e-8,
+            f32::INFINITY,
+        ] {
+            // Round trip through the bits of a half float, which represents all these values.
+            let bits = half_bits(value);
+            assert_eq!(f16_to_f32(bits).to_bits(), value.to_bits(), "{value}");
+        }
+        assert!(f16_to_f32(0x7e00).is_nan());
+    }
+
+    fn half_bits(value: f32) -> u16 {
+        let bits = value.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let exponent = ((bits >> 23) & 0xff) as i32 - 127 + 15;
+        let mantissa = bits & 0x7f_ffff;
+        if value == 0.0 {
+            sign
+        } else if value.is_infinite() {
+            sign | 0x7c00
+        } else if exponent <= 0 {
+            sign | (((mantissa | 0x80_0000) >> (14 - exponent)) as u16)
+        } else {
+            sign | ((exponent as u16) << 10) | ((mantissa >> 13) as u16)
+        }
+    }
 }
@@ -3,11 +3,12 @@ use std::io::Read;
 
 #[cfg(feature = "basis-universal")]
 use basis_universal::{
-    DecodeFlags, LowLevelUastcTranscoder, SliceParametersUastc, TranscoderBlockFormat,
+    DecodeFlags, LowLevelUastcTranscoder, SliceParametersUastc, TranscodeParameters, Transcoder,
+    TranscoderBlockFormat,
 };
 use bevy_color::Srgba;
 use bevy_utils::default;
-#[cfg(any(feature = "flate2", feature = "ruzstd"))]
+#[cfg(any(feature = "flate2", feature = "ruzstd", feature = "basis-universal"))]
 use ktx2::SupercompressionScheme;
 use ktx2::{
     BasicDataFormatDescriptor, ChannelTypeQualifiers, ColorModel, DataFormatDescriptorHeader,
@@ -71,6 +72,9 @@ pub fn ktx2_buffer_to_image(
                     })?;
                     levels.push(decompressed);
                 }
+                // BasisLZ levels are transcoded along with the supercompression global data
+                #[cfg(feature = "basis-universal")]
+                SupercompressionScheme::BasisLZ => levels.push(_level_data.to_vec()),
                 _ => {
                     return Err(TextureError::SuperDecompressionError(format!(
                         "Unsupported supercompression scheme: {supercompression_scheme:?}",
@@ -151,13 +155,8 @@ pub fn ktx2_buffer_to_image(
                 TranscodeFormat::Uastc(data_format) => {
                     let (transcode_block_format, texture_format) =
                         get_transcoded_formats(supported_compressed_formats, data_format, is_srgb);
-                    let texture_format_info = texture_format;
-                    let (block_width_pixels, block_height_pixels) = (
-                        texture_format_info.block_dimensions().0,
-                        texture_format_info.block_dimensions().1,
-                    );
-                    // Texture is not a depth or stencil format, it is possible to pass `None` and unwrap
-                    let block_bytes = texture_format_info.block_copy_size(None).unwrap();
+                    // The levels are made of UASTC blocks, whatever they are transcoded to.
+                    let (block_width_pixels, block_height_pixels, block_bytes) = (4, 4, 16);
 
                     let transcoder = LowLevelUastcTranscoder::new();
                     for (level, level_data) in levels.iter().enumerate() {
@@ -202,18 +201,51 @@ pub fn ktx2_buffer_to_image(
                     }
                     texture_format
                 }
-                // ETC1S is a subset of ETC1 which is a subset of ETC2
-                // TODO: Implement transcoding
+                #[cfg(feature = "basis-universal")]
                 TranscodeFormat::Etc1s => {
-                    let texture_format = if is_srgb {
-                        TextureFormat::Etc2Rgb8UnormSrgb
-                    } else {
-                        TextureFormat::Etc2Rgb8Unorm
-                    };
-                    if !supported_compressed_formats.supports(texture_format) {
-                        return Err(error);
+                    if depth > 1 {
+                        return Err(TextureError::UnsupportedTextureFormat(
+                            "3D ETC1S textures are not supported".to_string(),
+                        ));
+                    }
+                    let image_count = layer_count * face_count;
+                    let basis = basis_lz_to_basis(
+                        ktx2.supercompression_global_data(),
+                        &levels,
+                        width,
+                        height,
+                        image_count,
+                    )?;
+                    let (transcode_format, texture_format) =
+                        super::basis::get_transcoded_formats(supported_compressed_formats, is_srgb);
+
+                    let mut transcoder = Transcoder::new();
+                    transcoder.prepare_transcoding(&basis).map_err(|_| {
+                        TextureError::TranscodeError(
+                            "Failed to prepare for transcoding from ETC1S".to_string(),
+                        )
+                    })?;
+                    for (level, transcoded_level) in transcoded.iter_mut().enumerate() {
+                        for image_index in 0..image_count {
+                            let mut data = transcoder
+                                .transcode_image_level(
+                                    &basis,
+                                    transcode_format,
+                                    TranscodeParameters {
+                                        image_index,
+                                        level_index: level as u32,
+                                        decode_flags: Some(DecodeFlags::HIGH_QUALITY),
+                                        ..Default::default()
+                                    },
+                                )
+                                .map_err(|error| {
+                                    TextureError::TranscodeError(format!(
+                                        "Failed to transcode mip level {level} from ETC1S to {transcode_format:?}: {error:?}",
+                                    ))
+                                })?;
+                            transcoded_level.append(&mut data);
+                        }
                     }
-                    transcoded = levels.to_vec();
                     texture_format
                 }
                 #[cfg(not(feature = "basis-universal"))]
@@ -307,6 +339,141 @@ pub fn ktx2_buffer_to_image(
     Ok(image)
 }
 
+/// Rebuilds a `.basis` file from the ETC1S levels of a KTX2 file supercompressed with `BasisLZ`,
+/// whose global data holds the codebooks and slice locations of the `.basis` format.
+#[cfg(feature = "basis-universal")]
+fn basis_lz_to_basis(
+    global_data: &[u8],
+    levels: &[Vec<u8>],
+    width: u32,
+    height: u32,
+    image_count: u32,
+) -> Result<Vec<u8>, TextureError> {
+    const GLOBAL_HEADER_SIZE: usize = 20;
+    const IMAGE_DESC_SIZE: usize = 20;
+    const BASIS_HEADER_SIZE: usize = 77;
+    const SLICE_DESC_SIZE: usize = 23;
+
+    let read = |offset: usize, size: usize| {
+        global_data
+            .get(offset..offset + size)
+            .map(|bytes| {
+                let mut value = [0; 4];
+                value[..size].copy_from_slice(bytes);
+                u32::from_le_bytes(value)
+            })
+            .ok_or_else(|| TextureError::InvalidData("Truncated BasisLZ global data".to_string()))
+    };
+    let image_desc = |level: usize, image: u32| {
+        GLOBAL_HEADER_SIZE + IMAGE_DESC_SIZE * (level * image_count as usize + image as usize)
+    };
+    let (endpoint_count, selector_count) = (read(0, 2)?, read(2, 2)?);
+    // The endpoints, selectors, tables and extended data follow the image descriptions.
+    let lengths = [read(4, 4)?, read(8, 4)?, read(12, 4)?, read(16, 4)?];
+    let codebooks_offset = image_desc(levels.len(), 0);
+    let codebooks_length = lengths.iter().sum::<u32>() as usize;
+    let codebooks = global_data
+        .get(codebooks_offset..codebooks_offset + codebooks_length)
+        .ok_or_else(|| TextureError::InvalidData("Truncated BasisLZ global data".to_string()))?;
+    let has_alpha = read(image_desc(0, 0) + 16, 4)? != 0;
+
+    let slices_per_level = if has_alpha { 2 } else { 1 };
+    let slice_count = levels.len() * image_count as usize * slices_per_level;
+    let codebooks_file_offset = BASIS_HEADER_SIZE + SLICE_DESC_SIZE * slice_count;
+    let slices_file_offset = codebooks_file_offset + codebooks.len();
+    let mut slice_descs = Vec::with_capacity(SLICE_DESC_SIZE * slice_count);
+    let mut slices = Vec::new();
+    // Slices are ordered by image then level, with the alpha slice after the color slice.
+    for image in 0..image_count {
+        for (level, level_data) in levels.iter().enumerate() {
+            let (level_width, level_height) = (
+                (width >> level).max(1) as usize,
+                (height >> level).max(1) as usize,
+            );
+            let desc = image_desc(level, image);
+            let color = (read(desc + 4, 4)?, read(desc + 8, 4)?, 0);
+            let alpha = (read(desc + 12, 4)?, read(desc + 16, 4)?, 1);
+            for (offset, length, flags) in [color, alpha].into_iter().take(slices_per_level) {
+                let data = level_data
+                    .get(offset as usize..(offset + length) as usize)
+                    .ok_or_else(|| {
+                        TextureError::InvalidData(format!(
+                            "BasisLZ slice out of bounds of mip level {level}",
+                        ))
+                    })?;
+                for (value, size) in [
+                    (image as usize, 3),
+                    (level, 1),
+                    (flags, 1),
+                    (level_width, 2),
+                    (level_height, 2),
+                    (level_width.div_ceil(4), 2),
+                    (level_height.div_ceil(4), 2),
+                    (slices_file_offset + slices.len(), 4),
+                    (data.len(), 4),
+                    (0, 2),
+                ] {
+                    slice_descs.extend_from_slice(&(value as u32).to_le_bytes()[..size]);
+                }
+                slices.extend_from_slice(data);
+            }
+        }
+    }
+
+    let mut codebook_file_offsets = [0; 4];
+    let mut offset = codebooks_file_offset;
+    for (file_offset, length) in codebook_file_offsets.iter_mut().zip(lengths) {
+        *file_offset = offset;
+        offset += length as usize;
+    }
+    let [endpoints_length, selectors_length, tables_length, extended_length] =
+        lengths.map(|length| length as usize);
+    let [endpoints_offset, selectors_offset, tables_offset, extended_offset] =
+        codebook_file_offsets;
+    // The ETC1S format and flag, and the alpha slices flag.
+    let flags = if has_alpha { 1 | 4 } else { 1 };
+    let file_size = slices_file_offset + slices.len();
+
+    let mut basis = Vec::with_capacity(file_size);
+    for (value, size) in [
+        // The signature, version and header size, then the header checksum, the data size and
+        // the data checksum. Checksums are left empty as they are not verified when transcoding.
+        (0x4273, 2),
+        (0x13, 2),
+        (BASIS_HEADER_SIZE, 2),
+        (0, 2),
+        (file_size - BASIS_HEADER_SIZE, 4),
+        (0, 2),
+        (slice_count, 3),
+        (image_count as usize, 3),
+        (0, 1),
+        (flags, 2),
+        // The texture type, frame duration, reserved field and user data are unused.
+        (0, 1),
+        (0, 3),
+        (0, 4),
+        (0, 4),
+        (0, 4),
+        (endpoint_count as usize, 2),
+        (endpoints_offset, 4),
+        (endpoints_length, 3),
+        (selector_count as usize, 2),
+        (selectors_offset, 4),
+        (selectors_length, 3),
+        (tables_offset, 4),
+        (tables_length, 4),
+        (BASIS_HEADER_SIZE, 4),
+        (extended_offset, 4),
+        (extended_length, 4),
+    ] {
+        basis.extend_from_slice(&(value as u32).to_le_bytes()[..size]);
+    }
+    basis.extend_from_slice(&slice_descs);
+    basis.extend_from_slice(codebooks);
+    basis.extend_from_slice(&slices);
+    Ok(basis)
+}
+
 #[cfg(feature = "basis-universal")]
 pub fn get_transcoded_formats(
     supported_compressed_formats: CompressedImageFormats,
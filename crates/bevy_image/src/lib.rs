@@ -29,6 +29,7 @@ mod exr_texture_loader;
 #[cfg(feature = "hdr")]
 mod hdr_texture_loader;
 mod image_loader;
+mod image_saver;
 #[cfg(feature = "ktx2")]
 mod ktx2;
 mod texture_atlas;
@@ -44,6 +45,7 @@ pub use exr_texture_loader::*;
 #[cfg(feature = "hdr")]
 pub use hdr_texture_loader::*;
 pub use image_loader::*;
+pub use image_saver::*;
 #[cfg(feature = "ktx2")]
 pub use ktx2::*;
 pub use texture_atlas::*;
@@ -18,6 +18,9 @@ pub mod prelude;
 mod default_plugins;
 pub use default_plugins::*;
 
+mod startup_args;
+pub use startup_args::*;
+
 #[cfg(feature = "bevy_window")]
 pub use bevy_a11y as a11y;
 #[cfg(feature = "bevy_animation")]
@@ -0,0 +1,251 @@
+use bevy_app::{App, Plugin, PluginGroup, PluginGroupBuilder};
+use bevy_ecs::{reflect::ReflectResource, system::Resource};
+use bevy_math::Vec2;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use thiserror::Error;
+
+/// Startup settings parsed from the command-line arguments, to change how an app starts without
+/// rebuilding it, for example in QA or automation.
+///
+/// | Argument                  | Setting                                 |
+/// |---------------------------|-----------------------------------------|
+/// | `--window-size <W>x<H>`   | [`window_size`](Self::window_size)      |
+/// | `--vsync`, `--no-vsync`   | [`vsync`](Self::vsync)                  |
+/// | `--asset-root <PATH>`     | [`asset_root`](Self::asset_root)        |
+/// | `--log-filter <FILTER>`   | [`log_filter`](Self::log_filter)        |
+/// | `--headless`              | [`headless`](Self::headless)            |
+///
+/// Values can also be given as `--argument=value`. Other arguments are ignored, so that apps can
+/// parse their own arguments as well.
+///
+/// The [`StartupArgsPlugin`] applies them to the plugins of a [`PluginGroup`], and inserts them
+/// as a resource.
+#[derive(Resource, Reflect, Debug, Clone, Default, PartialEq)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct StartupArgs {
+    /// The logical size of the primary window.
+    pub window_size: Option<Vec2>,
+    /// Whether the primary window waits for vertical sync.
+    pub vsync: Option<bool>,
+    /// The folder the unprocessed assets are loaded from, relative to the project root.
+    pub asset_root: Option<String>,
+    /// The filter of the log, using the `EnvFilter` syntax, e.g. `wgpu=error,bevy_render=info`.
+    pub log_filter: Option<String>,
+    /// Whether to run without window, using the
+    /// [`ScheduleRunnerPlugin`](bevy_app::ScheduleRunnerPlugin) instead.
+    pub headless: bool,
+}
+
+/// An error that occurred while parsing [`StartupArgs`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum StartupArgsError {
+    /// An argument was given without its value.
+    #[error("missing value for the argument `{0}`")]
+    MissingValue(String),
+    /// The value of an argument is invalid.
+    #[error("invalid value `{value}` for the argument `{argument}`")]
+    InvalidValue {
+        /// The argument.
+        argument: String,
+        /// Its value.
+        value: String,
+    },
+}
+
+impl StartupArgs {
+    /// Parses the arguments the app was started with.
+    pub fn from_env() -> Result<Self, StartupArgsError> {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    /// Parses `args`, which don't include the name of the program.
+    pub fn parse(
+        args: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<Self, StartupArgsError> {
+        let mut startup_args = Self::default();
+        let mut args = args.into_iter().map(Into::into);
+        while let Some(arg) = args.next() {
+            let (argument, inline_value) = match arg.split_once('=') {
+                Some((argument, value)) => (argument.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            match argument.as_str() {
+                "--vsync" => startup_args.vsync = Some(true),
+                "--no-vsync" => startup_args.vsync = Some(false),
+                "--headless" => startup_args.headless = true,
+                "--window-size" | "--asset-root" | "--log-filter" => {
+                    let Some(value) = inline_value.or_else(|| args.next()) else {
+                        return Err(StartupArgsError::MissingValue(argument));
+                    };
+                    match argument.as_str() {
+                        "--window-size" => {
+                            let Some(size) = parse_size(&value) else {
+                                return Err(StartupArgsError::InvalidValue { argument, value });
+                            };
+                            startup_args.window_size = Some(size);
+                        }
+                        "--asset-root" => startup_args.asset_root = Some(value),
+                        _ => startup_args.log_filter = Some(value),
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(startup_args)
+    }
+
+    /// Overrides the settings of the plugins in `plugins` with these arguments, keeping the other
+    /// settings of these plugins.
+    pub fn apply(&self, plugins: impl PluginGroup) -> PluginGroupBuilder {
+        let mut plugins = plugins.build();
+
+        if let Some(filter) = &self.log_filter {
+            if let Some(log) = plugins.get_mut::<bevy_log::LogPlugin>() {
+                log.filter.clone_from(filter);
+            }
+        }
+
+        #[cfg(feature = "bevy_asset")]
+        if let Some(root) = &self.asset_root {
+            if let Some(asset) = plugins.get_mut::<bevy_asset::AssetPlugin>() {
+                asset.file_path.clone_from(root);
+            }
+        }
+
+        #[cfg(feature = "bevy_window")]
+        if let Some(window_plugin) = plugins.get_mut::<bevy_window::WindowPlugin>() {
+            if self.headless {
+                window_plugin.primary_window = None;
+                window_plugin.exit_condition = bevy_window::ExitCondition::DontExit;
+            } else if let Some(window) = &mut window_plugin.primary_window {
+                if let Some(size) = self.window_size {
+                    window.resolution.set(size.x, size.y);
+                }
+                if let Some(vsync) = self.vsync {
+                    window.present_mode = if vsync {
+                        bevy_window::PresentMode::AutoVsync
+                    } else {
+                        bevy_window::PresentMode::AutoNoVsync
+                    };
+                }
+            }
+        }
+
+        if self.headless {
+            #[cfg(feature = "bevy_winit")]
+            if plugins.contains::<bevy_winit::WinitPlugin>() {
+                plugins = plugins.disable::<bevy_winit::WinitPlugin>();
+            }
+            if !plugins.contains::<bevy_app::ScheduleRunnerPlugin>() {
+                plugins = plugins.add(bevy_app::ScheduleRunnerPlugin::run_loop(
+                    core::time::Duration::from_secs_f64(1.0 / 60.0),
+                ));
+            }
+        }
+
+        plugins
+    }
+}
+
+/// Parses a size written as `<width>x<height>`.
+fn parse_size(value: &str) -> Option<Vec2> {
+    let (width, height) = value.split_once('x')?;
+    let size = Vec2::new(width.trim().parse().ok()?, height.trim().parse().ok()?);
+    (size.x > 0.0 && size.y > 0.0).then_some(size)
+}
+
+/// Applies [`StartupArgs`] to a [`PluginGroup`], such as [`DefaultPlugins`](crate::DefaultPlugins),
+/// before its plugins are built, and inserts them as a resource.
+///
+/// ```no_run
+/// # use bevy_app::App;
+/// # use bevy_internal::{DefaultPlugins, StartupArgsPlugin};
+/// let startup_args = StartupArgsPlugin::from_env().unwrap_or_else(|error| {
+///     eprintln!("{error}");
+///     std::process::exit(2);
+/// });
+/// App::new()
+///     .add_plugins(startup_args.configure(DefaultPlugins))
+///     .run();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StartupArgsPlugin {
+    /// The arguments to apply.
+    pub args: StartupArgs,
+}
+
+impl StartupArgsPlugin {
+    /// Creates the plugin from the arguments the app was started with.
+    pub fn from_env() -> Result<Self, StartupArgsError> {
+        StartupArgs::from_env().map(|args| Self { args })
+    }
+
+    /// Applies the arguments to `plugins`, and adds this plugin to them.
+    pub fn configure(self, plugins: impl PluginGroup) -> PluginGroupBuilder {
+        self.args.apply(plugins).add(self)
+    }
+}
+
+impl Plugin for StartupArgsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<StartupArgs>()
+            .insert_resource(self.args.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::NoopPluginGroup;
+    use bevy_log::LogPlugin;
+
+    #[test]
+    fn parse_args() {
+        let args = StartupArgs::parse([
+            "--window-size",
+            "1280x720",
+            "--no-vsync",
+            "--asset-root=qa/assets",
+            "--level",
+            "3",
+            "--headless",
+        ])
+        .unwrap();
+        assert_eq!(
+            args,
+            StartupArgs {
+                window_size: Some(Vec2::new(1280.0, 720.0)),
+                vsync: Some(false),
+                asset_root: Some("qa/assets".to_string()),
+                log_filter: None,
+                headless: true,
+            }
+        );
+
+        assert_eq!(
+            StartupArgs::parse(["--window-size=wide"]),
+            Err(StartupArgsError::InvalidValue {
+                argument: "--window-size".to_string(),
+                value: "wide".to_string(),
+            })
+        );
+        assert_eq!(
+            StartupArgs::parse(["--log-filter"]),
+            Err(StartupArgsError::MissingValue("--log-filter".to_string()))
+        );
+    }
+
+    #[test]
+    fn apply_keeps_other_settings() {
+        let args = StartupArgs::parse(["--log-filter", "warn"]).unwrap();
+        let mut plugins = args.apply(PluginGroupBuilder::start::<NoopPluginGroup>().add(
+            LogPlugin {
+                level: bevy_log::Level::TRACE,
+                ..Default::default()
+            },
+        ));
+        let log = plugins.get_mut::<LogPlugin>().unwrap();
+        assert_eq!(log.filter, "warn");
+        assert_eq!(log.level, bevy_log::Level::TRACE);
+    }
+}
@@ -58,16 +58,18 @@ pub use tracing::{
 pub use tracing_subscriber;
 
 use bevy_app::{App, Plugin};
+use bevy_ecs::system::Resource;
+use thiserror::Error;
 use tracing_log::LogTracer;
 use tracing_subscriber::{
     filter::{FromEnvError, ParseError},
+    layer::Layered,
     prelude::*,
     registry::Registry,
-    EnvFilter, Layer,
+    reload, EnvFilter, Layer,
 };
 #[cfg(feature = "tracing-chrome")]
 use {
-    bevy_ecs::system::Resource,
     bevy_utils::synccell::SyncCell,
     tracing_subscriber::fmt::{format::DefaultFields, FormattedFields},
 };
@@ -250,6 +252,43 @@ pub type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync + 'static>;
 /// The default [`LogPlugin`] [`EnvFilter`].
 pub const DEFAULT_FILTER: &str = "wgpu=error,naga=warn";
 
+/// A resource changing the filter of the [`LogPlugin`] while the app is running.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_log::LogFilter;
+/// fn enable_debug_logs(filter: Res<LogFilter>) {
+///     filter.set("info,wgpu=error,my_game::ai=debug").unwrap();
+/// }
+/// ```
+#[derive(Resource, Clone)]
+pub struct LogFilter(reload::Handle<EnvFilter, Layered<Option<BoxedLayer>, Registry>>);
+
+impl LogFilter {
+    /// Replaces the filter of the [`LogPlugin`], using the [`EnvFilter`] format.
+    pub fn set(&self, filter: &str) -> Result<(), LogFilterError> {
+        let filter = EnvFilter::builder().parse(filter)?;
+        self.0.reload(filter)?;
+        Ok(())
+    }
+
+    /// Returns the current filter of the [`LogPlugin`], in the [`EnvFilter`] format.
+    pub fn get(&self) -> Option<String> {
+        self.0.with_current(ToString::to_string).ok()
+    }
+}
+
+/// An error returned by [`LogFilter::set`].
+#[derive(Error, Debug)]
+pub enum LogFilterError {
+    /// The filter isn't in the [`EnvFilter`] format.
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    /// The subscriber of the [`LogPlugin`] was dropped, because another one was already set.
+    #[error(transparent)]
+    Reload(#[from] reload::Error),
+}
+
 impl Default for LogPlugin {
     fn default() -> Self {
         Self {
@@ -291,6 +330,8 @@ impl Plugin for LogPlugin {
                 Ok::<EnvFilter, FromEnvError>(EnvFilter::builder().parse_lossy(&default_filter))
             })
             .unwrap();
+        let (filter_layer, filter_handle) = reload::Layer::new(filter_layer);
+        app.insert_resource(LogFilter(filter_handle));
         let subscriber = subscriber.with(filter_layer);
 
         #[cfg(feature = "trace")]
@@ -0,0 +1,402 @@
+use super::{Aabb3d, BoundingSphere, BoundingVolume, IntersectsVolume, RayCast3d};
+use crate::{Vec3A, Vec4};
+use alloc::vec::Vec;
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+
+/// The maximum number of primitives in a leaf of a [`Bvh3d`].
+const MAX_LEAF_SIZE: usize = 4;
+
+/// A bounding volume hierarchy over a set of primitives in 3D, each given by its [`Aabb3d`].
+///
+/// The hierarchy is a binary tree of nested [`Aabb3d`]s, which quickly finds the primitives that may
+/// intersect a ray or a volume without testing every one of them. It doesn't store the primitives
+/// themselves: queries produce the indices of the primitives in the order their bounds were given,
+/// and precise tests are left to the caller.
+///
+/// ```
+/// # use bevy_math::{bounding::{Aabb3d, Bvh3d, RayCast3d}, Dir3, Vec3};
+/// let boxes: Vec<Aabb3d> = (0..100)
+///     .map(|i| Aabb3d::new(Vec3::new(i as f32 * 2.0, 0.0, 0.0), Vec3::splat(0.5)))
+///     .collect();
+/// let bvh = Bvh3d::new(&boxes);
+///
+/// // Find the nearest box along a ray, here testing the bounds themselves.
+/// let ray = RayCast3d::new(Vec3::new(-10.0, 0.0, 0.0), Dir3::X, f32::MAX);
+/// let hit = bvh.ray_cast(&ray, |index, ray| ray.aabb_intersection_at(&boxes[index]));
+/// assert_eq!(hit.map(|(index, _)| index), Some(0));
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug))]
+pub struct Bvh3d {
+    /// The nodes of the tree in depth-first order, so that the left child of a branch is the node
+    /// following it.
+    nodes: Vec<BvhNode>,
+    /// The indices of the primitives, ordered so that those of each leaf are contiguous.
+    indices: Vec<usize>,
+    /// The bounds of the primitives, in the order of [`Bvh3d::indices`].
+    bounds: Vec<Aabb3d>,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug))]
+struct BvhNode {
+    aabb: Aabb3d,
+    /// For leaves, the first of their [`Bvh3d::indices`]. For branches, the index of the right child.
+    first: usize,
+    /// For leaves, the number of their primitives. Branches have none.
+    count: usize,
+}
+
+impl Bvh3d {
+    /// Builds a hierarchy over primitives with the given bounds.
+    ///
+    /// Primitives are split in halves along the longest axis of their centers, which builds
+    /// balanced trees quickly.
+    pub fn new<'a>(bounds: impl IntoIterator<Item = &'a Aabb3d>) -> Self {
+        let bounds: Vec<Aabb3d> = bounds.into_iter().copied().collect();
+        let mut bvh = Self {
+            nodes: Vec::with_capacity((2 * bounds.len() / MAX_LEAF_SIZE).max(1)),
+            indices: (0..bounds.len()).collect(),
+            bounds: Vec::new(),
+        };
+        if !bounds.is_empty() {
+            let centers: Vec<Vec3A> = bounds.iter().map(BoundingVolume::center).collect();
+            bvh.build(&bounds, &centers, 0, bounds.len());
+        }
+        bvh.bounds = bvh.indices.iter().map(|&index| bounds[index]).collect();
+        bvh
+    }
+
+    /// Builds the node for the primitives in `indices[start..end]` and its children, returning its
+    /// index.
+    fn build(&mut self, bounds: &[Aabb3d], centers: &[Vec3A], start: usize, end: usize) -> usize {
+        let indices = &mut self.indices[start..end];
+        let aabb = indices
+            .iter()
+            .map(|&index| bounds[index])
+            .reduce(|a, b| a.merge(&b))
+            .unwrap();
+        let node = self.nodes.len();
+        self.nodes.push(BvhNode {
+            aabb,
+            first: start,
+            count: indices.len(),
+        });
+        if indices.len() <= MAX_LEAF_SIZE {
+            return node;
+        }
+
+        let (min, max) = indices.iter().fold(
+            (Vec3A::splat(f32::INFINITY), Vec3A::splat(f32::NEG_INFINITY)),
+            |(min, max), &index| (min.min(centers[index]), max.max(centers[index])),
+        );
+        let extent = max - min;
+        // Primitives with the same center can't be split any further.
+        if extent.max_element() <= 0.0 {
+            return node;
+        }
+        let axis = if extent.x >= extent.y && extent.x >= extent.z {
+            0
+        } else if extent.y >= extent.z {
+            1
+        } else {
+            2
+        };
+        let middle = indices.len() / 2;
+        indices.select_nth_unstable_by(middle, |&a, &b| {
+            centers[a][axis].total_cmp(&centers[b][axis])
+        });
+
+        self.build(bounds, centers, start, start + middle);
+        let right = self.build(bounds, centers, start + middle, end);
+        self.nodes[node].first = right;
+        self.nodes[node].count = 0;
+        node
+    }
+
+    /// Returns the number of primitives in the hierarchy.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns `true` if the hierarchy has no primitives.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns the bounds of all the primitives, or `None` if there are none.
+    #[inline]
+    pub fn aabb(&self) -> Option<Aabb3d> {
+        self.nodes.first().map(|node| node.aabb)
+    }
+
+    /// Finds the nearest primitive hit by `ray`, returning its index and the distance to the hit.
+    ///
+    /// `hit` is called with the index of each primitive whose bounds are hit by the ray, and a ray
+    /// whose [`max`](RayCast3d::max) is the distance to the nearest hit so far. It returns the
+    /// distance to the primitive along the ray if it is hit. Primitives are roughly visited from
+    /// nearest to furthest, and those whose bounds are further than the nearest hit are skipped.
+    pub fn ray_cast(
+        &self,
+        ray: &RayCast3d,
+        mut hit: impl FnMut(usize, &RayCast3d) -> Option<f32>,
+    ) -> Option<(usize, f32)> {
+        let mut ray = ray.clone();
+        let mut nearest = None;
+        let distance = ray.aabb_intersection_at(&self.nodes.first()?.aabb)?;
+        let mut stack = Vec::with_capacity(32);
+        stack.push((0, distance));
+        while let Some((index, distance)) = stack.pop() {
+            // The nearest hit may have been found since this node was pushed.
+            if distance > ray.max {
+                continue;
+            }
+            let node = &self.nodes[index];
+            if node.count > 0 {
+                let range = node.first..node.first + node.count;
+                for (&primitive, bounds) in
+                    self.indices[range.clone()].iter().zip(&self.bounds[range])
+                {
+                    if ray.aabb_intersection_at(bounds).is_none() {
+                        continue;
+                    }
+                    if let Some(distance) = hit(primitive, &ray) {
+                        if distance <= ray.max {
+                            ray.max = distance;
+                            nearest = Some((primitive, distance));
+                        }
+                    }
+                }
+                continue;
+            }
+
+            let children = [index + 1, node.first].map(|child| {
+                ray.aabb_intersection_at(&self.nodes[child].aabb)
+                    .map(|distance| (child, distance))
+            });
+            match children {
+                [Some(left), Some(right)] => {
+                    // The nearest child is visited first.
+                    let (near, far) = if left.1 <= right.1 {
+                        (left, right)
+                    } else {
+                        (right, left)
+                    };
+                    stack.push(far);
+                    stack.push(near);
+                }
+                [Some(child), None] | [None, Some(child)] => stack.push(child),
+                [None, None] => {}
+            }
+        }
+        nearest
+    }
+
+    /// Returns an iterator over the indices of the primitives whose bounds satisfy `overlaps`.
+    ///
+    /// `overlaps` must be `true` for the bounds of a node whenever it is for a primitive in it,
+    /// which is the case of overlap tests: it is tested against the nested bounds of the
+    /// hierarchy to skip whole branches.
+    pub fn query<F>(&self, overlaps: F) -> BvhQuery<'_, F>
+    where
+        F: Fn(&Aabb3d) -> bool,
+    {
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        BvhQuery {
+            bvh: self,
+            overlaps,
+            stack,
+            leaf: [].iter().zip([].iter()),
+        }
+    }
+
+    /// Returns an iterator over the indices of the primitives whose bounds intersect `aabb`.
+    pub fn intersecting_aabb<'a>(&'a self, aabb: &'a Aabb3d) -> impl Iterator<Item = usize> + 'a {
+        self.query(move |bounds| bounds.intersects(aabb))
+    }
+
+    /// Returns an iterator over the indices of the primitives whose bounds intersect `sphere`.
+    pub fn intersecting_sphere<'a>(
+        &'a self,
+        sphere: &'a BoundingSphere,
+    ) -> impl Iterator<Item = usize> + 'a {
+        self.query(move |bounds| bounds.intersects(sphere))
+    }
+
+    /// Returns an iterator over the indices of the primitives whose bounds intersect `ray`.
+    pub fn intersecting_ray<'a>(&'a self, ray: &'a RayCast3d) -> impl Iterator<Item = usize> + 'a {
+        self.query(move |bounds| ray.intersects(bounds))
+    }
+
+    /// Returns an iterator over the indices of the primitives whose bounds are at least partly
+    /// inside a frustum, such as the view frustum of a camera.
+    ///
+    /// The frustum is the intersection of the half-spaces given by `planes`, each as a plane normal
+    /// and the signed distance from the plane to the origin along it: a point `p` is inside a
+    /// half-space `plane` if `plane.xyz().dot(p) + plane.w >= 0`. This is the layout of the
+    /// half-spaces of the `Frustum` of `bevy_render`.
+    ///
+    /// Bounds outside the frustum but not behind any single plane, near its corners, are
+    /// conservatively considered inside.
+    pub fn intersecting_frustum<'a>(
+        &'a self,
+        planes: &'a [Vec4],
+    ) -> impl Iterator<Item = usize> + 'a {
+        self.query(move |bounds| {
+            let center = bounds.center();
+            let half_size = bounds.half_size();
+            planes.iter().all(|plane| {
+                let normal = Vec3A::from_vec4(*plane);
+                let radius = half_size.dot(normal.abs());
+                normal.dot(center) + plane.w + radius >= 0.0
+            })
+        })
+    }
+}
+
+/// An iterator over the indices of the primitives of a [`Bvh3d`] matching a query, produced by
+/// [`Bvh3d::query`].
+pub struct BvhQuery<'a, F> {
+    bvh: &'a Bvh3d,
+    overlaps: F,
+    stack: Vec<usize>,
+    leaf: core::iter::Zip<core::slice::Iter<'a, usize>, core::slice::Iter<'a, Aabb3d>>,
+}
+
+impl<'a, F> Iterator for BvhQuery<'a, F>
+where
+    F: Fn(&Aabb3d) -> bool,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            for (&index, bounds) in self.leaf.by_ref() {
+                if (self.overlaps)(bounds) {
+                    return Some(index);
+                }
+            }
+            let index = self.stack.pop()?;
+            let node = &self.bvh.nodes[index];
+            if !(self.overlaps)(&node.aabb) {
+                continue;
+            }
+            if node.count > 0 {
+                let range = node.first..node.first + node.count;
+                self.leaf = self.bvh.indices[range.clone()]
+                    .iter()
+                    .zip(self.bvh.bounds[range].iter());
+            } else {
+                self.stack.push(node.first);
+                self.stack.push(index + 1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dir3A, Vec3, Vec4};
+    use alloc::vec;
+
+    fn grid() -> Vec<Aabb3d> {
+        let mut boxes = Vec::new();
+        for x in 0..10 {
+            for y in 0..10 {
+                for z in 0..10 {
+                    boxes.push(Aabb3d::new(
+                        Vec3::new(x as f32, y as f32, z as f32) * 2.0,
+                        Vec3::splat(0.5),
+                    ));
+                }
+            }
+        }
+        boxes
+    }
+
+    fn sorted(iter: impl Iterator<Item = usize>) -> Vec<usize> {
+        let mut indices: Vec<usize> = iter.collect();
+        indices.sort_unstable();
+        indices
+    }
+
+    #[test]
+    fn ray_cast_finds_nearest() {
+        let boxes = grid();
+        let bvh = Bvh3d::new(&boxes);
+        assert_eq!(bvh.len(), 1000);
+
+        let ray = RayCast3d::new(Vec3::new(4.0, 6.0, 100.0), Dir3A::NEG_Z, f32::MAX);
+        let mut tested = 0;
+        let hit = bvh.ray_cast(&ray, |index, ray| {
+            tested += 1;
+            ray.aabb_intersection_at(&boxes[index])
+        });
+        let (index, distance) = hit.unwrap();
+        assert_eq!(boxes[index].center(), Vec3A::new(4.0, 6.0, 18.0));
+        assert_eq!(distance, 100.0 - 18.5);
+        // Only the leaves along the ray are tested, and not all of those.
+        assert!(tested <= 8, "tested {tested} primitives");
+
+        let miss = RayCast3d::new(Vec3::new(5.0, 6.0, 100.0), Dir3A::NEG_Z, f32::MAX);
+        assert!(bvh
+            .ray_cast(&miss, |index, ray| ray.aabb_intersection_at(&boxes[index]))
+            .is_none());
+    }
+
+    #[test]
+    fn volume_queries_match_brute_force() {
+        let boxes = grid();
+        let bvh = Bvh3d::new(&boxes);
+
+        let sphere = BoundingSphere::new(Vec3::new(7.0, 3.0, 9.0), 4.0);
+        let expected: Vec<usize> = (0..boxes.len())
+            .filter(|&index| boxes[index].intersects(&sphere))
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(sorted(bvh.intersecting_sphere(&sphere)), expected);
+
+        let aabb = Aabb3d::new(Vec3::new(10.0, 10.0, 10.0), Vec3::new(3.0, 1.0, 20.0));
+        let expected: Vec<usize> = (0..boxes.len())
+            .filter(|&index| boxes[index].intersects(&aabb))
+            .collect();
+        assert_eq!(sorted(bvh.intersecting_aabb(&aabb)), expected);
+    }
+
+    #[test]
+    fn frustum_query() {
+        let boxes = grid();
+        let bvh = Bvh3d::new(&boxes);
+        // The slab `x <= 3.0`, `y >= 17.0`.
+        let planes = vec![
+            Vec4::new(-1.0, 0.0, 0.0, 3.0),
+            Vec4::new(0.0, 1.0, 0.0, -17.0),
+        ];
+        let indices = sorted(bvh.intersecting_frustum(&planes));
+        assert_eq!(indices.len(), 2 * 10);
+        for index in indices {
+            let center = boxes[index].center();
+            assert!(center.x <= 2.0 && center.y == 18.0);
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let bvh = Bvh3d::new(&[]);
+        assert!(bvh.is_empty());
+        assert!(bvh.aabb().is_none());
+        assert_eq!(
+            bvh.intersecting_sphere(&BoundingSphere::new(Vec3::ZERO, 1.0))
+                .count(),
+            0
+        );
+    }
+}
@@ -4,6 +4,9 @@
 //! - [`BoundingVolume`] is a generic abstraction for any bounding volume
 //! - [`IntersectsVolume`] abstracts intersection tests against a [`BoundingVolume`]
 //! - [`Bounded2d`]/[`Bounded3d`] are abstractions for shapes to generate [`BoundingVolume`]s
+//!
+//! Large sets of bounding volumes can be queried efficiently through a bounding volume hierarchy,
+//! see `Bvh3d`.
 
 /// A trait that generalizes different bounding volumes.
 /// Bounding volumes are simplified shapes that are used to get simpler ways to check for
@@ -111,6 +114,11 @@ pub use bounded2d::*;
 mod bounded3d;
 pub use bounded3d::*;
 
+#[cfg(feature = "alloc")]
+mod bvh3d;
+#[cfg(feature = "alloc")]
+pub use bvh3d::*;
+
 mod raycast2d;
 pub use raycast2d::*;
 mod raycast3d;
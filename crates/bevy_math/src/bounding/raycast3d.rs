@@ -57,10 +57,13 @@ impl RayCast3d {
         let tmin = (min - self.origin) * self.direction_recip;
         let tmax = (max - self.origin) * self.direction_recip;
 
-        // An axis that is not relevant to the ray direction will be NaN. When one of the arguments
-        // to min/max is NaN, the other argument is used.
+        // An axis that is not relevant to the ray direction will be NaN when the origin is on the
+        // boundary of the box along that axis, and is ignored. The SIMD min/max operations don't
+        // ignore NaN, so it is replaced explicitly.
         // An axis for which the direction is the wrong way will return an arbitrarily large
         // negative value.
+        let tmin = Vec3A::select(tmin.is_nan_mask(), Vec3A::NEG_INFINITY, tmin);
+        let tmax = Vec3A::select(tmax.is_nan_mask(), Vec3A::INFINITY, tmax);
         let tmin = tmin.max_element().max(0.);
         let tmax = tmax.min_element().min(self.max);
 
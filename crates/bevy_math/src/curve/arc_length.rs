@@ -0,0 +1,421 @@
+//! Arc length parametrization of curves, and other measurements based on distance.
+//!
+//! Most curves, including [splines], are not traversed at constant speed: samples evenly spaced
+//! in parameter space are not evenly spaced along the curve. The [`ArcLengthCurve`] produced by
+//! [`CurveArcLengthExt::reparametrize_by_arc_length`] is parametrized by the distance travelled
+//! along its base curve instead, which is what constant speed motion along a path needs:
+//! ```rust
+//! # use bevy_math::{vec2, prelude::*};
+//! let points = [vec2(0.0, 0.0), vec2(1.0, 4.0), vec2(4.0, 4.0), vec2(5.0, 0.0)];
+//! let spline = CubicBezier::new([points]).to_curve().unwrap();
+//!
+//! // Sampling this curve at `distance` gives the point `distance` away from the start of the
+//! // spline, measured along it:
+//! let path = spline.reparametrize_by_arc_length(64).unwrap();
+//! let position = path.sample_clamped(1.5);
+//!
+//! // Points spaced `0.25` apart along the spline, for example to place the vertices of a road:
+//! let vertices: Vec<Vec2> = path.samples_with_spacing(0.25).collect();
+//! ```
+//!
+//! Lengths are measured by summing the distances between samples evenly spaced in parameter
+//! space, so their accuracy depends on the number of segments used, which should grow with the
+//! curvature of the curve.
+//!
+//! [splines]: crate::cubic_splines
+
+use super::{
+    cores::{uneven_interp, InterpolationDatum},
+    interval::Interval,
+    Curve, CurveExt, ResamplingError,
+};
+use crate::NormedVectorSpace;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+
+/// The number of iterations of the golden-section search refining closest points, which shrinks
+/// the searched interval below a millionth of a segment.
+const CLOSEST_POINT_ITERATIONS: usize = 32;
+
+/// The inverse of the golden ratio, `(sqrt(5) - 1) / 2`.
+const INVERSE_GOLDEN_RATIO: f32 = 0.618_034;
+
+/// A curve parametrized by the distance travelled along its base curve, so that it is traversed at
+/// constant speed. Curves of this type are produced by
+/// [`CurveArcLengthExt::reparametrize_by_arc_length`].
+///
+/// The domain of this curve is `[0, length]`, where `length` is the length of the base curve.
+/// Distances are mapped to parameters of the base curve by interpolating over a table of the
+/// length travelled at evenly spaced parameters.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(from_reflect = false)
+)]
+pub struct ArcLengthCurve<T, C> {
+    curve: C,
+    /// The parameters of the base curve at which lengths were measured, in increasing order.
+    parameters: Vec<f32>,
+    /// The length of the base curve from its start to each parameter, in increasing order.
+    lengths: Vec<f32>,
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, C> ArcLengthCurve<T, C>
+where
+    T: NormedVectorSpace,
+    C: Curve<T>,
+{
+    /// Create an arc length parametrization of `curve`, measuring its length on `segments`
+    /// segments evenly spaced in parameter space.
+    ///
+    /// # Errors
+    ///
+    /// If `segments` is zero or if `curve` has unbounded domain, a [`ResamplingError`] is returned.
+    pub fn new(curve: C, segments: usize) -> Result<Self, ResamplingError> {
+        let (parameters, samples) = parameters_and_samples(&curve, segments)?;
+        let mut kept_parameters = Vec::with_capacity(parameters.len());
+        let mut lengths = Vec::with_capacity(parameters.len());
+        let mut length = 0.0;
+        let mut previous = samples[0];
+        for (index, (parameter, sample)) in parameters.into_iter().zip(samples).enumerate() {
+            length += previous.distance(sample);
+            previous = sample;
+            // Lengths must be strictly increasing to be interpolated, so points which didn't move
+            // from the previous one are skipped.
+            if index == 0 || lengths.last().is_some_and(|&last| length > last) {
+                kept_parameters.push(parameter);
+                lengths.push(length);
+            }
+        }
+        Ok(Self {
+            curve,
+            parameters: kept_parameters,
+            lengths,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// The length of the base curve.
+    #[inline]
+    pub fn length(&self) -> f32 {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// The parameter of the base curve reached after travelling `distance` along it from its start.
+    ///
+    /// The distance is clamped to `[0, length]`.
+    pub fn parameter_at(&self, distance: f32) -> f32 {
+        interpolate_table(&self.lengths, &self.parameters, distance)
+    }
+
+    /// The distance travelled along the base curve from its start to the parameter `t`.
+    ///
+    /// The parameter is clamped to the domain of the base curve.
+    pub fn distance_at(&self, t: f32) -> f32 {
+        interpolate_table(&self.parameters, &self.lengths, t)
+    }
+
+    /// Samples of this curve spaced `spacing` apart along it, starting from its start. The end of
+    /// the curve is only included if the length is a multiple of `spacing`.
+    ///
+    /// If `spacing` is not a positive number, only the start of the curve is produced.
+    pub fn samples_with_spacing(&self, spacing: f32) -> impl Iterator<Item = T> + '_ {
+        let count = if spacing > 0.0 {
+            // The small tolerance keeps the end when the length is a multiple of the spacing.
+            (self.length() / spacing * (1.0 + f32::EPSILON)) as usize
+        } else {
+            0
+        };
+        (0..=count).map(move |index| self.sample_clamped(index as f32 * spacing))
+    }
+
+    /// The base curve.
+    pub fn base(&self) -> &C {
+        &self.curve
+    }
+
+    /// Returns the base curve, discarding the arc length parametrization.
+    pub fn into_base(self) -> C {
+        self.curve
+    }
+}
+
+impl<T, C> Curve<T> for ArcLengthCurve<T, C>
+where
+    T: NormedVectorSpace,
+    C: Curve<T>,
+{
+    #[inline]
+    fn domain(&self) -> Interval {
+        // The length is finite and nonnegative, which makes for a valid interval.
+        Interval::new(0.0, self.length()).unwrap()
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> T {
+        self.curve.sample_unchecked(self.parameter_at(t))
+    }
+}
+
+/// The closest point of a curve to some point, as produced by [`CurveArcLengthExt::closest_point`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub struct ClosestPoint<T> {
+    /// The parameter of the curve at the closest point.
+    pub parameter: f32,
+    /// The closest point itself, which is the sample of the curve at [`parameter`](Self::parameter).
+    pub point: T,
+    /// The distance from the closest point to the point it is closest to.
+    pub distance: f32,
+}
+
+/// Extension trait implemented by [curves] whose output can be measured, allowing access to
+/// methods based on distance such as arc length parametrization.
+///
+/// This trait is automatically implemented for all curves with output in a
+/// [`NormedVectorSpace`]. See the [module-level documentation] for details.
+///
+/// [curves]: Curve
+/// [module-level documentation]: self
+pub trait CurveArcLengthExt<T>: Curve<T>
+where
+    T: NormedVectorSpace,
+{
+    /// The length of this curve, measured on `segments` segments evenly spaced in parameter space.
+    ///
+    /// # Errors
+    ///
+    /// If `segments` is zero or if this curve has unbounded domain, a [`ResamplingError`] is
+    /// returned.
+    fn arc_length(&self, segments: usize) -> Result<f32, ResamplingError> {
+        let (_, samples) = parameters_and_samples(self, segments)?;
+        Ok(samples
+            .windows(2)
+            .map(|pair| pair[0].distance(pair[1]))
+            .sum())
+    }
+
+    /// Create a new curve parametrized by the distance travelled along this one, so that it is
+    /// traversed at constant speed. The length is measured on `segments` segments evenly spaced in
+    /// parameter space.
+    ///
+    /// # Errors
+    ///
+    /// If `segments` is zero or if this curve has unbounded domain, a [`ResamplingError`] is
+    /// returned.
+    fn reparametrize_by_arc_length(
+        self,
+        segments: usize,
+    ) -> Result<ArcLengthCurve<T, Self>, ResamplingError>
+    where
+        Self: Sized,
+    {
+        ArcLengthCurve::new(self, segments)
+    }
+
+    /// Sample this curve at `samples` points evenly spaced along it, including its start and end,
+    /// measuring its length on `segments` segments evenly spaced in parameter space.
+    ///
+    /// To keep the evenly spaced samples as a curve instead, resample the
+    /// [arc length parametrization] of this curve, for example with
+    /// [`resample_auto`](super::CurveResampleExt::resample_auto).
+    ///
+    /// # Errors
+    ///
+    /// If `samples` is less than two, if `segments` is zero or if this curve has unbounded domain,
+    /// a [`ResamplingError`] is returned.
+    ///
+    /// [arc length parametrization]: CurveArcLengthExt::reparametrize_by_arc_length
+    fn evenly_spaced_samples(
+        &self,
+        samples: usize,
+        segments: usize,
+    ) -> Result<Vec<T>, ResamplingError> {
+        let curve = ArcLengthCurve::new(self, segments)?;
+        let samples = curve.samples(samples)?.collect();
+        Ok(samples)
+    }
+
+    /// Find the point of this curve closest to `point`.
+    ///
+    /// The curve is first sampled on `segments` segments evenly spaced in parameter space, then the
+    /// closest sample is refined within its neighboring segments. The result is only guaranteed to
+    /// be the closest point when no other part of the curve comes closer within a segment.
+    ///
+    /// # Errors
+    ///
+    /// If `segments` is zero or if this curve has unbounded domain, a [`ResamplingError`] is
+    /// returned.
+    fn closest_point(&self, point: T, segments: usize) -> Result<ClosestPoint<T>, ResamplingError> {
+        let (parameters, samples) = parameters_and_samples(self, segments)?;
+        let closest = samples
+            .iter()
+            .map(|sample| sample.distance_squared(point))
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map_or(0, |(index, _)| index);
+
+        // Golden-section search of the segments on either side of the closest sample.
+        let distance = |t: f32| self.sample_unchecked(t).distance_squared(point);
+        let inverse_phi = INVERSE_GOLDEN_RATIO;
+        let mut start = parameters[closest.saturating_sub(1)];
+        let mut end = parameters[(closest + 1).min(parameters.len() - 1)];
+        let mut lower = end - inverse_phi * (end - start);
+        let mut upper = start + inverse_phi * (end - start);
+        let mut lower_distance = distance(lower);
+        let mut upper_distance = distance(upper);
+        for _ in 0..CLOSEST_POINT_ITERATIONS {
+            if lower_distance < upper_distance {
+                end = upper;
+                upper = lower;
+                upper_distance = lower_distance;
+                lower = end - inverse_phi * (end - start);
+                lower_distance = distance(lower);
+            } else {
+                start = lower;
+                lower = upper;
+                lower_distance = upper_distance;
+                upper = start + inverse_phi * (end - start);
+                upper_distance = distance(upper);
+            }
+        }
+
+        // The search can't reach the ends of the curve exactly, so the closest sample is kept if
+        // it's closer.
+        let refined = (start + end) / 2.0;
+        let parameter = if distance(refined) < samples[closest].distance_squared(point) {
+            refined
+        } else {
+            parameters[closest]
+        };
+        let closest_point = self.sample_unchecked(parameter);
+        Ok(ClosestPoint {
+            parameter,
+            point: closest_point,
+            distance: closest_point.distance(point),
+        })
+    }
+}
+
+impl<C, T> CurveArcLengthExt<T> for C
+where
+    C: Curve<T> + ?Sized,
+    T: NormedVectorSpace,
+{
+}
+
+/// Samples `curve` at `segments + 1` evenly spaced parameters, returning them along with the
+/// samples.
+fn parameters_and_samples<T, C>(
+    curve: &C,
+    segments: usize,
+) -> Result<(Vec<f32>, Vec<T>), ResamplingError>
+where
+    C: Curve<T> + ?Sized,
+{
+    let domain = curve.domain();
+    if segments == 0 {
+        return Err(ResamplingError::NotEnoughSamples(segments + 1));
+    }
+    let parameters: Vec<f32> = domain
+        .spaced_points(segments + 1)
+        .map_err(|_| ResamplingError::UnboundedDomain)?
+        .collect();
+    let samples = parameters
+        .iter()
+        .map(|&t| curve.sample_unchecked(t))
+        .collect();
+    Ok((parameters, samples))
+}
+
+/// Interpolates the value in `to` corresponding to `value` in `from`, where both are increasing.
+fn interpolate_table(from: &[f32], to: &[f32], value: f32) -> f32 {
+    if from.len() == 1 {
+        return to[0];
+    }
+    match uneven_interp(from, value) {
+        InterpolationDatum::Exact(index)
+        | InterpolationDatum::LeftTail(index)
+        | InterpolationDatum::RightTail(index) => to[index],
+        InterpolationDatum::Between(lower, upper, s) => to[lower] + (to[upper] - to[lower]) * s,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cubic_splines::{CubicBezier, CubicGenerator},
+        curve::FunctionCurve,
+        ops, vec2, Vec2,
+    };
+    use approx::assert_abs_diff_eq;
+    use core::f32::consts::{PI, TAU};
+
+    #[test]
+    fn arc_length_of_circle() {
+        let circle = FunctionCurve::new(Interval::new(0.0, TAU).unwrap(), |t| {
+            vec2(ops::cos(t), ops::sin(t))
+        });
+        assert_abs_diff_eq!(circle.arc_length(1000).unwrap(), TAU, epsilon = 1e-3);
+        assert!(circle.arc_length(0).is_err());
+    }
+
+    #[test]
+    fn constant_speed() {
+        // Quadratic in its parameter, so that it moves slowly at first.
+        let curve = FunctionCurve::new(Interval::UNIT, |t| vec2(t * t * 4.0, 0.0));
+        let path = curve.reparametrize_by_arc_length(256).unwrap();
+        assert_abs_diff_eq!(path.length(), 4.0, epsilon = 1e-4);
+        assert_abs_diff_eq!(path.sample(1.0).unwrap(), vec2(1.0, 0.0), epsilon = 1e-2);
+        assert_abs_diff_eq!(path.parameter_at(1.0), 0.5, epsilon = 1e-2);
+        assert_abs_diff_eq!(path.distance_at(0.5), 1.0, epsilon = 1e-2);
+
+        let samples: Vec<Vec2> = path.samples_with_spacing(1.0).collect();
+        assert_eq!(samples.len(), 5);
+        for (index, sample) in samples.iter().enumerate() {
+            assert_abs_diff_eq!(sample.x, index as f32, epsilon = 1e-2);
+        }
+    }
+
+    #[test]
+    fn evenly_spaced_spline_samples() {
+        let points = [[
+            vec2(0.0, 0.0),
+            vec2(1.0, 4.0),
+            vec2(4.0, 4.0),
+            vec2(5.0, 0.0),
+        ]];
+        let spline = CubicBezier::new(points).to_curve().unwrap();
+        let samples = spline.evenly_spaced_samples(9, 512).unwrap();
+        let spacings: Vec<f32> = samples.windows(2).map(|w| w[0].distance(w[1])).collect();
+        // Chords are a little shorter than the arcs they span where the spline curves the most.
+        for spacing in &spacings {
+            assert_abs_diff_eq!(*spacing, spacings[0], epsilon = 5e-2);
+        }
+        assert_abs_diff_eq!(samples[8], vec2(5.0, 0.0), epsilon = 1e-4);
+    }
+
+    #[test]
+    fn closest_point_on_arc() {
+        let arc = FunctionCurve::new(Interval::new(0.0, PI).unwrap(), |t| {
+            vec2(ops::cos(t), ops::sin(t))
+        });
+        let closest = arc.closest_point(vec2(0.0, 3.0), 8).unwrap();
+        assert_abs_diff_eq!(closest.parameter, PI / 2.0, epsilon = 1e-4);
+        assert_abs_diff_eq!(closest.point, vec2(0.0, 1.0), epsilon = 1e-4);
+        assert_abs_diff_eq!(closest.distance, 2.0, epsilon = 1e-4);
+
+        // Points beyond the ends are closest to the ends.
+        let closest = arc.closest_point(vec2(2.0, -1.0), 8).unwrap();
+        assert_eq!(closest.parameter, 0.0);
+    }
+}
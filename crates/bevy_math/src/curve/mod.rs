@@ -286,6 +286,8 @@
 //! (curve.domain(), |t| curve.sample_unchecked(t))` is an equivalent function curve.
 
 pub mod adaptors;
+#[cfg(feature = "alloc")]
+pub mod arc_length;
 pub mod cores;
 pub mod derivatives;
 pub mod easing;
@@ -302,6 +304,7 @@ pub use interval::{interval, Interval};
 
 #[cfg(feature = "alloc")]
 pub use {
+    arc_length::{ArcLengthCurve, ClosestPoint, CurveArcLengthExt},
     cores::{EvenCore, UnevenCore},
     sample_curves::*,
 };
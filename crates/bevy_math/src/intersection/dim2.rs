@@ -0,0 +1,300 @@
+use super::RayHit2d;
+use crate::{
+    ops,
+    primitives::{Capsule2d, Circle, Plane2d, Rectangle, Segment2d},
+    Dir2, Isometry2d, Ray2d, Vec2,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A trait for casting rays against 2D primitives, see the [module documentation](super).
+pub trait PrimitiveRayCast2d {
+    /// Casts `ray` against the shape placed by `isometry`, returning the first hit at most at
+    /// `max_distance` along the ray, if any.
+    fn ray_cast(
+        &self,
+        isometry: impl Into<Isometry2d>,
+        ray: Ray2d,
+        max_distance: f32,
+    ) -> Option<RayHit2d>;
+
+    /// Checks whether `ray` hits the shape placed by `isometry` at most at `max_distance` along
+    /// the ray.
+    fn intersects_ray(
+        &self,
+        isometry: impl Into<Isometry2d>,
+        ray: Ray2d,
+        max_distance: f32,
+    ) -> bool {
+        self.ray_cast(isometry, ray, max_distance).is_some()
+    }
+}
+
+/// A ray in the local space of a shape.
+struct LocalRay {
+    origin: Vec2,
+    direction: Vec2,
+}
+
+impl LocalRay {
+    fn new(isometry: Isometry2d, ray: Ray2d) -> Self {
+        let inverse = isometry.rotation.inverse();
+        Self {
+            origin: inverse * (ray.origin - isometry.translation),
+            direction: inverse * *ray.direction,
+        }
+    }
+
+    fn point(&self, distance: f32) -> Vec2 {
+        self.origin + self.direction * distance
+    }
+}
+
+/// Makes the hit of `ray` at `distance` with the given normal in the local space of the shape, or
+/// the hit of a ray starting inside of the shape if `normal` is `None`.
+fn hit(
+    isometry: Isometry2d,
+    ray: Ray2d,
+    distance: f32,
+    normal: Option<Vec2>,
+    max_distance: f32,
+) -> Option<RayHit2d> {
+    if distance > max_distance {
+        return None;
+    }
+    let normal = match normal {
+        Some(normal) => Dir2::new(isometry.rotation * normal).ok()?,
+        None => -ray.direction,
+    };
+    Some(RayHit2d {
+        distance,
+        point: ray.get_point(distance),
+        normal,
+    })
+}
+
+/// Returns the distance to the first intersection of a ray with a circle centered at the origin,
+/// or `0.0` if the ray starts inside of it.
+fn circle_distance(ray: &LocalRay, radius: f32) -> Option<f32> {
+    let b = ray.origin.dot(ray.direction);
+    let c = ray.origin.length_squared() - radius * radius;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+    if b > 0.0 {
+        return None;
+    }
+    let discriminant = b * b - c;
+    (discriminant >= 0.0).then(|| -b - ops::sqrt(discriminant))
+}
+
+impl PrimitiveRayCast2d for Circle {
+    fn ray_cast(
+        &self,
+        isometry: impl Into<Isometry2d>,
+        ray: Ray2d,
+        max_distance: f32,
+    ) -> Option<RayHit2d> {
+        let isometry = isometry.into();
+        let local = LocalRay::new(isometry, ray);
+        let distance = circle_distance(&local, self.radius)?;
+        let normal = (distance > 0.0).then(|| local.point(distance));
+        hit(isometry, ray, distance, normal, max_distance)
+    }
+}
+
+impl PrimitiveRayCast2d for Rectangle {
+    fn ray_cast(
+        &self,
+        isometry: impl Into<Isometry2d>,
+        ray: Ray2d,
+        max_distance: f32,
+    ) -> Option<RayHit2d> {
+        let isometry = isometry.into();
+        let local = LocalRay::new(isometry, ray);
+
+        // The slab method, keeping track of the edge through which the ray enters the rectangle.
+        let mut entry = f32::NEG_INFINITY;
+        let mut exit = f32::INFINITY;
+        let mut normal = Vec2::ZERO;
+        for axis in 0..2 {
+            let origin = local.origin[axis];
+            let direction = local.direction[axis];
+            let half_size = self.half_size[axis];
+            if ops::abs(direction) < f32::EPSILON {
+                if ops::abs(origin) > half_size {
+                    return None;
+                }
+                continue;
+            }
+            let mut near = (-half_size - origin) / direction;
+            let mut far = (half_size - origin) / direction;
+            let mut sign = -1.0;
+            if near > far {
+                core::mem::swap(&mut near, &mut far);
+                sign = 1.0;
+            }
+            if near > entry {
+                entry = near;
+                normal = Vec2::ZERO;
+                normal[axis] = sign;
+            }
+            exit = exit.min(far);
+            if entry > exit {
+                return None;
+            }
+        }
+        if exit < 0.0 {
+            return None;
+        }
+        if entry < 0.0 {
+            return hit(isometry, ray, 0.0, None, max_distance);
+        }
+        hit(isometry, ray, entry, Some(normal), max_distance)
+    }
+}
+
+impl PrimitiveRayCast2d for Capsule2d {
+    fn ray_cast(
+        &self,
+        isometry: impl Into<Isometry2d>,
+        ray: Ray2d,
+        max_distance: f32,
+    ) -> Option<RayHit2d> {
+        let isometry = isometry.into();
+        let local = LocalRay::new(isometry, ray);
+        let origin = local.origin;
+        let axis_point = Vec2::new(0.0, origin.y.clamp(-self.half_length, self.half_length));
+        if origin.distance_squared(axis_point) <= self.radius * self.radius {
+            return hit(isometry, ray, 0.0, None, max_distance);
+        }
+
+        // The capsule is the union of its straight sides and of the circles at its ends.
+        let side = |x: f32| {
+            if ops::abs(local.direction.x) < f32::EPSILON {
+                return None;
+            }
+            let distance = (x - origin.x) / local.direction.x;
+            let y = origin.y + local.direction.y * distance;
+            (distance >= 0.0 && ops::abs(y) <= self.half_length)
+                .then_some((distance, Vec2::new(ops::copysign(1.0, x), 0.0)))
+        };
+        let end = |y: f32| {
+            let center = Vec2::new(0.0, y);
+            let ray = LocalRay {
+                origin: origin - center,
+                direction: local.direction,
+            };
+            circle_distance(&ray, self.radius).map(|distance| (distance, ray.point(distance)))
+        };
+        let (distance, normal) = [
+            side(self.radius),
+            side(-self.radius),
+            end(self.half_length),
+            end(-self.half_length),
+        ]
+        .into_iter()
+        .flatten()
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))?;
+        hit(isometry, ray, distance, Some(normal), max_distance)
+    }
+}
+
+impl PrimitiveRayCast2d for Segment2d {
+    fn ray_cast(
+        &self,
+        isometry: impl Into<Isometry2d>,
+        ray: Ray2d,
+        max_distance: f32,
+    ) -> Option<RayHit2d> {
+        let isometry = isometry.into();
+        let local = LocalRay::new(isometry, ray);
+        let start = self.point1();
+        let edge = self.point2() - start;
+        let denominator = local.direction.perp_dot(edge);
+        if ops::abs(denominator) < f32::EPSILON {
+            return None;
+        }
+        let offset = start - local.origin;
+        let distance = offset.perp_dot(edge) / denominator;
+        let along = offset.perp_dot(local.direction) / denominator;
+        if distance < 0.0 || !(0.0..=1.0).contains(&along) {
+            return None;
+        }
+        let normal = edge.perp();
+        let normal = if normal.dot(local.direction) > 0.0 {
+            -normal
+        } else {
+            normal
+        };
+        hit(isometry, ray, distance, Some(normal), max_distance)
+    }
+}
+
+/// Clips a convex or concave polygon by a line through `line_origin`, keeping the part on the side
+/// the normal of the line points to.
+///
+/// The result is empty if the whole polygon is on the other side.
+#[cfg(feature = "alloc")]
+pub fn clip_polygon_2d(polygon: &[Vec2], line: Plane2d, line_origin: Vec2) -> Vec<Vec2> {
+    super::dim3::clip_polygon(polygon, |point| (point - line_origin).dot(*line.normal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rot2;
+    use approx::assert_relative_eq;
+    use core::f32::consts::FRAC_PI_4;
+
+    #[test]
+    fn ray_cast_2d_shapes() {
+        let ray = Ray2d::new(Vec2::new(-10.0, 0.0), Dir2::X);
+
+        let rectangle = Rectangle::new(2.0, 2.0);
+        let isometry = Isometry2d::new(Vec2::ZERO, Rot2::radians(FRAC_PI_4));
+        let hit = rectangle.ray_cast(isometry, ray, 20.0).unwrap();
+        assert_relative_eq!(hit.distance, 10.0 - ops::sqrt(2.0), epsilon = 1e-5);
+        assert_relative_eq!(hit.point, Vec2::new(-ops::sqrt(2.0), 0.0), epsilon = 1e-5);
+
+        let circle = Circle::new(1.0);
+        let hit = circle.ray_cast(Vec2::new(0.0, 0.5), ray, 20.0).unwrap();
+        assert!(hit.normal.x < 0.0 && hit.normal.y < 0.0);
+        assert!(!circle.intersects_ray(Vec2::new(0.0, 1.5), ray, 20.0));
+
+        let capsule = Capsule2d::new(0.5, 2.0);
+        let hit = capsule.ray_cast(Isometry2d::IDENTITY, ray, 20.0).unwrap();
+        assert_relative_eq!(hit.distance, 9.5);
+        assert_eq!(hit.normal, Dir2::NEG_X);
+        let above = Ray2d::new(Vec2::new(0.0, 10.0), Dir2::NEG_Y);
+        let hit = capsule.ray_cast(Isometry2d::IDENTITY, above, 20.0).unwrap();
+        assert_relative_eq!(hit.distance, 8.5);
+
+        let segment = Segment2d::new(Dir2::Y, 2.0);
+        let hit = segment.ray_cast(Vec2::new(1.0, 0.5), ray, 20.0).unwrap();
+        assert_relative_eq!(hit.distance, 11.0);
+        assert_eq!(hit.normal, Dir2::NEG_X);
+        assert!(segment.ray_cast(Vec2::new(1.0, 1.5), ray, 20.0).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn clip_triangle_by_line() {
+        let triangle = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        let clipped = clip_polygon_2d(&triangle, Plane2d::new(Vec2::NEG_Y), Vec2::Y);
+        assert_eq!(
+            clipped,
+            [
+                Vec2::new(0.0, 0.0),
+                Vec2::new(2.0, 0.0),
+                Vec2::new(1.0, 1.0),
+                Vec2::new(0.0, 1.0),
+            ]
+        );
+    }
+}
@@ -0,0 +1,695 @@
+use super::{ClosestPoints3d, RayHit3d};
+use crate::{
+    bounding::Aabb3d,
+    ops,
+    primitives::{Capsule3d, Cuboid, Cylinder, InfinitePlane3d, Sphere, Triangle3d},
+    Dir3, Isometry3d, Ray3d, Vec3,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A trait for casting rays against 3D primitives, see the [module documentation](super).
+pub trait PrimitiveRayCast3d {
+    /// Casts `ray` against the shape placed by `isometry`, returning the first hit at most at
+    /// `max_distance` along the ray, if any.
+    fn ray_cast(
+        &self,
+        isometry: impl Into<Isometry3d>,
+        ray: Ray3d,
+        max_distance: f32,
+    ) -> Option<RayHit3d>;
+
+    /// Checks whether `ray` hits the shape placed by `isometry` at most at `max_distance` along
+    /// the ray.
+    fn intersects_ray(
+        &self,
+        isometry: impl Into<Isometry3d>,
+        ray: Ray3d,
+        max_distance: f32,
+    ) -> bool {
+        self.ray_cast(isometry, ray, max_distance).is_some()
+    }
+}
+
+/// A ray in the local space of a shape.
+struct LocalRay {
+    origin: Vec3,
+    direction: Vec3,
+}
+
+impl LocalRay {
+    fn new(isometry: Isometry3d, ray: Ray3d) -> Self {
+        let inverse = isometry.rotation.inverse();
+        Self {
+            origin: inverse * (ray.origin - Vec3::from(isometry.translation)),
+            direction: inverse * *ray.direction,
+        }
+    }
+
+    fn point(&self, distance: f32) -> Vec3 {
+        self.origin + self.direction * distance
+    }
+}
+
+/// Makes the hit of `ray` at `distance` with the given normal in the local space of the shape, or
+/// the hit of a ray starting inside of the shape if `normal` is `None`.
+fn hit(
+    isometry: Isometry3d,
+    ray: Ray3d,
+    distance: f32,
+    normal: Option<Vec3>,
+    max_distance: f32,
+) -> Option<RayHit3d> {
+    if distance > max_distance {
+        return None;
+    }
+    let normal = match normal {
+        Some(normal) => Dir3::new(isometry.rotation * normal).ok()?,
+        None => -ray.direction,
+    };
+    Some(RayHit3d {
+        distance,
+        point: ray.get_point(distance),
+        normal,
+    })
+}
+
+/// Returns the distance to the first intersection of a ray with a sphere centered at the origin,
+/// or `0.0` if the ray starts inside of it.
+fn sphere_distance(ray: &LocalRay, radius: f32) -> Option<f32> {
+    let b = ray.origin.dot(ray.direction);
+    let c = ray.origin.length_squared() - radius * radius;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+    if b > 0.0 {
+        return None;
+    }
+    let discriminant = b * b - c;
+    (discriminant >= 0.0).then(|| -b - ops::sqrt(discriminant))
+}
+
+/// Returns the distance to the first intersection of a ray with the side of a cylinder around the
+/// `y` axis, between `-half_height` and `half_height`, for a ray starting outside of it.
+fn cylinder_side_distance(ray: &LocalRay, radius: f32, half_height: f32) -> Option<f32> {
+    let (origin, direction) = (ray.origin, ray.direction);
+    let a = direction.x * direction.x + direction.z * direction.z;
+    if a < f32::EPSILON {
+        return None;
+    }
+    let b = origin.x * direction.x + origin.z * direction.z;
+    let c = origin.x * origin.x + origin.z * origin.z - radius * radius;
+    let discriminant = b * b - a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let distance = (-b - ops::sqrt(discriminant)) / a;
+    let y = origin.y + direction.y * distance;
+    (distance >= 0.0 && ops::abs(y) <= half_height).then_some(distance)
+}
+
+fn min_hit(hits: impl IntoIterator<Item = Option<(f32, Vec3)>>) -> Option<(f32, Vec3)> {
+    hits.into_iter()
+        .flatten()
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+}
+
+impl PrimitiveRayCast3d for Sphere {
+    fn ray_cast(
+        &self,
+        isometry: impl Into<Isometry3d>,
+        ray: Ray3d,
+        max_distance: f32,
+    ) -> Option<RayHit3d> {
+        let isometry = isometry.into();
+        let local = LocalRay::new(isometry, ray);
+        let distance = sphere_distance(&local, self.radius)?;
+        let normal = (distance > 0.0).then(|| local.point(distance));
+        hit(isometry, ray, distance, normal, max_distance)
+    }
+}
+
+impl PrimitiveRayCast3d for Cuboid {
+    fn ray_cast(
+        &self,
+        isometry: impl Into<Isometry3d>,
+        ray: Ray3d,
+        max_distance: f32,
+    ) -> Option<RayHit3d> {
+        let isometry = isometry.into();
+        let local = LocalRay::new(isometry, ray);
+
+        // The slab method, keeping track of the face through which the ray enters the box.
+        let mut entry = f32::NEG_INFINITY;
+        let mut exit = f32::INFINITY;
+        let mut normal = Vec3::ZERO;
+        for axis in 0..3 {
+            let origin = local.origin[axis];
+            let direction = local.direction[axis];
+            let half_size = self.half_size[axis];
+            if ops::abs(direction) < f32::EPSILON {
+                if ops::abs(origin) > half_size {
+                    return None;
+                }
+                continue;
+            }
+            let mut near = (-half_size - origin) / direction;
+            let mut far = (half_size - origin) / direction;
+            let mut sign = -1.0;
+            if near > far {
+                core::mem::swap(&mut near, &mut far);
+                sign = 1.0;
+            }
+            if near > entry {
+                entry = near;
+                normal = Vec3::ZERO;
+                normal[axis] = sign;
+            }
+            exit = exit.min(far);
+            if entry > exit {
+                return None;
+            }
+        }
+        if exit < 0.0 {
+            return None;
+        }
+        if entry < 0.0 {
+            return hit(isometry, ray, 0.0, None, max_distance);
+        }
+        hit(isometry, ray, entry, Some(normal), max_distance)
+    }
+}
+
+impl PrimitiveRayCast3d for Capsule3d {
+    fn ray_cast(
+        &self,
+        isometry: impl Into<Isometry3d>,
+        ray: Ray3d,
+        max_distance: f32,
+    ) -> Option<RayHit3d> {
+        let isometry = isometry.into();
+        let local = LocalRay::new(isometry, ray);
+        let origin = local.origin;
+        let axis_point = Vec3::new(
+            0.0,
+            origin.y.clamp(-self.half_length, self.half_length),
+            0.0,
+        );
+        if origin.distance_squared(axis_point) <= self.radius * self.radius {
+            return hit(isometry, ray, 0.0, None, max_distance);
+        }
+
+        // The capsule is the union of its cylindrical side and of the spheres at its ends.
+        let side = cylinder_side_distance(&local, self.radius, self.half_length).map(|distance| {
+            let point = local.point(distance);
+            (distance, Vec3::new(point.x, 0.0, point.z))
+        });
+        let end = |y: f32| {
+            let center = Vec3::new(0.0, y, 0.0);
+            let ray = LocalRay {
+                origin: origin - center,
+                direction: local.direction,
+            };
+            sphere_distance(&ray, self.radius).map(|distance| (distance, ray.point(distance)))
+        };
+        let (distance, normal) = min_hit([side, end(self.half_length), end(-self.half_length)])?;
+        hit(isometry, ray, distance, Some(normal), max_distance)
+    }
+}
+
+impl PrimitiveRayCast3d for Cylinder {
+    fn ray_cast(
+        &self,
+        isometry: impl Into<Isometry3d>,
+        ray: Ray3d,
+        max_distance: f32,
+    ) -> Option<RayHit3d> {
+        let isometry = isometry.into();
+        let local = LocalRay::new(isometry, ray);
+        let (origin, direction) = (local.origin, local.direction);
+        let radius_squared = self.radius * self.radius;
+        if ops::abs(origin.y) <= self.half_height
+            && origin.x * origin.x + origin.z * origin.z <= radius_squared
+        {
+            return hit(isometry, ray, 0.0, None, max_distance);
+        }
+
+        let side = cylinder_side_distance(&local, self.radius, self.half_height).map(|distance| {
+            let point = local.point(distance);
+            (distance, Vec3::new(point.x, 0.0, point.z))
+        });
+        let cap = |y: f32| {
+            if ops::abs(direction.y) < f32::EPSILON {
+                return None;
+            }
+            let distance = (y - origin.y) / direction.y;
+            let point = local.point(distance);
+            (distance >= 0.0 && point.x * point.x + point.z * point.z <= radius_squared)
+                .then_some((distance, Vec3::new(0.0, ops::copysign(1.0, y), 0.0)))
+        };
+        let (distance, normal) = min_hit([side, cap(self.half_height), cap(-self.half_height)])?;
+        hit(isometry, ray, distance, Some(normal), max_distance)
+    }
+}
+
+impl PrimitiveRayCast3d for Triangle3d {
+    fn ray_cast(
+        &self,
+        isometry: impl Into<Isometry3d>,
+        ray: Ray3d,
+        max_distance: f32,
+    ) -> Option<RayHit3d> {
+        let isometry = isometry.into();
+        let local = LocalRay::new(isometry, ray);
+        let [a, b, c] = self.vertices;
+
+        // The Möller-Trumbore algorithm.
+        let edge1 = b - a;
+        let edge2 = c - a;
+        let p = local.direction.cross(edge2);
+        let determinant = edge1.dot(p);
+        if ops::abs(determinant) < f32::EPSILON {
+            return None;
+        }
+        let inverse = 1.0 / determinant;
+        let s = local.origin - a;
+        let u = s.dot(p) * inverse;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = s.cross(edge1);
+        let v = local.direction.dot(q) * inverse;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+        let distance = edge2.dot(q) * inverse;
+        if distance < 0.0 {
+            return None;
+        }
+
+        let normal = edge1.cross(edge2);
+        let normal = if normal.dot(local.direction) > 0.0 {
+            -normal
+        } else {
+            normal
+        };
+        hit(isometry, ray, distance, Some(normal), max_distance)
+    }
+}
+
+impl PrimitiveRayCast3d for InfinitePlane3d {
+    fn ray_cast(
+        &self,
+        isometry: impl Into<Isometry3d>,
+        ray: Ray3d,
+        max_distance: f32,
+    ) -> Option<RayHit3d> {
+        let isometry = isometry.into();
+        let local = LocalRay::new(isometry, ray);
+        let normal = *self.normal;
+        let denominator = normal.dot(local.direction);
+        if ops::abs(denominator) < f32::EPSILON {
+            return None;
+        }
+        let distance = -local.origin.dot(normal) / denominator;
+        if distance < 0.0 {
+            return None;
+        }
+        let normal = if denominator > 0.0 { -normal } else { normal };
+        hit(isometry, ray, distance, Some(normal), max_distance)
+    }
+}
+
+/// Computes the closest points between the segments from `a[0]` to `a[1]` and from `b[0]` to
+/// `b[1]`.
+pub fn segment_segment_closest_points(a: [Vec3; 2], b: [Vec3; 2]) -> ClosestPoints3d {
+    let direction_a = a[1] - a[0];
+    let direction_b = b[1] - b[0];
+    let offset = a[0] - b[0];
+    let length_a = direction_a.length_squared();
+    let length_b = direction_b.length_squared();
+    let f = direction_b.dot(offset);
+
+    // From "Real-Time Collision Detection" by Christer Ericson, section 5.1.9.
+    let (s, t) = if length_a <= f32::EPSILON && length_b <= f32::EPSILON {
+        (0.0, 0.0)
+    } else if length_a <= f32::EPSILON {
+        (0.0, (f / length_b).clamp(0.0, 1.0))
+    } else {
+        let c = direction_a.dot(offset);
+        if length_b <= f32::EPSILON {
+            ((-c / length_a).clamp(0.0, 1.0), 0.0)
+        } else {
+            let b = direction_a.dot(direction_b);
+            let denominator = length_a * length_b - b * b;
+            let s = if denominator > f32::EPSILON {
+                ((b * f - c * length_b) / denominator).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let t = (b * s + f) / length_b;
+            if t < 0.0 {
+                ((-c / length_a).clamp(0.0, 1.0), 0.0)
+            } else if t > 1.0 {
+                (((b - c) / length_a).clamp(0.0, 1.0), 1.0)
+            } else {
+                (s, t)
+            }
+        }
+    };
+
+    let point_a = a[0] + direction_a * s;
+    let point_b = b[0] + direction_b * t;
+    ClosestPoints3d {
+        point_a,
+        point_b,
+        distance: point_a.distance(point_b),
+    }
+}
+
+/// Returns the endpoints of the segment at the core of a capsule placed by `isometry`.
+fn capsule_segment(capsule: &Capsule3d, isometry: Isometry3d) -> [Vec3; 2] {
+    [
+        Vec3::from(isometry.transform_point(Vec3::new(0.0, -capsule.half_length, 0.0))),
+        Vec3::from(isometry.transform_point(Vec3::new(0.0, capsule.half_length, 0.0))),
+    ]
+}
+
+/// Offsets the closest points between the cores of two shapes by their radii.
+fn inflate(core: ClosestPoints3d, radius_a: f32, radius_b: f32, fallback: Vec3) -> ClosestPoints3d {
+    let direction = (core.point_b - core.point_a)
+        .try_normalize()
+        .unwrap_or(fallback);
+    ClosestPoints3d {
+        point_a: core.point_a + direction * radius_a,
+        point_b: core.point_b - direction * radius_b,
+        distance: core.distance - radius_a - radius_b,
+    }
+}
+
+/// Computes the closest points between the segment from `segment[0]` to `segment[1]` and the
+/// capsule placed by `isometry`.
+pub fn segment_capsule_closest_points(
+    segment: [Vec3; 2],
+    capsule: &Capsule3d,
+    isometry: impl Into<Isometry3d>,
+) -> ClosestPoints3d {
+    let isometry = isometry.into();
+    let core = segment_segment_closest_points(segment, capsule_segment(capsule, isometry));
+    inflate(core, 0.0, capsule.radius, isometry.rotation * Vec3::X)
+}
+
+/// Computes the closest points between two capsules placed by `isometry_a` and `isometry_b`.
+pub fn capsule_capsule_closest_points(
+    capsule_a: &Capsule3d,
+    isometry_a: impl Into<Isometry3d>,
+    capsule_b: &Capsule3d,
+    isometry_b: impl Into<Isometry3d>,
+) -> ClosestPoints3d {
+    let isometry_a = isometry_a.into();
+    let core = segment_segment_closest_points(
+        capsule_segment(capsule_a, isometry_a),
+        capsule_segment(capsule_b, isometry_b.into()),
+    );
+    inflate(
+        core,
+        capsule_a.radius,
+        capsule_b.radius,
+        isometry_a.rotation * Vec3::X,
+    )
+}
+
+/// Computes the closest points between a sphere placed at `center` and the capsule placed by
+/// `isometry`.
+pub fn sphere_capsule_closest_points(
+    sphere: &Sphere,
+    center: Vec3,
+    capsule: &Capsule3d,
+    isometry: impl Into<Isometry3d>,
+) -> ClosestPoints3d {
+    let isometry = isometry.into();
+    let core = segment_segment_closest_points([center, center], capsule_segment(capsule, isometry));
+    inflate(
+        core,
+        sphere.radius,
+        capsule.radius,
+        isometry.rotation * Vec3::X,
+    )
+}
+
+/// Checks whether a triangle intersects an axis-aligned bounding box, including when one contains
+/// the other.
+pub fn triangle_intersects_aabb(triangle: &Triangle3d, aabb: &Aabb3d) -> bool {
+    // The separating axis theorem, from "Fast 3D Triangle-Box Overlap Testing" by Tomas
+    // Akenine-Möller.
+    let center = Vec3::from((aabb.min + aabb.max) / 2.0);
+    let half_size = Vec3::from((aabb.max - aabb.min) / 2.0);
+    let vertices = triangle.vertices.map(|vertex| vertex - center);
+    let separated = |axis: Vec3| {
+        let projections = vertices.map(|vertex| vertex.dot(axis));
+        let min = projections[0].min(projections[1]).min(projections[2]);
+        let max = projections[0].max(projections[1]).max(projections[2]);
+        let radius = half_size.dot(axis.abs());
+        min > radius || max < -radius
+    };
+
+    if [Vec3::X, Vec3::Y, Vec3::Z].into_iter().any(separated) {
+        return false;
+    }
+    let edges = [
+        vertices[1] - vertices[0],
+        vertices[2] - vertices[1],
+        vertices[0] - vertices[2],
+    ];
+    if separated(edges[0].cross(edges[1])) {
+        return false;
+    }
+    !edges.iter().any(|edge| {
+        [Vec3::X, Vec3::Y, Vec3::Z].into_iter().any(|axis| {
+            let axis = axis.cross(*edge);
+            axis.length_squared() > f32::EPSILON && separated(axis)
+        })
+    })
+}
+
+/// Clips a convex or concave polygon by a plane through `plane_origin`, keeping the part on the
+/// side the normal of the plane points to.
+///
+/// The result is empty if the whole polygon is on the other side.
+#[cfg(feature = "alloc")]
+pub fn clip_polygon_3d(polygon: &[Vec3], plane: InfinitePlane3d, plane_origin: Vec3) -> Vec<Vec3> {
+    clip_polygon(polygon, |point| (point - plane_origin).dot(*plane.normal))
+}
+
+/// Clips a polygon by the plane where `signed_distance` is zero, keeping the part where it is
+/// positive, with the Sutherland-Hodgman algorithm.
+#[cfg(feature = "alloc")]
+pub(super) fn clip_polygon<P>(polygon: &[P], signed_distance: impl Fn(P) -> f32) -> Vec<P>
+where
+    P: Copy
+        + core::ops::Add<Output = P>
+        + core::ops::Sub<Output = P>
+        + core::ops::Mul<f32, Output = P>,
+{
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (index, &start) in polygon.iter().enumerate() {
+        let end = polygon[(index + 1) % polygon.len()];
+        let (start_distance, end_distance) = (signed_distance(start), signed_distance(end));
+        if start_distance >= 0.0 {
+            clipped.push(start);
+        }
+        if (start_distance > 0.0 && end_distance < 0.0)
+            || (start_distance < 0.0 && end_distance > 0.0)
+        {
+            let t = start_distance / (start_distance - end_distance);
+            clipped.push(start + (end - start) * t);
+        }
+    }
+    clipped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Quat, Vec3A};
+    use approx::assert_relative_eq;
+    use core::f32::consts::FRAC_PI_4;
+
+    #[test]
+    fn ray_cast_oriented_box() {
+        let cuboid = Cuboid::new(2.0, 2.0, 2.0);
+        let isometry = Isometry3d::new(Vec3::new(5.0, 0.0, 0.0), Quat::from_rotation_z(FRAC_PI_4));
+        let ray = Ray3d::new(Vec3::ZERO, Dir3::X);
+
+        // The box is rotated so that its edge faces the ray.
+        let hit = cuboid.ray_cast(isometry, ray, 10.0).unwrap();
+        assert_relative_eq!(hit.distance, 5.0 - ops::sqrt(2.0), epsilon = 1e-5);
+        assert!(hit.normal.x < 0.0);
+        assert!(cuboid.ray_cast(isometry, ray, 3.0).is_none());
+        assert!(cuboid
+            .ray_cast(isometry, Ray3d::new(Vec3::ZERO, Dir3::NEG_X), 10.0)
+            .is_none());
+
+        // From the inside.
+        let inside = cuboid.ray_cast(
+            isometry,
+            Ray3d::new(isometry.translation.into(), Dir3::Y),
+            10.0,
+        );
+        assert_eq!(inside.unwrap().distance, 0.0);
+    }
+
+    #[test]
+    fn ray_cast_round_shapes() {
+        let ray = Ray3d::new(Vec3::new(0.0, 10.0, 0.0), Dir3::NEG_Y);
+
+        let capsule = Capsule3d::new(0.5, 2.0);
+        let hit = capsule.ray_cast(Isometry3d::IDENTITY, ray, 20.0).unwrap();
+        assert_relative_eq!(hit.distance, 8.5);
+        assert_eq!(hit.normal, Dir3::Y);
+
+        // Along the side of a capsule lying along `x`.
+        let isometry = Isometry3d::from_rotation(Quat::from_rotation_z(FRAC_PI_4 * 2.0));
+        let hit = capsule.ray_cast(isometry, ray, 20.0).unwrap();
+        assert_relative_eq!(hit.distance, 9.5);
+        assert_relative_eq!(hit.normal.y, 1.0, epsilon = 1e-5);
+
+        let cylinder = Cylinder::new(1.0, 2.0);
+        let hit = cylinder
+            .ray_cast(Vec3::new(0.5, 0.0, 0.0), ray, 20.0)
+            .unwrap();
+        assert_relative_eq!(hit.distance, 9.0);
+        assert_eq!(hit.normal, Dir3::Y);
+
+        let sphere = Sphere::new(1.0);
+        let hit = sphere
+            .ray_cast(Vec3::new(0.0, 1.0, 0.0), ray, 20.0)
+            .unwrap();
+        assert_relative_eq!(hit.distance, 8.0);
+        assert_relative_eq!(hit.point, Vec3::new(0.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn ray_cast_triangle_and_plane() {
+        let triangle = Triangle3d::new(
+            Vec3::new(-1.0, 0.0, -1.0),
+            Vec3::new(1.0, 0.0, -1.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        );
+        let down = Ray3d::new(Vec3::new(0.0, 2.0, 0.0), Dir3::NEG_Y);
+        let up = Ray3d::new(Vec3::new(0.0, -2.0, 0.0), Dir3::Y);
+        for ray in [down, up] {
+            let hit = triangle.ray_cast(Isometry3d::IDENTITY, ray, 5.0).unwrap();
+            assert_relative_eq!(hit.distance, 2.0);
+            assert_eq!(hit.normal, -ray.direction);
+        }
+        let outside = Ray3d::new(Vec3::new(2.0, 2.0, 0.0), Dir3::NEG_Y);
+        assert!(!triangle.intersects_ray(Isometry3d::IDENTITY, outside, 5.0));
+
+        let plane = InfinitePlane3d::new(Vec3::Y);
+        let hit = plane.ray_cast(Vec3::new(0.0, 1.0, 0.0), down, 5.0).unwrap();
+        assert_relative_eq!(hit.distance, 1.0);
+        assert_eq!(hit.normal, Dir3::Y);
+    }
+
+    #[test]
+    fn closest_points() {
+        let points = segment_segment_closest_points(
+            [Vec3::new(-1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0)],
+            [Vec3::new(0.5, 1.0, -1.0), Vec3::new(0.5, 1.0, 1.0)],
+        );
+        assert_relative_eq!(points.point_a, Vec3::new(0.5, 0.0, 0.0));
+        assert_relative_eq!(points.point_b, Vec3::new(0.5, 1.0, 0.0));
+        assert_relative_eq!(points.distance, 1.0);
+
+        let capsule = Capsule3d::new(0.25, 1.0);
+        let points = segment_capsule_closest_points(
+            [Vec3::new(1.0, -1.0, 0.0), Vec3::new(1.0, 1.0, 0.0)],
+            &capsule,
+            Isometry3d::IDENTITY,
+        );
+        assert_relative_eq!(points.distance, 0.75);
+        assert_relative_eq!(points.point_b.x, 0.25);
+
+        // Overlapping capsules.
+        let points = capsule_capsule_closest_points(
+            &capsule,
+            Isometry3d::IDENTITY,
+            &capsule,
+            Vec3::new(0.25, 0.0, 0.0),
+        );
+        assert_relative_eq!(points.distance, -0.25);
+
+        let points = sphere_capsule_closest_points(
+            &Sphere::new(0.5),
+            Vec3::new(0.0, 2.0, 0.0),
+            &capsule,
+            Isometry3d::IDENTITY,
+        );
+        assert_relative_eq!(points.distance, 0.75);
+        assert_relative_eq!(points.point_b, Vec3::new(0.0, 0.75, 0.0));
+    }
+
+    #[test]
+    fn triangle_aabb() {
+        let aabb = Aabb3d::new(Vec3A::ZERO, Vec3A::ONE);
+        let triangle = |offset: Vec3| {
+            Triangle3d::new(
+                Vec3::new(-0.5, 0.0, 0.0) + offset,
+                Vec3::new(0.5, 0.0, 0.0) + offset,
+                Vec3::new(0.0, 0.5, 0.0) + offset,
+            )
+        };
+        assert!(triangle_intersects_aabb(&triangle(Vec3::ZERO), &aabb));
+        assert!(triangle_intersects_aabb(
+            &triangle(Vec3::new(1.25, 0.0, 0.0)),
+            &aabb
+        ));
+        assert!(!triangle_intersects_aabb(
+            &triangle(Vec3::new(2.0, 0.0, 0.0)),
+            &aabb
+        ));
+        assert!(!triangle_intersects_aabb(
+            &triangle(Vec3::new(0.0, 0.0, 1.5)),
+            &aabb
+        ));
+
+        // A triangle crossing a corner of the box diagonally, whose bounding box overlaps it.
+        let diagonal = Triangle3d::new(
+            Vec3::new(3.5, 0.0, 0.0),
+            Vec3::new(0.0, 3.5, 0.0),
+            Vec3::new(0.0, 0.0, 3.5),
+        );
+        assert!(!triangle_intersects_aabb(&diagonal, &aabb));
+        let diagonal = Triangle3d::new(
+            Vec3::new(2.5, 0.0, 0.0),
+            Vec3::new(0.0, 2.5, 0.0),
+            Vec3::new(0.0, 0.0, 2.5),
+        );
+        assert!(triangle_intersects_aabb(&diagonal, &aabb));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn clip_polygon_by_plane() {
+        let square = [
+            Vec3::new(-1.0, -1.0, 0.0),
+            Vec3::new(1.0, -1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(-1.0, 1.0, 0.0),
+        ];
+        let clipped = clip_polygon_3d(&square, InfinitePlane3d::new(Vec3::X), Vec3::ZERO);
+        assert_eq!(
+            clipped,
+            [
+                Vec3::new(0.0, -1.0, 0.0),
+                Vec3::new(1.0, -1.0, 0.0),
+                Vec3::new(1.0, 1.0, 0.0),
+                Vec3::new(0.0, 1.0, 0.0),
+            ]
+        );
+        let clipped = clip_polygon_3d(&square, InfinitePlane3d::new(Vec3::X), Vec3::X * 2.0);
+        assert!(clipped.is_empty());
+    }
+}
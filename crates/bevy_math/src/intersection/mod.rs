@@ -0,0 +1,75 @@
+//! Intersection and distance queries between primitive shapes.
+//!
+//! Ray casts against the [primitives](crate::primitives) are provided by the [`PrimitiveRayCast2d`]
+//! and [`PrimitiveRayCast3d`] traits, returning a [`RayHit2d`] or [`RayHit3d`] with the distance,
+//! point and normal of the hit. The shapes are placed in the world by an isometry, so that for
+//! example a [`Cuboid`](crate::primitives::Cuboid) is tested as an oriented bounding box.
+//!
+//! Distances between shapes are computed by functions returning the [`ClosestPoints3d`] of both of
+//! them, such as [`segment_capsule_closest_points`], and some overlap tests without further
+//! information are also provided, such as [`triangle_intersects_aabb`].
+//!
+//! Polygons can be clipped by planes with [`clip_polygon_3d`] and [`clip_polygon_2d`].
+//!
+//! ```
+//! # use bevy_math::{intersection::*, primitives::Cuboid, Dir3, Isometry3d, Quat, Ray3d, Vec3};
+//! let obb = Cuboid::new(2.0, 2.0, 2.0);
+//! let isometry = Isometry3d::new(Vec3::new(0.0, 0.0, -5.0), Quat::from_rotation_y(0.5));
+//! let ray = Ray3d::new(Vec3::ZERO, Dir3::NEG_Z);
+//!
+//! let hit = obb.ray_cast(isometry, ray, f32::MAX).unwrap();
+//! assert!(hit.distance > 3.0 && hit.distance < 4.0);
+//! assert!(hit.normal.dot(Vec3::Z) > 0.0);
+//! ```
+
+mod dim2;
+mod dim3;
+
+pub use dim2::*;
+pub use dim3::*;
+
+use crate::{Dir2, Dir3, Vec2, Vec3};
+
+/// The intersection of a ray with a shape in 2D, see [`PrimitiveRayCast2d`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit2d {
+    /// The distance along the ray to the hit.
+    ///
+    /// It is `0.0` for rays starting inside of the shape.
+    pub distance: f32,
+    /// The point of the hit.
+    pub point: Vec2,
+    /// The normal of the surface at the hit, facing the origin of the ray.
+    ///
+    /// It is opposite to the direction of rays starting inside of the shape.
+    pub normal: Dir2,
+}
+
+/// The intersection of a ray with a shape in 3D, see [`PrimitiveRayCast3d`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit3d {
+    /// The distance along the ray to the hit.
+    ///
+    /// It is `0.0` for rays starting inside of the shape.
+    pub distance: f32,
+    /// The point of the hit.
+    pub point: Vec3,
+    /// The normal of the surface at the hit, facing the origin of the ray.
+    ///
+    /// It is opposite to the direction of rays starting inside of the shape.
+    pub normal: Dir3,
+}
+
+/// The closest points between two shapes in 3D, and the distance between them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClosestPoints3d {
+    /// The point of the first shape closest to the second one.
+    pub point_a: Vec3,
+    /// The point of the second shape closest to the first one.
+    pub point_b: Vec3,
+    /// The distance between the shapes.
+    ///
+    /// It is negative when they overlap, in which case the points are the deepest points of each
+    /// shape inside the other and `-distance` is the penetration depth.
+    pub distance: f32,
+}
@@ -0,0 +1,149 @@
+//! A random number generator producing the same numbers on every platform.
+//!
+//! Lockstep simulations, replays and procedural generation need random numbers that only depend on
+//! a seed. [`DeterministicRng`] is a small and fast generator whose output is fully specified by its
+//! seed and stream, unlike the generators of [`rand`] which may change between versions or
+//! platforms, and whose state can be saved and restored.
+//!
+//! Along with the `libm` feature, which makes the transcendental functions of [`ops`](crate::ops)
+//! platform-independent, this allows floating-point simulations to produce identical results
+//! across platforms, as long as the random numbers are requested in the same order. Systems
+//! running in parallel should therefore not share a generator: each of them should get its own
+//! stream, for example with [`DeterministicRng::fork`].
+//!
+//! The engine itself doesn't use this generator: Bevy's systems don't draw random numbers on the
+//! CPU, so the determinism of a simulation only depends on the generators it owns, which are
+//! usually stored in resources or components seeded by the app.
+//!
+//! ```
+//! # use bevy_math::{primitives::Circle, sampling::DeterministicRng, ShapeSample};
+//! # use rand::Rng;
+//! let mut rng = DeterministicRng::new(1234);
+//! // Independent generators, for example for different systems:
+//! let mut spawning = rng.fork(0);
+//! let mut weather = rng.fork(1);
+//!
+//! let spawn_point = Circle::new(10.0).sample_interior(&mut spawning);
+//! let rain: bool = weather.gen();
+//! ```
+
+use rand::{Error, RngCore, SeedableRng};
+
+/// A deterministic random number generator, see the [module documentation](self).
+///
+/// This is the PCG32 generator of Melissa O'Neill (XSH RR variant with a 64-bit state), which is
+/// not cryptographically secure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeterministicRng {
+    state: u64,
+    increment: u64,
+}
+
+impl DeterministicRng {
+    const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+
+    /// Creates a generator from a `seed`, using the default stream.
+    pub fn new(seed: u64) -> Self {
+        Self::with_stream(seed, 0)
+    }
+
+    /// Creates a generator from a `seed` and a `stream`.
+    ///
+    /// Generators with the same seed and different streams produce unrelated numbers.
+    pub fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        rng.step();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.step();
+        rng
+    }
+
+    /// Creates a new generator for `stream` from the next number of this generator.
+    ///
+    /// Forking a generator with the same state and `stream` always gives the same generator.
+    pub fn fork(&mut self, stream: u64) -> Self {
+        Self::with_stream(self.next_u64(), stream)
+    }
+
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+    }
+}
+
+impl Default for DeterministicRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl RngCore for DeterministicRng {
+    fn next_u32(&mut self) -> u32 {
+        let state = self.state;
+        self.step();
+        let xor_shifted = (((state >> 18) ^ state) >> 27) as u32;
+        xor_shifted.rotate_right((state >> 59) as u32)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let low = self.next_u32() as u64;
+        low | ((self.next_u32() as u64) << 32)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(4) {
+            let bytes = self.next_u32().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for DeterministicRng {
+    /// The seed, followed by the stream, in little endian.
+    type Seed = [u8; 16];
+
+    fn from_seed(seed: [u8; 16]) -> Self {
+        let [seed, stream] = [0, 8].map(|start| {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&seed[start..start + 8]);
+            u64::from_le_bytes(bytes)
+        });
+        Self::with_stream(seed, stream)
+    }
+
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_the_reference_implementation() {
+        // The outputs of the reference `pcg32-demo` for a seed of 42 and a stream of 54.
+        let mut rng = DeterministicRng::with_stream(42, 54);
+        let numbers: [u32; 6] = core::array::from_fn(|_| rng.next_u32());
+        assert_eq!(
+            numbers,
+            [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e]
+        );
+
+        let mut a = DeterministicRng::new(7);
+        let mut b = a;
+        assert_eq!(a.fork(3), b.fork(3));
+        assert_ne!(a.fork(1).next_u64(), a.fork(2).next_u64());
+    }
+}
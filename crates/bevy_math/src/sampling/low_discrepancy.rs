@@ -0,0 +1,148 @@
+//! Low-discrepancy sequences, generating points that evenly cover the unit square or cube.
+//!
+//! Each new point of a low-discrepancy sequence falls away from the previous ones, so that any
+//! number of the first points of the sequence is spread like blue noise, without the clumps and
+//! holes of points sampled uniformly. This makes them useful for progressive sampling, where the
+//! number of points is not known in advance, as a cheaper alternative to
+//! [Poisson-disc sampling](super::poisson_disc).
+//!
+//! The [`R2Sequence`] and [`R3Sequence`] are the [additive recurrences] of Martin Roberts. They
+//! are deterministic, but can be shifted by a random offset to get different points every time:
+//! ```
+//! # use bevy_math::{sampling::R2Sequence, Vec2};
+//! # use rand::{rngs::StdRng, SeedableRng};
+//! let rng = &mut StdRng::seed_from_u64(42);
+//! // Spawn points in a 100 x 50 area:
+//! let spawn_points: Vec<Vec2> = R2Sequence::random(rng)
+//!     .map(|point| point * Vec2::new(100.0, 50.0))
+//!     .take(20)
+//!     .collect();
+//! ```
+//!
+//! [additive recurrences]: https://extremelearning.com.au/unreasonable-effectiveness-of-quasirandom-sequences/
+
+use crate::{Vec2, Vec3};
+use rand::Rng;
+
+/// The inverses of the powers of the plastic number, the unique real root of `x³ = x + 1`.
+const R2_ALPHA: [f64; 2] = [0.754_877_666_246_692_8, 0.569_840_290_998_053_3];
+
+/// The inverses of the powers of the unique real root of `x⁴ = x + 1`.
+const R3_ALPHA: [f64; 3] = [
+    0.819_172_513_396_164_4,
+    0.671_043_606_703_789_2,
+    0.549_700_477_901_970_4,
+];
+
+/// An infinite sequence of points evenly covering the unit square, from `(0, 0)` included to
+/// `(1, 1)` excluded. See the [module documentation](self).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct R2Sequence {
+    /// The index of the next point.
+    pub index: u64,
+    /// The offset added to every point, modulo 1.
+    pub offset: Vec2,
+}
+
+impl R2Sequence {
+    /// Creates a sequence starting from its first point, with no offset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a sequence with a random offset, so that it generates different points.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            index: 0,
+            offset: Vec2::new(rng.gen(), rng.gen()),
+        }
+    }
+
+    /// Returns the point at `index` of the sequence.
+    pub fn get(&self, index: u64) -> Vec2 {
+        let [x, y] = R2_ALPHA.map(|alpha| fract(alpha * index as f64));
+        (Vec2::new(x as f32, y as f32) + self.offset).fract()
+    }
+}
+
+impl Iterator for R2Sequence {
+    type Item = Vec2;
+
+    fn next(&mut self) -> Option<Vec2> {
+        let point = self.get(self.index);
+        self.index += 1;
+        Some(point)
+    }
+}
+
+/// An infinite sequence of points evenly covering the unit cube, from `(0, 0, 0)` included to
+/// `(1, 1, 1)` excluded. See the [module documentation](self).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct R3Sequence {
+    /// The index of the next point.
+    pub index: u64,
+    /// The offset added to every point, modulo 1.
+    pub offset: Vec3,
+}
+
+impl R3Sequence {
+    /// Creates a sequence starting from its first point, with no offset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a sequence with a random offset, so that it generates different points.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            index: 0,
+            offset: Vec3::new(rng.gen(), rng.gen(), rng.gen()),
+        }
+    }
+
+    /// Returns the point at `index` of the sequence.
+    pub fn get(&self, index: u64) -> Vec3 {
+        let [x, y, z] = R3_ALPHA.map(|alpha| fract(alpha * index as f64));
+        (Vec3::new(x as f32, y as f32, z as f32) + self.offset).fract()
+    }
+}
+
+impl Iterator for R3Sequence {
+    type Item = Vec3;
+
+    fn next(&mut self) -> Option<Vec3> {
+        let point = self.get(self.index);
+        self.index += 1;
+        Some(point)
+    }
+}
+
+/// The fractional part of `x`, without requiring `std`.
+fn fract(x: f64) -> f64 {
+    x - (x as u64) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn r2_sequence_covers_the_unit_square() {
+        // Every cell of a 4 x 4 grid gets exactly one of the first 16 points, or almost.
+        let mut cells = [0; 16];
+        for point in R2Sequence::new().take(16) {
+            assert!(point.cmpge(Vec2::ZERO).all() && point.cmplt(Vec2::ONE).all());
+            let cell = (point * 4.0).as_uvec2();
+            cells[(cell.x + 4 * cell.y) as usize] += 1;
+        }
+        assert!(cells.iter().all(|count| *count <= 2));
+        assert!(cells.iter().filter(|count| **count == 0).count() <= 2);
+
+        let sequence = R3Sequence {
+            index: 0,
+            offset: Vec3::splat(0.5),
+        };
+        for point in sequence.take(100) {
+            assert!(point.cmpge(Vec3::ZERO).all() && point.cmplt(Vec3::ONE).all());
+        }
+    }
+}
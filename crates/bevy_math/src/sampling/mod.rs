@@ -2,12 +2,24 @@
 //!
 //! To use this, the "rand" feature must be enabled.
 
+pub mod deterministic_rng;
+pub mod low_discrepancy;
 #[cfg(feature = "alloc")]
 pub mod mesh_sampling;
+#[cfg(feature = "alloc")]
+pub mod poisson_disc;
 pub mod shape_sampling;
 pub mod standard;
+#[cfg(feature = "alloc")]
+pub mod stratified;
 
+pub use deterministic_rng::*;
+pub use low_discrepancy::*;
 #[cfg(feature = "alloc")]
 pub use mesh_sampling::*;
+#[cfg(feature = "alloc")]
+pub use poisson_disc::*;
 pub use shape_sampling::*;
 pub use standard::*;
+#[cfg(feature = "alloc")]
+pub use stratified::*;
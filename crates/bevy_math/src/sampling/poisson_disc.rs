@@ -0,0 +1,267 @@
+//! Poisson-disc sampling, generating random points that are never closer to each other than a
+//! minimum distance.
+//!
+//! Unlike points sampled uniformly at random, which form clumps and leave holes, Poisson-disc
+//! samples are evenly spread while still looking natural, which makes them a good fit for
+//! scattering foliage or choosing spawn points. The points are generated with
+//! [Bridson's algorithm], in a bounding box and optionally only where a predicate accepts them:
+//! ```
+//! # use bevy_math::{bounding::Aabb2d, sampling::PoissonDiscSampler, Vec2};
+//! # use rand::{rngs::StdRng, SeedableRng};
+//! let rng = &mut StdRng::seed_from_u64(42);
+//! let sampler = PoissonDiscSampler::new(1.0);
+//! // Points in a 20 x 20 square, at least 1 unit away from each other:
+//! let trees = sampler.sample_2d(rng, Aabb2d::new(Vec2::ZERO, Vec2::splat(10.0)));
+//! // Only keep the points in a circle of radius 10:
+//! let bushes = sampler.sample_2d_in(
+//!     rng,
+//!     Aabb2d::new(Vec2::ZERO, Vec2::splat(10.0)),
+//!     |point| point.length() <= 10.0,
+//! );
+//! ```
+//!
+//! [Bridson's algorithm]: https://www.cs.ubc.ca/~rbridson/docs/bridson-siggraph07-poissondisk.pdf
+
+use crate::{
+    bounding::{Aabb2d, Aabb3d},
+    ops,
+    primitives::Sphere,
+    IVec3, ShapeSample, Vec2, Vec3,
+};
+use alloc::{vec, vec::Vec};
+use core::f32::consts::TAU;
+use rand::Rng;
+
+/// Generates random points in 2D or 3D that are at least [`min_distance`](Self::min_distance)
+/// away from each other, see the [module documentation](self).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PoissonDiscSampler {
+    /// The minimum distance between two points.
+    pub min_distance: f32,
+    /// The number of candidates tried around each point before giving up on finding a new point
+    /// nearby. Higher values give denser results, at the cost of speed. Defaults to 30.
+    pub attempts: u32,
+}
+
+impl PoissonDiscSampler {
+    /// Creates a sampler generating points at least `min_distance` away from each other.
+    pub fn new(min_distance: f32) -> Self {
+        Self {
+            min_distance,
+            attempts: 30,
+        }
+    }
+
+    /// Sets the number of candidates tried around each point, see
+    /// [`attempts`](Self::attempts).
+    pub fn with_attempts(mut self, attempts: u32) -> Self {
+        self.attempts = attempts;
+        self
+    }
+
+    /// Generates points filling the given `bounds`.
+    pub fn sample_2d<R: Rng + ?Sized>(&self, rng: &mut R, bounds: Aabb2d) -> Vec<Vec2> {
+        self.sample_2d_in(rng, bounds, |_| true)
+    }
+
+    /// Generates points in the given `bounds` for which `contains` returns `true`.
+    ///
+    /// The region described by `contains` should be connected, as points are only generated
+    /// around one another.
+    pub fn sample_2d_in<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        bounds: Aabb2d,
+        contains: impl Fn(Vec2) -> bool,
+    ) -> Vec<Vec2> {
+        let annulus = |rng: &mut R, radius: f32| {
+            let (sin, cos) = ops::sin_cos(rng.gen_range(0.0..TAU));
+            Vec3::new(cos, sin, 0.0) * radius
+        };
+        bridson(
+            rng,
+            bounds.min.extend(0.0),
+            bounds.max.extend(0.0),
+            self,
+            annulus,
+            |point| contains(point.truncate()),
+        )
+        .into_iter()
+        .map(Vec3::truncate)
+        .collect()
+    }
+
+    /// Generates points filling the given `bounds`.
+    pub fn sample_3d<R: Rng + ?Sized>(&self, rng: &mut R, bounds: Aabb3d) -> Vec<Vec3> {
+        self.sample_3d_in(rng, bounds, |_| true)
+    }
+
+    /// Generates points in the given `bounds` for which `contains` returns `true`.
+    ///
+    /// The region described by `contains` should be connected, as points are only generated
+    /// around one another.
+    pub fn sample_3d_in<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        bounds: Aabb3d,
+        contains: impl Fn(Vec3) -> bool,
+    ) -> Vec<Vec3> {
+        let shell = |rng: &mut R, radius: f32| Sphere::new(radius).sample_boundary(rng);
+        bridson(
+            rng,
+            bounds.min.into(),
+            bounds.max.into(),
+            self,
+            shell,
+            contains,
+        )
+    }
+}
+
+/// A grid of cells small enough to contain at most one point each, storing the index of the point
+/// in each cell.
+struct Grid {
+    min: Vec3,
+    cell_size: f32,
+    size: IVec3,
+    cells: Vec<u32>,
+}
+
+impl Grid {
+    const EMPTY: u32 = u32::MAX;
+
+    fn cell(&self, point: Vec3) -> IVec3 {
+        ((point - self.min) / self.cell_size)
+            .as_ivec3()
+            .clamp(IVec3::ZERO, self.size - 1)
+    }
+
+    fn index(&self, cell: IVec3) -> usize {
+        (cell.x + self.size.x * (cell.y + self.size.y * cell.z)) as usize
+    }
+}
+
+/// Bridson's algorithm in 3D, which also works in 2D with bounds that are flat along z and an
+/// `offset` function only returning offsets in the xy plane.
+fn bridson<R: Rng + ?Sized>(
+    rng: &mut R,
+    min: Vec3,
+    max: Vec3,
+    sampler: &PoissonDiscSampler,
+    offset: impl Fn(&mut R, f32) -> Vec3,
+    contains: impl Fn(Vec3) -> bool,
+) -> Vec<Vec3> {
+    let radius = sampler.min_distance;
+    let mut points = Vec::new();
+    if radius.is_nan() || radius <= 0.0 || min.cmpgt(max).any() {
+        return points;
+    }
+
+    let flat = min.z == max.z;
+    let cell_size = radius / ops::sqrt(if flat { 2.0 } else { 3.0 });
+    let size = ((max - min) / cell_size).as_ivec3() + 1;
+    let mut grid = Grid {
+        min,
+        cell_size,
+        size,
+        cells: vec![Grid::EMPTY; (size.x * size.y * size.z) as usize],
+    };
+    // Points closer than `radius` are at most two cells away.
+    let reach = IVec3::new(2, 2, if flat { 0 } else { 2 });
+
+    let Some(first) = (0..sampler.attempts.max(1))
+        .map(|_| {
+            Vec3::new(
+                rng.gen_range(min.x..=max.x),
+                rng.gen_range(min.y..=max.y),
+                rng.gen_range(min.z..=max.z),
+            )
+        })
+        .find(|point| contains(*point))
+    else {
+        return points;
+    };
+    let first_cell = grid.index(grid.cell(first));
+    grid.cells[first_cell] = 0;
+    points.push(first);
+    let mut active = vec![0];
+
+    while !active.is_empty() {
+        let active_index = rng.gen_range(0..active.len());
+        let center = points[active[active_index] as usize];
+        let candidate = (0..sampler.attempts).find_map(|_| {
+            let distance = rng.gen_range(radius..2.0 * radius);
+            let candidate = center + offset(rng, distance);
+            if candidate.cmplt(min).any() || candidate.cmpgt(max).any() || !contains(candidate) {
+                return None;
+            }
+            let cell = grid.cell(candidate);
+            let low = (cell - reach).max(IVec3::ZERO);
+            let high = (cell + reach).min(size - 1);
+            for z in low.z..=high.z {
+                for y in low.y..=high.y {
+                    for x in low.x..=high.x {
+                        let neighbor = grid.cells[grid.index(IVec3::new(x, y, z))];
+                        if neighbor != Grid::EMPTY
+                            && points[neighbor as usize].distance_squared(candidate)
+                                < radius * radius
+                        {
+                            return None;
+                        }
+                    }
+                }
+            }
+            Some(candidate)
+        });
+        match candidate {
+            Some(candidate) => {
+                let cell = grid.index(grid.cell(candidate));
+                grid.cells[cell] = points.len() as u32;
+                active.push(points.len() as u32);
+                points.push(candidate);
+            }
+            None => {
+                active.swap_remove(active_index);
+            }
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn poisson_disc_points_are_spread() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let sampler = PoissonDiscSampler::new(1.0);
+
+        let bounds = Aabb2d::new(Vec2::ZERO, Vec2::splat(5.0));
+        let points = sampler.sample_2d_in(&mut rng, bounds, |point| point.length() <= 5.0);
+        // A disc of radius 5 fits about 70 points at distance 1.
+        assert!(points.len() > 40, "{} points", points.len());
+        for (i, a) in points.iter().enumerate() {
+            assert!(a.length() <= 5.0);
+            for b in &points[i + 1..] {
+                assert!(a.distance(*b) >= 1.0);
+            }
+        }
+
+        let bounds = Aabb3d::new(Vec3::ZERO, Vec3::splat(2.0));
+        let points = sampler.sample_3d(&mut rng, bounds);
+        assert!(points.len() > 20, "{} points", points.len());
+        for (i, a) in points.iter().enumerate() {
+            assert!(a.abs().max_element() <= 2.0);
+            for b in &points[i + 1..] {
+                assert!(a.distance(*b) >= 1.0);
+            }
+        }
+
+        assert!(sampler
+            .sample_2d_in(&mut rng, Aabb2d::new(Vec2::ZERO, Vec2::ONE), |_| false)
+            .is_empty());
+    }
+}
@@ -0,0 +1,280 @@
+//! Frame-rate independent smoothing and steering helpers, for cameras and objects following a
+//! target.
+//!
+//! [`SmoothDamp`] moves a value towards a target like a critically damped spring: the value
+//! accelerates and decelerates smoothly, and reaches the target without overshooting it. Unlike
+//! [`StableInterpolate::smooth_nudge`], the motion is continuous when the target starts or stops
+//! moving, at the cost of keeping track of a velocity.
+//!
+//! [`LookAtConstraints`] computes the rotation looking in a direction, constraining the up vector,
+//! the pitch and the roll, as typically needed by cameras.
+//!
+//! ```
+//! # use bevy_math::{smoothing::*, Quat, Vec3};
+//! # let delta_time: f32 = 1.0 / 60.0;
+//! let mut position = Vec3::ZERO;
+//! let mut velocity = Vec3::ZERO;
+//! let target = Vec3::new(4.0, 0.0, 2.0);
+//!
+//! // Calling this every frame moves `position` to `target` in about half a second.
+//! position.smooth_damp(&target, &mut velocity, 0.5, f32::INFINITY, delta_time);
+//!
+//! let mut rotation = Quat::IDENTITY;
+//! let mut angular_velocity = Vec3::ZERO;
+//! let look = LookAtConstraints::default().looking_at(position, Vec3::new(0.0, 10.0, 0.0));
+//! if let Some(look) = look {
+//!     rotation.smooth_damp(&look, &mut angular_velocity, 0.2, f32::INFINITY, delta_time);
+//! }
+//! ```
+//!
+//! [`StableInterpolate::smooth_nudge`]: crate::StableInterpolate::smooth_nudge
+
+use core::f32::consts::FRAC_PI_2;
+
+use crate::{ops, Dir3, Mat3, NormedVectorSpace, Quat, Vec3};
+
+/// A value that can smoothly move towards a target, see the [module documentation](self).
+pub trait SmoothDamp: Sized {
+    /// The type of the rate of change of the value.
+    type Velocity;
+
+    /// Moves this value towards `target` like a critically damped spring over a duration of
+    /// `delta`, updating `velocity` along the way.
+    ///
+    /// `smooth_time` is roughly the time it takes to reach the target, and `max_speed` limits the
+    /// speed at which the value moves, which can be [`f32::INFINITY`] for no limit. `velocity`
+    /// must be kept between calls, and is initially zero.
+    ///
+    /// The motion is exact rather than integrated, which makes it independent of the framerate:
+    /// for a fixed target, two calls with a `delta` of 0.1 give the same result as one call with a
+    /// `delta` of 0.2.
+    fn smooth_damp(
+        &mut self,
+        target: &Self,
+        velocity: &mut Self::Velocity,
+        smooth_time: f32,
+        max_speed: f32,
+        delta: f32,
+    );
+}
+
+/// Moves the `offset` from a target towards zero like a critically damped spring, returning the
+/// new offset.
+fn damp_offset<V: NormedVectorSpace>(
+    initial_offset: V,
+    velocity: &mut V,
+    smooth_time: f32,
+    max_speed: f32,
+    delta: f32,
+) -> V {
+    if smooth_time <= 0.0 {
+        *velocity = V::ZERO;
+        return V::ZERO;
+    }
+    let max_offset = max_speed * smooth_time;
+    let length = initial_offset.norm();
+    let offset = if length > max_offset {
+        initial_offset * (max_offset / length)
+    } else {
+        initial_offset
+    };
+
+    // The exact solution of `x'' = -2ωx' - ω²x` after `delta`, for the initial offset and velocity.
+    let omega = 2.0 / smooth_time;
+    let decay = ops::exp(-omega * delta);
+    let temp = (*velocity + offset * omega) * delta;
+    let new_offset = (offset + temp) * decay;
+
+    // Stop at the target rather than overshooting it, which can happen with a high initial
+    // velocity. The offsets point in opposite directions when their dot product is negative.
+    if (new_offset + initial_offset).norm_squared() < (new_offset - initial_offset).norm_squared() {
+        *velocity = V::ZERO;
+        return V::ZERO;
+    }
+    *velocity = (*velocity - temp * omega) * decay;
+    new_offset
+}
+
+impl<V: NormedVectorSpace> SmoothDamp for V {
+    type Velocity = V;
+
+    fn smooth_damp(
+        &mut self,
+        target: &Self,
+        velocity: &mut V,
+        smooth_time: f32,
+        max_speed: f32,
+        delta: f32,
+    ) {
+        *self = *target + damp_offset(*self - *target, velocity, smooth_time, max_speed, delta);
+    }
+}
+
+impl SmoothDamp for Quat {
+    /// The angular velocity, as a scaled axis in radians per unit of time.
+    type Velocity = Vec3;
+
+    /// Rotates this rotation towards `target` along the shortest path, where `max_speed` limits
+    /// the angular speed in radians per unit of time.
+    fn smooth_damp(
+        &mut self,
+        target: &Self,
+        velocity: &mut Vec3,
+        smooth_time: f32,
+        max_speed: f32,
+        delta: f32,
+    ) {
+        let mut offset = *self * target.inverse();
+        if offset.w < 0.0 {
+            offset = -offset;
+        }
+        let offset = damp_offset(
+            offset.to_scaled_axis(),
+            velocity,
+            smooth_time,
+            max_speed,
+            delta,
+        );
+        *self = (Quat::from_scaled_axis(offset) * *target).normalize();
+    }
+}
+
+/// Constraints on the rotation of something looking towards a direction, such as a camera.
+///
+/// The rotations follow the conventions of Bevy, where the forward direction is -Z and the up
+/// direction is +Y.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct LookAtConstraints {
+    /// The direction the up direction of the rotation is as close as possible to.
+    pub up: Dir3,
+    /// The rotation around the forward direction applied after looking at the target, in radians.
+    ///
+    /// Positive values roll counterclockwise as seen from behind.
+    pub roll: f32,
+    /// The maximum angle between the forward direction and the plane perpendicular to
+    /// [`up`](Self::up), in radians. Defaults to `π/2`, allowing to look straight up or down.
+    pub max_pitch: f32,
+}
+
+impl Default for LookAtConstraints {
+    fn default() -> Self {
+        Self {
+            up: Dir3::Y,
+            roll: 0.0,
+            max_pitch: FRAC_PI_2,
+        }
+    }
+}
+
+impl LookAtConstraints {
+    /// Returns the rotation looking from `eye` towards `target`, or `None` if they are the same.
+    pub fn looking_at(&self, eye: Vec3, target: Vec3) -> Option<Quat> {
+        Dir3::new(target - eye)
+            .ok()
+            .map(|direction| self.looking_to(direction))
+    }
+
+    /// Returns the rotation looking towards `direction`.
+    ///
+    /// When looking straight along the up direction, the result rotates around an arbitrary axis.
+    pub fn looking_to(&self, direction: Dir3) -> Quat {
+        let up = *self.up;
+        let pitch = ops::asin(direction.dot(up).clamp(-1.0, 1.0));
+        let max_pitch = self.max_pitch.clamp(0.0, FRAC_PI_2);
+        let clamped_pitch = pitch.clamp(-max_pitch, max_pitch);
+        let horizontal = (*direction - up * direction.dot(up))
+            .try_normalize()
+            .unwrap_or_else(|| up.any_orthonormal_vector());
+        let forward = if clamped_pitch == pitch {
+            *direction
+        } else {
+            let (sin, cos) = ops::sin_cos(clamped_pitch);
+            horizontal * cos + up * sin
+        };
+
+        let back = -forward;
+        let right = up
+            .cross(back)
+            .try_normalize()
+            .unwrap_or_else(|| horizontal.cross(back).normalize());
+        let up = back.cross(right);
+        Quat::from_mat3(&Mat3::from_cols(right, up, back)) * Quat::from_rotation_z(self.roll)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f32::consts::FRAC_PI_4;
+
+    #[test]
+    fn smooth_damp_is_framerate_independent() {
+        let target = Vec3::new(3.0, -1.0, 2.0);
+        let step = |steps: usize| {
+            let (mut position, mut velocity) = (Vec3::ZERO, Vec3::ZERO);
+            for _ in 0..steps {
+                position.smooth_damp(
+                    &target,
+                    &mut velocity,
+                    0.5,
+                    f32::INFINITY,
+                    1.0 / steps as f32,
+                );
+            }
+            (position, velocity)
+        };
+        let (slow, slow_velocity) = step(6);
+        let (fast, fast_velocity) = step(144);
+        assert_relative_eq!(slow, fast, epsilon = 1e-4);
+        assert_relative_eq!(slow_velocity, fast_velocity, epsilon = 1e-4);
+        assert!(slow.distance(target) < 0.1 * target.length());
+
+        // The value never overshoots a fixed target.
+        let (mut value, mut velocity) = (0.0_f32, 0.0);
+        for _ in 0..100 {
+            value.smooth_damp(&1.0, &mut velocity, 0.1, 5.0, 0.02);
+            assert!(value <= 1.0);
+        }
+        assert_relative_eq!(value, 1.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn smooth_damp_quat() {
+        let target = Quat::from_rotation_y(2.0);
+        let mut rotation = Quat::from_rotation_x(-0.5);
+        let mut velocity = Vec3::ZERO;
+        for _ in 0..120 {
+            rotation.smooth_damp(&target, &mut velocity, 0.25, f32::INFINITY, 1.0 / 60.0);
+        }
+        assert!(rotation.angle_between(target) < 1e-3);
+        assert!(velocity.length() < 1e-2);
+    }
+
+    #[test]
+    fn look_at_constraints() {
+        let constraints = LookAtConstraints::default();
+        let rotation = constraints
+            .looking_at(Vec3::ZERO, Vec3::new(1.0, 0.0, 0.0))
+            .unwrap();
+        assert_relative_eq!(rotation * Vec3::NEG_Z, Vec3::X, epsilon = 1e-6);
+        assert_relative_eq!(rotation * Vec3::Y, Vec3::Y, epsilon = 1e-6);
+        assert!(constraints.looking_at(Vec3::ONE, Vec3::ONE).is_none());
+
+        // Looking straight up still gives a valid rotation.
+        let rotation = constraints.looking_to(Dir3::Y);
+        assert_relative_eq!(rotation * Vec3::NEG_Z, Vec3::Y, epsilon = 1e-6);
+
+        let constraints = LookAtConstraints {
+            max_pitch: FRAC_PI_4,
+            roll: FRAC_PI_2,
+            ..Default::default()
+        };
+        let rotation = constraints.looking_to(Dir3::Y);
+        let forward = rotation * Vec3::NEG_Z;
+        assert_relative_eq!(forward.dot(Vec3::Y), ops::sin(FRAC_PI_4), epsilon = 1e-6);
+        // Rolling by a quarter turn makes the local up direction horizontal.
+        assert_relative_eq!((rotation * Vec3::Y).dot(Vec3::Y), 0.0, epsilon = 1e-6);
+    }
+}
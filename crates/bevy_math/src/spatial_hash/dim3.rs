@@ -0,0 +1,374 @@
+use core::hash::Hash;
+
+use crate::{
+    bounding::{Aabb3d, BoundingSphere, IntersectsVolume, RayCast3d},
+    ops, IVec3, Ray3d, Vec3, Vec3A,
+};
+use alloc::vec::Vec;
+use hashbrown::{HashMap, HashSet};
+
+/// A uniform grid storing objects in the cubic cells their bounding box overlaps, see the
+/// [module documentation](super).
+///
+/// The objects are identified by keys of type `T`, typically entities.
+#[derive(Clone, Debug)]
+pub struct SpatialHash3d<T> {
+    cell_size: f32,
+    cells: HashMap<IVec3, Vec<T>>,
+    items: HashMap<T, Aabb3d>,
+}
+
+impl<T: Copy + Eq + Hash> SpatialHash3d<T> {
+    /// Creates an empty grid with cubic cells of the given size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is not strictly positive.
+    pub fn new(cell_size: f32) -> Self {
+        assert!(cell_size > 0.0, "the cell size must be strictly positive");
+        Self {
+            cell_size,
+            cells: HashMap::default(),
+            items: HashMap::default(),
+        }
+    }
+
+    /// Returns the size of the cells.
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Returns the number of objects in the grid.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no objects in the grid.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Removes all the objects from the grid.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.items.clear();
+    }
+
+    /// Returns the coordinates of the cell containing `point`.
+    pub fn cell(&self, point: impl Into<Vec3A>) -> IVec3 {
+        (point.into() / self.cell_size).floor().as_ivec3()
+    }
+
+    /// Returns the coordinates of the first and last cells overlapped by `aabb`.
+    fn cell_range(&self, aabb: &Aabb3d) -> (IVec3, IVec3) {
+        (self.cell(aabb.min), self.cell(aabb.max))
+    }
+
+    /// Returns the bounding box of `item`, if it is in the grid.
+    pub fn get(&self, item: T) -> Option<Aabb3d> {
+        self.items.get(&item).copied()
+    }
+
+    /// Returns `true` if `item` is in the grid.
+    pub fn contains(&self, item: T) -> bool {
+        self.items.contains_key(&item)
+    }
+
+    /// Returns an iterator over the objects in the grid and their bounding box, in no particular
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = (T, Aabb3d)> + '_ {
+        self.items.iter().map(|(item, aabb)| (*item, *aabb))
+    }
+
+    /// Inserts `item` with the given bounding box, or moves it if it is already in the grid.
+    ///
+    /// Moving an object within the same cells is cheap, so that this can be called every frame for
+    /// all the objects.
+    pub fn insert(&mut self, item: T, aabb: Aabb3d) {
+        let range = self.cell_range(&aabb);
+        if let Some(previous) = self.items.insert(item, aabb) {
+            if self.cell_range(&previous) == range {
+                return;
+            }
+            self.remove_from_cells(item, &previous);
+        }
+        for_each_cell(range, |cell| self.cells.entry(cell).or_default().push(item));
+    }
+
+    /// Inserts `item` at the given point, or moves it if it is already in the grid.
+    pub fn insert_point(&mut self, item: T, point: impl Into<Vec3A>) {
+        let point = point.into();
+        self.insert(
+            item,
+            Aabb3d {
+                min: point,
+                max: point,
+            },
+        );
+    }
+
+    /// Removes `item` from the grid, returning its bounding box if it was in the grid.
+    pub fn remove(&mut self, item: T) -> Option<Aabb3d> {
+        let aabb = self.items.remove(&item)?;
+        self.remove_from_cells(item, &aabb);
+        Some(aabb)
+    }
+
+    fn remove_from_cells(&mut self, item: T, aabb: &Aabb3d) {
+        for_each_cell(self.cell_range(aabb), |cell| {
+            if let Some(items) = self.cells.get_mut(&cell) {
+                if let Some(index) = items.iter().position(|other| *other == item) {
+                    items.swap_remove(index);
+                }
+                if items.is_empty() {
+                    self.cells.remove(&cell);
+                }
+            }
+        });
+    }
+
+    /// Returns the objects stored in `cell`.
+    pub fn items_in_cell(&self, cell: IVec3) -> &[T] {
+        self.cells.get(&cell).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Returns an iterator over the objects whose bounding box intersects `aabb`.
+    ///
+    /// Every object is returned once, even if it overlaps several cells.
+    pub fn query_aabb(&self, aabb: Aabb3d) -> impl Iterator<Item = T> + '_ {
+        let range = self.cell_range(&aabb);
+        self.candidates(range)
+            .filter(move |(_, _, other)| other.intersects(&aabb))
+            .map(|(_, item, _)| item)
+    }
+
+    /// Returns an iterator over the objects whose bounding box intersects the sphere of the given
+    /// `center` and `radius`.
+    ///
+    /// Every object is returned once, even if it overlaps several cells.
+    pub fn query_sphere(
+        &self,
+        center: impl Into<Vec3A>,
+        radius: f32,
+    ) -> impl Iterator<Item = T> + '_ {
+        let sphere = BoundingSphere::new(center, radius);
+        let range = self.cell_range(&sphere.aabb_3d());
+        self.candidates(range)
+            .filter(move |(_, _, other)| other.intersects(&sphere))
+            .map(|(_, item, _)| item)
+    }
+
+    /// Returns the objects in the cells of `range` with their bounding box, only returning each
+    /// object in the first cell of `range` it overlaps.
+    fn candidates(&self, range: (IVec3, IVec3)) -> impl Iterator<Item = (IVec3, T, Aabb3d)> + '_ {
+        let (min, max) = range;
+        (min.z..=max.z)
+            .flat_map(move |z| {
+                (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| IVec3::new(x, y, z)))
+            })
+            .filter_map(|cell| Some((cell, self.cells.get(&cell)?)))
+            .flat_map(move |(cell, items)| {
+                items.iter().filter_map(move |item| {
+                    let aabb = self.items[item];
+                    let first_cell = self.cell(aabb.min).max(min);
+                    (first_cell == cell).then_some((cell, *item, aabb))
+                })
+            })
+    }
+
+    /// Returns an iterator over the cells crossed by `ray` up to `max_distance`, in order.
+    ///
+    /// The iterator is infinite if `max_distance` is.
+    pub fn cells_on_ray(&self, ray: Ray3d, max_distance: f32) -> GridRayIter3d {
+        GridRayIter3d::new(ray, self.cell_size, max_distance)
+    }
+
+    /// Returns the first object whose bounding box is hit by `ray` up to `max_distance`, along
+    /// with the distance of the hit.
+    ///
+    /// Only the cells up to the first hit are visited, but all the cells up to `max_distance` are
+    /// visited if nothing is hit, so it should be finite.
+    pub fn ray_cast(&self, ray: Ray3d, max_distance: f32) -> Option<(T, f32)> {
+        let cast = RayCast3d::from_ray(ray, max_distance);
+        let mut closest: Option<(T, f32)> = None;
+        for (cell, entry) in self.cells_on_ray(ray, max_distance) {
+            if closest.is_some_and(|(_, distance)| distance <= entry) {
+                break;
+            }
+            for item in self.items_in_cell(cell) {
+                if let Some(distance) = cast.aabb_intersection_at(&self.items[item]) {
+                    if closest.is_none_or(|(_, closest)| distance < closest) {
+                        closest = Some((*item, distance));
+                    }
+                }
+            }
+        }
+        closest
+    }
+
+    /// Returns all the objects whose bounding box is hit by `ray` up to `max_distance`, along with
+    /// the distance of the hit, sorted by distance.
+    ///
+    /// All the cells up to `max_distance` are visited, so it should be finite.
+    pub fn ray_cast_all(&self, ray: Ray3d, max_distance: f32) -> Vec<(T, f32)> {
+        let cast = RayCast3d::from_ray(ray, max_distance);
+        let mut visited = HashSet::<T>::default();
+        let mut hits = Vec::new();
+        for (cell, _) in self.cells_on_ray(ray, max_distance) {
+            for item in self.items_in_cell(cell) {
+                if visited.insert(*item) {
+                    if let Some(distance) = cast.aabb_intersection_at(&self.items[item]) {
+                        hits.push((*item, distance));
+                    }
+                }
+            }
+        }
+        hits.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        hits
+    }
+}
+
+/// Calls `f` on every cell between the first and last cells of `range`, included.
+fn for_each_cell(range: (IVec3, IVec3), mut f: impl FnMut(IVec3)) {
+    let (min, max) = range;
+    for z in min.z..=max.z {
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                f(IVec3::new(x, y, z));
+            }
+        }
+    }
+}
+
+/// An iterator over the cells of a uniform grid crossed by a ray, in order, along with the
+/// distance at which the ray enters them. See [`SpatialHash3d::cells_on_ray`].
+#[derive(Clone, Debug)]
+pub struct GridRayIter3d {
+    cell: IVec3,
+    step: IVec3,
+    next_crossing: Vec3,
+    crossing_interval: Vec3,
+    distance: f32,
+    max_distance: f32,
+}
+
+impl GridRayIter3d {
+    /// Creates an iterator over the cells of the given size crossed by `ray` up to
+    /// `max_distance`.
+    pub fn new(ray: Ray3d, cell_size: f32, max_distance: f32) -> Self {
+        let origin = ray.origin / cell_size;
+        let cell = origin.floor().as_ivec3();
+        let direction = *ray.direction;
+        let step = direction.signum().as_ivec3();
+        // The distances along the ray to the next boundary of a cell along each axis, and between
+        // two boundaries along each axis.
+        let mut next_crossing = Vec3::INFINITY;
+        let mut crossing_interval = Vec3::INFINITY;
+        for axis in 0..3 {
+            if direction[axis] != 0.0 {
+                let boundary = if direction[axis] > 0.0 {
+                    cell[axis] as f32 + 1.0
+                } else {
+                    cell[axis] as f32
+                };
+                next_crossing[axis] = (boundary - origin[axis]) * cell_size / direction[axis];
+                crossing_interval[axis] = cell_size / ops::abs(direction[axis]);
+            }
+        }
+        Self {
+            cell,
+            step,
+            next_crossing,
+            crossing_interval,
+            distance: 0.0,
+            max_distance,
+        }
+    }
+}
+
+impl Iterator for GridRayIter3d {
+    /// The coordinates of the cell, and the distance at which the ray enters it.
+    type Item = (IVec3, f32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.distance > self.max_distance {
+            return None;
+        }
+        let current = (self.cell, self.distance);
+        let axis = if self.next_crossing.x < self.next_crossing.y {
+            if self.next_crossing.x < self.next_crossing.z {
+                0
+            } else {
+                2
+            }
+        } else if self.next_crossing.y < self.next_crossing.z {
+            1
+        } else {
+            2
+        };
+        self.distance = self.next_crossing[axis];
+        self.next_crossing[axis] += self.crossing_interval[axis];
+        self.cell[axis] += self.step[axis];
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Dir3;
+    use alloc::vec;
+
+    #[test]
+    fn spatial_hash_3d_queries() {
+        let mut grid = SpatialHash3d::new(1.0);
+        grid.insert(0, Aabb3d::new(Vec3::ZERO, Vec3::splat(1.5)));
+        grid.insert_point(1, Vec3::new(3.5, 0.5, 0.5));
+        grid.insert_point(2, Vec3::new(-3.5, 0.5, 0.5));
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.items_in_cell(IVec3::ZERO), [0]);
+
+        // The large box spans several cells but is returned once.
+        let mut found: Vec<_> = grid
+            .query_aabb(Aabb3d::new(Vec3::new(1.0, 0.0, 0.0), Vec3::splat(2.75)))
+            .collect();
+        found.sort();
+        assert_eq!(found, [0, 1]);
+        let found: Vec<_> = grid.query_sphere(Vec3::new(-3.0, 0.5, 0.5), 1.0).collect();
+        assert_eq!(found, [2]);
+
+        // Moving and removing objects updates the cells.
+        grid.insert_point(1, Vec3::new(-3.0, 0.5, 0.5));
+        let mut found: Vec<_> = grid.query_sphere(Vec3::new(-3.0, 0.5, 0.5), 1.0).collect();
+        found.sort();
+        assert_eq!(found, [1, 2]);
+        assert_eq!(grid.items_in_cell(IVec3::new(3, 0, 0)), []);
+        assert!(grid.remove(0).is_some());
+        assert!(grid
+            .query_aabb(Aabb3d::new(Vec3::ZERO, Vec3::ONE))
+            .next()
+            .is_none());
+
+        let ray = Ray3d::new(Vec3::new(10.0, 0.5, 0.5), Dir3::NEG_X);
+        assert_eq!(grid.ray_cast(ray, 100.0), Some((1, 13.0)));
+        assert_eq!(grid.ray_cast(ray, 10.0), None);
+        assert_eq!(grid.ray_cast_all(ray, 100.0), vec![(1, 13.0), (2, 13.5)]);
+    }
+
+    #[test]
+    fn cells_on_ray() {
+        let ray = Ray3d::new(
+            Vec3::new(0.5, 0.5, 0.5),
+            Dir3::new(Vec3::new(1.0, 1.0, 0.0)).unwrap(),
+        );
+        let cells: Vec<_> = GridRayIter3d::new(ray, 1.0, 2.0)
+            .map(|(cell, _)| cell)
+            .collect();
+        assert_eq!(cells.first(), Some(&IVec3::ZERO));
+        assert_eq!(cells.last(), Some(&IVec3::new(1, 1, 0)));
+        // Every step moves to a neighboring cell.
+        for pair in cells.windows(2) {
+            assert_eq!((pair[1] - pair[0]).abs().element_sum(), 1);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Uniform grids storing shapes in the cells they overlap, for fast neighbor queries.
+//!
+//! Finding the objects close to each other, for gameplay logic or as the broad phase of collision
+//! detection, requires comparing every pair of objects when done naively. A [`SpatialHash2d`] or
+//! [`SpatialHash3d`] divides space into square or cubic cells and stores each object in the cells
+//! its bounding box overlaps, so that queries only look at the objects in the cells they overlap.
+//!
+//! Only the cells containing objects are stored, in a hash map, so that the grid is unbounded and
+//! its memory only depends on the number of objects. The size of the cells should be about the
+//! size of the typical object or query: smaller cells store large objects in many cells, while
+//! larger cells return more objects to filter out.
+//!
+//! ```
+//! # use bevy_math::{bounding::Aabb3d, spatial_hash::SpatialHash3d, Dir3, Ray3d, Vec3};
+//! // The objects are identified by keys, typically entities.
+//! let mut grid = SpatialHash3d::new(4.0);
+//! grid.insert(1, Aabb3d::new(Vec3::ZERO, Vec3::splat(0.5)));
+//! grid.insert_point(2, Vec3::new(3.0, 0.0, 0.0));
+//! grid.insert_point(3, Vec3::new(50.0, 0.0, 0.0));
+//!
+//! let mut neighbors: Vec<u32> = grid.query_sphere(Vec3::new(2.0, 0.0, 0.0), 2.0).collect();
+//! neighbors.sort();
+//! assert_eq!(neighbors, [1, 2]);
+//!
+//! let ray = Ray3d::new(Vec3::new(-10.0, 0.0, 0.0), Dir3::X);
+//! assert_eq!(grid.ray_cast(ray, 100.0), Some((1, 9.5)));
+//! ```
+
+mod dim2;
+mod dim3;
+
+pub use dim2::*;
+pub use dim3::*;
@@ -5,6 +5,7 @@ use bevy_math::{
 
 use super::{MeshBuilder, Meshable};
 use crate::{Indices, Mesh, PrimitiveTopology, VertexAttributeValues};
+use bevy_asset::RenderAssetUsages;
 
 /// A type representing a segment of the perimeter of an extrudable mesh.
 pub enum PerimeterSegment {
@@ -229,183 +230,20 @@ where
 
             debug_assert!(self.segments > 0);
 
-            let layers = self.segments + 1;
             let layer_depth_delta = self.half_depth * 2.0 / self.segments as f32;
-
-            let perimeter = self.base_builder.perimeter();
-            let (vert_count, index_count) =
-                perimeter
-                    .iter()
-                    .fold((0, 0), |(verts, indices), perimeter| {
-                        (
-                            verts + layers * perimeter.vertices_per_layer() as usize,
-                            indices + self.segments * perimeter.indices_per_segment(),
-                        )
-                    });
-            let mut positions = Vec::with_capacity(vert_count);
-            let mut normals = Vec::with_capacity(vert_count);
-            let mut indices = Vec::with_capacity(index_count);
-            let mut uvs = Vec::with_capacity(vert_count);
-
-            // Compute the amount of horizontal space allocated to each segment of the perimeter.
-            let uv_segment_delta = 1. / perimeter.len() as f32;
-            for (i, segment) in perimeter.into_iter().enumerate() {
-                // The start of the x range of the area of the current perimeter-segment.
-                let uv_start = i as f32 * uv_segment_delta;
-
-                match segment {
-                    PerimeterSegment::Flat {
-                        indices: segment_indices,
-                    } => {
-                        let uv_delta = uv_segment_delta / (segment_indices.len() - 1) as f32;
-                        for i in 0..(segment_indices.len() - 1) {
-                            let uv_x = uv_start + uv_delta * i as f32;
-                            // Get the positions for the current and the next index.
-                            let a = cap_verts[segment_indices[i] as usize];
-                            let b = cap_verts[segment_indices[i + 1] as usize];
-
-                            // Get the index of the next vertex added to the mantel.
-                            let index = positions.len() as u32;
-
-                            // Push the positions of the two indices and their equivalent points on each layer.
-                            for i in 0..layers {
-                                let i = i as f32;
-                                let z = a[2] - layer_depth_delta * i;
-                                positions.push([a[0], a[1], z]);
-                                positions.push([b[0], b[1], z]);
-
-                                // UVs for the mantel are between (0, 0.5) and (1, 1).
-                                let uv_y = 0.5 + 0.5 * i / self.segments as f32;
-                                uvs.push([uv_x, uv_y]);
-                                uvs.push([uv_x + uv_delta, uv_y]);
-                            }
-
-                            // The normal is calculated to be the normal of the line segment connecting a and b.
-                            let n = Vec3::from_array([b[1] - a[1], a[0] - b[0], 0.])
-                                .normalize_or_zero()
-                                .to_array();
-                            normals.extend_from_slice(&vec![n; 2 * layers]);
-
-                            // Add the indices for the vertices created above to the mesh.
-                            for i in 0..self.segments as u32 {
-                                let base_index = index + 2 * i;
-                                indices.extend_from_slice(&[
-                                    base_index,
-                                    base_index + 2,
-                                    base_index + 1,
-                                    base_index + 1,
-                                    base_index + 2,
-                                    base_index + 3,
-                                ]);
-                            }
-                        }
-                    }
-                    PerimeterSegment::Smooth {
-                        first_normal,
-                        last_normal,
-                        indices: segment_indices,
-                    } => {
-                        let uv_delta = uv_segment_delta / (segment_indices.len() - 1) as f32;
-
-                        // Since the indices for this segment will be added after its vertices have been added,
-                        // we need to store the index of the first vertex that is part of this segment.
-                        let base_index = positions.len() as u32;
-
-                        // If there is a first vertex, we need to add it and its counterparts on each layer.
-                        // The normal is provided by `segment.first_normal`.
-                        if let Some(i) = segment_indices.first() {
-                            let p = cap_verts[*i as usize];
-                            for i in 0..layers {
-                                let i = i as f32;
-                                let z = p[2] - layer_depth_delta * i;
-                                positions.push([p[0], p[1], z]);
-
-                                let uv_y = 0.5 + 0.5 * i / self.segments as f32;
-                                uvs.push([uv_start, uv_y]);
-                            }
-                            normals.extend_from_slice(&vec![
-                                first_normal.extend(0.).to_array();
-                                layers
-                            ]);
-                        }
-
-                        // For all points inbetween the first and last vertices, we can automatically compute the normals.
-                        for i in 1..(segment_indices.len() - 1) {
-                            let uv_x = uv_start + uv_delta * i as f32;
-
-                            // Get the positions for the last, current and the next index.
-                            let a = cap_verts[segment_indices[i - 1] as usize];
-                            let b = cap_verts[segment_indices[i] as usize];
-                            let c = cap_verts[segment_indices[i + 1] as usize];
-
-                            // Add the current vertex and its counterparts on each layer.
-                            for i in 0..layers {
-                                let i = i as f32;
-                                let z = b[2] - layer_depth_delta * i;
-                                positions.push([b[0], b[1], z]);
-
-                                let uv_y = 0.5 + 0.5 * i / self.segments as f32;
-                                uvs.push([uv_x, uv_y]);
-                            }
-
-                            // The normal for the current vertices can be calculated based on the two neighboring vertices.
-                            // The normal is interpolated between the normals of the two line segments connecting the current vertex with its neighbors.
-                            // Closer vertices have a stronger effect on the normal than more distant ones.
-                            let n = {
-                                let ab = Vec2::from_slice(&b) - Vec2::from_slice(&a);
-                                let bc = Vec2::from_slice(&c) - Vec2::from_slice(&b);
-                                let n = ab.normalize_or_zero() + bc.normalize_or_zero();
-                                Vec2::new(n.y, -n.x)
-                                    .normalize_or_zero()
-                                    .extend(0.)
-                                    .to_array()
-                            };
-                            normals.extend_from_slice(&vec![n; layers]);
-                        }
-
-                        // If there is a last vertex, we need to add it and its counterparts on each layer.
-                        // The normal is provided by `segment.last_normal`.
-                        if let Some(i) = segment_indices.last() {
-                            let p = cap_verts[*i as usize];
-                            for i in 0..layers {
-                                let i = i as f32;
-                                let z = p[2] - layer_depth_delta * i;
-                                positions.push([p[0], p[1], z]);
-
-                                let uv_y = 0.5 + 0.5 * i / self.segments as f32;
-                                uvs.push([uv_start + uv_segment_delta, uv_y]);
-                            }
-                            normals.extend_from_slice(&vec![
-                                last_normal.extend(0.).to_array();
-                                layers
-                            ]);
-                        }
-
-                        let columns = segment_indices.len() as u32;
-                        let segments = self.segments as u32;
-                        let layers = segments + 1;
-                        for s in 0..segments {
-                            for column in 0..(columns - 1) {
-                                let index = base_index + s + column * layers;
-                                indices.extend_from_slice(&[
-                                    index,
-                                    index + 1,
-                                    index + layers,
-                                    index + layers,
-                                    index + 1,
-                                    index + layers + 1,
-                                ]);
-                            }
-                        }
-                    }
-                }
-            }
-
-            Mesh::new(PrimitiveTopology::TriangleList, front_face.asset_usage)
-                .with_inserted_indices(Indices::U32(indices))
-                .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
-                .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
-                .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+            build_mantel(
+                cap_verts,
+                self.base_builder.perimeter(),
+                self.segments,
+                |layer, position, normal| {
+                    // The layers go from the front face to the back face.
+                    let z = self.half_depth - layer_depth_delta * layer as f32;
+                    (position.extend(z), normal.extend(0.))
+                },
+                // UVs for the mantel are between (0, 0.5) and (1, 1).
+                |layer| 0.5 + 0.5 * layer as f32 / self.segments as f32,
+                front_face.asset_usage,
+            )
         };
 
         front_face.merge(&back_face);
@@ -423,3 +261,170 @@ where
         value.mesh().build()
     }
 }
+
+/// Builds the mantel of a mesh swept from a 2D shape with the given `perimeter`, whose vertices are
+/// the `cap_verts` of its base mesh, over `segments` segments.
+///
+/// `transform` maps the position and normal of a vertex of the perimeter to their 3D counterparts
+/// on a given layer, from `0` to `segments`, and `layer_uv_y` gives the `y` texture coordinate of
+/// a layer. The triangles face outwards when moving from one layer to the next goes along the
+/// negative `z` axis of the shape, as for the front-to-back layers of an [`Extrusion`].
+pub(super) fn build_mantel(
+    cap_verts: &[[f32; 3]],
+    perimeter: Vec<PerimeterSegment>,
+    segments: usize,
+    transform: impl Fn(usize, Vec2, Vec2) -> (Vec3, Vec3),
+    layer_uv_y: impl Fn(usize) -> f32,
+    asset_usage: RenderAssetUsages,
+) -> Mesh {
+    let layers = segments + 1;
+    let (vert_count, index_count) = perimeter
+        .iter()
+        .fold((0, 0), |(verts, indices), perimeter| {
+            (
+                verts + layers * perimeter.vertices_per_layer() as usize,
+                indices + segments * perimeter.indices_per_segment(),
+            )
+        });
+    let mut positions = Vec::with_capacity(vert_count);
+    let mut normals = Vec::with_capacity(vert_count);
+    let mut indices = Vec::with_capacity(index_count);
+    let mut uvs = Vec::with_capacity(vert_count);
+
+    // Pushes the position and normal of a vertex of the perimeter on the given layer.
+    let mut push = |layer: usize, position: [f32; 3], normal: Vec2| {
+        let (position, normal) = transform(layer, Vec2::new(position[0], position[1]), normal);
+        positions.push(position.to_array());
+        normals.push(normal.to_array());
+    };
+
+    // Compute the amount of horizontal space allocated to each segment of the perimeter.
+    let uv_segment_delta = 1. / perimeter.len() as f32;
+    for (i, segment) in perimeter.into_iter().enumerate() {
+        // The start of the x range of the area of the current perimeter-segment.
+        let uv_start = i as f32 * uv_segment_delta;
+
+        match segment {
+            PerimeterSegment::Flat {
+                indices: segment_indices,
+            } => {
+                let uv_delta = uv_segment_delta / (segment_indices.len() - 1) as f32;
+                for i in 0..(segment_indices.len() - 1) {
+                    let uv_x = uv_start + uv_delta * i as f32;
+                    // Get the positions for the current and the next index.
+                    let a = cap_verts[segment_indices[i] as usize];
+                    let b = cap_verts[segment_indices[i + 1] as usize];
+
+                    // Get the index of the next vertex added to the mantel.
+                    let index = uvs.len() as u32;
+
+                    // The normal is calculated to be the normal of the line segment connecting a and b.
+                    let n = Vec2::new(b[1] - a[1], a[0] - b[0]).normalize_or_zero();
+
+                    // Push the positions of the two indices and their equivalent points on each layer.
+                    for layer in 0..layers {
+                        push(layer, a, n);
+                        push(layer, b, n);
+
+                        let uv_y = layer_uv_y(layer);
+                        uvs.push([uv_x, uv_y]);
+                        uvs.push([uv_x + uv_delta, uv_y]);
+                    }
+
+                    // Add the indices for the vertices created above to the mesh.
+                    for i in 0..segments as u32 {
+                        let base_index = index + 2 * i;
+                        indices.extend_from_slice(&[
+                            base_index,
+                            base_index + 2,
+                            base_index + 1,
+                            base_index + 1,
+                            base_index + 2,
+                            base_index + 3,
+                        ]);
+                    }
+                }
+            }
+            PerimeterSegment::Smooth {
+                first_normal,
+                last_normal,
+                indices: segment_indices,
+            } => {
+                let uv_delta = uv_segment_delta / (segment_indices.len() - 1) as f32;
+
+                // Since the indices for this segment will be added after its vertices have been added,
+                // we need to store the index of the first vertex that is part of this segment.
+                let base_index = uvs.len() as u32;
+
+                // If there is a first vertex, we need to add it and its counterparts on each layer.
+                // The normal is provided by `segment.first_normal`.
+                if let Some(i) = segment_indices.first() {
+                    let p = cap_verts[*i as usize];
+                    for layer in 0..layers {
+                        push(layer, p, first_normal);
+                        uvs.push([uv_start, layer_uv_y(layer)]);
+                    }
+                }
+
+                // For all points inbetween the first and last vertices, we can automatically compute the normals.
+                for i in 1..(segment_indices.len() - 1) {
+                    let uv_x = uv_start + uv_delta * i as f32;
+
+                    // Get the positions for the last, current and the next index.
+                    let a = cap_verts[segment_indices[i - 1] as usize];
+                    let b = cap_verts[segment_indices[i] as usize];
+                    let c = cap_verts[segment_indices[i + 1] as usize];
+
+                    // The normal for the current vertices can be calculated based on the two neighboring vertices.
+                    // The normal is interpolated between the normals of the two line segments connecting the current vertex with its neighbors.
+                    // Closer vertices have a stronger effect on the normal than more distant ones.
+                    let n = {
+                        let ab = Vec2::from_slice(&b) - Vec2::from_slice(&a);
+                        let bc = Vec2::from_slice(&c) - Vec2::from_slice(&b);
+                        let n = ab.normalize_or_zero() + bc.normalize_or_zero();
+                        Vec2::new(n.y, -n.x).normalize_or_zero()
+                    };
+
+                    // Add the current vertex and its counterparts on each layer.
+                    for layer in 0..layers {
+                        push(layer, b, n);
+                        uvs.push([uv_x, layer_uv_y(layer)]);
+                    }
+                }
+
+                // If there is a last vertex, we need to add it and its counterparts on each layer.
+                // The normal is provided by `segment.last_normal`.
+                if let Some(i) = segment_indices.last() {
+                    let p = cap_verts[*i as usize];
+                    for layer in 0..layers {
+                        push(layer, p, last_normal);
+                        uvs.push([uv_start + uv_segment_delta, layer_uv_y(layer)]);
+                    }
+                }
+
+                let columns = segment_indices.len() as u32;
+                let segments = segments as u32;
+                let layers = segments + 1;
+                for s in 0..segments {
+                    for column in 0..(columns - 1) {
+                        let index = base_index + s + column * layers;
+                        indices.extend_from_slice(&[
+                            index,
+                            index + 1,
+                            index + layers,
+                            index + layers,
+                            index + 1,
+                            index + layers + 1,
+                        ]);
+                    }
+                }
+            }
+        }
+    }
+
+    Mesh::new(PrimitiveTopology::TriangleList, asset_usage)
+        .with_inserted_indices(Indices::U32(indices))
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+}
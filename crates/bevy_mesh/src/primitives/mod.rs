@@ -18,6 +18,9 @@
 //! let circle = meshes.add(Circle { radius: 25.0 }.mesh().resolution(64));
 //! # }
 //! ```
+//!
+//! 2D shapes implementing [`Extrudable`] can also be swept along a path with a [`SweepMeshBuilder`],
+//! or revolved around an axis with a [`LatheMeshBuilder`].
 
 mod dim2;
 pub use dim2::*;
@@ -28,6 +31,9 @@ pub use dim3::*;
 mod extrusion;
 pub use extrusion::*;
 
+mod sweep;
+pub use sweep::*;
+
 use super::Mesh;
 
 /// A trait for shapes that can be turned into a [`Mesh`].
@@ -0,0 +1,518 @@
+use core::f32::consts::TAU;
+
+use bevy_math::{ops, Quat, Vec2, Vec3};
+
+use super::{build_mantel, Extrudable, MeshBuilder, PerimeterSegment};
+use crate::{Indices, Mesh, PrimitiveTopology, VertexAttributeValues};
+use bevy_asset::RenderAssetUsages;
+
+/// The placement of a 2D shape along a sweep, mapping the `x` and `y` axes of the shape to `right`
+/// and `up`. Its `z` axis is `right.cross(up)`, and the sweep goes along the negative `z` axis.
+#[derive(Clone, Copy, Debug)]
+struct Frame {
+    origin: Vec3,
+    right: Vec3,
+    up: Vec3,
+}
+
+impl Frame {
+    fn back(&self) -> Vec3 {
+        self.right.cross(self.up)
+    }
+
+    fn position(&self, position: Vec2) -> Vec3 {
+        self.origin + self.right * position.x + self.up * position.y
+    }
+
+    fn normal(&self, normal: Vec3) -> Vec3 {
+        self.right * normal.x + self.up * normal.y + self.back() * normal.z
+    }
+}
+
+/// Builds the mesh of a 2D shape swept through the given `frames`, with `layer_uv_y` giving the `y`
+/// texture coordinate of each frame and caps at the first and last frames if `caps` is `true`.
+fn build_swept_mesh<B: Extrudable>(
+    base_builder: &B,
+    offset: Vec2,
+    frames: &[Frame],
+    layer_uv_y: impl Fn(usize) -> f32,
+    caps: bool,
+) -> Mesh {
+    let mut base = base_builder.build();
+    let Some(VertexAttributeValues::Float32x3(cap_verts)) =
+        base.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+    else {
+        panic!("The base mesh did not have vertex positions");
+    };
+    for position in cap_verts.iter_mut() {
+        position[0] += offset.x;
+        position[1] += offset.y;
+    }
+    let cap_verts = cap_verts.clone();
+
+    let mut mesh = build_mantel(
+        &cap_verts,
+        base_builder.perimeter(),
+        frames.len() - 1,
+        |layer, position, normal| {
+            let frame = &frames[layer];
+            (
+                frame.position(position),
+                frame.normal(normal.extend(0.)).normalize_or_zero(),
+            )
+        },
+        layer_uv_y,
+        base.asset_usage,
+    );
+
+    if caps {
+        mesh.merge(&cap(&base, &cap_verts, &frames[0], false));
+        mesh.merge(&cap(&base, &cap_verts, &frames[frames.len() - 1], true));
+    }
+    mesh
+}
+
+/// Places the base mesh of a sweep at a `frame`, facing backwards along the sweep if `end` is
+/// `false` and forwards otherwise.
+///
+/// As with [`Extrusion`](bevy_math::primitives::Extrusion)s, the start cap has its texture
+/// coordinates between `(0, 0)` and `(0.5, 0.5)`, and the end cap between `(0.5, 0)` and `(1, 0.5)`.
+fn cap(base: &Mesh, cap_verts: &[[f32; 3]], frame: &Frame, end: bool) -> Mesh {
+    let mut cap = base.clone();
+    let positions: Vec<[f32; 3]> = cap_verts
+        .iter()
+        .map(|p| frame.position(Vec2::new(p[0], p[1])).to_array())
+        .collect();
+    cap.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+
+    let z = if end { -1. } else { 1. };
+    if let Some(VertexAttributeValues::Float32x3(normals)) =
+        cap.attribute_mut(Mesh::ATTRIBUTE_NORMAL)
+    {
+        for normal in normals {
+            let [x, y, normal_z] = *normal;
+            *normal = frame.normal(Vec3::new(x, y, normal_z * z)).to_array();
+        }
+    }
+    if let Some(VertexAttributeValues::Float32x2(uvs)) = cap.attribute_mut(Mesh::ATTRIBUTE_UV_0) {
+        let u_offset = if end { 0.5 } else { 0. };
+        for uv in uvs {
+            *uv = [uv[0] * 0.5 + u_offset, uv[1] * 0.5];
+        }
+    }
+    // Mirroring the cap to face forwards inverts its winding order, which is restored by swapping
+    // the first and second indices of each triangle.
+    if end {
+        match cap.indices_mut() {
+            Some(Indices::U16(indices)) => {
+                indices.chunks_exact_mut(3).for_each(|arr| arr.swap(1, 0));
+            }
+            Some(Indices::U32(indices)) => {
+                indices.chunks_exact_mut(3).for_each(|arr| arr.swap(1, 0));
+            }
+            None => {}
+        }
+    }
+    cap
+}
+
+/// A builder for a [`Mesh`] sweeping a 2D shape along a path in 3D, with the shape in the plane
+/// perpendicular to the path at each of its points.
+///
+/// The `x` and `y` axes of the shape are mapped to the right and up of the path, which are
+/// carried along it from the [`up`](Self::up) direction at its start, so that the sweep doesn't
+/// twist more than the path itself.
+///
+/// ```
+/// # use bevy_math::{prelude::Circle, Vec3};
+/// # use bevy_mesh::{Mesh, MeshBuilder, Meshable, SweepMeshBuilder};
+/// // A pipe with a bend.
+/// let path = [Vec3::ZERO, Vec3::new(0.0, 0.0, -2.0), Vec3::new(2.0, 0.0, -4.0)];
+/// let pipe: Mesh = SweepMeshBuilder::new(Circle::new(0.25).mesh(), path).build();
+/// ```
+pub struct SweepMeshBuilder<B: Extrudable> {
+    /// The builder of the shape to sweep.
+    pub base_builder: B,
+    /// The points of the path the shape is swept along.
+    pub path: Vec<Vec3>,
+    /// The up direction of the shape at the start of the path.
+    ///
+    /// If it is parallel to the path there, an arbitrary perpendicular direction is used instead.
+    pub up: Vec3,
+    /// Whether to close the ends of the sweep with the mesh of the shape. Defaults to `true`.
+    pub caps: bool,
+}
+
+impl<B: Extrudable> SweepMeshBuilder<B> {
+    /// Creates a new [`SweepMeshBuilder`] sweeping the shape built by `base_builder` along `path`.
+    pub fn new(base_builder: B, path: impl IntoIterator<Item = Vec3>) -> Self {
+        Self {
+            base_builder,
+            path: path.into_iter().collect(),
+            up: Vec3::Y,
+            caps: true,
+        }
+    }
+
+    /// Sets the up direction of the shape at the start of the path.
+    pub fn up(mut self, up: Vec3) -> Self {
+        self.up = up;
+        self
+    }
+
+    /// Sets whether to close the ends of the sweep with the mesh of the shape.
+    pub fn caps(mut self, caps: bool) -> Self {
+        self.caps = caps;
+        self
+    }
+
+    /// Computes the frames of the shape at each point of the path, carrying the up direction along
+    /// the path by rotating it as little as possible between points.
+    fn frames(&self) -> Vec<Frame> {
+        let path = &self.path;
+        let tangent = |i: usize| {
+            let previous = path[i.saturating_sub(1)];
+            let next = path[(i + 1).min(path.len() - 1)];
+            (next - previous).normalize_or_zero()
+        };
+
+        let mut frames = Vec::with_capacity(path.len());
+        let mut previous_tangent = tangent(0);
+        let mut up = self.up.reject_from(previous_tangent).normalize_or_zero();
+        if up == Vec3::ZERO {
+            up = previous_tangent.any_orthonormal_vector();
+        }
+        for (i, &origin) in path.iter().enumerate() {
+            let tangent = tangent(i);
+            up = (Quat::from_rotation_arc(previous_tangent, tangent) * up)
+                .reject_from(tangent)
+                .normalize_or(up);
+            previous_tangent = tangent;
+            frames.push(Frame {
+                origin,
+                right: tangent.cross(up),
+                up,
+            });
+        }
+        frames
+    }
+}
+
+impl<B: Extrudable> MeshBuilder for SweepMeshBuilder<B> {
+    fn build(&self) -> Mesh {
+        if self.path.len() < 2 {
+            return Mesh::new(
+                PrimitiveTopology::TriangleList,
+                RenderAssetUsages::default(),
+            )
+            .with_inserted_indices(Indices::U32(Vec::new()))
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, Vec::<[f32; 3]>::new())
+            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, Vec::<[f32; 3]>::new())
+            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, Vec::<[f32; 2]>::new());
+        }
+
+        // UVs for the mantel are between (0, 0.5) and (1, 1), proportionally to the distance
+        // along the path.
+        let mut lengths = Vec::with_capacity(self.path.len());
+        let mut length = 0.;
+        lengths.push(length);
+        for pair in self.path.windows(2) {
+            length += pair[0].distance(pair[1]);
+            lengths.push(length);
+        }
+        let frames = self.frames();
+        build_swept_mesh(
+            &self.base_builder,
+            Vec2::ZERO,
+            &frames,
+            |layer| {
+                let progress = if length > 0. {
+                    lengths[layer] / length
+                } else {
+                    0.
+                };
+                0.5 + 0.5 * progress
+            },
+            self.caps,
+        )
+    }
+}
+
+/// A builder for a [`Mesh`] revolving a 2D shape around the `y` axis, also known as lathing.
+///
+/// The `x` axis of the shape is the distance from the axis of revolution, so the shape should be
+/// on the positive side of it, for example through the [`offset`](Self::offset). Revolving a
+/// [`Circle`](bevy_math::primitives::Circle) offset from the axis makes a torus, and revolving a
+/// [`PolylineProfile`] makes the surface of a vase or a bottle.
+///
+/// ```
+/// # use bevy_math::{prelude::Rectangle, Vec2};
+/// # use bevy_mesh::{LatheMeshBuilder, Mesh, MeshBuilder, Meshable};
+/// // A ring with a square cross section.
+/// let ring: Mesh = LatheMeshBuilder::new(Rectangle::new(0.5, 0.5).mesh())
+///     .offset(Vec2::new(2.0, 0.0))
+///     .resolution(64)
+///     .build();
+/// ```
+pub struct LatheMeshBuilder<B: Extrudable> {
+    /// The builder of the shape to revolve.
+    pub base_builder: B,
+    /// The translation of the shape, away from the axis of revolution along `x`.
+    pub offset: Vec2,
+    /// The angle of the revolution, in radians. Defaults to a full revolution.
+    pub angle: f32,
+    /// The number of segments around the axis, for a full revolution. Defaults to `32`.
+    pub resolution: u32,
+    /// Whether to close the ends of a partial revolution with the mesh of the shape. Defaults to
+    /// `true`.
+    pub caps: bool,
+}
+
+impl<B: Extrudable> LatheMeshBuilder<B> {
+    /// Creates a new [`LatheMeshBuilder`] revolving the shape built by `base_builder` around the `y`
+    /// axis.
+    pub fn new(base_builder: B) -> Self {
+        Self {
+            base_builder,
+            offset: Vec2::ZERO,
+            angle: TAU,
+            resolution: 32,
+            caps: true,
+        }
+    }
+
+    /// Sets the translation of the shape, away from the axis of revolution along `x`.
+    pub fn offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the angle of the revolution, in radians, for a partial revolution.
+    pub fn angle(mut self, angle: f32) -> Self {
+        self.angle = angle;
+        self
+    }
+
+    /// Sets the number of segments around the axis, for a full revolution.
+    pub fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Sets whether to close the ends of a partial revolution with the mesh of the shape.
+    pub fn caps(mut self, caps: bool) -> Self {
+        self.caps = caps;
+        self
+    }
+}
+
+impl<B: Extrudable> MeshBuilder for LatheMeshBuilder<B> {
+    fn build(&self) -> Mesh {
+        let angle = self.angle.clamp(-TAU, TAU);
+        let full = ops::abs(angle) >= TAU;
+        let segments = ((self.resolution as f32 * ops::abs(angle) / TAU).ceil() as usize).max(1);
+        let frames: Vec<Frame> = (0..=segments)
+            .map(|segment| {
+                let (sin, cos) = ops::sin_cos(angle * segment as f32 / segments as f32);
+                Frame {
+                    origin: Vec3::ZERO,
+                    right: Vec3::new(cos, 0., -sin),
+                    up: Vec3::Y,
+                }
+            })
+            .collect();
+
+        // UVs for the mantel are between (0, 0.5) and (1, 1), proportionally to the angle.
+        build_swept_mesh(
+            &self.base_builder,
+            self.offset,
+            &frames,
+            |layer| 0.5 + 0.5 * layer as f32 / segments as f32,
+            self.caps && !full,
+        )
+    }
+}
+
+/// A 2D profile made of a path of points, to be swept by a [`SweepMeshBuilder`] or revolved by a
+/// [`LatheMeshBuilder`] into a surface.
+///
+/// Unlike the meshes of primitive shapes, a profile has no surface of its own, so sweeping it
+/// doesn't produce caps.
+#[derive(Clone, Debug, Default)]
+pub struct PolylineProfile {
+    /// The points of the profile.
+    ///
+    /// They must be ordered such that the *outside* of the surface is to the right when walking
+    /// along them.
+    pub points: Vec<Vec2>,
+    /// Whether the last point connects back to the first one.
+    pub closed: bool,
+    /// Whether the surface is shaded smooth instead of flat.
+    pub smooth: bool,
+}
+
+impl PolylineProfile {
+    /// Creates a new open [`PolylineProfile`] through the given points, shaded flat.
+    pub fn new(points: impl IntoIterator<Item = Vec2>) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            closed: false,
+            smooth: false,
+        }
+    }
+
+    /// Sets whether the last point connects back to the first one.
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+
+    /// Sets whether the surface is shaded smooth instead of flat.
+    pub fn smooth(mut self, smooth: bool) -> Self {
+        self.smooth = smooth;
+        self
+    }
+}
+
+impl MeshBuilder for PolylineProfile {
+    fn build(&self) -> Mesh {
+        let positions: Vec<[f32; 3]> = self.points.iter().map(|p| [p.x, p.y, 0.]).collect();
+        let normals = vec![[0., 0., 1.]; positions.len()];
+        let uvs = vec![[0., 0.]; positions.len()];
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_indices(Indices::U32(Vec::new()))
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    }
+}
+
+impl Extrudable for PolylineProfile {
+    fn perimeter(&self) -> Vec<PerimeterSegment> {
+        if self.points.len() < 2 {
+            return Vec::new();
+        }
+        let mut indices: Vec<u32> = (0..self.points.len() as u32).collect();
+        if self.closed {
+            indices.push(0);
+        }
+        if !self.smooth {
+            return vec![PerimeterSegment::Flat { indices }];
+        }
+
+        // The normal at each end of the profile is that of the neighboring segment, unless it
+        // is closed, in which case both ends share the normal between the first and last segments.
+        let segment_normal = |a: Vec2, b: Vec2| {
+            let direction = (b - a).normalize_or_zero();
+            Vec2::new(direction.y, -direction.x)
+        };
+        let count = self.points.len();
+        let first = segment_normal(self.points[0], self.points[1]);
+        let last = if self.closed {
+            segment_normal(self.points[count - 1], self.points[0])
+        } else {
+            segment_normal(self.points[count - 2], self.points[count - 1])
+        };
+        let (first_normal, last_normal) = if self.closed {
+            let normal = (first + last).normalize_or(first);
+            (normal, normal)
+        } else {
+            (first, last)
+        };
+        vec![PerimeterSegment::Smooth {
+            first_normal,
+            last_normal,
+            indices,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Meshable;
+    use bevy_math::primitives::{Circle, Rectangle};
+
+    fn bounds(mesh: &Mesh) -> (Vec3, Vec3) {
+        let Some(VertexAttributeValues::Float32x3(positions)) =
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        else {
+            panic!("Expected positions f32x3");
+        };
+        positions
+            .iter()
+            .fold((Vec3::INFINITY, Vec3::NEG_INFINITY), |(min, max), &p| {
+                (min.min(p.into()), max.max(p.into()))
+            })
+    }
+
+    /// Checks that every triangle faces away from `center`.
+    fn assert_faces_outwards(mesh: &Mesh, center: impl Fn(Vec3) -> Vec3) {
+        let Some(VertexAttributeValues::Float32x3(positions)) =
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        else {
+            panic!("Expected positions f32x3");
+        };
+        let indices: Vec<usize> = mesh.indices().unwrap().iter().collect();
+        for triangle in indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| Vec3::from(positions[triangle[i]]));
+            let normal = (b - a).cross(c - a);
+            let centroid = (a + b + c) / 3.;
+            assert!(normal.dot(centroid - center(centroid)) > 0.);
+        }
+    }
+
+    #[test]
+    fn sweep_along_straight_path_matches_extrusion() {
+        let path = [Vec3::ZERO, Vec3::new(0., 0., -1.), Vec3::new(0., 0., -2.)];
+        let mesh = SweepMeshBuilder::new(Rectangle::new(1., 2.).mesh(), path).build();
+        let (min, max) = bounds(&mesh);
+        assert_eq!(min, Vec3::new(-0.5, -1., -2.));
+        assert_eq!(max, Vec3::new(0.5, 1., 0.));
+        assert_faces_outwards(&mesh, |p| Vec3::new(0., 0., p.z.clamp(-1.5, -0.5)));
+    }
+
+    #[test]
+    fn sweep_keeps_the_shape_perpendicular_to_the_path() {
+        let path = [Vec3::ZERO, Vec3::X, Vec3::new(1., 1., 0.)];
+        let mesh = SweepMeshBuilder::new(Circle::new(0.1).mesh(), path)
+            .caps(false)
+            .build();
+        let (min, max) = bounds(&mesh);
+        assert!(min.x < 0.01 && max.x > 0.99);
+        assert!(min.y < 0.01 && max.y > 0.99);
+        assert!(min.z > -0.11 && max.z < 0.11);
+    }
+
+    #[test]
+    fn lathe_makes_a_torus() {
+        let mesh = LatheMeshBuilder::new(Circle::new(0.5).mesh())
+            .offset(Vec2::new(2., 0.))
+            .resolution(48)
+            .build();
+        let (min, max) = bounds(&mesh);
+        assert!((max.x - 2.5).abs() < 0.01 && (min.z + 2.5).abs() < 0.01);
+        assert!((max.y - 0.5).abs() < 0.01);
+        assert_faces_outwards(&mesh, |p| Vec3::new(p.x, 0., p.z).normalize() * 2.);
+    }
+
+    #[test]
+    fn lathe_profile() {
+        // A cylinder without caps, from a profile going up its side.
+        let profile = PolylineProfile::new([Vec2::new(1., -1.), Vec2::new(1., 1.)]);
+        let mesh = LatheMeshBuilder::new(profile).resolution(16).build();
+        assert_eq!(mesh.count_vertices(), 2 * 17);
+        assert_faces_outwards(&mesh, |p| Vec3::new(0., p.y, 0.));
+
+        let half = LatheMeshBuilder::new(Rectangle::new(1., 1.).mesh())
+            .offset(Vec2::new(1., 0.))
+            .angle(TAU / 2.)
+            .build();
+        // Revolving counterclockwise around the `y` axis, from `x` towards `-z`.
+        let (min, max) = bounds(&half);
+        assert!(max.z < 0.01 && (min.z + 1.5).abs() < 0.01);
+    }
+}
@@ -22,6 +22,7 @@ pub const MESHLET_MESH_ASSET_VERSION: u64 = 1;
 /// A mesh that has been pre-processed into multiple small clusters of triangles called meshlets.
 ///
 /// A [`bevy_render::mesh::Mesh`] can be converted to a [`MeshletMesh`] using `MeshletMesh::from_mesh` when the `meshlet_processor` cargo feature is enabled.
+/// Meshes can also be converted when processing assets, using the `MeshletMeshProcessor`.
 /// The conversion step is very slow, and is meant to be ran once ahead of time, and not during runtime. This type of mesh is not suitable for
 /// dynamically generated geometry.
 ///
@@ -4,7 +4,7 @@ use super::asset::{
 use alloc::borrow::Cow;
 use bevy_math::{ops::log2, IVec3, Vec2, Vec3, Vec3Swizzles};
 use bevy_render::{
-    mesh::{Indices, Mesh},
+    mesh::{Indices, Mesh, VertexAttributeValues},
     render_resource::PrimitiveTopology,
 };
 use bevy_utils::HashMap;
@@ -17,6 +17,7 @@ use meshopt::{
     simplify_with_attributes_and_locks, Meshlets, SimplifyOptions, VertexDataAdapter, VertexStream,
 };
 use metis::{option::Opt, Graph};
+use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 use thiserror::Error;
 
@@ -32,6 +33,39 @@ pub const MESHLET_DEFAULT_VERTEX_POSITION_QUANTIZATION_FACTOR: u8 = 4;
 
 const CENTIMETERS_PER_METER: f32 = 100.0;
 
+/// Settings for [`MeshletMesh::from_mesh_with_settings`].
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MeshletMeshConversionSettings {
+    /// The amount of precision to use when quantizing vertex positions.
+    ///
+    /// See [`MeshletMesh::from_mesh`] for details.
+    pub vertex_position_quantization_factor: u8,
+    /// The maximum amount of simplified levels of detail to build on top of the original mesh.
+    ///
+    /// If `None`, levels of detail are built until the mesh can't be simplified any further.
+    /// Fewer levels make the conversion faster and the resulting asset smaller,
+    /// at the cost of rendering more triangles for distant meshes.
+    pub max_lod_levels: Option<u32>,
+    /// Whether to repair meshes which don't meet the requirements of [`MeshletMesh::from_mesh`]
+    /// instead of returning an error.
+    ///
+    /// Repairing a mesh removes unsupported vertex attributes, generates missing indices,
+    /// computes smooth normals if they are missing, and fills in missing UVs with zeros.
+    /// Meshes that don't use [`PrimitiveTopology::TriangleList`] or have no positions can't be repaired.
+    pub repair_mesh: bool,
+}
+
+impl Default for MeshletMeshConversionSettings {
+    fn default() -> Self {
+        Self {
+            vertex_position_quantization_factor:
+                MESHLET_DEFAULT_VERTEX_POSITION_QUANTIZATION_FACTOR,
+            max_lod_levels: None,
+            repair_mesh: true,
+        }
+    }
+}
+
 impl MeshletMesh {
     /// Process a [`Mesh`] to generate a [`MeshletMesh`].
     ///
@@ -63,6 +97,37 @@ impl MeshletMesh {
     pub fn from_mesh(
         mesh: &Mesh,
         vertex_position_quantization_factor: u8,
+    ) -> Result<Self, MeshToMeshletMeshConversionError> {
+        Self::build(mesh, vertex_position_quantization_factor, None)
+    }
+
+    /// Process a [`Mesh`] to generate a [`MeshletMesh`], using the given settings.
+    ///
+    /// Unlike [`MeshletMesh::from_mesh`], this can repair meshes that don't meet the requirements for conversion
+    /// and limit the amount of levels of detail that are built. See [`MeshletMeshConversionSettings`] for details.
+    ///
+    /// This function requires the `meshlet_processor` cargo feature.
+    pub fn from_mesh_with_settings(
+        mesh: &Mesh,
+        settings: &MeshletMeshConversionSettings,
+    ) -> Result<Self, MeshToMeshletMeshConversionError> {
+        let mesh = if settings.repair_mesh {
+            repair_input_mesh(mesh)?
+        } else {
+            Cow::Borrowed(mesh)
+        };
+
+        Self::build(
+            &mesh,
+            settings.vertex_position_quantization_factor,
+            settings.max_lod_levels,
+        )
+    }
+
+    fn build(
+        mesh: &Mesh,
+        vertex_position_quantization_factor: u8,
+        max_lod_levels: Option<u32>,
     ) -> Result<Self, MeshToMeshletMeshConversionError> {
         // Validate mesh format
         let indices = validate_input_mesh(mesh)?;
@@ -113,7 +178,12 @@ impl MeshletMesh {
 
         // Build further LODs
         let mut simplification_queue = 0..meshlets.len();
-        while simplification_queue.len() > 1 {
+        let mut lod_levels = 0;
+        while simplification_queue.len() > 1
+            && max_lod_levels.is_none_or(|max_lod_levels| lod_levels < max_lod_levels)
+        {
+            lod_levels += 1;
+
             // For each meshlet build a list of connected meshlets (meshlets that share a vertex)
             let connected_meshlets_per_meshlet = find_connected_meshlets(
                 simplification_queue.clone(),
@@ -245,11 +315,69 @@ fn validate_input_mesh(mesh: &Mesh) -> Result<Cow<'_, [u32]>, MeshToMeshletMeshC
         return Err(MeshToMeshletMeshConversionError::WrongMeshVertexAttributes);
     }
 
-    match mesh.indices() {
-        Some(Indices::U32(indices)) => Ok(Cow::Borrowed(indices.as_slice())),
-        Some(Indices::U16(indices)) => Ok(indices.iter().map(|i| *i as u32).collect()),
-        _ => Err(MeshToMeshletMeshConversionError::MeshMissingIndices),
+    let indices: Cow<'_, [u32]> = match mesh.indices() {
+        Some(Indices::U32(indices)) => Cow::Borrowed(indices.as_slice()),
+        Some(Indices::U16(indices)) => indices.iter().map(|i| *i as u32).collect(),
+        _ => return Err(MeshToMeshletMeshConversionError::MeshMissingIndices),
+    };
+
+    if indices.is_empty() {
+        return Err(MeshToMeshletMeshConversionError::MeshHasNoTriangles);
+    }
+    let vertex_count = mesh.count_vertices();
+    if !indices.len().is_multiple_of(3) || indices.iter().any(|i| *i as usize >= vertex_count) {
+        return Err(MeshToMeshletMeshConversionError::InvalidMeshIndices);
+    }
+
+    Ok(indices)
+}
+
+/// Returns a copy of the mesh that meets the requirements of [`validate_input_mesh`], if it doesn't already.
+fn repair_input_mesh(mesh: &Mesh) -> Result<Cow<'_, Mesh>, MeshToMeshletMeshConversionError> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return Err(MeshToMeshletMeshConversionError::WrongMeshPrimitiveTopology);
+    }
+    if !mesh.contains_attribute(Mesh::ATTRIBUTE_POSITION) {
+        return Err(MeshToMeshletMeshConversionError::WrongMeshVertexAttributes);
+    }
+
+    let needs_repair = mesh.indices().is_none()
+        || mesh.attributes().map(|(attribute, _)| attribute.id).ne([
+            Mesh::ATTRIBUTE_POSITION.id,
+            Mesh::ATTRIBUTE_NORMAL.id,
+            Mesh::ATTRIBUTE_UV_0.id,
+        ]);
+    if !needs_repair {
+        return Ok(Cow::Borrowed(mesh));
+    }
+
+    let mut repaired = Mesh::new(PrimitiveTopology::TriangleList, mesh.asset_usage);
+    for attribute in [
+        Mesh::ATTRIBUTE_POSITION,
+        Mesh::ATTRIBUTE_NORMAL,
+        Mesh::ATTRIBUTE_UV_0,
+    ] {
+        if let Some(values) = mesh.attribute(attribute.id) {
+            repaired.insert_attribute(attribute, values.clone());
+        }
     }
+
+    let vertex_count = mesh.count_vertices();
+    repaired.insert_indices(match mesh.indices() {
+        Some(indices) => indices.clone(),
+        None => Indices::U32((0..vertex_count as u32).collect()),
+    });
+    if !repaired.contains_attribute(Mesh::ATTRIBUTE_NORMAL) {
+        repaired.compute_smooth_normals();
+    }
+    if !repaired.contains_attribute(Mesh::ATTRIBUTE_UV_0) {
+        repaired.insert_attribute(
+            Mesh::ATTRIBUTE_UV_0,
+            VertexAttributeValues::Float32x2(vec![[0.0, 0.0]; vertex_count]),
+        );
+    }
+
+    Ok(Cow::Owned(repaired))
 }
 
 fn compute_meshlets(
@@ -728,4 +856,80 @@ pub enum MeshToMeshletMeshConversionError {
     WrongMeshVertexAttributes,
     #[error("Mesh has no indices")]
     MeshMissingIndices,
+    #[error("Mesh has no triangles")]
+    MeshHasNoTriangles,
+    #[error("Mesh indices do not form whole triangles of existing vertices")]
+    InvalidMeshIndices,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::primitives::Sphere;
+    use bevy_render::mesh::Meshable;
+
+    fn sphere() -> Mesh {
+        Sphere::new(1.0).mesh().ico(6).unwrap()
+    }
+
+    #[test]
+    fn limit_lod_levels() {
+        let mesh = sphere();
+        let all_lods = MeshletMesh::from_mesh(&mesh, 4).unwrap();
+        let no_lods = MeshletMesh::from_mesh_with_settings(
+            &mesh,
+            &MeshletMeshConversionSettings {
+                max_lod_levels: Some(0),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert!(no_lods.meshlets.len() < all_lods.meshlets.len());
+        assert!(no_lods
+            .meshlet_simplification_errors
+            .iter()
+            .all(|error| error.group_error == f16::ZERO));
+    }
+
+    #[test]
+    fn repair_mesh() {
+        let mut mesh = sphere()
+            .with_removed_attribute(Mesh::ATTRIBUTE_NORMAL)
+            .with_removed_attribute(Mesh::ATTRIBUTE_UV_0)
+            .with_inserted_attribute(
+                Mesh::ATTRIBUTE_COLOR,
+                VertexAttributeValues::Float32x4(vec![[1.0; 4]; sphere().count_vertices()]),
+            );
+        mesh.duplicate_vertices();
+
+        assert!(matches!(
+            MeshletMesh::from_mesh(&mesh, 4),
+            Err(MeshToMeshletMeshConversionError::WrongMeshVertexAttributes)
+        ));
+        assert!(MeshletMesh::from_mesh_with_settings(
+            &mesh,
+            &MeshletMeshConversionSettings {
+                repair_mesh: false,
+                ..Default::default()
+            }
+        )
+        .is_err());
+        assert!(MeshletMesh::from_mesh_with_settings(
+            &mesh,
+            &MeshletMeshConversionSettings::default()
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn reject_invalid_indices() {
+        let mut mesh = sphere();
+        mesh.insert_indices(Indices::U32(vec![0, 1, u32::MAX]));
+
+        assert!(matches!(
+            MeshletMesh::from_mesh_with_settings(&mesh, &MeshletMeshConversionSettings::default()),
+            Err(MeshToMeshletMeshConversionError::InvalidMeshIndices)
+        ));
+    }
 }
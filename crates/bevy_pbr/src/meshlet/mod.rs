@@ -10,6 +10,8 @@ mod meshlet_mesh_manager;
 mod persistent_buffer;
 mod persistent_buffer_impls;
 mod pipelines;
+#[cfg(feature = "meshlet_processor")]
+mod processor;
 mod resource_manager;
 mod visibility_buffer_raster_node;
 
@@ -36,8 +38,15 @@ pub use self::asset::{
     MeshletMesh, MeshletMeshLoader, MeshletMeshSaver, MESHLET_MESH_ASSET_VERSION,
 };
 #[cfg(feature = "meshlet_processor")]
-pub use self::from_mesh::{
-    MeshToMeshletMeshConversionError, MESHLET_DEFAULT_VERTEX_POSITION_QUANTIZATION_FACTOR,
+pub use self::{
+    from_mesh::{
+        MeshToMeshletMeshConversionError, MeshletMeshConversionSettings,
+        MESHLET_DEFAULT_VERTEX_POSITION_QUANTIZATION_FACTOR,
+    },
+    processor::{
+        MeshletMeshProcessor, MeshletMeshTransformer, MeshletMeshTransformerError,
+        MeshletMeshTransformerSettings,
+    },
 };
 use self::{
     graph::NodeMeshlet,
@@ -0,0 +1,90 @@
+use super::{
+    asset::{MeshletMesh, MeshletMeshSaver},
+    from_mesh::{MeshToMeshletMeshConversionError, MeshletMeshConversionSettings},
+};
+use bevy_asset::{
+    processor::LoadTransformAndSave,
+    transformer::{AssetTransformer, TransformedAsset},
+    Asset, AssetLoader,
+};
+use bevy_render::mesh::Mesh;
+use core::{any::Any, marker::PhantomData};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// An asset processor that converts the meshes loaded by `L` into `.meshlet_mesh` [`MeshletMesh`] assets.
+///
+/// This runs [`MeshletMesh::from_mesh_with_settings`] when assets are processed, so that meshes authored in
+/// regular formats can be rendered with the [`super::MeshletPlugin`] without a separate offline conversion step.
+///
+/// `L` can either load a [`Mesh`] directly, or load an asset with labeled [`Mesh`] sub-assets, such as a glTF file,
+/// in which case [`MeshletMeshTransformerSettings::mesh_label`] selects the mesh to convert.
+pub type MeshletMeshProcessor<L> =
+    LoadTransformAndSave<L, MeshletMeshTransformer<<L as AssetLoader>::Asset>, MeshletMeshSaver>;
+
+/// An [`AssetTransformer`] that converts a [`Mesh`], or a labeled [`Mesh`] sub-asset of an `A`, into a [`MeshletMesh`].
+///
+/// See [`MeshletMeshProcessor`] for converting meshes when processing assets.
+pub struct MeshletMeshTransformer<A = Mesh> {
+    marker: PhantomData<fn(A)>,
+}
+
+impl<A> Default for MeshletMeshTransformer<A> {
+    fn default() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Settings for the [`MeshletMeshTransformer`].
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct MeshletMeshTransformerSettings {
+    /// The label of the [`Mesh`] sub-asset to convert, such as `"Mesh0/Primitive0"` for glTF files.
+    ///
+    /// If `None`, the loaded asset itself is converted, which requires it to be a [`Mesh`].
+    pub mesh_label: Option<String>,
+    /// The settings used to convert the mesh.
+    pub conversion: MeshletMeshConversionSettings,
+}
+
+/// An error produced by the [`MeshletMeshTransformer`].
+#[derive(Error, Debug)]
+pub enum MeshletMeshTransformerError {
+    #[error("The loaded asset has no `Mesh` sub-asset labeled {0:?}")]
+    MissingMesh(String),
+    #[error("The loaded asset is not a `Mesh`, so a `mesh_label` has to be set")]
+    NotAMesh,
+    #[error(transparent)]
+    Conversion(#[from] MeshToMeshletMeshConversionError),
+}
+
+impl<A: Asset> AssetTransformer for MeshletMeshTransformer<A> {
+    type AssetInput = A;
+    type AssetOutput = MeshletMesh;
+    type Settings = MeshletMeshTransformerSettings;
+    type Error = MeshletMeshTransformerError;
+
+    async fn transform<'a>(
+        &'a self,
+        mut asset: TransformedAsset<Self::AssetInput>,
+        settings: &'a Self::Settings,
+    ) -> Result<TransformedAsset<Self::AssetOutput>, Self::Error> {
+        let meshlet_mesh = match &settings.mesh_label {
+            Some(label) => {
+                let mesh = asset
+                    .get_labeled::<Mesh, _>(label.as_str())
+                    .ok_or_else(|| MeshletMeshTransformerError::MissingMesh(label.clone()))?;
+                MeshletMesh::from_mesh_with_settings(&mesh, &settings.conversion)?
+            }
+            None => {
+                let mesh = (asset.get() as &dyn Any)
+                    .downcast_ref::<Mesh>()
+                    .ok_or(MeshletMeshTransformerError::NotAMesh)?;
+                MeshletMesh::from_mesh_with_settings(mesh, &settings.conversion)?
+            }
+        };
+
+        Ok(asset.replace_asset(meshlet_mesh))
+    }
+}
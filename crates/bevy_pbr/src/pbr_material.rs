@@ -473,6 +473,41 @@ pub struct StandardMaterial {
     #[cfg(feature = "pbr_multi_layer_material_textures")]
     pub clearcoat_normal_texture: Option<Handle<Image>>,
 
+    /// The color of a soft, velvety layer on top of the main PBR layer, as
+    /// seen on cloth and fabrics, which reflects light back at grazing angles.
+    ///
+    /// Defaults to [`Color::BLACK`], specifying no sheen layer.
+    pub sheen_color: Color,
+
+    /// The roughness of the sheen layer. This is specified in the same way as
+    /// the [`StandardMaterial::perceptual_roughness`].
+    ///
+    /// If the [`StandardMaterial::sheen_color`] is black, this has no effect.
+    ///
+    /// Defaults to 0.0.
+    pub sheen_perceptual_roughness: f32,
+
+    /// The strength of a thin film on top of the main PBR layer, whose
+    /// interference causes a shift in hue depending on the view angle, as seen
+    /// on soap bubbles, oil slicks and insect wings.
+    ///
+    /// This value ranges from 0 (no film) to 1 (full strength).
+    ///
+    /// Defaults to zero, specifying no thin film.
+    pub iridescence: f32,
+
+    /// The index of refraction of the thin film of the
+    /// [`StandardMaterial::iridescence`] layer.
+    ///
+    /// Defaults to 1.3.
+    pub iridescence_ior: f32,
+
+    /// The thickness of the thin film of the [`StandardMaterial::iridescence`]
+    /// layer, in nanometers.
+    ///
+    /// Defaults to 400.0.
+    pub iridescence_thickness: f32,
+
     /// Increases the roughness along a specific direction, so that the specular
     /// highlight will be stretched instead of being a circular lobe.
     ///
@@ -815,6 +850,11 @@ impl Default for StandardMaterial {
             clearcoat_normal_channel: UvChannel::Uv0,
             #[cfg(feature = "pbr_multi_layer_material_textures")]
             clearcoat_normal_texture: None,
+            sheen_color: Color::BLACK,
+            sheen_perceptual_roughness: 0.0,
+            iridescence: 0.0,
+            iridescence_ior: 1.3,
+            iridescence_thickness: 400.0,
             anisotropy_strength: 0.0,
             anisotropy_rotation: 0.0,
             #[cfg(feature = "pbr_anisotropy_texture")]
@@ -916,6 +956,8 @@ pub struct StandardMaterialUniform {
     pub emissive: Vec4,
     /// Color white light takes after traveling through the attenuation distance underneath the material surface
     pub attenuation_color: Vec4,
+    /// Color of the sheen layer, which is disabled when black
+    pub sheen_color: Vec4,
     /// The transform applied to the UVs corresponding to `ATTRIBUTE_UV_0` on the mesh before sampling. Default is identity.
     pub uv_transform: Mat3,
     /// Linear perceptual roughness, clamped to [0.089, 1.0] in the shader
@@ -938,6 +980,11 @@ pub struct StandardMaterialUniform {
     pub attenuation_distance: f32,
     pub clearcoat: f32,
     pub clearcoat_perceptual_roughness: f32,
+    pub sheen_perceptual_roughness: f32,
+    pub iridescence: f32,
+    pub iridescence_ior: f32,
+    /// Thickness of the iridescent thin film, in nanometers
+    pub iridescence_thickness: f32,
     pub anisotropy_strength: f32,
     pub anisotropy_rotation: Vec2,
     /// The [`StandardMaterialFlags`] accessible in the `wgsl` shader.
@@ -1078,6 +1125,11 @@ impl AsBindGroupShaderType<StandardMaterialUniform> for StandardMaterial {
             reflectance: self.reflectance,
             clearcoat: self.clearcoat,
             clearcoat_perceptual_roughness: self.clearcoat_perceptual_roughness,
+            sheen_color: LinearRgba::from(self.sheen_color).to_vec4(),
+            sheen_perceptual_roughness: self.sheen_perceptual_roughness,
+            iridescence: self.iridescence,
+            iridescence_ior: self.iridescence_ior,
+            iridescence_thickness: self.iridescence_thickness,
             anisotropy_strength: self.anisotropy_strength,
             anisotropy_rotation,
             diffuse_transmission: self.diffuse_transmission,
@@ -1125,6 +1177,8 @@ bitflags! {
         const CLEARCOAT_UV             = 0x040000;
         const CLEARCOAT_ROUGHNESS_UV   = 0x080000;
         const CLEARCOAT_NORMAL_UV      = 0x100000;
+        const SHEEN                    = 0x200000;
+        const IRIDESCENCE              = 0x400000;
         const DEPTH_BIAS               = 0xffffffff_00000000;
     }
 }
@@ -1170,6 +1224,13 @@ impl From<&StandardMaterial> for StandardMaterialKey {
             material.clearcoat > 0.0 && material.clearcoat_normal_texture.is_some(),
         );
 
+        key.set(
+            StandardMaterialKey::SHEEN,
+            LinearRgba::from(material.sheen_color).to_f32_array_no_alpha() != [0.0; 3],
+        );
+
+        key.set(StandardMaterialKey::IRIDESCENCE, material.iridescence > 0.0);
+
         key.set(
             StandardMaterialKey::ANISOTROPY,
             material.anisotropy_strength > 0.0,
@@ -1342,6 +1403,11 @@ impl Material for StandardMaterial {
                     StandardMaterialKey::CLEARCOAT_NORMAL_MAP,
                     "STANDARD_MATERIAL_CLEARCOAT_NORMAL_MAP",
                 ),
+                (StandardMaterialKey::SHEEN, "STANDARD_MATERIAL_SHEEN"),
+                (
+                    StandardMaterialKey::IRIDESCENCE,
+                    "STANDARD_MATERIAL_IRIDESCENCE",
+                ),
                 (
                     StandardMaterialKey::ANISOTROPY,
                     "STANDARD_MATERIAL_ANISOTROPY",
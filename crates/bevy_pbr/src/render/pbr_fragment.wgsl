@@ -237,12 +237,22 @@ fn pbr_input_from_standard_material(
         pbr_input.material.ior = pbr_bindings::material[slot].ior;
         pbr_input.material.attenuation_color = pbr_bindings::material[slot].attenuation_color;
         pbr_input.material.attenuation_distance = pbr_bindings::material[slot].attenuation_distance;
+        pbr_input.material.sheen_color = pbr_bindings::material[slot].sheen_color;
+        pbr_input.material.sheen_perceptual_roughness = pbr_bindings::material[slot].sheen_perceptual_roughness;
+        pbr_input.material.iridescence = pbr_bindings::material[slot].iridescence;
+        pbr_input.material.iridescence_ior = pbr_bindings::material[slot].iridescence_ior;
+        pbr_input.material.iridescence_thickness = pbr_bindings::material[slot].iridescence_thickness;
         pbr_input.material.alpha_cutoff = pbr_bindings::material[slot].alpha_cutoff;
 #else   // BINDLESS
         pbr_input.material.reflectance = pbr_bindings::material.reflectance;
         pbr_input.material.ior = pbr_bindings::material.ior;
         pbr_input.material.attenuation_color = pbr_bindings::material.attenuation_color;
         pbr_input.material.attenuation_distance = pbr_bindings::material.attenuation_distance;
+        pbr_input.material.sheen_color = pbr_bindings::material.sheen_color;
+        pbr_input.material.sheen_perceptual_roughness = pbr_bindings::material.sheen_perceptual_roughness;
+        pbr_input.material.iridescence = pbr_bindings::material.iridescence;
+        pbr_input.material.iridescence_ior = pbr_bindings::material.iridescence_ior;
+        pbr_input.material.iridescence_thickness = pbr_bindings::material.iridescence_thickness;
         pbr_input.material.alpha_cutoff = pbr_bindings::material.alpha_cutoff;
 #endif  // BINDLESS
 
@@ -328,7 +328,18 @@ fn apply_pbr_lighting(
     // Calculate the world position of the second Lambertian lobe used for diffuse transmission, by subtracting material thickness
     let diffuse_transmissive_lobe_world_position = in.world_position - vec4<f32>(in.world_normal, 0.0) * thickness;
 
-    let F0 = calculate_F0(output_color.rgb, metallic, reflectance);
+    var F0 = calculate_F0(output_color.rgb, metallic, reflectance);
+#ifdef STANDARD_MATERIAL_IRIDESCENCE
+    // Blend in the reflectance of the thin film, evaluated once for the view
+    // angle, so that it's picked up by both direct and indirect specular light.
+    let iridescence_F0 = lighting::iridescence_fresnel(
+        in.material.iridescence_ior,
+        in.material.iridescence_thickness,
+        NdotV,
+        F0,
+    );
+    F0 = mix(F0, iridescence_F0, in.material.iridescence);
+#endif  // STANDARD_MATERIAL_IRIDESCENCE
     let F_ab = lighting::F_AB(perceptual_roughness, NdotV);
 
     var direct_light: vec3<f32> = vec3<f32>(0.0);
@@ -356,6 +367,11 @@ fn apply_pbr_lighting(
     lighting_input.layers[LAYER_CLEARCOAT].roughness = clearcoat_roughness;
     lighting_input.clearcoat_strength = clearcoat;
 #endif  // STANDARD_MATERIAL_CLEARCOAT
+#ifdef STANDARD_MATERIAL_SHEEN
+    lighting_input.sheen_color = in.material.sheen_color.rgb;
+    lighting_input.sheen_roughness =
+        lighting::perceptualRoughnessToRoughness(in.material.sheen_perceptual_roughness);
+#endif  // STANDARD_MATERIAL_SHEEN
 #ifdef STANDARD_MATERIAL_ANISOTROPY
     lighting_input.anisotropy = in.anisotropy_strength;
     lighting_input.Ta = in.anisotropy_T;
@@ -383,6 +399,10 @@ fn apply_pbr_lighting(
     transmissive_lighting_input.layers[LAYER_CLEARCOAT].roughness = 0.0;
     transmissive_lighting_input.clearcoat_strength = 0.0;
 #endif  // STANDARD_MATERIAL_CLEARCOAT
+#ifdef STANDARD_MATERIAL_SHEEN
+    transmissive_lighting_input.sheen_color = vec3(0.0);
+    transmissive_lighting_input.sheen_roughness = 1.0;
+#endif  // STANDARD_MATERIAL_SHEEN
 #ifdef STANDARD_MATERIAL_ANISOTROPY
     lighting_input.anisotropy = in.anisotropy_strength;
     lighting_input.Ta = in.anisotropy_T;
@@ -92,6 +92,13 @@ struct LightingInput {
     clearcoat_strength: f32,
 #endif  // STANDARD_MATERIAL_CLEARCOAT
 
+#ifdef STANDARD_MATERIAL_SHEEN
+    // The color of the sheen layer.
+    sheen_color: vec3<f32>,
+    // The roughness of the sheen layer.
+    sheen_roughness: f32,
+#endif  // STANDARD_MATERIAL_SHEEN
+
 #ifdef STANDARD_MATERIAL_ANISOTROPY
     // The anisotropy strength, reflecting the amount of increased roughness in
     // the tangent direction.
@@ -345,6 +352,140 @@ fn specular_clearcoat(
     return vec2(Fc, Frc);
 }
 
+#ifdef STANDARD_MATERIAL_SHEEN
+
+// The "Charlie" sheen distribution.
+//
+// <https://google.github.io/filament/Filament.html#materialsystem/clothmodel>
+fn D_Charlie(roughness: f32, NdotH: f32) -> f32 {
+    let inv_alpha = 1.0 / roughness;
+    let sin2h = max(1.0 - NdotH * NdotH, 0.0078125);
+    return (2.0 + inv_alpha) * pow(sin2h, inv_alpha * 0.5) / (2.0 * PI);
+}
+
+// The sheen visibility term from Neubelt and Pettineo 2013, "Crafting a
+// Next-gen Material Pipeline for The Order: 1886".
+fn V_Neubelt(NdotV: f32, NdotL: f32) -> f32 {
+    return saturate(1.0 / (4.0 * (NdotL + NdotV - NdotL * NdotV)));
+}
+
+// Calculates the light reflected by the sheen layer, per
+// `KHR_materials_sheen`.
+fn specular_sheen(
+    input: ptr<function, LightingInput>,
+    derived_input: ptr<function, DerivedLightingInput>,
+) -> vec3<f32> {
+    let NdotV = (*input).layers[LAYER_BASE].NdotV;
+    let D = D_Charlie((*input).sheen_roughness, (*derived_input).NdotH);
+    let V = V_Neubelt(NdotV, (*derived_input).NdotL);
+    return (*input).sheen_color * D * V;
+}
+
+// The factor by which the sheen layer darkens the layers below it.
+//
+// This approximates the directional albedo of the sheen layer by its average
+// over roughnesses and view angles, instead of looking it up in a table.
+fn sheen_albedo_scaling(input: ptr<function, LightingInput>) -> f32 {
+    let sheen_color = (*input).sheen_color;
+    return 1.0 - 0.157 * max(sheen_color.r, max(sheen_color.g, sheen_color.b));
+}
+
+#endif  // STANDARD_MATERIAL_SHEEN
+
+#ifdef STANDARD_MATERIAL_IRIDESCENCE
+
+fn ior_to_fresnel0(transmitted_ior: vec3<f32>, incident_ior: f32) -> vec3<f32> {
+    let r = (transmitted_ior - vec3(incident_ior)) / (transmitted_ior + vec3(incident_ior));
+    return r * r;
+}
+
+fn fresnel0_to_ior(F0: vec3<f32>) -> vec3<f32> {
+    let sqrt_F0 = sqrt(clamp(F0, vec3(0.0), vec3(0.9999)));
+    return (vec3(1.0) + sqrt_F0) / (vec3(1.0) - sqrt_F0);
+}
+
+// Evaluates the interference of the sensitivity of the XYZ color matching
+// functions for an optical path difference, in nanometers, and returns it in
+// linear Rec. 709.
+fn iridescence_sensitivity(OPD: f32, shift: vec3<f32>) -> vec3<f32> {
+    let phase = 2.0 * PI * OPD * 1.0e-9;
+    let val = vec3(5.4856e-13, 4.4201e-13, 5.2481e-13);
+    let pos = vec3(1.6810e+06, 1.7953e+06, 2.2084e+06);
+    let variance = vec3(4.3278e+09, 9.3046e+09, 6.6121e+09);
+
+    var xyz = val * sqrt(2.0 * PI * variance) * cos(pos * phase + shift) *
+        exp(-(phase * phase) * variance);
+    xyz.x += 9.7470e-14 * sqrt(2.0 * PI * 4.5282e+09) * cos(2.2399e+06 * phase + shift.x) *
+        exp(-4.5282e+09 * phase * phase);
+    xyz /= 1.0685e-7;
+
+    let xyz_to_rec709 = mat3x3<f32>(
+        3.2404542, -0.9692660, 0.0556434,
+        -1.5371385, 1.8760108, -0.2040259,
+        -0.4985314, 0.0415560, 1.0572252
+    );
+    return xyz_to_rec709 * xyz;
+}
+
+// Calculates the Fresnel reflectance of a thin film of the given index of
+// refraction and thickness, in nanometers, on top of a base layer of reflectance
+// `base_F0`, per `KHR_materials_iridescence`.
+//
+// Belcour and Barla 2017, "A Practical Extension to Microfacet Theory for the
+// Modeling of Varying Iridescence".
+fn iridescence_fresnel(
+    film_ior: f32,
+    film_thickness: f32,
+    cos_theta1: f32,
+    base_F0: vec3<f32>,
+) -> vec3<f32> {
+    let outside_ior = 1.0;
+    // The film vanishes as its thickness goes to zero.
+    let iridescence_ior = mix(outside_ior, film_ior, smoothstep(0.0, 0.03, film_thickness));
+
+    // Snell's law for the angle in the film, handling total internal reflection.
+    let sin_theta2_sq =
+        pow(outside_ior / iridescence_ior, 2.0) * (1.0 - cos_theta1 * cos_theta1);
+    let cos_theta2_sq = 1.0 - sin_theta2_sq;
+    if (cos_theta2_sq < 0.0) {
+        return vec3(1.0);
+    }
+    let cos_theta2 = sqrt(cos_theta2_sq);
+
+    // First interface.
+    let R0 = ior_to_fresnel0(vec3(iridescence_ior), outside_ior).x;
+    let R12 = F_Schlick(R0, 1.0, cos_theta1);
+    let T121 = 1.0 - R12;
+    let phi12 = select(0.0, PI, iridescence_ior < outside_ior);
+    let phi21 = PI - phi12;
+
+    // Second interface.
+    let base_ior = fresnel0_to_ior(base_F0);
+    let R1 = ior_to_fresnel0(base_ior, iridescence_ior);
+    let R23 = F_Schlick_vec(R1, 1.0, cos_theta2);
+    let phi23 = select(vec3(0.0), vec3(PI), base_ior < vec3(iridescence_ior));
+
+    // Phase shift.
+    let OPD = 2.0 * iridescence_ior * film_thickness * cos_theta2;
+    let phi = vec3(phi21) + phi23;
+
+    // Compound terms.
+    let R123 = clamp(R12 * R23, vec3(1e-5), vec3(0.9999));
+    let r123 = sqrt(R123);
+    let Rs = (T121 * T121) * R23 / (vec3(1.0) - R123);
+
+    // Reflectance for the first orders of interference.
+    var I = R12 + Rs;
+    var Cm = Rs - T121;
+    for (var m = 1; m <= 2; m += 1) {
+        Cm *= r123;
+        I += Cm * 2.0 * iridescence_sensitivity(f32(m) * OPD, f32(m) * phi);
+    }
+    return max(I, vec3(0.0));
+}
+
+#endif  // STANDARD_MATERIAL_IRIDESCENCE
+
 #ifdef STANDARD_MATERIAL_ANISOTROPY
 
 fn specular_anisotropy(
@@ -471,9 +612,9 @@ fn point_light(
     let specular_intensity = specular_L_intensity.w;
 
 #ifdef STANDARD_MATERIAL_ANISOTROPY
-    let specular_light = specular_anisotropy(input, &specular_derived_input, L, specular_intensity);
+    var specular_light = specular_anisotropy(input, &specular_derived_input, L, specular_intensity);
 #else   // STANDARD_MATERIAL_ANISOTROPY
-    let specular_light = specular(input, &specular_derived_input, specular_intensity);
+    var specular_light = specular(input, &specular_derived_input, specular_intensity);
 #endif  // STANDARD_MATERIAL_ANISOTROPY
 
     // Clearcoat
@@ -516,6 +657,13 @@ fn point_light(
         diffuse = diffuse_color * Fd_Burley(input, &derived_input);
     }
 
+#ifdef STANDARD_MATERIAL_SHEEN
+    // The sheen layer sits on top of the base layer and darkens it.
+    let sheen_scaling = sheen_albedo_scaling(input);
+    diffuse = diffuse * sheen_scaling + specular_sheen(input, &derived_input);
+    specular_light *= sheen_scaling;
+#endif  // STANDARD_MATERIAL_SHEEN
+
     // See https://google.github.io/filament/Filament.html#mjx-eqn-pointLightLuminanceEquation
     // Lout = f(v,l) Φ / { 4 π d^2 }⟨n⋅l⟩
     // where
@@ -594,11 +742,18 @@ fn directional_light(
     }
 
 #ifdef STANDARD_MATERIAL_ANISOTROPY
-    let specular_light = specular_anisotropy(input, &derived_input, L, 1.0);
+    var specular_light = specular_anisotropy(input, &derived_input, L, 1.0);
 #else   // STANDARD_MATERIAL_ANISOTROPY
-    let specular_light = specular(input, &derived_input, 1.0);
+    var specular_light = specular(input, &derived_input, 1.0);
 #endif  // STANDARD_MATERIAL_ANISOTROPY
 
+#ifdef STANDARD_MATERIAL_SHEEN
+    // The sheen layer sits on top of the base layer and darkens it.
+    let sheen_scaling = sheen_albedo_scaling(input);
+    diffuse = diffuse * sheen_scaling + specular_sheen(input, &derived_input);
+    specular_light *= sheen_scaling;
+#endif  // STANDARD_MATERIAL_SHEEN
+
 #ifdef STANDARD_MATERIAL_CLEARCOAT
     let clearcoat_N = (*input).layers[LAYER_CLEARCOAT].N;
     let clearcoat_strength = (*input).clearcoat_strength;
@@ -6,6 +6,7 @@ struct StandardMaterial {
     base_color: vec4<f32>,
     emissive: vec4<f32>,
     attenuation_color: vec4<f32>,
+    sheen_color: vec4<f32>,
     uv_transform: mat3x3<f32>,
     perceptual_roughness: f32,
     metallic: f32,
@@ -17,6 +18,10 @@ struct StandardMaterial {
     attenuation_distance: f32,
     clearcoat: f32,
     clearcoat_perceptual_roughness: f32,
+    sheen_perceptual_roughness: f32,
+    iridescence: f32,
+    iridescence_ior: f32,
+    iridescence_thickness: f32,
     anisotropy_strength: f32,
     anisotropy_rotation: vec2<f32>,
     // 'flags' is a bit field indicating various options. u32 is 32 bits so we have up to 32 options.
@@ -82,6 +87,11 @@ fn standard_material_new() -> StandardMaterial {
     material.attenuation_color = vec4<f32>(1.0, 1.0, 1.0, 1.0);
     material.clearcoat = 0.0;
     material.clearcoat_perceptual_roughness = 0.0;
+    material.sheen_color = vec4<f32>(0.0, 0.0, 0.0, 1.0);
+    material.sheen_perceptual_roughness = 0.0;
+    material.iridescence = 0.0;
+    material.iridescence_ior = 1.3;
+    material.iridescence_thickness = 400.0;
     material.flags = STANDARD_MATERIAL_FLAGS_ALPHA_MODE_OPAQUE;
     material.alpha_cutoff = 0.5;
     material.parallax_depth_scale = 0.1;
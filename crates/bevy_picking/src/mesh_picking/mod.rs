@@ -19,7 +19,10 @@ use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_reflect::prelude::*;
 use bevy_render::{prelude::*, view::RenderLayers};
-use ray_cast::{MeshRayCast, MeshRayCastSettings, RayCastVisibility, SimplifiedMesh};
+use ray_cast::{
+    update_mesh_bvh_cache, MeshBvhCache, MeshRayCast, MeshRayCastSettings, RayCastVisibility,
+    SimplifiedMesh,
+};
 
 /// Runtime settings for the [`MeshPickingPlugin`].
 #[derive(Resource, Reflect)]
@@ -62,8 +65,14 @@ pub struct MeshPickingPlugin;
 impl Plugin for MeshPickingPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MeshPickingSettings>()
+            .init_resource::<MeshBvhCache>()
             .register_type::<(RayCastPickable, MeshPickingSettings, SimplifiedMesh)>()
-            .add_systems(PreUpdate, update_hits.in_set(PickSet::Backend));
+            .add_systems(
+                PreUpdate,
+                (update_mesh_bvh_cache, update_hits)
+                    .chain()
+                    .in_set(PickSet::Backend),
+            );
     }
 }
 
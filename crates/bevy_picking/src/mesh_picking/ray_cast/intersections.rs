@@ -1,4 +1,7 @@
-use bevy_math::{bounding::Aabb3d, Dir3, Mat4, Ray3d, Vec3, Vec3A};
+use bevy_math::{
+    bounding::{Aabb3d, Bvh3d, RayCast3d},
+    Dir3, Mat4, Ray3d, Vec3, Vec3A,
+};
 use bevy_reflect::Reflect;
 use bevy_render::mesh::{Indices, Mesh, PrimitiveTopology};
 
@@ -29,11 +32,14 @@ pub struct RayTriangleHit {
 }
 
 /// Casts a ray on a mesh, and returns the intersection.
+///
+/// If given, `bvh` must be the [`mesh_triangle_bvh`] of the mesh.
 pub(super) fn ray_intersection_over_mesh(
     mesh: &Mesh,
     transform: &Mat4,
     ray: Ray3d,
     culling: Backfaces,
+    bvh: Option<&Bvh3d>,
 ) -> Option<RayMeshHit> {
     if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
         return None; // ray_mesh_intersection assumes vertices are laid out in a triangle list
@@ -47,14 +53,57 @@ pub(super) fn ray_intersection_over_mesh(
         .and_then(|normal_values| normal_values.as_float3());
 
     match mesh.indices() {
-        Some(Indices::U16(indices)) => {
-            ray_mesh_intersection(ray, transform, positions, normals, Some(indices), culling)
+        Some(Indices::U16(indices)) => mesh_intersection(
+            ray,
+            transform,
+            positions,
+            normals,
+            Some(indices),
+            culling,
+            bvh,
+        ),
+        Some(Indices::U32(indices)) => mesh_intersection(
+            ray,
+            transform,
+            positions,
+            normals,
+            Some(indices),
+            culling,
+            bvh,
+        ),
+        None => mesh_intersection::<usize>(ray, transform, positions, normals, None, culling, bvh),
+    }
+}
+
+/// Builds a [`Bvh3d`] over the triangles of a mesh, in mesh space, to accelerate ray casts with
+/// [`ray_mesh_intersection_with_bvh`].
+///
+/// Returns `None` if the mesh isn't a triangle list with vertex positions.
+pub fn mesh_triangle_bvh(mesh: &Mesh) -> Option<Bvh3d> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return None;
+    }
+    let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION)?.as_float3()?;
+    let bounds = |[a, b, c]: [usize; 3]| {
+        let [a, b, c] = [a, b, c].map(|vertex| Vec3A::from(positions[vertex]));
+        Aabb3d {
+            min: a.min(b).min(c),
+            max: a.max(b).max(c),
         }
-        Some(Indices::U32(indices)) => {
-            ray_mesh_intersection(ray, transform, positions, normals, Some(indices), culling)
+    };
+    let triangles: Vec<Aabb3d> = match mesh.indices() {
+        Some(indices) => {
+            let indices: Vec<usize> = indices.iter().collect();
+            indices
+                .chunks_exact(3)
+                .map(|triangle| bounds([triangle[0], triangle[1], triangle[2]]))
+                .collect()
         }
-        None => ray_mesh_intersection::<usize>(ray, transform, positions, normals, None, culling),
-    }
+        None => (0..positions.len() / 3)
+            .map(|triangle| bounds([3 * triangle, 3 * triangle + 1, 3 * triangle + 2]))
+            .collect(),
+    };
+    Some(Bvh3d::new(&triangles))
 }
 
 /// Checks if a ray intersects a mesh, and returns the nearest intersection if one exists.
@@ -66,11 +115,50 @@ pub fn ray_mesh_intersection<I: TryInto<usize> + Clone + Copy>(
     indices: Option<&[I]>,
     backface_culling: Backfaces,
 ) -> Option<RayMeshHit> {
-    // The ray cast can hit the same mesh many times, so we need to track which hit is
-    // closest to the camera, and record that.
-    let mut closest_hit_distance = f32::MAX;
-    let mut closest_hit = None;
+    mesh_intersection(
+        ray,
+        mesh_transform,
+        positions,
+        vertex_normals,
+        indices,
+        backface_culling,
+        None,
+    )
+}
+
+/// Checks if a ray intersects a mesh, and returns the nearest intersection if one exists, only
+/// testing the triangles whose bounds in `bvh` are hit by the ray.
+///
+/// `bvh` must be the [`mesh_triangle_bvh`] of the mesh.
+pub fn ray_mesh_intersection_with_bvh<I: TryInto<usize> + Clone + Copy>(
+    ray: Ray3d,
+    mesh_transform: &Mat4,
+    positions: &[[f32; 3]],
+    vertex_normals: Option<&[[f32; 3]]>,
+    indices: Option<&[I]>,
+    backface_culling: Backfaces,
+    bvh: &Bvh3d,
+) -> Option<RayMeshHit> {
+    mesh_intersection(
+        ray,
+        mesh_transform,
+        positions,
+        vertex_normals,
+        indices,
+        backface_culling,
+        Some(bvh),
+    )
+}
 
+fn mesh_intersection<I: TryInto<usize> + Clone + Copy>(
+    ray: Ray3d,
+    mesh_transform: &Mat4,
+    positions: &[[f32; 3]],
+    vertex_normals: Option<&[[f32; 3]]>,
+    indices: Option<&[I]>,
+    backface_culling: Backfaces,
+    bvh: Option<&Bvh3d>,
+) -> Option<RayMeshHit> {
     let world_to_mesh = mesh_transform.inverse();
 
     let mesh_space_ray = Ray3d::new(
@@ -78,108 +166,81 @@ pub fn ray_mesh_intersection<I: TryInto<usize> + Clone + Copy>(
         Dir3::new(world_to_mesh.transform_vector3(*ray.direction)).ok()?,
     );
 
-    if let Some(indices) = indices {
-        // The index list must be a multiple of three. If not, the mesh is malformed and the raycast
-        // result might be nonsensical.
-        if indices.len() % 3 != 0 {
-            return None;
-        }
+    // The index list must be a multiple of three. If not, the mesh is malformed and the raycast
+    // result might be nonsensical.
+    if indices.is_some_and(|indices| indices.len() % 3 != 0) {
+        return None;
+    }
 
-        for triangle in indices.chunks_exact(3) {
-            let [a, b, c] = [
-                triangle[0].try_into().ok()?,
-                triangle[1].try_into().ok()?,
-                triangle[2].try_into().ok()?,
-            ];
-
-            let triangle_index = Some(a);
-            let tri_vertex_positions = &[
-                Vec3::from(positions[a]),
-                Vec3::from(positions[b]),
-                Vec3::from(positions[c]),
-            ];
-            let tri_normals = vertex_normals.map(|normals| {
-                [
-                    Vec3::from(normals[a]),
-                    Vec3::from(normals[b]),
-                    Vec3::from(normals[c]),
-                ]
-            });
+    // Tests the triangle at `index` for a hit closer than `max_distance`, in mesh space.
+    let test_triangle = |index: usize, max_distance: f32| {
+        let (vertices, triangle_index) = match indices {
+            Some(indices) => {
+                let vertices = [
+                    indices[3 * index].try_into().ok()?,
+                    indices[3 * index + 1].try_into().ok()?,
+                    indices[3 * index + 2].try_into().ok()?,
+                ];
+                (vertices, vertices[0])
+            }
+            None => ([3 * index, 3 * index + 1, 3 * index + 2], index),
+        };
+        let tri_vertex_positions = &vertices.map(|vertex| Vec3::from(positions[vertex]));
+        let tri_normals =
+            vertex_normals.map(|normals| vertices.map(|vertex| Vec3::from(normals[vertex])));
+
+        let mut hit = triangle_intersection(
+            tri_vertex_positions,
+            tri_normals.as_ref(),
+            max_distance,
+            &mesh_space_ray,
+            backface_culling,
+        )?;
+        hit.triangle_index = Some(triangle_index);
+        Some(hit)
+    };
 
-            let Some(hit) = triangle_intersection(
-                tri_vertex_positions,
-                tri_normals.as_ref(),
-                closest_hit_distance,
-                &mesh_space_ray,
-                backface_culling,
-            ) else {
-                continue;
-            };
-
-            closest_hit = Some(RayMeshHit {
-                point: mesh_transform.transform_point3(hit.point),
-                normal: mesh_transform.transform_vector3(hit.normal),
-                barycentric_coords: hit.barycentric_coords,
-                distance: mesh_transform
-                    .transform_vector3(mesh_space_ray.direction * hit.distance)
-                    .length(),
-                triangle: hit.triangle.map(|tri| {
-                    [
-                        mesh_transform.transform_point3(tri[0]),
-                        mesh_transform.transform_point3(tri[1]),
-                        mesh_transform.transform_point3(tri[2]),
-                    ]
-                }),
-                triangle_index,
+    // The ray cast can hit the same mesh many times, so we need to track which hit is
+    // closest to the camera, and record that.
+    let mut closest_hit = None;
+    match bvh {
+        Some(bvh) => {
+            let ray = RayCast3d::from_ray(mesh_space_ray, f32::MAX);
+            bvh.ray_cast(&ray, |index, ray| {
+                let hit = test_triangle(index, ray.max)?;
+                let distance = hit.distance;
+                closest_hit = Some(hit);
+                Some(distance)
             });
-            closest_hit_distance = hit.distance;
         }
-    } else {
-        for (i, triangle) in positions.chunks_exact(3).enumerate() {
-            let &[a, b, c] = triangle else {
-                continue;
-            };
-            let triangle_index = Some(i);
-            let tri_vertex_positions = &[Vec3::from(a), Vec3::from(b), Vec3::from(c)];
-            let tri_normals = vertex_normals.map(|normals| {
-                [
-                    Vec3::from(normals[i]),
-                    Vec3::from(normals[i + 1]),
-                    Vec3::from(normals[i + 2]),
-                ]
-            });
-
-            let Some(hit) = triangle_intersection(
-                tri_vertex_positions,
-                tri_normals.as_ref(),
-                closest_hit_distance,
-                &mesh_space_ray,
-                backface_culling,
-            ) else {
-                continue;
-            };
-
-            closest_hit = Some(RayMeshHit {
-                point: mesh_transform.transform_point3(hit.point),
-                normal: mesh_transform.transform_vector3(hit.normal),
-                barycentric_coords: hit.barycentric_coords,
-                distance: mesh_transform
-                    .transform_vector3(mesh_space_ray.direction * hit.distance)
-                    .length(),
-                triangle: hit.triangle.map(|tri| {
-                    [
-                        mesh_transform.transform_point3(tri[0]),
-                        mesh_transform.transform_point3(tri[1]),
-                        mesh_transform.transform_point3(tri[2]),
-                    ]
-                }),
-                triangle_index,
-            });
-            closest_hit_distance = hit.distance;
+        None => {
+            let triangle_count = indices.map_or(positions.len() / 3, |indices| indices.len() / 3);
+            let mut closest_hit_distance = f32::MAX;
+            for index in 0..triangle_count {
+                if let Some(hit) = test_triangle(index, closest_hit_distance) {
+                    closest_hit_distance = hit.distance;
+                    closest_hit = Some(hit);
+                }
+            }
         }
     }
 
-    closest_hit
+    closest_hit.map(|hit| RayMeshHit {
+        point: mesh_transform.transform_point3(hit.point),
+        normal: mesh_transform.transform_vector3(hit.normal),
+        barycentric_coords: hit.barycentric_coords,
+        distance: mesh_transform
+            .transform_vector3(mesh_space_ray.direction * hit.distance)
+            .length(),
+        triangle: hit.triangle.map(|tri| {
+            [
+                mesh_transform.transform_point3(tri[0]),
+                mesh_transform.transform_point3(tri[1]),
+                mesh_transform.transform_point3(tri[2]),
+            ]
+        }),
+        triangle_index: hit.triangle_index,
+    })
 }
 
 fn triangle_intersection(
@@ -336,4 +397,26 @@ mod tests {
         let result = ray_triangle_intersection(&ray, &triangle, Backfaces::Cull);
         assert!(result.is_none());
     }
+
+    #[test]
+    fn ray_cast_mesh_with_bvh() {
+        use bevy_math::primitives::Sphere;
+        use bevy_render::mesh::Meshable;
+
+        let mesh = Sphere::new(1.0).mesh().ico(4).unwrap();
+        let bvh = mesh_triangle_bvh(&mesh).unwrap();
+        let transform = Mat4::from_translation(Vec3::new(0.0, 0.0, -5.0));
+        for (x, y) in [(0.0, 0.0), (0.3, -0.5), (0.7, 0.7), (1.5, 0.0)] {
+            let ray = Ray3d::new(Vec3::new(x, y, 0.0), Dir3::NEG_Z);
+            let brute_force =
+                ray_intersection_over_mesh(&mesh, &transform, ray, Backfaces::Cull, None);
+            let accelerated =
+                ray_intersection_over_mesh(&mesh, &transform, ray, Backfaces::Cull, Some(&bvh));
+            assert_eq!(brute_force.is_some(), accelerated.is_some());
+            if let (Some(brute_force), Some(accelerated)) = (brute_force, accelerated) {
+                assert_eq!(brute_force.distance, accelerated.distance);
+                assert_eq!(brute_force.triangle_index, accelerated.triangle_index);
+            }
+        }
+    }
 }
@@ -6,18 +6,25 @@ mod intersections;
 
 use bevy_derive::{Deref, DerefMut};
 
-use bevy_math::{bounding::Aabb3d, Ray3d};
+use bevy_math::{
+    bounding::{Aabb3d, Bvh3d},
+    Ray3d,
+};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_render::mesh::Mesh;
 
 use intersections::*;
-pub use intersections::{ray_aabb_intersection_3d, ray_mesh_intersection, RayMeshHit};
+pub use intersections::{
+    mesh_triangle_bvh, ray_aabb_intersection_3d, ray_mesh_intersection,
+    ray_mesh_intersection_with_bvh, RayMeshHit,
+};
 
-use bevy_asset::{Assets, Handle};
+use bevy_asset::{AssetEvent, AssetId, Assets, Handle};
 use bevy_ecs::{prelude::*, system::lifetimeless::Read, system::SystemParam};
 use bevy_math::FloatOrd;
 use bevy_render::{prelude::*, primitives::Aabb};
 use bevy_transform::components::GlobalTransform;
+use bevy_utils::HashMap;
 use tracing::*;
 
 /// How a ray cast should handle [`Visibility`].
@@ -110,6 +117,81 @@ pub struct RayCastBackfaces;
 #[reflect(Component, Debug)]
 pub struct SimplifiedMesh(pub Handle<Mesh>);
 
+/// Bounding volume hierarchies over the triangles of meshes, used by [`MeshRayCast`] to only test
+/// the triangles near a ray instead of all of them.
+///
+/// The [`MeshPickingPlugin`](super::MeshPickingPlugin) keeps this resource up to date for all
+/// meshes with at least [`MeshBvhCache::MIN_TRIANGLES`] triangles, through
+/// [`update_mesh_bvh_cache`]. Without it, every triangle of a mesh is tested.
+#[derive(Resource, Default)]
+pub struct MeshBvhCache {
+    bvhs: HashMap<AssetId<Mesh>, Bvh3d>,
+}
+
+impl MeshBvhCache {
+    /// The number of triangles from which hierarchies are built by [`update_mesh_bvh_cache`], as
+    /// testing a few triangles is faster than traversing a hierarchy.
+    pub const MIN_TRIANGLES: usize = 64;
+
+    /// Returns the hierarchy over the triangles of a mesh, if one was built.
+    pub fn get(&self, id: impl Into<AssetId<Mesh>>) -> Option<&Bvh3d> {
+        self.bvhs.get(&id.into())
+    }
+
+    /// Builds the hierarchy over the triangles of `mesh`, whatever its number of triangles,
+    /// replacing any previous one. Returns `false` if the mesh can't be ray cast.
+    pub fn insert(&mut self, id: impl Into<AssetId<Mesh>>, mesh: &Mesh) -> bool {
+        let id = id.into();
+        match mesh_triangle_bvh(mesh) {
+            Some(bvh) => {
+                self.bvhs.insert(id, bvh);
+                true
+            }
+            None => {
+                self.bvhs.remove(&id);
+                false
+            }
+        }
+    }
+
+    /// Removes the hierarchy over the triangles of a mesh.
+    pub fn remove(&mut self, id: impl Into<AssetId<Mesh>>) -> Option<Bvh3d> {
+        self.bvhs.remove(&id.into())
+    }
+}
+
+/// Builds the [`MeshBvhCache`] hierarchies of meshes with enough triangles when they are added or
+/// modified, and removes those of removed meshes.
+pub fn update_mesh_bvh_cache(
+    mut events: EventReader<AssetEvent<Mesh>>,
+    meshes: Res<Assets<Mesh>>,
+    mut cache: ResMut<MeshBvhCache>,
+) {
+    for event in events.read() {
+        match *event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => match meshes.get(id) {
+                Some(mesh) if triangle_count(mesh) >= MeshBvhCache::MIN_TRIANGLES => {
+                    cache.insert(id, mesh);
+                }
+                _ => {
+                    cache.remove(id);
+                }
+            },
+            AssetEvent::Removed { id } | AssetEvent::Unused { id } => {
+                cache.remove(id);
+            }
+            AssetEvent::LoadedWithDependencies { .. } => {}
+        }
+    }
+}
+
+fn triangle_count(mesh: &Mesh) -> usize {
+    match mesh.indices() {
+        Some(indices) => indices.len() / 3,
+        None => mesh.count_vertices() / 3,
+    }
+}
+
 type MeshFilter = Or<(With<Mesh3d>, With<Mesh2d>, With<SimplifiedMesh>)>;
 
 /// Add this ray casting [`SystemParam`] to your system to cast rays into the world with an
@@ -170,6 +252,8 @@ pub struct MeshRayCast<'w, 's> {
     #[doc(hidden)]
     pub meshes: Res<'w, Assets<Mesh>>,
     #[doc(hidden)]
+    pub bvhs: Option<Res<'w, MeshBvhCache>>,
+    #[doc(hidden)]
     pub hits: Local<'s, Vec<(FloatOrd, (Entity, RayMeshHit))>>,
     #[doc(hidden)]
     pub output: Local<'s, Vec<(Entity, RayMeshHit)>>,
@@ -287,7 +371,9 @@ impl<'w, 's> MeshRayCast<'w, 's> {
                 // Perform the actual ray cast.
                 let _ray_cast_guard = ray_cast_guard.enter();
                 let transform = transform.compute_matrix();
-                let intersection = ray_intersection_over_mesh(mesh, &transform, ray, backfaces);
+                let bvh = self.bvhs.as_ref().and_then(|bvhs| bvhs.get(mesh_handle));
+                let intersection =
+                    ray_intersection_over_mesh(mesh, &transform, ray, backfaces, bvh);
 
                 if let Some(intersection) = intersection {
                     let distance = FloatOrd(intersection.distance);
@@ -365,6 +365,13 @@ impl<'a> ReflectDerive<'a> {
                 |(declaration_index, field)| -> Result<StructField, syn::Error> {
                     let attrs = FieldAttributes::parse_attributes(&field.attrs)?;
 
+                    if let (Some(rename), None) = (&attrs.rename, &field.ident) {
+                        return Err(syn::Error::new(
+                            rename.span(),
+                            "only named fields can be renamed",
+                        ));
+                    }
+
                     let reflection_index = if attrs.ignore.is_ignored() {
                         None
                     } else {
@@ -492,10 +499,21 @@ impl<'a> ReflectMeta<'a> {
 }
 
 impl<'a> StructField<'a> {
+    /// The name of this field as seen by the reflection API.
+    ///
+    /// This is the identifier of the field, unless it was renamed with `#[reflect(rename = "...")]`.
+    /// Returns `None` for unnamed fields.
+    pub fn reflected_name(&self) -> Option<String> {
+        match &self.attrs.rename {
+            Some(rename) => Some(rename.value()),
+            None => self.data.ident.as_ref().map(ToString::to_string),
+        }
+    }
+
     /// Generates a `TokenStream` for `NamedField` or `UnnamedField` construction.
     pub fn to_info_tokens(&self, bevy_reflect_path: &Path) -> proc_macro2::TokenStream {
-        let name = match &self.data.ident {
-            Some(ident) => ident.to_string().to_token_stream(),
+        let name = match self.reflected_name() {
+            Some(name) => name.to_token_stream(),
             None => self.reflection_index.to_token_stream(),
         };
 
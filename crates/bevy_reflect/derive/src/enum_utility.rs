@@ -43,11 +43,8 @@ pub(crate) trait VariantBuilder: Sized {
     /// * `this`: The identifier of the enum
     /// * `field`: The field to access
     fn access_field(&self, this: &Ident, field: VariantField) -> TokenStream {
-        match &field.field.data.ident {
-            Some(field_ident) => {
-                let name = field_ident.to_string();
-                quote!(#this.field(#name))
-            }
+        match field.field.reflected_name() {
+            Some(name) => quote!(#this.field(#name)),
             None => {
                 if let Some(field_index) = field.field.reflection_index {
                     quote!(#this.field_at(#field_index))
@@ -246,10 +243,9 @@ impl<'a> VariantBuilder for TryApplyVariantBuilder<'a> {
 
         let bevy_reflect_path = self.reflect_enum.meta().bevy_reflect_path();
 
-        let field_name = match &field.data.ident {
-            Some(ident) => format!("{ident}"),
-            None => format!(".{}", field.declaration_index),
-        };
+        let field_name = field
+            .reflected_name()
+            .unwrap_or_else(|| format!(".{}", field.declaration_index));
 
         quote! {
             #alias.ok_or(#bevy_reflect_path::ApplyError::MissingEnumField {
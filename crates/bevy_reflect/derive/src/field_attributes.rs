@@ -16,6 +16,7 @@ mod kw {
     syn::custom_keyword!(skip_serializing);
     syn::custom_keyword!(default);
     syn::custom_keyword!(remote);
+    syn::custom_keyword!(rename);
 }
 
 pub(crate) const IGNORE_SERIALIZATION_ATTR: &str = "skip_serializing";
@@ -80,6 +81,8 @@ pub(crate) struct FieldAttributes {
     pub custom_attributes: CustomAttributes,
     /// For defining the remote wrapper type that should be used in place of the field for reflection logic.
     pub remote: Option<Type>,
+    /// The name of the field as seen by the reflection API, if it differs from its identifier.
+    pub rename: Option<LitStr>,
 }
 
 impl FieldAttributes {
@@ -125,6 +128,8 @@ impl FieldAttributes {
             self.parse_default(input)
         } else if lookahead.peek(kw::remote) {
             self.parse_remote(input)
+        } else if lookahead.peek(kw::rename) {
+            self.parse_rename(input)
         } else {
             Err(lookahead.error())
         }
@@ -217,6 +222,23 @@ impl FieldAttributes {
         Ok(())
     }
 
+    /// Parse `rename` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(rename = "new_name")]`
+    fn parse_rename(&mut self, input: ParseStream) -> syn::Result<()> {
+        if let Some(rename) = self.rename.as_ref() {
+            return Err(input.error(format!("field already renamed to {:?}", rename.value())));
+        }
+
+        input.parse::<kw::rename>()?;
+        input.parse::<Token![=]>()?;
+
+        self.rename = Some(input.parse()?);
+
+        Ok(())
+    }
+
     /// Returns `Some(true)` if the field has a generic remote type.
     ///
     /// If the remote type is not generic, returns `Some(false)`.
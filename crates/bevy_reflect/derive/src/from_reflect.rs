@@ -1,6 +1,6 @@
 use crate::{
     container_attributes::REFLECT_DEFAULT,
-    derive_data::ReflectEnum,
+    derive_data::{ReflectEnum, StructField},
     enum_utility::{EnumVariantOutputData, FromReflectVariantBuilder, VariantBuilder},
     field_attributes::DefaultBehavior,
     ident::ident_or_index,
@@ -10,7 +10,7 @@ use crate::{
 use bevy_macro_utils::fq_std::{FQClone, FQDefault, FQOption};
 use proc_macro2::Span;
 use quote::{quote, ToTokens};
-use syn::{Field, Ident, Lit, LitInt, LitStr, Member};
+use syn::{Ident, Lit, LitInt, LitStr, Member};
 
 /// Implements `FromReflect` for the given struct
 pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenStream {
@@ -231,7 +231,7 @@ fn get_active_fields(
             .map(|field| {
                 let member = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
                 let accessor = get_field_accessor(
-                    field.data,
+                    field,
                     field.reflection_index.expect("field should be active"),
                     is_tuple,
                 );
@@ -317,14 +317,11 @@ fn get_active_fields(
 ///
 /// This differs from a member in that it needs to be a number for tuple structs
 /// and a string for standard structs.
-fn get_field_accessor(field: &Field, index: usize, is_tuple: bool) -> Lit {
+fn get_field_accessor(field: &StructField, index: usize, is_tuple: bool) -> Lit {
     if is_tuple {
         Lit::Int(LitInt::new(&index.to_string(), Span::call_site()))
     } else {
-        field
-            .ident
-            .as_ref()
-            .map(|ident| Lit::Str(LitStr::new(&ident.to_string(), Span::call_site())))
-            .unwrap_or_else(|| Lit::Str(LitStr::new(&index.to_string(), Span::call_site())))
+        let name = field.reflected_name().unwrap_or_else(|| index.to_string());
+        Lit::Str(LitStr::new(&name, Span::call_site()))
     }
 }
@@ -390,7 +390,7 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
             EnumVariantFields::Named(fields) => {
                 let field_len = process_fields(fields, |field: &StructField| {
                     let field_ident = field.data.ident.as_ref().unwrap();
-                    let field_name = field_ident.to_string();
+                    let field_name = field.reflected_name().unwrap();
                     let reflection_index = field
                         .reflection_index
                         .expect("reflection index should exist for active field");
@@ -9,6 +9,14 @@ pub(crate) fn impl_from_arg(
     let bevy_reflect = meta.bevy_reflect_path();
     let type_path = meta.type_path();
 
+    // Types implementing `FromReflect` can also be created from values that only represent them,
+    // such as a `DynamicStruct` or `DynamicList`
+    let take_owned = if meta.from_reflect().should_auto_derive() {
+        quote!(take_from_reflect)
+    } else {
+        quote!(take_owned)
+    };
+
     let (impl_generics, ty_generics, where_clause) = type_path.generics().split_for_impl();
     let where_reflect_clause = where_clause_options.extend_where_clause(where_clause);
 
@@ -16,7 +24,7 @@ pub(crate) fn impl_from_arg(
         impl #impl_generics #bevy_reflect::func::args::FromArg for #type_path #ty_generics #where_reflect_clause {
             type This<'from_arg> = #type_path #ty_generics;
             fn from_arg(arg: #bevy_reflect::func::args::Arg) -> #FQResult<Self::This<'_>, #bevy_reflect::func::args::ArgError> {
-                arg.take_owned()
+                arg.#take_owned()
             }
         }
 
@@ -16,18 +16,30 @@ pub(crate) fn impl_get_ownership(
             fn ownership() -> #bevy_reflect::func::args::Ownership {
                 #bevy_reflect::func::args::Ownership::Owned
             }
+
+            fn value_type() -> #bevy_reflect::Type {
+                #bevy_reflect::Type::of::<#type_path #ty_generics>()
+            }
         }
 
         impl #impl_generics #bevy_reflect::func::args::GetOwnership for &'_ #type_path #ty_generics #where_reflect_clause {
             fn ownership() -> #bevy_reflect::func::args::Ownership {
                 #bevy_reflect::func::args::Ownership::Ref
             }
+
+            fn value_type() -> #bevy_reflect::Type {
+                #bevy_reflect::Type::of::<#type_path #ty_generics>()
+            }
         }
 
         impl #impl_generics #bevy_reflect::func::args::GetOwnership for &'_ mut #type_path #ty_generics #where_reflect_clause {
             fn ownership() -> #bevy_reflect::func::args::Ownership {
                 #bevy_reflect::func::args::Ownership::Mut
             }
+
+            fn value_type() -> #bevy_reflect::Type {
+                #bevy_reflect::Type::of::<#type_path #ty_generics>()
+            }
         }
     }
 }
@@ -17,10 +17,7 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
         .active_fields()
         .map(|field| {
             field
-                .data
-                .ident
-                .as_ref()
-                .map(ToString::to_string)
+                .reflected_name()
                 .unwrap_or_else(|| field.declaration_index.to_string())
         })
         .collect::<Vec<String>>();
@@ -339,6 +339,24 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// What this does is register the `SerializationData` type within the `GetTypeRegistration` implementation,
 /// which will be used by the reflection serializers to determine whether or not the field is serializable.
 ///
+/// ## `#[reflect(rename = "...")]`
+///
+/// This attribute changes the name a named field is given by the reflection API,
+/// including its `TypeInfo`, field access by name, and serialization.
+///
+/// This can be useful when the name of a field in Rust isn't the name that should be exposed
+/// to scenes and tools, such as when mirroring the fields of a [remote type].
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// struct Player {
+///   #[reflect(rename = "health")]
+///   hp: u32,
+/// }
+/// ```
+///
+/// [remote type]: macro@reflect_remote
+///
 /// ## `#[reflect(@...)]`
 ///
 /// This attribute can be used to register custom attributes to the field's `TypeInfo`.
@@ -517,6 +535,49 @@ pub fn derive_type_path(input: TokenStream) -> TokenStream {
 /// assert_eq!("Hello, World!", reflected_my_trait.print());
 /// ```
 ///
+/// # Boxed Trait Objects
+///
+/// Traits with a `Reflect` supertrait can be marked with `#[reflect_trait(boxed)]`
+/// to also make `Box<dyn MyTrait>` reflectable.
+/// This allows trait objects to be stored in the fields of reflected types,
+/// where they can be inspected, serialized, and deserialized like any other value.
+///
+/// Values are converted back to trait objects using the constructors added by each
+/// implementor's `ReflectMyTrait` type data, so implementors must also implement `FromReflect`,
+/// and should be registered in the type registry before any conversion takes place.
+///
+/// ```ignore (bevy_reflect is not accessible from this crate)
+/// #[reflect_trait(boxed)]
+/// trait Shape: Reflect {
+///   fn area(&self) -> f32;
+/// }
+///
+/// #[derive(Reflect)]
+/// #[reflect(Shape)]
+/// struct Square(f32);
+///
+/// impl Shape for Square {
+///   fn area(&self) -> f32 {
+///     self.0 * self.0
+///   }
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Drawing {
+///   shapes: Vec<Box<dyn Shape>>,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Drawing>();
+/// registry.register::<Square>();
+///
+/// let drawing = Drawing { shapes: vec![Box::new(Square(2.0))] };
+/// assert_eq!(drawing.path::<f32>("shapes[0].0"), Ok(&2.0));
+///
+/// // Also converts the shapes back into trait objects
+/// let drawing = Drawing::from_reflect(&drawing).unwrap();
+/// ```
+///
 /// [object-safe]: https://doc.rust-lang.org/reference/items/traits.html#object-safety
 #[proc_macro_attribute]
 pub fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
@@ -541,6 +602,12 @@ pub fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
 /// Generics and lifetimes do _not_ need to have the same names, however, they _do_ need to follow the same order.
 /// Additionally, whether generics are inlined or placed in a where clause should not matter.
 ///
+/// While the names of the fields need to match, the names exposed to reflection don't:
+/// fields can be given a different name with `#[reflect(rename = "...")]`,
+/// and skipped entirely with `#[reflect(ignore)]`, just like when deriving `Reflect`.
+/// Since the wrapper is created from an existing value of the remote type,
+/// ignored fields are still stored, but are defaulted when created with `FromReflect`.
+///
 /// Lastly, all macros and doc-comments should be placed __below__ this attribute.
 /// If they are placed above, they will not be properly passed to the generated wrapper type.
 ///
@@ -4,7 +4,11 @@ use bevy_macro_utils::{
 };
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse::Parse, parse_macro_input, Attribute, ItemTrait, Token};
+use syn::{parse::Parse, parse_macro_input, spanned::Spanned, Attribute, ItemTrait, Token};
+
+mod kw {
+    syn::custom_keyword!(boxed);
+}
 
 pub(crate) struct TraitInfo {
     item_trait: ItemTrait,
@@ -24,11 +28,41 @@ impl Parse for TraitInfo {
     }
 }
 
+/// The arguments of the `#[reflect_trait]` attribute.
+#[derive(Default)]
+struct TraitArgs {
+    /// Whether `Box<dyn MyTrait>` should be reflectable, as requested with `#[reflect_trait(boxed)]`.
+    boxed: bool,
+}
+
+impl Parse for TraitArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = Self::default();
+        if input.is_empty() {
+            return Ok(args);
+        }
+
+        input.parse::<kw::boxed>()?;
+        args.boxed = true;
+        if !input.is_empty() {
+            return Err(input.error("expected end of arguments"));
+        }
+
+        Ok(args)
+    }
+}
+
 /// A trait attribute macro that allows a reflected type to be downcast to a trait object.
 ///
 /// This generates a struct that takes the form `ReflectMyTrait`. An instance of this struct can then be
 /// used to perform the conversion.
-pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStream {
+///
+/// With `#[reflect_trait(boxed)]`, `Box<dyn MyTrait>` is made reflectable as well.
+pub(crate) fn reflect_trait(args: &TokenStream, input: TokenStream) -> TokenStream {
+    let args = match syn::parse::<TraitArgs>(args.clone()) {
+        Ok(args) => args,
+        Err(err) => return err.into_compile_error().into(),
+    };
     let trait_info = parse_macro_input!(input as TraitInfo);
     let item_trait = &trait_info.item_trait;
     let trait_ident = &item_trait.ident;
@@ -49,6 +83,72 @@ pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStr
         " Downcast a `Box<dyn Reflect>` type to `Box<dyn {trait_ident}>`.\n\n If the type cannot be downcast, this will return `Err(Box<dyn Reflect>)`.",
     );
 
+    let (from_type_bounds, register_constructor, boxed_impls) = if args.boxed {
+        if !item_trait.generics.params.is_empty() {
+            return syn::Error::new(
+                item_trait.generics.span(),
+                "`#[reflect_trait(boxed)]` does not support generic traits",
+            )
+            .into_compile_error()
+            .into();
+        }
+
+        let trait_name = trait_ident.to_string();
+        let short_type_path = format!("dyn {trait_ident}");
+        let from_reflect_doc = format!(
+            " Convert a reflected value into a `Box<dyn {trait_ident}>`, using the constructor of the type it represents.\n\n If the type did not register `{reflect_trait_ident}`, or the value cannot be converted to it, `None` is returned.",
+        );
+        let boxed_impls = quote! {
+            impl #reflect_trait_ident {
+                #[doc = #from_reflect_doc]
+                pub fn from_reflect(
+                    reflect_value: &dyn #bevy_reflect_path::PartialReflect,
+                ) -> #FQOption<#bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #trait_ident>> {
+                    <dyn #trait_ident as #bevy_reflect_path::ReflectTraitObject>::constructors()
+                        .from_reflect(reflect_value)
+                }
+            }
+
+            impl #bevy_reflect_path::TypePath for dyn #trait_ident {
+                fn type_path() -> &'static str {
+                    ::core::concat!("dyn ", ::core::module_path!(), "::", #trait_name)
+                }
+
+                fn short_type_path() -> &'static str {
+                    #short_type_path
+                }
+            }
+
+            impl #bevy_reflect_path::ReflectTraitObject for dyn #trait_ident {
+                fn constructors() -> &'static #bevy_reflect_path::TraitObjectConstructors<Self> {
+                    static CONSTRUCTORS: #bevy_reflect_path::TraitObjectConstructors<dyn #trait_ident> =
+                        #bevy_reflect_path::TraitObjectConstructors::new();
+                    &CONSTRUCTORS
+                }
+            }
+        };
+        let register_constructor = quote! {
+            <dyn #trait_ident as #bevy_reflect_path::ReflectTraitObject>::constructors().insert(
+                ::core::any::TypeId::of::<T>(),
+                |reflect_value| {
+                    <T as #bevy_reflect_path::FromReflect>::from_reflect(reflect_value)
+                        .map(|value| #bevy_reflect_path::__macro_exports::alloc_utils::Box::new(value) as #bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #trait_ident>)
+                },
+            );
+        };
+        (
+            quote!(#bevy_reflect_path::FromReflect),
+            register_constructor,
+            boxed_impls,
+        )
+    } else {
+        (
+            quote!(#bevy_reflect_path::Reflect),
+            proc_macro2::TokenStream::new(),
+            proc_macro2::TokenStream::new(),
+        )
+    };
+
     TokenStream::from(quote! {
         #item_trait
 
@@ -77,8 +177,9 @@ pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStr
             }
         }
 
-        impl<T: #trait_ident + #bevy_reflect_path::Reflect> #bevy_reflect_path::FromType<T> for #reflect_trait_ident {
+        impl<T: #trait_ident + #from_type_bounds> #bevy_reflect_path::FromType<T> for #reflect_trait_ident {
             fn from_type() -> Self {
+                #register_constructor
                 Self {
                     get_func: |reflect_value| {
                         <dyn #bevy_reflect_path::Reflect>::downcast_ref::<T>(reflect_value).map(|value| value as &dyn #trait_ident)
@@ -92,5 +193,7 @@ pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStr
                 }
             }
         }
+
+        #boxed_impls
     })
 }
@@ -1,6 +1,6 @@
 use crate::Reflect;
 use alloc::boxed::Box;
-use bevy_utils::TypeIdMap;
+use bevy_utils::{NoOpHash, TypeIdMap};
 use core::{
     any::TypeId,
     fmt::{Debug, Formatter},
@@ -38,6 +38,12 @@ pub struct CustomAttributes {
     attributes: TypeIdMap<CustomAttribute>,
 }
 
+/// An empty collection of custom attributes,
+/// returned for types that can't be given custom attributes.
+pub(crate) static EMPTY_CUSTOM_ATTRIBUTES: CustomAttributes = CustomAttributes {
+    attributes: TypeIdMap::with_hasher(NoOpHash),
+};
+
 impl CustomAttributes {
     /// Inserts a custom attribute into the collection.
     ///
@@ -180,7 +186,7 @@ mod tests {
     use super::*;
     use crate as bevy_reflect;
     use crate::{type_info::Typed, TypeInfo, VariantInfo};
-    use alloc::{format, string::String};
+    use alloc::{format, string::String, vec::Vec};
     use core::ops::RangeInclusive;
 
     #[derive(Reflect, PartialEq, Debug)]
@@ -233,6 +239,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_get_custom_attributes_from_type_info() {
+        #[derive(Reflect)]
+        #[reflect(@Tooltip::new("A slider"))]
+        struct Slider {
+            value: f32,
+        }
+
+        #[derive(Reflect)]
+        #[reflect(@Tooltip::new("A color"))]
+        enum Color {
+            Red,
+        }
+
+        let info = <Slider as Typed>::type_info();
+        assert_eq!(
+            Some(&Tooltip::new("A slider")),
+            info.get_attribute::<Tooltip>()
+        );
+
+        let info = <Color as Typed>::type_info();
+        assert!(info.has_attribute::<Tooltip>());
+
+        let info = <Vec<f32> as Typed>::type_info();
+        assert!(info.custom_attributes().is_empty());
+        assert!(!info.has_attribute_by_id(TypeId::of::<Tooltip>()));
+    }
+
     #[test]
     fn should_derive_custom_attributes_on_struct_container() {
         #[derive(Reflect)]
@@ -0,0 +1,784 @@
+//! Structural diffing of reflected values.
+//!
+//! Comparing two reflected values with `PartialReflect::diff` produces a [`ReflectDiff`]:
+//! a minimal patch describing how to turn the first value into the second.
+//! Only the parts that changed are recorded, such as modified struct fields
+//! or the elements inserted into and removed from a list.
+//!
+//! The patch can then be applied to a value with `PartialReflect::apply_diff`,
+//! and serialized with a [`ReflectDiffSerializer`] and [`ReflectDiffDeserializer`],
+//! which makes it a building block for network replication, undo/redo, and scene patching.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy_reflect::{PartialReflect, Reflect};
+//! # use bevy_reflect::diff::ReflectDiff;
+//! #[derive(Reflect, Clone, Debug, PartialEq)]
+//! struct Inventory {
+//!     gold: u32,
+//!     items: Vec<String>,
+//! }
+//!
+//! let old = Inventory {
+//!     gold: 10,
+//!     items: vec!["sword".to_string(), "shield".to_string()],
+//! };
+//! let new = Inventory {
+//!     gold: 10,
+//!     items: vec!["sword".to_string(), "potion".to_string(), "shield".to_string()],
+//! };
+//!
+//! let diff = old.as_partial_reflect().diff(new.as_partial_reflect());
+//! // Only the inserted item is recorded:
+//! assert!(matches!(&diff, ReflectDiff::Struct(fields) if fields.len() == 1));
+//!
+//! let mut value = old.clone();
+//! value.as_partial_reflect_mut().apply_diff(&diff).unwrap();
+//! assert_eq!(value, new);
+//! ```
+
+mod serde;
+
+pub use self::serde::{ReflectDiffDeserializer, ReflectDiffSerializer};
+
+use alloc::{borrow::Cow, boxed::Box, string::ToString, vec, vec::Vec};
+use core::fmt::{Debug, Formatter};
+
+use thiserror::Error;
+
+use crate::{
+    ApplyError, Enum, List, Map, PartialReflect, ReflectKind, ReflectMut, ReflectRef, Set, Struct,
+    TypeInfo, VariantType,
+};
+
+/// A structural patch describing how to turn one reflected value into another.
+///
+/// Diffs are created with `PartialReflect::diff` and applied with `PartialReflect::apply_diff`.
+///
+/// See the [module-level documentation] for more information.
+///
+/// [module-level documentation]: crate::diff
+pub enum ReflectDiff {
+    /// The values are equal.
+    Unchanged,
+    /// The value was replaced entirely.
+    ///
+    /// This is used for [opaque] values, values whose type or [kind] changed,
+    /// and enums whose variant changed.
+    ///
+    /// [opaque]: ReflectKind::Opaque
+    /// [kind]: ReflectKind
+    Replaced(Box<dyn PartialReflect>),
+    /// The named fields of a struct or struct variant that changed.
+    Struct(Vec<(Cow<'static, str>, ReflectDiff)>),
+    /// The indexed fields or elements of a tuple, tuple struct, tuple variant, or array that changed.
+    Indexed(Vec<(usize, ReflectDiff)>),
+    /// The changes made to a list, in the order they should be applied.
+    List(Vec<ListChange>),
+    /// The changes made to the entries of a map.
+    Map(Vec<MapChange>),
+    /// The changes made to the values of a set.
+    Set(Vec<SetChange>),
+}
+
+/// A change made to a [`List`] as part of a [`ReflectDiff`].
+///
+/// Indices refer to the list as it is when the change is applied,
+/// after any previous changes in the same diff.
+pub enum ListChange {
+    /// A value was inserted at the given index.
+    Insert(usize, Box<dyn PartialReflect>),
+    /// The value at the given index was removed.
+    Remove(usize),
+    /// The value at the given index was modified.
+    Modify(usize, ReflectDiff),
+}
+
+/// A change made to a [`Map`] as part of a [`ReflectDiff`].
+pub enum MapChange {
+    /// An entry was inserted with the given key and value.
+    Insert(Box<dyn PartialReflect>, Box<dyn PartialReflect>),
+    /// The entry with the given key was removed.
+    Remove(Box<dyn PartialReflect>),
+    /// The value of the entry with the given key was modified.
+    Modify(Box<dyn PartialReflect>, ReflectDiff),
+}
+
+/// A change made to a [`Set`] as part of a [`ReflectDiff`].
+pub enum SetChange {
+    /// The given value was inserted.
+    Insert(Box<dyn PartialReflect>),
+    /// The given value was removed.
+    Remove(Box<dyn PartialReflect>),
+}
+
+/// An error that occurs when applying a [`ReflectDiff`] to a value it wasn't created for.
+#[derive(Error, Debug)]
+pub enum DiffApplyError {
+    /// The diff can't be applied to a value of this kind.
+    #[error("cannot apply a diff of a `{expected}` to a value of kind `{received}`")]
+    MismatchedKinds {
+        expected: ReflectKind,
+        received: ReflectKind,
+    },
+    /// The value has no field with the given name or index.
+    #[error("value has no field `{0}`")]
+    MissingField(Cow<'static, str>),
+    /// The index of a list change is out of bounds.
+    #[error("index {index} is out of bounds for a list of length {len}")]
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The map has no entry with the key of a modified entry.
+    #[error("map has no entry with key `{0}`")]
+    MissingKey(Cow<'static, str>),
+    /// A replaced value couldn't be applied.
+    #[error(transparent)]
+    Apply(#[from] ApplyError),
+}
+
+impl ReflectDiff {
+    /// Returns `true` if the diff contains no changes.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+
+    /// Returns a diff that replaces the value with a clone of `value`.
+    fn replaced(value: &dyn PartialReflect) -> Self {
+        Self::Replaced(value.clone_value())
+    }
+
+    /// Returns [`ReflectDiff::Unchanged`] if `changes` is empty, otherwise wraps them with `f`.
+    fn from_changes<T>(changes: Vec<T>, f: impl FnOnce(Vec<T>) -> Self) -> Self {
+        if changes.is_empty() {
+            Self::Unchanged
+        } else {
+            f(changes)
+        }
+    }
+}
+
+impl Clone for ReflectDiff {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Unchanged => Self::Unchanged,
+            Self::Replaced(value) => Self::Replaced(value.clone_value()),
+            Self::Struct(fields) => Self::Struct(fields.clone()),
+            Self::Indexed(fields) => Self::Indexed(fields.clone()),
+            Self::List(changes) => Self::List(changes.clone()),
+            Self::Map(changes) => Self::Map(changes.clone()),
+            Self::Set(changes) => Self::Set(changes.clone()),
+        }
+    }
+}
+
+impl Clone for ListChange {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Insert(index, value) => Self::Insert(*index, value.clone_value()),
+            Self::Remove(index) => Self::Remove(*index),
+            Self::Modify(index, diff) => Self::Modify(*index, diff.clone()),
+        }
+    }
+}
+
+impl Clone for MapChange {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Insert(key, value) => Self::Insert(key.clone_value(), value.clone_value()),
+            Self::Remove(key) => Self::Remove(key.clone_value()),
+            Self::Modify(key, diff) => Self::Modify(key.clone_value(), diff.clone()),
+        }
+    }
+}
+
+impl Clone for SetChange {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Insert(value) => Self::Insert(value.clone_value()),
+            Self::Remove(value) => Self::Remove(value.clone_value()),
+        }
+    }
+}
+
+impl Debug for ReflectDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unchanged => f.write_str("Unchanged"),
+            Self::Replaced(value) => f.debug_tuple("Replaced").field(value).finish(),
+            Self::Struct(fields) => f.debug_tuple("Struct").field(fields).finish(),
+            Self::Indexed(fields) => f.debug_tuple("Indexed").field(fields).finish(),
+            Self::List(changes) => f.debug_tuple("List").field(changes).finish(),
+            Self::Map(changes) => f.debug_tuple("Map").field(changes).finish(),
+            Self::Set(changes) => f.debug_tuple("Set").field(changes).finish(),
+        }
+    }
+}
+
+impl Debug for ListChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Insert(index, value) => {
+                f.debug_tuple("Insert").field(index).field(value).finish()
+            }
+            Self::Remove(index) => f.debug_tuple("Remove").field(index).finish(),
+            Self::Modify(index, diff) => f.debug_tuple("Modify").field(index).field(diff).finish(),
+        }
+    }
+}
+
+impl Debug for MapChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Insert(key, value) => f.debug_tuple("Insert").field(key).field(value).finish(),
+            Self::Remove(key) => f.debug_tuple("Remove").field(key).finish(),
+            Self::Modify(key, diff) => f.debug_tuple("Modify").field(key).field(diff).finish(),
+        }
+    }
+}
+
+impl Debug for SetChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Insert(value) => f.debug_tuple("Insert").field(value).finish(),
+            Self::Remove(value) => f.debug_tuple("Remove").field(value).finish(),
+        }
+    }
+}
+
+impl dyn PartialReflect {
+    /// Returns a [`ReflectDiff`] describing how to turn this value into `other`.
+    ///
+    /// Values of the same type, or dynamic values representing the same type, are compared structurally,
+    /// recording only the fields and elements that changed.
+    /// Otherwise, the diff replaces the value entirely.
+    ///
+    /// [Opaque] values are compared with `PartialReflect::reflect_partial_eq`
+    /// and are always replaced if they don't support comparison.
+    ///
+    /// See the [module-level documentation] for more information.
+    ///
+    /// [Opaque]: ReflectKind::Opaque
+    /// [module-level documentation]: crate::diff
+    pub fn diff(&self, other: &dyn PartialReflect) -> ReflectDiff {
+        let represented =
+            |value: &dyn PartialReflect| value.get_represented_type_info().map(TypeInfo::type_id);
+        if represented(self) != represented(other) {
+            return ReflectDiff::replaced(other);
+        }
+
+        match (self.reflect_ref(), other.reflect_ref()) {
+            (ReflectRef::Struct(old), ReflectRef::Struct(new)) => diff_struct(old, new),
+            (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new)) => diff_indexed(
+                old.field_len(),
+                new.field_len(),
+                |index| old.field(index),
+                |index| new.field(index),
+            )
+            .unwrap_or_else(|| ReflectDiff::replaced(other)),
+            (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) => diff_indexed(
+                old.field_len(),
+                new.field_len(),
+                |index| old.field(index),
+                |index| new.field(index),
+            )
+            .unwrap_or_else(|| ReflectDiff::replaced(other)),
+            (ReflectRef::Array(old), ReflectRef::Array(new)) => diff_indexed(
+                old.len(),
+                new.len(),
+                |index| old.get(index),
+                |index| new.get(index),
+            )
+            .unwrap_or_else(|| ReflectDiff::replaced(other)),
+            (ReflectRef::List(old), ReflectRef::List(new)) => diff_list(old, new),
+            (ReflectRef::Map(old), ReflectRef::Map(new)) => diff_map(old, new),
+            (ReflectRef::Set(old), ReflectRef::Set(new)) => diff_set(old, new),
+            (ReflectRef::Enum(old), ReflectRef::Enum(new)) => {
+                diff_enum(old, new).unwrap_or_else(|| ReflectDiff::replaced(other))
+            }
+            _ if self.reflect_partial_eq(other) == Some(true) => ReflectDiff::Unchanged,
+            _ => ReflectDiff::replaced(other),
+        }
+    }
+
+    /// Applies the given [`ReflectDiff`] to this value.
+    ///
+    /// Applying the diff of `a` and `b` to a value equal to `a` makes it equal to `b`.
+    /// Diffs can also be applied to other values of the same type,
+    /// in which case only the recorded changes are made.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the diff doesn't fit the structure of this value,
+    /// such as when a recorded field is missing or a list index is out of bounds.
+    /// Changes made before the error occurred are not undone.
+    pub fn apply_diff(&mut self, diff: &ReflectDiff) -> Result<(), DiffApplyError> {
+        match (diff, self.reflect_mut()) {
+            (ReflectDiff::Unchanged, _) => Ok(()),
+            (ReflectDiff::Replaced(value), _) => Ok(self.try_apply(value.as_ref())?),
+            (ReflectDiff::Struct(fields), ReflectMut::Struct(value)) => {
+                apply_fields(fields, |name, diff| {
+                    value.field_mut(name).map(|field| field.apply_diff(diff))
+                })
+            }
+            (ReflectDiff::Struct(fields), ReflectMut::Enum(value)) => {
+                apply_fields(fields, |name, diff| {
+                    value.field_mut(name).map(|field| field.apply_diff(diff))
+                })
+            }
+            (ReflectDiff::Indexed(fields), ReflectMut::TupleStruct(value)) => {
+                apply_indexed(fields, |index, diff| {
+                    value.field_mut(index).map(|field| field.apply_diff(diff))
+                })
+            }
+            (ReflectDiff::Indexed(fields), ReflectMut::Tuple(value)) => {
+                apply_indexed(fields, |index, diff| {
+                    value.field_mut(index).map(|field| field.apply_diff(diff))
+                })
+            }
+            (ReflectDiff::Indexed(fields), ReflectMut::Array(value)) => {
+                apply_indexed(fields, |index, diff| {
+                    value.get_mut(index).map(|field| field.apply_diff(diff))
+                })
+            }
+            (ReflectDiff::Indexed(fields), ReflectMut::Enum(value)) => {
+                apply_indexed(fields, |index, diff| {
+                    value
+                        .field_at_mut(index)
+                        .map(|field| field.apply_diff(diff))
+                })
+            }
+            (ReflectDiff::List(changes), ReflectMut::List(value)) => apply_list(changes, value),
+            (ReflectDiff::Map(changes), ReflectMut::Map(value)) => apply_map(changes, value),
+            (ReflectDiff::Set(changes), ReflectMut::Set(value)) => {
+                for change in changes {
+                    match change {
+                        SetChange::Insert(item) => value.insert_boxed(item.clone_value()),
+                        SetChange::Remove(item) => value.remove(item.as_ref()),
+                    };
+                }
+                Ok(())
+            }
+            (diff, value) => Err(DiffApplyError::MismatchedKinds {
+                expected: diff.kind(),
+                received: value.kind(),
+            }),
+        }
+    }
+}
+
+impl ReflectDiff {
+    /// The [kind] of value this diff can be applied to.
+    ///
+    /// [kind]: ReflectKind
+    fn kind(&self) -> ReflectKind {
+        match self {
+            Self::Unchanged | Self::Replaced(_) => ReflectKind::Opaque,
+            Self::Struct(_) => ReflectKind::Struct,
+            Self::Indexed(_) => ReflectKind::Tuple,
+            Self::List(_) => ReflectKind::List,
+            Self::Map(_) => ReflectKind::Map,
+            Self::Set(_) => ReflectKind::Set,
+        }
+    }
+}
+
+fn diff_struct(old: &dyn Struct, new: &dyn Struct) -> ReflectDiff {
+    let fields = (0..new.field_len())
+        .filter_map(|index| {
+            let name = new.name_at(index)?;
+            let value = new.field_at(index)?;
+            let diff = match old.field(name) {
+                Some(old_value) => old_value.diff(value),
+                None => ReflectDiff::replaced(value),
+            };
+            (!diff.is_unchanged()).then(|| (Cow::Owned(name.to_string()), diff))
+        })
+        .collect();
+    ReflectDiff::from_changes(fields, ReflectDiff::Struct)
+}
+
+/// Diffs the fields of two values accessed by index.
+///
+/// Returns `None` if the values have a different number of fields.
+fn diff_indexed<'a>(
+    old_len: usize,
+    new_len: usize,
+    old: impl Fn(usize) -> Option<&'a dyn PartialReflect>,
+    new: impl Fn(usize) -> Option<&'a dyn PartialReflect>,
+) -> Option<ReflectDiff> {
+    if old_len != new_len {
+        return None;
+    }
+
+    let fields = (0..new_len)
+        .filter_map(|index| {
+            let diff = old(index)?.diff(new(index)?);
+            (!diff.is_unchanged()).then_some((index, diff))
+        })
+        .collect();
+    Some(ReflectDiff::from_changes(fields, ReflectDiff::Indexed))
+}
+
+fn diff_enum(old: &dyn Enum, new: &dyn Enum) -> Option<ReflectDiff> {
+    if old.variant_name() != new.variant_name() || old.field_len() != new.field_len() {
+        return None;
+    }
+
+    match new.variant_type() {
+        VariantType::Unit => Some(ReflectDiff::Unchanged),
+        VariantType::Tuple => diff_indexed(
+            old.field_len(),
+            new.field_len(),
+            |index| old.field_at(index),
+            |index| new.field_at(index),
+        ),
+        VariantType::Struct => {
+            let mut fields = Vec::new();
+            for index in 0..new.field_len() {
+                let (Some(name), Some(value)) = (new.name_at(index), new.field_at(index)) else {
+                    continue;
+                };
+                let diff = old.field(name)?.diff(value);
+                if !diff.is_unchanged() {
+                    fields.push((Cow::Owned(name.to_string()), diff));
+                }
+            }
+            Some(ReflectDiff::from_changes(fields, ReflectDiff::Struct))
+        }
+    }
+}
+
+/// Diffs two lists by aligning their longest common subsequence of equal elements.
+///
+/// Unaligned elements at the same position are diffed with each other,
+/// while the remaining ones are recorded as insertions and removals.
+fn diff_list(old: &dyn List, new: &dyn List) -> ReflectDiff {
+    let old_items = old.iter().collect::<Vec<_>>();
+    let new_items = new.iter().collect::<Vec<_>>();
+    let (old_len, new_len) = (old_items.len(), new_items.len());
+
+    // `lengths[i][j]` is the length of the longest common subsequence of `old[i..]` and `new[j..]`.
+    let mut lengths = vec![vec![0_usize; new_len + 1]; old_len + 1];
+    for i in (0..old_len).rev() {
+        for j in (0..new_len).rev() {
+            lengths[i][j] = if old_items[i].reflect_partial_eq(new_items[j]) == Some(true) {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut changes = Vec::new();
+    let (mut i, mut j, mut index) = (0, 0, 0);
+    while i < old_len || j < new_len {
+        let is_common = i < old_len
+            && j < new_len
+            && lengths[i][j] == lengths[i + 1][j + 1] + 1
+            && old_items[i].reflect_partial_eq(new_items[j]) == Some(true);
+        let skips_old = i < old_len && lengths[i][j] == lengths[i + 1][j];
+        let skips_new = j < new_len && lengths[i][j] == lengths[i][j + 1];
+
+        if is_common {
+            i += 1;
+            j += 1;
+            index += 1;
+        } else if skips_old && skips_new {
+            let diff = old_items[i].diff(new_items[j]);
+            if !diff.is_unchanged() {
+                changes.push(ListChange::Modify(index, diff));
+            }
+            i += 1;
+            j += 1;
+            index += 1;
+        } else if skips_old {
+            changes.push(ListChange::Remove(index));
+            i += 1;
+        } else {
+            changes.push(ListChange::Insert(index, new_items[j].clone_value()));
+            j += 1;
+            index += 1;
+        }
+    }
+
+    ReflectDiff::from_changes(changes, ReflectDiff::List)
+}
+
+fn diff_map(old: &dyn Map, new: &dyn Map) -> ReflectDiff {
+    let mut changes = old
+        .iter()
+        .filter(|(key, _)| new.get(*key).is_none())
+        .map(|(key, _)| MapChange::Remove(key.clone_value()))
+        .collect::<Vec<_>>();
+
+    for (key, value) in new.iter() {
+        match old.get(key) {
+            Some(old_value) => {
+                let diff = old_value.diff(value);
+                if !diff.is_unchanged() {
+                    changes.push(MapChange::Modify(key.clone_value(), diff));
+                }
+            }
+            None => changes.push(MapChange::Insert(key.clone_value(), value.clone_value())),
+        }
+    }
+
+    ReflectDiff::from_changes(changes, ReflectDiff::Map)
+}
+
+fn diff_set(old: &dyn Set, new: &dyn Set) -> ReflectDiff {
+    let removed = old
+        .iter()
+        .filter(|value| !new.contains(*value))
+        .map(|value| SetChange::Remove(value.clone_value()));
+    let inserted = new
+        .iter()
+        .filter(|value| !old.contains(*value))
+        .map(|value| SetChange::Insert(value.clone_value()));
+
+    ReflectDiff::from_changes(removed.chain(inserted).collect(), ReflectDiff::Set)
+}
+
+/// Applies the diff of each named field with `apply`, which returns `None` if the field is missing.
+fn apply_fields(
+    fields: &[(Cow<'static, str>, ReflectDiff)],
+    mut apply: impl FnMut(&str, &ReflectDiff) -> Option<Result<(), DiffApplyError>>,
+) -> Result<(), DiffApplyError> {
+    for (name, diff) in fields {
+        apply(name, diff).ok_or_else(|| DiffApplyError::MissingField(name.clone()))??;
+    }
+    Ok(())
+}
+
+/// Applies the diff of each indexed field with `apply`, which returns `None` if the field is missing.
+fn apply_indexed(
+    fields: &[(usize, ReflectDiff)],
+    mut apply: impl FnMut(usize, &ReflectDiff) -> Option<Result<(), DiffApplyError>>,
+) -> Result<(), DiffApplyError> {
+    for (index, diff) in fields {
+        apply(*index, diff)
+            .ok_or_else(|| DiffApplyError::MissingField(Cow::Owned(index.to_string())))??;
+    }
+    Ok(())
+}
+
+fn apply_list(changes: &[ListChange], list: &mut dyn List) -> Result<(), DiffApplyError> {
+    for change in changes {
+        let len = list.len();
+        let out_of_bounds = |index| DiffApplyError::IndexOutOfBounds { index, len };
+
+        match change {
+            ListChange::Insert(index, value) if *index <= len => {
+                list.insert(*index, value.clone_value());
+            }
+            ListChange::Remove(index) if *index < len => {
+                list.remove(*index);
+            }
+            ListChange::Modify(index, diff) => {
+                list.get_mut(*index)
+                    .ok_or_else(|| out_of_bounds(*index))?
+                    .apply_diff(diff)?;
+            }
+            ListChange::Insert(index, _) | ListChange::Remove(index) => {
+                return Err(out_of_bounds(*index));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn apply_map(changes: &[MapChange], map: &mut dyn Map) -> Result<(), DiffApplyError> {
+    for change in changes {
+        match change {
+            MapChange::Insert(key, value) => {
+                map.insert_boxed(key.clone_value(), value.clone_value());
+            }
+            MapChange::Remove(key) => {
+                map.remove(key.as_ref());
+            }
+            MapChange::Modify(key, diff) => {
+                map.get_mut(key.as_ref())
+                    .ok_or_else(|| {
+                        DiffApplyError::MissingKey(Cow::Owned(alloc::format!("{key:?}")))
+                    })?
+                    .apply_diff(diff)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_reflect, DynamicStruct, Reflect};
+    use alloc::string::String;
+    use bevy_utils::{HashMap, HashSet};
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        health: (u32, u32),
+        position: [f32; 2],
+        inventory: Vec<Item>,
+        stats: HashMap<String, i32>,
+        tags: HashSet<String>,
+        state: State,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum State {
+        Idle,
+        Moving { speed: f32, target: [f32; 2] },
+        Attacking(u32),
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Item {
+        id: u32,
+        count: u32,
+    }
+
+    fn player() -> Player {
+        Player {
+            name: String::from("Alice"),
+            health: (80, 100),
+            position: [0.0, 0.0],
+            inventory: vec![Item { id: 1, count: 1 }, Item { id: 2, count: 5 }],
+            stats: HashMap::from_iter([(String::from("strength"), 10)]),
+            tags: HashSet::from_iter([String::from("hero")]),
+            state: State::Moving {
+                speed: 1.0,
+                target: [1.0, 1.0],
+            },
+        }
+    }
+
+    fn assert_roundtrip(old: &Player, new: &Player) -> ReflectDiff {
+        let diff = old.as_partial_reflect().diff(new.as_partial_reflect());
+        let mut value = old.clone();
+        value.as_partial_reflect_mut().apply_diff(&diff).unwrap();
+        assert_eq!(&value, new);
+        diff
+    }
+
+    #[test]
+    fn should_diff_equal_values_as_unchanged() {
+        let diff = player()
+            .as_partial_reflect()
+            .diff(player().as_partial_reflect());
+        assert!(diff.is_unchanged());
+    }
+
+    #[test]
+    fn should_only_record_changed_fields() {
+        let old = player();
+        let mut new = player();
+        new.health.0 = 50;
+        new.position[1] = 2.0;
+
+        let diff = assert_roundtrip(&old, &new);
+        let ReflectDiff::Struct(fields) = diff else {
+            panic!("expected struct diff");
+        };
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].0, "health");
+        assert!(matches!(&fields[0].1, ReflectDiff::Indexed(fields) if fields.len() == 1));
+        assert_eq!(fields[1].0, "position");
+    }
+
+    #[test]
+    fn should_diff_list_insertions_and_removals() {
+        let old = player();
+        let mut new = player();
+        new.inventory.remove(0);
+        new.inventory.push(Item { id: 3, count: 1 });
+
+        let diff = assert_roundtrip(&old, &new);
+        let ReflectDiff::Struct(fields) = diff else {
+            panic!("expected struct diff");
+        };
+        assert!(matches!(
+            &fields[0].1,
+            ReflectDiff::List(changes) if matches!(
+                changes.as_slice(),
+                [ListChange::Remove(0), ListChange::Insert(1, _)]
+            )
+        ));
+
+        let mut new = player();
+        new.inventory[1].count = 4;
+        let diff = assert_roundtrip(&old, &new);
+        let ReflectDiff::Struct(fields) = diff else {
+            panic!("expected struct diff");
+        };
+        assert!(matches!(
+            &fields[0].1,
+            ReflectDiff::List(changes) if matches!(changes.as_slice(), [ListChange::Modify(1, _)])
+        ));
+
+        let mut new = player();
+        new.inventory.insert(1, Item { id: 4, count: 2 });
+        let diff = assert_roundtrip(&old, &new);
+        let ReflectDiff::Struct(fields) = diff else {
+            panic!("expected struct diff");
+        };
+        assert!(matches!(
+            &fields[0].1,
+            ReflectDiff::List(changes) if matches!(changes.as_slice(), [ListChange::Insert(1, _)])
+        ));
+    }
+
+    #[test]
+    fn should_diff_maps_sets_and_enums() {
+        let old = player();
+        let mut new = player();
+        new.stats.insert(String::from("agility"), 3);
+        *new.stats.get_mut("strength").unwrap() = 12;
+        new.tags.remove("hero");
+        new.tags.insert(String::from("villain"));
+        new.state = State::Moving {
+            speed: 2.0,
+            target: [1.0, 1.0],
+        };
+        assert_roundtrip(&old, &new);
+
+        new.state = State::Attacking(3);
+        let diff = assert_roundtrip(&old, &new);
+        let ReflectDiff::Struct(fields) = diff else {
+            panic!("expected struct diff");
+        };
+        let (_, state) = fields.iter().find(|(name, _)| name == "state").unwrap();
+        assert!(matches!(state, ReflectDiff::Replaced(_)));
+
+        new.state = State::Idle;
+        assert_roundtrip(&old, &new);
+    }
+
+    #[test]
+    fn should_apply_diff_to_other_values() {
+        let old = player();
+        let mut new = player();
+        new.name = String::from("Bob");
+        let diff = old.as_partial_reflect().diff(new.as_partial_reflect());
+
+        let mut other = player();
+        other.health = (1, 1);
+        other.as_partial_reflect_mut().apply_diff(&diff).unwrap();
+        assert_eq!(other.name, "Bob");
+        assert_eq!(other.health, (1, 1));
+
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("health", 5_u32);
+        assert!(matches!(
+            dynamic.as_partial_reflect_mut().apply_diff(&diff),
+            Err(DiffApplyError::MissingField(name)) if name == "name"
+        ));
+
+        let mut value = 5_u32;
+        assert!(matches!(
+            value.as_partial_reflect_mut().apply_diff(&diff),
+            Err(DiffApplyError::MismatchedKinds { .. })
+        ));
+    }
+}
@@ -0,0 +1,655 @@
+use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
+use core::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+};
+
+use serde::{
+    de::{DeserializeSeed, EnumAccess, Error, MapAccess, SeqAccess, VariantAccess, Visitor},
+    ser::SerializeTupleVariant,
+    Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    diff::{ListChange, MapChange, ReflectDiff, SetChange},
+    serde::{ReflectDeserializer, ReflectSerializer},
+    PartialReflect, TypeRegistry,
+};
+
+const DIFF_VARIANTS: &[&str] = &[
+    "Unchanged",
+    "Replaced",
+    "Struct",
+    "Indexed",
+    "List",
+    "Map",
+    "Set",
+];
+const CHANGE_VARIANTS: &[&str] = &["Insert", "Remove", "Modify"];
+
+/// A serializer for [`ReflectDiff`] values.
+///
+/// Values stored in the diff, such as replaced values and inserted list elements,
+/// are serialized with a [`ReflectSerializer`], so their types must be registered in the given [`TypeRegistry`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{PartialReflect, Reflect, TypeRegistry};
+/// # use bevy_reflect::diff::{ReflectDiffDeserializer, ReflectDiffSerializer};
+/// # use serde::de::DeserializeSeed;
+/// #[derive(Reflect, Debug, PartialEq)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Position>();
+///
+/// let old = Position { x: 1.0, y: 2.0 };
+/// let new = Position { x: 1.0, y: 5.0 };
+/// let diff = old.as_partial_reflect().diff(new.as_partial_reflect());
+///
+/// let text = ron::to_string(&ReflectDiffSerializer::new(&diff, &registry)).unwrap();
+/// assert_eq!(text, r#"Struct({"y":Replaced({"f32":5.0})})"#);
+///
+/// let mut deserializer = ron::Deserializer::from_str(&text).unwrap();
+/// let diff = ReflectDiffDeserializer::new(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+///
+/// let mut value = Position { x: 1.0, y: 2.0 };
+/// value.as_partial_reflect_mut().apply_diff(&diff).unwrap();
+/// assert_eq!(value, new);
+/// ```
+pub struct ReflectDiffSerializer<'a> {
+    diff: &'a ReflectDiff,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffSerializer<'a> {
+    /// Create a new serializer for the given diff.
+    pub fn new(diff: &'a ReflectDiff, registry: &'a TypeRegistry) -> Self {
+        Self { diff, registry }
+    }
+}
+
+impl Serialize for ReflectDiffSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let registry = self.registry;
+        match self.diff {
+            ReflectDiff::Unchanged => {
+                serializer.serialize_unit_variant("ReflectDiff", 0, DIFF_VARIANTS[0])
+            }
+            ReflectDiff::Replaced(value) => serializer.serialize_newtype_variant(
+                "ReflectDiff",
+                1,
+                DIFF_VARIANTS[1],
+                &ReflectSerializer::new(value.as_ref(), registry),
+            ),
+            ReflectDiff::Struct(fields) => serializer.serialize_newtype_variant(
+                "ReflectDiff",
+                2,
+                DIFF_VARIANTS[2],
+                &FieldsSerializer { fields, registry },
+            ),
+            ReflectDiff::Indexed(fields) => serializer.serialize_newtype_variant(
+                "ReflectDiff",
+                3,
+                DIFF_VARIANTS[3],
+                &FieldsSerializer { fields, registry },
+            ),
+            ReflectDiff::List(changes) => serializer.serialize_newtype_variant(
+                "ReflectDiff",
+                4,
+                DIFF_VARIANTS[4],
+                &ChangesSerializer { changes, registry },
+            ),
+            ReflectDiff::Map(changes) => serializer.serialize_newtype_variant(
+                "ReflectDiff",
+                5,
+                DIFF_VARIANTS[5],
+                &ChangesSerializer { changes, registry },
+            ),
+            ReflectDiff::Set(changes) => serializer.serialize_newtype_variant(
+                "ReflectDiff",
+                6,
+                DIFF_VARIANTS[6],
+                &ChangesSerializer { changes, registry },
+            ),
+        }
+    }
+}
+
+/// Serializes named fields as a map and indexed fields as a sequence of pairs.
+struct FieldsSerializer<'a, K> {
+    fields: &'a [(K, ReflectDiff)],
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for FieldsSerializer<'_, Cow<'static, str>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.fields
+                .iter()
+                .map(|(name, diff)| (name, ReflectDiffSerializer::new(diff, self.registry))),
+        )
+    }
+}
+
+impl Serialize for FieldsSerializer<'_, usize> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.fields
+                .iter()
+                .map(|(index, diff)| (index, ReflectDiffSerializer::new(diff, self.registry))),
+        )
+    }
+}
+
+struct ChangesSerializer<'a, T> {
+    changes: &'a [T],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, T> Serialize for ChangesSerializer<'a, T>
+where
+    ChangeSerializer<'a, T>: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.changes.iter().map(|change| ChangeSerializer {
+            change,
+            registry: self.registry,
+        }))
+    }
+}
+
+struct ChangeSerializer<'a, T> {
+    change: &'a T,
+    registry: &'a TypeRegistry,
+}
+
+impl ChangeSerializer<'_, ()> {
+    fn serialize_pair<S: Serializer>(
+        serializer: S,
+        name: &'static str,
+        variant_index: u32,
+        first: &impl Serialize,
+        second: &impl Serialize,
+    ) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_tuple_variant(
+            name,
+            variant_index,
+            CHANGE_VARIANTS[variant_index as usize],
+            2,
+        )?;
+        state.serialize_field(first)?;
+        state.serialize_field(second)?;
+        state.end()
+    }
+}
+
+impl Serialize for ChangeSerializer<'_, ListChange> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let registry = self.registry;
+        match self.change {
+            ListChange::Insert(index, value) => ChangeSerializer::serialize_pair(
+                serializer,
+                "ListChange",
+                0,
+                index,
+                &ReflectSerializer::new(value.as_ref(), registry),
+            ),
+            ListChange::Remove(index) => {
+                serializer.serialize_newtype_variant("ListChange", 1, CHANGE_VARIANTS[1], index)
+            }
+            ListChange::Modify(index, diff) => ChangeSerializer::serialize_pair(
+                serializer,
+                "ListChange",
+                2,
+                index,
+                &ReflectDiffSerializer::new(diff, registry),
+            ),
+        }
+    }
+}
+
+impl Serialize for ChangeSerializer<'_, MapChange> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let registry = self.registry;
+        match self.change {
+            MapChange::Insert(key, value) => ChangeSerializer::serialize_pair(
+                serializer,
+                "MapChange",
+                0,
+                &ReflectSerializer::new(key.as_ref(), registry),
+                &ReflectSerializer::new(value.as_ref(), registry),
+            ),
+            MapChange::Remove(key) => serializer.serialize_newtype_variant(
+                "MapChange",
+                1,
+                CHANGE_VARIANTS[1],
+                &ReflectSerializer::new(key.as_ref(), registry),
+            ),
+            MapChange::Modify(key, diff) => ChangeSerializer::serialize_pair(
+                serializer,
+                "MapChange",
+                2,
+                &ReflectSerializer::new(key.as_ref(), registry),
+                &ReflectDiffSerializer::new(diff, registry),
+            ),
+        }
+    }
+}
+
+impl Serialize for ChangeSerializer<'_, SetChange> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (variant_index, value) = match self.change {
+            SetChange::Insert(value) => (0, value),
+            SetChange::Remove(value) => (1, value),
+        };
+        serializer.serialize_newtype_variant(
+            "SetChange",
+            variant_index,
+            CHANGE_VARIANTS[variant_index as usize],
+            &ReflectSerializer::new(value.as_ref(), self.registry),
+        )
+    }
+}
+
+/// A deserializer for [`ReflectDiff`] values.
+///
+/// This is the deserializer counterpart to [`ReflectDiffSerializer`].
+///
+/// Values stored in the diff are deserialized with a [`ReflectDeserializer`],
+/// so they will generally be dynamic values representing their original types.
+/// These are converted when the diff is applied.
+#[derive(Clone, Copy)]
+pub struct ReflectDiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffDeserializer<'a> {
+    /// Create a new deserializer for diffs whose values are registered in the given registry.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for ReflectDiffDeserializer<'_> {
+    type Value = ReflectDiff;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_enum("ReflectDiff", DIFF_VARIANTS, self)
+    }
+}
+
+impl<'de> Visitor<'de> for ReflectDiffDeserializer<'_> {
+    type Value = ReflectDiff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected diff")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let registry = self.registry;
+        let (variant_index, variant) = data.variant_seed(VariantDeserializer(DIFF_VARIANTS))?;
+        match variant_index {
+            0 => variant.unit_variant().map(|()| ReflectDiff::Unchanged),
+            1 => variant
+                .newtype_variant_seed(ValueDeserializer(registry))
+                .map(ReflectDiff::Replaced),
+            2 => variant
+                .newtype_variant_seed(FieldsDeserializer(self))
+                .map(ReflectDiff::Struct),
+            3 => variant
+                .newtype_variant_seed(SeqDeserializer(PairDeserializer(
+                    PhantomData::<usize>,
+                    self,
+                )))
+                .map(ReflectDiff::Indexed),
+            4 => variant
+                .newtype_variant_seed(SeqDeserializer(ListChangeDeserializer(registry)))
+                .map(ReflectDiff::List),
+            5 => variant
+                .newtype_variant_seed(SeqDeserializer(MapChangeDeserializer(registry)))
+                .map(ReflectDiff::Map),
+            _ => variant
+                .newtype_variant_seed(SeqDeserializer(SetChangeDeserializer(registry)))
+                .map(ReflectDiff::Set),
+        }
+    }
+}
+
+/// Deserializes a variant name or index into the index of one of the given variants.
+struct VariantDeserializer(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for VariantDeserializer {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantDeserializer {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "one of {:?}", self.0)
+    }
+
+    fn visit_u64<E: Error>(self, index: u64) -> Result<Self::Value, E> {
+        usize::try_from(index)
+            .ok()
+            .filter(|index| *index < self.0.len())
+            .ok_or_else(|| Error::invalid_value(serde::de::Unexpected::Unsigned(index), &self))
+    }
+
+    fn visit_str<E: Error>(self, name: &str) -> Result<Self::Value, E> {
+        self.0
+            .iter()
+            .position(|variant| *variant == name)
+            .ok_or_else(|| Error::unknown_variant(name, self.0))
+    }
+}
+
+/// Deserializes a reflected value with a [`ReflectDeserializer`].
+#[derive(Clone, Copy)]
+struct ValueDeserializer<'a>(&'a TypeRegistry);
+
+impl<'de> DeserializeSeed<'de> for ValueDeserializer<'_> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        ReflectDeserializer::new(self.0).deserialize(deserializer)
+    }
+}
+
+/// Deserializes a map of field names to diffs.
+struct FieldsDeserializer<'a>(ReflectDiffDeserializer<'a>);
+
+impl<'de> DeserializeSeed<'de> for FieldsDeserializer<'_> {
+    type Value = Vec<(Cow<'static, str>, ReflectDiff)>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldsDeserializer<'_> {
+    type Value = Vec<(Cow<'static, str>, ReflectDiff)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("map of field names to diffs")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut fields = Vec::with_capacity(map.size_hint().unwrap_or_default());
+        while let Some(name) = map.next_key::<String>()? {
+            fields.push((Cow::Owned(name), map.next_value_seed(self.0)?));
+        }
+        Ok(fields)
+    }
+}
+
+/// Deserializes a sequence of values with the given seed.
+struct SeqDeserializer<S>(S);
+
+impl<'de, S: DeserializeSeed<'de> + Copy> DeserializeSeed<'de> for SeqDeserializer<S> {
+    type Value = Vec<S::Value>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de> + Copy> Visitor<'de> for SeqDeserializer<S> {
+    type Value = Vec<S::Value>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("sequence of changes")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(self.0)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Deserializes a pair of values with the given seeds.
+#[derive(Clone, Copy)]
+struct PairDeserializer<A, B>(A, B);
+
+impl<'de, A: DeserializeSeed<'de>, B: DeserializeSeed<'de>> DeserializeSeed<'de>
+    for PairDeserializer<A, B>
+{
+    type Value = (A::Value, B::Value);
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de, A: DeserializeSeed<'de>, B: DeserializeSeed<'de>> Visitor<'de>
+    for PairDeserializer<A, B>
+{
+    type Value = (A::Value, B::Value);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("pair of values")
+    }
+
+    fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+        let first = seq
+            .next_element_seed(self.0)?
+            .ok_or_else(|| Error::invalid_length(0, &"pair of values"))?;
+        let second = seq
+            .next_element_seed(self.1)?
+            .ok_or_else(|| Error::invalid_length(1, &"pair of values"))?;
+        Ok((first, second))
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ListChangeDeserializer<'a>(&'a TypeRegistry);
+
+impl<'de> DeserializeSeed<'de> for ListChangeDeserializer<'_> {
+    type Value = ListChange;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_enum("ListChange", CHANGE_VARIANTS, self)
+    }
+}
+
+impl<'de> Visitor<'de> for ListChangeDeserializer<'_> {
+    type Value = ListChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("list change")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (variant_index, variant) = data.variant_seed(VariantDeserializer(CHANGE_VARIANTS))?;
+        match variant_index {
+            0 => variant
+                .tuple_variant(
+                    2,
+                    PairDeserializer(PhantomData::<usize>, ValueDeserializer(self.0)),
+                )
+                .map(|(index, value)| ListChange::Insert(index, value)),
+            1 => variant.newtype_variant().map(ListChange::Remove),
+            _ => variant
+                .tuple_variant(
+                    2,
+                    PairDeserializer(PhantomData::<usize>, ReflectDiffDeserializer::new(self.0)),
+                )
+                .map(|(index, diff)| ListChange::Modify(index, diff)),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct MapChangeDeserializer<'a>(&'a TypeRegistry);
+
+impl<'de> DeserializeSeed<'de> for MapChangeDeserializer<'_> {
+    type Value = MapChange;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_enum("MapChange", CHANGE_VARIANTS, self)
+    }
+}
+
+impl<'de> Visitor<'de> for MapChangeDeserializer<'_> {
+    type Value = MapChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("map change")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (variant_index, variant) = data.variant_seed(VariantDeserializer(CHANGE_VARIANTS))?;
+        let key = ValueDeserializer(self.0);
+        match variant_index {
+            0 => variant
+                .tuple_variant(2, PairDeserializer(key, ValueDeserializer(self.0)))
+                .map(|(key, value)| MapChange::Insert(key, value)),
+            1 => variant.newtype_variant_seed(key).map(MapChange::Remove),
+            _ => variant
+                .tuple_variant(
+                    2,
+                    PairDeserializer(key, ReflectDiffDeserializer::new(self.0)),
+                )
+                .map(|(key, diff)| MapChange::Modify(key, diff)),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct SetChangeDeserializer<'a>(&'a TypeRegistry);
+
+impl<'de> DeserializeSeed<'de> for SetChangeDeserializer<'_> {
+    type Value = SetChange;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_enum("SetChange", &CHANGE_VARIANTS[..2], self)
+    }
+}
+
+impl<'de> Visitor<'de> for SetChangeDeserializer<'_> {
+    type Value = SetChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("set change")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (variant_index, variant) =
+            data.variant_seed(VariantDeserializer(&CHANGE_VARIANTS[..2]))?;
+        let value = variant.newtype_variant_seed(ValueDeserializer(self.0))?;
+        Ok(match variant_index {
+            0 => SetChange::Insert(value),
+            _ => SetChange::Remove(value),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_reflect, Reflect};
+    use alloc::{string::ToString, vec};
+    use bevy_utils::{HashMap, HashSet};
+    use bincode::Options;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Level {
+        name: String,
+        spawns: Vec<(i32, i32)>,
+        doors: HashMap<u32, bool>,
+        flags: HashSet<u32>,
+        weather: Weather,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum Weather {
+        Clear,
+        Rain(f32),
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Level>();
+        registry
+    }
+
+    #[test]
+    fn should_roundtrip_diff() {
+        let old = Level {
+            name: "cave".to_string(),
+            spawns: vec![(0, 0), (1, 1), (2, 2)],
+            doors: HashMap::from_iter([(1, false), (2, true)]),
+            flags: HashSet::from_iter([1, 2]),
+            weather: Weather::Rain(0.5),
+        };
+        let new = Level {
+            name: "forest".to_string(),
+            spawns: vec![(0, 0), (2, 2), (3, 3)],
+            doors: HashMap::from_iter([(1, true), (3, false)]),
+            flags: HashSet::from_iter([2, 3]),
+            weather: Weather::Clear,
+        };
+
+        let registry = registry();
+        let diff = old.as_partial_reflect().diff(new.as_partial_reflect());
+
+        for text in [
+            ron::to_string(&ReflectDiffSerializer::new(&diff, &registry)).unwrap(),
+            ron::ser::to_string_pretty(
+                &ReflectDiffSerializer::new(&diff, &registry),
+                ron::ser::PrettyConfig::default(),
+            )
+            .unwrap(),
+        ] {
+            let mut deserializer = ron::Deserializer::from_str(&text).unwrap();
+            let diff = ReflectDiffDeserializer::new(&registry)
+                .deserialize(&mut deserializer)
+                .unwrap();
+
+            let mut value = old.clone();
+            value.as_partial_reflect_mut().apply_diff(&diff).unwrap();
+            assert_eq!(value, new);
+        }
+    }
+
+    #[test]
+    fn should_roundtrip_diff_with_variant_indices() {
+        let old = Level {
+            name: "cave".to_string(),
+            spawns: vec![(0, 0)],
+            doors: HashMap::default(),
+            flags: HashSet::default(),
+            weather: Weather::Rain(0.5),
+        };
+        let mut new = old.clone();
+        new.spawns.insert(0, (5, 5));
+        new.weather = Weather::Rain(1.0);
+
+        let registry = registry();
+        let diff = old.as_partial_reflect().diff(new.as_partial_reflect());
+
+        let bytes = bincode::serialize(&ReflectDiffSerializer::new(&diff, &registry)).unwrap();
+        let diff = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(ReflectDiffDeserializer::new(&registry), &bytes)
+            .unwrap();
+
+        let mut value = old.clone();
+        value.as_partial_reflect_mut().apply_diff(&diff).unwrap();
+        assert_eq!(value, new);
+    }
+}
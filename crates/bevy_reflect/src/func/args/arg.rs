@@ -1,9 +1,12 @@
 use crate::{
-    func::args::{ArgError, FromArg, Ownership},
-    PartialReflect, Reflect, TypePath,
+    func::args::{
+        coerce::{coerce_number, Coercion},
+        ArgError, FromArg, Ownership,
+    },
+    FromReflect, PartialReflect, Reflect, TypePath,
 };
-use alloc::{boxed::Box, string::ToString};
-use core::ops::Deref;
+use alloc::{borrow::Cow, boxed::Box, string::ToString};
+use core::{any::TypeId, ops::Deref};
 
 /// Represents an argument that can be passed to a [`DynamicFunction`] or [`DynamicFunctionMut`].
 ///
@@ -13,12 +16,49 @@ use core::ops::Deref;
 pub struct Arg<'a> {
     index: usize,
     value: ArgValue<'a>,
+    name: Option<Cow<'static, str>>,
+    coerce_numbers: bool,
 }
 
 impl<'a> Arg<'a> {
     /// Create a new [`Arg`] with the given index and value.
     pub fn new(index: usize, value: ArgValue<'a>) -> Self {
-        Self { index, value }
+        Self {
+            index,
+            value,
+            name: None,
+            coerce_numbers: false,
+        }
+    }
+
+    /// Enable numeric coercion for this argument.
+    ///
+    /// See [`ArgList::with_numeric_coercion`] for details.
+    ///
+    /// [`ArgList::with_numeric_coercion`]: crate::func::args::ArgList::with_numeric_coercion
+    pub fn with_numeric_coercion(mut self) -> Self {
+        self.coerce_numbers = true;
+        self
+    }
+
+    /// Set the name of the parameter this argument is passed to.
+    ///
+    /// See [`ArgList::push_named`] for details.
+    ///
+    /// [`ArgList::push_named`]: crate::func::args::ArgList::push_named
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.set_name(name);
+        self
+    }
+
+    /// Set the name of the parameter this argument is passed to.
+    pub(crate) fn set_name(&mut self, name: impl Into<Cow<'static, str>>) {
+        self.name = Some(name.into());
+    }
+
+    /// The name of the parameter this argument is passed to, if it was given one.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
     }
 
     /// The index of the argument.
@@ -41,6 +81,17 @@ impl<'a> Arg<'a> {
         self.value
     }
 
+    /// The [ownership] of the argument's value.
+    ///
+    /// [ownership]: Ownership
+    pub fn ownership(&self) -> Ownership {
+        match self.value {
+            ArgValue::Owned(_) => Ownership::Owned,
+            ArgValue::Ref(_) => Ownership::Ref,
+            ArgValue::Mut(_) => Ownership::Mut,
+        }
+    }
+
     /// Take the value of the argument and attempt to convert it to a concrete value, `T`.
     ///
     /// This is a convenience method for calling [`FromArg::from_arg`] on the argument.
@@ -84,10 +135,28 @@ impl<'a> Arg<'a> {
     /// ```
     pub fn take_owned<T: Reflect + TypePath>(self) -> Result<T, ArgError> {
         match self.value {
-            ArgValue::Owned(arg) => arg.try_take().map_err(|arg| ArgError::UnexpectedType {
-                index: self.index,
-                expected: alloc::borrow::Cow::Borrowed(T::type_path()),
-                received: alloc::borrow::Cow::Owned(arg.reflect_type_path().to_string()),
+            ArgValue::Owned(arg) => arg.try_take().or_else(|arg| {
+                let coercion = if self.coerce_numbers {
+                    coerce_number(&*arg, TypeId::of::<T>())
+                } else {
+                    Coercion::NotNumeric
+                };
+
+                match coercion {
+                    Coercion::Widened(value) => Ok(value
+                        .try_take()
+                        .expect("coerced value should be of the target type")),
+                    Coercion::Lossy => Err(ArgError::LossyConversion {
+                        index: self.index,
+                        expected: Cow::Borrowed(T::type_path()),
+                        received: Cow::Owned(arg.reflect_type_path().to_string()),
+                    }),
+                    Coercion::NotNumeric => Err(ArgError::UnexpectedType {
+                        index: self.index,
+                        expected: Cow::Borrowed(T::type_path()),
+                        received: Cow::Owned(arg.reflect_type_path().to_string()),
+                    }),
+                }
             }),
             ArgValue::Ref(_) => Err(ArgError::InvalidOwnership {
                 index: self.index,
@@ -102,6 +171,42 @@ impl<'a> Arg<'a> {
         }
     }
 
+    /// Returns `Ok(T)` if the argument is [`ArgValue::Owned`],
+    /// using [`FromReflect`] to convert values that aren't already a `T`.
+    ///
+    /// Unlike [`Self::take_owned`], this accepts values that merely represent `T`,
+    /// such as a [`DynamicList`] for a `Vec<T>` or a [`DynamicEnum`] for an `Option<T>`.
+    ///
+    /// If the argument is not owned or can't be converted, returns an error.
+    ///
+    /// It's generally preferred to use [`Self::take`] instead of this method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::DynamicList;
+    /// # use bevy_reflect::func::ArgList;
+    /// let list = DynamicList::from_iter([1_i32, 2, 3]);
+    /// let mut args = ArgList::new().push_owned(list);
+    /// let value = args.take::<Vec<i32>>().unwrap();
+    /// assert_eq!(value, vec![1, 2, 3]);
+    /// ```
+    ///
+    /// [`DynamicList`]: crate::DynamicList
+    /// [`DynamicEnum`]: crate::DynamicEnum
+    pub fn take_from_reflect<T: FromReflect + TypePath>(self) -> Result<T, ArgError> {
+        if let ArgValue::Owned(value) = &self.value {
+            let is_concrete = value.try_as_reflect().is_some_and(<dyn Reflect>::is::<T>);
+            if !is_concrete {
+                if let Some(value) = T::from_reflect(value.as_ref()) {
+                    return Ok(value);
+                }
+            }
+        }
+
+        self.take_owned()
+    }
+
     /// Returns `Ok(&T)` if the argument is [`ArgValue::Ref`].
     ///
     /// If the argument is not a reference, returns an error.
@@ -129,8 +234,8 @@ impl<'a> Arg<'a> {
                     .try_downcast_ref()
                     .ok_or_else(|| ArgError::UnexpectedType {
                         index: self.index,
-                        expected: alloc::borrow::Cow::Borrowed(T::type_path()),
-                        received: alloc::borrow::Cow::Owned(arg.reflect_type_path().to_string()),
+                        expected: Cow::Borrowed(T::type_path()),
+                        received: Cow::Owned(arg.reflect_type_path().to_string()),
                     })?)
             }
             ArgValue::Mut(_) => Err(ArgError::InvalidOwnership {
@@ -169,12 +274,12 @@ impl<'a> Arg<'a> {
                 received: Ownership::Ref,
             }),
             ArgValue::Mut(arg) => {
-                let received = alloc::borrow::Cow::Owned(arg.reflect_type_path().to_string());
+                let received = Cow::Owned(arg.reflect_type_path().to_string());
                 Ok(arg
                     .try_downcast_mut()
                     .ok_or_else(|| ArgError::UnexpectedType {
                         index: self.index,
-                        expected: alloc::borrow::Cow::Borrowed(T::type_path()),
+                        expected: Cow::Borrowed(T::type_path()),
                         received,
                     })?)
             }
@@ -0,0 +1,146 @@
+use alloc::boxed::Box;
+use core::any::TypeId;
+
+use crate::PartialReflect;
+
+/// The kind and bit width of a primitive numeric type.
+#[derive(Clone, Copy)]
+enum NumberKind {
+    Signed(u32),
+    Unsigned(u32),
+    Float(u32),
+}
+
+impl NumberKind {
+    /// Returns `true` if every value of `self` can be represented exactly by `target`.
+    fn widens_to(self, target: NumberKind) -> bool {
+        match (self, target) {
+            (Self::Signed(from), Self::Signed(to)) | (Self::Unsigned(from), Self::Unsigned(to)) => {
+                from <= to
+            }
+            (Self::Unsigned(from), Self::Signed(to)) => from < to,
+            (Self::Signed(from) | Self::Unsigned(from), Self::Float(to)) => {
+                from <= float_exact_integer_bits(to)
+            }
+            (Self::Float(from), Self::Float(to)) => from <= to,
+            (Self::Signed(_), Self::Unsigned(_)) | (Self::Float(_), _) => false,
+        }
+    }
+}
+
+/// The width of the integer types that a float of the given width can represent exactly.
+fn float_exact_integer_bits(bits: u32) -> u32 {
+    match bits {
+        32 => 16,
+        _ => 32,
+    }
+}
+
+/// A primitive numeric value read from a reflected value.
+#[derive(Clone, Copy)]
+enum Number {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+/// The result of attempting to [coerce](coerce_number) a numeric value.
+pub(crate) enum Coercion {
+    /// The value was converted without loss.
+    Widened(Box<dyn PartialReflect>),
+    /// Both types are numeric, but the conversion would lose information.
+    Lossy,
+    /// Either type isn't a primitive numeric type.
+    NotNumeric,
+}
+
+macro_rules! impl_number_types {
+    ($($signed:ty),*; $($unsigned:ty),*; $($float:ty),*) => {
+        /// Reads the given value as a primitive number.
+        fn read_number(value: &dyn PartialReflect) -> Option<(Number, NumberKind)> {
+            let value = value.try_as_reflect()?;
+            $(if let Some(value) = value.downcast_ref::<$signed>() {
+                return Some((Number::Signed(*value as i128), NumberKind::Signed(<$signed>::BITS)));
+            })*
+            $(if let Some(value) = value.downcast_ref::<$unsigned>() {
+                return Some((Number::Unsigned(*value as u128), NumberKind::Unsigned(<$unsigned>::BITS)));
+            })*
+            $(if let Some(value) = value.downcast_ref::<$float>() {
+                return Some((Number::Float(*value as f64), NumberKind::Float(size_of::<$float>() as u32 * 8)));
+            })*
+            None
+        }
+
+        /// Returns the kind of the primitive numeric type with the given [`TypeId`].
+        fn number_kind(type_id: TypeId) -> Option<NumberKind> {
+            $(if type_id == TypeId::of::<$signed>() {
+                return Some(NumberKind::Signed(<$signed>::BITS));
+            })*
+            $(if type_id == TypeId::of::<$unsigned>() {
+                return Some(NumberKind::Unsigned(<$unsigned>::BITS));
+            })*
+            $(if type_id == TypeId::of::<$float>() {
+                return Some(NumberKind::Float(size_of::<$float>() as u32 * 8));
+            })*
+            None
+        }
+
+        /// Creates a value of the primitive numeric type with the given [`TypeId`].
+        ///
+        /// The number must be representable by the target type.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "Not every cast in this macro is lossy, so the lints may not always trigger."
+        )]
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_possible_wrap,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss,
+            reason = "The number is guaranteed to be representable by the target type."
+        )]
+        fn make_number(number: Number, type_id: TypeId) -> Option<Box<dyn PartialReflect>> {
+            $(if type_id == TypeId::of::<$signed>() {
+                return Some(Box::new(match number {
+                    Number::Signed(value) => value as $signed,
+                    Number::Unsigned(value) => value as $signed,
+                    Number::Float(value) => value as $signed,
+                }));
+            })*
+            $(if type_id == TypeId::of::<$unsigned>() {
+                return Some(Box::new(match number {
+                    Number::Signed(value) => value as $unsigned,
+                    Number::Unsigned(value) => value as $unsigned,
+                    Number::Float(value) => value as $unsigned,
+                }));
+            })*
+            $(if type_id == TypeId::of::<$float>() {
+                return Some(Box::new(match number {
+                    Number::Signed(value) => value as $float,
+                    Number::Unsigned(value) => value as $float,
+                    Number::Float(value) => value as $float,
+                }));
+            })*
+            None
+        }
+    };
+}
+
+impl_number_types!(i8, i16, i32, i64, i128, isize; u8, u16, u32, u64, u128, usize; f32, f64);
+
+/// Attempts to convert a primitive numeric value to the primitive numeric type with the given [`TypeId`].
+///
+/// Only widening conversions, which never lose information, are performed.
+/// For example, an `i32` can be converted to an `i64` or `f64`, but not to an `f32` or `u32`.
+pub(crate) fn coerce_number(value: &dyn PartialReflect, target: TypeId) -> Coercion {
+    let (Some((number, kind)), Some(target_kind)) = (read_number(value), number_kind(target))
+    else {
+        return Coercion::NotNumeric;
+    };
+
+    if !kind.widens_to(target_kind) {
+        return Coercion::Lossy;
+    }
+
+    make_number(number, target).map_or(Coercion::NotNumeric, Coercion::Widened)
+}
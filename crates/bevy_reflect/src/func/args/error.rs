@@ -16,6 +16,17 @@ pub enum ArgError {
         expected: Cow<'static, str>,
         received: Cow<'static, str>,
     },
+    /// The argument is a number that can't be converted to the expected numeric type without losing information.
+    ///
+    /// This is only returned when [numeric coercion] is enabled.
+    ///
+    /// [numeric coercion]: crate::func::args::ArgList::with_numeric_coercion
+    #[error("expected `{expected}` but received `{received}`, which can't be converted without loss (@ argument index {index})")]
+    LossyConversion {
+        index: usize,
+        expected: Cow<'static, str>,
+        received: Cow<'static, str>,
+    },
     /// The argument has the wrong ownership.
     #[error("expected {expected} value but received {received} value (@ argument index {index})")]
     InvalidOwnership {
@@ -28,6 +39,23 @@ pub enum ArgError {
     /// [`ArgList`]: crate::func::args::ArgList
     #[error("expected an argument but received none")]
     EmptyArgList,
+    /// A named argument doesn't match the name of any of the function's arguments.
+    #[error("received argument named `{name}` but the function has no argument with that name")]
+    UnknownName { name: Cow<'static, str> },
+    /// An argument was given both positionally and by name, or by the same name more than once.
+    #[error("received more than one value for the same argument (@ argument index {index})")]
+    DuplicateArg { index: usize },
+    /// An argument without a [default value] was not given when calling with named arguments.
+    ///
+    /// [default value]: crate::func::args::ArgInfo::with_default
+    #[error("missing value for argument (@ argument index {index})")]
+    MissingArg { index: usize },
+    /// More arguments were given than the function takes.
+    #[error("received unexpected argument (@ argument index {index})")]
+    UnexpectedArg { index: usize },
+    /// A positional argument was given after a named one.
+    #[error("received positional argument after named arguments (@ argument index {index})")]
+    PositionalAfterNamed { index: usize },
 }
 
 /// The given argument count is out of bounds.
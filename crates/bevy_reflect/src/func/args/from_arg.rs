@@ -33,11 +33,17 @@ pub trait FromArg {
 ///
 /// This will implement it for `$ty`, `&$ty`, and `&mut $ty`.
 ///
+/// When prefixed with `@from_reflect`, owned arguments are converted using [`Arg::take_from_reflect`].
+///
 /// See [`impl_function_traits`] for details on syntax.
 ///
 /// [`impl_function_traits`]: crate::func::macros::impl_function_traits
 macro_rules! impl_from_arg {
+    (@from_reflect $($tokens:tt)*) => {
+        $crate::func::args::impl_from_arg!(@impl take_from_reflect; $($tokens)*);
+    };
     (
+        @impl $take_owned:ident;
         $ty: ty
         $(;
             <
@@ -65,7 +71,7 @@ macro_rules! impl_from_arg {
         {
             type This<'from_arg> = $ty;
             fn from_arg(arg: $crate::func::args::Arg) -> Result<Self::This<'_>, $crate::func::args::ArgError> {
-                arg.take_owned()
+                arg.$take_owned()
             }
         }
 
@@ -99,6 +105,9 @@ macro_rules! impl_from_arg {
             }
         }
     };
+    ($($tokens:tt)*) => {
+        $crate::func::args::impl_from_arg!(@impl take_owned; $($tokens)*);
+    };
 }
 
 pub(crate) use impl_from_arg;
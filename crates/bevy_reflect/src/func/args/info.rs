@@ -1,9 +1,10 @@
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, boxed::Box, sync::Arc};
+use core::fmt::{Debug, Formatter};
 
 use crate::{
     func::args::{GetOwnership, Ownership},
     type_info::impl_type_methods,
-    Type, TypePath,
+    FromReflect, PartialReflect, Type, TypePath,
 };
 
 /// Type information for an [`Arg`] used in a [`DynamicFunction`] or [`DynamicFunctionMut`].
@@ -23,6 +24,14 @@ pub struct ArgInfo {
     ///
     /// [type]: Type
     ty: Type,
+    /// The [type] of the value behind the argument, without its reference.
+    ///
+    /// [type]: Type
+    value_ty: Type,
+    /// The value to use when the argument is omitted.
+    default: Option<ArgDefault>,
+    /// Converts the list of variadic arguments into the argument's type, if it is variadic.
+    variadic: Option<fn(&dyn PartialReflect) -> Option<Box<dyn PartialReflect>>>,
 }
 
 impl ArgInfo {
@@ -35,9 +44,18 @@ impl ArgInfo {
             name: None,
             ownership: T::ownership(),
             ty: Type::of::<T>(),
+            value_ty: T::value_type(),
+            default: None,
+            variadic: None,
         }
     }
 
+    /// Set the index of the argument within its function.
+    pub(crate) fn with_index(mut self, index: usize) -> Self {
+        self.index = index;
+        self
+    }
+
     /// Set the name of the argument.
     ///
     /// Reflected arguments are not required to have a name and by default are not given one,
@@ -47,6 +65,88 @@ impl ArgInfo {
         self
     }
 
+    /// Set the default value of the argument.
+    ///
+    /// When a function is called with fewer arguments than it takes,
+    /// the missing trailing arguments are filled in with their default values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the argument isn't [owned] or if `T` isn't the type of the argument.
+    ///
+    /// [owned]: Ownership::Owned
+    pub fn with_default<T: PartialReflect + TypePath + Clone + Send + Sync>(
+        mut self,
+        value: T,
+    ) -> Self {
+        assert_eq!(
+            self.ownership,
+            Ownership::Owned,
+            "only owned arguments can have a default value"
+        );
+        assert_eq!(
+            self.value_ty,
+            Type::of::<T>(),
+            "the default value of an argument must be of the argument's type"
+        );
+        self.default = Some(ArgDefault::new(value));
+        self
+    }
+
+    /// The default value of the argument, if it was given one.
+    ///
+    /// See [`Self::with_default`] for details.
+    pub fn default_value(&self) -> Option<&ArgDefault> {
+        self.default.as_ref()
+    }
+
+    /// Mark the argument as variadic.
+    ///
+    /// A variadic argument must be the last argument of its function and be a [list] type, such as `Vec<T>`.
+    /// When the function is called, any arguments from the index of the variadic argument onwards
+    /// are collected into a [`DynamicList`], which is then converted to `T` using [`FromReflect`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the argument isn't [owned] or if `T` isn't the type of the argument.
+    ///
+    /// [list]: crate::List
+    /// [`DynamicList`]: crate::DynamicList
+    /// [owned]: Ownership::Owned
+    pub fn with_variadic<T: FromReflect + TypePath>(mut self) -> Self {
+        assert_eq!(
+            self.ownership,
+            Ownership::Owned,
+            "only owned arguments can be variadic"
+        );
+        assert_eq!(
+            self.value_ty,
+            Type::of::<T>(),
+            "a variadic argument must be converted to the argument's type"
+        );
+        self.variadic = Some(|list| {
+            T::from_reflect(list).map(|value| Box::new(value) as Box<dyn PartialReflect>)
+        });
+        self
+    }
+
+    /// Returns `true` if the argument is [variadic].
+    ///
+    /// [variadic]: Self::with_variadic
+    pub fn is_variadic(&self) -> bool {
+        self.variadic.is_some()
+    }
+
+    /// Converts the collected variadic arguments into a value of this argument's type.
+    ///
+    /// Returns `None` if the argument isn't variadic or the conversion fails.
+    pub(crate) fn collect_variadic(
+        &self,
+        list: &dyn PartialReflect,
+    ) -> Option<Box<dyn PartialReflect>> {
+        self.variadic.and_then(|from_list| from_list(list))
+    }
+
     /// The index of the argument within its function.
     pub fn index(&self) -> usize {
         self.index
@@ -77,6 +177,16 @@ impl ArgInfo {
 
     impl_type_methods!(ty);
 
+    /// The [`Type`] of the value behind the argument.
+    ///
+    /// Unlike [`Self::ty`], this is the same for `T`, `&T`, and `&mut T`,
+    /// with the [ownership] telling them apart.
+    ///
+    /// [ownership]: Self::ownership
+    pub fn value_type(&self) -> &Type {
+        &self.value_ty
+    }
+
     /// Get an ID representing the argument.
     ///
     /// This will return `ArgId::Name` if the argument has a name,
@@ -89,6 +199,28 @@ impl ArgInfo {
     }
 }
 
+/// The default value of an argument, as set by [`ArgInfo::with_default`].
+#[derive(Clone)]
+pub struct ArgDefault(Arc<dyn Fn() -> Box<dyn PartialReflect> + Send + Sync>);
+
+impl ArgDefault {
+    /// Create a new [`ArgDefault`] that produces clones of the given value.
+    pub fn new<T: PartialReflect + Clone + Send + Sync>(value: T) -> Self {
+        Self(Arc::new(move || Box::new(value.clone())))
+    }
+
+    /// Returns a new instance of the default value.
+    pub fn value(&self) -> Box<dyn PartialReflect> {
+        (self.0)()
+    }
+}
+
+impl Debug for ArgDefault {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.value().debug(f)
+    }
+}
+
 /// A representation of an argument.
 ///
 /// This is primarily used for error reporting and debugging.
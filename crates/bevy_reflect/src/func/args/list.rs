@@ -1,13 +1,21 @@
 use crate::{
     func::{
-        args::{Arg, ArgValue, FromArg},
-        ArgError,
+        args::{
+            coerce::{coerce_number, Coercion},
+            Arg, ArgInfo, ArgValue, FromArg, Ownership,
+        },
+        ArgError, FunctionInfo, SignatureInfo,
     },
-    PartialReflect, Reflect, TypePath,
+    DynamicList, PartialReflect, Reflect, TypePath,
 };
 use alloc::{
+    borrow::Cow,
     boxed::Box,
     collections::vec_deque::{Iter, VecDeque},
+    format,
+    string::ToString,
+    vec,
+    vec::Vec,
 };
 
 /// A list of arguments that can be passed to a [`DynamicFunction`] or [`DynamicFunctionMut`].
@@ -43,6 +51,10 @@ pub struct ArgList<'a> {
     /// This flag should be set when an argument is removed from the beginning of the list,
     /// so that any future push operations will re-index the arguments.
     needs_reindex: bool,
+    /// Whether [numeric coercion] is enabled for the arguments in this list.
+    ///
+    /// [numeric coercion]: Self::with_numeric_coercion
+    coerce_numbers: bool,
 }
 
 impl<'a> ArgList<'a> {
@@ -51,6 +63,56 @@ impl<'a> ArgList<'a> {
         Self {
             list: VecDeque::new(),
             needs_reindex: false,
+            coerce_numbers: false,
+        }
+    }
+
+    /// Enable numeric coercion for the arguments in this list.
+    ///
+    /// When enabled, taking an [owned] argument as a primitive numeric type succeeds
+    /// if the argument contains a different primitive numeric type that can be converted without loss.
+    /// For example, an `f32` or `i32` can be taken as an `f64`, and a `u8` can be taken as an `i16`.
+    ///
+    /// Conversions that could lose information, such as from an `f64` to an `f32`
+    /// or from an `i32` to a `u32`, return [`ArgError::LossyConversion`] instead.
+    ///
+    /// This is useful when calling functions from scripting languages,
+    /// which rarely distinguish between numeric types.
+    /// Note that [overloaded] functions still select their overload based on the exact argument types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{ArgError, ArgList};
+    /// let mut args = ArgList::new()
+    ///     .with_numeric_coercion()
+    ///     .push_owned(1.5_f32)
+    ///     .push_owned(2.5_f64);
+    ///
+    /// assert_eq!(args.take::<f64>().unwrap(), 1.5);
+    /// assert!(matches!(args.take::<f32>(), Err(ArgError::LossyConversion { .. })));
+    /// ```
+    ///
+    /// [owned]: ArgValue::Owned
+    /// [overloaded]: crate::func::DynamicFunction::with_overload
+    pub fn with_numeric_coercion(mut self) -> Self {
+        self.coerce_numbers = true;
+        self
+    }
+
+    /// Returns `true` if [numeric coercion] is enabled for the arguments in this list.
+    ///
+    /// [numeric coercion]: Self::with_numeric_coercion
+    pub fn coerces_numbers(&self) -> bool {
+        self.coerce_numbers
+    }
+
+    /// Applies the settings of this list to an argument removed from it.
+    fn prepare_arg(&self, arg: Arg<'a>) -> Arg<'a> {
+        if self.coerce_numbers {
+            arg.with_numeric_coercion()
+        } else {
+            arg
         }
     }
 
@@ -103,13 +165,94 @@ impl<'a> ArgList<'a> {
         self.push_arg(ArgValue::Owned(arg))
     }
 
+    /// Push an [`ArgValue`] onto the list as a named argument.
+    ///
+    /// Named arguments are matched against the [names] of the function's arguments when it's called,
+    /// so they can be passed in any order and arguments with [default values] can be skipped.
+    /// Named arguments must come after any positional arguments.
+    ///
+    /// If an argument was previously removed from the beginning of the list,
+    /// this method will also re-index the list.
+    ///
+    /// [names]: crate::func::args::ArgInfo::name
+    /// [default values]: crate::func::args::ArgInfo::with_default
+    pub fn push_named_arg(self, name: impl Into<Cow<'static, str>>, arg: ArgValue<'a>) -> Self {
+        let mut list = self.push_arg(arg);
+        if let Some(arg) = list.list.back_mut() {
+            arg.set_name(name);
+        }
+        list
+    }
+
+    /// Push an [`ArgValue::Ref`] onto the list as a named argument.
+    ///
+    /// See [`Self::push_named_arg`] for details.
+    pub fn push_named_ref(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        arg: &'a dyn PartialReflect,
+    ) -> Self {
+        self.push_named_arg(name, ArgValue::Ref(arg))
+    }
+
+    /// Push an [`ArgValue::Mut`] onto the list as a named argument.
+    ///
+    /// See [`Self::push_named_arg`] for details.
+    pub fn push_named_mut(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        arg: &'a mut dyn PartialReflect,
+    ) -> Self {
+        self.push_named_arg(name, ArgValue::Mut(arg))
+    }
+
+    /// Push an [`ArgValue::Owned`] onto the list as a named argument.
+    ///
+    /// See [`Self::push_named_arg`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{ArgList, IntoFunction};
+    /// fn spawn(speed: f32, count: u32) -> f32 {
+    ///     speed * count as f32
+    /// }
+    ///
+    /// let func = spawn.into_function().with_arg_names(["speed", "count"]);
+    ///
+    /// let args = ArgList::new()
+    ///     .push_named("count", 3_u32)
+    ///     .push_named("speed", 1.5_f32);
+    /// let value = func.call(args).unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<f32>().unwrap(), 4.5);
+    /// ```
+    pub fn push_named(self, name: impl Into<Cow<'static, str>>, arg: impl PartialReflect) -> Self {
+        self.push_named_arg(name, ArgValue::Owned(Box::new(arg)))
+    }
+
+    /// Insert an [`ArgValue`] at the given index, re-indexing the rest of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the list.
+    pub(crate) fn insert_arg(&mut self, index: usize, arg: ArgValue<'a>) {
+        self.list.insert(index, Arg::new(index, arg));
+        for (index, arg) in self.list.iter_mut().enumerate() {
+            arg.set_index(index);
+        }
+        self.needs_reindex = false;
+    }
+
     /// Remove the first argument in the list and return it.
     ///
     /// It's generally preferred to use [`Self::take`] instead of this method
     /// as it provides a more ergonomic way to immediately downcast the argument.
     pub fn take_arg(&mut self) -> Result<Arg<'a>, ArgError> {
         self.needs_reindex = true;
-        self.list.pop_front().ok_or(ArgError::EmptyArgList)
+        self.list
+            .pop_front()
+            .map(|arg| self.prepare_arg(arg))
+            .ok_or(ArgError::EmptyArgList)
     }
 
     /// Remove the first argument in the list and return `Ok(T::This)`.
@@ -200,7 +343,10 @@ impl<'a> ArgList<'a> {
     /// It's generally preferred to use [`Self::pop`] instead of this method
     /// as it provides a more ergonomic way to immediately downcast the argument.
     pub fn pop_arg(&mut self) -> Result<Arg<'a>, ArgError> {
-        self.list.pop_back().ok_or(ArgError::EmptyArgList)
+        self.list
+            .pop_back()
+            .map(|arg| self.prepare_arg(arg))
+            .ok_or(ArgError::EmptyArgList)
     }
 
     /// Remove the last argument in the list and return `Ok(T::This)`.
@@ -291,6 +437,196 @@ impl<'a> ArgList<'a> {
         self.list.iter()
     }
 
+    /// Removes and returns the values of all arguments from the given index onwards.
+    pub(crate) fn split_off(&mut self, at: usize) -> impl Iterator<Item = ArgValue<'a>> {
+        self.list
+            .split_off(at.min(self.list.len()))
+            .into_iter()
+            .map(Arg::take_value)
+    }
+
+    /// Checks the arguments in this list against the given function information without consuming them.
+    ///
+    /// This verifies that the number, [ownership], and types of the arguments,
+    /// including any [named arguments], match one of the function's signatures,
+    /// taking [default values], [variadic] arguments, and [numeric coercion] into account.
+    ///
+    /// Unlike calling the function, which stops at the first invalid argument,
+    /// this collects every mismatch so they can all be reported at once.
+    /// For [overloaded] functions, the errors of the signature with the fewest mismatches are returned.
+    ///
+    /// Owned dynamic values that don't represent a concrete type, such as a plain [`DynamicList`],
+    /// are assumed to be valid since they can only be checked when converted with [`FromReflect`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{ArgError, ArgList, IntoFunction};
+    /// fn spawn(name: String, count: u32, speed: &f32) {}
+    ///
+    /// let func = spawn.into_function();
+    ///
+    /// let speed = 2.0_f32;
+    /// let args = ArgList::new().push_owned(String::from("Enemy")).push_owned(3_u32).push_ref(&speed);
+    /// assert!(args.validate(func.info()).is_ok());
+    ///
+    /// let args = ArgList::new().push_owned(3_u32).push_owned(speed);
+    /// let errors = args.validate(func.info()).unwrap_err();
+    /// assert_eq!(errors.len(), 3);
+    /// assert!(matches!(errors[0], ArgError::UnexpectedType { index: 0, .. }));
+    /// assert!(matches!(errors[1], ArgError::UnexpectedType { index: 1, .. }));
+    /// assert!(matches!(errors[2], ArgError::MissingArg { index: 2 }));
+    /// ```
+    ///
+    /// [ownership]: Ownership
+    /// [named arguments]: Self::push_named
+    /// [default values]: ArgInfo::with_default
+    /// [variadic]: ArgInfo::with_variadic
+    /// [numeric coercion]: Self::with_numeric_coercion
+    /// [overloaded]: crate::func::DynamicFunction::with_overload
+    /// [`FromReflect`]: crate::FromReflect
+    pub fn validate(&self, info: &FunctionInfo) -> Result<(), Vec<ArgError>> {
+        let mut closest: Option<Vec<ArgError>> = None;
+        for signature in info.signatures() {
+            let errors = self.signature_errors(signature);
+            if errors.is_empty() {
+                return Ok(());
+            }
+
+            if closest
+                .as_ref()
+                .map_or(true, |closest| errors.len() < closest.len())
+            {
+                closest = Some(errors);
+            }
+        }
+
+        Err(closest.unwrap_or_default())
+    }
+
+    /// Returns every mismatch between the arguments in this list and the given signature.
+    fn signature_errors(&self, signature: &SignatureInfo) -> Vec<ArgError> {
+        let infos = signature.args();
+        let fixed_count = infos.len() - usize::from(signature.is_variadic());
+        let mut errors = Vec::new();
+        let mut given = vec![false; infos.len()];
+        let mut variadic = Vec::new();
+        let mut is_named = false;
+
+        for (position, arg) in self.list.iter().enumerate() {
+            let index = match arg.name() {
+                Some(name) => {
+                    is_named = true;
+                    match infos.iter().position(|info| info.name() == Some(name)) {
+                        Some(index) => index,
+                        None => {
+                            errors.push(ArgError::UnknownName {
+                                name: Cow::Owned(name.to_string()),
+                            });
+                            continue;
+                        }
+                    }
+                }
+                None if is_named => {
+                    errors.push(ArgError::PositionalAfterNamed { index: position });
+                    continue;
+                }
+                None if position < fixed_count => position,
+                None if signature.is_variadic() => {
+                    variadic.push(arg);
+                    continue;
+                }
+                None => {
+                    errors.push(ArgError::UnexpectedArg { index: position });
+                    continue;
+                }
+            };
+
+            if given[index] {
+                errors.push(ArgError::DuplicateArg { index });
+                continue;
+            }
+            given[index] = true;
+            errors.extend(self.arg_error(&infos[index], arg));
+        }
+
+        if let Some(info) = infos.get(fixed_count).filter(|_| !variadic.is_empty()) {
+            given[fixed_count] = true;
+
+            // The variadic argument may also be passed directly as a single value.
+            let is_direct = variadic.len() == 1 && self.arg_error(info, variadic[0]).is_none();
+            let list = variadic
+                .iter()
+                .map(|arg| arg.value().clone_value())
+                .collect::<DynamicList>();
+            if !is_direct && info.collect_variadic(&list).is_none() {
+                let received = variadic
+                    .iter()
+                    .map(|arg| arg.value().reflect_type_path())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                errors.push(ArgError::UnexpectedType {
+                    index: fixed_count,
+                    expected: Cow::Borrowed(info.value_type().path()),
+                    received: Cow::Owned(format!("[{received}]")),
+                });
+            }
+        }
+
+        for (info, given) in infos.iter().zip(given) {
+            if !given && info.default_value().is_none() && !info.is_variadic() {
+                errors.push(ArgError::MissingArg {
+                    index: info.index(),
+                });
+            }
+        }
+
+        errors
+    }
+
+    /// Returns the error that passing the given argument to the one described by `info` would cause, if any.
+    fn arg_error(&self, info: &ArgInfo, arg: &Arg) -> Option<ArgError> {
+        if arg.ownership() != info.ownership() {
+            return Some(ArgError::InvalidOwnership {
+                index: info.index(),
+                expected: info.ownership(),
+                received: arg.ownership(),
+            });
+        }
+
+        let value = arg.value();
+        let is_owned = arg.ownership() == Ownership::Owned;
+        match value.get_represented_type_info() {
+            Some(type_info) if type_info.ty() == info.value_type() => return None,
+            None if is_owned && value.is_dynamic() => return None,
+            _ => {}
+        }
+
+        let expected = Cow::Borrowed(info.value_type().path());
+        let received = Cow::Owned(value.reflect_type_path().to_string());
+        if !is_owned || !self.coerce_numbers {
+            return Some(ArgError::UnexpectedType {
+                index: info.index(),
+                expected,
+                received,
+            });
+        }
+
+        match coerce_number(&**value, info.value_type().id()) {
+            Coercion::Widened(_) => None,
+            Coercion::Lossy => Some(ArgError::LossyConversion {
+                index: info.index(),
+                expected,
+                received,
+            }),
+            Coercion::NotNumeric => Some(ArgError::UnexpectedType {
+                index: info.index(),
+                expected,
+                received,
+            }),
+        }
+    }
+
     /// Returns the number of arguments in the list.
     pub fn len(&self) -> usize {
         self.list.len()
@@ -415,4 +751,153 @@ mod tests {
         assert!(args.list[2].value().reflect_partial_eq(&123).unwrap());
         assert_eq!(args.list[2].index(), 2);
     }
+
+    #[test]
+    fn should_coerce_numbers_when_enabled() {
+        let mut args = ArgList::new()
+            .with_numeric_coercion()
+            .push_owned(1_i32)
+            .push_owned(2_u8)
+            .push_owned(3_u16)
+            .push_owned(4.5_f32)
+            .push_owned(5_i64)
+            .push_owned(-1_i8)
+            .push_owned(String::from("6"));
+
+        assert_eq!(args.take::<f64>().unwrap(), 1.0);
+        assert_eq!(args.take::<i16>().unwrap(), 2);
+        assert_eq!(args.take::<f32>().unwrap(), 3.0);
+        assert_eq!(args.take::<f64>().unwrap(), 4.5);
+        assert!(matches!(
+            args.take::<f64>(),
+            Err(ArgError::LossyConversion { index: 4, .. })
+        ));
+        assert!(matches!(
+            args.take::<u32>(),
+            Err(ArgError::LossyConversion { index: 5, .. })
+        ));
+        assert!(matches!(
+            args.take::<i32>(),
+            Err(ArgError::UnexpectedType { index: 6, .. })
+        ));
+    }
+
+    #[test]
+    fn should_not_coerce_numbers_by_default() {
+        let mut args = ArgList::new().push_owned(1_i32);
+        assert!(matches!(
+            args.take::<i64>(),
+            Err(ArgError::UnexpectedType { .. })
+        ));
+    }
+
+    #[test]
+    fn should_validate_args() {
+        use crate::func::{IntoFunction, TypedFunction};
+
+        fn update(name: String, value: &mut i32, scale: f64) {
+            let _ = (name, value, scale);
+        }
+
+        let info = update.get_function_info();
+
+        let mut value = 0_i32;
+        let args = ArgList::new()
+            .push_owned(String::from("health"))
+            .push_mut(&mut value)
+            .push_owned(2.0_f64);
+        assert_eq!(args.validate(&info), Ok(()));
+
+        let value = 0_i32;
+        let args = ArgList::new()
+            .push_owned(String::from("health"))
+            .push_ref(&value)
+            .push_owned(2_i32)
+            .push_owned(true);
+        assert_eq!(
+            args.validate(&info),
+            Err(vec![
+                ArgError::InvalidOwnership {
+                    index: 1,
+                    expected: Ownership::Mut,
+                    received: Ownership::Ref,
+                },
+                ArgError::UnexpectedType {
+                    index: 2,
+                    expected: Cow::Borrowed("f64"),
+                    received: Cow::Borrowed("i32"),
+                },
+                ArgError::UnexpectedArg { index: 3 },
+            ])
+        );
+
+        let mut value = 0_i32;
+        let args = ArgList::new()
+            .with_numeric_coercion()
+            .push_owned(String::from("health"))
+            .push_mut(&mut value)
+            .push_owned(2_i32);
+        assert_eq!(args.validate(&info), Ok(()));
+
+        let func = update
+            .into_function()
+            .with_arg_names(["name", "value", "scale"])
+            .with_arg_default(2, 1.0_f64);
+        let mut value = 0_i32;
+        let args = ArgList::new()
+            .push_named_mut("value", &mut value)
+            .push_named("name", String::from("health"));
+        assert_eq!(args.validate(func.info()), Ok(()));
+
+        let args = ArgList::new()
+            .push_named("speed", 1.0_f64)
+            .push_owned(String::from("health"));
+        assert_eq!(
+            args.validate(func.info()),
+            Err(vec![
+                ArgError::UnknownName {
+                    name: Cow::Borrowed("speed"),
+                },
+                ArgError::PositionalAfterNamed { index: 1 },
+                ArgError::MissingArg { index: 0 },
+                ArgError::MissingArg { index: 1 },
+            ])
+        );
+    }
+
+    #[test]
+    fn should_validate_against_closest_overload() {
+        use crate::func::IntoFunction;
+
+        let func = (|a: i32, b: i32| a + b)
+            .into_function()
+            .with_overload(|a: f32, b: f32, c: f32| a + b + c)
+            .with_overload(
+                (|values: Vec<u8>| values.len())
+                    .into_function()
+                    .with_variadic::<Vec<u8>>(),
+            );
+
+        let args = ArgList::new()
+            .push_owned(1.0_f32)
+            .push_owned(2.0_f32)
+            .push_owned(3.0_f32);
+        assert_eq!(args.validate(func.info()), Ok(()));
+
+        let args = ArgList::new()
+            .push_owned(1_u8)
+            .push_owned(2_u8)
+            .push_owned(3_u8);
+        assert_eq!(args.validate(func.info()), Ok(()));
+
+        let args = ArgList::new().push_owned(1_i32).push_owned(2.0_f32);
+        assert_eq!(
+            args.validate(func.info()),
+            Err(vec![ArgError::UnexpectedType {
+                index: 1,
+                expected: Cow::Borrowed("i32"),
+                received: Cow::Borrowed("f32"),
+            }])
+        );
+    }
 }
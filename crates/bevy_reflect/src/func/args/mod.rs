@@ -12,6 +12,7 @@ pub use list::*;
 pub use ownership::*;
 
 mod arg;
+mod coerce;
 mod count;
 mod error;
 mod from_arg;
@@ -1,3 +1,4 @@
+use crate::Type;
 use core::fmt::{Display, Formatter};
 
 /// A trait for getting the ownership of a type.
@@ -14,10 +15,18 @@ use core::fmt::{Display, Formatter};
 pub trait GetOwnership {
     /// Returns the ownership of [`Self`].
     fn ownership() -> Ownership;
+
+    /// Returns the [`Type`] of the value behind [`Self`].
+    ///
+    /// This is `T` for `T`, `&T`, and `&mut T` alike,
+    /// which allows overloaded functions to match arguments by their type and [ownership].
+    ///
+    /// [ownership]: GetOwnership::ownership
+    fn value_type() -> Type;
 }
 
 /// The ownership of a type.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Ownership {
     /// The type is a reference (i.e. `&T`).
     Ref,
@@ -74,6 +83,10 @@ macro_rules! impl_get_ownership {
             fn ownership() -> $crate::func::args::Ownership {
                 $crate::func::args::Ownership::Owned
             }
+
+            fn value_type() -> $crate::Type {
+                $crate::Type::of::<$ty>()
+            }
         }
 
         impl <
@@ -88,6 +101,10 @@ macro_rules! impl_get_ownership {
             fn ownership() -> $crate::func::args::Ownership {
                 $crate::func::args::Ownership::Ref
             }
+
+            fn value_type() -> $crate::Type {
+                $crate::Type::of::<$ty>()
+            }
         }
 
         impl <
@@ -102,6 +119,10 @@ macro_rules! impl_get_ownership {
             fn ownership() -> $crate::func::args::Ownership {
                 $crate::func::args::Ownership::Mut
             }
+
+            fn value_type() -> $crate::Type {
+                $crate::Type::of::<$ty>()
+            }
         }
     };
 }
@@ -0,0 +1,343 @@
+use alloc::{borrow::Cow, boxed::Box, sync::Arc};
+use core::{
+    fmt::{Debug, Formatter},
+    future::{ready, Future},
+    pin::Pin,
+};
+
+use crate::{
+    func::{
+        args::{ArgCount, ArgList},
+        dynamic_function_internal::DynamicFunctionInternal,
+        DynamicFunction, FunctionInfo, FunctionOverloadError, FunctionResult, IntoAsyncFunction,
+    },
+    FromReflect, PartialReflect, TypePath,
+};
+
+/// A boxed [`Future`] returned by calling a [`DynamicAsyncFunction`].
+///
+/// It resolves to the [`FunctionResult`] of the call.
+pub type FunctionFuture<'a> = Pin<Box<dyn Future<Output = FunctionResult<'a>> + Send + 'a>>;
+
+/// An [`Arc`] containing a callback to a reflected asynchronous function.
+///
+/// The `Arc` is used to both ensure that it is `Send + Sync`
+/// and to allow for the callback to be easily cloned.
+type ArcAsyncFn<'env> = Arc<dyn for<'a> Fn(ArgList<'a>) -> FunctionFuture<'a> + Send + Sync + 'env>;
+
+/// A dynamic representation of an asynchronous function.
+///
+/// This type can be used to represent any `async` function or closure, or any function or closure
+/// returning a [`Future`], whose arguments and output all implement [`Reflect`].
+///
+/// Unlike a [`DynamicFunction`], calling this function returns a [`FunctionFuture`] instead of the result itself.
+/// The future can be awaited or spawned onto a task pool, which allows reflected functions to perform IO,
+/// asset loading, or other long-running work without blocking the caller.
+///
+/// Arguments are validated and extracted when the function is called, so the returned future doesn't
+/// borrow from the [`ArgList`]. This means that only owned arguments are supported when converting
+/// functions with [`IntoAsyncFunction`].
+///
+/// Besides that, it supports [overloading] and [default arguments] just like [`DynamicFunction`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, IntoAsyncFunction};
+/// # use bevy_tasks::block_on;
+/// async fn double(value: i32) -> i32 {
+///     value * 2
+/// }
+///
+/// let func = double.into_async_function();
+///
+/// let args = ArgList::new().push_owned(21_i32);
+/// let value = block_on(func.call(args)).unwrap().unwrap_owned();
+/// assert_eq!(value.try_take::<i32>().unwrap(), 42);
+/// ```
+///
+/// [`Reflect`]: crate::Reflect
+/// [overloading]: Self::with_overload
+/// [default arguments]: Self::with_arg_default
+#[derive(Clone)]
+pub struct DynamicAsyncFunction<'env> {
+    internal: DynamicFunctionInternal<ArcAsyncFn<'env>>,
+}
+
+impl<'env> DynamicAsyncFunction<'env> {
+    /// Create a new [`DynamicAsyncFunction`].
+    ///
+    /// The given function is called synchronously with the arguments and returns the future
+    /// that performs the actual work.
+    ///
+    /// It's important that the function signature matches the provided [`FunctionInfo`]
+    /// as this will be used to validate arguments when [calling] the function.
+    /// This is also required in order for [function overloading] to work correctly.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic for any of the following reasons:
+    /// - No [`SignatureInfo`] is provided.
+    /// - A provided [`SignatureInfo`] has more arguments than [`ArgCount::MAX_COUNT`].
+    /// - The conversion to [`FunctionInfo`] fails.
+    ///
+    /// [calling]: Self::call
+    /// [`SignatureInfo`]: crate::func::SignatureInfo
+    /// [function overloading]: Self::with_overload
+    pub fn new<F: for<'a> Fn(ArgList<'a>) -> FunctionFuture<'a> + Send + Sync + 'env>(
+        func: F,
+        info: impl TryInto<FunctionInfo, Error: Debug>,
+    ) -> Self {
+        Self {
+            internal: DynamicFunctionInternal::new(Arc::new(func), info.try_into().unwrap()),
+        }
+    }
+
+    /// Set the name of the function.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.internal = self.internal.with_name(name);
+        self
+    }
+
+    /// Set the default value of the argument at the given index.
+    ///
+    /// See [`DynamicFunction::with_arg_default`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function doesn't take an owned `T` at the given index.
+    pub fn with_arg_default<T: PartialReflect + TypePath + Clone + Send + Sync>(
+        mut self,
+        index: usize,
+        value: T,
+    ) -> Self {
+        self.internal = self.internal.with_arg_default(index, value);
+        self
+    }
+
+    /// Set the names of the function's arguments, in order.
+    ///
+    /// See [`DynamicFunction::with_arg_names`] for details.
+    pub fn with_arg_names<N: Into<Cow<'static, str>>>(
+        mut self,
+        names: impl IntoIterator<Item = N>,
+    ) -> Self {
+        self.internal = self.internal.with_arg_names(names);
+        self
+    }
+
+    /// Mark the last argument of the function as [variadic].
+    ///
+    /// See [`DynamicFunction::with_variadic`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function's last argument isn't an owned `T`.
+    ///
+    /// [variadic]: crate::func::args::ArgInfo::with_variadic
+    pub fn with_variadic<T: FromReflect + TypePath>(mut self) -> Self {
+        self.internal = self.internal.with_variadic::<T>();
+        self
+    }
+
+    /// Add an overload to this function.
+    ///
+    /// See [`DynamicFunction::with_overload`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function, `F`, contains a signature already found in this function.
+    ///
+    /// For a non-panicking version, see [`try_with_overload`].
+    ///
+    /// [`try_with_overload`]: Self::try_with_overload
+    pub fn with_overload<'a, F: IntoAsyncFunction<'a, Marker>, Marker>(
+        self,
+        function: F,
+    ) -> DynamicAsyncFunction<'a>
+    where
+        'env: 'a,
+    {
+        self.try_with_overload(function).unwrap_or_else(|(_, err)| {
+            panic!("{}", err);
+        })
+    }
+
+    /// Attempt to add an overload to this function.
+    ///
+    /// If the function, `F`, contains a signature already found in this function,
+    /// an error will be returned along with the original function.
+    ///
+    /// For a panicking version, see [`with_overload`].
+    ///
+    /// [`with_overload`]: Self::with_overload
+    pub fn try_with_overload<F: IntoAsyncFunction<'env, Marker>, Marker>(
+        mut self,
+        function: F,
+    ) -> Result<Self, (Box<Self>, FunctionOverloadError)> {
+        let function = function.into_async_function();
+
+        match self.internal.merge(function.internal) {
+            Ok(_) => Ok(self),
+            Err(err) => Err((Box::new(self), err)),
+        }
+    }
+
+    /// Call the function with the given arguments.
+    ///
+    /// The arguments are validated immediately,
+    /// and any error is returned by the future when it is polled.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the number of arguments provided does not match
+    /// the number of arguments expected by the function's [`FunctionInfo`].
+    ///
+    /// The function itself may also return any errors it needs to.
+    pub fn call<'a>(&self, args: ArgList<'a>) -> FunctionFuture<'a> {
+        let args = match self.internal.prepare_args(args) {
+            Ok(args) => args,
+            Err(err) => return Box::pin(ready(Err(err))),
+        };
+        if let Err(err) = self.internal.validate_args(&args) {
+            return Box::pin(ready(Err(err)));
+        }
+        match self.internal.get(&args) {
+            Ok(func) => func(args),
+            Err(err) => Box::pin(ready(Err(err))),
+        }
+    }
+
+    /// Returns the function info.
+    pub fn info(&self) -> &FunctionInfo {
+        self.internal.info()
+    }
+
+    /// The name of the function.
+    ///
+    /// See [`DynamicFunction::name`] for details.
+    pub fn name(&self) -> Option<&Cow<'static, str>> {
+        self.internal.name()
+    }
+
+    /// Returns `true` if the function is [overloaded].
+    ///
+    /// [overloaded]: Self::with_overload
+    pub fn is_overloaded(&self) -> bool {
+        self.internal.is_overloaded()
+    }
+
+    /// Returns the number of arguments the function expects.
+    ///
+    /// For [overloaded] functions that can have a variable number of arguments,
+    /// this will contain the full set of counts for all signatures.
+    ///
+    /// [overloaded]: Self::with_overload
+    pub fn arg_count(&self) -> ArgCount {
+        self.internal.arg_count()
+    }
+}
+
+/// Outputs the function's signature.
+///
+/// This takes the format: `DynamicAsyncFunction(fn {name}({arg1}: {type1}, {arg2}: {type2}, ...) -> {return_type})`.
+///
+/// Names for arguments and the function itself are optional and will default to `_` if not provided.
+impl<'env> Debug for DynamicAsyncFunction<'env> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "DynamicAsyncFunction({:?})", &self.internal)
+    }
+}
+
+/// Wraps a [`DynamicFunction`] so that calling it returns a future that is immediately ready.
+impl<'env> From<DynamicFunction<'env>> for DynamicAsyncFunction<'env> {
+    #[inline]
+    fn from(function: DynamicFunction<'env>) -> Self {
+        Self {
+            internal: function.internal.map_functions(|func| {
+                Arc::new(move |args| Box::pin(ready(func(args))) as FunctionFuture)
+            }),
+        }
+    }
+}
+
+impl<'env> IntoAsyncFunction<'env, ()> for DynamicAsyncFunction<'env> {
+    #[inline]
+    fn into_async_function(self) -> DynamicAsyncFunction<'env> {
+        self
+    }
+}
+
+impl<'env> IntoAsyncFunction<'env, DynamicFunction<'env>> for DynamicFunction<'env> {
+    #[inline]
+    fn into_async_function(self) -> DynamicAsyncFunction<'env> {
+        DynamicAsyncFunction::from(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::func::{FunctionError, IntoFunction};
+    use alloc::string::{String, ToString};
+    use bevy_tasks::block_on;
+
+    #[test]
+    fn should_call_async_function() {
+        async fn join(a: String, b: String) -> String {
+            a + &b
+        }
+
+        let func = join.into_async_function();
+        let args = ArgList::new()
+            .push_owned(String::from("Hello, "))
+            .push_owned(String::from("world!"));
+        let value = block_on(func.call(args)).unwrap().unwrap_owned();
+        assert_eq!(value.try_take::<String>().unwrap(), "Hello, world!");
+    }
+
+    #[test]
+    fn should_call_closure_returning_future() {
+        let offset = 10;
+        let func = (move |value: i32| async move { value + offset }).into_async_function();
+        let args = ArgList::new().push_owned(5_i32);
+        let value = block_on(func.call(args)).unwrap().unwrap_owned();
+        assert_eq!(value.try_take::<i32>().unwrap(), 15);
+    }
+
+    #[test]
+    fn should_overload_async_function() {
+        let func = (|value: i32| async move { value.to_string() })
+            .into_async_function()
+            .with_overload(|value: f32| async move { value.to_string() })
+            .with_overload((|value: bool| value.to_string()).into_function());
+
+        let args = ArgList::new().push_owned(1_i32);
+        let value = block_on(func.call(args)).unwrap().unwrap_owned();
+        assert_eq!(value.try_take::<String>().unwrap(), "1");
+
+        let args = ArgList::new().push_owned(1.5_f32);
+        let value = block_on(func.call(args)).unwrap().unwrap_owned();
+        assert_eq!(value.try_take::<String>().unwrap(), "1.5");
+
+        let args = ArgList::new().push_owned(true);
+        let value = block_on(func.call(args)).unwrap().unwrap_owned();
+        assert_eq!(value.try_take::<String>().unwrap(), "true");
+    }
+
+    #[test]
+    fn should_return_error_from_future() {
+        let func = (|value: i32| async move { value }).into_async_function();
+
+        let args = ArgList::new();
+        let result = block_on(func.call(args));
+        assert!(matches!(
+            result,
+            Err(FunctionError::ArgCountMismatch { received: 0, .. })
+        ));
+
+        let args = ArgList::new().push_owned(1_u32);
+        let result = block_on(func.call(args));
+        assert!(matches!(result, Err(FunctionError::ArgError(_))));
+    }
+}
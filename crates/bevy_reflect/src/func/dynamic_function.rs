@@ -2,14 +2,15 @@ use crate::{
     self as bevy_reflect,
     __macro_exports::RegisterForReflection,
     func::{
-        args::{ArgCount, ArgList},
+        args::{ArgCount, ArgList, ArgValue, Ownership},
         dynamic_function_internal::DynamicFunctionInternal,
+        generic::generic_name,
         info::FunctionInfo,
-        DynamicFunctionMut, Function, FunctionOverloadError, FunctionResult, IntoFunction,
-        IntoFunctionMut,
+        DynamicFunctionMut, Function, FunctionOverloadError, FunctionPipelineError, FunctionResult,
+        IntoFunction, IntoFunctionMut,
     },
-    ApplyError, MaybeTyped, PartialReflect, Reflect, ReflectKind, ReflectMut, ReflectOwned,
-    ReflectRef, TypeInfo, TypePath,
+    ApplyError, FromReflect, MaybeTyped, PartialReflect, Reflect, ReflectKind, ReflectMut,
+    ReflectOwned, ReflectRef, Type, TypeInfo, TypePath,
 };
 use alloc::{borrow::Cow, boxed::Box, sync::Arc};
 use bevy_reflect_derive::impl_type_path;
@@ -110,6 +111,335 @@ impl<'env> DynamicFunction<'env> {
         self
     }
 
+    /// Set the default value of the argument at the given index.
+    ///
+    /// When the function is called with fewer arguments than it takes,
+    /// the missing trailing arguments are filled in with their default values.
+    ///
+    /// For [overloaded] functions, the default is set for every signature
+    /// that takes an owned `T` at the given index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{ArgList, IntoFunction};
+    /// let greet = (|name: String, punctuation: char| format!("Hello, {name}{punctuation}"))
+    ///     .into_function()
+    ///     .with_arg_default(1, '!');
+    ///
+    /// let args = ArgList::new().push_owned(String::from("world"));
+    /// let value = greet.call(args).unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<String>().unwrap(), "Hello, world!");
+    ///
+    /// let args = ArgList::new().push_owned(String::from("world")).push_owned('?');
+    /// let value = greet.call(args).unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<String>().unwrap(), "Hello, world?");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function doesn't take an owned `T` at the given index.
+    ///
+    /// [overloaded]: Self::with_overload
+    pub fn with_arg_default<T: PartialReflect + TypePath + Clone + Send + Sync>(
+        mut self,
+        index: usize,
+        value: T,
+    ) -> Self {
+        self.internal = self.internal.with_arg_default(index, value);
+        self
+    }
+
+    /// Set the names of the function's arguments, in order.
+    ///
+    /// Functions created with [`IntoFunction`] can't infer the names of their arguments,
+    /// so they need to be set in order to call the function with [named arguments].
+    ///
+    /// For [overloaded] functions, the names apply to the leading arguments of every signature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{ArgList, IntoFunction};
+    /// let move_by = (|position: f32, speed: f32, scale: f32| position + speed * scale)
+    ///     .into_function()
+    ///     .with_arg_names(["position", "speed", "scale"])
+    ///     .with_arg_default(2, 1.0_f32);
+    ///
+    /// let args = ArgList::new()
+    ///     .push_owned(1.0_f32)
+    ///     .push_named("speed", 2.0_f32);
+    /// let value = move_by.call(args).unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<f32>().unwrap(), 3.0);
+    /// ```
+    ///
+    /// [named arguments]: ArgList::push_named
+    /// [overloaded]: Self::with_overload
+    pub fn with_arg_names<N: Into<Cow<'static, str>>>(
+        mut self,
+        names: impl IntoIterator<Item = N>,
+    ) -> Self {
+        self.internal = self.internal.with_arg_names(names);
+        self
+    }
+
+    /// Mark the last argument of the function as [variadic].
+    ///
+    /// When the function is called, any arguments from the index of the last argument onwards
+    /// are collected into a list and converted to `T`, which must be a [list] type such as `Vec<T>`.
+    /// A value of type `T` can still be passed directly as the last argument.
+    ///
+    /// For [overloaded] functions, this applies to every signature whose last argument is an owned `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{ArgList, IntoFunction};
+    /// let sum = (|scale: i32, values: Vec<i32>| scale * values.iter().sum::<i32>())
+    ///     .into_function()
+    ///     .with_variadic::<Vec<i32>>();
+    ///
+    /// let args = ArgList::new().push_owned(2_i32).push_owned(1_i32).push_owned(2_i32).push_owned(3_i32);
+    /// let value = sum.call(args).unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<i32>().unwrap(), 12);
+    ///
+    /// let args = ArgList::new().push_owned(2_i32);
+    /// let value = sum.call(args).unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<i32>().unwrap(), 0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function's last argument isn't an owned `T`.
+    ///
+    /// [variadic]: crate::func::args::ArgInfo::with_variadic
+    /// [list]: crate::List
+    /// [overloaded]: Self::with_overload
+    pub fn with_variadic<T: FromReflect + TypePath>(mut self) -> Self {
+        self.internal = self.internal.with_variadic::<T>();
+        self
+    }
+
+    /// Bind the argument at the given index to the given value.
+    ///
+    /// This returns a new function that takes one less argument,
+    /// passing the bound value in its place whenever it's called.
+    /// This is also known as [partial application].
+    ///
+    /// Since the function may be called any number of times, the bound value is reused for every call:
+    /// - [`ArgValue::Ref`] values are passed as the same reference.
+    /// - [`ArgValue::Owned`] values are cloned using [`PartialReflect::clone_value`].
+    ///   As this returns a dynamic representation for non-opaque types such as structs,
+    ///   these should instead be bound with [`Self::bind_value`].
+    ///
+    /// For [overloaded] functions, only the signatures that take the bound value at the given index are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{ArgList, ArgValue, IntoFunction};
+    /// let add = (|a: i32, b: i32| a + b).into_function();
+    ///
+    /// let add_5 = add.bind(0, ArgValue::Owned(Box::new(5_i32)));
+    /// assert!(add_5.arg_count().contains(1));
+    ///
+    /// let args = ArgList::new().push_owned(10_i32);
+    /// let value = add_5.call(args).unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<i32>().unwrap(), 15);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an [`ArgValue::Mut`], since a mutable reference can't be shared between calls,
+    /// or if no signature takes a value of its type and [ownership] at the given index.
+    ///
+    /// [partial application]: https://en.wikipedia.org/wiki/Partial_application
+    /// [overloaded]: Self::with_overload
+    /// [ownership]: crate::func::args::Ownership
+    pub fn bind(self, index: usize, value: ArgValue<'static>) -> Self {
+        let ty = value
+            .get_represented_type_info()
+            .map(|info| *info.ty())
+            .expect("bound values must have type information");
+
+        match value {
+            ArgValue::Ref(value) => {
+                self.bind_with(index, Ownership::Ref, ty, move || ArgValue::Ref(value))
+            }
+            ArgValue::Owned(value) => self.bind_with(index, Ownership::Owned, ty, move || {
+                ArgValue::Owned(value.clone_value())
+            }),
+            ArgValue::Mut(_) => panic!("mutable references can't be bound to a `DynamicFunction`"),
+        }
+    }
+
+    /// Bind the argument at the given index to the given owned value.
+    ///
+    /// The value is cloned for every call of the returned function.
+    /// See [`Self::bind`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no signature takes an owned `T` at the given index.
+    pub fn bind_value<T: PartialReflect + TypePath + Clone>(self, index: usize, value: T) -> Self {
+        self.bind_with(index, Ownership::Owned, Type::of::<T>(), move || {
+            ArgValue::Owned(Box::new(value.clone()))
+        })
+    }
+
+    fn bind_with(
+        self,
+        index: usize,
+        ownership: Ownership,
+        ty: Type,
+        value: impl Fn() -> ArgValue<'static> + Send + Sync + 'env,
+    ) -> Self {
+        let info = self
+            .info()
+            .without_arg(index, |arg| {
+                arg.ownership() == ownership && *arg.value_type() == ty
+            })
+            .unwrap_or_else(|| {
+                panic!(
+                    "no signature takes {ownership} `{}` at index {index}",
+                    ty.path()
+                )
+            });
+
+        DynamicFunction::new(
+            move |mut args| {
+                args.insert_arg(index, value());
+                self.call(args)
+            },
+            info,
+        )
+    }
+
+    /// Chain this function with the given function, `next`.
+    ///
+    /// The returned function takes the same arguments as this one,
+    /// and passes its [`Return`] value as the only argument of `next`,
+    /// returning the output of `next`.
+    /// Any other arguments of `next` must either have a [default value] or be [bound] beforehand.
+    ///
+    /// For longer chains, see [`FunctionPipeline`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{ArgList, IntoFunction};
+    /// let add = (|a: i32, b: i32| a + b).into_function();
+    /// let double = (|value: i32| value * 2).into_function();
+    ///
+    /// let add_then_double = add.then(double);
+    ///
+    /// let args = ArgList::new().push_owned(1_i32).push_owned(2_i32);
+    /// let value = add_then_double.call(args).unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<i32>().unwrap(), 6);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if no signature of `next` takes the [type and ownership] returned by this function.
+    ///
+    /// For a non-panicking version, see [`try_then`].
+    ///
+    /// [`Return`]: crate::func::Return
+    /// [`FunctionPipeline`]: crate::func::FunctionPipeline
+    /// [default value]: Self::with_arg_default
+    /// [bound]: Self::bind
+    /// [type and ownership]: crate::func::ReturnInfo
+    /// [`try_then`]: Self::try_then
+    pub fn then<F: IntoFunction<'env, Marker>, Marker>(self, next: F) -> Self {
+        self.try_then(next).unwrap_or_else(|err| {
+            panic!("{}", err);
+        })
+    }
+
+    /// Attempt to chain this function with the given function, `next`.
+    ///
+    /// If no signature of `next` takes the return value of this function,
+    /// an error will be returned.
+    ///
+    /// For a panicking version, see [`then`].
+    ///
+    /// [`then`]: Self::then
+    pub fn try_then<F: IntoFunction<'env, Marker>, Marker>(
+        self,
+        next: F,
+    ) -> Result<Self, FunctionPipelineError> {
+        let next = next.into_function();
+        let info = self
+            .info()
+            .then(next.info())
+            .ok_or(FunctionPipelineError::SignatureMismatch { step: 1 })?;
+
+        Ok(DynamicFunction::new(
+            move |args| {
+                let value = self.call(args)?;
+                next.call(ArgList::new().push_arg(value.into()))
+            },
+            info,
+        ))
+    }
+
+    /// Create a new [`DynamicFunction`] from a set of instantiations of a generic function.
+    ///
+    /// Each instantiation is added as an [overload] of the first one,
+    /// so calling the function picks the monomorphization matching the types of the given arguments.
+    /// The function is named after the first instantiation, without its generic arguments
+    /// (e.g. `my_crate::add<i32>` becomes `my_crate::add`).
+    ///
+    /// The [`generic_function`] macro can be used to create the instantiations from a list of types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{ArgList, DynamicFunction, IntoFunction};
+    /// # use core::ops::Mul;
+    /// fn square<T: Mul<Output = T> + Copy>(value: T) -> T {
+    ///     value * value
+    /// }
+    ///
+    /// let func = DynamicFunction::from_instantiations([
+    ///     square::<i32>.into_function(),
+    ///     square::<f32>.into_function(),
+    /// ])
+    /// .unwrap();
+    /// assert!(func.name().unwrap().ends_with("::square"));
+    ///
+    /// let args = ArgList::new().push_owned(1.5_f32);
+    /// let value = func.call(args).unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<f32>().unwrap(), 2.25);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no instantiations are given or if two of them have the same argument signature.
+    ///
+    /// [overload]: Self::with_overload
+    /// [`generic_function`]: crate::generic_function
+    pub fn from_instantiations(
+        instantiations: impl IntoIterator<Item = DynamicFunction<'env>>,
+    ) -> Result<Self, FunctionOverloadError> {
+        let mut instantiations = instantiations.into_iter();
+        let first = instantiations
+            .next()
+            .ok_or(FunctionOverloadError::MissingSignature)?;
+        let name = first.name().cloned().map(generic_name);
+
+        let function = instantiations.try_fold(first, |function, instantiation| {
+            function
+                .try_with_overload(instantiation)
+                .map_err(|(_, err)| err)
+        })?;
+
+        Ok(match name {
+            Some(name) => function.with_name(name),
+            None => function,
+        })
+    }
+
     /// Add an overload to this function.
     ///
     /// Overloads allow a single [`DynamicFunction`] to represent multiple functions of different signatures.
@@ -264,6 +594,7 @@ impl<'env> DynamicFunction<'env> {
     ///
     /// The function itself may also return any errors it needs to.
     pub fn call<'a>(&self, args: ArgList<'a>) -> FunctionResult<'a> {
+        let args = self.internal.prepare_args(args)?;
         self.internal.validate_args(&args)?;
         let func = self.internal.get(&args)?;
         func(args)
@@ -468,7 +799,7 @@ impl<'env> IntoFunctionMut<'env, ()> for DynamicFunction<'env> {
 mod tests {
     use super::*;
     use crate::func::signature::ArgumentSignature;
-    use crate::func::{FunctionError, IntoReturn, SignatureInfo};
+    use crate::func::{ArgError, FunctionError, IntoReturn, SignatureInfo};
     use crate::Type;
     use alloc::{format, string::String, vec, vec::Vec};
     use bevy_utils::HashSet;
@@ -671,6 +1002,275 @@ mod tests {
         assert_eq!(result.try_take::<f32>().unwrap(), 100.0);
     }
 
+    #[test]
+    fn should_allow_function_overloading_by_ownership() {
+        let func = (|value: i32| format!("owned {value}"))
+            .into_function()
+            .with_overload(|value: &i32| format!("ref {value}"))
+            .with_overload(|value: &mut i32| format!("mut {value}"));
+
+        let args = ArgList::default().push_owned(1_i32);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<String>().unwrap(), "owned 1");
+
+        let value = 2_i32;
+        let args = ArgList::default().push_ref(&value);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<String>().unwrap(), "ref 2");
+
+        let mut value = 3_i32;
+        let args = ArgList::default().push_mut(&mut value);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<String>().unwrap(), "mut 3");
+
+        let value = 4_u32;
+        let args = ArgList::default().push_ref(&value);
+        let result = func.call(args);
+        assert!(matches!(result, Err(FunctionError::NoOverload { .. })));
+    }
+
+    #[test]
+    fn should_fill_in_default_args() {
+        fn add(a: i32, b: i32, c: i32) -> i32 {
+            a + b + c
+        }
+
+        let func = add
+            .into_function()
+            .with_arg_default(1, 10_i32)
+            .with_arg_default(2, 100_i32);
+        assert!(func.arg_count().contains(1));
+        assert!(func.arg_count().contains(2));
+        assert!(func.arg_count().contains(3));
+        assert!(!func.arg_count().contains(0));
+
+        let args = ArgList::default().push_owned(1_i32);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<i32>().unwrap(), 111);
+
+        let args = ArgList::default().push_owned(1_i32).push_owned(2_i32);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<i32>().unwrap(), 103);
+
+        let args = ArgList::default();
+        let result = func.call(args);
+        assert!(matches!(
+            result,
+            Err(FunctionError::ArgCountMismatch { received: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn should_fill_in_default_args_for_matching_overload() {
+        let func = (|a: i32, b: i32| a * b)
+            .into_function()
+            .with_overload(|a: f32, b: f32| a * b)
+            .with_arg_default(1, 2_i32)
+            .with_arg_default(1, 0.5_f32);
+
+        let args = ArgList::default().push_owned(21_i32);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<i32>().unwrap(), 42);
+
+        let args = ArgList::default().push_owned(4.0_f32);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<f32>().unwrap(), 2.0);
+    }
+
+    #[test]
+    fn should_collect_variadic_args() {
+        fn join(separator: String, parts: Vec<String>) -> String {
+            parts.join(&separator)
+        }
+
+        let func = join.into_function().with_variadic::<Vec<String>>();
+        assert!(func.info().base().is_variadic());
+        assert!(func.arg_count().contains(1));
+        assert!(func.arg_count().contains(ArgCount::MAX_COUNT));
+
+        let a = String::from("a");
+        let args = ArgList::default()
+            .push_owned(String::from(", "))
+            .push_ref(&a)
+            .push_owned(String::from("b"))
+            .push_owned(String::from("c"));
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<String>().unwrap(), "a, b, c");
+
+        let args = ArgList::default().push_owned(String::from(", "));
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<String>().unwrap(), "");
+
+        // A list can still be passed directly
+        let args = ArgList::default()
+            .push_owned(String::from("-"))
+            .push_owned(vec![String::from("a"), String::from("b")]);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<String>().unwrap(), "a-b");
+
+        let args = ArgList::default()
+            .push_owned(String::from(", "))
+            .push_owned(1_i32);
+        let result = func.call(args);
+        assert!(matches!(result, Err(FunctionError::ArgError(_))));
+    }
+
+    #[test]
+    fn should_dispatch_variadic_overloads() {
+        let func = (|values: Vec<i32>| values.iter().sum::<i32>())
+            .into_function()
+            .with_overload(|values: Vec<f32>| values.iter().sum::<f32>())
+            .with_variadic::<Vec<i32>>()
+            .with_variadic::<Vec<f32>>();
+
+        let args = ArgList::default().push_owned(1_i32).push_owned(2_i32);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<i32>().unwrap(), 3);
+
+        let args = ArgList::default().push_owned(vec![1.5_f32, 2.0]);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<f32>().unwrap(), 3.5);
+
+        let args = ArgList::default().push_owned(1.5_f32).push_owned(2.0_f32);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<f32>().unwrap(), 3.5);
+    }
+
+    #[test]
+    fn should_reorder_named_args() {
+        fn describe(name: String, age: u32, scale: f32) -> String {
+            format!("{name} {age} {scale}")
+        }
+
+        let func = describe
+            .into_function()
+            .with_arg_names(["name", "age", "scale"])
+            .with_arg_default(2, 1.5_f32);
+
+        let args = ArgList::default()
+            .push_named("scale", 2.0_f32)
+            .push_named("age", 30_u32)
+            .push_named("name", String::from("Alice"));
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<String>().unwrap(), "Alice 30 2");
+
+        let args = ArgList::default()
+            .push_owned(String::from("Bob"))
+            .push_named("age", 20_u32);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<String>().unwrap(), "Bob 20 1.5");
+    }
+
+    #[test]
+    fn should_error_on_invalid_named_args() {
+        let func = (|a: i32, b: i32| a - b)
+            .into_function()
+            .with_arg_names(["a", "b"]);
+
+        let args = ArgList::default().push_named("c", 1_i32);
+        assert_eq!(
+            func.call(args).unwrap_err(),
+            FunctionError::ArgError(ArgError::UnknownName { name: "c".into() })
+        );
+
+        let args = ArgList::default().push_owned(1_i32).push_named("a", 2_i32);
+        assert_eq!(
+            func.call(args).unwrap_err(),
+            FunctionError::ArgError(ArgError::DuplicateArg { index: 0 })
+        );
+
+        let args = ArgList::default().push_named("a", 1_i32);
+        assert_eq!(
+            func.call(args).unwrap_err(),
+            FunctionError::ArgError(ArgError::MissingArg { index: 1 })
+        );
+
+        let args = ArgList::default().push_named("b", 1_i32).push_owned(2_i32);
+        assert_eq!(
+            func.call(args).unwrap_err(),
+            FunctionError::ArgError(ArgError::PositionalAfterNamed { index: 1 })
+        );
+    }
+
+    #[test]
+    fn should_dispatch_named_args_to_matching_overload() {
+        let func = (|value: i32, count: usize| value * count as i32)
+            .into_function()
+            .with_overload(|text: String, count: usize| text.repeat(count))
+            .with_arg_names(["value", "count"]);
+
+        let args = ArgList::default()
+            .push_named("count", 3_usize)
+            .push_named("value", 2_i32);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<i32>().unwrap(), 6);
+
+        let args = ArgList::default()
+            .push_named("count", 2_usize)
+            .push_named("value", String::from("ab"));
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<String>().unwrap(), "abab");
+    }
+
+    #[test]
+    fn should_bind_args() {
+        fn greet(greeting: &String, name: String, punctuation: char) -> String {
+            format!("{greeting}, {name}{punctuation}")
+        }
+
+        let greeting: &'static String = Box::leak(Box::new(String::from("Hello")));
+
+        let func = greet
+            .into_function()
+            .bind(0, ArgValue::Ref(greeting))
+            .bind(1, ArgValue::Owned(Box::new('!')));
+        assert_eq!(func.info().base().arg_count(), 1);
+        assert_eq!(func.info().base().args()[0].index(), 0);
+
+        let args = ArgList::default().push_owned(String::from("world"));
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<String>().unwrap(), "Hello, world!");
+
+        let args = ArgList::default().push_owned(String::from("there"));
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<String>().unwrap(), "Hello, there!");
+    }
+
+    #[test]
+    fn should_bind_value_for_matching_overloads() {
+        let func = (|values: Vec<i32>, scale: i32| values.iter().sum::<i32>() * scale)
+            .into_function()
+            .with_overload(|values: Vec<f32>, scale: f32| values.iter().sum::<f32>() * scale)
+            .bind_value(0, vec![1_i32, 2, 3]);
+        assert!(!func.is_overloaded());
+
+        let args = ArgList::default().push_owned(2_i32);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<i32>().unwrap(), 12);
+    }
+
+    #[test]
+    #[should_panic(expected = "no signature takes owned `u8` at index 0")]
+    fn should_panic_on_binding_mismatched_value() {
+        let _ = (|a: i32| a)
+            .into_function()
+            .bind(0, ArgValue::Owned(Box::new(1_u8)));
+    }
+
+    #[test]
+    fn should_coerce_numeric_args() {
+        let func = (|a: f64, b: i64| a * b as f64)
+            .into_function()
+            .with_arg_names(["a", "b"]);
+
+        let args = ArgList::default()
+            .with_numeric_coercion()
+            .push_named("b", 2_i32)
+            .push_named("a", 1.5_f32);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<f64>().unwrap(), 3.0);
+    }
+
     #[test]
     fn should_allow_variable_arguments_via_overloading() {
         fn add_2(a: i32, b: i32) -> i32 {
@@ -1,7 +1,8 @@
-use crate::func::args::ArgCount;
+use crate::func::args::{Arg, ArgCount, ArgError, ArgInfo, ArgValue, Ownership};
 use crate::func::signature::{ArgListSignature, ArgumentSignature};
-use crate::func::{ArgList, FunctionError, FunctionInfo, FunctionOverloadError};
-use alloc::{borrow::Cow, vec, vec::Vec};
+use crate::func::{ArgList, FunctionError, FunctionInfo, FunctionOverloadError, SignatureInfo};
+use crate::{DynamicList, FromReflect, PartialReflect, TypePath};
+use alloc::{borrow::Cow, boxed::Box, string::ToString, vec, vec::Vec};
 use bevy_utils::HashMap;
 use core::fmt::{Debug, Formatter};
 
@@ -54,45 +55,331 @@ impl<F> DynamicFunctionInternal<F> {
     /// Get an immutable reference to the function.
     ///
     /// If the function is not overloaded, it will always be returned regardless of the arguments.
-    /// Otherwise, the function will be selected based on the arguments provided.
+    /// Otherwise, the function will be selected based on the types and [ownership] of the arguments provided.
     ///
     /// If no overload matches the provided arguments, returns [`FunctionError::NoOverload`].
+    ///
+    /// [ownership]: crate::func::args::Ownership
     pub fn get(&self, args: &ArgList) -> Result<&F, FunctionError> {
         if !self.info.is_overloaded() {
             return Ok(&self.functions[0]);
         }
 
-        let signature = ArgListSignature::from(args);
-        self.arg_map
-            .get(&signature)
-            .map(|index| &self.functions[*index])
-            .ok_or_else(|| FunctionError::NoOverload {
-                expected: self.arg_map.keys().cloned().collect(),
-                received: ArgumentSignature::from(args),
-            })
+        self.overload_index(args)
+            .map(|index| &self.functions[index])
     }
 
     /// Get a mutable reference to the function.
     ///
     /// If the function is not overloaded, it will always be returned regardless of the arguments.
-    /// Otherwise, the function will be selected based on the arguments provided.
+    /// Otherwise, the function will be selected based on the types and [ownership] of the arguments provided.
     ///
     /// If no overload matches the provided arguments, returns [`FunctionError::NoOverload`].
+    ///
+    /// [ownership]: crate::func::args::Ownership
     pub fn get_mut(&mut self, args: &ArgList) -> Result<&mut F, FunctionError> {
         if !self.info.is_overloaded() {
             return Ok(&mut self.functions[0]);
         }
 
-        let signature = ArgListSignature::from(args);
-        self.arg_map
-            .get(&signature)
-            .map(|index| &mut self.functions[*index])
+        self.overload_index(args)
+            .map(|index| &mut self.functions[index])
+    }
+
+    /// Returns the index of the overload matching the given arguments.
+    fn overload_index(&self, args: &ArgList) -> Result<usize, FunctionError> {
+        // The signature of an owned argument is the type of its value,
+        // so a list of owned arguments can be looked up directly.
+        if args.iter().all(|arg| arg.ownership() == Ownership::Owned) {
+            if let Some(index) = self.arg_map.get(&ArgListSignature::from(args)) {
+                return Ok(*index);
+            }
+        }
+
+        // Otherwise, the argument types need to be compared without their references.
+        self.info
+            .signatures()
+            .iter()
+            .find(|signature| {
+                signature.arg_count() == args.len() && Self::args_match(signature.args(), args)
+            })
+            .and_then(|signature| self.arg_map.get(&ArgumentSignature::from(signature)))
+            .copied()
             .ok_or_else(|| FunctionError::NoOverload {
                 expected: self.arg_map.keys().cloned().collect(),
                 received: ArgumentSignature::from(args),
             })
     }
 
+    /// Returns `true` if the given arguments have the types and [ownership] of the leading arguments in `infos`.
+    ///
+    /// [ownership]: crate::func::args::Ownership
+    fn args_match(infos: &[ArgInfo], args: &ArgList) -> bool {
+        infos
+            .iter()
+            .zip(args.iter())
+            .all(|(info, arg)| Self::arg_matches(info, arg))
+    }
+
+    /// Returns `true` if the given argument has the type and [ownership] described by `info`.
+    ///
+    /// [ownership]: crate::func::args::Ownership
+    fn arg_matches(info: &ArgInfo, arg: &Arg) -> bool {
+        info.ownership() == arg.ownership()
+            && arg
+                .value()
+                .get_represented_type_info()
+                .is_some_and(|type_info| type_info.ty() == info.value_type())
+    }
+
+    /// Returns `true` if the given arguments can be collected into the [variadic] argument of the signature,
+    /// or if the signature isn't variadic.
+    ///
+    /// [variadic]: ArgInfo::with_variadic
+    fn variadic_args_match(signature: &SignatureInfo, args: &ArgList) -> bool {
+        let Some(info) = signature.args().last().filter(|info| info.is_variadic()) else {
+            return true;
+        };
+
+        let list = args
+            .iter()
+            .skip(signature.arg_count() - 1)
+            .map(|arg| arg.value().clone_value())
+            .collect::<DynamicList>();
+        info.collect_variadic(&list).is_some()
+    }
+
+    /// Returns the position in `args` of the value passed to each argument of the signature,
+    /// or `None` if the argument wasn't given and should use its [default value].
+    ///
+    /// [default value]: ArgInfo::with_default
+    fn named_arg_positions(
+        signature: &SignatureInfo,
+        args: &ArgList,
+    ) -> Result<Vec<Option<usize>>, FunctionError> {
+        let infos = signature.args();
+        let mut positions = vec![None; infos.len()];
+        let mut is_named = false;
+
+        for (position, arg) in args.iter().enumerate() {
+            let index = match arg.name() {
+                Some(name) => {
+                    is_named = true;
+                    infos
+                        .iter()
+                        .position(|info| info.name() == Some(name))
+                        .ok_or_else(|| ArgError::UnknownName {
+                            name: Cow::Owned(name.to_string()),
+                        })?
+                }
+                None if is_named => {
+                    return Err(ArgError::PositionalAfterNamed { index: position }.into())
+                }
+                None if position < infos.len() => position,
+                None => {
+                    return Err(FunctionError::ArgCountMismatch {
+                        expected: ArgCount::new(infos.len()).unwrap_or_default(),
+                        received: args.len(),
+                    })
+                }
+            };
+
+            if positions[index].replace(position).is_some() {
+                return Err(ArgError::DuplicateArg { index }.into());
+            }
+        }
+
+        for (position, info) in positions.iter().zip(infos) {
+            if position.is_none() && info.default_value().is_none() && !info.is_variadic() {
+                return Err(ArgError::MissingArg {
+                    index: info.index(),
+                }
+                .into());
+            }
+        }
+
+        Ok(positions)
+    }
+
+    /// Reorders a list containing [named arguments] to match the order of a signature's arguments.
+    ///
+    /// Arguments that weren't given are filled in with their [default value],
+    /// or an empty list for [variadic] arguments.
+    ///
+    /// For overloaded functions, the first signature that takes the given arguments is used.
+    ///
+    /// [named arguments]: ArgList::push_named
+    /// [default value]: ArgInfo::with_default
+    /// [variadic]: ArgInfo::with_variadic
+    fn order_named_args<'a>(&self, mut args: ArgList<'a>) -> Result<ArgList<'a>, FunctionError> {
+        let mut error = None;
+        let mut resolved = None;
+        for signature in self.info.signatures() {
+            match Self::named_arg_positions(signature, &args) {
+                Ok(positions) => {
+                    let matches = !self.is_overloaded()
+                        || positions
+                            .iter()
+                            .zip(signature.args())
+                            .all(|(position, info)| {
+                                position.map_or(true, |position| {
+                                    args.iter()
+                                        .nth(position)
+                                        .is_some_and(|arg| Self::arg_matches(info, arg))
+                                })
+                            });
+                    if matches {
+                        resolved = Some((signature, positions));
+                        break;
+                    }
+                }
+                Err(err) => {
+                    error.get_or_insert(err);
+                }
+            }
+        }
+
+        let Some((signature, positions)) = resolved else {
+            return Err(error.filter(|_| !self.is_overloaded()).unwrap_or_else(|| {
+                FunctionError::NoOverload {
+                    expected: self.arg_map.keys().cloned().collect(),
+                    received: ArgumentSignature::from(&args),
+                }
+            }));
+        };
+
+        let mut values = args.split_off(0).map(Some).collect::<Vec<_>>();
+        let mut ordered = ArgList::new();
+        if args.coerces_numbers() {
+            ordered = ordered.with_numeric_coercion();
+        }
+        for (position, info) in positions.into_iter().zip(signature.args()) {
+            ordered = match position.and_then(|position| values[position].take()) {
+                Some(value) => ordered.push_arg(value),
+                None => match info.default_value() {
+                    Some(default) => ordered.push_boxed(default.value()),
+                    None => {
+                        let list = DynamicList::default();
+                        ordered.push_boxed(
+                            info.collect_variadic(&list)
+                                .unwrap_or_else(|| Box::new(list)),
+                        )
+                    }
+                },
+            };
+        }
+
+        Ok(ordered)
+    }
+
+    /// Prepares the given arguments for calling one of the functions.
+    ///
+    /// If the list contains [named arguments], they're first reordered to match the function's arguments.
+    ///
+    /// Otherwise, this fills in the [default values] of the trailing arguments missing from the list,
+    /// and collects the arguments passed to a [variadic] argument into a single list.
+    ///
+    /// The arguments are left untouched if a signature takes exactly the given arguments.
+    /// Otherwise, the first signature whose leading arguments match the given ones is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the named arguments don't match any of the function's signatures.
+    ///
+    /// [named arguments]: ArgList::push_named
+    /// [default values]: ArgInfo::with_default
+    /// [variadic]: ArgInfo::with_variadic
+    pub fn prepare_args<'a>(&self, mut args: ArgList<'a>) -> Result<ArgList<'a>, FunctionError> {
+        if args.iter().any(|arg| arg.name().is_some()) {
+            return self.order_named_args(args);
+        }
+
+        let count = args.len();
+        let signatures = self.info.signatures();
+        let is_exact_match = |signature: &SignatureInfo| {
+            signature.arg_count() == count
+                && ((!self.is_overloaded() && !signature.is_variadic())
+                    || Self::args_match(signature.args(), &args))
+        };
+        if signatures.iter().any(is_exact_match) {
+            return Ok(args);
+        }
+
+        let fixed_arg_count = |signature: &SignatureInfo| {
+            signature.arg_count() - usize::from(signature.is_variadic())
+        };
+        let Some(signature) = signatures.iter().find(|signature| {
+            signature.required_arg_count() <= count
+                && (signature.is_variadic() || count < signature.arg_count())
+                && (!self.is_overloaded()
+                    || (Self::args_match(&signature.args()[..fixed_arg_count(signature)], &args)
+                        && Self::variadic_args_match(signature, &args)))
+        }) else {
+            return Ok(args);
+        };
+
+        let fixed_count = fixed_arg_count(signature);
+        let variadic = signature.is_variadic().then(|| {
+            args.split_off(fixed_count)
+                .map(|value| match value {
+                    ArgValue::Owned(value) => value,
+                    ArgValue::Ref(value) => value.clone_value(),
+                    ArgValue::Mut(value) => value.clone_value(),
+                })
+                .collect::<DynamicList>()
+        });
+
+        for info in &signature.args()[count.min(fixed_count)..fixed_count] {
+            if let Some(default) = info.default_value() {
+                args = args.push_boxed(default.value());
+            }
+        }
+
+        if let Some(list) = variadic {
+            // If the list can't be converted, pass it as is so the function reports the mismatch
+            let info = &signature.args()[fixed_count];
+            args = args.push_boxed(
+                info.collect_variadic(&list)
+                    .unwrap_or_else(|| Box::new(list)),
+            );
+        }
+
+        Ok(args)
+    }
+
+    /// Set the names of the function's arguments, in order.
+    ///
+    /// See [`FunctionInfo::with_arg_names`] for details.
+    pub fn with_arg_names<N: Into<Cow<'static, str>>>(
+        mut self,
+        names: impl IntoIterator<Item = N>,
+    ) -> Self {
+        self.info = self.info.with_arg_names(names);
+        self
+    }
+
+    /// Mark the last argument of the function as [variadic].
+    ///
+    /// See [`FunctionInfo::with_variadic`] for details.
+    ///
+    /// [variadic]: ArgInfo::with_variadic
+    pub fn with_variadic<T: FromReflect + TypePath>(mut self) -> Self {
+        self.info = self.info.with_variadic::<T>();
+        self
+    }
+
+    /// Set the default value of the argument at the given index.
+    ///
+    /// See [`FunctionInfo::with_arg_default`] for details.
+    pub fn with_arg_default<T: PartialReflect + TypePath + Clone + Send + Sync>(
+        mut self,
+        index: usize,
+        value: T,
+    ) -> Self {
+        self.info = self.info.with_arg_default(index, value);
+        self
+    }
+
     /// Returns the function information contained in the map.
     #[inline]
     pub fn info(&self) -> &FunctionInfo {
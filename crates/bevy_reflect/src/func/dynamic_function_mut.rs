@@ -1,6 +1,8 @@
 use alloc::{borrow::Cow, boxed::Box, sync::Arc};
 use core::fmt::{Debug, Formatter};
 
+use crate::{FromReflect, PartialReflect, TypePath};
+
 use crate::func::{
     args::{ArgCount, ArgList},
     dynamic_function_internal::DynamicFunctionInternal,
@@ -112,6 +114,95 @@ impl<'env> DynamicFunctionMut<'env> {
         self
     }
 
+    /// Set the default value of the argument at the given index.
+    ///
+    /// When the function is called with fewer arguments than it takes,
+    /// the missing trailing arguments are filled in with their default values.
+    ///
+    /// For [overloaded] functions, the default is set for every signature
+    /// that takes an owned `T` at the given index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{ArgList, IntoFunctionMut};
+    /// let mut greet = (|name: String, punctuation: char| format!("Hello, {name}{punctuation}"))
+    ///     .into_function_mut()
+    ///     .with_arg_default(1, '!');
+    ///
+    /// let args = ArgList::new().push_owned(String::from("world"));
+    /// let value = greet.call(args).unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<String>().unwrap(), "Hello, world!");
+    ///
+    /// let args = ArgList::new().push_owned(String::from("world")).push_owned('?');
+    /// let value = greet.call(args).unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<String>().unwrap(), "Hello, world?");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function doesn't take an owned `T` at the given index.
+    ///
+    /// [overloaded]: Self::with_overload
+    pub fn with_arg_default<T: PartialReflect + TypePath + Clone + Send + Sync>(
+        mut self,
+        index: usize,
+        value: T,
+    ) -> Self {
+        self.internal = self.internal.with_arg_default(index, value);
+        self
+    }
+
+    /// Set the names of the function's arguments, in order.
+    ///
+    /// See [`DynamicFunction::with_arg_names`] for details.
+    ///
+    /// [`DynamicFunction::with_arg_names`]: crate::func::DynamicFunction::with_arg_names
+    pub fn with_arg_names<N: Into<Cow<'static, str>>>(
+        mut self,
+        names: impl IntoIterator<Item = N>,
+    ) -> Self {
+        self.internal = self.internal.with_arg_names(names);
+        self
+    }
+
+    /// Mark the last argument of the function as [variadic].
+    ///
+    /// When the function is called, any arguments from the index of the last argument onwards
+    /// are collected into a list and converted to `T`, which must be a [list] type such as `Vec<T>`.
+    /// A value of type `T` can still be passed directly as the last argument.
+    ///
+    /// For [overloaded] functions, this applies to every signature whose last argument is an owned `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{ArgList, IntoFunctionMut};
+    /// let mut sum = (|scale: i32, values: Vec<i32>| scale * values.iter().sum::<i32>())
+    ///     .into_function_mut()
+    ///     .with_variadic::<Vec<i32>>();
+    ///
+    /// let args = ArgList::new().push_owned(2_i32).push_owned(1_i32).push_owned(2_i32).push_owned(3_i32);
+    /// let value = sum.call(args).unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<i32>().unwrap(), 12);
+    ///
+    /// let args = ArgList::new().push_owned(2_i32);
+    /// let value = sum.call(args).unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<i32>().unwrap(), 0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function's last argument isn't an owned `T`.
+    ///
+    /// [variadic]: crate::func::args::ArgInfo::with_variadic
+    /// [list]: crate::List
+    /// [overloaded]: Self::with_overload
+    pub fn with_variadic<T: FromReflect + TypePath>(mut self) -> Self {
+        self.internal = self.internal.with_variadic::<T>();
+        self
+    }
+
     /// Add an overload to this function.
     ///
     /// Overloads allow a single [`DynamicFunctionMut`] to represent multiple functions of different signatures.
@@ -233,6 +324,7 @@ impl<'env> DynamicFunctionMut<'env> {
     ///
     /// [`call_once`]: DynamicFunctionMut::call_once
     pub fn call<'a>(&mut self, args: ArgList<'a>) -> FunctionResult<'a> {
+        let args = self.internal.prepare_args(args)?;
         self.internal.validate_args(&args)?;
         let func = self.internal.get_mut(&args)?;
         func(args)
@@ -27,6 +27,27 @@ pub enum FunctionError {
     },
 }
 
+/// An error that occurs when calling a [reflected method] with `PartialReflect::reflect_call`.
+///
+/// [reflected method]: crate::func::ReflectMethods
+#[derive(Debug, Error, PartialEq)]
+pub enum MethodCallError {
+    /// The type of the receiver has no [`ReflectMethods`] registered.
+    ///
+    /// [`ReflectMethods`]: crate::func::ReflectMethods
+    #[error("type `{type_path}` has no registered methods")]
+    NoMethods { type_path: Cow<'static, str> },
+    /// The type of the receiver has no method with the given name.
+    #[error("type `{type_path}` has no method named `{name}`")]
+    MissingMethod {
+        type_path: Cow<'static, str>,
+        name: Cow<'static, str>,
+    },
+    /// An error occurred while calling the method.
+    #[error(transparent)]
+    FunctionError(#[from] FunctionError),
+}
+
 /// The result of calling a [`DynamicFunction`] or [`DynamicFunctionMut`].
 ///
 /// Returns `Ok(value)` if the function was called successfully,
@@ -55,6 +76,21 @@ pub enum FunctionOverloadError {
     TooManyArguments(ArgumentSignature),
 }
 
+/// An error that occurs when chaining functions with [`DynamicFunction::then`] or a [`FunctionPipeline`].
+///
+/// [`DynamicFunction::then`]: crate::func::DynamicFunction::then
+/// [`FunctionPipeline`]: crate::func::FunctionPipeline
+#[derive(Debug, Error, PartialEq)]
+pub enum FunctionPipelineError {
+    /// No signature of the function at the given step takes the return value of the previous step
+    /// as its only argument.
+    #[error("function at step {step} cannot take the return value of the previous step as its only argument")]
+    SignatureMismatch {
+        /// The index of the function in the chain, starting from `0`.
+        step: usize,
+    },
+}
+
 /// An error that occurs when registering a function into a [`FunctionRegistry`].
 ///
 /// [`FunctionRegistry`]: crate::func::FunctionRegistry
@@ -68,4 +104,10 @@ pub enum FunctionRegistrationError {
     /// The function is missing a name by which it can be registered.
     #[error("function name is missing")]
     MissingName,
+    /// The function could not be added as an overload of the function registered with the same name.
+    #[error("could not overload function {name:?}: {error}")]
+    Overload {
+        name: Cow<'static, str>,
+        error: FunctionOverloadError,
+    },
 }
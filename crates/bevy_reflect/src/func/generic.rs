@@ -0,0 +1,192 @@
+use alloc::borrow::Cow;
+
+/// Creates a [`DynamicFunction`] from a set of instantiations of a generic function.
+///
+/// Each instantiation is added as an [overload], so calling the function picks the
+/// monomorphization matching the types of the given arguments.
+/// The function is named after the generic function itself, without any generic arguments.
+///
+/// Functions with a single generic parameter take a list of types,
+/// while functions with multiple generic parameters take a list of `<...>` groups.
+///
+/// See [`DynamicFunction::from_instantiations`] for the non-macro equivalent.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::generic_function;
+/// # use bevy_reflect::func::ArgList;
+/// # use core::ops::Add;
+/// fn add<T: Add<Output = T>>(a: T, b: T) -> T {
+///     a + b
+/// }
+///
+/// fn convert<A: Into<B>, B>(value: A) -> B {
+///     value.into()
+/// }
+///
+/// let add = generic_function!(add, [i32, f32, f64]);
+/// assert!(add.name().unwrap().ends_with("::add"));
+///
+/// let args = ArgList::new().push_owned(1.5_f32).push_owned(2.0_f32);
+/// let value = add.call(args).unwrap().unwrap_owned();
+/// assert_eq!(value.try_take::<f32>().unwrap(), 3.5);
+///
+/// let convert = generic_function!(convert, [<u8, u32>, <f32, f64>]);
+///
+/// let args = ArgList::new().push_owned(7_u8);
+/// let value = convert.call(args).unwrap().unwrap_owned();
+/// assert_eq!(value.try_take::<u32>().unwrap(), 7);
+/// ```
+///
+/// # Panics
+///
+/// Panics if two instantiations have the same argument signature.
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+/// [overload]: crate::func::DynamicFunction::with_overload
+/// [`DynamicFunction::from_instantiations`]: crate::func::DynamicFunction::from_instantiations
+#[macro_export]
+macro_rules! generic_function {
+    (@instantiate [$($func:ident)::+] [$($done:expr),*] []) => {
+        $crate::func::DynamicFunction::from_instantiations([$($done),*])
+            .unwrap_or_else(|err| panic!("{}", err))
+    };
+    (@instantiate [$($func:ident)::+] [$($done:expr),*] [<$($T:ty),+> $(, $($rest:tt)*)?]) => {
+        $crate::generic_function!(
+            @instantiate [$($func)::+]
+            [$($done,)* $crate::func::IntoFunction::into_function($($func)::+::<$($T),+>)]
+            [$($($rest)*)?]
+        )
+    };
+    ($($func:ident)::+, [$(<$($T:ty),+ $(,)?>),+ $(,)?]) => {
+        $crate::generic_function!(@instantiate [$($func)::+] [] [$(<$($T),+>),+])
+    };
+    ($($func:ident)::+, [$($T:ty),+ $(,)?]) => {
+        $crate::generic_function!(@instantiate [$($func)::+] [] [$(<$T>),+])
+    };
+}
+
+/// Returns the given function name without its trailing generic arguments.
+///
+/// For example, `my_crate::add<i32>` becomes `my_crate::add`,
+/// while `my_crate::Foo<i32>::bar` is returned unchanged.
+pub(super) fn generic_name(name: Cow<'static, str>) -> Cow<'static, str> {
+    let Some(start) = generic_args_start(&name) else {
+        return name;
+    };
+
+    match name {
+        Cow::Borrowed(name) => Cow::Borrowed(&name[..start]),
+        Cow::Owned(mut name) => {
+            name.truncate(start);
+            Cow::Owned(name)
+        }
+    }
+}
+
+/// Returns the index of the `<` opening the trailing generic arguments of the given name, if any.
+fn generic_args_start(name: &str) -> Option<usize> {
+    if !name.ends_with('>') {
+        return None;
+    }
+
+    let mut depth = 0_usize;
+    for (index, char) in name.char_indices().rev() {
+        match char {
+            '>' => depth += 1,
+            '<' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::func::{ArgList, DynamicFunction, FunctionRegistry, IntoFunction};
+    use alloc::{format, string::String, vec::Vec};
+    use core::ops::Add;
+
+    fn add<T: Add<Output = T>>(a: T, b: T) -> T {
+        a + b
+    }
+
+    fn wrap<T>(value: T) -> Vec<T> {
+        alloc::vec![value]
+    }
+
+    #[test]
+    fn should_strip_generic_args() {
+        assert_eq!(
+            generic_name(Cow::Borrowed("my_crate::add<i32>")),
+            "my_crate::add"
+        );
+        assert_eq!(
+            generic_name(Cow::Owned(String::from(
+                "my_crate::wrap<alloc::vec::Vec<u8>>"
+            ))),
+            "my_crate::wrap"
+        );
+        assert_eq!(
+            generic_name(Cow::Borrowed("my_crate::Foo<i32>::bar")),
+            "my_crate::Foo<i32>::bar"
+        );
+        assert_eq!(
+            generic_name(Cow::Borrowed("my_crate::add")),
+            "my_crate::add"
+        );
+    }
+
+    #[test]
+    fn should_call_matching_instantiation() {
+        let func = generic_function!(add, [i32, f32, u64]);
+        assert_eq!(func.name().unwrap(), &format!("{}::add", module_path!()));
+
+        let args = ArgList::new().push_owned(1_i32).push_owned(2_i32);
+        let value = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(value.try_take::<i32>().unwrap(), 3);
+
+        let args = ArgList::new().push_owned(1_u64).push_owned(2_u64);
+        let value = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(value.try_take::<u64>().unwrap(), 3);
+
+        let func = generic_function!(self::wrap, [Vec<u8>, u8]);
+        let args = ArgList::new().push_owned(alloc::vec![1_u8]);
+        let value = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(value.try_take::<Vec<Vec<u8>>>().unwrap(), [[1]]);
+    }
+
+    #[test]
+    fn should_register_generic_function() {
+        let mut registry = FunctionRegistry::default();
+        registry
+            .register_generic([add::<i32>.into_function(), add::<f64>.into_function()])
+            .unwrap();
+
+        let name = format!("{}::add", module_path!());
+        let args = ArgList::new().push_owned(0.5_f64).push_owned(0.25_f64);
+        let value = registry.call(&name, args).unwrap().unwrap().unwrap_owned();
+        assert_eq!(value.try_take::<f64>().unwrap(), 0.75);
+
+        // New instantiations are added as overloads of the existing registration.
+        registry
+            .register_generic([add::<u8>.into_function()])
+            .unwrap();
+        assert_eq!(registry.get(&name).unwrap().info().signatures().len(), 3);
+
+        assert!(registry
+            .register_generic([add::<i32>.into_function()])
+            .is_err());
+        assert!(registry
+            .register_generic(Vec::<DynamicFunction>::new())
+            .is_err());
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
     func::signature::ArgumentSignature,
     func::FunctionOverloadError,
     type_info::impl_type_methods,
-    Type, TypePath,
+    FromReflect, PartialReflect, Type, TypePath,
 };
 
 use variadics_please::all_tuples;
@@ -36,9 +36,12 @@ impl FunctionInfo {
     /// Panics if the given signature has more than the maximum number of arguments
     /// as specified by [`ArgCount::MAX_COUNT`].
     pub fn new(signature: SignatureInfo) -> Self {
+        let mut arg_count = ArgCount::new(signature.arg_count()).unwrap();
+        signature.add_arg_counts(&mut arg_count);
+
         Self {
             name: signature.name.clone(),
-            arg_count: ArgCount::new(signature.arg_count()).unwrap(),
+            arg_count,
             signatures: vec![signature].into(),
         }
     }
@@ -134,7 +137,7 @@ impl FunctionInfo {
             return Err(signature);
         }
 
-        self.arg_count.add(signature.arg_count());
+        signature.add_arg_counts(&mut self.arg_count);
         self.signatures = IntoIterator::into_iter(self.signatures)
             .chain(Some(signature))
             .collect();
@@ -151,6 +154,169 @@ impl FunctionInfo {
         self.arg_count
     }
 
+    /// Set the default value of the argument at the given index.
+    ///
+    /// The default is set for every signature that takes an [owned] `T` at that index,
+    /// which allows it to be used for [overloaded] functions as well.
+    /// See [`ArgInfo::with_default`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no signature takes an owned `T` at the given index.
+    ///
+    /// [owned]: crate::func::args::Ownership::Owned
+    /// [overloaded]: crate::func#overloading-functions
+    pub fn with_arg_default<T: PartialReflect + TypePath + Clone + Send + Sync>(
+        mut self,
+        index: usize,
+        value: T,
+    ) -> Self {
+        let mut found = false;
+        let mut arg_count = ArgCount::default();
+        self.signatures = IntoIterator::into_iter(self.signatures)
+            .map(|signature| {
+                let takes_default = signature.args.get(index).is_some_and(|arg| {
+                    arg.ownership() == Ownership::Owned && *arg.value_type() == Type::of::<T>()
+                });
+                let signature = if takes_default {
+                    found = true;
+                    signature.with_arg_default(index, value.clone())
+                } else {
+                    signature
+                };
+                arg_count.add(signature.arg_count());
+                signature.add_arg_counts(&mut arg_count);
+                signature
+            })
+            .collect();
+
+        assert!(
+            found,
+            "no signature takes an owned `{}` at index {index}",
+            T::type_path()
+        );
+
+        self.arg_count = arg_count;
+        self
+    }
+
+    /// Set the names of the function's arguments, in order.
+    ///
+    /// The names are applied to the leading arguments of every signature,
+    /// which allows them to be used for [overloaded] functions as well.
+    /// See [`SignatureInfo::with_arg_names`] for details.
+    ///
+    /// [overloaded]: crate::func#overloading-functions
+    pub fn with_arg_names<N: Into<Cow<'static, str>>>(
+        mut self,
+        names: impl IntoIterator<Item = N>,
+    ) -> Self {
+        let names = names.into_iter().map(Into::into).collect::<Vec<_>>();
+        self.signatures = IntoIterator::into_iter(self.signatures)
+            .map(|signature| {
+                let count = signature.arg_count().min(names.len());
+                signature.with_arg_names(names[..count].iter().cloned())
+            })
+            .collect();
+        self
+    }
+
+    /// Returns the information of this function with the argument at the given index removed.
+    ///
+    /// Only the signatures whose argument at that index satisfies `predicate` are kept.
+    /// Returns `None` if there are no such signatures.
+    pub(super) fn without_arg(
+        &self,
+        index: usize,
+        predicate: impl Fn(&ArgInfo) -> bool,
+    ) -> Option<Self> {
+        let signatures = self
+            .signatures
+            .iter()
+            .filter(|signature| signature.args.get(index).is_some_and(&predicate))
+            .map(|signature| signature.without_arg(index));
+
+        FunctionInfo::try_from_iter(signatures)
+            .ok()
+            .map(|info| info.with_name(self.name.clone()))
+    }
+
+    /// Returns the information of a function that passes the return value of this function
+    /// as the only argument of the `next` function.
+    ///
+    /// Each signature of this function is paired with the first signature of `next`
+    /// whose first argument matches its return type and ownership,
+    /// and whose remaining arguments all have default values.
+    /// The resulting signatures take the arguments of this function and return the output of `next`.
+    ///
+    /// Returns `None` if no signatures can be paired.
+    pub(super) fn then(&self, next: &FunctionInfo) -> Option<Self> {
+        let signatures = self.signatures.iter().filter_map(|signature| {
+            let return_info = signature.return_info();
+            next.signatures
+                .iter()
+                .find(|next| {
+                    next.required_arg_count() <= 1
+                        && next.args.first().is_some_and(|arg| {
+                            !arg.is_variadic()
+                                && arg.ownership() == return_info.ownership()
+                                && arg.ty() == return_info.ty()
+                        })
+                })
+                .map(|next| SignatureInfo {
+                    name: None,
+                    return_info: next.return_info.clone(),
+                    ..signature.clone()
+                })
+        });
+
+        FunctionInfo::try_from_iter(signatures)
+            .ok()
+            .map(|info| info.with_name(None::<Cow<'static, str>>))
+    }
+
+    /// Mark the last argument of the function as [variadic].
+    ///
+    /// This applies to every signature whose last argument is an [owned] `T`,
+    /// which allows it to be used for [overloaded] functions as well.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no signature's last argument is an owned `T`.
+    ///
+    /// [variadic]: ArgInfo::with_variadic
+    /// [owned]: crate::func::args::Ownership::Owned
+    /// [overloaded]: crate::func#overloading-functions
+    pub fn with_variadic<T: FromReflect + TypePath>(mut self) -> Self {
+        let mut found = false;
+        let mut arg_count = ArgCount::default();
+        self.signatures = IntoIterator::into_iter(self.signatures)
+            .map(|signature| {
+                let takes_variadic = signature.args.last().is_some_and(|arg| {
+                    arg.ownership() == Ownership::Owned && *arg.value_type() == Type::of::<T>()
+                });
+                let signature = if takes_variadic {
+                    found = true;
+                    signature.with_variadic::<T>()
+                } else {
+                    signature
+                };
+                arg_count.add(signature.arg_count());
+                signature.add_arg_counts(&mut arg_count);
+                signature
+            })
+            .collect();
+
+        assert!(
+            found,
+            "no signature takes an owned `{}` as its last argument",
+            T::type_path()
+        );
+
+        self.arg_count = arg_count;
+        self
+    }
+
     /// The signatures of the function.
     ///
     /// This is guaranteed to always contain at least one signature.
@@ -287,6 +453,109 @@ impl SignatureInfo {
         self
     }
 
+    /// Set the default value of the argument at the given index.
+    ///
+    /// See [`ArgInfo::with_default`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no argument at the given index,
+    /// or if it doesn't take an [owned] `T`.
+    ///
+    /// [owned]: crate::func::args::Ownership::Owned
+    pub fn with_arg_default<T: PartialReflect + TypePath + Clone + Send + Sync>(
+        mut self,
+        index: usize,
+        value: T,
+    ) -> Self {
+        let arg = self
+            .args
+            .get_mut(index)
+            .unwrap_or_else(|| panic!("no argument at index {index}"));
+        *arg = arg.clone().with_default(value);
+        self
+    }
+
+    /// Set the names of the function's arguments, in order.
+    ///
+    /// This is useful for naming the arguments of signatures created with [`IntoFunction`],
+    /// which can't infer them from the function type alone.
+    /// Named arguments can then be passed to the function with [`ArgList::push_named`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if more names are given than the function has arguments.
+    ///
+    /// [`IntoFunction`]: crate::func::IntoFunction
+    /// [`ArgList::push_named`]: crate::func::ArgList::push_named
+    pub fn with_arg_names<N: Into<Cow<'static, str>>>(
+        mut self,
+        names: impl IntoIterator<Item = N>,
+    ) -> Self {
+        let mut args = self.args.iter_mut();
+        for name in names {
+            let arg = args
+                .next()
+                .expect("received more argument names than arguments");
+            *arg = arg.clone().with_name(name);
+        }
+        self
+    }
+
+    /// Returns a copy of this signature with the argument at the given index removed.
+    fn without_arg(&self, index: usize) -> Self {
+        let args = self
+            .args
+            .iter()
+            .enumerate()
+            .filter(|(arg_index, _)| *arg_index != index)
+            .enumerate()
+            .map(|(new_index, (_, arg))| arg.clone().with_index(new_index))
+            .collect();
+
+        Self {
+            args,
+            ..self.clone()
+        }
+    }
+
+    /// Push a [variadic] argument onto the function's argument list.
+    ///
+    /// This must be the last argument of the function.
+    ///
+    /// [variadic]: ArgInfo::with_variadic
+    pub fn with_variadic_arg<T: FromReflect + TypePath + GetOwnership>(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        let index = self.args.len();
+        self.args = IntoIterator::into_iter(self.args)
+            .chain(Some(
+                ArgInfo::new::<T>(index)
+                    .with_name(name)
+                    .with_variadic::<T>(),
+            ))
+            .collect();
+        self
+    }
+
+    /// Mark the last argument of the function as [variadic].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function has no arguments, or if its last argument isn't an [owned] `T`.
+    ///
+    /// [variadic]: ArgInfo::with_variadic
+    /// [owned]: crate::func::args::Ownership::Owned
+    pub fn with_variadic<T: FromReflect + TypePath>(mut self) -> Self {
+        let arg = self
+            .args
+            .last_mut()
+            .expect("a function without arguments can't be variadic");
+        *arg = arg.clone().with_variadic::<T>();
+        self
+    }
+
     /// Set the arguments of the function.
     ///
     /// This will completely replace any existing arguments.
@@ -345,6 +614,41 @@ impl SignatureInfo {
         self.args.len()
     }
 
+    /// The number of arguments that must be provided when calling the function.
+    ///
+    /// This excludes the trailing arguments that have a [default value],
+    /// which are filled in when omitted.
+    ///
+    /// [default value]: ArgInfo::with_default
+    pub fn required_arg_count(&self) -> usize {
+        self.args.len()
+            - self
+                .args
+                .iter()
+                .rev()
+                .take_while(|arg| arg.default_value().is_some() || arg.is_variadic())
+                .count()
+    }
+
+    /// Returns `true` if the last argument of the function is [variadic].
+    ///
+    /// [variadic]: ArgInfo::with_variadic
+    pub fn is_variadic(&self) -> bool {
+        self.args.last().is_some_and(ArgInfo::is_variadic)
+    }
+
+    /// Adds the argument counts this signature can be called with, besides [`Self::arg_count`].
+    fn add_arg_counts(&self, arg_count: &mut ArgCount) {
+        let max_count = if self.is_variadic() {
+            ArgCount::MAX_COUNT
+        } else {
+            self.arg_count()
+        };
+        for count in self.required_arg_count()..=max_count {
+            arg_count.add(count);
+        }
+    }
+
     /// The return information of the function.
     pub fn return_info(&self) -> &ReturnInfo {
         &self.return_info
@@ -725,7 +1029,7 @@ all_tuples!(impl_typed_function, 0, 15, Arg, arg);
 /// | Function pointer   | `fn() -> String`        | `None`                  |
 ///
 /// [`type_name`]: core::any::type_name
-fn create_info<F>() -> SignatureInfo {
+pub(super) fn create_info<F>() -> SignatureInfo {
     let name = core::any::type_name::<F>();
 
     if name.ends_with("{{closure}}") || name.starts_with("fn(") {
@@ -0,0 +1,100 @@
+use alloc::{boxed::Box, vec};
+use core::future::Future;
+
+use variadics_please::all_tuples;
+
+use crate::{
+    func::{
+        args::{ArgCount, ArgInfo, FromArg, GetOwnership},
+        info::create_info,
+        macros::count_tokens,
+        DynamicAsyncFunction, FunctionError, FunctionFuture, IntoReturn,
+    },
+    Reflect, TypePath,
+};
+
+/// A trait for types that can be converted into a [`DynamicAsyncFunction`].
+///
+/// This trait is automatically implemented for `async` functions, and for functions and closures
+/// returning a [`Future`], as long as their arguments and output implement [`Reflect`].
+///
+/// Because the returned future must not borrow from the arguments it was called with,
+/// only functions taking owned arguments can be converted.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, IntoAsyncFunction};
+/// # use bevy_tasks::block_on;
+/// async fn add(a: i32, b: i32) -> i32 {
+///     a + b
+/// }
+///
+/// let func = add.into_async_function();
+/// let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+/// let value = block_on(func.call(args)).unwrap().unwrap_owned();
+/// assert_eq!(value.try_take::<i32>().unwrap(), 100);
+/// ```
+pub trait IntoAsyncFunction<'env, Marker> {
+    /// Converts [`Self`] into a [`DynamicAsyncFunction`].
+    fn into_async_function(self) -> DynamicAsyncFunction<'env>;
+}
+
+macro_rules! impl_into_async_function {
+    ($(($Arg:ident, $arg:ident)),*) => {
+        // === (...) -> impl Future<Output = ReturnType> === //
+        impl<'env, $($Arg,)* ReturnType, Fut, Function> IntoAsyncFunction<'env, fn($($Arg),*) -> [Fut]> for Function
+        where
+            // This clause ensures that arguments are owned,
+            // so that the future doesn't borrow from the argument list
+            $($Arg: for<'a> FromArg<This<'a> = $Arg> + TypePath + GetOwnership,)*
+            ReturnType: IntoReturn + Reflect + TypePath + GetOwnership,
+            Fut: Future<Output = ReturnType> + Send + 'static,
+            Function: Fn($($Arg),*) -> Fut + Send + Sync + 'env,
+        {
+            #[expect(
+                clippy::allow_attributes,
+                reason = "This lint is part of a macro, which may not always trigger the `unused_mut` lint."
+            )]
+            #[allow(
+                unused_mut,
+                unused_variables,
+                reason = "Some invocations of this macro may trigger the `unused_mut` or `unused_variables` lints, where others won't."
+            )]
+            fn into_async_function(self) -> DynamicAsyncFunction<'env> {
+                const COUNT: usize = count_tokens!($($Arg)*);
+
+                let mut index = 0;
+                let info = create_info::<Function>()
+                    .with_args(vec![$(ArgInfo::new::<$Arg>({
+                        index += 1;
+                        index - 1
+                    })),*])
+                    .with_return::<ReturnType>();
+
+                DynamicAsyncFunction::new(
+                    move |mut args| -> FunctionFuture {
+                        if args.len() != COUNT {
+                            return Box::pin(core::future::ready(Err(FunctionError::ArgCountMismatch {
+                                expected: ArgCount::new(COUNT).unwrap(),
+                                received: args.len(),
+                            })));
+                        }
+
+                        // Extract all arguments (in order) before creating the future
+                        $(let $arg = match args.take::<$Arg>() {
+                            Ok(arg) => arg,
+                            Err(err) => return Box::pin(core::future::ready(Err(err.into()))),
+                        };)*
+
+                        let future = (self)($($arg,)*);
+                        Box::pin(async move { Ok(future.await.into_return()) })
+                    },
+                    info,
+                )
+            }
+        }
+    };
+}
+
+all_tuples!(impl_into_async_function, 0, 15, Arg, arg);
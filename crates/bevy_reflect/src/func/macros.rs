@@ -24,8 +24,21 @@
 /// ```ignore
 /// impl_function_traits!(foo::bar::Baz<T, N>; <T> [const N: usize]);
 /// ```
+///
+/// Types implementing [`FromReflect`] can be prefixed with `@from_reflect`,
+/// so that owned arguments which only represent the type can also be converted:
+///
+/// ```ignore
+/// impl_function_traits!(@from_reflect foo::bar::Baz<T>; <T: FromReflect>);
+/// ```
+///
+/// [`FromReflect`]: crate::FromReflect
 macro_rules! impl_function_traits {
+    (@from_reflect $($tokens:tt)*) => {
+        $crate::func::macros::impl_function_traits!(@impl [@from_reflect] $($tokens)*);
+    };
     (
+        @impl [$($from_arg_mode:tt)*]
         $ty: ty
         $(;
             <
@@ -60,6 +73,7 @@ macro_rules! impl_function_traits {
             )?
         );
         $crate::func::args::impl_from_arg!(
+            $($from_arg_mode)*
             $ty
             $(;
                 <
@@ -94,6 +108,9 @@ macro_rules! impl_function_traits {
             )?
         );
     };
+    ($($tokens:tt)*) => {
+        $crate::func::macros::impl_function_traits!(@impl [] $($tokens)*);
+    };
 }
 
 pub(crate) use impl_function_traits;
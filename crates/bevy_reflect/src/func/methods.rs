@@ -0,0 +1,310 @@
+use alloc::borrow::Cow;
+use core::{
+    any::TypeId,
+    fmt::{Debug, Formatter},
+};
+
+use bevy_utils::HashMap;
+
+use crate::{
+    func::{
+        args::{ArgValue, Ownership},
+        ArgList, DynamicFunction, FunctionRegistrationError, FunctionResult, IntoFunction,
+        MethodCallError, Return,
+    },
+    FromType, GetTypeRegistration, PartialReflect, TypeRegistry,
+};
+
+/// Type data containing the [reflected methods] of a type.
+///
+/// Methods are regular [`DynamicFunction`]s whose first argument is the receiver,
+/// i.e. `&self` or `&mut self`.
+/// They can be called on a reflected value of the type with `PartialReflect::reflect_call`,
+/// which passes the value as the receiver.
+///
+/// Methods are most easily registered with [`TypeRegistry::register_method`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{PartialReflect, Reflect, TypeRegistry};
+/// # use bevy_reflect::func::ArgList;
+/// #[derive(Reflect)]
+/// struct Player {
+///     health: i32,
+/// }
+///
+/// impl Player {
+///     fn damage(&mut self, amount: i32) {
+///         self.health -= amount;
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register_method::<Player, _, _>("damage", Player::damage).unwrap();
+///
+/// let mut player = Player { health: 100 };
+/// let args = ArgList::new().push_owned(25_i32);
+/// player.as_partial_reflect_mut().reflect_call(&registry, "damage", args).unwrap();
+/// assert_eq!(player.health, 75);
+/// ```
+///
+/// [reflected methods]: crate::func#method-reflection
+#[derive(Clone, Default)]
+pub struct ReflectMethods {
+    methods: HashMap<Cow<'static, str>, DynamicFunction<'static>>,
+}
+
+impl ReflectMethods {
+    /// Registers the given function as a method with the given name.
+    ///
+    /// The first argument of the function is the receiver, which must be taken by reference.
+    ///
+    /// If a method with the same name has already been registered, an error is returned.
+    pub fn register<F, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        method: F,
+    ) -> Result<&mut Self, FunctionRegistrationError>
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        let name = name.into();
+        if self.methods.contains_key(&name) {
+            return Err(FunctionRegistrationError::DuplicateName(name));
+        }
+
+        let method = method.into_function().with_name(name.clone());
+        self.methods.insert(name, method);
+        Ok(self)
+    }
+
+    /// Calls the method with the given name on `receiver`.
+    ///
+    /// The receiver is passed as the first argument before the ones in `args`,
+    /// either by reference or mutable reference depending on what the method takes.
+    ///
+    /// Returns `None` if no method with the given name has been registered,
+    /// otherwise returns the result of the method call.
+    pub fn call<'a>(
+        &self,
+        name: &str,
+        receiver: &'a mut dyn PartialReflect,
+        mut args: ArgList<'a>,
+    ) -> Option<FunctionResult<'a>> {
+        let method = self.get(name)?;
+        let is_ref = method
+            .info()
+            .base()
+            .args()
+            .first()
+            .is_some_and(|arg| arg.ownership() == Ownership::Ref);
+        let receiver = if is_ref {
+            ArgValue::Ref(receiver)
+        } else {
+            ArgValue::Mut(receiver)
+        };
+
+        args.insert_arg(0, receiver);
+        Some(method.call(args))
+    }
+
+    /// Get a reference to the method with the given name.
+    pub fn get(&self, name: &str) -> Option<&DynamicFunction<'static>> {
+        self.methods.get(name)
+    }
+
+    /// Returns `true` if a method with the given name has been registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.methods.contains_key(name)
+    }
+
+    /// Returns an iterator over the names and functions of the registered methods.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, &DynamicFunction<'static>)> {
+        self.methods
+            .iter()
+            .map(|(name, method)| (name.as_ref(), method))
+    }
+
+    /// Returns the number of registered methods.
+    pub fn len(&self) -> usize {
+        self.methods.len()
+    }
+
+    /// Returns `true` if no methods have been registered.
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+}
+
+impl Debug for ReflectMethods {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.methods.values()).finish()
+    }
+}
+
+impl<T> FromType<T> for ReflectMethods {
+    fn from_type() -> Self {
+        Self::default()
+    }
+}
+
+impl TypeRegistry {
+    /// Registers the given function as a [method] of `T` with the given name.
+    ///
+    /// This registers `T` and its [`ReflectMethods`] type data if they haven't been registered yet.
+    ///
+    /// If `T` already has a method with the same name, an error is returned.
+    ///
+    /// [method]: ReflectMethods
+    pub fn register_method<T, F, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        method: F,
+    ) -> Result<&mut Self, FunctionRegistrationError>
+    where
+        T: GetTypeRegistration,
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        self.register::<T>();
+        let registration = self
+            .get_mut(TypeId::of::<T>())
+            .expect("type should be registered");
+        if !registration.contains::<ReflectMethods>() {
+            registration.insert(ReflectMethods::default());
+        }
+
+        registration
+            .data_mut::<ReflectMethods>()
+            .expect("type data should be registered")
+            .register(name, method)?;
+        Ok(self)
+    }
+}
+
+impl dyn PartialReflect {
+    /// Calls the [reflected method] with the given name on this value.
+    ///
+    /// The method is looked up in the [`ReflectMethods`] registered for the type this value represents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type has no registered methods, if it has no method with the given name,
+    /// or if the method call itself fails.
+    ///
+    /// [reflected method]: ReflectMethods
+    pub fn reflect_call<'a>(
+        &'a mut self,
+        registry: &TypeRegistry,
+        name: &str,
+        args: ArgList<'a>,
+    ) -> Result<Return<'a>, MethodCallError> {
+        let type_path = || Cow::Owned(self.reflect_type_path().into());
+        let methods = self
+            .get_represented_type_info()
+            .and_then(|info| registry.get_type_data::<ReflectMethods>(info.type_id()))
+            .ok_or_else(|| MethodCallError::NoMethods {
+                type_path: type_path(),
+            })?;
+
+        if !methods.contains(name) {
+            return Err(MethodCallError::MissingMethod {
+                type_path: type_path(),
+                name: Cow::Owned(name.into()),
+            });
+        }
+
+        Ok(methods
+            .call(name, self, args)
+            .expect("method should exist")?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_reflect, func::FunctionError, Reflect};
+    use alloc::string::String;
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    struct Counter {
+        count: i32,
+    }
+
+    impl Counter {
+        fn get(&self) -> i32 {
+            self.count
+        }
+
+        fn add(&mut self, amount: i32) -> &i32 {
+            self.count += amount;
+            &self.count
+        }
+    }
+
+    #[test]
+    fn should_call_registered_methods() {
+        let mut registry = TypeRegistry::default();
+        registry
+            .register_method::<Counter, _, _>("get", Counter::get)
+            .unwrap()
+            .register_method::<Counter, _, _>("add", Counter::add)
+            .unwrap();
+
+        let mut counter = Counter { count: 1 };
+        let value = counter
+            .as_partial_reflect_mut()
+            .reflect_call(&registry, "add", ArgList::new().push_owned(2_i32))
+            .unwrap();
+        assert_eq!(value.unwrap_ref().try_downcast_ref::<i32>(), Some(&3));
+
+        let value = counter
+            .as_partial_reflect_mut()
+            .reflect_call(&registry, "get", ArgList::new())
+            .unwrap();
+        assert_eq!(value.unwrap_owned().try_take::<i32>().unwrap(), 3);
+
+        let methods = registry
+            .get_type_data::<ReflectMethods>(TypeId::of::<Counter>())
+            .unwrap();
+        assert_eq!(methods.len(), 2);
+    }
+
+    #[test]
+    fn should_error_on_invalid_method_calls() {
+        let mut registry = TypeRegistry::default();
+        registry
+            .register_method::<Counter, _, _>("get", Counter::get)
+            .unwrap();
+
+        let mut counter = Counter { count: 1 };
+        let result =
+            counter
+                .as_partial_reflect_mut()
+                .reflect_call(&registry, "reset", ArgList::new());
+        assert!(matches!(result, Err(MethodCallError::MissingMethod { .. })));
+
+        let result = counter.as_partial_reflect_mut().reflect_call(
+            &registry,
+            "get",
+            ArgList::new().push_owned(1_i32),
+        );
+        assert!(matches!(
+            result,
+            Err(MethodCallError::FunctionError(
+                FunctionError::ArgCountMismatch { .. }
+            ))
+        ));
+
+        let mut value = String::from("text");
+        let result = value
+            .as_partial_reflect_mut()
+            .reflect_call(&registry, "get", ArgList::new());
+        assert!(matches!(result, Err(MethodCallError::NoMethods { .. })));
+
+        assert!(matches!(
+            registry.register_method::<Counter, _, _>("get", Counter::get),
+            Err(FunctionRegistrationError::DuplicateName(_))
+        ));
+    }
+}
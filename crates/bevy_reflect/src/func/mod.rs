@@ -106,6 +106,25 @@
 //!
 //! This means that reflected functions cannot be generic themselves.
 //! To get around this limitation, you can consider [overloading] your function with multiple concrete types.
+//! The [`generic_function`] macro does this for a list of concrete types,
+//! creating a single function named after the generic one:
+//!
+//! ```
+//! # use bevy_reflect::generic_function;
+//! # use bevy_reflect::func::ArgList;
+//! # use core::ops::Add;
+//! fn add<T: Add<Output = T>>(a: T, b: T) -> T {
+//!     a + b
+//! }
+//!
+//! let add = generic_function!(add, [i32, f32]);
+//!
+//! let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+//! let value = add.call(args).unwrap().unwrap_owned();
+//! assert_eq!(value.try_take::<i32>().unwrap(), 100);
+//! ```
+//!
+//! Such functions can be registered in a [`FunctionRegistry`] using [`FunctionRegistry::register_generic`].
 //!
 //! # Overloading Functions
 //!
@@ -120,6 +139,26 @@
 //!
 //! To learn more, see the docs on [`DynamicFunction::with_overload`].
 //!
+//! # Asynchronous Functions
+//!
+//! Functions that need to perform IO or other long-running work can be represented by a [`DynamicAsyncFunction`],
+//! which can be created from `async` functions and closures returning a future using [`IntoAsyncFunction`].
+//! Calling it returns a [`FunctionFuture`] that resolves to the result of the call,
+//! and can be awaited or spawned onto a task pool.
+//!
+//! # Function Composition
+//!
+//! Functions can be chained together with [`DynamicFunction::then`] or a [`FunctionPipeline`],
+//! which pass the [`Return`] value of each function as the only argument of the next one.
+//! This allows data-driven processing chains to be built without writing glue closures.
+//!
+//! # Method Reflection
+//!
+//! Methods of a type can be registered in the [`TypeRegistry`] as [`ReflectMethods`] type data
+//! using [`TypeRegistry::register_method`].
+//! They can then be called on any reflected value of that type with `PartialReflect::reflect_call`,
+//! which passes the value as the receiver.
+//!
 //! # Function Registration
 //!
 //! This module also provides a [`FunctionRegistry`] that can be used to register functions and closures
@@ -149,38 +188,55 @@
 //! assert_eq!(value.unwrap_owned().try_downcast_ref::<i32>(), Some(&50));
 //! ```
 //!
+//! Registered functions can be referred to from scenes and other data files with a [`FunctionReference`],
+//! which serializes as the name of the function.
+//!
 //! [`PartialReflect`]: crate::PartialReflect
+//! [`TypeRegistry`]: crate::TypeRegistry
+//! [`TypeRegistry::register_method`]: crate::TypeRegistry::register_method
 //! [`Reflect`]: crate::Reflect
 //! [lack of variadic generics]: https://poignardazur.github.io/2024/05/25/report-on-rustnl-variadics/
 //! [coherence issues]: https://doc.rust-lang.org/rustc/lints/listing/warn-by-default.html#coherence-leak-check
 //! [monomorphized]: https://en.wikipedia.org/wiki/Monomorphization
+//! [`generic_function`]: crate::generic_function
 //! [overloading]: #overloading-functions
 //! [function overloading]: https://en.wikipedia.org/wiki/Function_overloading
 //! [variadic functions]: https://en.wikipedia.org/wiki/Variadic_function
 
 pub use args::{ArgError, ArgList, ArgValue};
+pub use dynamic_async_function::*;
 pub use dynamic_function::*;
 pub use dynamic_function_mut::*;
 pub use error::*;
 pub use function::*;
 pub use info::*;
+pub use into_async_function::*;
 pub use into_function::*;
 pub use into_function_mut::*;
+pub use methods::*;
+pub use pipeline::*;
+pub use reference::*;
 pub use reflect_fn::*;
 pub use reflect_fn_mut::*;
 pub use registry::*;
 pub use return_type::*;
 
 pub mod args;
+mod dynamic_async_function;
 mod dynamic_function;
 mod dynamic_function_internal;
 mod dynamic_function_mut;
 mod error;
 mod function;
+mod generic;
 mod info;
+mod into_async_function;
 mod into_function;
 mod into_function_mut;
 pub(crate) mod macros;
+mod methods;
+mod pipeline;
+mod reference;
 mod reflect_fn;
 mod reflect_fn_mut;
 mod registry;
@@ -263,4 +319,48 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn should_convert_dynamic_args() {
+        use crate::{DynamicEnum, DynamicList, DynamicMap, DynamicTuple};
+        use alloc::{string::String, vec::Vec};
+        use bevy_utils::HashMap;
+
+        fn describe(name: Option<String>, values: Vec<i32>, weights: HashMap<String, f32>) -> f32 {
+            assert_eq!(name.as_deref(), Some("total"));
+            values.iter().sum::<i32>() as f32 * weights["scale"]
+        }
+
+        let func = describe.into_function();
+
+        let mut name = DynamicTuple::default();
+        name.insert(String::from("total"));
+        let name = DynamicEnum::new("Some", name);
+
+        let values = DynamicList::from_iter([1_i32, 2, 3]);
+
+        let mut weights = DynamicMap::default();
+        weights.insert(String::from("scale"), 0.5_f32);
+
+        let args = ArgList::new()
+            .push_owned(name)
+            .push_owned(values)
+            .push_owned(weights);
+        let value = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(value.try_take::<f32>().unwrap(), 3.0);
+
+        // Values that can't be converted still report the expected type
+        let args = ArgList::new()
+            .push_owned(Option::<String>::None)
+            .push_owned(DynamicList::from_iter([String::from("a")]))
+            .push_owned(HashMap::<String, f32>::default());
+        assert_eq!(
+            func.call(args).unwrap_err(),
+            FunctionError::ArgError(ArgError::UnexpectedType {
+                index: 1,
+                expected: Cow::Borrowed(Vec::<i32>::type_path()),
+                received: Cow::Borrowed(DynamicList::type_path()),
+            })
+        );
+    }
 }
@@ -0,0 +1,177 @@
+use alloc::{borrow::Cow, vec, vec::Vec};
+
+use crate::func::{DynamicFunction, FunctionPipelineError, IntoFunction};
+
+/// A builder for chaining a sequence of functions into a single [`DynamicFunction`].
+///
+/// When called, the built function calls the first function with the given arguments,
+/// then passes the [`Return`] value of each function as the only argument of the next one,
+/// returning the output of the last function.
+///
+/// Each step is validated against the [`FunctionInfo`] of the previous one when the pipeline is [built].
+/// See [`DynamicFunction::then`] for details.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, FunctionPipeline};
+/// let pipeline = FunctionPipeline::new(|text: String| text.trim().to_string())
+///     .then(|text: String| text.to_uppercase())
+///     .then(|text: String| text.len())
+///     .with_name("normalized_len")
+///     .build()
+///     .unwrap();
+///
+/// let args = ArgList::new().push_owned(String::from("  hello  "));
+/// let value = pipeline.call(args).unwrap().unwrap_owned();
+/// assert_eq!(value.try_take::<usize>().unwrap(), 5);
+/// ```
+///
+/// [`Return`]: crate::func::Return
+/// [`FunctionInfo`]: crate::func::FunctionInfo
+/// [built]: Self::build
+#[derive(Clone, Debug)]
+pub struct FunctionPipeline<'env> {
+    name: Option<Cow<'static, str>>,
+    steps: Vec<DynamicFunction<'env>>,
+}
+
+impl<'env> FunctionPipeline<'env> {
+    /// Create a new pipeline starting with the given function.
+    pub fn new<F: IntoFunction<'env, Marker>, Marker>(first: F) -> Self {
+        Self {
+            name: None,
+            steps: vec![first.into_function()],
+        }
+    }
+
+    /// Add a function to the end of the pipeline.
+    pub fn then<F: IntoFunction<'env, Marker>, Marker>(mut self, next: F) -> Self {
+        self.steps.push(next.into_function());
+        self
+    }
+
+    /// Set the name of the built function.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Returns the functions in the pipeline, in order.
+    pub fn steps(&self) -> &[DynamicFunction<'env>] {
+        &self.steps
+    }
+
+    /// Chain the functions of the pipeline into a single [`DynamicFunction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a function can't take the return value of the previous one.
+    pub fn build(self) -> Result<DynamicFunction<'env>, FunctionPipelineError> {
+        let mut steps = self.steps.into_iter().enumerate();
+        let (_, first) = steps.next().expect("pipeline should have a first function");
+
+        let function = steps.try_fold(first, |function, (step, next)| {
+            function
+                .try_then(next)
+                .map_err(|_| FunctionPipelineError::SignatureMismatch { step })
+        })?;
+
+        Ok(match self.name {
+            Some(name) => function.with_name(name),
+            None => function,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        self as bevy_reflect,
+        func::{ArgList, IntoFunction},
+        Reflect, TypePath,
+    };
+    use alloc::string::{String, ToString};
+
+    #[test]
+    fn should_chain_functions() {
+        let pipeline = FunctionPipeline::new(|a: i32, b: i32| a + b)
+            .then(|value: i32| value * 2)
+            .then(|value: i32| value.to_string())
+            .with_name("add_double_format")
+            .build()
+            .unwrap();
+
+        assert_eq!(pipeline.name().unwrap(), "add_double_format");
+        assert_eq!(
+            pipeline.info().base().return_info().type_path(),
+            String::type_path()
+        );
+
+        let args = ArgList::new().push_owned(1_i32).push_owned(2_i32);
+        let value = pipeline.call(args).unwrap().unwrap_owned();
+        assert_eq!(value.try_take::<String>().unwrap(), "6");
+    }
+
+    #[test]
+    fn should_chain_references() {
+        #[derive(Reflect)]
+        struct Player {
+            health: i32,
+        }
+
+        fn health(player: &Player) -> &i32 {
+            &player.health
+        }
+
+        let pipeline = FunctionPipeline::new(health)
+            .then(|value: &i32| *value + 1)
+            .build()
+            .unwrap();
+
+        let player = Player { health: 41 };
+        let args = ArgList::new().push_ref(&player);
+        let value = pipeline.call(args).unwrap().unwrap_owned();
+        assert_eq!(value.try_take::<i32>().unwrap(), 42);
+    }
+
+    #[test]
+    fn should_chain_matching_overloads() {
+        let func = (|value: i32| value)
+            .into_function()
+            .with_overload(|value: f32| value)
+            .then(
+                (|value: i32| value.to_string())
+                    .into_function()
+                    .with_overload(|value: f32| value.to_string()),
+            );
+
+        assert_eq!(func.info().signatures().len(), 2);
+        assert!(func.name().is_none());
+
+        let args = ArgList::new().push_owned(1.5_f32);
+        let value = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(value.try_take::<String>().unwrap(), "1.5");
+    }
+
+    #[test]
+    fn should_error_on_mismatched_steps() {
+        let result = FunctionPipeline::new(|value: i32| value)
+            .then(|value: i32| value.to_string())
+            .then(|value: i32, other: i32| value + other)
+            .build();
+        assert_eq!(
+            result.unwrap_err(),
+            FunctionPipelineError::SignatureMismatch { step: 2 }
+        );
+
+        let result = (|value: i32| value)
+            .into_function()
+            .try_then(|value: &i32| *value);
+        assert_eq!(
+            result.unwrap_err(),
+            FunctionPipelineError::SignatureMismatch { step: 1 }
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate as bevy_reflect;
+use crate::{
+    func::{ArgList, DynamicFunction, FunctionRegistry, FunctionResult},
+    Reflect, ReflectDeserialize, ReflectSerialize,
+};
+use alloc::{borrow::Cow, string::String};
+use core::fmt::{Display, Formatter};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A serializable reference to a function registered in a [`FunctionRegistry`].
+///
+/// This only stores the name the function was registered with,
+/// and serializes as a plain string,
+/// which allows scenes, assets, and other data files to refer to callable functions.
+///
+/// The referenced function can then be looked up in the registry with [`Self::get`]
+/// or called directly with [`Self::call`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, FunctionReference, FunctionRegistry};
+/// let mut registry = FunctionRegistry::default();
+/// registry
+///     .register_with_name("damage", |health: i32, amount: i32| health - amount)
+///     .unwrap();
+///
+/// let on_hit = FunctionReference::new("damage");
+///
+/// let args = ArgList::new().push_owned(100_i32).push_owned(25_i32);
+/// let value = on_hit.call(&registry, args).unwrap().unwrap().unwrap_owned();
+/// assert_eq!(value.try_take::<i32>().unwrap(), 75);
+/// ```
+#[derive(Reflect, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[reflect(opaque)]
+#[reflect(Debug, PartialEq, Hash, Serialize, Deserialize)]
+#[type_path = "bevy_reflect::func"]
+pub struct FunctionReference(Cow<'static, str>);
+
+impl FunctionReference {
+    /// Create a new reference to the function registered with the given name.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(name.into())
+    }
+
+    /// The name of the referenced function.
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the referenced function from the given registry.
+    ///
+    /// Returns `None` if no function is registered with this name.
+    pub fn get<'r>(&self, registry: &'r FunctionRegistry) -> Option<&'r DynamicFunction<'static>> {
+        registry.get(&self.0)
+    }
+
+    /// Get the [generation] of the referenced function in the given registry.
+    ///
+    /// Comparing this against a previously seen generation allows detecting that
+    /// the referenced function was replaced, such as when reloading logic at runtime.
+    ///
+    /// Returns `None` if no function is registered with this name.
+    ///
+    /// [generation]: FunctionRegistry::function_generation
+    pub fn generation(&self, registry: &FunctionRegistry) -> Option<u64> {
+        registry.function_generation(&self.0)
+    }
+
+    /// Call the referenced function from the given registry with the given arguments.
+    ///
+    /// Returns `None` if no function is registered with this name,
+    /// otherwise returns the result of the function call.
+    pub fn call<'a>(
+        &self,
+        registry: &FunctionRegistry,
+        args: ArgList<'a>,
+    ) -> Option<FunctionResult<'a>> {
+        registry.call(&self.0, args)
+    }
+}
+
+impl Display for FunctionReference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&'static str> for FunctionReference {
+    fn from(name: &'static str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<String> for FunctionReference {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl Serialize for FunctionReference {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for FunctionReference {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        serde::{ReflectDeserializer, ReflectSerializer},
+        TypeRegistry,
+    };
+    use serde::de::DeserializeSeed;
+
+    #[test]
+    fn should_call_referenced_function() {
+        let mut registry = FunctionRegistry::default();
+        registry
+            .register_with_name("add", |a: i32, b: i32| a + b)
+            .unwrap();
+
+        let reference = FunctionReference::new("add");
+        assert!(reference.get(&registry).is_some());
+
+        let args = ArgList::new().push_owned(1_i32).push_owned(2_i32);
+        let value = reference.call(&registry, args).unwrap().unwrap();
+        assert_eq!(value.unwrap_owned().try_take::<i32>().unwrap(), 3);
+
+        let missing = FunctionReference::new("sub");
+        assert!(missing.get(&registry).is_none());
+        assert!(missing.call(&registry, ArgList::new()).is_none());
+    }
+
+    #[test]
+    fn should_follow_replaced_function() {
+        let mut registry = FunctionRegistry::default();
+        registry.register_with_name("speed", || 1_i32).unwrap();
+
+        let reference = FunctionReference::new("speed");
+        let seen = reference.generation(&registry);
+        assert!(seen.is_some());
+
+        registry.overwrite_registration_with_name("speed", || 2_i32);
+        assert_ne!(reference.generation(&registry), seen);
+
+        let value = reference.call(&registry, ArgList::new()).unwrap().unwrap();
+        assert_eq!(value.unwrap_owned().try_take::<i32>().unwrap(), 2);
+    }
+
+    #[test]
+    fn should_serialize_as_name() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<FunctionReference>();
+
+        let reference = FunctionReference::new("my_crate::on_hit");
+        let serializer = ReflectSerializer::new(&reference, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(
+            output,
+            r#"{"bevy_reflect::func::FunctionReference":"my_crate::on_hit"}"#
+        );
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            value.try_downcast_ref::<FunctionReference>(),
+            Some(&reference)
+        );
+        assert!(value.reflect_partial_eq(&reference).unwrap());
+    }
+}
@@ -5,7 +5,8 @@ use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use bevy_utils::HashMap;
 
 use crate::func::{
-    ArgList, DynamicFunction, FunctionRegistrationError, FunctionResult, IntoFunction,
+    generic::generic_name, ArgList, DynamicFunction, FunctionRegistrationError, FunctionResult,
+    IntoFunction,
 };
 
 /// A registry of [reflected functions].
@@ -14,8 +15,17 @@ use crate::func::{
 ///
 /// All functions must be `'static` as they are stored as [`DynamicFunction<'static>`].
 ///
+/// Registered functions can be replaced at runtime, such as when reloading gameplay logic,
+/// using [`overwrite_registration`] or [`unregister`].
+/// Each time a function is registered, replaced, or overloaded,
+/// it is given a new [generation], which can be compared against a previously seen
+/// generation to detect that the function behind a name has changed.
+///
 /// [reflected functions]: crate::func
 /// [`TypeRegistry`]: crate::TypeRegistry
+/// [`overwrite_registration`]: Self::overwrite_registration
+/// [`unregister`]: Self::unregister
+/// [generation]: Self::function_generation
 #[derive(Default)]
 pub struct FunctionRegistry {
     /// Maps function [names] to their respective [`DynamicFunctions`].
@@ -23,6 +33,10 @@ pub struct FunctionRegistry {
     /// [names]: DynamicFunction::name
     /// [`DynamicFunctions`]: DynamicFunction
     functions: HashMap<Cow<'static, str>, DynamicFunction<'static>>,
+    /// Maps function names to the generation they were last changed in.
+    generations: HashMap<Cow<'static, str>, u64>,
+    /// The generation of the most recent change to the registry.
+    generation: u64,
 }
 
 impl FunctionRegistry {
@@ -114,9 +128,10 @@ impl FunctionRegistry {
             .ok_or(FunctionRegistrationError::MissingName)?
             .clone();
         self.functions
-            .try_insert(name, function.into_function())
+            .try_insert(name.clone(), function.into_function())
             .map_err(|err| FunctionRegistrationError::DuplicateName(err.entry.key().clone()))?;
 
+        self.mark_changed(name);
         Ok(self)
     }
 
@@ -242,6 +257,7 @@ impl FunctionRegistry {
             .ok_or(FunctionRegistrationError::MissingName)?
             .clone();
 
+        self.mark_changed(name.clone());
         Ok(self.functions.insert(name, function))
     }
 
@@ -281,9 +297,162 @@ impl FunctionRegistry {
             Err(FunctionRegistrationError::DuplicateName(_)) => {
                 unreachable!("should overwrite functions with the same name")
             }
+            Err(FunctionRegistrationError::Overload { .. }) => {
+                unreachable!("should not overload functions")
+            }
         }
     }
 
+    /// Registers the given function, adding it as an [overload] of any function
+    /// already registered with the same [name].
+    ///
+    /// This allows multiple signatures to be registered under a single name,
+    /// with the one to call being chosen from the types and ownership of the arguments.
+    ///
+    /// Because the function must have a name,
+    /// anonymous functions (e.g. `|a: i32, b: i32| { a + b }`) and closures must instead
+    /// be registered using [`register_overload_with_name`] or manually converted to a [`DynamicFunction`]
+    /// and named using [`DynamicFunction::with_name`].
+    /// Failure to do so will result in an error being returned.
+    ///
+    /// If the existing function already has an overload with the same signature,
+    /// an error will be returned and the existing function will be left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{ArgList, FunctionRegistrationError, FunctionRegistry};
+    /// # fn main() -> Result<(), FunctionRegistrationError> {
+    /// let mut registry = FunctionRegistry::default();
+    /// registry
+    ///     .register_overload_with_name("add", |a: i32, b: i32| a + b)?
+    ///     .register_overload_with_name("add", |a: f32, b: f32| a + b)?;
+    ///
+    /// let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+    /// let value = registry.call("add", args).unwrap().unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<i32>().unwrap(), 100);
+    ///
+    /// let args = ArgList::new().push_owned(25.0_f32).push_owned(75.0_f32);
+    /// let value = registry.call("add", args).unwrap().unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<f32>().unwrap(), 100.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [overload]: DynamicFunction::with_overload
+    /// [name]: DynamicFunction::name
+    /// [`register_overload_with_name`]: Self::register_overload_with_name
+    pub fn register_overload<F, Marker>(
+        &mut self,
+        function: F,
+    ) -> Result<&mut Self, FunctionRegistrationError>
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        let function = function.into_function();
+        let name = function
+            .name()
+            .ok_or(FunctionRegistrationError::MissingName)?
+            .clone();
+
+        let function = match self.functions.remove(&name) {
+            Some(existing) => match existing.try_with_overload(function) {
+                Ok(overloaded) => overloaded,
+                Err((existing, error)) => {
+                    self.functions.insert(name.clone(), *existing);
+                    return Err(FunctionRegistrationError::Overload { name, error });
+                }
+            },
+            None => function,
+        };
+
+        self.mark_changed(name.clone());
+        self.functions.insert(name, function);
+        Ok(self)
+    }
+
+    /// Registers the given instantiations of a generic function under the name of the generic function itself.
+    ///
+    /// The instantiations are combined into a single function using [`DynamicFunction::from_instantiations`],
+    /// which is then [registered as an overload] of any function already registered with that name.
+    /// This allows further instantiations to be registered later on.
+    ///
+    /// Nothing is registered if an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{ArgList, FunctionRegistry, IntoFunction};
+    /// # use core::ops::Add;
+    /// fn add<T: Add<Output = T>>(a: T, b: T) -> T {
+    ///     a + b
+    /// }
+    ///
+    /// let mut registry = FunctionRegistry::default();
+    /// registry
+    ///     .register_generic([add::<i32>.into_function(), add::<f32>.into_function()])
+    ///     .unwrap();
+    ///
+    /// // Both instantiations are registered as a single function named after `add`:
+    /// assert_eq!(registry.len(), 1);
+    /// let func = registry.iter().next().unwrap();
+    /// assert!(func.name().unwrap().ends_with("::add"));
+    ///
+    /// let args = ArgList::new().push_owned(1.5_f32).push_owned(2.0_f32);
+    /// let value = func.call(args).unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<f32>().unwrap(), 3.5);
+    /// ```
+    ///
+    /// [registered as an overload]: Self::register_overload
+    pub fn register_generic(
+        &mut self,
+        instantiations: impl IntoIterator<Item = DynamicFunction<'static>>,
+    ) -> Result<&mut Self, FunctionRegistrationError> {
+        let mut instantiations = instantiations.into_iter().peekable();
+        let name = instantiations
+            .peek()
+            .and_then(DynamicFunction::name)
+            .cloned()
+            .map(generic_name)
+            .ok_or(FunctionRegistrationError::MissingName)?;
+
+        let function = DynamicFunction::from_instantiations(instantiations)
+            .map_err(|error| FunctionRegistrationError::Overload { name, error })?;
+        self.register_overload(function)
+    }
+
+    /// Registers the given function with the given name, adding it as an [overload]
+    /// of any function already registered with that name.
+    ///
+    /// This method is a convenience around calling [`IntoFunction::into_function`] and [`DynamicFunction::with_name`]
+    /// on the function and inserting it into the registry using the [`register_overload`] method.
+    ///
+    /// [overload]: DynamicFunction::with_overload
+    /// [`register_overload`]: Self::register_overload
+    pub fn register_overload_with_name<F, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        function: F,
+    ) -> Result<&mut Self, FunctionRegistrationError>
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        let function = function.into_function().with_name(name);
+        self.register_overload(function)
+    }
+
+    /// Removes the function with the given [name] from the registry.
+    ///
+    /// Returns the removed function, if any.
+    ///
+    /// [name]: DynamicFunction::name
+    pub fn unregister(&mut self, name: &str) -> Option<DynamicFunction<'static>> {
+        let function = self.functions.remove(name)?;
+        self.generations.remove(name);
+        self.generation += 1;
+        Some(function)
+    }
+
     /// Calls the function with the given [name] and [args].
     ///
     /// Returns `None` if no function with the given name is registered.
@@ -315,6 +484,44 @@ impl FunctionRegistry {
         self.functions.values()
     }
 
+    /// Returns the generation of the function with the given [name].
+    ///
+    /// A function is given a new generation each time it is registered, replaced, or overloaded,
+    /// so a change in generation indicates that calls by name may now reach a different function.
+    /// Generations are unique within the registry and increase over time,
+    /// even across functions being unregistered and registered again.
+    ///
+    /// Returns `None` if no function with the given name is registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{ArgList, FunctionRegistry};
+    /// let mut registry = FunctionRegistry::default();
+    /// registry.register_with_name("speed", || 1.0_f32).unwrap();
+    /// let seen = registry.function_generation("speed");
+    ///
+    /// // Hot-swap the implementation
+    /// registry.overwrite_registration_with_name("speed", || 2.0_f32);
+    /// assert_ne!(registry.function_generation("speed"), seen);
+    ///
+    /// let value = registry.call("speed", ArgList::new()).unwrap().unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<f32>().unwrap(), 2.0);
+    /// ```
+    ///
+    /// [name]: DynamicFunction::name
+    pub fn function_generation(&self, name: &str) -> Option<u64> {
+        self.generations.get(name).copied()
+    }
+
+    /// Returns the generation of the most recent change to the registry.
+    ///
+    /// This is increased whenever any function is registered, replaced, overloaded, or unregistered,
+    /// which makes it a cheap way to check whether anything changed since it was last seen.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
     /// Returns the number of registered functions.
     pub fn len(&self) -> usize {
         self.functions.len()
@@ -324,6 +531,12 @@ impl FunctionRegistry {
     pub fn is_empty(&self) -> bool {
         self.functions.is_empty()
     }
+
+    /// Gives the function with the given name a new generation.
+    fn mark_changed(&mut self, name: Cow<'static, str>) {
+        self.generation += 1;
+        self.generations.insert(name, self.generation);
+    }
 }
 
 impl Debug for FunctionRegistry {
@@ -426,6 +639,38 @@ mod tests {
         assert_eq!(value.try_downcast_ref::<i32>(), Some(&123));
     }
 
+    #[test]
+    fn should_register_overloaded_function() {
+        let mut registry = FunctionRegistry::default();
+        registry
+            .register_overload_with_name("add", |a: i32, b: i32| a + b)
+            .unwrap()
+            .register_overload_with_name("add", |a: f32, b: f32| a + b)
+            .unwrap();
+
+        let function = registry.get("add").unwrap();
+        assert!(function.is_overloaded());
+
+        let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+        let value = function.call(args).unwrap().unwrap_owned();
+        assert_eq!(value.try_downcast_ref::<i32>(), Some(&100));
+
+        let args = ArgList::new().push_owned(25.0_f32).push_owned(75.0_f32);
+        let value = function.call(args).unwrap().unwrap_owned();
+        assert_eq!(value.try_downcast_ref::<f32>(), Some(&100.0));
+
+        let result = registry.register_overload_with_name("add", |a: i32, b: i32| a - b);
+        assert!(matches!(
+            result,
+            Err(FunctionRegistrationError::Overload { .. })
+        ));
+
+        // The existing overloads should be kept.
+        let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+        let value = registry.call("add", args).unwrap().unwrap().unwrap_owned();
+        assert_eq!(value.try_downcast_ref::<i32>(), Some(&100));
+    }
+
     #[test]
     fn should_only_register_function_once() {
         fn foo() -> i32 {
@@ -478,6 +723,49 @@ mod tests {
         assert_eq!(value.try_downcast_ref::<i32>(), Some(&321));
     }
 
+    #[test]
+    fn should_track_function_generations() {
+        let mut registry = FunctionRegistry::default();
+        assert_eq!(registry.generation(), 0);
+        assert_eq!(registry.function_generation("foo"), None);
+
+        registry.register_with_name("foo", || 1_i32).unwrap();
+        registry.register_with_name("bar", || 2_i32).unwrap();
+        let foo = registry.function_generation("foo").unwrap();
+        let bar = registry.function_generation("bar").unwrap();
+        assert_ne!(foo, bar);
+        assert_eq!(registry.generation(), bar);
+
+        // Failed registrations don't change anything
+        registry.register_with_name("foo", || 3_i32).unwrap_err();
+        assert_eq!(registry.function_generation("foo"), Some(foo));
+
+        registry.overwrite_registration_with_name("foo", || 3_i32);
+        let swapped = registry.function_generation("foo").unwrap();
+        assert!(swapped > foo);
+        assert_eq!(registry.function_generation("bar"), Some(bar));
+
+        let value = registry
+            .call("foo", ArgList::new())
+            .unwrap()
+            .unwrap()
+            .unwrap_owned();
+        assert_eq!(value.try_downcast_ref::<i32>(), Some(&3));
+
+        registry
+            .register_overload_with_name("foo", |a: i32| a)
+            .unwrap();
+        let overloaded = registry.function_generation("foo").unwrap();
+        assert!(overloaded > swapped);
+
+        assert!(registry.unregister("foo").is_some());
+        assert_eq!(registry.function_generation("foo"), None);
+        assert!(registry.generation() > overloaded);
+
+        registry.register_with_name("foo", || 4_i32).unwrap();
+        assert!(registry.function_generation("foo").unwrap() > overloaded);
+    }
+
     #[test]
     fn should_call_function_via_registry() {
         fn add(a: i32, b: i32) -> i32 {
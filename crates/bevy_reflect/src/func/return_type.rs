@@ -1,4 +1,4 @@
-use crate::PartialReflect;
+use crate::{func::args::ArgValue, PartialReflect};
 use alloc::boxed::Box;
 
 /// The return type of a [`DynamicFunction`] or [`DynamicFunctionMut`].
@@ -68,6 +68,16 @@ impl<'a> Return<'a> {
     }
 }
 
+impl<'a> From<Return<'a>> for ArgValue<'a> {
+    fn from(value: Return<'a>) -> Self {
+        match value {
+            Return::Owned(value) => ArgValue::Owned(value),
+            Return::Ref(value) => ArgValue::Ref(value),
+            Return::Mut(value) => ArgValue::Mut(value),
+        }
+    }
+}
+
 /// A trait for types that can be converted into a [`Return`] value.
 ///
 /// This trait exists so that types can be automatically converted into a [`Return`]
@@ -586,7 +586,7 @@ macro_rules! impl_reflect_for_veclike {
 impl_reflect_for_veclike!(Vec<T>, Vec::insert, Vec::remove, Vec::push, Vec::pop, [T]);
 impl_type_path!(::alloc::vec::Vec<T>);
 #[cfg(feature = "functions")]
-crate::func::macros::impl_function_traits!(Vec<T>; <T: FromReflect + MaybeTyped + TypePath + GetTypeRegistration>);
+crate::func::macros::impl_function_traits!(@from_reflect Vec<T>; <T: FromReflect + MaybeTyped + TypePath + GetTypeRegistration>);
 
 impl_reflect_for_veclike!(
     VecDeque<T>,
@@ -598,7 +598,7 @@ impl_reflect_for_veclike!(
 );
 impl_type_path!(::alloc::collections::VecDeque<T>);
 #[cfg(feature = "functions")]
-crate::func::macros::impl_function_traits!(VecDeque<T>; <T: FromReflect + MaybeTyped + TypePath + GetTypeRegistration>);
+crate::func::macros::impl_function_traits!(@from_reflect VecDeque<T>; <T: FromReflect + MaybeTyped + TypePath + GetTypeRegistration>);
 
 macro_rules! impl_reflect_for_hashmap {
     ($ty:path) => {
@@ -848,7 +848,7 @@ impl_type_path!(::std::collections::hash_map::RandomState);
 #[cfg(feature = "std")]
 impl_type_path!(::std::collections::HashMap<K, V, S>);
 #[cfg(all(feature = "functions", feature = "std"))]
-crate::func::macros::impl_function_traits!(::std::collections::HashMap<K, V, S>;
+crate::func::macros::impl_function_traits!(@from_reflect ::std::collections::HashMap<K, V, S>;
     <
         K: FromReflect + MaybeTyped + TypePath + GetTypeRegistration + Eq + Hash,
         V: FromReflect + MaybeTyped + TypePath + GetTypeRegistration,
@@ -859,7 +859,7 @@ crate::func::macros::impl_function_traits!(::std::collections::HashMap<K, V, S>;
 impl_reflect_for_hashmap!(bevy_utils::hashbrown::HashMap<K, V, S>);
 impl_type_path!(::bevy_utils::hashbrown::HashMap<K, V, S>);
 #[cfg(feature = "functions")]
-crate::func::macros::impl_function_traits!(::bevy_utils::hashbrown::HashMap<K, V, S>;
+crate::func::macros::impl_function_traits!(@from_reflect ::bevy_utils::hashbrown::HashMap<K, V, S>;
     <
         K: FromReflect + MaybeTyped + TypePath + GetTypeRegistration + Eq + Hash,
         V: FromReflect + MaybeTyped + TypePath + GetTypeRegistration,
@@ -1077,7 +1077,7 @@ impl_reflect_for_hashset!(::std::collections::HashSet<V,S>);
 #[cfg(feature = "std")]
 impl_type_path!(::std::collections::HashSet<V, S>);
 #[cfg(all(feature = "functions", feature = "std"))]
-crate::func::macros::impl_function_traits!(::std::collections::HashSet<V, S>;
+crate::func::macros::impl_function_traits!(@from_reflect ::std::collections::HashSet<V, S>;
     <
         V: Hash + Eq + FromReflect + TypePath + GetTypeRegistration,
         S: TypePath + BuildHasher + Default + Send + Sync
@@ -1087,7 +1087,7 @@ crate::func::macros::impl_function_traits!(::std::collections::HashSet<V, S>;
 impl_reflect_for_hashset!(::bevy_utils::hashbrown::HashSet<V,S>);
 impl_type_path!(::bevy_utils::hashbrown::HashSet<V, S>);
 #[cfg(feature = "functions")]
-crate::func::macros::impl_function_traits!(::bevy_utils::hashbrown::HashSet<V, S>;
+crate::func::macros::impl_function_traits!(@from_reflect ::bevy_utils::hashbrown::HashSet<V, S>;
     <
         V: Hash + Eq + FromReflect + TypePath + GetTypeRegistration,
         S: TypePath + BuildHasher + Default + Send + Sync
@@ -1320,7 +1320,7 @@ where
 
 impl_type_path!(::alloc::collections::BTreeMap<K, V>);
 #[cfg(feature = "functions")]
-crate::func::macros::impl_function_traits!(::alloc::collections::BTreeMap<K, V>;
+crate::func::macros::impl_function_traits!(@from_reflect ::alloc::collections::BTreeMap<K, V>;
     <
         K: FromReflect + MaybeTyped + TypePath + GetTypeRegistration + Eq + Ord,
         V: FromReflect + MaybeTyped + TypePath + GetTypeRegistration
@@ -570,6 +570,7 @@ extern crate std;
 extern crate alloc;
 
 mod array;
+pub mod diff;
 mod fields;
 mod from_reflect;
 #[cfg(feature = "functions")]
@@ -583,6 +584,7 @@ mod reflectable;
 mod remote;
 mod set;
 mod struct_trait;
+mod trait_object;
 mod tuple;
 mod tuple_struct;
 mod type_info;
@@ -647,6 +649,7 @@ pub use reflectable::*;
 pub use remote::*;
 pub use set::*;
 pub use struct_trait::*;
+pub use trait_object::*;
 pub use tuple::*;
 pub use tuple_struct::*;
 pub use type_info::*;
@@ -751,7 +754,10 @@ mod tests {
     use super::{prelude::*, *};
     use crate as bevy_reflect;
     use crate::{
-        serde::{ReflectDeserializer, ReflectSerializer},
+        serde::{
+            ReflectDeserializer, ReflectSerializer, TypedReflectDeserializer,
+            TypedReflectSerializer,
+        },
         utility::GenericTypePathCell,
     };
 
@@ -2650,6 +2656,100 @@ bevy_reflect::tests::Test {
         assert_eq!("Goodbye", data.0.value);
     }
 
+    #[test]
+    fn should_reflect_remote_type_with_field_mapping() {
+        mod external_crate {
+            #[derive(Debug, Default, PartialEq)]
+            pub struct TheirType {
+                pub x: f32,
+                pub y: f32,
+                pub cache: u64,
+            }
+        }
+
+        #[reflect_remote(external_crate::TheirType)]
+        #[derive(Debug, Default)]
+        struct MyType {
+            #[reflect(rename = "horizontal")]
+            pub x: f32,
+            #[reflect(rename = "vertical")]
+            pub y: f32,
+            #[reflect(ignore)]
+            pub cache: u64,
+        }
+
+        let info = MyType::type_info().as_struct().unwrap();
+        assert_eq!(info.field_names(), &["horizontal", "vertical"]);
+
+        let mut data = MyType(external_crate::TheirType {
+            x: 1.0,
+            y: 2.0,
+            cache: 123,
+        });
+        assert_eq!(
+            data.field("horizontal").unwrap().try_downcast_ref(),
+            Some(&1.0_f32)
+        );
+        assert!(data.field("x").is_none());
+        assert!(data.field("cache").is_none());
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("vertical", 5.0_f32);
+        data.apply(&patch);
+        assert_eq!(data.0.y, 5.0);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<MyType>();
+
+        let serializer = TypedReflectSerializer::new(&data, &registry);
+        let output = ron::to_string(&serializer).unwrap();
+        assert_eq!(output, "(horizontal:1.0,vertical:5.0)");
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let value = TypedReflectDeserializer::of::<MyType>(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let value = MyType::from_reflect(value.as_partial_reflect()).unwrap();
+        assert_eq!(
+            value.0,
+            external_crate::TheirType {
+                x: 1.0,
+                y: 5.0,
+                cache: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn should_reflect_renamed_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Shape {
+            Circle {
+                #[reflect(rename = "r")]
+                radius: f32,
+            },
+        }
+
+        let info = Shape::type_info().as_enum().unwrap();
+        let variant = info.variant("Circle").unwrap().as_struct_variant().unwrap();
+        assert_eq!(variant.field_names(), &["r"]);
+
+        let mut shape = Shape::Circle { radius: 1.0 };
+        assert_eq!(shape.field("r").unwrap().try_downcast_ref(), Some(&1.0_f32));
+        assert!(shape.field("radius").is_none());
+        assert_eq!(shape.name_at(0), Some("r"));
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("r", 2.0_f32);
+        let patch = DynamicEnum::new("Circle", patch);
+        shape.apply(&patch);
+        assert_eq!(shape, Shape::Circle { radius: 2.0 });
+        assert_eq!(
+            Shape::from_reflect(&patch),
+            Some(Shape::Circle { radius: 2.0 })
+        );
+    }
+
     #[test]
     fn should_reflect_remote_value_type() {
         mod external_crate {
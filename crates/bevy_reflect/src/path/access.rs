@@ -3,7 +3,7 @@
 use alloc::borrow::Cow;
 use core::fmt;
 
-use super::error::AccessErrorKind;
+use super::{error::AccessErrorKind, PathFilter};
 use crate::{AccessError, PartialReflect, ReflectKind, ReflectMut, ReflectRef, VariantType};
 
 type InnerResult<T> = Result<T, AccessErrorKind>;
@@ -22,6 +22,18 @@ pub enum Access<'a> {
     TupleIndex(usize),
     /// An index-based access on a list.
     ListIndex(usize),
+    /// A wildcard access on every field or element of a struct, tuple, list, map, set, or enum.
+    ///
+    /// Since it can match multiple values, this can only be used when [querying] a path.
+    ///
+    /// [querying]: super::ReflectPath::reflect_elements
+    Wildcard,
+    /// A wildcard access on every field or element that matches the given [`PathFilter`].
+    ///
+    /// Since it can match multiple values, this can only be used when [querying] a path.
+    ///
+    /// [querying]: super::ReflectPath::reflect_elements
+    Filter(PathFilter<'a>),
 }
 
 impl fmt::Display for Access<'_> {
@@ -31,6 +43,8 @@ impl fmt::Display for Access<'_> {
             Access::FieldIndex(index) => write!(f, "#{index}"),
             Access::TupleIndex(index) => write!(f, ".{index}"),
             Access::ListIndex(index) => write!(f, "[{index}]"),
+            Access::Wildcard => f.write_str("[*]"),
+            Access::Filter(filter) => write!(f, "[?{filter}]"),
         }
     }
 }
@@ -47,6 +61,8 @@ impl<'a> Access<'a> {
             Self::FieldIndex(value) => Access::FieldIndex(value),
             Self::TupleIndex(value) => Access::TupleIndex(value),
             Self::ListIndex(value) => Access::ListIndex(value),
+            Self::Wildcard => Access::Wildcard,
+            Self::Filter(filter) => Access::Filter(filter.into_owned()),
         }
     }
 
@@ -70,6 +86,8 @@ impl<'a> Access<'a> {
             |expected, actual| AccessErrorKind::IncompatibleEnumVariantTypes { expected, actual };
 
         match (self, base.reflect_ref()) {
+            (Self::Wildcard | Self::Filter(_), _) => Err(AccessErrorKind::MultipleElements),
+
             (Self::Field(field), Struct(struct_ref)) => Ok(struct_ref.field(field.as_ref())),
             (Self::Field(field), Enum(enum_ref)) => match enum_ref.variant_type() {
                 VariantType::Struct => Ok(enum_ref.field(field.as_ref())),
@@ -129,6 +147,8 @@ impl<'a> Access<'a> {
             |expected, actual| AccessErrorKind::IncompatibleEnumVariantTypes { expected, actual };
 
         match (self, base.reflect_mut()) {
+            (Self::Wildcard | Self::Filter(_), _) => Err(AccessErrorKind::MultipleElements),
+
             (Self::Field(field), Struct(struct_mut)) => Ok(struct_mut.field_mut(field.as_ref())),
             (Self::Field(field), Enum(enum_mut)) => match enum_mut.variant_type() {
                 VariantType::Struct => Ok(enum_mut.field_mut(field.as_ref())),
@@ -171,6 +191,8 @@ impl<'a> Access<'a> {
         match self {
             Self::Field(value) => value,
             Self::FieldIndex(value) | Self::TupleIndex(value) | Self::ListIndex(value) => value,
+            Self::Wildcard => &"*",
+            Self::Filter(filter) => filter,
         }
     }
 
@@ -179,6 +201,96 @@ impl<'a> Access<'a> {
             Self::Field(_) => "field",
             Self::FieldIndex(_) => "field index",
             Self::TupleIndex(_) | Self::ListIndex(_) => "index",
+            Self::Wildcard => "wildcard",
+            Self::Filter(_) => "filter",
+        }
+    }
+
+    /// Calls `f` with every value matched by this access.
+    ///
+    /// Wildcards and filters match the fields or elements of `base`,
+    /// while other accesses match the single element they target.
+    pub(super) fn for_each_match<'r>(
+        &self,
+        base: &'r dyn PartialReflect,
+        offset: Option<usize>,
+        mut f: impl FnMut(&'r dyn PartialReflect) -> Result<(), AccessError<'a>>,
+    ) -> Result<(), AccessError<'a>> {
+        use ReflectRef::*;
+
+        let filter = match self {
+            Self::Wildcard => None,
+            Self::Filter(filter) => Some(filter),
+            _ => return f(self.element(base, offset)?),
+        };
+        let mut visit = |value: &'r dyn PartialReflect| match filter {
+            Some(filter) if !filter.matches(value) => Ok(()),
+            _ => f(value),
+        };
+
+        match base.reflect_ref() {
+            Struct(struct_ref) => struct_ref.iter_fields().try_for_each(visit),
+            TupleStruct(tuple) => tuple.iter_fields().try_for_each(visit),
+            Tuple(tuple) => tuple.iter_fields().try_for_each(visit),
+            List(list) => list.iter().try_for_each(visit),
+            Array(array) => array.iter().try_for_each(visit),
+            Map(map) => map.iter().try_for_each(|(_, value)| visit(value)),
+            Set(set) => set.iter().try_for_each(visit),
+            Enum(enum_ref) => enum_ref
+                .iter_fields()
+                .try_for_each(|field| visit(field.value())),
+            actual => Err(AccessErrorKind::IncompatibleTypes {
+                expected: ReflectKind::List,
+                actual: actual.into(),
+            }
+            .with_access(self.clone(), offset)),
+        }
+    }
+
+    /// Calls `f` with a mutable reference to every value matched by this access.
+    ///
+    /// See [`Access::for_each_match`] for details.
+    /// Since the values of a set can't be mutated, wildcards and filters don't support sets.
+    pub(super) fn for_each_match_mut(
+        &self,
+        base: &mut dyn PartialReflect,
+        offset: Option<usize>,
+        f: &mut dyn FnMut(&mut dyn PartialReflect) -> Result<(), AccessError<'a>>,
+    ) -> Result<(), AccessError<'a>> {
+        use ReflectMut::*;
+
+        let filter = match self {
+            Self::Wildcard => None,
+            Self::Filter(filter) => Some(filter),
+            _ => return f(self.element_mut(base, offset)?),
+        };
+        let mut visit = |value: Option<&mut dyn PartialReflect>| match (value, filter) {
+            (Some(value), Some(filter)) if !filter.matches(value) => Ok(()),
+            (Some(value), _) => f(value),
+            (None, _) => Ok(()),
+        };
+
+        match base.reflect_mut() {
+            Struct(struct_mut) => (0..struct_mut.field_len())
+                .try_for_each(|index| visit(struct_mut.field_at_mut(index))),
+            TupleStruct(tuple) => {
+                (0..tuple.field_len()).try_for_each(|index| visit(tuple.field_mut(index)))
+            }
+            Tuple(tuple) => {
+                (0..tuple.field_len()).try_for_each(|index| visit(tuple.field_mut(index)))
+            }
+            List(list) => (0..list.len()).try_for_each(|index| visit(list.get_mut(index))),
+            Array(array) => (0..array.len()).try_for_each(|index| visit(array.get_mut(index))),
+            Map(map) => (0..map.len())
+                .try_for_each(|index| visit(map.get_at_mut(index).map(|(_, value)| value))),
+            Enum(enum_mut) => {
+                (0..enum_mut.field_len()).try_for_each(|index| visit(enum_mut.field_at_mut(index)))
+            }
+            actual => Err(AccessErrorKind::IncompatibleTypes {
+                expected: ReflectKind::List,
+                actual: actual.into(),
+            }
+            .with_access(self.clone(), offset)),
         }
     }
 }
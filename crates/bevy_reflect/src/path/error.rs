@@ -27,6 +27,10 @@ pub enum AccessErrorKind {
         /// The actual [`VariantType`] that was found.
         actual: VariantType,
     },
+
+    /// An error that occurs when using a [`Wildcard`](Access::Wildcard) or [`Filter`](Access::Filter) access,
+    /// which can match multiple values, to access a single element.
+    MultipleElements,
 }
 
 impl AccessErrorKind {
@@ -110,7 +114,11 @@ impl fmt::Display for AccessError<'_> {
                         f,
                         "The {type_accessed} accessed doesn't have index `{}`",
                         access.display_value()
-                    )
+                    ),
+                    Access::Wildcard | Access::Filter(_) => write!(
+                        f,
+                        "The {type_accessed} accessed doesn't have any elements"
+                    ),
                 }
             }
             AccessErrorKind::IncompatibleTypes { expected, actual } => write!(
@@ -123,6 +131,11 @@ impl fmt::Display for AccessError<'_> {
                 "Expected variant {} access to access a {expected:?} variant, found a {actual:?} variant instead.",
                 access.kind()
             ),
+            AccessErrorKind::MultipleElements => write!(
+                f,
+                "A {} access can match multiple elements, so it can only be used when querying a path.",
+                access.kind()
+            ),
         }
     }
 }
@@ -0,0 +1,198 @@
+//! Filters for selecting elements within a path query.
+
+use alloc::{borrow::Cow, string::String};
+use core::{cmp::Ordering, fmt};
+
+use super::{ParsedPath, ReflectPath};
+use crate::{PartialReflect, ReflectRef};
+
+/// A condition on the fields or elements matched by an [`Access::Filter`].
+///
+/// A filter selects the elements for which the value at its [`path`] compares
+/// to its literal value with the given [`FilterOp`].
+/// If no comparison is given, the value at the path must be `true`.
+///
+/// Literals are parsed as the type of the compared value, which may be any primitive number,
+/// `bool`, `char`, or string type.
+/// Enums are compared by the name of their current variant.
+/// Elements for which the path can't be accessed never match,
+/// while an empty path compares each element itself.
+///
+/// In path strings, filters are written as `[?path]` or `[?path <op> literal]`,
+/// such as `[?active]`, `[?price >= 10]`, `[?name == "sword"]`, or `[? > 0]`.
+///
+/// [`Access::Filter`]: super::Access::Filter
+/// [`path`]: PathFilter::path
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PathFilter<'a> {
+    /// The path to the compared value, relative to each element.
+    pub path: ParsedPath,
+    /// The comparison made with the value, along with the literal it's compared to.
+    ///
+    /// String literals keep their surrounding quotes.
+    pub condition: Option<(FilterOp, Cow<'a, str>)>,
+}
+
+/// A comparison made by a [`PathFilter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FilterOp {
+    /// `==`
+    Eq,
+    /// `!=`
+    Ne,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+}
+
+impl FilterOp {
+    /// The symbols of each operator, ordered so that longer symbols are matched first.
+    const SYMBOLS: [(&'static str, Self); 6] = [
+        ("==", Self::Eq),
+        ("!=", Self::Ne),
+        ("<=", Self::Le),
+        (">=", Self::Ge),
+        ("<", Self::Lt),
+        (">", Self::Gt),
+    ];
+
+    /// Returns the symbol of this operator.
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Eq => "==",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+        }
+    }
+
+    fn test(self, ordering: Ordering) -> bool {
+        match self {
+            Self::Eq => ordering.is_eq(),
+            Self::Ne => ordering.is_ne(),
+            Self::Lt => ordering.is_lt(),
+            Self::Le => ordering.is_le(),
+            Self::Gt => ordering.is_gt(),
+            Self::Ge => ordering.is_ge(),
+        }
+    }
+}
+
+impl fmt::Display for FilterOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+impl<'a> PathFilter<'a> {
+    /// Parses the contents of a `[?...]` filter.
+    ///
+    /// Returns `None` if the filter path is invalid or the comparison has no literal.
+    pub(super) fn parse(filter: &'a str) -> Option<Self> {
+        let op_start = filter.find(['=', '!', '<', '>']);
+        let (path, condition) = match op_start {
+            Some(start) => {
+                let (symbol, op) = FilterOp::SYMBOLS
+                    .into_iter()
+                    .find(|(symbol, _)| filter[start..].starts_with(symbol))?;
+                let literal = filter[start + symbol.len()..].trim();
+                if literal.is_empty() {
+                    return None;
+                }
+                (&filter[..start], Some((op, Cow::Borrowed(literal))))
+            }
+            None => (filter, None),
+        };
+
+        let path = ParsedPath::parse(path.trim()).ok()?;
+        Some(Self { path, condition })
+    }
+
+    /// Converts this into an "owned" value.
+    pub fn into_owned(self) -> PathFilter<'static> {
+        PathFilter {
+            path: self.path,
+            condition: self
+                .condition
+                .map(|(op, literal)| (op, Cow::Owned(literal.into_owned()))),
+        }
+    }
+
+    /// Returns `true` if the given element matches this filter.
+    pub fn matches(&self, element: &dyn PartialReflect) -> bool {
+        let Ok(value) = self.path.reflect_element(element) else {
+            return false;
+        };
+
+        match &self.condition {
+            Some((op, literal)) => {
+                compare(value, literal).is_some_and(|ordering| op.test(ordering))
+            }
+            None => value.try_downcast_ref::<bool>() == Some(&true),
+        }
+    }
+}
+
+impl fmt::Display for PathFilter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path)?;
+        if let Some((op, literal)) = &self.condition {
+            write!(f, " {op} {literal}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares the given value to a literal parsed as the value's type.
+///
+/// Returns `None` if the value isn't of a supported type or the literal can't be parsed.
+fn compare(value: &dyn PartialReflect, literal: &str) -> Option<Ordering> {
+    macro_rules! compare_parsed {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.try_downcast_ref::<$ty>() {
+                    return value.partial_cmp(&literal.parse::<$ty>().ok()?);
+                }
+            )*
+        };
+    }
+
+    compare_parsed!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool);
+
+    let string = unquote(literal);
+    if let Some(value) = value.try_downcast_ref::<String>() {
+        return Some(value.as_str().cmp(string?));
+    }
+    if let Some(value) = value.try_downcast_ref::<&'static str>() {
+        return Some((*value).cmp(string?));
+    }
+    if let Some(value) = value.try_downcast_ref::<Cow<'static, str>>() {
+        return Some(value.as_ref().cmp(string?));
+    }
+    if let Some(value) = value.try_downcast_ref::<char>() {
+        let mut chars = string?.chars();
+        return match (chars.next(), chars.next()) {
+            (Some(char), None) => Some(value.cmp(&char)),
+            _ => None,
+        };
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Enum(value) => Some(value.variant_name().cmp(literal)),
+        _ => None,
+    }
+}
+
+/// Returns the contents of a single- or double-quoted string literal.
+fn unquote(literal: &str) -> Option<&str> {
+    ['"', '\'']
+        .into_iter()
+        .find_map(|quote| literal.strip_prefix(quote)?.strip_suffix(quote))
+}
@@ -4,6 +4,9 @@ pub use access::*;
 mod error;
 pub use error::*;
 
+mod filter;
+pub use filter::{FilterOp, PathFilter};
+
 mod parse;
 pub use parse::ParseError;
 use parse::PathParser;
@@ -62,6 +65,23 @@ pub trait ReflectPath<'a>: Sized {
         root: &mut dyn PartialReflect,
     ) -> PathResult<'a, &mut dyn PartialReflect>;
 
+    /// Gets references to every element matched by this path on the given [`Reflect`] object.
+    ///
+    /// See [`GetPath::reflect_path_iter`] for more details.
+    fn reflect_elements(
+        self,
+        root: &dyn PartialReflect,
+    ) -> PathResult<'a, impl Iterator<Item = &dyn PartialReflect>>;
+
+    /// Calls `f` with a mutable reference to every element matched by this path on the given [`Reflect`] object.
+    ///
+    /// See [`GetPath::reflect_path_for_each_mut`] for more details.
+    fn reflect_elements_mut(
+        self,
+        root: &mut dyn PartialReflect,
+        f: impl FnMut(&mut dyn PartialReflect),
+    ) -> PathResult<'a, ()>;
+
     /// Gets a `&T` to the specified element on the given [`Reflect`] object.
     ///
     /// See [`GetPath::path`] for more details.
@@ -99,6 +119,72 @@ impl<'a> ReflectPath<'a> for &'a str {
         }
         Ok(root)
     }
+    fn reflect_elements(
+        self,
+        root: &dyn PartialReflect,
+    ) -> PathResult<'a, impl Iterator<Item = &dyn PartialReflect>> {
+        let accesses = parse_accesses(self)?;
+        let mut elements = Vec::new();
+        query(
+            root,
+            accesses.iter().map(|(access, offset)| (access, *offset)),
+            &mut elements,
+        )?;
+        Ok(elements.into_iter())
+    }
+    fn reflect_elements_mut(
+        self,
+        root: &mut dyn PartialReflect,
+        mut f: impl FnMut(&mut dyn PartialReflect),
+    ) -> PathResult<'a, ()> {
+        let accesses = parse_accesses(self)?;
+        query_mut(
+            root,
+            accesses.iter().map(|(access, offset)| (access, *offset)),
+            &mut f,
+        )?;
+        Ok(())
+    }
+}
+
+fn parse_accesses(path: &str) -> PathResult<Vec<(Access, Option<usize>)>> {
+    PathParser::new(path)
+        .map(|(access, offset)| access.map(|access| (access, Some(offset))))
+        .collect()
+}
+
+/// Pushes every element matched by `accesses` on `root` to `elements`.
+fn query<'r, 'p, 'a: 'p>(
+    root: &'r dyn PartialReflect,
+    mut accesses: impl Iterator<Item = (&'p Access<'a>, Option<usize>)> + Clone,
+    elements: &mut Vec<&'r dyn PartialReflect>,
+) -> Result<(), AccessError<'a>> {
+    match accesses.next() {
+        Some((access, offset)) => access.for_each_match(root, offset, |value| {
+            query(value, accesses.clone(), elements)
+        }),
+        None => {
+            elements.push(root);
+            Ok(())
+        }
+    }
+}
+
+/// Calls `f` with every element matched by `accesses` on `root`.
+fn query_mut<'p, 'a: 'p>(
+    root: &mut dyn PartialReflect,
+    mut accesses: impl Iterator<Item = (&'p Access<'a>, Option<usize>)> + Clone,
+    f: &mut dyn FnMut(&mut dyn PartialReflect),
+) -> Result<(), AccessError<'a>> {
+    match accesses.next() {
+        Some((access, offset)) => access.for_each_match_mut(root, offset, &mut |value| {
+            query_mut(value, accesses.clone(), f)
+        }),
+        None => {
+            f(root);
+            Ok(())
+        }
+    }
 }
 /// A trait which allows nested [`Reflect`] values to be retrieved with path strings.
 ///
@@ -237,12 +323,69 @@ impl<'a> ReflectPath<'a> for &'a str {
 /// );
 /// ```
 ///
+/// # Wildcards and Filters
+///
+/// Paths passed to [`reflect_path_iter`] and [`reflect_path_for_each_mut`] may match multiple values.
+///
+/// A wildcard (`[*]` or `.*`) matches every field or element of a struct, tuple, list, array, map, set, or enum.
+/// For maps, it matches the values of each entry.
+///
+/// A filter (`[?path]` or `[?path <op> literal]`) works like a wildcard,
+/// but only matches the fields or elements for which the condition holds.
+/// The condition compares the value at the given path, relative to each element,
+/// using one of the `==`, `!=`, `<`, `<=`, `>`, or `>=` operators.
+/// Without a comparison, the value at the path must be `true`.
+/// See [`PathFilter`] for details.
+///
+/// Trying to access a single value with a path containing a wildcard or filter returns an error.
+///
+/// ## Example
+/// ```
+/// # use bevy_reflect::{GetPath, PartialReflect, Reflect};
+/// #[derive(Reflect)]
+/// struct Shop {
+///   items: Vec<Item>,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Item {
+///   name: String,
+///   price: u32,
+///   on_sale: bool,
+/// }
+///
+/// let mut shop = Shop {
+///   items: vec![
+///     Item { name: "sword".to_string(), price: 100, on_sale: false },
+///     Item { name: "shield".to_string(), price: 80, on_sale: true },
+///   ],
+/// };
+///
+/// let prices = shop
+///   .reflect_path_iter("items[*].price")
+///   .unwrap()
+///   .filter_map(<dyn PartialReflect>::try_downcast_ref::<u32>)
+///   .collect::<Vec<_>>();
+/// assert_eq!(prices, [&100, &80]);
+///
+/// shop.reflect_path_for_each_mut("items[?on_sale].price", |price| {
+///   *price.try_downcast_mut::<u32>().unwrap() /= 2;
+/// })
+/// .unwrap();
+/// assert_eq!(shop.items[1].price, 40);
+///
+/// let expensive = shop.reflect_path_iter("items[?price >= 50].name").unwrap().count();
+/// assert_eq!(expensive, 1);
+/// ```
+///
 /// [`Struct`]: crate::Struct
 /// [`Tuple`]: crate::Tuple
 /// [`TupleStruct`]: crate::TupleStruct
 /// [`List`]: crate::List
 /// [`Array`]: crate::Array
 /// [`Enum`]: crate::Enum
+/// [`reflect_path_iter`]: GetPath::reflect_path_iter
+/// [`reflect_path_for_each_mut`]: GetPath::reflect_path_for_each_mut
 #[diagnostic::on_unimplemented(
     message = "`{Self}` does not implement `GetPath` so cannot be accessed by reflection path",
     note = "consider annotating `{Self}` with `#[derive(Reflect)]`"
@@ -288,6 +431,31 @@ pub trait GetPath: PartialReflect {
     fn path_mut<'p, T: Reflect>(&mut self, path: impl ReflectPath<'p>) -> PathResult<'p, &mut T> {
         path.element_mut(self.as_partial_reflect_mut())
     }
+
+    /// Returns an iterator over every value matched by `path`.
+    ///
+    /// Unlike [`reflect_path`](GetPath::reflect_path), the path may contain
+    /// [wildcards and filters](GetPath#wildcards-and-filters) matching multiple values.
+    /// Paths without them match a single value.
+    fn reflect_path_iter<'p>(
+        &self,
+        path: impl ReflectPath<'p>,
+    ) -> PathResult<'p, impl Iterator<Item = &dyn PartialReflect>> {
+        path.reflect_elements(self.as_partial_reflect())
+    }
+
+    /// Calls `f` with a mutable reference to every value matched by `path`.
+    ///
+    /// This is the mutable counterpart to [`reflect_path_iter`](GetPath::reflect_path_iter).
+    ///
+    /// Values are visited one at a time, so an error may occur after some of them have already been visited.
+    fn reflect_path_for_each_mut<'p>(
+        &mut self,
+        path: impl ReflectPath<'p>,
+        f: impl FnMut(&mut dyn PartialReflect),
+    ) -> PathResult<'p, ()> {
+        path.reflect_elements_mut(self.as_partial_reflect_mut(), f)
+    }
 }
 
 // Implement `GetPath` for `dyn Reflect`
@@ -453,6 +621,30 @@ impl<'a> ReflectPath<'a> for &'a ParsedPath {
         }
         Ok(root)
     }
+    fn reflect_elements(
+        self,
+        root: &dyn PartialReflect,
+    ) -> PathResult<'a, impl Iterator<Item = &dyn PartialReflect>> {
+        let mut elements = Vec::new();
+        query(
+            root,
+            self.0.iter().map(|access| (&access.access, access.offset)),
+            &mut elements,
+        )?;
+        Ok(elements.into_iter())
+    }
+    fn reflect_elements_mut(
+        self,
+        root: &mut dyn PartialReflect,
+        mut f: impl FnMut(&mut dyn PartialReflect),
+    ) -> PathResult<'a, ()> {
+        query_mut(
+            root,
+            self.0.iter().map(|access| (&access.access, access.offset)),
+            &mut f,
+        )?;
+        Ok(())
+    }
 }
 impl<const N: usize> From<[OffsetAccess; N]> for ParsedPath {
     fn from(value: [OffsetAccess; N]) -> Self {
@@ -514,7 +706,11 @@ mod tests {
     use super::*;
     use crate as bevy_reflect;
     use crate::*;
-    use alloc::vec;
+    use alloc::{
+        string::{String, ToString},
+        vec,
+    };
+    use bevy_utils::HashMap;
 
     #[derive(Reflect, PartialEq, Debug)]
     struct A {
@@ -826,4 +1022,161 @@ mod tests {
             ]
         );
     }
+
+    fn f32_values<'a>(values: impl Iterator<Item = &'a dyn PartialReflect>) -> Vec<f32> {
+        values
+            .map(|value| *value.try_downcast_ref::<f32>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn parse_wildcards_and_filters() {
+        let path = ParsedPath::parse("y[*].mосква").unwrap();
+        assert_eq!(path[1].access, Access::Wildcard);
+        assert_eq!(
+            ParsedPath::parse("y.*").unwrap()[1].access,
+            Access::Wildcard
+        );
+
+        let path = ParsedPath::parse("y[?mосква >= 1.5].mосква").unwrap();
+        let Access::Filter(filter) = &path[1].access else {
+            panic!("expected filter access");
+        };
+        assert_eq!(filter.path, ParsedPath::parse("mосква").unwrap());
+        assert_eq!(filter.condition, Some((FilterOp::Ge, "1.5".into())));
+        assert_eq!(path[2].offset, Some(23));
+        assert_eq!(
+            ParsedPath::parse(&path.to_string()).unwrap().to_string(),
+            path.to_string()
+        );
+
+        assert!(ParsedPath::parse("y[?]").is_ok());
+        assert!(ParsedPath::parse("y[?x.0[1] == 2]").is_ok());
+        assert!(ParsedPath::parse("y[?x ==]").is_err());
+        assert!(ParsedPath::parse("y[?x == 1").is_err());
+    }
+
+    #[test]
+    fn reflect_path_iter() {
+        let a = a_sample();
+
+        assert_eq!(
+            f32_values(a.reflect_path_iter("y[*].mосква").unwrap()),
+            [1.0, 2.0]
+        );
+        assert_eq!(
+            f32_values(a.reflect_path_iter("y[?mосква > 1.5].mосква").unwrap()),
+            [2.0]
+        );
+        assert_eq!(a.reflect_path_iter("tuple_variant.*").unwrap().count(), 2);
+        assert_eq!(a.reflect_path_iter("array[?!= 75]").unwrap().count(), 2);
+        assert_eq!(a.reflect_path_iter("[?== Unit]").unwrap().count(), 1);
+        assert_eq!(
+            a.reflect_path_iter("[?0 == 123]").unwrap().count(),
+            1,
+            "only `tuple_variant` has a first tuple field equal to 123"
+        );
+        assert_eq!(a.reflect_path_iter("tuple[?]").unwrap().count(), 1);
+
+        // Paths without wildcards match a single value.
+        assert_eq!(
+            f32_values(a.reflect_path_iter("x.łørđ.mосква").unwrap()),
+            [3.14]
+        );
+
+        let parsed = ParsedPath::parse("y[*].mосква").unwrap();
+        assert_eq!(f32_values(parsed.reflect_elements(&a).unwrap()), [1.0, 2.0]);
+
+        let mut map = HashMap::<String, C>::default();
+        map.insert("a".to_string(), C { mосква: 5.0 });
+        assert_eq!(
+            f32_values(map.reflect_path_iter("[*].mосква").unwrap()),
+            [5.0]
+        );
+    }
+
+    #[test]
+    fn reflect_path_filters_compare_types() {
+        #[derive(Reflect)]
+        struct Item {
+            name: String,
+            tag: &'static str,
+            grade: char,
+            state: F,
+        }
+
+        let items = vec![
+            Item {
+                name: "sword".to_string(),
+                tag: "weapon",
+                grade: 'a',
+                state: F::Unit,
+            },
+            Item {
+                name: "shield".to_string(),
+                tag: "armor",
+                grade: 'b',
+                state: F::Tuple(1, 2),
+            },
+        ];
+
+        let count = |path: &str| items.reflect_path_iter(path).unwrap().count();
+        assert_eq!(count(r#"[?name == "sword"]"#), 1);
+        assert_eq!(count("[?name != 'sword']"), 1);
+        assert_eq!(count("[?name == sword]"), 0);
+        assert_eq!(count("[?tag < 'b']"), 1);
+        assert_eq!(count("[?grade >= 'a']"), 2);
+        assert_eq!(count("[?state == Tuple]"), 1);
+        assert_eq!(count("[?missing == 1]"), 0);
+    }
+
+    #[test]
+    fn reflect_path_for_each_mut() {
+        let mut a = a_sample();
+
+        a.reflect_path_for_each_mut("y[*].mосква", |value| {
+            *value.try_downcast_mut::<f32>().unwrap() *= 10.0;
+        })
+        .unwrap();
+        assert_eq!(a.y, vec![C { mосква: 10.0 }, C { mосква: 20.0 }]);
+
+        let mut visited = 0;
+        a.reflect_path_for_each_mut("array[?> 80]", |value| {
+            *value.try_downcast_mut::<i32>().unwrap() = 0;
+            visited += 1;
+        })
+        .unwrap();
+        assert_eq!(visited, 2);
+        assert_eq!(a.array, [0, 75, 0]);
+
+        let parsed = ParsedPath::parse("tuple_variant.*").unwrap();
+        parsed
+            .reflect_elements_mut(&mut a, |value| {
+                *value.try_downcast_mut::<u32>().unwrap() += 1;
+            })
+            .unwrap();
+        assert_eq!(a.tuple_variant, F::Tuple(124, 322));
+    }
+
+    #[test]
+    fn reflect_path_query_errors() {
+        let mut a = a_sample();
+
+        assert_eq!(
+            a.reflect_path("y[*].mосква").err().unwrap(),
+            ReflectPathError::InvalidAccess(AccessError {
+                kind: AccessErrorKind::MultipleElements,
+                access: Access::Wildcard,
+                offset: Some(2),
+            })
+        );
+        assert!(a.reflect_path_mut("y[?mосква > 1]").is_err());
+
+        assert_eq!(
+            a.reflect_path_iter("w[*]").err().unwrap(),
+            invalid_access(2, ReflectKind::Opaque, ReflectKind::List, "w[*]")
+        );
+        assert!(a.reflect_path_iter("y[*].missing").is_err());
+        assert!(a.reflect_path_for_each_mut("x.*.mосква", |_| {}).is_err());
+    }
 }
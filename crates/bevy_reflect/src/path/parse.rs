@@ -5,7 +5,7 @@ use core::{
 };
 use thiserror::Error;
 
-use super::{Access, ReflectPathError};
+use super::{Access, PathFilter, ReflectPathError};
 
 /// An error that occurs when parsing reflect path strings.
 #[derive(Debug, PartialEq, Eq, Error)]
@@ -32,6 +32,9 @@ enum Error<'a> {
 
     #[error("a ']' was found before an opening '['")]
     CloseBeforeOpen,
+
+    #[error("invalid filter '{0}'")]
+    InvalidFilter(&'a str),
 }
 
 pub(super) struct PathParser<'a> {
@@ -88,6 +91,7 @@ impl<'a> PathParser<'a> {
             Token::Pound => self.next_ident()?.field_index(),
             Token::Ident(ident) => Ok(ident.field()),
             Token::CloseBracket => Err(Error::CloseBeforeOpen),
+            Token::OpenBracket if self.remaining.first() == Some(&b'?') => self.filter(),
             Token::OpenBracket => {
                 let index_ident = self.next_ident()?.list_index()?;
                 match self.next_token() {
@@ -99,6 +103,39 @@ impl<'a> PathParser<'a> {
         }
     }
 
+    /// Parses the `?...]` following the opening bracket of a filter.
+    fn filter(&mut self) -> Result<Access<'a>, Error<'a>> {
+        let mut depth = 0_usize;
+        let close = self.remaining.iter().position(|byte| match byte {
+            b'[' => {
+                depth += 1;
+                false
+            }
+            b']' if depth == 0 => true,
+            b']' => {
+                depth -= 1;
+                false
+            }
+            _ => false,
+        });
+        let Some(close) = close else {
+            return Err(Error::Unclosed);
+        };
+
+        #[expect(
+            unsafe_code,
+            reason = "We have fulfilled the Safety requirements for `from_utf8_unchecked`."
+        )]
+        // SAFETY: `self.remaining` is valid UTF8 (see `next_token`),
+        // and both the `?` and `]` it's split at are ASCII characters.
+        let filter = unsafe { from_utf8_unchecked(&self.remaining[1..close]) };
+        self.remaining = &self.remaining[close + 1..];
+
+        PathFilter::parse(filter)
+            .map(Access::Filter)
+            .ok_or(Error::InvalidFilter(filter))
+    }
+
     fn offset(&self) -> usize {
         self.path.len() - self.remaining.len()
     }
@@ -126,6 +163,9 @@ struct Ident<'a>(&'a str);
 
 impl<'a> Ident<'a> {
     fn field(self) -> Access<'a> {
+        if self.0 == "*" {
+            return Access::Wildcard;
+        }
         let field = |_| Access::Field(self.0.into());
         self.0.parse().map(Access::TupleIndex).unwrap_or_else(field)
     }
@@ -133,6 +173,9 @@ impl<'a> Ident<'a> {
         Ok(Access::FieldIndex(self.0.parse()?))
     }
     fn list_index(self) -> Result<Access<'a>, Error<'a>> {
+        if self.0 == "*" {
+            return Ok(Access::Wildcard);
+        }
         Ok(Access::ListIndex(self.0.parse()?))
     }
 }
@@ -0,0 +1,438 @@
+//! A compact binary format for reflected values.
+
+mod de;
+mod ser;
+
+use crate::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    PartialReflect, TypeRegistration, TypeRegistry,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_utils::TypeIdMap;
+use core::fmt::Display;
+use de::BinaryDeserializer;
+use ser::BinarySerializer;
+use serde::{de::DeserializeSeed, Serialize};
+use thiserror::Error;
+
+/// Writes reflected values in a compact binary format,
+/// to be read back with a [`BinaryReflectReader`].
+///
+/// Unlike the [`ReflectSerializer`], which embeds the full type path of every value,
+/// the writer interns type paths into a table written once at the start of the output,
+/// with each value referring to its type by index.
+/// Values themselves are written without any field names,
+/// using variable-length integers in the style of [postcard].
+///
+/// This makes the output much smaller than text formats like RON,
+/// which is useful for runtime save games or network snapshots,
+/// at the cost of not being human-readable.
+/// Since field names aren't written, data can only be read back by builds
+/// whose types have the same layout, unless the types are versioned with [`ReflectMigrate`].
+///
+/// Every written value must be registered in the [`TypeRegistry`],
+/// along with the types of all of its fields.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry, FromReflect, serde::{BinaryReflectWriter, BinaryReflectReader}};
+/// #[derive(Reflect, Debug, PartialEq)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Position>();
+///
+/// let mut writer = BinaryReflectWriter::new(&registry);
+/// writer.write(&Position { x: 1.0, y: 2.0 }).unwrap();
+/// writer.write(&Position { x: 3.0, y: 4.0 }).unwrap();
+/// let bytes = writer.finish();
+///
+/// let values = BinaryReflectReader::new(&bytes, &registry)
+///     .unwrap()
+///     .map(|value| Position::from_reflect(value.unwrap().as_partial_reflect()).unwrap())
+///     .collect::<Vec<_>>();
+/// assert_eq!(values, vec![Position { x: 1.0, y: 2.0 }, Position { x: 3.0, y: 4.0 }]);
+/// ```
+///
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [postcard]: https://docs.rs/postcard
+/// [`ReflectMigrate`]: crate::serde::ReflectMigrate
+pub struct BinaryReflectWriter<'a> {
+    registry: &'a TypeRegistry,
+    type_indices: TypeIdMap<usize>,
+    type_paths: Vec<&'static str>,
+    values: Vec<u8>,
+    len: usize,
+}
+
+impl<'a> BinaryReflectWriter<'a> {
+    /// Create a new writer using the given registry.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            type_indices: TypeIdMap::default(),
+            type_paths: Vec::new(),
+            values: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Writes the given value.
+    ///
+    /// Dynamic values are written as the type they represent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value doesn't represent a registered type,
+    /// or if it can't be serialized.
+    /// In that case, nothing is written.
+    pub fn write(&mut self, value: &dyn PartialReflect) -> Result<(), BinaryError> {
+        let info = value.get_represented_type_info().ok_or_else(|| {
+            BinaryError::Custom(alloc::format!(
+                "cannot serialize dynamic value without represented type: `{}`",
+                value.reflect_type_path()
+            ))
+        })?;
+        if !self.registry.contains(info.type_id()) {
+            return Err(BinaryError::UnregisteredType(info.type_path().to_string()));
+        }
+
+        let start = self.values.len();
+        let type_index = match self.type_indices.get(&info.type_id()) {
+            Some(index) => *index,
+            None => self.type_paths.len(),
+        };
+
+        let mut serializer = BinarySerializer {
+            output: &mut self.values,
+        };
+        serializer.write_varint(type_index as u128);
+        if let Err(error) =
+            TypedReflectSerializer::new(value, self.registry).serialize(&mut serializer)
+        {
+            self.values.truncate(start);
+            return Err(error);
+        }
+
+        if type_index == self.type_paths.len() {
+            self.type_indices.insert(info.type_id(), type_index);
+            self.type_paths.push(info.type_path());
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    /// The number of values written so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no values were written yet.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bytes of the type table followed by all written values.
+    pub fn finish(self) -> Vec<u8> {
+        let mut output = Vec::with_capacity(self.values.len());
+        let mut serializer = BinarySerializer {
+            output: &mut output,
+        };
+        serializer.write_varint(self.type_paths.len() as u128);
+        for type_path in &self.type_paths {
+            serializer.write_varint(type_path.len() as u128);
+            serializer.output.extend_from_slice(type_path.as_bytes());
+        }
+        output.extend_from_slice(&self.values);
+        output
+    }
+}
+
+/// Reads reflected values written by a [`BinaryReflectWriter`].
+///
+/// The type table is read when the reader is created,
+/// resolving each type path using the [`TypeRegistry`].
+/// Values are then read one by one by iterating over the reader,
+/// with each value deserialized the same way as by the [`TypedReflectDeserializer`].
+///
+/// See [`BinaryReflectWriter`] for an example.
+pub struct BinaryReflectReader<'a, 'de> {
+    registry: &'a TypeRegistry,
+    types: Vec<&'a TypeRegistration>,
+    deserializer: BinaryDeserializer<'de>,
+}
+
+impl<'a, 'de> BinaryReflectReader<'a, 'de> {
+    /// Create a new reader for the given bytes, reading their type table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type table is invalid,
+    /// or refers to types that aren't registered in the given registry.
+    pub fn new(bytes: &'de [u8], registry: &'a TypeRegistry) -> Result<Self, BinaryError> {
+        let mut deserializer = BinaryDeserializer { input: bytes };
+        let len = deserializer.read_varint()?;
+        let types = (0..len)
+            .map(|_| {
+                let type_path = deserializer.read_str()?;
+                registry
+                    .get_with_type_path(type_path)
+                    .ok_or_else(|| BinaryError::UnregisteredType(type_path.to_string()))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            registry,
+            types,
+            deserializer,
+        })
+    }
+
+    /// The registrations of the types in the type table, in the order they were first written.
+    pub fn types(&self) -> &[&'a TypeRegistration] {
+        &self.types
+    }
+
+    fn read(&mut self) -> Result<Box<dyn PartialReflect>, BinaryError> {
+        let index = self.deserializer.read_varint()?;
+        let registration = usize::try_from(index)
+            .ok()
+            .and_then(|index| self.types.get(index))
+            .ok_or(BinaryError::InvalidTypeIndex(index))?;
+        TypedReflectDeserializer::new(registration, self.registry)
+            .deserialize(&mut self.deserializer)
+    }
+}
+
+impl Iterator for BinaryReflectReader<'_, '_> {
+    type Item = Result<Box<dyn PartialReflect>, BinaryError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.deserializer.input.is_empty() {
+            return None;
+        }
+
+        let value = self.read();
+        if value.is_err() {
+            // The remaining values can't be located after an error
+            self.deserializer.input = &[];
+        }
+        Some(value)
+    }
+}
+
+/// An error that occurs when writing or reading the binary format
+/// of a [`BinaryReflectWriter`] or [`BinaryReflectReader`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum BinaryError {
+    /// The input ended before a value was fully read.
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    /// The input contains invalid data.
+    #[error("invalid data: {0}")]
+    InvalidData(&'static str),
+    /// A value referred to a type outside of the type table.
+    #[error("type index {0} is out of bounds of the type table")]
+    InvalidTypeIndex(u128),
+    /// The type table contains a type that isn't registered.
+    #[error("type `{0}` is not registered in the type registry")]
+    UnregisteredType(String),
+    /// A sequence or map was serialized without a known length.
+    #[error("sequences and maps must have a known length")]
+    UnknownLength,
+    /// A value was deserialized without knowing its type,
+    /// which the binary format doesn't support.
+    #[error("the binary format is not self-describing")]
+    NotSelfDescribing,
+    /// A custom error from a serializer or deserializer.
+    #[error("{0}")]
+    Custom(String),
+}
+
+impl serde::ser::Error for BinaryError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+impl serde::de::Error for BinaryError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_reflect, serde::ReflectSerializer, FromReflect, Reflect, TypePath};
+    use alloc::{borrow::ToOwned, vec};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Debug, PartialEq, Clone)]
+    enum Shape {
+        Circle(f32),
+        Rect { width: u16, height: u16 },
+        Empty,
+    }
+
+    #[derive(Reflect, Debug, PartialEq, Clone)]
+    struct Entity {
+        name: String,
+        id: u64,
+        offset: i32,
+        alive: bool,
+        initial: char,
+        shapes: Vec<Shape>,
+        parent: Option<u32>,
+        tags: HashMap<String, i8>,
+        position: (f64, f64),
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Entity>();
+        registry.register::<Shape>();
+        registry
+    }
+
+    fn entity() -> Entity {
+        Entity {
+            name: "player".to_owned(),
+            id: u64::MAX,
+            offset: -300,
+            alive: true,
+            initial: 'é',
+            shapes: vec![
+                Shape::Circle(0.5),
+                Shape::Rect {
+                    width: 3,
+                    height: 400,
+                },
+                Shape::Empty,
+            ],
+            parent: Some(7),
+            tags: HashMap::from_iter([("team".to_owned(), -1)]),
+            position: (1.5, -2.25),
+        }
+    }
+
+    #[test]
+    fn should_round_trip_values() {
+        let registry = registry();
+        let mut writer = BinaryReflectWriter::new(&registry);
+        writer.write(&entity()).unwrap();
+        writer.write(&Shape::Circle(2.0)).unwrap();
+        writer
+            .write(&Entity {
+                parent: None,
+                ..entity()
+            })
+            .unwrap();
+        assert_eq!(writer.len(), 3);
+        let bytes = writer.finish();
+
+        let mut reader = BinaryReflectReader::new(&bytes, &registry).unwrap();
+        assert_eq!(reader.types().len(), 2);
+
+        let value = reader.next().unwrap().unwrap();
+        assert_eq!(
+            Entity::from_reflect(value.as_partial_reflect()),
+            Some(entity())
+        );
+        let value = reader.next().unwrap().unwrap();
+        assert_eq!(
+            Shape::from_reflect(value.as_partial_reflect()),
+            Some(Shape::Circle(2.0))
+        );
+        let value = reader.next().unwrap().unwrap();
+        assert_eq!(
+            Entity::from_reflect(value.as_partial_reflect())
+                .unwrap()
+                .parent,
+            None
+        );
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn should_be_smaller_than_ron() {
+        let registry = registry();
+        let entities = vec![entity(); 10];
+
+        let mut writer = BinaryReflectWriter::new(&registry);
+        let mut ron_len = 0;
+        for entity in &entities {
+            writer.write(entity).unwrap();
+            ron_len += ron::to_string(&ReflectSerializer::new(entity, &registry))
+                .unwrap()
+                .len();
+        }
+        let bytes = writer.finish();
+
+        assert!(bytes.len() * 3 < ron_len);
+    }
+
+    #[test]
+    fn should_write_dynamic_values() {
+        let registry = registry();
+        let mut writer = BinaryReflectWriter::new(&registry);
+        writer.write(entity().clone_value().as_ref()).unwrap();
+        let bytes = writer.finish();
+
+        let value = BinaryReflectReader::new(&bytes, &registry)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            Entity::from_reflect(value.as_partial_reflect()),
+            Some(entity())
+        );
+    }
+
+    #[test]
+    fn should_return_errors() {
+        let registry = registry();
+
+        #[derive(Reflect)]
+        struct Unregistered;
+
+        let mut writer = BinaryReflectWriter::new(&registry);
+        assert!(writer.write(&Unregistered).is_err());
+        assert!(writer.is_empty());
+        writer.write(&Shape::Empty).unwrap();
+        let bytes = writer.finish();
+
+        let mut empty_registry = TypeRegistry::empty();
+        assert_eq!(
+            BinaryReflectReader::new(&bytes, &empty_registry).err(),
+            Some(BinaryError::UnregisteredType(Shape::type_path().to_owned()))
+        );
+
+        empty_registry.register::<Shape>();
+        let mut reader =
+            BinaryReflectReader::new(&bytes[..bytes.len() - 1], &empty_registry).unwrap();
+        assert_eq!(
+            reader.next().unwrap().err(),
+            Some(BinaryError::UnexpectedEnd)
+        );
+        assert!(reader.next().is_none());
+
+        let mut invalid_index = bytes.clone();
+        let len = invalid_index.len();
+        invalid_index[len - 2] = 5;
+        let mut reader = BinaryReflectReader::new(&invalid_index, &empty_registry).unwrap();
+        assert_eq!(
+            reader.next().unwrap().err(),
+            Some(BinaryError::InvalidTypeIndex(5))
+        );
+    }
+}
@@ -1,6 +1,8 @@
 #[cfg(feature = "debug_stack")]
 use crate::serde::de::error_utils::TYPE_INFO_STACK;
-use crate::serde::{ReflectDeserializeWithRegistry, SerializationData};
+use crate::serde::{
+    MigrationError, ReflectDeserializeWithRegistry, ReflectMigrate, SerializationData,
+};
 use crate::{
     serde::{
         de::{
@@ -14,7 +16,7 @@ use crate::{
 };
 use alloc::boxed::Box;
 use core::{fmt, fmt::Formatter};
-use serde::de::{DeserializeSeed, Error, IgnoredAny, MapAccess, Visitor};
+use serde::de::{DeserializeSeed, Error, IgnoredAny, MapAccess, SeqAccess, Visitor};
 
 use super::ReflectDeserializerProcessor;
 
@@ -273,6 +275,8 @@ pub struct TypedReflectDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
     processor: Option<&'a mut P>,
+    /// Whether the version of types registering [`ReflectMigrate`] was already deserialized.
+    versioned: bool,
 }
 
 impl<'a> TypedReflectDeserializer<'a, ()> {
@@ -290,6 +294,7 @@ impl<'a> TypedReflectDeserializer<'a, ()> {
             registration,
             registry,
             processor: None,
+            versioned: false,
         }
     }
 
@@ -308,6 +313,7 @@ impl<'a> TypedReflectDeserializer<'a, ()> {
             registration,
             registry,
             processor: None,
+            versioned: false,
         }
     }
 }
@@ -331,6 +337,7 @@ impl<'a, P: ReflectDeserializerProcessor> TypedReflectDeserializer<'a, P> {
             registration,
             registry,
             processor: Some(processor),
+            versioned: false,
         }
     }
 
@@ -344,6 +351,7 @@ impl<'a, P: ReflectDeserializerProcessor> TypedReflectDeserializer<'a, P> {
             registration,
             registry,
             processor,
+            versioned: false,
         }
     }
 }
@@ -357,6 +365,21 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
     where
         D: serde::Deserializer<'de>,
     {
+        // Versioned types are deserialized from a tuple of their version and their value
+        if !self.versioned {
+            if let Some(migrate) = self.registration.data::<ReflectMigrate>() {
+                return deserializer.deserialize_tuple(
+                    2,
+                    VersionedVisitor {
+                        migrate,
+                        registration: self.registration,
+                        registry: self.registry,
+                        processor: self.processor,
+                    },
+                );
+            }
+        }
+
         let deserialize_internal = || -> Result<Self::Value, D::Error> {
             // First, check if our processor wants to deserialize this type
             // This takes priority over any other deserialization operations
@@ -529,3 +552,61 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
         output
     }
 }
+
+/// A [`Visitor`] for deserializing the version and value of types registering [`ReflectMigrate`],
+/// upgrading values with older versions to the current one.
+struct VersionedVisitor<'a, P> {
+    migrate: &'a ReflectMigrate,
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+}
+
+impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for VersionedVisitor<'_, P> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a tuple containing the version and value of the reflected type")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let version: u32 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+
+        if version == self.migrate.version() {
+            return seq
+                .next_element_seed(TypedReflectDeserializer {
+                    registration: self.registration,
+                    registry: self.registry,
+                    processor: self.processor,
+                    versioned: true,
+                })?
+                .ok_or_else(|| Error::invalid_length(1, &"a tuple of version and value"));
+        }
+
+        let latest = self.migrate.version();
+        let Some(layout) = self.migrate.layout(version).filter(|_| version < latest) else {
+            return Err(make_custom_error(if version > latest {
+                MigrationError::UnsupportedVersion { version, latest }
+            } else {
+                MigrationError::MissingMigration { version }
+            }));
+        };
+
+        let value = seq
+            .next_element_seed(TypedReflectDeserializer::new_internal(
+                &layout,
+                self.registry,
+                self.processor,
+            ))?
+            .ok_or_else(|| Error::invalid_length(1, &"a tuple of version and value"))?;
+
+        self.migrate
+            .migrate(version, value)
+            .map_err(make_custom_error)
+    }
+}
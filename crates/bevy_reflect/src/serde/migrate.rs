@@ -0,0 +1,403 @@
+use crate::{FromReflect, FromType, GetTypeRegistration, PartialReflect, TypeRegistration};
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+use core::fmt;
+use thiserror::Error;
+
+/// A function upgrading a value from one layout version to the next.
+type MigrateFn = Arc<
+    dyn Fn(Box<dyn PartialReflect>) -> Result<Box<dyn PartialReflect>, MigrationError>
+        + Send
+        + Sync,
+>;
+
+/// A type whose serialized layout is versioned, allowing values serialized with
+/// older layouts to be upgraded when they're deserialized.
+///
+/// Types implementing this trait should register the [`ReflectMigrate`] type data,
+/// usually by adding `#[reflect(Migrate)]` to the type definition.
+/// The reflection serializers then store the current [`VERSION`] alongside each value,
+/// and the reflection deserializers run the [migrations] needed to upgrade values
+/// serialized with an older version.
+///
+/// Older layouts are described by types that only exist to be migrated from,
+/// such as a copy of the type before a field was added or renamed.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry, FromReflect, serde::{Migrate, ReflectMigrate, ReflectDeserializer}};
+/// # use serde::de::DeserializeSeed;
+/// // The layout of `Player` before `health` was renamed to `hp`.
+/// #[derive(Reflect)]
+/// struct PlayerV0 {
+///     health: u32,
+/// }
+///
+/// #[derive(Reflect, Debug, PartialEq)]
+/// #[reflect(Migrate)]
+/// struct Player {
+///     hp: u32,
+/// }
+///
+/// impl Migrate for Player {
+///     const VERSION: u32 = 1;
+///
+///     fn migrations(migrate: ReflectMigrate) -> ReflectMigrate {
+///         migrate.with_migration(0, |old: PlayerV0| Player { hp: old.health })
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Player>();
+///
+/// // A value serialized with the first version of the layout
+/// let input = r#"{
+///     "my_crate::Player": (0, (health: 10)),
+/// }"#;
+/// # let input = input.replace("my_crate", module_path!());
+///
+/// let mut deserializer = ron::de::Deserializer::from_str(&input).unwrap();
+/// let value = ReflectDeserializer::new(&registry).deserialize(&mut deserializer).unwrap();
+/// assert_eq!(Player::from_reflect(value.as_partial_reflect()), Some(Player { hp: 10 }));
+/// ```
+///
+/// [`VERSION`]: Migrate::VERSION
+/// [migrations]: ReflectMigrate::with_migration
+pub trait Migrate {
+    /// The version of the current layout of this type.
+    ///
+    /// This should be increased whenever the layout changes,
+    /// along with adding a migration from the previous version.
+    const VERSION: u32;
+
+    /// Adds the migrations upgrading older layouts of this type to the given type data.
+    ///
+    /// See [`ReflectMigrate::with_migration`] for details.
+    fn migrations(migrate: ReflectMigrate) -> ReflectMigrate {
+        migrate
+    }
+}
+
+/// Type data used to upgrade values serialized with an older layout of a type.
+///
+/// This type data is usually created for types implementing [`Migrate`],
+/// but can also be created manually and added [via the registry].
+///
+/// See [`Migrate`] for details.
+///
+/// [via the registry]: crate::TypeRegistry::register_type_data
+#[derive(Clone)]
+pub struct ReflectMigrate {
+    version: u32,
+    migrations: Vec<Migration>,
+}
+
+#[derive(Clone)]
+struct Migration {
+    from_version: u32,
+    layout: fn() -> TypeRegistration,
+    migrate: MigrateFn,
+}
+
+impl ReflectMigrate {
+    /// Create type data for a type whose current layout has the given version.
+    pub fn new(version: u32) -> Self {
+        Self {
+            version,
+            migrations: Vec::new(),
+        }
+    }
+
+    /// The version of the current layout of the type.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Adds a migration upgrading values from the given version to the next one.
+    ///
+    /// The layout of the old version is described by the type `Old`,
+    /// which values serialized with that version are deserialized as.
+    /// The migration then converts them to the layout of the next version,
+    /// which should be the type the next migration converts from,
+    /// or the type itself when upgrading to the current version.
+    ///
+    /// Values are upgraded one version at a time,
+    /// so every version between the oldest supported version and the current version
+    /// needs its own migration.
+    pub fn with_migration<Old, New>(
+        mut self,
+        from_version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) -> Self
+    where
+        Old: FromReflect + GetTypeRegistration,
+        New: PartialReflect,
+    {
+        self.migrations
+            .retain(|migration| migration.from_version != from_version);
+        self.migrations.push(Migration {
+            from_version,
+            layout: Old::get_type_registration,
+            migrate: Arc::new(move |value| {
+                let old = Old::from_reflect(value.as_ref()).ok_or_else(|| {
+                    MigrationError::InvalidLayout {
+                        version: from_version,
+                        type_path: value.reflect_type_path().into(),
+                    }
+                })?;
+                Ok(Box::new(migrate(old)))
+            }),
+        });
+        self
+    }
+
+    /// Returns the registration of the type describing the layout of the given version.
+    ///
+    /// Returns `None` if no migration from the given version was added.
+    pub fn layout(&self, version: u32) -> Option<TypeRegistration> {
+        self.migration(version)
+            .map(|migration| (migration.layout)())
+    }
+
+    /// Upgrades a value with the layout of the given version to the current version.
+    ///
+    /// Values which already have the current version are returned as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the version is newer than the current version,
+    /// if a migration is missing for any of the versions in between,
+    /// or if the value doesn't match the layout a migration expects.
+    pub fn migrate(
+        &self,
+        version: u32,
+        mut value: Box<dyn PartialReflect>,
+    ) -> Result<Box<dyn PartialReflect>, MigrationError> {
+        if version > self.version {
+            return Err(MigrationError::UnsupportedVersion {
+                version,
+                latest: self.version,
+            });
+        }
+
+        for version in version..self.version {
+            let migration = self
+                .migration(version)
+                .ok_or(MigrationError::MissingMigration { version })?;
+            value = (migration.migrate)(value)?;
+        }
+
+        Ok(value)
+    }
+
+    fn migration(&self, version: u32) -> Option<&Migration> {
+        self.migrations
+            .iter()
+            .find(|migration| migration.from_version == version)
+    }
+}
+
+impl fmt::Debug for ReflectMigrate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReflectMigrate")
+            .field("version", &self.version)
+            .field(
+                "migrations",
+                &self
+                    .migrations
+                    .iter()
+                    .map(|migration| migration.from_version)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<T: Migrate> FromType<T> for ReflectMigrate {
+    fn from_type() -> Self {
+        T::migrations(Self::new(T::VERSION))
+    }
+}
+
+/// An error that occurs when upgrading a value with [`ReflectMigrate`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MigrationError {
+    /// The value was serialized with a newer version than the current one.
+    #[error("version {version} is newer than the latest supported version {latest}")]
+    UnsupportedVersion {
+        /// The version of the value.
+        version: u32,
+        /// The current version of the type.
+        latest: u32,
+    },
+    /// No migration was added to upgrade values from the given version.
+    #[error("no migration was added from version {version}")]
+    MissingMigration {
+        /// The version no migration was found for.
+        version: u32,
+    },
+    /// The value didn't match the layout expected by the migration from the given version.
+    #[error("value of type `{type_path}` does not match the layout of version {version}")]
+    InvalidLayout {
+        /// The version the value was migrated from.
+        version: u32,
+        /// The type path of the value.
+        type_path: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        self as bevy_reflect,
+        serde::{ReflectDeserializer, ReflectSerializer, TypedReflectDeserializer},
+        Reflect, TypeRegistry,
+    };
+    use alloc::{string::ToString, vec};
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect)]
+    struct PlayerV0 {
+        health: u32,
+    }
+
+    #[derive(Reflect)]
+    struct PlayerV1 {
+        hp: u32,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(Migrate)]
+    struct Player {
+        hp: u32,
+        level: u8,
+    }
+
+    impl Migrate for Player {
+        const VERSION: u32 = 2;
+
+        fn migrations(migrate: ReflectMigrate) -> ReflectMigrate {
+            migrate
+                .with_migration(0, |old: PlayerV0| PlayerV1 { hp: old.health })
+                .with_migration(1, |old: PlayerV1| Player {
+                    hp: old.hp,
+                    level: 1,
+                })
+        }
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Save {
+        players: Vec<Player>,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Save>();
+        registry
+    }
+
+    fn deserialize(input: &str, registry: &TypeRegistry) -> Result<Save, ron::Error> {
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let value =
+            TypedReflectDeserializer::of::<Save>(registry).deserialize(&mut deserializer)?;
+        Ok(Save::from_reflect(value.as_partial_reflect()).unwrap())
+    }
+
+    #[test]
+    fn should_serialize_version() {
+        let registry = registry();
+        let player = Player { hp: 3, level: 7 };
+
+        let output = ron::to_string(&ReflectSerializer::new(&player, &registry)).unwrap();
+        let expected = r#"{"bevy_reflect::serde::migrate::tests::Player":(2,(hp:3,level:7))}"#;
+        assert_eq!(output, expected);
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            Player::from_reflect(value.as_partial_reflect()),
+            Some(player)
+        );
+    }
+
+    #[test]
+    fn should_migrate_old_versions() {
+        let registry = registry();
+
+        let save = deserialize(
+            "(players: [(0, (health: 5)), (1, (hp: 6)), (2, (hp: 7, level: 3))])",
+            &registry,
+        )
+        .unwrap();
+        assert_eq!(
+            save.players,
+            vec![
+                Player { hp: 5, level: 1 },
+                Player { hp: 6, level: 1 },
+                Player { hp: 7, level: 3 },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_migrate_with_bincode() {
+        use bincode::Options;
+
+        let registry = registry();
+        let old = PlayerV1 { hp: 9 };
+        let mut bytes = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .serialize(&1_u32)
+            .unwrap();
+        bytes.extend(
+            bincode::DefaultOptions::new()
+                .with_fixint_encoding()
+                .serialize(&crate::serde::TypedReflectSerializer::new(&old, &registry))
+                .unwrap(),
+        );
+
+        let value = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(TypedReflectDeserializer::of::<Player>(&registry), &bytes)
+            .unwrap();
+        assert_eq!(
+            Player::from_reflect(value.as_partial_reflect()),
+            Some(Player { hp: 9, level: 1 })
+        );
+    }
+
+    #[test]
+    fn should_fail_on_unknown_versions() {
+        let registry = registry();
+
+        let error = deserialize("(players: [(3, (hp: 7, level: 3))])", &registry).unwrap_err();
+        assert!(error.to_string().contains(
+            &MigrationError::UnsupportedVersion {
+                version: 3,
+                latest: 2
+            }
+            .to_string()
+        ));
+
+        let migrate = ReflectMigrate::new(2).with_migration(1, |old: PlayerV1| Player {
+            hp: old.hp,
+            level: 1,
+        });
+        assert_eq!(
+            migrate
+                .migrate(0, Box::new(PlayerV0 { health: 1 }))
+                .unwrap_err(),
+            MigrationError::MissingMigration { version: 0 }
+        );
+        assert!(matches!(
+            migrate
+                .migrate(1, Box::new(PlayerV0 { health: 1 }))
+                .unwrap_err(),
+            MigrationError::InvalidLayout { version: 1, .. }
+        ));
+    }
+}
@@ -1,8 +1,12 @@
+mod binary;
 mod de;
+mod migrate;
 mod ser;
 mod type_data;
 
+pub use binary::*;
 pub use de::*;
+pub use migrate::*;
 pub use ser::*;
 pub use type_data::*;
 
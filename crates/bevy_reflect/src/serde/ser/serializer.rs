@@ -7,9 +7,13 @@ use crate::{
         sets::SetSerializer, structs::StructSerializer, tuple_structs::TupleStructSerializer,
         tuples::TupleSerializer,
     },
+    serde::ReflectMigrate,
     PartialReflect, ReflectRef, TypeRegistry,
 };
-use serde::{ser::SerializeMap, Serialize, Serializer};
+use serde::{
+    ser::{SerializeMap, SerializeTuple},
+    Serialize, Serializer,
+};
 
 use super::ReflectSerializerProcessor;
 
@@ -172,6 +176,8 @@ pub struct TypedReflectSerializer<'a, P = ()> {
     value: &'a dyn PartialReflect,
     registry: &'a TypeRegistry,
     processor: Option<&'a P>,
+    /// Whether the version of types registering [`ReflectMigrate`] was already serialized.
+    versioned: bool,
 }
 
 impl<'a> TypedReflectSerializer<'a, ()> {
@@ -189,6 +195,7 @@ impl<'a> TypedReflectSerializer<'a, ()> {
             value,
             registry,
             processor: None,
+            versioned: false,
         }
     }
 }
@@ -212,6 +219,7 @@ impl<'a, P> TypedReflectSerializer<'a, P> {
             value,
             registry,
             processor: Some(processor),
+            versioned: false,
         }
     }
 
@@ -225,6 +233,7 @@ impl<'a, P> TypedReflectSerializer<'a, P> {
             value,
             registry,
             processor,
+            versioned: false,
         }
     }
 }
@@ -234,6 +243,22 @@ impl<P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'_, P>
     where
         S: Serializer,
     {
+        // Versioned types are serialized as a tuple of their version and their value
+        if !self.versioned {
+            if let Some(migrate) = self.value.get_represented_type_info().and_then(|info| {
+                self.registry
+                    .get_type_data::<ReflectMigrate>(info.type_id())
+            }) {
+                let mut state = serializer.serialize_tuple(2)?;
+                state.serialize_element(&migrate.version())?;
+                state.serialize_element(&TypedReflectSerializer {
+                    versioned: true,
+                    ..*self
+                })?;
+                return state.end();
+            }
+        }
+
         #[cfg(feature = "debug_stack")]
         {
             if let Some(info) = self.value.get_represented_type_info() {
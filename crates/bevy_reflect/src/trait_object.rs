@@ -0,0 +1,394 @@
+use crate::{
+    serde::{
+        DeserializeWithRegistry, ReflectDeserializeWithRegistry, ReflectDeserializer,
+        ReflectSerializeWithRegistry, ReflectSerializer, SerializeWithRegistry,
+    },
+    utility::{GenericTypeInfoCell, GenericTypePathCell},
+    ApplyError, FromReflect, FromType, GetTypeRegistration, OpaqueInfo, PartialReflect, Reflect,
+    ReflectFromPtr, ReflectFromReflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath,
+    TypeRegistration, TypeRegistry, Typed,
+};
+use alloc::{boxed::Box, format};
+use bevy_utils::{NoOpHash, TypeIdMap};
+use core::{any::TypeId, fmt::Formatter};
+use serde::{de::DeserializeSeed, Serialize};
+
+#[cfg(feature = "std")]
+use std::sync::{PoisonError, RwLock};
+
+#[cfg(not(feature = "std"))]
+use spin::RwLock;
+
+/// A function converting a reflected value into a boxed trait object.
+pub type TraitObjectConstructor<T> = fn(&dyn PartialReflect) -> Option<Box<T>>;
+
+/// A trait object type, such as `dyn MyTrait`, which can be reflected when boxed.
+///
+/// This trait is implemented for `dyn MyTrait` by the [`#[reflect_trait(boxed)]`](crate::reflect_trait)
+/// attribute macro, and it is not meant to be implemented manually.
+///
+/// It allows `Box<dyn MyTrait>` to implement [`Reflect`], [`FromReflect`], and [`GetTypeRegistration`],
+/// so that trait objects can be stored in reflected types without making them opaque.
+/// The box is transparent to reflection: its [`ReflectRef`] and [`ReflectMut`] are those of the
+/// underlying value, so it can be inspected and accessed with [reflection paths] like any other value.
+///
+/// It is serialized like a value serialized with a [`ReflectSerializer`],
+/// along with the type path of the underlying value,
+/// and can be deserialized into any type that registered the `ReflectMyTrait` type data.
+///
+/// Note that `Box<dyn MyTrait>` is a reflected type of its own,
+/// so methods like [`Reflect::as_reflect`] should be called on the dereferenced box
+/// in order to downcast the underlying value.
+///
+/// [reflection paths]: crate::GetPath
+pub trait ReflectTraitObject: Reflect + TypePath {
+    /// The constructors for each type implementing the trait,
+    /// indexed by the [`TypeId`] of that type.
+    fn constructors() -> &'static TraitObjectConstructors<Self>;
+}
+
+/// A map of [constructors] converting reflected values into boxed trait objects `Box<T>`.
+///
+/// Each implementor of the trait adds its constructor to the map when its
+/// `ReflectMyTrait` type data is created.
+///
+/// [constructors]: TraitObjectConstructor
+pub struct TraitObjectConstructors<T: ?Sized + 'static>(
+    RwLock<TypeIdMap<TraitObjectConstructor<T>>>,
+);
+
+impl<T: ?Sized + 'static> TraitObjectConstructors<T> {
+    /// Creates an empty map of constructors.
+    pub const fn new() -> Self {
+        Self(RwLock::new(TypeIdMap::with_hasher(NoOpHash)))
+    }
+
+    /// Adds the constructor for the type with the given [`TypeId`].
+    pub fn insert(&self, type_id: TypeId, constructor: TraitObjectConstructor<T>) {
+        let write_lock = self.0.write();
+
+        #[cfg(feature = "std")]
+        let write_lock = write_lock.unwrap_or_else(PoisonError::into_inner);
+
+        let mut write_lock = write_lock;
+        write_lock.insert(type_id, constructor);
+    }
+
+    /// Returns the constructor for the type with the given [`TypeId`], if any.
+    pub fn get(&self, type_id: TypeId) -> Option<TraitObjectConstructor<T>> {
+        let read_lock = self.0.read();
+
+        #[cfg(feature = "std")]
+        let read_lock = read_lock.unwrap_or_else(PoisonError::into_inner);
+
+        read_lock.get(&type_id).copied()
+    }
+
+    /// Converts the given value into a boxed trait object,
+    /// using the constructor of the type it represents.
+    ///
+    /// Returns `None` if the represented type didn't add a constructor,
+    /// or if the value couldn't be converted to that type.
+    pub fn from_reflect(&self, value: &dyn PartialReflect) -> Option<Box<T>> {
+        let type_id = value.get_represented_type_info()?.type_id();
+        self.get(type_id)?(value)
+    }
+}
+
+impl<T: ?Sized + 'static> Default for TraitObjectConstructors<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the underlying value of a boxed trait object,
+/// or the value itself if it isn't one.
+fn unbox<T: ?Sized + ReflectTraitObject>(value: &dyn PartialReflect) -> &dyn PartialReflect {
+    match value.try_downcast_ref::<Box<T>>() {
+        Some(value) => T::as_partial_reflect(value),
+        None => value,
+    }
+}
+
+impl<T: ?Sized + ReflectTraitObject> TypePath for Box<T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("alloc::boxed::Box<{}>", T::type_path()))
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("Box<{}>", T::short_type_path()))
+    }
+
+    fn type_ident() -> Option<&'static str> {
+        Some("Box")
+    }
+
+    fn crate_name() -> Option<&'static str> {
+        Some("alloc")
+    }
+
+    fn module_path() -> Option<&'static str> {
+        Some("alloc::boxed")
+    }
+}
+
+impl<T: ?Sized + ReflectTraitObject> Typed for Box<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| TypeInfo::Opaque(OpaqueInfo::new::<Self>()))
+    }
+}
+
+impl<T: ?Sized + ReflectTraitObject> PartialReflect for Box<T> {
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    fn into_partial_reflect(self: Box<Self>) -> Box<dyn PartialReflect> {
+        self
+    }
+
+    fn as_partial_reflect(&self) -> &dyn PartialReflect {
+        self
+    }
+
+    fn as_partial_reflect_mut(&mut self) -> &mut dyn PartialReflect {
+        self
+    }
+
+    fn try_into_reflect(self: Box<Self>) -> Result<Box<dyn Reflect>, Box<dyn PartialReflect>> {
+        Ok(self)
+    }
+
+    fn try_as_reflect(&self) -> Option<&dyn Reflect> {
+        Some(self)
+    }
+
+    fn try_as_reflect_mut(&mut self) -> Option<&mut dyn Reflect> {
+        Some(self)
+    }
+
+    fn try_apply(&mut self, value: &dyn PartialReflect) -> Result<(), ApplyError> {
+        let value = unbox::<T>(value);
+        T::try_apply(self, value).or_else(|error| {
+            // Replace the underlying value if it has a different type
+            *self = T::constructors().from_reflect(value).ok_or(error)?;
+            Ok(())
+        })
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        T::reflect_ref(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        T::reflect_mut(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        T::reflect_owned(*self)
+    }
+
+    fn clone_value(&self) -> Box<dyn PartialReflect> {
+        match T::constructors().from_reflect(T::as_partial_reflect(self)) {
+            Some(value) => Box::new(value),
+            None => T::clone_value(self),
+        }
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        T::reflect_hash(self)
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn PartialReflect) -> Option<bool> {
+        T::reflect_partial_eq(self, unbox::<T>(value))
+    }
+
+    fn debug(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        T::debug(self, f)
+    }
+}
+
+crate::impl_full_reflect!(<T> for Box<T> where T: ?Sized + ReflectTraitObject);
+
+impl<T: ?Sized + ReflectTraitObject> FromReflect for Box<T> {
+    fn from_reflect(reflect: &dyn PartialReflect) -> Option<Self> {
+        T::constructors().from_reflect(unbox::<T>(reflect))
+    }
+}
+
+impl<T: ?Sized + ReflectTraitObject> GetTypeRegistration for Box<T> {
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration.insert::<ReflectFromReflect>(FromType::<Self>::from_type());
+        registration.insert::<ReflectSerializeWithRegistry>(FromType::<Self>::from_type());
+        registration.insert::<ReflectDeserializeWithRegistry>(FromType::<Self>::from_type());
+        registration
+    }
+}
+
+impl<T: ?Sized + ReflectTraitObject> SerializeWithRegistry for Box<T> {
+    fn serialize<S>(&self, serializer: S, registry: &TypeRegistry) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        ReflectSerializer::new(T::as_partial_reflect(self), registry).serialize(serializer)
+    }
+}
+
+impl<'de, T: ?Sized + ReflectTraitObject> DeserializeWithRegistry<'de> for Box<T> {
+    fn deserialize<D>(deserializer: D, registry: &TypeRegistry) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = ReflectDeserializer::new(registry).deserialize(deserializer)?;
+        T::constructors()
+            .from_reflect(value.as_partial_reflect())
+            .ok_or_else(|| {
+                serde::de::Error::custom(format_args!(
+                    "type `{}` cannot be converted to `{}`",
+                    value.reflect_type_path(),
+                    T::type_path()
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_reflect,
+        serde::{TypedReflectDeserializer, TypedReflectSerializer},
+        FromReflect, GetPath, PartialReflect, Reflect, TypePath, TypeRegistry,
+    };
+    use alloc::{boxed::Box, vec, vec::Vec};
+    use bevy_reflect_derive::reflect_trait;
+    use serde::de::DeserializeSeed;
+
+    #[reflect_trait(boxed)]
+    trait Shape: Reflect {
+        fn area(&self) -> f32;
+    }
+
+    #[derive(Reflect)]
+    #[reflect(Shape)]
+    struct Square {
+        side: f32,
+    }
+
+    impl Shape for Square {
+        fn area(&self) -> f32 {
+            self.side * self.side
+        }
+    }
+
+    #[derive(Reflect)]
+    #[reflect(Shape)]
+    struct Rectangle(f32, f32);
+
+    impl Shape for Rectangle {
+        fn area(&self) -> f32 {
+            self.0 * self.1
+        }
+    }
+
+    #[derive(Reflect)]
+    struct Drawing {
+        main: Box<dyn Shape>,
+        others: Vec<Box<dyn Shape>>,
+    }
+
+    fn drawing() -> Drawing {
+        Drawing {
+            main: Box::new(Square { side: 2.0 }),
+            others: vec![Box::new(Rectangle(1.0, 3.0))],
+        }
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Drawing>();
+        registry.register::<Square>();
+        registry.register::<Rectangle>();
+        registry
+    }
+
+    fn areas(drawing: &Drawing) -> Vec<f32> {
+        core::iter::once(&drawing.main)
+            .chain(&drawing.others)
+            .map(|shape| shape.area())
+            .collect()
+    }
+
+    #[test]
+    fn should_reflect_boxed_trait_objects() {
+        let _ = registry();
+        let mut drawing = drawing();
+
+        assert_eq!(
+            <Box<dyn Shape>>::type_path(),
+            "alloc::boxed::Box<dyn bevy_reflect::trait_object::tests::Shape>"
+        );
+        assert_eq!(<Box<dyn Shape>>::short_type_path(), "Box<dyn Shape>");
+
+        assert_eq!(drawing.path::<f32>("main.side"), Ok(&2.0));
+        assert_eq!(drawing.path::<f32>("others[0].1"), Ok(&3.0));
+        *drawing.path_mut::<f32>("main.side").unwrap() = 4.0;
+        assert_eq!(drawing.main.area(), 16.0);
+
+        let main = (*drawing.main)
+            .as_reflect()
+            .downcast_ref::<Square>()
+            .unwrap();
+        assert_eq!(main.side, 4.0);
+    }
+
+    #[test]
+    fn should_clone_and_apply_boxed_trait_objects() {
+        let _ = registry();
+        let mut drawing = drawing();
+
+        let cloned = Drawing::from_reflect(drawing.clone_value().as_partial_reflect()).unwrap();
+        assert_eq!(areas(&cloned), vec![4.0, 3.0]);
+
+        // Applying a value of another type replaces the underlying value
+        let rectangle: Box<dyn Shape> = Box::new(Rectangle(2.0, 5.0));
+        drawing.main.apply(rectangle.as_partial_reflect());
+        assert!((*drawing.main).as_reflect().is::<Rectangle>());
+        assert_eq!(areas(&drawing), vec![10.0, 3.0]);
+    }
+
+    #[test]
+    fn should_serialize_boxed_trait_objects() {
+        let registry = registry();
+        let drawing = drawing();
+
+        let output = ron::to_string(&TypedReflectSerializer::new(&drawing, &registry)).unwrap();
+        let expected = r#"(main:{"bevy_reflect::trait_object::tests::Square":(side:2.0)},others:[{"bevy_reflect::trait_object::tests::Rectangle":(1.0,3.0)}])"#;
+        assert_eq!(output, expected);
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let value = TypedReflectDeserializer::of::<Drawing>(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let drawing = Drawing::from_reflect(value.as_partial_reflect()).unwrap();
+        assert_eq!(areas(&drawing), vec![4.0, 3.0]);
+    }
+
+    #[test]
+    fn should_convert_with_type_data() {
+        let registry = registry();
+
+        let value = Square { side: 3.0 }.clone_value();
+        let shape = ReflectShape::from_reflect(value.as_partial_reflect()).unwrap();
+        assert_eq!(shape.area(), 9.0);
+
+        let type_data = registry
+            .get_type_data::<ReflectShape>(core::any::TypeId::of::<Rectangle>())
+            .unwrap();
+        assert_eq!(type_data.get(&Rectangle(2.0, 2.0)).unwrap().area(), 4.0);
+    }
+}
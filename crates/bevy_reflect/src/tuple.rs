@@ -750,7 +750,7 @@ const _: () = {
     macro_rules! impl_get_ownership_tuple {
     ($(#[$meta:meta])* $($name: ident),*) => {
         $(#[$meta])*
-        $crate::func::args::impl_get_ownership!(($($name,)*); <$($name),*>);
+        $crate::func::args::impl_get_ownership!(($($name,)*); <$($name: TypePath),*>);
     };
 }
 
@@ -1,4 +1,5 @@
 use crate::{
+    attributes::{impl_custom_attribute_methods, EMPTY_CUSTOM_ATTRIBUTES},
     ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
     DynamicTupleStruct, EnumInfo, Generics, ListInfo, MapInfo, PartialReflect, Reflect,
     ReflectKind, SetInfo, StructInfo, TupleInfo, TupleStructInfo, TypePath, TypePathTable,
@@ -294,6 +295,18 @@ impl TypeInfo {
         }
     }
 
+    impl_custom_attribute_methods!(
+        self,
+        match self {
+            Self::Struct(info) => info.custom_attributes(),
+            Self::TupleStruct(info) => info.custom_attributes(),
+            Self::Enum(info) => info.custom_attributes(),
+            // Other kinds of types can't be given custom attributes
+            _ => &EMPTY_CUSTOM_ATTRIBUTES,
+        },
+        "type"
+    );
+
     impl_generic_info_methods!(self => {
         match self {
             Self::Struct(info) => info.generics(),
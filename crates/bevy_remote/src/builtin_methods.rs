@@ -3,24 +3,34 @@
 use core::any::TypeId;
 
 use anyhow::{anyhow, Result as AnyhowResult};
+#[cfg(feature = "bevy_asset")]
+use bevy_asset::{AssetServer, LoadState, ReflectAsset, UntypedAssetId};
+#[cfg(feature = "reflect_functions")]
+use bevy_ecs::reflect::AppFunctionRegistry;
 use bevy_ecs::{
     component::ComponentId,
     entity::Entity,
-    event::EventCursor,
-    query::QueryBuilder,
+    event::{EventCursor, Events},
+    query::{QueryBuilder, QueryState},
     reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
     removal_detection::RemovedComponentEntity,
-    system::{In, Local},
+    schedule::{NodeId, Schedules, Stepping},
+    system::{In, Local, Res, ResMut},
     world::{EntityRef, EntityWorldMut, FilteredEntityRef, World},
 };
 use bevy_hierarchy::BuildChildren as _;
+#[cfg(feature = "reflect_functions")]
+use bevy_reflect::func::{
+    args::{ArgInfo, Ownership},
+    DynamicFunction, FunctionRegistry, SignatureInfo,
+};
 use bevy_reflect::{
     prelude::ReflectDefault,
     serde::{ReflectSerializer, TypedReflectDeserializer},
-    NamedField, OpaqueInfo, PartialReflect, ReflectDeserialize, ReflectSerialize, TypeInfo,
-    TypeRegistration, TypeRegistry, VariantInfo,
+    GenericInfo, NamedField, OpaqueInfo, PartialReflect, ReflectDeserialize, ReflectSerialize,
+    TypeInfo, TypeRegistration, TypeRegistry, VariantInfo,
 };
-use bevy_utils::HashMap;
+use bevy_utils::{HashMap, HashSet};
 use serde::{de::DeserializeSeed as _, Deserialize, Serialize};
 use serde_json::{json, Map, Value};
 
@@ -56,9 +66,36 @@ pub const BRP_GET_AND_WATCH_METHOD: &str = "bevy/get+watch";
 /// The method path for a `bevy/list+watch` request.
 pub const BRP_LIST_AND_WATCH_METHOD: &str = "bevy/list+watch";
 
+/// The method path for a `bevy/query+watch` request.
+pub const BRP_QUERY_AND_WATCH_METHOD: &str = "bevy/query+watch";
+
 /// The method path for a `bevy/registry/schema` request.
 pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "bevy/registry/schema";
 
+/// The method path for a `bevy/registry/functions` request.
+#[cfg(feature = "reflect_functions")]
+pub const BRP_REGISTRY_FUNCTIONS_METHOD: &str = "bevy/registry/functions";
+
+/// The dialect of the documents returned by [`export_registry_json_schema`].
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// The method path for a `bevy/assets/list` request.
+#[cfg(feature = "bevy_asset")]
+pub const BRP_LIST_ASSETS_METHOD: &str = "bevy/assets/list";
+
+/// The method path for a `bevy/assets/reload` request.
+#[cfg(feature = "bevy_asset")]
+pub const BRP_RELOAD_ASSET_METHOD: &str = "bevy/assets/reload";
+
+/// The method path for a `bevy/schedules/list` request.
+pub const BRP_LIST_SCHEDULES_METHOD: &str = "bevy/schedules/list";
+
+/// The method path for a `bevy/stepping` request.
+pub const BRP_STEPPING_METHOD: &str = "bevy/stepping";
+
+/// The method path for a `bevy/stepping/breakpoint` request.
+pub const BRP_STEPPING_BREAKPOINT_METHOD: &str = "bevy/stepping/breakpoint";
+
 /// `bevy/get`: Retrieves one or more components from the entity with the given
 /// ID.
 ///
@@ -197,6 +234,72 @@ pub struct BrpListParams {
     pub entity: Entity,
 }
 
+/// `bevy/assets/list`: Returns the assets currently stored in the world, either of every
+/// reflected asset type (no params provided) or of a single type (params provided).
+///
+/// The server responds with a [`BrpListAssetsResponse`].
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpListAssetsParams {
+    /// The [full path] of the asset type to list.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    #[serde(rename = "type")]
+    pub type_path: String,
+}
+
+/// `bevy/assets/reload`: Reloads the asset at the given path, if it is currently loaded.
+///
+/// The server responds with a null.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpReloadAssetParams {
+    /// The path of the asset, as passed to `AssetServer::load`.
+    pub path: String,
+}
+
+/// `bevy/stepping`: Controls system stepping, see [`Stepping`].
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingParams {
+    /// What to do at the start of the next frame.
+    pub action: BrpSteppingAction,
+}
+
+/// An action performed by a `bevy/stepping` request.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrpSteppingAction {
+    /// Begin stepping, see [`Stepping::enable`].
+    Enable,
+    /// Resume normal system execution, see [`Stepping::disable`].
+    Disable,
+    /// Run the next system, see [`Stepping::step_frame`].
+    Step,
+    /// Run the remaining systems until the next breakpoint, see [`Stepping::continue_frame`].
+    Continue,
+}
+
+/// `bevy/stepping/breakpoint`: Sets or clears a stepping breakpoint on a system.
+///
+/// Setting a breakpoint also adds its schedule to those being stepped.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingBreakpointParams {
+    /// The label of the schedule containing the system, as listed by `bevy/schedules/list`.
+    pub schedule: String,
+
+    /// The name of the system, as listed by `bevy/schedules/list`.
+    ///
+    /// Every system of the schedule with this name is affected.
+    pub system: String,
+
+    /// Whether the breakpoint is set or cleared.
+    pub enabled: bool,
+}
+
 /// Describes the data that is to be fetched in a query.
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct BrpQuery {
@@ -333,9 +436,82 @@ pub struct BrpListWatchingResponse {
     removed: Vec<String>,
 }
 
+/// The response to a `bevy/assets/list` request.
+#[cfg(feature = "bevy_asset")]
+pub type BrpListAssetsResponse = Vec<BrpAssetInfo>;
+
+/// An asset listed by a `bevy/assets/list` request.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpAssetInfo {
+    /// The full path of the asset type.
+    #[serde(rename = "type")]
+    pub type_path: String,
+
+    /// The ID of the asset.
+    pub id: BrpAssetId,
+
+    /// The path the asset was loaded from, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub path: Option<String>,
+
+    /// The load state of the asset, if it is tracked by the asset server.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub load_state: Option<BrpLoadState>,
+}
+
+/// An asset ID, as listed by a `bevy/assets/list` request.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrpAssetId {
+    /// A runtime asset index, see [`AssetIndex::to_bits`](bevy_asset::AssetIndex::to_bits).
+    Index(u64),
+    /// A stable asset UUID.
+    Uuid(String),
+}
+
+/// The load state of an asset, as listed by a `bevy/assets/list` request.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrpLoadState {
+    /// The asset has not started loading yet.
+    NotLoaded,
+    /// The asset is being loaded.
+    Loading,
+    /// The asset has been loaded.
+    Loaded,
+    /// The asset failed to load, with the given error.
+    Failed(String),
+}
+
+/// The response to a `bevy/schedules/list` request.
+pub type BrpListSchedulesResponse = Vec<BrpScheduleInfo>;
+
+/// A schedule listed by a `bevy/schedules/list` request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpScheduleInfo {
+    /// The label of the schedule.
+    pub label: String,
+
+    /// The names of the systems in the schedule, in the order they were added.
+    pub systems: Vec<String>,
+}
+
 /// The response to a `bevy/query` request.
 pub type BrpQueryResponse = Vec<BrpQueryRow>;
 
+/// A single response from a `bevy/query+watch` request.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpQueryWatchingResponse {
+    /// The entities that started matching the query, or whose requested components were added,
+    /// changed or removed in the last tick.
+    pub changed: Vec<BrpQueryRow>,
+    /// The entities that stopped matching the query in the last tick.
+    pub removed: Vec<Entity>,
+}
+
 /// One query match result: a single entity paired with the requested components.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct BrpQueryRow {
@@ -560,98 +736,240 @@ fn reflect_component(
     Ok(serialized_object)
 }
 
-/// Handles a `bevy/query` request coming from a client.
-pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
-    let BrpQueryParams {
-        data: BrpQuery {
-            components,
-            option,
-            has,
-        },
-        filter: BrpQueryFilter { without, with },
-        strict,
-    } = parse_some(params)?;
-
-    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
-    let type_registry = app_type_registry.read();
-
-    let components = get_component_ids(&type_registry, world, components, strict)
-        .map_err(BrpError::component_error)?;
-    let option = get_component_ids(&type_registry, world, option, strict)
-        .map_err(BrpError::component_error)?;
-    let has =
-        get_component_ids(&type_registry, world, has, strict).map_err(BrpError::component_error)?;
-    let without = get_component_ids(&type_registry, world, without, strict)
-        .map_err(BrpError::component_error)?;
-    let with = get_component_ids(&type_registry, world, with, strict)
-        .map_err(BrpError::component_error)?;
+/// A `bevy/query` request resolved against the world and its type registry.
+struct PreparedQuery<'r> {
+    /// The query matching the requested entities.
+    query: QueryState<FilteredEntityRef<'static>>,
+    /// The IDs of the fetched components, optional or not.
+    fetched: Vec<ComponentId>,
+    /// The IDs of the components checked for presence.
+    has: Vec<ComponentId>,
+    /// The IDs of the components that must be present on matching entities.
+    with: Vec<ComponentId>,
+    /// The IDs of the components that must not be present on matching entities.
+    without: Vec<ComponentId>,
+    paths_and_reflect_components: Vec<(&'r str, &'r ReflectComponent)>,
+    has_paths_and_reflect_components: Vec<(&'r str, &'r ReflectComponent)>,
+}
 
-    let mut query = QueryBuilder::<FilteredEntityRef>::new(world);
-    for (_, component) in &components {
-        query.ref_id(*component);
-    }
-    for (_, option) in &option {
-        query.optional(|query| {
-            query.ref_id(*option);
-        });
-    }
-    for (_, has) in &has {
-        query.optional(|query| {
-            query.ref_id(*has);
-        });
-    }
-    for (_, without) in without {
-        query.without_id(without);
-    }
-    for (_, with) in with {
-        query.with_id(with);
+impl<'r> PreparedQuery<'r> {
+    fn new(
+        params: BrpQueryParams,
+        world: &mut World,
+        type_registry: &'r TypeRegistry,
+    ) -> BrpResult<Self> {
+        let BrpQueryParams {
+            data:
+                BrpQuery {
+                    components,
+                    option,
+                    has,
+                },
+            filter: BrpQueryFilter { without, with },
+            strict,
+        } = params;
+
+        let components = get_component_ids(type_registry, world, components, strict)
+            .map_err(BrpError::component_error)?;
+        let option = get_component_ids(type_registry, world, option, strict)
+            .map_err(BrpError::component_error)?;
+        let has = get_component_ids(type_registry, world, has, strict)
+            .map_err(BrpError::component_error)?;
+        let without = get_component_ids(type_registry, world, without, strict)
+            .map_err(BrpError::component_error)?;
+        let with = get_component_ids(type_registry, world, with, strict)
+            .map_err(BrpError::component_error)?;
+
+        let mut query = QueryBuilder::<FilteredEntityRef>::new(world);
+        for (_, component) in &components {
+            query.ref_id(*component);
+        }
+        for (_, option) in &option {
+            query.optional(|query| {
+                query.ref_id(*option);
+            });
+        }
+        for (_, has) in &has {
+            query.optional(|query| {
+                query.ref_id(*has);
+            });
+        }
+        for (_, without) in &without {
+            query.without_id(*without);
+        }
+        for (_, with) in &with {
+            query.with_id(*with);
+        }
+        let query = query.build();
+
+        // Required components must be present on matching entities, just like `with` components.
+        let with = components
+            .iter()
+            .chain(&with)
+            .map(|(_, component_id)| *component_id)
+            .collect();
+        let without = without
+            .into_iter()
+            .map(|(_, component_id)| component_id)
+            .collect();
+
+        // At this point, we can safely unify `components` and `option`, since we only retrieved
+        // entities that actually have all the `components` already.
+        let fetched: Vec<(TypeId, ComponentId)> = components.into_iter().chain(option).collect();
+
+        // We also will just collect the `ReflectComponent` values from the type registry all
+        // at once so that we can reuse them between components.
+        let paths_and_reflect_components: Vec<(&str, &ReflectComponent)> = fetched
+            .iter()
+            .map(|(type_id, _)| reflect_component_from_id(*type_id, type_registry))
+            .collect::<AnyhowResult<Vec<(&str, &ReflectComponent)>>>()
+            .map_err(BrpError::component_error)?;
+
+        // ... and the analogous construction for `has`:
+        let has_paths_and_reflect_components: Vec<(&str, &ReflectComponent)> = has
+            .iter()
+            .map(|(type_id, _)| reflect_component_from_id(*type_id, type_registry))
+            .collect::<AnyhowResult<Vec<(&str, &ReflectComponent)>>>()
+            .map_err(BrpError::component_error)?;
+
+        Ok(Self {
+            query,
+            fetched: fetched.into_iter().map(|(_, id)| id).collect(),
+            has: has.into_iter().map(|(_, id)| id).collect(),
+            with,
+            without,
+            paths_and_reflect_components,
+            has_paths_and_reflect_components,
+        })
     }
 
-    // At this point, we can safely unify `components` and `option`, since we only retrieved
-    // entities that actually have all the `components` already.
-    //
-    // We also will just collect the `ReflectComponent` values from the type registry all
-    // at once so that we can reuse them between components.
-    let paths_and_reflect_components: Vec<(&str, &ReflectComponent)> = components
-        .into_iter()
-        .chain(option)
-        .map(|(type_id, _)| reflect_component_from_id(type_id, &type_registry))
-        .collect::<AnyhowResult<Vec<(&str, &ReflectComponent)>>>()
-        .map_err(BrpError::component_error)?;
-
-    // ... and the analogous construction for `has`:
-    let has_paths_and_reflect_components: Vec<(&str, &ReflectComponent)> = has
-        .into_iter()
-        .map(|(type_id, _)| reflect_component_from_id(type_id, &type_registry))
-        .collect::<AnyhowResult<Vec<(&str, &ReflectComponent)>>>()
-        .map_err(BrpError::component_error)?;
-
-    let mut response = BrpQueryResponse::default();
-    let mut query = query.build();
-    for row in query.iter(world) {
+    /// Builds the response row of an entity matching the query.
+    fn row(&self, row: FilteredEntityRef, type_registry: &TypeRegistry) -> BrpResult<BrpQueryRow> {
         // The map of component values:
         let components_map = build_components_map(
             row.clone(),
-            paths_and_reflect_components.iter().copied(),
-            &type_registry,
+            self.paths_and_reflect_components.iter().copied(),
+            type_registry,
         )
         .map_err(BrpError::component_error)?;
 
         // The map of boolean-valued component presences:
         let has_map = build_has_map(
             row.clone(),
-            has_paths_and_reflect_components.iter().copied(),
+            self.has_paths_and_reflect_components.iter().copied(),
         );
-        response.push(BrpQueryRow {
+
+        Ok(BrpQueryRow {
             entity: row.id(),
             components: components_map,
             has: has_map,
-        });
+        })
+    }
+}
+
+/// Handles a `bevy/query` request coming from a client.
+pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let params = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let prepared = PreparedQuery::new(params, world, &type_registry)?;
+
+    let mut response = BrpQueryResponse::default();
+    for row in prepared.query.iter_manual(world) {
+        response.push(prepared.row(row, &type_registry)?);
     }
 
     serde_json::to_value(response).map_err(BrpError::internal)
 }
 
+/// Handles a `bevy/query+watch` request coming from a client.
+pub fn process_remote_query_watching_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult<Option<Value>> {
+    let params = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let prepared = PreparedQuery::new(params, world, &type_registry)?;
+
+    // Entities gaining a `without` component stop matching, so look for those that otherwise match.
+    let without_queries: Vec<(ComponentId, QueryState<FilteredEntityRef>)> = prepared
+        .without
+        .iter()
+        .map(|&without| {
+            let mut query = QueryBuilder::<FilteredEntityRef>::new(world);
+            query.ref_id(without);
+            for &with in &prepared.with {
+                query.with_id(with);
+            }
+            (without, query.build())
+        })
+        .collect();
+
+    let world = &*world;
+    let (last_run, this_run) = (world.last_change_tick(), world.read_change_tick());
+
+    // Only the removals of the current update are read, so that every watcher sees them
+    // regardless of how many requests are being watched.
+    let removed_this_tick = |component_ids: &[ComponentId]| {
+        component_ids
+            .iter()
+            .filter_map(|component_id| world.removed_components().get(*component_id))
+            .flat_map(Events::iter_current_update_events)
+            .map(|event| Entity::from(event.clone()))
+            .collect::<HashSet<_>>()
+    };
+    // Losing a fetched component or gaining a `without` one changes a row.
+    let mut changed_entities = removed_this_tick(&prepared.fetched);
+    changed_entities.extend(removed_this_tick(&prepared.has));
+    changed_entities.extend(removed_this_tick(&prepared.without));
+    // Losing a required component makes an entity stop matching.
+    let mut removed_entities = removed_this_tick(&prepared.with);
+
+    let mut response = BrpQueryWatchingResponse::default();
+    for row in prepared.query.iter_manual(world) {
+        let entity = row.id();
+        removed_entities.remove(&entity);
+
+        let changed = changed_entities.contains(&entity)
+            || prepared
+                .fetched
+                .iter()
+                .chain(&prepared.has)
+                .filter_map(|component_id| row.get_change_ticks_by_id(*component_id))
+                .any(|ticks| ticks.is_changed(last_run, this_run));
+        if changed {
+            response.changed.push(prepared.row(row, &type_registry)?);
+        }
+    }
+
+    for (without, query) in &without_queries {
+        for row in query.iter_manual(world) {
+            let just_added = row
+                .get_change_ticks_by_id(*without)
+                .is_some_and(|ticks| ticks.is_added(last_run, this_run));
+            if just_added {
+                removed_entities.insert(row.id());
+            }
+        }
+    }
+
+    if response.changed.is_empty() && removed_entities.is_empty() {
+        return Ok(None);
+    }
+
+    response.removed = removed_entities.into_iter().collect();
+    response.removed.sort();
+
+    Ok(Some(
+        serde_json::to_value(response).map_err(BrpError::internal)?,
+    ))
+}
+
 /// Handles a `bevy/spawn` request coming from a client.
 pub fn process_remote_spawn_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
     let BrpSpawnParams { components } = parse_some(params)?;
@@ -838,6 +1156,183 @@ pub fn process_remote_list_watching_request(
     }
 }
 
+/// Handles a `bevy/assets/list` request coming from a client.
+#[cfg(feature = "bevy_asset")]
+pub fn process_remote_list_assets_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let type_path = params
+        .map(parse::<BrpListAssetsParams>)
+        .transpose()?
+        .map(|params| params.type_path);
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+    let asset_server = world.get_resource::<AssetServer>();
+
+    let asset_types: Vec<(&str, &ReflectAsset)> = match &type_path {
+        Some(type_path) => {
+            let registration = type_registry.get_with_type_path(type_path).ok_or_else(|| {
+                BrpError::asset_error(format!("Unknown asset type: `{type_path}`"))
+            })?;
+            let reflect_asset = registration.data::<ReflectAsset>().ok_or_else(|| {
+                BrpError::asset_error(format!("Asset `{type_path}` isn't reflectable"))
+            })?;
+            vec![(registration.type_info().type_path(), reflect_asset)]
+        }
+        None => type_registry
+            .iter_with_data::<ReflectAsset>()
+            .map(|(registration, reflect_asset)| {
+                (registration.type_info().type_path(), reflect_asset)
+            })
+            .collect(),
+    };
+
+    let mut response = BrpListAssetsResponse::default();
+    for (type_path, reflect_asset) in asset_types {
+        // Asset types can be registered without their `Assets` collection being initialized.
+        let initialized = world
+            .components()
+            .get_resource_id(reflect_asset.assets_resource_type_id())
+            .is_some_and(|component_id| world.contains_resource_by_id(component_id));
+        if !initialized {
+            continue;
+        }
+
+        for id in reflect_asset.ids(world) {
+            response.push(BrpAssetInfo {
+                type_path: type_path.to_owned(),
+                id: match id {
+                    UntypedAssetId::Index { index, .. } => BrpAssetId::Index(index.to_bits()),
+                    UntypedAssetId::Uuid { uuid, .. } => BrpAssetId::Uuid(uuid.to_string()),
+                },
+                path: asset_server
+                    .and_then(|asset_server| asset_server.get_path(id))
+                    .map(|path| path.to_string()),
+                load_state: asset_server
+                    .and_then(|asset_server| asset_server.get_load_state(id))
+                    .map(|load_state| match load_state {
+                        LoadState::NotLoaded => BrpLoadState::NotLoaded,
+                        LoadState::Loading => BrpLoadState::Loading,
+                        LoadState::Loaded => BrpLoadState::Loaded,
+                        LoadState::Failed(error) => BrpLoadState::Failed(error.to_string()),
+                    }),
+            });
+        }
+    }
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/assets/reload` request coming from a client.
+#[cfg(feature = "bevy_asset")]
+pub fn process_remote_reload_asset_request(
+    In(params): In<Option<Value>>,
+    asset_server: Option<Res<AssetServer>>,
+) -> BrpResult {
+    let BrpReloadAssetParams { path } = parse_some(params)?;
+    let asset_server =
+        asset_server.ok_or_else(|| BrpError::resource_not_present("bevy_asset::AssetServer"))?;
+
+    if asset_server.get_handle_untyped(&path).is_none() {
+        return Err(BrpError::asset_error(format!(
+            "Asset `{path}` isn't loaded"
+        )));
+    }
+    asset_server.reload(path);
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/schedules/list` request coming from a client.
+///
+/// Schedules that are currently running, such as `Main`, aren't listed.
+pub fn process_remote_list_schedules_request(
+    In(_params): In<Option<Value>>,
+    schedules: Res<Schedules>,
+) -> BrpResult {
+    let mut response: BrpListSchedulesResponse = schedules
+        .iter()
+        .map(|(label, schedule)| BrpScheduleInfo {
+            label: format!("{label:?}"),
+            systems: schedule
+                .graph()
+                .systems()
+                .map(|(_, system, _)| system.name().into_owned())
+                .collect(),
+        })
+        .collect();
+
+    // Schedules are stored in a hash map, so sort them to keep the response stable.
+    response.sort_by(|a, b| a.label.cmp(&b.label));
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/stepping` request coming from a client.
+pub fn process_remote_stepping_request(
+    In(params): In<Option<Value>>,
+    stepping: Option<ResMut<Stepping>>,
+) -> BrpResult {
+    let BrpSteppingParams { action } = parse_some(params)?;
+    let mut stepping =
+        stepping.ok_or_else(|| BrpError::resource_not_present("bevy_ecs::schedule::Stepping"))?;
+
+    match action {
+        BrpSteppingAction::Enable => stepping.enable(),
+        BrpSteppingAction::Disable => stepping.disable(),
+        BrpSteppingAction::Step => stepping.step_frame(),
+        BrpSteppingAction::Continue => stepping.continue_frame(),
+    };
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/breakpoint` request coming from a client.
+pub fn process_remote_stepping_breakpoint_request(
+    In(params): In<Option<Value>>,
+    schedules: Res<Schedules>,
+    stepping: Option<ResMut<Stepping>>,
+) -> BrpResult {
+    let BrpSteppingBreakpointParams {
+        schedule: label,
+        system: name,
+        enabled,
+    } = parse_some(params)?;
+    let mut stepping =
+        stepping.ok_or_else(|| BrpError::resource_not_present("bevy_ecs::schedule::Stepping"))?;
+
+    let schedule = schedules
+        .iter()
+        .find(|(schedule_label, _)| format!("{schedule_label:?}") == label)
+        .map(|(_, schedule)| schedule)
+        .ok_or_else(|| BrpError::schedule_not_found(&label))?;
+    let nodes: Vec<NodeId> = schedule
+        .graph()
+        .systems()
+        .filter(|(_, system, _)| system.name() == name)
+        .map(|(node, _, _)| node)
+        .collect();
+    if nodes.is_empty() {
+        return Err(BrpError::system_not_found(&name, &label));
+    }
+
+    let schedule_label = schedule.label();
+    if enabled {
+        stepping.add_schedule(schedule_label);
+    }
+    for node in nodes {
+        if enabled {
+            stepping.set_breakpoint_node(schedule_label, node);
+        } else {
+            stepping.clear_breakpoint_node(schedule_label, node);
+        }
+    }
+
+    Ok(Value::Null)
+}
+
 /// Handles a `bevy/registry/schema` request (list all registry types in form of schema) coming from a client.
 pub fn export_registry_types(In(params): In<Option<Value>>, world: &World) -> BrpResult {
     let filter: BrpJsonSchemaQueryFilter = match params {
@@ -846,8 +1341,35 @@ pub fn export_registry_types(In(params): In<Option<Value>>, world: &World) -> Br
     };
 
     let types = world.resource::<AppTypeRegistry>();
-    let types = types.read();
-    let schemas = types
+    let schemas = export_registry_schema(&types.read(), &filter);
+
+    serde_json::to_value(schemas).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/registry/functions` request (list all functions in the [`AppFunctionRegistry`]) coming from a client.
+///
+/// Responds with an empty object if no [`AppFunctionRegistry`] exists in the world.
+#[cfg(feature = "reflect_functions")]
+pub fn export_registry_functions(In(_params): In<Option<Value>>, world: &World) -> BrpResult {
+    let schemas = world
+        .get_resource::<AppFunctionRegistry>()
+        .map(|functions| export_function_schemas(&functions.read()))
+        .unwrap_or_default();
+
+    serde_json::to_value(schemas).map_err(BrpError::internal)
+}
+
+/// Exports the schemas of the types in the given registry that pass the given filter,
+/// keyed by their type paths.
+///
+/// Types are referred to with `$ref`s pointing to `#/$defs/{type_path}`,
+/// so the schemas can be used as the `$defs` of a JSON Schema document.
+/// See [`export_registry_json_schema`] to export a complete document instead.
+pub fn export_registry_schema(
+    registry: &TypeRegistry,
+    filter: &BrpJsonSchemaQueryFilter,
+) -> HashMap<String, JsonSchemaBevyType> {
+    registry
         .iter()
         .map(export_type)
         .filter(|(_, schema)| {
@@ -884,13 +1406,86 @@ pub fn export_registry_types(In(params): In<Option<Value>>, world: &World) -> Br
 
             true
         })
-        .collect::<HashMap<String, JsonSchemaBevyType>>();
+        .collect()
+}
 
-    serde_json::to_value(schemas).map_err(BrpError::internal)
+/// Exports the types in the given registry that pass the given filter as a JSON Schema document.
+///
+/// This can be written to a file to provide autocompletion and validation of Bevy types
+/// in external editors and tools.
+/// The schema of each type is stored in the `$defs` of the document, keyed by its type path.
+pub fn export_registry_json_schema(
+    registry: &TypeRegistry,
+    filter: &BrpJsonSchemaQueryFilter,
+) -> Value {
+    json!({
+        "$schema": JSON_SCHEMA_DIALECT,
+        "$defs": export_registry_schema(registry, filter),
+    })
+}
+
+/// Exports the signatures of the functions in the given registry, keyed by their names.
+///
+/// Argument and return types are referred to with `$ref`s pointing to `#/$defs/{type_path}`,
+/// matching the schemas returned by [`export_registry_schema`].
+#[cfg(feature = "reflect_functions")]
+pub fn export_function_schemas(
+    registry: &FunctionRegistry,
+) -> HashMap<String, JsonSchemaBevyFunction> {
+    registry
+        .iter()
+        .filter_map(|function| {
+            let name = function.name()?.to_string();
+            Some((name.clone(), export_function(name, function)))
+        })
+        .collect()
+}
+
+#[cfg(feature = "reflect_functions")]
+fn export_function(name: String, function: &DynamicFunction) -> JsonSchemaBevyFunction {
+    JsonSchemaBevyFunction {
+        name,
+        signatures: function
+            .info()
+            .signatures()
+            .iter()
+            .map(export_signature)
+            .collect(),
+    }
+}
+
+#[cfg(feature = "reflect_functions")]
+fn export_signature(info: &SignatureInfo) -> JsonSchemaBevySignature {
+    JsonSchemaBevySignature {
+        args: info.args().iter().map(export_arg).collect(),
+        r#return: JsonSchemaBevyArg {
+            index: None,
+            name: None,
+            type_path: info.return_info().type_path().to_owned(),
+            ref_type: (info.return_info().ownership() == Ownership::Owned)
+                .then(|| json!({ "$ref": format!("#/$defs/{}", info.return_info().type_path()) })),
+            ownership: info.return_info().ownership().into(),
+            has_default: false,
+            variadic: false,
+        },
+    }
+}
+
+#[cfg(feature = "reflect_functions")]
+fn export_arg(info: &ArgInfo) -> JsonSchemaBevyArg {
+    JsonSchemaBevyArg {
+        index: Some(info.index()),
+        name: info.name().map(str::to_owned),
+        type_path: info.type_path().to_owned(),
+        ref_type: Some(json!({ "$ref": format!("#/$defs/{}", info.value_type().path()) })),
+        ownership: info.ownership().into(),
+        has_default: info.default_value().is_some(),
+        variadic: info.is_variadic(),
+    }
 }
 
 /// Exports schema info for a given type
-fn export_type(reg: &TypeRegistration) -> (String, JsonSchemaBevyType) {
+pub fn export_type(reg: &TypeRegistration) -> (String, JsonSchemaBevyType) {
     let t = reg.type_info();
     let binding = t.type_path_table();
 
@@ -902,6 +1497,7 @@ fn export_type(reg: &TypeRegistration) -> (String, JsonSchemaBevyType) {
         type_path: type_path.to_owned(),
         crate_name: binding.crate_name().map(str::to_owned),
         module_path: binding.module_path().map(str::to_owned),
+        generics: t.generics().iter().map(export_generic).collect(),
         ..Default::default()
     };
     match t {
@@ -1022,6 +1618,23 @@ fn export_type(reg: &TypeRegistration) -> (String, JsonSchemaBevyType) {
     (t.type_path().to_owned(), typed_schema)
 }
 
+fn export_generic(info: &GenericInfo) -> JsonSchemaGeneric {
+    let (kind, default_type_path) = match info {
+        GenericInfo::Type(info) => (
+            GenericKind::Type,
+            info.default().map(|ty| ty.path().to_owned()),
+        ),
+        GenericInfo::Const(_) => (GenericKind::Const, None),
+    };
+
+    JsonSchemaGeneric {
+        name: info.name().to_string(),
+        kind,
+        type_path: info.type_path().to_owned(),
+        default_type_path,
+    }
+}
+
 fn get_registrered_reflect_types(reg: &TypeRegistration) -> Vec<String> {
     // Vec could be moved to allow registering more types by game maker.
     let registered_reflect_types: [(TypeId, &str); 5] = [
@@ -1060,6 +1673,9 @@ pub struct JsonSchemaBevyType {
     /// Bevy specific field, names of the types that type reflects.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub reflect_types: Vec<String>,
+    /// Bevy specific field, the generic parameters of the type and the types they're instantiated with.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub generics: Vec<JsonSchemaGeneric>,
     /// Bevy specific field, [`TypeInfo`] type mapping.
     pub kind: SchemaKind,
     /// Bevy specific field, provided when [`SchemaKind`] `kind` field is equal to [`SchemaKind::Map`].
@@ -1113,6 +1729,107 @@ pub struct JsonSchemaBevyType {
     pub items: Option<Value>,
 }
 
+/// A generic parameter of a type exported in a [`JsonSchemaBevyType`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSchemaGeneric {
+    /// The name of the parameter, e.g. `T`.
+    pub name: String,
+    /// Whether this is a type or const parameter.
+    pub kind: GenericKind,
+    /// The full path of the type the parameter is instantiated with.
+    ///
+    /// For const parameters, this is the type of the constant.
+    pub type_path: String,
+    /// The full path of the default type of a type parameter, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub default_type_path: Option<String>,
+}
+
+/// Kind of a [`JsonSchemaGeneric`] parameter.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum GenericKind {
+    /// A type parameter, e.g. `T` in `Foo<T>`.
+    Type,
+    /// A const parameter, e.g. `N` in `Foo<const N: usize>`.
+    Const,
+}
+
+/// Exported schema of a function registered in the [`AppFunctionRegistry`].
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSchemaBevyFunction {
+    /// The name the function is registered with.
+    pub name: String,
+    /// The signatures of the function.
+    ///
+    /// Overloaded functions have more than one signature.
+    pub signatures: Vec<JsonSchemaBevySignature>,
+}
+
+/// A signature of a [`JsonSchemaBevyFunction`].
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSchemaBevySignature {
+    /// The arguments of the signature, in order.
+    pub args: Vec<JsonSchemaBevyArg>,
+    /// The return value of the signature.
+    pub r#return: JsonSchemaBevyArg,
+}
+
+/// An argument or return value of a [`JsonSchemaBevySignature`].
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSchemaBevyArg {
+    /// The index of the argument, or `None` for return values.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub index: Option<usize>,
+    /// The name of the argument, if known.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub name: Option<String>,
+    /// The full path of the type of the value, including any reference.
+    pub type_path: String,
+    /// Reference to the schema of the type of the value, without any reference.
+    ///
+    /// This is omitted for return values that are references.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none", default)]
+    pub ref_type: Option<Value>,
+    /// How the value is passed.
+    pub ownership: ArgOwnership,
+    /// Whether the argument may be omitted in favor of a default value.
+    #[serde(skip_serializing_if = "core::ops::Not::not", default)]
+    pub has_default: bool,
+    /// Whether any number of trailing arguments of this type are accepted.
+    #[serde(skip_serializing_if = "core::ops::Not::not", default)]
+    pub variadic: bool,
+}
+
+/// How a [`JsonSchemaBevyArg`] is passed, maps the reflected [`Ownership`].
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ArgOwnership {
+    /// The value is passed as `&T`.
+    Ref,
+    /// The value is passed as `&mut T`.
+    Mut,
+    /// The value is passed as `T`.
+    Owned,
+}
+
+#[cfg(feature = "reflect_functions")]
+impl From<Ownership> for ArgOwnership {
+    fn from(value: Ownership) -> Self {
+        match value {
+            Ownership::Ref => Self::Ref,
+            Ownership::Mut => Self::Mut,
+            Ownership::Owned => Self::Owned,
+        }
+    }
+}
+
 /// Kind of json schema, maps [`TypeInfo`] type
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub enum SchemaKind {
@@ -1424,7 +2141,7 @@ mod tests {
     }
     use super::*;
     use bevy_ecs::{component::Component, system::Resource};
-    use bevy_reflect::Reflect;
+    use bevy_reflect::{Reflect, TypePath};
 
     #[test]
     fn serialization_tests() {
@@ -1434,6 +2151,14 @@ mod tests {
             has: Default::default(),
         });
         test_serialize_deserialize(BrpListWatchingResponse::default());
+        test_serialize_deserialize(BrpQueryWatchingResponse {
+            changed: vec![BrpQueryRow {
+                components: Default::default(),
+                entity: Entity::from_raw(0),
+                has: Default::default(),
+            }],
+            removed: vec![Entity::from_raw(1)],
+        });
         test_serialize_deserialize(BrpQuery::default());
         test_serialize_deserialize(BrpJsonSchemaQueryFilter::default());
         test_serialize_deserialize(BrpJsonSchemaQueryFilter {
@@ -1636,4 +2361,180 @@ mod tests {
         });
         assert_eq!(schema_as_value, value);
     }
+
+    #[test]
+    fn reflect_export_generics() {
+        #[derive(Reflect)]
+        struct Buffer<T, const N: usize> {
+            items: [T; N],
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Buffer<f32, 4>>();
+        registry.register::<Buffer<u8, 2>>();
+
+        let schemas = export_registry_schema(&registry, &BrpJsonSchemaQueryFilter::default());
+        let schema = &schemas[&Buffer::<f32, 4>::type_path().to_owned()];
+        assert_eq!(
+            schema.generics,
+            vec![
+                JsonSchemaGeneric {
+                    name: "T".to_owned(),
+                    kind: GenericKind::Type,
+                    type_path: "f32".to_owned(),
+                    default_type_path: None,
+                },
+                JsonSchemaGeneric {
+                    name: "N".to_owned(),
+                    kind: GenericKind::Const,
+                    type_path: "usize".to_owned(),
+                    default_type_path: None,
+                },
+            ]
+        );
+        assert_eq!(
+            schemas[&Buffer::<u8, 2>::type_path().to_owned()].generics[0].type_path,
+            "u8"
+        );
+
+        let document = export_registry_json_schema(&registry, &BrpJsonSchemaQueryFilter::default());
+        assert_eq!(document["$schema"], JSON_SCHEMA_DIALECT);
+        assert_eq!(
+            document["$defs"][Buffer::<f32, 4>::type_path()]["generics"][1]["kind"],
+            "Const"
+        );
+        assert!(document["$defs"]["f32"].is_object());
+    }
+
+    #[cfg(feature = "reflect_functions")]
+    #[test]
+    fn reflect_export_functions() {
+        fn scale(value: &mut f32, factor: f32) -> bool {
+            *value *= factor;
+            true
+        }
+
+        let mut registry = FunctionRegistry::default();
+        registry.register_with_name("scale", scale).unwrap();
+
+        let schemas = serde_json::to_value(export_function_schemas(&registry)).unwrap();
+        assert_eq!(
+            schemas,
+            json!({
+                "scale": {
+                    "name": "scale",
+                    "signatures": [{
+                        "args": [
+                            {
+                                "index": 0,
+                                "typePath": "&mut f32",
+                                "type": { "$ref": "#/$defs/f32" },
+                                "ownership": "Mut",
+                            },
+                            {
+                                "index": 1,
+                                "typePath": "f32",
+                                "type": { "$ref": "#/$defs/f32" },
+                                "ownership": "Owned",
+                            },
+                        ],
+                        "return": {
+                            "typePath": "bool",
+                            "type": { "$ref": "#/$defs/bool" },
+                            "ownership": "Owned",
+                        },
+                    }],
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn query_watching_reports_changes() {
+        #[derive(Reflect, Component, Default, Deserialize, Serialize)]
+        #[reflect(Component, Serialize, Deserialize)]
+        struct Health(u32);
+
+        let mut world = World::new();
+        let atr = AppTypeRegistry::default();
+        atr.write().register::<Health>();
+        world.insert_resource(atr);
+        world.register_component::<Health>();
+
+        let params = json!({
+            "data": {
+                "components": [Health::type_path()],
+            },
+        });
+        let watch = |world: &mut World| {
+            let response = process_remote_query_watching_request(In(Some(params.clone())), world)
+                .expect("query should be valid")
+                .map(|value| serde_json::from_value::<BrpQueryWatchingResponse>(value).unwrap());
+            world.clear_trackers();
+            response
+        };
+
+        let entity = world.spawn(Health(10)).id();
+        let response = watch(&mut world).expect("spawned entity should be reported");
+        assert_eq!(response.changed.len(), 1);
+        assert_eq!(response.changed[0].entity, entity);
+        assert!(response.removed.is_empty());
+
+        assert!(watch(&mut world).is_none());
+
+        world.get_mut::<Health>(entity).unwrap().0 = 5;
+        let response = watch(&mut world).expect("changed component should be reported");
+        assert_eq!(response.changed[0].components.len(), 1);
+
+        world.entity_mut(entity).remove::<Health>();
+        let response = watch(&mut world).expect("removed entity should be reported");
+        assert!(response.changed.is_empty());
+        assert_eq!(response.removed, vec![entity]);
+    }
+
+    #[test]
+    fn stepping_breakpoints_target_listed_systems() {
+        use bevy_ecs::{
+            schedule::{Schedule, ScheduleLabel},
+            system::RunSystemOnce,
+        };
+
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct TestSchedule;
+
+        fn test_system() {}
+
+        let mut world = World::new();
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_systems(test_system);
+        world.add_schedule(schedule);
+        world.init_resource::<Stepping>();
+
+        let response = world
+            .run_system_once_with(process_remote_list_schedules_request, None)
+            .unwrap()
+            .unwrap();
+        let response: BrpListSchedulesResponse = serde_json::from_value(response).unwrap();
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].label, "TestSchedule");
+        assert_eq!(response[0].systems.len(), 1);
+
+        let mut set_breakpoint = |system: &str| {
+            world
+                .run_system_once_with(
+                    process_remote_stepping_breakpoint_request,
+                    Some(json!({
+                        "schedule": "TestSchedule",
+                        "system": system,
+                        "enabled": true,
+                    })),
+                )
+                .unwrap()
+        };
+        assert!(set_breakpoint(&response[0].systems[0]).is_ok());
+        assert_eq!(
+            set_breakpoint("missing_system").unwrap_err().code,
+            error_codes::SYSTEM_NOT_FOUND
+        );
+    }
 }
@@ -9,7 +9,9 @@
 #![cfg(not(target_family = "wasm"))]
 
 use crate::{
-    error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult, BrpSender,
+    error_codes,
+    transport::{self, BrpReply},
+    BrpBatch, BrpError, BrpMessage, BrpResponse, BrpResult, BrpSender,
 };
 use anyhow::Result as AnyhowResult;
 use async_channel::{Receiver, Sender};
@@ -289,14 +291,7 @@ async fn process_request_batch(
                 match response {
                     BrpHttpResponse::Complete(res) => responses.push(res),
                     BrpHttpResponse::Stream(BrpStream { id, .. }) => {
-                        responses.push(BrpResponse::new(
-                            id,
-                            Err(BrpError {
-                                code: error_codes::INVALID_REQUEST,
-                                message: "Streaming can not be used in batch requests".to_string(),
-                                data: None,
-                            }),
-                        ));
+                        responses.push(transport::batch_streaming_error(id));
                     }
                 }
             }
@@ -349,57 +344,13 @@ async fn process_single_request(
     request: Value,
     request_sender: &Sender<BrpMessage>,
 ) -> AnyhowResult<BrpHttpResponse<BrpResponse, BrpStream>> {
-    // Reach in and get the request ID early so that we can report it even when parsing fails.
-    let id = request.as_object().and_then(|map| map.get("id")).cloned();
-
-    let request: BrpRequest = match serde_json::from_value(request) {
-        Ok(v) => v,
-        Err(err) => {
-            return Ok(BrpHttpResponse::Complete(BrpResponse::new(
-                id,
-                Err(BrpError {
-                    code: error_codes::INVALID_REQUEST,
-                    message: err.to_string(),
-                    data: None,
-                }),
-            )));
-        }
-    };
-
-    if request.jsonrpc != "2.0" {
-        return Ok(BrpHttpResponse::Complete(BrpResponse::new(
+    Ok(match transport::dispatch(request, request_sender).await? {
+        BrpReply::Complete(response) => BrpHttpResponse::Complete(response),
+        BrpReply::Stream { id, receiver } => BrpHttpResponse::Stream(BrpStream {
             id,
-            Err(BrpError {
-                code: error_codes::INVALID_REQUEST,
-                message: String::from("JSON-RPC request requires `\"jsonrpc\": \"2.0\"`"),
-                data: None,
-            }),
-        )));
-    }
-
-    let watch = request.method.contains("+watch");
-    let size = if watch { 8 } else { 1 };
-    let (result_sender, result_receiver) = async_channel::bounded(size);
-
-    let _ = request_sender
-        .send(BrpMessage {
-            method: request.method,
-            params: request.params,
-            sender: result_sender,
-        })
-        .await;
-
-    if watch {
-        Ok(BrpHttpResponse::Stream(BrpStream {
-            id: request.id,
-            rx: Box::pin(result_receiver),
-        }))
-    } else {
-        let result = result_receiver.recv().await?;
-        Ok(BrpHttpResponse::Complete(BrpResponse::new(
-            request.id, result,
-        )))
-    }
+            rx: Box::pin(receiver),
+        }),
+    })
 }
 
 struct BrpStream {
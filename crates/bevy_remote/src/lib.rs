@@ -6,6 +6,14 @@
 //! over HTTP. These *remote clients* can inspect and alter the state of the
 //! entity-component system.
 //!
+//! Other transports are available behind cargo features, and all share the methods registered
+//! in the [`RemotePlugin`]:
+//! - `websocket`: The `RemoteWebSocketPlugin` accepts WebSocket connections, which can be used
+//!   from the web pages of allowed origins and multiplex several watching requests over a single
+//!   connection.
+//! - `unix_socket`: The `RemoteUnixSocketPlugin` accepts connections on a Unix domain socket
+//!   exchanging one JSON message per line, for local tools on Unix platforms.
+//!
 //! The Bevy Remote Protocol is based on the JSON-RPC 2.0 protocol.
 //!
 //! ## Request objects
@@ -261,6 +269,113 @@
 //! - `removed`: An array of fully-qualified type names of components removed from the entity
 //!   in the last tick.
 //!
+//! ### bevy/query+watch
+//!
+//! Watch the entities matching a query.
+//!
+//! Only changes are reported, so clients should send a `bevy/query` request with the same
+//! `params` to get the initial state of the query.
+//!
+//! `params`: The same as `bevy/query`.
+//!
+//! `result`:
+//! - `changed`: An array of objects, one per entity that started matching the query or whose
+//!   requested components were added, changed or removed in the last tick, in the same format as
+//!   the `bevy/query` result.
+//! - `removed`: An array of the IDs of entities that stopped matching the query in the last tick,
+//!   including despawned entities.
+//!
+//! ### bevy/schedules/list
+//!
+//! List the schedules of the app and their systems.
+//!
+//! Schedules that are currently running, such as `Main`, aren't listed.
+//!
+//! `params`: None.
+//!
+//! `result`: An array of objects, one per schedule, each with the following fields:
+//! - `label`: The label of the schedule, e.g. `Update`.
+//! - `systems`: An array of the names of the systems in the schedule.
+//!
+//! ### bevy/stepping
+//!
+//! Control [system stepping], taking effect at the start of the next frame. This requires the
+//! [`Stepping`] resource to be present in the world.
+//!
+//! `params`:
+//! - `action`: One of `"enable"`, `"disable"`, `"step"` (run the next system) or `"continue"`
+//!   (run systems until the next breakpoint).
+//!
+//! `result`: null.
+//!
+//! ### bevy/stepping/breakpoint
+//!
+//! Set or clear a stepping breakpoint on a system. This requires the [`Stepping`] resource to be
+//! present in the world.
+//!
+//! `params`:
+//! - `schedule`: The label of the schedule containing the system, as listed by
+//!   `bevy/schedules/list`. Setting a breakpoint also adds the schedule to those being stepped.
+//! - `system`: The name of the system, as listed by `bevy/schedules/list`.
+//! - `enabled`: Whether the breakpoint is set or cleared.
+//!
+//! `result`: null.
+//!
+//! ### bevy/assets/list
+//!
+//! List the assets stored in the world. This requires the `bevy_asset` feature.
+//!
+//! When `params` is not provided, this lists the assets of every reflected asset type. If
+//! `params` is provided, this lists only the assets of the given type.
+//!
+//! `params`:
+//! - `type`: The [fully-qualified type name] of the asset type to list.
+//!
+//! `result`: An array of objects, one per asset, each with the following fields:
+//! - `type`: The [fully-qualified type name] of the asset type.
+//! - `id`: The ID of the asset, either `{ "index": <number> }` or `{ "uuid": <string> }`.
+//! - `path` (optional): The path the asset was loaded from.
+//! - `load_state` (optional): One of `"not_loaded"`, `"loading"`, `"loaded"` or
+//!   `{ "failed": <error message> }`, for assets tracked by the asset server.
+//!
+//! ### bevy/assets/reload
+//!
+//! Reload the asset at the given path, if it is currently loaded. This requires the `bevy_asset`
+//! feature.
+//!
+//! `params`:
+//! - `path`: The path of the asset, as passed to `AssetServer::load`.
+//!
+//! `result`: null.
+//!
+//! ### bevy/registry/schema
+//!
+//! Export the schemas of the reflected types in the type registry, following the [JSON Schema]
+//! format with extra Bevy specific fields, such as the generic parameters of each type.
+//!
+//! `params` (optional):
+//! - `with_crates`: Only export types from the given crates.
+//! - `without_crates`: Don't export types from the given crates.
+//! - `type_limit`: Only export types registering all of the reflect types in `with`, and none of
+//!   the reflect types in `without`, e.g. `{ "with": ["Component"] }`.
+//!
+//! `result`: An object mapping the [fully-qualified type name] of each type to its schema. Types
+//! refer to each other with `$ref`s of the form `#/$defs/<type name>`.
+//!
+//! ### bevy/registry/functions
+//!
+//! Export the signatures of the functions in the `AppFunctionRegistry`. This requires the
+//! `reflect_functions` feature.
+//!
+//! `result`: An object mapping the name of each function to an object with the following fields:
+//! - `name`: The name of the function.
+//! - `signatures`: An array of signatures, each with an array of `args` and a `return` value.
+//!   Arguments have an `index`, an optional `name`, a `typePath`, a `type` `$ref` to the schema
+//!   of the type, an `ownership` of `"Ref"`, `"Mut"` or `"Owned"`, and the optional `hasDefault`
+//!   and `variadic` flags.
+//!
+//! [JSON Schema]: https://json-schema.org/
+//!
 //!
 //! ## Custom methods
 //!
@@ -304,6 +419,8 @@
 //! [the `serde` documentation]: https://serde.rs/
 //! [fully-qualified type names]: bevy_reflect::TypePath::type_path
 //! [fully-qualified type name]: bevy_reflect::TypePath::type_path
+//! [system stepping]: bevy_ecs::schedule::Stepping
+//! [`Stepping`]: bevy_ecs::schedule::Stepping
 
 #![warn(
     clippy::allow_attributes,
@@ -311,6 +428,8 @@
     reason = "See #17111; To be removed once all crates are in-line with these attributes"
 )]
 
+extern crate alloc;
+
 use async_channel::{Receiver, Sender};
 use bevy_app::{prelude::*, MainScheduleOrder};
 use bevy_derive::{Deref, DerefMut};
@@ -328,6 +447,12 @@ use std::sync::RwLock;
 pub mod builtin_methods;
 #[cfg(feature = "http")]
 pub mod http;
+#[cfg(any(feature = "http", feature = "websocket", feature = "unix_socket"))]
+mod transport;
+#[cfg(feature = "unix_socket")]
+pub mod unix_socket;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 const CHANNEL_SIZE: usize = 16;
 
@@ -382,7 +507,7 @@ impl RemotePlugin {
 
 impl Default for RemotePlugin {
     fn default() -> Self {
-        Self::empty()
+        let plugin = Self::empty()
             .with_method(
                 builtin_methods::BRP_GET_METHOD,
                 builtin_methods::process_remote_get_request,
@@ -427,6 +552,41 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_LIST_AND_WATCH_METHOD,
                 builtin_methods::process_remote_list_watching_request,
             )
+            .with_watching_method(
+                builtin_methods::BRP_QUERY_AND_WATCH_METHOD,
+                builtin_methods::process_remote_query_watching_request,
+            )
+            .with_method(
+                builtin_methods::BRP_LIST_SCHEDULES_METHOD,
+                builtin_methods::process_remote_list_schedules_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_METHOD,
+                builtin_methods::process_remote_stepping_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_BREAKPOINT_METHOD,
+                builtin_methods::process_remote_stepping_breakpoint_request,
+            );
+
+        #[cfg(feature = "reflect_functions")]
+        let plugin = plugin.with_method(
+            builtin_methods::BRP_REGISTRY_FUNCTIONS_METHOD,
+            builtin_methods::export_registry_functions,
+        );
+
+        #[cfg(feature = "bevy_asset")]
+        let plugin = plugin
+            .with_method(
+                builtin_methods::BRP_LIST_ASSETS_METHOD,
+                builtin_methods::process_remote_list_assets_request,
+            )
+            .with_method(
+                builtin_methods::BRP_RELOAD_ASSET_METHOD,
+                builtin_methods::process_remote_reload_asset_request,
+            );
+
+        plugin
     }
 }
 
@@ -724,6 +884,46 @@ impl BrpError {
             data: None,
         }
     }
+
+    /// Resource required by the method wasn't found in the world.
+    #[must_use]
+    pub fn resource_not_present(resource: &str) -> Self {
+        Self {
+            code: error_codes::RESOURCE_NOT_PRESENT,
+            message: format!("Resource `{resource}` not present in the world"),
+            data: None,
+        }
+    }
+
+    /// Schedule wasn't found in the world.
+    #[must_use]
+    pub fn schedule_not_found(schedule: &str) -> Self {
+        Self {
+            code: error_codes::SCHEDULE_NOT_FOUND,
+            message: format!("Schedule `{schedule}` not found"),
+            data: None,
+        }
+    }
+
+    /// System wasn't found in a schedule.
+    #[must_use]
+    pub fn system_not_found(system: &str, schedule: &str) -> Self {
+        Self {
+            code: error_codes::SYSTEM_NOT_FOUND,
+            message: format!("System `{system}` not found in Schedule `{schedule}`"),
+            data: None,
+        }
+    }
+
+    /// An arbitrary asset error.
+    #[must_use]
+    pub fn asset_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::ASSET_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
 }
 
 /// Error codes used by BRP.
@@ -759,6 +959,18 @@ pub mod error_codes {
 
     /// Cannot reparent an entity to itself.
     pub const SELF_REPARENT: i16 = -23404;
+
+    /// Could not find resource in the world.
+    pub const RESOURCE_NOT_PRESENT: i16 = -23405;
+
+    /// Could not find schedule.
+    pub const SCHEDULE_NOT_FOUND: i16 = -23406;
+
+    /// Could not find system in schedule.
+    pub const SYSTEM_NOT_FOUND: i16 = -23407;
+
+    /// Could not find or reload asset.
+    pub const ASSET_ERROR: i16 = -23408;
 }
 
 /// The result of a request.
@@ -0,0 +1,187 @@
+//! Helpers shared by the BRP transports.
+//!
+//! Every transport forwards the requests it receives to the [`RemotePlugin`](crate::RemotePlugin)
+//! through the [`BrpSender`](crate::BrpSender), so they all share the same method registry.
+//! Message-based transports, such as the WebSocket and Unix domain socket ones, additionally share the
+//! way batches and watching requests are multiplexed over a single connection.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{error_codes, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult};
+use anyhow::Result as AnyhowResult;
+use async_channel::{Receiver, Sender};
+use serde_json::Value;
+#[cfg(any(feature = "websocket", feature = "unix_socket"))]
+use {
+    crate::BrpBatch,
+    bevy_tasks::{futures_lite::future, IoTaskPool},
+};
+
+/// The reply to a single request forwarded by [`dispatch`].
+pub(crate) enum BrpReply {
+    /// The response to a regular request.
+    Complete(BrpResponse),
+    /// The stream of results of a watching request.
+    Stream {
+        /// The ID of the watching request, repeated in every response.
+        id: Option<Value>,
+        /// The results produced every time the watched data changes.
+        receiver: Receiver<BrpResult>,
+    },
+}
+
+/// Validates a single JSON-RPC request and forwards it to the [`RemotePlugin`](crate::RemotePlugin).
+///
+/// Regular requests are awaited, while watching requests (those whose method contains `+watch`)
+/// return as soon as they are sent.
+pub(crate) async fn dispatch(
+    request: Value,
+    request_sender: &Sender<BrpMessage>,
+) -> AnyhowResult<BrpReply> {
+    // Reach in and get the request ID early so that we can report it even when parsing fails.
+    let id = request.as_object().and_then(|map| map.get("id")).cloned();
+
+    let request: BrpRequest = match serde_json::from_value(request) {
+        Ok(v) => v,
+        Err(err) => {
+            return Ok(BrpReply::Complete(BrpResponse::new(
+                id,
+                Err(BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: err.to_string(),
+                    data: None,
+                }),
+            )));
+        }
+    };
+
+    if request.jsonrpc != "2.0" {
+        return Ok(BrpReply::Complete(BrpResponse::new(
+            id,
+            Err(BrpError {
+                code: error_codes::INVALID_REQUEST,
+                message: String::from("JSON-RPC request requires `\"jsonrpc\": \"2.0\"`"),
+                data: None,
+            }),
+        )));
+    }
+
+    let watch = request.method.contains("+watch");
+    let size = if watch { 8 } else { 1 };
+    let (result_sender, result_receiver) = async_channel::bounded(size);
+
+    let _ = request_sender
+        .send(BrpMessage {
+            method: request.method,
+            params: request.params,
+            sender: result_sender,
+        })
+        .await;
+
+    if watch {
+        Ok(BrpReply::Stream {
+            id: request.id,
+            receiver: result_receiver,
+        })
+    } else {
+        let result = result_receiver.recv().await?;
+        Ok(BrpReply::Complete(BrpResponse::new(request.id, result)))
+    }
+}
+
+/// Returns the response sent back when streaming is used in a batch request.
+pub(crate) fn batch_streaming_error(id: Option<Value>) -> BrpResponse {
+    BrpResponse::new(
+        id,
+        Err(BrpError {
+            code: error_codes::INVALID_REQUEST,
+            message: "Streaming can not be used in batch requests".to_string(),
+            data: None,
+        }),
+    )
+}
+
+/// The state shared by every request received on a message-based connection.
+#[cfg(any(feature = "websocket", feature = "unix_socket"))]
+#[derive(Clone)]
+pub(crate) struct BrpConnection {
+    /// Forwards requests to the [`RemotePlugin`](crate::RemotePlugin).
+    pub request_sender: Sender<BrpMessage>,
+    /// Sends serialized responses back to the client.
+    pub response_sender: Sender<String>,
+    /// Never sent to; closed once the connection ends, to stop the ongoing watching requests.
+    pub closed: Receiver<()>,
+}
+
+#[cfg(any(feature = "websocket", feature = "unix_socket"))]
+impl BrpConnection {
+    /// Processes a message received from the client, containing either a single request or a
+    /// batch of them.
+    ///
+    /// The message is processed in the background, so that slow requests don't hold back the
+    /// next ones. Each result of a watching request is sent back as its own message, until the
+    /// connection is closed.
+    pub fn process_message(&self, message: &[u8]) {
+        let connection = self.clone();
+        let batch: Result<BrpBatch, _> = serde_json::from_slice(message);
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = connection.process_batch(batch).await;
+            })
+            .detach();
+    }
+
+    async fn process_batch(&self, batch: Result<BrpBatch, serde_json::Error>) -> AnyhowResult<()> {
+        match batch {
+            Ok(BrpBatch::Single(request)) => match dispatch(request, &self.request_sender).await? {
+                BrpReply::Complete(response) => {
+                    self.response_sender
+                        .send(serde_json::to_string(&response)?)
+                        .await?;
+                }
+                BrpReply::Stream { id, receiver } => loop {
+                    // Stop as soon as the connection is closed, which drops the receiver and
+                    // lets the watching request be cleaned up.
+                    let result = future::or(async { receiver.recv().await.ok() }, async {
+                        let _ = self.closed.recv().await;
+                        None
+                    })
+                    .await;
+                    let Some(result) = result else {
+                        break;
+                    };
+                    let response = BrpResponse::new(id.clone(), result);
+                    self.response_sender
+                        .send(serde_json::to_string(&response)?)
+                        .await?;
+                },
+            },
+            Ok(BrpBatch::Batch(requests)) => {
+                let mut responses = Vec::new();
+                for request in requests {
+                    match dispatch(request, &self.request_sender).await? {
+                        BrpReply::Complete(response) => responses.push(response),
+                        BrpReply::Stream { id, .. } => responses.push(batch_streaming_error(id)),
+                    }
+                }
+                self.response_sender
+                    .send(serde_json::to_string(&responses)?)
+                    .await?;
+            }
+            Err(err) => {
+                let response = BrpResponse::new(
+                    None,
+                    Err(BrpError {
+                        code: error_codes::INVALID_REQUEST,
+                        message: err.to_string(),
+                        data: None,
+                    }),
+                );
+                self.response_sender
+                    .send(serde_json::to_string(&response)?)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
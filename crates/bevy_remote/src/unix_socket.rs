@@ -0,0 +1,141 @@
+//! The BRP transport using JSON-RPC over Unix domain sockets.
+//!
+//! Adding the [`RemoteUnixSocketPlugin`] to your [`App`] causes Bevy to accept connections on a
+//! Unix domain socket (by default, `bevy_remote.sock` in the temporary directory) while your app
+//! is running. This is meant for local tools, which can connect without opening a network port.
+//!
+//! Clients send one JSON-RPC request, or batch of requests, per line, and receive one response
+//! per line. Since requests are processed concurrently, responses may arrive in a different order
+//! than their requests: use the request `id` to match them. Watching requests, such as
+//! `bevy/get+watch`, send a new response with the same `id` every time the watched data changes,
+//! until the connection is closed.
+//!
+//! This BRP transport is only available on Unix platforms.
+
+#![cfg(unix)]
+
+use crate::{transport::BrpConnection, BrpMessage, BrpSender};
+use alloc::sync::Arc;
+use anyhow::Result as AnyhowResult;
+use async_channel::Sender;
+use async_io::Async;
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::system::{Res, Resource};
+use bevy_tasks::{
+    futures_lite::{io::BufReader, AsyncBufReadExt, AsyncWriteExt},
+    IoTaskPool,
+};
+use std::{
+    os::unix::net::{UnixListener, UnixStream},
+    path::PathBuf,
+};
+
+/// The default name of the socket, created in the temporary directory.
+pub const DEFAULT_SOCKET_NAME: &str = "bevy_remote.sock";
+
+/// Add this plugin to your [`App`] to allow local connections over a Unix domain socket to
+/// inspect and modify entities. It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// Any file already present at the socket's path is removed when the server starts.
+pub struct RemoteUnixSocketPlugin {
+    /// The path of the socket.
+    path: PathBuf,
+}
+
+impl Default for RemoteUnixSocketPlugin {
+    fn default() -> Self {
+        Self {
+            path: std::env::temp_dir().join(DEFAULT_SOCKET_NAME),
+        }
+    }
+}
+
+impl Plugin for RemoteUnixSocketPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(SocketPath(self.path.clone()))
+            .add_systems(Startup, start_unix_socket_server);
+    }
+}
+
+impl RemoteUnixSocketPlugin {
+    /// Set the path of the socket that the server will listen on.
+    #[must_use]
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = path.into();
+        self
+    }
+}
+
+/// A resource containing the path of the Unix domain socket that Bevy will listen on.
+///
+/// Currently, changing this while the application is running has no effect; this merely
+/// reflects the path that is set during the setup of the [`RemoteUnixSocketPlugin`].
+#[derive(Debug, Resource)]
+pub struct SocketPath(pub PathBuf);
+
+/// A system that starts up the Bevy Remote Protocol Unix domain socket server.
+fn start_unix_socket_server(request_sender: Res<BrpSender>, path: Res<SocketPath>) {
+    IoTaskPool::get()
+        .spawn(server_main(path.0.clone(), request_sender.clone()))
+        .detach();
+}
+
+/// The Bevy Remote Protocol Unix domain socket server main loop.
+async fn server_main(path: PathBuf, request_sender: Sender<BrpMessage>) -> AnyhowResult<()> {
+    // A socket left behind by a previous run would prevent binding.
+    let _ = std::fs::remove_file(&path);
+    let listener = Async::<UnixListener>::bind(&path)?;
+    loop {
+        let (client, _) = listener.accept().await?;
+
+        let request_sender = request_sender.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = handle_client(client, request_sender).await;
+            })
+            .detach();
+    }
+}
+
+async fn handle_client(
+    client: Async<UnixStream>,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    let client = Arc::new(client);
+
+    let (response_sender, response_receiver) = async_channel::unbounded::<String>();
+    // Dropped once the client disconnects, which closes the channel.
+    let (_closed_sender, closed) = async_channel::bounded::<()>(1);
+    let connection = BrpConnection {
+        request_sender,
+        response_sender,
+        closed,
+    };
+
+    // Responses are written from their own task, so that they don't wait for the next line.
+    let writer = client.clone();
+    IoTaskPool::get()
+        .spawn(async move {
+            let mut writer = &*writer;
+            while let Ok(mut response) = response_receiver.recv().await {
+                response.push('\n');
+                if writer.write_all(response.as_bytes()).await.is_err() {
+                    break;
+                }
+            }
+        })
+        .detach();
+
+    let mut reader = BufReader::new(&*client);
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line).await? == 0 {
+            return Ok(());
+        }
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        connection.process_message(&line);
+    }
+}
@@ -0,0 +1,444 @@
+//! The BRP transport using JSON-RPC over the WebSocket protocol.
+//!
+//! Adding the [`RemoteWebSocketPlugin`] to your [`App`] causes Bevy to accept WebSocket
+//! connections (by default, on port 15703) while your app is running.
+//!
+//! Clients send one JSON-RPC request, or batch of requests, per text message, and receive one
+//! response per message. Since requests are processed concurrently, responses may arrive in a
+//! different order than their requests: use the request `id` to match them. Watching requests,
+//! such as `bevy/get+watch`, send a new response with the same `id` every time the watched data
+//! changes, until the connection is closed.
+//!
+//! Unlike HTTP streaming, a WebSocket connection can be used from browsers to watch several requests over
+//! a single connection.
+//!
+//! Browsers don't apply CORS to WebSocket connections, so any web page could connect to the
+//! server. To prevent that, handshakes sent with an `Origin` header, which browsers always send,
+//! are refused unless the origin was allowed with [`RemoteWebSocketPlugin::with_allowed_origin`].
+//! Native clients don't send this header, and are always accepted.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{transport::BrpConnection, BrpMessage, BrpSender};
+use alloc::sync::Arc;
+use anyhow::{anyhow, bail, Result as AnyhowResult};
+use async_channel::Sender;
+use async_io::Async;
+use base64::{prelude::BASE64_STANDARD, Engine as _};
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::system::{Res, Resource};
+use bevy_tasks::{
+    futures_lite::{AsyncReadExt, AsyncWriteExt},
+    IoTaskPool,
+};
+use core::net::{IpAddr, Ipv4Addr};
+use sha1::{Digest, Sha1};
+use std::net::{TcpListener, TcpStream};
+
+/// The default port that Bevy will listen on for WebSocket connections.
+///
+/// This is the port following the default port of the HTTP transport.
+pub const DEFAULT_WEBSOCKET_PORT: u16 = 15703;
+
+/// The default host address that Bevy will use for its WebSocket server.
+pub const DEFAULT_WEBSOCKET_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+
+/// The GUID appended to the client's key to compute the handshake's accept key, as defined by
+/// [RFC 6455](https://datatracker.ietf.org/doc/html/rfc6455#section-1.3).
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Largest handshake request accepted, headers included.
+const MAX_HANDSHAKE_LENGTH: usize = 8 * 1024;
+
+/// Largest message accepted, after reassembling fragmented frames.
+const MAX_MESSAGE_LENGTH: usize = 16 * 1024 * 1024;
+
+/// Add this plugin to your [`App`] to allow remote connections over the WebSocket protocol to inspect and
+/// modify entities. It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+///
+/// The defaults are:
+/// - [`DEFAULT_WEBSOCKET_ADDR`] : 127.0.0.1.
+/// - [`DEFAULT_WEBSOCKET_PORT`] : 15703.
+/// - No allowed origins, so browsers can't connect.
+pub struct RemoteWebSocketPlugin {
+    /// The address that Bevy will bind to.
+    address: IpAddr,
+    /// The port that Bevy will listen on.
+    port: u16,
+    /// The origins of the web pages allowed to connect.
+    allowed_origins: Vec<String>,
+}
+
+impl Default for RemoteWebSocketPlugin {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_WEBSOCKET_ADDR,
+            port: DEFAULT_WEBSOCKET_PORT,
+            allowed_origins: Vec::new(),
+        }
+    }
+}
+
+impl Plugin for RemoteWebSocketPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(WebSocketHost {
+            address: self.address,
+            port: self.port,
+            allowed_origins: self.allowed_origins.clone(),
+        })
+        .add_systems(Startup, start_websocket_server);
+    }
+}
+
+impl RemoteWebSocketPlugin {
+    /// Set the IP address that the server will use.
+    #[must_use]
+    pub fn with_address(mut self, address: impl Into<IpAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+    /// Set the remote port that the server will listen on.
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+    /// Allow the web pages served from `origin`, such as `http://localhost:8080`, to connect.
+    ///
+    /// Only allow origins you trust: their pages get full access to the world.
+    #[must_use]
+    pub fn with_allowed_origin(mut self, origin: impl Into<String>) -> Self {
+        self.allowed_origins.push(origin.into());
+        self
+    }
+}
+
+/// A resource containing the IP address and port that Bevy will host its WebSocket server on.
+///
+/// Currently, changing this while the application is running has no effect; this merely
+/// reflects the values set during the setup of the [`RemoteWebSocketPlugin`].
+#[derive(Debug, Resource)]
+pub struct WebSocketHost {
+    /// The address that Bevy is bound to.
+    pub address: IpAddr,
+    /// The port that Bevy is listening on.
+    pub port: u16,
+    /// The origins of the web pages allowed to connect.
+    pub allowed_origins: Vec<String>,
+}
+
+/// A system that starts up the Bevy Remote Protocol WebSocket server.
+fn start_websocket_server(request_sender: Res<BrpSender>, host: Res<WebSocketHost>) {
+    IoTaskPool::get()
+        .spawn(server_main(
+            host.address,
+            host.port,
+            host.allowed_origins.clone().into(),
+            request_sender.clone(),
+        ))
+        .detach();
+}
+
+/// The Bevy Remote Protocol WebSocket server main loop.
+async fn server_main(
+    address: IpAddr,
+    port: u16,
+    allowed_origins: Arc<[String]>,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    let listener = Async::<TcpListener>::bind((address, port))?;
+    loop {
+        let (client, _) = listener.accept().await?;
+
+        let allowed_origins = allowed_origins.clone();
+        let request_sender = request_sender.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = handle_client(client, &allowed_origins, request_sender).await;
+            })
+            .detach();
+    }
+}
+
+async fn handle_client(
+    client: Async<TcpStream>,
+    allowed_origins: &[String],
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    let client = Arc::new(client);
+    handshake(&mut &*client, allowed_origins).await?;
+
+    let (response_sender, response_receiver) = async_channel::unbounded::<String>();
+    let (frame_sender, frame_receiver) = async_channel::unbounded::<Frame>();
+    // Dropped once the client disconnects, which closes the channel.
+    let (_closed_sender, closed) = async_channel::bounded::<()>(1);
+    let connection = BrpConnection {
+        request_sender,
+        response_sender,
+        closed,
+    };
+
+    // Responses are written from their own task, so that they don't wait for the next message.
+    let writer = client.clone();
+    IoTaskPool::get()
+        .spawn(async move {
+            let mut writer = &*writer;
+            loop {
+                let frame = bevy_tasks::futures_lite::future::or(
+                    async { response_receiver.recv().await.ok().map(Frame::Text) },
+                    async { frame_receiver.recv().await.ok() },
+                )
+                .await;
+                let Some(frame) = frame else {
+                    break;
+                };
+                let close = matches!(frame, Frame::Close);
+                if write_frame(&mut writer, &frame).await.is_err() || close {
+                    break;
+                }
+            }
+        })
+        .detach();
+
+    let mut reader = &*client;
+    let mut message = Vec::new();
+    loop {
+        let (fin, opcode, payload) = read_frame(&mut reader).await?;
+        match opcode {
+            // Continuation, text and binary frames.
+            0x0..=0x2 => {
+                if message.len() + payload.len() > MAX_MESSAGE_LENGTH {
+                    let _ = frame_sender.send(Frame::Close).await;
+                    bail!("WebSocket message too long");
+                }
+                message.extend_from_slice(&payload);
+                if fin {
+                    connection.process_message(&message);
+                    message.clear();
+                }
+            }
+            // Close.
+            0x8 => {
+                let _ = frame_sender.send(Frame::Close).await;
+                return Ok(());
+            }
+            // Ping.
+            0x9 => {
+                let _ = frame_sender.send(Frame::Pong(payload)).await;
+            }
+            // Pong, and reserved opcodes.
+            _ => {}
+        }
+    }
+}
+
+/// Reads the HTTP upgrade request of the client and accepts it, unless it comes from a web page
+/// whose origin is not in `allowed_origins`.
+async fn handshake(stream: &mut &Async<TcpStream>, allowed_origins: &[String]) -> AnyhowResult<()> {
+    let mut request = Vec::new();
+    let mut buffer = [0; 1024];
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        if request.len() >= MAX_HANDSHAKE_LENGTH {
+            bail!("WebSocket handshake too long");
+        }
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            bail!("Connection closed during WebSocket handshake");
+        }
+        request.extend_from_slice(&buffer[..read]);
+    }
+
+    let request = core::str::from_utf8(&request)?;
+    let header = |header: &str| {
+        request.lines().skip(1).find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.trim()
+                .eq_ignore_ascii_case(header)
+                .then(|| value.trim())
+        })
+    };
+    if let Some(origin) = header("origin") {
+        if !allowed_origins
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(origin))
+        {
+            stream
+                .write_all(
+                    b"HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                )
+                .await?;
+            bail!("WebSocket handshake from a forbidden origin: {origin}");
+        }
+    }
+    let Some(key) = header("sec-websocket-key") else {
+        stream
+            .write_all(
+                b"HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            )
+            .await?;
+        bail!("Not a WebSocket handshake");
+    };
+
+    stream
+        .write_all(
+            format!(
+                "HTTP/1.1 101 Switching Protocols\r\n\
+                Upgrade: websocket\r\n\
+                Connection: Upgrade\r\n\
+                Sec-WebSocket-Accept: {}\r\n\r\n",
+                accept_key(key)
+            )
+            .as_bytes(),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Computes the `Sec-WebSocket-Accept` header answering the client's `Sec-WebSocket-Key`.
+fn accept_key(key: &str) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(key.as_bytes());
+    hasher.update(WEBSOCKET_GUID.as_bytes());
+    BASE64_STANDARD.encode(hasher.finalize())
+}
+
+/// A frame sent to the client.
+enum Frame {
+    Text(String),
+    Pong(Vec<u8>),
+    Close,
+}
+
+/// Reads a frame sent by the client, returning its FIN bit, opcode and unmasked payload.
+async fn read_frame(stream: &mut &Async<TcpStream>) -> AnyhowResult<(bool, u8, Vec<u8>)> {
+    let mut header = [0; 2];
+    stream.read_exact(&mut header).await?;
+    let fin = header[0] & 0x80 != 0;
+    let opcode = header[0] & 0x0F;
+    let masked = header[1] & 0x80 != 0;
+
+    let length = match header[1] & 0x7F {
+        126 => {
+            let mut length = [0; 2];
+            stream.read_exact(&mut length).await?;
+            u16::from_be_bytes(length) as usize
+        }
+        127 => {
+            let mut length = [0; 8];
+            stream.read_exact(&mut length).await?;
+            usize::try_from(u64::from_be_bytes(length))?
+        }
+        length => length as usize,
+    };
+    if length > MAX_MESSAGE_LENGTH {
+        return Err(anyhow!("WebSocket frame too long"));
+    }
+
+    // Clients must mask every frame they send.
+    if !masked {
+        bail!("Received an unmasked WebSocket frame");
+    }
+    let mut mask = [0; 4];
+    stream.read_exact(&mut mask).await?;
+
+    let mut payload = vec![0; length];
+    stream.read_exact(&mut payload).await?;
+    for (i, byte) in payload.iter_mut().enumerate() {
+        *byte ^= mask[i % 4];
+    }
+
+    Ok((fin, opcode, payload))
+}
+
+/// Writes an unfragmented, unmasked frame.
+async fn write_frame(stream: &mut &Async<TcpStream>, frame: &Frame) -> AnyhowResult<()> {
+    let (opcode, payload) = match frame {
+        Frame::Text(text) => (0x1, text.as_bytes()),
+        Frame::Pong(payload) => (0xA, payload.as_slice()),
+        Frame::Close => (0x8, [].as_slice()),
+    };
+
+    let mut buffer = Vec::with_capacity(payload.len() + 10);
+    buffer.push(0x80 | opcode);
+    match payload.len() {
+        length @ 0..=125 => buffer.push(length as u8),
+        length @ 126..=0xFFFF => {
+            buffer.push(126);
+            buffer.extend_from_slice(&(length as u16).to_be_bytes());
+        }
+        length => {
+            buffer.push(127);
+            buffer.extend_from_slice(&(length as u64).to_be_bytes());
+        }
+    }
+    buffer.extend_from_slice(payload);
+
+    stream.write_all(&buffer).await?;
+    stream.flush().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_tasks::futures_lite::future;
+
+    /// Sends a handshake with the given `Origin` header to a server allowing `allowed_origins`,
+    /// returning whether the server accepted it and its response.
+    fn try_handshake(origin: Option<&str>, allowed_origins: &[String]) -> (bool, String) {
+        let listener = Async::<TcpListener>::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let address = listener.get_ref().local_addr().unwrap();
+        let origin = origin
+            .map(|origin| format!("Origin: {origin}\r\n"))
+            .unwrap_or_default();
+        future::block_on(future::zip(
+            async {
+                let (server, _) = listener.accept().await.unwrap();
+                handshake(&mut &server, allowed_origins).await.is_ok()
+            },
+            async {
+                let mut client = Async::<TcpStream>::connect(address).await.unwrap();
+                let request = format!(
+                    "GET / HTTP/1.1\r\n\
+                    Host: 127.0.0.1\r\n\
+                    Upgrade: websocket\r\n\
+                    Connection: Upgrade\r\n\
+                    Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\
+                    Sec-WebSocket-Version: 13\r\n\
+                    {origin}\r\n"
+                );
+                client.write_all(request.as_bytes()).await.unwrap();
+                let mut response = String::new();
+                client.read_to_string(&mut response).await.unwrap();
+                response
+            },
+        ))
+    }
+
+    #[test]
+    fn foreign_origin_is_refused() {
+        let allowed = ["http://localhost:8080".to_string()];
+
+        let (accepted, response) = try_handshake(Some("https://example.com"), &allowed);
+        assert!(!accepted);
+        assert!(response.starts_with("HTTP/1.1 403 Forbidden"));
+
+        let (accepted, response) = try_handshake(Some("http://localhost:8080"), &allowed);
+        assert!(accepted);
+        assert!(response.starts_with("HTTP/1.1 101 Switching Protocols"));
+
+        // Native clients don't send an origin.
+        let (accepted, _) = try_handshake(None, &[]);
+        assert!(accepted);
+    }
+
+    #[test]
+    fn accept_key_matches_rfc_example() {
+        assert_eq!(
+            accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+    }
+}
@@ -90,11 +90,13 @@ impl Plugin for ImagePlugin {
                 bevy_asset::transformer::IdentityAssetTransformer<Image>,
                 CompressedImageSaver,
             >>(CompressedImageSaver.into());
-            processor.set_default_processor::<bevy_asset::processor::LoadTransformAndSave<
-                ImageLoader,
-                bevy_asset::transformer::IdentityAssetTransformer<Image>,
-                CompressedImageSaver,
-            >>("png");
+            for extension in ["png", "jpg", "jpeg"] {
+                processor.set_default_processor::<bevy_asset::processor::LoadTransformAndSave<
+                    ImageLoader,
+                    bevy_asset::transformer::IdentityAssetTransformer<Image>,
+                    CompressedImageSaver,
+                >>(extension);
+            }
         }
 
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
@@ -3,10 +3,10 @@ use bevy_asset::Asset;
 use bevy_ecs::reflect::ReflectResource;
 use bevy_ecs::{
     entity::{Entity, EntityHashMap, SceneEntityMapper},
-    reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
+    reflect::{AppTypeRegistry, DynamicComponents, ReflectComponent, ReflectMapEntities},
     world::World,
 };
-use bevy_reflect::{PartialReflect, TypePath, TypeRegistry};
+use bevy_reflect::{DynamicStruct, PartialReflect, Struct, TypePath, TypeRegistry};
 
 #[cfg(feature = "serialize")]
 use crate::serde::SceneSerializer;
@@ -37,6 +37,9 @@ pub struct DynamicEntity {
     /// A vector of boxed components that belong to the given entity and
     /// implement the [`PartialReflect`] trait.
     pub components: Vec<Box<dyn PartialReflect>>,
+    /// The values of the components registered at runtime with
+    /// [`World::register_dynamic_component`] that belong to the given entity, by component name.
+    pub dynamic_components: Vec<(String, DynamicStruct)>,
 }
 
 impl DynamicScene {
@@ -57,7 +60,8 @@ impl DynamicScene {
     ///
     /// This method will return a [`SceneSpawnError`] if a type either is not registered
     /// in the provided [`AppTypeRegistry`] resource, or doesn't reflect the
-    /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource) trait.
+    /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource) trait,
+    /// or if a dynamic component isn't registered in `world` with a matching schema.
     pub fn write_to_world_with(
         &self,
         world: &mut World,
@@ -117,6 +121,27 @@ impl DynamicScene {
                     &type_registry,
                 );
             }
+
+            for (name, value) in &scene_entity.dynamic_components {
+                let (id, default_value) = world
+                    .get_resource::<DynamicComponents>()
+                    .and_then(|components| components.get_info(components.get_id(name)?))
+                    .map(|info| (info.id(), info.default_value()))
+                    .ok_or_else(|| SceneSpawnError::UnregisteredDynamicComponent {
+                        name: name.clone(),
+                    })?;
+                let mut entity = world.entity_mut(entity);
+                let mut component = entity
+                    .get_dynamic(id)
+                    .map_or(default_value, Struct::clone_dynamic);
+                component.try_apply(value).map_err(|error| {
+                    SceneSpawnError::InvalidDynamicComponent {
+                        name: name.clone(),
+                        error,
+                    }
+                })?;
+                entity.insert_dynamic(id, component);
+            }
         }
 
         // Insert resources after all entities have been added to the world.
@@ -3,11 +3,11 @@ use alloc::collections::BTreeMap;
 use bevy_ecs::{
     component::{Component, ComponentId},
     prelude::Entity,
-    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    reflect::{AppTypeRegistry, DynamicComponents, ReflectComponent, ReflectResource},
     system::Resource,
     world::World,
 };
-use bevy_reflect::{PartialReflect, ReflectFromReflect};
+use bevy_reflect::{PartialReflect, ReflectFromReflect, Struct};
 use bevy_utils::default;
 
 /// A [`DynamicScene`] builder, used to build a scene from a [`World`] by extracting some entities and resources.
@@ -21,6 +21,9 @@ use bevy_utils::default;
 ///
 /// Extraction happens immediately and uses the filter as it exists during the time of extraction.
 ///
+/// Components registered at runtime with [`World::register_dynamic_component`] have no type to filter
+/// by and are always extracted, into [`DynamicEntity::dynamic_components`].
+///
 /// # Resource Extraction
 ///
 /// By default, all resources registered with [`ReflectResource`] type data in a world's [`AppTypeRegistry`] will be extracted.
@@ -225,8 +228,9 @@ impl<'w> DynamicSceneBuilder<'w> {
     /// These were likely created because none of their components were present in the provided type registry upon extraction.
     #[must_use]
     pub fn remove_empty_entities(mut self) -> Self {
-        self.extracted_scene
-            .retain(|_, entity| !entity.components.is_empty());
+        self.extracted_scene.retain(|_, entity| {
+            !entity.components.is_empty() || !entity.dynamic_components.is_empty()
+        });
 
         self
     }
@@ -267,6 +271,7 @@ impl<'w> DynamicSceneBuilder<'w> {
     #[must_use]
     pub fn extract_entities(mut self, entities: impl Iterator<Item = Entity>) -> Self {
         let type_registry = self.original_world.resource::<AppTypeRegistry>().read();
+        let dynamic_components = self.original_world.get_resource::<DynamicComponents>();
 
         for entity in entities {
             if self.extracted_scene.contains_key(&entity) {
@@ -276,10 +281,22 @@ impl<'w> DynamicSceneBuilder<'w> {
             let mut entry = DynamicEntity {
                 entity,
                 components: Vec::new(),
+                dynamic_components: Vec::new(),
             };
 
             let original_entity = self.original_world.entity(entity);
             for component_id in original_entity.archetype().components() {
+                let dynamic_info = dynamic_components
+                    .and_then(|components| Some((components, components.get_info(component_id)?)));
+                if let Some((components, info)) = dynamic_info {
+                    if let Some(value) = components.get(original_entity, component_id) {
+                        entry
+                            .dynamic_components
+                            .push((info.name().into(), value.clone_dynamic()));
+                    }
+                    continue;
+                }
+
                 let mut extract_and_push = || {
                     let type_id = self
                         .original_world
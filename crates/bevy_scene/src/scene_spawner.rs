@@ -8,7 +8,7 @@ use bevy_ecs::{
     world::{Mut, World},
 };
 use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt, Parent};
-use bevy_reflect::Reflect;
+use bevy_reflect::{ApplyError, Reflect};
 use bevy_utils::{HashMap, HashSet};
 use thiserror::Error;
 use uuid::Uuid;
@@ -115,6 +115,20 @@ pub enum SceneSpawnError {
         /// The unregistered type.
         type_path: String,
     },
+    /// Scene contains a dynamic component that isn't registered in the world.
+    #[error("scene contains the dynamic component `{name}`, which is not registered. consider registering it using `World::register_dynamic_component`")]
+    UnregisteredDynamicComponent {
+        /// Name of the unregistered dynamic component.
+        name: String,
+    },
+    /// Scene contains a value of a dynamic component that doesn't match its schema.
+    #[error("scene contains an invalid value of the dynamic component `{name}`: {error}")]
+    InvalidDynamicComponent {
+        /// Name of the dynamic component.
+        name: String,
+        /// The error raised when applying the value to the schema of the component.
+        error: ApplyError,
+    },
     /// Scene contains a proxy without a represented type.
     #[error("scene contains dynamic type `{type_path}` without a represented type. consider changing this using `set_represented_type`.")]
     NoRepresentedType {
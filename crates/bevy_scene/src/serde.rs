@@ -4,10 +4,10 @@ use crate::{DynamicEntity, DynamicScene};
 use bevy_ecs::entity::Entity;
 use bevy_reflect::{
     serde::{
-        ReflectDeserializer, TypeRegistrationDeserializer, TypedReflectDeserializer,
-        TypedReflectSerializer,
+        ReflectDeserializer, ReflectSerializer, TypeRegistrationDeserializer,
+        TypedReflectDeserializer, TypedReflectSerializer,
     },
-    PartialReflect, ReflectFromReflect, TypeRegistry,
+    DynamicStruct, PartialReflect, ReflectFromReflect, Struct, TypeRegistry,
 };
 use bevy_utils::HashSet;
 use core::fmt::Formatter;
@@ -28,6 +28,8 @@ pub const SCENE_ENTITIES: &str = "entities";
 pub const ENTITY_STRUCT: &str = "Entity";
 /// Name of the serialized component field in an entity struct.
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
+/// Name of the serialized dynamic component field in an entity struct.
+pub const ENTITY_FIELD_DYNAMIC_COMPONENTS: &str = "dynamic_components";
 
 /// Serializer for a [`DynamicScene`].
 ///
@@ -137,7 +139,12 @@ impl<'a> Serialize for EntitySerializer<'a> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct(ENTITY_STRUCT, 1)?;
+        // Human-readable formats can omit fields, which keeps scenes without dynamic components
+        // unchanged. Other formats rely on the number of fields instead.
+        let has_dynamic_components =
+            !self.entity.dynamic_components.is_empty() || !serializer.is_human_readable();
+        let mut state =
+            serializer.serialize_struct(ENTITY_STRUCT, 1 + usize::from(has_dynamic_components))?;
         state.serialize_field(
             ENTITY_FIELD_COMPONENTS,
             &SceneMapSerializer {
@@ -145,6 +152,69 @@ impl<'a> Serialize for EntitySerializer<'a> {
                 registry: self.registry,
             },
         )?;
+        if has_dynamic_components {
+            state.serialize_field(
+                ENTITY_FIELD_DYNAMIC_COMPONENTS,
+                &DynamicComponentsSerializer {
+                    components: &self.entity.dynamic_components,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Handles serializing the dynamic components of an entity as a map of component name to a map
+/// of field name to field value.
+///
+/// Note: The components are sorted by name before they're serialized.
+pub struct DynamicComponentsSerializer<'a> {
+    /// The names and values of the dynamic components to serialize.
+    pub components: &'a [(String, DynamicStruct)],
+    /// Type registry in which the types of the fields of the components are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for DynamicComponentsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut components = self.components.iter().collect::<Vec<_>>();
+        components.sort_by_key(|(name, _)| name);
+
+        let mut state = serializer.serialize_map(Some(components.len()))?;
+        for (name, value) in components {
+            state.serialize_entry(
+                name,
+                &DynamicFieldsSerializer {
+                    value,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+struct DynamicFieldsSerializer<'a> {
+    value: &'a DynamicStruct,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for DynamicFieldsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.value.field_len()))?;
+        for (index, field) in self.value.iter_fields().enumerate() {
+            state.serialize_entry(
+                self.value.name_at(index).unwrap(),
+                &ReflectSerializer::new(field, self.registry),
+            )?;
+        }
         state.end()
     }
 }
@@ -202,9 +272,10 @@ enum SceneField {
 }
 
 #[derive(Deserialize)]
-#[serde(field_identifier, rename_all = "lowercase")]
+#[serde(field_identifier, rename_all = "snake_case")]
 enum EntityField {
     Components,
+    DynamicComponents,
 }
 
 /// Handles scene deserialization.
@@ -364,7 +435,7 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
     {
         deserializer.deserialize_struct(
             ENTITY_STRUCT,
-            &[ENTITY_FIELD_COMPONENTS],
+            &[ENTITY_FIELD_COMPONENTS, ENTITY_FIELD_DYNAMIC_COMPONENTS],
             SceneEntityVisitor {
                 entity: self.entity,
                 registry: self.type_registry,
@@ -394,10 +465,16 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
                 registry: self.registry,
             })?
             .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
+        let dynamic_components = seq
+            .next_element_seed(DynamicComponentsDeserializer {
+                registry: self.registry,
+            })?
+            .unwrap_or_default();
 
         Ok(DynamicEntity {
             entity: self.entity,
             components,
+            dynamic_components,
         })
     }
 
@@ -406,6 +483,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         A: MapAccess<'de>,
     {
         let mut components = None;
+        let mut dynamic_components = None;
         while let Some(key) = map.next_key()? {
             match key {
                 EntityField::Components => {
@@ -417,6 +495,16 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
                         registry: self.registry,
                     })?);
                 }
+                EntityField::DynamicComponents => {
+                    if dynamic_components.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_DYNAMIC_COMPONENTS));
+                    }
+
+                    dynamic_components =
+                        Some(map.next_value_seed(DynamicComponentsDeserializer {
+                            registry: self.registry,
+                        })?);
+                }
             }
         }
 
@@ -426,10 +514,116 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         Ok(DynamicEntity {
             entity: self.entity,
             components,
+            dynamic_components: dynamic_components.unwrap_or_default(),
+        })
+    }
+}
+
+/// Handles deserialization of the dynamic components of an entity.
+pub struct DynamicComponentsDeserializer<'a> {
+    /// Type registry in which the types of the fields of the components are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for DynamicComponentsDeserializer<'a> {
+    type Value = Vec<(String, DynamicStruct)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(DynamicComponentsVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct DynamicComponentsVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for DynamicComponentsVisitor<'a> {
+    type Value = Vec<(String, DynamicStruct)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of dynamic components")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut components: Vec<(String, DynamicStruct)> = Vec::new();
+        while let Some(name) = map.next_key::<String>()? {
+            if components.iter().any(|(added, _)| *added == name) {
+                return Err(Error::custom(format_args!(
+                    "duplicate dynamic component: `{name}`"
+                )));
+            }
+            let value = map.next_value_seed(DynamicFieldsDeserializer {
+                registry: self.registry,
+            })?;
+            components.push((name, value));
+        }
+
+        Ok(components)
+    }
+}
+
+struct DynamicFieldsDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for DynamicFieldsDeserializer<'a> {
+    type Value = DynamicStruct;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(DynamicFieldsVisitor {
+            registry: self.registry,
         })
     }
 }
 
+struct DynamicFieldsVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for DynamicFieldsVisitor<'a> {
+    type Value = DynamicStruct;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of dynamic component fields")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut value = DynamicStruct::default();
+        while let Some(name) = map.next_key::<String>()? {
+            let field = map.next_value_seed(ReflectDeserializer::new(self.registry))?;
+
+            // Attempt to convert using FromReflect.
+            let field = field
+                .get_represented_type_info()
+                .and_then(|info| {
+                    self.registry
+                        .get_type_data::<ReflectFromReflect>(info.type_id())
+                })
+                .and_then(|fr| fr.from_reflect(field.as_partial_reflect()))
+                .map(PartialReflect::into_partial_reflect)
+                .unwrap_or(field);
+
+            value.insert_boxed(name, field);
+        }
+
+        Ok(value)
+    }
+}
+
 /// Handles deserialization of a sequence of values with unique types.
 pub struct SceneMapDeserializer<'a> {
     /// Type registry in which the types of the values to deserialize are registered.
@@ -515,13 +709,14 @@ mod tests {
         DynamicScene, DynamicSceneBuilder,
     };
     use bevy_ecs::{
+        component::ComponentId,
         entity::{Entity, EntityHashMap, VisitEntities, VisitEntitiesMut},
         prelude::{Component, ReflectComponent, ReflectResource, Resource, World},
         query::{With, Without},
         reflect::{AppTypeRegistry, ReflectMapEntities},
         world::FromWorld,
     };
-    use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
+    use bevy_reflect::{DynamicStruct, GetField, Reflect, ReflectDeserialize, ReflectSerialize};
     use bincode::Options;
     use serde::{de::DeserializeSeed, Deserialize, Serialize};
     use std::io::BufReader;
@@ -778,6 +973,48 @@ mod tests {
             .all(|r| world.get_entity(r.0).is_err()));
     }
 
+    #[test]
+    fn should_roundtrip_dynamic_components() {
+        fn register_health(world: &mut World) -> ComponentId {
+            let mut schema = DynamicStruct::default();
+            schema.insert("current", 0u32);
+            schema.insert("name", String::new());
+            world.register_dynamic_component("Health", schema)
+        }
+
+        let mut world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<u32>();
+        let health = register_health(&mut world);
+        let mut value = DynamicStruct::default();
+        value.insert("current", 7u32);
+        value.insert("name", String::from("Player"));
+        world.spawn(Foo(1)).insert_dynamic(health, value);
+
+        let (scene, deserialized_scene) = roundtrip_ron(&world);
+        assert_scene_eq(&scene, &deserialized_scene);
+        assert_eq!(1, deserialized_scene.entities[0].dynamic_components.len());
+
+        let mut dst_world = create_world();
+        let health = register_health(&mut dst_world);
+        deserialized_scene
+            .write_to_world(&mut dst_world, &mut EntityHashMap::default())
+            .unwrap();
+
+        let entity = dst_world
+            .query_filtered::<Entity, With<Foo>>()
+            .single(&dst_world);
+        let entity = dst_world.entity_mut(entity);
+        let value = entity.get_dynamic(health).unwrap();
+        assert_eq!(value.get_field::<u32>("current"), Some(&7));
+        assert_eq!(
+            value.get_field::<String>("name").map(String::as_str),
+            Some("Player")
+        );
+    }
+
     #[test]
     fn should_roundtrip_with_custom_serialization() {
         let mut world = create_world();
@@ -819,7 +1056,7 @@ mod tests {
                 0, 1, 128, 128, 128, 128, 16, 1, 37, 98, 101, 118, 121, 95, 115, 99, 101, 110, 101,
                 58, 58, 115, 101, 114, 100, 101, 58, 58, 116, 101, 115, 116, 115, 58, 58, 77, 121,
                 67, 111, 109, 112, 111, 110, 101, 110, 116, 1, 2, 3, 102, 102, 166, 63, 205, 204,
-                108, 64, 1, 12, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33
+                108, 64, 1, 12, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33, 0
             ],
             serialized_scene
         );
@@ -857,11 +1094,11 @@ mod tests {
 
         assert_eq!(
             vec![
-                146, 128, 129, 207, 0, 0, 0, 1, 0, 0, 0, 0, 145, 129, 217, 37, 98, 101, 118, 121,
+                146, 128, 129, 207, 0, 0, 0, 1, 0, 0, 0, 0, 146, 129, 217, 37, 98, 101, 118, 121,
                 95, 115, 99, 101, 110, 101, 58, 58, 115, 101, 114, 100, 101, 58, 58, 116, 101, 115,
                 116, 115, 58, 58, 77, 121, 67, 111, 109, 112, 111, 110, 101, 110, 116, 147, 147, 1,
                 2, 3, 146, 202, 63, 166, 102, 102, 202, 64, 108, 204, 205, 129, 165, 84, 117, 112,
-                108, 101, 172, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33
+                108, 101, 172, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33, 128
             ],
             buf
         );
@@ -904,7 +1141,8 @@ mod tests {
                 58, 58, 115, 101, 114, 100, 101, 58, 58, 116, 101, 115, 116, 115, 58, 58, 77, 121,
                 67, 111, 109, 112, 111, 110, 101, 110, 116, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
                 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 102, 102, 166, 63, 205, 204, 108, 64, 1, 0, 0, 0,
-                12, 0, 0, 0, 0, 0, 0, 0, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33
+                12, 0, 0, 0, 0, 0, 0, 0, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33, 0,
+                0, 0, 0, 0, 0, 0, 0
             ],
             serialized_scene
         );
@@ -8,7 +8,7 @@ use crate::{
         setup_state_transitions_in_world, ComputedStates, FreelyMutableState, NextState, State,
         StateTransition, StateTransitionEvent, StateTransitionSteps, States, SubStates,
     },
-    state_scoped::clear_state_scoped_entities,
+    state_scoped::{clear_state_scoped_entities, clear_state_scoped_resources},
 };
 
 #[cfg(feature = "bevy_reflect")]
@@ -57,12 +57,13 @@ pub trait AppExtStates {
     /// This method is idempotent: it has no effect when called again using the same generic type.
     fn add_sub_state<S: SubStates>(&mut self) -> &mut Self;
 
-    /// Enable state-scoped entity clearing for state `S`.
+    /// Enable state-scoped entity and resource clearing for state `S`.
     ///
     /// If the [`States`] trait was derived with the `#[states(scoped_entities)]` attribute, it
     /// will be called automatically.
     ///
-    /// For more information refer to [`StateScoped`](crate::state_scoped::StateScoped).
+    /// For more information refer to [`StateScoped`](crate::state_scoped::StateScoped) and
+    /// [`StateScopedResources`](crate::state_scoped::StateScopedResources).
     fn enable_state_scoped_entities<S: States>(&mut self) -> &mut Self;
 
     #[cfg(feature = "bevy_reflect")]
@@ -226,7 +227,11 @@ impl AppExtStates for SubApp {
         // because [`OnExit`] only runs for one specific variant of the state.
         self.add_systems(
             StateTransition,
-            clear_state_scoped_entities::<S>.in_set(StateTransitionSteps::ExitSchedules),
+            (
+                clear_state_scoped_entities::<S>,
+                clear_state_scoped_resources::<S>,
+            )
+                .in_set(StateTransitionSteps::ExitSchedules),
         )
     }
 
@@ -318,10 +323,11 @@ mod tests {
     use crate::{
         self as bevy_state,
         app::StatesPlugin,
-        state::{State, StateTransition, StateTransitionEvent},
+        commands::CommandsStatesExt,
+        state::{NextState, State, StateTransition, StateTransitionEvent},
     };
     use bevy_app::App;
-    use bevy_ecs::event::Events;
+    use bevy_ecs::{event::Events, system::Resource};
     use bevy_state_macros::States;
 
     use super::AppExtStates;
@@ -373,4 +379,43 @@ mod tests {
         assert_eq!(last.exited, None);
         assert_eq!(last.entered, Some(TestState::C));
     }
+
+    #[derive(Resource, Default)]
+    struct ScopedToA;
+
+    #[derive(Resource, Default)]
+    struct ScopedToB;
+
+    #[test]
+    fn state_scoped_resources() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin);
+        app.init_state::<TestState>();
+        app.enable_state_scoped_entities::<TestState>();
+
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+        world
+            .commands()
+            .insert_state_scoped_resource(TestState::A, ScopedToA);
+        world
+            .commands()
+            .init_state_scoped_resource::<_, ScopedToB>(TestState::B);
+        world.flush();
+        assert!(world.contains_resource::<ScopedToA>());
+        assert!(world.contains_resource::<ScopedToB>());
+
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::B);
+        world.run_schedule(StateTransition);
+        assert!(!world.contains_resource::<ScopedToA>());
+        assert!(world.contains_resource::<ScopedToB>());
+
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::C);
+        world.run_schedule(StateTransition);
+        assert!(!world.contains_resource::<ScopedToB>());
+    }
 }
@@ -1,7 +1,13 @@
-use bevy_ecs::{system::Commands, world::World};
+use bevy_ecs::{
+    system::{Commands, Resource},
+    world::{FromWorld, World},
+};
 use log::debug;
 
-use crate::state::{FreelyMutableState, NextState};
+use crate::{
+    state::{FreelyMutableState, NextState, States},
+    state_scoped::StateScopedResources,
+};
 
 /// Extension trait for [`Commands`] adding `bevy_state` helpers.
 pub trait CommandsStatesExt {
@@ -13,6 +19,19 @@ pub trait CommandsStatesExt {
     /// Note that commands introduce sync points to the ECS schedule, so modifying `NextState`
     /// directly may be more efficient depending on your use-case.
     fn set_state<S: FreelyMutableState>(&mut self, state: S);
+
+    /// Inserts `resource`, which is removed when the world's state of type `S` exits `state`.
+    ///
+    /// This requires state-scoped entities to be enabled for `S`, see
+    /// [`StateScopedResources`].
+    fn insert_state_scoped_resource<S: States, R: Resource>(&mut self, state: S, resource: R);
+
+    /// Initializes the resource `R` with its [`FromWorld`] implementation, and removes it when the
+    /// world's state of type `S` exits `state`.
+    ///
+    /// This requires state-scoped entities to be enabled for `S`, see
+    /// [`StateScopedResources`].
+    fn init_state_scoped_resource<S: States, R: Resource + FromWorld>(&mut self, state: S);
 }
 
 impl CommandsStatesExt for Commands<'_, '_> {
@@ -27,4 +46,24 @@ impl CommandsStatesExt for Commands<'_, '_> {
             next.set(state);
         });
     }
+
+    fn insert_state_scoped_resource<S: States, R: Resource>(&mut self, state: S, resource: R) {
+        self.queue(move |w: &mut World| {
+            w.insert_resource(resource);
+            scope_resource::<S, R>(w, state);
+        });
+    }
+
+    fn init_state_scoped_resource<S: States, R: Resource + FromWorld>(&mut self, state: S) {
+        self.queue(move |w: &mut World| {
+            w.init_resource::<R>();
+            scope_resource::<S, R>(w, state);
+        });
+    }
+}
+
+fn scope_resource<S: States, R: Resource>(w: &mut World, state: S) {
+    let id = w.register_resource::<R>();
+    w.get_resource_or_init::<StateScopedResources<S>>()
+        .insert(state, id);
 }
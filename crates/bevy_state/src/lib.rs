@@ -58,8 +58,10 @@ pub mod condition;
 /// Provides definitions for the basic traits required by the state system
 pub mod state;
 
-/// Provides [`StateScoped`](crate::state_scoped::StateScoped) and
-/// [`clear_state_scoped_entities`](crate::state_scoped::clear_state_scoped_entities) for managing lifetime of entities.
+/// Provides [`StateScoped`](crate::state_scoped::StateScoped),
+/// [`clear_state_scoped_entities`](crate::state_scoped::clear_state_scoped_entities) and
+/// [`StateScopedResources`](crate::state_scoped::StateScopedResources) for managing lifetime of
+/// entities and resources.
 pub mod state_scoped;
 #[cfg(feature = "bevy_app")]
 /// Provides [`App`](bevy_app::App) and [`SubApp`](bevy_app::SubApp) with methods for registering
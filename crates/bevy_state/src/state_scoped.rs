@@ -1,15 +1,18 @@
+use alloc::vec::Vec;
 #[cfg(feature = "bevy_reflect")]
 use bevy_ecs::reflect::ReflectComponent;
 use bevy_ecs::{
-    component::Component,
+    component::{Component, ComponentId},
     entity::Entity,
     event::EventReader,
-    system::{Commands, Query},
+    system::{Commands, Query, Resource},
+    world::World,
 };
 #[cfg(feature = "bevy_hierarchy")]
 use bevy_hierarchy::DespawnRecursiveExt;
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::prelude::*;
+use bevy_utils::HashMap;
 
 use crate::state::{StateTransitionEvent, States};
 
@@ -90,3 +93,68 @@ pub fn clear_state_scoped_entities<S: States>(
         }
     }
 }
+
+/// Keeps track of the resources inserted with
+/// [`insert_state_scoped_resource`](crate::commands::CommandsStatesExt::insert_state_scoped_resource),
+/// which are removed when the world's state of type `S` exits the state they are scoped to.
+///
+/// Like [`StateScoped`] entities, state-scoped resources need to be enabled with the
+/// `#[states(scoped_entities)]` attribute or
+/// [`enable_state_scoped_entities`](crate::app::AppExtStates::enable_state_scoped_entities).
+#[derive(Resource)]
+pub struct StateScopedResources<S: States> {
+    resources: HashMap<S, Vec<ComponentId>>,
+}
+
+impl<S: States> Default for StateScopedResources<S> {
+    fn default() -> Self {
+        Self {
+            resources: HashMap::default(),
+        }
+    }
+}
+
+impl<S: States> StateScopedResources<S> {
+    /// Scopes the resource with the given [`ComponentId`] to `state`.
+    pub fn insert(&mut self, state: S, id: ComponentId) {
+        let resources = self.resources.entry(state).or_default();
+        if !resources.contains(&id) {
+            resources.push(id);
+        }
+    }
+
+    /// Returns `true` if the resource with the given [`ComponentId`] is scoped to `state`.
+    pub fn contains(&self, state: &S, id: ComponentId) -> bool {
+        self.resources
+            .get(state)
+            .is_some_and(|resources| resources.contains(&id))
+    }
+}
+
+/// Removes the resources scoped to a state with [`StateScopedResources<S>`]
+/// when the world state exits it.
+pub fn clear_state_scoped_resources<S: States>(
+    mut commands: Commands,
+    mut transitions: EventReader<StateTransitionEvent<S>>,
+) {
+    let Some(transition) = transitions.read().last() else {
+        return;
+    };
+    if transition.entered == transition.exited {
+        return;
+    }
+    let Some(exited) = transition.exited.clone() else {
+        return;
+    };
+    commands.queue(move |world: &mut World| {
+        let Some(resources) = world
+            .get_resource_mut::<StateScopedResources<S>>()
+            .and_then(|mut scoped| scoped.resources.remove(&exited))
+        else {
+            return;
+        };
+        for id in resources {
+            world.remove_resource_by_id(id);
+        }
+    });
+}
@@ -51,6 +51,9 @@ pub mod futures;
 #[cfg(any(feature = "async_executor", feature = "edge_executor"))]
 mod executor;
 
+mod priority;
+pub use priority::TaskPriority;
+
 mod slice;
 pub use slice::{ParallelSlice, ParallelSliceMut};
 
@@ -59,6 +62,14 @@ mod task;
 
 pub use task::Task;
 
+#[cfg(feature = "std")]
+mod spawn;
+#[cfg(feature = "std")]
+pub use spawn::{spawn, spawn_local};
+
+mod tracked_task;
+pub use tracked_task::{CancellationToken, Cancelled, Progress, TaskContext, TrackedTask};
+
 #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
 mod task_pool;
 
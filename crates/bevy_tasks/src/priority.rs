@@ -0,0 +1,192 @@
+/// The priority of a task spawned with `TaskPool::spawn_with_priority`.
+///
+/// Threads of a pool prefer running tasks of higher priority that are ready to make progress:
+/// a task yields to tasks of higher priority waiting to be polled instead of running. To avoid
+/// starving lower priorities while the pool is saturated with work of higher priority, a task
+/// only yields a limited number of times in a row before running anyway.
+///
+/// Priorities only affect the order in which ready tasks are polled; a long running poll is not
+/// interrupted by tasks of higher priority.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TaskPriority {
+    /// Work that should not delay anything else, such as prefetching or caching.
+    Background,
+    /// The default priority.
+    #[default]
+    Normal,
+    /// Latency-sensitive work, such as decoding audio or meshing visible chunks.
+    High,
+}
+
+impl TaskPriority {
+    /// The number of priorities.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+    pub(crate) const COUNT: usize = 3;
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+pub(crate) use lanes::{Lanes, Prioritized};
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+mod lanes {
+    use alloc::{sync::Arc, task::Wake};
+    use core::{
+        future::Future,
+        pin::Pin,
+        sync::atomic::{AtomicU8, AtomicUsize, Ordering},
+        task::{Context, Poll, Waker},
+    };
+    use std::sync::{Mutex, PoisonError};
+
+    use super::TaskPriority;
+
+    /// The number of times in a row a task yields to tasks of higher priority before running
+    /// anyway.
+    const MAX_DEFERRALS: u32 = 16;
+
+    /// Counts the prioritized tasks of a pool that are waiting to be polled, for each priority.
+    #[derive(Clone, Debug, Default)]
+    pub(crate) struct Lanes {
+        queued: Arc<[AtomicUsize; TaskPriority::COUNT]>,
+    }
+
+    impl Lanes {
+        /// Returns `true` if a task of higher priority than `priority` is waiting to be polled.
+        pub(crate) fn higher_queued(&self, priority: TaskPriority) -> bool {
+            self.queued[priority.index() + 1..]
+                .iter()
+                .any(|queued| queued.load(Ordering::Acquire) > 0)
+        }
+    }
+
+    const IDLE: u8 = 0;
+    const QUEUED: u8 = 1;
+    const DONE: u8 = 2;
+
+    /// The waker of a prioritized task, which keeps track of whether the task is queued.
+    struct LaneWaker {
+        lanes: Lanes,
+        priority: TaskPriority,
+        state: AtomicU8,
+        inner: Mutex<Option<Waker>>,
+    }
+
+    impl LaneWaker {
+        fn counter(&self) -> &AtomicUsize {
+            &self.lanes.queued[self.priority.index()]
+        }
+
+        fn enqueue(&self) {
+            if self
+                .state
+                .compare_exchange(IDLE, QUEUED, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                self.counter().fetch_add(1, Ordering::AcqRel);
+            }
+        }
+
+        fn dequeue(&self) {
+            if self
+                .state
+                .compare_exchange(QUEUED, IDLE, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                self.counter().fetch_sub(1, Ordering::AcqRel);
+            }
+        }
+
+        /// Stops counting the task, which may still be woken through clones of its waker.
+        fn finish(&self) {
+            if self.state.swap(DONE, Ordering::AcqRel) == QUEUED {
+                self.counter().fetch_sub(1, Ordering::AcqRel);
+            }
+        }
+    }
+
+    impl Wake for LaneWaker {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.enqueue();
+            let inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+            if let Some(inner) = inner.as_ref() {
+                inner.wake_by_ref();
+            }
+        }
+    }
+
+    /// Wraps the future of a task spawned with a [`TaskPriority`], to yield to tasks of higher
+    /// priority.
+    pub(crate) struct Prioritized<F> {
+        future: F,
+        waker: Arc<LaneWaker>,
+        deferrals: u32,
+    }
+
+    impl<F> Prioritized<F> {
+        pub(crate) fn new(future: F, priority: TaskPriority, lanes: Lanes) -> Self {
+            let waker = Arc::new(LaneWaker {
+                lanes,
+                priority,
+                state: AtomicU8::new(IDLE),
+                inner: Mutex::new(None),
+            });
+            // The task is queued as soon as it is spawned.
+            waker.enqueue();
+            Self {
+                future,
+                waker,
+                deferrals: 0,
+            }
+        }
+    }
+
+    impl<F: Future> Future for Prioritized<F> {
+        type Output = F::Output;
+
+        #[expect(unsafe_code, reason = "Required to pin the wrapped future.")]
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            // SAFETY: `future` is structurally pinned, and the other fields are never pinned.
+            let this = unsafe { self.get_unchecked_mut() };
+            {
+                let mut inner = this
+                    .waker
+                    .inner
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner);
+                match inner.as_mut() {
+                    Some(inner) => inner.clone_from(cx.waker()),
+                    None => *inner = Some(cx.waker().clone()),
+                }
+            }
+            this.waker.dequeue();
+
+            if this.deferrals < MAX_DEFERRALS && this.waker.lanes.higher_queued(this.waker.priority)
+            {
+                this.deferrals += 1;
+                this.waker.wake_by_ref();
+                return Poll::Pending;
+            }
+            this.deferrals = 0;
+
+            let waker = Waker::from(Arc::clone(&this.waker));
+            let mut cx = Context::from_waker(&waker);
+            // SAFETY: `this` is pinned, so `future` is too.
+            unsafe { Pin::new_unchecked(&mut this.future) }.poll(&mut cx)
+        }
+    }
+
+    impl<F> Drop for Prioritized<F> {
+        fn drop(&mut self) {
+            self.waker.finish();
+        }
+    }
+}
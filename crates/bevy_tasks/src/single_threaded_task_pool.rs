@@ -1,7 +1,7 @@
 use alloc::{string::String, vec::Vec};
 use core::{cell::RefCell, future::Future, marker::PhantomData, mem};
 
-use crate::Task;
+use crate::{Task, TaskContext, TaskPriority, TrackedTask};
 
 #[cfg(feature = "std")]
 use std::thread_local;
@@ -150,6 +150,9 @@ impl TaskPool {
         1
     }
 
+    /// No op on the single threaded task pool
+    pub fn resize(&self, _num_threads: usize) {}
+
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
     /// passing a scope object into it. The scope object provided to the callback can be used
     /// to spawn tasks. This function will await the completion of all tasks before returning.
@@ -261,6 +264,32 @@ impl TaskPool {
         };
     }
 
+    /// Spawns a static future with the given [`TaskPriority`].
+    ///
+    /// Since tasks are run on a single thread, the priority is ignored and this is
+    /// equivalent to [`TaskPool::spawn`].
+    pub fn spawn_with_priority<T>(
+        &self,
+        _priority: TaskPriority,
+        future: impl Future<Output = T> + 'static + MaybeSend + MaybeSync,
+    ) -> Task<T>
+    where
+        T: 'static + MaybeSend + MaybeSync,
+    {
+        self.spawn(future)
+    }
+
+    /// Spawns a static future that can be cancelled and report its progress through the given
+    /// [`TaskContext`], see [`TrackedTask`].
+    pub fn spawn_tracked<T, Fut>(&self, f: impl FnOnce(TaskContext) -> Fut) -> TrackedTask<T>
+    where
+        T: 'static + MaybeSend + MaybeSync,
+        Fut: Future<Output = T> + 'static + MaybeSend + MaybeSync,
+    {
+        let context = TaskContext::default();
+        TrackedTask::new(self.spawn(f(context.clone())), context)
+    }
+
     /// Spawns a static future on the JS event loop. This is exactly the same as [`TaskPool::spawn`].
     pub fn spawn_local<T>(
         &self,
@@ -0,0 +1,80 @@
+use core::future::Future;
+
+use crate::{ConditionalSend, Task};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::AsyncComputeTaskPool;
+
+/// Spawns a future in the background, on the [`AsyncComputeTaskPool`](crate::AsyncComputeTaskPool) on native platforms and
+/// with `wasm_bindgen_futures::spawn_local` on the web.
+///
+/// This allows code that needs to run in the background, such as asset loaders or network
+/// clients, to use the same API on every platform. The future doesn't need to be [`Send`] on the
+/// web, where everything runs on the main thread.
+///
+/// The returned [`Task`] can be awaited, polled or detached like any other task. Dropping it
+/// cancels the future.
+///
+/// # Panics
+///
+/// Panics on native platforms if the [`AsyncComputeTaskPool`](crate::AsyncComputeTaskPool) has not been initialized yet, which
+/// is done by `TaskPoolPlugin`.
+pub fn spawn<T>(future: impl Future<Output = T> + ConditionalSend + 'static) -> Task<T>
+where
+    T: ConditionalSend + 'static,
+{
+    #[cfg(target_arch = "wasm32")]
+    return Task::wrap_future(future);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    AsyncComputeTaskPool::get().spawn(future)
+}
+
+/// Spawns a future that is not [`Send`] on the current thread, with
+/// `wasm_bindgen_futures::spawn_local` on the web.
+///
+/// On native platforms, the future runs on the local executor of the current thread. When called
+/// from the main thread of a Bevy app, it is polled every frame by
+/// [`tick_global_task_pools_on_main_thread`](crate::tick_global_task_pools_on_main_thread), which
+/// makes it suitable for futures that hold main-thread-only handles.
+///
+/// # Panics
+///
+/// Panics on native platforms if the [`AsyncComputeTaskPool`](crate::AsyncComputeTaskPool) has not been initialized yet, which
+/// is done by `TaskPoolPlugin`.
+pub fn spawn_local<T: 'static>(future: impl Future<Output = T> + 'static) -> Task<T> {
+    #[cfg(target_arch = "wasm32")]
+    return Task::wrap_future(future);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    AsyncComputeTaskPool::get().spawn_local(future)
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::{
+        block_on, tick_global_task_pools_on_main_thread, ComputeTaskPool, IoTaskPool, TaskPool,
+    };
+    use alloc::rc::Rc;
+
+    #[test]
+    fn spawn_and_spawn_local() {
+        ComputeTaskPool::get_or_init(TaskPool::new);
+        AsyncComputeTaskPool::get_or_init(TaskPool::new);
+        IoTaskPool::get_or_init(TaskPool::new);
+
+        assert_eq!(block_on(spawn(async { 1 })), 1);
+
+        // `Rc` is not `Send`.
+        let value = Rc::new(2);
+        let task = spawn_local(async move { *value });
+        for _ in 0..100 {
+            if task.is_finished() {
+                break;
+            }
+            tick_global_task_pools_on_main_thread();
+        }
+        assert_eq!(block_on(task), 2);
+    }
+}
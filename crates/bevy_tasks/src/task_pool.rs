@@ -1,6 +1,13 @@
 use alloc::{boxed::Box, format, string::String, vec::Vec};
-use core::{future::Future, marker::PhantomData, mem, panic::AssertUnwindSafe};
+use core::{
+    future::Future,
+    marker::PhantomData,
+    mem,
+    panic::AssertUnwindSafe,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use std::{
+    sync::{Mutex, PoisonError},
     thread::{self, JoinHandle},
     thread_local,
 };
@@ -17,8 +24,9 @@ use alloc::sync::Arc;
 
 use crate::{
     block_on,
+    priority::{Lanes, Prioritized},
     thread_executor::{ThreadExecutor, ThreadExecutorTicker},
-    Task,
+    Task, TaskContext, TaskPriority, TrackedTask,
 };
 
 struct CallOnDrop(Option<Arc<dyn Fn() + Send + Sync + 'static>>);
@@ -128,14 +136,46 @@ impl TaskPoolBuilder {
 ///
 /// If the result is not required, one may also use [`Task::detach`] and the pool
 /// will still execute a task, even if it is dropped.
+///
+/// The number of threads of a pool can be changed while it is running with
+/// [`TaskPool::resize`].
 #[derive(Debug)]
 pub struct TaskPool {
     /// The executor for the pool.
     executor: Arc<crate::executor::Executor<'static>>,
+    /// The tasks spawned with a priority waiting to be run.
+    lanes: Lanes,
 
     // The inner state of the pool.
-    threads: Vec<JoinHandle<()>>,
-    shutdown_tx: async_channel::Sender<()>,
+    config: ThreadConfig,
+    threads: Mutex<Workers>,
+    thread_num: AtomicUsize,
+}
+
+/// The options of a [`TaskPoolBuilder`] used to spawn the threads of a pool.
+struct ThreadConfig {
+    stack_size: Option<usize>,
+    thread_name: Option<String>,
+    on_thread_spawn: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+    on_thread_destroy: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+}
+
+impl core::fmt::Debug for ThreadConfig {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ThreadConfig")
+            .field("stack_size", &self.stack_size)
+            .field("thread_name", &self.thread_name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The threads of a pool, each with its own shutdown channel so that they can be stopped
+/// individually.
+#[derive(Debug, Default)]
+struct Workers {
+    /// The index given to the name of the next thread.
+    next_index: usize,
+    workers: Vec<(JoinHandle<()>, async_channel::Sender<()>)>,
 }
 
 impl TaskPool {
@@ -155,72 +195,125 @@ impl TaskPool {
     }
 
     fn new_internal(builder: TaskPoolBuilder) -> Self {
-        let (shutdown_tx, shutdown_rx) = async_channel::unbounded::<()>();
-
-        let executor = Arc::new(crate::executor::Executor::new());
-
         let num_threads = builder
             .num_threads
             .unwrap_or_else(crate::available_parallelism);
 
-        let threads = (0..num_threads)
-            .map(|i| {
-                let ex = Arc::clone(&executor);
-                let shutdown_rx = shutdown_rx.clone();
+        let pool = Self {
+            executor: Arc::new(crate::executor::Executor::new()),
+            lanes: Default::default(),
+            config: ThreadConfig {
+                stack_size: builder.stack_size,
+                thread_name: builder.thread_name,
+                on_thread_spawn: builder.on_thread_spawn,
+                on_thread_destroy: builder.on_thread_destroy,
+            },
+            threads: Mutex::default(),
+            thread_num: AtomicUsize::new(0),
+        };
+        pool.resize(num_threads);
+        pool
+    }
 
-                let thread_name = if let Some(thread_name) = builder.thread_name.as_deref() {
-                    format!("{thread_name} ({i})")
-                } else {
-                    format!("TaskPool ({i})")
-                };
-                let mut thread_builder = thread::Builder::new().name(thread_name);
+    fn spawn_thread(&self, index: usize) -> (JoinHandle<()>, async_channel::Sender<()>) {
+        let (shutdown_tx, shutdown_rx) = async_channel::unbounded::<()>();
+        let ex = Arc::clone(&self.executor);
 
-                if let Some(stack_size) = builder.stack_size {
-                    thread_builder = thread_builder.stack_size(stack_size);
-                }
+        let thread_name = if let Some(thread_name) = self.config.thread_name.as_deref() {
+            format!("{thread_name} ({index})")
+        } else {
+            format!("TaskPool ({index})")
+        };
+        let mut thread_builder = thread::Builder::new().name(thread_name);
 
-                let on_thread_spawn = builder.on_thread_spawn.clone();
-                let on_thread_destroy = builder.on_thread_destroy.clone();
+        if let Some(stack_size) = self.config.stack_size {
+            thread_builder = thread_builder.stack_size(stack_size);
+        }
 
-                thread_builder
-                    .spawn(move || {
-                        TaskPool::LOCAL_EXECUTOR.with(|local_executor| {
-                            if let Some(on_thread_spawn) = on_thread_spawn {
-                                on_thread_spawn();
-                                drop(on_thread_spawn);
-                            }
-                            let _destructor = CallOnDrop(on_thread_destroy);
-                            loop {
-                                let res = std::panic::catch_unwind(|| {
-                                    let tick_forever = async move {
-                                        loop {
-                                            local_executor.tick().await;
-                                        }
-                                    };
-                                    block_on(ex.run(tick_forever.or(shutdown_rx.recv())))
-                                });
-                                if let Ok(value) = res {
-                                    // Use unwrap_err because we expect a Closed error
-                                    value.unwrap_err();
-                                    break;
+        let on_thread_spawn = self.config.on_thread_spawn.clone();
+        let on_thread_destroy = self.config.on_thread_destroy.clone();
+
+        let handle = thread_builder
+            .spawn(move || {
+                TaskPool::LOCAL_EXECUTOR.with(|local_executor| {
+                    if let Some(on_thread_spawn) = on_thread_spawn {
+                        on_thread_spawn();
+                        drop(on_thread_spawn);
+                    }
+                    let _destructor = CallOnDrop(on_thread_destroy);
+                    loop {
+                        let res = std::panic::catch_unwind(|| {
+                            let tick_forever = async move {
+                                loop {
+                                    local_executor.tick().await;
                                 }
-                            }
+                            };
+                            block_on(ex.run(tick_forever.or(shutdown_rx.recv())))
                         });
-                    })
-                    .expect("Failed to spawn thread.")
+                        if let Ok(value) = res {
+                            // Use unwrap_err because we expect a Closed error
+                            value.unwrap_err();
+                            break;
+                        }
+                    }
+                });
             })
-            .collect();
-
-        Self {
-            executor,
-            threads,
-            shutdown_tx,
-        }
+            .expect("Failed to spawn thread.");
+        (handle, shutdown_tx)
     }
 
     /// Return the number of threads owned by the task pool
     pub fn thread_num(&self) -> usize {
-        self.threads.len()
+        self.thread_num.load(Ordering::Relaxed)
+    }
+
+    /// Changes the number of threads owned by the task pool, for example to use less cores when
+    /// running on battery, or to give the cores of a pool to another one once a loading phase
+    /// ends.
+    ///
+    /// New threads are spawned with the options the pool was built with. When shrinking the pool,
+    /// the removed threads finish polling their current task and then stop, and this waits for
+    /// them to do so. The tasks that were waiting to be run keep waiting in the pool and are run
+    /// by the remaining threads, so nothing is lost, but a pool shrunk to zero threads only runs
+    /// its tasks while it is ticked, for example in [`TaskPool::scope`]. Only the tasks spawned
+    /// with [`TaskPool::spawn_local`] on a removed thread are dropped, since no other thread can
+    /// run them.
+    pub fn resize(&self, num_threads: usize) {
+        let removed = {
+            let mut threads = self.lock_threads();
+            while threads.workers.len() < num_threads {
+                let index = threads.next_index;
+                threads.next_index += 1;
+                let worker = self.spawn_thread(index);
+                threads.workers.push(worker);
+            }
+            self.thread_num.store(num_threads, Ordering::Relaxed);
+            threads.workers.split_off(num_threads)
+        };
+        Self::join_threads(removed);
+    }
+
+    fn lock_threads(&self) -> std::sync::MutexGuard<'_, Workers> {
+        self.threads.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn join_threads(workers: Vec<(JoinHandle<()>, async_channel::Sender<()>)>) {
+        for (_, shutdown_tx) in &workers {
+            shutdown_tx.close();
+        }
+
+        let panicking = thread::panicking();
+        let current = thread::current().id();
+        for (join_handle, _) in workers {
+            // A thread stopping itself would wait forever for its own termination.
+            if join_handle.thread().id() == current {
+                continue;
+            }
+            let res = join_handle.join();
+            if !panicking {
+                res.expect("Task thread panicked while executing.");
+            }
+        }
     }
 
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
@@ -423,7 +516,7 @@ impl TaskPool {
                     results
                 };
 
-                let tick_task_pool_executor = tick_task_pool_executor || self.threads.is_empty();
+                let tick_task_pool_executor = tick_task_pool_executor || self.thread_num() == 0;
 
                 // we get this from a thread local so we should always be on the scope executors thread.
                 // note: it is possible `scope_executor` and `external_executor` is the same executor,
@@ -560,6 +653,35 @@ impl TaskPool {
         Task::new(self.executor.spawn(future))
     }
 
+    /// Spawns a static future onto the thread pool with the given [`TaskPriority`], see
+    /// [`TaskPool::spawn`].
+    ///
+    /// Tasks of higher priority that are ready to run are preferred by the threads of the pool,
+    /// so that latency-sensitive work isn't stuck behind background work. Tasks spawned with
+    /// [`TaskPool::spawn`] and scoped tasks are not prioritized: they never yield to prioritized
+    /// tasks, and prioritized tasks don't yield to them.
+    pub fn spawn_with_priority<T>(
+        &self,
+        priority: TaskPriority,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        self.spawn(Prioritized::new(future, priority, self.lanes.clone()))
+    }
+
+    /// Spawns a static future that can be cancelled and report its progress through the given
+    /// [`TaskContext`], see [`TrackedTask`].
+    pub fn spawn_tracked<T, Fut>(&self, f: impl FnOnce(TaskContext) -> Fut) -> TrackedTask<T>
+    where
+        T: Send + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        let context = TaskContext::default();
+        TrackedTask::new(self.spawn(f(context.clone())), context)
+    }
+
     /// Spawns a static future on the thread-local async executor for the
     /// current thread. The task will run entirely on the thread the task was
     /// spawned on.
@@ -605,15 +727,9 @@ impl Default for TaskPool {
 
 impl Drop for TaskPool {
     fn drop(&mut self) {
-        self.shutdown_tx.close();
-
-        let panicking = thread::panicking();
-        for join_handle in self.threads.drain(..) {
-            let res = join_handle.join();
-            if !panicking {
-                res.expect("Task thread panicked while executing.");
-            }
-        }
+        let workers = mem::take(&mut self.lock_threads().workers);
+        self.thread_num.store(0, Ordering::Relaxed);
+        Self::join_threads(workers);
     }
 }
 
@@ -785,6 +901,67 @@ mod tests {
         assert_eq!(-10, counter.load(Ordering::Relaxed));
     }
 
+    #[test]
+    fn test_resize() {
+        let destroyed = Arc::new(AtomicI32::new(0));
+        let end_counter = destroyed.clone();
+        let pool = TaskPoolBuilder::new()
+            .num_threads(2)
+            .on_thread_destroy(move || {
+                end_counter.fetch_add(1, Ordering::Relaxed);
+            })
+            .build();
+
+        pool.resize(6);
+        assert_eq!(pool.thread_num(), 6);
+        pool.resize(1);
+        assert_eq!(pool.thread_num(), 1);
+        // The removed threads have stopped when `resize` returns.
+        assert_eq!(destroyed.load(Ordering::Relaxed), 5);
+
+        // Tasks spawned while the pool has no threads run once it grows again.
+        pool.resize(0);
+        let task = pool.spawn(async { 42 });
+        pool.resize(2);
+        assert_eq!(block_on(task), 42);
+
+        let outputs = pool.scope(|scope| {
+            for i in 0..10 {
+                scope.spawn(async move { i });
+            }
+        });
+        assert_eq!(outputs.iter().sum::<i32>(), 45);
+        drop(pool);
+        assert_eq!(destroyed.load(Ordering::Relaxed), 8);
+    }
+
+    #[test]
+    fn test_spawn_with_priority() {
+        let pool = TaskPoolBuilder::new().num_threads(0).build();
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let spawn = |priority, i| {
+            let order = order.clone();
+            pool.spawn_with_priority(priority, async move {
+                order.lock().unwrap().push(i);
+            })
+        };
+        let mut tasks = Vec::new();
+        for i in 0..4 {
+            tasks.push(spawn(TaskPriority::Background, i));
+        }
+        for i in 4..8 {
+            tasks.push(spawn(TaskPriority::High, i));
+        }
+
+        pool.resize(1);
+        for task in tasks {
+            block_on(task);
+        }
+        let order = order.lock().unwrap();
+        assert!(order[..4].iter().all(|i| *i >= 4), "{order:?}");
+        assert!(!pool.lanes.higher_queued(TaskPriority::Background));
+    }
+
     #[test]
     fn test_mixed_spawn_on_scope_and_spawn() {
         let pool = TaskPool::new();
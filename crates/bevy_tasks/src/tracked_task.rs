@@ -0,0 +1,265 @@
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::{AtomicBool, AtomicU32, Ordering};
+
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+#[cfg(feature = "portable-atomic")]
+use portable_atomic_util::Arc;
+
+#[cfg(not(feature = "portable-atomic"))]
+use alloc::sync::Arc;
+
+use crate::{futures::check_ready, Task};
+
+/// A flag shared between a task and its owner, used to ask the task to stop early.
+///
+/// Cancellation is cooperative: the task is expected to check [`is_cancelled`](Self::is_cancelled)
+/// or [`check`](Self::check) between units of work and return when it has been cancelled, so that
+/// it can release its resources or return partial results. Unlike dropping a [`Task`], this never
+/// interrupts the task in the middle of its work.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a token that has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the cancellation of the tasks holding this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if [`cancel`](Self::cancel) has been called on this token or any of its
+    /// clones.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Returns [`Cancelled`] if this token has been cancelled, to stop a task with `?`.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The error returned by [`CancellationToken::check`] when a task has been cancelled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the task has been cancelled")
+    }
+}
+
+impl core::error::Error for Cancelled {}
+
+/// The progress of a task, between `0.0` and `1.0`, shared between the task and its owner.
+///
+/// The task reports its progress with [`set`](Self::set), which can be read at any time from
+/// another thread, for example by a system displaying a loading bar.
+#[derive(Clone, Debug, Default)]
+pub struct Progress(Arc<AtomicU32>);
+
+impl Progress {
+    /// Creates a progress handle starting at `0.0`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the progress, clamped between `0.0` and `1.0`. NaN is treated as `0.0`.
+    pub fn set(&self, progress: f32) {
+        let progress = if progress.is_nan() {
+            0.0
+        } else {
+            progress.clamp(0.0, 1.0)
+        };
+        self.0.store(progress.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Returns the last progress that has been [`set`](Self::set).
+    pub fn get(&self) -> f32 {
+        f32::from_bits(self.0.load(Ordering::Relaxed))
+    }
+}
+
+/// The handles given to a task spawned with `TaskPool::spawn_tracked`, to observe its
+/// cancellation and report its progress.
+#[derive(Clone, Debug, Default)]
+pub struct TaskContext {
+    /// Whether the owner of the task has asked it to stop.
+    pub cancellation: CancellationToken,
+    /// The progress reported by the task.
+    pub progress: Progress,
+}
+
+impl TaskContext {
+    /// Shorthand for [`CancellationToken::is_cancelled`].
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation.is_cancelled()
+    }
+
+    /// Shorthand for [`CancellationToken::check`].
+    pub fn check(&self) -> Result<(), Cancelled> {
+        self.cancellation.check()
+    }
+
+    /// Shorthand for [`Progress::set`].
+    pub fn set_progress(&self, progress: f32) {
+        self.progress.set(progress);
+    }
+}
+
+/// A [`Task`] that can be cancelled cooperatively and reports its progress.
+///
+/// Tracked tasks are usually created with `TaskPool::spawn_tracked`, and can be polled without
+/// blocking from a system with [`try_take`](Self::try_take):
+///
+/// ```
+/// # use bevy_tasks::{TaskPool, TrackedTask};
+/// let pool = TaskPool::new();
+/// let mut task = pool.spawn_tracked(|context| async move {
+///     let mut sum = 0;
+///     for i in 0..100 {
+///         if context.is_cancelled() {
+///             break;
+///         }
+///         sum += i;
+///         context.set_progress((i + 1) as f32 / 100.0);
+///     }
+///     sum
+/// });
+/// // Later, in a system:
+/// if let Some(sum) = task.try_take() {
+///     // The task is done.
+/// } else {
+///     let progress = task.progress();
+/// }
+/// ```
+///
+/// Like [`Task`], a tracked task is aborted when it is dropped.
+#[derive(Debug)]
+#[must_use = "Tasks are canceled when dropped, use `.detach()` to run them in the background."]
+pub struct TrackedTask<T> {
+    task: Task<T>,
+    context: TaskContext,
+}
+
+impl<T> TrackedTask<T> {
+    /// Wraps a task with the context it has been given.
+    pub fn new(task: Task<T>, context: TaskContext) -> Self {
+        Self { task, context }
+    }
+
+    /// Asks the task to stop. It will still run until it notices its cancellation, and its output
+    /// can then be retrieved as usual.
+    pub fn cancel(&self) {
+        self.context.cancellation.cancel();
+    }
+
+    /// Returns `true` if the task has been asked to stop.
+    pub fn is_cancelled(&self) -> bool {
+        self.context.is_cancelled()
+    }
+
+    /// Returns the progress last reported by the task.
+    pub fn progress(&self) -> f32 {
+        self.context.progress.get()
+    }
+
+    /// Returns the context shared with the task, which can be cloned to be read from elsewhere.
+    pub fn context(&self) -> &TaskContext {
+        &self.context
+    }
+
+    /// Returns `true` if the task has finished running.
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Returns the output of the task if it has finished, without blocking.
+    ///
+    /// The output can only be retrieved once, the task being done afterwards.
+    pub fn try_take(&mut self) -> Option<T> {
+        check_ready(&mut self.task)
+    }
+
+    /// Detaches the task to let it keep running in the background, while keeping the ability to
+    /// cancel it and read its progress through the returned context.
+    pub fn detach(self) -> TaskContext {
+        self.task.detach();
+        self.context
+    }
+
+    /// Returns the underlying [`Task`].
+    pub fn into_inner(self) -> Task<T> {
+        self.task
+    }
+}
+
+impl<T> Future for TrackedTask<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.task).poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "multi_threaded")]
+    #[test]
+    fn cancel_and_report_progress() {
+        let pool = crate::TaskPool::new();
+        let (sender, receiver) = async_channel::unbounded::<()>();
+        let mut task = pool.spawn_tracked(move |context| async move {
+            let mut steps = 0;
+            while context.check().is_ok() {
+                steps += 1;
+                context.set_progress(steps as f32 / 4.0);
+                // Wait for the test to allow the next step.
+                if receiver.recv().await.is_err() {
+                    break;
+                }
+            }
+            steps
+        });
+
+        assert!(!task.is_cancelled());
+        sender.send_blocking(()).unwrap();
+        sender.send_blocking(()).unwrap();
+        while task.progress() < 0.75 {
+            core::hint::spin_loop();
+        }
+        assert!(task.try_take().is_none());
+
+        task.cancel();
+        sender.send_blocking(()).unwrap();
+        assert_eq!(crate::block_on(&mut task), 3);
+        assert!(task.is_cancelled());
+    }
+
+    #[test]
+    fn progress_is_clamped() {
+        let progress = Progress::new();
+        progress.set(2.0);
+        assert_eq!(progress.get(), 1.0);
+        progress.set(f32::NAN);
+        assert_eq!(progress.get(), 0.0);
+    }
+}
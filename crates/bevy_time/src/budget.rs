@@ -0,0 +1,246 @@
+use alloc::collections::VecDeque;
+use bevy_app::{App, Plugin};
+use bevy_ecs::{
+    intern::Interned,
+    schedule::{IntoSystemConfigs, ScheduleLabel, SystemSet},
+    system::{ResMut, Resource},
+};
+use bevy_utils::Instant;
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+/// A per-frame time budget shared by the systems of the system set `S`, to spread expensive work,
+/// such as meshing chunks or finding paths, over several frames instead of causing frame spikes.
+///
+/// The budget starts right before the set runs and ends right after it, as set up by
+/// [`FrameBudgetPlugin`]. Systems in the set check how much of the budget is left with
+/// [`remaining`](Self::remaining) or [`is_exhausted`](Self::is_exhausted), and leave the rest of
+/// their work for the next frames once it is spent, reporting how much work they left with
+/// [`defer`](Self::defer). [`process`](Self::process) does both for a queue of work items.
+///
+/// The budget measures the wall-clock time since the set started, so systems of the set running
+/// in parallel share it, and it is only respected if the systems check it often enough.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_app::prelude::*;
+/// # use bevy_time::{FrameBudget, FrameBudgetPlugin};
+/// # use std::{collections::VecDeque, time::Duration};
+/// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct ChunkMeshing;
+///
+/// #[derive(Resource, Default)]
+/// struct DirtyChunks(VecDeque<u32>);
+///
+/// fn mesh_chunks(budget: Res<FrameBudget<ChunkMeshing>>, mut dirty: ResMut<DirtyChunks>) {
+///     budget.process(&mut dirty.0, |chunk| {
+///         // Mesh the chunk.
+///     });
+/// }
+///
+/// App::new()
+///     .init_resource::<DirtyChunks>()
+///     .add_plugins(FrameBudgetPlugin::new(
+///         Update,
+///         ChunkMeshing,
+///         Duration::from_millis(2),
+///     ))
+///     .add_systems(Update, mesh_chunks.in_set(ChunkMeshing));
+/// ```
+#[derive(Resource, Debug)]
+pub struct FrameBudget<S: Send + Sync + 'static> {
+    budget: Duration,
+    start: Option<Instant>,
+    deferred: AtomicUsize,
+    last_used: Duration,
+    last_deferred: usize,
+    overruns: u64,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<S: Send + Sync + 'static> FrameBudget<S> {
+    /// Creates a budget of the given duration per frame.
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            start: None,
+            deferred: AtomicUsize::new(0),
+            last_used: Duration::ZERO,
+            last_deferred: 0,
+            overruns: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the time the set is allowed to spend each frame.
+    pub fn budget(&self) -> Duration {
+        self.budget
+    }
+
+    /// Sets the time the set is allowed to spend each frame, starting with the next frame.
+    pub fn set_budget(&mut self, budget: Duration) {
+        self.budget = budget;
+    }
+
+    /// Returns the time spent since the set started running this frame, or zero outside of the
+    /// set.
+    pub fn elapsed(&self) -> Duration {
+        self.start.map_or(Duration::ZERO, |start| start.elapsed())
+    }
+
+    /// Returns the time left in the budget of this frame.
+    pub fn remaining(&self) -> Duration {
+        self.budget.saturating_sub(self.elapsed())
+    }
+
+    /// Returns `true` if the budget of this frame has been spent.
+    pub fn is_exhausted(&self) -> bool {
+        self.elapsed() >= self.budget
+    }
+
+    /// Records that `count` work items have been left for the next frames, for diagnostics.
+    pub fn defer(&self, count: usize) {
+        self.deferred.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Calls `f` on the items at the front of `queue` until it is empty or the budget is spent,
+    /// and records the remaining items as [deferred](Self::defer). Returns the number of
+    /// processed items.
+    ///
+    /// At least one item is processed on every call, so that the queue always makes progress even
+    /// if the budget is spent by other systems.
+    pub fn process<T>(&self, queue: &mut VecDeque<T>, mut f: impl FnMut(T)) -> usize {
+        let mut processed = 0;
+        while let Some(item) = queue.pop_front() {
+            f(item);
+            processed += 1;
+            if self.is_exhausted() {
+                break;
+            }
+        }
+        self.defer(queue.len());
+        processed
+    }
+
+    /// Returns the time the set took during the last frame.
+    pub fn last_used(&self) -> Duration {
+        self.last_used
+    }
+
+    /// Returns the number of work items the set deferred to later frames during the last frame.
+    pub fn last_deferred(&self) -> usize {
+        self.last_deferred
+    }
+
+    /// Returns the number of frames during which the set took longer than its budget.
+    pub fn overruns(&self) -> u64 {
+        self.overruns
+    }
+
+    fn begin(&mut self) {
+        self.start = Some(Instant::now());
+        *self.deferred.get_mut() = 0;
+    }
+
+    fn end(&mut self) {
+        self.last_used = self.elapsed();
+        self.last_deferred = *self.deferred.get_mut();
+        if self.last_used > self.budget {
+            self.overruns += 1;
+        }
+        self.start = None;
+    }
+}
+
+/// Gives a [`FrameBudget`] to the system set `S` in the given schedule.
+pub struct FrameBudgetPlugin<S> {
+    schedule: Interned<dyn ScheduleLabel>,
+    set: S,
+    budget: Duration,
+}
+
+impl<S: SystemSet + Clone> FrameBudgetPlugin<S> {
+    /// Creates a plugin giving `budget` per frame to `set` when it runs in `schedule`.
+    pub fn new(schedule: impl ScheduleLabel, set: S, budget: Duration) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            set,
+            budget,
+        }
+    }
+}
+
+impl<S: SystemSet + Clone> Plugin for FrameBudgetPlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(FrameBudget::<S>::new(self.budget))
+            .add_systems(
+                self.schedule,
+                (
+                    begin_frame_budget::<S>.before(self.set.clone()),
+                    end_frame_budget::<S>.after(self.set.clone()),
+                ),
+            );
+    }
+}
+
+/// Starts the [`FrameBudget`] of `S` for this frame.
+pub fn begin_frame_budget<S: Send + Sync + 'static>(mut budget: ResMut<FrameBudget<S>>) {
+    budget.begin();
+}
+
+/// Ends the [`FrameBudget`] of `S` for this frame and records its statistics.
+pub fn end_frame_budget<S: Send + Sync + 'static>(mut budget: ResMut<FrameBudget<S>>) {
+    budget.end();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::Update;
+    use bevy_ecs::system::Res;
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Amortized;
+
+    #[derive(Resource)]
+    struct Work(VecDeque<u32>, Vec<usize>);
+
+    #[test]
+    fn work_is_spread_over_frames() {
+        let mut app = App::new();
+        app.add_plugins(FrameBudgetPlugin::new(
+            Update,
+            Amortized,
+            Duration::from_millis(5),
+        ))
+        .insert_resource(Work((0..20).collect(), Vec::new()))
+        .add_systems(
+            Update,
+            (|budget: Res<FrameBudget<Amortized>>, mut work: ResMut<Work>| {
+                let processed = budget.process(&mut work.0, |_| {
+                    std::thread::sleep(Duration::from_millis(1));
+                });
+                work.1.push(processed);
+            })
+            .in_set(Amortized),
+        );
+
+        app.update();
+        let budget = app.world().resource::<FrameBudget<Amortized>>();
+        assert!(budget.last_used() >= Duration::from_millis(5));
+        assert_eq!(budget.elapsed(), Duration::ZERO);
+        let first_frame = app.world().resource::<Work>().1[0];
+        assert!((1..20).contains(&first_frame));
+        assert_eq!(budget.last_deferred(), 20 - first_frame);
+
+        for _ in 0..20 {
+            app.update();
+        }
+        let work = app.world().resource::<Work>();
+        assert!(work.0.is_empty());
+        assert_eq!(work.1.iter().sum::<usize>(), 20);
+    }
+}
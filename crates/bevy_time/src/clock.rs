@@ -0,0 +1,540 @@
+use alloc::vec::Vec;
+use bevy_app::{App, First, MainScheduleOrder, Plugin};
+use bevy_ecs::{
+    schedule::{
+        InternedScheduleLabel, InternedSystemSet, IntoSystemConfigs, ScheduleLabel, SystemSet,
+    },
+    system::{Res, ResMut},
+};
+use core::{fmt, marker::PhantomData, time::Duration};
+
+use crate::{
+    real::Real,
+    time::Time,
+    time_system,
+    timer_components::add_timer_component_systems,
+    virt::{scale_delta, Virtual},
+    TimeSystem,
+};
+
+/// A named virtual clock, that can be paused and sped up independently of [`Virtual`] time and of
+/// the other clocks.
+///
+/// A specialization of the [`Time`] structure. **For method documentation, see
+/// [`Time<Clock<L>>#impl-Time<Clock<L>>`].**
+///
+/// Normally used as `Time<Clock<L>>`, where `L` is a marker type naming the clock, such as
+/// `Gameplay`, `Ui` or `Cutscene`. The clock is inserted as a resource and updated based on
+/// [`Time<Real>`](Real) by [`ClockPlugin<L>`], during [`First`] right after [`Time<Virtual>`].
+///
+/// Named clocks behave like [`Virtual`] time, with their own pause state, relative speed and
+/// maximum delta. This allows slowing down or freezing parts of the game, for example with a
+/// hit-stop effect on the gameplay clock, while other parts, such as UI animations using their
+/// own clock, keep running at normal speed. Pausing [`Time<Virtual>`] doesn't pause the named
+/// clocks.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::{prelude::*, Clock, ClockPlugin};
+/// struct Gameplay;
+/// struct Ui;
+///
+/// fn hit_stop(mut gameplay: ResMut<Time<Clock<Gameplay>>>) {
+///     gameplay.set_relative_speed(0.1);
+/// }
+///
+/// fn move_enemies(time: Res<Time<Clock<Gameplay>>>) {
+///     // Slowed down by the hit-stop.
+///     let delta = time.delta_secs();
+/// }
+///
+/// fn animate_menu(time: Res<Time<Clock<Ui>>>) {
+///     // Not slowed down.
+///     let delta = time.delta_secs();
+/// }
+///
+/// App::new()
+///     .add_plugins((ClockPlugin::<Gameplay>::default(), ClockPlugin::<Ui>::default()))
+///     .add_systems(Update, (hit_stop, move_enemies, animate_menu));
+/// ```
+///
+/// [`Virtual`]: crate::Virtual
+/// [`Time<Virtual>`]: crate::Virtual
+pub struct Clock<L> {
+    max_delta: Duration,
+    paused: bool,
+    relative_speed: f64,
+    effective_speed: f64,
+    marker: PhantomData<fn() -> L>,
+}
+
+impl<L: Send + Sync + 'static> Time<Clock<L>> {
+    /// Returns the maximum amount of time that can be added to this clock by a single update, as
+    /// [`Duration`]. See [`Time<Virtual>::max_delta`](Time::max_delta).
+    #[inline]
+    pub fn max_delta(&self) -> Duration {
+        self.context().max_delta
+    }
+
+    /// Sets the maximum amount of time that can be added to this clock by a single update, as
+    /// [`Duration`]. See [`Time<Virtual>::set_max_delta`](Time::set_max_delta).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_delta` is zero.
+    #[inline]
+    pub fn set_max_delta(&mut self, max_delta: Duration) {
+        assert_ne!(max_delta, Duration::ZERO, "tried to set max delta to zero");
+        self.context_mut().max_delta = max_delta;
+    }
+
+    /// Returns the speed the clock advances relative to your system clock, as [`f32`].
+    #[inline]
+    pub fn relative_speed(&self) -> f32 {
+        self.relative_speed_f64() as f32
+    }
+
+    /// Returns the speed the clock advances relative to your system clock, as [`f64`].
+    #[inline]
+    pub fn relative_speed_f64(&self) -> f64 {
+        self.context().relative_speed
+    }
+
+    /// Returns the speed the clock advanced relative to your system clock in this update, as
+    /// [`f32`].
+    ///
+    /// Returns `0.0` if the clock was paused or what the `relative_speed` value was at the start
+    /// of this update.
+    #[inline]
+    pub fn effective_speed(&self) -> f32 {
+        self.context().effective_speed as f32
+    }
+
+    /// Returns the speed the clock advanced relative to your system clock in this update, as
+    /// [`f64`].
+    ///
+    /// Returns `0.0` if the clock was paused or what the `relative_speed` value was at the start
+    /// of this update.
+    #[inline]
+    pub fn effective_speed_f64(&self) -> f64 {
+        self.context().effective_speed
+    }
+
+    /// Sets the speed the clock advances relative to your system clock, given as an [`f32`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    #[inline]
+    pub fn set_relative_speed(&mut self, ratio: f32) {
+        self.set_relative_speed_f64(ratio as f64);
+    }
+
+    /// Sets the speed the clock advances relative to your system clock, given as an [`f64`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    #[inline]
+    pub fn set_relative_speed_f64(&mut self, ratio: f64) {
+        assert!(ratio.is_finite(), "tried to go infinitely fast");
+        assert!(ratio >= 0.0, "tried to go back in time");
+        self.context_mut().relative_speed = ratio;
+    }
+
+    /// Stops the clock, preventing it from advancing until resumed.
+    #[inline]
+    pub fn pause(&mut self) {
+        self.context_mut().paused = true;
+    }
+
+    /// Resumes the clock if paused.
+    #[inline]
+    pub fn unpause(&mut self) {
+        self.context_mut().paused = false;
+    }
+
+    /// Returns `true` if the clock is currently paused.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.context().paused
+    }
+
+    /// Returns `true` if the clock was paused at the start of this update.
+    #[inline]
+    pub fn was_paused(&self) -> bool {
+        self.context().effective_speed == 0.0
+    }
+
+    /// Updates the elapsed duration of `self` by `raw_delta`, up to the `max_delta`.
+    fn advance_with_raw_delta(&mut self, raw_delta: Duration) {
+        let context = self.context();
+        let (delta, effective_speed) = scale_delta(
+            raw_delta,
+            context.max_delta,
+            context.paused,
+            context.relative_speed,
+        );
+        self.context_mut().effective_speed = effective_speed;
+        self.advance_by(delta);
+    }
+}
+
+impl<L> Default for Clock<L> {
+    fn default() -> Self {
+        Self {
+            // The same default as `Time<Virtual>`.
+            max_delta: Duration::from_millis(250),
+            paused: false,
+            relative_speed: 1.0,
+            effective_speed: 1.0,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<L> Clone for Clock<L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L> Copy for Clock<L> {}
+
+impl<L> fmt::Debug for Clock<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Clock")
+            .field("max_delta", &self.max_delta)
+            .field("paused", &self.paused)
+            .field("relative_speed", &self.relative_speed)
+            .field("effective_speed", &self.effective_speed)
+            .finish()
+    }
+}
+
+/// Adds the [`Time<Clock<L>>`](Clock) resource and advances it every update, along with the
+/// [timer components](crate::TimerComponent) using it.
+///
+/// The clock can also be assigned to schedules and system sets, so that their systems read it
+/// through the generic [`Time`] resource, without knowing which clock they run on:
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::{prelude::*, Clock, ClockPlugin, TimePlugin};
+/// struct Gameplay;
+/// struct Ui;
+///
+/// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct UiAnimations;
+///
+/// fn slow_motion(mut gameplay: ResMut<Time<Clock<Gameplay>>>) {
+///     gameplay.set_relative_speed(0.25);
+/// }
+///
+/// fn move_enemies(time: Res<Time>) {
+///     // Slowed down, `Update` reads the gameplay clock.
+///     let delta = time.delta_secs();
+/// }
+///
+/// fn animate_menu(time: Res<Time>) {
+///     // Not slowed down, `UiAnimations` reads the UI clock.
+///     let delta = time.delta_secs();
+/// }
+///
+/// App::new()
+///     .add_plugins((
+///         TimePlugin,
+///         ClockPlugin::<Gameplay>::default().with_schedule(Update),
+///         ClockPlugin::<Ui>::default().with_set(PostUpdate, UiAnimations),
+///     ))
+///     .add_systems(Update, (slow_motion, move_enemies))
+///     .add_systems(PostUpdate, animate_menu.in_set(UiAnimations));
+/// ```
+///
+/// Requires the [`TimePlugin`](crate::TimePlugin).
+pub struct ClockPlugin<L> {
+    schedules: Vec<InternedScheduleLabel>,
+    sets: Vec<(InternedScheduleLabel, InternedSystemSet)>,
+    marker: PhantomData<fn() -> L>,
+}
+
+impl<L> Default for ClockPlugin<L> {
+    fn default() -> Self {
+        Self {
+            schedules: Vec::new(),
+            sets: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<L> ClockPlugin<L> {
+    /// Makes the generic [`Time`] report this clock while `schedule` runs.
+    ///
+    /// The schedule must be one of the [`MainScheduleOrder`] schedules, such as
+    /// [`Update`](bevy_app::Update), which are run by the [`Main`](bevy_app::Main) schedule.
+    pub fn with_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        let schedule = schedule.intern();
+        if !self.schedules.contains(&schedule) {
+            self.schedules.push(schedule);
+        }
+        self
+    }
+
+    /// Makes the generic [`Time`] report this clock while the systems in `set` run, in
+    /// `schedule`.
+    ///
+    /// The generic [`Time`] is set to this clock before the set runs, and back to
+    /// [`Time<Virtual>`](Virtual) after it ran, by the [`use_clock_time`] and
+    /// [`use_virtual_time`] systems, in the [`ClockTimeSystems::Use`] and
+    /// [`ClockTimeSystems::Restore`] sets of `set`. Other systems of `schedule` reading [`Time`]
+    /// should run before the former or after the latter, otherwise they may read either clock.
+    pub fn with_set(mut self, schedule: impl ScheduleLabel, set: impl SystemSet) -> Self {
+        let entry = (schedule.intern(), set.intern());
+        if !self.sets.contains(&entry) {
+            self.sets.push(entry);
+        }
+        self
+    }
+}
+
+impl<L: Send + Sync + 'static> Plugin for ClockPlugin<L> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Time<Clock<L>>>().add_systems(
+            First,
+            update_clock_time::<L>.in_set(TimeSystem).after(time_system),
+        );
+        add_timer_component_systems::<Clock<L>>(app);
+
+        for &schedule in &self.schedules {
+            app.add_systems(UseClockTime(schedule), use_clock_time::<L>)
+                .add_systems(UseVirtualTime(schedule), use_virtual_time);
+            let mut order = app.world_mut().resource_mut::<MainScheduleOrder>();
+            // Another clock plugin may have been assigned this schedule already.
+            if !order.labels.contains(&UseClockTime(schedule).intern()) {
+                order.insert_before(schedule, UseClockTime(schedule));
+                order.insert_after(schedule, UseVirtualTime(schedule));
+            }
+        }
+        for &(schedule, set) in &self.sets {
+            app.add_systems(
+                schedule,
+                (
+                    use_clock_time::<L>
+                        .in_set(ClockTimeSystems::Use(set))
+                        .before(set),
+                    use_virtual_time
+                        .in_set(ClockTimeSystems::Restore(set))
+                        .after(set),
+                ),
+            );
+        }
+    }
+}
+
+/// The systems setting the generic [`Time`] around a system set assigned to a [`Clock`] with
+/// [`ClockPlugin::with_set`].
+///
+/// Each set has its own pair of systems, so systems can be ordered around a given set, for
+/// example `.after(ClockTimeSystems::Restore(UiAnimations.intern()))` to read
+/// [`Time<Virtual>`](Virtual) after the `UiAnimations` set.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClockTimeSystems {
+    /// Sets the generic [`Time`] to the clock of the set, before the set runs.
+    Use(InternedSystemSet),
+    /// Sets the generic [`Time`] back to [`Time<Virtual>`](Virtual), after the set ran.
+    Restore(InternedSystemSet),
+}
+
+/// Runs right before a schedule reading a [`Clock`], to set the generic [`Time`] to it.
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct UseClockTime(InternedScheduleLabel);
+
+/// Runs right after a schedule reading a [`Clock`], to set the generic [`Time`] back to
+/// [`Time<Virtual>`](Virtual).
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct UseVirtualTime(InternedScheduleLabel);
+
+/// Sets the generic [`Time`] to [`Time<Clock<L>>`](Clock).
+pub fn use_clock_time<L: Send + Sync + 'static>(
+    mut time: ResMut<Time>,
+    clock: Res<Time<Clock<L>>>,
+) {
+    *time = clock.as_generic();
+}
+
+/// Sets the generic [`Time`] back to [`Time<Virtual>`](Virtual).
+pub fn use_virtual_time(mut time: ResMut<Time>, virtual_time: Res<Time<Virtual>>) {
+    *time = virtual_time.as_generic();
+}
+
+/// Advances [`Time<Clock<L>>`](Clock) based on the elapsed [`Time<Real>`].
+///
+/// The clock will be advanced up to its [`Time::max_delta`].
+pub fn update_clock_time<L: Send + Sync + 'static>(
+    mut clock: ResMut<Time<Clock<L>>>,
+    real: Res<Time<Real>>,
+) {
+    clock.advance_with_raw_delta(real.delta());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TimePlugin, TimeUpdateStrategy};
+    use bevy_app::{PostUpdate, Update};
+    use bevy_ecs::{schedule::IntoSystemSetConfigs, system::Resource};
+
+    struct Gameplay;
+    struct Ui;
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct UiSystems;
+
+    #[derive(Resource, Default)]
+    struct Deltas(Vec<(&'static str, Duration)>);
+
+    #[test]
+    fn clocks_are_independent() {
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            ClockPlugin::<Gameplay>::default(),
+            ClockPlugin::<Ui>::default(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<Time<Clock<Gameplay>>>()
+            .set_relative_speed(0.5);
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.update();
+
+        let world = app.world();
+        let gameplay = world.resource::<Time<Clock<Gameplay>>>();
+        assert_eq!(gameplay.delta(), Duration::from_millis(50));
+        assert_eq!(gameplay.effective_speed(), 0.5);
+        let ui = world.resource::<Time<Clock<Ui>>>();
+        assert_eq!(ui.delta(), Duration::from_millis(100));
+        assert_eq!(ui.elapsed(), Duration::from_millis(100));
+        assert_eq!(world.resource::<Time<Virtual>>().delta(), Duration::ZERO);
+
+        app.world_mut().resource_mut::<Time<Clock<Ui>>>().pause();
+        app.update();
+        let ui = app.world().resource::<Time<Clock<Ui>>>();
+        assert!(ui.was_paused());
+        assert_eq!(ui.delta(), Duration::ZERO);
+        assert_eq!(ui.elapsed(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn schedules_and_sets_read_their_clock() {
+        fn record(name: &'static str) -> impl FnMut(Res<Time>, ResMut<Deltas>) {
+            move |time, mut deltas| deltas.0.push((name, time.delta()))
+        }
+
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            ClockPlugin::<Gameplay>::default().with_schedule(Update),
+            ClockPlugin::<Ui>::default().with_set(PostUpdate, UiSystems),
+        ))
+        .init_resource::<Deltas>()
+        .add_systems(Update, record("update"))
+        .add_systems(
+            PostUpdate,
+            (
+                record("ui").in_set(UiSystems),
+                record("post_update").after(ClockTimeSystems::Restore(UiSystems.intern())),
+            ),
+        )
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<Time<Clock<Gameplay>>>()
+            .set_relative_speed(0.5);
+        app.world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .set_relative_speed(2.0);
+        app.world_mut().resource_mut::<Deltas>().0.clear();
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Deltas>().0,
+            [
+                ("update", Duration::from_millis(50)),
+                ("ui", Duration::from_millis(100)),
+                ("post_update", Duration::from_millis(200)),
+            ]
+        );
+        assert_eq!(
+            app.world().resource::<Time>().delta(),
+            Duration::from_millis(200)
+        );
+    }
+
+    #[test]
+    fn sets_and_schedules_assigned_twice() {
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct HudSystems;
+
+        fn record(name: &'static str) -> impl FnMut(Res<Time>, ResMut<Deltas>) {
+            move |time, mut deltas| deltas.0.push((name, time.delta()))
+        }
+
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            ClockPlugin::<Gameplay>::default()
+                .with_schedule(Update)
+                .with_schedule(Update),
+            ClockPlugin::<Ui>::default()
+                .with_set(PostUpdate, UiSystems)
+                .with_set(PostUpdate, HudSystems),
+        ))
+        .init_resource::<Deltas>()
+        .configure_sets(PostUpdate, UiSystems.before(HudSystems))
+        .add_systems(
+            PostUpdate,
+            (
+                record("ui").in_set(UiSystems),
+                record("between")
+                    .after(ClockTimeSystems::Restore(UiSystems.intern()))
+                    .before(ClockTimeSystems::Use(HudSystems.intern())),
+                record("hud").in_set(HudSystems),
+            ),
+        )
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.update();
+
+        let labels = &app.world().resource::<MainScheduleOrder>().labels;
+        let count = |label: InternedScheduleLabel| labels.iter().filter(|&&l| l == label).count();
+        assert_eq!(count(UseClockTime(Update.intern()).intern()), 1);
+        assert_eq!(count(UseVirtualTime(Update.intern()).intern()), 1);
+
+        app.world_mut()
+            .resource_mut::<Time<Clock<Ui>>>()
+            .set_relative_speed(0.5);
+        app.world_mut().resource_mut::<Deltas>().0.clear();
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Deltas>().0,
+            [
+                ("ui", Duration::from_millis(50)),
+                ("between", Duration::from_millis(100)),
+                ("hud", Duration::from_millis(50)),
+            ]
+        );
+    }
+}
@@ -1,5 +1,10 @@
-use bevy_app::FixedMain;
-use bevy_ecs::world::World;
+use alloc::vec::Vec;
+use bevy_app::{App, FixedMain, Plugin, RunFixedMainLoop, RunFixedMainLoopSystem};
+use bevy_ecs::{
+    schedule::{InternedScheduleLabel, IntoSystemConfigs, ScheduleLabel},
+    system::Resource,
+    world::World,
+};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
 use core::time::Duration;
@@ -251,6 +256,149 @@ pub(super) fn run_fixed_main_schedule(world: &mut World) {
     *world.resource_mut::<Time>() = world.resource::<Time<Virtual>>().as_generic();
 }
 
+/// The fixed timestep clocks of the schedules added with [`FixedSchedulePlugin`], each
+/// running at its own rate, independently of [`FixedMain`] and [`Time<Fixed>`](Fixed).
+///
+/// The clocks can be read to get the [`overstep()`](Time::overstep) of a schedule, for example to
+/// interpolate what it simulates, or modified to change its [`timestep()`](Time::timestep).
+#[derive(Resource, Default, Debug)]
+pub struct FixedSchedules {
+    clocks: Vec<(InternedScheduleLabel, Time<Fixed>)>,
+}
+
+impl FixedSchedules {
+    /// Returns the clock of the fixed schedule with the given `label`, if there is one.
+    pub fn get(&self, label: impl ScheduleLabel) -> Option<&Time<Fixed>> {
+        let label = label.intern();
+        self.clocks
+            .iter()
+            .find_map(|(schedule, time)| (*schedule == label).then_some(time))
+    }
+
+    /// Returns the clock of the fixed schedule with the given `label` mutably, if there is one.
+    pub fn get_mut(&mut self, label: impl ScheduleLabel) -> Option<&mut Time<Fixed>> {
+        let label = label.intern();
+        self.clocks
+            .iter_mut()
+            .find_map(|(schedule, time)| (*schedule == label).then_some(time))
+    }
+
+    /// Runs the schedule with the given `label` on a fixed timestep following `time`, replacing
+    /// its clock if it already had one.
+    pub fn insert(&mut self, label: impl ScheduleLabel, time: Time<Fixed>) {
+        match self.get_mut(label.intern()) {
+            Some(clock) => *clock = time,
+            None => self.clocks.push((label.intern(), time)),
+        }
+    }
+
+    /// Stops running the schedule with the given `label`, and returns its clock.
+    pub fn remove(&mut self, label: impl ScheduleLabel) -> Option<Time<Fixed>> {
+        let label = label.intern();
+        let index = self
+            .clocks
+            .iter()
+            .position(|(schedule, _)| *schedule == label)?;
+        Some(self.clocks.remove(index).1)
+    }
+
+    /// Returns an iterator over the labels of the fixed schedules and their clocks.
+    pub fn iter(&self) -> impl Iterator<Item = (InternedScheduleLabel, &Time<Fixed>)> {
+        self.clocks.iter().map(|(label, time)| (*label, time))
+    }
+}
+
+/// Runs a schedule on its own fixed timestep, in addition to [`FixedMain`].
+///
+/// This lets logic with different needs run at different rates, such as physics at 60 Hz, AI at
+/// 10 Hz and autosaves every minute, instead of running everything at the rate of
+/// [`FixedUpdate`](bevy_app::FixedUpdate). The schedule runs after [`FixedMain`], zero or more
+/// times per update, like it. While it runs, the generic [`Time`] resource reports its clock,
+/// which is stored in the [`FixedSchedules`] resource. Requires the [`TimePlugin`](crate::TimePlugin).
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// # use bevy_time::{FixedSchedulePlugin, TimePlugin};
+/// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct AiUpdate;
+///
+/// fn plan_paths() {}
+///
+/// App::new()
+///     .add_plugins((TimePlugin, FixedSchedulePlugin::from_hz(AiUpdate, 10.0)))
+///     .add_systems(AiUpdate, plan_paths);
+/// ```
+pub struct FixedSchedulePlugin {
+    label: InternedScheduleLabel,
+    timestep: Duration,
+}
+
+impl FixedSchedulePlugin {
+    /// Runs the schedule with the given `label` every `timestep`.
+    pub fn new(label: impl ScheduleLabel, timestep: Duration) -> Self {
+        Self {
+            label: label.intern(),
+            timestep,
+        }
+    }
+
+    /// Runs the schedule with the given `label` `hz` times per second.
+    pub fn from_hz(label: impl ScheduleLabel, hz: f64) -> Self {
+        Self::new(label, Time::<Fixed>::from_hz(hz).timestep())
+    }
+}
+
+impl Plugin for FixedSchedulePlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<FixedSchedules>() {
+            app.init_resource::<FixedSchedules>().add_systems(
+                RunFixedMainLoop,
+                run_fixed_schedules
+                    .in_set(RunFixedMainLoopSystem::FixedMainLoop)
+                    .after(run_fixed_main_schedule),
+            );
+        }
+        app.init_schedule(self.label);
+        app.world_mut()
+            .resource_mut::<FixedSchedules>()
+            .insert(self.label, Time::<Fixed>::from_duration(self.timestep));
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+/// Runs each schedule of [`FixedSchedules`] zero or more times based on delta of
+/// [`Time<Virtual>`](Virtual) and the [`Time::overstep`] of its clock.
+fn run_fixed_schedules(world: &mut World) {
+    let delta = world.resource::<Time<Virtual>>().delta();
+    let mut schedules = world.resource_mut::<FixedSchedules>();
+    for (_, time) in &mut schedules.clocks {
+        time.accumulate(delta);
+    }
+    let labels: Vec<_> = schedules.clocks.iter().map(|(label, _)| *label).collect();
+
+    for label in labels {
+        let _ = world.try_schedule_scope(label, |world, schedule| loop {
+            let mut schedules = world.resource_mut::<FixedSchedules>();
+            let Some(time) = schedules.get_mut(label) else {
+                break;
+            };
+            if !time.expend() {
+                break;
+            }
+            let time = time.as_generic();
+            *world.resource_mut::<Time>() = time;
+            schedule.run(world);
+        });
+    }
+
+    *world.resource_mut::<Time>() = world.resource::<Time<Virtual>>().as_generic();
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -11,6 +11,10 @@
     html_favicon_url = "https://bevyengine.org/assets/icon.png"
 )]
 
+extern crate alloc;
+
+mod budget;
+mod clock;
 /// Common run conditions
 pub mod common_conditions;
 mod fixed;
@@ -18,13 +22,17 @@ mod real;
 mod stopwatch;
 mod time;
 mod timer;
+mod timer_components;
 mod virt;
 
+pub use budget::*;
+pub use clock::*;
 pub use fixed::*;
 pub use real::*;
 pub use stopwatch::*;
 pub use time::*;
 pub use timer::*;
+pub use timer_components::*;
 pub use virt::*;
 
 /// The time prelude.
@@ -32,7 +40,7 @@ pub use virt::*;
 /// This includes the most common types in this crate, re-exported for your convenience.
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{Fixed, Real, Time, Timer, TimerMode, Virtual};
+    pub use crate::{Clock, ClockPlugin, Fixed, Real, Time, Timer, TimerMode, Virtual};
 }
 
 use bevy_app::{prelude::*, RunFixedMainLoop};
@@ -44,6 +52,7 @@ use bevy_utils::Instant;
 use core::time::Duration;
 pub use crossbeam_channel::TrySendError;
 use crossbeam_channel::{Receiver, Sender};
+use timer_components::add_timer_component_systems;
 use tracing::warn;
 
 /// Adds time functionality to Apps.
@@ -82,6 +91,8 @@ impl Plugin for TimePlugin {
             RunFixedMainLoop,
             run_fixed_main_schedule.in_set(RunFixedMainLoopSystem::FixedMainLoop),
         );
+        add_timer_component_systems::<Virtual>(app);
+        add_timer_component_systems::<Real>(app);
 
         // Ensure the events are not dropped until `FixedMain` systems can observe them
         app.add_systems(FixedPostUpdate, signal_event_update_system);
@@ -162,10 +173,13 @@ pub fn time_system(
 
 #[cfg(test)]
 mod tests {
-    use crate::{Fixed, Time, TimePlugin, TimeUpdateStrategy, Virtual};
+    use crate::{
+        Fixed, FixedSchedulePlugin, FixedSchedules, Time, TimePlugin, TimeUpdateStrategy, Virtual,
+    };
     use bevy_app::{App, FixedUpdate, Startup, Update};
     use bevy_ecs::{
         event::{Event, EventReader, EventRegistry, EventWriter, Events, ShouldUpdateEvents},
+        schedule::ScheduleLabel,
         system::{Local, Res, ResMut, Resource},
     };
     use core::error::Error;
@@ -269,6 +283,69 @@ mod tests {
         assert_eq!(counter.0, 2, "Fixed update should have run twice");
     }
 
+    #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct FastUpdate;
+
+    #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct SlowUpdate;
+
+    #[derive(Resource, Default)]
+    struct FixedScheduleCounters {
+        fast: u8,
+        slow: u8,
+    }
+
+    #[test]
+    fn fixed_schedules_run_at_their_own_rate() {
+        let time_step = Duration::from_millis(10);
+
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            FixedSchedulePlugin::new(FastUpdate, Duration::from_millis(10)),
+            FixedSchedulePlugin::new(SlowUpdate, Duration::from_millis(25)),
+        ))
+        .add_systems(
+            FastUpdate,
+            |time: Res<Time>, mut counters: ResMut<FixedScheduleCounters>| {
+                assert_eq!(time.delta(), Duration::from_millis(10));
+                counters.fast += 1;
+            },
+        )
+        .add_systems(
+            SlowUpdate,
+            |time: Res<Time>, mut counters: ResMut<FixedScheduleCounters>| {
+                assert_eq!(time.delta(), Duration::from_millis(25));
+                counters.slow += 1;
+            },
+        )
+        .init_resource::<FixedScheduleCounters>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(time_step));
+
+        // The first update has no delta, so 40ms have elapsed after 5 updates.
+        for _ in 0..5 {
+            app.update();
+        }
+
+        let counters = app.world().resource::<FixedScheduleCounters>();
+        assert_eq!(counters.fast, 4);
+        assert_eq!(counters.slow, 1);
+
+        let schedules = app.world().resource::<FixedSchedules>();
+        assert_eq!(
+            schedules.get(FastUpdate).unwrap().overstep(),
+            Duration::ZERO
+        );
+        assert_eq!(
+            schedules.get(SlowUpdate).unwrap().overstep(),
+            Duration::from_millis(15)
+        );
+        assert_eq!(
+            app.world().resource::<Time>().delta(),
+            app.world().resource::<Time<Virtual>>().delta()
+        );
+    }
+
     #[test]
     fn events_get_dropped_regression_test_11528() -> Result<(), impl Error> {
         let (tx1, rx1) = std::sync::mpsc::channel();
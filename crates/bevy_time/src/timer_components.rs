@@ -0,0 +1,371 @@
+use bevy_app::{App, First};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::Event,
+    schedule::{IntoSystemConfigs, SystemSet},
+    system::{Commands, Query, Res},
+};
+use core::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+
+use crate::{Stopwatch, Time, TimeSystem, Timer, TimerMode, Virtual};
+
+/// A [`Timer`] component, ticked every update by the clock `T`, that triggers a [`TimerFinished`]
+/// event on its entity each time it finishes.
+///
+/// The timer is ticked with the delta of [`Time<T>`], which defaults to [`Time<Virtual>`], so
+/// that it is paused and scaled along with its clock. [`Time<Real>`](crate::Real) and the
+/// [named clocks](crate::Clock) can be used as well.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::{TimerComponent, TimerFinished, TimerMode};
+/// fn spawn_bomb(mut commands: Commands) {
+///     commands
+///         .spawn(TimerComponent::from_seconds(3.0, TimerMode::Once))
+///         .observe(|trigger: Trigger<TimerFinished>, mut commands: Commands| {
+///             commands.entity(trigger.target()).despawn();
+///         });
+/// }
+/// # bevy_ecs::system::assert_is_system(spawn_bomb);
+/// ```
+#[derive(Component)]
+pub struct TimerComponent<T: Default + Send + Sync + 'static = Virtual> {
+    timer: Timer,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl TimerComponent {
+    /// Creates a new timer using [`Time<Virtual>`], see [`Timer::new`].
+    pub fn new(duration: Duration, mode: TimerMode) -> Self {
+        Timer::new(duration, mode).into()
+    }
+
+    /// Creates a new timer using [`Time<Virtual>`] with a given duration in seconds, see
+    /// [`Timer::from_seconds`].
+    pub fn from_seconds(duration: f32, mode: TimerMode) -> Self {
+        Timer::from_seconds(duration, mode).into()
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> TimerComponent<T> {
+    /// Creates a timer component using the clock `T`, for example
+    /// `TimerComponent::<Real>::with_clock(timer)`.
+    pub fn with_clock(timer: Timer) -> Self {
+        timer.into()
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> From<Timer> for TimerComponent<T> {
+    fn from(timer: Timer) -> Self {
+        Self {
+            timer,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// A [`Stopwatch`] component, ticked every update by the clock `T`.
+///
+/// Like [`TimerComponent`], the stopwatch is ticked with the delta of [`Time<T>`], which defaults
+/// to [`Time<Virtual>`].
+#[derive(Component)]
+pub struct StopwatchComponent<T: Default + Send + Sync + 'static = Virtual> {
+    stopwatch: Stopwatch,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl StopwatchComponent {
+    /// Creates a new stopwatch using [`Time<Virtual>`], starting at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> Default for StopwatchComponent<T> {
+    fn default() -> Self {
+        Stopwatch::new().into()
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> From<Stopwatch> for StopwatchComponent<T> {
+    fn from(stopwatch: Stopwatch) -> Self {
+        Self {
+            stopwatch,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// A cooldown component, ticked every update by the clock `T`, that triggers a [`CooldownReady`]
+/// event on its entity when it becomes ready again.
+///
+/// A cooldown starts ready. Using it with [`try_use`](Self::try_use) makes it unavailable for its
+/// duration, as measured by [`Time<T>`], which defaults to [`Time<Virtual>`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::Cooldown;
+/// #[derive(Component)]
+/// struct Weapon;
+///
+/// fn fire(mut weapons: Query<&mut Cooldown, With<Weapon>>) {
+///     for mut cooldown in &mut weapons {
+///         if cooldown.try_use() {
+///             // Spawn a projectile.
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(fire);
+/// ```
+#[derive(Component)]
+pub struct Cooldown<T: Default + Send + Sync + 'static = Virtual> {
+    timer: Timer,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl Cooldown {
+    /// Creates a ready cooldown using [`Time<Virtual>`] with the given duration.
+    pub fn new(duration: Duration) -> Self {
+        Self::with_clock(duration)
+    }
+
+    /// Creates a ready cooldown using [`Time<Virtual>`] with the given duration in seconds.
+    pub fn from_seconds(duration: f32) -> Self {
+        Self::new(Duration::from_secs_f32(duration))
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> Cooldown<T> {
+    /// Creates a ready cooldown using the clock `T` with the given duration, for example
+    /// `Cooldown::<Real>::with_clock(duration)`.
+    pub fn with_clock(duration: Duration) -> Self {
+        let mut cooldown = Self {
+            timer: Timer::new(duration, TimerMode::Once),
+            marker: PhantomData,
+        };
+        cooldown.make_ready();
+        cooldown
+    }
+
+    /// Returns `true` if the cooldown is ready to be used.
+    pub fn is_ready(&self) -> bool {
+        self.timer.finished()
+    }
+
+    /// Uses the cooldown if it is ready, making it unavailable for its duration. Returns `false`
+    /// if it wasn't ready.
+    pub fn try_use(&mut self) -> bool {
+        let ready = self.is_ready();
+        if ready {
+            self.timer.reset();
+        }
+        ready
+    }
+
+    /// Makes the cooldown ready immediately. This doesn't trigger [`CooldownReady`].
+    pub fn make_ready(&mut self) {
+        let duration = self.timer.duration();
+        self.timer.set_elapsed(duration);
+        // Ticking updates the finished state of the timer.
+        self.timer.tick(Duration::ZERO);
+    }
+
+    /// Returns the time left before the cooldown is ready.
+    pub fn remaining(&self) -> Duration {
+        self.timer.remaining()
+    }
+
+    /// Returns the fraction of the cooldown that has elapsed, from `0.0` when it was just used to
+    /// `1.0` when it is ready.
+    pub fn fraction(&self) -> f32 {
+        self.timer.fraction()
+    }
+
+    /// Returns the duration of the cooldown.
+    pub fn duration(&self) -> Duration {
+        self.timer.duration()
+    }
+
+    /// Sets the duration of the cooldown, keeping the time elapsed since it was used.
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.timer.set_duration(duration);
+    }
+}
+
+macro_rules! impl_clocked {
+    ($component:ident, $field:ident: $inner:ty) => {
+        impl<T: Default + Send + Sync + 'static> Deref for $component<T> {
+            type Target = $inner;
+
+            fn deref(&self) -> &$inner {
+                &self.$field
+            }
+        }
+
+        impl<T: Default + Send + Sync + 'static> DerefMut for $component<T> {
+            fn deref_mut(&mut self) -> &mut $inner {
+                &mut self.$field
+            }
+        }
+
+        impl<T: Default + Send + Sync + 'static> Clone for $component<T> {
+            fn clone(&self) -> Self {
+                Self {
+                    $field: self.$field.clone(),
+                    marker: PhantomData,
+                }
+            }
+        }
+
+        impl<T: Default + Send + Sync + 'static> fmt::Debug for $component<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($component))
+                    .field(&self.$field)
+                    .finish()
+            }
+        }
+    };
+}
+
+impl_clocked!(TimerComponent, timer: Timer);
+impl_clocked!(StopwatchComponent, stopwatch: Stopwatch);
+
+impl<T: Default + Send + Sync + 'static> Clone for Cooldown<T> {
+    fn clone(&self) -> Self {
+        Self {
+            timer: self.timer.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> fmt::Debug for Cooldown<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Cooldown").field(&self.timer).finish()
+    }
+}
+
+/// Triggered on the entity of a [`TimerComponent`] each time it finishes.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimerFinished {
+    /// The number of times the timer finished during the update, which can be more than one for
+    /// a repeating timer with a short duration.
+    pub times: u32,
+}
+
+/// Triggered on the entity of a [`Cooldown`] when it becomes ready again.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CooldownReady;
+
+/// The systems ticking the [`TimerComponent`], [`StopwatchComponent`] and [`Cooldown`]
+/// components, which run in [`First`] after [`TimeSystem`].
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub struct TimerComponentSystems;
+
+/// Ticks the timer components of the clock `T`, triggering [`TimerFinished`] and
+/// [`CooldownReady`] events.
+pub fn tick_timer_components<T: Default + Send + Sync + 'static>(
+    time: Res<Time<T>>,
+    mut timers: Query<(Entity, &mut TimerComponent<T>)>,
+    mut stopwatches: Query<&mut StopwatchComponent<T>>,
+    mut cooldowns: Query<(Entity, &mut Cooldown<T>)>,
+    mut commands: Commands,
+) {
+    let delta = time.delta();
+    for (entity, mut timer) in &mut timers {
+        let times = timer.tick(delta).times_finished_this_tick();
+        if times > 0 {
+            commands.trigger_targets(TimerFinished { times }, entity);
+        }
+    }
+    for mut stopwatch in &mut stopwatches {
+        stopwatch.tick(delta);
+    }
+    for (entity, mut cooldown) in &mut cooldowns {
+        if !cooldown.is_ready() && cooldown.timer.tick(delta).just_finished() {
+            commands.trigger_targets(CooldownReady, entity);
+        }
+    }
+}
+
+/// Adds [`tick_timer_components`] for the clock `T` to the app.
+pub(crate) fn add_timer_component_systems<T: Default + Send + Sync + 'static>(app: &mut App) {
+    app.add_systems(
+        First,
+        tick_timer_components::<T>
+            .in_set(TimerComponentSystems)
+            .after(TimeSystem),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Real, TimePlugin, TimeUpdateStrategy};
+    use bevy_ecs::{observer::Trigger, system::ResMut, world::World};
+
+    #[derive(bevy_ecs::system::Resource, Default)]
+    struct Finished(u32, u32);
+
+    #[test]
+    fn timers_follow_their_clock() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .init_resource::<Finished>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+
+        let world: &mut World = app.world_mut();
+        let virtual_timer = world
+            .spawn(TimerComponent::<Virtual>::from_seconds(
+                0.25,
+                TimerMode::Repeating,
+            ))
+            .observe(
+                |_: Trigger<TimerFinished>, mut finished: ResMut<Finished>| {
+                    finished.0 += 1;
+                },
+            )
+            .id();
+        let cooldown = world
+            .spawn(Cooldown::<Real>::with_clock(Duration::from_millis(150)))
+            .observe(
+                |_: Trigger<CooldownReady>, mut finished: ResMut<Finished>| {
+                    finished.1 += 1;
+                },
+            )
+            .id();
+        assert!(world.get_mut::<Cooldown<Real>>(cooldown).unwrap().try_use());
+        assert!(!world.get_mut::<Cooldown<Real>>(cooldown).unwrap().try_use());
+
+        // The first update starts the clocks, the next ones advance them by 100 ms.
+        app.update();
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Finished>().1, 1);
+        assert!(app
+            .world()
+            .get::<Cooldown<Real>>(cooldown)
+            .unwrap()
+            .is_ready());
+
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Finished>().0, 0);
+        let timer = app.world().get::<TimerComponent>(virtual_timer).unwrap();
+        assert_eq!(timer.elapsed(), Duration::from_millis(200));
+
+        app.world_mut().resource_mut::<Time<Virtual>>().unpause();
+        app.update();
+        assert_eq!(app.world().resource::<Finished>().0, 1);
+        assert_eq!(app.world().resource::<Finished>().1, 1);
+    }
+}
@@ -229,33 +229,46 @@ impl Time<Virtual> {
 
     /// Updates the elapsed duration of `self` by `raw_delta`, up to the `max_delta`.
     fn advance_with_raw_delta(&mut self, raw_delta: Duration) {
-        let max_delta = self.context().max_delta;
-        let clamped_delta = if raw_delta > max_delta {
-            debug!(
-                "delta time larger than maximum delta, clamping delta to {:?} and skipping {:?}",
-                max_delta,
-                raw_delta - max_delta
-            );
-            max_delta
-        } else {
-            raw_delta
-        };
-        let effective_speed = if self.context().paused {
-            0.0
-        } else {
-            self.context().relative_speed
-        };
-        let delta = if effective_speed != 1.0 {
-            clamped_delta.mul_f64(effective_speed)
-        } else {
-            // avoid rounding when at normal speed
-            clamped_delta
-        };
+        let context = self.context();
+        let (delta, effective_speed) = scale_delta(
+            raw_delta,
+            context.max_delta,
+            context.paused,
+            context.relative_speed,
+        );
         self.context_mut().effective_speed = effective_speed;
         self.advance_by(delta);
     }
 }
 
+/// Clamps `raw_delta` to `max_delta` and scales it by the speed of a clock, returning the scaled
+/// delta and the speed it was scaled by.
+pub(crate) fn scale_delta(
+    raw_delta: Duration,
+    max_delta: Duration,
+    paused: bool,
+    relative_speed: f64,
+) -> (Duration, f64) {
+    let clamped_delta = if raw_delta > max_delta {
+        debug!(
+            "delta time larger than maximum delta, clamping delta to {:?} and skipping {:?}",
+            max_delta,
+            raw_delta - max_delta
+        );
+        max_delta
+    } else {
+        raw_delta
+    };
+    let effective_speed = if paused { 0.0 } else { relative_speed };
+    let delta = if effective_speed != 1.0 {
+        clamped_delta.mul_f64(effective_speed)
+    } else {
+        // avoid rounding when at normal speed
+        clamped_delta
+    };
+    (delta, effective_speed)
+}
+
 impl Default for Virtual {
     fn default() -> Self {
         Self {
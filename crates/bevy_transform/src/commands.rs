@@ -2,11 +2,20 @@
 //! while preserving [`GlobalTransform`].
 
 use crate::prelude::{GlobalTransform, Transform};
-use bevy_ecs::{entity::Entity, system::EntityCommands, world::EntityWorldMut};
-use bevy_hierarchy::BuildChildren;
+use bevy_ecs::{
+    entity::Entity,
+    system::EntityCommands,
+    world::{EntityWorldMut, World},
+};
+use bevy_hierarchy::{BuildChildren, Parent};
 
 /// Collection of methods similar to [`BuildChildren`], but preserving each
 /// entity's [`GlobalTransform`].
+///
+/// The global transforms are computed from the [`Transform`]s of the entities and of their
+/// ancestors when the hierarchy is changed, so they are preserved even if a [`Transform`] changed
+/// since the last transform propagation, for example when picking up an object that was moved
+/// during the same frame.
 pub trait BuildChildrenTransformExt {
     /// Change this entity's parent while preserving this entity's [`GlobalTransform`]
     /// by updating its [`Transform`].
@@ -27,6 +36,32 @@ pub trait BuildChildrenTransformExt {
     /// the next time commands are applied
     /// (during [`ApplyDeferred`](bevy_ecs::schedule::ApplyDeferred)).
     fn remove_parent_in_place(&mut self) -> &mut Self;
+
+    /// Add `child` as a child of this entity while preserving the child's [`GlobalTransform`]
+    /// by updating its [`Transform`].
+    ///
+    /// See [`BuildChildren::add_child`] for a method that doesn't update the [`Transform`].
+    ///
+    /// Note that both the hierarchy and transform updates will only execute
+    /// the next time commands are applied
+    /// (during [`ApplyDeferred`](bevy_ecs::schedule::ApplyDeferred)).
+    fn add_child_in_place(&mut self, child: Entity) -> &mut Self;
+}
+
+/// Computes the [`GlobalTransform`] of `entity` from its [`Transform`] and those of its ancestors,
+/// falling back to the [`GlobalTransform`] of the first of them without a [`Transform`].
+fn current_global_transform(world: &World, entity: Entity) -> Option<GlobalTransform> {
+    let mut global_transform = GlobalTransform::IDENTITY;
+    let mut next = Some(entity);
+    while let Some(entity) = next {
+        let entity = world.get_entity(entity).ok()?;
+        let Some(transform) = entity.get::<Transform>() else {
+            return Some(*entity.get::<GlobalTransform>()? * global_transform);
+        };
+        global_transform = *transform * global_transform;
+        next = entity.get::<Parent>().map(Parent::get);
+    }
+    Some(global_transform)
 }
 
 impl BuildChildrenTransformExt for EntityCommands<'_> {
@@ -41,17 +76,24 @@ impl BuildChildrenTransformExt for EntityCommands<'_> {
             entity.remove_parent_in_place();
         })
     }
+
+    fn add_child_in_place(&mut self, child: Entity) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.add_child_in_place(child);
+        })
+    }
 }
 
 impl BuildChildrenTransformExt for EntityWorldMut<'_> {
     fn set_parent_in_place(&mut self, parent: Entity) -> &mut Self {
         let child = self.id();
         self.world_scope(|world| {
+            let child_global = current_global_transform(world, child);
             world.entity_mut(parent).add_child(child);
             // FIXME: Replace this closure with a `try` block. See: https://github.com/rust-lang/rust/issues/31436.
             let mut update_transform = || {
-                let parent = *world.get_entity(parent).ok()?.get::<GlobalTransform>()?;
-                let child_global = *world.get_entity(child).ok()?.get::<GlobalTransform>()?;
+                let parent = current_global_transform(world, parent)?;
+                let child_global = child_global?;
                 let mut child_entity = world.get_entity_mut(child).ok()?;
                 let mut child = child_entity.get_mut::<Transform>()?;
                 *child = child_global.reparented_to(&parent);
@@ -65,10 +107,11 @@ impl BuildChildrenTransformExt for EntityWorldMut<'_> {
     fn remove_parent_in_place(&mut self) -> &mut Self {
         let child = self.id();
         self.world_scope(|world| {
+            let child_global = current_global_transform(world, child);
             world.entity_mut(child).remove_parent();
             // FIXME: Replace this closure with a `try` block. See: https://github.com/rust-lang/rust/issues/31436.
             let mut update_transform = || {
-                let child_global = *world.get_entity(child).ok()?.get::<GlobalTransform>()?;
+                let child_global = child_global?;
                 let mut child_entity = world.get_entity_mut(child).ok()?;
                 let mut child = child_entity.get_mut::<Transform>()?;
                 *child = child_global.compute_transform();
@@ -78,4 +121,63 @@ impl BuildChildrenTransformExt for EntityWorldMut<'_> {
         });
         self
     }
+
+    fn add_child_in_place(&mut self, child: Entity) -> &mut Self {
+        let parent = self.id();
+        self.world_scope(|world| {
+            world.entity_mut(child).set_parent_in_place(parent);
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::{mark_dirty_trees, propagate_transforms, sync_simple_transforms};
+    use bevy_ecs::schedule::{IntoSystemConfigs, Schedule};
+    use bevy_math::{Quat, Vec3};
+    use bevy_tasks::{ComputeTaskPool, TaskPool};
+
+    fn propagate(world: &mut World) {
+        let mut schedule = Schedule::default();
+        schedule.add_systems((
+            sync_simple_transforms,
+            (mark_dirty_trees, propagate_transforms).chain(),
+        ));
+        schedule.run(world);
+    }
+
+    #[test]
+    fn reparenting_in_place_uses_current_transforms() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        let hand = world
+            .spawn(Transform::from_xyz(1.0, 2.0, 0.0).with_rotation(Quat::from_rotation_y(1.0)))
+            .id();
+        let item = world.spawn(Transform::from_xyz(5.0, 0.0, 0.0)).id();
+        propagate(&mut world);
+
+        // Both move during the frame, before the transforms are propagated again.
+        world.get_mut::<Transform>(hand).unwrap().translation.x = 3.0;
+        world.get_mut::<Transform>(item).unwrap().translation.z = 4.0;
+        world.entity_mut(hand).add_child_in_place(item);
+        propagate(&mut world);
+
+        let global = world.get::<GlobalTransform>(item).unwrap().translation();
+        assert!(global.abs_diff_eq(Vec3::new(5.0, 0.0, 4.0), 1e-5));
+
+        world
+            .entity_mut(hand)
+            .get_mut::<Transform>()
+            .unwrap()
+            .translation
+            .y = 10.0;
+        world.entity_mut(item).remove_parent_in_place();
+        propagate(&mut world);
+
+        let global = world.get::<GlobalTransform>(item).unwrap().translation();
+        assert!(global.abs_diff_eq(Vec3::new(5.0, 8.0, 4.0), 1e-5));
+        assert!(world.get::<Parent>(item).is_none());
+    }
 }
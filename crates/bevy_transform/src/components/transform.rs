@@ -37,7 +37,11 @@ use {bevy_ecs::reflect::ReflectComponent, bevy_reflect::prelude::*};
 /// [transform_example]: https://github.com/bevyengine/bevy/blob/latest/examples/transforms/transform.rs
 #[derive(Debug, PartialEq, Clone, Copy)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "bevy-support", derive(Component), require(GlobalTransform))]
+#[cfg_attr(
+    feature = "bevy-support",
+    derive(Component),
+    require(GlobalTransform, TransformTreeChanged)
+)]
 #[cfg_attr(
     feature = "bevy_reflect",
     derive(Reflect),
@@ -560,6 +564,22 @@ impl Default for Transform {
     }
 }
 
+/// A marker of the hierarchies with a changed [`Transform`], used to skip the unchanged ones during
+/// transform propagation.
+///
+/// It is marked as changed on every entity whose [`Transform`] or [`Parent`](bevy_hierarchy::Parent)
+/// changed, and on all of its ancestors, by [`mark_dirty_trees`](crate::systems::mark_dirty_trees).
+/// [`propagate_transforms`](crate::systems::propagate_transforms) then only descends into the
+/// children whose marker is changed, so a static hierarchy costs nothing to propagate.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "bevy-support", derive(Component))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug)
+)]
+pub struct TransformTreeChanged;
+
 /// The transform is expected to be non-degenerate and without shearing, or the output
 /// will be invalid.
 impl From<GlobalTransform> for Transform {
@@ -0,0 +1,281 @@
+//! Support for worlds too large for `f32` coordinates, through a floating origin.
+//!
+//! The precision of an `f32` decreases with its magnitude: a few kilometers away from the origin,
+//! positions can only be represented to about a millimeter, which is already visible as jittering
+//! when rendering. Rather than rendering in `f64`, the [`LargeWorldPlugin`] keeps the [`Transform`]
+//! of entities relative to a [`WorldOrigin`] close to the camera, where `f32` is precise, while
+//! their actual position in the world is a [`WorldPosition`] in `f64`.
+//!
+//! The origin follows the entity marked with [`FloatingOrigin`], typically the camera, and is
+//! moved to it once it strays farther than [`LargeWorldPlugin::rebase_distance`], which updates the
+//! [`Transform`] of every entity with a [`WorldPosition`].
+//!
+//! ```
+//! # use bevy_app::{App, Update};
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_math::DVec3;
+//! # use bevy_transform::{large_world::*, prelude::*};
+//! # #[derive(Component)]
+//! # struct Spaceship;
+//! fn fly(mut ships: Query<&mut WorldPosition, With<Spaceship>>) {
+//!     for mut position in &mut ships {
+//!         // Simulate in `f64`, even millions of kilometers away from the origin.
+//!         position.0 += DVec3::new(0.0, 0.0, -1000.0);
+//!     }
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_plugins((TransformPlugin, LargeWorldPlugin::default()))
+//!     .add_systems(Update, fly);
+//! app.world_mut().spawn((
+//!     Spaceship,
+//!     WorldPosition(DVec3::new(1.0e12, 0.0, 0.0)),
+//!     FloatingOrigin,
+//! ));
+//! ```
+//!
+//! Only entities without a [`Parent`] can have a [`WorldPosition`]: the [`Transform`] of their
+//! children stays relative to them, and is propagated as usual.
+
+use bevy_app::{App, Plugin, PostStartup, PostUpdate};
+use bevy_ecs::{
+    change_detection::{DetectChanges, Ref},
+    component::Component,
+    prelude::require,
+    query::{With, Without},
+    schedule::{IntoSystemConfigs, IntoSystemSetConfigs, SystemSet},
+    system::{Query, Res, ResMut, Resource},
+};
+use bevy_hierarchy::Parent;
+use bevy_math::{DVec3, Vec3};
+
+use crate::{
+    components::{GlobalTransform, Transform},
+    TransformSystem,
+};
+
+#[cfg(feature = "bevy_reflect")]
+use {bevy_ecs::reflect::ReflectComponent, bevy_reflect::prelude::*};
+
+/// The position of an entity in the world, in `f64`.
+///
+/// The translation of its [`Transform`] is kept relative to the [`WorldOrigin`] by the
+/// [`LargeWorldPlugin`], so should not be changed directly.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+#[require(Transform)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug)
+)]
+pub struct WorldPosition(pub DVec3);
+
+impl WorldPosition {
+    /// Moves the position by `offset`.
+    pub fn translate(&mut self, offset: Vec3) {
+        self.0 += offset.as_dvec3();
+    }
+}
+
+/// Marks the entity the [`WorldOrigin`] follows, typically the camera.
+///
+/// It must also have a [`WorldPosition`]. If several entities are marked, only one of them is
+/// followed.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[require(WorldPosition)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug)
+)]
+pub struct FloatingOrigin;
+
+/// The position in the world of the origin of [`Transform`]s and [`GlobalTransform`]s.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct WorldOrigin(pub DVec3);
+
+impl WorldOrigin {
+    /// Returns the position in the world of a translation relative to the origin.
+    pub fn to_world(&self, translation: Vec3) -> DVec3 {
+        self.0 + translation.as_dvec3()
+    }
+
+    /// Returns the translation relative to the origin of a position in the world.
+    pub fn to_local(&self, position: DVec3) -> Vec3 {
+        (position - self.0).as_vec3()
+    }
+
+    /// Returns the position in the world of an entity from its [`GlobalTransform`], including
+    /// entities that are in a hierarchy.
+    pub fn world_position(&self, global_transform: &GlobalTransform) -> DVec3 {
+        self.to_world(global_transform.translation())
+    }
+}
+
+/// Set for the systems of the [`LargeWorldPlugin`], which run before
+/// [`TransformSystem::TransformPropagate`].
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub struct LargeWorldSystem;
+
+/// The [`LargeWorldPlugin::rebase_distance`] used by the systems of the plugin.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct FloatingOriginSettings {
+    /// See [`LargeWorldPlugin::rebase_distance`].
+    pub rebase_distance: f64,
+}
+
+/// A plugin moving the [`WorldOrigin`] along with the [`FloatingOrigin`], and keeping the
+/// [`Transform`] of entities with a [`WorldPosition`] relative to it. See the
+/// [module documentation](self).
+pub struct LargeWorldPlugin {
+    /// How far the [`FloatingOrigin`] can get from the [`WorldOrigin`] before the origin is moved
+    /// to it. Defaults to 1000 units, where an `f32` is still precise to about 0.1 millimeter.
+    ///
+    /// Moving the origin updates the [`Transform`] of every entity with a [`WorldPosition`], so it
+    /// should not happen every frame.
+    pub rebase_distance: f64,
+}
+
+impl Default for LargeWorldPlugin {
+    fn default() -> Self {
+        Self {
+            rebase_distance: 1000.0,
+        }
+    }
+}
+
+impl Plugin for LargeWorldPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<WorldPosition>()
+            .register_type::<FloatingOrigin>();
+
+        app.init_resource::<WorldOrigin>()
+            .insert_resource(FloatingOriginSettings {
+                rebase_distance: self.rebase_distance,
+            })
+            .configure_sets(
+                PostStartup,
+                LargeWorldSystem.before(TransformSystem::TransformPropagate),
+            )
+            .configure_sets(
+                PostUpdate,
+                LargeWorldSystem.before(TransformSystem::TransformPropagate),
+            )
+            .add_systems(
+                PostStartup,
+                (rebase_floating_origin, apply_world_positions)
+                    .chain()
+                    .in_set(LargeWorldSystem),
+            )
+            .add_systems(
+                PostUpdate,
+                (rebase_floating_origin, apply_world_positions)
+                    .chain()
+                    .in_set(LargeWorldSystem),
+            );
+    }
+}
+
+/// Moves the [`WorldOrigin`] to the [`FloatingOrigin`] once it is farther than
+/// [`FloatingOriginSettings::rebase_distance`] from it.
+pub fn rebase_floating_origin(
+    settings: Res<FloatingOriginSettings>,
+    mut origin: ResMut<WorldOrigin>,
+    focus: Query<&WorldPosition, With<FloatingOrigin>>,
+) {
+    let Some(focus) = focus.iter().next() else {
+        return;
+    };
+    if focus.0.distance_squared(origin.0) > settings.rebase_distance * settings.rebase_distance {
+        origin.0 = focus.0;
+    }
+}
+
+/// Updates the translation of the [`Transform`] of entities with a [`WorldPosition`], relative to
+/// the [`WorldOrigin`].
+///
+/// Only the entities whose [`WorldPosition`] changed are updated, unless the origin just moved.
+pub fn apply_world_positions(
+    origin: Res<WorldOrigin>,
+    mut query: Query<(Ref<WorldPosition>, &mut Transform), Without<Parent>>,
+) {
+    for (position, mut transform) in &mut query {
+        if !position.is_changed() && !origin.is_changed() {
+            continue;
+        }
+        let translation = origin.to_local(position.0);
+        // Avoid triggering a propagation when nothing moved.
+        if transform.translation != translation {
+            transform.translation = translation;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TransformPlugin;
+    use bevy_app::App;
+
+    fn app(rebase_distance: f64) -> App {
+        let mut app = App::new();
+        app.add_plugins((TransformPlugin, LargeWorldPlugin { rebase_distance }));
+        app
+    }
+
+    #[test]
+    fn transforms_are_relative_to_the_origin() {
+        let mut app = app(100.0);
+        let far = 1.0e9;
+        let camera = app
+            .world_mut()
+            .spawn((WorldPosition(DVec3::new(far, 0.0, 0.0)), FloatingOrigin))
+            .id();
+        let near = app
+            .world_mut()
+            .spawn(WorldPosition(DVec3::new(far + 0.25, 0.0, 0.0)))
+            .id();
+        app.update();
+
+        // The origin moved to the camera, and the nearby entity is precisely positioned.
+        assert_eq!(app.world().resource::<WorldOrigin>().0.x, far);
+        assert_eq!(
+            app.world()
+                .get::<GlobalTransform>(near)
+                .unwrap()
+                .translation(),
+            Vec3::new(0.25, 0.0, 0.0)
+        );
+
+        // Moving within the rebase distance doesn't move the origin.
+        app.world_mut()
+            .get_mut::<WorldPosition>(camera)
+            .unwrap()
+            .translate(Vec3::new(50.0, 0.0, 0.0));
+        app.update();
+        assert_eq!(app.world().resource::<WorldOrigin>().0.x, far);
+        assert_eq!(
+            app.world().get::<Transform>(camera).unwrap().translation,
+            Vec3::new(50.0, 0.0, 0.0)
+        );
+
+        // Beyond it the origin moves, and every transform follows.
+        app.world_mut()
+            .get_mut::<WorldPosition>(camera)
+            .unwrap()
+            .translate(Vec3::new(100.0, 0.0, 0.0));
+        app.update();
+        let origin = *app.world().resource::<WorldOrigin>();
+        assert_eq!(origin.0.x, far + 150.0);
+        assert_eq!(
+            app.world().get::<Transform>(near).unwrap().translation,
+            Vec3::new(-149.75, 0.0, 0.0)
+        );
+        let global_transform = app.world().get::<GlobalTransform>(near).unwrap();
+        assert_eq!(
+            origin.world_position(global_transform),
+            DVec3::new(far + 0.25, 0.0, 0.0)
+        );
+    }
+}
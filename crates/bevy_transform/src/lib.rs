@@ -33,6 +33,8 @@ pub mod plugins;
 /// Helpers related to computing global transforms
 #[cfg(feature = "bevy-support")]
 pub mod helper;
+#[cfg(feature = "bevy-support")]
+pub mod large_world;
 /// Systems responsible for transform propagation
 #[cfg(feature = "bevy-support")]
 pub mod systems;
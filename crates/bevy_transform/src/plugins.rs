@@ -4,11 +4,13 @@ use bevy_hierarchy::ValidParentCheckPlugin;
 
 use crate::{
     components::GlobalTransform,
-    systems::{propagate_transforms, sync_simple_transforms},
+    systems::{
+        mark_dirty_trees, propagate_transforms, sync_simple_transforms, TransformPropagationStats,
+    },
 };
 
 #[cfg(feature = "bevy_reflect")]
-use crate::components::Transform;
+use crate::components::{Transform, TransformTreeChanged};
 
 /// Set enum for the systems relating to transform propagation
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
@@ -30,9 +32,11 @@ impl Plugin for TransformPlugin {
 
         #[cfg(feature = "bevy_reflect")]
         app.register_type::<Transform>()
-            .register_type::<GlobalTransform>();
+            .register_type::<GlobalTransform>()
+            .register_type::<TransformTreeChanged>();
 
-        app.add_plugins(ValidParentCheckPlugin::<GlobalTransform>::default())
+        app.init_resource::<TransformPropagationStats>()
+            .add_plugins(ValidParentCheckPlugin::<GlobalTransform>::default())
             .configure_sets(
                 PostStartup,
                 PropagateTransformsSet.in_set(TransformSystem::TransformPropagate),
@@ -47,7 +51,9 @@ impl Plugin for TransformPlugin {
                         // These systems cannot access the same entities,
                         // due to subtle query filtering that is not yet correctly computed in the ambiguity detector
                         .ambiguous_with(PropagateTransformsSet),
-                    propagate_transforms.in_set(PropagateTransformsSet),
+                    (mark_dirty_trees, propagate_transforms)
+                        .chain()
+                        .in_set(PropagateTransformsSet),
                 ),
             )
             .configure_sets(
@@ -60,7 +66,9 @@ impl Plugin for TransformPlugin {
                     sync_simple_transforms
                         .in_set(TransformSystem::TransformPropagate)
                         .ambiguous_with(PropagateTransformsSet),
-                    propagate_transforms.in_set(PropagateTransformsSet),
+                    (mark_dirty_trees, propagate_transforms)
+                        .chain()
+                        .in_set(PropagateTransformsSet),
                 ),
             );
     }
@@ -1,13 +1,36 @@
-use crate::components::{GlobalTransform, Transform};
+use crate::components::{GlobalTransform, Transform, TransformTreeChanged};
 use alloc::vec::Vec;
 use bevy_ecs::{
     change_detection::Ref,
-    prelude::{Changed, DetectChanges, Entity, Query, With, Without},
+    prelude::{Changed, DetectChanges, DetectChangesMut, Entity, Query, With, Without},
     query::{Added, Or},
     removal_detection::RemovedComponents,
-    system::{Local, ParamSet},
+    system::{Local, ParamSet, ResMut, Resource, SystemChangeTick},
 };
 use bevy_hierarchy::{Children, Parent};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Statistics about the last run of [`propagate_transforms`], for diagnostics.
+///
+/// It is updated if present, which [`TransformPlugin`](crate::TransformPlugin) ensures.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransformPropagationStats {
+    /// The number of hierarchies with a changed [`Transform`], out of all hierarchies.
+    pub dirty_roots: usize,
+    /// The number of hierarchies in total.
+    pub roots: usize,
+    /// The number of entities in a hierarchy whose [`GlobalTransform`] was updated.
+    pub propagated: usize,
+    /// The number of entities in a hierarchy that were visited, updated or not.
+    pub visited: usize,
+}
+
+#[derive(Default)]
+struct PropagationCounters {
+    dirty_roots: AtomicUsize,
+    propagated: AtomicUsize,
+    visited: AtomicUsize,
+}
 
 /// Update [`GlobalTransform`] component of entities that aren't in the hierarchy
 ///
@@ -43,28 +66,84 @@ pub fn sync_simple_transforms(
     }
 }
 
+/// Marks the [`TransformTreeChanged`] of every entity whose [`Transform`] or [`Parent`] changed,
+/// and of all of its ancestors, so that [`propagate_transforms`] only visits the changed parts of
+/// hierarchies.
+///
+/// Third party plugins should ensure that this runs before [`propagate_transforms`].
+pub fn mark_dirty_trees(
+    changed_transforms: Query<
+        Entity,
+        Or<(Changed<Transform>, Changed<Parent>, Added<GlobalTransform>)>,
+    >,
+    mut orphaned: RemovedComponents<Parent>,
+    mut transforms: Query<(Option<&Parent>, &mut TransformTreeChanged)>,
+    ticks: SystemChangeTick,
+) {
+    for entity in changed_transforms.iter().chain(orphaned.read()) {
+        let mut next = entity;
+        while let Ok((parent, mut tree)) = transforms.get_mut(next) {
+            // The ancestors of an entity marked during this run were marked along with it.
+            if tree.last_changed() == ticks.this_run() {
+                break;
+            }
+            tree.set_changed();
+            let Some(parent) = parent else { break };
+            next = parent.get();
+        }
+    }
+}
+
 /// Update [`GlobalTransform`] component of entities based on entity hierarchy and
 /// [`Transform`] component.
 ///
-/// Third party plugins should ensure that this is used in concert with [`sync_simple_transforms`].
+/// Only the subtrees whose [`TransformTreeChanged`] was marked by [`mark_dirty_trees`] are
+/// visited, and the roots of different hierarchies are processed in parallel. Entities without a
+/// [`TransformTreeChanged`] are always visited.
+///
+/// Third party plugins should ensure that this is used in concert with [`sync_simple_transforms`]
+/// and [`mark_dirty_trees`].
 pub fn propagate_transforms(
     mut root_query: Query<
-        (Entity, &Children, Ref<Transform>, &mut GlobalTransform),
+        (
+            Entity,
+            &Children,
+            Ref<Transform>,
+            &mut GlobalTransform,
+            Option<Ref<TransformTreeChanged>>,
+        ),
         Without<Parent>,
     >,
     mut orphaned: RemovedComponents<Parent>,
-    transform_query: Query<(Ref<Transform>, &mut GlobalTransform, Option<&Children>), With<Parent>>,
+    transform_query: Query<
+        (
+            Ref<Transform>,
+            &mut GlobalTransform,
+            Option<&Children>,
+            Option<Ref<TransformTreeChanged>>,
+        ),
+        With<Parent>,
+    >,
     parent_query: Query<(Entity, Ref<Parent>), With<GlobalTransform>>,
     mut orphaned_entities: Local<Vec<Entity>>,
+    stats: Option<ResMut<TransformPropagationStats>>,
 ) {
     orphaned_entities.clear();
     orphaned_entities.extend(orphaned.read());
     orphaned_entities.sort_unstable();
+    let counters = PropagationCounters::default();
     root_query.par_iter_mut().for_each(
-        |(entity, children, transform, mut global_transform)| {
+        |(entity, children, transform, mut global_transform, tree)| {
+            if tree.is_some_and(|tree| !tree.is_changed()) {
+                return;
+            }
+            counters.dirty_roots.fetch_add(1, Ordering::Relaxed);
+            counters.visited.fetch_add(1, Ordering::Relaxed);
+
             let changed = transform.is_changed() || global_transform.is_added() || orphaned_entities.binary_search(&entity).is_ok();
             if changed {
                 *global_transform = GlobalTransform::from(*transform);
+                counters.propagated.fetch_add(1, Ordering::Relaxed);
             }
 
             for (child, actual_parent) in parent_query.iter_many(children) {
@@ -88,11 +167,21 @@ pub fn propagate_transforms(
                         &parent_query,
                         child,
                         changed || actual_parent.is_changed(),
+                        &counters,
                     );
                 }
             }
         },
     );
+
+    if let Some(mut stats) = stats {
+        *stats = TransformPropagationStats {
+            dirty_roots: counters.dirty_roots.into_inner(),
+            roots: root_query.iter().len(),
+            propagated: counters.propagated.into_inner(),
+            visited: counters.visited.into_inner(),
+        };
+    }
 }
 
 /// Recursively propagates the transforms for `entity` and all of its descendants.
@@ -115,15 +204,21 @@ pub fn propagate_transforms(
 unsafe fn propagate_recursive(
     parent: &GlobalTransform,
     transform_query: &Query<
-        (Ref<Transform>, &mut GlobalTransform, Option<&Children>),
+        (
+            Ref<Transform>,
+            &mut GlobalTransform,
+            Option<&Children>,
+            Option<Ref<TransformTreeChanged>>,
+        ),
         With<Parent>,
     >,
     parent_query: &Query<(Entity, Ref<Parent>), With<GlobalTransform>>,
     entity: Entity,
     mut changed: bool,
+    counters: &PropagationCounters,
 ) {
     let (global_matrix, children) = {
-        let Ok((transform, mut global_transform, children)) =
+        let Ok((transform, mut global_transform, children, tree)) =
             // SAFETY: This call cannot create aliased mutable references.
             //   - The top level iteration parallelizes on the roots of the hierarchy.
             //   - The caller ensures that each child has one and only one unique parent throughout the entire
@@ -154,9 +249,17 @@ unsafe fn propagate_recursive(
                 return;
             };
 
+        // Nothing changed in the subtree of an entity whose tree isn't marked, unless its parent
+        // changed, in which case the whole subtree is updated regardless.
+        if !changed && tree.is_some_and(|tree| !tree.is_changed()) {
+            return;
+        }
+        counters.visited.fetch_add(1, Ordering::Relaxed);
+
         changed |= transform.is_changed() || global_transform.is_added();
         if changed {
             *global_transform = parent.mul_transform(*transform);
+            counters.propagated.fetch_add(1, Ordering::Relaxed);
         }
         (global_transform, children)
     };
@@ -179,6 +282,7 @@ unsafe fn propagate_recursive(
                 parent_query,
                 child,
                 changed || actual_parent.is_changed(),
+                counters,
             );
         }
     }
@@ -204,7 +308,10 @@ mod test {
         let offset_transform = |offset| Transform::from_xyz(offset, offset, offset);
 
         let mut schedule = Schedule::default();
-        schedule.add_systems((sync_simple_transforms, propagate_transforms));
+        schedule.add_systems((
+            sync_simple_transforms,
+            (mark_dirty_trees, propagate_transforms).chain(),
+        ));
 
         let mut command_queue = CommandQueue::default();
         let mut commands = Commands::new(&mut command_queue, &world);
@@ -255,7 +362,10 @@ mod test {
         let mut world = World::default();
 
         let mut schedule = Schedule::default();
-        schedule.add_systems((sync_simple_transforms, propagate_transforms));
+        schedule.add_systems((
+            sync_simple_transforms,
+            (mark_dirty_trees, propagate_transforms).chain(),
+        ));
 
         // Root entity
         world.spawn(Transform::from_xyz(1.0, 0.0, 0.0));
@@ -285,7 +395,10 @@ mod test {
         let mut world = World::default();
 
         let mut schedule = Schedule::default();
-        schedule.add_systems((sync_simple_transforms, propagate_transforms));
+        schedule.add_systems((
+            sync_simple_transforms,
+            (mark_dirty_trees, propagate_transforms).chain(),
+        ));
 
         // Root entity
         let mut queue = CommandQueue::default();
@@ -317,7 +430,10 @@ mod test {
         let mut world = World::default();
 
         let mut schedule = Schedule::default();
-        schedule.add_systems((sync_simple_transforms, propagate_transforms));
+        schedule.add_systems((
+            sync_simple_transforms,
+            (mark_dirty_trees, propagate_transforms).chain(),
+        ));
 
         // Add parent entities
         let mut children = Vec::new();
@@ -393,7 +509,13 @@ mod test {
         let mut app = App::new();
         ComputeTaskPool::get_or_init(TaskPool::default);
 
-        app.add_systems(Update, (sync_simple_transforms, propagate_transforms));
+        app.add_systems(
+            Update,
+            (
+                sync_simple_transforms,
+                (mark_dirty_trees, propagate_transforms).chain(),
+            ),
+        );
 
         let translation = vec3(1.0, 0.0, 0.0);
 
@@ -439,7 +561,13 @@ mod test {
         let mut temp = World::new();
         let mut app = App::new();
 
-        app.add_systems(Update, (propagate_transforms, sync_simple_transforms));
+        app.add_systems(
+            Update,
+            (
+                (mark_dirty_trees, propagate_transforms).chain(),
+                sync_simple_transforms,
+            ),
+        );
 
         fn setup_world(world: &mut World) -> (Entity, Entity) {
             let mut grandchild = Entity::from_raw(0);
@@ -476,7 +604,10 @@ mod test {
 
         // Create transform propagation schedule
         let mut schedule = Schedule::default();
-        schedule.add_systems((sync_simple_transforms, propagate_transforms));
+        schedule.add_systems((
+            sync_simple_transforms,
+            (mark_dirty_trees, propagate_transforms).chain(),
+        ));
 
         // Spawn a `Transform` entity with a local translation of `Vec3::ONE`
         let mut spawn_transform_bundle =
@@ -517,4 +648,71 @@ mod test {
             *world.entity(child).get::<GlobalTransform>().unwrap()
         );
     }
+
+    #[test]
+    fn propagation_skips_unchanged_trees() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::default();
+        world.init_resource::<TransformPropagationStats>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((
+            sync_simple_transforms,
+            (mark_dirty_trees, propagate_transforms).chain(),
+        ));
+
+        // Two hierarchies of a root with two children, the first of which has a child.
+        let spawn_tree = |world: &mut World| {
+            let mut leaf = Entity::PLACEHOLDER;
+            let root = world
+                .spawn(Transform::from_xyz(1.0, 0.0, 0.0))
+                .with_children(|parent| {
+                    parent
+                        .spawn(Transform::from_xyz(0.0, 1.0, 0.0))
+                        .with_children(|parent| {
+                            leaf = parent.spawn(Transform::from_xyz(0.0, 0.0, 1.0)).id();
+                        });
+                    parent.spawn(Transform::IDENTITY);
+                })
+                .id();
+            (root, leaf)
+        };
+        let (_, leaf) = spawn_tree(&mut world);
+        let (other_root, _) = spawn_tree(&mut world);
+
+        schedule.run(&mut world);
+        let stats = *world.resource::<TransformPropagationStats>();
+        assert_eq!(stats.roots, 2);
+        assert_eq!(stats.dirty_roots, 2);
+        assert_eq!(stats.propagated, 8);
+
+        // Nothing changed, so nothing is visited.
+        schedule.run(&mut world);
+        let stats = *world.resource::<TransformPropagationStats>();
+        assert_eq!(stats.dirty_roots, 0);
+        assert_eq!(stats.visited, 0);
+
+        // Only the path from the root to the changed leaf is visited.
+        world.get_mut::<Transform>(leaf).unwrap().translation.z = 2.0;
+        schedule.run(&mut world);
+        let stats = *world.resource::<TransformPropagationStats>();
+        assert_eq!(stats.dirty_roots, 1);
+        assert_eq!(stats.visited, 3);
+        assert_eq!(stats.propagated, 1);
+        assert_eq!(
+            *world.get::<GlobalTransform>(leaf).unwrap(),
+            GlobalTransform::from_xyz(1.0, 1.0, 2.0)
+        );
+
+        // A changed root updates its whole tree.
+        world
+            .get_mut::<Transform>(other_root)
+            .unwrap()
+            .translation
+            .x = 2.0;
+        schedule.run(&mut world);
+        let stats = *world.resource::<TransformPropagationStats>();
+        assert_eq!(stats.dirty_roots, 1);
+        assert_eq!(stats.propagated, 4);
+    }
 }
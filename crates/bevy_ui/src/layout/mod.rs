@@ -490,7 +490,7 @@ mod tests {
     use bevy_render::{camera::ManualTextureViews, prelude::Camera};
     use bevy_transform::{
         prelude::GlobalTransform,
-        systems::{propagate_transforms, sync_simple_transforms},
+        systems::{mark_dirty_trees, propagate_transforms, sync_simple_transforms},
     };
     use bevy_utils::{prelude::default, HashMap};
     use bevy_window::{
@@ -544,6 +544,7 @@ mod tests {
                 ApplyDeferred,
                 ui_layout_system,
                 sync_simple_transforms,
+                mark_dirty_trees,
                 propagate_transforms,
             )
                 .chain(),
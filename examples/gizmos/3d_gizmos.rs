@@ -78,6 +78,7 @@ fn setup(
     commands.spawn((
         Text::new(
             "Press 'T' to toggle drawing gizmos on top of everything else in the scene\n\
+            Press 'X' to toggle drawing occluded parts of gizmos dimmed (x-ray)\n\
             Press 'P' to toggle perspective for line gizmos\n\
             Hold 'Left' or 'Right' to change the line width of straight gizmos\n\
             Hold 'Up' or 'Down' to change the line width of round gizmos\n\
@@ -213,6 +214,14 @@ fn update_config(
             config.depth_bias = if config.depth_bias == 0. { -1. } else { 0. };
         }
     }
+    if keyboard.just_pressed(KeyCode::KeyX) {
+        for (_, config, _) in config_store.iter_mut() {
+            config.depth_mode = match config.depth_mode {
+                GizmoDepthMode::XRay { .. } => GizmoDepthMode::DepthTest,
+                _ => GizmoDepthMode::XRAY,
+            };
+        }
+    }
     if keyboard.just_pressed(KeyCode::KeyP) {
         for (_, config, _) in config_store.iter_mut() {
             // Toggle line perspective